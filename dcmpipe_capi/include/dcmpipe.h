@@ -0,0 +1,79 @@
+/*
+ * C ABI for parsing DICOM datasets with dcmpipe.
+ *
+ * Datasets are parsed into a DcmpipeDataset handle which must be released with
+ * dcmpipe_dataset_free(). Element handles are owned by their dataset and valid until it's
+ * released. Strings returned as `char *` must be released with dcmpipe_string_free(), while
+ * strings returned as `const char *` are owned by the library.
+ *
+ * Functions which fail return NULL or a negative value, after which dcmpipe_last_error()
+ * describes the failure.
+ */
+
+#ifndef DCMPIPE_H
+#define DCMPIPE_H
+
+#include <stddef.h>
+#include <stdint.h>
+#include <sys/types.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct DcmpipeDataset DcmpipeDataset;
+typedef struct DcmpipeElement DcmpipeElement;
+
+/* The most recent failure on this thread, or NULL. Valid until the next failure. */
+const char *dcmpipe_last_error(void);
+
+/* Parses a dataset from a buffer, which is copied. */
+DcmpipeDataset *dcmpipe_open_buffer(const uint8_t *data, size_t len);
+
+/* Parses the dataset of the file at the UTF-8 path. */
+DcmpipeDataset *dcmpipe_open_path(const char *path);
+
+void dcmpipe_dataset_free(DcmpipeDataset *dataset);
+
+/* The number of elements, including those within sequences. */
+size_t dcmpipe_element_count(const DcmpipeDataset *dataset);
+
+/* The element at the index, in the order encoded, or NULL if out of range. */
+const DcmpipeElement *dcmpipe_element_at(const DcmpipeDataset *dataset, size_t index);
+
+/* The top-level element with the tag, e.g. 0x00100010, or NULL if not present. */
+const DcmpipeElement *dcmpipe_find_element(const DcmpipeDataset *dataset, uint32_t tag);
+
+uint32_t dcmpipe_element_tag(const DcmpipeElement *element);
+
+/* The VR, e.g. "PN". Owned by the element. */
+const char *dcmpipe_element_vr(const DcmpipeElement *element);
+
+/* The number of sequences the element is nested within, 0 for top-level elements. */
+size_t dcmpipe_element_depth(const DcmpipeElement *element);
+
+/* The path of the element within the dataset. Owned by the element. */
+const char *dcmpipe_element_path(const DcmpipeElement *element);
+
+/* The number of values, or -1 if the value can't be parsed. */
+ssize_t dcmpipe_element_value_count(const DcmpipeElement *element);
+
+/* The values separated by '\', or NULL if binary. Release with dcmpipe_string_free(). */
+char *dcmpipe_element_string(const DcmpipeElement *element);
+
+/* Writes the numeric value at the index to out, returning 0, or -1 on failure. */
+int dcmpipe_element_f64(const DcmpipeElement *element, size_t index, double *out);
+
+/* Writes the integer value at the index to out, returning 0, or -1 on failure. */
+int dcmpipe_element_i64(const DcmpipeElement *element, size_t index, int64_t *out);
+
+/* The encoded bytes of the value, writing their number to len. Owned by the element. */
+const uint8_t *dcmpipe_element_bytes(const DcmpipeElement *element, size_t *len);
+
+void dcmpipe_string_free(char *string);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* DCMPIPE_H */
@@ -0,0 +1,436 @@
+//! A C ABI over `dcmpipe_lib`, for embedding the parser in C, C++, or C# applications. The
+//! declarations are in `include/dcmpipe.h`.
+//!
+//! A dataset is parsed from a buffer or a path into an opaque `DcmpipeDataset` handle, which must
+//! be released with `dcmpipe_dataset_free()`. Its elements, including those within sequences, are
+//! visited in order by index, and the `DcmpipeElement` handles returned are owned by the dataset
+//! and valid until it's released. Strings returned as `char *` are allocated and must be released
+//! with `dcmpipe_string_free()`, while strings returned as `const char *` are owned by a handle.
+//!
+//! Functions which fail return null or a negative value, after which `dcmpipe_last_error()`
+//! describes the failure. Panics are caught rather than unwinding into the caller.
+
+use std::{
+    cell::RefCell,
+    ffi::{c_char, c_int, CStr, CString},
+    fs::File,
+    io::{BufReader, Cursor, Read},
+    panic::{catch_unwind, UnwindSafe},
+    ptr, slice,
+};
+
+use dcmpipe_lib::{
+    core::{
+        dcmelement::DicomElement,
+        dcmobject::DicomRoot,
+        defn::tag::{Tag, TagPath},
+        read::ParserBuilder,
+        values::RawValue,
+    },
+    dict::stdlookup::STANDARD_DICOM_DICTIONARY,
+};
+
+/// A parsed dataset and a handle for each of its elements.
+pub struct DcmpipeDataset {
+    // The handles point into the dataset, so are declared first to be dropped first.
+    elements: Vec<DcmpipeElement>,
+    _dcmroot: Box<DicomRoot<'static>>,
+}
+
+/// An element of a dataset, along with its location in the dataset.
+pub struct DcmpipeElement {
+    element: *const DicomElement<'static>,
+    depth: usize,
+    vr: CString,
+    path: CString,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Describes the most recent failure of a function called on this thread, or returns null if none
+/// has failed. The string is owned by the library and valid until the next failure on this
+/// thread.
+#[no_mangle]
+pub extern "C" fn dcmpipe_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(ptr::null(), |error| error.as_ptr())
+    })
+}
+
+/// Parses a dataset from a buffer of `len` bytes, which may start with a File Preamble. The buffer
+/// is copied, so may be released once this returns. Returns null if the buffer isn't DICOM.
+///
+/// # Safety
+///
+/// `data` must point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn dcmpipe_open_buffer(data: *const u8, len: usize) -> *mut DcmpipeDataset {
+    if data.is_null() {
+        return fail("buffer is null");
+    }
+    let bytes: Vec<u8> = slice::from_raw_parts(data, len).to_vec();
+    guard(ptr::null_mut(), move || open(Cursor::new(bytes)))
+}
+
+/// Parses the dataset of the file at the path, which is UTF-8. Returns null if the file can't be
+/// read or isn't DICOM.
+///
+/// # Safety
+///
+/// `path` must be a null-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn dcmpipe_open_path(path: *const c_char) -> *mut DcmpipeDataset {
+    let Some(path) = to_str(path) else {
+        return fail("path is null or not UTF-8");
+    };
+    guard(ptr::null_mut(), move || match File::open(path) {
+        Ok(file) => open(BufReader::new(file)),
+        Err(e) => fail(&format!("failed to open {path}: {e}")),
+    })
+}
+
+/// Releases the dataset and its elements.
+///
+/// # Safety
+///
+/// `dataset` must be null or a dataset returned by `dcmpipe_open_buffer()` or
+/// `dcmpipe_open_path()` which hasn't been released.
+#[no_mangle]
+pub unsafe extern "C" fn dcmpipe_dataset_free(dataset: *mut DcmpipeDataset) {
+    if !dataset.is_null() {
+        drop(Box::from_raw(dataset));
+    }
+}
+
+/// The number of elements in the dataset, including those within sequences.
+///
+/// # Safety
+///
+/// `dataset` must be a valid dataset.
+#[no_mangle]
+pub unsafe extern "C" fn dcmpipe_element_count(dataset: *const DcmpipeDataset) -> usize {
+    dataset.as_ref().map_or(0, |dataset| dataset.elements.len())
+}
+
+/// Gets the element at the index, in the order elements are encoded in the dataset. The elements
+/// of a sequence's items follow the sequence. Returns null if the index is out of range.
+///
+/// # Safety
+///
+/// `dataset` must be a valid dataset.
+#[no_mangle]
+pub unsafe extern "C" fn dcmpipe_element_at(
+    dataset: *const DcmpipeDataset,
+    index: usize,
+) -> *const DcmpipeElement {
+    match dataset.as_ref().and_then(|ds| ds.elements.get(index)) {
+        Some(element) => element,
+        None => fail_with(ptr::null(), &format!("no element at index {index}")),
+    }
+}
+
+/// Gets the top-level element of the dataset with the tag, e.g. `0x00100010` for Patient's Name.
+/// Returns null if the dataset doesn't have the element.
+///
+/// # Safety
+///
+/// `dataset` must be a valid dataset.
+#[no_mangle]
+pub unsafe extern "C" fn dcmpipe_find_element(
+    dataset: *const DcmpipeDataset,
+    tag: u32,
+) -> *const DcmpipeElement {
+    let found: Option<&DcmpipeElement> = dataset.as_ref().and_then(|dataset| {
+        dataset
+            .elements
+            .iter()
+            .find(|handle| handle.depth == 0 && element(handle).tag() == tag)
+    });
+    match found {
+        Some(handle) => handle,
+        None => fail_with(
+            ptr::null(),
+            &format!("no element {}", Tag::format_tag_to_display(tag)),
+        ),
+    }
+}
+
+/// The tag of the element, with the group in the high 16 bits.
+///
+/// # Safety
+///
+/// `element` must be a valid element.
+#[no_mangle]
+pub unsafe extern "C" fn dcmpipe_element_tag(element: *const DcmpipeElement) -> u32 {
+    element
+        .as_ref()
+        .map_or(0, |handle| self::element(handle).tag())
+}
+
+/// The VR of the element, e.g. `PN`. The string is owned by the element.
+///
+/// # Safety
+///
+/// `element` must be a valid element.
+#[no_mangle]
+pub unsafe extern "C" fn dcmpipe_element_vr(element: *const DcmpipeElement) -> *const c_char {
+    element
+        .as_ref()
+        .map_or(ptr::null(), |handle| handle.vr.as_ptr())
+}
+
+/// The number of sequences the element is nested within, 0 for top-level elements.
+///
+/// # Safety
+///
+/// `element` must be a valid element.
+#[no_mangle]
+pub unsafe extern "C" fn dcmpipe_element_depth(element: *const DcmpipeElement) -> usize {
+    element.as_ref().map_or(0, |handle| handle.depth)
+}
+
+/// The path of the element within the dataset, including the sequences and items it's nested
+/// within, e.g. `ReferencedStudySequence[1].ReferencedSOPInstanceUID`. The string is owned by the
+/// element.
+///
+/// # Safety
+///
+/// `element` must be a valid element.
+#[no_mangle]
+pub unsafe extern "C" fn dcmpipe_element_path(element: *const DcmpipeElement) -> *const c_char {
+    element
+        .as_ref()
+        .map_or(ptr::null(), |handle| handle.path.as_ptr())
+}
+
+/// The number of values of the element, or -1 if its value can't be parsed.
+///
+/// # Safety
+///
+/// `element` must be a valid element.
+#[no_mangle]
+pub unsafe extern "C" fn dcmpipe_element_value_count(element: *const DcmpipeElement) -> isize {
+    guard(-1, || match values(element) {
+        Ok(values) => values.len() as isize,
+        Err(e) => fail_with(-1, &e),
+    })
+}
+
+/// The values of the element as a string, multiple values separated by `\`. Numbers are formatted
+/// in decimal and attribute tags as `(GGGG,EEEE)`. Returns null if the value is binary, e.g. OB,
+/// see `dcmpipe_element_bytes()`. The string must be released with `dcmpipe_string_free()`.
+///
+/// # Safety
+///
+/// `element` must be a valid element.
+#[no_mangle]
+pub unsafe extern "C" fn dcmpipe_element_string(element: *const DcmpipeElement) -> *mut c_char {
+    guard(ptr::null_mut(), || {
+        let joined: String = match values(element) {
+            Ok(values) => values.join("\\"),
+            Err(e) => return fail(&e),
+        };
+        match CString::new(joined) {
+            Ok(string) => string.into_raw(),
+            Err(_) => fail("value contains a null character"),
+        }
+    })
+}
+
+/// Writes the numeric value at the index to `out`, returning 0, or returns -1 if the element
+/// doesn't have a numeric value at the index.
+///
+/// # Safety
+///
+/// `element` must be a valid element and `out` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn dcmpipe_element_f64(
+    element: *const DcmpipeElement,
+    index: usize,
+    out: *mut f64,
+) -> c_int {
+    guard(-1, || {
+        let value: Result<f64, String> = values(element).and_then(|values| {
+            let value: &String = values
+                .get(index)
+                .ok_or_else(|| format!("no value at index {index}"))?;
+            value
+                .parse::<f64>()
+                .map_err(|_| format!("value is not numeric: {value}"))
+        });
+        match (value, out.is_null()) {
+            (Ok(value), false) => {
+                *out = value;
+                0
+            }
+            (Ok(_), true) => fail_with(-1, "out is null"),
+            (Err(e), _) => fail_with(-1, &e),
+        }
+    })
+}
+
+/// Writes the integer value at the index to `out`, returning 0, or returns -1 if the element
+/// doesn't have an integer value at the index.
+///
+/// # Safety
+///
+/// `element` must be a valid element and `out` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn dcmpipe_element_i64(
+    element: *const DcmpipeElement,
+    index: usize,
+    out: *mut i64,
+) -> c_int {
+    guard(-1, || {
+        let value: Result<i64, String> = values(element).and_then(|values| {
+            let value: &String = values
+                .get(index)
+                .ok_or_else(|| format!("no value at index {index}"))?;
+            value
+                .parse::<i64>()
+                .map_err(|_| format!("value is not an integer: {value}"))
+        });
+        match (value, out.is_null()) {
+            (Ok(value), false) => {
+                *out = value;
+                0
+            }
+            (Ok(_), true) => fail_with(-1, "out is null"),
+            (Err(e), _) => fail_with(-1, &e),
+        }
+    })
+}
+
+/// The bytes of the element's value as encoded, writing their number to `len`. The bytes are owned
+/// by the element. Returns null with a `len` of 0 if the value is empty or isn't held in memory.
+///
+/// # Safety
+///
+/// `element` must be a valid element and `len` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn dcmpipe_element_bytes(
+    element: *const DcmpipeElement,
+    len: *mut usize,
+) -> *const u8 {
+    let data: &[u8] = element
+        .as_ref()
+        .map_or(&[], |handle| self::element(handle).data());
+    if !len.is_null() {
+        *len = data.len();
+    }
+    if data.is_empty() {
+        ptr::null()
+    } else {
+        data.as_ptr()
+    }
+}
+
+/// Releases a string returned by this library.
+///
+/// # Safety
+///
+/// `string` must be null or a string returned as `char *` by this library which hasn't been
+/// released.
+#[no_mangle]
+pub unsafe extern "C" fn dcmpipe_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+fn open(dataset: impl Read) -> *mut DcmpipeDataset {
+    let mut parser = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .build(dataset);
+    let dcmroot: DicomRoot<'static> = match DicomRoot::parse(&mut parser) {
+        Ok(Some(dcmroot)) => dcmroot,
+        Ok(None) => return fail("dataset is not DICOM"),
+        Err(e) => return fail(&format!("failed to parse dataset: {e}")),
+    };
+    // The elements are boxed along with the root, so their addresses are stable while the dataset
+    // is alive.
+    let dcmroot: Box<DicomRoot<'static>> = Box::new(dcmroot);
+    let elements: Vec<DcmpipeElement> = dcmroot
+        .iter_elements()
+        .map(
+            |(path, element): (TagPath, &DicomElement<'static>)| DcmpipeElement {
+                element,
+                depth: path.nodes.len().saturating_sub(1),
+                vr: CString::new(element.vr().ident).unwrap_or_default(),
+                path: CString::new(TagPath::format_tagpath_to_display(
+                    &path,
+                    Some(&STANDARD_DICOM_DICTIONARY),
+                ))
+                .unwrap_or_default(),
+            },
+        )
+        .collect();
+    Box::into_raw(Box::new(DcmpipeDataset {
+        elements,
+        _dcmroot: dcmroot,
+    }))
+}
+
+fn element(handle: &DcmpipeElement) -> &DicomElement<'static> {
+    // The element is owned by the dataset the handle belongs to, which outlives the handle.
+    unsafe { &*handle.element }
+}
+
+/// Parses the values of the element as strings.
+unsafe fn values(element: *const DcmpipeElement) -> Result<Vec<String>, String> {
+    let element: &DicomElement = self::element(element.as_ref().ok_or("element is null")?);
+    let value: RawValue = element.parse_value().map_err(|e| e.to_string())?;
+    let strings: Vec<String> = match value {
+        RawValue::Attribute(attrs) => attrs
+            .iter()
+            .map(|attr| Tag::format_tag_to_display(attr.0))
+            .collect(),
+        RawValue::Uid(uid) => vec![uid],
+        RawValue::Strings(strings) => strings,
+        RawValue::Shorts(values) => to_strings(&values),
+        RawValue::UnsignedShorts(values) => to_strings(&values),
+        RawValue::Integers(values) => to_strings(&values),
+        RawValue::UnsignedIntegers(values) => to_strings(&values),
+        RawValue::Longs(values) => to_strings(&values),
+        RawValue::UnsignedLongs(values) => to_strings(&values),
+        RawValue::Floats(values) => to_strings(&values),
+        RawValue::Doubles(values) => to_strings(&values),
+        RawValue::Words(values) => to_strings(&values),
+        RawValue::DoubleWords(values) => to_strings(&values),
+        RawValue::QuadWords(values) => to_strings(&values),
+        RawValue::Bytes(bytes) if bytes.is_empty() => Vec::new(),
+        RawValue::Bytes(_) | RawValue::BulkData(_) => return Err("value is binary".to_owned()),
+    };
+    Ok(strings)
+}
+
+fn to_strings<T: ToString>(values: &[T]) -> Vec<String> {
+    values.iter().map(T::to_string).collect()
+}
+
+unsafe fn to_str<'s>(string: *const c_char) -> Option<&'s str> {
+    if string.is_null() {
+        return None;
+    }
+    CStr::from_ptr(string).to_str().ok()
+}
+
+/// Records the failure for `dcmpipe_last_error()` and returns null.
+fn fail<T>(message: &str) -> *mut T {
+    fail_with(ptr::null_mut(), message)
+}
+
+fn fail_with<T>(result: T, message: &str) -> T {
+    let error: CString = CString::new(message.replace('\0', "")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(error));
+    result
+}
+
+/// Runs the function, returning `failed` if it panics.
+fn guard<T>(failed: T, f: impl FnOnce() -> T + UnwindSafe) -> T {
+    catch_unwind(f).unwrap_or_else(|_| fail_with(failed, "panicked"))
+}
@@ -0,0 +1,115 @@
+use std::{
+    ffi::{CStr, CString},
+    ptr,
+};
+
+use dcmpipe_capi::*;
+use dcmpipe_lib::testkit::Fixture;
+
+const PATIENT_ID: u32 = 0x0010_0020;
+const SOP_INSTANCE_UID: u32 = 0x0008_0018;
+const REFERENCED_SOP_INSTANCE_UID: u32 = 0x0008_1155;
+const FILE_META_INFORMATION_VERSION: u32 = 0x0002_0001;
+
+unsafe fn owned_string(string: *mut std::ffi::c_char) -> String {
+    assert!(!string.is_null());
+    let value: String = CStr::from_ptr(string).to_string_lossy().into_owned();
+    dcmpipe_string_free(string);
+    value
+}
+
+unsafe fn last_error() -> String {
+    CStr::from_ptr(dcmpipe_last_error())
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Checks parsing a dataset from a buffer and reading its elements, including those in sequences.
+#[test]
+fn test_open_buffer() {
+    let fixture = Fixture::UndefinedLengthSequence;
+    let bytes: Vec<u8> = fixture.to_bytes().expect("Fixture should be written");
+    unsafe {
+        let dataset = dcmpipe_open_buffer(bytes.as_ptr(), bytes.len());
+        assert!(!dataset.is_null());
+
+        let patient_id = dcmpipe_find_element(dataset, PATIENT_ID);
+        assert_eq!(PATIENT_ID, dcmpipe_element_tag(patient_id));
+        assert_eq!(c"LO", CStr::from_ptr(dcmpipe_element_vr(patient_id)));
+        assert_eq!(0, dcmpipe_element_depth(patient_id));
+        assert_eq!(1, dcmpipe_element_value_count(patient_id));
+        assert_eq!("TESTKIT", owned_string(dcmpipe_element_string(patient_id)));
+
+        let sop_uid = dcmpipe_find_element(dataset, SOP_INSTANCE_UID);
+        assert_eq!(
+            fixture.sop_instance_uid(),
+            owned_string(dcmpipe_element_string(sop_uid))
+        );
+
+        // Elements within the sequence's items are visited, but not found at the top level.
+        let count: usize = dcmpipe_element_count(dataset);
+        let nested: Vec<String> = (0..count)
+            .map(|index| dcmpipe_element_at(dataset, index))
+            .filter(|element| dcmpipe_element_tag(*element) == REFERENCED_SOP_INSTANCE_UID)
+            .map(|element| {
+                assert_eq!(1, dcmpipe_element_depth(element));
+                owned_string(dcmpipe_element_string(element))
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                format!("{}.1", fixture.sop_instance_uid()),
+                format!("{}.2", fixture.sop_instance_uid()),
+            ],
+            nested
+        );
+        assert!(dcmpipe_find_element(dataset, REFERENCED_SOP_INSTANCE_UID).is_null());
+        assert!(last_error().contains("(0008,1155)"));
+        assert!(dcmpipe_element_at(dataset, count).is_null());
+
+        // Binary values are only available as bytes.
+        let version = dcmpipe_find_element(dataset, FILE_META_INFORMATION_VERSION);
+        assert!(dcmpipe_element_string(version).is_null());
+        let mut len: usize = 0;
+        let data: *const u8 = dcmpipe_element_bytes(version, &mut len);
+        assert_eq!(&[0u8, 1], std::slice::from_raw_parts(data, len));
+
+        let mut number: f64 = 0.0;
+        assert_eq!(-1, dcmpipe_element_f64(patient_id, 0, &mut number));
+        assert!(last_error().contains("not numeric"));
+
+        dcmpipe_dataset_free(dataset);
+    }
+}
+
+/// Checks numeric values, and failures to open datasets.
+#[test]
+fn test_values_and_errors() {
+    let bytes: Vec<u8> = Fixture::UndefinedLengthSequence
+        .to_bytes()
+        .expect("Fixture should be written");
+    unsafe {
+        let dataset = dcmpipe_open_buffer(bytes.as_ptr(), bytes.len());
+        // The group length of the File Meta group is a UL.
+        let group_length = dcmpipe_element_at(dataset, 0);
+        assert_eq!(0x0002_0000, dcmpipe_element_tag(group_length));
+        let mut length: i64 = 0;
+        assert_eq!(0, dcmpipe_element_i64(group_length, 0, &mut length));
+        assert!(length > 0);
+        let mut float: f64 = 0.0;
+        assert_eq!(0, dcmpipe_element_f64(group_length, 0, &mut float));
+        assert_eq!(length as f64, float);
+        assert_eq!(-1, dcmpipe_element_i64(group_length, 1, &mut length));
+        assert!(last_error().contains("no value at index 1"));
+        dcmpipe_dataset_free(dataset);
+
+        let garbage: [u8; 4] = [1, 2, 3, 4];
+        assert!(dcmpipe_open_buffer(garbage.as_ptr(), garbage.len()).is_null());
+        assert!(!last_error().is_empty());
+        assert!(dcmpipe_open_buffer(ptr::null(), 0).is_null());
+
+        let missing: CString = CString::new("/nonexistent/dataset.dcm").expect("valid path");
+        assert!(dcmpipe_open_path(missing.as_ptr()).is_null());
+        assert!(last_error().contains("failed to open"));
+    }
+}
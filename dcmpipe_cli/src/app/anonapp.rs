@@ -0,0 +1,165 @@
+//! The anon command de-identifies DICOM datasets, writing the results to a new location.
+
+use std::{
+    env,
+    fs::{self, File},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Context, Result};
+use walkdir::WalkDir;
+
+use dcmpipe_lib::{
+    core::{
+        dcmobject::DicomRoot,
+        pixeldata::redact_regions,
+        read::{Parser, ParserBuilder},
+        write::{builder::WriterBuilder, writer::Writer},
+    },
+    dict::stdlookup::STANDARD_DICOM_DICTIONARY,
+};
+
+use crate::{
+    app::CommandApplication,
+    args::{AnonArgs, DateMode},
+    shutdown,
+};
+
+use self::{dates::DateHandling, deid::Deidentifier, profile::Profile, remap::ReidentificationMap};
+
+mod dates;
+mod deid;
+mod profile;
+mod remap;
+
+/// The pipeline stage recorded as modifying elements, when tracking provenance.
+static ANON_STAGE: &str = "anon";
+
+pub struct AnonApp {
+    args: AnonArgs,
+    map: ReidentificationMap,
+    dates: DateHandling,
+    profile: Profile,
+}
+
+impl AnonApp {
+    pub fn new(args: AnonArgs) -> AnonApp {
+        AnonApp {
+            args,
+            map: ReidentificationMap::default(),
+            dates: DateHandling::Keep,
+            profile: Profile::default(),
+        }
+    }
+
+    /// Lists the datasets to de-identify along with the path each is written to.
+    fn get_files(&self) -> Result<Vec<(PathBuf, PathBuf)>> {
+        if self.args.source.is_file() {
+            return Ok(vec![(
+                self.args.source.clone(),
+                self.args.destination.clone(),
+            )]);
+        }
+        if !self.args.source.is_dir() {
+            return Err(anyhow!("invalid source: {}", self.args.source.display()));
+        }
+
+        let mut files: Vec<(PathBuf, PathBuf)> = Vec::new();
+        for entry in WalkDir::new(&self.args.source)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+        {
+            let relative: &Path = entry.path().strip_prefix(&self.args.source)?;
+            let dest: PathBuf = self.args.destination.join(relative);
+            files.push((entry.into_path(), dest));
+        }
+        Ok(files)
+    }
+
+    /// De-identifies a single dataset, returning `false` if it isn't DICOM.
+    fn anonymize(&mut self, path: &Path, dest: &Path) -> Result<bool> {
+        let file: File =
+            File::open(path).with_context(|| format!("Failed to open: {}", path.display()))?;
+        let mut parser: Parser<'_, File> = ParserBuilder::default()
+            .dictionary(&STANDARD_DICOM_DICTIONARY)
+            .build(file);
+        let Some(mut dcmroot) = DicomRoot::parse(&mut parser)? else {
+            return Ok(false);
+        };
+
+        if !self.args.mask.is_empty() {
+            redact_regions(&mut dcmroot, &self.args.mask, ANON_STAGE)
+                .context("Failed to mask pixel data")?;
+        }
+        Deidentifier::new(&mut self.map, &self.dates, &self.profile)
+            .deidentify(&mut dcmroot)
+            .context("Failed to de-identify")?;
+
+        dcmroot.update_value_lengths();
+        let mut writer: Writer<Vec<u8>> =
+            WriterBuilder::for_file().ts(dcmroot.ts()).build(Vec::new());
+        writer.write_dcmroot(&dcmroot)?;
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create folder: {}", parent.display()))?;
+        }
+        fs::write(dest, writer.into_dataset()?)
+            .with_context(|| format!("Failed to write: {}", dest.display()))?;
+        Ok(true)
+    }
+}
+
+impl CommandApplication for AnonApp {
+    fn run(&mut self) -> Result<()> {
+        shutdown::install_handler()?;
+
+        let passphrase: Option<String> =
+            read_secret(self.args.map_password_env.as_deref(), "map passphrase")?;
+        let date_seed: Option<String> =
+            read_secret(self.args.date_seed_env.as_deref(), "date seed")?;
+        if let Some(profile_path) = &self.args.profile {
+            self.profile = Profile::load(profile_path)?;
+        }
+        let dates: DateMode = self.args.dates.or(self.profile.dates()).unwrap_or_default();
+        self.dates = DateHandling::new(dates, self.args.max_date_shift, date_seed);
+        if let Some(map_path) = self.args.map.as_ref().filter(|p| p.is_file()) {
+            self.map = ReidentificationMap::load(map_path, passphrase.as_deref())?;
+        }
+
+        let mut anonymized: usize = 0;
+        let mut skipped: usize = 0;
+        for (path, dest) in self.get_files()? {
+            if shutdown::is_requested() {
+                println!("Anonymization interrupted before: {}", path.display());
+                break;
+            }
+
+            match self.anonymize(&path, &dest) {
+                Ok(true) => anonymized += 1,
+                Ok(false) => {
+                    println!("Skipping non-DICOM file: {}", path.display());
+                    skipped += 1;
+                }
+                Err(e) => {
+                    println!("Skipping {}, {e:#}", path.display());
+                    skipped += 1;
+                }
+            }
+        }
+
+        // The map is saved even if interrupted, as it applies to the datasets already written.
+        if let Some(map_path) = &self.args.map {
+            self.map.save(map_path, passphrase.as_deref())?;
+        }
+
+        println!("Anonymized {anonymized} files, skipped {skipped}");
+        Ok(())
+    }
+}
+
+/// Reads a secret from the given environment variable, if one was specified.
+fn read_secret(var: Option<&str>, name: &str) -> Result<Option<String>> {
+    var.map(|var| env::var(var).with_context(|| format!("Failed to read {name} from ${var}")))
+        .transpose()
+}
@@ -0,0 +1,245 @@
+//! De-identification of the values of `DA` and `DT` elements, which begin with a `YYYYMMDD` date.
+
+use rand::{rngs::OsRng, Rng};
+use sha2::{Digest, Sha256};
+
+use crate::args::DateMode;
+
+/// How dates are de-identified, see `DateMode`.
+#[derive(Debug, Clone)]
+pub(super) enum DateHandling {
+    Keep,
+    Shift {
+        /// The most days dates are shifted by, earlier or later.
+        max_days: u32,
+        /// The secret each patient's shift is derived from, otherwise shifts are random.
+        seed: Option<String>,
+    },
+    Year,
+    Clear,
+}
+
+impl DateHandling {
+    pub(super) fn new(mode: DateMode, max_days: u32, seed: Option<String>) -> DateHandling {
+        match mode {
+            DateMode::Keep => DateHandling::Keep,
+            DateMode::Shift => DateHandling::Shift { max_days, seed },
+            DateMode::Year => DateHandling::Year,
+            DateMode::Clear => DateHandling::Clear,
+        }
+    }
+
+    /// The number of days to shift the dates of a patient by, which is never zero. With a seed
+    /// this is the same for the same Patient ID and seed.
+    pub(super) fn patient_shift(&self, patient_id: &str) -> i64 {
+        let DateHandling::Shift { max_days, seed } = self else {
+            return 0;
+        };
+        let max_days: u64 = u64::from(*max_days);
+        let offset: u64 = match seed {
+            Some(seed) => {
+                let digest = Sha256::new()
+                    .chain_update(seed.as_bytes())
+                    .chain_update([0u8])
+                    .chain_update(patient_id.as_bytes())
+                    .finalize();
+                let mut bytes: [u8; 8] = [0u8; 8];
+                bytes.copy_from_slice(&digest[..8]);
+                u64::from_le_bytes(bytes) % (2 * max_days)
+            }
+            None => OsRng.gen_range(0..2 * max_days),
+        };
+        // Map [0, 2 * max) to [-max, -1] and [1, max].
+        let shift: i64 = offset as i64 - max_days as i64;
+        if shift >= 0 {
+            shift + 1
+        } else {
+            shift
+        }
+    }
+
+    /// De-identifies the value of a `DA` or `DT` element, which is `None` if it should be left
+    /// as-is. The value is removed if it can't be shifted or truncated.
+    pub(super) fn apply(&self, value: &str, shift: i64, is_datetime: bool) -> Option<String> {
+        match self {
+            DateHandling::Keep => None,
+            DateHandling::Shift { .. } => Some(shift_date(value, shift).unwrap_or_default()),
+            DateHandling::Year => Some(truncate_to_year(value, is_datetime).unwrap_or_default()),
+            DateHandling::Clear => Some(String::new()),
+        }
+    }
+}
+
+/// Shifts the date at the start of a `DA` or `DT` value by the given number of days, keeping any
+/// time and offset which follow it. Returns `None` if the value doesn't begin with a valid date.
+fn shift_date(value: &str, days: i64) -> Option<String> {
+    let (date, rest) = value.split_at_checked(8)?;
+    if !date.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let year: i64 = date[0..4].parse().ok()?;
+    let month: i64 = date[4..6].parse().ok()?;
+    let day: i64 = date[6..8].parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    // Days past the end of the month, e.g. February 31st, would otherwise roll into the next.
+    let days_since_epoch: i64 = days_from_civil(year, month, day);
+    if civil_from_days(days_since_epoch) != (year, month, day) {
+        return None;
+    }
+
+    let (year, month, day) = civil_from_days(days_since_epoch + days);
+    if !(0..=9999).contains(&year) {
+        return None;
+    }
+    Some(format!("{year:04}{month:02}{day:02}{rest}"))
+}
+
+/// Truncates a `DA` value to January 1st of its year, or a `DT` value to its year. Returns `None`
+/// if the value doesn't begin with a year.
+fn truncate_to_year(value: &str, is_datetime: bool) -> Option<String> {
+    let year: &str = value.get(0..4)?;
+    if !year.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    if is_datetime {
+        Some(year.to_owned())
+    } else {
+        Some(format!("{year}0101"))
+    }
+}
+
+/// The number of days since 1970-01-01 of the given proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year: i64 = if month <= 2 { year - 1 } else { year };
+    let era: i64 = year.div_euclid(400);
+    let year_of_era: i64 = year - era * 400;
+    let day_of_year: i64 = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era: i64 = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The proleptic Gregorian date which is the given number of days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days: i64 = days + 719_468;
+    let era: i64 = days.div_euclid(146_097);
+    let day_of_era: i64 = days - era * 146_097;
+    let year_of_era: i64 =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year: i64 = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp: i64 = (5 * day_of_year + 2) / 153;
+    let day: i64 = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month: i64 = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year: i64 = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_civil_days() {
+        assert_eq!(0, days_from_civil(1970, 1, 1));
+        assert_eq!((1970, 1, 1), civil_from_days(0));
+        assert_eq!(-1, days_from_civil(1969, 12, 31));
+        assert_eq!(11_016, days_from_civil(2000, 2, 29));
+        assert_eq!((2000, 2, 29), civil_from_days(11_016));
+        for days in [-719_528, -1, 0, 59, 11_016, 2_932_896] {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days, days_from_civil(year, month, day));
+        }
+    }
+
+    #[test]
+    fn test_shift_date_rollover() {
+        // Month
+        assert_eq!(Some("20230201".to_owned()), shift_date("20230131", 1));
+        assert_eq!(Some("20230131".to_owned()), shift_date("20230201", -1));
+        // Year
+        assert_eq!(Some("20240101".to_owned()), shift_date("20231231", 1));
+        assert_eq!(Some("20221231".to_owned()), shift_date("20230101", -1));
+        // Leap day
+        assert_eq!(Some("20240229".to_owned()), shift_date("20240228", 1));
+        assert_eq!(Some("20240301".to_owned()), shift_date("20240229", 1));
+        assert_eq!(Some("20230301".to_owned()), shift_date("20230228", 1));
+        assert_eq!(Some("19000301".to_owned()), shift_date("19000228", 1));
+        assert_eq!(Some("20000229".to_owned()), shift_date("20000228", 1));
+        assert_eq!(Some("20250228".to_owned()), shift_date("20240229", 365));
+    }
+
+    #[test]
+    fn test_shift_datetime() {
+        assert_eq!(
+            Some("20240101123045.123456+0100".to_owned()),
+            shift_date("20231231123045.123456+0100", 1)
+        );
+        assert_eq!(Some("2023123023".to_owned()), shift_date("2023123123", -1));
+    }
+
+    #[test]
+    fn test_shift_invalid_date() {
+        assert_eq!(None, shift_date("20230231", 1));
+        assert_eq!(None, shift_date("20230229", 1));
+        assert_eq!(None, shift_date("20231301", 1));
+        assert_eq!(None, shift_date("20230100", 1));
+        assert_eq!(None, shift_date("2023", 1));
+        assert_eq!(None, shift_date("2023-01-01", 1));
+        assert_eq!(
+            Some(String::new()),
+            DateHandling::Shift {
+                max_days: 10,
+                seed: None
+            }
+            .apply("20230231", 1, false)
+        );
+    }
+
+    #[test]
+    fn test_shift_out_of_range() {
+        assert_eq!(None, shift_date("00000101", -1));
+        assert_eq!(None, shift_date("99991231", 1));
+        assert_eq!(Some("00000101".to_owned()), shift_date("00000102", -1));
+        assert_eq!(Some("99991231".to_owned()), shift_date("99991230", 1));
+    }
+
+    #[test]
+    fn test_patient_shift() {
+        for max_days in [1, 2, 365] {
+            let handling: DateHandling = DateHandling::Shift {
+                max_days,
+                seed: Some("secret".to_owned()),
+            };
+            for id in 0..1000 {
+                let patient_id: String = format!("PAT{id}");
+                let shift: i64 = handling.patient_shift(&patient_id);
+                assert_ne!(0, shift);
+                assert!(shift.unsigned_abs() <= u64::from(max_days));
+                assert_eq!(shift, handling.patient_shift(&patient_id));
+            }
+        }
+
+        let random: DateHandling = DateHandling::Shift {
+            max_days: 1,
+            seed: None,
+        };
+        for _ in 0..100 {
+            assert!([-1, 1].contains(&random.patient_shift("PAT")));
+        }
+        assert_eq!(0, DateHandling::Keep.patient_shift("PAT"));
+    }
+
+    #[test]
+    fn test_truncate_to_year() {
+        assert_eq!(
+            Some("20230101".to_owned()),
+            truncate_to_year("20231231", false)
+        );
+        assert_eq!(
+            Some("2023".to_owned()),
+            truncate_to_year("20231231123045", true)
+        );
+        assert_eq!(None, truncate_to_year("20a3", false));
+    }
+}
@@ -0,0 +1,335 @@
+//! De-identification of the attributes of a dataset, loosely following the Basic Application Level
+//! Confidentiality Profile of Part 15, Annex E.
+//!
+//! - Private elements and attributes identifying the patient, institution, or staff are removed.
+//! - Patient IDs, Accession Numbers, Study IDs, and UIDs other than those defined by the standard
+//!   are replaced, consistently through the re-identification map.
+//! - Dates are shifted, truncated, or removed. Shifted dates of a patient are all shifted by the
+//!   number of days recorded in the map for the patient.
+//!
+//! The actions of an anonymization profile take precedence over these rules, see `profile`.
+
+use anyhow::{anyhow, Result};
+use sha2::{Digest, Sha256};
+
+use dcmpipe_lib::{
+    core::{
+        dcmelement::DicomElement,
+        dcmobject::DicomRoot,
+        defn::{
+            dcmdict::DicomDictionary,
+            tag::{Tag, TagPath},
+            vr,
+        },
+        values::RawValue,
+    },
+    dict::tags,
+};
+
+use super::{
+    dates::DateHandling,
+    profile::{PrivateTagPolicy, Profile, TagAction},
+    remap::ReidentificationMap,
+    ANON_STAGE,
+};
+
+/// Attributes which are removed entirely.
+static REMOVED: [&Tag; 26] = [
+    &tags::InstitutionName,
+    &tags::InstitutionAddress,
+    &tags::ReferringPhysiciansAddress,
+    &tags::ReferringPhysiciansTelephoneNumbers,
+    &tags::StationName,
+    &tags::InstitutionalDepartmentName,
+    &tags::PhysiciansofRecord,
+    &tags::PerformingPhysiciansName,
+    &tags::NameofPhysiciansReadingStudy,
+    &tags::OperatorsName,
+    &tags::IssuerofPatientID,
+    &tags::PatientsBirthTime,
+    &tags::OtherPatientIDs,
+    &tags::OtherPatientNames,
+    &tags::OtherPatientIDsSequence,
+    &tags::PatientsBirthName,
+    &tags::PatientsAddress,
+    &tags::PatientsMothersBirthName,
+    &tags::MilitaryRank,
+    &tags::BranchofService,
+    &tags::MedicalRecordLocator,
+    &tags::PatientsTelephoneNumbers,
+    &tags::Occupation,
+    &tags::AdditionalPatientHistory,
+    &tags::PatientComments,
+    &tags::DeviceSerialNumber,
+];
+
+/// Attributes whose value is removed, as they're required to be present.
+static EMPTIED: [&Tag; 2] = [&tags::ReferringPhysiciansName, &tags::RequestingPhysician];
+
+/// The value of `DeidentificationMethod` added to de-identified datasets.
+static DEIDENTIFICATION_METHOD: &str = "dcmpipe anon";
+
+/// A change to a single element.
+enum Action {
+    Remove,
+    Replace(RawValue),
+}
+
+/// De-identifies datasets, recording replaced values in the re-identification map.
+pub(super) struct Deidentifier<'m> {
+    map: &'m mut ReidentificationMap,
+    dates: &'m DateHandling,
+    profile: &'m Profile,
+}
+
+impl<'m> Deidentifier<'m> {
+    pub(super) fn new(
+        map: &'m mut ReidentificationMap,
+        dates: &'m DateHandling,
+        profile: &'m Profile,
+    ) -> Deidentifier<'m> {
+        Deidentifier {
+            map,
+            dates,
+            profile,
+        }
+    }
+
+    pub(super) fn deidentify(&mut self, dcmroot: &mut DicomRoot) -> Result<()> {
+        let patient_id: String = match dcmroot.get_child_by_tag(tags::PatientID.tag) {
+            Some(obj) => strings(obj.element())?.join("\\"),
+            None => String::new(),
+        };
+        let date_shift: i64 = match self.dates {
+            DateHandling::Shift { .. } => {
+                let dates: &DateHandling = self.dates;
+                self.map
+                    .date_shift(&patient_id, || dates.patient_shift(&patient_id))
+            }
+            _ => 0,
+        };
+
+        let dictionary: &dyn DicomDictionary = dcmroot.dictionary();
+        let mut actions: Vec<(TagPath, Action)> = Vec::new();
+        for (tagpath, element) in dcmroot.iter_elements() {
+            if let Some(action) = self.action(element, &patient_id, date_shift, dictionary)? {
+                actions.push((tagpath, action));
+            }
+        }
+
+        for (tagpath, action) in actions {
+            // Elements within a sequence which was already removed no longer exist.
+            match action {
+                Action::Remove => {
+                    dcmroot.remove_element(&tagpath);
+                }
+                Action::Replace(value) => {
+                    dcmroot
+                        .modify_element(&tagpath, ANON_STAGE, |element| {
+                            element.encode_value(value, None)
+                        })
+                        .transpose()?;
+                }
+            }
+        }
+
+        if let Some(sop_uid) = dcmroot
+            .get_child_by_tag(tags::SOPInstanceUID.tag)
+            .map(|obj| obj.element().parse_value())
+            .transpose()?
+        {
+            dcmroot
+                .modify_element(
+                    &TagPath::from(&tags::MediaStorageSOPInstanceUID),
+                    ANON_STAGE,
+                    |element| element.encode_value(sop_uid, None),
+                )
+                .transpose()?;
+        }
+
+        insert_string(dcmroot, &tags::PatientIdentityRemoved, &vr::CS, "YES")?;
+        insert_string(
+            dcmroot,
+            &tags::DeidentificationMethod,
+            &vr::LO,
+            DEIDENTIFICATION_METHOD,
+        )?;
+        Ok(())
+    }
+
+    /// Determines how the element is de-identified, if at all.
+    fn action(
+        &mut self,
+        element: &DicomElement,
+        patient_id: &str,
+        date_shift: i64,
+        dictionary: &dyn DicomDictionary,
+    ) -> Result<Option<Action>> {
+        let tag: u32 = element.tag();
+        // The File Meta group only refers to the dataset by its SOP Instance UID, which is
+        // updated to match once the dataset is de-identified.
+        if tag >> 16 == 0x0002 {
+            return Ok(None);
+        }
+        if let Some(action) = self.profile.action(tag) {
+            return self.profile_action(element, action);
+        }
+        if Tag::is_private(tag) {
+            return Ok(match self.profile.private_tags() {
+                PrivateTagPolicy::Remove => Some(Action::Remove),
+                PrivateTagPolicy::Keep => None,
+            });
+        }
+        if REMOVED.iter().any(|t| t.tag == tag) {
+            return Ok(Some(Action::Remove));
+        }
+        if EMPTIED.iter().any(|t| t.tag == tag) {
+            return Ok(Some(Action::Replace(RawValue::Strings(Vec::new()))));
+        }
+        if element.is_seq_like() || element.is_empty() {
+            return Ok(None);
+        }
+
+        let action: Option<Action> = if tag == tags::PatientID.tag {
+            replace_string(self.map.patient_id(patient_id))
+        } else if tag == tags::PatientsName.tag {
+            // The patient remains recognizable by their replacement ID.
+            if patient_id.is_empty() {
+                Some(Action::Replace(RawValue::Strings(Vec::new())))
+            } else {
+                replace_string(self.map.patient_id(patient_id))
+            }
+        } else if tag == tags::AccessionNumber.tag {
+            replace_string(self.map.accession_number(&strings(element)?.join("\\")))
+        } else if tag == tags::StudyID.tag {
+            replace_string(self.map.study_id(&strings(element)?.join("\\")))
+        } else if element.vr() == &vr::UI {
+            let uids: Vec<String> = strings(element)?;
+            let replaced: Vec<String> = uids
+                .iter()
+                .map(|uid| {
+                    if dictionary.get_uid_by_uid(uid).is_some() {
+                        uid.clone()
+                    } else {
+                        self.map.uid(uid)
+                    }
+                })
+                .collect();
+            (replaced != uids).then_some(Action::Replace(RawValue::Strings(replaced)))
+        } else if element.vr() == &vr::DA || element.vr() == &vr::DT {
+            let is_datetime: bool = element.vr() == &vr::DT;
+            let dates: Vec<String> = strings(element)?;
+            let replaced: Option<Vec<String>> = dates
+                .iter()
+                .map(|date| self.dates.apply(date, date_shift, is_datetime))
+                .collect();
+            replaced.map(|dates| Action::Replace(RawValue::Strings(dates)))
+        } else {
+            self.scrub_action(element)?
+        };
+        Ok(action)
+    }
+
+    /// The change to the element from the profile's scrub rules, if any match its values.
+    fn scrub_action(&self, element: &DicomElement) -> Result<Option<Action>> {
+        if element.is_empty() || !self.profile.is_scrubbed(element.tag(), element.vr()) {
+            return Ok(None);
+        }
+        let values: Vec<String> = strings(element)?;
+        let scrubbed: Vec<Option<String>> = values
+            .iter()
+            .map(|value| self.profile.scrub(element.tag(), element.vr(), value))
+            .collect();
+        if scrubbed.iter().all(Option::is_none) {
+            return Ok(None);
+        }
+        let values: Vec<String> = scrubbed
+            .into_iter()
+            .zip(values)
+            .map(|(scrubbed, value)| scrubbed.unwrap_or(value))
+            .collect();
+        Ok(Some(Action::Replace(RawValue::Strings(values))))
+    }
+
+    /// The change to the element for the action given by the profile.
+    fn profile_action(
+        &mut self,
+        element: &DicomElement,
+        action: &TagAction,
+    ) -> Result<Option<Action>> {
+        let action: Option<Action> = match action {
+            TagAction::Keep => self.scrub_action(element)?,
+            TagAction::Remove => Some(Action::Remove),
+            TagAction::Empty => Some(Action::Replace(RawValue::Bytes(Vec::new()))),
+            TagAction::Replace(value) => replace_string(value.clone()),
+            TagAction::Hash => {
+                if element.is_empty() {
+                    return Ok(None);
+                }
+                let is_uid: bool = element.vr() == &vr::UI;
+                let values: Vec<String> = match element.parse_value()? {
+                    RawValue::Uid(uid) => uid.split('\\').map(str::to_owned).collect(),
+                    RawValue::Strings(strings) => strings,
+                    _ => {
+                        return Err(anyhow!(
+                            "only text values can be hashed: {}",
+                            Tag::format_tag_to_display(element.tag())
+                        ))
+                    }
+                };
+                let hashed: Vec<String> = values
+                    .iter()
+                    .map(|value| hash_value(self.profile.hash_salt(), value, is_uid))
+                    .collect();
+                // Hashed UIDs are recorded so they can be re-identified.
+                if is_uid {
+                    for (value, hashed) in values.iter().zip(&hashed) {
+                        self.map.record_uid(value, hashed.clone());
+                    }
+                }
+                Some(Action::Replace(RawValue::Strings(hashed)))
+            }
+        };
+        Ok(action)
+    }
+}
+
+/// Hashes a value with the salt. UIDs hash to a `2.25` UID, and other values to 16 hex digits,
+/// which fits within the value of any text VR.
+fn hash_value(salt: &str, value: &str, is_uid: bool) -> String {
+    let digest = Sha256::new()
+        .chain_update(salt.as_bytes())
+        .chain_update(value.as_bytes())
+        .finalize();
+    if is_uid {
+        let mut bytes: [u8; 16] = [0u8; 16];
+        bytes.copy_from_slice(&digest[..16]);
+        format!("2.25.{}", u128::from_be_bytes(bytes))
+    } else {
+        digest[..8]
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+}
+
+fn replace_string(value: String) -> Option<Action> {
+    Some(Action::Replace(RawValue::Strings(vec![value])))
+}
+
+/// The string values of the element, with multiple values of UIDs split apart.
+fn strings(element: &DicomElement) -> Result<Vec<String>> {
+    Ok(match element.parse_value()? {
+        RawValue::Uid(uid) => uid.split('\\').map(str::to_owned).collect(),
+        RawValue::Strings(strings) => strings,
+        _ => Vec::new(),
+    })
+}
+
+/// Inserts a top-level element with a single string value, replacing any existing element.
+fn insert_string(dcmroot: &mut DicomRoot, tag: &Tag, vr: vr::VRRef, value: &str) -> Result<()> {
+    let mut element: DicomElement = DicomElement::new_empty(tag, vr, dcmroot.ts());
+    element.encode_value(RawValue::Strings(vec![value.to_owned()]), None)?;
+    dcmroot.insert_element(&TagPath::from(tag), element, ANON_STAGE)?;
+    Ok(())
+}
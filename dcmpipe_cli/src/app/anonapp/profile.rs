@@ -0,0 +1,219 @@
+//! Anonymization profiles customize how datasets are de-identified, so that a site's approved
+//! de-identification rules can be codified in a file rather than code. Profiles are read as JSON
+//! if the file has a `.json` extension, otherwise as TOML.
+//!
+//! ```toml
+//! # What to do with private elements, "remove" or "keep".
+//! private_tags = "remove"
+//! # How dates are de-identified, as with `--dates`.
+//! dates = "shift"
+//! # Prepended to values prior to hashing, which should be kept secret.
+//! hash_salt = "..."
+//!
+//! # Actions for specific attributes, by keyword or `(gggg,eeee)`, which take precedence over the
+//! # built-in rules.
+//! [tags]
+//! InstitutionName = "keep"
+//! StudyDescription = "remove"
+//! "(0010,1010)" = "empty"
+//! PatientID = "hash"
+//! OperatorsName = { replace = "ANONYMOUS" }
+//!
+//! # Replaces text matching a pattern within the values of the given attributes, or of all
+//! # text attributes if none are given.
+//! [[scrub]]
+//! pattern = "(?i)\\bdr\\.? [a-z]+"
+//! replacement = "REDACTED"
+//! tags = ["StudyDescription", "SeriesDescription"]
+//! ```
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs,
+    path::Path,
+};
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::Deserialize;
+
+use dcmpipe_lib::{
+    core::defn::{tag::Tag, vr, vr::VRRef},
+    dict::stdlookup::STANDARD_DICOM_DICTIONARY,
+};
+
+use crate::args::DateMode;
+
+/// The VRs of text attributes which are scrubbed by rules that don't list attributes.
+static SCRUBBED_VRS: [VRRef; 7] = [
+    &vr::LO,
+    &vr::LT,
+    &vr::PN,
+    &vr::SH,
+    &vr::ST,
+    &vr::UC,
+    &vr::UT,
+];
+
+/// What to do with private elements.
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub(super) enum PrivateTagPolicy {
+    #[default]
+    Remove,
+    Keep,
+}
+
+/// What to do with a specific attribute.
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub(super) enum TagAction {
+    /// Leave the value as-is, other than scrubbing.
+    Keep,
+    /// Remove the element.
+    Remove,
+    /// Remove the element's value.
+    Empty,
+    /// Replace the value with a salted hash of it, see `hash_value()`. Hashed UIDs are recorded in
+    /// the re-identification map, other hashed values can't be re-identified.
+    Hash,
+    /// Replace the value with the given value.
+    Replace(String),
+}
+
+#[derive(Deserialize, Default, Debug)]
+#[serde(default, deny_unknown_fields)]
+struct ProfileFile {
+    private_tags: PrivateTagPolicy,
+    dates: Option<DateMode>,
+    hash_salt: String,
+    tags: BTreeMap<String, TagAction>,
+    scrub: Vec<ScrubRuleFile>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct ScrubRuleFile {
+    pattern: String,
+    #[serde(default)]
+    replacement: String,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+struct ScrubRule {
+    pattern: Regex,
+    replacement: String,
+    /// The attributes the rule applies to, otherwise all text attributes.
+    tags: Option<HashSet<u32>>,
+}
+
+impl ScrubRule {
+    fn applies(&self, tag: u32, vr: VRRef) -> bool {
+        match &self.tags {
+            Some(tags) => tags.contains(&tag),
+            None => SCRUBBED_VRS.contains(&vr),
+        }
+    }
+}
+
+/// An anonymization profile. The default profile applies only the built-in rules.
+#[derive(Default)]
+pub(super) struct Profile {
+    private_tags: PrivateTagPolicy,
+    dates: Option<DateMode>,
+    hash_salt: String,
+    tags: HashMap<u32, TagAction>,
+    scrub: Vec<ScrubRule>,
+}
+
+impl Profile {
+    pub(super) fn load(path: &Path) -> Result<Profile> {
+        let contents: String = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read profile: {}", path.display()))?;
+        let file: ProfileFile = if path.extension().is_some_and(|ext| ext == "json") {
+            serde_json::from_str(&contents)
+                .with_context(|| format!("Invalid profile: {}", path.display()))?
+        } else {
+            toml::from_str(&contents)
+                .with_context(|| format!("Invalid profile: {}", path.display()))?
+        };
+
+        let mut tags: HashMap<u32, TagAction> = HashMap::new();
+        for (key, action) in file.tags {
+            tags.insert(parse_tag(&key)?, action);
+        }
+        let mut scrub: Vec<ScrubRule> = Vec::new();
+        for rule in file.scrub {
+            let pattern: Regex = Regex::new(&rule.pattern)
+                .with_context(|| format!("Invalid scrub pattern: {}", rule.pattern))?;
+            let rule_tags: Option<HashSet<u32>> = if rule.tags.is_empty() {
+                None
+            } else {
+                Some(
+                    rule.tags
+                        .iter()
+                        .map(|key| parse_tag(key))
+                        .collect::<Result<_>>()?,
+                )
+            };
+            scrub.push(ScrubRule {
+                pattern,
+                replacement: rule.replacement,
+                tags: rule_tags,
+            });
+        }
+
+        Ok(Profile {
+            private_tags: file.private_tags,
+            dates: file.dates,
+            hash_salt: file.hash_salt,
+            tags,
+            scrub,
+        })
+    }
+
+    pub(super) fn private_tags(&self) -> PrivateTagPolicy {
+        self.private_tags
+    }
+
+    pub(super) fn dates(&self) -> Option<DateMode> {
+        self.dates
+    }
+
+    pub(super) fn hash_salt(&self) -> &str {
+        &self.hash_salt
+    }
+
+    /// The action the profile specifies for the attribute, if any.
+    pub(super) fn action(&self, tag: u32) -> Option<&TagAction> {
+        self.tags.get(&tag)
+    }
+
+    /// Whether any scrub rules apply to the attribute.
+    pub(super) fn is_scrubbed(&self, tag: u32, vr: VRRef) -> bool {
+        self.scrub.iter().any(|rule| rule.applies(tag, vr))
+    }
+
+    /// Applies the scrub rules to a value of the attribute, returning `None` if none matched.
+    pub(super) fn scrub(&self, tag: u32, vr: VRRef, value: &str) -> Option<String> {
+        let mut scrubbed: Option<String> = None;
+        for rule in self.scrub.iter().filter(|rule| rule.applies(tag, vr)) {
+            let current: &str = scrubbed.as_deref().unwrap_or(value);
+            if rule.pattern.is_match(current) {
+                scrubbed = Some(
+                    rule.pattern
+                        .replace_all(current, rule.replacement.as_str())
+                        .into_owned(),
+                );
+            }
+        }
+        scrubbed
+    }
+}
+
+/// Parses an attribute given by keyword or tag number, as accepted by `Tag::parse_tag()`.
+fn parse_tag(key: &str) -> Result<u32> {
+    Tag::parse_tag(key, Some(&STANDARD_DICOM_DICTIONARY))
+        .with_context(|| format!("Unknown attribute in profile: {key}"))
+}
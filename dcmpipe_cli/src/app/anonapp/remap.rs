@@ -0,0 +1,283 @@
+//! The re-identification map records the original value of every UID and identifier replaced
+//! while anonymizing, along with the number of days each patient's dates were shifted by. Applying
+//! an existing map replaces values the same way they were replaced in earlier batches, so that
+//! datasets of the same patient or study remain linked.
+//!
+//! The map is written as JSON, optionally encrypted with AES-256-GCM using a key derived from a
+//! passphrase with Argon2. An encrypted map is the `ENCRYPTED_MAGIC` prefix followed by the salt,
+//! nonce, and ciphertext.
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes256Gcm, Nonce,
+};
+use anyhow::{anyhow, Context, Result};
+use argon2::Argon2;
+use rand::{rngs::OsRng, Rng, RngCore};
+use serde::{Deserialize, Serialize};
+
+use crate::app::generate_uid;
+
+static ENCRYPTED_MAGIC: &[u8] = b"DCMPIPE-REID-AES256GCM\n";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(super) struct ReidentificationMap {
+    /// Replacement UIDs by original UID.
+    uids: BTreeMap<String, String>,
+    /// Replacement Patient IDs by original Patient ID.
+    patient_ids: BTreeMap<String, String>,
+    /// Replacement Accession Numbers by original Accession Number.
+    accession_numbers: BTreeMap<String, String>,
+    /// Replacement Study IDs by original Study ID.
+    study_ids: BTreeMap<String, String>,
+    /// The number of days dates were shifted by, by original Patient ID.
+    date_shifts: BTreeMap<String, i64>,
+}
+
+impl ReidentificationMap {
+    /// Loads the map from the given file, decrypting it with the passphrase if it's encrypted.
+    pub(super) fn load(path: &Path, passphrase: Option<&str>) -> Result<ReidentificationMap> {
+        let bytes: Vec<u8> =
+            fs::read(path).with_context(|| format!("Failed to read: {}", path.display()))?;
+        let json: Vec<u8> = match bytes.strip_prefix(ENCRYPTED_MAGIC) {
+            Some(encrypted) => {
+                let passphrase: &str = passphrase.ok_or_else(|| {
+                    anyhow!(
+                        "map is encrypted, a passphrase is required: {}",
+                        path.display()
+                    )
+                })?;
+                decrypt(encrypted, passphrase)?
+            }
+            None => bytes,
+        };
+        serde_json::from_slice(&json)
+            .with_context(|| format!("Failed to parse map: {}", path.display()))
+    }
+
+    /// Saves the map to the given file, encrypting it if a passphrase is given.
+    pub(super) fn save(&self, path: &Path, passphrase: Option<&str>) -> Result<()> {
+        let json: Vec<u8> = serde_json::to_vec_pretty(self)?;
+        let bytes: Vec<u8> = match passphrase {
+            Some(passphrase) => encrypt(&json, passphrase)?,
+            None => json,
+        };
+        fs::write(path, bytes).with_context(|| format!("Failed to write map: {}", path.display()))
+    }
+
+    /// The replacement for the given UID, generating a new `2.25` UID if it has none.
+    pub(super) fn uid(&mut self, original: &str) -> String {
+        replacement(&mut self.uids, original, generate_uid)
+    }
+
+    /// Records the replacement of a UID which was hashed, so it can be re-identified.
+    pub(super) fn record_uid(&mut self, original: &str, hashed: String) {
+        self.uids.insert(original.to_owned(), hashed);
+    }
+
+    /// The replacement for the given Patient ID, generating a new one if it has none.
+    pub(super) fn patient_id(&mut self, original: &str) -> String {
+        replacement(&mut self.patient_ids, original, || random_id("ANON", 8))
+    }
+
+    /// The replacement for the given Accession Number, generating a new one if it has none.
+    pub(super) fn accession_number(&mut self, original: &str) -> String {
+        replacement(&mut self.accession_numbers, original, || random_id("A", 12))
+    }
+
+    /// The replacement for the given Study ID, generating a new one if it has none.
+    pub(super) fn study_id(&mut self, original: &str) -> String {
+        replacement(&mut self.study_ids, original, || random_id("S", 8))
+    }
+
+    /// The number of days to shift dates of the patient with the given original Patient ID,
+    /// recording `shift` as the patient's shift if there isn't one already.
+    pub(super) fn date_shift(&mut self, patient_id: &str, shift: impl FnOnce() -> i64) -> i64 {
+        *self
+            .date_shifts
+            .entry(patient_id.to_owned())
+            .or_insert_with(shift)
+    }
+}
+
+/// Looks up the replacement for the original value, generating and recording a new one if there
+/// is none. Generated values are unique among the existing replacements.
+fn replacement(
+    replacements: &mut BTreeMap<String, String>,
+    original: &str,
+    generate: impl Fn() -> String,
+) -> String {
+    if let Some(existing) = replacements.get(original) {
+        return existing.clone();
+    }
+    let mut generated: String = generate();
+    while replacements.values().any(|v| v == &generated) {
+        generated = generate();
+    }
+    replacements.insert(original.to_owned(), generated.clone());
+    generated
+}
+
+/// Generates an identifier of the given number of random decimal digits.
+fn random_id(prefix: &str, digits: usize) -> String {
+    let digits: String = (0..digits)
+        .map(|_| char::from(b'0' + OsRng.gen_range(0..10)))
+        .collect();
+    format!("{prefix}{digits}")
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<Aes256Gcm> {
+    let mut key: [u8; 32] = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| anyhow!("Failed to derive key: {e}"))?;
+    Ok(Aes256Gcm::new(&key.into()))
+}
+
+fn encrypt(plaintext: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    let mut salt: [u8; SALT_LEN] = [0u8; SALT_LEN];
+    let mut nonce: [u8; NONCE_LEN] = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut salt);
+    OsRng.fill_bytes(&mut nonce);
+    let ciphertext: Vec<u8> = derive_key(passphrase, &salt)?
+        .encrypt(Nonce::from_slice(&nonce), plaintext)
+        .map_err(|_| anyhow!("Failed to encrypt map"))?;
+
+    let mut bytes: Vec<u8> =
+        Vec::with_capacity(ENCRYPTED_MAGIC.len() + SALT_LEN + NONCE_LEN + ciphertext.len());
+    bytes.extend_from_slice(ENCRYPTED_MAGIC);
+    bytes.extend_from_slice(&salt);
+    bytes.extend_from_slice(&nonce);
+    bytes.extend_from_slice(&ciphertext);
+    Ok(bytes)
+}
+
+fn decrypt(encrypted: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    if encrypted.len() < SALT_LEN + NONCE_LEN {
+        return Err(anyhow!("encrypted map is truncated"));
+    }
+    let (salt, rest) = encrypted.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    derive_key(passphrase, salt)?
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| anyhow!("Failed to decrypt map, the passphrase may be incorrect"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    /// A path in the temporary directory unique to the test.
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("dcmpipe-remap-{}-{name}", std::process::id()))
+    }
+
+    fn test_map() -> ReidentificationMap {
+        let mut map: ReidentificationMap = ReidentificationMap::default();
+        map.uid("1.2.3.4");
+        map.patient_id("PAT1");
+        map.accession_number("ACC1");
+        map.study_id("STUDY1");
+        map.date_shift("PAT1", || -42);
+        map
+    }
+
+    #[test]
+    fn test_encrypted_round_trip() {
+        let path: PathBuf = temp_path("round-trip");
+        let mut map: ReidentificationMap = test_map();
+        map.save(&path, Some("secret")).unwrap();
+        let bytes: Vec<u8> = fs::read(&path).unwrap();
+        assert!(bytes.starts_with(ENCRYPTED_MAGIC));
+
+        let mut loaded: ReidentificationMap =
+            ReidentificationMap::load(&path, Some("secret")).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(map.uid("1.2.3.4"), loaded.uid("1.2.3.4"));
+        assert_eq!(map.patient_id("PAT1"), loaded.patient_id("PAT1"));
+        assert_eq!(
+            map.accession_number("ACC1"),
+            loaded.accession_number("ACC1")
+        );
+        assert_eq!(map.study_id("STUDY1"), loaded.study_id("STUDY1"));
+        assert_eq!(-42, loaded.date_shift("PAT1", || 7));
+    }
+
+    #[test]
+    fn test_encrypted_requires_passphrase() {
+        let path: PathBuf = temp_path("passphrase");
+        test_map().save(&path, Some("secret")).unwrap();
+        let missing = ReidentificationMap::load(&path, None);
+        let wrong = ReidentificationMap::load(&path, Some("not the secret"));
+        fs::remove_file(&path).unwrap();
+
+        assert!(missing
+            .unwrap_err()
+            .to_string()
+            .contains("passphrase is required"));
+        assert!(wrong
+            .unwrap_err()
+            .to_string()
+            .contains("Failed to decrypt map"));
+    }
+
+    #[test]
+    fn test_encrypted_truncated() {
+        let path: PathBuf = temp_path("truncated");
+        test_map().save(&path, Some("secret")).unwrap();
+        let bytes: Vec<u8> = fs::read(&path).unwrap();
+
+        // Missing part of the nonce.
+        fs::write(&path, &bytes[..ENCRYPTED_MAGIC.len() + SALT_LEN + 4]).unwrap();
+        let short = ReidentificationMap::load(&path, Some("secret"));
+        // Missing the authentication tag at the end of the ciphertext.
+        fs::write(&path, &bytes[..bytes.len() - 8]).unwrap();
+        let unauthenticated = ReidentificationMap::load(&path, Some("secret"));
+        fs::remove_file(&path).unwrap();
+
+        assert!(short.unwrap_err().to_string().contains("truncated"));
+        assert!(unauthenticated
+            .unwrap_err()
+            .to_string()
+            .contains("Failed to decrypt map"));
+    }
+
+    #[test]
+    fn test_plain_map() {
+        let path: PathBuf = temp_path("plain");
+        fs::write(
+            &path,
+            r#"{"uids": {"1.2.3.4": "2.25.1234"}, "date_shifts": {"PAT1": 10}}"#,
+        )
+        .unwrap();
+        // A passphrase is only used for encrypted maps.
+        let mut loaded: ReidentificationMap =
+            ReidentificationMap::load(&path, Some("secret")).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!("2.25.1234", loaded.uid("1.2.3.4"));
+        assert_eq!(10, loaded.date_shift("PAT1", || 7));
+        assert_eq!(7, loaded.date_shift("PAT2", || 7));
+
+        let path: PathBuf = temp_path("plain-saved");
+        let mut map: ReidentificationMap = test_map();
+        map.save(&path, None).unwrap();
+        let mut loaded: ReidentificationMap = ReidentificationMap::load(&path, None).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(map.patient_id("PAT1"), loaded.patient_id("PAT1"));
+    }
+
+    #[test]
+    fn test_recorded_uid() {
+        let mut map: ReidentificationMap = ReidentificationMap::default();
+        map.record_uid("1.2.3.4", "2.25.5678".to_owned());
+        assert_eq!("2.25.5678", map.uid("1.2.3.4"));
+        assert_ne!("2.25.5678", map.uid("1.2.3.5"));
+    }
+}
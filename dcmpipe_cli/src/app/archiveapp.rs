@@ -1,23 +1,537 @@
-use anyhow::{anyhow, Result};
+use std::{
+    collections::HashMap,
+    ffi::{OsStr, OsString},
+    fs::{self, File},
+    io::{self, Cursor},
+    path::{Path, PathBuf},
+};
 
-use crate::{app::CommandApplication, args::ArchiveArgs};
+use anyhow::{anyhow, Context, Result};
+use walkdir::WalkDir;
+
+use dcmpipe_lib::{
+    core::{
+        compressed::{self, DatasetFile, Manifest, COMPRESSED_EXTENSION},
+        dcmelement::DicomElement,
+        dcmobject::DicomRoot,
+        defn::{dcmdict::DicomDictionary, tag::TagPath, ts::TSRef},
+        hash::{ContentHash, DatasetHasher, HashScope},
+        pixeldata::jpeg::{encode_jpeg_baseline, JpegOptions},
+        read::{stop::ParseStop, ParserBuilder},
+        write::{builder::WriterBuilder, transcode::transcode, writer::Writer},
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags, transfer_syntaxes},
+    progress::ProgressReporter,
+    source::{Entries, EntryReader, Source, SourceEntry},
+};
+
+use crate::{
+    app::{progress::ProgressBar, CommandApplication, ElementWithLineFmt, TagValue},
+    args::{ArchiveArgs, DuplicatePolicy},
+    config::Config,
+    shutdown,
+};
+
+/// The layout used when none is given on the command-line or in the configuration.
+pub(crate) static DEFAULT_LAYOUT: &str = "{SeriesInstanceUID}/{SOPInstanceUID}.dcm";
+
+/// Used in place of a value which is missing, empty, or entirely made up of unsafe characters.
+static MISSING_VALUE: &str = "UNKNOWN";
+
+/// Characters other than alphanumerics which are allowed to remain in a value used as part of a
+/// file or folder name.
+static SAFE_PUNCTUATION: &str = "-_.^+=, ";
+
+/// The maximum number of characters of a value used in a file or folder name.
+const MAX_VALUE_LEN: usize = 64;
+
+/// Shorthand names accepted in layouts in addition to dictionary keywords.
+static LAYOUT_ALIASES: [(&str, &str); 3] = [
+    ("StudyUID", "StudyInstanceUID"),
+    ("SeriesUID", "SeriesInstanceUID"),
+    ("SOPUID", "SOPInstanceUID"),
+];
 
 pub struct ArchiveApp {
     args: ArchiveArgs,
+    config: Config,
 }
 
 impl ArchiveApp {
-    pub fn new(args: ArchiveArgs) -> ArchiveApp {
-        ArchiveApp { args }
+    pub fn new(args: ArchiveArgs, config: Config) -> ArchiveApp {
+        ArchiveApp { args, config }
+    }
+
+    /// Scans the archive for datasets archived previously, returning the path of each by its
+    /// SOPInstanceUID.
+    fn index_archive(&self) -> Result<HashMap<String, PathBuf>> {
+        let mut archived: HashMap<String, PathBuf> = HashMap::new();
+        if !self.args.destination.is_dir() {
+            return Ok(archived);
+        }
+
+        let parser_builder: ParserBuilder<'_> = ParserBuilder::default()
+            .stop(ParseStop::AfterTagValue(tags::SOPInstanceUID.tag.into()))
+            .dictionary(&STANDARD_DICOM_DICTIONARY);
+        let walkdir = WalkDir::new(&self.args.destination)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file());
+        for entry in walkdir {
+            // Compressed files have a manifest, so don't need to be decompressed.
+            let sop_uid: Option<String> = match compressed::read_manifest(entry.path()) {
+                Ok(Some(manifest)) => {
+                    Some(manifest.sop_instance_uid).filter(|sop_uid| !sop_uid.is_empty())
+                }
+                _ => {
+                    let file: DatasetFile = DatasetFile::open(entry.path())?;
+                    DicomRoot::parse(&mut parser_builder.build(file))
+                        .ok()
+                        .flatten()
+                        .and_then(|dcmroot| sop_instance_uid(&dcmroot))
+                }
+            };
+            if let Some(sop_uid) = sop_uid {
+                archived.entry(sop_uid).or_insert_with(|| entry.into_path());
+            }
+        }
+        Ok(archived)
+    }
+
+    /// Resolves the transfer syntax datasets should be re-encoded into, if any.
+    fn transcode_ts(&self) -> Result<Option<TSRef>> {
+        let Some(name) = self.args.transcode.as_deref().or(self
+            .config
+            .archive
+            .transcode
+            .as_deref())
+        else {
+            return Ok(None);
+        };
+        STANDARD_DICOM_DICTIONARY
+            .get_ts_by_name(name)
+            .or_else(|| STANDARD_DICOM_DICTIONARY.get_ts_by_uid(name))
+            .map(Some)
+            .ok_or_else(|| anyhow!("Unknown transfer syntax: {name}"))
     }
 }
 
 impl CommandApplication for ArchiveApp {
     fn run(&mut self) -> Result<()> {
-        Err(anyhow!(
-            "Archive is not implemented for source: {:?}, destination: {:?}",
-            self.args.source,
-            self.args.destination
-        ))
+        shutdown::install_handler()?;
+
+        let layout: &str = self
+            .args
+            .layout
+            .as_deref()
+            .or(self.config.archive.layout.as_deref())
+            .unwrap_or(DEFAULT_LAYOUT);
+        let layout: Layout = Layout::parse(layout)?;
+        let on_duplicate: DuplicatePolicy = self
+            .args
+            .on_duplicate
+            .or(self.config.archive.on_duplicate)
+            .unwrap_or_default();
+        let compress: bool = self.args.compress || self.config.archive.compress.unwrap_or(false);
+
+        let transcode_ts: Option<TSRef> = self.transcode_ts()?;
+        let jpeg_options: JpegOptions = JpegOptions {
+            quality: self
+                .args
+                .quality
+                .or(self.config.archive.quality)
+                .unwrap_or(JpegOptions::default().quality),
+            ..JpegOptions::default()
+        };
+
+        let mut archived_paths: HashMap<String, PathBuf> = self.index_archive()?;
+        // Re-encoding or hashing requires the entire dataset, otherwise parsing can stop at the
+        // pixel data.
+        let parse_stop: ParseStop = if transcode_ts.is_some() || self.args.hash {
+            ParseStop::EndOfDataset
+        } else {
+            ParseStop::BeforeTagValue(tags::PixelData.tag.into())
+        };
+        let parser_builder: ParserBuilder<'_> = ParserBuilder::default()
+            .stop(parse_stop)
+            .dictionary(&STANDARD_DICOM_DICTIONARY);
+
+        let mut archived: usize = 0;
+        let mut skipped: usize = 0;
+        let mut progress: ProgressBar = ProgressBar::new(self.args.no_progress);
+        let mut entries: Entries = Source::new(&self.args.source).entries()?;
+        while let Some(entry) = entries.next() {
+            progress.report(entries.progress());
+            let entry: SourceEntry = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    let e: anyhow::Error = e.into();
+                    progress.println(format_args!("Skipping unreadable archive: {e:#}"));
+                    tracing::warn!(error = format!("{e:#}"), "skipped unreadable archive");
+                    skipped += 1;
+                    continue;
+                }
+            };
+            if shutdown::is_requested() {
+                progress.println(format_args!(
+                    "Archive interrupted before: {}",
+                    entry.path().display()
+                ));
+                break;
+            }
+
+            let path: &Path = entry.path();
+            let file: EntryReader<'_> = entry
+                .open()
+                .with_context(|| format!("Failed to open: {}", path.display()))?;
+            let mut dcmroot: DicomRoot<'_> = match DicomRoot::parse(&mut parser_builder.build(file))
+            {
+                Ok(Some(dcmroot)) => dcmroot,
+                Ok(None) => {
+                    progress.println(format_args!("Skipping non-DICOM file: {}", path.display()));
+                    skipped += 1;
+                    continue;
+                }
+                Err(e) => {
+                    progress.println(format_args!(
+                        "Skipping invalid DICOM file: {}, {e}",
+                        path.display()
+                    ));
+                    tracing::warn!(path = %path.display(), error = %e, "skipped invalid dataset");
+                    skipped += 1;
+                    continue;
+                }
+            };
+
+            let encoded: Option<Vec<u8>> = match transcode_ts {
+                Some(to) if to.uid() != dcmroot.ts().uid() => {
+                    match encode(&mut dcmroot, to, &jpeg_options) {
+                        Ok(encoded) => Some(encoded),
+                        Err(e) => {
+                            progress.println(format_args!(
+                                "Skipping {}, unable to transcode: {e}",
+                                path.display()
+                            ));
+                            tracing::warn!(path = %path.display(), error = %e, "skipped dataset failing to transcode");
+                            skipped += 1;
+                            continue;
+                        }
+                    }
+                }
+                _ => None,
+            };
+
+            let sop_uid: Option<String> = sop_instance_uid(&dcmroot);
+            let mut dest: PathBuf = self.args.destination.join(layout.render(&dcmroot));
+            if compress {
+                dest = compressed_path(&dest);
+            }
+            let existing: Option<PathBuf> = sop_uid
+                .as_ref()
+                .and_then(|sop_uid| archived_paths.get(sop_uid).cloned())
+                .or_else(|| dest.exists().then(|| dest.clone()));
+            if let Some(existing) = existing {
+                // The normalized hash is unaffected by transcoding, so the parsed dataset can be
+                // compared regardless of whether it's re-encoded. Lossy compression changes the
+                // pixel data, but is deterministic so matches the same dataset archived before.
+                if self.args.hash {
+                    let hash: ContentHash = dataset_hash(&dcmroot)?;
+                    if dataset_file_hash(&parser_builder, &existing)? == Some(hash) {
+                        progress.println(format_args!(
+                            "Skipping {}, identical to: {} ({hash})",
+                            path.display(),
+                            existing.display()
+                        ));
+                        skipped += 1;
+                        continue;
+                    }
+                }
+
+                match on_duplicate {
+                    DuplicatePolicy::Skip => {
+                        progress.println(format_args!(
+                            "Skipping {}, duplicate of: {}",
+                            path.display(),
+                            existing.display()
+                        ));
+                        skipped += 1;
+                        continue;
+                    }
+                    DuplicatePolicy::Overwrite => dest = existing,
+                    DuplicatePolicy::Rename => dest = unique_path(&dest),
+                    DuplicatePolicy::Error => {
+                        return Err(anyhow!(
+                            "{} is a duplicate of: {}",
+                            path.display(),
+                            existing.display()
+                        ));
+                    }
+                }
+            }
+
+            if let Some(parent) = dest.parent() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create folder: {}", parent.display()))?;
+            }
+            store(&entry, &dcmroot, encoded.as_deref(), compress, &dest)?;
+            if let Some(sop_uid) = sop_uid {
+                archived_paths.entry(sop_uid).or_insert(dest);
+            }
+            archived += 1;
+        }
+        progress.finish(entries.progress());
+
+        println!("Archived {archived} files, skipped {skipped}");
+        Ok(())
+    }
+}
+
+/// A part of a single path component in a layout.
+enum LayoutPart {
+    Literal(String),
+    Value(TagPath),
+}
+
+/// A template for the destination path of an archived dataset, relative to the archive folder,
+/// e.g. `{PatientID}/{StudyDate}_{StudyUID}/{SeriesNumber}_{SeriesUID}/{InstanceNumber}.dcm`.
+///
+/// Attributes are referenced within braces by keyword or tag, including within sequences, as
+/// accepted by `TagPath::parse()`. Values are sanitized so they can only ever produce a single
+/// path component.
+pub(crate) struct Layout {
+    components: Vec<Vec<LayoutPart>>,
+}
+
+impl Layout {
+    /// Parses a layout template, failing if it references unknown attributes or if it could
+    /// produce a path outside of the archive folder.
+    pub(crate) fn parse(template: &str) -> Result<Layout> {
+        let mut components: Vec<Vec<LayoutPart>> = Vec::new();
+        for component in template.split('/') {
+            if component.is_empty() || component == "." || component == ".." {
+                return Err(anyhow!(
+                    "Layout must be a relative path without empty, '.', or '..' folders: {template}"
+                ));
+            }
+
+            let mut parts: Vec<LayoutPart> = Vec::new();
+            let mut rest: &str = component;
+            while !rest.is_empty() {
+                let Some(start) = rest.find(['{', '}']) else {
+                    parts.push(LayoutPart::Literal(rest.to_owned()));
+                    break;
+                };
+                if rest[start..].starts_with('}') {
+                    return Err(anyhow!("Unmatched '}}' in layout: {template}"));
+                }
+                if start > 0 {
+                    parts.push(LayoutPart::Literal(rest[..start].to_owned()));
+                }
+
+                let (key, remaining) = rest[start + 1..]
+                    .split_once('}')
+                    .ok_or_else(|| anyhow!("Unmatched '{{' in layout: {template}"))?;
+                let key: &str = LAYOUT_ALIASES
+                    .iter()
+                    .find(|(alias, _keyword)| *alias == key)
+                    .map_or(key, |(_alias, keyword)| keyword);
+                let tagpath: TagPath = TagPath::parse(key, Some(&STANDARD_DICOM_DICTIONARY))
+                    .with_context(|| format!("Unknown attribute in layout: {key}"))?;
+                parts.push(LayoutPart::Value(tagpath));
+                rest = remaining;
+            }
+            components.push(parts);
+        }
+
+        Ok(Layout { components })
+    }
+
+    /// Renders the relative path for the given dataset.
+    pub(crate) fn render(&self, dcmroot: &DicomRoot<'_>) -> PathBuf {
+        let mut path: PathBuf = PathBuf::new();
+        for parts in &self.components {
+            let mut component: String = String::new();
+            for part in parts {
+                match part {
+                    LayoutPart::Literal(literal) => component.push_str(literal),
+                    LayoutPart::Value(tagpath) => {
+                        let value: Option<String> = dcmroot
+                            .get_child_by_tagpath(tagpath)
+                            .and_then(|obj| element_value(obj.element()));
+                        component.push_str(&sanitize(value.as_deref().unwrap_or_default()));
+                    }
+                }
+            }
+            path.push(component);
+        }
+        path
+    }
+}
+
+/// Gets the SOPInstanceUID of the dataset, if present.
+fn sop_instance_uid(dcmroot: &DicomRoot<'_>) -> Option<String> {
+    dcmroot
+        .get_child_by_tag(tags::SOPInstanceUID.tag)
+        .and_then(|obj| String::try_from(obj.element()).ok())
+        .filter(|sop_uid| !sop_uid.is_empty())
+}
+
+/// Re-encodes the dataset into the transfer syntax `to`, returning the bytes of the new file. The
+/// pixel data is compressed when `to` is JPEG Baseline, modifying the dataset.
+fn encode(dcmroot: &mut DicomRoot<'_>, to: TSRef, jpeg_options: &JpegOptions) -> Result<Vec<u8>> {
+    let mut writer: Writer<Vec<u8>> = WriterBuilder::for_file().ts(to).build(Vec::new());
+    if to.uid() == transfer_syntaxes::JPEGBaselineProcess1.uid() {
+        // The pixel data is compressed and the dataset re-encoded in place.
+        encode_jpeg_baseline(dcmroot, jpeg_options, "archive")?;
+        writer.write_dcmroot(dcmroot)?;
+    } else {
+        let elements: Vec<DicomElement> = transcode(dcmroot, to)?;
+        writer.write_elements(elements.iter())?;
+    }
+    Ok(writer.into_dataset()?)
+}
+
+/// Computes the normalized content hash of the entire dataset, used to identify duplicates.
+fn dataset_hash(dcmroot: &DicomRoot<'_>) -> Result<ContentHash> {
+    Ok(DatasetHasher::new(HashScope::Full)
+        .normalize(true)
+        .hash(dcmroot)?)
+}
+
+/// Computes the normalized content hash of the dataset in the file, or `None` if the file can't be
+/// parsed as DICOM.
+fn dataset_file_hash(
+    parser_builder: &ParserBuilder<'_>,
+    path: &Path,
+) -> Result<Option<ContentHash>> {
+    let file: DatasetFile =
+        DatasetFile::open(path).with_context(|| format!("Failed to open: {}", path.display()))?;
+    match DicomRoot::parse(&mut parser_builder.build(file)) {
+        Ok(Some(dcmroot)) => Ok(Some(dataset_hash(&dcmroot)?)),
+        _ => Ok(None),
+    }
+}
+
+/// Writes the dataset of the entry to `dest`, either its re-encoded bytes if given or otherwise the
+/// file itself. Files which were compressed are decompressed unless `compress` is set, in which
+/// case the dataset is compressed with a manifest created from `dcmroot`.
+fn store(
+    entry: &SourceEntry,
+    dcmroot: &DicomRoot<'_>,
+    encoded: Option<&[u8]>,
+    compress: bool,
+    dest: &Path,
+) -> Result<()> {
+    let path: &Path = entry.path();
+    let write_context = || format!("Failed to write: {}", dest.display());
+    // Files within archives have already been read into memory.
+    let encoded: Option<&[u8]> = encoded.or(entry.bytes());
+    match (encoded, compress) {
+        (Some(encoded), false) => fs::write(dest, encoded).with_context(write_context)?,
+        (Some(encoded), true) => {
+            let manifest: Manifest = Manifest::from_dataset(dcmroot, encoded.len() as u64);
+            let file: File = File::create(dest).with_context(write_context)?;
+            compressed::compress(
+                &manifest,
+                Cursor::new(encoded),
+                file,
+                compressed::DEFAULT_LEVEL,
+            )
+            .with_context(write_context)?;
+        }
+        (None, true) => {
+            let manifest: Manifest = Manifest::from_dataset(dcmroot, original_len(path)?);
+            let source: DatasetFile = DatasetFile::open(path)
+                .with_context(|| format!("Failed to open: {}", path.display()))?;
+            let file: File = File::create(dest).with_context(write_context)?;
+            compressed::compress(&manifest, source, file, compressed::DEFAULT_LEVEL)
+                .with_context(write_context)?;
+        }
+        (None, false) => {
+            let mut source: DatasetFile = DatasetFile::open(path)
+                .with_context(|| format!("Failed to open: {}", path.display()))?;
+            if source.is_compressed() {
+                let mut file: File = File::create(dest).with_context(write_context)?;
+                io::copy(&mut source, &mut file).with_context(write_context)?;
+            } else {
+                fs::copy(path, dest).with_context(|| {
+                    format!("Failed to copy {} to {}", path.display(), dest.display())
+                })?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The length of the dataset file once decompressed, taken from its manifest if it has one.
+fn original_len(path: &Path) -> Result<u64> {
+    if let Some(manifest) = compressed::read_manifest(path)? {
+        return Ok(manifest.len);
+    }
+    let mut source: DatasetFile = DatasetFile::open(path)?;
+    if source.is_compressed() {
+        Ok(io::copy(&mut source, &mut io::sink())?)
+    } else {
+        Ok(fs::metadata(path)?.len())
+    }
+}
+
+/// Appends the extension of compressed files to the file name of `path`.
+fn compressed_path(path: &Path) -> PathBuf {
+    let mut compressed: OsString = path.as_os_str().to_owned();
+    compressed.push(".");
+    compressed.push(COMPRESSED_EXTENSION);
+    PathBuf::from(compressed)
+}
+
+/// Finds a path which doesn't exist by adding a numbered suffix to the file name of `path`.
+pub(crate) fn unique_path(path: &Path) -> PathBuf {
+    let stem: &OsStr = path.file_stem().unwrap_or_default();
+    let ext: Option<&OsStr> = path.extension();
+    (1..)
+        .map(|n| {
+            let mut file_name: OsString = stem.to_owned();
+            file_name.push(format!("_{n}"));
+            if let Some(ext) = ext {
+                file_name.push(".");
+                file_name.push(ext);
+            }
+            path.with_file_name(file_name)
+        })
+        .find(|candidate| !candidate.exists())
+        .unwrap_or_else(|| path.to_path_buf())
+}
+
+/// Gets the value of the element as a single string, or `None` if it has no displayable value.
+pub(crate) fn element_value(elem: &DicomElement) -> Option<String> {
+    if elem.vr().is_character_string {
+        return String::try_from(elem).ok();
+    }
+    match TagValue::from(ElementWithLineFmt(elem, false)) {
+        TagValue::Stringified(value) => Some(value),
+        _ => None,
+    }
+}
+
+/// Replaces any characters which are not safe to use in a file or folder name, including path
+/// separators, and trims leading and trailing dots and whitespace.
+fn sanitize(value: &str) -> String {
+    let sanitized: String = value
+        .trim()
+        .chars()
+        .take(MAX_VALUE_LEN)
+        .map(|c| {
+            if c.is_alphanumeric() || SAFE_PUNCTUATION.contains(c) {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let sanitized: &str = sanitized.trim_matches(|c: char| c == '.' || c.is_whitespace());
+    if sanitized.is_empty() {
+        MISSING_VALUE.to_owned()
+    } else {
+        sanitized.to_owned()
     }
 }
@@ -1,15 +1,18 @@
-//! The browse command opens a TUI for navigating through a DICOM data set.
+//! The browse command opens a TUI for navigating through a DICOM data set, and editing it. When
+//! given a folder, the datasets within it are listed in a tree by patient, study, and series. The
+//! first frame of the dataset's Pixel Data can be previewed alongside its elements, and the raw
+//! bytes of the selected element shown in a hex view.
 
 use std::{
     collections::HashMap,
-    fs::File,
+    fs,
     io::{stdout, Stdout},
     ops::Sub,
-    path::Path,
+    path::{Path, PathBuf},
     time::Duration,
 };
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, Context, Result};
 
 use crossterm::{
     event::{
@@ -24,30 +27,61 @@ use crossterm::{
 };
 use dcmpipe_lib::{
     core::{
+        charset::CSRef,
+        compressed::DatasetFile,
+        dcmelement::DicomElement,
         dcmobject::{DicomObject, DicomRoot},
+        dcmsqelem::SequenceElement,
         defn::{
             constants,
+            dcmdict::DicomDictionary,
             tag::{Tag, TagNode, TagPath},
+            ts::TSRef,
+            vl::ValueLength,
+            vr::{self, VRRef},
         },
         read::Parser,
+        values::{Attribute, RawValue},
+        write::{builder::WriterBuilder, writer::Writer},
     },
-    dict::stdlookup::STANDARD_DICOM_DICTIONARY,
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags, transfer_syntaxes as ts},
 };
 use ratatui::{
     backend::CrosstermBackend,
     layout::{Alignment, Constraint, Layout},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{block::Title, Block, Borders, Cell, Row, Table, TableState},
+    widgets::{block::Title, Block, Borders, Cell, Paragraph, Row, Table, TableState},
     Frame, Terminal,
 };
 
-use crate::{app::CommandApplication, args::BrowseArgs};
+use crate::{
+    app::{parse_input_value, CommandApplication},
+    args::BrowseArgs,
+    completion::tags_with_prefix,
+    config::Config,
+};
+
+use super::{
+    format_code_items,
+    formatter::{ElementFormatter, Highlight},
+    ElementWithLineFmt, TagCategory, TagValue,
+};
 
-use super::{ElementWithLineFmt, TagCategory, TagValue};
+use self::{
+    hexview::{HexView, HEX_PANE_HEIGHT},
+    preview::ImagePreview,
+    seriestree::{SeriesTree, TREE_PANE_WIDTH},
+};
+
+mod hexview;
+mod preview;
+mod seriestree;
 
 pub struct BrowseApp {
     args: BrowseArgs,
+    #[cfg_attr(not(feature = "index"), allow(dead_code))]
+    config: Config,
 }
 
 #[derive(Debug)]
@@ -76,6 +110,8 @@ impl std::error::Error for BrowseError {}
 struct DicomDocumentModel<'app> {
     /// The file path the DICOM dataset was loaded from.
     file_path: &'app Path,
+    /// Formats the values of elements, kept to rebuild the node models on `refresh()`.
+    formatter: ElementFormatter,
     /// The mapping of DICOM nodes to its respective data model. The empty path represents the root
     /// of the DICOM data set, whose model contains all the top-level DICOM elements.
     node_models: HashMap<TagPath, DicomNodeModel<'app>>,
@@ -116,25 +152,103 @@ enum UserAction {
     Quit,
     NavIntoLevel(usize),
     NavUpLevel,
+    EditValue(usize),
+    DeleteElement(usize),
+    AddElement,
+    Save,
+    Open(PathBuf),
+    TogglePreview,
+    /// Adjusts the preview's window center and width by a number of steps.
+    AdjustWindow(i32, i32),
+    ResetWindow,
+    ToggleHexView,
+    /// Scrolls the hex view by a number of lines.
+    ScrollHexView(isize),
+}
+
+/// How the browsing of a single dataset ended.
+enum LoopExit {
+    Quit,
+    /// The user opened another dataset from the series tree, which has been loaded.
+    Open(PathBuf, Box<DicomRoot<'static>>),
+}
+
+/// The element selected in the table, or why no element is selected.
+type SelectedElement<'a> = Result<(TagPath, &'a DicomElement<'a>), String>;
+
+/// The panes shown alongside the element table, which are rendered only if present or visible.
+struct Panes<'a> {
+    tree: Option<&'a mut SeriesTree>,
+    preview: &'a ImagePreview,
+    /// The hex view, along with the element whose bytes it shows.
+    hexview: Option<(&'a mut HexView, SelectedElement<'a>)>,
+}
+
+/// The pipeline stage recorded as the source of edits, if the dataset tracks provenance.
+static EDIT_STAGE: &str = "browse";
+
+/// The maximum number of tag keywords listed while entering the tag of a new element.
+const MAX_TAG_SUGGESTIONS: usize = 6;
+
+/// Binary VRs whose values can be entered as text, in addition to character strings.
+static EDITABLE_BINARY_VRS: [VRRef; 9] = [
+    &vr::AT,
+    &vr::FD,
+    &vr::FL,
+    &vr::SL,
+    &vr::SS,
+    &vr::SV,
+    &vr::UL,
+    &vr::US,
+    &vr::UV,
+];
+
+/// The state of any edits made to the dataset.
+#[derive(Default)]
+struct EditState {
+    /// The text currently being entered by the user, if any.
+    prompt: Option<Prompt>,
+    /// A message shown in the status line, such as the result of the last edit.
+    message: Option<String>,
+    /// Whether the dataset has been modified since it was loaded or last saved.
+    modified: bool,
+    /// Set when the user tries to quit or open another dataset with unsaved changes, so that a
+    /// second attempt will go ahead.
+    confirm_discard: bool,
+}
+
+/// Text being entered by the user in the status line.
+struct Prompt {
+    kind: PromptKind,
+    label: String,
+    input: String,
+}
+
+/// What the text being entered by the user is for.
+enum PromptKind {
+    /// The new value of the element at the path.
+    EditValue(TagPath),
+    /// The keyword or tag number of a new element to add to the current level.
+    AddTag,
+    /// The value of a new element to add at the path.
+    AddValue(TagPath, VRRef),
+}
+
+/// User keyboard events while entering text in a `Prompt`.
+enum PromptAction {
+    None,
+    Cancel,
+    Submit,
 }
 
 impl CommandApplication for BrowseApp {
     fn run(&mut self) -> Result<()> {
-        let path: &Path = self.args.file.as_path();
-        let mut parser: Parser<'_, File> = super::parse_file(path, true)?;
-        let parse_result = DicomRoot::parse(&mut parser);
-
-        let dcmroot = match parse_result {
-            Ok(Some(dcmroot)) => dcmroot,
-            Ok(None) => return Err(anyhow!("Not valid DICOM.")),
-            Err(err) => return Err(anyhow!(err)),
-        };
-
-        let doc_model = DicomDocumentModel::parse(path, &dcmroot);
+        let (path, mut tree): (PathBuf, Option<SeriesTree>) = self.open()?;
+        let dcmroot: DicomRoot<'static> = load_dataset(&path)?;
 
         let mut terminal = self.init()?;
 
-        let app_result = self.run_loop(&mut terminal, &dcmroot, doc_model);
+        let app_result = self.run_session(&mut terminal, path, dcmroot, tree.as_mut());
 
         self.close(terminal)?;
 
@@ -145,32 +259,131 @@ impl CommandApplication for BrowseApp {
 }
 
 impl<'app> DicomDocumentModel<'app> {
-    fn parse(path: &'app Path, dcmroot: &DicomRoot) -> DicomDocumentModel<'app> {
-        let node_models = DicomNodeModel::parse(dcmroot.as_obj());
+    fn parse(
+        path: &'app Path,
+        dcmroot: &DicomRoot,
+        formatter: ElementFormatter,
+    ) -> DicomDocumentModel<'app> {
+        let node_models = DicomNodeModel::parse(dcmroot.as_obj(), &formatter);
         let count = node_models.len();
         DicomDocumentModel {
             file_path: path,
+            formatter,
             node_models,
             node_views: HashMap::with_capacity(count),
         }
     }
+
+    /// Rebuilds the node models after the dataset was modified, retaining the view state of nodes
+    /// which still exist.
+    fn refresh(&mut self, dcmroot: &DicomRoot) {
+        self.node_models = DicomNodeModel::parse(dcmroot.as_obj(), &self.formatter);
+        let node_models = &self.node_models;
+        self.node_views.retain(|tagpath, view_state| {
+            let Some(model) = node_models.get(tagpath) else {
+                return false;
+            };
+            view_state.num_rows = model.rows.len();
+            view_state.max_name_width = model.max_name_width;
+            if let Some(selected) = view_state.table_state.selected() {
+                let last_row = view_state.num_rows.saturating_sub(1);
+                view_state.table_state.select(Some(selected.min(last_row)));
+            }
+            true
+        });
+    }
+}
+
+impl EditState {
+    /// Builds the line shown at the bottom of the screen, which is either the text being entered
+    /// by the user or the most recent message.
+    fn status_line(&self) -> Line<'static> {
+        if let Some(prompt) = &self.prompt {
+            let mut spans: Vec<Span<'static>> = vec![
+                Span::styled(
+                    format!("{}: ", prompt.label),
+                    Style::default().fg(Color::LightYellow),
+                ),
+                Span::raw(prompt.input.clone()),
+                Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+            ];
+            if matches!(prompt.kind, PromptKind::AddTag) && !prompt.input.is_empty() {
+                let suggestions: Vec<&str> = tags_with_prefix(&prompt.input)
+                    .into_iter()
+                    .take(MAX_TAG_SUGGESTIONS)
+                    .map(|tag| tag.keyword)
+                    .collect();
+                spans.push(Span::styled(
+                    format!("  {}", suggestions.join(" ")),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            return Line::from(spans);
+        }
+
+        let mut spans: Vec<Span<'static>> = Vec::new();
+        if self.modified {
+            spans.push(Span::styled(
+                "[Modified] ",
+                Style::default().fg(Color::LightRed),
+            ));
+        }
+        match &self.message {
+            Some(message) => spans.push(Span::raw(message.clone())),
+            None => spans.push(Span::styled(
+                "e: edit  a: add  x: delete  s: save  p: preview  b: bytes  q: quit",
+                Style::default().fg(Color::DarkGray),
+            )),
+        }
+        Line::from(spans)
+    }
+}
+
+impl Prompt {
+    /// Completes the keyword being entered for a new element, up to the longest prefix shared by
+    /// all matching keywords.
+    fn complete(&mut self) {
+        if !matches!(self.kind, PromptKind::AddTag) {
+            return;
+        }
+        let candidates = tags_with_prefix(&self.input);
+        let Some(first) = candidates.first() else {
+            return;
+        };
+        let common_len: usize = candidates
+            .iter()
+            .map(|tag| {
+                first
+                    .keyword
+                    .bytes()
+                    .zip(tag.keyword.bytes())
+                    .take_while(|(a, b)| a == b)
+                    .count()
+            })
+            .min()
+            .unwrap_or_default();
+        self.input = first.keyword[..common_len].to_owned();
+    }
 }
 
 impl<'model> DicomNodeModel<'model> {
-    fn parse(dcmobj: &DicomObject) -> HashMap<TagPath, DicomNodeModel<'model>> {
+    fn parse(
+        dcmobj: &DicomObject,
+        formatter: &ElementFormatter,
+    ) -> HashMap<TagPath, DicomNodeModel<'model>> {
         let total_sub_items = dcmobj.item_count() + dcmobj.child_count();
         let mut map: HashMap<TagPath, DicomNodeModel<'model>> =
             HashMap::with_capacity(total_sub_items);
         let mut rows: Vec<Row<'model>> = Vec::with_capacity(total_sub_items);
         let mut max_name_width: u16 = 0;
         for item in dcmobj.iter_items() {
-            let (row, child_map, name_len) = DicomNodeModel::parse_dcmobj(item);
+            let (row, child_map, name_len) = DicomNodeModel::parse_dcmobj(item, formatter);
             rows.push(row);
             map.extend(child_map);
             max_name_width = max_name_width.max(name_len);
         }
         for (_child_tag, child) in dcmobj.iter_child_nodes() {
-            let (row, child_map, name_len) = DicomNodeModel::parse_dcmobj(child);
+            let (row, child_map, name_len) = DicomNodeModel::parse_dcmobj(child, formatter);
             rows.push(row);
             map.extend(child_map);
             max_name_width = max_name_width.max(name_len);
@@ -189,18 +402,26 @@ impl<'model> DicomNodeModel<'model> {
 
     fn parse_dcmobj(
         child: &DicomObject,
+        formatter: &ElementFormatter,
     ) -> (Row<'model>, HashMap<TagPath, DicomNodeModel<'model>>, u16) {
         let mut map: HashMap<TagPath, DicomNodeModel<'model>> = HashMap::new();
         let child_tag = child.element().tag();
         if child.item_count() > 0 || child.child_count() > 0 {
-            let child_map = DicomNodeModel::parse(child);
+            let child_map = DicomNodeModel::parse(child, formatter);
             map.extend(child_map);
         }
 
         let tag_render: TagCategory = child.element().into();
         let elem_name = tag_render.to_string();
         let name_len = elem_name.len() as u16;
-        let elem_value: TagValue = ElementWithLineFmt(child.element(), false).into();
+        let code_items: Vec<Vec<&DicomElement>> = child
+            .iter_items()
+            .map(|item| item.iter_child_nodes().map(|(_, c)| c.element()).collect())
+            .collect();
+        let elem_value: TagValue = match format_code_items(&code_items) {
+            Some(codes) if child.element().vr() == &vr::SQ => TagValue::Stringified(codes),
+            _ => ElementWithLineFmt(child.element(), false).into(),
+        };
 
         let mut cells: Vec<Cell> = Vec::with_capacity(5);
         cells.push(
@@ -209,47 +430,25 @@ impl<'model> DicomNodeModel<'model> {
             } else {
                 ""
             })
-            .style(Style::default().fg(Color::DarkGray)),
+            .style(highlight_style(Highlight::Muted)),
         );
 
         cells.push(
             Cell::from(Tag::format_tag_to_display(child_tag))
-                .style(Style::default().fg(Color::DarkGray)),
+                .style(highlight_style(Highlight::Muted)),
         );
 
-        match tag_render {
-            TagCategory::Known(_, _) => {
-                cells.push(Cell::from(elem_name));
-            }
-            _ => {
-                cells.push(
-                    Cell::from(elem_name).style(
-                        Style::default()
-                            .fg(Color::DarkGray)
-                            .add_modifier(Modifier::ITALIC),
-                    ),
-                );
-            }
-        }
+        let name_style: Style = highlight_style(ElementFormatter::name_highlight(&tag_render));
+        cells.push(Cell::from(elem_name).style(name_style));
 
-        cells.push(
-            Cell::from(child.element().vr().ident).style(Style::default().fg(Color::DarkGray)),
-        );
+        cells.push(Cell::from(child.element().vr().ident).style(highlight_style(Highlight::Muted)));
 
-        let cell = match elem_value {
-            TagValue::Sequence => Cell::from(""),
-            TagValue::Error(_err_str) => {
-                Cell::from("<InvalidValue>").style(Style::default().fg(Color::Red))
-            }
-            TagValue::Uid(uid, name) => Cell::from(Line::from(vec![
-                Span::styled(uid, Style::default()),
-                Span::styled(
-                    format!(" {}", name),
-                    Style::default().fg(Color::LightYellow),
-                ),
-            ])),
-            TagValue::Stringified(str_val) => Cell::from(str_val),
-        };
+        let spans: Vec<Span> = formatter
+            .value_parts(elem_value)
+            .into_iter()
+            .map(|(text, highlight)| Span::styled(text, highlight_style(highlight)))
+            .collect();
+        let cell = Cell::from(Line::from(spans));
         cells.push(cell);
 
         (Row::new(cells), map, name_len)
@@ -257,8 +456,56 @@ impl<'model> DicomNodeModel<'model> {
 }
 
 impl<'app> BrowseApp {
-    pub fn new(args: BrowseArgs) -> BrowseApp {
-        BrowseApp { args }
+    pub fn new(args: BrowseArgs, config: Config) -> BrowseApp {
+        BrowseApp { args, config }
+    }
+
+    /// The dataset to browse first, along with the series tree it's within when browsing a folder
+    /// or the results of an index query.
+    fn open(&self) -> Result<(PathBuf, Option<SeriesTree>)> {
+        let single_file: bool = self.args.file.as_deref().is_some_and(Path::is_file);
+        if self.args.output.is_some() && !single_file {
+            return Err(anyhow!(
+                "--output can only be used when browsing a single file"
+            ));
+        }
+
+        #[cfg(feature = "index")]
+        if !self.args.query.is_empty() {
+            let db_uri: &str = self
+                .args
+                .db
+                .as_deref()
+                .or(self.config.index.db.as_deref())
+                .ok_or_else(|| {
+                    anyhow!("No database URI, use --db or set index.db in dcmpipe.toml")
+                })?;
+            let files: Vec<PathBuf> =
+                crate::app::indexapp::query_instances(db_uri, &self.args.query)?;
+            let tree: SeriesTree = SeriesTree::from_files(files);
+            let first: PathBuf = tree
+                .first_instance()
+                .ok_or_else(|| {
+                    anyhow!(
+                        "No indexed datasets found for: {}",
+                        self.args.query.join(", ")
+                    )
+                })?
+                .to_path_buf();
+            return Ok((first, Some(tree)));
+        }
+
+        let path: PathBuf = self
+            .args
+            .file
+            .clone()
+            .ok_or_else(|| anyhow!("No file to browse"))?;
+        if !path.is_dir() {
+            return Ok((path, None));
+        }
+        let tree: SeriesTree = SeriesTree::scan(&path)?;
+        let first: PathBuf = tree.first_instance().unwrap_or(&path).to_path_buf();
+        Ok((first, Some(tree)))
     }
 
     fn init(&self) -> Result<Terminal<CrosstermBackend<Stdout>>> {
@@ -277,28 +524,67 @@ impl<'app> BrowseApp {
         Ok(())
     }
 
-    fn run_loop<'dict>(
+    /// Browses the given dataset, then each dataset subsequently opened from the series tree, until
+    /// the user quits.
+    fn run_session(
         &self,
         terminal: &mut Terminal<CrosstermBackend<Stdout>>,
-        dcmroot: &'dict DicomRoot,
-        mut doc_model: DicomDocumentModel<'app>,
+        mut path: PathBuf,
+        mut dcmroot: DicomRoot<'static>,
+        mut tree: Option<&mut SeriesTree>,
     ) -> Result<()> {
+        let mut preview = ImagePreview::default();
+        let mut hexview = HexView::default();
+        loop {
+            if let Some(tree) = tree.as_deref_mut() {
+                tree.set_opened(&path);
+            }
+            preview.reset(&dcmroot);
+            let formatter: ElementFormatter =
+                ElementFormatter::default().max_value_width(self.args.max_value_width);
+            let doc_model = DicomDocumentModel::parse(&path, &dcmroot, formatter);
+            match self.run_loop(
+                terminal,
+                &mut dcmroot,
+                doc_model,
+                tree.as_deref_mut(),
+                &mut preview,
+                &mut hexview,
+            )? {
+                LoopExit::Quit => return Ok(()),
+                LoopExit::Open(next_path, next_dcmroot) => {
+                    path = next_path;
+                    dcmroot = *next_dcmroot;
+                }
+            }
+        }
+    }
+
+    fn run_loop(
+        &self,
+        terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+        dcmroot: &mut DicomRoot,
+        mut doc_model: DicomDocumentModel<'app>,
+        mut tree: Option<&mut SeriesTree>,
+        preview: &mut ImagePreview,
+        hexview: &mut HexView,
+    ) -> Result<LoopExit> {
         let root_path = TagPath::empty();
         let default_table_state = TableState::new().with_selected(Some(0));
         let filename = doc_model
             .file_path
             .file_name()
             .unwrap_or_default()
-            .to_str()
-            .unwrap_or_default()
-            .to_string();
+            .to_string_lossy()
+            .into_owned();
 
         let mut current_tagpath = root_path.clone();
         let mut user_action = UserAction::None;
+        let mut edit_state = EditState::default();
 
         loop {
             if let UserAction::Quit = user_action {
-                break;
+                return Ok(LoopExit::Quit);
             }
 
             let Some(table_model) = doc_model.node_models.get(&current_tagpath) else {
@@ -330,11 +616,99 @@ impl<'app> BrowseApp {
             // The view_state is small and intended to be cloned every iteration.
             let render_view_state = view_state.clone();
 
-            terminal.draw(|frame| self.render(render_model, render_view_state, frame))?;
+            let status_line = edit_state.status_line();
+
+            let panes = Panes {
+                tree: tree.as_deref_mut(),
+                preview,
+                hexview: hexview.visible.then(|| {
+                    let selected: SelectedElement = view_state
+                        .table_state
+                        .selected()
+                        .ok_or_else(|| anyhow!("No element is selected"))
+                        .and_then(|sel_idx| {
+                            get_selected_element(dcmroot, &current_tagpath, sel_idx)
+                        })
+                        .map_err(|e| e.to_string());
+                    (&mut *hexview, selected)
+                }),
+            };
+            terminal.draw(|frame| {
+                self.render(render_model, render_view_state, status_line, panes, frame)
+            })?;
+
+            let mut modified = false;
+            if let Some(prompt) = edit_state.prompt.as_mut() {
+                // While text is being entered all keyboard events go to the prompt.
+                user_action = UserAction::None;
+                match self.process_prompt_input(prompt)? {
+                    PromptAction::None => {}
+                    PromptAction::Cancel => edit_state.prompt = None,
+                    PromptAction::Submit => {
+                        if let Some(prompt) = edit_state.prompt.take() {
+                            match self.submit_prompt(
+                                dcmroot,
+                                &current_tagpath,
+                                &prompt,
+                                &mut edit_state,
+                            ) {
+                                Ok(changed) => modified = changed,
+                                Err(e) => {
+                                    // Leave the prompt open so the input can be corrected.
+                                    edit_state.message = Some(e.to_string());
+                                    edit_state.prompt = Some(prompt);
+                                }
+                            }
+                        }
+                    }
+                }
+            } else {
+                // Check for user event. If the user event would modify the ViewState it will also
+                // be updated (table offset/selection).
+                user_action = self.process_user_input(&mut view_state, tree.as_deref_mut())?;
+            }
 
-            // Check for user event. If the user event would modify the ViewState it will also be
-            // updated (table offset/selection).
-            user_action = self.process_user_input(&mut view_state)?;
+            match user_action {
+                UserAction::TogglePreview => preview.toggle(dcmroot),
+                UserAction::AdjustWindow(center_steps, width_steps) => {
+                    preview.adjust_window(center_steps, width_steps)
+                }
+                UserAction::ResetWindow => preview.reset_window(),
+                UserAction::ToggleHexView => hexview.visible = !hexview.visible,
+                UserAction::ScrollHexView(lines) => hexview.scroll(lines),
+                _ => {}
+            }
+
+            if let UserAction::Open(path) = &user_action {
+                if path == doc_model.file_path {
+                    user_action = UserAction::None;
+                }
+            }
+            let discards_edits: bool =
+                matches!(user_action, UserAction::Quit | UserAction::Open(_));
+            if discards_edits && edit_state.modified && !edit_state.confirm_discard {
+                edit_state.confirm_discard = true;
+                edit_state.message = Some(
+                    "Unsaved changes, repeat to continue without saving or press s to save"
+                        .to_owned(),
+                );
+                user_action = UserAction::None;
+            } else if !matches!(user_action, UserAction::None) {
+                edit_state.confirm_discard = false;
+            }
+
+            if let UserAction::Open(path) = &user_action {
+                match load_dataset(path) {
+                    Ok(next_dcmroot) => {
+                        return Ok(LoopExit::Open(path.clone(), Box::new(next_dcmroot)))
+                    }
+                    Err(e) => {
+                        edit_state.message =
+                            Some(format!("Unable to open {}: {e}", path.display()));
+                        user_action = UserAction::None;
+                    }
+                }
+            }
 
             // Update the table state after updating offset/selection from user input, but prior to
             // updating/modifying the path if the user navigated away from the current node.
@@ -342,6 +716,23 @@ impl<'app> BrowseApp {
                 .node_views
                 .insert(current_tagpath.clone(), view_state);
 
+            let file_path: &Path = doc_model.file_path;
+            match self.edit(
+                dcmroot,
+                file_path,
+                &current_tagpath,
+                &user_action,
+                &mut edit_state,
+            ) {
+                Ok(changed) => modified |= changed,
+                Err(e) => edit_state.message = Some(e.to_string()),
+            }
+            if modified {
+                edit_state.modified = true;
+                doc_model.refresh(dcmroot);
+                preview.reset(dcmroot);
+            }
+
             current_tagpath = self.get_tagpath_from_user_action(
                 dcmroot,
                 &doc_model,
@@ -349,25 +740,191 @@ impl<'app> BrowseApp {
                 current_tagpath,
             )?;
         }
-        Ok(())
     }
 
-    /// Polls for user input events and updates `ViewState` based on the user's interaction.
-    fn process_user_input(&self, view_state: &mut DicomNodeViewState) -> Result<UserAction> {
+    /// Polls for user input events while text is being entered into the prompt.
+    fn process_prompt_input(&self, prompt: &mut Prompt) -> Result<PromptAction> {
+        if !event::poll(Duration::from_millis(200))? {
+            return Ok(PromptAction::None);
+        }
+        let Key(key) = event::read()? else {
+            return Ok(PromptAction::None);
+        };
+        if key.kind != KeyEventKind::Press {
+            return Ok(PromptAction::None);
+        }
+
+        match key.code {
+            KeyCode::Esc => return Ok(PromptAction::Cancel),
+            KeyCode::Enter => return Ok(PromptAction::Submit),
+            KeyCode::Backspace => {
+                prompt.input.pop();
+            }
+            KeyCode::Tab => prompt.complete(),
+            Char(c) => prompt.input.push(c),
+            _ => {}
+        }
+        Ok(PromptAction::None)
+    }
+
+    /// Performs the editing action chosen by the user, returning whether the dataset was modified.
+    fn edit(
+        &self,
+        dcmroot: &mut DicomRoot,
+        file_path: &Path,
+        current_tagpath: &TagPath,
+        user_action: &UserAction,
+        edit_state: &mut EditState,
+    ) -> Result<bool> {
+        match user_action {
+            UserAction::EditValue(sel_idx) => {
+                let (tagpath, element) = get_selected_element(dcmroot, current_tagpath, *sel_idx)?;
+                check_editable(element)?;
+                edit_state.prompt = Some(Prompt {
+                    kind: PromptKind::EditValue(tagpath),
+                    label: format_element_label(element.tag(), element.vr()),
+                    input: format_input_value(element)?,
+                });
+                Ok(false)
+            }
+            UserAction::DeleteElement(sel_idx) => {
+                let (tagpath, element) = get_selected_element(dcmroot, current_tagpath, *sel_idx)?;
+                if element.tag() == tags::TransferSyntaxUID.tag {
+                    return Err(anyhow!("TransferSyntaxUID can't be removed"));
+                }
+                let name: String = format_tag_name(element.tag());
+                dcmroot.remove_element(&tagpath);
+                edit_state.message = Some(format!("Deleted {name}"));
+                Ok(true)
+            }
+            UserAction::AddElement => {
+                let is_item: bool = current_tagpath
+                    .nodes
+                    .last()
+                    .is_some_and(|node| node.item().is_some());
+                if !current_tagpath.is_empty() && !is_item {
+                    return Err(anyhow!(
+                        "Elements can only be added to the dataset or an item"
+                    ));
+                }
+                edit_state.prompt = Some(Prompt {
+                    kind: PromptKind::AddTag,
+                    label: "Add tag".to_owned(),
+                    input: String::new(),
+                });
+                Ok(false)
+            }
+            UserAction::Save => {
+                let path: &Path = self.args.output.as_deref().unwrap_or(file_path);
+                dcmroot.update_value_lengths();
+                let mut writer: Writer<Vec<u8>> =
+                    WriterBuilder::for_file().ts(dcmroot.ts()).build(Vec::new());
+                writer.write_dcmroot(dcmroot)?;
+                fs::write(path, writer.into_dataset()?)
+                    .with_context(|| format!("Failed to save: {}", path.display()))?;
+                edit_state.modified = false;
+                edit_state.message = Some(format!("Saved to {}", path.display()));
+                Ok(false)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Applies the text entered by the user into the prompt, returning whether the dataset was
+    /// modified.
+    fn submit_prompt(
+        &self,
+        dcmroot: &mut DicomRoot,
+        current_tagpath: &TagPath,
+        prompt: &Prompt,
+        edit_state: &mut EditState,
+    ) -> Result<bool> {
+        match &prompt.kind {
+            PromptKind::EditValue(tagpath) => {
+                let vr: VRRef = dcmroot
+                    .get_child_by_tagpath(tagpath)
+                    .map(|obj| obj.element().vr())
+                    .ok_or_else(|| BrowseError::InvalidTagPath(tagpath.clone()))?;
+                let value: RawValue = parse_input_value(vr, &prompt.input)?;
+                dcmroot
+                    .modify_element(tagpath, EDIT_STAGE, |element| {
+                        element.encode_value(value, None)
+                    })
+                    .ok_or_else(|| BrowseError::InvalidTagPath(tagpath.clone()))??;
+                edit_state.message = Some(format!("Updated {}", prompt.label));
+                Ok(true)
+            }
+            PromptKind::AddTag => {
+                let tag: u32 = Tag::parse_tag(&prompt.input, Some(&STANDARD_DICOM_DICTIONARY))
+                    .map_err(|_e| anyhow!("Unknown tag: {}", prompt.input))?;
+                let vr: VRRef = new_element_vr(tag)?;
+                let mut tagpath: TagPath = current_tagpath.clone();
+                tagpath.nodes.push(TagNode::from(tag));
+                if dcmroot.get_child_by_tagpath(&tagpath).is_some() {
+                    return Err(anyhow!("{} already exists", format_tag_name(tag)));
+                }
+                edit_state.message = None;
+                edit_state.prompt = Some(Prompt {
+                    kind: PromptKind::AddValue(tagpath, vr),
+                    label: format_element_label(tag, vr),
+                    input: String::new(),
+                });
+                Ok(false)
+            }
+            PromptKind::AddValue(tagpath, vr) => {
+                let value: RawValue = parse_input_value(vr, &prompt.input)?;
+                let tag: u32 = tagpath.nodes.last().map(TagNode::tag).unwrap_or_default();
+                let mut element: DicomElement = new_element(dcmroot, current_tagpath, tag, vr);
+                element.encode_value(value, None)?;
+                dcmroot.insert_element(tagpath, element, EDIT_STAGE)?;
+                edit_state.message = Some(format!("Added {}", prompt.label));
+                Ok(true)
+            }
+        }
+    }
+
+    /// Polls for user input events and updates `ViewState` based on the user's interaction. When
+    /// browsing a folder, events for the series tree update its selection instead.
+    fn process_user_input(
+        &self,
+        view_state: &mut DicomNodeViewState,
+        tree: Option<&mut SeriesTree>,
+    ) -> Result<UserAction> {
         let user_action = if event::poll(Duration::from_millis(200))? {
             match event::read()? {
                 Key(key) => match key.kind {
-                    KeyEventKind::Press => self.event_keypress(view_state, key),
+                    KeyEventKind::Press => match tree {
+                        Some(tree) if key.code == KeyCode::Tab => {
+                            tree.focused = !tree.focused;
+                            UserAction::None
+                        }
+                        Some(tree) if tree.focused => self.event_tree_keypress(tree, key),
+                        _ => self.event_keypress(view_state, key),
+                    },
                     KeyEventKind::Release => self.event_keyrelease(view_state, key),
                     _ => UserAction::None,
                 },
-                Mouse(mouse) => match mouse.kind {
-                    MouseEventKind::Down(button) | MouseEventKind::Drag(button) => {
-                        self.event_mouse_down(view_state, mouse, button)
+                Mouse(mouse) => match tree {
+                    Some(tree) if mouse.column < TREE_PANE_WIDTH => {
+                        self.event_tree_mouse(tree, mouse)
+                    }
+                    tree => {
+                        if let (Some(tree), MouseEventKind::Down(_)) = (tree, mouse.kind) {
+                            tree.focused = false;
+                        }
+                        match mouse.kind {
+                            MouseEventKind::Down(button) | MouseEventKind::Drag(button) => {
+                                self.event_mouse_down(view_state, mouse, button)
+                            }
+                            MouseEventKind::ScrollDown => {
+                                self.event_mouse_scroll_down(view_state, mouse)
+                            }
+                            MouseEventKind::ScrollUp => {
+                                self.event_mouse_scroll_up(view_state, mouse)
+                            }
+                            _ => UserAction::None,
+                        }
                     }
-                    MouseEventKind::ScrollDown => self.event_mouse_scroll_down(view_state, mouse),
-                    MouseEventKind::ScrollUp => self.event_mouse_scroll_up(view_state, mouse),
-                    _ => UserAction::None,
                 },
                 _ => UserAction::None,
             }
@@ -390,6 +947,16 @@ impl<'app> BrowseApp {
         match user_action {
             UserAction::None => {}
             UserAction::Quit => {}
+            UserAction::EditValue(_)
+            | UserAction::DeleteElement(_)
+            | UserAction::AddElement
+            | UserAction::Save
+            | UserAction::Open(_)
+            | UserAction::TogglePreview
+            | UserAction::AdjustWindow(_, _)
+            | UserAction::ResetWindow
+            | UserAction::ToggleHexView
+            | UserAction::ScrollHexView(_) => {}
             UserAction::NavIntoLevel(sel_idx) => {
                 let sel_idx = *sel_idx;
                 let next_path = if current_tagpath.is_empty() {
@@ -460,6 +1027,25 @@ impl<'app> BrowseApp {
                 }
             }
             Char('h') | KeyCode::Left | KeyCode::Backspace => UserAction::NavUpLevel,
+            Char('e') => view_state
+                .table_state
+                .selected()
+                .map_or(UserAction::None, UserAction::EditValue),
+            Char('x') | KeyCode::Delete => view_state
+                .table_state
+                .selected()
+                .map_or(UserAction::None, UserAction::DeleteElement),
+            Char('a') => UserAction::AddElement,
+            Char('s') => UserAction::Save,
+            Char('p') => UserAction::TogglePreview,
+            Char('[') => UserAction::AdjustWindow(-1, 0),
+            Char(']') => UserAction::AdjustWindow(1, 0),
+            Char('-') => UserAction::AdjustWindow(0, -1),
+            Char('=') | Char('+') => UserAction::AdjustWindow(0, 1),
+            Char('0') => UserAction::ResetWindow,
+            Char('b') => UserAction::ToggleHexView,
+            Char('J') => UserAction::ScrollHexView(1),
+            Char('K') => UserAction::ScrollHexView(-1),
             Char('j') | KeyCode::Down => {
                 self.table_select_next(view_state, 1);
                 UserAction::None
@@ -484,6 +1070,52 @@ impl<'app> BrowseApp {
         }
     }
 
+    fn event_tree_keypress(&self, tree: &mut SeriesTree, event: KeyEvent) -> UserAction {
+        match event.code {
+            Char('q') | KeyCode::Esc => UserAction::Quit,
+            Char('l') | KeyCode::Right | KeyCode::Enter => {
+                tree.selected_instance().map_or(UserAction::None, |path| {
+                    UserAction::Open(path.to_path_buf())
+                })
+            }
+            Char('j') | KeyCode::Down => {
+                tree.select_next(1);
+                UserAction::None
+            }
+            Char('k') | KeyCode::Up => {
+                tree.select_next(-1);
+                UserAction::None
+            }
+            Char('d') => {
+                if event.modifiers.contains(KeyModifiers::CONTROL) {
+                    tree.select_next(15);
+                }
+                UserAction::None
+            }
+            Char('u') => {
+                if event.modifiers.contains(KeyModifiers::CONTROL) {
+                    tree.select_next(-15);
+                }
+                UserAction::None
+            }
+            _ => UserAction::None,
+        }
+    }
+
+    fn event_tree_mouse(&self, tree: &mut SeriesTree, event: MouseEvent) -> UserAction {
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                tree.focused = true;
+                // Subtract 1 for the border of the tree.
+                tree.select_visible(event.row.saturating_sub(1) as usize);
+            }
+            MouseEventKind::ScrollDown => tree.select_next(1),
+            MouseEventKind::ScrollUp => tree.select_next(-1),
+            _ => {}
+        }
+        UserAction::None
+    }
+
     fn event_mouse_down(
         &self,
         view_state: &'app mut DicomNodeViewState,
@@ -534,8 +1166,7 @@ impl<'app> BrowseApp {
             .table_state
             .offset()
             .saturating_add_signed(modifier)
-            .min(view_state.num_rows)
-            .max(0);
+            .min(view_state.num_rows);
         *view_state.table_state.offset_mut() = i;
     }
 
@@ -545,13 +1176,19 @@ impl<'app> BrowseApp {
             Some(i) => view_state
                 .num_rows
                 .sub(1)
-                .min(i.saturating_add_signed(modifier))
-                .max(0),
+                .min(i.saturating_add_signed(modifier)),
         };
         view_state.table_state.select(Some(i));
     }
 
-    fn render(&self, model: DicomNodeModel, mut view_state: DicomNodeViewState, frame: &mut Frame) {
+    fn render(
+        &self,
+        model: DicomNodeModel,
+        mut view_state: DicomNodeViewState,
+        status_line: Line,
+        panes: Panes,
+        frame: &mut Frame,
+    ) {
         let column_widths = [
             Constraint::Length(1),
             Constraint::Length(11),
@@ -595,13 +1232,230 @@ impl<'app> BrowseApp {
             .constraints([Constraint::Min(1), Constraint::Length(1)])
             .split(frame.size());
 
-        frame.render_stateful_widget(table, sections[0], &mut view_state.table_state);
+        let mut table_area = match panes.tree {
+            Some(tree) => {
+                let split = Layout::default()
+                    .direction(ratatui::layout::Direction::Horizontal)
+                    .constraints([Constraint::Length(TREE_PANE_WIDTH), Constraint::Min(1)])
+                    .split(sections[0]);
+                tree.render(frame, split[0]);
+                split[1]
+            }
+            None => sections[0],
+        };
+        if panes.preview.visible {
+            let split = Layout::default()
+                .direction(ratatui::layout::Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(table_area);
+            table_area = split[0];
+            panes.preview.render(frame, split[1]);
+        }
+        if let Some((hexview, selected)) = panes.hexview {
+            let split = Layout::default()
+                .direction(ratatui::layout::Direction::Vertical)
+                .constraints([Constraint::Min(1), Constraint::Length(HEX_PANE_HEIGHT)])
+                .split(table_area);
+            table_area = split[0];
+            hexview.render(frame, split[1], selected);
+        }
+
+        frame.render_stateful_widget(table, table_area, &mut view_state.table_state);
+        frame.render_widget(Paragraph::new(status_line), sections[1]);
+    }
+}
+
+/// Parses the entire dataset at the given path.
+fn load_dataset(path: &Path) -> Result<DicomRoot<'static>> {
+    let mut parser: Parser<'static, DatasetFile> = super::parse_file(path, true)?;
+    match DicomRoot::parse(&mut parser) {
+        Ok(Some(dcmroot)) => Ok(dcmroot),
+        Ok(None) => Err(anyhow!("Not valid DICOM.")),
+        Err(err) => Err(anyhow!(err)),
+    }
+}
+
+/// Gets the element at the given row of the current node, along with its path. Rows of items
+/// within a sequence are not elements so can't be selected for editing.
+fn get_selected_element<'a>(
+    dcmroot: &'a DicomRoot,
+    current_tagpath: &TagPath,
+    sel_idx: usize,
+) -> Result<(TagPath, &'a DicomElement<'a>)> {
+    let node: &DicomObject = if current_tagpath.is_empty() {
+        dcmroot.as_obj()
+    } else {
+        dcmroot
+            .get_child_by_tagpath(current_tagpath)
+            .ok_or_else(|| BrowseError::InvalidTagPath(current_tagpath.clone()))?
+    };
+    let child_idx: usize = sel_idx
+        .checked_sub(node.item_count())
+        .ok_or_else(|| anyhow!("Items can't be edited, only the elements within them"))?;
+    let child: &DicomObject =
+        get_nth_child(node, child_idx).ok_or_else(|| anyhow!("No element is selected"))?;
+
+    let tag: u32 = child.element().tag();
+    if tag == constants::tags::ITEM
+        || tag == constants::tags::ITEM_DELIMITATION_ITEM
+        || tag == constants::tags::SEQUENCE_DELIMITATION_ITEM
+    {
+        return Err(anyhow!("Items and delimiters can't be edited"));
+    }
+
+    let mut tagpath: TagPath = current_tagpath.clone();
+    tagpath.nodes.push(TagNode::from(tag));
+    Ok((tagpath, child.element()))
+}
+
+/// Checks whether the value of the element can be edited as text.
+fn check_editable(element: &DicomElement) -> Result<()> {
+    if element.tag() == tags::TransferSyntaxUID.tag {
+        Err(anyhow!(
+            "TransferSyntaxUID can't be changed as the dataset would no longer match it"
+        ))
+    } else if element.is_seq_like() {
+        Err(anyhow!(
+            "Sequences can't be edited, only the elements within them"
+        ))
+    } else if !is_editable_vr(element.vr()) {
+        Err(anyhow!(
+            "Values of VR {} can't be edited",
+            element.vr().ident
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Whether values of the VR can be entered as text.
+fn is_editable_vr(vr: VRRef) -> bool {
+    vr.is_character_string || EDITABLE_BINARY_VRS.contains(&vr)
+}
+
+/// Determines the VR of a new element, which must be one whose value can be entered as text.
+fn new_element_vr(tag: u32) -> Result<VRRef> {
+    if tag == tags::TransferSyntaxUID.tag {
+        return Err(anyhow!("TransferSyntaxUID can't be added"));
+    }
+    // Private creators are always LO, see Part 5, Ch 7.8.1.
+    let vr: VRRef = if Tag::is_private_creator(tag) {
+        &vr::LO
+    } else {
+        STANDARD_DICOM_DICTIONARY
+            .get_tag_by_number(tag)
+            .and_then(|tag| tag.implicit_vr())
+            .ok_or_else(|| anyhow!("The VR of {} is unknown", Tag::format_tag_to_display(tag)))?
+    };
+    if vr == &vr::SQ {
+        Err(anyhow!("Sequences can't be added"))
+    } else if !is_editable_vr(vr) {
+        Err(anyhow!("Values of VR {} can't be entered", vr.ident))
+    } else {
+        Ok(vr)
+    }
+}
+
+/// Creates an empty element to add to the current node. The transfer syntax is taken from
+/// existing elements in the node, as elements within private sequences use implicit VR.
+fn new_element(
+    dcmroot: &DicomRoot,
+    current_tagpath: &TagPath,
+    tag: u32,
+    vr: VRRef,
+) -> DicomElement<'static> {
+    let node: Option<&DicomObject> = if current_tagpath.is_empty() {
+        Some(dcmroot.as_obj())
+    } else {
+        dcmroot.get_child_by_tagpath(current_tagpath)
+    };
+    let sibling: Option<&DicomElement> = node.and_then(|node| {
+        node.iter_child_nodes()
+            .map(|(_tag, child)| child.element())
+            .find(|element| element.tag() > constants::tags::FILE_META_GROUP_END)
+    });
+
+    let cs: CSRef = dcmroot.cs();
+    let (ts, sq_path): (TSRef, Vec<SequenceElement>) =
+        if tag <= constants::tags::FILE_META_GROUP_END {
+            (&ts::ExplicitVRLittleEndian, Vec::new())
+        } else if let Some(sibling) = sibling {
+            (sibling.ts(), sibling.sequence_path().clone())
+        } else {
+            (dcmroot.ts(), Vec::new())
+        };
+    DicomElement::new(
+        tag,
+        vr,
+        ValueLength::Explicit(0),
+        ts,
+        cs,
+        Vec::new(),
+        sq_path,
+    )
+}
+
+/// Formats the value of the element as text for editing, with multiple values separated by `\`.
+fn format_input_value(element: &DicomElement) -> Result<String> {
+    let values: Vec<String> = match element.parse_value()? {
+        RawValue::Attribute(attrs) => attrs
+            .into_iter()
+            .map(|Attribute(tag)| Tag::format_tag_to_display(tag))
+            .collect(),
+        RawValue::Uid(uid) => vec![uid],
+        RawValue::Strings(strings) => strings,
+        RawValue::Shorts(values) => to_strings(values),
+        RawValue::UnsignedShorts(values) => to_strings(values),
+        RawValue::Integers(values) => to_strings(values),
+        RawValue::UnsignedIntegers(values) => to_strings(values),
+        RawValue::Longs(values) => to_strings(values),
+        RawValue::UnsignedLongs(values) => to_strings(values),
+        RawValue::Floats(values) => to_strings(values),
+        RawValue::Doubles(values) => to_strings(values),
+        RawValue::Bytes(_)
+        | RawValue::Words(_)
+        | RawValue::DoubleWords(_)
+        | RawValue::QuadWords(_)
+        | RawValue::BulkData(_) => return Err(anyhow!("Binary values can't be edited")),
+    };
+    Ok(values.join("\\"))
+}
+
+fn to_strings<T: ToString>(values: Vec<T>) -> Vec<String> {
+    values.iter().map(T::to_string).collect()
+}
+
+/// Formats the tag as its keyword if known, otherwise its tag number.
+fn format_tag_name(tag: u32) -> String {
+    STANDARD_DICOM_DICTIONARY
+        .get_tag_by_number(tag)
+        .map_or_else(
+            || Tag::format_tag_to_display(tag),
+            |tag| tag.ident.to_owned(),
+        )
+}
+
+/// Formats the label shown when entering the value of an element.
+fn format_element_label(tag: u32, vr: VRRef) -> String {
+    format!("{} {}", format_tag_name(tag), vr.ident)
+}
+
+/// The style of cells and spans with the highlight of the element formatter.
+fn highlight_style(highlight: Highlight) -> Style {
+    match highlight {
+        Highlight::Plain => Style::default(),
+        Highlight::Muted => Style::default().fg(Color::DarkGray),
+        Highlight::Unnamed => Style::default()
+            .fg(Color::DarkGray)
+            .add_modifier(Modifier::ITALIC),
+        Highlight::Annotation => Style::default().fg(Color::LightYellow),
+        Highlight::Error => Style::default().fg(Color::Red),
     }
 }
 
 /// Treates a DICOM element's children as an ordered list to get a child node based on index. This
 /// is only useful for mapping the view-index to the model-index.
-fn get_nth_child(dcmobj: &DicomObject, index: usize) -> Option<&DicomObject> {
+fn get_nth_child<'a, 'b>(dcmobj: &'a DicomObject<'b>, index: usize) -> Option<&'a DicomObject<'b>> {
     dcmobj.iter_child_nodes().skip(index).map(|e| e.1).next()
 }
 
@@ -0,0 +1,133 @@
+//! The hex view shows the raw bytes of the selected element's value with their offsets, for
+//! inspecting private or malformed values which can't otherwise be rendered.
+
+use dcmpipe_lib::core::{
+    dcmelement::DicomElement,
+    defn::tag::{Tag, TagPath},
+};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{block::Title, Block, Borders, Paragraph},
+    Frame,
+};
+
+/// The height of the hex view pane, in rows.
+pub(super) const HEX_PANE_HEIGHT: u16 = 12;
+
+/// The number of bytes shown on each line.
+const BYTES_PER_LINE: usize = 16;
+
+/// The hex dump of the value of the selected element.
+#[derive(Default)]
+pub(super) struct HexView {
+    /// Whether the hex view is shown.
+    pub(super) visible: bool,
+    /// The index of the first line shown, when the value doesn't fit within the pane.
+    scroll: usize,
+    /// The path of the element last shown, so the scroll position is reset when the selection
+    /// changes.
+    tagpath: Option<TagPath>,
+}
+
+impl HexView {
+    /// Scrolls the dump by the given number of lines.
+    pub(super) fn scroll(&mut self, lines: isize) {
+        self.scroll = self.scroll.saturating_add_signed(lines);
+    }
+
+    /// Renders the bytes of the selected element, or the reason there are none to show.
+    pub(super) fn render(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        selected: Result<(TagPath, &DicomElement), String>,
+    ) {
+        let block = Block::default()
+            .title(Title::from(Span::styled(
+                "[Bytes]",
+                Style::default().add_modifier(Modifier::BOLD),
+            )))
+            .borders(Borders::all());
+
+        let (tagpath, element) = match selected {
+            Ok(selected) => selected,
+            Err(message) => {
+                self.tagpath = None;
+                let text =
+                    Paragraph::new(Span::styled(message, Style::default().fg(Color::DarkGray)))
+                        .block(block);
+                frame.render_widget(text, area);
+                return;
+            }
+        };
+        if self.tagpath.as_ref() != Some(&tagpath) {
+            self.scroll = 0;
+            self.tagpath = Some(tagpath);
+        }
+
+        let data: &[u8] = element.data();
+        let num_lines: usize = data.len().div_ceil(BYTES_PER_LINE);
+        let visible_lines: usize = area.height.saturating_sub(2).into();
+        self.scroll = self.scroll.min(num_lines.saturating_sub(visible_lines));
+
+        let details: String = format!(
+            "[{} {} bytes]",
+            Tag::format_tag_to_display(element.tag()),
+            data.len()
+        );
+        let block = block.title(
+            Title::from(Span::styled(details, Style::default().fg(Color::LightBlue)))
+                .alignment(Alignment::Right),
+        );
+
+        let lines: Vec<Line> = data
+            .chunks(BYTES_PER_LINE)
+            .enumerate()
+            .skip(self.scroll)
+            .take(visible_lines)
+            .map(|(i, bytes)| format_line(i * BYTES_PER_LINE, bytes))
+            .collect();
+        let text = if lines.is_empty() {
+            Paragraph::new(Span::styled(
+                "<Empty>",
+                Style::default().fg(Color::DarkGray),
+            ))
+        } else {
+            Paragraph::new(lines)
+        };
+        frame.render_widget(text.block(block), area);
+    }
+}
+
+/// Formats one line of the dump as the offset, the bytes in hex, and the bytes as ASCII where
+/// printable.
+fn format_line(offset: usize, bytes: &[u8]) -> Line<'static> {
+    let mut hex: String = String::with_capacity(BYTES_PER_LINE * 3 + 1);
+    for (i, byte) in bytes.iter().enumerate() {
+        if i == BYTES_PER_LINE / 2 {
+            hex.push(' ');
+        }
+        hex.push_str(&format!("{byte:02X} "));
+    }
+    let ascii: String = bytes
+        .iter()
+        .map(|byte| {
+            if byte.is_ascii_graphic() || *byte == b' ' {
+                *byte as char
+            } else {
+                '.'
+            }
+        })
+        .collect();
+
+    Line::from(vec![
+        Span::styled(
+            format!("{offset:08X}  "),
+            Style::default().fg(Color::DarkGray),
+        ),
+        Span::raw(format!("{hex:<width$} ", width = BYTES_PER_LINE * 3 + 1)),
+        Span::styled(ascii, Style::default().fg(Color::LightYellow)),
+    ])
+}
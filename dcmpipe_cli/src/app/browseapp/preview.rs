@@ -0,0 +1,182 @@
+//! The image preview shows the first frame of the dataset's Pixel Data, drawn with unicode
+//! half-block characters so that each terminal cell displays two pixels stacked vertically.
+
+use dcmpipe_lib::core::{
+    dcmobject::DicomRoot,
+    pixeldata::{self, Photometric, PixelFrame, Window},
+};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{block::Title, Block, Borders, Paragraph, Widget, Wrap},
+    Frame,
+};
+
+/// The character drawn in each cell, whose foreground is the upper pixel and background is the
+/// lower pixel.
+const UPPER_HALF_BLOCK: char = '\u{2580}';
+
+/// The fraction of the frame's range of values the window center or width changes by per step.
+const WINDOW_STEP_FRACTION: f64 = 0.02;
+
+/// The preview of the first frame of the dataset being browsed.
+#[derive(Default)]
+pub(super) struct ImagePreview {
+    /// Whether the preview is shown.
+    pub(super) visible: bool,
+    /// The decoded frame, or the reason it couldn't be decoded. This is `None` until the preview is
+    /// first shown for the dataset.
+    frame: Option<Result<PixelFrame, String>>,
+    /// The window currently applied to monochrome frames.
+    window: Option<Window>,
+    /// The frame converted to RGB using the current window.
+    pixels: Vec<[u8; 3]>,
+}
+
+impl ImagePreview {
+    /// Shows or hides the preview, decoding the frame if it hasn't been yet.
+    pub(super) fn toggle(&mut self, dcmroot: &DicomRoot) {
+        self.visible = !self.visible;
+        if self.visible && self.frame.is_none() {
+            self.load(dcmroot);
+        }
+    }
+
+    /// Discards the decoded frame, such as when another dataset is opened or the dataset is
+    /// modified. It is decoded again if the preview is visible.
+    pub(super) fn reset(&mut self, dcmroot: &DicomRoot) {
+        self.frame = None;
+        self.window = None;
+        self.pixels.clear();
+        if self.visible {
+            self.load(dcmroot);
+        }
+    }
+
+    /// Adjusts the center and width of the window by the given number of steps.
+    pub(super) fn adjust_window(&mut self, center_steps: i32, width_steps: i32) {
+        let (Some(Ok(frame)), Some(window)) = (&self.frame, self.window) else {
+            return;
+        };
+        let (min, max) = frame.min_max().unwrap_or((0.0, 0.0));
+        let step: f64 = ((max - min) * WINDOW_STEP_FRACTION).max(1.0);
+        self.window = Some(Window::new(
+            window.center + f64::from(center_steps) * step,
+            window.width + f64::from(width_steps) * step,
+        ));
+        self.update_pixels();
+    }
+
+    /// Restores the window specified by the dataset, or the automatic window if the dataset has no
+    /// window or its window does not cover the frame.
+    pub(super) fn reset_window(&mut self) {
+        if let Some(Ok(frame)) = &self.frame {
+            self.window = Some(frame.suggested_window());
+            self.update_pixels();
+        }
+    }
+
+    pub(super) fn render(&self, frame: &mut Frame, area: Rect) {
+        let mut block = Block::default()
+            .title(Title::from(Span::styled(
+                "[Preview]",
+                Style::default().add_modifier(Modifier::BOLD),
+            )))
+            .borders(Borders::all());
+
+        match &self.frame {
+            Some(Ok(pixel_frame)) => {
+                let mut details: String =
+                    format!("[{}x{}]", pixel_frame.columns(), pixel_frame.rows());
+                if let (Some(window), Photometric::Monochrome1 | Photometric::Monochrome2) =
+                    (self.window, pixel_frame.photometric())
+                {
+                    details = format!("[C:{:.0} W:{:.0}]{details}", window.center, window.width);
+                }
+                block = block.title(
+                    Title::from(Span::styled(details, Style::default().fg(Color::LightBlue)))
+                        .alignment(Alignment::Right),
+                );
+                let inner: Rect = block.inner(area);
+                frame.render_widget(block, area);
+                frame.render_widget(
+                    HalfBlockImage {
+                        pixels: &self.pixels,
+                        columns: pixel_frame.columns(),
+                        rows: pixel_frame.rows(),
+                    },
+                    inner,
+                );
+            }
+            Some(Err(message)) => {
+                let text = Paragraph::new(Line::from(Span::styled(
+                    message.clone(),
+                    Style::default().fg(Color::DarkGray),
+                )))
+                .wrap(Wrap { trim: true })
+                .block(block);
+                frame.render_widget(text, area);
+            }
+            None => frame.render_widget(block, area),
+        }
+    }
+
+    fn load(&mut self, dcmroot: &DicomRoot) {
+        let decoded = pixeldata::decode_frame(dcmroot, 0).map_err(|e| e.to_string());
+        self.window = decoded.as_ref().ok().map(PixelFrame::suggested_window);
+        self.frame = Some(decoded);
+        self.update_pixels();
+    }
+
+    fn update_pixels(&mut self) {
+        if let (Some(Ok(frame)), Some(window)) = (&self.frame, &self.window) {
+            self.pixels = frame.to_rgb8(window);
+        }
+    }
+}
+
+/// Draws RGB pixels scaled to fit the area, preserving their aspect ratio.
+struct HalfBlockImage<'a> {
+    pixels: &'a [[u8; 3]],
+    columns: usize,
+    rows: usize,
+}
+
+impl Widget for HalfBlockImage<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if self.columns == 0 || self.rows == 0 || self.pixels.len() < self.columns * self.rows {
+            return;
+        }
+
+        // Each cell holds two pixels vertically, which are roughly square in most terminals.
+        let area_width: f64 = f64::from(area.width);
+        let area_height: f64 = f64::from(area.height) * 2.0;
+        let scale: f64 = (area_width / self.columns as f64).min(area_height / self.rows as f64);
+        let width: u16 = (self.columns as f64 * scale).floor() as u16;
+        let height: u16 = (self.rows as f64 * scale).floor() as u16;
+        let left: u16 = area.x + (area.width - width) / 2;
+        let top: u16 = area.y + (area.height - height.div_ceil(2)) / 2;
+
+        let sample = |x: u16, y: u16| -> Color {
+            let column: usize = ((f64::from(x) + 0.5) / scale) as usize;
+            let row: usize = ((f64::from(y) + 0.5) / scale) as usize;
+            let [r, g, b] =
+                self.pixels[row.min(self.rows - 1) * self.columns + column.min(self.columns - 1)];
+            Color::Rgb(r, g, b)
+        };
+
+        for y in (0..height).step_by(2) {
+            for x in 0..width {
+                let cell = buf.get_mut(left + x, top + y / 2);
+                cell.set_char(UPPER_HALF_BLOCK).set_fg(sample(x, y));
+                if y + 1 < height {
+                    cell.set_bg(sample(x, y + 1));
+                } else {
+                    cell.set_bg(Color::Reset);
+                }
+            }
+        }
+    }
+}
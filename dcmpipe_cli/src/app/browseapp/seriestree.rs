@@ -0,0 +1,335 @@
+//! The series tree lists the datasets of a folder, or of the series matching an index query, grouped
+//! by patient, study, and series, so that each instance can be opened for browsing within the same
+//! session.
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Result};
+use dcmpipe_lib::{
+    core::{
+        compressed::DatasetFile,
+        dcmobject::DicomRoot,
+        defn::tag::Tag,
+        read::{stop::ParseStop, ParserBuilder},
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags},
+};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+use walkdir::WalkDir;
+
+/// The width of the series tree pane, in columns.
+pub(super) const TREE_PANE_WIDTH: u16 = 48;
+
+/// The number of columns each level of the tree is indented by.
+const INDENT_WIDTH: usize = 2;
+
+/// Patients keyed by name and ID.
+type Patients = BTreeMap<(String, String), Patient>;
+
+struct Patient {
+    label: String,
+    /// Studies keyed by date and StudyInstanceUID.
+    studies: BTreeMap<(String, String), Study>,
+}
+
+struct Study {
+    label: String,
+    /// Series keyed by SeriesNumber and SeriesInstanceUID.
+    series: BTreeMap<(i64, String), Series>,
+}
+
+struct Series {
+    label: String,
+    /// Instances with their InstanceNumber, sorted once scanning completes.
+    instances: Vec<(i64, PathBuf)>,
+}
+
+/// A single line in the tree, which is either a patient, study, series, or instance.
+struct TreeRow {
+    /// The level within the tree, where patients are zero and instances are three.
+    depth: usize,
+    label: String,
+    /// The path of the dataset, for instance rows.
+    path: Option<PathBuf>,
+}
+
+/// The patient/study/series/instance hierarchy of the datasets within a folder.
+pub(super) struct SeriesTree {
+    rows: Vec<TreeRow>,
+    /// The number of files which could not be parsed as DICOM.
+    skipped: usize,
+    /// The index of the row for the dataset currently being browsed.
+    opened: Option<usize>,
+    pub(super) state: ListState,
+    /// Whether keyboard input goes to the tree rather than the element table.
+    pub(super) focused: bool,
+}
+
+impl SeriesTree {
+    /// Scans the folder recursively, grouping all DICOM datasets by the UIDs of their patient,
+    /// study, and series.
+    pub(super) fn scan(folder: &Path) -> Result<SeriesTree> {
+        let files = WalkDir::new(folder)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .map(|e| e.into_path());
+        let tree: SeriesTree = SeriesTree::from_files(files);
+        if tree.rows.is_empty() {
+            return Err(anyhow!("No DICOM datasets found in: {}", folder.display()));
+        }
+        Ok(tree)
+    }
+
+    /// Groups the DICOM datasets among the files by the UIDs of their patient, study, and series,
+    /// e.g. the files of series found in the index. Files which can't be read are skipped.
+    pub(super) fn from_files(files: impl IntoIterator<Item = PathBuf>) -> SeriesTree {
+        let parser_builder: ParserBuilder<'_> = ParserBuilder::default()
+            .stop(ParseStop::BeforeTagValue(tags::PixelData.tag.into()))
+            .dictionary(&STANDARD_DICOM_DICTIONARY);
+
+        let mut patients: Patients = BTreeMap::new();
+        let mut skipped: usize = 0;
+        for path in files {
+            let dcmroot = DatasetFile::open(&path)
+                .map_err(anyhow::Error::from)
+                .and_then(|file| Ok(DicomRoot::parse(&mut parser_builder.build(file))?));
+            match dcmroot {
+                Ok(Some(dcmroot)) => add_instance(&mut patients, &dcmroot, path),
+                _ => skipped += 1,
+            }
+        }
+
+        SeriesTree {
+            rows: flatten(patients),
+            skipped,
+            opened: None,
+            state: ListState::default().with_selected(Some(0)),
+            focused: false,
+        }
+    }
+
+    /// The path of the first instance in the tree.
+    pub(super) fn first_instance(&self) -> Option<&Path> {
+        self.rows.iter().find_map(|row| row.path.as_deref())
+    }
+
+    /// The path of the instance for the selected row. If a patient, study, or series is selected
+    /// then this is the first instance within it.
+    pub(super) fn selected_instance(&self) -> Option<&Path> {
+        let selected: usize = self.state.selected()?;
+        let depth: usize = self.rows.get(selected)?.depth;
+        self.rows
+            .iter()
+            .skip(selected)
+            .enumerate()
+            .take_while(|(i, row)| *i == 0 || row.depth > depth)
+            .find_map(|(_i, row)| row.path.as_deref())
+    }
+
+    /// Marks the instance with the given path as the one being browsed, and selects it.
+    pub(super) fn set_opened(&mut self, path: &Path) {
+        self.opened = self
+            .rows
+            .iter()
+            .position(|row| row.path.as_deref() == Some(path));
+        if self.opened.is_some() {
+            self.state.select(self.opened);
+        }
+    }
+
+    /// Moves the selection by the given number of rows.
+    pub(super) fn select_next(&mut self, modifier: isize) {
+        let last_row: usize = self.rows.len().saturating_sub(1);
+        let i: usize = match self.state.selected() {
+            None => 0,
+            Some(i) => last_row.min(i.saturating_add_signed(modifier)),
+        };
+        self.state.select(Some(i));
+    }
+
+    /// Selects the row at the given position within the visible rows of the tree.
+    pub(super) fn select_visible(&mut self, row_index: usize) {
+        let i: usize = self.state.offset().saturating_add(row_index);
+        if i < self.rows.len() {
+            self.state.select(Some(i));
+        }
+    }
+
+    /// Renders the tree, updating the scroll offset to keep the selected row visible.
+    pub(super) fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let style: Style = if Some(i) == self.opened {
+                    Style::default()
+                        .fg(Color::LightBlue)
+                        .add_modifier(Modifier::BOLD)
+                } else if row.path.is_none() {
+                    Style::default().fg(Color::LightYellow)
+                } else {
+                    Style::default()
+                };
+                ListItem::new(Line::from(vec![
+                    Span::raw(" ".repeat(row.depth * INDENT_WIDTH)),
+                    Span::styled(row.label.clone(), style),
+                ]))
+            })
+            .collect();
+
+        let mut title: String = format!("[{} instances]", self.instance_count());
+        if self.skipped > 0 {
+            title = format!("{title}[{} skipped]", self.skipped);
+        }
+        let border_style: Style = if self.focused {
+            Style::default().fg(Color::LightYellow)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        title,
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ))
+                    .borders(Borders::all())
+                    .border_style(border_style),
+            )
+            .highlight_style(Style::default().bg(Color::Rgb(64, 64, 64)));
+
+        frame.render_stateful_widget(list, area, &mut self.state);
+    }
+
+    fn instance_count(&self) -> usize {
+        self.rows.iter().filter(|row| row.path.is_some()).count()
+    }
+}
+
+/// Adds the dataset to the patient, study, and series it belongs to.
+fn add_instance(patients: &mut Patients, dcmroot: &DicomRoot, path: PathBuf) {
+    let patient_name: String = get_value(dcmroot, &tags::PatientsName);
+    let patient_id: String = get_value(dcmroot, &tags::PatientID);
+    let patient: &mut Patient = patients
+        .entry((patient_name.clone(), patient_id.clone()))
+        .or_insert_with(|| Patient {
+            label: join_label(&[&patient_name.replace('^', " "), &format!("[{patient_id}]")]),
+            studies: BTreeMap::new(),
+        });
+
+    let study_date: String = get_value(dcmroot, &tags::StudyDate);
+    let study_uid: String = get_value(dcmroot, &tags::StudyInstanceUID);
+    let study: &mut Study = patient
+        .studies
+        .entry((study_date.clone(), study_uid.clone()))
+        .or_insert_with(|| {
+            let study_desc: String = get_value(dcmroot, &tags::StudyDescription);
+            let label: String = join_label(&[&study_date, &study_desc]);
+            Study {
+                label: if label.is_empty() { study_uid } else { label },
+                series: BTreeMap::new(),
+            }
+        });
+
+    let series_number: String = get_value(dcmroot, &tags::SeriesNumber);
+    let series_uid: String = get_value(dcmroot, &tags::SeriesInstanceUID);
+    let series: &mut Series = study
+        .series
+        .entry((parse_number(&series_number), series_uid.clone()))
+        .or_insert_with(|| {
+            let modality: String = get_value(dcmroot, &tags::Modality);
+            let series_desc: String = get_value(dcmroot, &tags::SeriesDescription);
+            let number: String = if series_number.is_empty() {
+                String::new()
+            } else {
+                format!("#{series_number}")
+            };
+            let label: String = join_label(&[&modality, &number, &series_desc]);
+            Series {
+                label: if label.is_empty() { series_uid } else { label },
+                instances: Vec::new(),
+            }
+        });
+
+    let instance_number: i64 = parse_number(&get_value(dcmroot, &tags::InstanceNumber));
+    series.instances.push((instance_number, path));
+}
+
+/// Flattens the hierarchy into the rows of the tree, in display order.
+fn flatten(patients: Patients) -> Vec<TreeRow> {
+    let mut rows: Vec<TreeRow> = Vec::new();
+    for patient in patients.into_values() {
+        rows.push(TreeRow {
+            depth: 0,
+            label: patient.label,
+            path: None,
+        });
+        for study in patient.studies.into_values() {
+            rows.push(TreeRow {
+                depth: 1,
+                label: study.label,
+                path: None,
+            });
+            for mut series in study.series.into_values() {
+                series.instances.sort();
+                rows.push(TreeRow {
+                    depth: 2,
+                    label: format!("{} ({})", series.label, series.instances.len()),
+                    path: None,
+                });
+                for (number, path) in series.instances {
+                    let file_name: String = path
+                        .file_name()
+                        .map(|name| name.to_string_lossy().into_owned())
+                        .unwrap_or_default();
+                    let label: String = if number == i64::MAX {
+                        file_name
+                    } else {
+                        format!("#{number} {file_name}")
+                    };
+                    rows.push(TreeRow {
+                        depth: 3,
+                        label,
+                        path: Some(path),
+                    });
+                }
+            }
+        }
+    }
+    rows
+}
+
+/// Gets the value of the element as a string, or an empty string if it's not present.
+fn get_value(dcmroot: &DicomRoot, tag: &Tag) -> String {
+    dcmroot
+        .get_child_by_tag(tag.tag)
+        .and_then(|obj| String::try_from(obj.element()).ok())
+        .map(|value| value.trim().to_owned())
+        .unwrap_or_default()
+}
+
+/// Parses an IS value for sorting, where missing or invalid values sort last.
+fn parse_number(value: &str) -> i64 {
+    value.trim().parse::<i64>().unwrap_or(i64::MAX)
+}
+
+/// Joins the non-empty parts of a label with spaces.
+fn join_label(parts: &[&str]) -> String {
+    parts
+        .iter()
+        .filter(|part| !part.is_empty() && **part != "[]")
+        .copied()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
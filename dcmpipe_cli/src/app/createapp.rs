@@ -0,0 +1,461 @@
+//! The create command builds a DICOM file from a DICOM JSON file or a file of `Attribute=value`
+//! lines, e.g. for generating test fixtures or worklist entries.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{Map, Value};
+
+use dcmpipe_lib::{
+    core::{
+        charset::{self, CSRef, DEFAULT_CHARACTER_SET},
+        dcmelement::DicomElement,
+        defn::{
+            constants::{tags as coretags, ts as corets},
+            dcmdict::DicomDictionary,
+            tag::{Tag, TagPath},
+            ts::TSRef,
+            vl::ValueLength,
+            vr::{self, VRRef},
+        },
+        write::{builder::WriterBuilder, writer::Writer},
+        RawValue,
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags, transfer_syntaxes::ExplicitVRLittleEndian},
+    dimse::constants::IMPLEMENTATION_CLASS_UID,
+};
+
+use crate::{
+    app::{generate_uid, parse_attribute, parse_input_value, parse_vr, CommandApplication},
+    args::CreateArgs,
+};
+
+/// The VRs whose values are given as binary, rather than as text.
+const BINARY_VRS: [VRRef; 7] = [
+    &vr::OB,
+    &vr::OD,
+    &vr::OF,
+    &vr::OL,
+    &vr::OV,
+    &vr::OW,
+    &vr::UN,
+];
+
+/// The attributes of the dataset or of a sequence item, by tag.
+#[derive(Default)]
+struct Item(BTreeMap<u32, Attr>);
+
+enum Attr {
+    Value { vr: VRRef, value: RawValue },
+    Sequence(Vec<Item>),
+}
+
+pub struct CreateApp {
+    args: CreateArgs,
+}
+
+impl CreateApp {
+    pub fn new(args: CreateArgs) -> CreateApp {
+        CreateApp { args }
+    }
+}
+
+impl CommandApplication for CreateApp {
+    fn run(&mut self) -> Result<()> {
+        let ts: TSRef = STANDARD_DICOM_DICTIONARY
+            .get_ts_by_name(&self.args.ts)
+            .or_else(|| STANDARD_DICOM_DICTIONARY.get_ts_by_uid(&self.args.ts))
+            .ok_or_else(|| anyhow!("Unknown transfer syntax: {}", self.args.ts))?;
+        if ts.encapsulated() {
+            return Err(anyhow!(
+                "Datasets can't be created in an encapsulated transfer syntax: {}",
+                ts.uid().name()
+            ));
+        }
+
+        let input: &Path = &self.args.input;
+        let text: String = fs::read_to_string(input)
+            .with_context(|| format!("Failed to read: {}", input.display()))?;
+        let source = Source {
+            dir: input.parent().map(Path::to_path_buf).unwrap_or_default(),
+        };
+        let mut dataset: Item = if text.trim_start().starts_with(['{', '[']) {
+            source.read_json(&text)?
+        } else {
+            source.read_lines(&text)?
+        };
+        add_file_meta(&mut dataset, ts);
+
+        let cs: CSRef = specific_charset(&dataset)?;
+        let mut elements: Vec<DicomElement<'static>> = Vec::new();
+        encode_item(&dataset, ts, cs, &mut elements)?;
+
+        let mut writer: Writer<Vec<u8>> = WriterBuilder::for_file().ts(ts).build(Vec::new());
+        writer.write_elements(elements.iter())?;
+        let bytes: Vec<u8> = writer.into_dataset()?;
+        let output: &Path = &self.args.output;
+        fs::write(output, &bytes)
+            .with_context(|| format!("Failed to write: {}", output.display()))?;
+        println!(
+            "Wrote {} attributes as {} to {}",
+            dataset.0.len(),
+            ts.uid().name(),
+            output.display()
+        );
+        Ok(())
+    }
+}
+
+/// Where the input was read from, for resolving references to files of binary values.
+struct Source {
+    dir: PathBuf,
+}
+
+impl Source {
+    /// Reads lines of `Attribute=value`. The attribute is a tag path, e.g. `PatientName`,
+    /// `(0010,0010)`, or `ReferencedStudySequence[1].ReferencedSOPInstanceUID`, and may end with
+    /// `:VR` to give the VR of attributes not in the dictionary. Blank lines and those starting
+    /// with `#` are ignored.
+    fn read_lines(&self, text: &str) -> Result<Item> {
+        let mut dataset: Item = Item::default();
+        for (number, line) in text.lines().enumerate() {
+            let line: &str = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            self.read_line(&mut dataset, line)
+                .with_context(|| format!("Invalid line {}: {line}", number + 1))?;
+        }
+        Ok(dataset)
+    }
+
+    fn read_line(&self, dataset: &mut Item, line: &str) -> Result<()> {
+        let (attr, value) = line
+            .split_once('=')
+            .ok_or_else(|| anyhow!("expected Attribute=value"))?;
+        let (tagpath, vr) = parse_attribute(attr)?;
+        let Some((last, parents)) = tagpath.nodes.split_last() else {
+            return Err(anyhow!("missing attribute"));
+        };
+
+        let mut item: &mut Item = dataset;
+        for node in parents {
+            item = item.item_mut(node.tag(), node.item().unwrap_or(1))?;
+        }
+        if vr == &vr::SQ {
+            // Items are created by the attributes within them, so only an empty sequence is given.
+            if !value.is_empty() {
+                return Err(anyhow!("sequences can't have a value"));
+            }
+            item.0
+                .entry(last.tag())
+                .or_insert_with(|| Attr::Sequence(Vec::new()));
+            return Ok(());
+        }
+        let value: RawValue = if BINARY_VRS.contains(&vr) {
+            RawValue::Bytes(self.read_binary(value)?)
+        } else {
+            parse_input_value(vr, value)?
+        };
+        item.0.insert(last.tag(), Attr::Value { vr, value });
+        Ok(())
+    }
+
+    /// Reads a binary value given as `base64:<data>` or `@<file>`, relative to the input.
+    fn read_binary(&self, value: &str) -> Result<Vec<u8>> {
+        if let Some(data) = value.strip_prefix("base64:") {
+            Ok(STANDARD.decode(data.trim())?)
+        } else if let Some(file) = value.strip_prefix('@') {
+            let path: PathBuf = self.dir.join(file);
+            fs::read(&path).with_context(|| format!("Failed to read: {}", path.display()))
+        } else if value.is_empty() {
+            Ok(Vec::new())
+        } else {
+            Err(anyhow!(
+                "binary values must be given as base64:<data> or @<file>"
+            ))
+        }
+    }
+
+    /// Reads a dataset in the DICOM JSON Model of Part 18, Annex F. A list of datasets, as
+    /// returned by QIDO-RS, may be given if it has a single dataset.
+    fn read_json(&self, text: &str) -> Result<Item> {
+        let json: Value = serde_json::from_str(text).context("Invalid DICOM JSON")?;
+        match &json {
+            Value::Object(object) => self.json_item(object),
+            Value::Array(datasets) => match datasets.as_slice() {
+                [Value::Object(object)] => self.json_item(object),
+                _ => Err(anyhow!(
+                    "Expected a single dataset, found {}",
+                    datasets.len()
+                )),
+            },
+            _ => Err(anyhow!("Expected a DICOM JSON object")),
+        }
+    }
+
+    fn json_item(&self, object: &Map<String, Value>) -> Result<Item> {
+        let mut item: Item = Item::default();
+        for (key, attr) in object {
+            let tag: u32 = u32::from_str_radix(key, 16)
+                .ok()
+                .filter(|_| key.len() == 8)
+                .ok_or_else(|| anyhow!("Invalid tag: {key}"))?;
+            let attr: Attr = self.json_attr(attr).with_context(|| {
+                format!("Invalid attribute {}", Tag::format_tag_to_display(tag))
+            })?;
+            item.0.insert(tag, attr);
+        }
+        Ok(item)
+    }
+
+    fn json_attr(&self, attr: &Value) -> Result<Attr> {
+        let vr: VRRef = attr
+            .get("vr")
+            .and_then(Value::as_str)
+            .ok_or_else(|| anyhow!("missing vr"))
+            .and_then(parse_vr)?;
+
+        if let Some(data) = attr.get("InlineBinary").and_then(Value::as_str) {
+            let value: RawValue = RawValue::Bytes(STANDARD.decode(data)?);
+            return Ok(Attr::Value { vr, value });
+        }
+        if let Some(uri) = attr.get("BulkDataURI").and_then(Value::as_str) {
+            let file: &str = uri.strip_prefix("file://").unwrap_or(uri);
+            let value: RawValue = RawValue::Bytes(self.read_binary(&format!("@{file}"))?);
+            return Ok(Attr::Value { vr, value });
+        }
+
+        let values: &[Value] = match attr.get("Value") {
+            Some(Value::Array(values)) => values,
+            Some(_) => return Err(anyhow!("Value must be an array")),
+            None => &[],
+        };
+        if vr == &vr::SQ {
+            let items: Vec<Item> = values
+                .iter()
+                .map(|item| match item {
+                    Value::Object(object) => self.json_item(object),
+                    _ => Err(anyhow!("items must be objects")),
+                })
+                .collect::<Result<Vec<Item>>>()?;
+            return Ok(Attr::Sequence(items));
+        }
+
+        let values: Vec<String> = values
+            .iter()
+            .map(|value| json_value_text(vr, value))
+            .collect::<Result<Vec<String>>>()?;
+        let value: RawValue = if vr.is_character_string {
+            // Values are kept whole, as those of e.g. LT may contain `\`.
+            RawValue::Strings(values)
+        } else {
+            parse_input_value(vr, &values.join("\\"))?
+        };
+        Ok(Attr::Value { vr, value })
+    }
+}
+
+impl Item {
+    /// Gets the item of the sequence with the given 1-based index, adding an item if the index is
+    /// one past the existing items.
+    fn item_mut(&mut self, tag: u32, index: usize) -> Result<&mut Item> {
+        let attr: &mut Attr = self
+            .0
+            .entry(tag)
+            .or_insert_with(|| Attr::Sequence(Vec::new()));
+        let Attr::Sequence(items) = attr else {
+            return Err(anyhow!(
+                "{} is not a sequence",
+                Tag::format_tag_to_display(tag)
+            ));
+        };
+        if index == items.len() + 1 {
+            items.push(Item::default());
+        }
+        if index == 0 || index > items.len() {
+            return Err(anyhow!(
+                "item {index} of {} must follow item {}",
+                Tag::format_tag_to_display(tag),
+                items.len()
+            ));
+        }
+        Ok(&mut items[index - 1])
+    }
+
+    fn uid(&self, tag: u32) -> Option<String> {
+        match self.0.get(&tag) {
+            Some(Attr::Value {
+                value: RawValue::Uid(uid),
+                ..
+            }) => Some(uid.clone()),
+            Some(Attr::Value {
+                value: RawValue::Strings(values),
+                ..
+            }) => values.first().cloned(),
+            _ => None,
+        }
+    }
+
+    fn set_default(&mut self, tag: u32, vr: VRRef, value: RawValue) {
+        self.0.entry(tag).or_insert(Attr::Value { vr, value });
+    }
+}
+
+/// Adds the File Meta group, keeping any of its attributes given in the input other than the
+/// Transfer Syntax UID. A SOP Instance UID is generated if the input has a SOP Class UID but not
+/// a SOP Instance UID.
+fn add_file_meta(dataset: &mut Item, ts: TSRef) {
+    let sop_class_uid: Option<String> = dataset.uid(tags::SOPClassUID.tag);
+    if sop_class_uid.is_some() {
+        dataset.set_default(
+            tags::SOPInstanceUID.tag,
+            &vr::UI,
+            RawValue::Uid(generate_uid()),
+        );
+    }
+    let sop_instance_uid: String = dataset
+        .uid(tags::SOPInstanceUID.tag)
+        .unwrap_or_else(generate_uid);
+
+    dataset.set_default(
+        tags::FileMetaInformationVersion.tag,
+        &vr::OB,
+        RawValue::Bytes(vec![0x00, 0x01]),
+    );
+    if let Some(sop_class_uid) = sop_class_uid {
+        dataset.set_default(
+            tags::MediaStorageSOPClassUID.tag,
+            &vr::UI,
+            RawValue::Uid(sop_class_uid),
+        );
+    }
+    dataset.set_default(
+        tags::MediaStorageSOPInstanceUID.tag,
+        &vr::UI,
+        RawValue::Uid(sop_instance_uid),
+    );
+    dataset.0.insert(
+        tags::TransferSyntaxUID.tag,
+        Attr::Value {
+            vr: &vr::UI,
+            value: RawValue::Uid(ts.uid().uid().to_owned()),
+        },
+    );
+    dataset.set_default(
+        tags::ImplementationClassUID.tag,
+        &vr::UI,
+        RawValue::Uid(IMPLEMENTATION_CLASS_UID.to_owned()),
+    );
+}
+
+/// The character set named by the dataset's Specific Character Set, if given.
+fn specific_charset(dataset: &Item) -> Result<CSRef> {
+    let Some(Attr::Value {
+        value: RawValue::Strings(values),
+        ..
+    }) = dataset.0.get(&tags::SpecificCharacterSet.tag)
+    else {
+        return Ok(DEFAULT_CHARACTER_SET);
+    };
+    // Only a single character set is supported, rather than code extensions.
+    match values.iter().find(|value| !value.trim().is_empty()) {
+        Some(name) => charset::lookup_charset(name.trim())
+            .ok_or_else(|| anyhow!("Unsupported character set: {name}")),
+        None => Ok(DEFAULT_CHARACTER_SET),
+    }
+}
+
+/// Encodes the attributes of the item, in order. Sequences and items are given undefined length
+/// so their lengths needn't be computed.
+fn encode_item(
+    item: &Item,
+    ts: TSRef,
+    cs: CSRef,
+    elements: &mut Vec<DicomElement<'static>>,
+) -> Result<()> {
+    // Items and delimiters are always encoded in implicit VR.
+    let implicit_ts: TSRef = if ts.big_endian() {
+        &corets::ImplicitVRBigEndian
+    } else {
+        &corets::ImplicitVRLittleEndian
+    };
+    let implicit = |tag: u32, vl: ValueLength| {
+        DicomElement::new(tag, &vr::UN, vl, implicit_ts, cs, Vec::new(), Vec::new())
+    };
+
+    for (tag, attr) in &item.0 {
+        match attr {
+            Attr::Value { vr, value } => {
+                let elem_ts: TSRef = if *tag <= coretags::FILE_META_GROUP_END {
+                    &ExplicitVRLittleEndian
+                } else {
+                    ts
+                };
+                let mut element: DicomElement<'static> = DicomElement::new(
+                    *tag,
+                    vr,
+                    ValueLength::Explicit(0),
+                    elem_ts,
+                    vr.get_proper_cs(cs),
+                    Vec::new(),
+                    Vec::new(),
+                );
+                element
+                    .encode_value(value.clone(), None)
+                    .with_context(|| format!("Invalid value of {}", describe_tag(*tag)))?;
+                elements.push(element);
+            }
+            Attr::Sequence(items) => {
+                elements.push(DicomElement::new(
+                    *tag,
+                    &vr::SQ,
+                    ValueLength::UndefinedLength,
+                    ts,
+                    cs,
+                    Vec::new(),
+                    Vec::new(),
+                ));
+                for item in items {
+                    elements.push(implicit(coretags::ITEM, ValueLength::UndefinedLength));
+                    encode_item(item, ts, cs, elements)?;
+                    elements.push(implicit(
+                        coretags::ITEM_DELIMITATION_ITEM,
+                        ValueLength::Explicit(0),
+                    ));
+                }
+                elements.push(implicit(
+                    coretags::SEQUENCE_DELIMITATION_ITEM,
+                    ValueLength::Explicit(0),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The text of a value in DICOM JSON, where Person Names are objects of their component groups.
+fn json_value_text(vr: VRRef, value: &Value) -> Result<String> {
+    match value {
+        Value::String(text) => Ok(text.clone()),
+        Value::Number(number) => Ok(number.to_string()),
+        Value::Null => Ok(String::new()),
+        Value::Object(groups) if vr == &vr::PN => {
+            let groups: Vec<&str> = ["Alphabetic", "Ideographic", "Phonetic"]
+                .iter()
+                .map(|group| groups.get(*group).and_then(Value::as_str).unwrap_or(""))
+                .collect();
+            Ok(groups.join("=").trim_end_matches('=').to_owned())
+        }
+        _ => Err(anyhow!("Unsupported value: {value}")),
+    }
+}
+
+fn describe_tag(tag: u32) -> String {
+    TagPath::format_tagpath_to_display(&TagPath::from(tag), Some(&STANDARD_DICOM_DICTIONARY))
+}
@@ -0,0 +1,307 @@
+//! The dedup command finds duplicate datasets within a folder, and can replace them with hard
+//! links or delete them.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::{self, File},
+    io::{self, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+use walkdir::WalkDir;
+
+use dcmpipe_lib::{
+    core::{
+        dcmobject::DicomRoot,
+        hash::{ContentHash, DatasetHasher, HashScope},
+        read::{stop::ParseStop, ParserBuilder},
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags},
+};
+
+use crate::{
+    app::CommandApplication,
+    args::{DedupAction, DedupArgs, DedupMatch},
+    shutdown,
+};
+
+/// The values by which a dataset can match another.
+#[derive(Default)]
+struct DatasetKeys {
+    sop_uid: Option<String>,
+    bytes: Option<[u8; 32]>,
+    pixels: Option<ContentHash>,
+}
+
+/// A value shared by duplicates.
+#[derive(PartialEq, Eq, Hash)]
+enum Key<'k> {
+    SopUid(&'k str),
+    Bytes(&'k [u8; 32]),
+    Pixels(&'k ContentHash),
+}
+
+impl Key<'_> {
+    fn reason(&self) -> &'static str {
+        match self {
+            Key::SopUid(_) => "same SOPInstanceUID",
+            Key::Bytes(_) => "identical file",
+            Key::Pixels(_) => "identical Pixel Data",
+        }
+    }
+}
+
+pub struct DedupApp {
+    args: DedupArgs,
+}
+
+impl DedupApp {
+    pub fn new(args: DedupArgs) -> DedupApp {
+        DedupApp { args }
+    }
+
+    fn get_files(&self) -> Vec<PathBuf> {
+        WalkDir::new(&self.args.folder)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .map(|e| e.into_path())
+            .collect()
+    }
+
+    /// Reads the keys of the dataset in the file, or `None` if it isn't a valid DICOM dataset.
+    fn read_keys(
+        &self,
+        parser_builder: &ParserBuilder<'_>,
+        path: &Path,
+    ) -> Result<Option<DatasetKeys>> {
+        let file: File =
+            File::open(path).with_context(|| format!("Failed to open: {}", path.display()))?;
+        let dcmroot: DicomRoot<'_> = match DicomRoot::parse(&mut parser_builder.build(file)) {
+            Ok(Some(dcmroot)) => dcmroot,
+            Ok(None) => return Ok(None),
+            Err(e) => {
+                println!("Skipping invalid DICOM file: {}, {e}", path.display());
+                return Ok(None);
+            }
+        };
+
+        let mut keys: DatasetKeys = DatasetKeys {
+            sop_uid: dcmroot
+                .get_child_by_tag(tags::SOPInstanceUID.tag)
+                .and_then(|obj| String::try_from(obj.element()).ok())
+                .filter(|sop_uid| !sop_uid.is_empty()),
+            ..Default::default()
+        };
+        if self.args.matches.contains(&DedupMatch::Pixels)
+            && dcmroot.get_child_by_tag(tags::PixelData.tag).is_some()
+        {
+            let hasher: DatasetHasher = DatasetHasher::new(HashScope::PixelData).normalize(true);
+            keys.pixels = Some(hasher.hash(&dcmroot)?);
+        }
+        if self.args.matches.contains(&DedupMatch::Bytes) {
+            let mut hasher = Sha256::new();
+            let mut file: File =
+                File::open(path).with_context(|| format!("Failed to open: {}", path.display()))?;
+            io::copy(&mut file, &mut hasher)
+                .with_context(|| format!("Failed to read: {}", path.display()))?;
+            keys.bytes = Some(hasher.finalize().into());
+        }
+        Ok(Some(keys))
+    }
+}
+
+impl CommandApplication for DedupApp {
+    fn run(&mut self) -> Result<()> {
+        shutdown::install_handler()?;
+
+        // Only matching the Pixel Data requires parsing the entire dataset.
+        let parse_stop: ParseStop = if self.args.matches.contains(&DedupMatch::Pixels) {
+            ParseStop::EndOfDataset
+        } else {
+            ParseStop::AfterTagValue(tags::SOPInstanceUID.tag.into())
+        };
+        let parser_builder: ParserBuilder<'_> = ParserBuilder::default()
+            .stop(parse_stop)
+            .dictionary(&STANDARD_DICOM_DICTIONARY);
+
+        let mut datasets: Vec<(PathBuf, DatasetKeys)> = Vec::new();
+        for path in self.get_files() {
+            if shutdown::is_requested() {
+                println!("Dedup interrupted before: {}", path.display());
+                return Ok(());
+            }
+            if let Some(keys) = self.read_keys(&parser_builder, &path)? {
+                datasets.push((path, keys));
+            }
+        }
+
+        // Each duplicate refers to the index of the first dataset with the same key, which may
+        // itself be a duplicate by a later key.
+        let mut duplicate_of: Vec<Option<(usize, &'static str)>> = vec![None; datasets.len()];
+        let key_fns: [fn(&DatasetKeys) -> Option<Key<'_>>; 3] = [
+            |keys| keys.sop_uid.as_deref().map(Key::SopUid),
+            |keys| keys.bytes.as_ref().map(Key::Bytes),
+            |keys| keys.pixels.as_ref().map(Key::Pixels),
+        ];
+        for key_fn in key_fns {
+            let mut first: HashMap<Key<'_>, usize> = HashMap::new();
+            for (index, (_path, keys)) in datasets.iter().enumerate() {
+                if duplicate_of[index].is_some() {
+                    continue;
+                }
+                let Some(key) = key_fn(keys) else {
+                    continue;
+                };
+                let reason: &'static str = key.reason();
+                match first.get(&key) {
+                    Some(original) => duplicate_of[index] = Some((*original, reason)),
+                    None => {
+                        first.insert(key, index);
+                    }
+                }
+            }
+        }
+
+        let mut groups: BTreeMap<usize, Vec<(usize, &'static str)>> = BTreeMap::new();
+        for (index, duplicate) in duplicate_of.iter().enumerate() {
+            let Some((mut original, reason)) = *duplicate else {
+                continue;
+            };
+            while let Some((next, _reason)) = duplicate_of[original] {
+                original = next;
+            }
+            groups.entry(original).or_default().push((index, reason));
+        }
+
+        let relative = |index: usize| -> String {
+            let path: &Path = &datasets[index].0;
+            path.strip_prefix(&self.args.folder)
+                .unwrap_or(path)
+                .display()
+                .to_string()
+        };
+        let mut handled: usize = 0;
+        let mut differing: usize = 0;
+        for (original, duplicates) in &groups {
+            println!("{}", relative(*original));
+            let original_path: &Path = &datasets[*original].0;
+            for (index, reason) in duplicates {
+                println!("  {} ({reason})", relative(*index));
+                let path: &Path = &datasets[*index].0;
+                // Files are only replaced or deleted if they're identical to the file kept, which
+                // isn't implied by a matching key, e.g. a corrected dataset with the same UID, or
+                // a file grouped with the kept file through a match on another of its keys.
+                if self.args.action != DedupAction::Report && !same_contents(original_path, path)? {
+                    println!("    kept, as its contents differ");
+                    differing += 1;
+                    continue;
+                }
+                match self.args.action {
+                    DedupAction::Report => {}
+                    DedupAction::Hardlink => {
+                        if !is_hard_link(original_path, path) {
+                            replace_with_hard_link(original_path, path)?;
+                            handled += 1;
+                        }
+                    }
+                    DedupAction::Delete => {
+                        fs::remove_file(path)
+                            .with_context(|| format!("Failed to delete: {}", path.display()))?;
+                        handled += 1;
+                    }
+                }
+            }
+        }
+
+        let duplicates: usize = groups.values().map(Vec::len).sum();
+        println!(
+            "Found {duplicates} duplicates of {} datasets in {} scanned",
+            groups.len(),
+            datasets.len()
+        );
+        match self.args.action {
+            DedupAction::Report => {}
+            DedupAction::Hardlink => println!("Replaced {handled} duplicates with hard links"),
+            DedupAction::Delete => println!("Deleted {handled} duplicates"),
+        }
+        if differing > 0 {
+            println!("Kept {differing} duplicates whose contents differ from the file kept");
+        }
+        Ok(())
+    }
+}
+
+/// Whether the files have identical bytes.
+fn same_contents(a: &Path, b: &Path) -> Result<bool> {
+    let open = |path: &Path| -> Result<(u64, BufReader<File>)> {
+        let file: File =
+            File::open(path).with_context(|| format!("Failed to open: {}", path.display()))?;
+        let len: u64 = file
+            .metadata()
+            .with_context(|| format!("Failed to read: {}", path.display()))?
+            .len();
+        Ok((len, BufReader::new(file)))
+    };
+    let (a_len, mut a_reader) = open(a)?;
+    let (b_len, mut b_reader) = open(b)?;
+    if a_len != b_len {
+        return Ok(false);
+    }
+
+    let mut a_buf: [u8; 8192] = [0u8; 8192];
+    let mut b_buf: [u8; 8192] = [0u8; 8192];
+    loop {
+        let read: usize = a_reader
+            .read(&mut a_buf)
+            .with_context(|| format!("Failed to read: {}", a.display()))?;
+        if read == 0 {
+            return Ok(true);
+        }
+        b_reader
+            .read_exact(&mut b_buf[..read])
+            .with_context(|| format!("Failed to read: {}", b.display()))?;
+        if a_buf[..read] != b_buf[..read] {
+            return Ok(false);
+        }
+    }
+}
+
+/// Replaces `path` with a hard link to `original`. The link is created alongside `path` and then
+/// renamed over it, so `path` is never missing if linking fails.
+fn replace_with_hard_link(original: &Path, path: &Path) -> Result<()> {
+    let mut link_name = path.as_os_str().to_owned();
+    link_name.push(".dedup");
+    let link: PathBuf = PathBuf::from(link_name);
+    fs::hard_link(original, &link).with_context(|| {
+        format!(
+            "Failed to link {} to {}",
+            link.display(),
+            original.display()
+        )
+    })?;
+    if let Err(e) = fs::rename(&link, path) {
+        let _ = fs::remove_file(&link);
+        return Err(e).with_context(|| format!("Failed to replace: {}", path.display()));
+    }
+    Ok(())
+}
+
+/// Whether both paths are already hard links to the same file.
+#[cfg(unix)]
+fn is_hard_link(a: &Path, b: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    match (fs::metadata(a), fs::metadata(b)) {
+        (Ok(a), Ok(b)) => a.dev() == b.dev() && a.ino() == b.ino(),
+        _ => false,
+    }
+}
+
+#[cfg(not(unix))]
+fn is_hard_link(_a: &Path, _b: &Path) -> bool {
+    false
+}
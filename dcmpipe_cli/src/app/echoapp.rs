@@ -0,0 +1,178 @@
+use std::{
+    net::{SocketAddr, TcpStream},
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Result};
+use dcmpipe_lib::{
+    core::defn::dcmdict::DicomDictionary,
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, uids},
+    dimse::{
+        assoc::{Association, AssociationBuilder},
+        commands::{CommandBuilder, Message},
+        constants::{status, CommandField},
+    },
+};
+
+use crate::{
+    app::{associate_remote, calling_ae, resolve_remote, CommandApplication},
+    args::EchoArgs,
+    config::Config,
+    shutdown,
+};
+
+pub struct EchoApp {
+    args: EchoArgs,
+    config: Config,
+}
+
+impl EchoApp {
+    pub fn new(args: EchoArgs, config: Config) -> EchoApp {
+        EchoApp { args, config }
+    }
+
+    /// Sends C-ECHO requests over the association, printing the round-trip time of each, and
+    /// returns the round-trip times of those which succeeded along with the number sent.
+    fn echo(
+        &self,
+        assoc: &mut Association,
+        stream: &mut TcpStream,
+        ctx_id: u8,
+    ) -> Result<(Vec<Duration>, u32)> {
+        let mut round_trips: Vec<Duration> = Vec::new();
+        let mut sent: u32 = 0;
+        for _ in 0..self.args.count {
+            if shutdown::is_requested() {
+                break;
+            }
+
+            let msg_id: u16 = assoc.next_msg_id();
+            let rq = CommandBuilder::c_echo_rq(msg_id, uids::VerificationSOPClass.uid).build()?;
+            let started: Instant = Instant::now();
+            assoc.write_message(&Message::new(ctx_id, rq, None), stream)?;
+            let rsp: Message = assoc
+                .read_message(stream)?
+                .ok_or_else(|| anyhow!("Association released before a response was received"))?;
+            let elapsed: Duration = started.elapsed();
+            sent += 1;
+
+            if rsp.command_field() != Some(CommandField::CEchoRsp)
+                || rsp.message_id_responded_to() != Some(msg_id)
+            {
+                return Err(anyhow!(
+                    "Unexpected response: {:?} to message {:?}",
+                    rsp.command_field(),
+                    rsp.message_id_responded_to()
+                ));
+            }
+            match rsp.status() {
+                Some(status::SUCCESS) => {
+                    println!("C-ECHO {msg_id}: Success in {}", format_ms(elapsed));
+                    round_trips.push(elapsed);
+                }
+                Some(status) => {
+                    println!(
+                        "C-ECHO {msg_id}: Status {status:#06X} in {}",
+                        format_ms(elapsed)
+                    );
+                }
+                None => println!("C-ECHO {msg_id}: No status in {}", format_ms(elapsed)),
+            }
+        }
+        Ok((round_trips, sent))
+    }
+}
+
+impl CommandApplication for EchoApp {
+    fn run(&mut self) -> Result<()> {
+        shutdown::install_handler()?;
+
+        let calling_ae: &str = calling_ae(&self.args.remote, &self.config);
+        let addr: SocketAddr = resolve_remote(&self.args.remote)?;
+        let assoc_builder: AssociationBuilder = AssociationBuilder::default()
+            .ae_title(calling_ae)
+            .abstract_syntax(uids::VerificationSOPClass.uid);
+
+        let started: Instant = Instant::now();
+        let (mut assoc, mut stream) = associate_remote(&self.args.remote, &addr, &assoc_builder)?;
+        println!(
+            "Association with {}@{addr} as {calling_ae} accepted in {}",
+            self.args.remote.called_ae,
+            format_ms(started.elapsed())
+        );
+        if self.args.verbose {
+            print_association(&assoc);
+        }
+
+        let Some(ctx_id) = assoc
+            .get_pres_ctx_by_abstract_syntax(uids::VerificationSOPClass.uid)
+            .map(|pres_ctx| pres_ctx.ctx_id())
+        else {
+            assoc.release(&mut stream)?;
+            return Err(anyhow!("Verification SOP Class was not accepted"));
+        };
+
+        let result: Result<(Vec<Duration>, u32)> = self.echo(&mut assoc, &mut stream, ctx_id);
+        let (round_trips, sent) = match result {
+            Ok(echoed) => echoed,
+            Err(e) => {
+                // The association can't be relied on after a network or protocol error.
+                let _ = assoc.abort(&mut stream);
+                return Err(e);
+            }
+        };
+        assoc.release(&mut stream)?;
+
+        let succeeded: usize = round_trips.len();
+        print!("{succeeded} of {sent} C-ECHO requests succeeded");
+        if let (Some(min), Some(max)) = (round_trips.iter().min(), round_trips.iter().max()) {
+            let avg: Duration = round_trips.iter().sum::<Duration>() / succeeded as u32;
+            print!(
+                ", round-trip min/avg/max: {}/{}/{}",
+                format_ms(*min),
+                format_ms(avg),
+                format_ms(*max)
+            );
+        }
+        println!();
+
+        if succeeded < sent as usize {
+            return Err(anyhow!(
+                "{} C-ECHO requests failed",
+                sent as usize - succeeded
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Prints the details negotiated for the association.
+fn print_association(assoc: &Association) {
+    println!("  Calling AE: {}", assoc.this_ae());
+    println!("  Called AE: {}", assoc.peer_ae());
+    println!(
+        "  Implementation: {} {}",
+        assoc.peer_impl_class_uid().unwrap_or("<none>"),
+        assoc.peer_impl_ver_name().unwrap_or_default()
+    );
+    match assoc.peer_max_pdu_length() {
+        0 => println!("  Max PDU length: unlimited"),
+        max_pdu_length => println!("  Max PDU length: {max_pdu_length}"),
+    }
+    println!("  Accepted presentation contexts:");
+    for pres_ctx in assoc.pres_ctxs() {
+        let abstract_syntax: &str = STANDARD_DICOM_DICTIONARY
+            .get_uid_by_uid(pres_ctx.abstract_syntax())
+            .map_or(pres_ctx.abstract_syntax(), |uid| uid.name);
+        println!(
+            "    {}: {abstract_syntax}, {}",
+            pres_ctx.ctx_id(),
+            pres_ctx.ts().uid().name()
+        );
+    }
+}
+
+/// Formats a duration in milliseconds, with sub-millisecond precision as echoes are often quick.
+fn format_ms(duration: Duration) -> String {
+    format!("{:.2} ms", duration.as_secs_f64() * 1000.0)
+}
@@ -0,0 +1,197 @@
+//! The edit command applies a template of attribute changes to datasets, e.g. every instance of a
+//! study, keeping references between them consistent when UIDs are reassigned. Templates are read
+//! as JSON if the file has a `.json` extension, otherwise as TOML.
+//!
+//! ```json
+//! {
+//!   "set": {
+//!     "StudyDescription": "Research copy",
+//!     "ReferencedStudySequence[1].ReferencedSOPClassUID": "1.2.840.10008.3.1.2.3.1",
+//!     "(0009,1001):LO": "private value"
+//!   },
+//!   "remove": ["InstitutionName", "(0008,0081)"],
+//!   "reassign_uids": ["StudyInstanceUID", "SeriesInstanceUID", "SOPInstanceUID"]
+//! }
+//! ```
+//!
+//! Attributes are given as in `create`, with values of text and numbers as text. Each value of a
+//! reassigned UID is given a new UID, and all references to it within the edited datasets are
+//! updated to match.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use walkdir::WalkDir;
+
+use dcmpipe_lib::{
+    core::{
+        dcmobject::DicomRoot,
+        defn::tag::Tag,
+        edit::{EditTemplate, UidMap},
+        read::Parser,
+        write::{builder::WriterBuilder, writer::Writer},
+        RawValue,
+    },
+    dict::stdlookup::STANDARD_DICOM_DICTIONARY,
+};
+
+use crate::{
+    app::{generate_uid, parse_attribute, parse_file, parse_input_value, CommandApplication},
+    args::EditArgs,
+};
+
+/// The pipeline stage recorded as modifying elements, when tracking provenance.
+static EDIT_STAGE: &str = "edit";
+
+/// The template file, see the module documentation.
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct TemplateFile {
+    #[serde(default)]
+    set: BTreeMap<String, String>,
+    #[serde(default)]
+    remove: Vec<String>,
+    #[serde(default)]
+    reassign_uids: Vec<String>,
+}
+
+pub struct EditApp {
+    args: EditArgs,
+}
+
+impl EditApp {
+    pub fn new(args: EditArgs) -> EditApp {
+        EditApp { args }
+    }
+
+    /// Lists the datasets to edit along with the path each is written to.
+    fn get_files(&self) -> Result<Vec<(PathBuf, PathBuf)>> {
+        let source: &Path = &self.args.source;
+        if source.is_file() {
+            let dest: PathBuf = self.args.output.clone().unwrap_or_else(|| source.into());
+            return Ok(vec![(source.to_path_buf(), dest)]);
+        }
+        if !source.is_dir() {
+            return Err(anyhow!("invalid source: {}", source.display()));
+        }
+        if !self.args.recursive {
+            return Err(anyhow!(
+                "{} is a folder, use --recursive to edit the datasets within it",
+                source.display()
+            ));
+        }
+
+        let mut files: Vec<(PathBuf, PathBuf)> = Vec::new();
+        for entry in WalkDir::new(source)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+        {
+            let dest: PathBuf = match &self.args.output {
+                Some(output) => output.join(entry.path().strip_prefix(source)?),
+                None => entry.path().to_path_buf(),
+            };
+            files.push((entry.into_path(), dest));
+        }
+        Ok(files)
+    }
+
+    fn edit(&self, template: &EditTemplate, uids: &UidMap, path: &Path, dest: &Path) -> Result<()> {
+        let mut dcmroot: DicomRoot<'_> = parse_dataset(path)?;
+        template
+            .apply(&mut dcmroot, uids, EDIT_STAGE)
+            .context("Failed to edit")?;
+
+        let mut writer: Writer<Vec<u8>> =
+            WriterBuilder::for_file().ts(dcmroot.ts()).build(Vec::new());
+        writer.write_dcmroot(&dcmroot)?;
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create folder: {}", parent.display()))?;
+        }
+        fs::write(dest, writer.into_dataset()?)
+            .with_context(|| format!("Failed to write: {}", dest.display()))?;
+        Ok(())
+    }
+}
+
+impl CommandApplication for EditApp {
+    fn run(&mut self) -> Result<()> {
+        let template: EditTemplate = load_template(&self.args.template)?;
+        let files: Vec<(PathBuf, PathBuf)> = self.get_files()?;
+
+        // New UIDs are assigned for all datasets first, as each may reference any of the others.
+        let mut uids: UidMap = UidMap::default();
+        let mut datasets: Vec<(PathBuf, PathBuf)> = Vec::new();
+        let mut skipped: usize = 0;
+        for (path, dest) in files {
+            let assigned: Result<usize> = parse_dataset(&path)
+                .and_then(|dcmroot| Ok(template.assign_uids(&dcmroot, &mut uids, generate_uid)?));
+            match assigned {
+                Ok(_) => datasets.push((path, dest)),
+                Err(e) => {
+                    println!("Skipping {}, {e:#}", path.display());
+                    skipped += 1;
+                }
+            }
+        }
+
+        let mut edited: usize = 0;
+        for (path, dest) in datasets {
+            match self.edit(&template, &uids, &path, &dest) {
+                Ok(()) => edited += 1,
+                Err(e) => {
+                    println!("Skipping {}, {e:#}", path.display());
+                    skipped += 1;
+                }
+            }
+        }
+
+        println!(
+            "Edited {edited} files, skipped {skipped}, reassigned {} UIDs",
+            uids.len()
+        );
+        Ok(())
+    }
+}
+
+/// Loads the template of changes, see the module documentation.
+fn load_template(path: &Path) -> Result<EditTemplate> {
+    let contents: String = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read template: {}", path.display()))?;
+    let file: TemplateFile = if path.extension().is_some_and(|ext| ext == "json") {
+        serde_json::from_str(&contents)
+            .with_context(|| format!("Invalid template: {}", path.display()))?
+    } else {
+        toml::from_str(&contents)
+            .with_context(|| format!("Invalid template: {}", path.display()))?
+    };
+
+    let mut template: EditTemplate = EditTemplate::default();
+    for (attr, value) in &file.set {
+        let (tagpath, vr) = parse_attribute(attr)?;
+        let value: RawValue =
+            parse_input_value(vr, value).with_context(|| format!("Invalid value of {attr}"))?;
+        template = template.set(tagpath, vr, value);
+    }
+    for attr in &file.remove {
+        template = template.remove(parse_attribute(attr)?.0);
+    }
+    for attr in &file.reassign_uids {
+        let tag: u32 = Tag::parse_tag(attr, Some(&STANDARD_DICOM_DICTIONARY))
+            .map_err(|_e| anyhow!("Invalid tag: {attr}"))?;
+        template = template.reassign_uid(tag);
+    }
+    Ok(template)
+}
+
+fn parse_dataset(path: &Path) -> Result<DicomRoot<'static>> {
+    let mut parser: Parser<'static, _> = parse_file(path, false)?;
+    DicomRoot::parse(&mut parser)?.ok_or_else(|| anyhow!("file is not dicom: {}", path.display()))
+}
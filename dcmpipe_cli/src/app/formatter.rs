@@ -0,0 +1,277 @@
+//! Formatting of elements as text, shared by the print command's listing and the element table of
+//! the browse command. Each part of a rendered element is given a `Highlight`, which `print`
+//! renders as ANSI colors and `browse` as the styles of its cells.
+
+use std::fmt::Display;
+
+use crossterm::style::Stylize;
+use dcmpipe_lib::{
+    core::{
+        dcmelement::DicomElement,
+        defn::{tag::Tag, ts::TSRef, vl::ValueLength, vr},
+    },
+    dict::tags,
+};
+
+use super::{TagCategory, TagValue};
+
+static HIDE_GROUP_TAGS: bool = false;
+static HIDE_DELIMITATION_TAGS: bool = false;
+
+/// The width names are padded to, less the indentation, so that the lengths and values of elements
+/// line up at each level of nesting.
+const NAME_WIDTH: usize = 36;
+
+/// Appended to values truncated by `max_value_width`.
+const TRUNCATION_MARK: &str = "..";
+
+/// How a part of a rendered element is highlighted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Highlight {
+    Plain,
+    /// Tag numbers, VRs, lengths, and offsets.
+    Muted,
+    /// Names of private and unknown tags.
+    Unnamed,
+    /// Names of UIDs and readable forms of values.
+    Annotation,
+    Error,
+}
+
+/// Formats elements as text, see `format_line()`.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ElementFormatter {
+    /// The number of characters values are truncated to.
+    max_value_width: Option<usize>,
+    /// The number of levels of sequences whose contents are formatted, see `is_within_depth()`.
+    max_depth: Option<usize>,
+    /// Whether lines start with the byte offset and encoded length of the element.
+    offsets: bool,
+    /// Whether lines are highlighted with ANSI colors.
+    color: bool,
+}
+
+impl ElementFormatter {
+    pub(crate) fn max_value_width(mut self, max_value_width: Option<usize>) -> Self {
+        self.max_value_width = max_value_width;
+        self
+    }
+
+    pub(crate) fn max_depth(mut self, max_depth: Option<usize>) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    pub(crate) fn offsets(mut self, offsets: bool) -> Self {
+        self.offsets = offsets;
+        self
+    }
+
+    pub(crate) fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Whether the element is nested within no more sequences than `max_depth`. Elements at the
+    /// top level of the dataset have a depth of 0, and the items of their sequences, along with
+    /// the elements within those items, a depth of 1.
+    pub(crate) fn is_within_depth(&self, element: &DicomElement) -> bool {
+        let depth: usize = element
+            .sequence_path()
+            .iter()
+            .filter(|sq_el| sq_el.seq_tag() != tags::Item.tag)
+            .count();
+        self.max_depth.is_none_or(|max_depth| depth <= max_depth)
+    }
+
+    /// The highlight of the element's name, by whether it's named by the dictionary.
+    pub(crate) fn name_highlight(tag_name: &TagCategory) -> Highlight {
+        match tag_name {
+            TagCategory::Known(_, _) => Highlight::Plain,
+            _ => Highlight::Unnamed,
+        }
+    }
+
+    /// The parts of a value, truncated to `max_value_width`. Empty values have no parts.
+    pub(crate) fn value_parts(&self, tag_value: TagValue) -> Vec<(String, Highlight)> {
+        let mut parts: Vec<(String, Highlight)> = match tag_value {
+            TagValue::Sequence => Vec::new(),
+            TagValue::Error(_) => vec![(tag_value.to_string(), Highlight::Error)],
+            TagValue::Uid(uid, name) if name.is_empty() => vec![(uid, Highlight::Plain)],
+            TagValue::Uid(value, readable) | TagValue::Readable(value, readable) => vec![
+                (value, Highlight::Plain),
+                (format!(" => {readable}"), Highlight::Annotation),
+            ],
+            TagValue::Stringified(value) => vec![(value, Highlight::Plain)],
+        };
+        parts.retain(|(text, _)| !text.is_empty());
+        match self.max_value_width {
+            Some(max_value_width) => truncate(parts, max_value_width),
+            None => parts,
+        }
+    }
+
+    /// Formats an element on a single line, indented by its depth in sequences
+    /// ```
+    /// (gggg,eeee) VR TagName [VL] | TagValue
+    /// ```
+    /// or
+    /// ```
+    /// (gggg,eeee) VR TagName [0] <empty>
+    /// ```
+    /// Names for unknown tags will render as `<UnknownTag>`. With `offsets` the line starts with
+    /// the element's byte offset and encoded length, or `-` where they're unknown.
+    ///
+    /// Returns `None` for elements which aren't shown, such as padding between elements.
+    pub(crate) fn format_line(
+        &self,
+        ts: TSRef,
+        element: &DicomElement,
+        tag_value: TagValue,
+    ) -> Option<String> {
+        // Group Length tags are deprecated, see note on Part 5 Section 7.2
+        if HIDE_GROUP_TAGS && element.tag().trailing_zeros() >= 16 {
+            return None;
+        }
+
+        // These are delimiter items that are not very useful to see
+        if HIDE_DELIMITATION_TAGS
+            && (element.tag() == tags::ItemDelimitationItem.tag
+                || element.tag() == tags::SequenceDelimitationItem.tag)
+        {
+            return None;
+        }
+
+        // Some (malformed?) datasets have a bunch of zeroes between elements.
+        if element.tag() == 0
+            && ((ts.explicit_vr() && element.vr() == &vr::INVALID)
+                || (!ts.explicit_vr() && element.vr() == &vr::UN))
+            && element.vl() == ValueLength::Explicit(0)
+        {
+            return None;
+        }
+
+        let mut line: String = String::new();
+        if self.offsets {
+            let offset: String = element
+                .offset()
+                .map_or_else(|| "-".to_owned(), |offset| offset.to_string());
+            let encoded_len: String = element
+                .encoded_len()
+                .map_or_else(|| "-".to_owned(), |len| len.to_string());
+            line.push_str(&self.paint(format!("{offset:>10} {encoded_len:>10}"), Highlight::Muted));
+            line.push_str("  ");
+        }
+
+        let tag_name: TagCategory = element.into();
+        let name_highlight: Highlight = ElementFormatter::name_highlight(&tag_name);
+        let vr: &str = element.vr().ident;
+        let vl: String = match element.vl() {
+            ValueLength::Explicit(len) => {
+                if len % 2 != 0 {
+                    format!("[*{:?}]", element.vl())
+                } else {
+                    format!("[{:?}]", element.vl())
+                }
+            }
+            ValueLength::UndefinedLength => "[u/l]".to_string(),
+        };
+
+        // Sequence path will nest tags under ITEM elements. Double the indentation level for the
+        // number of nested sequences (non-ITEM), and each ITEM element should be nested one level.
+        // If the current element is a delimiter then reduce the associated indentation level.
+        let seq_path = element.sequence_path();
+        let non_item_parents = seq_path
+            .iter()
+            .filter(|sq_el| sq_el.seq_tag() != tags::Item.tag)
+            .count();
+        let item_parents = seq_path
+            .iter()
+            .filter(|sq_el| sq_el.seq_tag() == tags::Item.tag)
+            .count();
+        let mut indent_width = non_item_parents * 2 + item_parents;
+        if element.tag() == tags::ItemDelimitationItem.tag {
+            indent_width -= 1;
+        } else if element.tag() == tags::SequenceDelimitationItem.tag {
+            indent_width -= 2;
+        }
+        line.push_str(&" ".repeat(indent_width));
+
+        if element.tag() == tags::Item.tag {
+            line.push_str(&self.paint(tag_name, name_highlight));
+            if let Some(last_seq_elem) = seq_path.last() {
+                let item_no: String = last_seq_elem
+                    .item()
+                    .map(|item_no: usize| format!("#{}", item_no))
+                    .unwrap_or_else(|| "#[NO ITEM NUMBER]".to_string());
+                line.push_str(&format!(" {item_no} "));
+                line.push_str(&self.paint(format!("{vr} {vl}"), Highlight::Muted));
+            }
+            return Some(line);
+        }
+
+        // The name is padded on its own so that padding isn't colored.
+        let name: String = tag_name.to_string();
+        let name_width: usize = NAME_WIDTH.saturating_sub(indent_width);
+        let padding: String = " ".repeat(name_width.saturating_sub(name.len()));
+        line.push_str(&self.paint(Tag::format_tag_to_display(element.tag()), Highlight::Muted));
+        line.push(' ');
+        line.push_str(&self.paint(vr, Highlight::Muted));
+        line.push(' ');
+        line.push_str(&self.paint(name, name_highlight));
+        line.push_str(&padding);
+        line.push(' ');
+        line.push_str(&self.paint(vl, Highlight::Muted));
+
+        let parts: Vec<(String, Highlight)> = self.value_parts(tag_value);
+        if !parts.is_empty() {
+            if element.is_empty() && !element.is_seq_like() {
+                line.push(' ');
+            } else {
+                line.push_str(" | ");
+            }
+            for (text, highlight) in parts {
+                line.push_str(&self.paint(text, highlight));
+            }
+        }
+        Some(line)
+    }
+
+    /// Renders the text with the ANSI colors of the highlight, if `color` is enabled.
+    fn paint(&self, text: impl Display, highlight: Highlight) -> String {
+        if !self.color {
+            return text.to_string();
+        }
+        let text: String = text.to_string();
+        match highlight {
+            Highlight::Plain => text,
+            Highlight::Muted => text.dark_grey().to_string(),
+            Highlight::Unnamed => text.dark_grey().italic().to_string(),
+            Highlight::Annotation => text.yellow().to_string(),
+            Highlight::Error => text.red().to_string(),
+        }
+    }
+}
+
+/// Truncates the parts of a value to the number of characters, marking where it was truncated.
+fn truncate(parts: Vec<(String, Highlight)>, width: usize) -> Vec<(String, Highlight)> {
+    let len: usize = parts.iter().map(|(text, _)| text.chars().count()).sum();
+    if len <= width {
+        return parts;
+    }
+
+    let mut remaining: usize = width.saturating_sub(TRUNCATION_MARK.len());
+    let mut truncated: Vec<(String, Highlight)> = Vec::with_capacity(parts.len());
+    let mut last_highlight: Highlight = Highlight::Plain;
+    for (text, highlight) in parts {
+        if remaining == 0 {
+            break;
+        }
+        let kept: String = text.chars().take(remaining).collect();
+        remaining -= kept.chars().count();
+        last_highlight = highlight;
+        truncated.push((kept, highlight));
+    }
+    truncated.push((TRUNCATION_MARK.to_owned(), last_highlight));
+    truncated
+}
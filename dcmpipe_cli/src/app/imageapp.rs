@@ -0,0 +1,220 @@
+//! The image command renders frames of a dataset's Pixel Data to PNG or JPEG images.
+
+use std::{
+    ffi::OsString,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Context, Result};
+
+use dcmpipe_lib::core::{
+    dcmobject::DicomRoot,
+    pixeldata::{FrameDecoder, Overlay, PixelFrame, VoiLut, Window},
+    read::Parser,
+};
+
+use crate::{
+    app::{parse_file, render::RgbImage, CommandApplication},
+    args::{ImageArgs, ImageFormat},
+};
+
+/// The color overlays are burned in with.
+static OVERLAY_COLOR: [u8; 3] = [255, 255, 255];
+
+pub struct ImageApp {
+    args: ImageArgs,
+}
+
+/// How monochrome values are mapped to display values.
+enum Display {
+    /// The dataset's window if it covers the values, otherwise an automatic window.
+    Suggested,
+    Auto,
+    Window(Window),
+    Lut(VoiLut),
+}
+
+impl ImageApp {
+    pub fn new(args: ImageArgs) -> ImageApp {
+        ImageApp { args }
+    }
+
+    fn display(&self, dcmroot: &DicomRoot<'_>) -> Result<Display> {
+        if let Some(window) = &self.args.window {
+            let values: Vec<f64> = window
+                .split(',')
+                .map(|value| {
+                    value
+                        .trim()
+                        .parse::<f64>()
+                        .with_context(|| format!("Invalid window value: {value}"))
+                })
+                .collect::<Result<Vec<f64>>>()?;
+            let [center, width] = values[..] else {
+                return Err(anyhow!(
+                    "The window must be given as CENTER,WIDTH: {window}"
+                ));
+            };
+            if width < 1.0 {
+                return Err(anyhow!("The window width must be at least 1: {width}"));
+            }
+            return Ok(Display::Window(Window::new(center, width)));
+        }
+        if let Some(number) = self.args.voi_lut {
+            let mut luts: Vec<VoiLut> =
+                VoiLut::read_all(dcmroot).context("Failed to read the VOI LUTs")?;
+            if number == 0 || number > luts.len() {
+                return Err(anyhow!(
+                    "The dataset has {} VOI LUTs, not a VOI LUT {number}",
+                    luts.len()
+                ));
+            }
+            return Ok(Display::Lut(luts.swap_remove(number - 1)));
+        }
+        if self.args.auto_window {
+            return Ok(Display::Auto);
+        }
+        Ok(Display::Suggested)
+    }
+
+    fn format(&self) -> ImageFormat {
+        if let Some(format) = self.args.format {
+            return format;
+        }
+        let extension: Option<String> = self
+            .args
+            .output
+            .as_deref()
+            .and_then(Path::extension)
+            .map(|ext| ext.to_string_lossy().to_lowercase());
+        match extension.as_deref() {
+            Some("jpg" | "jpeg") => ImageFormat::Jpeg,
+            _ => ImageFormat::Png,
+        }
+    }
+
+    /// The file a frame, numbered from 1, is written to.
+    fn output(&self, format: ImageFormat, frame: usize, numbered: bool) -> PathBuf {
+        let extension: &str = match format {
+            ImageFormat::Png => "png",
+            ImageFormat::Jpeg => "jpg",
+        };
+        let path: PathBuf = self
+            .args
+            .output
+            .clone()
+            .unwrap_or_else(|| self.args.file.with_extension(extension));
+        if !numbered {
+            return path;
+        }
+        let mut name: OsString = path.file_stem().unwrap_or_default().to_os_string();
+        name.push(format!("_{frame:04}"));
+        if let Some(ext) = path.extension() {
+            name.push(".");
+            name.push(ext);
+        }
+        path.with_file_name(name)
+    }
+
+    fn render(
+        &self,
+        frame: &PixelFrame,
+        index: usize,
+        display: &Display,
+        overlays: &[Overlay],
+    ) -> RgbImage {
+        let pixels: Vec<[u8; 3]> = match display {
+            Display::Suggested => frame.to_rgb8(&frame.suggested_window()),
+            Display::Auto => frame.to_rgb8(&frame.auto_window()),
+            Display::Window(window) => frame.to_rgb8(window),
+            Display::Lut(lut) => frame.to_rgb8_with_lut(lut),
+        };
+        let mut image: RgbImage = RgbImage::new(frame.columns(), frame.rows(), pixels);
+        if self.args.invert {
+            image.invert();
+        }
+        for overlay in overlays {
+            overlay.burn_in(index, &mut image.pixels, image.width, OVERLAY_COLOR);
+        }
+        image.scale(self.args.scale)
+    }
+}
+
+impl CommandApplication for ImageApp {
+    fn run(&mut self) -> Result<()> {
+        if !(self.args.scale.is_finite() && self.args.scale > 0.0) {
+            return Err(anyhow!("Invalid scale: {}", self.args.scale));
+        }
+        let path: &Path = &self.args.file;
+        let mut parser: Parser<'_, _> = parse_file(path, false)?;
+        let dcmroot: DicomRoot<'_> = DicomRoot::parse(&mut parser)?
+            .ok_or_else(|| anyhow!("file is not dicom: {}", path.display()))?;
+        let decoder: FrameDecoder<'_> = FrameDecoder::new(&dcmroot)
+            .with_context(|| format!("Failed to decode Pixel Data: {}", path.display()))?;
+
+        let frames: Vec<usize> = parse_frames(&self.args.frames, decoder.frame_count())?;
+        let display: Display = self.display(&dcmroot)?;
+        let overlays: Vec<Overlay> = if self.args.overlays {
+            Overlay::read_all(&dcmroot).context("Failed to read the overlays")?
+        } else {
+            Vec::new()
+        };
+        let format: ImageFormat = self.format();
+
+        for &index in &frames {
+            let frame: PixelFrame = decoder
+                .decode(index)
+                .with_context(|| format!("Failed to decode frame {}", index + 1))?;
+            let image: RgbImage = self.render(&frame, index, &display, &overlays);
+            let encoded: Vec<u8> = match format {
+                ImageFormat::Png => image.encode_png()?,
+                ImageFormat::Jpeg => image.encode_jpeg(self.args.quality)?,
+            };
+            let output: PathBuf = self.output(format, index + 1, frames.len() > 1);
+            fs::write(&output, &encoded)
+                .with_context(|| format!("Failed to write: {}", output.display()))?;
+            println!(
+                "Wrote frame {} as {}x{} to {}",
+                index + 1,
+                image.width,
+                image.height,
+                output.display()
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Parses a list of frames and ranges numbered from 1, e.g. `1,3-5`, or `all`, into zero-based
+/// frame indices.
+fn parse_frames(spec: &str, count: usize) -> Result<Vec<usize>> {
+    if spec.trim().eq_ignore_ascii_case("all") {
+        return Ok((0..count).collect());
+    }
+    let parse = |number: &str| -> Result<usize> {
+        let number: usize = number
+            .trim()
+            .parse()
+            .with_context(|| format!("Invalid frame: {number}"))?;
+        if number == 0 || number > count {
+            return Err(anyhow!("Frame {number} is not within 1 to {count}"));
+        }
+        Ok(number - 1)
+    };
+    let mut frames: Vec<usize> = Vec::new();
+    for part in spec.split(',') {
+        match part.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (parse(first)?, parse(last)?);
+                if first > last {
+                    return Err(anyhow!("Invalid range of frames: {part}"));
+                }
+                frames.extend(first..=last);
+            }
+            None => frames.push(parse(part)?),
+        }
+    }
+    frames.dedup();
+    Ok(frames)
+}
@@ -1,38 +1,101 @@
 use std::{
-    collections::HashMap,
-    fs::File,
+    collections::{BTreeMap, HashMap},
+    io::ErrorKind,
+    net::{TcpListener, TcpStream},
     path::{Path, PathBuf},
+    thread,
 };
 
-use bson::{doc, oid::ObjectId, spec::BinarySubtype, Array, Binary, Bson, Document};
-use mongodb::sync::{Client, Collection, Cursor, Database};
-use walkdir::WalkDir;
+use bson::{doc, oid::ObjectId, spec::BinarySubtype, Array, Binary, Bson, Document, Regex};
+use mongodb::{
+    error::BulkWriteFailure,
+    options::{InsertManyOptions, ReplaceOptions, UpdateOptions},
+    sync::{Client, Collection, Cursor, Database},
+};
 
 use anyhow::{anyhow, Context, Result};
 use dcmpipe_lib::{
     core::{
+        charset::UTF8_CHARACTER_SET,
         dcmelement::DicomElement,
         dcmobject::{DicomObject, DicomRoot},
-        defn::tag::Tag,
-        read::{stop::ParseStop, Parser, ParserBuilder},
+        defn::{
+            dcmdict::DicomDictionary,
+            tag::{Tag, TagRef},
+            ts::TSRef,
+            vl::ValueLength,
+            vr::{self, VRRef},
+        },
+        matching::{range_end, Matcher, Matching},
+        pixeldata::{FrameDecoder, PixelFrame},
         RawValue,
     },
-    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags},
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags, uids},
+    dimse::{
+        assoc::{Association, AssociationBuilder},
+        commands::{encode_dataset, CommandBuilder, Message},
+        constants::{status, CommandField},
+        query::{QueryIdentifier, QueryLevel, QueryModel},
+    },
 };
 
 use crate::{
-    app::CommandApplication,
-    args::{IndexArgs, IndexCommand},
+    app::{
+        parse_file,
+        pathref::{decode_pathref, encode_pathref},
+        progress::ProgressBar,
+        render::RgbImage,
+        CommandApplication, ACCEPT_POLL_INTERVAL, ASSOCIATION_TIMEOUT, DEFAULT_AE_TITLE,
+        MAX_ERROR_COMMENT_LEN,
+    },
+    args::{ImageFormat, IndexArgs, IndexCommand},
+    config::Config,
+    shutdown,
 };
 
+mod attributes;
+mod batch;
+mod drift;
+mod http;
+#[cfg(feature = "index-postgres")]
+mod pg;
+mod scan;
+mod schema;
+
+pub(crate) use self::attributes::AttributeFilter;
+use self::batch::Batching;
+use self::drift::{IndexedFile, COMPARED_KEYS};
+
 static SERIES_UID_KEY: &str = "0020000E";
 static SOP_UID_KEY: &str = "00080018";
+static STUDY_UID_KEY: &str = "0020000D";
+static PATIENT_ID_KEY: &str = "00100020";
+static PATIENT_NAME_KEY: &str = "00100010";
+static STUDY_DATE_KEY: &str = "00080020";
+static SERIES_DATE_KEY: &str = "00080021";
+static ACCESSION_KEY: &str = "00080050";
+static MODALITY_KEY: &str = "00080060";
+static MODALITIES_IN_STUDY_KEY: &str = "00080061";
+static NUM_PATIENT_STUDIES_KEY: &str = "00201200";
+static NUM_PATIENT_SERIES_KEY: &str = "00201202";
+static NUM_PATIENT_INSTANCES_KEY: &str = "00201204";
+static NUM_STUDY_SERIES_KEY: &str = "00201206";
+static NUM_STUDY_INSTANCES_KEY: &str = "00201208";
 
 static DATABASE_NAME: &str = "dicom_database";
 static COLLECTION_NAME: &str = "series";
+static STUDY_COLLECTION_NAME: &str = "studies";
+static PATIENT_COLLECTION_NAME: &str = "patients";
+static THUMBNAIL_COLLECTION_NAME: &str = "thumbnails";
+
+/// The quality of thumbnails encoded as JPEG.
+static THUMBNAIL_JPEG_QUALITY: u8 = 85;
 
 static MONGO_ID_KEY: &str = "_id";
 
+/// The code of MongoDB's errors for writes of a duplicate key.
+const DUPLICATE_KEY_CODE: i32 = 11000;
+
 /// Tracks a dicom document scanned from disk or from the database. I was originally going to make
 /// this an enum with variants `FromDisk` and `FromDb` and then try to merge so that the same
 /// record is updated from disk contents rather than creating new records, however it was easier
@@ -42,6 +105,21 @@ struct DicomDoc {
     key: String,
     doc: Document,
     id: Option<ObjectId>,
+    /// The instances scanned, for rendering thumbnails and for backends which index instances
+    /// individually. Not stored in the series record.
+    instances: Vec<ScannedInstance>,
+}
+
+/// An instance of a series found by `index scan`.
+struct ScannedInstance {
+    path: PathBuf,
+    instance_number: i64,
+    #[cfg_attr(not(feature = "index-postgres"), allow(dead_code))]
+    sop_uid: Option<String>,
+    /// The SHA-256 checksum of the file, with `index scan --checksums`.
+    sha256: Option<String>,
+    /// Whether the instance is within a zip or tar archive, so it can't be opened by its path.
+    archived: bool,
 }
 
 impl DicomDoc {
@@ -50,24 +128,194 @@ impl DicomDoc {
             key,
             doc: Document::new(),
             id: None,
+            instances: Vec::new(),
         }
     }
+
+    /// The middle of the scanned instances on-disk when ordered by InstanceNumber.
+    fn middle_instance(&self) -> Option<&Path> {
+        let mut instances: Vec<(i64, &Path)> = self
+            .instances
+            .iter()
+            .filter(|instance| !instance.archived)
+            .map(|instance| (instance.instance_number, instance.path.as_path()))
+            .collect();
+        instances.sort();
+        instances
+            .get(instances.len() / 2)
+            .map(|(_number, path)| *path)
+    }
+}
+
+/// The database the index is stored in, chosen by the scheme of the db URI.
+enum Backend {
+    Mongo,
+    #[cfg(feature = "index-postgres")]
+    Postgres(Box<pg::PgIndex>),
+}
+
+/// Stores the thumbnail of a series, rendered from the instance at the path.
+type ThumbnailStore<'s> = Box<dyn FnMut(&str, &Path, Thumbnail) -> Result<()> + 's>;
+
+/// A thumbnail of a series, rendered by `index scan --thumbnails`.
+pub(crate) struct Thumbnail {
+    pub format: ImageFormat,
+    pub width: usize,
+    pub height: usize,
+    /// The encoded image.
+    pub data: Vec<u8>,
 }
 
 pub struct IndexApp {
     args: IndexArgs,
+    config: Config,
 }
 
 impl CommandApplication for IndexApp {
     fn run(&mut self) -> Result<()> {
+        shutdown::install_handler()?;
+
+        // The query servers are only implemented over MongoDB, which is checked before connecting
+        // so that a PostgreSQL index isn't migrated by a command which can't use it.
+        #[cfg(feature = "index-postgres")]
+        if matches!(
+            self.args.cmd,
+            IndexCommand::Serve { .. } | IndexCommand::Http { .. }
+        ) && pg::is_postgres_uri(self.db_uri()?)
+        {
+            return Err(anyhow!(
+                "index serve and index http require a MongoDB index, not a PostgreSQL one"
+            ));
+        }
+
+        let mut backend: Backend = self.backend()?;
         match &self.args.cmd {
-            IndexCommand::Scan { folder } => {
+            IndexCommand::Scan {
+                folder,
+                no_progress,
+                thumbnails,
+                thumbnail_format,
+                thumbnail_size,
+                include,
+                exclude,
+                no_private,
+                max_value_len,
+                concurrency,
+                batch_size,
+                retries,
+                checksums,
+            } => {
                 let folder = folder.clone();
-                let uid_to_doc: HashMap<String, DicomDoc> = self.scan_dir(folder)?;
-                self.upsert_records(uid_to_doc)?;
+                let filter: AttributeFilter =
+                    self.attribute_filter(include, exclude, *no_private, *max_value_len)?;
+                let batching: Batching = Batching {
+                    size: usize::try_from(*batch_size)?,
+                    retries: *retries,
+                };
+                let (thumbnails, format, size) = (*thumbnails, *thumbnail_format, *thumbnail_size);
+                if size == 0 {
+                    return Err(anyhow!("Invalid thumbnail size: {size}"));
+                }
+                let progress: ProgressBar = ProgressBar::new(*no_progress);
+                // If interrupted the records scanned so far are still flushed to the database.
+                let uid_to_doc: HashMap<String, DicomDoc> = scan::scan_dir(
+                    folder,
+                    progress,
+                    &filter,
+                    usize::from(*concurrency),
+                    *checksums,
+                )?;
+                let sources: Vec<(String, PathBuf)> = if thumbnails {
+                    uid_to_doc
+                        .values()
+                        .filter_map(|dicom_doc| {
+                            dicom_doc
+                                .middle_instance()
+                                .map(|path| (dicom_doc.key.clone(), path.to_path_buf()))
+                        })
+                        .collect()
+                } else {
+                    Vec::new()
+                };
+                match &mut backend {
+                    Backend::Mongo => {
+                        self.upsert_records(uid_to_doc, &batching)?;
+                        self.aggregate_records()?;
+                    }
+                    #[cfg(feature = "index-postgres")]
+                    Backend::Postgres(index) => {
+                        index.upsert_records(uid_to_doc, &batching)?;
+                        index.aggregate_records()?;
+                    }
+                }
+                if thumbnails {
+                    self.store_thumbnails(&mut backend, sources, format, size)?;
+                }
+            }
+            IndexCommand::Verify { sample } => {
+                let sample: usize = *sample;
+                match &mut backend {
+                    Backend::Mongo => {
+                        self.verify_records()?;
+                        self.aggregate_records()?;
+                        if sample > 0 {
+                            drift::check_sample(self.indexed_files()?, sample)?;
+                        }
+                    }
+                    #[cfg(feature = "index-postgres")]
+                    Backend::Postgres(index) => {
+                        index.verify_records()?;
+                        index.aggregate_records()?;
+                        if sample > 0 {
+                            drift::check_sample(index.indexed_files()?, sample)?;
+                        }
+                    }
+                }
+            }
+            IndexCommand::Aggregate => match &mut backend {
+                Backend::Mongo => self.aggregate_records()?,
+                #[cfg(feature = "index-postgres")]
+                Backend::Postgres(index) => index.aggregate_records()?,
+            },
+            IndexCommand::Serve {
+                host,
+                port,
+                aetitle,
+            } => {
+                let addr: String = format!("{host}:{port}");
+                let aetitle: String = aetitle
+                    .as_ref()
+                    .or(self.config.aetitle.as_ref())
+                    .map_or(DEFAULT_AE_TITLE, |aetitle| aetitle)
+                    .to_owned();
+                self.serve(&addr, &aetitle)?;
             }
-            IndexCommand::Verify {} => {
-                self.verify_records()?;
+            IndexCommand::Http { host, port } => {
+                let addr: String = format!("{host}:{port}");
+                http::serve(&self.get_database()?, &addr)?;
+            }
+            IndexCommand::Thumbnail { series_uid, output } => {
+                let thumbnail: Option<Thumbnail> = match &mut backend {
+                    Backend::Mongo => read_thumbnail(&self.get_database()?, series_uid)?,
+                    #[cfg(feature = "index-postgres")]
+                    Backend::Postgres(index) => index.read_thumbnail(series_uid)?,
+                };
+                let thumbnail: Thumbnail =
+                    thumbnail.ok_or_else(|| anyhow!("No thumbnail for series: {series_uid}"))?;
+                let output: PathBuf = output.clone().unwrap_or_else(|| {
+                    PathBuf::from(series_uid).with_extension(match thumbnail.format {
+                        ImageFormat::Png => "png",
+                        ImageFormat::Jpeg => "jpg",
+                    })
+                });
+                std::fs::write(&output, &thumbnail.data)
+                    .with_context(|| format!("Failed to write: {}", output.display()))?;
+                println!(
+                    "Wrote {}x{} thumbnail to {}",
+                    thumbnail.width,
+                    thumbnail.height,
+                    output.display()
+                );
             }
         }
         Ok(())
@@ -75,131 +323,131 @@ impl CommandApplication for IndexApp {
 }
 
 impl IndexApp {
-    pub fn new(args: IndexArgs) -> IndexApp {
-        IndexApp { args }
+    pub fn new(args: IndexArgs, config: Config) -> IndexApp {
+        IndexApp { args, config }
     }
 
-    fn get_dicom_coll(&self) -> Result<Collection<Document>> {
-        let client: Client = Client::with_uri_str(&self.args.db)
-            .with_context(|| format!("Invalid database URI: {}", &self.args.db))?;
-        let database: Database = client.database(DATABASE_NAME);
-        Ok(database.collection(COLLECTION_NAME))
+    /// The db URI from the command-line, falling back to the configuration.
+    fn db_uri(&self) -> Result<&str> {
+        self.args
+            .db
+            .as_deref()
+            .or(self.config.index.db.as_deref())
+            .ok_or_else(|| anyhow!("No database URI, use --db or set index.db in dcmpipe.toml"))
     }
 
-    /// Scans a directory and returns the map of all scanned documents
-    fn scan_dir(&mut self, folder: PathBuf) -> Result<HashMap<String, DicomDoc>> {
-        let mut uid_to_doc: HashMap<String, DicomDoc> = HashMap::new();
-
-        let walkdir = WalkDir::new(folder).into_iter().filter_map(|e| e.ok());
+    /// Connects to the backend of the db URI, migrating the index to the current schema.
+    fn backend(&self) -> Result<Backend> {
+        #[cfg(feature = "index-postgres")]
+        if pg::is_postgres_uri(self.db_uri()?) {
+            return Ok(Backend::Postgres(Box::new(pg::PgIndex::connect(
+                self.db_uri()?,
+            )?)));
+        }
+        schema::migrate_mongo(&self.get_database()?)?;
+        Ok(Backend::Mongo)
+    }
 
-        let parser_builder: ParserBuilder<'_> = ParserBuilder::default()
-            .stop(ParseStop::BeforeTagValue(tags::PixelData.tag.into()))
-            .dictionary(&STANDARD_DICOM_DICTIONARY);
-        for entry in walkdir {
-            if !entry.metadata()?.file_type().is_file() {
-                continue;
+    /// The attributes to index, from the command-line falling back to the configuration.
+    fn attribute_filter(
+        &self,
+        include: &[String],
+        exclude: &[String],
+        no_private: bool,
+        max_value_len: Option<usize>,
+    ) -> Result<AttributeFilter> {
+        let config = &self.config.index;
+        let or_config = |values: &'_ [String], config: &'_ Option<Vec<String>>| -> Vec<String> {
+            if values.is_empty() {
+                config.clone().unwrap_or_default()
+            } else {
+                values.to_vec()
             }
+        };
+        AttributeFilter::new(
+            &or_config(include, &config.include),
+            &or_config(exclude, &config.exclude),
+            !no_private && config.private.unwrap_or(true),
+            max_value_len.or(config.max_value_len),
+        )
+    }
 
-            let file: File = File::open(entry.path())?;
-            let mut parser: Parser<'_, File> = parser_builder.build(file);
-
-            let dcm_root: Option<DicomRoot<'_>> = DicomRoot::parse(&mut parser)?;
-            if dcm_root.is_none() {
-                continue;
-            }
-            let dcm_root: DicomRoot<'_> = dcm_root.unwrap();
-
-            let uid_obj: &DicomObject = dcm_root
-                .get_child_by_tag(tags::SeriesInstanceUID.tag)
-                .or_else(|| dcm_root.get_child_by_tag(tags::SOPInstanceUID.tag))
-                .ok_or_else(|| {
-                    anyhow!(
-                        "DICOM file has no SeriesInstanceUID or SOPInstanceUID: {:?}",
-                        entry.path().display()
-                    )
-                })?;
-            let uid_key: String = uid_obj.element().try_into()?;
-            let entry_key: String = uid_key.clone();
-            let dicom_doc: &mut DicomDoc = uid_to_doc
-                .entry(entry_key)
-                .or_insert_with(|| DicomDoc::new(uid_key.clone()));
-
-            let metadata_doc: &mut Document = dicom_doc
-                .doc
-                .entry("metadata".to_owned())
-                .or_insert_with(|| Document::new().into())
-                .as_document_mut()
-                .ok_or_else(|| anyhow!("Field failure: metadata"))?;
-            let files_field: &mut Array = metadata_doc
-                .entry("files".to_owned())
-                .or_insert_with(|| Vec::<String>::new().into())
-                .as_array_mut()
-                .ok_or_else(|| anyhow!("Field failure: metadata.files"))?;
-            files_field.push(format!("{}", entry.path().display()).into());
-            metadata_doc.insert("serieskey", uid_key);
-
-            for (_child_tag, child_obj) in dcm_root.iter_child_nodes() {
-                let child_elem: &DicomElement = child_obj.element();
-                if child_elem.is_seq_like() {
-                    // TODO: handle sequences
-                } else {
-                    insert_elem_entry(child_elem, &mut dicom_doc.doc)?;
-                }
-            }
-        }
+    fn get_database(&self) -> Result<Database> {
+        connect_database(self.db_uri()?)
+    }
 
-        Ok(uid_to_doc)
+    fn get_dicom_coll(&self) -> Result<Collection<Document>> {
+        Ok(self.get_database()?.collection(COLLECTION_NAME))
     }
 
-    /// Queries mongo for existing documents and updates `self.uid_to_doc` with a related id field
-    /// if appropriate, or marks the document as missing on-disk and then deletes it.
-    /// Performs all updates to mongo based on the scan results.
-    fn upsert_records(&mut self, mut uid_to_doc: HashMap<String, DicomDoc>) -> Result<()> {
+    /// Queries mongo for the existing records of the scanned series, then inserts the records of
+    /// new series and replaces those of existing series, in batches which are retried after
+    /// transient errors.
+    fn upsert_records(
+        &mut self,
+        mut uid_to_doc: HashMap<String, DicomDoc>,
+        batching: &Batching,
+    ) -> Result<()> {
         let dicom_coll: Collection<Document> = self.get_dicom_coll()?;
 
-        let mut serieskeys: Vec<Bson> = Vec::new();
-        for key in uid_to_doc.keys() {
-            serieskeys.push(Bson::String(key.clone()));
-        }
-        let query: Document = doc! {
-            "metadata.serieskey" : {
-                "$in" : serieskeys
-            }
-        };
-
-        for dicom_doc in self.query_docs(&dicom_coll, Some(query))? {
-            if let Some(existing) = uid_to_doc.get_mut(&dicom_doc.key) {
-                existing.id = dicom_doc.id;
+        let mut keys: Vec<String> = uid_to_doc.keys().cloned().collect();
+        keys.sort();
+        let (mut inserted, mut updated): (usize, usize) = (0, 0);
+        for batch in keys.chunks(batching.size) {
+            let serieskeys: Vec<Bson> = batch.iter().cloned().map(Bson::String).collect();
+            let query: Document = doc! {
+                "metadata.serieskey" : {
+                    "$in" : serieskeys
+                }
+            };
+            let existing: Vec<DicomDoc> = batching
+                .retry(|| Ok(self.query_docs(&dicom_coll, Some(query.clone()))?.collect()))?;
+            for dicom_doc in existing {
+                if let Some(scanned) = uid_to_doc.get_mut(&dicom_doc.key) {
+                    scanned.id = dicom_doc.id;
+                }
             }
-        }
 
-        let mut inserts: Vec<Document> = Vec::new();
-        let mut updates: Vec<(ObjectId, Document)> = Vec::new();
-        let keys: Vec<String> = uid_to_doc.keys().cloned().collect();
-        for key in keys {
-            if let Some(mut dicom_doc) = uid_to_doc.remove(&key) {
-                match dicom_doc.id {
-                    None => inserts.push(dicom_doc.doc),
-                    Some(id) => {
-                        dicom_doc.doc.insert(MONGO_ID_KEY, id);
-                        updates.push((id, dicom_doc.doc));
+            // New records are given their id up front so that retrying an insert doesn't
+            // duplicate those inserted by the failed attempt.
+            let mut inserts: Vec<Document> = Vec::new();
+            let mut updates: Vec<(ObjectId, Document)> = Vec::new();
+            for key in batch {
+                if let Some(mut dicom_doc) = uid_to_doc.remove(key) {
+                    let id: ObjectId = dicom_doc.id.unwrap_or_else(ObjectId::new);
+                    dicom_doc.doc.insert(MONGO_ID_KEY, id);
+                    match dicom_doc.id {
+                        None => inserts.push(dicom_doc.doc),
+                        Some(id) => updates.push((id, dicom_doc.doc)),
                     }
                 }
             }
-        }
 
-        println!("Inserting {} records", inserts.len());
-        if !inserts.is_empty() {
-            dicom_coll.insert_many(inserts, None)?;
-        }
+            if !inserts.is_empty() {
+                batching.retry(|| insert_new(&dicom_coll, &inserts))?;
+            }
+            // There's no API for mass replacing documents, so do one-by-one.
+            batching.retry(|| {
+                for (id, doc) in &updates {
+                    let query: Document = doc! { MONGO_ID_KEY: id };
+                    dicom_coll.replace_one(query, doc, None)?;
+                }
+                Ok(())
+            })?;
+            inserted += inserts.len();
+            updated += updates.len();
 
-        // There's no API for mass replacing documents, so do one-by-one.
-        println!("Updating {} records", updates.len());
-        for (id, doc) in updates.into_iter() {
-            let query: Document = doc! { MONGO_ID_KEY: id };
-            dicom_coll.update_one(query, doc, None)?;
+            if shutdown::is_requested() {
+                println!(
+                    "Upsert interrupted, {} series not written",
+                    uid_to_doc.len()
+                );
+                break;
+            }
         }
 
+        println!("Inserted {inserted} records");
+        println!("Updated {updated} records");
         Ok(())
     }
 
@@ -210,6 +458,11 @@ impl IndexApp {
         let mut updated_records: Vec<Document> = Vec::new();
         let mut missing_records: Vec<Document> = Vec::new();
         for mut dicom_doc in self.query_docs(&dicom_coll, None)? {
+            if shutdown::is_requested() {
+                println!("Verify interrupted, applying changes for verified records");
+                break;
+            }
+
             record_count += 1;
             let md_doc_opt = dicom_doc
                 .doc
@@ -237,7 +490,7 @@ impl IndexApp {
             let num_files: usize = files_array.len();
             files_array.retain(|bson| match bson.as_str() {
                 None => false,
-                Some(path) => Path::new(path).is_file(),
+                Some(pathref) => decode_pathref(pathref).is_file(),
             });
 
             match files_array.len() {
@@ -250,8 +503,9 @@ impl IndexApp {
         println!("Verified {} records", record_count);
 
         println!("Updating {} records", updated_records.len());
-        if !updated_records.is_empty() {
-            dicom_coll.insert_many(updated_records, None)?;
+        for doc in &updated_records {
+            let id: ObjectId = doc.get_object_id(MONGO_ID_KEY)?;
+            dicom_coll.replace_one(doc! { MONGO_ID_KEY: id }, doc, None)?;
         }
 
         println!("Removing {} records", missing_records.len());
@@ -268,11 +522,296 @@ impl IndexApp {
                 }
             };
             dicom_coll.delete_many(query, None)?;
+
+            let serieskeys: Vec<Bson> = missing_records
+                .iter()
+                .filter_map(|doc| doc.get_document("metadata").ok())
+                .filter_map(|metadata| metadata.get_str("serieskey").ok())
+                .map(Bson::from)
+                .collect::<Vec<Bson>>();
+            let query = doc! {
+                MONGO_ID_KEY : {
+                    "$in": serieskeys
+                }
+            };
+            self.get_database()?
+                .collection::<Document>(THUMBNAIL_COLLECTION_NAME)
+                .delete_many(query, None)?;
         }
 
         Ok(())
     }
 
+    /// The files of the series records, along with their indexed values, for `verify --sample`.
+    fn indexed_files(&self) -> Result<Vec<IndexedFile>> {
+        let dicom_coll: Collection<Document> = self.get_dicom_coll()?;
+        let mut files: Vec<IndexedFile> = Vec::new();
+        for doc in dicom_coll.find(None, None)? {
+            let doc: Document = doc?;
+            let Ok(metadata) = doc.get_document("metadata") else {
+                continue;
+            };
+            let attributes: BTreeMap<String, serde_json::Value> = COMPARED_KEYS
+                .iter()
+                .filter_map(|key| {
+                    let value: &Bson = doc.get(key)?;
+                    Some((key.to_string(), value.clone().into_relaxed_extjson()))
+                })
+                .collect();
+            let checksums: HashMap<&str, &str> = metadata
+                .get_array("checksums")
+                .into_iter()
+                .flatten()
+                .filter_map(Bson::as_document)
+                .filter_map(|checksum| {
+                    Some((
+                        checksum.get_str("file").ok()?,
+                        checksum.get_str("sha256").ok()?,
+                    ))
+                })
+                .collect();
+            let pathrefs = metadata
+                .get_array("files")
+                .into_iter()
+                .flatten()
+                .filter_map(Bson::as_str);
+            for pathref in pathrefs {
+                files.push(IndexedFile {
+                    pathref: pathref.to_owned(),
+                    attributes: attributes.clone(),
+                    sha256: checksums.get(pathref).map(|sha256| sha256.to_string()),
+                });
+            }
+        }
+        Ok(files)
+    }
+
+    /// Renders a thumbnail of each series from the given instance, replacing any previous
+    /// thumbnail of the series. Instances which can't be rendered, e.g. those without Pixel Data,
+    /// are skipped.
+    fn store_thumbnails(
+        &self,
+        backend: &mut Backend,
+        sources: Vec<(String, PathBuf)>,
+        format: ImageFormat,
+        size: usize,
+    ) -> Result<()> {
+        let mut store: ThumbnailStore<'_> = match backend {
+            Backend::Mongo => {
+                let thumbnail_coll: Collection<Document> =
+                    self.get_database()?.collection(THUMBNAIL_COLLECTION_NAME);
+                let options: ReplaceOptions = ReplaceOptions::builder().upsert(true).build();
+                Box::new(move |serieskey, path, thumbnail| {
+                    let doc: Document = doc! {
+                        MONGO_ID_KEY: serieskey,
+                        "format": thumbnail_format_name(thumbnail.format),
+                        "width": thumbnail.width as i64,
+                        "height": thumbnail.height as i64,
+                        "file": encode_pathref(path),
+                        "data": Binary {
+                            subtype: BinarySubtype::Generic,
+                            bytes: thumbnail.data,
+                        },
+                    };
+                    let query: Document = doc! { MONGO_ID_KEY: serieskey };
+                    thumbnail_coll.replace_one(query, doc, options.clone())?;
+                    Ok(())
+                })
+            }
+            #[cfg(feature = "index-postgres")]
+            Backend::Postgres(index) => Box::new(|serieskey, path, thumbnail| {
+                index.store_thumbnail(serieskey, path, &thumbnail)
+            }),
+        };
+        let mut stored: usize = 0;
+        for (serieskey, path) in sources {
+            if shutdown::is_requested() {
+                println!("Thumbnails interrupted, {stored} stored");
+                return Ok(());
+            }
+            let thumbnail: Thumbnail = match render_thumbnail(&path, format, size) {
+                Ok(thumbnail) => thumbnail,
+                Err(e) => {
+                    tracing::debug!(
+                        path = %path.display(),
+                        error = format!("{e:#}"),
+                        "skipped thumbnail"
+                    );
+                    continue;
+                }
+            };
+            store(&serieskey, &path, thumbnail)?;
+            stored += 1;
+        }
+        println!("Stored {stored} thumbnails");
+        Ok(())
+    }
+
+    /// Rebuilds the study and patient level collections from the series records, so that
+    /// hierarchical queries can be answered without scanning all series. The roll-up documents
+    /// use the DICOM attributes defined for Query/Retrieve where possible, such as
+    /// ModalitiesInStudy and NumberOfStudyRelatedInstances, keyed the same as series records.
+    fn aggregate_records(&mut self) -> Result<()> {
+        let database: Database = self.get_database()?;
+        let dicom_coll: Collection<Document> = database.collection(COLLECTION_NAME);
+        let study_coll: Collection<Document> = database.collection(STUDY_COLLECTION_NAME);
+
+        // Each series record has the list of files which are the series instances. Series
+        // attributes which differ per-series are rolled up into ranges or sets.
+        let study_pipeline: Vec<Document> = vec![
+            doc! { "$match": { STUDY_UID_KEY: { "$exists": true } } },
+            doc! { "$group": {
+                MONGO_ID_KEY: format!("${STUDY_UID_KEY}"),
+                PATIENT_ID_KEY: { "$first": format!("${PATIENT_ID_KEY}") },
+                PATIENT_NAME_KEY: { "$first": format!("${PATIENT_NAME_KEY}") },
+                ACCESSION_KEY: { "$first": format!("${ACCESSION_KEY}") },
+                STUDY_DATE_KEY: { "$min": format!("${STUDY_DATE_KEY}") },
+                MODALITIES_IN_STUDY_KEY: { "$addToSet": format!("${MODALITY_KEY}") },
+                NUM_STUDY_SERIES_KEY: { "$sum": 1 },
+                NUM_STUDY_INSTANCES_KEY: {
+                    "$sum": { "$size": { "$ifNull": [ "$metadata.files", [] ] } }
+                },
+                "series_date_min": { "$min": format!("${SERIES_DATE_KEY}") },
+                "series_date_max": { "$max": format!("${SERIES_DATE_KEY}") },
+                "series": { "$addToSet": "$metadata.serieskey" },
+            } },
+            doc! { "$set": {
+                STUDY_UID_KEY: format!("${MONGO_ID_KEY}"),
+                "metadata": {
+                    "studykey": format!("${MONGO_ID_KEY}"),
+                    "series": "$series",
+                    "series_date_range": {
+                        "min": "$series_date_min",
+                        "max": "$series_date_max",
+                    },
+                },
+            } },
+            doc! { "$unset": [ "series", "series_date_min", "series_date_max" ] },
+            doc! { "$out": STUDY_COLLECTION_NAME },
+        ];
+        dicom_coll
+            .aggregate(study_pipeline, None)
+            .with_context(|| format!("Failed to aggregate {STUDY_COLLECTION_NAME}"))?;
+
+        let patient_pipeline: Vec<Document> = vec![
+            doc! { "$match": { PATIENT_ID_KEY: { "$exists": true } } },
+            doc! { "$group": {
+                MONGO_ID_KEY: format!("${PATIENT_ID_KEY}"),
+                PATIENT_NAME_KEY: { "$first": format!("${PATIENT_NAME_KEY}") },
+                NUM_PATIENT_STUDIES_KEY: { "$sum": 1 },
+                NUM_PATIENT_SERIES_KEY: { "$sum": format!("${NUM_STUDY_SERIES_KEY}") },
+                NUM_PATIENT_INSTANCES_KEY: { "$sum": format!("${NUM_STUDY_INSTANCES_KEY}") },
+                MODALITIES_IN_STUDY_KEY: { "$push": format!("${MODALITIES_IN_STUDY_KEY}") },
+                "study_date_min": { "$min": format!("${STUDY_DATE_KEY}") },
+                "study_date_max": { "$max": format!("${STUDY_DATE_KEY}") },
+                "studies": { "$addToSet": format!("${MONGO_ID_KEY}") },
+            } },
+            doc! { "$set": {
+                PATIENT_ID_KEY: format!("${MONGO_ID_KEY}"),
+                // Flatten the per-study modalities into a single set.
+                MODALITIES_IN_STUDY_KEY: {
+                    "$reduce": {
+                        "input": format!("${MODALITIES_IN_STUDY_KEY}"),
+                        "initialValue": [],
+                        "in": { "$setUnion": [ "$$value", "$$this" ] },
+                    }
+                },
+                "metadata": {
+                    "patientkey": format!("${MONGO_ID_KEY}"),
+                    "studies": "$studies",
+                    "study_date_range": {
+                        "min": "$study_date_min",
+                        "max": "$study_date_max",
+                    },
+                },
+            } },
+            doc! { "$unset": [ "studies", "study_date_min", "study_date_max" ] },
+            doc! { "$out": PATIENT_COLLECTION_NAME },
+        ];
+        study_coll
+            .aggregate(patient_pipeline, None)
+            .with_context(|| format!("Failed to aggregate {PATIENT_COLLECTION_NAME}"))?;
+
+        println!(
+            "Aggregated {} studies, {} patients",
+            study_coll.count_documents(None, None)?,
+            database
+                .collection::<Document>(PATIENT_COLLECTION_NAME)
+                .count_documents(None, None)?
+        );
+
+        Ok(())
+    }
+
+    /// Listens for associations and answers C-FIND queries against the series, study, and patient
+    /// collections. Associations are handled one at a time and a shutdown request is honored
+    /// between associations.
+    fn serve(&self, addr: &str, aetitle: &str) -> Result<()> {
+        let database: Database = self.get_database()?;
+        let listener: TcpListener =
+            TcpListener::bind(addr).with_context(|| format!("Failed to listen on {addr}"))?;
+        // Poll for connections so that shutdown requests are noticed while idle.
+        listener.set_nonblocking(true)?;
+
+        let assoc_builder: AssociationBuilder = AssociationBuilder::default()
+            .ae_title(aetitle)
+            .abstract_syntax(uids::VerificationSOPClass.uid)
+            .abstract_syntax(uids::PatientRootQueryRetrieveInformationModelFIND.uid)
+            .abstract_syntax(uids::StudyRootQueryRetrieveInformationModelFIND.uid);
+
+        println!("Listening on {addr} as {aetitle}");
+        while !shutdown::is_requested() {
+            let (stream, peer_addr) = match listener.accept() {
+                Ok(conn) => conn,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                    thread::sleep(ACCEPT_POLL_INTERVAL);
+                    continue;
+                }
+                Err(e) => return Err(e).context("Failed to accept connection"),
+            };
+
+            // A failed association shouldn't bring down the server.
+            if let Err(e) = self.handle_association(&database, &assoc_builder, stream) {
+                eprintln!("Association with {peer_addr} failed: {e:?}");
+            }
+        }
+        println!("Stopped listening on {addr}");
+
+        Ok(())
+    }
+
+    /// Negotiates an association on the given stream and answers requests until it's released.
+    fn handle_association(
+        &self,
+        database: &Database,
+        assoc_builder: &AssociationBuilder,
+        mut stream: TcpStream,
+    ) -> Result<()> {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(ASSOCIATION_TIMEOUT))?;
+
+        let assoc: Association = assoc_builder.accept(&mut stream)?;
+        println!("Association accepted from {}", assoc.peer_ae());
+        while let Some(rq) = assoc.read_message(&mut stream)? {
+            match rq.command_field() {
+                Some(CommandField::CEchoReq) => {
+                    let rsp = CommandBuilder::c_echo_rsp(&rq, status::SUCCESS).build()?;
+                    assoc.write_message(&Message::new(rq.ctx_id(), rsp, None), &mut stream)?;
+                }
+                Some(CommandField::CFindReq) => {
+                    handle_find(database, &assoc, &rq, &mut stream)?;
+                }
+                other => {
+                    assoc.abort(&mut stream)?;
+                    return Err(anyhow!("Unsupported command: {other:?}"));
+                }
+            }
+        }
+        println!("Association released by {}", assoc.peer_ae());
+
+        Ok(())
+    }
+
     /// Query for all dicom records in the given collection and returns an iterator over `DicomDoc`
     fn query_docs(
         &mut self,
@@ -281,7 +820,7 @@ impl IndexApp {
     ) -> Result<impl Iterator<Item = DicomDoc>> {
         let all_dicom_docs: Cursor<Document> = dicom_coll
             .find(query, None)
-            .with_context(|| format!("Invalid database: {}", &self.args.db))?;
+            .with_context(|| format!("Invalid database: {}", self.db_uri().unwrap_or_default()))?;
 
         let doc_iter = all_dicom_docs.filter_map(|doc_res| {
             let doc: Document = match doc_res {
@@ -307,6 +846,7 @@ impl IndexApp {
                 key: doc_key,
                 doc,
                 id: Some(doc_id),
+                instances: Vec::new(),
             })
         });
 
@@ -314,10 +854,495 @@ impl IndexApp {
     }
 }
 
+/// Renders the first frame of the dataset at the given path with its suggested window, scaled down
+/// so that neither its width nor height exceed `size`.
+fn render_thumbnail(path: &Path, format: ImageFormat, size: usize) -> Result<Thumbnail> {
+    let mut parser = parse_file(path, false)?;
+    let dcmroot: DicomRoot<'_> = DicomRoot::parse(&mut parser)?
+        .ok_or_else(|| anyhow!("file is not dicom: {}", path.display()))?;
+    let frame: PixelFrame = FrameDecoder::new(&dcmroot)?.decode(0)?;
+    let image: RgbImage = RgbImage::new(
+        frame.columns(),
+        frame.rows(),
+        frame.to_rgb8(&frame.suggested_window()),
+    );
+    let largest: usize = image.width.max(image.height).max(1);
+    let image: RgbImage = image.scale((size as f64 / largest as f64).min(1.0));
+    let data: Vec<u8> = match format {
+        ImageFormat::Png => image.encode_png()?,
+        ImageFormat::Jpeg => image.encode_jpeg(THUMBNAIL_JPEG_QUALITY)?,
+    };
+    Ok(Thumbnail {
+        format,
+        width: image.width,
+        height: image.height,
+        data,
+    })
+}
+
+/// Reads the thumbnail of the series with the given SeriesInstanceUID, if one was rendered.
+pub(crate) fn read_thumbnail(database: &Database, series_uid: &str) -> Result<Option<Thumbnail>> {
+    let Some(doc) = database
+        .collection::<Document>(THUMBNAIL_COLLECTION_NAME)
+        .find_one(doc! { MONGO_ID_KEY: series_uid }, None)?
+    else {
+        return Ok(None);
+    };
+    Ok(Some(Thumbnail {
+        format: parse_thumbnail_format(doc.get_str("format")?)?,
+        width: usize::try_from(doc.get_i64("width")?)?,
+        height: usize::try_from(doc.get_i64("height")?)?,
+        data: doc.get_binary_generic("data")?.clone(),
+    }))
+}
+
+/// The name a thumbnail's format is stored as.
+fn thumbnail_format_name(format: ImageFormat) -> &'static str {
+    match format {
+        ImageFormat::Png => "png",
+        ImageFormat::Jpeg => "jpeg",
+    }
+}
+
+fn parse_thumbnail_format(name: &str) -> Result<ImageFormat> {
+    match name {
+        "png" => Ok(ImageFormat::Png),
+        "jpeg" => Ok(ImageFormat::Jpeg),
+        other => Err(anyhow!("Unknown thumbnail format: {other}")),
+    }
+}
+
+/// The paths of the instances of the series matching the queries, each `ATTRIBUTE=VALUE` with the
+/// value matched as the key of a C-FIND query, for `browse --query`. Instances within archives, or
+/// whose files no longer exist, are omitted.
+pub(crate) fn query_instances(db_uri: &str, queries: &[String]) -> Result<Vec<PathBuf>> {
+    let mut keys: Vec<(String, Matcher)> = Vec::new();
+    for query in queries {
+        let (attribute, value) = query
+            .split_once('=')
+            .ok_or_else(|| anyhow!("Invalid query, expected ATTRIBUTE=VALUE: {query}"))?;
+        let tag: u32 = Tag::parse_tag(attribute.trim(), Some(&STANDARD_DICOM_DICTIONARY))
+            .with_context(|| format!("Unknown attribute: {attribute}"))?;
+        let vr: VRRef = STANDARD_DICOM_DICTIONARY
+            .get_tag_by_number(tag)
+            .and_then(|tag| tag.implicit_vr)
+            .unwrap_or(&vr::LO);
+        keys.push((
+            Tag::format_tag_to_path_display(tag),
+            Matcher::parse(vr, value),
+        ));
+    }
+
+    #[cfg(feature = "index-postgres")]
+    let pathrefs: Vec<String> = if pg::is_postgres_uri(db_uri) {
+        pg::PgIndex::connect(db_uri)?.query_instances(&keys)?
+    } else {
+        query_mongo_instances(db_uri, &keys)?
+    };
+    #[cfg(not(feature = "index-postgres"))]
+    let pathrefs: Vec<String> = query_mongo_instances(db_uri, &keys)?;
+
+    Ok(pathrefs
+        .iter()
+        .map(|pathref| decode_pathref(pathref))
+        .filter(|path| path.is_file())
+        .collect())
+}
+
+/// The path references of the instances of the series records matching the keys.
+fn query_mongo_instances(db_uri: &str, keys: &[(String, Matcher)]) -> Result<Vec<String>> {
+    let conditions: Array = keys
+        .iter()
+        .filter_map(|(key, matcher)| match_condition(key, matcher, matcher.matching()))
+        .map(Bson::Document)
+        .collect();
+    let filter: Document = if conditions.is_empty() {
+        Document::new()
+    } else {
+        doc! { "$and": conditions }
+    };
+    let mut pathrefs: Vec<String> = Vec::new();
+    for doc in connect_database(db_uri)?
+        .collection::<Document>(COLLECTION_NAME)
+        .find(filter, None)?
+    {
+        let doc: Document = doc?;
+        let files = doc
+            .get_document("metadata")
+            .and_then(|metadata| metadata.get_array("files"))
+            .into_iter()
+            .flatten()
+            .filter_map(Bson::as_str);
+        pathrefs.extend(files.map(str::to_owned));
+    }
+    Ok(pathrefs)
+}
+
+/// Opens the index database at the given URI, migrating it to the current schema.
+pub(crate) fn open_database(db_uri: &str) -> Result<Database> {
+    let database: Database = connect_database(db_uri)?;
+    schema::migrate_mongo(&database)?;
+    Ok(database)
+}
+
+fn connect_database(db_uri: &str) -> Result<Database> {
+    let client: Client =
+        Client::with_uri_str(db_uri).with_context(|| format!("Invalid database URI: {db_uri}"))?;
+    Ok(client.database(DATABASE_NAME))
+}
+
+/// Adds a single dataset to the index, merging it into the record of its series. Unlike `index
+/// scan` the series record is updated in place, so files of the series indexed previously remain
+/// listed.
+pub(crate) fn index_dataset(
+    database: &Database,
+    filter: &AttributeFilter,
+    path: &Path,
+    dcmroot: &DicomRoot<'_>,
+) -> Result<()> {
+    let uid_obj: &DicomObject = dcmroot
+        .get_child_by_tag(tags::SeriesInstanceUID.tag)
+        .or_else(|| dcmroot.get_child_by_tag(tags::SOPInstanceUID.tag))
+        .ok_or_else(|| anyhow!("DICOM file has no SeriesInstanceUID or SOPInstanceUID"))?;
+    let uid_key: String = uid_obj.element().try_into()?;
+
+    let mut fields: Document = Document::new();
+    for (_child_tag, child_obj) in dcmroot.iter_child_nodes() {
+        let child_elem: &DicomElement = child_obj.element();
+        if !child_elem.is_seq_like() && filter.is_indexed(child_elem.tag()) {
+            insert_elem_entry(child_elem, filter, &mut fields)?;
+        }
+    }
+    fields.insert("metadata.serieskey", uid_key.clone());
+
+    let query: Document = doc! { "metadata.serieskey": &uid_key };
+    let update: Document = doc! {
+        "$set": fields,
+        "$addToSet": { "metadata.files": encode_pathref(path) },
+    };
+    let options: UpdateOptions = UpdateOptions::builder().upsert(true).build();
+    database
+        .collection::<Document>(COLLECTION_NAME)
+        .update_one(query, update, options)?;
+    Ok(())
+}
+
+/// Answers a C-FIND request, sending a pending response for each matching record followed by
+/// a final response.
+pub(crate) fn handle_find(
+    database: &Database,
+    assoc: &Association,
+    rq: &Message,
+    stream: &mut TcpStream,
+) -> Result<()> {
+    let ts: TSRef = assoc
+        .get_pres_ctx(rq.ctx_id())
+        .map(|pres_ctx| pres_ctx.ts())
+        .ok_or_else(|| anyhow!("No presentation context: {}", rq.ctx_id()))?;
+
+    let model: QueryModel = rq
+        .affected_sop_class_uid()
+        .and_then(|uid| QueryModel::from_sop_class(&uid))
+        .unwrap_or(QueryModel::PatientRoot);
+    let identifier: Result<QueryIdentifier, (u16, String)> = rq
+        .decode_dataset(ts)
+        .map_err(|e| (status::UNABLE_TO_PROCESS, e.to_string()))
+        .and_then(|identifier| {
+            identifier.ok_or_else(|| {
+                (
+                    status::IDENTIFIER_DOES_NOT_MATCH,
+                    "Missing query identifier".to_owned(),
+                )
+            })
+        })
+        .and_then(|identifier| {
+            QueryIdentifier::validate(model, &identifier).map_err(|errors| {
+                let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+                (status::IDENTIFIER_DOES_NOT_MATCH, errors.join("; "))
+            })
+        });
+    let matches: Result<Vec<Vec<u8>>, (u16, String)> = identifier.and_then(|identifier| {
+        query_index(database, &identifier, ts)
+            .map_err(|e| (status::UNABLE_TO_PROCESS, format!("{e}")))
+    });
+
+    let matches: Vec<Vec<u8>> = match matches {
+        Ok(matches) => matches,
+        Err((status, comment)) => {
+            let comment: String = comment.chars().take(MAX_ERROR_COMMENT_LEN).collect();
+            let rsp = CommandBuilder::c_find_rsp(rq, status)
+                .string(&tags::ErrorComment, &comment)
+                .build()?;
+            assoc.write_message(&Message::new(rq.ctx_id(), rsp, None), stream)?;
+            return Ok(());
+        }
+    };
+
+    for matched in matches {
+        let rsp = CommandBuilder::c_find_rsp(rq, status::PENDING)
+            .dataset(true)
+            .build()?;
+        assoc.write_message(&Message::new(rq.ctx_id(), rsp, Some(matched)), stream)?;
+    }
+    let rsp = CommandBuilder::c_find_rsp(rq, status::SUCCESS).build()?;
+    assoc.write_message(&Message::new(rq.ctx_id(), rsp, None), stream)?;
+
+    Ok(())
+}
+
+/// Queries the collection for the level of the given C-FIND identifier, returning the encoded
+/// response identifier for each match. Keys are matched as described by `core::matching`, other
+/// than sequence matching which is not supported.
+fn query_index(
+    database: &Database,
+    identifier: &QueryIdentifier,
+    ts: TSRef,
+) -> Result<Vec<Vec<u8>>> {
+    let coll_name: &str = match identifier.level() {
+        QueryLevel::Patient => PATIENT_COLLECTION_NAME,
+        QueryLevel::Study => STUDY_COLLECTION_NAME,
+        QueryLevel::Series => COLLECTION_NAME,
+        other => return Err(anyhow!("Unsupported QueryRetrieveLevel: {other}")),
+    };
+
+    let mut conditions: Array = Array::new();
+    for key in identifier.matching_keys() {
+        let matcher: &Matcher = key.matcher();
+        let path: String = Tag::format_tag_to_path_display(key.tag());
+        if let Some(condition) = match_condition(&path, matcher, matcher.matching()) {
+            conditions.push(Bson::Document(condition));
+        }
+    }
+    let return_keys: Vec<(u32, VRRef)> = identifier
+        .keys()
+        .iter()
+        .filter(|key| key.vr() != &vr::SQ)
+        .map(|key| (key.tag(), key.vr()))
+        .collect();
+    let level: &str = identifier.level().as_str();
+
+    let filter: Document = if conditions.is_empty() {
+        Document::new()
+    } else {
+        doc! { "$and": conditions }
+    };
+    let coll: Collection<Document> = database.collection(coll_name);
+    let mut matches: Vec<Vec<u8>> = Vec::new();
+    for doc in coll.find(filter, None)? {
+        matches.push(build_identifier(&doc?, level, &return_keys, ts)?);
+    }
+    Ok(matches)
+}
+
+/// Builds the mongo query condition for the key's matching, or `None` if the key should match
+/// everything. Names are matched case-insensitively with regular expressions.
+fn match_condition(key: &str, matcher: &Matcher, matching: &Matching) -> Option<Document> {
+    let exact = |value: &str| -> Bson {
+        if matcher.is_case_insensitive() {
+            Bson::RegularExpression(Regex {
+                pattern: wildcard_to_regex(value),
+                options: "i".to_owned(),
+            })
+        } else {
+            Bson::String(value.to_owned())
+        }
+    };
+    let condition: Bson = match matching {
+        Matching::Universal => return None,
+        Matching::Single(value) => exact(value),
+        Matching::Wildcard(pattern) => Bson::RegularExpression(Regex {
+            pattern: wildcard_to_regex(pattern),
+            options: if matcher.is_case_insensitive() {
+                "i"
+            } else {
+                ""
+            }
+            .to_owned(),
+        }),
+        Matching::Range(min, max) => {
+            let mut range: Document = Document::new();
+            if let Some(min) = min {
+                range.insert("$gte", min);
+            }
+            if let Some(max) = max {
+                range.insert("$lte", range_end(matcher.vr(), max));
+            }
+            Bson::Document(range)
+        }
+        Matching::List(matchings) => {
+            let any: Array = matchings
+                .iter()
+                .filter_map(|matching| match_condition(key, matcher, matching))
+                .map(Bson::Document)
+                .collect();
+            return Some(doc! { "$or": any });
+        }
+    };
+    Some(doc! { key: condition })
+}
+
+/// Converts a DICOM wildcard match, `*` and `?`, into an anchored regular expression.
+fn wildcard_to_regex(value: &str) -> String {
+    let mut pattern: String = String::with_capacity(value.len() + 2);
+    pattern.push('^');
+    for c in value.chars() {
+        match c {
+            '*' => pattern.push_str(".*"),
+            '?' => pattern.push('.'),
+            '\\' | '.' | '+' | '(' | ')' | '[' | ']' | '{' | '}' | '|' | '^' | '$' => {
+                pattern.push('\\');
+                pattern.push(c);
+            }
+            _ => pattern.push(c),
+        }
+    }
+    pattern.push('$');
+    pattern
+}
+
+/// Encodes the response identifier for a matched record, with a value for each requested key the
+/// record has.
+fn build_identifier(
+    doc: &Document,
+    level: &str,
+    return_keys: &[(u32, VRRef)],
+    ts: TSRef,
+) -> Result<Vec<u8>> {
+    let mut child_nodes: BTreeMap<u32, DicomObject> = BTreeMap::new();
+    let fixed_values: [(TagRef, VRRef, RawValue); 2] = [
+        (
+            &tags::SpecificCharacterSet,
+            &vr::CS,
+            RawValue::Strings(vec!["ISO_IR 192".to_owned()]),
+        ),
+        (
+            &tags::QueryRetrieveLevel,
+            &vr::CS,
+            RawValue::Strings(vec![level.to_owned()]),
+        ),
+    ];
+    for (tag, vr, value) in fixed_values {
+        let mut elem: DicomElement = new_rsp_elem(tag.tag, vr, ts);
+        elem.encode_value(value, None)?;
+        child_nodes.insert(tag.tag, DicomObject::new(elem));
+    }
+
+    for (tag, vr) in return_keys {
+        let mut elem: DicomElement = new_rsp_elem(*tag, vr, ts);
+        let value: Option<RawValue> = doc
+            .get(Tag::format_tag_to_path_display(*tag))
+            .and_then(|bson| bson_to_value(bson, vr));
+        if let Some(value) = value {
+            elem.encode_value(value, None)?;
+        }
+        child_nodes.insert(*tag, DicomObject::new(elem));
+    }
+
+    let dcmroot: DicomRoot<'_> = DicomRoot::new(
+        ts,
+        UTF8_CHARACTER_SET,
+        &STANDARD_DICOM_DICTIONARY,
+        child_nodes,
+        Vec::new(),
+    );
+    Ok(encode_dataset(&dcmroot, ts)?)
+}
+
+/// Creates an empty element for a response identifier, which is encoded as UTF-8.
+fn new_rsp_elem(tag: u32, vr: VRRef, ts: TSRef) -> DicomElement<'static> {
+    DicomElement::new(
+        tag,
+        vr,
+        ValueLength::Explicit(0),
+        ts,
+        UTF8_CHARACTER_SET,
+        Vec::new(),
+        Vec::new(),
+    )
+}
+
+/// Converts an indexed bson value back into a value for the given VR. This is the inverse of
+/// `insert_elem_entry()` for the VRs which can be used as query keys.
+fn bson_to_value(bson: &Bson, vr: VRRef) -> Option<RawValue> {
+    let values: Vec<&Bson> = match bson {
+        Bson::Array(array) => array.iter().collect(),
+        other => vec![other],
+    };
+
+    if vr.is_character_string {
+        let strings: Vec<String> = values
+            .into_iter()
+            .filter_map(|value| match value {
+                Bson::String(s) => Some(s.clone()),
+                Bson::Int32(i) => Some(i.to_string()),
+                Bson::Int64(i) => Some(i.to_string()),
+                Bson::Double(d) => Some(d.to_string()),
+                _ => None,
+            })
+            .collect();
+        return Some(RawValue::Strings(strings));
+    }
+
+    let numbers: Vec<f64> = values
+        .into_iter()
+        .filter_map(|value| match value {
+            Bson::Int32(i) => Some(f64::from(*i)),
+            Bson::Int64(i) => Some(*i as f64),
+            Bson::Double(d) => Some(*d),
+            _ => None,
+        })
+        .collect();
+    if vr == &vr::US {
+        Some(RawValue::UnsignedShorts(
+            numbers.into_iter().map(|n| n as u16).collect(),
+        ))
+    } else if vr == &vr::SS {
+        Some(RawValue::Shorts(
+            numbers.into_iter().map(|n| n as i16).collect(),
+        ))
+    } else if vr == &vr::UL {
+        Some(RawValue::UnsignedIntegers(
+            numbers.into_iter().map(|n| n as u32).collect(),
+        ))
+    } else if vr == &vr::SL {
+        Some(RawValue::Integers(
+            numbers.into_iter().map(|n| n as i32).collect(),
+        ))
+    } else if vr == &vr::FL {
+        Some(RawValue::Floats(
+            numbers.into_iter().map(|n| n as f32).collect(),
+        ))
+    } else if vr == &vr::FD {
+        Some(RawValue::Doubles(numbers))
+    } else {
+        None
+    }
+}
+
+/// Inserts the new records, which have their ids, skipping those already inserted.
+fn insert_new(dicom_coll: &Collection<Document>, records: &[Document]) -> Result<()> {
+    let options: InsertManyOptions = InsertManyOptions::builder().ordered(false).build();
+    match dicom_coll.insert_many(records, options) {
+        Ok(_) => Ok(()),
+        Err(e) => match &*e.kind {
+            mongodb::error::ErrorKind::BulkWrite(BulkWriteFailure {
+                write_errors: Some(errors),
+                write_concern_error: None,
+                ..
+            }) if errors.iter().all(|error| error.code == DUPLICATE_KEY_CODE) => Ok(()),
+            _ => Err(e.into()),
+        },
+    }
+}
+
 /// Builds a bson value from the given `DicomElement` and inserts it into the bson document
-fn insert_elem_entry(elem: &DicomElement, dicom_doc: &mut Document) -> Result<()> {
+fn insert_elem_entry(
+    elem: &DicomElement,
+    filter: &AttributeFilter,
+    dicom_doc: &mut Document,
+) -> Result<()> {
     let key: String = Tag::format_tag_to_path_display(elem.tag());
-    let raw_value: RawValue = elem.parse_value()?;
+    let raw_value: RawValue = filter.truncate(elem.tag(), elem.parse_value()?);
     match raw_value {
         RawValue::Attribute(attrs) => {
             if !attrs.is_empty() {
@@ -450,6 +1475,9 @@ fn insert_elem_entry(elem: &DicomElement, dicom_doc: &mut Document) -> Result<()
                 }
             }
         }
+        RawValue::BulkData(bulk_data) => {
+            dicom_doc.insert(key, bulk_data.to_string());
+        }
         RawValue::QuadWords(qwords) => {
             let mut qwords = qwords
                 .into_iter()
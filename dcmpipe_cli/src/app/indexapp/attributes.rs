@@ -0,0 +1,122 @@
+//! Selection of the attributes stored in index records, see `AttributeFilter`.
+
+use std::collections::HashSet;
+
+use anyhow::{Context, Result};
+use dcmpipe_lib::{
+    core::{defn::tag::Tag, RawValue},
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags},
+};
+
+use crate::config::IndexConfig;
+
+/// The attributes the index relies on, for keying records, rolling series up into studies and
+/// patients, and the columns of PostgreSQL indexes. These are indexed whatever the filter.
+static REQUIRED_TAGS: [u32; 17] = [
+    tags::SOPInstanceUID.tag,
+    tags::SeriesInstanceUID.tag,
+    tags::StudyInstanceUID.tag,
+    tags::PatientID.tag,
+    tags::PatientsName.tag,
+    tags::StudyDate.tag,
+    tags::SeriesDate.tag,
+    tags::AccessionNumber.tag,
+    tags::Modality.tag,
+    tags::InstanceNumber.tag,
+    tags::SeriesNumber.tag,
+    tags::StudyTime.tag,
+    tags::SOPClassUID.tag,
+    tags::PatientsBirthDate.tag,
+    tags::PatientsSex.tag,
+    tags::StudyDescription.tag,
+    tags::SeriesDescription.tag,
+];
+
+/// Which top-level attributes of datasets are stored in index records, and how long their values
+/// may be. By default all attributes other than sequences are indexed with their full values.
+#[derive(Debug)]
+pub(crate) struct AttributeFilter {
+    /// When given, only these attributes are indexed.
+    include: Option<HashSet<u32>>,
+    exclude: HashSet<u32>,
+    /// Whether private attributes are indexed, other than those given in `include`.
+    private: bool,
+    /// The number of characters values of character strings are truncated to.
+    max_value_len: Option<usize>,
+}
+
+impl AttributeFilter {
+    /// Creates the filter from the attributes given by keyword or tag number, as accepted by
+    /// `Tag::parse_tag()`. An empty `include` indexes all attributes.
+    pub(crate) fn new(
+        include: &[String],
+        exclude: &[String],
+        private: bool,
+        max_value_len: Option<usize>,
+    ) -> Result<AttributeFilter> {
+        let include: Option<HashSet<u32>> = if include.is_empty() {
+            None
+        } else {
+            Some(parse_tags(include)?)
+        };
+        Ok(AttributeFilter {
+            include,
+            exclude: parse_tags(exclude)?,
+            private,
+            max_value_len,
+        })
+    }
+
+    /// Creates the filter from `[index]` of `dcmpipe.toml`.
+    pub(crate) fn from_config(config: &IndexConfig) -> Result<AttributeFilter> {
+        AttributeFilter::new(
+            config.include.as_deref().unwrap_or_default(),
+            config.exclude.as_deref().unwrap_or_default(),
+            config.private.unwrap_or(true),
+            config.max_value_len,
+        )
+    }
+
+    /// Whether the attribute with the given tag is indexed.
+    pub(crate) fn is_indexed(&self, tag: u32) -> bool {
+        if REQUIRED_TAGS.contains(&tag) {
+            return true;
+        }
+        if self.exclude.contains(&tag) {
+            return false;
+        }
+        match &self.include {
+            Some(include) => include.contains(&tag),
+            None => self.private || !Tag::is_private(tag),
+        }
+    }
+
+    /// Truncates the character strings of the value of the attribute with the given tag to the
+    /// maximum length. The values of required attributes aren't truncated.
+    pub(crate) fn truncate(&self, tag: u32, value: RawValue) -> RawValue {
+        match (value, self.max_value_len) {
+            (RawValue::Strings(strings), Some(max_len)) if !REQUIRED_TAGS.contains(&tag) => {
+                RawValue::Strings(
+                    strings
+                        .into_iter()
+                        .map(|string| match string.char_indices().nth(max_len) {
+                            Some((end, _)) => string[..end].to_owned(),
+                            None => string,
+                        })
+                        .collect(),
+                )
+            }
+            (value, _) => value,
+        }
+    }
+}
+
+fn parse_tags(attributes: &[String]) -> Result<HashSet<u32>> {
+    attributes
+        .iter()
+        .map(|attribute| {
+            Tag::parse_tag(attribute, Some(&STANDARD_DICOM_DICTIONARY))
+                .with_context(|| format!("Unknown attribute to index: {attribute}"))
+        })
+        .collect()
+}
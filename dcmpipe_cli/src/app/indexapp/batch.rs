@@ -0,0 +1,76 @@
+//! Batching of writes to the index, so that large scans are written in bounded chunks which are
+//! retried after transient errors, rather than in one request which fails as a whole.
+
+use std::{thread, time::Duration};
+
+use anyhow::Result;
+use mongodb::error::{ErrorKind, RETRYABLE_WRITE_ERROR, TRANSIENT_TRANSACTION_ERROR};
+
+use crate::shutdown;
+
+/// The delay before the first retry, doubled for each retry after.
+const RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// How writes to the index are batched.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Batching {
+    /// The number of records written per batch.
+    pub size: usize,
+    /// The number of times a batch is retried after a transient error.
+    pub retries: u32,
+}
+
+impl Batching {
+    /// Runs the write of a batch, retrying it after transient errors. The write must be safe to
+    /// run again after having partially succeeded.
+    pub(super) fn retry<T>(&self, mut write: impl FnMut() -> Result<T>) -> Result<T> {
+        let mut attempt: u32 = 0;
+        loop {
+            match write() {
+                Ok(written) => return Ok(written),
+                Err(e) if attempt < self.retries && is_transient(&e) => {
+                    if shutdown::is_requested() {
+                        return Err(e);
+                    }
+                    let delay: Duration = RETRY_DELAY * 2u32.pow(attempt);
+                    attempt += 1;
+                    eprintln!(
+                        "Retrying write to the index in {delay:?} ({attempt} of {}): {e:#}",
+                        self.retries
+                    );
+                    thread::sleep(delay);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// Whether the error is likely to pass if the write is retried, e.g. a dropped connection or a
+/// failover of the database, rather than a problem with the records written.
+fn is_transient(e: &anyhow::Error) -> bool {
+    if let Some(e) = e.downcast_ref::<mongodb::error::Error>() {
+        return e.contains_label(RETRYABLE_WRITE_ERROR)
+            || e.contains_label(TRANSIENT_TRANSACTION_ERROR)
+            || matches!(
+                *e.kind,
+                ErrorKind::Io(_)
+                    | ErrorKind::ConnectionPoolCleared { .. }
+                    | ErrorKind::ServerSelection { .. }
+            );
+    }
+    #[cfg(feature = "index-postgres")]
+    if let Some(e) = e.downcast_ref::<postgres::Error>() {
+        use postgres::error::SqlState;
+        // Serialization failures and deadlocks abort the transaction but pass when retried, as do
+        // the errors of class 08, connection exceptions.
+        return e.is_closed()
+            || e.code().is_some_and(|code| {
+                *code == SqlState::T_R_SERIALIZATION_FAILURE
+                    || *code == SqlState::T_R_DEADLOCK_DETECTED
+                    || code.code().starts_with("08")
+            })
+            || std::error::Error::source(e).is_some_and(|source| source.is::<std::io::Error>());
+    }
+    false
+}
@@ -0,0 +1,163 @@
+//! Sampling of indexed files for `index verify --sample`, re-reading them to detect files which
+//! were modified or replaced since they were indexed, which checking that the files exist can't.
+
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Context, Result};
+use bson::Document;
+use dcmpipe_lib::{
+    core::{
+        compressed::DatasetFile,
+        dcmobject::DicomRoot,
+        defn::{dcmdict::DicomDictionary, tag::Tag},
+        read::{stop::ParseStop, Parser, ParserBuilder},
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags},
+};
+use rand::seq::SliceRandom;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::{app::pathref::decode_pathref, shutdown};
+
+use super::{
+    insert_elem_entry, AttributeFilter, MODALITY_KEY, PATIENT_ID_KEY, PATIENT_NAME_KEY,
+    SERIES_UID_KEY, STUDY_DATE_KEY, STUDY_UID_KEY,
+};
+
+/// The attributes compared with those of sampled files, which are the same for all instances of a
+/// series so can be compared with the series record.
+pub(super) static COMPARED_KEYS: [&str; 6] = [
+    SERIES_UID_KEY,
+    STUDY_UID_KEY,
+    PATIENT_ID_KEY,
+    PATIENT_NAME_KEY,
+    STUDY_DATE_KEY,
+    MODALITY_KEY,
+];
+
+/// An indexed file, with the indexed values it's compared against.
+pub(super) struct IndexedFile {
+    pub pathref: String,
+    /// The indexed values of `COMPARED_KEYS`, as JSON by key. Attributes absent from the index are
+    /// expected to be absent from the file.
+    pub attributes: BTreeMap<String, Value>,
+    /// The SHA-256 checksum of the file when indexed, if `index scan --checksums` was used.
+    pub sha256: Option<String>,
+}
+
+/// The SHA-256 checksum of the contents, as hex.
+pub(super) fn checksum(mut reader: impl Read) -> io::Result<String> {
+    let mut hasher = Sha256::new();
+    io::copy(&mut reader, &mut hasher)?;
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect())
+}
+
+/// Re-reads up to `sample` of the files, chosen at random, reporting those whose attributes or
+/// checksum differ from those indexed. Files which no longer exist aren't sampled.
+pub(super) fn check_sample(mut files: Vec<IndexedFile>, sample: usize) -> Result<()> {
+    files.retain(|file| decode_pathref(&file.pathref).is_file());
+    let sampled: Vec<&IndexedFile> = files
+        .choose_multiple(&mut rand::thread_rng(), sample)
+        .collect();
+    let filter: AttributeFilter = AttributeFilter::new(&[], &[], true, None)?;
+
+    let mut checked: usize = 0;
+    let mut drifted: usize = 0;
+    for file in sampled {
+        if shutdown::is_requested() {
+            println!("Sampling interrupted");
+            break;
+        }
+        checked += 1;
+        let path: PathBuf = decode_pathref(&file.pathref);
+        let differences: Vec<String> = match compare(file, &path, &filter) {
+            Ok(differences) => differences,
+            Err(e) => vec![format!("{e:#}")],
+        };
+        if !differences.is_empty() {
+            drifted += 1;
+            println!("Drifted: {}", path.display());
+            for difference in differences {
+                println!("  {difference}");
+            }
+        }
+    }
+    println!("Sampled {checked} files, {drifted} differ from the index");
+    Ok(())
+}
+
+/// Describes how the file differs from its indexed values.
+fn compare(file: &IndexedFile, path: &Path, filter: &AttributeFilter) -> Result<Vec<String>> {
+    let mut differences: Vec<String> = Vec::new();
+    if let Some(indexed) = &file.sha256 {
+        let actual: String = File::open(path)
+            .and_then(checksum)
+            .with_context(|| format!("Failed to read: {}", path.display()))?;
+        if *indexed != actual {
+            differences.push(format!("checksum {actual}, indexed {indexed}"));
+        }
+    }
+
+    let mut parser: Parser<'_, DatasetFile> = ParserBuilder::default()
+        .stop(ParseStop::BeforeTagValue(tags::PixelData.tag.into()))
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .build(DatasetFile::open(path)?);
+    let dcmroot: DicomRoot<'_> =
+        DicomRoot::parse(&mut parser)?.ok_or_else(|| anyhow!("No longer a DICOM file"))?;
+    let mut fields: Document = Document::new();
+    for key in COMPARED_KEYS {
+        let tag: u32 = u32::from_str_radix(key, 16)?;
+        if let Some(obj) = dcmroot.get_child_by_tag(tag) {
+            insert_elem_entry(obj.element(), filter, &mut fields)?;
+        }
+    }
+
+    for key in COMPARED_KEYS {
+        let indexed: Option<Value> = file.attributes.get(key).map(normalize);
+        let actual: Option<Value> = fields
+            .get(key)
+            .map(|value| normalize(&value.clone().into_relaxed_extjson()));
+        if indexed != actual {
+            let tag: u32 = u32::from_str_radix(key, 16)?;
+            let name: &str = STANDARD_DICOM_DICTIONARY
+                .get_tag_by_number(tag)
+                .map_or("", |tag| tag.ident);
+            differences.push(format!(
+                "{name} {} {}, indexed {}",
+                Tag::format_tag_to_display(tag),
+                display(&actual),
+                display(&indexed),
+            ));
+        }
+    }
+    Ok(differences)
+}
+
+/// Trims the padding of strings, which indexes may have stripped.
+fn normalize(value: &Value) -> Value {
+    match value {
+        Value::String(string) => Value::String(
+            string
+                .trim_matches(|c: char| c == '\0' || c.is_whitespace())
+                .to_owned(),
+        ),
+        Value::Array(values) => Value::Array(values.iter().map(normalize).collect()),
+        value => value.clone(),
+    }
+}
+
+fn display(value: &Option<Value>) -> String {
+    value
+        .as_ref()
+        .map_or_else(|| "(absent)".to_owned(), Value::to_string)
+}
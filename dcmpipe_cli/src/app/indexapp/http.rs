@@ -0,0 +1,463 @@
+//! A read-only HTTP API over the index, see `index http`.
+//!
+//! Records are returned as JSON in the form they're stored in the database, keyed by tag number as
+//! `GGGGEEEE`, so tools can query the archive without a MongoDB client. Collections are filtered
+//! by query parameters named by tag keyword or number, whose values are matched as keys of a C-FIND
+//! query, e.g. `/studies?PatientName=DOE^*&StudyDate=20240101-`. The `limit` and `offset`
+//! parameters page through the results.
+//!
+//! Routes:
+//!   - `GET /patients`, `GET /studies`, `GET /series`
+//!   - `GET /patients/{PatientID}/studies`
+//!   - `GET /studies/{StudyInstanceUID}/series`
+//!   - `GET /series/{SeriesInstanceUID}`
+//!   - `GET /series/{SeriesInstanceUID}/files`, the paths of the series instances
+//!   - `GET /series/{SeriesInstanceUID}/files/{index}`, the content of an instance, from 0
+//!   - `GET /series/{SeriesInstanceUID}/thumbnail`
+
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, ErrorKind, Read, Write},
+    net::{TcpListener, TcpStream},
+    path::PathBuf,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use bson::{doc, Array, Bson, Document};
+use mongodb::{options::FindOptions, sync::Database};
+use serde_json::{json, Value};
+
+use dcmpipe_lib::{
+    core::{
+        defn::{dcmdict::DicomDictionary, tag::Tag, vr},
+        matching::Matcher,
+    },
+    dict::stdlookup::STANDARD_DICOM_DICTIONARY,
+};
+
+use crate::{
+    app::{pathref::decode_pathref, ACCEPT_POLL_INTERVAL},
+    args::ImageFormat,
+    shutdown,
+};
+
+use super::{
+    match_condition, read_thumbnail, Thumbnail, COLLECTION_NAME, MONGO_ID_KEY,
+    PATIENT_COLLECTION_NAME, PATIENT_ID_KEY, SERIES_UID_KEY, STUDY_COLLECTION_NAME, STUDY_UID_KEY,
+};
+
+/// How long a client has to send its request, in total.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long a client has to receive each write of the response.
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The most connections handled at once. Further connections are answered with 503 until one of
+/// them is closed.
+const MAX_CONNECTIONS: usize = 32;
+
+/// The longest request line or header accepted.
+const MAX_LINE_LEN: u64 = 8 * 1024;
+
+/// A failed request, answered with the status and a JSON body describing the error.
+struct HttpError {
+    status: u16,
+    message: String,
+}
+
+impl HttpError {
+    fn bad_request(message: impl Into<String>) -> HttpError {
+        HttpError {
+            status: 400,
+            message: message.into(),
+        }
+    }
+
+    fn not_found(message: impl Into<String>) -> HttpError {
+        HttpError {
+            status: 404,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<anyhow::Error> for HttpError {
+    fn from(e: anyhow::Error) -> HttpError {
+        HttpError {
+            status: 500,
+            message: format!("{e:#}"),
+        }
+    }
+}
+
+impl From<mongodb::error::Error> for HttpError {
+    fn from(e: mongodb::error::Error) -> HttpError {
+        anyhow::Error::from(e).into()
+    }
+}
+
+enum Response {
+    Json(Value),
+    Bytes(&'static str, Vec<u8>),
+    File(PathBuf),
+}
+
+/// Listens for HTTP requests until shutdown is requested. Connections are handled concurrently, up
+/// to `MAX_CONNECTIONS`, each answering a single request.
+pub(super) fn serve(database: &Database, addr: &str) -> Result<()> {
+    let listener: TcpListener =
+        TcpListener::bind(addr).with_context(|| format!("Failed to listen on {addr}"))?;
+    // Poll for connections so that shutdown requests are noticed while idle.
+    listener.set_nonblocking(true)?;
+
+    println!("Listening on http://{addr}");
+    let connections: AtomicUsize = AtomicUsize::new(0);
+    thread::scope(|scope| -> Result<()> {
+        while !shutdown::is_requested() {
+            let (stream, peer_addr) = match listener.accept() {
+                Ok(conn) => conn,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                    thread::sleep(ACCEPT_POLL_INTERVAL);
+                    continue;
+                }
+                Err(e) => return Err(e).context("Failed to accept connection"),
+            };
+            if connections.fetch_add(1, Ordering::SeqCst) >= MAX_CONNECTIONS {
+                connections.fetch_sub(1, Ordering::SeqCst);
+                if let Err(e) = reject_connection(stream) {
+                    tracing::debug!(peer = %peer_addr, error = format!("{e:#}"), "reject failed");
+                }
+                continue;
+            }
+            let connections: &AtomicUsize = &connections;
+            scope.spawn(move || {
+                if let Err(e) = handle_connection(database, stream) {
+                    tracing::debug!(peer = %peer_addr, error = format!("{e:#}"), "request failed");
+                }
+                connections.fetch_sub(1, Ordering::SeqCst);
+            });
+        }
+        Ok(())
+    })?;
+    println!("Stopped listening on {addr}");
+
+    Ok(())
+}
+
+/// Reads the request from the stream until its deadline, so that a client sending it slowly can't
+/// hold the connection for longer than `REQUEST_TIMEOUT`.
+struct RequestReader<'s> {
+    stream: &'s TcpStream,
+    deadline: Instant,
+}
+
+impl Read for RequestReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining: Duration = self.deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(io::Error::new(ErrorKind::TimedOut, "request timed out"));
+        }
+        self.stream.set_read_timeout(Some(remaining))?;
+        self.stream.read(buf)
+    }
+}
+
+/// Answers a connection accepted while `MAX_CONNECTIONS` are being handled with 503, without
+/// reading its request.
+fn reject_connection(mut stream: TcpStream) -> Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_write_timeout(Some(RESPONSE_TIMEOUT))?;
+    write_error(
+        &mut stream,
+        HttpError {
+            status: 503,
+            message: "Too many connections, try again later".to_owned(),
+        },
+    )
+}
+
+fn handle_connection(database: &Database, mut stream: TcpStream) -> Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_write_timeout(Some(RESPONSE_TIMEOUT))?;
+
+    let mut reader: BufReader<RequestReader<'_>> = BufReader::new(RequestReader {
+        stream: &stream,
+        deadline: Instant::now() + REQUEST_TIMEOUT,
+    });
+    let request_line: String = read_line(&mut reader)?;
+    // The headers aren't needed, but are read so the client isn't reset before the response.
+    while !read_line(&mut reader)?.is_empty() {}
+
+    let mut parts = request_line.split_whitespace();
+    let (method, target) = (parts.next().unwrap_or_default(), parts.next());
+    let response: Result<Response, HttpError> = match (method, target) {
+        ("GET", Some(target)) => route(database, target),
+        (_, Some(_)) => Err(HttpError {
+            status: 405,
+            message: format!("Unsupported method: {method}"),
+        }),
+        _ => Err(HttpError::bad_request("Invalid request line")),
+    };
+    let response: Response = match response {
+        Ok(Response::File(path)) => match File::open(&path) {
+            Ok(file) => return write_file(&mut stream, file),
+            Err(e) => {
+                let e: anyhow::Error =
+                    anyhow::Error::from(e).context(format!("Failed to open: {}", path.display()));
+                return write_error(&mut stream, e.into());
+            }
+        },
+        Ok(response) => response,
+        Err(e) => return write_error(&mut stream, e),
+    };
+    match response {
+        Response::Json(value) => write_response(
+            &mut stream,
+            200,
+            "application/json",
+            value.to_string().as_bytes(),
+        ),
+        Response::Bytes(content_type, data) => {
+            write_response(&mut stream, 200, content_type, &data)
+        }
+        Response::File(_) => Ok(()),
+    }
+}
+
+/// Reads a line of the request without its line ending.
+fn read_line(reader: &mut impl BufRead) -> Result<String> {
+    let mut line: String = String::new();
+    reader.by_ref().take(MAX_LINE_LEN).read_line(&mut line)?;
+    if !line.ends_with('\n') {
+        return Err(anyhow::anyhow!("Request line is incomplete or too long"));
+    }
+    Ok(line.trim_end_matches(['\r', '\n']).to_owned())
+}
+
+fn route(database: &Database, target: &str) -> Result<Response, HttpError> {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let params: Vec<(String, String)> = parse_query(query)?;
+    let segments: Vec<String> = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(percent_decode)
+        .collect::<Result<Vec<String>, HttpError>>()?;
+    let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+
+    match segments[..] {
+        ["patients"] => list(database, PATIENT_COLLECTION_NAME, &params, None),
+        ["studies"] => list(database, STUDY_COLLECTION_NAME, &params, None),
+        ["series"] => list(database, COLLECTION_NAME, &params, None),
+        ["patients", patient_id, "studies"] => list(
+            database,
+            STUDY_COLLECTION_NAME,
+            &params,
+            Some(doc! { PATIENT_ID_KEY: patient_id }),
+        ),
+        ["studies", study_uid, "series"] => list(
+            database,
+            COLLECTION_NAME,
+            &params,
+            Some(doc! { STUDY_UID_KEY: study_uid }),
+        ),
+        ["series", series_uid] => Ok(Response::Json(record_to_json(find_series(
+            database, series_uid,
+        )?))),
+        ["series", series_uid, "files"] => {
+            let files: Vec<Value> = series_files(&find_series(database, series_uid)?)
+                .into_iter()
+                .map(Value::String)
+                .collect();
+            Ok(Response::Json(Value::Array(files)))
+        }
+        ["series", series_uid, "files", index] => {
+            let index: usize = index
+                .parse()
+                .map_err(|_| HttpError::bad_request(format!("Invalid file index: {index}")))?;
+            let files: Vec<String> = series_files(&find_series(database, series_uid)?);
+            let pathref: &String = files.get(index).ok_or_else(|| {
+                HttpError::not_found(format!("Series {series_uid} has {} files", files.len()))
+            })?;
+            let path: PathBuf = decode_pathref(pathref);
+            if !path.is_file() {
+                // Files within archives are listed by the path of the archive joined with their
+                // path within it, and aren't served.
+                return Err(HttpError::not_found(format!("Not a file: {pathref}")));
+            }
+            Ok(Response::File(path))
+        }
+        ["series", series_uid, "thumbnail"] => {
+            let thumbnail: Thumbnail = read_thumbnail(database, series_uid)?
+                .ok_or_else(|| HttpError::not_found(format!("No thumbnail: {series_uid}")))?;
+            let content_type: &str = match thumbnail.format {
+                ImageFormat::Png => "image/png",
+                ImageFormat::Jpeg => "image/jpeg",
+            };
+            Ok(Response::Bytes(content_type, thumbnail.data))
+        }
+        _ => Err(HttpError::not_found(format!("No such resource: {path}"))),
+    }
+}
+
+/// Lists the records of the collection matching the query parameters and the given condition.
+fn list(
+    database: &Database,
+    coll_name: &str,
+    params: &[(String, String)],
+    condition: Option<Document>,
+) -> Result<Response, HttpError> {
+    let mut conditions: Array = Array::new();
+    conditions.extend(condition.map(Bson::Document));
+    let mut options: FindOptions = FindOptions::default();
+    for (key, value) in params {
+        match key.as_str() {
+            "limit" => options.limit = Some(parse_param(key, value)?),
+            "offset" => options.skip = Some(parse_param(key, value)?),
+            _ => conditions.extend(key_condition(key, value)?.map(Bson::Document)),
+        }
+    }
+    let filter: Document = if conditions.is_empty() {
+        Document::new()
+    } else {
+        doc! { "$and": conditions }
+    };
+
+    let mut records: Vec<Value> = Vec::new();
+    for doc in database
+        .collection::<Document>(coll_name)
+        .find(filter, options)?
+    {
+        records.push(record_to_json(doc?));
+    }
+    Ok(Response::Json(Value::Array(records)))
+}
+
+/// The condition matching the key named by a query parameter, as with the keys of a C-FIND query.
+fn key_condition(key: &str, value: &str) -> Result<Option<Document>, HttpError> {
+    let tag: u32 = Tag::parse_tag(key, Some(&STANDARD_DICOM_DICTIONARY))
+        .map_err(|_| HttpError::bad_request(format!("Unknown attribute: {key}")))?;
+    let vr = STANDARD_DICOM_DICTIONARY
+        .get_tag_by_number(tag)
+        .and_then(|tag| tag.implicit_vr)
+        .unwrap_or(&vr::LO);
+    let matcher: Matcher = Matcher::parse(vr, value);
+    Ok(match_condition(
+        &Tag::format_tag_to_path_display(tag),
+        &matcher,
+        matcher.matching(),
+    ))
+}
+
+fn parse_param<T: std::str::FromStr>(key: &str, value: &str) -> Result<T, HttpError> {
+    value
+        .parse()
+        .map_err(|_| HttpError::bad_request(format!("Invalid {key}: {value}")))
+}
+
+fn find_series(database: &Database, series_uid: &str) -> Result<Document, HttpError> {
+    database
+        .collection::<Document>(COLLECTION_NAME)
+        .find_one(doc! { SERIES_UID_KEY: series_uid }, None)?
+        .ok_or_else(|| HttpError::not_found(format!("No series: {series_uid}")))
+}
+
+/// The path references of the series instances.
+fn series_files(series: &Document) -> Vec<String> {
+    series
+        .get_document("metadata")
+        .and_then(|metadata| metadata.get_array("files"))
+        .map(|files| {
+            files
+                .iter()
+                .filter_map(|file| file.as_str().map(str::to_owned))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Converts a record to JSON, without the database's own ID of the record.
+fn record_to_json(mut doc: Document) -> Value {
+    doc.remove(MONGO_ID_KEY);
+    Bson::Document(doc).into_relaxed_extjson()
+}
+
+/// Parses the parameters of a query string, e.g. `a=1&b=2`.
+fn parse_query(query: &str) -> Result<Vec<(String, String)>, HttpError> {
+    query
+        .split('&')
+        .filter(|param| !param.is_empty())
+        .map(|param| {
+            let (key, value) = param.split_once('=').unwrap_or((param, ""));
+            Ok((
+                percent_decode(&key.replace('+', " "))?,
+                percent_decode(&value.replace('+', " "))?,
+            ))
+        })
+        .collect()
+}
+
+/// Decodes the `%XX` escapes of a URL component.
+fn percent_decode(value: &str) -> Result<String, HttpError> {
+    let invalid = || HttpError::bad_request(format!("Invalid escape in: {value}"));
+    let bytes: &[u8] = value.as_bytes();
+    let mut decoded: Vec<u8> = Vec::with_capacity(bytes.len());
+    let mut index: usize = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let hex: &str = value.get(index + 1..index + 3).ok_or_else(invalid)?;
+            decoded.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8(decoded).map_err(|_| invalid())
+}
+
+fn write_response(
+    stream: &mut TcpStream,
+    status: u16,
+    content_type: &str,
+    body: &[u8],
+) -> Result<()> {
+    write_head(stream, status, content_type, body.len() as u64)?;
+    stream.write_all(body)?;
+    Ok(stream.flush()?)
+}
+
+fn write_error(stream: &mut TcpStream, e: HttpError) -> Result<()> {
+    let body: Value = json!({ "error": e.message });
+    write_response(
+        stream,
+        e.status,
+        "application/json",
+        body.to_string().as_bytes(),
+    )
+}
+
+fn write_file(stream: &mut TcpStream, mut file: File) -> Result<()> {
+    let len: u64 = file.metadata()?.len();
+    write_head(stream, 200, "application/dicom", len)?;
+    io::copy(&mut file, stream)?;
+    Ok(stream.flush()?)
+}
+
+fn write_head(stream: &mut TcpStream, status: u16, content_type: &str, len: u64) -> Result<()> {
+    let reason: &str = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: {content_type}\r\nContent-Length: {len}\r\nConnection: close\r\n\r\n"
+    )?;
+    Ok(())
+}
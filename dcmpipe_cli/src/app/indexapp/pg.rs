@@ -0,0 +1,795 @@
+//! A PostgreSQL backend for the index, used when the db URI is a `postgres://` or `postgresql://`
+//! URI.
+//!
+//! Rather than a record per series, the index is normalized into `patients`, `studies`, `series`,
+//! and `instances` tables so the archive can be reported on with SQL. Each series also keeps all of
+//! its top-level attributes as JSONB in `series.attributes`, keyed by tag number as `GGGGEEEE` as
+//! with the MongoDB records. The schema is created, and upgraded by later versions of dcmpipe, by
+//! the migrations in `MIGRATIONS` as the index is connected to.
+
+use std::{collections::HashMap, path::Path};
+
+use anyhow::{Context, Result};
+use bson::{Bson, Document};
+use dcmpipe_lib::core::matching::{range_end, Matcher, Matching};
+use postgres::{types::ToSql, Client, NoTls, Row, Transaction};
+use serde_json::Value;
+
+use crate::{
+    app::pathref::{decode_pathref, encode_pathref},
+    shutdown,
+};
+
+use super::{
+    batch::Batching,
+    drift::{IndexedFile, COMPARED_KEYS},
+    parse_thumbnail_format,
+    schema::{check_version, report_migration},
+    thumbnail_format_name, DicomDoc, Thumbnail, ACCESSION_KEY, MODALITY_KEY, PATIENT_ID_KEY,
+    PATIENT_NAME_KEY, SERIES_DATE_KEY, STUDY_DATE_KEY, STUDY_UID_KEY,
+};
+
+static PATIENT_BIRTH_DATE_KEY: &str = "00100030";
+static PATIENT_SEX_KEY: &str = "00100040";
+static STUDY_TIME_KEY: &str = "00080030";
+static STUDY_DESCRIPTION_KEY: &str = "00081030";
+static SERIES_NUMBER_KEY: &str = "00200011";
+static SERIES_DESCRIPTION_KEY: &str = "0008103E";
+
+/// Identifies the advisory lock held while migrating, so that concurrent commands don't both
+/// migrate the same database.
+const MIGRATION_LOCK_ID: i64 = 0x0064_636D_7069_7065;
+
+/// The migrations of the schema, in order, with a description of what they change. See
+/// `indexapp::schema`.
+static MIGRATIONS: [(&str, &str); 2] = [
+    (
+        "create the patient, study, series, instance, and thumbnail tables",
+        r#"
+CREATE TABLE patients (
+    patient_id TEXT PRIMARY KEY,
+    patient_name TEXT,
+    birth_date TEXT,
+    sex TEXT
+);
+
+CREATE TABLE studies (
+    study_uid TEXT PRIMARY KEY,
+    patient_id TEXT REFERENCES patients (patient_id) ON DELETE CASCADE,
+    study_date TEXT,
+    study_time TEXT,
+    accession_number TEXT,
+    study_description TEXT
+);
+CREATE INDEX studies_patient_id ON studies (patient_id);
+CREATE INDEX studies_study_date ON studies (study_date);
+CREATE INDEX studies_accession_number ON studies (accession_number);
+
+-- Series without a SeriesInstanceUID are keyed by the SOPInstanceUID, as with MongoDB.
+CREATE TABLE series (
+    series_uid TEXT PRIMARY KEY,
+    study_uid TEXT REFERENCES studies (study_uid) ON DELETE CASCADE,
+    modality TEXT,
+    series_number BIGINT,
+    series_date TEXT,
+    series_description TEXT,
+    attributes JSONB NOT NULL
+);
+CREATE INDEX series_study_uid ON series (study_uid);
+CREATE INDEX series_modality ON series (modality);
+
+-- Instances within zip and tar archives have the path of the archive joined with their path
+-- within it.
+CREATE TABLE instances (
+    path TEXT PRIMARY KEY,
+    series_uid TEXT NOT NULL REFERENCES series (series_uid) ON DELETE CASCADE,
+    sop_instance_uid TEXT,
+    instance_number BIGINT
+);
+CREATE INDEX instances_series_uid ON instances (series_uid);
+CREATE INDEX instances_sop_instance_uid ON instances (sop_instance_uid);
+
+CREATE TABLE thumbnails (
+    series_uid TEXT PRIMARY KEY REFERENCES series (series_uid) ON DELETE CASCADE,
+    format TEXT NOT NULL,
+    width INTEGER NOT NULL,
+    height INTEGER NOT NULL,
+    path TEXT NOT NULL,
+    data BYTEA NOT NULL
+);
+"#,
+    ),
+    (
+        "record the checksums of instances",
+        "ALTER TABLE instances ADD COLUMN sha256 TEXT;",
+    ),
+];
+
+/// Whether the db URI is of a PostgreSQL database.
+pub(super) fn is_postgres_uri(uri: &str) -> bool {
+    uri.starts_with("postgres://") || uri.starts_with("postgresql://")
+}
+
+/// An index stored in a PostgreSQL database.
+pub(super) struct PgIndex {
+    uri: String,
+    client: Client,
+}
+
+impl PgIndex {
+    /// Connects to the database, migrating it to the current schema.
+    pub(super) fn connect(uri: &str) -> Result<PgIndex> {
+        let client: Client =
+            Client::connect(uri, NoTls).with_context(|| format!("Failed to connect to: {uri}"))?;
+        let mut index: PgIndex = PgIndex {
+            uri: uri.to_owned(),
+            client,
+        };
+        index.migrate()?;
+        Ok(index)
+    }
+
+    /// Reconnects to the database if the connection was closed, e.g. by a failed write.
+    fn reconnect_if_closed(&mut self) -> Result<()> {
+        if self.client.is_closed() {
+            self.client = Client::connect(&self.uri, NoTls)
+                .with_context(|| format!("Failed to connect to: {}", self.uri))?;
+        }
+        Ok(())
+    }
+
+    /// Applies the migrations which haven't been applied to the database.
+    fn migrate(&mut self) -> Result<()> {
+        let mut tx: Transaction<'_> = self.client.transaction()?;
+        tx.execute("SELECT pg_advisory_xact_lock($1)", &[&MIGRATION_LOCK_ID])?;
+        tx.batch_execute(
+            "CREATE TABLE IF NOT EXISTS schema_migrations (
+                version INTEGER PRIMARY KEY,
+                description TEXT NOT NULL,
+                applied TIMESTAMPTZ NOT NULL DEFAULT now()
+            )",
+        )?;
+        let version: i32 = tx
+            .query_one(
+                "SELECT COALESCE(MAX(version), 0) FROM schema_migrations",
+                &[],
+            )?
+            .get(0);
+        let version: usize = check_version(version, MIGRATIONS.len())?;
+        let mut applied: Vec<(i32, &str)> = Vec::new();
+        for (number, (description, migration)) in MIGRATIONS.iter().enumerate().skip(version) {
+            let number: i32 = i32::try_from(number + 1)?;
+            tx.batch_execute(migration)
+                .with_context(|| format!("Failed to migrate the index to version {number}"))?;
+            tx.execute(
+                "INSERT INTO schema_migrations (version, description) VALUES ($1, $2)",
+                &[&number, description],
+            )?;
+            applied.push((number, description));
+        }
+        tx.commit()?;
+        for (number, description) in applied {
+            report_migration(number, description);
+        }
+        Ok(())
+    }
+
+    /// Inserts or updates the patient, study, series, and instance records of the scanned series.
+    /// As with MongoDB, the instances of a series are replaced by those scanned. Each batch of
+    /// series is written in a transaction, which is retried after transient errors.
+    pub(super) fn upsert_records(
+        &mut self,
+        uid_to_doc: HashMap<String, DicomDoc>,
+        batching: &Batching,
+    ) -> Result<()> {
+        let mut series: Vec<&DicomDoc> = uid_to_doc.values().collect();
+        series.sort_by(|a, b| a.key.cmp(&b.key));
+        let (mut series_count, mut instance_count): (usize, usize) = (0, 0);
+        for batch in series.chunks(batching.size) {
+            instance_count += batching.retry(|| {
+                self.reconnect_if_closed()?;
+                self.upsert_batch(batch)
+            })?;
+            series_count += batch.len();
+            if shutdown::is_requested() {
+                println!(
+                    "Upsert interrupted, {} series not written",
+                    series.len() - series_count
+                );
+                break;
+            }
+        }
+        println!("Upserted {series_count} series of {instance_count} instances");
+        Ok(())
+    }
+
+    /// Writes the records of the series in a transaction, returning the number of instances.
+    fn upsert_batch(&mut self, batch: &[&DicomDoc]) -> Result<usize> {
+        let mut tx: Transaction<'_> = self.client.transaction()?;
+        let mut instance_count: usize = 0;
+        for dicom_doc in batch {
+            let doc: &Document = &dicom_doc.doc;
+            let patient_id: Option<String> = doc_str(doc, PATIENT_ID_KEY);
+            if let Some(patient_id) = &patient_id {
+                tx.execute(
+                    "INSERT INTO patients (patient_id, patient_name, birth_date, sex)
+                    VALUES ($1, $2, $3, $4)
+                    ON CONFLICT (patient_id) DO UPDATE SET
+                        patient_name = COALESCE(EXCLUDED.patient_name, patients.patient_name),
+                        birth_date = COALESCE(EXCLUDED.birth_date, patients.birth_date),
+                        sex = COALESCE(EXCLUDED.sex, patients.sex)",
+                    &[
+                        patient_id,
+                        &doc_str(doc, PATIENT_NAME_KEY),
+                        &doc_str(doc, PATIENT_BIRTH_DATE_KEY),
+                        &doc_str(doc, PATIENT_SEX_KEY),
+                    ],
+                )?;
+            }
+
+            let study_uid: Option<String> = doc_str(doc, STUDY_UID_KEY);
+            if let Some(study_uid) = &study_uid {
+                tx.execute(
+                    "INSERT INTO studies (study_uid, patient_id, study_date, study_time,
+                        accession_number, study_description)
+                    VALUES ($1, $2, $3, $4, $5, $6)
+                    ON CONFLICT (study_uid) DO UPDATE SET
+                        patient_id = EXCLUDED.patient_id,
+                        study_date = EXCLUDED.study_date,
+                        study_time = EXCLUDED.study_time,
+                        accession_number = EXCLUDED.accession_number,
+                        study_description = EXCLUDED.study_description",
+                    &[
+                        study_uid,
+                        &patient_id,
+                        &doc_str(doc, STUDY_DATE_KEY),
+                        &doc_str(doc, STUDY_TIME_KEY),
+                        &doc_str(doc, ACCESSION_KEY),
+                        &doc_str(doc, STUDY_DESCRIPTION_KEY),
+                    ],
+                )?;
+            }
+
+            let series_number: Option<i64> =
+                doc_str(doc, SERIES_NUMBER_KEY).and_then(|number| number.trim().parse().ok());
+            tx.execute(
+                "INSERT INTO series (series_uid, study_uid, modality, series_number, series_date,
+                    series_description, attributes)
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                ON CONFLICT (series_uid) DO UPDATE SET
+                    study_uid = EXCLUDED.study_uid,
+                    modality = EXCLUDED.modality,
+                    series_number = EXCLUDED.series_number,
+                    series_date = EXCLUDED.series_date,
+                    series_description = EXCLUDED.series_description,
+                    attributes = EXCLUDED.attributes",
+                &[
+                    &dicom_doc.key,
+                    &study_uid,
+                    &doc_str(doc, MODALITY_KEY),
+                    &series_number,
+                    &doc_str(doc, SERIES_DATE_KEY),
+                    &doc_str(doc, SERIES_DESCRIPTION_KEY),
+                    &attributes_json(doc),
+                ],
+            )?;
+
+            let paths: Vec<String> = dicom_doc
+                .instances
+                .iter()
+                .map(|instance| encode_pathref(&instance.path))
+                .collect();
+            tx.execute(
+                "DELETE FROM instances WHERE series_uid = $1 AND NOT (path = ANY($2))",
+                &[&dicom_doc.key, &paths],
+            )?;
+            for (instance, path) in dicom_doc.instances.iter().zip(&paths) {
+                tx.execute(
+                    "INSERT INTO instances (path, series_uid, sop_instance_uid, instance_number,
+                        sha256)
+                    VALUES ($1, $2, $3, $4, $5)
+                    ON CONFLICT (path) DO UPDATE SET
+                        series_uid = EXCLUDED.series_uid,
+                        sop_instance_uid = EXCLUDED.sop_instance_uid,
+                        instance_number = EXCLUDED.instance_number,
+                        sha256 = EXCLUDED.sha256",
+                    &[
+                        path,
+                        &dicom_doc.key,
+                        &instance.sop_uid.as_deref().map(strip_nulls),
+                        &instance.instance_number,
+                        &instance.sha256,
+                    ],
+                )?;
+            }
+            instance_count += paths.len();
+        }
+        tx.commit()?;
+        Ok(instance_count)
+    }
+
+    /// Removes the instances whose files no longer exist.
+    pub(super) fn verify_records(&mut self) -> Result<()> {
+        let rows: Vec<Row> = self.client.query("SELECT path FROM instances", &[])?;
+        let mut missing: Vec<String> = Vec::new();
+        let mut record_count: usize = 0;
+        for row in rows {
+            if shutdown::is_requested() {
+                println!("Verify interrupted, applying changes for verified records");
+                break;
+            }
+            record_count += 1;
+            let path: String = row.get(0);
+            if !decode_pathref(&path).is_file() {
+                missing.push(path);
+            }
+        }
+        println!("Verified {} records", record_count);
+
+        println!("Removing {} records", missing.len());
+        if !missing.is_empty() {
+            self.client
+                .execute("DELETE FROM instances WHERE path = ANY($1)", &[&missing])?;
+        }
+        Ok(())
+    }
+
+    /// The instances, along with the indexed values of their series, for `verify --sample`.
+    pub(super) fn indexed_files(&mut self) -> Result<Vec<IndexedFile>> {
+        let rows: Vec<Row> = self.client.query(
+            "SELECT instances.path, instances.sha256, series.attributes
+            FROM instances JOIN series USING (series_uid)",
+            &[],
+        )?;
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let attributes: Value = row.get(2);
+                IndexedFile {
+                    pathref: row.get(0),
+                    sha256: row.get(1),
+                    attributes: COMPARED_KEYS
+                        .iter()
+                        .filter_map(|key| Some((key.to_string(), attributes.get(key)?.clone())))
+                        .collect(),
+                }
+            })
+            .collect())
+    }
+
+    /// The paths of the instances of the series whose attributes match all the keys, as C-FIND keys.
+    /// The keys are matched by the SQL query, see `SqlConditions`.
+    pub(super) fn query_instances(&mut self, keys: &[(String, Matcher)]) -> Result<Vec<String>> {
+        let mut conditions: SqlConditions = SqlConditions::default();
+        for (key, matcher) in keys {
+            conditions.push_key(key, matcher);
+        }
+        let params: Vec<&(dyn ToSql + Sync)> = conditions
+            .params
+            .iter()
+            .map(|param| param as &(dyn ToSql + Sync))
+            .collect();
+        let rows: Vec<Row> = self.client.query(
+            &format!(
+                "SELECT instances.path
+                FROM series JOIN instances USING (series_uid)
+                WHERE {}
+                ORDER BY series.series_uid, instances.path",
+                conditions.to_sql()
+            ),
+            &params,
+        )?;
+        Ok(rows.into_iter().map(|row| row.get(0)).collect())
+    }
+
+    /// Removes the series, studies, and patients which no longer have any instances. The study and
+    /// patient records are otherwise kept up to date as series are upserted.
+    pub(super) fn aggregate_records(&mut self) -> Result<()> {
+        let mut tx: Transaction<'_> = self.client.transaction()?;
+        tx.batch_execute(
+            "DELETE FROM series WHERE NOT EXISTS
+                (SELECT 1 FROM instances WHERE instances.series_uid = series.series_uid);
+            DELETE FROM studies WHERE NOT EXISTS
+                (SELECT 1 FROM series WHERE series.study_uid = studies.study_uid);
+            DELETE FROM patients WHERE NOT EXISTS
+                (SELECT 1 FROM studies WHERE studies.patient_id = patients.patient_id);",
+        )?;
+        let studies: i64 = tx.query_one("SELECT COUNT(*) FROM studies", &[])?.get(0);
+        let patients: i64 = tx.query_one("SELECT COUNT(*) FROM patients", &[])?.get(0);
+        tx.commit()?;
+        println!("Aggregated {studies} studies, {patients} patients");
+        Ok(())
+    }
+
+    /// Stores the thumbnail of the series rendered from the instance at the given path, replacing
+    /// any previous thumbnail.
+    pub(super) fn store_thumbnail(
+        &mut self,
+        series_uid: &str,
+        path: &Path,
+        thumbnail: &Thumbnail,
+    ) -> Result<()> {
+        self.client.execute(
+            "INSERT INTO thumbnails (series_uid, format, width, height, path, data)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (series_uid) DO UPDATE SET
+                format = EXCLUDED.format,
+                width = EXCLUDED.width,
+                height = EXCLUDED.height,
+                path = EXCLUDED.path,
+                data = EXCLUDED.data",
+            &[
+                &series_uid,
+                &thumbnail_format_name(thumbnail.format),
+                &i32::try_from(thumbnail.width)?,
+                &i32::try_from(thumbnail.height)?,
+                &encode_pathref(path),
+                &thumbnail.data,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Reads the thumbnail of the series with the given SeriesInstanceUID, if one was rendered.
+    pub(super) fn read_thumbnail(&mut self, series_uid: &str) -> Result<Option<Thumbnail>> {
+        let Some(row) = self.client.query_opt(
+            "SELECT format, width, height, data FROM thumbnails WHERE series_uid = $1",
+            &[&series_uid],
+        )?
+        else {
+            return Ok(None);
+        };
+        Ok(Some(Thumbnail {
+            format: parse_thumbnail_format(row.get(0))?,
+            width: usize::try_from(row.get::<_, i32>(1))?,
+            height: usize::try_from(row.get::<_, i32>(2))?,
+            data: row.get(3),
+        }))
+    }
+}
+
+/// Conditions on the attributes of series records, whose values are passed as parameters. Keys are
+/// matched as by `indexapp::match_condition()` for MongoDB: a multi-valued attribute matches if any
+/// of its values do, and names are matched case-insensitively.
+#[derive(Default)]
+struct SqlConditions {
+    conditions: Vec<String>,
+    params: Vec<String>,
+}
+
+impl SqlConditions {
+    /// Adds the condition of the key, unless it matches everything.
+    fn push_key(&mut self, key: &str, matcher: &Matcher) {
+        let Some(condition) = self.matching_condition(matcher, matcher.matching()) else {
+            return;
+        };
+        let attribute: String = format!("series.attributes -> {}", self.param(key));
+        // Multi-valued attributes are indexed as arrays.
+        self.conditions.push(format!(
+            "EXISTS (SELECT 1 FROM jsonb_array_elements_text(
+                CASE jsonb_typeof({attribute})
+                    WHEN 'array' THEN {attribute}
+                    ELSE jsonb_build_array({attribute})
+                END) AS value WHERE {condition})"
+        ));
+    }
+
+    /// The condition on `value` for the matching, or `None` if it matches everything.
+    fn matching_condition(&mut self, matcher: &Matcher, matching: &Matching) -> Option<String> {
+        let condition: String = match matching {
+            Matching::Universal => return None,
+            Matching::Single(value) if matcher.is_case_insensitive() => {
+                format!("lower(value) = lower({})", self.param(value))
+            }
+            Matching::Single(value) => format!("value = {}", self.param(value)),
+            Matching::Wildcard(pattern) => {
+                let like: &str = if matcher.is_case_insensitive() {
+                    "ILIKE"
+                } else {
+                    "LIKE"
+                };
+                format!("value {like} {}", self.param(&wildcard_to_like(pattern)))
+            }
+            Matching::Range(min, max) => {
+                let mut bounds: Vec<String> = Vec::new();
+                if let Some(min) = min {
+                    bounds.push(format!("value COLLATE \"C\" >= {}", self.param(min)));
+                }
+                if let Some(max) = max {
+                    let max: String = range_end(matcher.vr(), max);
+                    bounds.push(format!("value COLLATE \"C\" <= {}", self.param(&max)));
+                }
+                if bounds.is_empty() {
+                    return None;
+                }
+                bounds.join(" AND ")
+            }
+            Matching::List(matchings) => {
+                let mut any: Vec<String> = Vec::new();
+                for matching in matchings {
+                    any.push(self.matching_condition(matcher, matching)?);
+                }
+                format!("({})", any.join(" OR "))
+            }
+        };
+        Some(condition)
+    }
+
+    /// Adds the parameter, returning its placeholder.
+    fn param(&mut self, value: &str) -> String {
+        self.params.push(strip_nulls(value));
+        format!("${}", self.params.len())
+    }
+
+    /// The conditions joined for a WHERE clause.
+    fn to_sql(&self) -> String {
+        if self.conditions.is_empty() {
+            "TRUE".to_owned()
+        } else {
+            self.conditions.join(" AND ")
+        }
+    }
+}
+
+/// Converts a DICOM wildcard match, `*` and `?`, into a LIKE pattern.
+fn wildcard_to_like(value: &str) -> String {
+    let mut pattern: String = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '*' => pattern.push('%'),
+            '?' => pattern.push('_'),
+            '%' | '_' | '\\' => {
+                pattern.push('\\');
+                pattern.push(c);
+            }
+            _ => pattern.push(c),
+        }
+    }
+    pattern
+}
+
+/// The first value of the indexed attribute as a string, if present. PostgreSQL doesn't allow NULL
+/// characters within text, so any are removed.
+fn doc_str(doc: &Document, key: &str) -> Option<String> {
+    let value: &Bson = match doc.get(key)? {
+        Bson::Array(values) => values.first()?,
+        value => value,
+    };
+    let value: String = match value {
+        Bson::String(value) => strip_nulls(value),
+        Bson::Int32(value) => value.to_string(),
+        Bson::Int64(value) => value.to_string(),
+        Bson::Double(value) => value.to_string(),
+        _ => return None,
+    };
+    Some(value).filter(|value| !value.is_empty())
+}
+
+/// The top-level attributes of the series record as JSON, without its metadata.
+fn attributes_json(doc: &Document) -> Value {
+    let mut attributes: Document = doc.clone();
+    attributes.remove("metadata");
+    let mut json: Value = Bson::Document(attributes).into_relaxed_extjson();
+    strip_json_nulls(&mut json);
+    json
+}
+
+fn strip_nulls(value: &str) -> String {
+    value.replace('\0', "")
+}
+
+/// Removes NULL characters from the strings of the JSON value, which JSONB doesn't allow.
+fn strip_json_nulls(value: &mut Value) {
+    match value {
+        Value::String(string) if string.contains('\0') => *string = strip_nulls(string),
+        Value::Array(values) => values.iter_mut().for_each(strip_json_nulls),
+        Value::Object(object) => object.values_mut().for_each(strip_json_nulls),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dcmpipe_lib::core::defn::vr;
+
+    use super::*;
+
+    /// The conditions of the keys, with whitespace collapsed, and their parameters.
+    fn conditions(keys: &[(&str, Matcher)]) -> (String, Vec<String>) {
+        let mut conditions: SqlConditions = SqlConditions::default();
+        for (key, matcher) in keys {
+            conditions.push_key(key, matcher);
+        }
+        let sql: String = conditions
+            .to_sql()
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ");
+        (sql, conditions.params)
+    }
+
+    /// The condition of a key on the attribute given by the parameter `key`, which follows the
+    /// parameters of the value's condition.
+    fn key_condition(key: &str, value_condition: &str) -> String {
+        format!(
+            "EXISTS (SELECT 1 FROM jsonb_array_elements_text( CASE \
+            jsonb_typeof(series.attributes -> {key}) WHEN 'array' THEN series.attributes -> {key} \
+            ELSE jsonb_build_array(series.attributes -> {key}) END) AS value WHERE \
+            {value_condition})"
+        )
+    }
+
+    #[test]
+    fn test_universal_conditions() {
+        assert_eq!(("TRUE".to_owned(), Vec::new()), conditions(&[]));
+        assert_eq!(
+            ("TRUE".to_owned(), Vec::new()),
+            conditions(&[
+                (PATIENT_ID_KEY, Matcher::parse(&vr::LO, "")),
+                (PATIENT_NAME_KEY, Matcher::parse(&vr::PN, "*")),
+                (STUDY_DATE_KEY, Matcher::parse(&vr::DA, "-")),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_single_conditions() {
+        assert_eq!(
+            (
+                key_condition("$2", "value = $1"),
+                vec!["PAT1".to_owned(), PATIENT_ID_KEY.to_owned()]
+            ),
+            conditions(&[(PATIENT_ID_KEY, Matcher::parse(&vr::LO, "PAT1 "))])
+        );
+        assert_eq!(
+            (
+                key_condition("$2", "lower(value) = lower($1)"),
+                vec!["Doe^John".to_owned(), PATIENT_NAME_KEY.to_owned()]
+            ),
+            conditions(&[(PATIENT_NAME_KEY, Matcher::parse(&vr::PN, "Doe^John"))])
+        );
+    }
+
+    #[test]
+    fn test_wildcard_conditions() {
+        assert_eq!(
+            (
+                key_condition("$2", "value LIKE $1"),
+                vec!["A\\%1_%".to_owned(), ACCESSION_KEY.to_owned()]
+            ),
+            conditions(&[(ACCESSION_KEY, Matcher::parse(&vr::SH, "A%1?*"))])
+        );
+        assert_eq!(
+            (
+                key_condition("$2", "value ILIKE $1"),
+                vec!["doe^%".to_owned(), PATIENT_NAME_KEY.to_owned()]
+            ),
+            conditions(&[(PATIENT_NAME_KEY, Matcher::parse(&vr::PN, "doe^*"))])
+        );
+        assert_eq!("a\\\\b\\_c", wildcard_to_like("a\\b_c"));
+    }
+
+    #[test]
+    fn test_range_conditions() {
+        assert_eq!(
+            (
+                key_condition(
+                    "$3",
+                    "value COLLATE \"C\" >= $1 AND value COLLATE \"C\" <= $2"
+                ),
+                vec![
+                    "20230101".to_owned(),
+                    "20231231".to_owned(),
+                    STUDY_DATE_KEY.to_owned()
+                ]
+            ),
+            conditions(&[(STUDY_DATE_KEY, Matcher::parse(&vr::DA, "20230101-20231231"))])
+        );
+        // The end of a time range includes the whole of its last minute.
+        assert_eq!(
+            (
+                key_condition("$2", "value COLLATE \"C\" <= $1"),
+                vec!["093059.999999".to_owned(), STUDY_TIME_KEY.to_owned()]
+            ),
+            conditions(&[(STUDY_TIME_KEY, Matcher::parse(&vr::TM, "-0930"))])
+        );
+    }
+
+    #[test]
+    fn test_list_conditions() {
+        let (sql, params) = conditions(&[
+            (MODALITY_KEY, Matcher::parse(&vr::CS, "CT\\MR")),
+            (PATIENT_ID_KEY, Matcher::parse(&vr::LO, "PAT\u{0}1")),
+        ]);
+        assert_eq!(
+            format!(
+                "{} AND {}",
+                key_condition("$3", "(value = $1 OR value = $2)"),
+                key_condition("$5", "value = $4")
+            ),
+            sql
+        );
+        // NULL characters aren't allowed within text parameters.
+        assert_eq!(
+            vec!["CT", "MR", MODALITY_KEY, "PAT1", PATIENT_ID_KEY],
+            params
+        );
+        // A universal value within a list matches everything.
+        assert_eq!(
+            ("TRUE".to_owned(), Vec::new()),
+            conditions(&[(MODALITY_KEY, Matcher::parse(&vr::CS, "CT\\*"))])
+        );
+    }
+
+    /// Migrates a new schema of the database given by `DCMPIPE_TEST_POSTGRES_URI`, which must not
+    /// have query parameters, and queries it.
+    #[test]
+    #[ignore = "requires a PostgreSQL database, given by DCMPIPE_TEST_POSTGRES_URI"]
+    fn test_migrate_and_query() -> Result<()> {
+        let uri: String = std::env::var("DCMPIPE_TEST_POSTGRES_URI")?;
+        let schema: String = format!("dcmpipe_test_{}", std::process::id());
+        let mut admin: Client = Client::connect(&uri, NoTls)?;
+        admin.batch_execute(&format!("CREATE SCHEMA {schema}"))?;
+        let result: Result<()> =
+            migrate_and_query(&format!("{uri}?options=-csearch_path%3D{schema}"));
+        admin.batch_execute(&format!("DROP SCHEMA {schema} CASCADE"))?;
+        result
+    }
+
+    fn migrate_and_query(uri: &str) -> Result<()> {
+        let mut index: PgIndex = PgIndex::connect(uri)?;
+        // Connecting to a migrated index leaves it as-is.
+        let mut index_again: PgIndex = PgIndex::connect(uri)?;
+        let versions: Vec<i32> = index_again
+            .client
+            .query(
+                "SELECT version FROM schema_migrations ORDER BY version",
+                &[],
+            )?
+            .iter()
+            .map(|row| row.get(0))
+            .collect();
+        assert_eq!(vec![1, 2], versions);
+
+        index.client.batch_execute(
+            r#"
+            INSERT INTO series (series_uid, attributes) VALUES
+                ('1.1', '{"00080060": "CT", "00100010": "Doe^John", "00080020": "20230102"}'),
+                ('1.2', '{"00080060": ["MR", "PT"], "00100010": "Roe^Jane"}');
+            INSERT INTO instances (path, series_uid) VALUES
+                ('/ct/1.dcm', '1.1'), ('/ct/2.dcm', '1.1'), ('/mr/1.dcm', '1.2');
+            "#,
+        )?;
+        let query = |index: &mut PgIndex, keys: &[(&str, &str, vr::VRRef)]| {
+            let keys: Vec<(String, Matcher)> = keys
+                .iter()
+                .map(|(key, value, vr)| ((*key).to_owned(), Matcher::parse(vr, value)))
+                .collect();
+            index.query_instances(&keys)
+        };
+        assert_eq!(
+            vec!["/ct/1.dcm", "/ct/2.dcm", "/mr/1.dcm"],
+            query(&mut index, &[])?
+        );
+        assert_eq!(
+            vec!["/mr/1.dcm"],
+            query(&mut index, &[(MODALITY_KEY, "PT", &vr::CS)])?
+        );
+        assert_eq!(
+            vec!["/ct/1.dcm", "/ct/2.dcm"],
+            query(&mut index, &[(PATIENT_NAME_KEY, "DOE*", &vr::PN)])?
+        );
+        assert_eq!(
+            vec!["/ct/1.dcm", "/ct/2.dcm"],
+            query(
+                &mut index,
+                &[
+                    (STUDY_DATE_KEY, "20230101-20230131", &vr::DA),
+                    (MODALITY_KEY, "CT\\MR", &vr::CS)
+                ]
+            )?
+        );
+        assert!(query(&mut index, &[(STUDY_DATE_KEY, "-20221231", &vr::DA)])?.is_empty());
+        Ok(())
+    }
+}
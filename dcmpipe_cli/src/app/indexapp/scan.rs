@@ -0,0 +1,298 @@
+//! Scanning of folders for `index scan`, reading files on worker threads.
+//!
+//! Entries are listed on the calling thread, which reads those within archives into memory, and
+//! parsed by the workers. The queue of entries is bounded so listing doesn't get far ahead of
+//! parsing. Parsed entries are merged into the series records in the order they're listed, so the
+//! records don't depend on which worker finishes first.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::File,
+    path::PathBuf,
+    sync::{
+        mpsc::{self, Receiver, Sender, SyncSender},
+        Mutex,
+    },
+    thread,
+};
+
+use anyhow::{anyhow, Context, Result};
+use bson::{doc, Array, Document};
+use dcmpipe_lib::{
+    core::{
+        dcmelement::DicomElement,
+        dcmobject::{DicomObject, DicomRoot},
+        read::{stop::ParseStop, Parser, ParserBuilder},
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags},
+    progress::ProgressReporter,
+    source::{Entries, EntryReader, Source, SourceEntry},
+};
+
+use crate::{
+    app::{pathref::encode_pathref, progress::ProgressBar},
+    shutdown,
+};
+
+use super::{drift::checksum, insert_elem_entry, AttributeFilter, DicomDoc, ScannedInstance};
+
+/// An entry of the folder, by its position in the listing.
+type QueuedEntry = (usize, SourceEntry);
+
+/// The result of parsing an entry, by its position in the listing. Files which aren't DICOM are
+/// `None`.
+type ParsedEntry = (usize, Result<Option<ScannedFile>>);
+
+/// The indexed attributes of a file.
+struct ScannedFile {
+    series_key: String,
+    fields: Document,
+    instance: ScannedInstance,
+}
+
+/// Merges parsed entries into the series records in the order they were listed.
+struct Merger {
+    uid_to_doc: HashMap<String, DicomDoc>,
+    /// Entries parsed ahead of an entry listed before them.
+    pending: BTreeMap<usize, Result<Option<ScannedFile>>>,
+    /// The position of the next entry to merge.
+    next: usize,
+    /// The first failure to parse an entry, after which no more entries are merged.
+    failure: Option<anyhow::Error>,
+}
+
+/// Scans the folder, including zip and tar archives within it, returning the series records by
+/// their key. Files are parsed on `concurrency` worker threads, and with `checksums` their
+/// checksums are computed. A failure to parse a file stops the scan, while a shutdown request stops
+/// it with the records of the files scanned so far.
+pub(super) fn scan_dir(
+    folder: PathBuf,
+    mut progress: ProgressBar,
+    filter: &AttributeFilter,
+    concurrency: usize,
+    checksums: bool,
+) -> Result<HashMap<String, DicomDoc>> {
+    // Files within zip and tar archives are indexed by the path of the archive joined with
+    // their path within it.
+    let mut entries: Entries = Source::new(folder).entries()?;
+    let (queue, queued) = mpsc::sync_channel::<QueuedEntry>(concurrency * 2);
+    let queued: Mutex<Receiver<QueuedEntry>> = Mutex::new(queued);
+    let (parsed_sender, parsed) = mpsc::channel::<ParsedEntry>();
+    let mut merger: Merger = Merger {
+        uid_to_doc: HashMap::new(),
+        pending: BTreeMap::new(),
+        next: 0,
+        failure: None,
+    };
+
+    thread::scope(|scope| {
+        for _ in 0..concurrency {
+            let parsed_sender: Sender<ParsedEntry> = parsed_sender.clone();
+            let queued: &Mutex<Receiver<QueuedEntry>> = &queued;
+            scope.spawn(move || parse_entries(queued, filter, checksums, parsed_sender));
+        }
+        drop(parsed_sender);
+
+        list_entries(&mut entries, &mut progress, queue, &parsed, &mut merger);
+        for (position, scanned) in parsed {
+            merger.merge(position, scanned);
+        }
+    });
+    progress.finish(entries.progress());
+
+    match merger.failure {
+        Some(e) => Err(e),
+        None => Ok(merger.uid_to_doc),
+    }
+}
+
+/// Queues the entries for the workers, merging those parsed as it goes, until all are queued, a
+/// shutdown is requested, or an entry fails to parse.
+fn list_entries(
+    entries: &mut Entries,
+    progress: &mut ProgressBar,
+    queue: SyncSender<QueuedEntry>,
+    parsed: &Receiver<ParsedEntry>,
+    merger: &mut Merger,
+) {
+    let mut position: usize = 0;
+    while let Some(entry) = entries.next() {
+        progress.report(entries.progress());
+        let entry: SourceEntry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                let e: anyhow::Error = e.into();
+                progress.println(format_args!("Skipping unreadable archive: {e:#}"));
+                tracing::warn!(error = format!("{e:#}"), "skipped unreadable archive");
+                continue;
+            }
+        };
+        if shutdown::is_requested() {
+            progress.println(format_args!(
+                "Scan interrupted before: {}",
+                entry.path().display()
+            ));
+            break;
+        }
+
+        if queue.send((position, entry)).is_err() {
+            break;
+        }
+        position += 1;
+        for (position, scanned) in parsed.try_iter() {
+            merger.merge(position, scanned);
+        }
+        if merger.failure.is_some() {
+            break;
+        }
+    }
+}
+
+/// Parses entries from the queue until it's closed.
+fn parse_entries(
+    queued: &Mutex<Receiver<QueuedEntry>>,
+    filter: &AttributeFilter,
+    checksums: bool,
+    parsed: Sender<ParsedEntry>,
+) {
+    let parser_builder: ParserBuilder<'_> = ParserBuilder::default()
+        .stop(ParseStop::BeforeTagValue(tags::PixelData.tag.into()))
+        .dictionary(&STANDARD_DICOM_DICTIONARY);
+    loop {
+        // Another worker panicking while holding the lock leaves the queue usable.
+        let next: Option<QueuedEntry> = match queued.lock() {
+            Ok(queued) => queued.recv().ok(),
+            Err(poisoned) => poisoned.into_inner().recv().ok(),
+        };
+        let Some((position, entry)) = next else {
+            break;
+        };
+        let scanned: Result<Option<ScannedFile>> =
+            scan_entry(&parser_builder, &entry, filter, checksums);
+        if parsed.send((position, scanned)).is_err() {
+            break;
+        }
+    }
+}
+
+/// Parses the entry up to its Pixel Data, returning `None` if it isn't DICOM.
+fn scan_entry(
+    parser_builder: &ParserBuilder<'_>,
+    entry: &SourceEntry,
+    filter: &AttributeFilter,
+    checksums: bool,
+) -> Result<Option<ScannedFile>> {
+    let mut parser: Parser<'_, EntryReader<'_>> = parser_builder.build(entry.open()?);
+    let Some(dcm_root) = DicomRoot::parse(&mut parser)? else {
+        return Ok(None);
+    };
+
+    let uid_obj: &DicomObject = dcm_root
+        .get_child_by_tag(tags::SeriesInstanceUID.tag)
+        .or_else(|| dcm_root.get_child_by_tag(tags::SOPInstanceUID.tag))
+        .ok_or_else(|| {
+            anyhow!(
+                "DICOM file has no SeriesInstanceUID or SOPInstanceUID: {:?}",
+                entry.path().display()
+            )
+        })?;
+    let series_key: String = uid_obj.element().try_into()?;
+    let instance_number: i64 = dcm_root
+        .get_child_by_tag(tags::InstanceNumber.tag)
+        .and_then(|obj| String::try_from(obj.element()).ok())
+        .and_then(|number| number.trim().parse::<i64>().ok())
+        .unwrap_or_default();
+    let sop_uid: Option<String> = dcm_root
+        .get_child_by_tag(tags::SOPInstanceUID.tag)
+        .and_then(|obj| String::try_from(obj.element()).ok());
+
+    // The checksum is of the file as stored, rather than as decompressed for parsing.
+    let sha256: Option<String> = if checksums {
+        let sha256 = match entry.bytes() {
+            Some(bytes) => checksum(bytes),
+            None => File::open(entry.path()).and_then(checksum),
+        };
+        Some(sha256.with_context(|| format!("Failed to read: {}", entry.path().display()))?)
+    } else {
+        None
+    };
+
+    let mut fields: Document = Document::new();
+    for (_child_tag, child_obj) in dcm_root.iter_child_nodes() {
+        let child_elem: &DicomElement = child_obj.element();
+        if child_elem.is_seq_like() {
+            // TODO: handle sequences
+        } else if filter.is_indexed(child_elem.tag()) {
+            insert_elem_entry(child_elem, filter, &mut fields)?;
+        }
+    }
+
+    Ok(Some(ScannedFile {
+        series_key,
+        fields,
+        instance: ScannedInstance {
+            path: entry.path().to_path_buf(),
+            instance_number,
+            sop_uid,
+            sha256,
+            archived: entry.is_archived(),
+        },
+    }))
+}
+
+impl Merger {
+    /// Merges the parsed entry at the position, along with those parsed ahead of it.
+    fn merge(&mut self, position: usize, scanned: Result<Option<ScannedFile>>) {
+        self.pending.insert(position, scanned);
+        while let Some(scanned) = self.pending.remove(&self.next) {
+            self.next += 1;
+            if self.failure.is_some() {
+                continue;
+            }
+            match scanned {
+                Ok(Some(file)) => {
+                    if let Err(e) = self.merge_file(file) {
+                        self.failure = Some(e);
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => self.failure = Some(e),
+            }
+        }
+    }
+
+    /// Adds the file to its series record, its attributes replacing those of files listed before.
+    fn merge_file(&mut self, file: ScannedFile) -> Result<()> {
+        let dicom_doc: &mut DicomDoc = self
+            .uid_to_doc
+            .entry(file.series_key.clone())
+            .or_insert_with(|| DicomDoc::new(file.series_key.clone()));
+
+        let metadata_doc: &mut Document = dicom_doc
+            .doc
+            .entry("metadata".to_owned())
+            .or_insert_with(|| Document::new().into())
+            .as_document_mut()
+            .ok_or_else(|| anyhow!("Field failure: metadata"))?;
+        let files_field: &mut Array = metadata_doc
+            .entry("files".to_owned())
+            .or_insert_with(|| Vec::<String>::new().into())
+            .as_array_mut()
+            .ok_or_else(|| anyhow!("Field failure: metadata.files"))?;
+        let pathref: String = encode_pathref(&file.instance.path);
+        files_field.push(pathref.clone().into());
+        if let Some(sha256) = &file.instance.sha256 {
+            let checksums_field: &mut Array = metadata_doc
+                .entry("checksums".to_owned())
+                .or_insert_with(|| Vec::<Document>::new().into())
+                .as_array_mut()
+                .ok_or_else(|| anyhow!("Field failure: metadata.checksums"))?;
+            checksums_field.push(doc! { "file": pathref, "sha256": sha256 }.into());
+        }
+        metadata_doc.insert("serieskey", file.series_key);
+
+        dicom_doc.doc.extend(file.fields);
+        dicom_doc.instances.push(file.instance);
+        Ok(())
+    }
+}
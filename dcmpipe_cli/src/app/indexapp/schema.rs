@@ -0,0 +1,84 @@
+//! Versioning of the index schema, so that indexes created by earlier versions of dcmpipe are
+//! upgraded in place rather than rescanned.
+//!
+//! The schema's version is the number of migrations applied to the index, each recorded in
+//! `schema_migrations` along with when it was applied. Migrations which haven't been applied are
+//! applied in order as the index is opened. Migrations are only ever appended, and those of
+//! MongoDB indexes must be safe to apply more than once, as there's no way to prevent two commands
+//! from migrating the same index at once.
+
+use anyhow::{anyhow, Context, Result};
+use bson::{doc, DateTime, Document};
+use mongodb::{
+    options::{FindOneOptions, ReplaceOptions},
+    sync::{Collection, Database},
+    IndexModel,
+};
+
+use super::{COLLECTION_NAME, MONGO_ID_KEY, SERIES_UID_KEY, STUDY_UID_KEY};
+
+static MIGRATION_COLLECTION_NAME: &str = "schema_migrations";
+
+/// A migration of a MongoDB index, with a description of what it changes.
+type MongoMigration = (&'static str, fn(&Database) -> Result<()>);
+
+/// The migrations of MongoDB indexes, in order. Indexes created before versioning was introduced
+/// are version 0.
+static MONGO_MIGRATIONS: [MongoMigration; 1] =
+    [("index series records by their keys", index_series_keys)];
+
+/// Migrates the MongoDB index to the current schema.
+pub(super) fn migrate_mongo(database: &Database) -> Result<()> {
+    let migration_coll: Collection<Document> = database.collection(MIGRATION_COLLECTION_NAME);
+    let options: FindOneOptions = FindOneOptions::builder()
+        .sort(doc! { MONGO_ID_KEY: -1 })
+        .build();
+    let version: i32 = match migration_coll.find_one(None, options)? {
+        Some(latest) => latest.get_i32(MONGO_ID_KEY)?,
+        None => 0,
+    };
+    let version: usize = check_version(version, MONGO_MIGRATIONS.len())?;
+
+    let options: ReplaceOptions = ReplaceOptions::builder().upsert(true).build();
+    for (number, (description, migration)) in MONGO_MIGRATIONS.iter().enumerate().skip(version) {
+        let number: i32 = i32::try_from(number + 1)?;
+        migration(database)
+            .with_context(|| format!("Failed to migrate the index to version {number}"))?;
+        migration_coll.replace_one(
+            doc! { MONGO_ID_KEY: number },
+            doc! {
+                MONGO_ID_KEY: number,
+                "description": description,
+                "applied": DateTime::now(),
+            },
+            options.clone(),
+        )?;
+        report_migration(number, description);
+    }
+    Ok(())
+}
+
+/// Checks that this version of dcmpipe supports the schema version of an index, whose latest
+/// version is `latest`.
+pub(super) fn check_version(version: i32, latest: usize) -> Result<usize> {
+    match usize::try_from(version) {
+        Ok(version) if version <= latest => Ok(version),
+        _ => Err(anyhow!(
+            "The index schema is version {version}, newer than this version of dcmpipe supports"
+        )),
+    }
+}
+
+pub(super) fn report_migration(number: i32, description: &str) {
+    println!("Migrated the index to version {number}: {description}");
+}
+
+/// Version 1: the series records are looked up by their key when scanning, and by their study when
+/// aggregating or answering queries. Creating an index which exists is a no-op.
+fn index_series_keys(database: &Database) -> Result<()> {
+    let dicom_coll: Collection<Document> = database.collection(COLLECTION_NAME);
+    for key in ["metadata.serieskey", SERIES_UID_KEY, STUDY_UID_KEY] {
+        dicom_coll.create_index(IndexModel::builder().keys(doc! { key: 1 }).build(), None)?;
+    }
+    Ok(())
+}
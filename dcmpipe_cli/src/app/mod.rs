@@ -1,41 +1,141 @@
-use std::{fmt, fs::File, iter::Peekable, path::Path};
+use std::{
+    error::Error,
+    fmt,
+    io::Read,
+    iter::Peekable,
+    net::{SocketAddr, TcpStream, ToSocketAddrs},
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, Context, Result};
 use dcmpipe_lib::{
     core::{
+        compressed::DatasetFile,
         dcmelement::DicomElement,
-        defn::{dcmdict::DicomDictionary, tag::Tag},
+        defn::{
+            dcmdict::DicomDictionary,
+            tag::{Tag, TagPath},
+            vr::{self, VRRef, VR},
+        },
         read::{ParseError, Parser, ParserBuilder},
+        values::Attribute,
         RawValue,
     },
-    dict::stdlookup::STANDARD_DICOM_DICTIONARY,
+    dict::{code_sets, stdlookup::STANDARD_DICOM_DICTIONARY, tags},
+    dimse::assoc::{Association, AssociationBuilder},
+    source::{EntryReader, SourceEntry},
 };
+use rand::{rngs::OsRng, Rng};
+
+use crate::{args::RemoteArgs, config::Config};
 
+pub(crate) mod anonapp;
 pub(crate) mod archiveapp;
 pub(crate) mod browseapp;
+pub(crate) mod createapp;
+pub(crate) mod dedupapp;
+pub(crate) mod echoapp;
+pub(crate) mod editapp;
+pub(crate) mod formatter;
+pub(crate) mod imageapp;
 #[cfg(feature = "index")]
 pub(crate) mod indexapp;
+pub(crate) mod organizeapp;
+pub(crate) mod pathref;
 pub(crate) mod printapp;
+pub(crate) mod progress;
+pub(crate) mod refsapp;
+pub(crate) mod render;
+pub(crate) mod routing;
 pub(crate) mod scanapp;
+pub(crate) mod sendapp;
+pub(crate) mod serveapp;
+pub(crate) mod statsapp;
+pub(crate) mod tagsapp;
+pub(crate) mod videoapp;
+pub(crate) mod volumeapp;
+pub(crate) mod watchapp;
 
 static MAX_ITEMS_DISPLAYED: usize = 16;
 
+/// The attributes of the Code Sequence Macro which may be present in items rendered inline by
+/// `format_code_items()`.
+static CODE_ITEM_TAGS: [&Tag; 15] = [
+    &tags::CodeValue,
+    &tags::CodingSchemeDesignator,
+    &tags::CodingSchemeVersion,
+    &tags::CodeMeaning,
+    &tags::LongCodeValue,
+    &tags::URNCodeValue,
+    &tags::ContextIdentifier,
+    &tags::ContextUID,
+    &tags::MappingResource,
+    &tags::MappingResourceUID,
+    &tags::MappingResourceName,
+    &tags::ContextGroupVersion,
+    &tags::ContextGroupExtensionFlag,
+    &tags::ContextGroupLocalVersion,
+    &tags::ContextGroupExtensionCreatorUID,
+];
+
+/// The AE Title used by network commands when none is given on the command-line or in the
+/// configuration.
+pub(crate) static DEFAULT_AE_TITLE: &str = "DCMPIPE";
+
+/// Maximum length of an ErrorComment, which has a VR of LO.
+pub(crate) const MAX_ERROR_COMMENT_LEN: usize = 64;
+
+/// How often servers check for a shutdown request while waiting for an association.
+pub(crate) const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How long servers keep an idle association open before it's dropped.
+pub(crate) const ASSOCIATION_TIMEOUT: Duration = Duration::from_secs(60);
+
 pub(crate) trait CommandApplication {
     fn run(&mut self) -> Result<()>;
 }
 
-fn parse_file(path: &Path, allow_partial_object: bool) -> Result<Parser<'_, File>> {
+/// Generates a new `2.25` UID from a version 4 (random) UUID as a single integer, per Part 5,
+/// Annex B.2.
+pub(crate) fn generate_uid() -> String {
+    let mut uuid: u128 = OsRng.gen();
+    uuid &= !(0xF << 76);
+    uuid |= 0x4 << 76;
+    uuid &= !(0x3 << 62);
+    uuid |= 0x2 << 62;
+    format!("2.25.{uuid}")
+}
+
+fn parse_file(path: &Path, allow_partial_object: bool) -> Result<Parser<'static, DatasetFile>> {
     if !path.is_file() {
         return Err(anyhow!("invalid file: {}", path.display()));
     }
 
-    let file: File = File::open(path)?;
-    let mut parser: Parser<'_, File> = ParserBuilder::default()
+    let file: DatasetFile = DatasetFile::open(path)?;
+    let parser: Parser<'_, DatasetFile> = ParserBuilder::default()
         .allow_partial_object(allow_partial_object)
         .dictionary(&STANDARD_DICOM_DICTIONARY)
         .build(file);
+    check_dicom(parser, path)
+}
+
+/// Like `parse_file()`, for an entry of a source which may be within an archive.
+fn parse_entry(
+    entry: &SourceEntry,
+    allow_partial_object: bool,
+) -> Result<Parser<'_, EntryReader<'_>>> {
+    let parser: Parser<'_, EntryReader<'_>> = ParserBuilder::default()
+        .allow_partial_object(allow_partial_object)
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .build(entry.open()?);
+    check_dicom(parser, entry.path())
+}
 
-    let mut peeker: Peekable<&mut Parser<'_, File>> = parser.by_ref().peekable();
+/// Checks that the first element can be parsed, failing if the file is not DICOM or is empty.
+fn check_dicom<'d, R: Read>(mut parser: Parser<'d, R>, path: &Path) -> Result<Parser<'d, R>> {
+    let mut peeker: Peekable<&mut Parser<'d, R>> = parser.by_ref().peekable();
 
     let first: Option<&Result<DicomElement, ParseError>> = peeker.peek();
     if let Some(Err(_)) = first {
@@ -47,6 +147,44 @@ fn parse_file(path: &Path, allow_partial_object: bool) -> Result<Parser<'_, File
     Ok(parser)
 }
 
+/// The AE Title network commands identify themselves with.
+fn calling_ae<'a>(remote: &'a RemoteArgs, config: &'a Config) -> &'a str {
+    remote
+        .calling_ae
+        .as_deref()
+        .or(config.aetitle.as_deref())
+        .unwrap_or(DEFAULT_AE_TITLE)
+}
+
+/// Resolves the address of the remote AE.
+fn resolve_remote(remote: &RemoteArgs) -> Result<SocketAddr> {
+    (remote.host.as_str(), remote.port)
+        .to_socket_addrs()
+        .with_context(|| format!("Failed to resolve host: {}", remote.host))?
+        .next()
+        .ok_or_else(|| anyhow!("No address for host: {}", remote.host))
+}
+
+/// Connects to the remote AE at `addr` and requests an association, applying the timeouts given on
+/// the command-line to the connection.
+fn associate_remote(
+    remote: &RemoteArgs,
+    addr: &SocketAddr,
+    assoc_builder: &AssociationBuilder,
+) -> Result<(Association, TcpStream)> {
+    let mut stream: TcpStream =
+        TcpStream::connect_timeout(addr, Duration::from_secs(remote.connect_timeout))
+            .with_context(|| format!("Failed to connect to {addr}"))?;
+    let timeout: Option<Duration> = Some(Duration::from_secs(remote.timeout));
+    stream.set_read_timeout(timeout)?;
+    stream.set_write_timeout(timeout)?;
+    let assoc: Association = assoc_builder
+        .request(&remote.called_ae, &mut stream)
+        .with_context(|| format!("Association with {} failed", remote.called_ae))?;
+    Ok((assoc, stream))
+}
+
+#[allow(dead_code)]
 pub(crate) enum TagCategory {
     Known(u32, String),
     PrivateCreator(u32),
@@ -57,7 +195,7 @@ pub(crate) enum TagCategory {
     Unknown(u32),
 }
 
-impl From<&DicomElement> for TagCategory {
+impl From<&DicomElement<'_>> for TagCategory {
     fn from(element: &DicomElement) -> Self {
         if let Some(tag) = STANDARD_DICOM_DICTIONARY.get_tag_by_number(element.tag()) {
             TagCategory::Known(tag.tag, tag.ident.to_string())
@@ -91,12 +229,13 @@ impl fmt::Display for TagCategory {
     }
 }
 
-pub(crate) struct ElementWithLineFmt<'elem>(pub &'elem DicomElement, pub bool);
+pub(crate) struct ElementWithLineFmt<'elem>(pub &'elem DicomElement<'elem>, pub bool);
 
 pub(crate) enum TagValue {
     Sequence,
     Error(String),
     Uid(String, String),
+    Readable(String, String),
     Stringified(String),
 }
 
@@ -112,6 +251,7 @@ impl fmt::Display for TagValue {
                     write!(f, "{} => {}", uid, name)
                 }
             }
+            TagValue::Readable(value, readable) => write!(f, "{} => {}", value, readable),
             TagValue::Stringified(value) => write!(f, "{}", value),
         }
     }
@@ -155,6 +295,21 @@ impl<'elem> From<ElementWithLineFmt<'elem>> for TagValue {
                 if multiline {
                     sep = "\n";
                 }
+                if strings
+                    .iter()
+                    .any(|val| readable_value(elem.vr(), val).is_some())
+                {
+                    let (add_ellipses, mut str_vals) = format_vec_to_strings(strings, |val| val);
+                    let mut readable: Vec<String> = str_vals
+                        .iter()
+                        .map(|val| readable_value(elem.vr(), val).unwrap_or_else(|| val.clone()))
+                        .collect();
+                    if add_ellipses {
+                        str_vals.push("..".to_string());
+                        readable.push("..".to_string());
+                    }
+                    return TagValue::Readable(str_vals.join(sep), readable.join(sep));
+                }
                 format_vec_to_strings(strings, |val: String| {
                     if !multiline {
                         val.replace("\r\n", " / ").replace('\n', " / ")
@@ -195,6 +350,7 @@ impl<'elem> From<ElementWithLineFmt<'elem>> for TagValue {
             RawValue::QuadWords(qwords) => {
                 format_vec_to_strings(qwords, |val: u64| format!("{:08x}", val))
             }
+            RawValue::BulkData(bulk_data) => (false, vec![bulk_data.to_string()]),
         };
 
         if add_ellipses {
@@ -210,6 +366,141 @@ impl<'elem> From<ElementWithLineFmt<'elem>> for TagValue {
     }
 }
 
+/// Renders a value of a person name, date, time, or date time in a human-readable form, or `None`
+/// if the value is of another VR or is already readable as encoded.
+///
+/// Person names are written out as `Prefix Given Middle Family, Suffix` using their alphabetic
+/// representation, dates as `YYYY-MM-DD`, and times as `HH:MM:SS.FFFFFF`.
+fn readable_value(vr: VRRef, value: &str) -> Option<String> {
+    let readable: Option<String> = if vr == &vr::PN {
+        readable_person_name(value)
+    } else if vr == &vr::DA {
+        readable_date(value)
+    } else if vr == &vr::TM {
+        readable_time(value)
+    } else if vr == &vr::DT {
+        readable_datetime(value)
+    } else {
+        None
+    };
+    readable.filter(|readable| readable != value)
+}
+
+fn readable_person_name(value: &str) -> Option<String> {
+    let alphabetic: &str = value.split('=').next().unwrap_or_default();
+    let mut components = alphabetic.split('^').map(str::trim);
+    let family: &str = components.next().unwrap_or_default();
+    let given: &str = components.next().unwrap_or_default();
+    let middle: &str = components.next().unwrap_or_default();
+    let prefix: &str = components.next().unwrap_or_default();
+    let suffix: &str = components.next().unwrap_or_default();
+
+    let name: String = [prefix, given, middle, family]
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ");
+    if name.is_empty() {
+        None
+    } else if suffix.is_empty() {
+        Some(name)
+    } else {
+        Some(format!("{name}, {suffix}"))
+    }
+}
+
+fn readable_date(value: &str) -> Option<String> {
+    if value.len() != 8 || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some(format!("{}-{}-{}", &value[..4], &value[4..6], &value[6..]))
+}
+
+fn readable_time(value: &str) -> Option<String> {
+    let (hhmmss, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if !matches!(hhmmss.len(), 2 | 4 | 6) || !hhmmss.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let mut time: String = hhmmss
+        .as_bytes()
+        .chunks(2)
+        .map(|part| String::from_utf8_lossy(part))
+        .collect::<Vec<_>>()
+        .join(":");
+    if !fraction.is_empty() {
+        time.push('.');
+        time.push_str(fraction);
+    }
+    Some(time)
+}
+
+fn readable_datetime(value: &str) -> Option<String> {
+    let (datetime, offset) = match value.find(['+', '-']) {
+        Some(index) => value.split_at(index),
+        None => (value, ""),
+    };
+    if datetime.len() < 8 || !datetime.is_char_boundary(8) {
+        return None;
+    }
+    let (date, time) = datetime.split_at(8);
+    let mut readable: String = readable_date(date)?;
+    if !time.is_empty() {
+        readable.push(' ');
+        readable.push_str(&readable_time(time)?);
+    }
+    if !offset.is_empty() {
+        if offset.len() != 5 {
+            return None;
+        }
+        readable.push_str(&format!(" {}:{}", &offset[..3], &offset[3..]));
+    }
+    Some(readable)
+}
+
+/// Renders the items of a Code Sequence inline as `(value, scheme, "meaning")`, separated by `\`.
+/// The meaning is looked up from the known context groups if an item has none. Returns `None` if
+/// there are no items or if any item has attributes other than those of the Code Sequence Macro,
+/// in which case the sequence should be rendered as usual.
+pub(crate) fn format_code_items(items: &[Vec<&DicomElement<'_>>]) -> Option<String> {
+    if items.is_empty() {
+        return None;
+    }
+    let mut codes: Vec<String> = Vec::with_capacity(items.len());
+    for item in items {
+        let mut value: Option<String> = None;
+        let mut scheme: Option<String> = None;
+        let mut meaning: Option<String> = None;
+        for element in item {
+            let tag: u32 = element.tag();
+            if tag == tags::ItemDelimitationItem.tag || tag == tags::SequenceDelimitationItem.tag {
+                continue;
+            }
+            if !CODE_ITEM_TAGS.iter().any(|code_tag| code_tag.tag == tag) {
+                return None;
+            }
+            let text = || String::try_from(*element).ok();
+            if tag == tags::CodeValue.tag
+                || tag == tags::LongCodeValue.tag
+                || tag == tags::URNCodeValue.tag
+            {
+                value = text();
+            } else if tag == tags::CodingSchemeDesignator.tag {
+                scheme = text();
+            } else if tag == tags::CodeMeaning.tag {
+                meaning = text();
+            }
+        }
+
+        let (value, scheme) = (value?, scheme?);
+        let meaning: String = meaning
+            .filter(|meaning| !meaning.is_empty())
+            .or_else(|| code_sets::get_code_meaning(&scheme, &value).map(str::to_owned))
+            .unwrap_or_default();
+        codes.push(format!("({value}, {scheme}, \"{meaning}\")"));
+    }
+    Some(codes.join("\\"))
+}
+
 /// Formats `vec` converting each element to a String based on the given `func`.
 /// Returns true if the input `vec` had more items than rendered, based on `MAX_ITEMS_DISPLAYED`.
 fn format_vec_to_strings<T, F: Fn(T) -> String>(vec: Vec<T>, func: F) -> (bool, Vec<String>) {
@@ -221,3 +512,97 @@ fn format_vec_to_strings<T, F: Fn(T) -> String>(vec: Vec<T>, func: F) -> (bool,
         .collect::<Vec<String>>();
     (formatted.len() < vec_len, formatted)
 }
+
+/// Parses text, e.g. entered by the user, into a value for the VR, with multiple values separated by
+/// `\`. Empty text results in an empty value.
+pub(crate) fn parse_input_value(vr: VRRef, input: &str) -> Result<RawValue> {
+    if input.is_empty() {
+        return Ok(RawValue::Bytes(Vec::new()));
+    }
+
+    if vr == &vr::AT {
+        let attrs: Result<Vec<Attribute>> = input
+            .split('\\')
+            .map(|value| {
+                Tag::parse_tag(value, Some(&STANDARD_DICOM_DICTIONARY))
+                    .map(Attribute)
+                    .map_err(|_e| anyhow!("Invalid tag: {value}"))
+            })
+            .collect();
+        Ok(RawValue::Attribute(attrs?))
+    } else if vr.is_character_string {
+        Ok(RawValue::Strings(
+            input.split('\\').map(str::to_owned).collect(),
+        ))
+    } else if vr == &vr::SS {
+        Ok(RawValue::Shorts(parse_numbers(input)?))
+    } else if vr == &vr::US {
+        Ok(RawValue::UnsignedShorts(parse_numbers(input)?))
+    } else if vr == &vr::SL {
+        Ok(RawValue::Integers(parse_numbers(input)?))
+    } else if vr == &vr::UL {
+        Ok(RawValue::UnsignedIntegers(parse_numbers(input)?))
+    } else if vr == &vr::SV {
+        Ok(RawValue::Longs(parse_numbers(input)?))
+    } else if vr == &vr::UV {
+        Ok(RawValue::UnsignedLongs(parse_numbers(input)?))
+    } else if vr == &vr::FL {
+        Ok(RawValue::Floats(parse_numbers(input)?))
+    } else if vr == &vr::FD {
+        Ok(RawValue::Doubles(parse_numbers(input)?))
+    } else {
+        Err(anyhow!("Values of VR {} can't be entered", vr.ident))
+    }
+}
+
+pub(crate) fn parse_numbers<T>(input: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    input
+        .split('\\')
+        .map(|value| {
+            value
+                .trim()
+                .parse::<T>()
+                .with_context(|| format!("Invalid value: {value}"))
+        })
+        .collect()
+}
+
+/// Parses a VR from its two-letter identifier, e.g. `LO`.
+pub(crate) fn parse_vr(ident: &str) -> Result<VRRef> {
+    <[u8; 2]>::try_from(ident.as_bytes())
+        .ok()
+        .and_then(|code| VR::from_code(u16::from_be_bytes(code)))
+        .ok_or_else(|| anyhow!("Unknown VR: {ident}"))
+}
+
+/// The VR of the attribute from the dictionary, or `UN` if it isn't known.
+pub(crate) fn dictionary_vr(tag: u32) -> VRRef {
+    if Tag::is_private_creator(tag) {
+        return &vr::LO;
+    }
+    STANDARD_DICOM_DICTIONARY
+        .get_tag_by_number(tag)
+        .and_then(|tag| tag.implicit_vr)
+        .unwrap_or(&vr::UN)
+}
+
+/// Parses the path of an attribute, which may end with `:VR` to give the VR of attributes not in
+/// the dictionary.
+pub(crate) fn parse_attribute(attr: &str) -> Result<(TagPath, VRRef)> {
+    let (path, vr) = match attr.rsplit_once(':') {
+        Some((path, vr)) => (path, Some(parse_vr(vr.trim())?)),
+        None => (attr, None),
+    };
+    let tagpath: TagPath = TagPath::parse(path.trim(), Some(&STANDARD_DICOM_DICTIONARY))
+        .with_context(|| format!("Invalid attribute: {attr}"))?;
+    let tag: u32 = tagpath
+        .nodes
+        .last()
+        .map(|node| node.tag())
+        .ok_or_else(|| anyhow!("Invalid attribute: {attr}"))?;
+    Ok((tagpath, vr.unwrap_or_else(|| dictionary_vr(tag))))
+}
@@ -0,0 +1,196 @@
+//! The organize command sorts datasets into folders named by the values of arbitrary attributes.
+
+use std::{
+    fs::{self, File},
+    io,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Context, Result};
+use walkdir::WalkDir;
+
+use dcmpipe_lib::{
+    core::{
+        dcmobject::DicomRoot,
+        read::{stop::ParseStop, ParserBuilder},
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags},
+};
+
+use crate::{
+    app::{
+        archiveapp::{unique_path, Layout},
+        CommandApplication,
+    },
+    args::{DuplicatePolicy, OrganizeArgs, OrganizeMode},
+    shutdown,
+};
+
+pub struct OrganizeApp {
+    args: OrganizeArgs,
+}
+
+impl OrganizeApp {
+    pub fn new(args: OrganizeArgs) -> OrganizeApp {
+        OrganizeApp { args }
+    }
+
+    /// The folder layout given by the `--by` attributes, one level of folders per attribute.
+    fn layout(&self) -> Result<Layout> {
+        let template: Vec<String> = self
+            .args
+            .by
+            .iter()
+            .map(|key| format!("{{{key}}}"))
+            .collect();
+        Layout::parse(&template.join("/"))
+    }
+
+    /// Places the file at `dest` according to `--mode`, replacing any existing file.
+    fn place(&self, path: &Path, dest: &Path) -> Result<()> {
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create folder: {}", parent.display()))?;
+        }
+        match self.args.mode {
+            OrganizeMode::Copy => {
+                fs::copy(path, dest).with_context(|| {
+                    format!("Failed to copy {} to {}", path.display(), dest.display())
+                })?;
+            }
+            OrganizeMode::Move => {
+                // Renaming fails across file systems, where the file has to be copied instead.
+                if fs::rename(path, dest).is_err() {
+                    fs::copy(path, dest)
+                        .and_then(|_| fs::remove_file(path))
+                        .with_context(|| {
+                            format!("Failed to move {} to {}", path.display(), dest.display())
+                        })?;
+                }
+            }
+            OrganizeMode::Symlink => {
+                let target: PathBuf = fs::canonicalize(path)
+                    .with_context(|| format!("Failed to resolve: {}", path.display()))?;
+                if fs::symlink_metadata(dest).is_ok() {
+                    fs::remove_file(dest)
+                        .with_context(|| format!("Failed to replace: {}", dest.display()))?;
+                }
+                symlink(&target, dest).with_context(|| {
+                    format!("Failed to link {} to {}", dest.display(), path.display())
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CommandApplication for OrganizeApp {
+    fn run(&mut self) -> Result<()> {
+        shutdown::install_handler()?;
+
+        let layout: Layout = self.layout()?;
+        let parser_builder: ParserBuilder<'_> = ParserBuilder::default()
+            .stop(ParseStop::BeforeTagValue(tags::PixelData.tag.into()))
+            .dictionary(&STANDARD_DICOM_DICTIONARY);
+
+        // The files are listed up front as the destination may be within the source folder.
+        let files: Vec<PathBuf> = WalkDir::new(&self.args.source)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .map(|e| e.into_path())
+            .collect();
+
+        let mut organized: usize = 0;
+        let mut skipped: usize = 0;
+        for path in &files {
+            if shutdown::is_requested() {
+                println!("Organize interrupted before: {}", path.display());
+                break;
+            }
+
+            let file: File = File::open(path)?;
+            let dcmroot: DicomRoot<'_> = match DicomRoot::parse(&mut parser_builder.build(file)) {
+                Ok(Some(dcmroot)) => dcmroot,
+                Ok(None) => {
+                    println!("Skipping non-DICOM file: {}", path.display());
+                    skipped += 1;
+                    continue;
+                }
+                Err(e) => {
+                    println!("Skipping invalid DICOM file: {}, {e}", path.display());
+                    skipped += 1;
+                    continue;
+                }
+            };
+
+            let Some(file_name) = path.file_name() else {
+                continue;
+            };
+            let mut dest: PathBuf = self
+                .args
+                .destination
+                .join(layout.render(&dcmroot))
+                .join(file_name);
+            if is_same_file(path, &dest) {
+                // Already organized, e.g. by a previous run within the source folder.
+                continue;
+            }
+            if fs::symlink_metadata(&dest).is_ok() {
+                match self.args.on_duplicate {
+                    DuplicatePolicy::Skip => {
+                        println!(
+                            "Skipping {}, destination exists: {}",
+                            path.display(),
+                            dest.display()
+                        );
+                        skipped += 1;
+                        continue;
+                    }
+                    DuplicatePolicy::Overwrite => {}
+                    DuplicatePolicy::Rename => dest = unique_path(&dest),
+                    DuplicatePolicy::Error => {
+                        return Err(anyhow!(
+                            "Destination of {} exists: {}",
+                            path.display(),
+                            dest.display()
+                        ));
+                    }
+                }
+            }
+
+            if self.args.dry_run {
+                println!("{} -> {}", path.display(), dest.display());
+            } else {
+                self.place(path, &dest)?;
+            }
+            organized += 1;
+        }
+
+        if self.args.dry_run {
+            println!("Would organize {organized} files, skipped {skipped}");
+        } else {
+            println!("Organized {organized} files, skipped {skipped}");
+        }
+        Ok(())
+    }
+}
+
+/// Whether both paths refer to the same existing file.
+fn is_same_file(a: &Path, b: &Path) -> bool {
+    match (fs::canonicalize(a), fs::canonicalize(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+#[cfg(unix)]
+fn symlink(target: &Path, link: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(target, link)
+}
+
+#[cfg(windows)]
+fn symlink(target: &Path, link: &Path) -> io::Result<()> {
+    std::os::windows::fs::symlink_file(target, link)
+}
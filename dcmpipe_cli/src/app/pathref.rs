@@ -0,0 +1,85 @@
+//! Lossless references to files, for storing paths in indexes and writing them to output.
+//!
+//! Paths aren't necessarily valid UTF-8, such as file names on shares written by older Windows
+//! systems in a legacy code page. `Path::display()` replaces the invalid bytes, so a path written
+//! that way can't be opened again. A path reference is the path itself when it's valid UTF-8, and
+//! otherwise the bytes of the path encoded as base64 following `PATHREF_PREFIX`.
+
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+/// Prefix of path references which encode the bytes of the path as base64.
+pub(crate) const PATHREF_PREFIX: &str = "base64:";
+
+/// Creates a reference to the path which can be converted back to the same path with
+/// `decode_pathref()`.
+pub(crate) fn encode_pathref(path: &Path) -> String {
+    match path.to_str() {
+        // Paths which happen to start with the prefix are encoded so they aren't mistaken for
+        // encoded paths.
+        Some(path) if !path.starts_with(PATHREF_PREFIX) => path.to_owned(),
+        _ => format!("{PATHREF_PREFIX}{}", STANDARD.encode(os_bytes(path))),
+    }
+}
+
+/// Converts a reference created with `encode_pathref()` back to its path. References which aren't
+/// validly encoded are treated as a path.
+pub(crate) fn decode_pathref(pathref: &str) -> PathBuf {
+    pathref
+        .strip_prefix(PATHREF_PREFIX)
+        .and_then(|encoded| STANDARD.decode(encoded).ok())
+        .and_then(from_os_bytes)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(pathref))
+}
+
+#[cfg(unix)]
+fn os_bytes(path: &Path) -> Vec<u8> {
+    use std::os::unix::ffi::OsStrExt;
+    path.as_os_str().as_bytes().to_vec()
+}
+
+#[cfg(unix)]
+fn from_os_bytes(bytes: Vec<u8>) -> Option<OsString> {
+    use std::os::unix::ffi::OsStringExt;
+    Some(OsString::from_vec(bytes))
+}
+
+/// Windows paths are sequences of 16-bit units which may not be valid UTF-16, encoded here as
+/// little-endian bytes.
+#[cfg(windows)]
+fn os_bytes(path: &Path) -> Vec<u8> {
+    use std::os::windows::ffi::OsStrExt;
+    path.as_os_str()
+        .encode_wide()
+        .flat_map(u16::to_le_bytes)
+        .collect()
+}
+
+#[cfg(windows)]
+fn from_os_bytes(bytes: Vec<u8>) -> Option<OsString> {
+    use std::os::windows::ffi::OsStringExt;
+    if bytes.len() % 2 != 0 {
+        return None;
+    }
+    let wide: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+        .collect();
+    Some(OsString::from_wide(&wide))
+}
+
+/// Other platforms only have UTF-8 paths, so are never encoded other than to escape the prefix.
+#[cfg(not(any(unix, windows)))]
+fn os_bytes(path: &Path) -> Vec<u8> {
+    path.to_string_lossy().into_owned().into_bytes()
+}
+
+#[cfg(not(any(unix, windows)))]
+fn from_os_bytes(bytes: Vec<u8>) -> Option<OsString> {
+    String::from_utf8(bytes).ok().map(OsString::from)
+}
@@ -1,33 +1,36 @@
 //! The print command renders the contents of a DICOM dataset to stdout, in a format similar to the
-//! dcmdump tool.
+//! dcmdump tool, or in dcmdump's own layout, see `dcmtk`.
+
+mod dcmtk;
 
 use std::{
-    fs::File,
-    io::{self, Write},
-    path::{Path, PathBuf},
+    io::{self, IsTerminal, Read, Write},
+    path::Path,
 };
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 
 use dcmpipe_lib::{
     core::{
         dcmelement::DicomElement,
-        dcmsqelem::SequenceElement,
-        defn::{constants::tags::FILE_META_GROUP_END, tag::Tag, ts::TSRef, vl::ValueLength, vr},
-        read::Parser,
+        dcmobject::DicomRoot,
+        defn::{constants::tags::FILE_META_GROUP_END, tag::TagPath, ts::TSRef, vr},
+        read::{ParseResult, Parser},
+        sizereport::SizeReport,
     },
-    dict::tags,
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags},
+    source::{archive_kind, Source, SourceEntry},
 };
 
 use crate::{
-    app::{parse_file, CommandApplication},
-    args::PrintArgs,
+    app::{parse_entry, parse_file, progress::format_size, CommandApplication},
+    args::{ColorChoice, PrintArgs, PrintFormat},
 };
 
-use super::{ElementWithLineFmt, TagCategory, TagValue};
+use super::{format_code_items, formatter::ElementFormatter, ElementWithLineFmt, TagValue};
 
-static HIDE_GROUP_TAGS: bool = false;
-static HIDE_DELIMITATION_TAGS: bool = false;
+/// The number of elements listed by the size report, largest first.
+const LARGEST_ELEMENTS: usize = 10;
 
 pub struct PrintApp {
     args: PrintArgs,
@@ -37,155 +40,216 @@ impl PrintApp {
     pub fn new(args: PrintArgs) -> PrintApp {
         PrintApp { args }
     }
+
+    /// The formatter of elements in the dcmpipe layout. With `--color auto` colors are used when
+    /// stdout is a terminal, unless the `NO_COLOR` environment variable is set.
+    fn formatter(&self) -> ElementFormatter {
+        let color: bool = match self.args.color {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+            }
+        };
+        ElementFormatter::default()
+            .max_value_width(self.args.max_value_width)
+            .max_depth(self.args.depth)
+            .offsets(self.args.offsets)
+            .color(color)
+    }
 }
 
 impl CommandApplication for PrintApp {
     fn run(&mut self) -> Result<()> {
-        let path_buf: PathBuf = self.args.file.clone();
-        let path: &Path = path_buf.as_path();
-        let mut parser: Parser<'_, File> = parse_file(path, true)?;
-
-        let mut stdout = io::stdout().lock();
-        stdout.write_all(format!(
-            "\n# Dicom-File-Format File: {:#?}\n\n# Dicom-Meta-Information-Header\n# Used TransferSyntax: {}\n",
-            path,
-            parser.ts().uid.ident).as_ref()
-        )?;
-
-        let mut prev_was_file_meta: bool = true;
-
-        while let Some(elem) = parser.next() {
-            let elem: DicomElement = elem?;
-
-            if prev_was_file_meta && elem.tag() > FILE_META_GROUP_END {
-                stdout.write_all(
-                    format!(
-                        "\n# Dicom-Data-Set\n# Used TransferSyntax: {}\n",
-                        parser.ts().uid.ident
-                    )
-                    .as_ref(),
-                )?;
-                prev_was_file_meta = false;
-            }
-
-            let printed: Option<String> = render_element(parser.ts(), &elem)?;
-
-            if let Some(printed) = printed {
-                stdout.write_all(format!("{}\n", printed).as_ref())?;
-            }
+        let path: &Path = self.args.file.as_path();
+        if !path.is_file() {
+            return Err(anyhow!("invalid file: {}", path.display()));
+        }
+        let format: Option<PrintFormat> = (!self.args.sizes).then_some(self.args.format);
+        let formatter: ElementFormatter = self.formatter();
+        if archive_kind(path)?.is_none() {
+            return print(format, &formatter, path, parse_file(path, true)?);
         }
 
+        // Each dataset within an archive is printed in turn, skipping other files.
+        for entry in Source::new(path).entries()? {
+            let entry: SourceEntry = entry?;
+            match parse_entry(&entry, true) {
+                Ok(parser) => print(format, &formatter, entry.path(), parser)?,
+                Err(_) => println!("\n# Skipping non-DICOM file: {}", entry.path().display()),
+            };
+        }
         Ok(())
     }
 }
 
-/// Renders an element on a single line, includes indentation based on depth in sequences
-/// ```
-/// (gggg,eeee) VR TagName [VL] | TagValue
-/// ```
-/// or
-/// ```
-/// (gggg,eeee) VR TagName [0] <empty>
-/// ```
-/// Names for unknown tags will render as `<UnknownTag>`
-fn render_element(ts: TSRef, element: &DicomElement) -> Result<Option<String>> {
-    // Group Length tags are deprecated, see note on Part 5 Section 7.2
-    if HIDE_GROUP_TAGS && element.tag().trailing_zeros() >= 16 {
-        return Ok(None);
+/// Prints the dataset read by the parser in the given layout, or its size report without one.
+fn print<R: Read>(
+    format: Option<PrintFormat>,
+    formatter: &ElementFormatter,
+    path: &Path,
+    mut parser: Parser<'_, R>,
+) -> Result<()> {
+    if format == Some(PrintFormat::Dcmpipe) {
+        return print_dataset(formatter, path, parser);
     }
 
-    // These are delimiter items that are not very useful to see
-    if HIDE_DELIMITATION_TAGS
-        && (element.tag() == tags::ItemDelimitationItem.tag
-            || element.tag() == tags::SequenceDelimitationItem.tag)
-    {
-        return Ok(None);
+    // Other layouts need the dataset in full, e.g. to count the items of each sequence.
+    let dcmroot: DicomRoot<'_> = DicomRoot::parse(&mut parser)?
+        .ok_or_else(|| anyhow!("file is not dicom: {}", path.display()))?;
+    let mut stdout = io::stdout().lock();
+    match format {
+        Some(_) => dcmtk::render_dataset(&dcmroot, &mut stdout),
+        None => print_sizes(path, &dcmroot.size_report(), &mut stdout),
     }
+}
 
-    // Some (malformed?) datasets have a bunch of zeroes between elements.
-    if element.tag() == 0
-        && ((ts.explicit_vr() && element.vr() == &vr::INVALID)
-            || (!ts.explicit_vr() && element.vr() == &vr::UN))
-        && element.vl() == ValueLength::Explicit(0)
-    {
-        return Ok(None);
+/// Prints the bytes used by each group, then the largest elements.
+fn print_sizes(path: &Path, report: &SizeReport, out: &mut impl Write) -> Result<()> {
+    writeln!(out, "\n# Sizes of: {}", path.display())?;
+    writeln!(
+        out,
+        "Total:   {} ({} bytes, {} in headers and {} in values)",
+        format_size(report.total_len()),
+        report.total_len(),
+        report.header_len(),
+        report.value_len()
+    )?;
+    writeln!(
+        out,
+        "Depth:   {} (of the most deeply nested sequence)",
+        report.max_depth()
+    )?;
+
+    writeln!(out, "\nGroup   Elements      Header       Value  Depth")?;
+    for group in &report.groups {
+        writeln!(
+            out,
+            "{:04X}  {:>10}  {:>10}  {:>10}  {:>5}",
+            group.group, group.elements, group.header_len, group.value_len, group.max_depth
+        )?;
     }
 
-    let tag_num: String = Tag::format_tag_to_display(element.tag());
-    let tag_name: TagCategory = element.into();
-    let vr: &str = element.vr().ident;
+    writeln!(out, "\nLargest Elements")?;
+    for element in report.largest(LARGEST_ELEMENTS) {
+        let path: String =
+            TagPath::format_tagpath_to_display(&element.tagpath, Some(&STANDARD_DICOM_DICTIONARY));
+        writeln!(
+            out,
+            "  {:>10}  {} {path}",
+            element.total_len(),
+            element.vr.ident
+        )?;
+    }
+    Ok(())
+}
+
+/// Prints the dataset read by the parser, which was opened from `path`.
+fn print_dataset<R: Read>(
+    formatter: &ElementFormatter,
+    path: &Path,
+    mut parser: Parser<'_, R>,
+) -> Result<()> {
+    let mut stdout = io::stdout().lock();
+    stdout.write_all(format!(
+        "\n# Dicom-File-Format File: {:#?}\n\n# Dicom-Meta-Information-Header\n# Used TransferSyntax: {}\n",
+        path,
+        parser.ts().uid.ident).as_ref()
+    )?;
+
+    let mut prev_was_file_meta: bool = true;
+
+    let mut next: Option<ParseResult<DicomElement>> = parser.next();
+    while let Some(elem) = next.take() {
+        let elem: DicomElement = elem?;
+
+        if prev_was_file_meta && elem.tag() > FILE_META_GROUP_END {
+            stdout.write_all(
+                format!(
+                    "\n# Dicom-Data-Set\n# Used TransferSyntax: {}\n",
+                    parser.ts().uid.ident
+                )
+                .as_ref(),
+            )?;
+            prev_was_file_meta = false;
+        }
 
-    let vl: String = match element.vl() {
-        ValueLength::Explicit(len) => {
-            if len % 2 != 0 {
-                format!("[*{:?}]", element.vl())
-            } else {
-                format!("[{:?}]", element.vl())
+        // Sequences are read in full before being rendered, so that Code Sequences can be
+        // rendered inline.
+        if elem.vr() == &vr::SQ {
+            let depth: usize = elem.sequence_path().len();
+            let mut sequence: Vec<DicomElement> = vec![elem];
+            loop {
+                next = parser.next();
+                match next {
+                    Some(Ok(child)) if child.sequence_path().len() > depth => sequence.push(child),
+                    _ => break,
+                }
             }
+            let mut lines: Vec<String> = Vec::new();
+            render_elements(formatter, parser.ts(), &sequence, &mut lines);
+            for line in lines {
+                stdout.write_all(format!("{}\n", line).as_ref())?;
+            }
+            continue;
         }
-        ValueLength::UndefinedLength => "[u/l]".to_string(),
-    };
-
-    // Sequence path will nest tags under ITEM elements. Double the indentation level for the
-    // number of nested sequences (non-ITEM), and each ITEM element should be nested one level.
-    // If the current element is a delimiter then reduce the associated indentation level.
-    let seq_path: &Vec<SequenceElement> = element.sequence_path();
-    let non_item_parents = seq_path
-        .iter()
-        .filter(|sq_el| sq_el.seq_tag() != tags::Item.tag)
-        .count();
-    let item_parents = seq_path
-        .iter()
-        .filter(|sq_el| sq_el.seq_tag() == tags::Item.tag)
-        .count();
-    let mut indent_width = non_item_parents * 2 + item_parents;
-    if element.tag() == tags::ItemDelimitationItem.tag {
-        indent_width -= 1;
-    } else if element.tag() == tags::SequenceDelimitationItem.tag {
-        indent_width -= 2;
-    }
 
-    if element.tag() == tags::Item.tag {
-        let item_desc: String = if let Some(last_seq_elem) = seq_path.last() {
-            format!(
-                "{} {} {}",
-                last_seq_elem
-                    .item()
-                    .map(|item_no: usize| format!("#{}", item_no))
-                    .unwrap_or_else(|| "#[NO ITEM NUMBER]".to_string()),
-                vr,
-                vl,
-            )
-        } else {
-            String::new()
-        };
-        return Ok(Some(format!(
-            "{indentation:indent_width$}{tag_name} {item_desc}",
-            indentation = "",
-            indent_width = indent_width,
-            tag_name = tag_name,
-            item_desc = item_desc,
-        )));
-    }
+        let tag_value: TagValue = ElementWithLineFmt(&elem, false).into();
+        let printed: Option<String> = formatter.format_line(parser.ts(), &elem, tag_value);
 
-    let tag_value: TagValue = ElementWithLineFmt(element, false).into();
-    let mut tag_value: String = tag_value.to_string();
-    if !tag_value.is_empty() {
-        if element.is_empty() {
-            tag_value = format!(" {}", tag_value);
-        } else {
-            tag_value = format!(" | {}", tag_value);
+        if let Some(printed) = printed {
+            stdout.write_all(format!("{}\n", printed).as_ref())?;
         }
+        next = parser.next();
     }
 
-    Ok(Some(format!(
-        "{indentation:indent_width$}{tag_num} {vr} {tag_name} {vl}{tag_value}",
-        indentation = "",
-        indent_width = indent_width,
-        tag_num = tag_num,
-        vr = vr,
-        tag_name = tag_name,
-        vl = vl,
-        tag_value = tag_value,
-    )))
+    Ok(())
+}
+
+/// Renders elements in the order they were read, recursing into sequences. Sequences whose items
+/// are all codes are rendered on a single line, see `format_code_items()`. Elements nested deeper
+/// than the formatter's `max_depth` are skipped.
+fn render_elements(
+    formatter: &ElementFormatter,
+    ts: TSRef,
+    elements: &[DicomElement],
+    lines: &mut Vec<String>,
+) {
+    let mut index: usize = 0;
+    while index < elements.len() {
+        let element: &DicomElement = &elements[index];
+        let mut end: usize = index + 1;
+        if element.vr() == &vr::SQ {
+            let depth: usize = element.sequence_path().len();
+            end += elements[end..]
+                .iter()
+                .take_while(|child| child.sequence_path().len() > depth)
+                .count();
+        }
+        let children: &[DicomElement] = &elements[index + 1..end];
+        if !formatter.is_within_depth(element) {
+            index = end;
+            continue;
+        }
+
+        let mut items: Vec<Vec<&DicomElement>> = Vec::new();
+        for child in children {
+            if child.tag() == tags::Item.tag {
+                items.push(Vec::new());
+            } else if let Some(item) = items.last_mut() {
+                item.push(child);
+            }
+        }
+        match format_code_items(&items) {
+            Some(codes) => {
+                lines.extend(formatter.format_line(ts, element, TagValue::Stringified(codes)));
+            }
+            None => {
+                let tag_value: TagValue = ElementWithLineFmt(element, false).into();
+                lines.extend(formatter.format_line(ts, element, tag_value));
+                render_elements(formatter, ts, children, lines);
+            }
+        }
+        index = end;
+    }
 }
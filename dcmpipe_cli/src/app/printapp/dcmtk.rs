@@ -0,0 +1,395 @@
+//! Renders datasets in the layout of dcmtk's dcmdump, so that workflows comparing against dcmdump
+//! output can compare against dcmpipe's instead.
+//!
+//! Each element is rendered on a line of its tag, VR, and value, padded to a column of 40
+//! characters and followed by the value length, value multiplicity, and keyword. Sequences and
+//! items are followed by delimitation items as dcmdump shows them, whether or not they were present
+//! in the dataset. Values longer than 70 characters are shortened, as dcmdump does by default.
+
+use std::io::Write;
+
+use anyhow::Result;
+
+use dcmpipe_lib::{
+    core::{
+        dcmelement::DicomElement,
+        dcmobject::{DicomObject, DicomRoot},
+        defn::{
+            constants::tags::FILE_META_GROUP_END, dcmdict::DicomDictionary, tag::Tag, ts::TSRef,
+            vl::ValueLength, vr,
+        },
+        values::RawValue,
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags, transfer_syntaxes as ts},
+};
+
+/// The width values are padded to, `DCM_OptPrintValueLength`.
+const VALUE_WIDTH: usize = 40;
+/// The length beyond which values are shortened, `DCM_OptPrintLineLength`.
+const LINE_WIDTH: usize = 70;
+
+/// The names dcmtk uses for common transfer syntaxes, as a value of `TransferSyntaxUID` and in the
+/// header of each part of the dataset. Other transfer syntaxes are named from the dictionary.
+static TRANSFER_SYNTAX_NAMES: [(&str, &str, &str); 13] = [
+    (
+        "1.2.840.10008.1.2",
+        "LittleEndianImplicit",
+        "Little Endian Implicit",
+    ),
+    (
+        "1.2.840.10008.1.2.1",
+        "LittleEndianExplicit",
+        "Little Endian Explicit",
+    ),
+    (
+        "1.2.840.10008.1.2.2",
+        "BigEndianExplicit",
+        "Big Endian Explicit",
+    ),
+    (
+        "1.2.840.10008.1.2.1.99",
+        "DeflatedLittleEndianExplicit",
+        "Deflated Explicit VR Little Endian",
+    ),
+    ("1.2.840.10008.1.2.4.50", "JPEGBaseline", "JPEG Baseline"),
+    (
+        "1.2.840.10008.1.2.4.51",
+        "JPEGExtended:Process2+4",
+        "JPEG Extended, Process 2+4",
+    ),
+    (
+        "1.2.840.10008.1.2.4.57",
+        "JPEGLossless:Non-hierarchical:Process14",
+        "JPEG Lossless, Non-hierarchical, Process 14",
+    ),
+    (
+        "1.2.840.10008.1.2.4.70",
+        "JPEGLossless:Non-hierarchical-1stOrderPrediction",
+        "JPEG Lossless, Non-hierarchical, 1st Order Prediction",
+    ),
+    (
+        "1.2.840.10008.1.2.4.80",
+        "JPEGLSLossless",
+        "JPEG-LS Lossless",
+    ),
+    (
+        "1.2.840.10008.1.2.4.81",
+        "JPEGLSLossy",
+        "JPEG-LS Lossy (Near-lossless)",
+    ),
+    (
+        "1.2.840.10008.1.2.4.90",
+        "JPEG2000LosslessOnly",
+        "JPEG 2000 (Lossless only)",
+    ),
+    ("1.2.840.10008.1.2.4.91", "JPEG2000", "JPEG 2000"),
+    ("1.2.840.10008.1.2.5", "RLELossless", "RLE Lossless"),
+];
+
+/// Renders the dataset, starting with its File Meta group if present.
+pub(super) fn render_dataset(dcmroot: &DicomRoot<'_>, out: &mut impl Write) -> Result<()> {
+    writeln!(out, "\n# Dicom-File-Format\n")?;
+    writeln!(out, "# Dicom-Meta-Information-Header")?;
+    writeln!(
+        out,
+        "# Used TransferSyntax: {}",
+        transfer_syntax_description(&ts::ExplicitVRLittleEndian)
+    )?;
+    let (meta, dataset): (Vec<&DicomObject<'_>>, Vec<&DicomObject<'_>>) = dcmroot
+        .iter_child_nodes()
+        .map(|(_, child)| child)
+        .partition(|child| child.element().tag() <= FILE_META_GROUP_END);
+    for child in meta {
+        render_object(child, 1, out)?;
+    }
+
+    writeln!(out, "\n# Dicom-Data-Set")?;
+    writeln!(
+        out,
+        "# Used TransferSyntax: {}",
+        transfer_syntax_description(dcmroot.ts())
+    )?;
+    for child in dataset {
+        render_object(child, 1, out)?;
+    }
+    Ok(())
+}
+
+/// Renders an element at the given nesting level, from 1, along with its items and their contents.
+fn render_object(obj: &DicomObject<'_>, level: usize, out: &mut impl Write) -> Result<()> {
+    let element: &DicomElement<'_> = obj.element();
+    if is_delimiter(element.tag()) {
+        return Ok(());
+    }
+    let undefined: bool = element.vl() == ValueLength::UndefinedLength;
+    let keyword: String = keyword(element);
+
+    if element.vr() == &vr::SQ || (element.is_seq_like() && obj.item_count() > 0) {
+        let is_pixel_sequence: bool = element.vr() != &vr::SQ;
+        let value: String = if is_pixel_sequence {
+            format!("(PixelSequence #={})", obj.item_count())
+        } else {
+            format!(
+                "(Sequence with {} length #={})",
+                if undefined { "undefined" } else { "explicit" },
+                obj.item_count()
+            )
+        };
+        Line {
+            level,
+            tag: element.tag(),
+            vr: element.vr().ident,
+            value: &value,
+            vl: element.vl(),
+            vm: 1,
+            keyword: &keyword,
+        }
+        .write(out)?;
+        for item in obj.iter_items() {
+            render_item(item, level + 1, is_pixel_sequence, out)?;
+        }
+        let value: &str = if undefined || is_pixel_sequence {
+            "(SequenceDelimitationItem)"
+        } else {
+            "(SequenceDelimitationItem for re-encod.)"
+        };
+        return Line {
+            level,
+            tag: tags::SequenceDelimitationItem.tag,
+            vr: "na",
+            value,
+            vl: ValueLength::Explicit(0),
+            vm: 0,
+            keyword: "SequenceDelimitationItem",
+        }
+        .write(out);
+    }
+
+    let (value, vm) = render_value(element);
+    Line {
+        level,
+        tag: element.tag(),
+        vr: element.vr().ident,
+        value: &value,
+        vl: element.vl(),
+        vm,
+        keyword: &keyword,
+    }
+    .write(out)
+}
+
+/// Renders an item of a sequence, or a fragment of encapsulated Pixel Data.
+fn render_item(
+    item: &DicomObject<'_>,
+    level: usize,
+    is_pixel_sequence: bool,
+    out: &mut impl Write,
+) -> Result<()> {
+    let element: &DicomElement<'_> = item.element();
+    if is_pixel_sequence {
+        let value: String = if element.is_empty() {
+            "(no value available)".to_owned()
+        } else {
+            join(element.data().iter().map(|byte| format!("{byte:02x}"))).0
+        };
+        return Line {
+            level,
+            tag: element.tag(),
+            vr: "pi",
+            value: &value,
+            vl: element.vl(),
+            vm: 1,
+            keyword: "Item",
+        }
+        .write(out);
+    }
+
+    let undefined: bool = element.vl() == ValueLength::UndefinedLength;
+    let children: Vec<&DicomObject<'_>> = item
+        .iter_child_nodes()
+        .map(|(_, child)| child)
+        .filter(|child| !is_delimiter(child.element().tag()))
+        .collect();
+    let value: String = format!(
+        "(Item with {} length #={})",
+        if undefined { "undefined" } else { "explicit" },
+        children.len()
+    );
+    Line {
+        level,
+        tag: element.tag(),
+        vr: "na",
+        value: &value,
+        vl: element.vl(),
+        vm: 1,
+        keyword: "Item",
+    }
+    .write(out)?;
+    for child in children {
+        render_object(child, level + 1, out)?;
+    }
+    let value: &str = if undefined {
+        "(ItemDelimitationItem)"
+    } else {
+        "(ItemDelimitationItem for re-encoding)"
+    };
+    Line {
+        level,
+        tag: tags::ItemDelimitationItem.tag,
+        vr: "na",
+        value,
+        vl: ValueLength::Explicit(0),
+        vm: 0,
+        keyword: "ItemDelimitationItem",
+    }
+    .write(out)
+}
+
+/// Renders the value of an element along with its value multiplicity. Strings are bracketed and
+/// known UIDs are named, while numbers and binary values are rendered as they are.
+fn render_value(element: &DicomElement<'_>) -> (String, usize) {
+    if element.is_empty() {
+        return ("(no value available)".to_owned(), 0);
+    }
+    if element.vr() == &vr::UI {
+        let Ok(uids) = Vec::<String>::try_from(element) else {
+            return ("(invalid value)".to_owned(), 1);
+        };
+        let value: String = match uids.as_slice() {
+            [uid] => uid_name(uid)
+                .map(|name| format!("={name}"))
+                .unwrap_or_else(|| format!("[{uid}]")),
+            _ => format!("[{}]", uids.join("\\")),
+        };
+        return (value, uids.len());
+    }
+    if element.vr().is_character_string {
+        return match Vec::<String>::try_from(element) {
+            Ok(values) => (format!("[{}]", values.join("\\")), values.len()),
+            Err(_) => ("(invalid value)".to_owned(), 1),
+        };
+    }
+
+    let Ok(value) = element.parse_value() else {
+        return ("(invalid value)".to_owned(), 1);
+    };
+    // Binary values of the OB, OW, OL, OV, OF, OD, and UN VRs have a value multiplicity of 1.
+    let binary: bool = [
+        &vr::OB,
+        &vr::OW,
+        &vr::OL,
+        &vr::OV,
+        &vr::OF,
+        &vr::OD,
+        &vr::UN,
+    ]
+    .contains(&element.vr());
+    let (value, vm): (String, usize) = match value {
+        RawValue::Attribute(values) => join(
+            values
+                .iter()
+                .map(|attr| format!("({:04x},{:04x})", attr.0 >> 16, attr.0 & 0xFFFF)),
+        ),
+        RawValue::Shorts(values) => join(values.iter()),
+        RawValue::UnsignedShorts(values) => join(values.iter()),
+        RawValue::Integers(values) => join(values.iter()),
+        RawValue::UnsignedIntegers(values) => join(values.iter()),
+        RawValue::Longs(values) => join(values.iter()),
+        RawValue::UnsignedLongs(values) => join(values.iter()),
+        RawValue::Floats(values) => join(values.iter()),
+        RawValue::Doubles(values) => join(values.iter()),
+        RawValue::Bytes(values) => join(values.iter().map(|value| format!("{value:02x}"))),
+        RawValue::Words(values) => join(values.iter().map(|value| format!("{value:04x}"))),
+        RawValue::DoubleWords(values) => join(values.iter().map(|value| format!("{value:08x}"))),
+        RawValue::QuadWords(values) => join(values.iter().map(|value| format!("{value:016x}"))),
+        RawValue::BulkData(_) => ("(not loaded)".to_owned(), 1),
+        RawValue::Uid(uid) => (format!("[{uid}]"), 1),
+        RawValue::Strings(values) => (format!("[{}]", values.join("\\")), values.len()),
+    };
+    (value, if binary { 1 } else { vm })
+}
+
+/// Joins values with the `\` separator, along with the number of values.
+fn join<T: ToString>(values: impl Iterator<Item = T>) -> (String, usize) {
+    let values: Vec<String> = values.map(|value| value.to_string()).collect();
+    (values.join("\\"), values.len())
+}
+
+/// A line of an element, at a nesting level from 1.
+struct Line<'l> {
+    level: usize,
+    tag: u32,
+    vr: &'l str,
+    value: &'l str,
+    vl: ValueLength,
+    vm: usize,
+    keyword: &'l str,
+}
+
+impl Line<'_> {
+    /// Writes the line with its value padded to `VALUE_WIDTH` and shortened beyond `LINE_WIDTH`,
+    /// as `printInfoLine()` of dcmtk.
+    fn write(&self, out: &mut impl Write) -> Result<()> {
+        let value: String = if self.value.chars().count() > LINE_WIDTH {
+            let shortened: String = self.value.chars().take(VALUE_WIDTH - 3).collect();
+            format!("{shortened}...")
+        } else {
+            self.value.to_owned()
+        };
+        let vl: String = match self.vl {
+            ValueLength::Explicit(len) => format!("{len:>3}"),
+            ValueLength::UndefinedLength => "u/l".to_owned(),
+        };
+        writeln!(
+            out,
+            "{indent}({group:04x},{elem:04x}) {vr} {value:<VALUE_WIDTH$} # {vl},{vm:>2} {keyword}",
+            indent = "  ".repeat(self.level.saturating_sub(1)),
+            group = self.tag >> 16,
+            elem = self.tag & 0xFFFF,
+            vr = self.vr,
+            vm = self.vm,
+            keyword = self.keyword,
+        )?;
+        Ok(())
+    }
+}
+
+/// The keyword of the element's tag, or the name dcmtk gives to tags not in its dictionary.
+fn keyword(element: &DicomElement<'_>) -> String {
+    let tag: u32 = element.tag();
+    match STANDARD_DICOM_DICTIONARY.get_tag_by_number(tag) {
+        Some(known) if !known.keyword().is_empty() => known.keyword().to_owned(),
+        Some(known) => known.ident().to_owned(),
+        None if Tag::is_private_creator(tag) => "PrivateCreator".to_owned(),
+        None if Tag::is_group_length(tag) || Tag::is_private_group_length(tag) => {
+            "GenericGroupLength".to_owned()
+        }
+        None => "Unknown Tag & Data".to_owned(),
+    }
+}
+
+/// The name of a UID as dcmtk shows it, if known.
+fn uid_name(uid: &str) -> Option<&'static str> {
+    TRANSFER_SYNTAX_NAMES
+        .iter()
+        .find(|(known, _, _)| *known == uid)
+        .map(|(_, name, _)| *name)
+        .or_else(|| {
+            STANDARD_DICOM_DICTIONARY
+                .get_uid_by_uid(uid)
+                .map(|uid| uid.ident())
+        })
+}
+
+/// The description of a transfer syntax as dcmtk shows it in the header of each part of a dataset.
+fn transfer_syntax_description(ts: TSRef) -> &'static str {
+    TRANSFER_SYNTAX_NAMES
+        .iter()
+        .find(|(uid, _, _)| *uid == ts.uid().uid())
+        .map(|(_, _, description)| *description)
+        .unwrap_or_else(|| ts.uid().ident())
+}
+
+fn is_delimiter(tag: u32) -> bool {
+    tag == tags::ItemDelimitationItem.tag || tag == tags::SequenceDelimitationItem.tag
+}
@@ -0,0 +1,130 @@
+//! A progress bar for long-running commands, rendered on the last line of stderr.
+
+use std::{
+    fmt::Display,
+    io::{self, IsTerminal, Write},
+    time::{Duration, Instant},
+};
+
+use dcmpipe_lib::progress::{Progress, ProgressReporter};
+
+/// How often the progress bar is redrawn.
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The number of characters in the bar itself.
+const BAR_WIDTH: usize = 24;
+
+/// Renders progress as a bar on stderr. It's only drawn when stderr is a terminal, so redirected
+/// output and logs aren't affected.
+pub(crate) struct ProgressBar {
+    enabled: bool,
+    drawn: bool,
+    last_drawn: Option<Instant>,
+}
+
+impl ProgressBar {
+    /// Creates a progress bar, which is disabled if `hidden` or if stderr isn't a terminal.
+    pub(crate) fn new(hidden: bool) -> ProgressBar {
+        ProgressBar {
+            enabled: !hidden && io::stderr().is_terminal(),
+            drawn: false,
+            last_drawn: None,
+        }
+    }
+
+    /// Prints a line to stdout, erasing the progress bar first so the line isn't drawn over it.
+    /// The bar is redrawn on the next report.
+    pub(crate) fn println<T: Display>(&mut self, line: T) {
+        self.clear();
+        println!("{line}");
+    }
+
+    /// Erases the progress bar.
+    fn clear(&mut self) {
+        if self.drawn {
+            eprint!("\r\x1b[2K");
+            let _ = io::stderr().flush();
+            self.drawn = false;
+        }
+    }
+
+    fn draw(&mut self, progress: &Progress) {
+        let mut line: String = String::new();
+        if let Some(fraction) = progress.fraction() {
+            let filled: usize = ((fraction * BAR_WIDTH as f64) as usize).min(BAR_WIDTH);
+            line.push_str(&format!(
+                "[{}{}] {:>3.0}%  ",
+                "#".repeat(filled),
+                "-".repeat(BAR_WIDTH - filled),
+                fraction * 100f64
+            ));
+        }
+        line.push_str(&format!(
+            "{} files  {}",
+            progress.files(),
+            format_size(progress.bytes())
+        ));
+        if let Some(total_bytes) = progress.total_bytes() {
+            line.push_str(&format!(" / {}", format_size(total_bytes)));
+        }
+        line.push_str(&format!(
+            "  {}/s",
+            format_size(progress.bytes_per_sec() as u64)
+        ));
+        if let Some(eta) = progress.eta() {
+            line.push_str(&format!("  ETA {}", format_duration(eta)));
+        }
+        // Lines wider than the terminal would wrap, leaving part of the bar behind when cleared.
+        if let Ok((columns @ 1.., _)) = crossterm::terminal::size() {
+            line.truncate(usize::from(columns) - 1);
+        }
+        eprint!("\r\x1b[2K{line}");
+        let _ = io::stderr().flush();
+        self.drawn = true;
+        self.last_drawn = Some(Instant::now());
+    }
+}
+
+impl ProgressReporter for ProgressBar {
+    fn report(&mut self, progress: &Progress) {
+        let due: bool = !self.drawn
+            || self
+                .last_drawn
+                .is_none_or(|last_drawn| last_drawn.elapsed() >= REDRAW_INTERVAL);
+        if self.enabled && due {
+            self.draw(progress);
+        }
+    }
+
+    fn finish(&mut self, _progress: &Progress) {
+        self.clear();
+    }
+}
+
+impl Drop for ProgressBar {
+    /// Erases the progress bar if the command ends early, such as with an error.
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+/// Formats a number of bytes with binary units, e.g. `1.50 GiB`.
+pub(crate) fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["KiB", "MiB", "GiB", "TiB", "PiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size: f64 = bytes as f64 / 1024f64;
+    let mut unit: usize = 0;
+    while size >= 1024f64 && unit < UNITS.len() - 1 {
+        size /= 1024f64;
+        unit += 1;
+    }
+    format!("{size:.2} {}", UNITS[unit])
+}
+
+/// Formats a duration as `hh:mm:ss`.
+fn format_duration(duration: Duration) -> String {
+    let secs: u64 = duration.as_secs();
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
@@ -0,0 +1,95 @@
+//! The refs command verifies that the references between the datasets within a folder resolve,
+//! e.g. that an RT Structure Set's contours refer to the CT instances of the study.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use walkdir::WalkDir;
+
+use dcmpipe_lib::core::{
+    dcmobject::DicomRoot,
+    integrity::{IntegrityIssue, ReferenceChecker},
+    read::Parser,
+};
+
+use crate::{
+    app::{parse_file, CommandApplication},
+    args::RefsArgs,
+};
+
+pub struct RefsApp {
+    args: RefsArgs,
+}
+
+impl RefsApp {
+    pub fn new(args: RefsArgs) -> RefsApp {
+        RefsApp { args }
+    }
+
+    fn get_files(&self) -> Vec<PathBuf> {
+        WalkDir::new(&self.args.folder)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_file())
+            .map(|entry| entry.into_path())
+            .collect()
+    }
+
+    /// The path of the file relative to the folder being checked, to keep reported issues short.
+    fn source(&self, path: &Path) -> String {
+        path.strip_prefix(&self.args.folder)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    }
+}
+
+impl CommandApplication for RefsApp {
+    fn run(&mut self) -> Result<()> {
+        if !self.args.folder.is_dir() {
+            return Err(anyhow!("invalid folder: {}", self.args.folder.display()));
+        }
+
+        let mut checker: ReferenceChecker = ReferenceChecker::default();
+        let mut skipped: usize = 0;
+        for path in self.get_files() {
+            let added: Result<bool> =
+                parse_file(&path, false).and_then(|mut parser: Parser<'_, _>| {
+                    match DicomRoot::parse(&mut parser)? {
+                        Some(dcmroot) => {
+                            checker.add(&self.source(&path), &dcmroot)?;
+                            Ok(true)
+                        }
+                        None => Ok(false),
+                    }
+                });
+            match added {
+                Ok(true) => {}
+                Ok(false) => skipped += 1,
+                Err(e) => {
+                    println!("Skipping {}, {e:#}", path.display());
+                    skipped += 1;
+                }
+            }
+        }
+
+        let issues: Vec<IntegrityIssue> = checker.check();
+        for issue in &issues {
+            println!("{issue}");
+        }
+        println!(
+            "Checked {} references of {} instances, skipped {skipped} files, found {} issues",
+            checker.reference_count(),
+            checker.instance_count(),
+            issues.len()
+        );
+        if !issues.is_empty() {
+            return Err(anyhow!(
+                "{} references are unresolved or inconsistent",
+                issues.len()
+            ));
+        }
+        Ok(())
+    }
+}
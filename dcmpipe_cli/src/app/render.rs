@@ -0,0 +1,136 @@
+//! Encoding of rendered frames into PNG and JPEG images.
+
+use std::io::Write;
+
+use anyhow::{anyhow, Result};
+use flate2::{write::ZlibEncoder, Compression};
+use jpeg_encoder::{ColorType, Encoder};
+
+static PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+
+/// An image of 8-bit RGB pixels in row-major order.
+#[derive(Clone, Debug)]
+pub(crate) struct RgbImage {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<[u8; 3]>,
+}
+
+impl RgbImage {
+    pub fn new(width: usize, height: usize, pixels: Vec<[u8; 3]>) -> RgbImage {
+        RgbImage {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Inverts the pixels, e.g. to display bone dark.
+    pub fn invert(&mut self) {
+        for pixel in &mut self.pixels {
+            *pixel = pixel.map(|sample| u8::MAX - sample);
+        }
+    }
+
+    /// Scales the image by the given factor with bilinear interpolation. The scaled image is at
+    /// least one pixel in each dimension.
+    pub fn scale(&self, factor: f64) -> RgbImage {
+        let width: usize = ((self.width as f64 * factor).round() as usize).max(1);
+        let height: usize = ((self.height as f64 * factor).round() as usize).max(1);
+        if (width == self.width && height == self.height) || self.pixels.is_empty() {
+            return self.clone();
+        }
+
+        // Maps a pixel of the scaled image to the nearest pixels of this image along one
+        // dimension, with the weight of the second.
+        let sample = |index: usize, from: usize, to: usize| -> (usize, usize, f64) {
+            let position: f64 = ((index as f64 + 0.5) * from as f64 / to as f64 - 0.5)
+                .clamp(0.0, (from - 1) as f64);
+            let first: usize = position.floor() as usize;
+            (first, (first + 1).min(from - 1), position - first as f64)
+        };
+        let mut pixels: Vec<[u8; 3]> = Vec::with_capacity(width * height);
+        for y in 0..height {
+            let (top, bottom, dy) = sample(y, self.height, height);
+            for x in 0..width {
+                let (left, right, dx) = sample(x, self.width, width);
+                let at = |row: usize, column: usize| self.pixels[row * self.width + column];
+                let (a, b, c, d) = (
+                    at(top, left),
+                    at(top, right),
+                    at(bottom, left),
+                    at(bottom, right),
+                );
+                pixels.push(std::array::from_fn(|i| {
+                    let upper: f64 = f64::from(a[i]) * (1.0 - dx) + f64::from(b[i]) * dx;
+                    let lower: f64 = f64::from(c[i]) * (1.0 - dx) + f64::from(d[i]) * dx;
+                    (upper * (1.0 - dy) + lower * dy).round() as u8
+                }));
+            }
+        }
+        RgbImage::new(width, height, pixels)
+    }
+
+    /// Whether all pixels are gray, so the image can be encoded with a single channel.
+    fn is_gray(&self) -> bool {
+        self.pixels.iter().all(|[r, g, b]| r == g && g == b)
+    }
+
+    /// Encodes the image as a PNG, in grayscale if all pixels are gray.
+    pub fn encode_png(&self) -> Result<Vec<u8>> {
+        let width: u32 = u32::try_from(self.width)?;
+        let height: u32 = u32::try_from(self.height)?;
+        let gray: bool = self.is_gray();
+
+        let mut header: Vec<u8> = Vec::with_capacity(13);
+        header.extend_from_slice(&width.to_be_bytes());
+        header.extend_from_slice(&height.to_be_bytes());
+        // Bit depth 8, color type grayscale or truecolor, then the default compression, filter,
+        // and no interlacing.
+        header.extend_from_slice(&[8, if gray { 0 } else { 2 }, 0, 0, 0]);
+
+        // Each scanline starts with its filter type, none.
+        let mut encoder: ZlibEncoder<Vec<u8>> =
+            ZlibEncoder::new(Vec::new(), Compression::default());
+        for row in self.pixels.chunks(self.width.max(1)) {
+            encoder.write_all(&[0])?;
+            for pixel in row {
+                if gray {
+                    encoder.write_all(&pixel[..1])?;
+                } else {
+                    encoder.write_all(pixel)?;
+                }
+            }
+        }
+        let data: Vec<u8> = encoder.finish()?;
+
+        let mut png: Vec<u8> = PNG_SIGNATURE.to_vec();
+        write_chunk(&mut png, b"IHDR", &header);
+        write_chunk(&mut png, b"IDAT", &data);
+        write_chunk(&mut png, b"IEND", &[]);
+        Ok(png)
+    }
+
+    /// Encodes the image as a baseline JPEG with the given quality, from 1 to 100.
+    pub fn encode_jpeg(&self, quality: u8) -> Result<Vec<u8>> {
+        let width: u16 = u16::try_from(self.width)
+            .map_err(|_| anyhow!("JPEG images can't be wider than {} pixels", u16::MAX))?;
+        let height: u16 = u16::try_from(self.height)
+            .map_err(|_| anyhow!("JPEG images can't be taller than {} pixels", u16::MAX))?;
+        let data: Vec<u8> = self.pixels.iter().flatten().copied().collect();
+        let mut jpeg: Vec<u8> = Vec::new();
+        Encoder::new(&mut jpeg, quality).encode(&data, width, height, ColorType::Rgb)?;
+        Ok(jpeg)
+    }
+}
+
+/// Appends a PNG chunk, its length and type followed by its data and CRC.
+fn write_chunk(png: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    png.extend_from_slice(chunk_type);
+    png.extend_from_slice(data);
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(chunk_type);
+    hasher.update(data);
+    png.extend_from_slice(&hasher.finalize().to_be_bytes());
+}
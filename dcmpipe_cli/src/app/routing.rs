@@ -0,0 +1,701 @@
+//! Routing of incoming datasets to destinations, by the rules of `[[route]]` tables in
+//! `dcmpipe.toml`.
+//!
+//! Each rule's conditions are all required to match, where a condition listing several values
+//! matches any of them. The datasets matching a rule are sent to each of its destinations: a
+//! folder, a remote AE with C-STORE, or the STOW-RS service of a DICOMweb server. Rules are
+//! evaluated in order, and a dataset is sent by every rule it matches unless a matching rule sets
+//! `stop`. The hosts of destinations are resolved as each dataset is sent, so a long-running
+//! router follows changes to their addresses.
+
+use std::{
+    fs::{self, File},
+    io::{BufRead, BufReader, Write},
+    net::{SocketAddr, TcpStream, ToSocketAddrs},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{anyhow, Context, Result};
+use regex::Regex;
+
+use dcmpipe_lib::{
+    core::{
+        dcmobject::DicomRoot,
+        defn::{dcmdict::DicomDictionary, tag::TagPath, uid::SOPClassCategory},
+        matching::Matcher,
+        read::{stop::ParseStop, ParserBuilder},
+    },
+    dict::{registry::sop_class_category, stdlookup::STANDARD_DICOM_DICTIONARY, tags},
+    dimse::constants::Status,
+};
+
+use crate::{
+    app::{
+        archiveapp::{element_value, Layout, DEFAULT_LAYOUT},
+        calling_ae, resolve_remote,
+        sendapp::{parse_dataset, send_dataset},
+    },
+    args::RemoteArgs,
+    config::{Config, RouteConfig, RouteDestination},
+};
+
+/// Seconds to wait for a connection to, and each response from, a remote AE or DICOMweb server.
+const CONNECT_TIMEOUT_SECS: u64 = 10;
+const RESPONSE_TIMEOUT_SECS: u64 = 60;
+
+/// Routes datasets by the rules of the configuration.
+pub(crate) struct Router {
+    routes: Vec<Route>,
+}
+
+/// A routing rule, with its conditions resolved.
+struct Route {
+    name: String,
+    modality: Vec<String>,
+    station_ae: Vec<String>,
+    sop_class: Vec<String>,
+    category: Vec<SOPClassCategory>,
+    accession: Option<Regex>,
+    attributes: Vec<(TagPath, Regex)>,
+    matching: Vec<(TagPath, String)>,
+    destinations: Vec<Destination>,
+    stop: bool,
+}
+
+enum Destination {
+    Folder {
+        dir: PathBuf,
+        layout: Layout,
+    },
+    Ae {
+        remote: RemoteArgs,
+        calling_ae: String,
+    },
+    Stow(StowEndpoint),
+}
+
+impl Router {
+    /// Resolves the routing rules of the configuration, failing if there are none or if any are
+    /// invalid.
+    pub(crate) fn new(config: &Config) -> Result<Router> {
+        if config.routes.is_empty() {
+            return Err(anyhow!("No routes, add [[route]] tables to dcmpipe.toml"));
+        }
+        let routes: Vec<Route> = config
+            .routes
+            .iter()
+            .enumerate()
+            .map(|(index, route)| {
+                let name: String = route
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| format!("route {}", index + 1));
+                Route::new(name.clone(), route, config)
+                    .with_context(|| format!("Invalid route: {name}"))
+            })
+            .collect::<Result<Vec<Route>>>()?;
+        Ok(Router { routes })
+    }
+
+    /// Get the folders which datasets may be copied into.
+    pub(crate) fn folders(&self) -> impl Iterator<Item = &Path> {
+        self.routes
+            .iter()
+            .flat_map(|route| &route.destinations)
+            .filter_map(|destination| match destination {
+                Destination::Folder { dir, .. } => Some(dir.as_path()),
+                _ => None,
+            })
+    }
+
+    /// Routes the dataset in the file, which is parsed up to its Pixel Data to match the rules.
+    pub(crate) fn route_file(&self, path: &Path, source_ae: Option<&str>) -> Result<Vec<&str>> {
+        let file: File =
+            File::open(path).with_context(|| format!("Failed to open: {}", path.display()))?;
+        let mut parser = ParserBuilder::default()
+            .stop(ParseStop::BeforeTagValue(tags::PixelData.tag.into()))
+            .dictionary(&STANDARD_DICOM_DICTIONARY)
+            .build(file);
+        let dcmroot: DicomRoot<'_> =
+            DicomRoot::parse(&mut parser)?.ok_or_else(|| anyhow!("file is not dicom"))?;
+        self.route(path, &dcmroot, source_ae)
+    }
+
+    /// Sends the dataset, parsed from the file at `path`, to the destinations of the rules it
+    /// matches, returning the names of those rules. The source AE is the AE Title the dataset was
+    /// received from, otherwise the Source Application Entity Title of the File Meta group is
+    /// used. All destinations are attempted even if some fail.
+    pub(crate) fn route(
+        &self,
+        path: &Path,
+        dcmroot: &DicomRoot<'_>,
+        source_ae: Option<&str>,
+    ) -> Result<Vec<&str>> {
+        let source_ae: Option<String> = source_ae
+            .map(str::to_owned)
+            .or_else(|| text_value(dcmroot, &TagPath::from(&tags::SourceApplicationEntityTitle)));
+
+        let mut matched: Vec<&str> = Vec::new();
+        let mut failures: Vec<String> = Vec::new();
+        for route in &self.routes {
+            if !route.matches(dcmroot, source_ae.as_deref()) {
+                continue;
+            }
+            matched.push(&route.name);
+            for destination in &route.destinations {
+                if let Err(e) = destination.send(path, dcmroot) {
+                    failures.push(format!("{}: {e:#}", route.name));
+                }
+            }
+            if route.stop {
+                break;
+            }
+        }
+
+        if !failures.is_empty() {
+            return Err(anyhow!("Routing failed, {}", failures.join("; ")));
+        }
+        Ok(matched)
+    }
+}
+
+impl Route {
+    fn new(name: String, route: &RouteConfig, config: &Config) -> Result<Route> {
+        let sop_class: Vec<String> = route
+            .sop_class
+            .iter()
+            .map(|sop_class| {
+                STANDARD_DICOM_DICTIONARY
+                    .get_uid_by_name(sop_class)
+                    .map(|uid| uid.uid().to_owned())
+                    .or_else(|| {
+                        sop_class
+                            .bytes()
+                            .all(|b| b.is_ascii_digit() || b == b'.')
+                            .then(|| sop_class.clone())
+                    })
+                    .ok_or_else(|| anyhow!("Unknown SOP Class: {sop_class}"))
+            })
+            .collect::<Result<Vec<String>>>()?;
+        let category: Vec<SOPClassCategory> = route
+            .category
+            .iter()
+            .map(|category| {
+                SOPClassCategory::from_name(category)
+                    .filter(|category| category.is_storage())
+                    .ok_or_else(|| anyhow!("Unknown category: {category}"))
+            })
+            .collect::<Result<Vec<SOPClassCategory>>>()?;
+        let accession: Option<Regex> = route
+            .accession
+            .as_deref()
+            .map(|pattern| {
+                Regex::new(pattern).with_context(|| format!("Invalid accession: {pattern}"))
+            })
+            .transpose()?;
+        let attributes: Vec<(TagPath, Regex)> = route
+            .attributes
+            .iter()
+            .map(|(key, pattern)| {
+                let tagpath: TagPath = TagPath::parse(key, Some(&STANDARD_DICOM_DICTIONARY))
+                    .with_context(|| format!("Unknown attribute: {key}"))?;
+                let pattern: Regex = Regex::new(pattern)
+                    .with_context(|| format!("Invalid pattern for {key}: {pattern}"))?;
+                Ok((tagpath, pattern))
+            })
+            .collect::<Result<Vec<(TagPath, Regex)>>>()?;
+        let matching: Vec<(TagPath, String)> = route
+            .matching
+            .iter()
+            .map(|(key, value)| {
+                let tagpath: TagPath = TagPath::parse(key, Some(&STANDARD_DICOM_DICTIONARY))
+                    .with_context(|| format!("Unknown attribute: {key}"))?;
+                Ok((tagpath, value.clone()))
+            })
+            .collect::<Result<Vec<(TagPath, String)>>>()?;
+        if route.destinations.is_empty() {
+            return Err(anyhow!("No destinations"));
+        }
+        let destinations: Vec<Destination> = route
+            .destinations
+            .iter()
+            .map(|destination| Destination::new(destination, config))
+            .collect::<Result<Vec<Destination>>>()?;
+
+        Ok(Route {
+            name,
+            modality: route.modality.clone(),
+            station_ae: route.station_ae.clone(),
+            sop_class,
+            category,
+            accession,
+            attributes,
+            matching,
+            destinations,
+            stop: route.stop,
+        })
+    }
+
+    fn matches(&self, dcmroot: &DicomRoot<'_>, source_ae: Option<&str>) -> bool {
+        let value = |tag| text_value(dcmroot, &TagPath::from(tag));
+        let any_of = |values: &[String], value: Option<String>| {
+            values.is_empty()
+                || value.is_some_and(|value| values.iter().any(|v| v.eq_ignore_ascii_case(&value)))
+        };
+
+        any_of(&self.modality, value(&tags::Modality))
+            && any_of(&self.station_ae, source_ae.map(str::to_owned))
+            && (self.sop_class.is_empty()
+                || value(&tags::SOPClassUID).is_some_and(|uid| self.sop_class.contains(&uid)))
+            && (self.category.is_empty()
+                || value(&tags::SOPClassUID)
+                    .and_then(|uid| sop_class_category(&uid))
+                    .is_some_and(|category| self.category.contains(&category)))
+            && self.accession.as_ref().is_none_or(|accession| {
+                accession.is_match(&value(&tags::AccessionNumber).unwrap_or_default())
+            })
+            && self.attributes.iter().all(|(tagpath, pattern)| {
+                pattern.is_match(&text_value(dcmroot, tagpath).unwrap_or_default())
+            })
+            && self
+                .matching
+                .iter()
+                .all(|(tagpath, key)| matches_key(dcmroot, tagpath, key))
+    }
+}
+
+impl Destination {
+    fn new(destination: &RouteDestination, config: &Config) -> Result<Destination> {
+        Ok(match destination {
+            RouteDestination::Folder { folder, layout } => {
+                let layout: &str = layout
+                    .as_deref()
+                    .or(config.archive.layout.as_deref())
+                    .unwrap_or(DEFAULT_LAYOUT);
+                Destination::Folder {
+                    dir: folder.clone(),
+                    layout: Layout::parse(layout)?,
+                }
+            }
+            RouteDestination::Ae { ae, host, port } => {
+                let remote: RemoteArgs = RemoteArgs {
+                    host: host.clone(),
+                    port: *port,
+                    called_ae: ae.clone(),
+                    calling_ae: None,
+                    connect_timeout: CONNECT_TIMEOUT_SECS,
+                    timeout: RESPONSE_TIMEOUT_SECS,
+                };
+                Destination::Ae {
+                    calling_ae: calling_ae(&remote, config).to_owned(),
+                    remote,
+                }
+            }
+            RouteDestination::Stow { stow } => Destination::Stow(StowEndpoint::parse(stow)?),
+        })
+    }
+
+    fn send(&self, path: &Path, dcmroot: &DicomRoot<'_>) -> Result<()> {
+        match self {
+            Destination::Folder { dir, layout } => {
+                let dest: PathBuf = dir.join(layout.render(dcmroot));
+                if let Some(parent) = dest.parent() {
+                    fs::create_dir_all(parent).with_context(|| {
+                        format!("Failed to create folder: {}", parent.display())
+                    })?;
+                }
+                fs::copy(path, &dest).with_context(|| {
+                    format!("Failed to copy {} to {}", path.display(), dest.display())
+                })?;
+            }
+            Destination::Ae { remote, calling_ae } => {
+                let addr: SocketAddr = resolve_remote(remote)?;
+                // The dataset may only have been parsed up to its Pixel Data.
+                let dcmroot: DicomRoot<'_> = parse_dataset(path)?;
+                let status: u16 = send_dataset(remote, calling_ae, &addr, path, &dcmroot)?;
+                if let Ok(Status::Failure(_)) = Status::try_from(u32::from(status)) {
+                    return Err(anyhow!(
+                        "{} responded with failure status {status:#06X}",
+                        remote.called_ae
+                    ));
+                }
+            }
+            Destination::Stow(endpoint) => endpoint.store(path)?,
+        }
+        Ok(())
+    }
+}
+
+/// The STOW-RS service of a DICOMweb server. Only plain HTTP is supported, so servers requiring
+/// TLS need to be reached through a proxy.
+#[derive(Debug, PartialEq)]
+struct StowEndpoint {
+    /// The host and port of the URL, as given in the `Host` header.
+    authority: String,
+    /// The host name or address, without the brackets of an IPv6 address.
+    host: String,
+    port: u16,
+    path: String,
+}
+
+impl StowEndpoint {
+    /// Parses the base URL of the DICOMweb service, e.g. `http://host:8080/dicom-web`. IPv6
+    /// addresses are enclosed in brackets, e.g. `http://[::1]:8080/dicom-web`.
+    fn parse(url: &str) -> Result<StowEndpoint> {
+        let rest: &str = url
+            .strip_prefix("http://")
+            .ok_or_else(|| anyhow!("Only http:// STOW-RS URLs are supported: {url}"))?;
+        let (authority, base) = rest.split_once('/').unwrap_or((rest, ""));
+        let (host, port) = match authority.strip_prefix('[') {
+            Some(bracketed) => {
+                let (host, rest) = bracketed
+                    .split_once(']')
+                    .ok_or_else(|| anyhow!("Invalid IPv6 address in URL: {url}"))?;
+                let port: Option<&str> = match rest {
+                    "" => None,
+                    _ => Some(
+                        rest.strip_prefix(':')
+                            .ok_or_else(|| anyhow!("Invalid port in URL: {url}"))?,
+                    ),
+                };
+                (host, port)
+            }
+            None => match authority.split_once(':') {
+                Some((host, port)) => (host, Some(port)),
+                None => (authority, None),
+            },
+        };
+        if host.is_empty() {
+            return Err(anyhow!("No host in URL: {url}"));
+        }
+        let port: u16 = match port {
+            Some(port) => port
+                .parse::<u16>()
+                .with_context(|| format!("Invalid port in URL: {url}"))?,
+            None => 80,
+        };
+        let base: &str = base.trim_end_matches('/');
+        let path: String = if base.is_empty() {
+            "/studies".to_owned()
+        } else {
+            format!("/{base}/studies")
+        };
+        Ok(StowEndpoint {
+            authority: authority.to_owned(),
+            host: host.to_owned(),
+            port,
+            path,
+        })
+    }
+
+    /// Resolves the address of the host.
+    fn resolve(&self) -> Result<SocketAddr> {
+        (self.host.as_str(), self.port)
+            .to_socket_addrs()
+            .with_context(|| format!("Failed to resolve host: {}", self.host))?
+            .next()
+            .ok_or_else(|| anyhow!("No address for host: {}", self.host))
+    }
+
+    /// Stores the file with a STOW-RS request, as a single part of a `multipart/related` body.
+    fn store(&self, path: &Path) -> Result<()> {
+        let dataset: Vec<u8> =
+            fs::read(path).with_context(|| format!("Failed to read: {}", path.display()))?;
+        let boundary: String = format!("dcmpipe-{:032x}", rand::random::<u128>());
+        let mut body: Vec<u8> = Vec::with_capacity(dataset.len() + 256);
+        write!(
+            body,
+            "--{boundary}\r\nContent-Type: application/dicom\r\n\r\n"
+        )?;
+        body.extend_from_slice(&dataset);
+        write!(body, "\r\n--{boundary}--\r\n")?;
+
+        let mut stream: TcpStream =
+            TcpStream::connect_timeout(&self.resolve()?, Duration::from_secs(CONNECT_TIMEOUT_SECS))
+                .with_context(|| format!("Failed to connect to {}", self.authority))?;
+        let timeout: Option<Duration> = Some(Duration::from_secs(RESPONSE_TIMEOUT_SECS));
+        stream.set_read_timeout(timeout)?;
+        stream.set_write_timeout(timeout)?;
+        write!(
+            stream,
+            "POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: multipart/related; type=\"application/dicom\"; boundary={boundary}\r\nAccept: application/dicom+json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            self.path,
+            self.authority,
+            body.len()
+        )?;
+        stream.write_all(&body)?;
+        stream.flush()?;
+
+        let mut status_line: String = String::new();
+        BufReader::new(&stream).read_line(&mut status_line)?;
+        let status: u16 = status_line
+            .split_whitespace()
+            .nth(1)
+            .and_then(|status| status.parse().ok())
+            .ok_or_else(|| anyhow!("Invalid response from {}: {status_line:?}", self.authority))?;
+        // 202 indicates some instances failed, which for a single instance means it wasn't stored.
+        if status != 200 {
+            return Err(anyhow!(
+                "{} responded with: {}",
+                self.authority,
+                status_line.trim_end()
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Whether the element at `tagpath` matches the key, see `core::matching`. A missing element only
+/// matches universal matching.
+fn matches_key(dcmroot: &DicomRoot<'_>, tagpath: &TagPath, key: &str) -> bool {
+    match dcmroot.get_child_by_tagpath(tagpath) {
+        Some(obj) => Matcher::parse(obj.element().vr(), key)
+            .matches_element(obj.element())
+            .unwrap_or(false),
+        None => key.is_empty() || key == "*",
+    }
+}
+
+/// The value of the element at `tagpath` as a single trimmed string, if present and not empty.
+fn text_value(dcmroot: &DicomRoot<'_>, tagpath: &TagPath) -> Option<String> {
+    dcmroot
+        .get_child_by_tagpath(tagpath)
+        .and_then(|obj| element_value(obj.element()))
+        .map(|value| value.trim().to_owned())
+        .filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use dcmpipe_lib::{
+        core::{
+            charset::DEFAULT_CHARACTER_SET,
+            dcmelement::DicomElement,
+            dcmobject::DicomObject,
+            defn::{tag::Tag, vr},
+            values::RawValue,
+        },
+        dict::transfer_syntaxes::ExplicitVRLittleEndian,
+    };
+
+    use super::*;
+
+    /// A dataset of a CT image with the given accession number.
+    fn ct_dataset(accession: &str) -> DicomRoot<'static> {
+        let elements: [(&Tag, vr::VRRef, &str); 5] = [
+            (&tags::SOPClassUID, &vr::UI, "1.2.840.10008.5.1.4.1.1.2"),
+            (&tags::StudyDate, &vr::DA, "20240115"),
+            (&tags::AccessionNumber, &vr::SH, accession),
+            (&tags::Modality, &vr::CS, "CT"),
+            (&tags::PatientsName, &vr::PN, "Doe^John"),
+        ];
+        let mut child_nodes: BTreeMap<u32, DicomObject<'static>> = BTreeMap::new();
+        for (tag, vr, value) in elements {
+            let mut element: DicomElement =
+                DicomElement::new_empty(tag, vr, &ExplicitVRLittleEndian);
+            element
+                .encode_value(RawValue::Strings(vec![value.to_owned()]), None)
+                .unwrap();
+            child_nodes.insert(tag.tag, DicomObject::new(element));
+        }
+        DicomRoot::new(
+            &ExplicitVRLittleEndian,
+            DEFAULT_CHARACTER_SET,
+            &STANDARD_DICOM_DICTIONARY,
+            child_nodes,
+            Vec::new(),
+        )
+    }
+
+    /// The names of the routes of the configuration which match the dataset.
+    fn matching_routes(
+        config: &str,
+        dcmroot: &DicomRoot<'_>,
+        source_ae: Option<&str>,
+    ) -> Vec<String> {
+        let config: Config = toml::from_str(config).unwrap();
+        Router::new(&config)
+            .unwrap()
+            .routes
+            .iter()
+            .filter(|route| route.matches(dcmroot, source_ae))
+            .map(|route| route.name.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_route_matching() {
+        let config: &str = r#"
+            [[route]]
+            name = "all"
+            destinations = [{ folder = "/tmp/all" }]
+
+            [[route]]
+            name = "ct"
+            modality = ["mr", "ct"]
+            destinations = [{ folder = "/tmp/ct" }]
+
+            [[route]]
+            name = "mr"
+            modality = ["MR"]
+            destinations = [{ folder = "/tmp/mr" }]
+
+            [[route]]
+            name = "from modality"
+            station_ae = ["MODALITY1"]
+            destinations = [{ folder = "/tmp/modality" }]
+
+            [[route]]
+            name = "ct images"
+            sop_class = ["CTImageStorage"]
+            category = ["image"]
+            destinations = [{ folder = "/tmp/images" }]
+
+            [[route]]
+            name = "accession"
+            accession = "^A1"
+            destinations = [{ folder = "/tmp/accession" }]
+
+            [[route]]
+            name = "attributes"
+            attributes = { PatientsName = "^Doe\\^" }
+            match = { StudyDate = "20240101-20240131", PatientsName = "DOE*" }
+            destinations = [{ folder = "/tmp/attributes" }]
+
+            [[route]]
+            name = "missing"
+            match = { StudyDescription = "*", PatientID = "" }
+            destinations = [{ folder = "/tmp/missing" }]
+
+            [[route]]
+            name = "missing value"
+            match = { PatientID = "PAT1" }
+            destinations = [{ folder = "/tmp/missing-value" }]
+        "#;
+        assert_eq!(
+            vec![
+                "all",
+                "ct",
+                "from modality",
+                "ct images",
+                "accession",
+                "attributes",
+                "missing"
+            ],
+            matching_routes(config, &ct_dataset("A123"), Some("MODALITY1"))
+        );
+        assert_eq!(
+            vec!["all", "ct", "ct images", "attributes", "missing"],
+            matching_routes(config, &ct_dataset("B123"), None)
+        );
+    }
+
+    #[test]
+    fn test_route_stop() {
+        let dir: PathBuf =
+            std::env::temp_dir().join(format!("dcmpipe-routing-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path: PathBuf = dir.join("ct.dcm");
+        fs::write(&path, b"not parsed").unwrap();
+        let config: Config = toml::from_str(&format!(
+            r#"
+            [[route]]
+            name = "first"
+            modality = ["CT"]
+            stop = true
+            destinations = [{{ folder = "{first}", layout = "first.dcm" }}]
+
+            [[route]]
+            name = "second"
+            destinations = [{{ folder = "{second}", layout = "second.dcm" }}]
+            "#,
+            first = dir.join("first").display(),
+            second = dir.join("second").display(),
+        ))
+        .unwrap();
+        let router: Router = Router::new(&config).unwrap();
+
+        let matched: Vec<&str> = router.route(&path, &ct_dataset("A123"), None).unwrap();
+        let first: bool = dir.join("first/first.dcm").is_file();
+        let second: bool = dir.join("second").exists();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(vec!["first"], matched);
+        assert!(first);
+        assert!(!second);
+        assert!(Router::new(&Config::default()).is_err());
+    }
+
+    #[test]
+    fn test_invalid_routes() {
+        for route in [
+            r#"modality = ["CT"]"#,
+            r#"sop_class = ["NotASOPClass"], destinations = [{ folder = "/tmp" }]"#,
+            r#"category = ["query"], destinations = [{ folder = "/tmp" }]"#,
+            r#"accession = "(", destinations = [{ folder = "/tmp" }]"#,
+            r#"match = { NotAnAttribute = "1" }, destinations = [{ folder = "/tmp" }]"#,
+            r#"destinations = [{ stow = "https://example.org/dicom-web" }]"#,
+        ] {
+            let config: Config = toml::from_str(&format!("route = [{{ {route} }}]")).unwrap();
+            assert!(Router::new(&config).is_err(), "{route}");
+        }
+    }
+
+    #[test]
+    fn test_stow_url() {
+        let endpoint = |authority: &str, host: &str, port: u16, path: &str| StowEndpoint {
+            authority: authority.to_owned(),
+            host: host.to_owned(),
+            port,
+            path: path.to_owned(),
+        };
+        assert_eq!(
+            endpoint(
+                "example.org:8080",
+                "example.org",
+                8080,
+                "/dicom-web/studies"
+            ),
+            StowEndpoint::parse("http://example.org:8080/dicom-web").unwrap()
+        );
+        assert_eq!(
+            endpoint("example.org", "example.org", 80, "/studies"),
+            StowEndpoint::parse("http://example.org").unwrap()
+        );
+        assert_eq!(
+            endpoint("example.org", "example.org", 80, "/a/b/studies"),
+            StowEndpoint::parse("http://example.org/a/b/").unwrap()
+        );
+        assert_eq!(
+            endpoint("[::1]:8080", "::1", 8080, "/dicom-web/studies"),
+            StowEndpoint::parse("http://[::1]:8080/dicom-web").unwrap()
+        );
+        assert_eq!(
+            endpoint("[fe80::1]", "fe80::1", 80, "/studies"),
+            StowEndpoint::parse("http://[fe80::1]/").unwrap()
+        );
+        assert_eq!(
+            "[::1]:8080".parse::<SocketAddr>().unwrap(),
+            StowEndpoint::parse("http://[::1]:8080")
+                .unwrap()
+                .resolve()
+                .unwrap()
+        );
+
+        for url in [
+            "https://example.org",
+            "example.org",
+            "http://",
+            "http://:8080",
+            "http://example.org:port",
+            "http://example.org:70000",
+            "http://::1/dicom-web",
+            "http://[::1/dicom-web",
+            "http://[::1]8080/dicom-web",
+        ] {
+            assert!(StowEndpoint::parse(url).is_err(), "{url}");
+        }
+    }
+}
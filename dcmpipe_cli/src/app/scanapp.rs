@@ -1,16 +1,23 @@
-use std::fs::File;
-use std::path::PathBuf;
+use std::path::Path;
 
 use anyhow::Result;
-use walkdir::WalkDir;
 
+use dcmpipe_lib::core::dcmelement::DicomElement;
+use dcmpipe_lib::core::dcmobject::DicomRoot;
+use dcmpipe_lib::core::hash::{ContentHash, DatasetHasher, HashScope};
 use dcmpipe_lib::core::read::{Parser, ParserBuilder};
 use dcmpipe_lib::dict::stdlookup::STANDARD_DICOM_DICTIONARY;
+use dcmpipe_lib::progress::ProgressReporter;
+use dcmpipe_lib::source::{Entries, EntryReader, Source, SourceEntry};
 
-use crate::{app::CommandApplication, args::ScanArgs};
+use crate::{
+    app::{pathref::encode_pathref, progress::ProgressBar, CommandApplication},
+    args::{HashScopeArg, ScanArgs},
+    shutdown,
+};
 
 enum ScanResult {
-    Success,
+    Success(Option<ContentHash>),
     NotDicom,
     InvalidData(Box<dyn std::error::Error>),
 }
@@ -24,14 +31,7 @@ impl ScanApp {
         ScanApp { args }
     }
 
-    fn get_files(&self) -> impl Iterator<Item = PathBuf> {
-        WalkDir::new(&self.args.folder)
-            .into_iter()
-            .map(|entry_res| entry_res.expect("walkdir entry").path().to_path_buf())
-            .filter(|path: &PathBuf| path.is_file())
-    }
-
-    fn parse_all_element_values(&self, parser: Parser<'_, File>) -> ScanResult {
+    fn parse_all_element_values(&self, parser: Parser<'_, EntryReader<'_>>) -> ScanResult {
         let mut is_first_elem: bool = true;
         for elem_result in parser {
             match elem_result {
@@ -51,32 +51,101 @@ impl ScanApp {
             is_first_elem = false;
         }
 
-        ScanResult::Success
+        ScanResult::Success(None)
+    }
+
+    /// Parses the entire dataset, checking that all element values parse, and computes its hash.
+    fn hash_dataset(
+        &self,
+        mut parser: Parser<'_, EntryReader<'_>>,
+        hasher: &DatasetHasher,
+    ) -> ScanResult {
+        let dcmroot: DicomRoot<'_> = match DicomRoot::parse(&mut parser) {
+            Ok(Some(dcmroot)) => dcmroot,
+            Ok(None) => return ScanResult::NotDicom,
+            Err(e) => return ScanResult::InvalidData(Box::new(e)),
+        };
+        let elements: Vec<&DicomElement> = match dcmroot.flatten() {
+            Ok(elements) => elements,
+            Err(e) => return ScanResult::InvalidData(Box::new(e)),
+        };
+        for elem in elements {
+            if let Err(e) = elem.parse_value() {
+                return ScanResult::InvalidData(Box::new(e));
+            }
+        }
+        match hasher.hash(&dcmroot) {
+            Ok(hash) => ScanResult::Success(Some(hash)),
+            Err(e) => ScanResult::InvalidData(Box::new(e)),
+        }
+    }
+}
+
+impl From<HashScopeArg> for HashScope {
+    fn from(value: HashScopeArg) -> Self {
+        match value {
+            HashScopeArg::Full => HashScope::Full,
+            HashScopeArg::Header => HashScope::Header,
+            HashScopeArg::PixelData => HashScope::PixelData,
+        }
     }
 }
 
 impl CommandApplication for ScanApp {
     fn run(&mut self) -> Result<()> {
+        shutdown::install_handler()?;
+
         let parser_builder: ParserBuilder<'_> =
             ParserBuilder::default().dictionary(&STANDARD_DICOM_DICTIONARY);
+        let hasher: Option<DatasetHasher> = self
+            .args
+            .hash
+            .map(|scope| DatasetHasher::new(scope.into()).normalize(self.args.normalize));
+
+        let mut progress: ProgressBar = ProgressBar::new(self.args.no_progress);
+        let mut entries: Entries = Source::new(&self.args.folder).entries()?;
+        while let Some(entry) = entries.next() {
+            progress.report(entries.progress());
+            let entry: SourceEntry = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    let e: anyhow::Error = e.into();
+                    progress.println(format_args!("Failure Reading: {e:#}"));
+                    tracing::warn!(error = format!("{e:#}"), "failed reading source");
+                    continue;
+                }
+            };
+            let path: &Path = entry.path();
+            if shutdown::is_requested() {
+                progress.println(format_args!("Scan interrupted before: {}", path.display()));
+                break;
+            }
 
-        for path in self.get_files() {
-            let file: File = File::open(path.clone())?;
-            let parser: Parser<'_, File> = parser_builder.build(file);
+            let parser: Parser<'_, EntryReader<'_>> = parser_builder.build(entry.open()?);
 
-            let relative_path: &str = path
-                .strip_prefix(&self.args.folder)?
-                .to_str()
-                .expect("relative path");
+            let relative_path: &Path = path.strip_prefix(&self.args.folder).unwrap_or(path);
 
-            match self.parse_all_element_values(parser) {
-                ScanResult::Success => {}  /*println!("Valid DICOM: {}", path_str),*/
-                ScanResult::NotDicom => {} /*println!("Not DICOM: {}", relative_path),*/
+            let result: ScanResult = match &hasher {
+                Some(hasher) => self.hash_dataset(parser, hasher),
+                None => self.parse_all_element_values(parser),
+            };
+            match result {
+                ScanResult::Success(Some(hash)) => {
+                    progress.println(format_args!("{hash}  {}", encode_pathref(relative_path)))
+                }
+                ScanResult::Success(None) => {} /*println!("Valid DICOM: {}", path_str),*/
+                ScanResult::NotDicom => {}      /*println!("Not DICOM: {}", relative_path),*/
                 ScanResult::InvalidData(e) => {
-                    println!("Failure Parsing: {}\n\t{}", relative_path, e)
+                    progress.println(format_args!(
+                        "Failure Parsing: {}\n\t{}",
+                        relative_path.display(),
+                        e
+                    ));
+                    tracing::warn!(path = %relative_path.display(), error = %e, "failed parsing dataset");
                 }
             };
         }
+        progress.finish(entries.progress());
 
         Ok(())
     }
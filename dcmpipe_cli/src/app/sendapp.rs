@@ -0,0 +1,481 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::File,
+    net::{SocketAddr, TcpStream},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc::{self, Sender},
+    },
+    thread,
+};
+
+use anyhow::{anyhow, Context, Result};
+use walkdir::WalkDir;
+
+use dcmpipe_lib::{
+    core::{
+        dcmelement::DicomElement,
+        dcmobject::DicomRoot,
+        defn::{constants::ts, tag::TagRef, ts::TSRef},
+        read::{stop::ParseStop, ParserBuilder},
+        write::transcode::transcode,
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags},
+    dimse::{
+        assoc::{Association, AssociationBuilder, PresentationContext},
+        commands::{encode_elements, CommandBuilder, Message},
+        constants::{CommandField, Priority, Status},
+        error::DimseError,
+    },
+};
+
+use crate::{
+    app::{associate_remote, calling_ae, resolve_remote, CommandApplication},
+    args::{RemoteArgs, SendArgs},
+    config::Config,
+    shutdown,
+};
+
+/// The uncompressed transfer syntaxes proposed in addition to a dataset's own, which it can be
+/// converted to if the remote AE doesn't accept its own.
+static FALLBACK_TS: [TSRef; 2] = [&ts::ExplicitVRLittleEndian, &ts::ImplicitVRLittleEndian];
+
+pub struct SendApp {
+    args: SendArgs,
+    config: Config,
+    /// Set once the remote AE rejects an association, after which no further datasets are sent as
+    /// it's expected to reject them all.
+    rejected: AtomicBool,
+}
+
+/// A dataset to send, with the attributes needed to negotiate a presentation context for it.
+struct SendFile {
+    path: PathBuf,
+    sop_class_uid: String,
+    sop_instance_uid: String,
+    ts: TSRef,
+}
+
+/// The result of sending a dataset.
+enum Outcome {
+    /// The remote AE responded with the given status.
+    Status(u16),
+    /// The dataset couldn't be sent, or no response was received.
+    Failed(String),
+}
+
+impl SendApp {
+    pub fn new(args: SendArgs, config: Config) -> SendApp {
+        SendApp {
+            args,
+            config,
+            rejected: AtomicBool::new(false),
+        }
+    }
+
+    /// Finds the datasets to send, reading each up to its Pixel Data. Files which aren't DICOM are
+    /// reported and skipped.
+    fn scan_files(&self) -> Result<(Vec<SendFile>, usize)> {
+        let parser_builder: ParserBuilder<'_> = ParserBuilder::default()
+            .stop(ParseStop::BeforeTagValue(tags::PixelData.tag.into()))
+            .dictionary(&STANDARD_DICOM_DICTIONARY);
+
+        let mut files: Vec<SendFile> = Vec::new();
+        let mut skipped: usize = 0;
+        for path in &self.args.paths {
+            if !path.exists() {
+                return Err(anyhow!("invalid path: {}", path.display()));
+            }
+            let walkdir = WalkDir::new(path)
+                .sort_by_file_name()
+                .into_iter()
+                .filter_map(|e| e.ok())
+                .filter(|e| e.file_type().is_file());
+            for entry in walkdir {
+                let path: &Path = entry.path();
+                let file: File = File::open(path)?;
+                let dcmroot: DicomRoot<'_> = match DicomRoot::parse(&mut parser_builder.build(file))
+                {
+                    Ok(Some(dcmroot)) => dcmroot,
+                    Ok(None) => {
+                        println!("Skipping non-DICOM file: {}", path.display());
+                        skipped += 1;
+                        continue;
+                    }
+                    Err(e) => {
+                        println!("Skipping invalid DICOM file: {}, {e}", path.display());
+                        skipped += 1;
+                        continue;
+                    }
+                };
+
+                let sop_class_uid: Option<String> = uid_value(
+                    &dcmroot,
+                    &[&tags::SOPClassUID, &tags::MediaStorageSOPClassUID],
+                );
+                let sop_instance_uid: Option<String> = uid_value(
+                    &dcmroot,
+                    &[&tags::SOPInstanceUID, &tags::MediaStorageSOPInstanceUID],
+                );
+                let (Some(sop_class_uid), Some(sop_instance_uid)) =
+                    (sop_class_uid, sop_instance_uid)
+                else {
+                    println!("Skipping dataset without SOP UIDs: {}", path.display());
+                    skipped += 1;
+                    continue;
+                };
+
+                files.push(SendFile {
+                    path: path.to_path_buf(),
+                    sop_class_uid,
+                    sop_instance_uid,
+                    ts: dcmroot.ts(),
+                });
+            }
+        }
+        Ok((files, skipped))
+    }
+
+    /// Sends datasets until there are none left or a shutdown is requested, reporting the outcome
+    /// of each. The association is established when first needed and re-established after any
+    /// network error.
+    fn send_files(
+        &self,
+        assoc_builder: &AssociationBuilder,
+        addr: &SocketAddr,
+        files: &[SendFile],
+        next_file: &AtomicUsize,
+        outcomes: Sender<(usize, Outcome)>,
+    ) {
+        let mut conn: Option<(Association, TcpStream)> = None;
+        while !shutdown::is_requested() && !self.rejected.load(Ordering::SeqCst) {
+            let index: usize = next_file.fetch_add(1, Ordering::SeqCst);
+            let Some(file) = files.get(index) else {
+                break;
+            };
+            let outcome: Outcome = self.send_with_retry(assoc_builder, addr, file, &mut conn);
+            if outcomes.send((index, outcome)).is_err() {
+                break;
+            }
+        }
+
+        if let Some((assoc, mut stream)) = conn {
+            // The datasets have all been sent so a failure to release cleanly isn't worth failing
+            // over.
+            if let Err(e) = assoc.release(&mut stream) {
+                eprintln!("Failed to release association: {e}");
+            }
+        }
+    }
+
+    /// Sends a dataset, retrying after failure statuses and network errors.
+    fn send_with_retry(
+        &self,
+        assoc_builder: &AssociationBuilder,
+        addr: &SocketAddr,
+        file: &SendFile,
+        conn: &mut Option<(Association, TcpStream)>,
+    ) -> Outcome {
+        // The entire dataset is needed to send it, unlike when scanning.
+        let dcmroot: DicomRoot<'_> = match parse_dataset(&file.path) {
+            Ok(dcmroot) => dcmroot,
+            Err(e) => return Outcome::Failed(format!("{e:#}")),
+        };
+
+        let mut outcome: Outcome = Outcome::Failed("not sent".to_owned());
+        for attempt in 0..=self.args.retries {
+            if attempt > 0 && shutdown::is_requested() {
+                break;
+            }
+
+            if conn.is_none() {
+                match associate_remote(&self.args.remote, addr, assoc_builder) {
+                    Ok(established) => *conn = Some(established),
+                    Err(e) => {
+                        if let Some(DimseError::AssociationRejected { .. }) = e.downcast_ref() {
+                            self.rejected.store(true, Ordering::SeqCst);
+                            return Outcome::Failed(format!("{e:#}"));
+                        }
+                        outcome = Outcome::Failed(format!("{e:#}"));
+                        continue;
+                    }
+                }
+            }
+            let Some((assoc, stream)) = conn.as_mut() else {
+                continue;
+            };
+
+            let Some(pres_ctx) = select_pres_ctx(assoc, file).cloned() else {
+                // Each association proposes the same presentation contexts, so retrying won't help.
+                return Outcome::Failed(format!(
+                    "no presentation context accepted for SOP Class {} in {}",
+                    file.sop_class_uid,
+                    file.ts.uid().ident()
+                ));
+            };
+            let dataset: Vec<u8> = match encode_for(&dcmroot, pres_ctx.ts()) {
+                Ok(dataset) => dataset,
+                Err(e) => return Outcome::Failed(format!("{e:#}")),
+            };
+
+            match store(assoc, stream, &pres_ctx, file, dataset) {
+                Ok(status) => {
+                    outcome = Outcome::Status(status);
+                    if !matches!(Status::try_from(u32::from(status)), Ok(Status::Failure(_))) {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    // The association can't be relied on after a network or protocol error.
+                    if let Some((assoc, mut stream)) = conn.take() {
+                        let _ = assoc.abort(&mut stream);
+                    }
+                    outcome = Outcome::Failed(format!("{e:#}"));
+                }
+            }
+        }
+        outcome
+    }
+}
+
+impl CommandApplication for SendApp {
+    fn run(&mut self) -> Result<()> {
+        shutdown::install_handler()?;
+
+        let calling_ae: &str = calling_ae(&self.args.remote, &self.config);
+        let addr: SocketAddr = resolve_remote(&self.args.remote)?;
+
+        let (files, skipped) = self.scan_files()?;
+        if files.is_empty() {
+            println!("No datasets to send, skipped {skipped}");
+            return Ok(());
+        }
+
+        // Propose each dataset's own transfer syntax, along with those it can be converted to.
+        let mut proposals: BTreeMap<(&str, &str), TSRef> = BTreeMap::new();
+        for file in &files {
+            proposals.insert((&file.sop_class_uid, file.ts.uid().uid()), file.ts);
+        }
+        let mut assoc_builder: AssociationBuilder =
+            AssociationBuilder::default().ae_title(calling_ae);
+        for ((sop_class_uid, _), file_ts) in proposals {
+            assoc_builder = assoc_builder.presentation_context(sop_class_uid, proposed_ts(file_ts));
+        }
+
+        println!(
+            "Sending {} datasets to {}@{addr} as {calling_ae}",
+            files.len(),
+            self.args.remote.called_ae
+        );
+        let next_file: AtomicUsize = AtomicUsize::new(0);
+        let (sender, receiver) = mpsc::channel::<(usize, Outcome)>();
+        let workers: usize = usize::from(self.args.concurrency).min(files.len());
+        let mut summary: BTreeMap<&'static str, usize> = BTreeMap::new();
+        let mut failures: Vec<(&Path, String)> = Vec::new();
+        thread::scope(|scope| {
+            for _ in 0..workers {
+                let sender: Sender<(usize, Outcome)> = sender.clone();
+                scope.spawn(|| {
+                    self.send_files(&assoc_builder, &addr, &files, &next_file, sender);
+                });
+            }
+            drop(sender);
+
+            for (index, outcome) in receiver {
+                let path: &Path = &files[index].path;
+                let category: &'static str = match &outcome {
+                    Outcome::Status(status) => match Status::try_from(u32::from(*status)) {
+                        Ok(Status::Success(_)) => "Success",
+                        Ok(Status::Warning(_)) => {
+                            println!("Warning {status:#06X}: {}", path.display());
+                            "Warning"
+                        }
+                        _ => {
+                            println!("Failure {status:#06X}: {}", path.display());
+                            failures.push((path, format!("status {status:#06X}")));
+                            "Failure"
+                        }
+                    },
+                    Outcome::Failed(reason) => {
+                        println!("Failed: {}, {reason}", path.display());
+                        failures.push((path, reason.clone()));
+                        "Failure"
+                    }
+                };
+                *summary.entry(category).or_default() += 1;
+            }
+        });
+
+        let sent: usize = summary.values().sum();
+        if sent < files.len() {
+            println!("{} datasets were not sent", files.len() - sent);
+        }
+        println!("Sent {sent} datasets, skipped {skipped}");
+        for category in ["Success", "Warning", "Failure"] {
+            println!(
+                "  {category}: {}",
+                summary.get(category).copied().unwrap_or_default()
+            );
+        }
+
+        if !failures.is_empty() {
+            println!("Failed datasets:");
+            for (path, reason) in &failures {
+                println!("  {}: {reason}", path.display());
+            }
+            return Err(anyhow!("{} datasets failed to send", failures.len()));
+        }
+        Ok(())
+    }
+}
+
+/// Sends a single dataset over an association of its own, returning the status of the response.
+/// Used where datasets arrive one at a time, so the SOP Classes to propose aren't known up front.
+pub(crate) fn send_dataset(
+    remote: &RemoteArgs,
+    calling_ae: &str,
+    addr: &SocketAddr,
+    path: &Path,
+    dcmroot: &DicomRoot<'_>,
+) -> Result<u16> {
+    let sop_class_uid: String = uid_value(
+        dcmroot,
+        &[&tags::SOPClassUID, &tags::MediaStorageSOPClassUID],
+    )
+    .ok_or_else(|| anyhow!("Dataset has no SOPClassUID"))?;
+    let sop_instance_uid: String = uid_value(
+        dcmroot,
+        &[&tags::SOPInstanceUID, &tags::MediaStorageSOPInstanceUID],
+    )
+    .ok_or_else(|| anyhow!("Dataset has no SOPInstanceUID"))?;
+    let file: SendFile = SendFile {
+        path: path.to_path_buf(),
+        sop_class_uid,
+        sop_instance_uid,
+        ts: dcmroot.ts(),
+    };
+
+    let assoc_builder: AssociationBuilder = AssociationBuilder::default()
+        .ae_title(calling_ae)
+        .presentation_context(&file.sop_class_uid, proposed_ts(file.ts));
+    let (mut assoc, mut stream) = associate_remote(remote, addr, &assoc_builder)?;
+    let result: Result<u16> = select_pres_ctx(&assoc, &file)
+        .cloned()
+        .ok_or_else(|| {
+            anyhow!(
+                "no presentation context accepted for SOP Class {} in {}",
+                file.sop_class_uid,
+                file.ts.uid().ident()
+            )
+        })
+        .and_then(|pres_ctx| {
+            let dataset: Vec<u8> = encode_for(dcmroot, pres_ctx.ts())?;
+            store(&mut assoc, &mut stream, &pres_ctx, &file, dataset)
+        });
+    match &result {
+        Ok(_) => {
+            if let Err(e) = assoc.release(&mut stream) {
+                eprintln!("Failed to release association: {e}");
+            }
+        }
+        Err(_) => {
+            let _ = assoc.abort(&mut stream);
+        }
+    }
+    result
+}
+
+/// The transfer syntaxes proposed for a dataset encoded in `file_ts`, which are its own along with
+/// those it can be converted to. Encapsulated pixel data can't be converted so is only proposed
+/// as-is.
+fn proposed_ts(file_ts: TSRef) -> Vec<TSRef> {
+    let mut transfer_syntaxes: Vec<TSRef> = vec![file_ts];
+    if !file_ts.encapsulated() {
+        let mut proposed: BTreeSet<&str> = BTreeSet::from([file_ts.uid().uid()]);
+        transfer_syntaxes.extend(
+            FALLBACK_TS
+                .iter()
+                .filter(|ts| proposed.insert(ts.uid().uid()))
+                .copied(),
+        );
+    }
+    transfer_syntaxes
+}
+
+/// Gets the first non-empty UID value of the given tags.
+fn uid_value(dcmroot: &DicomRoot<'_>, tags: &[TagRef]) -> Option<String> {
+    tags.iter().find_map(|tag| {
+        dcmroot
+            .get_child_by_tag(tag.tag)
+            .and_then(|obj| String::try_from(obj.element()).ok())
+            .filter(|uid| !uid.is_empty())
+    })
+}
+
+/// Parses the entire dataset of the file.
+pub(crate) fn parse_dataset(path: &Path) -> Result<DicomRoot<'static>> {
+    let file: File =
+        File::open(path).with_context(|| format!("Failed to open: {}", path.display()))?;
+    let mut parser = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .build(file);
+    DicomRoot::parse(&mut parser)?.ok_or_else(|| anyhow!("file is not dicom"))
+}
+
+/// Selects the presentation context to send the dataset over, preferring one whose transfer
+/// syntax is the dataset's own so it's sent without conversion.
+fn select_pres_ctx<'a>(assoc: &'a Association, file: &SendFile) -> Option<&'a PresentationContext> {
+    let mut candidates = assoc
+        .pres_ctxs()
+        .iter()
+        .filter(|pres_ctx| pres_ctx.abstract_syntax() == file.sop_class_uid);
+    candidates
+        .clone()
+        .find(|pres_ctx| pres_ctx.ts().uid().uid() == file.ts.uid().uid())
+        .or_else(|| {
+            candidates.find(|pres_ctx| !pres_ctx.ts().encapsulated() && !file.ts.encapsulated())
+        })
+}
+
+/// Encodes the dataset for sending in the transfer syntax `ts`, converting it if needed. Elements
+/// are passed through `transcode` even when the transfer syntax is unchanged, as it also corrects
+/// the encoding of private sequences with a VR of UN.
+fn encode_for(dcmroot: &DicomRoot<'_>, ts: TSRef) -> Result<Vec<u8>> {
+    let elements: Vec<DicomElement> = transcode(dcmroot, ts)?;
+    Ok(encode_elements(elements.iter(), ts)?)
+}
+
+/// Sends a C-STORE-RQ for the dataset and waits for the response, returning its status.
+fn store(
+    assoc: &mut Association,
+    stream: &mut TcpStream,
+    pres_ctx: &PresentationContext,
+    file: &SendFile,
+    dataset: Vec<u8>,
+) -> Result<u16> {
+    let msg_id: u16 = assoc.next_msg_id();
+    let rq = CommandBuilder::c_store_rq(
+        msg_id,
+        &file.sop_class_uid,
+        &file.sop_instance_uid,
+        Priority::Medium,
+    )
+    .build()?;
+    assoc.write_message(&Message::new(pres_ctx.ctx_id(), rq, Some(dataset)), stream)?;
+
+    let rsp: Message = assoc
+        .read_message(stream)?
+        .ok_or_else(|| anyhow!("Association released before a response was received"))?;
+    if rsp.command_field() != Some(CommandField::CStoreRsp)
+        || rsp.message_id_responded_to() != Some(msg_id)
+    {
+        return Err(anyhow!(
+            "Unexpected response: {:?} to message {:?}",
+            rsp.command_field(),
+            rsp.message_id_responded_to()
+        ));
+    }
+    rsp.status()
+        .ok_or_else(|| anyhow!("Response is missing a status"))
+}
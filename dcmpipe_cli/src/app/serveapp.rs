@@ -0,0 +1,405 @@
+use std::{
+    fs,
+    io::{Cursor, ErrorKind},
+    net::{TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    thread,
+};
+
+use anyhow::{anyhow, Context, Result};
+use dcmpipe_lib::{
+    core::{
+        dcmelement::DicomElement,
+        dcmobject::DicomRoot,
+        defn::{
+            ts::TSRef,
+            vr::{self, VRRef},
+        },
+        read::{stop::ParseStop, ParserBuilder},
+        write::builder::WriterBuilder,
+        RawValue,
+    },
+    dict::{
+        lookup::TS_BY_UID,
+        registry::storage_sop_classes,
+        stdlookup::STANDARD_DICOM_DICTIONARY,
+        tags,
+        transfer_syntaxes::{ExplicitVRLittleEndian, ImplicitVRLittleEndian},
+        uids,
+    },
+    dimse::{
+        assoc::{Association, AssociationBuilder},
+        commands::{CommandBuilder, Message},
+        constants::{
+            status, CommandField, DEFAULT_MAX_PDU_LENGTH, IMPLEMENTATION_CLASS_UID,
+            IMPLEMENTATION_VERSION_NAME,
+        },
+    },
+};
+#[cfg(feature = "index")]
+use mongodb::sync::Database;
+
+use crate::{
+    app::{
+        archiveapp::{Layout, DEFAULT_LAYOUT},
+        routing::Router,
+        CommandApplication, ACCEPT_POLL_INTERVAL, ASSOCIATION_TIMEOUT, DEFAULT_AE_TITLE,
+        MAX_ERROR_COMMENT_LEN,
+    },
+    args::ServeArgs,
+    config::Config,
+    shutdown,
+};
+
+pub struct ServeApp {
+    args: ServeArgs,
+    config: Config,
+}
+
+/// Where and how received datasets are stored.
+struct Storage {
+    dir: PathBuf,
+    layout: Layout,
+}
+
+impl ServeApp {
+    pub fn new(args: ServeArgs, config: Config) -> ServeApp {
+        ServeApp { args, config }
+    }
+
+    fn storage(&self) -> Result<Storage> {
+        let dir: PathBuf = self
+            .args
+            .storage_dir
+            .clone()
+            .or_else(|| self.config.serve.storage_dir.clone())
+            .ok_or_else(|| {
+                anyhow!("No storage folder, specify --storage-dir or serve.storage_dir in config")
+            })?;
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create folder: {}", dir.display()))?;
+        let layout: &str = self
+            .config
+            .archive
+            .layout
+            .as_deref()
+            .unwrap_or(DEFAULT_LAYOUT);
+        let layout: Layout = Layout::parse(layout)?;
+        Ok(Storage { dir, layout })
+    }
+
+    fn assoc_builder(&self, aetitle: &str) -> AssociationBuilder {
+        let max_pdu_length: u32 = self
+            .args
+            .max_pdu_length
+            .or(self.config.serve.max_pdu_length)
+            .unwrap_or(DEFAULT_MAX_PDU_LENGTH);
+        let allowed_aes: &[String] = if self.args.allowed_aes.is_empty() {
+            self.config.serve.allowed_aes.as_deref().unwrap_or_default()
+        } else {
+            &self.args.allowed_aes
+        };
+
+        // Datasets are stored as they're received so any transfer syntax can be accepted, though
+        // the uncompressed ones are preferred.
+        let mut other_ts: Vec<TSRef> = TS_BY_UID
+            .values()
+            .copied()
+            .filter(|ts| {
+                ts.uid() != ExplicitVRLittleEndian.uid() && ts.uid() != ImplicitVRLittleEndian.uid()
+            })
+            .collect();
+        other_ts.sort_by_key(|ts| ts.uid().uid());
+        let mut transfer_syntaxes: Vec<TSRef> =
+            vec![&ExplicitVRLittleEndian, &ImplicitVRLittleEndian];
+        transfer_syntaxes.append(&mut other_ts);
+
+        let mut assoc_builder: AssociationBuilder = AssociationBuilder::default()
+            .ae_title(aetitle)
+            .max_pdu_length(max_pdu_length)
+            .transfer_syntaxes(transfer_syntaxes)
+            .abstract_syntax(uids::VerificationSOPClass.uid);
+        for storage_class in storage_sop_classes() {
+            assoc_builder = assoc_builder.abstract_syntax(storage_class.uid());
+        }
+        #[cfg(feature = "index")]
+        if self.args.query {
+            assoc_builder = assoc_builder
+                .abstract_syntax(uids::PatientRootQueryRetrieveInformationModelFIND.uid)
+                .abstract_syntax(uids::StudyRootQueryRetrieveInformationModelFIND.uid);
+        }
+        for allowed_ae in allowed_aes {
+            assoc_builder = assoc_builder.allow_calling_ae(allowed_ae);
+        }
+        assoc_builder
+    }
+
+    /// Opens the index database if queries are to be answered.
+    #[cfg(feature = "index")]
+    fn database(&self) -> Result<Option<Database>> {
+        if !self.args.query {
+            return Ok(None);
+        }
+        let db_uri: &str = self
+            .args
+            .db
+            .as_deref()
+            .or(self.config.index.db.as_deref())
+            .ok_or_else(|| anyhow!("No index database, specify --db or index.db in config"))?;
+        crate::app::indexapp::open_database(db_uri).map(Some)
+    }
+}
+
+impl CommandApplication for ServeApp {
+    fn run(&mut self) -> Result<()> {
+        shutdown::install_handler()?;
+
+        let aetitle: &str = self
+            .args
+            .aetitle
+            .as_deref()
+            .or(self.config.aetitle.as_deref())
+            .unwrap_or(DEFAULT_AE_TITLE);
+        let storage: Storage = self.storage()?;
+        let assoc_builder: AssociationBuilder = self.assoc_builder(aetitle);
+        let handler: AssociationHandler<'_> = AssociationHandler {
+            storage: &storage,
+            router: self
+                .args
+                .route
+                .then(|| Router::new(&self.config))
+                .transpose()?,
+            #[cfg(feature = "index")]
+            database: self.database()?,
+        };
+
+        let addr: String = format!("{}:{}", self.args.host, self.args.port);
+        let listener: TcpListener =
+            TcpListener::bind(&addr).with_context(|| format!("Failed to listen on {addr}"))?;
+        // Poll for connections so that shutdown requests are noticed while idle.
+        listener.set_nonblocking(true)?;
+
+        println!(
+            "Listening on {addr} as {aetitle}, storing to: {}",
+            storage.dir.display()
+        );
+        // Each association is handled on its own thread. Once a shutdown is requested no more are
+        // accepted, and those in progress are allowed to finish.
+        thread::scope(|scope| -> Result<()> {
+            while !shutdown::is_requested() {
+                let (stream, peer_addr) = match listener.accept() {
+                    Ok(conn) => conn,
+                    Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                        thread::sleep(ACCEPT_POLL_INTERVAL);
+                        continue;
+                    }
+                    Err(e) => return Err(e).context("Failed to accept connection"),
+                };
+
+                let assoc_builder: &AssociationBuilder = &assoc_builder;
+                let handler: &AssociationHandler<'_> = &handler;
+                scope.spawn(move || {
+                    // A failed association shouldn't bring down the server.
+                    if let Err(e) = handler.handle(assoc_builder, stream) {
+                        eprintln!("Association with {peer_addr} failed: {e:?}");
+                    }
+                });
+            }
+            Ok(())
+        })?;
+        println!("Stopped listening on {addr}");
+
+        Ok(())
+    }
+}
+
+/// Answers the requests made over associations, shared between the threads handling them.
+struct AssociationHandler<'a> {
+    storage: &'a Storage,
+    router: Option<Router>,
+    #[cfg(feature = "index")]
+    database: Option<Database>,
+}
+
+impl AssociationHandler<'_> {
+    /// Negotiates an association on the given stream and answers requests until it's released.
+    fn handle(&self, assoc_builder: &AssociationBuilder, mut stream: TcpStream) -> Result<()> {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(ASSOCIATION_TIMEOUT))?;
+
+        let assoc: Association = assoc_builder.accept(&mut stream)?;
+        println!("Association accepted from {}", assoc.peer_ae());
+        let mut stored: usize = 0;
+        while let Some(mut rq) = assoc.read_message(&mut stream)? {
+            match rq.command_field() {
+                Some(CommandField::CEchoReq) => {
+                    let rsp = CommandBuilder::c_echo_rsp(&rq, status::SUCCESS).build()?;
+                    assoc.write_message(&Message::new(rq.ctx_id(), rsp, None), &mut stream)?;
+                }
+                Some(CommandField::CStoreReq) => {
+                    let (rsp, path) = match self.store(&assoc, &mut rq) {
+                        Ok(path) => {
+                            stored += 1;
+                            println!("Stored: {}", path.display());
+                            (
+                                CommandBuilder::c_store_rsp(&rq, status::SUCCESS),
+                                Some(path),
+                            )
+                        }
+                        Err((status, e)) => {
+                            eprintln!("Failed to store dataset from {}: {e}", assoc.peer_ae());
+                            let comment: String =
+                                format!("{e}").chars().take(MAX_ERROR_COMMENT_LEN).collect();
+                            let rsp = CommandBuilder::c_store_rsp(&rq, status)
+                                .string(&tags::ErrorComment, &comment);
+                            (rsp, None)
+                        }
+                    };
+                    assoc.write_message(
+                        &Message::new(rq.ctx_id(), rsp.build()?, None),
+                        &mut stream,
+                    )?;
+                    // Routing happens after responding so the requestor isn't kept waiting, and
+                    // since the dataset is stored a routing failure isn't reported to it.
+                    if let (Some(router), Some(path)) = (&self.router, path) {
+                        match router.route_file(&path, Some(assoc.peer_ae())) {
+                            Ok(routes) if routes.is_empty() => {
+                                println!("No routes matched: {}", path.display());
+                            }
+                            Ok(routes) => {
+                                println!("Routed {} by: {}", path.display(), routes.join(", "));
+                            }
+                            Err(e) => eprintln!("Failed to route {}: {e:#}", path.display()),
+                        }
+                    }
+                }
+                #[cfg(feature = "index")]
+                Some(CommandField::CFindReq) if self.database.is_some() => {
+                    if let Some(database) = &self.database {
+                        crate::app::indexapp::handle_find(database, &assoc, &rq, &mut stream)?;
+                    }
+                }
+                other => {
+                    assoc.abort(&mut stream)?;
+                    return Err(anyhow!("Unsupported command: {other:?}"));
+                }
+            }
+        }
+        println!(
+            "Association released by {}, stored {stored} datasets",
+            assoc.peer_ae()
+        );
+
+        Ok(())
+    }
+
+    /// Writes the dataset of a C-STORE request to the storage folder as a DICOM file, returning
+    /// its path. On failure the status to respond with is returned with the error. A dataset with
+    /// the same path as one previously stored replaces it.
+    fn store(
+        &self,
+        assoc: &Association,
+        rq: &mut Message,
+    ) -> Result<PathBuf, (u16, anyhow::Error)> {
+        let unable = |e: anyhow::Error| (status::UNABLE_TO_PROCESS, e);
+
+        let ts: TSRef = assoc
+            .get_pres_ctx(rq.ctx_id())
+            .map(|pres_ctx| pres_ctx.ts())
+            .ok_or_else(|| unable(anyhow!("No presentation context: {}", rq.ctx_id())))?;
+        let sop_class_uid: String = rq
+            .affected_sop_class_uid()
+            .ok_or_else(|| unable(anyhow!("Missing Affected SOP Class UID")))?;
+        let sop_instance_uid: String = rq
+            .affected_sop_instance_uid()
+            .ok_or_else(|| unable(anyhow!("Missing Affected SOP Instance UID")))?;
+        let dataset: Vec<u8> = rq
+            .take_dataset()
+            .ok_or_else(|| unable(anyhow!("Missing dataset")))?;
+
+        let file_meta: Vec<DicomElement> =
+            file_meta(&sop_class_uid, &sop_instance_uid, ts, assoc.peer_ae()).map_err(unable)?;
+        let mut writer = WriterBuilder::for_file().ts(ts).build(Vec::new());
+        writer
+            .write_elements(file_meta.iter())
+            .map_err(|e| unable(e.into()))?;
+        let mut bytes: Vec<u8> = writer.into_dataset().map_err(|e| unable(e.into()))?;
+        // The dataset is already encoded in the transfer syntax, so is written out as-is.
+        bytes.extend_from_slice(&dataset);
+
+        let mut parser = ParserBuilder::default()
+            .stop(ParseStop::BeforeTagValue(tags::PixelData.tag.into()))
+            .dictionary(&STANDARD_DICOM_DICTIONARY)
+            .build(Cursor::new(bytes.as_slice()));
+        let dcmroot: DicomRoot<'_> = DicomRoot::parse(&mut parser)
+            .map_err(|e| unable(e.into()))?
+            .ok_or_else(|| unable(anyhow!("Dataset could not be parsed")))?;
+        let path: PathBuf = self.storage.dir.join(self.storage.layout.render(&dcmroot));
+
+        write_file(&path, &bytes).map_err(|e| (status::OUT_OF_RESOURCES, e))?;
+        Ok(path)
+    }
+}
+
+/// Creates the File Meta Information for a received dataset.
+fn file_meta(
+    sop_class_uid: &str,
+    sop_instance_uid: &str,
+    ts: TSRef,
+    source_ae: &str,
+) -> Result<Vec<DicomElement<'static>>> {
+    let values: Vec<(u32, VRRef, RawValue)> = vec![
+        (
+            tags::FileMetaInformationVersion.tag,
+            &vr::OB,
+            RawValue::Bytes(vec![0x00, 0x01]),
+        ),
+        (
+            tags::MediaStorageSOPClassUID.tag,
+            &vr::UI,
+            RawValue::Uid(sop_class_uid.to_owned()),
+        ),
+        (
+            tags::MediaStorageSOPInstanceUID.tag,
+            &vr::UI,
+            RawValue::Uid(sop_instance_uid.to_owned()),
+        ),
+        (
+            tags::TransferSyntaxUID.tag,
+            &vr::UI,
+            RawValue::Uid(ts.uid().uid().to_owned()),
+        ),
+        (
+            tags::ImplementationClassUID.tag,
+            &vr::UI,
+            RawValue::Uid(IMPLEMENTATION_CLASS_UID.to_owned()),
+        ),
+        (
+            tags::ImplementationVersionName.tag,
+            &vr::SH,
+            RawValue::Strings(vec![IMPLEMENTATION_VERSION_NAME.to_owned()]),
+        ),
+        (
+            tags::SourceApplicationEntityTitle.tag,
+            &vr::AE,
+            RawValue::Strings(vec![source_ae.to_owned()]),
+        ),
+    ];
+
+    let mut elements: Vec<DicomElement> = Vec::with_capacity(values.len());
+    for (tag, vr, value) in values {
+        // The File Meta Information is always encoded as Explicit VR Little Endian.
+        let mut element = DicomElement::new_empty(tag, vr, &ExplicitVRLittleEndian);
+        element.encode_value(value, None)?;
+        elements.push(element);
+    }
+    Ok(elements)
+}
+
+fn write_file(path: &Path, bytes: &[u8]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create folder: {}", parent.display()))?;
+    }
+    fs::write(path, bytes).with_context(|| format!("Failed to write: {}", path.display()))
+}
@@ -0,0 +1,311 @@
+//! The stats command reports aggregate statistics of the datasets within a folder, to get an
+//! overview of the contents of an archive.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, Sender},
+    },
+    thread,
+};
+
+use anyhow::Result;
+use serde::Serialize;
+use walkdir::WalkDir;
+
+use dcmpipe_lib::{
+    core::{
+        compressed::DatasetFile,
+        dcmobject::{DicomObject, DicomRoot},
+        defn::{dcmdict::DicomDictionary, tag::Tag, vl::ValueLength},
+        read::ParserBuilder,
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags},
+};
+
+use crate::{
+    app::{progress::format_size, CommandApplication},
+    args::StatsArgs,
+    shutdown,
+};
+
+/// Values at least this long are skipped rather than read, so that Pixel Data is measured without
+/// reading it into memory.
+const DEFERRED_VALUE_LEN: u32 = 4096;
+
+/// The maximum width of the bars of the study date histogram.
+const HISTOGRAM_WIDTH: usize = 40;
+
+/// Counted in place of attributes which are missing or empty.
+static UNKNOWN: &str = "(unknown)";
+
+/// The attributes of a single dataset which contribute to the statistics.
+struct DatasetSummary {
+    modality: Option<String>,
+    sop_class: Option<String>,
+    transfer_syntax: String,
+    manufacturer: Option<String>,
+    study_year: Option<String>,
+    patient_id: Option<String>,
+    study_uid: Option<String>,
+    series_uid: Option<String>,
+    pixel_data_bytes: u64,
+}
+
+enum Outcome {
+    Dataset(Box<DatasetSummary>),
+    NotDicom,
+    Invalid(String),
+}
+
+/// The aggregate statistics, serialized as-is for `--json`.
+#[derive(Default, Serialize)]
+struct Stats {
+    files: usize,
+    datasets: usize,
+    not_dicom: usize,
+    invalid: usize,
+    patients: usize,
+    studies: usize,
+    series: usize,
+    pixel_data_bytes: u64,
+    modalities: BTreeMap<String, usize>,
+    sop_classes: BTreeMap<String, usize>,
+    transfer_syntaxes: BTreeMap<String, usize>,
+    manufacturers: BTreeMap<String, usize>,
+    study_years: BTreeMap<String, usize>,
+    #[serde(skip)]
+    patient_ids: HashSet<String>,
+    #[serde(skip)]
+    study_uids: HashSet<String>,
+    #[serde(skip)]
+    series_uids: HashSet<String>,
+}
+
+impl Stats {
+    fn add(&mut self, dataset: DatasetSummary) {
+        self.datasets += 1;
+        self.pixel_data_bytes += dataset.pixel_data_bytes;
+        count(&mut self.modalities, dataset.modality);
+        count(&mut self.sop_classes, dataset.sop_class);
+        count(&mut self.transfer_syntaxes, Some(dataset.transfer_syntax));
+        count(&mut self.manufacturers, dataset.manufacturer);
+        count(&mut self.study_years, dataset.study_year);
+        if let Some(patient_id) = dataset.patient_id {
+            self.patient_ids.insert(patient_id);
+        }
+        if let Some(study_uid) = dataset.study_uid {
+            self.study_uids.insert(study_uid);
+        }
+        if let Some(series_uid) = dataset.series_uid {
+            self.series_uids.insert(series_uid);
+        }
+        self.patients = self.patient_ids.len();
+        self.studies = self.study_uids.len();
+        self.series = self.series_uids.len();
+    }
+
+    fn print_table(&self) {
+        println!("Files:       {}", self.files);
+        println!(
+            "Datasets:    {} ({} not DICOM, {} invalid)",
+            self.datasets, self.not_dicom, self.invalid
+        );
+        println!("Patients:    {}", self.patients);
+        println!("Studies:     {}", self.studies);
+        println!("Series:      {}", self.series);
+        println!(
+            "Pixel Data:  {} ({} bytes)",
+            format_size(self.pixel_data_bytes),
+            self.pixel_data_bytes
+        );
+
+        print_counts("Modality", &self.modalities);
+        print_counts("SOP Class", &self.sop_classes);
+        print_counts("Transfer Syntax", &self.transfer_syntaxes);
+        print_counts("Manufacturer", &self.manufacturers);
+
+        // The histogram is ordered by year rather than by count.
+        println!("\nStudy Date");
+        let max: usize = self.study_years.values().copied().max().unwrap_or_default();
+        for (year, count) in &self.study_years {
+            let width: usize = (count * HISTOGRAM_WIDTH).div_ceil(max);
+            println!("  {year:<9} {count:>8} {}", "#".repeat(width));
+        }
+    }
+}
+
+fn count(counts: &mut BTreeMap<String, usize>, value: Option<String>) {
+    let key: String = value.unwrap_or_else(|| UNKNOWN.to_owned());
+    *counts.entry(key).or_default() += 1;
+}
+
+/// Prints the counts of a section, most frequent first.
+fn print_counts(title: &str, counts: &BTreeMap<String, usize>) {
+    println!("\n{title}");
+    let mut sorted: Vec<(&String, &usize)> = counts.iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    let key_width: usize = sorted.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+    for (key, count) in sorted {
+        println!("  {key:<key_width$} {count:>8}");
+    }
+}
+
+pub struct StatsApp {
+    args: StatsArgs,
+}
+
+impl StatsApp {
+    pub fn new(args: StatsArgs) -> StatsApp {
+        StatsApp { args }
+    }
+
+    fn get_files(&self) -> Vec<PathBuf> {
+        WalkDir::new(&self.args.folder)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_file())
+            .map(|entry| entry.into_path())
+            .collect()
+    }
+
+    /// Summarizes files taken from `files` until all are taken or a shutdown is requested.
+    fn summarize_files(
+        &self,
+        files: &[PathBuf],
+        next_file: &AtomicUsize,
+        outcomes: Sender<(usize, Outcome)>,
+    ) {
+        while !shutdown::is_requested() {
+            let index: usize = next_file.fetch_add(1, Ordering::SeqCst);
+            let Some(path) = files.get(index) else {
+                break;
+            };
+            if outcomes.send((index, summarize(path))).is_err() {
+                break;
+            }
+        }
+    }
+}
+
+impl CommandApplication for StatsApp {
+    fn run(&mut self) -> Result<()> {
+        shutdown::install_handler()?;
+
+        let files: Vec<PathBuf> = self.get_files();
+        let mut stats: Stats = Stats {
+            files: files.len(),
+            ..Default::default()
+        };
+
+        let next_file: AtomicUsize = AtomicUsize::new(0);
+        let (sender, receiver) = mpsc::channel::<(usize, Outcome)>();
+        let workers: usize = usize::from(self.args.concurrency).min(files.len());
+        let mut processed: usize = 0;
+        thread::scope(|scope| {
+            for _ in 0..workers {
+                let sender: Sender<(usize, Outcome)> = sender.clone();
+                scope.spawn(|| {
+                    self.summarize_files(&files, &next_file, sender);
+                });
+            }
+            drop(sender);
+
+            for (index, outcome) in receiver {
+                processed += 1;
+                match outcome {
+                    Outcome::Dataset(dataset) => stats.add(*dataset),
+                    Outcome::NotDicom => stats.not_dicom += 1,
+                    Outcome::Invalid(reason) => {
+                        // Reported separately so the statistics remain parseable as JSON.
+                        eprintln!("Invalid: {}, {reason}", files[index].display());
+                        stats.invalid += 1;
+                    }
+                }
+            }
+        });
+
+        if processed < files.len() {
+            eprintln!(
+                "Interrupted, {} of {} files were not read",
+                files.len() - processed,
+                files.len()
+            );
+        }
+
+        if self.args.json {
+            println!("{}", serde_json::to_string_pretty(&stats)?);
+        } else {
+            stats.print_table();
+        }
+        Ok(())
+    }
+}
+
+/// Parses the dataset of the file, without reading its Pixel Data, and collects its attributes.
+fn summarize(path: &Path) -> Outcome {
+    let file: DatasetFile = match DatasetFile::open(path) {
+        Ok(file) => file,
+        Err(e) => return Outcome::Invalid(e.to_string()),
+    };
+    let mut parser = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .defer_values(DEFERRED_VALUE_LEN)
+        .build(file);
+    let dcmroot: DicomRoot<'_> = match DicomRoot::parse(&mut parser) {
+        Ok(Some(dcmroot)) => dcmroot,
+        Ok(None) => return Outcome::NotDicom,
+        Err(e) => return Outcome::Invalid(e.to_string()),
+    };
+
+    let text = |tag: &Tag| -> Option<String> {
+        dcmroot
+            .get_child_by_tag(tag.tag)
+            .and_then(|obj| String::try_from(obj.element()).ok())
+            .map(|value| value.trim().to_owned())
+            .filter(|value| !value.is_empty())
+    };
+
+    let sop_class: Option<String> = text(&tags::SOPClassUID).map(|uid| {
+        STANDARD_DICOM_DICTIONARY
+            .get_uid_by_uid(&uid)
+            .map(|uid| uid.name().to_owned())
+            .unwrap_or(uid)
+    });
+    let study_year: Option<String> = text(&tags::StudyDate)
+        .and_then(|date| date.get(..4).map(str::to_owned))
+        .filter(|year| year.bytes().all(|b| b.is_ascii_digit()));
+    let pixel_data_bytes: u64 = [
+        &tags::PixelData,
+        &tags::FloatPixelData,
+        &tags::DoubleFloatPixelData,
+    ]
+    .iter()
+    .filter_map(|tag| dcmroot.get_child_by_tag(tag.tag))
+    .map(value_len)
+    .sum();
+
+    Outcome::Dataset(Box::new(DatasetSummary {
+        modality: text(&tags::Modality),
+        sop_class,
+        transfer_syntax: dcmroot.ts().uid().name().to_owned(),
+        manufacturer: text(&tags::Manufacturer),
+        study_year,
+        patient_id: text(&tags::PatientID),
+        study_uid: text(&tags::StudyInstanceUID),
+        series_uid: text(&tags::SeriesInstanceUID),
+        pixel_data_bytes,
+    }))
+}
+
+/// The length of the element's value, which for encapsulated Pixel Data is the total length of
+/// its fragments.
+fn value_len(obj: &DicomObject<'_>) -> u64 {
+    match obj.element().vl() {
+        ValueLength::Explicit(len) => u64::from(len),
+        ValueLength::UndefinedLength => obj.iter_items().map(value_len).sum(),
+    }
+}
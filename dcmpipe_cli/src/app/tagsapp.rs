@@ -0,0 +1,95 @@
+use anyhow::Result;
+
+use dcmpipe_lib::{
+    core::defn::{
+        dcmdict::DicomDictionary,
+        tag::{Tag, TagRef},
+        uid::UIDRef,
+    },
+    dict::{
+        lookup::{TAG_BY_KEYWORD, UID_BY_UID},
+        stdlookup::STANDARD_DICOM_DICTIONARY,
+    },
+};
+
+use crate::{app::CommandApplication, args::TagsArgs};
+
+pub struct TagsApp {
+    args: TagsArgs,
+}
+
+impl TagsApp {
+    pub fn new(args: TagsArgs) -> TagsApp {
+        TagsApp { args }
+    }
+}
+
+impl CommandApplication for TagsApp {
+    fn run(&mut self) -> Result<()> {
+        let (tags, uids) = search(&self.args.query, self.args.retired);
+        if tags.is_empty() && uids.is_empty() {
+            println!("No matches for: {}", self.args.query);
+            return Ok(());
+        }
+
+        for tag in tags {
+            let vr: &str = tag.implicit_vr().map_or("--", |vr| vr.ident);
+            let retired: &str = if tag.is_retired() { " (Retired)" } else { "" };
+            println!(
+                "{} {:<48} {vr} {:<8} {}{retired}",
+                Tag::format_tag_to_display(tag.tag),
+                tag.keyword,
+                tag.vm().to_string(),
+                tag.desc
+            );
+        }
+        for uid in uids {
+            let category: String = uid
+                .sop_class_category()
+                .map(|category| format!(" [{}]", category.name()))
+                .unwrap_or_default();
+            println!("{:<32} {:<48} {}{category}", uid.uid, uid.ident, uid.name);
+        }
+        Ok(())
+    }
+}
+
+/// Searches the standard dictionary for tags and UIDs matching the query. A tag number or UID
+/// value is looked up exactly, otherwise tags and UIDs whose keyword or name contains the query,
+/// ignoring case, are returned.
+fn search(query: &str, include_retired: bool) -> (Vec<TagRef>, Vec<UIDRef>) {
+    let query: &str = query.trim();
+    // Only tag numbers are parsed here, keywords are matched along with names below.
+    if let Ok(number) = Tag::parse_tag(query, None) {
+        let tag: Option<TagRef> = STANDARD_DICOM_DICTIONARY.get_tag_by_number(number);
+        return (tag.into_iter().collect(), Vec::new());
+    }
+    if let Some(uid) = UID_BY_UID.get(query) {
+        return (Vec::new(), vec![*uid]);
+    }
+
+    let query: String = query.to_lowercase();
+    let mut tags: Vec<TagRef> = TAG_BY_KEYWORD
+        .values()
+        .copied()
+        .filter(|tag| include_retired || !tag.is_retired())
+        .filter(|tag| {
+            tag.keyword.to_lowercase().contains(&query)
+                || tag.ident.to_lowercase().contains(&query)
+                || tag.desc.to_lowercase().contains(&query)
+        })
+        .collect();
+    tags.sort_by_key(|tag| tag.tag);
+
+    let mut uids: Vec<UIDRef> = UID_BY_UID
+        .values()
+        .copied()
+        .filter(|uid| include_retired || !uid.is_retired())
+        .filter(|uid| {
+            uid.ident.to_lowercase().contains(&query) || uid.name.to_lowercase().contains(&query)
+        })
+        .collect();
+    uids.sort_by_key(|uid| uid.ident);
+
+    (tags, uids)
+}
@@ -0,0 +1,144 @@
+//! The video command extracts and ingests video encapsulated in Pixel Data.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Context, Result};
+
+use dcmpipe_lib::{
+    core::{
+        dcmelement::DicomElement,
+        dcmobject::DicomRoot,
+        defn::{dcmdict::DicomDictionary, tag::TagPath, ts::TSRef, vr},
+        pixeldata::video::{encapsulate_video, extract_video, VideoAttributes, VideoFormat},
+        read::Parser,
+        write::{builder::WriterBuilder, writer::Writer},
+        RawValue,
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags},
+};
+
+use crate::{
+    app::{generate_uid, parse_file, CommandApplication},
+    args::{VideoArgs, VideoCommand},
+};
+
+/// The pipeline stage recorded as modifying elements, when tracking provenance.
+static VIDEO_STAGE: &str = "video";
+
+pub struct VideoApp {
+    args: VideoArgs,
+}
+
+impl VideoApp {
+    pub fn new(args: VideoArgs) -> VideoApp {
+        VideoApp { args }
+    }
+
+    fn extract(&self, file: &Path, output: Option<&Path>) -> Result<()> {
+        let dcmroot: DicomRoot<'_> = parse_dataset(file)?;
+        let (format, stream) = extract_video(&dcmroot)
+            .with_context(|| format!("Failed to extract video: {}", file.display()))?;
+        let output: PathBuf = output
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| file.with_extension(format.extension()));
+        fs::write(&output, &stream)
+            .with_context(|| format!("Failed to write: {}", output.display()))?;
+        println!(
+            "Wrote {} bytes of {} to {}",
+            stream.len(),
+            describe(format),
+            output.display()
+        );
+        Ok(())
+    }
+
+    fn ingest(
+        &self,
+        video: &Path,
+        template: &Path,
+        output: &Path,
+        ts: &str,
+        attributes: &VideoAttributes,
+    ) -> Result<()> {
+        let to: TSRef = STANDARD_DICOM_DICTIONARY
+            .get_ts_by_name(ts)
+            .or_else(|| STANDARD_DICOM_DICTIONARY.get_ts_by_uid(ts))
+            .ok_or_else(|| anyhow!("Unknown transfer syntax: {ts}"))?;
+        let stream: Vec<u8> =
+            fs::read(video).with_context(|| format!("Failed to read: {}", video.display()))?;
+
+        let mut dcmroot: DicomRoot<'_> = parse_dataset(template)?;
+        encapsulate_video(&mut dcmroot, &stream, to, attributes, VIDEO_STAGE)
+            .context("Failed to encapsulate video")?;
+
+        // The dataset is a new instance, so it mustn't share the template's SOP Instance UID.
+        let sop_uid: String = generate_uid();
+        let mut element: DicomElement =
+            DicomElement::new_empty(&tags::SOPInstanceUID, &vr::UI, dcmroot.ts());
+        element.encode_value(RawValue::Uid(sop_uid.clone()), None)?;
+        dcmroot.insert_element(&TagPath::from(&tags::SOPInstanceUID), element, VIDEO_STAGE)?;
+        dcmroot
+            .modify_element(
+                &TagPath::from(&tags::MediaStorageSOPInstanceUID),
+                VIDEO_STAGE,
+                |element| element.encode_value(RawValue::Uid(sop_uid), None),
+            )
+            .transpose()?;
+
+        dcmroot.update_value_lengths();
+        let mut writer: Writer<Vec<u8>> = WriterBuilder::for_file().ts(to).build(Vec::new());
+        writer.write_dcmroot(&dcmroot)?;
+        fs::write(output, writer.into_dataset()?)
+            .with_context(|| format!("Failed to write: {}", output.display()))?;
+        println!(
+            "Wrote {} bytes of video as {} to {}",
+            stream.len(),
+            to.uid().name(),
+            output.display()
+        );
+        Ok(())
+    }
+}
+
+impl CommandApplication for VideoApp {
+    fn run(&mut self) -> Result<()> {
+        match &self.args.cmd {
+            VideoCommand::Extract { file, output } => self.extract(file, output.as_deref()),
+            VideoCommand::Ingest {
+                video,
+                template,
+                output,
+                ts,
+                rows,
+                columns,
+                frames,
+                frame_time,
+            } => {
+                let attributes = VideoAttributes {
+                    rows: *rows,
+                    columns: *columns,
+                    frames: *frames,
+                    frame_time: *frame_time,
+                };
+                self.ingest(video, template, output, ts, &attributes)
+            }
+        }
+    }
+}
+
+fn parse_dataset(path: &Path) -> Result<DicomRoot<'static>> {
+    let mut parser: Parser<'static, _> = parse_file(path, false)?;
+    DicomRoot::parse(&mut parser)?.ok_or_else(|| anyhow!("file is not dicom: {}", path.display()))
+}
+
+/// A readable name of the stream format.
+fn describe(format: VideoFormat) -> &'static str {
+    match format {
+        VideoFormat::TransportStream => "an MPEG transport stream",
+        VideoFormat::ProgramStream => "an MPEG program stream",
+        VideoFormat::Elementary(_) => "an elementary stream",
+    }
+}
@@ -0,0 +1,354 @@
+//! The to-nifti and to-mhd commands assemble a series into a volume for research tooling, while
+//! the from-volume command creates a series from such a volume.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Context, Result};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use walkdir::WalkDir;
+
+use dcmpipe_lib::{
+    core::{
+        dcmelement::DicomElement,
+        dcmobject::DicomRoot,
+        defn::{
+            tag::{Tag, TagPath},
+            uid::UID,
+            vr::{self, VRRef},
+        },
+        pixeldata::{metaimage, nifti, volume, Volume},
+        write::{builder::WriterBuilder, writer::Writer},
+        RawValue,
+    },
+    dict::{tags, uids},
+};
+
+use crate::{
+    app::{generate_uid, parse_file, CommandApplication},
+    args::{FromVolumeArgs, VolumeArgs},
+};
+
+/// The pipeline stage recorded as modifying elements, when tracking provenance.
+static FROM_VOLUME_STAGE: &str = "from-volume";
+
+/// The single-frame and Enhanced multi-frame SOP Classes of each modality, along with the Legacy
+/// Converted Enhanced SOP Class of the modality.
+static SOP_CLASSES: [(&UID, &UID, &UID); 3] = [
+    (
+        &uids::CTImageStorage,
+        &uids::EnhancedCTImageStorage,
+        &uids::LegacyConvertedEnhancedCTImageStorage,
+    ),
+    (
+        &uids::MRImageStorage,
+        &uids::EnhancedMRImageStorage,
+        &uids::LegacyConvertedEnhancedMRImageStorage,
+    ),
+    (
+        &uids::PositronEmissionTomographyImageStorage,
+        &uids::EnhancedPETImageStorage,
+        &uids::LegacyConvertedEnhancedPETImageStorage,
+    ),
+];
+
+/// The file format a volume is written in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VolumeFormat {
+    Nifti,
+    MetaImage,
+}
+
+pub struct VolumeApp {
+    args: VolumeArgs,
+    format: VolumeFormat,
+}
+
+impl VolumeApp {
+    pub fn new(args: VolumeArgs, format: VolumeFormat) -> VolumeApp {
+        VolumeApp { args, format }
+    }
+
+    fn write_nifti(&self, volume: &Volume) -> Result<()> {
+        let output: &Path = &self.args.output;
+        let mut data: Vec<u8> = nifti::encode(volume)?;
+        if output.extension().is_some_and(|ext| ext == "gz") {
+            let mut encoder: GzEncoder<Vec<u8>> =
+                GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&data)?;
+            data = encoder.finish()?;
+        }
+        fs::write(output, &data).with_context(|| format!("Failed to write: {}", output.display()))
+    }
+
+    fn write_mhd(&self, volume: &Volume) -> Result<()> {
+        let output: &Path = &self.args.output;
+        let raw: PathBuf = output.with_extension("raw");
+        let data_file: String = raw
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .ok_or_else(|| anyhow!("invalid output: {}", output.display()))?;
+        fs::write(&raw, metaimage::encode_raw(volume))
+            .with_context(|| format!("Failed to write: {}", raw.display()))?;
+        fs::write(output, metaimage::header(volume, &data_file))
+            .with_context(|| format!("Failed to write: {}", output.display()))
+    }
+}
+
+impl CommandApplication for VolumeApp {
+    fn run(&mut self) -> Result<()> {
+        let instances: Vec<DicomRoot<'static>> =
+            load_series(&self.args.folder, self.args.series.as_deref())?;
+        let volume: Volume =
+            Volume::from_instances(&instances).context("Failed to assemble volume")?;
+        match self.format {
+            VolumeFormat::Nifti => self.write_nifti(&volume)?,
+            VolumeFormat::MetaImage => self.write_mhd(&volume)?,
+        }
+
+        let [columns, rows, slices] = volume.dimensions();
+        let [x, y, z] = volume.spacing();
+        println!(
+            "Wrote {columns}x{rows}x{slices} volume with {x}x{y}x{z} mm spacing from {} datasets to {}",
+            instances.len(),
+            self.args.output.display()
+        );
+        Ok(())
+    }
+}
+
+pub struct FromVolumeApp {
+    args: FromVolumeArgs,
+}
+
+impl FromVolumeApp {
+    pub fn new(args: FromVolumeArgs) -> FromVolumeApp {
+        FromVolumeApp { args }
+    }
+
+    /// Reads the volume as MetaImage if its extension is `.mhd`, otherwise as NIfTI.
+    fn read_volume(&self) -> Result<Volume> {
+        let path: &Path = &self.args.volume;
+        let read = |path: &Path| {
+            fs::read(path).with_context(|| format!("Failed to read: {}", path.display()))
+        };
+        let volume = if path.extension().is_some_and(|ext| ext == "mhd") {
+            let header: String = String::from_utf8(read(path)?)
+                .with_context(|| format!("Invalid MetaImage header: {}", path.display()))?;
+            let raw: PathBuf = path.with_file_name(metaimage::data_file(&header)?);
+            metaimage::decode(&header, &read(&raw)?)
+        } else {
+            let mut data: Vec<u8> = read(path)?;
+            if path.extension().is_some_and(|ext| ext == "gz") {
+                let mut decompressed: Vec<u8> = Vec::new();
+                GzDecoder::new(data.as_slice())
+                    .read_to_end(&mut decompressed)
+                    .with_context(|| format!("Failed to decompress: {}", path.display()))?;
+                data = decompressed;
+            }
+            nifti::decode(&data)
+        };
+        volume.with_context(|| format!("Failed to read volume: {}", path.display()))
+    }
+
+    /// Sets the attributes identifying a new instance of the derived series.
+    fn set_identity(
+        &self,
+        dcmroot: &mut DicomRoot<'_>,
+        sop_class: Option<&UID>,
+        series_uid: &str,
+        number: usize,
+    ) -> Result<()> {
+        let sop_uid: String = generate_uid();
+        let image_type: &[&str] = if self.args.enhanced {
+            &["DERIVED", "SECONDARY", "VOLUME", "NONE"]
+        } else {
+            &["DERIVED", "SECONDARY"]
+        };
+        let strings = |values: &[&str]| {
+            RawValue::Strings(values.iter().map(|value| (*value).to_owned()).collect())
+        };
+        let mut attributes: Vec<(&Tag, VRRef, RawValue)> = vec![
+            (
+                &tags::SOPInstanceUID,
+                &vr::UI,
+                RawValue::Uid(sop_uid.clone()),
+            ),
+            (
+                &tags::SeriesInstanceUID,
+                &vr::UI,
+                RawValue::Uid(series_uid.to_owned()),
+            ),
+            (&tags::ImageType, &vr::CS, strings(image_type)),
+            (
+                &tags::InstanceNumber,
+                &vr::IS,
+                RawValue::Strings(vec![number.to_string()]),
+            ),
+        ];
+        if let Some(sop_class) = sop_class {
+            attributes.push((
+                &tags::SOPClassUID,
+                &vr::UI,
+                RawValue::Uid(sop_class.uid().to_owned()),
+            ));
+        }
+        if let Some(description) = &self.args.description {
+            attributes.push((
+                &tags::SeriesDescription,
+                &vr::LO,
+                strings(&[description.as_str()]),
+            ));
+        }
+        for (tag, vr, value) in attributes {
+            let mut element: DicomElement = DicomElement::new_empty(tag, vr, dcmroot.ts());
+            element.encode_value(value, None)?;
+            dcmroot.insert_element(&TagPath::from(tag), element, FROM_VOLUME_STAGE)?;
+        }
+
+        // The File Meta group, if present, must identify the same instance.
+        let sop_class = sop_class.map(|sop_class| sop_class.uid().to_owned());
+        let media_uids = [
+            (&tags::MediaStorageSOPClassUID, sop_class),
+            (&tags::MediaStorageSOPInstanceUID, Some(sop_uid)),
+        ];
+        for (tag, uid) in media_uids {
+            let Some(uid) = uid else {
+                continue;
+            };
+            dcmroot
+                .modify_element(&TagPath::from(tag), FROM_VOLUME_STAGE, |element| {
+                    element.encode_value(RawValue::Uid(uid), None)
+                })
+                .transpose()?;
+        }
+        Ok(())
+    }
+
+    fn write(&self, dcmroot: &mut DicomRoot<'_>, file: &Path) -> Result<()> {
+        dcmroot.update_value_lengths();
+        let mut writer: Writer<Vec<u8>> =
+            WriterBuilder::for_file().ts(dcmroot.ts()).build(Vec::new());
+        writer.write_dcmroot(dcmroot)?;
+        fs::write(file, writer.into_dataset()?)
+            .with_context(|| format!("Failed to write: {}", file.display()))
+    }
+}
+
+impl CommandApplication for FromVolumeApp {
+    fn run(&mut self) -> Result<()> {
+        let volume: Volume = self.read_volume()?;
+        let mut template: DicomRoot<'static> =
+            load_series(&self.args.reference, self.args.series.as_deref())?
+                .into_iter()
+                .next()
+                .ok_or_else(|| anyhow!("No reference datasets"))?;
+        let sop_class: Option<&UID> = derived_sop_class(&template, self.args.enhanced)?;
+        fs::create_dir_all(&self.args.output)
+            .with_context(|| format!("Failed to create: {}", self.args.output.display()))?;
+
+        let series_uid: String = generate_uid();
+        let [columns, rows, slices] = volume.dimensions();
+        if self.args.enhanced {
+            volume::insert_frames(&mut template, &volume, FROM_VOLUME_STAGE)?;
+            self.set_identity(&mut template, sop_class, &series_uid, 1)?;
+            self.write(&mut template, &self.args.output.join("IM0001.dcm"))?;
+        } else {
+            for slice in 0..slices {
+                volume::insert_slice(&mut template, &volume, slice, FROM_VOLUME_STAGE)?;
+                self.set_identity(&mut template, sop_class, &series_uid, slice + 1)?;
+                let file: PathBuf = self.args.output.join(format!("IM{:04}.dcm", slice + 1));
+                self.write(&mut template, &file)?;
+            }
+        }
+
+        println!(
+            "Wrote {columns}x{rows}x{slices} volume as series {series_uid} to {}",
+            self.args.output.display()
+        );
+        Ok(())
+    }
+}
+
+/// The SOP Class of the derived series, the single-frame or Enhanced multi-frame SOP Class of the
+/// reference's modality. Single-frame images of other SOP Classes, e.g. Secondary Capture, keep
+/// the reference's SOP Class, given as `None`.
+fn derived_sop_class(template: &DicomRoot<'_>, enhanced: bool) -> Result<Option<&'static UID>> {
+    let uid: String = template
+        .get_child_by_tag(tags::SOPClassUID.tag)
+        .map(|obj| String::try_from(obj.element()))
+        .transpose()?
+        .map(|uid| uid.trim_end_matches(['\0', ' ']).to_owned())
+        .unwrap_or_default();
+    let classes = SOP_CLASSES.iter().find(|classes| {
+        [classes.0, classes.1, classes.2]
+            .iter()
+            .any(|class| class.uid() == uid)
+    });
+    match (classes, enhanced) {
+        (Some((single, _, _)), false) => Ok(Some(single)),
+        (Some((_, multi, _)), true) => Ok(Some(multi)),
+        (None, false) => Ok(None),
+        (None, true) => Err(anyhow!(
+            "No Enhanced multi-frame SOP Class for the reference's SOP Class {uid}"
+        )),
+    }
+}
+
+/// Parses the datasets of a series from the files within a folder. Without a SeriesInstanceUID
+/// the folder must hold a single series. Files which aren't DICOM are skipped.
+pub(crate) fn load_series(folder: &Path, series: Option<&str>) -> Result<Vec<DicomRoot<'static>>> {
+    if !folder.is_dir() {
+        return Err(anyhow!("invalid folder: {}", folder.display()));
+    }
+
+    let mut by_series: BTreeMap<String, Vec<DicomRoot<'static>>> = BTreeMap::new();
+    let files = WalkDir::new(folder)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file());
+    for entry in files {
+        let Ok(mut parser) = parse_file(entry.path(), false) else {
+            continue;
+        };
+        let dcmroot: DicomRoot<'static> = match DicomRoot::parse(&mut parser) {
+            Ok(Some(dcmroot)) => dcmroot,
+            Ok(None) => continue,
+            Err(e) => {
+                return Err(e)
+                    .with_context(|| format!("Failed to parse: {}", entry.path().display()))
+            }
+        };
+        let uid: String = dcmroot
+            .get_child_by_tag(tags::SeriesInstanceUID.tag)
+            .map(|obj| String::try_from(obj.element()))
+            .transpose()?
+            .map(|uid| uid.trim_end_matches(['\0', ' ']).to_owned())
+            .unwrap_or_default();
+        by_series.entry(uid).or_default().push(dcmroot);
+    }
+
+    match series {
+        Some(series) => by_series
+            .remove(series)
+            .ok_or_else(|| anyhow!("No datasets of series {series} in {}", folder.display())),
+        None if by_series.len() == 1 => Ok(by_series.into_values().next().unwrap_or_default()),
+        None if by_series.is_empty() => Err(anyhow!("No DICOM datasets in {}", folder.display())),
+        None => {
+            let listing: Vec<String> = by_series
+                .iter()
+                .map(|(uid, instances)| format!("  {uid} ({} datasets)", instances.len()))
+                .collect();
+            Err(anyhow!(
+                "{} holds multiple series, select one with --series:\n{}",
+                folder.display(),
+                listing.join("\n")
+            ))
+        }
+    }
+}
@@ -0,0 +1,236 @@
+//! The watch command monitors a folder for arriving datasets and dispatches each to a destination,
+//! acting as a lightweight router.
+
+use std::{
+    fs,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{anyhow, Context, Result};
+
+#[cfg(feature = "index")]
+use mongodb::sync::Database;
+
+#[cfg(feature = "index")]
+use crate::app::indexapp::AttributeFilter;
+
+use dcmpipe_lib::{
+    core::{
+        dcmobject::DicomRoot,
+        read::{stop::ParseStop, ParserBuilder},
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags},
+    dimse::constants::Status,
+    ingest::{
+        watcher::{WatchEvent, Watcher},
+        Sink, SinkError,
+    },
+};
+
+use crate::{
+    app::{
+        archiveapp::{Layout, DEFAULT_LAYOUT},
+        calling_ae, resolve_remote,
+        routing::Router,
+        sendapp::send_dataset,
+        CommandApplication,
+    },
+    args::{RemoteArgs, WatchArgs, WatchSink},
+    config::Config,
+    shutdown,
+};
+
+pub struct WatchApp {
+    args: WatchArgs,
+    config: Config,
+}
+
+/// Where arrived datasets are dispatched to.
+enum Destination {
+    Archive {
+        dir: PathBuf,
+        layout: Layout,
+    },
+    #[cfg(feature = "index")]
+    Index(Database, AttributeFilter),
+    Forward {
+        remote: RemoteArgs,
+        calling_ae: String,
+        addr: SocketAddr,
+    },
+    Route(Router),
+}
+
+/// Dispatches arrived datasets to the destination, removing their files afterwards if requested.
+struct Dispatcher {
+    destination: Destination,
+    remove: bool,
+}
+
+impl WatchApp {
+    pub fn new(args: WatchArgs, config: Config) -> WatchApp {
+        WatchApp { args, config }
+    }
+
+    fn destination(&self) -> Result<Destination> {
+        Ok(match &self.args.sink {
+            WatchSink::Archive {
+                destination,
+                layout,
+            } => {
+                let layout: &str = layout
+                    .as_deref()
+                    .or(self.config.archive.layout.as_deref())
+                    .unwrap_or(DEFAULT_LAYOUT);
+                self.check_outside_folder(destination)?;
+                Destination::Archive {
+                    dir: destination.clone(),
+                    layout: Layout::parse(layout)?,
+                }
+            }
+            #[cfg(feature = "index")]
+            WatchSink::Index { db } => {
+                let db_uri: &str = db
+                    .as_deref()
+                    .or(self.config.index.db.as_deref())
+                    .ok_or_else(|| {
+                        anyhow!("No database URI, use --db or set index.db in dcmpipe.toml")
+                    })?;
+                Destination::Index(
+                    crate::app::indexapp::open_database(db_uri)?,
+                    AttributeFilter::from_config(&self.config.index)?,
+                )
+            }
+            WatchSink::Forward { remote } => Destination::Forward {
+                remote: remote.clone(),
+                calling_ae: calling_ae(remote, &self.config).to_owned(),
+                addr: resolve_remote(remote)?,
+            },
+            WatchSink::Route => {
+                let router: Router = Router::new(&self.config)?;
+                for folder in router.folders() {
+                    self.check_outside_folder(folder)?;
+                }
+                Destination::Route(router)
+            }
+        })
+    }
+
+    /// Creates the destination folder, failing if it's within the watched folder as copies there
+    /// would themselves be dispatched.
+    fn check_outside_folder(&self, destination: &Path) -> Result<()> {
+        fs::create_dir_all(destination)
+            .with_context(|| format!("Failed to create folder: {}", destination.display()))?;
+        if fs::canonicalize(destination)?.starts_with(fs::canonicalize(&self.args.folder)?) {
+            return Err(anyhow!(
+                "Destination must not be within the watched folder: {}",
+                destination.display()
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl CommandApplication for WatchApp {
+    fn run(&mut self) -> Result<()> {
+        shutdown::install_handler()?;
+
+        if !self.args.folder.is_dir() {
+            return Err(anyhow!("Not a folder: {}", self.args.folder.display()));
+        }
+        let destination: Destination = self.destination()?;
+        // Forwarding sends the entire dataset, otherwise parsing can stop at the pixel data.
+        let parser_builder: ParserBuilder<'_> = match destination {
+            Destination::Forward { .. } => ParserBuilder::default(),
+            _ => {
+                ParserBuilder::default().stop(ParseStop::BeforeTagValue(tags::PixelData.tag.into()))
+            }
+        }
+        .dictionary(&STANDARD_DICOM_DICTIONARY);
+
+        let mut dispatcher: Dispatcher = Dispatcher {
+            destination,
+            remove: self.args.remove,
+        };
+        let mut watcher: Watcher<'_> = Watcher::new(&self.args.folder)
+            .parser_builder(parser_builder)
+            .ingest_existing(self.args.existing);
+
+        println!("Watching {}", self.args.folder.display());
+        let mut dispatched: usize = 0;
+        let mut failed: usize = 0;
+        watcher.watch(
+            &mut dispatcher,
+            Duration::from_secs(self.args.interval),
+            shutdown::is_requested,
+            |event| match event {
+                WatchEvent::Ingested(path) => {
+                    println!("Dispatched: {}", path.display());
+                    dispatched += 1;
+                }
+                WatchEvent::NotDicom(path) => {
+                    println!("Skipping non-DICOM file: {}", path.display());
+                }
+                WatchEvent::Failed(path, e) => {
+                    println!("Failed: {}, {:#}", path.display(), anyhow::Error::from(e));
+                    failed += 1;
+                }
+            },
+        )?;
+
+        println!("Stopped watching, dispatched {dispatched} datasets, {failed} failed");
+        Ok(())
+    }
+}
+
+impl Dispatcher {
+    fn dispatch(&mut self, path: &Path, dcmroot: &DicomRoot<'_>) -> Result<()> {
+        match &self.destination {
+            Destination::Archive { dir, layout } => {
+                let dest: PathBuf = dir.join(layout.render(dcmroot));
+                if let Some(parent) = dest.parent() {
+                    fs::create_dir_all(parent).with_context(|| {
+                        format!("Failed to create folder: {}", parent.display())
+                    })?;
+                }
+                fs::copy(path, &dest).with_context(|| {
+                    format!("Failed to copy {} to {}", path.display(), dest.display())
+                })?;
+            }
+            #[cfg(feature = "index")]
+            Destination::Index(database, filter) => {
+                crate::app::indexapp::index_dataset(database, filter, path, dcmroot)?;
+            }
+            Destination::Forward {
+                remote,
+                calling_ae,
+                addr,
+            } => {
+                let status: u16 = send_dataset(remote, calling_ae, addr, path, dcmroot)?;
+                if let Ok(Status::Failure(_)) = Status::try_from(u32::from(status)) {
+                    return Err(anyhow!("Failure status {status:#06X}"));
+                }
+            }
+            Destination::Route(router) => {
+                let routes: Vec<&str> = router.route(path, dcmroot, None)?;
+                if routes.is_empty() {
+                    println!("No routes matched: {}", path.display());
+                }
+            }
+        }
+
+        if self.remove {
+            fs::remove_file(path)
+                .with_context(|| format!("Failed to remove: {}", path.display()))?;
+        }
+        Ok(())
+    }
+}
+
+impl Sink for Dispatcher {
+    fn ingest(&mut self, path: &Path, dcmroot: &DicomRoot<'_>) -> Result<(), SinkError> {
+        self.dispatch(path, dcmroot).map_err(Into::into)
+    }
+}
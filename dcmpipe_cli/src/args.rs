@@ -1,10 +1,30 @@
 use std::path::PathBuf;
 
-use clap::{Args, Parser, Subcommand};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use clap_complete::ArgValueCompleter;
+use dcmpipe_lib::core::pixeldata::Region;
+use serde::Deserialize;
+
+use crate::completion::complete_tag_name;
 
 #[derive(Parser, Debug)]
 /// Explore DICOM
 pub struct Arguments {
+    /// The configuration file to use instead of searching for `dcmpipe.toml`.
+    ///
+    /// Per-user configuration is still applied for any values it doesn't set.
+    #[arg(long, global = true)]
+    pub config: Option<PathBuf>,
+
+    /// The most verbose level of log messages written to stderr. Logging is off by default, as
+    /// commands already report failures in their output.
+    #[arg(long, value_enum, global = true, default_value_t)]
+    pub log_level: LogLevel,
+
+    /// Write log messages as JSON, one object per line, for collection by log aggregators.
+    #[arg(long, global = true)]
+    pub log_json: bool,
+
     #[clap(subcommand)]
     pub command: Command,
 }
@@ -15,6 +35,16 @@ pub enum Command {
     Print(PrintArgs),
 
     /// Browse a DICOM dataset in a text-based user interface.
+    ///
+    /// The dataset can also be edited: `e` edits the value of the selected element, `x` deletes
+    /// it, and `a` adds a new element at the current level. Press `s` to save changes.
+    ///
+    /// When given a folder, its datasets are listed in a tree by patient, study, and series. Press
+    /// `tab` to switch between the tree and the elements, and `enter` to open an instance.
+    ///
+    /// Press `p` to preview the first frame of the Pixel Data. The window level is adjusted with `[`
+    /// and `]`, the window width with `-` and `+`, and `0` restores the dataset's window. Press `b`
+    /// to show the raw bytes of the selected element's value, scrolling them with `J` and `K`.
     Browse(BrowseArgs),
 
     /// Recursively scans a folder of DICOM datasets and prints results of parsing.
@@ -22,6 +52,22 @@ pub enum Command {
     /// This is primarily useful for locating DICOM files which fail to parse.
     Scan(ScanArgs),
 
+    /// Recursively scans a folder of DICOM datasets and reports aggregate statistics.
+    ///
+    /// Datasets are counted by modality, SOP class, transfer syntax, manufacturer, and the year of
+    /// the study date, along with the number of patients, studies, and series and the total size
+    /// of the Pixel Data.
+    Stats(StatsArgs),
+
+    /// Finds duplicate DICOM datasets within a folder.
+    ///
+    /// Datasets are duplicates if they have the same SOPInstanceUID, or with `--match` if they have
+    /// identical file contents or Pixel Data under different UIDs. The first file of each set of
+    /// duplicates, by path, is kept while the others are reported, replaced with hard links to it,
+    /// or deleted, according to `--action`. Duplicates whose bytes differ from the kept file, such
+    /// as a corrected dataset re-sent with the same SOPInstanceUID, are only ever reported.
+    Dedup(DedupArgs),
+
     /// Manage a database index of DICOM on disk.
     ///
     /// Recursively scans a folder for DICOM datasets, indexing them into a database.
@@ -31,35 +77,454 @@ pub enum Command {
     /// Archives DICOM datasets from a source folder into a destination folder.
     ///
     /// The source folder is assumed to be unstructured whereas the DICOM datasets will be copied
-    /// into the destination folder in a consistent structure:
+    /// into the destination folder in a consistent structure, determined by `--layout`. By default:
     ///   - One series per folder
     ///   - Each DICOM file will be named in the format `[SOP_UID].dcm`
     Archive(ArchiveArgs),
+
+    /// Sorts DICOM datasets into folders named by the values of the given attributes.
+    ///
+    /// Each `--by` attribute adds a level of folders, e.g. `--by Modality --by StudyDate` sorts
+    /// datasets into `CT/20240131/`. Files keep their original name and are moved, copied, or
+    /// symlinked into place. Unlike `archive` there is no index of the destination, so it's suited
+    /// to ad-hoc sorting of a folder.
+    Organize(OrganizeArgs),
+
+    /// De-identifies DICOM datasets, writing the results to a new file or folder.
+    ///
+    /// Private elements and attributes identifying the patient, institution, or staff are removed,
+    /// while IDs and UIDs are replaced with generated values. These rules can be customized with an
+    /// anonymization profile given by `--profile`. With `--map` the replacements are recorded so
+    /// the original values can be recovered, and so datasets anonymized in separate runs are
+    /// replaced consistently.
+    ///
+    /// Regions of the Pixel Data containing burned-in identifying information, such as the patient
+    /// banner of ultrasound images, are blacked out in every frame with `--mask`.
+    Anon(AnonArgs),
+
+    /// Sends DICOM datasets to a remote AE, acting as a Storage SCU.
+    ///
+    /// Folders are searched recursively. A presentation context is proposed for each SOP Class and
+    /// transfer syntax found, and datasets are converted to another uncompressed transfer syntax if
+    /// the remote AE doesn't accept their own. A summary of the response statuses is printed once
+    /// all datasets have been sent.
+    Send(SendArgs),
+
+    /// Verifies connectivity with a remote AE by sending C-ECHO requests.
+    ///
+    /// The round-trip time of each request is printed, followed by a summary. With `--verbose` the
+    /// presentation contexts and other details negotiated for the association are also printed.
+    Echo(EchoArgs),
+
+    /// Receives DICOM datasets from remote AEs, acting as a Verification and Storage SCP.
+    ///
+    /// Datasets received with C-STORE are written to the storage folder as they were received, in
+    /// the structure given by `archive.layout` from `dcmpipe.toml` which by default is one folder
+    /// per series.
+    /// With `--query` the index database is also searched to answer C-FIND queries, as with
+    /// `index serve`. Associations are handled concurrently.
+    Serve(ServeArgs),
+
+    /// Watches a folder for arriving DICOM datasets, dispatching each to a destination.
+    ///
+    /// The folder is scanned every `--interval` seconds, and files are dispatched once they're
+    /// completely written, making dcmpipe a lightweight router. Files whose names start with `.`
+    /// are ignored until renamed. Datasets are copied into a folder structure as with `archive`,
+    /// added to the index database, forwarded to a remote AE with C-STORE, or routed by the rules
+    /// of `dcmpipe.toml`.
+    Watch(WatchArgs),
+
+    /// Searches the DICOM dictionary for tags and UIDs.
+    ///
+    /// Tags are listed with their VR, VM, and name, and UIDs with their keyword and name.
+    Tags(TagsArgs),
+
+    /// Extracts or ingests video encapsulated in Pixel Data, without decoding it.
+    ///
+    /// Datasets of the MPEG-2, MPEG-4 AVC/H.264, and HEVC/H.265 transfer syntaxes hold a video
+    /// stream split into fragments. `extract` joins the fragments into a playable file, while
+    /// `ingest` encapsulates a video file into a copy of a template dataset.
+    Video(VideoArgs),
+
+    /// Creates a DICOM file from a DICOM JSON file or a file of `Attribute=value` lines.
+    ///
+    /// Lines name an attribute by keyword, tag, or path into sequences, e.g.
+    /// `PatientName=DOE^J` or `ReferencedStudySequence[1].ReferencedSOPInstanceUID=1.2.3`, with
+    /// multiple values separated by `\`. Attributes not in the dictionary give their VR as
+    /// `(0009,1001):LO=value`. Binary values are given as `base64:<data>` or `@<file>`, relative
+    /// to the input. The File Meta group is generated, along with a SOPInstanceUID if missing.
+    Create(CreateArgs),
+
+    /// Applies a template of attribute changes to a dataset, or every dataset within a folder.
+    ///
+    /// The template, in JSON or TOML, lists attributes to set, to remove, and whose UIDs are
+    /// reassigned, e.g. giving a copy of a study a new StudyInstanceUID. References to reassigned
+    /// UIDs within any of the edited datasets are updated to the new UIDs, so the instances of a
+    /// study still refer to one another.
+    Edit(EditArgs),
+
+    /// Verifies the references between the DICOM datasets within a folder, e.g. of a study.
+    ///
+    /// Referenced instances, series, studies, and frames of reference must be among the datasets,
+    /// with the referenced SOP Class matching the instance's, and instances referenced within an
+    /// item of a frame of reference, e.g. the contour images of an RT Structure Set, must be in
+    /// that frame of reference. Series whose instances differ in frame of reference, and
+    /// instances shared by multiple files, are also reported. Exits with an error if any issues
+    /// are found, e.g. to check a study before import into a treatment planning system.
+    Refs(RefsArgs),
+
+    /// Renders frames of a dataset's Pixel Data to PNG or JPEG images.
+    ///
+    /// Monochrome frames are displayed through the window given with `--window`, or the dataset's
+    /// window if it covers the pixel values and an automatic window otherwise. The dataset's VOI
+    /// LUT is used instead with `--voi-lut`. Overlay planes are burned in with `--overlays`. Each
+    /// frame is written to its own file, numbered when rendering multiple frames.
+    Image(ImageArgs),
+
+    /// Assembles a series from a folder into a volume written as NIfTI.
+    ///
+    /// Frames are ordered by their position along the normal of their orientation and must be
+    /// evenly spaced. The volume's orientation is written in the NIfTI RAS convention, as both its
+    /// qform and sform. Outputs ending in `.gz`, e.g. `volume.nii.gz`, are compressed.
+    ToNifti(VolumeArgs),
+
+    /// Assembles a series from a folder into a volume written as MetaImage.
+    ///
+    /// Frames are ordered as with `to-nifti`. The header, e.g. `volume.mhd`, is written along with
+    /// the voxel data in a `.raw` file of the same name, as read by ITK based tools.
+    ToMhd(VolumeArgs),
+
+    /// Creates a derived series from a NIfTI or MetaImage volume, e.g. a segmentation or a
+    /// registered image, in the context of the series it was derived from.
+    ///
+    /// Patient, study, and frame of reference attributes are copied from a dataset of the
+    /// reference series, while the new series has its own UIDs, geometry, and pixel values. Each
+    /// slice is written as its own image, or all slices as the frames of a single Enhanced
+    /// multi-frame object with `--enhanced`.
+    FromVolume(FromVolumeArgs),
 }
 
 #[derive(Args, Debug)]
 pub struct PrintArgs {
-    /// The file to process as a DICOM dataset.
+    /// The file to process as a DICOM dataset, or a zip or tar archive whose datasets are all
+    /// printed.
     pub file: PathBuf,
+
+    /// The layout to print datasets in.
+    #[arg(long, value_enum, default_value_t = PrintFormat::Dcmpipe)]
+    pub format: PrintFormat,
+
+    /// Print the bytes used by each group and the largest elements instead of the elements, to
+    /// diagnose why a dataset is unexpectedly large.
+    #[arg(long, conflicts_with = "format")]
+    pub sizes: bool,
+
+    /// Truncate values to this many characters in the dcmpipe layout, marking them with `..`.
+    #[arg(long, value_name = "CHARS", conflicts_with = "sizes")]
+    pub max_value_width: Option<usize>,
+
+    /// Only print the contents of sequences nested up to this depth in the dcmpipe layout. With
+    /// `0` sequences are printed without their items, unless they're Code Sequences printed on a
+    /// single line.
+    #[arg(long, conflicts_with = "sizes")]
+    pub depth: Option<usize>,
+
+    /// Start each line with the byte offset of the element within the dataset and its encoded
+    /// length, in the dcmpipe layout.
+    #[arg(long, conflicts_with = "sizes")]
+    pub offsets: bool,
+
+    /// When to highlight the dcmpipe layout with colors.
+    #[arg(long, value_enum, default_value_t = ColorChoice::Auto)]
+    pub color: ColorChoice,
 }
 
 #[derive(Args, Debug)]
 pub struct BrowseArgs {
-    /// The file to process as a DICOM dataset.
+    /// The file to process as a DICOM dataset, or a folder of datasets.
+    #[cfg_attr(not(feature = "index"), arg(required = true))]
+    #[cfg_attr(
+        feature = "index",
+        arg(required_unless_present = "query", conflicts_with = "query")
+    )]
+    pub file: Option<PathBuf>,
+
+    /// The file to save edits to, when browsing a single file. Defaults to overwriting the file
+    /// being browsed.
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Truncate values to this many characters, marking them with `..`.
+    #[arg(long, value_name = "CHARS")]
+    pub max_value_width: Option<usize>,
+
+    /// Browse the series of the index matching the query rather than a file, e.g.
+    /// `PatientID=12345`. The attribute is given by keyword or tag, and its value is matched as a
+    /// key of a C-FIND query, so may have wildcards or a date range. May be repeated, in which
+    /// case series must match all of them.
+    #[cfg(feature = "index")]
+    #[arg(long, value_name = "ATTRIBUTE=VALUE")]
+    pub query: Vec<String>,
+
+    /// The db URI of the index used with `--query`. Defaults to `index.db` from `dcmpipe.toml`.
+    #[cfg(feature = "index")]
+    #[arg(long, requires = "query")]
+    pub db: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct TagsArgs {
+    /// A tag keyword, tag number such as `(0010,0010)` or `00100010`, UID, or part of a name to
+    /// search for.
+    #[arg(add = ArgValueCompleter::new(complete_tag_name))]
+    pub query: String,
+
+    /// Include retired tags and UIDs when searching by name.
+    #[arg(long)]
+    pub retired: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct VideoArgs {
+    #[clap(subcommand)]
+    pub cmd: VideoCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum VideoCommand {
+    /// Writes the video stream of a dataset to a file.
+    Extract {
+        /// The DICOM dataset containing encapsulated video.
+        file: PathBuf,
+
+        /// The file to write the video to. Defaults to the dataset's path with the extension of
+        /// the detected stream format, e.g. `.ts` for an MPEG transport stream.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Encapsulates a video file into a dataset, with the other attributes from a template.
+    ///
+    /// The video must already be encoded as required by the transfer syntax, which is not checked.
+    /// The template's Pixel Data, if any, is replaced, and it's given a new SOPInstanceUID.
+    Ingest {
+        /// The video file, e.g. an MPEG transport stream or an H.264 elementary stream.
+        video: PathBuf,
+
+        /// The DICOM dataset whose attributes are copied, e.g. those of the patient and study.
+        #[arg(short, long)]
+        template: PathBuf,
+
+        /// The file to write the new dataset to.
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// The video transfer syntax, by name or UID, e.g. `MPEG4AVCH264HighProfileLevel41`.
+        #[arg(long)]
+        ts: String,
+
+        /// The height of the video, in pixels.
+        #[arg(long)]
+        rows: u16,
+
+        /// The width of the video, in pixels.
+        #[arg(long)]
+        columns: u16,
+
+        /// The number of frames of the video.
+        #[arg(long)]
+        frames: usize,
+
+        /// The time between frames, in milliseconds.
+        #[arg(long)]
+        frame_time: f64,
+    },
+}
+
+#[derive(Args, Debug)]
+pub struct CreateArgs {
+    /// A DICOM JSON file, per Part 18 Annex F, or a file of `Attribute=value` lines.
+    pub input: PathBuf,
+
+    /// The file to write the dataset to.
+    #[arg(short, long)]
+    pub output: PathBuf,
+
+    /// The transfer syntax to encode the dataset in, by name or UID.
+    #[arg(long, default_value = "ExplicitVRLittleEndian")]
+    pub ts: String,
+}
+
+#[derive(Args, Debug)]
+pub struct RefsArgs {
+    /// The folder to recursively scan for DICOM datasets.
+    pub folder: PathBuf,
+}
+
+#[derive(Args, Debug)]
+pub struct ImageArgs {
+    /// The DICOM dataset whose frames are rendered.
     pub file: PathBuf,
+
+    /// The file to write the image to. Defaults to the dataset's path with the extension of the
+    /// format. When rendering multiple frames, the frame number is appended to the file name, e.g.
+    /// `image_0003.png`.
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// The image format. Defaults to that of the output's extension, or PNG.
+    #[arg(long, value_enum)]
+    pub format: Option<ImageFormat>,
+
+    /// The frames to render, numbered from 1, as a list of frames and ranges, e.g. `1,3-5`, or
+    /// `all`.
+    #[arg(long, default_value = "1")]
+    pub frames: String,
+
+    /// The window as `CENTER,WIDTH`, e.g. `40,400`.
+    #[arg(long, allow_hyphen_values = true, conflicts_with_all = ["auto_window", "voi_lut"])]
+    pub window: Option<String>,
+
+    /// Use a window spanning the middle of the pixel values, ignoring the dataset's window.
+    #[arg(long, conflicts_with = "voi_lut")]
+    pub auto_window: bool,
+
+    /// Map values through the dataset's VOI LUT with the given number, from 1, instead of a
+    /// window.
+    #[arg(long)]
+    pub voi_lut: Option<usize>,
+
+    /// Invert the rendered pixels, e.g. to display bone dark.
+    #[arg(long)]
+    pub invert: bool,
+
+    /// Burn the dataset's overlay planes into the image.
+    #[arg(long)]
+    pub overlays: bool,
+
+    /// The factor to scale the image by, e.g. `0.5` for half its size.
+    #[arg(long, default_value_t = 1.0)]
+    pub scale: f64,
+
+    /// The quality of JPEG images, from 1 to 100.
+    #[arg(long, default_value_t = 90, value_parser = clap::value_parser!(u8).range(1..=100))]
+    pub quality: u8,
+}
+
+#[derive(Args, Debug)]
+pub struct FromVolumeArgs {
+    /// The volume to read, a NIfTI image (`.nii` or `.nii.gz`) or a MetaImage header (`.mhd`).
+    pub volume: PathBuf,
+
+    /// The folder to recursively scan for the datasets of the reference series.
+    #[arg(short, long)]
+    pub reference: PathBuf,
+
+    /// The SeriesInstanceUID of the reference series, required when the folder holds multiple
+    /// series.
+    #[arg(long)]
+    pub series: Option<String>,
+
+    /// The folder to write the datasets of the new series to.
+    #[arg(short, long)]
+    pub output: PathBuf,
+
+    /// Write a single Enhanced multi-frame object instead of an image per slice.
+    #[arg(long)]
+    pub enhanced: bool,
+
+    /// The SeriesDescription of the new series.
+    #[arg(long)]
+    pub description: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct VolumeArgs {
+    /// The folder to recursively scan for the datasets of the series.
+    pub folder: PathBuf,
+
+    /// The file to write the volume to.
+    #[arg(short, long)]
+    pub output: PathBuf,
+
+    /// The SeriesInstanceUID of the series, required when the folder holds multiple series.
+    #[arg(long)]
+    pub series: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct EditArgs {
+    /// The DICOM file, or folder of them with `--recursive`, to edit.
+    pub source: PathBuf,
+
+    /// The template of changes, in TOML or JSON.
+    #[arg(short, long)]
+    pub template: PathBuf,
+
+    /// Edits all datasets within the source folder and its subfolders.
+    #[arg(short, long)]
+    pub recursive: bool,
+
+    /// The file or folder to write edited datasets to, rather than overwriting them. Datasets from
+    /// a folder are written to the same relative paths within this folder.
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
 }
 
 #[derive(Args, Debug)]
 pub struct ScanArgs {
+    /// The folder to recursively scan for DICOM datasets, including those within zip and tar
+    /// archives. May also be a single archive.
+    pub folder: PathBuf,
+
+    /// Print a content hash of each valid dataset, covering the given part of the dataset.
+    #[arg(long, value_enum)]
+    pub hash: Option<HashScopeArg>,
+
+    /// Normalize datasets prior to hashing, so the hash doesn't depend on transfer syntax, File
+    /// Meta group, group lengths, or whether sequences have explicit or undefined lengths.
+    #[arg(long, requires = "hash")]
+    pub normalize: bool,
+
+    /// Don't show a progress bar. It's only shown when stderr is a terminal.
+    #[arg(long)]
+    pub no_progress: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct StatsArgs {
     /// The folder to recursively scan for DICOM datasets.
     pub folder: PathBuf,
+
+    /// Print the statistics as JSON rather than as tables.
+    #[arg(long)]
+    pub json: bool,
+
+    /// The number of datasets read in parallel.
+    #[arg(short = 'j', long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+    pub concurrency: u16,
+}
+
+#[derive(Args, Debug)]
+pub struct DedupArgs {
+    /// The folder to recursively scan for duplicate DICOM datasets.
+    pub folder: PathBuf,
+
+    /// Also treat datasets as duplicates if their content matches, regardless of their
+    /// SOPInstanceUID. Repeat to match on both.
+    #[arg(long = "match", value_enum)]
+    pub matches: Vec<DedupMatch>,
+
+    /// What to do with the duplicates found.
+    #[arg(long, value_enum, default_value_t)]
+    pub action: DedupAction,
 }
 
 #[derive(Args, Debug)]
 pub struct IndexArgs {
     #[arg(short, long)]
-    /// The db URI of the index.
-    pub db: String,
+    /// The db URI of the index. Defaults to `index.db` from `dcmpipe.toml`. When built with the
+    /// `index-postgres` feature, `postgres://` URIs store the index in PostgreSQL tables.
+    pub db: Option<String>,
 
     #[clap(subcommand)]
     /// Index sub-command
@@ -70,18 +535,555 @@ pub struct IndexArgs {
 pub enum IndexCommand {
     /// Recursively scans a folder for DICOM datasets, indexing them into a database.
     Scan {
-        /// The folder to scan for DICOM datasets.
+        /// The folder to scan for DICOM datasets, including those within zip and tar archives.
         folder: PathBuf,
+
+        /// Don't show a progress bar. It's only shown when stderr is a terminal.
+        #[arg(long)]
+        no_progress: bool,
+
+        /// Also render a thumbnail of each series scanned, from the first frame of its middle
+        /// instance by InstanceNumber, stored alongside the series records. Instances within zip
+        /// and tar archives aren't rendered.
+        #[arg(long)]
+        thumbnails: bool,
+
+        /// The format of thumbnails.
+        #[arg(long, value_enum, default_value_t = ImageFormat::Jpeg, requires = "thumbnails")]
+        thumbnail_format: ImageFormat,
+
+        /// The largest width or height of thumbnails, in pixels. Smaller images aren't enlarged.
+        #[arg(long, default_value_t = 128, requires = "thumbnails")]
+        thumbnail_size: usize,
+
+        /// Only index the given attribute, by keyword or tag, e.g. `StudyDescription` or
+        /// `(0029,1010)`. May be repeated. Attributes the index relies on, such as UIDs, PatientID,
+        /// and dates, are always indexed. Defaults to `index.include` from `dcmpipe.toml`,
+        /// otherwise all attributes other than sequences are indexed.
+        #[arg(long, value_name = "ATTRIBUTE", add = ArgValueCompleter::new(complete_tag_name))]
+        include: Vec<String>,
+
+        /// Don't index the given attribute, by keyword or tag. May be repeated. Defaults to
+        /// `index.exclude` from `dcmpipe.toml`.
+        #[arg(long, value_name = "ATTRIBUTE", add = ArgValueCompleter::new(complete_tag_name))]
+        exclude: Vec<String>,
+
+        /// Don't index private attributes, other than those given with `--include`. Defaults to
+        /// `index.private` from `dcmpipe.toml`.
+        #[arg(long)]
+        no_private: bool,
+
+        /// Truncate indexed values of character strings to this many characters. Defaults to
+        /// `index.max_value_len` from `dcmpipe.toml`, otherwise values aren't truncated.
+        #[arg(long)]
+        max_value_len: Option<usize>,
+
+        /// The number of files read in parallel.
+        #[arg(short = 'j', long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+        concurrency: u16,
+
+        /// The number of series records written to the database per batch.
+        #[arg(long, default_value_t = 500, value_parser = clap::value_parser!(u64).range(1..))]
+        batch_size: u64,
+
+        /// The number of times to retry writing a batch after a transient database error, such as
+        /// a dropped connection.
+        #[arg(long, default_value_t = 3)]
+        retries: u32,
+
+        /// Record the SHA-256 checksum of each file, so `verify --sample` can detect files which
+        /// were modified since they were indexed. Requires reading files in their entirety.
+        #[arg(long)]
+        checksums: bool,
     },
     /// Verify records in the database reference valid files on-disk.
-    Verify,
+    Verify {
+        /// Also re-read this many files, chosen at random, reporting those whose key attributes
+        /// or checksum differ from the index, e.g. files modified or overwritten since indexed.
+        #[arg(long, default_value_t = 0)]
+        sample: usize,
+    },
+    /// Rebuild the study and patient level records from the series records.
+    ///
+    /// This is done automatically after scan and verify.
+    Aggregate,
+    /// Answer C-FIND queries from the database, acting as a Query/Retrieve SCP.
+    ///
+    /// Supports the Patient Root and Study Root information models at the PATIENT, STUDY, and
+    /// SERIES levels, as well as C-ECHO. Associations are handled one at a time.
+    ///
+    /// Requires a MongoDB index, PostgreSQL indexes can't be served.
+    Serve {
+        /// The address to listen on.
+        #[arg(long, default_value = "0.0.0.0")]
+        host: String,
+
+        /// The port to listen on.
+        #[arg(short, long, default_value_t = 4242)]
+        port: u16,
+
+        /// The AE Title of this server, which requestors must call. Defaults to `aetitle` from
+        /// `dcmpipe.toml`, otherwise `DCMPIPE`.
+        #[arg(long)]
+        aetitle: Option<String>,
+    },
+    /// Serve the database over HTTP as a read-only JSON API.
+    ///
+    /// Patients, studies, and series are listed from `/patients`, `/studies`, and `/series`,
+    /// filtered by query parameters named by attribute keyword or tag which are matched as the keys
+    /// of a C-FIND query, e.g. `/studies?PatientID=12345&StudyDate=20240101-`. The files of a series
+    /// are listed from `/series/{uid}/files` and downloaded from `/series/{uid}/files/{index}`.
+    ///
+    /// Up to 32 connections are handled at once, and further connections are answered with 503.
+    /// Clients have 30 seconds to send their request.
+    ///
+    /// Requires a MongoDB index, PostgreSQL indexes can't be served.
+    Http {
+        /// The address to listen on.
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+
+        /// The port to listen on.
+        #[arg(short, long, default_value_t = 8080)]
+        port: u16,
+    },
+    /// Write the thumbnail of a series rendered by `scan --thumbnails` to a file.
+    Thumbnail {
+        /// The SeriesInstanceUID of the series.
+        series_uid: String,
+
+        /// The file to write the thumbnail to. Defaults to the series UID with the extension of
+        /// the thumbnail's format, in the current folder.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
 }
 
 #[derive(Args, Debug)]
 pub struct ArchiveArgs {
-    /// The source folder of DICOM datasets to process.
+    /// The source folder of DICOM datasets to process, including those within zip and tar
+    /// archives, e.g. exports from PACS. May also be a single archive.
     pub source: PathBuf,
 
     /// The destination folder to archive datasets into.
     pub destination: PathBuf,
+
+    /// The path of each dataset within the destination folder.
+    ///
+    /// Attributes are referenced by keyword or tag within braces, e.g.
+    /// `{PatientID}/{StudyDate}_{StudyUID}/{SeriesNumber}_{SeriesUID}/{InstanceNumber}.dcm`.
+    /// The shorthands `StudyUID`, `SeriesUID`, and `SOPUID` are also accepted. Values are
+    /// sanitized to be safe file and folder names, and missing values are replaced with `UNKNOWN`.
+    ///
+    /// Defaults to `archive.layout` from `dcmpipe.toml`, otherwise
+    /// `{SeriesInstanceUID}/{SOPInstanceUID}.dcm`.
+    #[arg(long)]
+    pub layout: Option<String>,
+
+    /// What to do with a dataset whose SOPInstanceUID is already in the archive, or whose
+    /// destination path is already taken. Defaults to `archive.on_duplicate` from
+    /// `dcmpipe.toml`, otherwise `skip`.
+    #[arg(long, value_enum)]
+    pub on_duplicate: Option<DuplicatePolicy>,
+
+    /// Compare the content hash of duplicates, quietly skipping those that are identical so the
+    /// `--on-duplicate` policy only applies to conflicting datasets. The hash is normalized, so
+    /// datasets are identical if their content is the same regardless of encoding.
+    #[arg(long)]
+    pub hash: bool,
+
+    /// Re-encode datasets into the given transfer syntax, by name or UID, so the archive only
+    /// contains one encoding, e.g. `ExplicitVRLittleEndian`. Datasets with encapsulated pixel data
+    /// can't be re-encoded and are skipped. `JPEGBaselineProcess1` compresses 8-bit pixel data
+    /// lossily, see `--quality`. Defaults to `archive.transcode` from `dcmpipe.toml`, otherwise
+    /// datasets are archived as-is.
+    #[arg(long)]
+    pub transcode: Option<String>,
+
+    /// The quality from 1 to 100 of lossy compression when re-encoding with `--transcode`. Lossy
+    /// compression is recorded in the LossyImageCompression attributes, while the SOPInstanceUID
+    /// is kept so the archive still refers to the same instance. Defaults to `archive.quality` from
+    /// `dcmpipe.toml`, otherwise 90.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=100))]
+    pub quality: Option<u8>,
+
+    /// Store datasets compressed with zstd, appending `.zst` to their file names. Compressed files
+    /// start with a manifest of their key attributes, and are decompressed transparently by the
+    /// commands reading datasets. Defaults to `archive.compress` from `dcmpipe.toml`.
+    #[arg(long)]
+    pub compress: bool,
+
+    /// Don't show a progress bar. It's only shown when stderr is a terminal.
+    #[arg(long)]
+    pub no_progress: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct OrganizeArgs {
+    /// The source folder of DICOM datasets to organize.
+    pub source: PathBuf,
+
+    /// The destination folder to organize datasets into, which may be the source folder.
+    pub destination: PathBuf,
+
+    /// An attribute whose value names a level of folders, by keyword or tag, e.g. `Modality`.
+    /// Repeat to add more levels. Missing values are replaced with `UNKNOWN`.
+    #[arg(long = "by", required = true)]
+    pub by: Vec<String>,
+
+    /// Whether files are copied, moved, or symlinked into the destination folder.
+    #[arg(long, value_enum, default_value_t)]
+    pub mode: OrganizeMode,
+
+    /// What to do when a file of the same name is already in the destination folder.
+    #[arg(long, value_enum, default_value_t)]
+    pub on_duplicate: DuplicatePolicy,
+
+    /// Print where each file would be placed without changing anything.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct AnonArgs {
+    /// The DICOM file, or folder of them, to de-identify.
+    pub source: PathBuf,
+
+    /// The file or folder to write de-identified datasets to. Datasets from a folder are written
+    /// to the same relative paths within this folder.
+    pub destination: PathBuf,
+
+    /// A region of the Pixel Data to black out, as `x,y,width,height` in pixels from the top-left
+    /// corner. May be given multiple times.
+    #[arg(long, value_parser = parse_region)]
+    pub mask: Vec<Region>,
+
+    /// An anonymization profile, in TOML or JSON, with actions for specific attributes, patterns
+    /// to scrub from text values, and what to do with private elements. Its actions take
+    /// precedence over the built-in rules.
+    #[arg(long)]
+    pub profile: Option<PathBuf>,
+
+    /// How the values of dates are de-identified. Times are kept as-is. Defaults to `dates` from
+    /// the profile, otherwise `keep`.
+    #[arg(long, value_enum)]
+    pub dates: Option<DateMode>,
+
+    /// The most days dates are shifted by, earlier or later, with `--dates shift`.
+    #[arg(long, default_value_t = 365, value_parser = clap::value_parser!(u32).range(1..))]
+    pub max_date_shift: u32,
+
+    /// Derive each patient's date shift from their Patient ID and a secret read from the given
+    /// environment variable, so patients are shifted the same in separate runs without `--map`.
+    /// Otherwise shifts are random, unless already recorded in the re-identification map.
+    #[arg(long)]
+    pub date_seed_env: Option<String>,
+
+    /// A JSON file recording the original value of each replaced ID and UID, and the number of days
+    /// each patient's dates were shifted by. If the file exists its replacements are applied
+    /// first, and it's updated with any new ones.
+    #[arg(long)]
+    pub map: Option<PathBuf>,
+
+    /// Encrypt the re-identification map with a passphrase read from the given environment
+    /// variable. Required to read a map which was encrypted.
+    #[arg(long, requires = "map")]
+    pub map_password_env: Option<String>,
+}
+
+/// Parses a region of Pixel Data given as `x,y,width,height`.
+fn parse_region(value: &str) -> Result<Region, String> {
+    let parts: Vec<usize> = value
+        .split(',')
+        .map(|part| part.trim().parse::<usize>())
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("invalid region {value}: {e}"))?;
+    match parts[..] {
+        [x, y, width, height] => Ok(Region {
+            x,
+            y,
+            width,
+            height,
+        }),
+        _ => Err(format!("region must be x,y,width,height: {value}")),
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct SendArgs {
+    /// The DICOM files, or folders of them, to send.
+    #[arg(required = true)]
+    pub paths: Vec<PathBuf>,
+
+    #[command(flatten)]
+    pub remote: RemoteArgs,
+
+    /// The number of associations used to send datasets in parallel.
+    #[arg(short = 'j', long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+    pub concurrency: u16,
+
+    /// The number of times to retry sending a dataset after a failure status or a network error.
+    #[arg(long, default_value_t = 2)]
+    pub retries: u32,
+}
+
+#[derive(Args, Debug)]
+pub struct EchoArgs {
+    #[command(flatten)]
+    pub remote: RemoteArgs,
+
+    /// The number of C-ECHO requests to send over the association.
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    pub count: u32,
+
+    /// Print the details of the association negotiated with the remote AE.
+    #[arg(short, long)]
+    pub verbose: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct ServeArgs {
+    /// The address to listen on.
+    #[arg(long, default_value = "0.0.0.0")]
+    pub host: String,
+
+    /// The port to listen on.
+    #[arg(short, long, default_value_t = 4242)]
+    pub port: u16,
+
+    /// The AE Title of this server, which requestors must call. Defaults to `aetitle` from
+    /// `dcmpipe.toml`, otherwise `DCMPIPE`.
+    #[arg(long)]
+    pub aetitle: Option<String>,
+
+    /// The folder received datasets are stored in. Defaults to `serve.storage_dir` from
+    /// `dcmpipe.toml`.
+    #[arg(long)]
+    pub storage_dir: Option<PathBuf>,
+
+    /// An AE Title which may associate with this server, and may be given more than once. Other
+    /// AEs are rejected. Defaults to `serve.allowed_aes` from `dcmpipe.toml`, otherwise any AE may
+    /// associate.
+    #[arg(long = "allow-ae")]
+    pub allowed_aes: Vec<String>,
+
+    /// The maximum length of PDUs this server will receive. Defaults to `serve.max_pdu_length`
+    /// from `dcmpipe.toml`, otherwise 16384.
+    #[arg(long)]
+    pub max_pdu_length: Option<u32>,
+
+    /// Also route each received dataset by the `[[route]]` rules of `dcmpipe.toml`, after it's
+    /// stored.
+    #[arg(long)]
+    pub route: bool,
+
+    /// Also answer C-FIND queries from the index database.
+    #[cfg(feature = "index")]
+    #[arg(long)]
+    pub query: bool,
+
+    /// The db URI of the index used with `--query`. Defaults to `index.db` from `dcmpipe.toml`.
+    #[cfg(feature = "index")]
+    #[arg(long, requires = "query")]
+    pub db: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct WatchArgs {
+    /// The folder to watch for arriving DICOM datasets, including sub-folders.
+    pub folder: PathBuf,
+
+    /// Seconds between scans of the folder.
+    #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u64).range(1..))]
+    pub interval: u64,
+
+    /// Also dispatch the datasets already in the folder when watching starts.
+    #[arg(long)]
+    pub existing: bool,
+
+    /// Delete each file once its dataset has been dispatched successfully.
+    #[arg(long)]
+    pub remove: bool,
+
+    /// Where datasets are dispatched to.
+    #[command(subcommand)]
+    pub sink: WatchSink,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum WatchSink {
+    /// Copy datasets into a folder, in the structure given by the layout.
+    Archive {
+        /// The folder to copy datasets into.
+        destination: PathBuf,
+
+        /// The path of each dataset within the destination folder, as with `archive --layout`.
+        /// Defaults to `archive.layout` from `dcmpipe.toml`, otherwise
+        /// `{SeriesInstanceUID}/{SOPInstanceUID}.dcm`.
+        #[arg(long)]
+        layout: Option<String>,
+    },
+    /// Add datasets to the index database, updating the record of their series.
+    #[cfg(feature = "index")]
+    Index {
+        /// The db URI of the index. Defaults to `index.db` from `dcmpipe.toml`.
+        #[arg(long)]
+        db: Option<String>,
+    },
+    /// Forward datasets to a remote AE with C-STORE.
+    Forward {
+        #[command(flatten)]
+        remote: RemoteArgs,
+    },
+    /// Route datasets by the `[[route]]` rules of `dcmpipe.toml`.
+    Route,
+}
+
+/// Options for connecting to a remote AE, shared by network commands.
+#[derive(Args, Clone, Debug)]
+pub struct RemoteArgs {
+    /// The host of the remote AE.
+    #[arg(long)]
+    pub host: String,
+
+    /// The port of the remote AE.
+    #[arg(short, long, default_value_t = 104)]
+    pub port: u16,
+
+    /// The AE Title of the remote AE.
+    #[arg(long)]
+    pub called_ae: String,
+
+    /// The AE Title of this AE. Defaults to `aetitle` from `dcmpipe.toml`, otherwise `DCMPIPE`.
+    #[arg(long)]
+    pub calling_ae: Option<String>,
+
+    /// Seconds to wait for a connection to the remote AE.
+    #[arg(long, default_value_t = 10)]
+    pub connect_timeout: u64,
+
+    /// Seconds to wait for each response from the remote AE.
+    #[arg(long, default_value_t = 60)]
+    pub timeout: u64,
+}
+
+/// How the organize command places files into their destination folder.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OrganizeMode {
+    /// Copy the files, leaving the source folder unchanged.
+    #[default]
+    Copy,
+    /// Move the files out of the source folder.
+    Move,
+    /// Create symbolic links to the files in the source folder.
+    Symlink,
+}
+
+/// Content by which the dedup command matches datasets with different SOPInstanceUIDs.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DedupMatch {
+    /// The files are identical byte for byte.
+    Bytes,
+    /// The value of the Pixel Data is identical, as with `scan --hash pixel-data --normalize`.
+    Pixels,
+}
+
+/// What the dedup command does with the duplicates it finds.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DedupAction {
+    /// Only list the duplicates.
+    #[default]
+    Report,
+    /// Replace each duplicate with a hard link to the file it duplicates, if their bytes are
+    /// identical.
+    Hardlink,
+    /// Delete the duplicates whose bytes are identical to the file kept.
+    Delete,
+}
+
+/// How the archive handles a dataset which duplicates one already archived.
+#[derive(ValueEnum, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DuplicatePolicy {
+    /// Leave the archived dataset in place and skip the new one.
+    #[default]
+    Skip,
+    /// Replace the archived dataset with the new one.
+    Overwrite,
+    /// Archive the new dataset alongside the existing one with a numbered suffix.
+    Rename,
+    /// Stop archiving with an error.
+    Error,
+}
+
+/// How the values of dates are de-identified.
+#[derive(ValueEnum, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DateMode {
+    /// Leave dates unchanged.
+    #[default]
+    Keep,
+    /// Shift all dates of each patient by the same number of days, keeping the intervals between
+    /// them.
+    Shift,
+    /// Truncate dates to their year, as January 1st of that year.
+    Year,
+    /// Remove the values of dates.
+    Clear,
+}
+
+/// The verbosity of log messages.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LogLevel {
+    /// No log messages.
+    #[default]
+    Off,
+    /// Failures which stop the command or an association.
+    Error,
+    /// Failures which don't stop the command, such as files which couldn't be parsed.
+    Warn,
+    /// Progress of associations and other notable events.
+    Info,
+    /// Details of each dataset parsed, written, or transferred.
+    Debug,
+    /// Each element parsed.
+    Trace,
+}
+
+/// The layout of datasets printed by the print command.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrintFormat {
+    /// Elements with their names and readable values, and Code Sequences on a single line.
+    Dcmpipe,
+    /// The layout of dcmtk's dcmdump, for comparing against its output.
+    Dcmtk,
+}
+
+/// When output is highlighted with colors.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// When writing to a terminal and the `NO_COLOR` environment variable isn't set.
+    Auto,
+    Always,
+    Never,
+}
+
+/// The format of images rendered by the image command.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// Lossless PNG.
+    Png,
+    /// Lossy JPEG, see `--quality`.
+    Jpeg,
+}
+
+/// The part of a dataset covered by its content hash.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashScopeArg {
+    /// The entire dataset.
+    Full,
+    /// All but the Pixel Data.
+    Header,
+    /// Only the Pixel Data.
+    PixelData,
 }
@@ -0,0 +1,34 @@
+//! Dynamic shell completions.
+//!
+//! Completions are generated by the binary itself when run with the `COMPLETE` environment
+//! variable set to the shell, e.g. `source <(COMPLETE=bash dcmpipe_cli)`. Options which accept tag
+//! names should use `complete_tag_name` so that dictionary keywords are offered as candidates.
+
+use std::ffi::OsStr;
+
+use clap_complete::CompletionCandidate;
+use dcmpipe_lib::{core::defn::tag::TagRef, dict::lookup::TAG_BY_KEYWORD};
+
+/// Completes a tag keyword from the standard dictionary, excluding retired tags.
+pub fn complete_tag_name(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(current) = current.to_str() else {
+        return Vec::new();
+    };
+
+    tags_with_prefix(current)
+        .into_iter()
+        .map(|tag| CompletionCandidate::new(tag.keyword).help(Some(tag.desc.into())))
+        .collect()
+}
+
+/// Finds the tags in the standard dictionary whose keyword starts with `prefix`, excluding retired
+/// tags, sorted by keyword.
+pub fn tags_with_prefix(prefix: &str) -> Vec<TagRef> {
+    let mut tags: Vec<TagRef> = TAG_BY_KEYWORD
+        .values()
+        .copied()
+        .filter(|tag| !tag.is_retired() && tag.keyword.starts_with(prefix))
+        .collect();
+    tags.sort_by_key(|tag| tag.keyword);
+    tags
+}
@@ -0,0 +1,271 @@
+//! Defaults for command-line options, loaded from `dcmpipe.toml` files.
+//!
+//! A per-user file is read from the platform configuration folder, e.g.
+//! `~/.config/dcmpipe/dcmpipe.toml`, and a per-project file is found by searching the current
+//! folder and its ancestors for `dcmpipe.toml`. Values in the per-project file take precedence
+//! over the per-user file, and options given on the command-line take precedence over both.
+//!
+//! ```toml
+//! # The AE Title used by network commands.
+//! aetitle = "DCMPIPE"
+//!
+//! [index]
+//! db = "mongodb://localhost:27017"
+//! exclude = ["ImageComments", "(0029,1010)"]
+//! private = false
+//! max_value_len = 256
+//!
+//! [archive]
+//! layout = "{PatientID}/{StudyUID}/{SeriesUID}/{SOPUID}.dcm"
+//! on_duplicate = "skip"
+//! transcode = "ExplicitVRLittleEndian"
+//! compress = true
+//!
+//! [serve]
+//! storage_dir = "/var/lib/dcmpipe/incoming"
+//! allowed_aes = ["MODALITY1", "PACS"]
+//! max_pdu_length = 65536
+//!
+//! # Routes for datasets received by `serve --route` or `watch route`, see `app::routing`.
+//! [[route]]
+//! name = "CT to PACS"
+//! modality = ["CT"]
+//! destinations = [
+//!     { folder = "/var/lib/dcmpipe/ct" },
+//!     { ae = "PACS", host = "pacs.example.org", port = 104 },
+//!     { stow = "http://dicomweb.example.org/dicom-web" },
+//! ]
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::args::DuplicatePolicy;
+
+static CONFIG_FILE_NAME: &str = "dcmpipe.toml";
+static CONFIG_FOLDER_NAME: &str = "dcmpipe";
+
+#[derive(Deserialize, Default, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The AE Title used by network commands.
+    pub aetitle: Option<String>,
+
+    pub index: IndexConfig,
+
+    pub archive: ArchiveConfig,
+
+    pub serve: ServeConfig,
+
+    /// The routing rules, evaluated in order.
+    #[serde(rename = "route")]
+    pub routes: Vec<RouteConfig>,
+}
+
+#[derive(Deserialize, Default, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct IndexConfig {
+    /// The db URI of the index.
+    pub db: Option<String>,
+
+    /// The only attributes indexed, by keyword or tag, otherwise all attributes are indexed.
+    pub include: Option<Vec<String>>,
+
+    /// Attributes not indexed, by keyword or tag.
+    pub exclude: Option<Vec<String>>,
+
+    /// Whether private attributes are indexed, by default true.
+    pub private: Option<bool>,
+
+    /// The number of characters indexed values are truncated to.
+    pub max_value_len: Option<usize>,
+}
+
+#[derive(Deserialize, Default, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct ArchiveConfig {
+    /// The path of each dataset within the archive folder.
+    pub layout: Option<String>,
+
+    /// How to handle datasets which are already in the archive.
+    pub on_duplicate: Option<DuplicatePolicy>,
+
+    /// The transfer syntax, by name or UID, to re-encode datasets into.
+    pub transcode: Option<String>,
+
+    /// The quality of lossy compression when re-encoding into a lossy transfer syntax.
+    pub quality: Option<u8>,
+
+    /// Whether datasets are stored compressed with zstd.
+    pub compress: Option<bool>,
+}
+
+#[derive(Deserialize, Default, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServeConfig {
+    /// The folder received datasets are stored in.
+    pub storage_dir: Option<PathBuf>,
+
+    /// The AE Titles which may associate with the server. If not set then any AE may.
+    pub allowed_aes: Option<Vec<String>>,
+
+    /// The maximum length of PDUs the server will receive.
+    pub max_pdu_length: Option<u32>,
+}
+
+/// A routing rule, sending datasets which match all of its conditions to its destinations. A rule
+/// without conditions matches every dataset.
+#[derive(Deserialize, Default, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct RouteConfig {
+    /// The name of the rule, used when reporting where datasets are routed.
+    pub name: Option<String>,
+
+    /// Matches datasets whose Modality is any of these.
+    pub modality: Vec<String>,
+
+    /// Matches datasets sent from any of these AE Titles, which for files is the Source
+    /// Application Entity Title of the File Meta group.
+    pub station_ae: Vec<String>,
+
+    /// Matches datasets whose SOP Class is any of these, by UID or keyword.
+    pub sop_class: Vec<String>,
+
+    /// Matches datasets whose SOP Class is in any of these categories: `image`, `sr`,
+    /// `presentation-state`, `waveform`, `document`, or `other-storage`.
+    pub category: Vec<String>,
+
+    /// Matches datasets whose Accession Number matches this regular expression.
+    pub accession: Option<String>,
+
+    /// Matches datasets whose attributes, by keyword or tag, match these regular expressions.
+    pub attributes: BTreeMap<String, String>,
+
+    /// Matches datasets whose attributes, by keyword or tag, match these values as the keys of a
+    /// C-FIND query do: with `*` and `?` wildcards, ranges of dates and times such as
+    /// `20240101-20240131`, and lists of values separated by `\`. Names are matched
+    /// case-insensitively.
+    #[serde(rename = "match")]
+    pub matching: BTreeMap<String, String>,
+
+    /// Where matching datasets are sent.
+    pub destinations: Vec<RouteDestination>,
+
+    /// Stop evaluating later rules once this rule matches.
+    pub stop: bool,
+}
+
+/// A destination of a routing rule.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum RouteDestination {
+    /// Copy datasets into a folder, in the structure given by the layout.
+    Folder {
+        folder: PathBuf,
+        layout: Option<String>,
+    },
+
+    /// Send datasets to a remote AE with C-STORE.
+    Ae {
+        ae: String,
+        host: String,
+        #[serde(default = "default_ae_port")]
+        port: u16,
+    },
+
+    /// Send datasets to the STOW-RS service of a DICOMweb server, given by its base URL.
+    Stow { stow: String },
+}
+
+fn default_ae_port() -> u16 {
+    104
+}
+
+impl Config {
+    /// Loads and merges the per-user and per-project configuration files. If `path` is given it's
+    /// used in place of searching for the per-project file.
+    pub fn load(path: Option<&Path>) -> Result<Config> {
+        let project_path: Option<PathBuf> = match path {
+            Some(path) => Some(path.to_path_buf()),
+            None => find_project_config(),
+        };
+
+        let mut config: Config = Config::default();
+        if let Some(path) = project_path {
+            config = config.or(Config::read(&path)?);
+        }
+        if let Some(path) = user_config_path().filter(|path| path.is_file()) {
+            config = config.or(Config::read(&path)?);
+        }
+        Ok(config)
+    }
+
+    fn read(path: &Path) -> Result<Config> {
+        let contents: String = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config: {}", path.display()))?;
+        toml::from_str(&contents).with_context(|| format!("Invalid config: {}", path.display()))
+    }
+
+    /// Fills in any values missing from this configuration with those from `other`.
+    fn or(self, other: Config) -> Config {
+        Config {
+            aetitle: self.aetitle.or(other.aetitle),
+            index: IndexConfig {
+                db: self.index.db.or(other.index.db),
+                include: self.index.include.or(other.index.include),
+                exclude: self.index.exclude.or(other.index.exclude),
+                private: self.index.private.or(other.index.private),
+                max_value_len: self.index.max_value_len.or(other.index.max_value_len),
+            },
+            archive: ArchiveConfig {
+                layout: self.archive.layout.or(other.archive.layout),
+                on_duplicate: self.archive.on_duplicate.or(other.archive.on_duplicate),
+                transcode: self.archive.transcode.or(other.archive.transcode),
+                quality: self.archive.quality.or(other.archive.quality),
+                compress: self.archive.compress.or(other.archive.compress),
+            },
+            serve: ServeConfig {
+                storage_dir: self.serve.storage_dir.or(other.serve.storage_dir),
+                allowed_aes: self.serve.allowed_aes.or(other.serve.allowed_aes),
+                max_pdu_length: self.serve.max_pdu_length.or(other.serve.max_pdu_length),
+            },
+            // Rules only make sense as a whole, so those of the per-project file replace any of the
+            // per-user file rather than being merged.
+            routes: if self.routes.is_empty() {
+                other.routes
+            } else {
+                self.routes
+            },
+        }
+    }
+}
+
+/// Searches the current folder and its ancestors for a per-project configuration file.
+fn find_project_config() -> Option<PathBuf> {
+    let cwd: PathBuf = env::current_dir().ok()?;
+    cwd.ancestors()
+        .map(|folder| folder.join(CONFIG_FILE_NAME))
+        .find(|path| path.is_file())
+}
+
+/// The location of the per-user configuration file, which may not exist.
+fn user_config_path() -> Option<PathBuf> {
+    let config_folder: PathBuf = if cfg!(windows) {
+        PathBuf::from(env::var_os("APPDATA")?)
+    } else if let Some(xdg_config) = env::var_os("XDG_CONFIG_HOME") {
+        PathBuf::from(xdg_config)
+    } else {
+        PathBuf::from(env::var_os("HOME")?).join(".config")
+    };
+    Some(
+        config_folder
+            .join(CONFIG_FOLDER_NAME)
+            .join(CONFIG_FILE_NAME),
+    )
+}
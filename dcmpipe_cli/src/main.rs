@@ -1,36 +1,99 @@
 use std::process;
 
-use clap::Parser;
+use anyhow::Result;
+use clap::{CommandFactory, Parser};
+use clap_complete::CompleteEnv;
+use tracing::level_filters::LevelFilter;
 
+use crate::app::anonapp::AnonApp;
 use crate::app::archiveapp::ArchiveApp;
 use crate::app::browseapp::BrowseApp;
+use crate::app::createapp::CreateApp;
+use crate::app::dedupapp::DedupApp;
+use crate::app::echoapp::EchoApp;
+use crate::app::editapp::EditApp;
+use crate::app::imageapp::ImageApp;
 #[cfg(feature = "index")]
 use crate::app::indexapp::IndexApp;
+use crate::app::organizeapp::OrganizeApp;
 use crate::app::printapp::PrintApp;
+use crate::app::refsapp::RefsApp;
 use crate::app::scanapp::ScanApp;
+use crate::app::sendapp::SendApp;
+use crate::app::serveapp::ServeApp;
+use crate::app::statsapp::StatsApp;
+use crate::app::tagsapp::TagsApp;
+use crate::app::videoapp::VideoApp;
+use crate::app::volumeapp::{FromVolumeApp, VolumeApp, VolumeFormat};
+use crate::app::watchapp::WatchApp;
 use crate::app::CommandApplication;
-use crate::args::{Arguments, Command};
+use crate::args::{Arguments, Command, LogLevel};
+use crate::config::Config;
 
 mod app;
 mod args;
+mod completion;
+mod config;
+mod shutdown;
 
 fn main() {
-    let mut app: Box<dyn CommandApplication> = make_app();
-    if let Err(e) = app.run() {
+    CompleteEnv::with_factory(Arguments::command).complete();
+
+    if let Err(e) = make_app().and_then(|mut app| app.run()) {
         eprintln!("Error: {:?}", e);
         process::exit(1);
     }
 }
 
-fn make_app() -> Box<dyn CommandApplication> {
+fn make_app() -> Result<Box<dyn CommandApplication>> {
     let args: Arguments = Arguments::parse();
+    init_logging(args.log_level, args.log_json);
+    let config: Config = Config::load(args.config.as_deref())?;
 
-    match args.command {
+    Ok(match args.command {
         Command::Print(args) => Box::new(PrintApp::new(args)),
-        Command::Browse(args) => Box::new(BrowseApp::new(args)),
+        Command::Browse(args) => Box::new(BrowseApp::new(args, config)),
         Command::Scan(args) => Box::new(ScanApp::new(args)),
+        Command::Stats(args) => Box::new(StatsApp::new(args)),
+        Command::Dedup(args) => Box::new(DedupApp::new(args)),
         #[cfg(feature = "index")]
-        Command::Index(args) => Box::new(IndexApp::new(args)),
-        Command::Archive(args) => Box::new(ArchiveApp::new(args)),
+        Command::Index(args) => Box::new(IndexApp::new(args, config)),
+        Command::Archive(args) => Box::new(ArchiveApp::new(args, config)),
+        Command::Organize(args) => Box::new(OrganizeApp::new(args)),
+        Command::Anon(args) => Box::new(AnonApp::new(args)),
+        Command::Send(args) => Box::new(SendApp::new(args, config)),
+        Command::Echo(args) => Box::new(EchoApp::new(args, config)),
+        Command::Serve(args) => Box::new(ServeApp::new(args, config)),
+        Command::Watch(args) => Box::new(WatchApp::new(args, config)),
+        Command::Tags(args) => Box::new(TagsApp::new(args)),
+        Command::Video(args) => Box::new(VideoApp::new(args)),
+        Command::Create(args) => Box::new(CreateApp::new(args)),
+        Command::Edit(args) => Box::new(EditApp::new(args)),
+        Command::Refs(args) => Box::new(RefsApp::new(args)),
+        Command::Image(args) => Box::new(ImageApp::new(args)),
+        Command::ToNifti(args) => Box::new(VolumeApp::new(args, VolumeFormat::Nifti)),
+        Command::ToMhd(args) => Box::new(VolumeApp::new(args, VolumeFormat::MetaImage)),
+        Command::FromVolume(args) => Box::new(FromVolumeApp::new(args)),
+    })
+}
+
+/// Writes log messages from the library and commands to stderr, so they're kept apart from the
+/// output of commands.
+fn init_logging(level: LogLevel, json: bool) {
+    let level: LevelFilter = match level {
+        LogLevel::Off => LevelFilter::OFF,
+        LogLevel::Error => LevelFilter::ERROR,
+        LogLevel::Warn => LevelFilter::WARN,
+        LogLevel::Info => LevelFilter::INFO,
+        LogLevel::Debug => LevelFilter::DEBUG,
+        LogLevel::Trace => LevelFilter::TRACE,
+    };
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(std::io::stderr);
+    if json {
+        subscriber.json().init();
+    } else {
+        subscriber.init();
     }
 }
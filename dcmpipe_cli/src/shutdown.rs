@@ -0,0 +1,36 @@
+//! Graceful shutdown for long-running commands.
+//!
+//! Commands which process many files opt in by calling `install_handler()` and periodically
+//! checking `is_requested()` between units of work, such as a file or an association. On the first
+//! SIGINT/SIGTERM the command is expected to finish its in-flight work, flush any partial results,
+//! and return normally. A second signal exits immediately.
+
+use std::{
+    process,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use anyhow::{Context, Result};
+
+static SHUTDOWN_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Exit code used when a second signal forces the process to exit, following the shell convention
+/// of 128 + SIGINT.
+const FORCED_EXIT_CODE: i32 = 130;
+
+/// Installs the SIGINT/SIGTERM handler. This should only be called once per process.
+pub fn install_handler() -> Result<()> {
+    ctrlc::set_handler(|| {
+        if SHUTDOWN_REQUESTED.swap(true, Ordering::SeqCst) {
+            eprintln!("Forcing exit");
+            process::exit(FORCED_EXIT_CODE);
+        }
+        eprintln!("Shutdown requested, finishing in-flight work. Signal again to force exit.");
+    })
+    .context("Failed to install signal handler")
+}
+
+/// Whether a shutdown has been requested.
+pub fn is_requested() -> bool {
+    SHUTDOWN_REQUESTED.load(Ordering::SeqCst)
+}
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Error, Write};
 use std::path::{Path, PathBuf};
@@ -30,7 +31,7 @@ static TRANSFER_SYNTAX_PREAMBLE: &str =
 
 #![allow(non_upper_case_globals)]
 
-use crate::core::defn::ts::TransferSyntax;
+use crate::core::defn::ts::{Compression, TransferSyntax};
 use crate::dict::uids;
 
 ";
@@ -39,7 +40,7 @@ static UID_PREAMBLE: &str = "//! This is an auto-generated file. Do not make mod
 
 #![allow(non_upper_case_globals)]
 
-use crate::core::defn::uid::UID;
+use crate::core::defn::uid::{SOPClassCategory, UIDType, UID};
 
 ";
 
@@ -53,6 +54,7 @@ pub static {}: UID = UID {{
     ident: \"{}\",
     uid: \"{}\",
     name: \"{}\",
+    uid_type: {},
 }};
 
 ", $($args)*)
@@ -71,6 +73,7 @@ pub static {}: TransferSyntax = TransferSyntax {{
     big_endian: {},
     deflated: {},
     encapsulated: {},
+    compression: {},
 }};
 
 ", $($args)*)
@@ -87,6 +90,7 @@ macro_rules! dicom_element_definition {
 /// - **VM:** {}
 pub static {}: Tag = Tag {{
     ident: \"{}\",
+    keyword: \"{}\",
     tag: 0x{:04X}_{:04X},
     implicit_vr: {},
     vm: {},
@@ -119,6 +123,8 @@ pub fn process_xml_files(files: Vec<File>, folder: &Path) -> Result<(), Error> {
 fn process_entries(xml_definitions: Vec<XmlDicomDefinition>, folder: &Path) -> Result<(), Error> {
     let mut tag_ident_lookup_phf: phf_codegen::Map<String> = phf_codegen::Map::new();
     let mut tag_tag_lookup_phf: phf_codegen::Map<u32> = phf_codegen::Map::new();
+    let mut tag_keyword_lookup_phf: phf_codegen::Map<String> = phf_codegen::Map::new();
+    let mut tag_keywords: HashSet<String> = HashSet::new();
 
     let mut uid_ident_lookup_phf: phf_codegen::Map<String> = phf_codegen::Map::new();
     let mut uid_id_lookup_phf: phf_codegen::Map<String> = phf_codegen::Map::new();
@@ -144,7 +150,7 @@ fn process_entries(xml_definitions: Vec<XmlDicomDefinition>, folder: &Path) -> R
         }
     }
 
-    xml_dicom_elements.sort_by(|a, b| a.tag.cmp(&b.tag));
+    xml_dicom_elements.sort_by_key(|a| a.tag);
     let mut dicom_elements = xml_dicom_elements
         .iter()
         .filter_map(|e| {
@@ -153,6 +159,8 @@ fn process_entries(xml_definitions: Vec<XmlDicomDefinition>, folder: &Path) -> R
                 "tags::",
                 &mut tag_ident_lookup_phf,
                 &mut tag_tag_lookup_phf,
+                &mut tag_keyword_lookup_phf,
+                &mut tag_keywords,
             )
         })
         .collect::<String>();
@@ -224,6 +232,14 @@ fn process_entries(xml_definitions: Vec<XmlDicomDefinition>, folder: &Path) -> R
     let map_display = tag_tag_lookup_phf.build();
     write!(&mut lookup_file, "{}", &map_display)?;
 
+    write!(&mut lookup_file, ";\n\n")?;
+    write!(
+        &mut lookup_file,
+        "pub static TAG_BY_KEYWORD: phf::Map<&'static str, TagRef> = "
+    )?;
+    let map_display = tag_keyword_lookup_phf.build();
+    write!(&mut lookup_file, "{}", &map_display)?;
+
     write!(&mut lookup_file, ";\n\n")?;
     write!(
         &mut lookup_file,
@@ -272,11 +288,7 @@ fn process_uid(
     }
 
     let type_clone = uid.uid_type.clone();
-    let comment_uid_type: String = if let Some(uid_type) = type_clone {
-        uid_type
-    } else {
-        String::new()
-    };
+    let comment_uid_type: String = type_clone.unwrap_or_default();
 
     let code: String = uid_definition!(
         uid.name,
@@ -285,7 +297,8 @@ fn process_uid(
         var_name,
         var_name,
         uid.value,
-        uid.name
+        uid.name,
+        uid_type_variant(&comment_uid_type, &uid.name)
     ); // field placeholders
 
     let var_name_key: String = var_name.clone();
@@ -358,7 +371,8 @@ fn process_transfer_syntax(
         explicit_vr_val,
         big_endian_val,
         deflated_val,
-        encapsulated_val
+        encapsulated_val,
+        compression_variant(&uid.name)
     );
     let var_name_key: String = var_name.clone();
     ident_lookup.entry(var_name_key, &format!("&ts::{}", var_name));
@@ -368,12 +382,95 @@ fn process_transfer_syntax(
     Some(code)
 }
 
+/// Gets the `UIDType` variant for the UID Type column of the UID table.
+fn uid_type_variant(uid_type: &str, name: &str) -> String {
+    match uid_type {
+        "SOP Class" => format!("UIDType::SOPClass({})", sop_class_category_variant(name)),
+        "Meta SOP Class" => "UIDType::MetaSOPClass".to_owned(),
+        "Transfer Syntax" => "UIDType::TransferSyntax".to_owned(),
+        "Well-known SOP Instance" => "UIDType::WellKnownSOPInstance".to_owned(),
+        "Service Class" => "UIDType::ServiceClass".to_owned(),
+        "Application Context Name" => "UIDType::ApplicationContextName".to_owned(),
+        "Application Hosting Model" => "UIDType::ApplicationHostingModel".to_owned(),
+        "Coding Scheme" | "DICOM UIDs as a Coding Scheme" => "UIDType::CodingScheme".to_owned(),
+        "Mapping Resource" => "UIDType::MappingResource".to_owned(),
+        "Synchronization Frame of Reference" => {
+            "UIDType::SynchronizationFrameOfReference".to_owned()
+        }
+        "LDAP OID" => "UIDType::LDAPOID".to_owned(),
+        _ => "UIDType::Other".to_owned(),
+    }
+}
+
+/// Categorizes a SOP Class by its name, as the standard has no such classification.
+fn sop_class_category_variant(name: &str) -> &'static str {
+    let name: String = name
+        .replace(" (Retired)", "")
+        .replace(" - Trial", "")
+        .replace(" SOP Class", "")
+        .replace(" - For Presentation", "")
+        .replace(" - For Processing", "");
+    // Storage of objects which are images but aren't named as such.
+    let other_images: [&str; 6] = [
+        "Enhanced US Volume Storage",
+        "Parametric Map Storage",
+        "Segmentation Storage",
+        "Ophthalmic Thickness Map Storage",
+        "Corneal Topography Map Storage",
+        "Ophthalmic Optical Coherence Tomography B-scan Volume Analysis Storage",
+    ];
+    // The DICOMDIR is only stored on media, it isn't a SOP Class which can be sent with C-STORE.
+    if name == "Media Storage Directory Storage" {
+        "SOPClassCategory::Service"
+    } else if name.ends_with("SR Storage")
+        || name.ends_with("Report Storage") && !name.starts_with("DICOS")
+        || name == "Procedure Log Storage"
+        || name == "Key Object Selection Document Storage"
+    {
+        "SOPClassCategory::StructuredReportStorage"
+    } else if name.ends_with("Presentation State Storage") {
+        "SOPClassCategory::PresentationStateStorage"
+    } else if name.ends_with("Image Storage") || other_images.contains(&name.as_str()) {
+        "SOPClassCategory::ImageStorage"
+    } else if name.ends_with("Waveform Storage") {
+        "SOPClassCategory::WaveformStorage"
+    } else if name.starts_with("Encapsulated ") && name.ends_with(" Storage") {
+        "SOPClassCategory::EncapsulatedDocumentStorage"
+    } else if name.ends_with(" Storage") {
+        "SOPClassCategory::OtherStorage"
+    } else if name.ends_with(" - FIND") || name.ends_with(" - MOVE") || name.ends_with(" - GET") {
+        "SOPClassCategory::QueryRetrieve"
+    } else {
+        "SOPClassCategory::Service"
+    }
+}
+
+/// Gets the `Compression` variant for a transfer syntax by its name. Names state when compression
+/// is lossless, while the remaining JPEG, MPEG, and HEVC transfer syntaxes may be lossy.
+fn compression_variant(name: &str) -> &'static str {
+    if name.contains("Lossy") {
+        "Compression::Lossy"
+    } else if name.contains("Lossless") {
+        "Compression::Lossless"
+    } else if name.contains("JPEG")
+        || name.contains("JPIP")
+        || name.contains("MPEG")
+        || name.contains("HEVC")
+    {
+        "Compression::Lossy"
+    } else {
+        "Compression::None"
+    }
+}
+
 /// Processes a dicom element into code definition
 fn process_element(
     element: &XmlDicomElement,
     dict: &str,
     ident_lookup: &mut phf_codegen::Map<String>,
     tag_lookup: &mut phf_codegen::Map<u32>,
+    keyword_lookup: &mut phf_codegen::Map<String>,
+    keywords: &mut HashSet<String>,
 ) -> Option<String> {
     let var_name: String = sanitize_var_name(&element.name);
     if var_name.is_empty() {
@@ -421,6 +518,7 @@ fn process_element(
         element.vm, // comment placeholders
         var_name,
         var_name,
+        element.keyword,
         tag_group,
         tag_element,
         vr_value,
@@ -431,6 +529,11 @@ fn process_element(
     let var_name_key: String = var_name.clone();
     ident_lookup.entry(var_name_key, &format!("&{}{}", dict, var_name));
     tag_lookup.entry(element.tag, &format!("&{}{}", dict, var_name));
+    // Not all elements have a keyword, and a few keywords are shared by elements of different
+    // tables, in which case the first element by tag number is used.
+    if !element.keyword.is_empty() && keywords.insert(element.keyword.clone()) {
+        keyword_lookup.entry(element.keyword.clone(), &format!("&{}{}", dict, var_name));
+    }
 
     Some(code)
 }
@@ -484,58 +484,38 @@ impl<R: BufRead> Iterator for XmlDicomDefinitionIterator<R> {
                 // state machine.
                 Ok(Event::Text(data)) => match self.state {
                     XmlDicomReadingState::InDicomElementCell(element_cell) => match element_cell {
-                        XmlDicomElementCell::Tag => {
-                            if self.element_tag.is_none() {
-                                self.element_tag = self.parse_text_bytes_as_u32(&data)
-                            }
+                        XmlDicomElementCell::Tag if self.element_tag.is_none() => {
+                            self.element_tag = self.parse_text_bytes_as_u32(&data)
                         }
-                        XmlDicomElementCell::Name => {
-                            if self.element_name.is_none() {
-                                self.element_name = Some(self.parse_text_bytes(&data))
-                            }
+                        XmlDicomElementCell::Name if self.element_name.is_none() => {
+                            self.element_name = Some(self.parse_text_bytes(&data))
                         }
-                        XmlDicomElementCell::Keyword => {
-                            if self.element_keyword.is_none() {
-                                self.element_keyword = Some(self.parse_text_bytes(&data))
-                            }
+                        XmlDicomElementCell::Keyword if self.element_keyword.is_none() => {
+                            self.element_keyword = Some(self.parse_text_bytes(&data))
                         }
-                        XmlDicomElementCell::VR => {
-                            if self.element_vr.is_none() {
-                                self.element_vr = Some(self.parse_text_bytes(&data))
-                            }
+                        XmlDicomElementCell::VR if self.element_vr.is_none() => {
+                            self.element_vr = Some(self.parse_text_bytes(&data))
                         }
-                        XmlDicomElementCell::VM => {
-                            if self.element_vm.is_none() {
-                                self.element_vm = Some(self.parse_text_bytes(&data))
-                            }
+                        XmlDicomElementCell::VM if self.element_vm.is_none() => {
+                            self.element_vm = Some(self.parse_text_bytes(&data))
                         }
-                        XmlDicomElementCell::Obs => {
-                            if self.element_obs.is_none() {
-                                self.element_obs = Some(self.parse_text_bytes(&data))
-                            }
+                        XmlDicomElementCell::Obs if self.element_obs.is_none() => {
+                            self.element_obs = Some(self.parse_text_bytes(&data))
                         }
                         _ => {}
                     },
                     XmlDicomReadingState::InDicomUidCell(uid_cell) => match uid_cell {
-                        XmlDicomUidCell::Value => {
-                            if self.uid_value.is_none() {
-                                self.uid_value = Some(self.parse_text_bytes(&data))
-                            }
+                        XmlDicomUidCell::Value if self.uid_value.is_none() => {
+                            self.uid_value = Some(self.parse_text_bytes(&data))
                         }
-                        XmlDicomUidCell::Name => {
-                            if self.uid_name.is_none() {
-                                self.uid_name = Some(self.parse_text_bytes(&data))
-                            }
+                        XmlDicomUidCell::Name if self.uid_name.is_none() => {
+                            self.uid_name = Some(self.parse_text_bytes(&data))
                         }
-                        XmlDicomUidCell::Type => {
-                            if self.uid_type.is_none() {
-                                self.uid_type = Some(self.parse_text_bytes(&data))
-                            }
+                        XmlDicomUidCell::Type if self.uid_type.is_none() => {
+                            self.uid_type = Some(self.parse_text_bytes(&data))
                         }
-                        XmlDicomUidCell::Part => {
-                            if self.uid_part.is_none() {
-                                self.uid_part = Some(self.parse_text_bytes(&data))
-                            }
+                        XmlDicomUidCell::Part if self.uid_part.is_none() => {
+                            self.uid_part = Some(self.parse_text_bytes(&data))
                         }
                         _ => {}
                     },
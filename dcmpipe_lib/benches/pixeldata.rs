@@ -0,0 +1,134 @@
+//! Benchmarks of decoding and displaying frames, comparing the per-sample transforms with and
+//! without the `simd` feature:
+//!
+//! ```text
+//! cargo bench -p dcmpipe_lib --bench pixeldata
+//! cargo bench -p dcmpipe_lib --bench pixeldata --features simd
+//! ```
+
+use std::collections::BTreeMap;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use dcmpipe_lib::{
+    core::{
+        charset::DEFAULT_CHARACTER_SET,
+        dcmelement::DicomElement,
+        dcmobject::{DicomObject, DicomRoot},
+        defn::{tag::TagRef, vr},
+        pixeldata::{FrameDecoder, PixelFrame, Window},
+        values::RawValue,
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags, transfer_syntaxes as ts},
+};
+
+const ROWS: u16 = 512;
+const COLUMNS: u16 = 512;
+
+/// Builds a 512x512 frame with the given Image Pixel attributes and Pixel Data.
+fn build_dataset(
+    photometric: &str,
+    samples_per_pixel: u16,
+    bits: (u16, u16),
+    pixel_representation: u16,
+    extra: Vec<(TagRef, RawValue)>,
+) -> DicomRoot<'static> {
+    let (bits_allocated, bits_stored) = bits;
+    let mut elements: Vec<(TagRef, RawValue)> = vec![
+        (
+            &tags::SamplesperPixel,
+            RawValue::UnsignedShorts(vec![samples_per_pixel]),
+        ),
+        (
+            &tags::PhotometricInterpretation,
+            RawValue::Strings(vec![photometric.to_owned()]),
+        ),
+        (
+            &tags::PlanarConfiguration,
+            RawValue::UnsignedShorts(vec![0]),
+        ),
+        (&tags::Rows, RawValue::UnsignedShorts(vec![ROWS])),
+        (&tags::Columns, RawValue::UnsignedShorts(vec![COLUMNS])),
+        (
+            &tags::BitsAllocated,
+            RawValue::UnsignedShorts(vec![bits_allocated]),
+        ),
+        (
+            &tags::BitsStored,
+            RawValue::UnsignedShorts(vec![bits_stored]),
+        ),
+        (
+            &tags::HighBit,
+            RawValue::UnsignedShorts(vec![bits_stored - 1]),
+        ),
+        (
+            &tags::PixelRepresentation,
+            RawValue::UnsignedShorts(vec![pixel_representation]),
+        ),
+    ];
+    elements.extend(extra);
+
+    let mut child_nodes: BTreeMap<u32, DicomObject> = BTreeMap::new();
+    for (tag, value) in elements {
+        let vr = tag.implicit_vr().unwrap_or(&vr::OB);
+        let mut element = DicomElement::new_empty(tag, vr, &ts::ExplicitVRLittleEndian);
+        element
+            .encode_value(value, None)
+            .expect("Value should be encodable");
+        child_nodes.insert(tag.tag, DicomObject::new(element));
+    }
+    DicomRoot::new(
+        &ts::ExplicitVRLittleEndian,
+        DEFAULT_CHARACTER_SET,
+        &STANDARD_DICOM_DICTIONARY,
+        child_nodes,
+        Vec::new(),
+    )
+}
+
+fn bench_ct(c: &mut Criterion) {
+    let pixels: usize = usize::from(ROWS) * usize::from(COLUMNS);
+    let words: Vec<u16> = (0..pixels).map(|i| (i % 4096) as u16).collect();
+    let dcmroot = build_dataset(
+        "MONOCHROME2",
+        1,
+        (16, 12),
+        1,
+        vec![
+            (&tags::RescaleSlope, RawValue::Strings(vec!["1".to_owned()])),
+            (
+                &tags::RescaleIntercept,
+                RawValue::Strings(vec!["-1024".to_owned()]),
+            ),
+            (&tags::PixelData, RawValue::Words(words)),
+        ],
+    );
+    let decoder = FrameDecoder::new(&dcmroot).expect("Frame should be decodable");
+    c.bench_function("decode 12-bit signed with rescale", |b| {
+        b.iter(|| decoder.decode(black_box(0)))
+    });
+
+    let frame: PixelFrame = decoder.decode(0).expect("Frame should be decodable");
+    let window: Window = Window::new(40.0, 400.0);
+    c.bench_function("window 12-bit to rgb8", |b| {
+        b.iter(|| frame.to_rgb8(black_box(&window)))
+    });
+}
+
+fn bench_ybr(c: &mut Criterion) {
+    let samples: usize = usize::from(ROWS) * usize::from(COLUMNS) * 3;
+    let bytes: Vec<u8> = (0..samples).map(|i| (i % 256) as u8).collect();
+    let dcmroot = build_dataset(
+        "YBR_FULL",
+        3,
+        (8, 8),
+        0,
+        vec![(&tags::PixelData, RawValue::Bytes(bytes))],
+    );
+    let decoder = FrameDecoder::new(&dcmroot).expect("Frame should be decodable");
+    c.bench_function("decode YBR_FULL to RGB", |b| {
+        b.iter(|| decoder.decode(black_box(0)))
+    });
+}
+
+criterion_group!(benches, bench_ct, bench_ybr);
+criterion_main!(benches);
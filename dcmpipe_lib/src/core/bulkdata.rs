@@ -0,0 +1,98 @@
+//! References to element values held outside of the dataset, such as in another file or behind a
+//! DICOMweb bulkdata endpoint.
+//!
+//! An element whose value is a `BulkDataRef` carries no value bytes of its own. When the element
+//! is written the referenced bytes are streamed into the dataset through a `BulkDataSource`, so
+//! large OB/OW values such as Pixel Data never need to be held in memory.
+//!
+//! The referenced bytes are the element's value field exactly as it's encoded in the transfer
+//! syntax the element is written with. Values of odd length are padded with a zero byte when
+//! written.
+
+use std::{
+    fmt,
+    io::{self, Read},
+    path::PathBuf,
+};
+#[cfg(feature = "fs")]
+use std::{
+    fs::File,
+    io::{Seek, SeekFrom},
+};
+
+/// A reference to the bytes of an element's value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BulkDataRef {
+    /// A range of bytes within a file.
+    File {
+        path: PathBuf,
+        offset: u64,
+        length: u32,
+    },
+    /// A resource retrieved from a URI, such as a DICOMweb `BulkDataURI`. The length must be
+    /// known up front as it's written ahead of the value.
+    Uri { uri: String, length: u32 },
+}
+
+impl BulkDataRef {
+    /// The number of bytes referenced, not including padding.
+    pub fn length(&self) -> u32 {
+        match self {
+            BulkDataRef::File { length, .. } => *length,
+            BulkDataRef::Uri { length, .. } => *length,
+        }
+    }
+}
+
+impl fmt::Display for BulkDataRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BulkDataRef::File {
+                path,
+                offset,
+                length,
+            } => write!(f, "{}[{offset}+{length}]", path.display()),
+            BulkDataRef::Uri { uri, length } => write!(f, "{uri}[{length}]"),
+        }
+    }
+}
+
+/// Opens the referenced bytes of bulk data for streaming while writing.
+pub trait BulkDataSource: fmt::Debug {
+    /// Opens a reader over the referenced bytes. The writer reads exactly `BulkDataRef::length()`
+    /// bytes from it, and fails if fewer are available.
+    fn open(&self, bulk_data: &BulkDataRef) -> io::Result<Box<dyn Read + '_>>;
+}
+
+/// The default `BulkDataSource`, which reads `BulkDataRef::File` references from the filesystem.
+/// URIs aren't retrieved, a `BulkDataSource` which supports the URIs in use must be given to the
+/// `WriterBuilder` instead. Without the `fs` feature files aren't read either.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FileBulkDataSource;
+
+impl BulkDataSource for FileBulkDataSource {
+    fn open(&self, bulk_data: &BulkDataRef) -> io::Result<Box<dyn Read + '_>> {
+        match bulk_data {
+            #[cfg(feature = "fs")]
+            BulkDataRef::File {
+                path,
+                offset,
+                length,
+            } => {
+                let mut file: File = File::open(path)?;
+                file.seek(SeekFrom::Start(*offset))?;
+                Ok(Box::new(file.take(u64::from(*length))))
+            }
+            #[cfg(not(feature = "fs"))]
+            BulkDataRef::File { path, .. } => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("reading files requires the fs feature: {}", path.display()),
+            )),
+            BulkDataRef::Uri { uri, .. } => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("no source is configured for bulk data URI: {uri}"),
+            )),
+        }
+    }
+}
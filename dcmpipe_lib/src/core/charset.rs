@@ -2,7 +2,7 @@
 
 use std::fmt::Debug;
 
-use encoding_rs::{Encoding, WINDOWS_1252};
+use encoding_rs::{Encoding, UTF_8, WINDOWS_1252};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -51,12 +51,16 @@ impl Debug for CSRef {
 
 pub static DEFAULT_CHARACTER_SET: CSRef = CSRef::of(WINDOWS_1252);
 
+/// The character set for `ISO_IR 192`, UTF-8.
+pub static UTF8_CHARACTER_SET: CSRef = CSRef::of(UTF_8);
+
 /// This is based off `encoding::label::encoding_from_whatwg_label` with a few minor changes
 /// - All whitespace, hyphens, and underscores are stripped when doing a lookup
 /// - Added `ISO-IR-192` mapping for `UTF-8`
+///
 /// See DICOM Part 2 Appendix D.6.2 Support of Character Sets - Character Sets
 /// <http://dicom.nema.org/medical/dicom/current/output/chtml/part02/sect_D.6.2.html>
-pub(crate) fn lookup_charset(label: &str) -> Option<CSRef> {
+pub fn lookup_charset(label: &str) -> Option<CSRef> {
     let label: String = label
         .chars()
         .map(|c| match c {
@@ -73,7 +77,7 @@ pub(crate) fn lookup_charset(label: &str) -> Option<CSRef> {
         .replace('-', "");
 
     match &label[..] {
-        "unicode11utf8" | "utf8" | "isoir192" => Some(CSRef::of(encoding_rs::UTF_8)),
+        "unicode11utf8" | "utf8" | "isoir192" => Some(UTF8_CHARACTER_SET),
         "866" | "cp866" | "csibm866" | "ibm866" => Some(CSRef::of(encoding_rs::IBM866)),
         "csisolatin2" | "iso88592" | "isoir101" | "iso88592:1987" | "l2" | "latin2" => {
             Some(CSRef::of(encoding_rs::ISO_8859_2))
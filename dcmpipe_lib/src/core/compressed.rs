@@ -0,0 +1,205 @@
+//! DICOM files stored with zstd compression.
+//!
+//! A compressed file is a zstd skippable frame holding a `Manifest` of the dataset, followed by a
+//! zstd frame of the entire original file. Standard zstd tools ignore the skippable frame, so
+//! `zstd -d` restores the original file, while the manifest allows the key attributes of a
+//! compressed file to be read without decompressing any of it.
+//!
+//! `DatasetFile` opens either kind of file, transparently decompressing those that are compressed,
+//! so parsing the dataset is the same regardless of how it's stored.
+
+use std::{
+    fs::File,
+    io::{self, BufReader, ErrorKind, Read, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+use zstd::stream::{read::Decoder, write::Encoder};
+
+use crate::{core::dcmobject::DicomRoot, dict::tags};
+
+/// The file extension appended to the names of compressed files.
+pub static COMPRESSED_EXTENSION: &str = "zst";
+
+/// The compression level used when none is specified, favouring speed over size.
+pub const DEFAULT_LEVEL: i32 = 3;
+
+/// The magic number starting a zstd frame.
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+
+/// The magic number starting the skippable frame of the manifest. Skippable frames have magic
+/// numbers `0x184D2A50` through `0x184D2A5F`, and this is the first.
+const MANIFEST_MAGIC: [u8; 4] = [0x50, 0x2A, 0x4D, 0x18];
+
+/// The first line of a manifest, identifying its format and version.
+static MANIFEST_HEADER: &str = "dcmpipe-manifest 1";
+
+/// The maximum size of a manifest which will be read, guarding against corrupt frame sizes.
+const MAX_MANIFEST_LEN: u32 = 64 * 1024;
+
+/// The key attributes of a compressed dataset, stored uncompressed ahead of it so that archives
+/// can be scanned and indexed without decompressing every file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Manifest {
+    pub sop_class_uid: String,
+    pub sop_instance_uid: String,
+    pub study_instance_uid: String,
+    pub series_instance_uid: String,
+    pub ts_uid: String,
+    /// The length of the original, uncompressed file.
+    pub len: u64,
+}
+
+impl Manifest {
+    /// Creates the manifest for a dataset whose original file is `len` bytes long. Missing
+    /// attributes are left empty.
+    pub fn from_dataset(dcmroot: &DicomRoot<'_>, len: u64) -> Manifest {
+        let value = |tag: u32| -> String {
+            dcmroot
+                .get_child_by_tag(tag)
+                .and_then(|obj| String::try_from(obj.element()).ok())
+                .unwrap_or_default()
+        };
+        Manifest {
+            sop_class_uid: value(tags::SOPClassUID.tag),
+            sop_instance_uid: value(tags::SOPInstanceUID.tag),
+            study_instance_uid: value(tags::StudyInstanceUID.tag),
+            series_instance_uid: value(tags::SeriesInstanceUID.tag),
+            ts_uid: dcmroot.ts().uid().uid().to_owned(),
+            len,
+        }
+    }
+
+    /// Encodes the manifest as lines of `key=value`.
+    fn encode(&self) -> String {
+        format!(
+            "{MANIFEST_HEADER}\nSOPClassUID={}\nSOPInstanceUID={}\nStudyInstanceUID={}\nSeriesInstanceUID={}\nTransferSyntaxUID={}\nLength={}\n",
+            self.sop_class_uid,
+            self.sop_instance_uid,
+            self.study_instance_uid,
+            self.series_instance_uid,
+            self.ts_uid,
+            self.len
+        )
+    }
+
+    /// Decodes a manifest, ignoring unrecognized keys so later versions can add to it.
+    fn decode(text: &str) -> io::Result<Manifest> {
+        let mut lines = text.lines();
+        if lines.next() != Some(MANIFEST_HEADER) {
+            return Err(invalid_data("unrecognized manifest"));
+        }
+        let mut manifest: Manifest = Manifest::default();
+        for line in lines {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let value: String = value.to_owned();
+            match key {
+                "SOPClassUID" => manifest.sop_class_uid = value,
+                "SOPInstanceUID" => manifest.sop_instance_uid = value,
+                "StudyInstanceUID" => manifest.study_instance_uid = value,
+                "SeriesInstanceUID" => manifest.series_instance_uid = value,
+                "TransferSyntaxUID" => manifest.ts_uid = value,
+                "Length" => {
+                    manifest.len = value
+                        .parse()
+                        .map_err(|_| invalid_data("invalid manifest length"))?;
+                }
+                _ => {}
+            }
+        }
+        Ok(manifest)
+    }
+}
+
+/// A DICOM file opened for reading, which is decompressed while read if it was compressed.
+pub enum DatasetFile {
+    Plain(File),
+    Compressed(Box<Decoder<'static, BufReader<File>>>),
+}
+
+impl DatasetFile {
+    /// Opens the file at the given path, detecting whether it's compressed from its content
+    /// rather than its name.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<DatasetFile> {
+        let mut file: File = File::open(path)?;
+        let magic: Option<[u8; 4]> = read_magic(&mut file)?;
+        file.seek(SeekFrom::Start(0))?;
+        match magic {
+            Some(ZSTD_MAGIC) | Some(MANIFEST_MAGIC) => {
+                Ok(DatasetFile::Compressed(Box::new(Decoder::new(file)?)))
+            }
+            _ => Ok(DatasetFile::Plain(file)),
+        }
+    }
+
+    pub fn is_compressed(&self) -> bool {
+        matches!(self, DatasetFile::Compressed(_))
+    }
+}
+
+impl Read for DatasetFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            DatasetFile::Plain(file) => file.read(buf),
+            DatasetFile::Compressed(decoder) => decoder.read(buf),
+        }
+    }
+}
+
+/// Writes the compressed form of the file read from `reader` to `writer`, preceded by its
+/// manifest. Returns the writer once the compressed stream has been completed.
+pub fn compress<R: Read, W: Write>(
+    manifest: &Manifest,
+    mut reader: R,
+    mut writer: W,
+    level: i32,
+) -> io::Result<W> {
+    let manifest: String = manifest.encode();
+    let manifest_len: u32 = u32::try_from(manifest.len())
+        .ok()
+        .filter(|len| *len <= MAX_MANIFEST_LEN)
+        .ok_or_else(|| invalid_data("manifest is too long"))?;
+    writer.write_all(&MANIFEST_MAGIC)?;
+    writer.write_all(&manifest_len.to_le_bytes())?;
+    writer.write_all(manifest.as_bytes())?;
+
+    let mut encoder: Encoder<'static, W> = Encoder::new(writer, level)?;
+    io::copy(&mut reader, &mut encoder)?;
+    encoder.finish()
+}
+
+/// Reads the manifest of a compressed file, without decompressing it. Returns `None` if the file
+/// isn't compressed or was compressed without a manifest.
+pub fn read_manifest<P: AsRef<Path>>(path: P) -> io::Result<Option<Manifest>> {
+    let mut file: File = File::open(path)?;
+    if read_magic(&mut file)? != Some(MANIFEST_MAGIC) {
+        return Ok(None);
+    }
+    let mut len: [u8; 4] = [0u8; 4];
+    file.read_exact(&mut len)?;
+    let len: u32 = u32::from_le_bytes(len);
+    if len > MAX_MANIFEST_LEN {
+        return Err(invalid_data("manifest is too long"));
+    }
+    let mut manifest: Vec<u8> = vec![0u8; len as usize];
+    file.read_exact(&mut manifest)?;
+    let manifest: &str =
+        std::str::from_utf8(&manifest).map_err(|_| invalid_data("manifest is not UTF-8"))?;
+    Manifest::decode(manifest).map(Some)
+}
+
+/// Reads the first four bytes of the file, or `None` if it's shorter than that.
+fn read_magic(file: &mut File) -> io::Result<Option<[u8; 4]>> {
+    let mut magic: [u8; 4] = [0u8; 4];
+    match file.read_exact(&mut magic) {
+        Ok(()) => Ok(Some(magic)),
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message.to_owned())
+}
@@ -2,8 +2,10 @@
 
 use std::fmt;
 use std::iter::once;
+use std::path::Path;
 
 use crate::core::{
+    bulkdata::BulkDataRef,
     charset::{CSRef, DEFAULT_CHARACTER_SET},
     dcmsqelem::SequenceElement,
     defn::{
@@ -19,21 +21,47 @@ use crate::core::{
 
 use super::write::valencode::ElemAndRawValue;
 
+/// The bytes of an element's value field.
+#[derive(Debug, Clone)]
+pub enum ValueField<'a> {
+    /// The value is held by the element.
+    Owned(Vec<u8>),
+    /// The value is a slice of the buffer the element was parsed from.
+    Borrowed(&'a [u8]),
+    /// The value was not read while parsing, see `ParserBuilder::defer_values()`. It's located at
+    /// the element's `value_offset()` within the dataset and has the element's value length.
+    Deferred,
+    /// The value is held outside of the dataset and is streamed in when written, see `bulkdata`.
+    BulkData(BulkDataRef),
+}
+
 /// Represents a DICOM Element including its Tag, VR, and Value
 /// Provides methods for parsing the element value as different native types
-pub struct DicomElement {
+///
+/// The value may be borrowed from the buffer the element was parsed from, with lifetime `'a`.
+/// Elements which own their value are `DicomElement<'static>`.
+pub struct DicomElement<'a> {
     tag: u32,
     vr: VRRef,
     vl: ValueLength,
 
-    data: Vec<u8>,
+    data: ValueField<'a>,
     sq_path: Vec<SequenceElement>,
 
     ts: TSRef,
     cs: CSRef,
+
+    /// The byte position of the value field within the dataset it was parsed from.
+    value_offset: Option<u64>,
+
+    /// The byte position of the element's tag within the dataset it was parsed from.
+    offset: Option<u64>,
+
+    /// The number of bytes the element was encoded with in the dataset it was parsed from.
+    encoded_len: Option<u64>,
 }
 
-impl fmt::Debug for DicomElement {
+impl fmt::Debug for DicomElement<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
@@ -46,7 +74,7 @@ impl fmt::Debug for DicomElement {
     }
 }
 
-impl DicomElement {
+impl DicomElement<'static> {
     pub fn new<T>(
         tag: T,
         vr: VRRef,
@@ -64,10 +92,13 @@ impl DicomElement {
             tag: Into::<u32>::into(tag),
             vr,
             vl,
-            data,
+            data: ValueField::Owned(data),
             sq_path,
             ts,
             cs,
+            value_offset: None,
+            offset: None,
+            encoded_len: None,
         }
     }
 
@@ -80,10 +111,13 @@ impl DicomElement {
             tag: Into::<u32>::into(tag),
             vr,
             vl: ValueLength::UndefinedLength,
-            data: Vec::with_capacity(0),
+            data: ValueField::Owned(Vec::with_capacity(0)),
             sq_path: Vec::with_capacity(0),
             ts,
             cs,
+            value_offset: None,
+            offset: None,
+            encoded_len: None,
         }
     }
 
@@ -97,13 +131,18 @@ impl DicomElement {
             tag: 0,
             vr: &vr::INVALID,
             vl: ValueLength::Explicit(0),
-            data: Vec::with_capacity(0),
+            data: ValueField::Owned(Vec::with_capacity(0)),
             sq_path: Vec::with_capacity(0),
             ts: &ts::ExplicitVRLittleEndian,
             cs: DEFAULT_CHARACTER_SET,
+            value_offset: None,
+            offset: None,
+            encoded_len: None,
         }
     }
+}
 
+impl<'a> DicomElement<'a> {
     pub fn tag(&self) -> u32 {
         self.tag
     }
@@ -112,6 +151,10 @@ impl DicomElement {
         self.vl
     }
 
+    pub(crate) fn set_vl(&mut self, vl: ValueLength) {
+        self.vl = vl;
+    }
+
     pub fn vr(&self) -> VRRef {
         self.vr
     }
@@ -124,10 +167,169 @@ impl DicomElement {
         self.cs
     }
 
-    pub fn data(&self) -> &Vec<u8> {
+    /// The bytes of the value field, padded to even length. This is empty if the value was
+    /// deferred while parsing and has not been resolved, see `is_deferred()`, or if the value is a
+    /// bulk data reference, see `bulk_data()`.
+    pub fn data(&self) -> &[u8] {
+        match &self.data {
+            ValueField::Owned(data) => data,
+            ValueField::Borrowed(data) => data,
+            ValueField::Deferred | ValueField::BulkData(_) => &[],
+        }
+    }
+
+    pub fn value_field(&self) -> &ValueField<'a> {
         &self.data
     }
 
+    /// Whether the value was deferred while parsing and has not been resolved, see
+    /// `DicomRoot::resolve_deferred()`.
+    pub fn is_deferred(&self) -> bool {
+        matches!(self.data, ValueField::Deferred)
+    }
+
+    /// The reference to the value held outside of the dataset, if the value is bulk data.
+    pub fn bulk_data(&self) -> Option<&BulkDataRef> {
+        match &self.data {
+            ValueField::BulkData(bulk_data) => Some(bulk_data),
+            _ => None,
+        }
+    }
+
+    /// The length of the value field in bytes, padded to even length. Unlike `data().len()` this
+    /// includes values which were deferred or are bulk data references.
+    pub fn value_len(&self) -> usize {
+        match (&self.data, self.vl) {
+            (ValueField::Deferred, ValueLength::Explicit(len)) => (len as usize + 1) & !1,
+            (ValueField::BulkData(bulk_data), _) => (bulk_data.length() as usize + 1) & !1,
+            _ => self.data().len(),
+        }
+    }
+
+    /// Converts this element to one which owns its value, copying a borrowed value.
+    pub fn into_owned(self) -> DicomElement<'static> {
+        let data: ValueField<'static> = match self.data {
+            ValueField::Owned(data) => ValueField::Owned(data),
+            ValueField::Borrowed(data) => ValueField::Owned(data.to_vec()),
+            ValueField::Deferred => ValueField::Deferred,
+            ValueField::BulkData(bulk_data) => ValueField::BulkData(bulk_data),
+        };
+        DicomElement {
+            tag: self.tag,
+            vr: self.vr,
+            vl: self.vl,
+            data,
+            sq_path: self.sq_path,
+            ts: self.ts,
+            cs: self.cs,
+            value_offset: self.value_offset,
+            offset: self.offset,
+            encoded_len: self.encoded_len,
+        }
+    }
+
+    /// Marks the value as deferred, located at the given position within the dataset.
+    pub(crate) fn defer_value(&mut self, value_offset: u64) {
+        self.data = ValueField::Deferred;
+        self.value_offset = Some(value_offset);
+    }
+
+    /// Refers a deferred value to its bytes within the file at `path`, which the dataset was parsed
+    /// from.
+    pub(crate) fn refer_deferred_value(&mut self, path: &Path) {
+        if let (ValueField::Deferred, ValueLength::Explicit(length), Some(offset)) =
+            (&self.data, self.vl, self.value_offset)
+        {
+            self.set_bulk_data(BulkDataRef::File {
+                path: path.to_path_buf(),
+                offset,
+                length,
+            });
+        }
+    }
+
+    /// Resolves a deferred value from the buffer the dataset was parsed from, borrowing it where
+    /// possible. Values of odd length are copied so the value remains padded to even length.
+    /// Returns `false` if the value lies outside the buffer.
+    pub(crate) fn resolve_value(&mut self, source: &'a [u8]) -> bool {
+        let (ValueField::Deferred, ValueLength::Explicit(len), Some(offset)) =
+            (&self.data, self.vl, self.value_offset)
+        else {
+            return true;
+        };
+        let Some(value) = usize::try_from(offset)
+            .ok()
+            .and_then(|start| source.get(start..start.checked_add(len as usize)?))
+        else {
+            return false;
+        };
+        self.data = if len % 2 == 0 {
+            ValueField::Borrowed(value)
+        } else {
+            let mut data: Vec<u8> = Vec::with_capacity(value.len() + 1);
+            data.extend_from_slice(value);
+            data.push(0);
+            ValueField::Owned(data)
+        };
+        true
+    }
+
+    /// The byte position of the value field within the dataset this element was parsed from, if
+    /// known. This is `None` for elements which weren't parsed, whose value has since been
+    /// encoded, or which were parsed from a deflated dataset.
+    pub fn value_offset(&self) -> Option<u64> {
+        self.value_offset
+    }
+
+    /// The byte position of the element's tag within the dataset this element was parsed from, if
+    /// known. Along with `encoded_len()` this locates the element's bytes in the dataset, such as
+    /// to patch or extract them in place. This is `None` for elements which weren't parsed or
+    /// which were parsed from a deflated dataset.
+    ///
+    /// Unlike `value_offset()` this continues to refer to the dataset after the value is changed.
+    pub fn offset(&self) -> Option<u64> {
+        self.offset
+    }
+
+    /// The number of bytes the element was encoded with in the dataset it was parsed from,
+    /// including its tag, VR, and value length. The length of a sequence or item includes its
+    /// contents and any delimitation item. This is `None` if `offset()` is, and for sequences and
+    /// items of undefined length, unless parsed with `DicomRoot::parse()` which determines their
+    /// length from their delimitation item.
+    pub fn encoded_len(&self) -> Option<u64> {
+        self.encoded_len
+    }
+
+    /// Sets where the element was encoded within the dataset it was parsed from.
+    pub(crate) fn set_encoded_range(&mut self, offset: u64, encoded_len: Option<u64>) {
+        self.offset = Some(offset);
+        self.encoded_len = encoded_len;
+    }
+
+    /// Determines the encoded length of a sequence or item of undefined length from the
+    /// delimitation item which ends it.
+    pub(crate) fn set_delimiter(&mut self, delimiter: &DicomElement) {
+        if let (None, Some(offset), Some(delimiter_offset), Some(delimiter_len)) = (
+            self.encoded_len,
+            self.offset,
+            delimiter.offset,
+            delimiter.encoded_len,
+        ) {
+            self.encoded_len = Some(delimiter_offset + delimiter_len - offset);
+        }
+    }
+
+    /// Sets the item number of the sequence at `depth` within this element's sequence path.
+    pub(crate) fn set_sequence_item(&mut self, depth: usize, item: usize) {
+        if let Some(sq) = self.sq_path.get_mut(depth) {
+            sq.set_item(item);
+        }
+    }
+
+    pub(crate) fn set_value_offset(&mut self, value_offset: Option<u64>) {
+        self.value_offset = value_offset;
+    }
+
     pub fn sequence_path(&self) -> &Vec<SequenceElement> {
         &self.sq_path
     }
@@ -139,7 +341,7 @@ impl DicomElement {
 
     /// Returns whether the the size of the value field for this element is zero.
     pub fn is_empty(&self) -> bool {
-        self.data.is_empty()
+        self.value_len() == 0
     }
 
     /// Checks if this `DicomElement` is a sentinel value, identified by having the following
@@ -180,13 +382,33 @@ impl DicomElement {
     ///
     /// * `value` - The value to be encoded according to `self.vr`.
     /// * `vl` - The value length to use. If `None` then the value length will be computed and
-    /// `ValueLength::Explicit` will be assigned to `self.vl`. If `Some` then it will only be used
-    /// if `self.is_seq_like()` would return true, otherwise the value length will be computed and
-    /// `ValueLength::Explicit` will be assigned to `self.vl`. Unconditionally, `self.vl` will be
-    /// assigned `ValueLength::Explicit(0)` if this element is `Item`, `ItemDelimitationItem`, or
-    /// `SequenceDelimitationItem`.
+    ///   `ValueLength::Explicit` will be assigned to `self.vl`. If `Some` then it will only be used
+    ///   if `self.is_seq_like()` would return true, otherwise the value length will be computed and
+    ///   `ValueLength::Explicit` will be assigned to `self.vl`. Unconditionally, `self.vl` will be
+    ///   assigned `ValueLength::Explicit(0)` if this element is `Item`, `ItemDelimitationItem`, or
+    ///   `SequenceDelimitationItem`.
+    ///
+    /// Encoding `RawValue::BulkData` makes this element refer to the bytes instead, with the value
+    /// length of the referenced bytes padded to even length. This isn't valid for sequences.
     pub fn encode_value(&mut self, value: RawValue, vl: Option<ValueLength>) -> ParseResult<()> {
-        self.data = ElemAndRawValue(self, value).try_into()?;
+        if let RawValue::BulkData(bulk_data) = value {
+            if self.vr == &vr::SQ
+                || self.tag == tags::ITEM
+                || self.tag == tags::ITEM_DELIMITATION_ITEM
+                || self.tag == tags::SEQUENCE_DELIMITATION_ITEM
+            {
+                return Err(read::ParseError::EncodeValueError {
+                    message: "sequences and items can't refer to bulk data".to_owned(),
+                    tagstring: TagPath::format_tagpath_to_display(&self.create_tagpath(), None),
+                    vr: self.vr,
+                });
+            }
+            self.set_bulk_data(bulk_data);
+            return Ok(());
+        }
+
+        self.data = ValueField::Owned(ElemAndRawValue(self, value).try_into()?);
+        self.value_offset = None;
 
         self.vl = if vl.is_some() && self.is_seq_like() || self.tag == tags::ITEM {
             vl.unwrap()
@@ -195,9 +417,55 @@ impl DicomElement {
         {
             ValueLength::Explicit(0)
         } else {
-            ValueLength::Explicit(self.data.len() as u32)
+            ValueLength::Explicit(self.data().len() as u32)
         };
 
         Ok(())
     }
+
+    /// Replaces this element's value with the given bytes, which should be of even length.
+    pub(crate) fn set_data(&mut self, data: Vec<u8>) {
+        self.vl = ValueLength::Explicit(data.len() as u32);
+        self.data = ValueField::Owned(data);
+        self.value_offset = None;
+    }
+
+    /// Makes this element's value the bytes referenced by `bulk_data`.
+    pub(crate) fn set_bulk_data(&mut self, bulk_data: BulkDataRef) {
+        self.vl = ValueLength::Explicit((bulk_data.length() + 1) & !1);
+        self.data = ValueField::BulkData(bulk_data);
+        self.value_offset = None;
+    }
+}
+
+/// Creates a sequence or item element with undefined length. Its contents are written as
+/// subsequent elements.
+#[cfg(any(feature = "dimse", feature = "fuzz", feature = "testkit"))]
+pub(crate) fn structural<T>(tag: T, vr: VRRef, ts: TSRef) -> DicomElement<'static>
+where
+    T: Into<u32>,
+{
+    DicomElement::new(
+        tag,
+        vr,
+        ValueLength::UndefinedLength,
+        ts,
+        DEFAULT_CHARACTER_SET,
+        Vec::new(),
+        Vec::new(),
+    )
+}
+
+/// Creates an item or sequence delimitation element.
+#[cfg(any(feature = "dimse", feature = "fuzz", feature = "testkit"))]
+pub(crate) fn delimiter(tag: u32, ts: TSRef) -> DicomElement<'static> {
+    DicomElement::new(
+        tag,
+        &vr::UN,
+        ValueLength::Explicit(0),
+        ts,
+        DEFAULT_CHARACTER_SET,
+        Vec::new(),
+        Vec::new(),
+    )
 }
@@ -4,51 +4,87 @@ use std::{
     collections::{btree_map, BTreeMap},
     fmt,
     io::Read,
+    path::Path,
 };
 
 use crate::core::{
     charset::CSRef,
     dcmelement::DicomElement,
+    dcmsqelem::SequenceElement,
     defn::{
-        constants::tags,
+        constants::{tags, ts},
         dcmdict::DicomDictionary,
-        tag::{TagNode, TagPath},
+        tag::{Tag, TagNode, TagPath},
         ts::TSRef,
         vl::ValueLength,
+        vr,
     },
+    filemeta::{self, FileMetaIssue},
+    padding,
+    provenance::{Provenance, ProvenanceKind},
+    sizereport::SizeReport,
+    values::RawValue,
 };
 
 use super::{
-    read::{ParseError, Parser},
-    write::error::WriteError,
+    read::{error::ParseErrorInfo, ParseError, Parser},
+    write::{
+        error::WriteError,
+        transcode::{check_transcodable, encoded_header_len, transcode_element, transcoded_vr_ts},
+    },
 };
 
+/// How `DicomRoot::merge()` resolves an attribute which is present in both datasets. Sequences
+/// present in both are always merged item by item, with this policy applied within each item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergePolicy {
+    /// The attribute of the other dataset replaces the existing one.
+    Overwrite,
+    /// The existing attribute is kept, only absent attributes are added.
+    KeepExisting,
+    /// The attribute of the other dataset replaces the existing one only if it has no value.
+    FillEmpty,
+}
+
+/// The dataset attributes are merged into, see `DicomRoot::merge()`.
+struct MergeTarget<'s> {
+    ts: TSRef,
+    cs: CSRef,
+    policy: MergePolicy,
+    track_provenance: bool,
+    stage: &'s str,
+}
+
 /// A root node of a DICOM dataset. This is the root object returned after parsing a dataset. It
 /// does not contain a `DicomElement` itself but will have either children or items.
-pub struct DicomRoot<'dict> {
+pub struct DicomRoot<'a> {
     ts: TSRef,
     cs: CSRef,
-    dictionary: &'dict dyn DicomDictionary,
+    dictionary: &'a dyn DicomDictionary,
+
+    /// Whether changes made through this root record `Provenance` on the affected elements.
+    track_provenance: bool,
 
     /// This is an object to be parent of all the root-level elements, but does not itself
     /// represent an element.
-    sentinel: DicomObject,
+    sentinel: DicomObject<'a>,
 }
 
-impl<'dict> DicomRoot<'dict> {
+impl<'a> DicomRoot<'a> {
     pub fn new(
         ts: TSRef,
         cs: CSRef,
-        dictionary: &dyn DicomDictionary,
-        child_nodes: BTreeMap<u32, DicomObject>,
-        items: Vec<DicomObject>,
-    ) -> DicomRoot<'_> {
+        dictionary: &'a dyn DicomDictionary,
+        child_nodes: BTreeMap<u32, DicomObject<'a>>,
+        items: Vec<DicomObject<'a>>,
+    ) -> DicomRoot<'a> {
         let sentinel_elem = DicomElement::new_sentinel();
         let sentinel = DicomObject::new_with_children(sentinel_elem, child_nodes, items);
         DicomRoot {
             ts,
             cs,
             dictionary,
+            track_provenance: false,
             sentinel,
         }
     }
@@ -56,7 +92,7 @@ impl<'dict> DicomRoot<'dict> {
     /// Returns a delegate object that holds all the root-level elements. Be cautious not to use
     /// the `DicomObject::as_element()` on the returned value as it does not represent an actual
     /// element within the dataset and does not hold valid data.
-    pub fn as_obj(&self) -> &DicomObject {
+    pub fn as_obj(&self) -> &DicomObject<'a> {
         &self.sentinel
     }
 
@@ -71,7 +107,7 @@ impl<'dict> DicomRoot<'dict> {
     }
 
     /// Get the dictionary used to encode the dataset.
-    pub fn dictionary(&self) -> &'dict dyn DicomDictionary {
+    pub fn dictionary(&self) -> &'a dyn DicomDictionary {
         self.dictionary
     }
 
@@ -79,11 +115,11 @@ impl<'dict> DicomRoot<'dict> {
         self.sentinel.child_count()
     }
 
-    pub fn get_child_by_tag(&self, tag: u32) -> Option<&DicomObject> {
+    pub fn get_child_by_tag(&self, tag: u32) -> Option<&DicomObject<'a>> {
         self.sentinel.get_child_by_tag(tag)
     }
 
-    pub fn iter_child_nodes(&self) -> btree_map::Iter<'_, u32, DicomObject> {
+    pub fn iter_child_nodes(&self) -> btree_map::Iter<'_, u32, DicomObject<'a>> {
         self.sentinel.iter_child_nodes()
     }
 
@@ -91,40 +127,365 @@ impl<'dict> DicomRoot<'dict> {
         self.sentinel.item_count()
     }
 
-    pub fn get_item_by_index(&self, index: usize) -> Option<&DicomObject> {
+    pub fn get_item_by_index(&self, index: usize) -> Option<&DicomObject<'a>> {
         self.sentinel.get_item_by_index(index)
     }
 
-    pub fn iter_items(&self) -> std::slice::Iter<DicomObject> {
+    pub fn iter_items(&self) -> std::slice::Iter<'_, DicomObject<'a>> {
         self.sentinel.iter_items()
     }
 
     /// Get a child node with the given `TagNode`.
-    pub fn get_child_by_tagnode(&self, tag_node: &TagNode) -> Option<&DicomObject> {
+    pub fn get_child_by_tagnode(&self, tag_node: &TagNode) -> Option<&DicomObject<'a>> {
         self.sentinel.get_child_by_tagnode(tag_node)
     }
 
     /// Get a child node with the given `TagPath`.
-    pub fn get_child_by_tagpath(&self, tagpath: &TagPath) -> Option<&DicomObject> {
+    pub fn get_child_by_tagpath(&self, tagpath: &TagPath) -> Option<&DicomObject<'a>> {
         self.sentinel.get_child_by_tagpath(tagpath)
     }
 
+    /// Get a mutable child node with the given `TagPath`. Changes made through the returned object
+    /// do not record provenance, see `DicomRoot::modify_element`.
+    pub fn get_child_by_tagpath_mut(&mut self, tagpath: &TagPath) -> Option<&mut DicomObject<'a>> {
+        self.sentinel.get_child_by_tagpath_mut(tagpath)
+    }
+
     /// Flattens this object into an ordered list of elements as they would appear in a dataset.
-    pub fn flatten(&self) -> Result<Vec<&DicomElement>, WriteError> {
+    pub fn flatten(&self) -> Result<Vec<&DicomElement<'a>>, WriteError> {
         self.sentinel.flatten()
     }
 
+    /// The object holding the top-level elements of the dataset as its children.
+    pub(crate) fn sentinel(&self) -> &DicomObject<'a> {
+        &self.sentinel
+    }
+
+    /// Iterates depth-first over the elements of the dataset in the order they would appear in
+    /// it, along with the `TagPath` of each element. Item and delimiter elements are not included,
+    /// elements within items are addressed through the item's sequence.
+    pub fn iter_elements(&self) -> ElementIter<'_, 'a> {
+        ElementIter {
+            stack: vec![ElementIterFrame::new(TagPath::empty(), &self.sentinel)],
+        }
+    }
+
+    /// Iterates depth-first over the elements of the dataset, as `iter_elements()`, allowing each
+    /// element to be changed. Changes made through the iterator do not record provenance, see
+    /// `DicomRoot::modify_element`, and `update_value_lengths()` should be called afterwards if
+    /// elements within sequences changed length.
+    pub fn iter_elements_mut(&mut self) -> ElementIterMut<'_, 'a> {
+        let DicomObject {
+            child_nodes, items, ..
+        } = &mut self.sentinel;
+        ElementIterMut {
+            stack: vec![ElementIterMutFrame {
+                path: TagPath::empty(),
+                items: items.iter_mut().enumerate(),
+                children: child_nodes.iter_mut(),
+            }],
+        }
+    }
+
+    /// Whether provenance is being recorded for elements in this root.
+    pub fn track_provenance(&self) -> bool {
+        self.track_provenance
+    }
+
+    /// Enables or disables recording provenance. When enabled, all elements which do not yet have
+    /// provenance are marked as `ProvenanceKind::Parsed`.
+    pub fn set_track_provenance(&mut self, track_provenance: bool) {
+        self.track_provenance = track_provenance;
+        if track_provenance {
+            self.sentinel.mark_untracked(&Provenance::parsed());
+        }
+    }
+
+    /// Get the provenance of the element at the given `TagPath`. This is `None` if the element
+    /// does not exist or if provenance was not being tracked.
+    pub fn get_provenance_by_tagpath(&self, tagpath: &TagPath) -> Option<&Provenance> {
+        self.get_child_by_tagpath(tagpath)
+            .and_then(|obj| obj.provenance())
+    }
+
+    /// Collects the provenance of all elements in this root which have provenance recorded, along
+    /// with the `TagPath` of each element, in dataset order. Item and delimiter elements are not
+    /// included.
+    pub fn collect_provenance(&self) -> Vec<(TagPath, &Provenance)> {
+        let mut collected: Vec<(TagPath, &Provenance)> = Vec::new();
+        self.sentinel
+            .collect_provenance(&TagPath::empty(), &mut collected);
+        collected
+    }
+
+    /// Modifies the element at the given `TagPath` with the given function. If provenance is being
+    /// tracked, the element is marked as modified by the given pipeline stage, unless that stage
+    /// also synthesized it. Returns `None` if no element exists at the path.
+    pub fn modify_element<F, R>(&mut self, tagpath: &TagPath, stage: &str, modify: F) -> Option<R>
+    where
+        F: FnOnce(&mut DicomElement<'a>) -> R,
+    {
+        let track_provenance: bool = self.track_provenance;
+        let obj: &mut DicomObject<'a> = self.sentinel.get_child_by_tagpath_mut(tagpath)?;
+        let result: R = modify(obj.element_mut());
+        if track_provenance {
+            let is_own_synthesized: bool = obj.provenance().is_some_and(|p| {
+                p.kind() == ProvenanceKind::Synthesized && p.stage() == Some(stage)
+            });
+            if !is_own_synthesized {
+                obj.set_provenance(Some(Provenance::modified(stage)));
+            }
+        }
+        Some(result)
+    }
+
+    /// Inserts an element created by the given pipeline stage at the given `TagPath`, replacing
+    /// any existing element with the same tag. The last node of the path is the new element, and
+    /// must have its tag and no item index. All prior nodes must refer to an existing sequence
+    /// item. If provenance is being tracked, the element is marked as synthesized by the given
+    /// stage, or as modified by it if an element was replaced, see `modify_element()`. Returns
+    /// the replaced object, if any.
+    pub fn insert_element(
+        &mut self,
+        tagpath: &TagPath,
+        element: DicomElement<'a>,
+        stage: &str,
+    ) -> Result<Option<DicomObject<'a>>, ParseError> {
+        let dictionary: &dyn DicomDictionary = self.dictionary;
+        let invalid_path = || ParseError::InvalidTagPath {
+            string_path: TagPath::format_tagpath_to_display(tagpath, Some(dictionary)),
+        };
+        let (last, parent_nodes) = tagpath.nodes.split_last().ok_or_else(invalid_path)?;
+        if last.tag() != element.tag()
+            || last.item().is_some()
+            || parent_nodes.iter().any(|node| node.item().is_none())
+        {
+            return Err(invalid_path());
+        }
+
+        let parent_path: TagPath = parent_nodes.to_vec().into();
+        let parent: &mut DicomObject<'a> = if parent_path.is_empty() {
+            &mut self.sentinel
+        } else {
+            self.sentinel
+                .get_child_by_tagpath_mut(&parent_path)
+                .ok_or_else(invalid_path)?
+        };
+
+        let tag: u32 = element.tag();
+        let replaced: Option<DicomObject<'a>> = parent.insert_child(DicomObject::new(element));
+        if self.track_provenance {
+            let is_own_synthesized: bool = replaced
+                .as_ref()
+                .and_then(DicomObject::provenance)
+                .is_some_and(|p| {
+                    p.kind() == ProvenanceKind::Synthesized && p.stage() == Some(stage)
+                });
+            let provenance: Provenance = if replaced.is_none() || is_own_synthesized {
+                Provenance::synthesized(stage)
+            } else {
+                Provenance::modified(stage)
+            };
+            if let Some(obj) = parent.get_child_by_tag_mut(tag) {
+                obj.set_provenance(Some(provenance));
+            }
+        }
+        Ok(replaced)
+    }
+
+    /// Removes the element at the given `TagPath`, along with any of its children and items.
+    /// Returns the removed object, or `None` if no element exists at the path.
+    pub fn remove_element(&mut self, tagpath: &TagPath) -> Option<DicomObject<'a>> {
+        let (last, parent_nodes) = tagpath.nodes.split_last()?;
+        if last.item().is_some() {
+            return None;
+        }
+        let parent_path: TagPath = parent_nodes.to_vec().into();
+        let parent: &mut DicomObject<'a> = if parent_path.is_empty() {
+            &mut self.sentinel
+        } else {
+            self.sentinel.get_child_by_tagpath_mut(&parent_path)?
+        };
+        parent.remove_child(last.tag())
+    }
+
+    /// Overlays the attributes of `other` onto this dataset, resolving attributes present in both
+    /// with `policy`. Sequences present in both are merged item by item, and items beyond those of
+    /// the existing sequence are appended. This is useful for applying worklist demographics to
+    /// acquired instances, or a template of corrections.
+    ///
+    /// Attributes are re-encoded into this dataset's transfer syntax and character set. The File
+    /// Meta group and Specific Character Set of `other` are not merged. If provenance is being
+    /// tracked, added elements are marked as synthesized by the given pipeline stage and replaced
+    /// elements as modified by it. Value lengths are updated, see `update_value_lengths()`.
+    ///
+    /// Returns the number of attributes added or replaced, not counting those within them.
+    pub fn merge(
+        &mut self,
+        other: &DicomRoot<'_>,
+        policy: MergePolicy,
+        stage: &str,
+    ) -> Result<usize, WriteError> {
+        let target: MergeTarget<'_> = MergeTarget {
+            ts: self.ts,
+            cs: self.cs,
+            policy,
+            track_provenance: self.track_provenance,
+            stage,
+        };
+        let merged: usize = self.sentinel.merge_children(&other.sentinel, &target)?;
+        self.update_value_lengths();
+        Ok(merged)
+    }
+
+    /// Recomputes the value length of all sequences and items which have an explicit value length,
+    /// based on the encoded size of their contents. This should be called prior to writing if
+    /// elements within such sequences were added, removed, or modified, as the value lengths are
+    /// otherwise retained from parsing.
+    pub fn update_value_lengths(&mut self) {
+        self.sentinel.update_contents_len();
+    }
+
+    /// Rewrites the dataset into a canonical encoding, so that datasets with the same content are
+    /// written with the same bytes however they were originally encoded, for comparing them
+    /// byte-wise or hashing them. In the canonical encoding:
+    /// - Elements are in order of tag, as they always are within a `DicomRoot`.
+    /// - Elements are in Explicit VR Little Endian, unless the Pixel Data is encapsulated in which
+    ///   case the transfer syntax is unchanged.
+    /// - Sequences and items have explicit value lengths, other than encapsulated Pixel Data and
+    ///   sequences with VR of UN, whose contents are left as they are.
+    /// - Values are padded as with `PaddingPolicy::Normalize`.
+    /// - The retired Group Length elements are removed.
+    ///
+    /// Values deferred while parsing must have been resolved. The character set of the dataset is
+    /// unchanged, and provenance isn't recorded for the changes.
+    pub fn canonicalize(&mut self) -> Result<(), WriteError> {
+        let canonical_ts: TSRef = if self.ts.encapsulated() {
+            self.ts
+        } else {
+            &ts::ExplicitVRLittleEndian
+        };
+        if self.ts != canonical_ts {
+            self.transcode_in_place(canonical_ts)?;
+        }
+        self.sentinel.canonicalize_contents()?;
+        self.update_value_lengths();
+        Ok(())
+    }
+
+    /// Re-encodes all elements into the transfer syntax `to` in place, see `transcode()`, updating
+    /// the `TransferSyntaxUID` of the File Meta group if present. Unlike `transcode()`, changing to
+    /// or from an encapsulated transfer syntax is not rejected, so the caller is responsible for
+    /// re-encoding Pixel Data. No elements are modified if any can't be re-encoded.
+    pub(crate) fn transcode_in_place(&mut self, to: TSRef) -> Result<(), WriteError> {
+        for element in self.flatten()? {
+            check_transcodable(element, to)?;
+        }
+        self.sentinel.transcode_contents(to);
+        if let Some(ts_obj) = self
+            .sentinel
+            .get_child_by_tag_mut(tags::TRANSFER_SYNTAX_UID)
+        {
+            ts_obj
+                .element
+                .encode_value(RawValue::Uid(to.uid().uid().to_owned()), None)?;
+        }
+        self.ts = to;
+        Ok(())
+    }
+
+    /// The number of bytes the dataset occupies when transcoded into the given transfer syntax and
+    /// written, see `transcode()`, not including the file preamble and `DICM` prefix. This is the
+    /// length prior to any deflate. If the dataset has a File Meta group this includes the
+    /// `FileMetaInformationGroupLength` element, which the `Writer` generates if not present, and
+    /// the `TransferSyntaxUID` is taken to be updated to `ts`.
+    ///
+    /// The length of individual elements along with their contents, which can be used to compute
+    /// offsets within the dataset, is available from `DicomObject::encoded_len()`.
+    pub fn encoded_len(&self, ts: TSRef) -> u64 {
+        let has_file_meta: bool = self
+            .iter_child_nodes()
+            .next()
+            .is_some_and(|(tag, _child)| *tag <= tags::FILE_META_GROUP_END);
+        let has_group_length: bool = self
+            .get_child_by_tag(tags::FILE_META_INFORMATION_GROUP_LENGTH)
+            .is_some();
+        // The generated FileMetaInformationGroupLength is a UL with a single value, in explicit VR.
+        let group_length_len: u64 = if has_file_meta && !has_group_length {
+            12
+        } else {
+            0
+        };
+        let contents_len: u64 = group_length_len + self.sentinel.contents_len(ts);
+        match self.get_child_by_tag(tags::TRANSFER_SYNTAX_UID) {
+            Some(ts_obj) => {
+                let ts_uid_len: usize = (ts.uid().uid().len() + 1) & !1;
+                contents_len - ts_obj.element().value_len() as u64 + ts_uid_len as u64
+            }
+            None => contents_len,
+        }
+    }
+
+    /// Checks the File Meta group of the dataset for the problems commonly found in files produced
+    /// by homegrown tools, see `FileMetaIssue`. A dataset without a File Meta group is reported as
+    /// missing each of its required elements.
+    pub fn check_file_meta(&self) -> Result<Vec<FileMetaIssue>, WriteError> {
+        filemeta::check(self)
+    }
+
+    /// Repairs the problems reported by `check_file_meta()`, returning those which were repaired.
+    /// The File Meta group is re-encoded in Explicit VR Little Endian, missing or mismatched
+    /// `MediaStorageSOPClassUID` and `MediaStorageSOPInstanceUID` are copied from the `SOPClassUID`
+    /// and `SOPInstanceUID` of the dataset, `TransferSyntaxUID` is set to the transfer syntax of the
+    /// dataset, and `FileMetaInformationGroupLength` is recomputed. A missing Media Storage UID
+    /// can't be repaired if the dataset lacks the corresponding UID.
+    ///
+    /// If provenance is being tracked, the repaired elements are marked as synthesized or modified
+    /// by the given pipeline stage.
+    pub fn repair_file_meta(&mut self, stage: &str) -> Result<Vec<FileMetaIssue>, WriteError> {
+        filemeta::repair(self, stage)
+    }
+
+    /// Reports the bytes the dataset occupies as encoded, by group and by element, split between
+    /// the headers and values of elements. This is useful to diagnose why a dataset is unexpectedly
+    /// large, e.g. due to a large private element or deeply nested sequences.
+    pub fn size_report(&self) -> SizeReport {
+        SizeReport::new(self)
+    }
+
+    /// Resolves the values deferred while parsing, see `ParserBuilder::defer_values()`, borrowing
+    /// them from `source` which must be the same bytes the dataset was parsed from. Values of odd
+    /// length are copied in order to be padded to even length.
+    pub fn resolve_deferred<'b>(self, source: &'b [u8]) -> Result<DicomRoot<'b>, ParseError>
+    where
+        'a: 'b,
+    {
+        let mut root: DicomRoot<'b> = self;
+        root.sentinel.resolve_deferred(source, root.dictionary)?;
+        Ok(root)
+    }
+
+    /// Refers the values deferred while parsing, see `ParserBuilder::defer_values()`, to their
+    /// bytes within the file at `path`, which must be the file the dataset was parsed from. The
+    /// values become bulk data references, see `bulkdata`, which are streamed from the file when
+    /// the dataset is written rather than read into memory.
+    pub fn reference_deferred(&mut self, path: &Path) {
+        self.sentinel.reference_deferred(path);
+    }
+
     /// Parses elements to build a `DicomObject` to represent the parsed dataset as an in-memory tree.
     /// Returns `None` if the parser's first element fails to parse properly, assumed to be a non-DICOM
     /// dataset. Any errors after a successful first element being parsed are returned as `Result::Err`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub fn parse<DatasetType: Read>(
-        parser: &mut Parser<'dict, DatasetType>,
-    ) -> Result<Option<DicomRoot<'dict>>, ParseError> {
-        let mut child_nodes: BTreeMap<u32, DicomObject> = BTreeMap::new();
-        let mut items: Vec<DicomObject> = Vec::new();
+        parser: &mut Parser<'a, DatasetType>,
+    ) -> Result<Option<DicomRoot<'a>>, ParseError> {
+        let mut child_nodes: BTreeMap<u32, DicomObject<'a>> = BTreeMap::new();
+        let mut items: Vec<DicomObject<'a>> = Vec::new();
 
-        let parse_result: Option<Result<DicomElement, ParseError>> =
-            DicomRoot::parse_recurse(parser, &mut child_nodes, &mut items, true);
+        let first_element: Option<Result<DicomElement<'a>, ParseError>> = parser.next();
+        let parse_result: Option<Result<DicomElement<'a>, ParseError>> =
+            DicomRoot::parse_recurse(parser, first_element, &mut child_nodes, &mut items, true);
 
         if !parser.behavior().allow_partial_object() {
             if let Some(Err(e)) = parse_result {
@@ -149,6 +510,47 @@ impl<'dict> DicomRoot<'dict> {
         Ok(Some(root))
     }
 
+    /// Continues parsing elements into this dataset from a parser which has been resumed with
+    /// `Parser::resume()` after stopping at its `ParseStop`, such as to read the Pixel Data after
+    /// deciding from the header of the dataset that it's needed. Parsing may have stopped within a
+    /// sequence, in which case elements are added into the sequence's items.
+    pub fn parse_resumed<DatasetType: Read>(
+        &mut self,
+        parser: &mut Parser<'a, DatasetType>,
+    ) -> Result<(), ParseError> {
+        let mut next_element: Option<Result<DicomElement<'a>, ParseError>> = parser.next();
+        while let Some(Ok(element)) = next_element {
+            let Some(parent) = self.sentinel.get_parent_mut(element.sequence_path()) else {
+                return Err(ParseErrorInfo(
+                    &element,
+                    "resumed element lies outside of the parsed dataset",
+                    Some(self.dictionary),
+                )
+                .into());
+            };
+            next_element = DicomRoot::parse_recurse(
+                parser,
+                Some(Ok(element)),
+                &mut parent.child_nodes,
+                &mut parent.items,
+                false,
+            );
+        }
+
+        // Parsing may have stopped before the transfer syntax or specific character set was read.
+        self.ts = parser.ts();
+        self.cs = parser.cs();
+        if self.track_provenance {
+            self.sentinel.mark_untracked(&Provenance::parsed());
+        }
+        if !parser.behavior().allow_partial_object() {
+            if let Some(Err(e)) = next_element {
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
     /// Iterates through the parser populating values into the given `child_nodes` map. Elements which
     /// are sequence-like (contain sub-elements) will be recursed into so child elements are added to
     /// their node. The sequence path length is used to determine when parsing an element whether it
@@ -156,17 +558,18 @@ impl<'dict> DicomRoot<'dict> {
     /// sequence can be the end of multiple sequences).
     ///
     /// `parser` The parser elements are being read from
+    /// `next_element` The first element to be added, usually the next element from `parser`
     /// `child_nodes` The map of child nodes which elements should be parsed into
     /// `items` The list of nodes which item elements should be parsed into
     /// `is_root_level` Whether the root level is being parsed, or within child nodes
     fn parse_recurse<DatasetType: Read>(
         parser: &mut Parser<'_, DatasetType>,
-        child_nodes: &mut BTreeMap<u32, DicomObject>,
-        items: &mut Vec<DicomObject>,
+        mut next_element: Option<Result<DicomElement<'a>, ParseError>>,
+        child_nodes: &mut BTreeMap<u32, DicomObject<'a>>,
+        items: &mut Vec<DicomObject<'a>>,
         is_root_level: bool,
-    ) -> Option<Result<DicomElement, ParseError>> {
+    ) -> Option<Result<DicomElement<'a>, ParseError>> {
         let mut prev_seq_path_len: usize = 0;
-        let mut next_element: Option<Result<DicomElement, ParseError>> = parser.next();
 
         // If the first element at the root level is an error then this is probably not valid dicom.
         if is_root_level {
@@ -189,17 +592,40 @@ impl<'dict> DicomRoot<'dict> {
                 return Some(Ok(element));
             }
 
-            let mut possible_next_elem: Option<Result<DicomElement, ParseError>> = None;
+            let mut possible_next_elem: Option<Result<DicomElement<'a>, ParseError>> = None;
             // Checking sequence or item tag should match dcmparser.read_dicom_element() which
             // does not read a value for those elements but lets the parser read its value as
             // separate elements which we're considering child elements.
-            let dcmobj: DicomObject = if element.is_seq_like()
+            let dcmobj: DicomObject<'a> = if element.is_seq_like()
                 || (tag == tags::ITEM && element.vl() != ValueLength::Explicit(0))
             {
-                let mut child_nodes: BTreeMap<u32, DicomObject> = BTreeMap::new();
-                let mut items: Vec<DicomObject> = Vec::new();
-                possible_next_elem =
-                    DicomRoot::parse_recurse(parser, &mut child_nodes, &mut items, false);
+                let mut child_nodes: BTreeMap<u32, DicomObject<'a>> = BTreeMap::new();
+                let mut items: Vec<DicomObject<'a>> = Vec::new();
+                let first_child: Option<Result<DicomElement<'a>, ParseError>> = parser.next();
+                // A sequence without contents, such as one with explicit length of zero or whose
+                // contents were skipped, is immediately followed by an element which is not its child.
+                let has_children: bool = !matches!(&first_child,
+                    Some(Ok(child)) if child.sequence_path().len() < cur_seq_path_len);
+                possible_next_elem = if has_children {
+                    DicomRoot::parse_recurse(
+                        parser,
+                        first_child,
+                        &mut child_nodes,
+                        &mut items,
+                        false,
+                    )
+                } else {
+                    first_child
+                };
+                // Sequences and items of undefined length end with a delimitation item, parsed as
+                // their last child.
+                let mut element: DicomElement<'a> = element;
+                if let Some(delimiter) = child_nodes
+                    .get(&tags::SEQUENCE_DELIMITATION_ITEM)
+                    .or_else(|| child_nodes.get(&tags::ITEM_DELIMITATION_ITEM))
+                {
+                    element.set_delimiter(delimiter.element());
+                }
                 DicomObject::new_with_children(element, child_nodes, items)
             } else {
                 DicomObject::new(element)
@@ -258,50 +684,89 @@ impl std::fmt::Debug for DicomRoot<'_> {
 }
 
 /// A DICOM object which represents a DICOM Element and may have child elements
-pub struct DicomObject {
+pub struct DicomObject<'a> {
     /// The element of the current node. For sequence elements this will be the sequence element
     /// itself.
-    element: DicomElement,
+    element: DicomElement<'a>,
     /// Child nodes which may be elements of sub-sequences.
-    child_nodes: BTreeMap<u32, DicomObject>,
+    child_nodes: BTreeMap<u32, DicomObject<'a>>,
     /// Item nodes don't go into the map since there can be multiple of them.
-    items: Vec<DicomObject>,
+    items: Vec<DicomObject<'a>>,
+    /// How this element came to be, only recorded if the `DicomRoot` is tracking provenance.
+    provenance: Option<Provenance>,
 }
 
-impl DicomObject {
-    pub fn new(element: DicomElement) -> DicomObject {
+impl<'a> DicomObject<'a> {
+    pub fn new(element: DicomElement<'a>) -> DicomObject<'a> {
         DicomObject {
             element,
             child_nodes: BTreeMap::new(),
             items: Vec::new(),
+            provenance: None,
         }
     }
 
     pub fn new_with_children(
-        element: DicomElement,
-        child_nodes: BTreeMap<u32, DicomObject>,
-        items: Vec<DicomObject>,
-    ) -> DicomObject {
+        element: DicomElement<'a>,
+        child_nodes: BTreeMap<u32, DicomObject<'a>>,
+        items: Vec<DicomObject<'a>>,
+    ) -> DicomObject<'a> {
         DicomObject {
             element,
             child_nodes,
             items,
+            provenance: None,
         }
     }
 
-    pub fn element(&self) -> &DicomElement {
+    pub fn element(&self) -> &DicomElement<'a> {
         &self.element
     }
 
+    pub fn element_mut(&mut self) -> &mut DicomElement<'a> {
+        &mut self.element
+    }
+
+    /// Get how this element came to be, if provenance was recorded.
+    pub fn provenance(&self) -> Option<&Provenance> {
+        self.provenance.as_ref()
+    }
+
+    /// Sets the provenance of this element.
+    pub fn set_provenance(&mut self, provenance: Option<Provenance>) {
+        self.provenance = provenance;
+    }
+
     pub fn child_count(&self) -> usize {
         self.child_nodes.len()
     }
 
-    pub fn get_child_by_tag(&self, tag: u32) -> Option<&DicomObject> {
+    pub fn get_child_by_tag(&self, tag: u32) -> Option<&DicomObject<'a>> {
         self.child_nodes.get(&tag)
     }
 
-    pub fn iter_child_nodes(&self) -> btree_map::Iter<'_, u32, DicomObject> {
+    pub fn get_child_by_tag_mut(&mut self, tag: u32) -> Option<&mut DicomObject<'a>> {
+        self.child_nodes.get_mut(&tag)
+    }
+
+    /// Inserts the given object as a child node, keyed by its element's tag. Returns the child
+    /// node which was replaced, if any.
+    pub fn insert_child(&mut self, child: DicomObject<'a>) -> Option<DicomObject<'a>> {
+        self.child_nodes.insert(child.element().tag(), child)
+    }
+
+    /// Appends the given object as an item, e.g. of a sequence. The item's element should be an
+    /// Item, whose value length is set with `DicomRoot::update_value_lengths()`.
+    pub fn push_item(&mut self, item: DicomObject<'a>) {
+        self.items.push(item);
+    }
+
+    /// Removes the child node with the given tag, returning it if present.
+    pub fn remove_child(&mut self, tag: u32) -> Option<DicomObject<'a>> {
+        self.child_nodes.remove(&tag)
+    }
+
+    pub fn iter_child_nodes(&self) -> btree_map::Iter<'_, u32, DicomObject<'a>> {
         self.child_nodes.iter()
     }
 
@@ -309,16 +774,20 @@ impl DicomObject {
         self.items.len()
     }
 
-    pub fn get_item_by_index(&self, index: usize) -> Option<&DicomObject> {
+    pub fn get_item_by_index(&self, index: usize) -> Option<&DicomObject<'a>> {
         self.items.get(index - 1)
     }
 
-    pub fn iter_items(&self) -> std::slice::Iter<DicomObject> {
+    pub fn get_item_by_index_mut(&mut self, index: usize) -> Option<&mut DicomObject<'a>> {
+        self.items.get_mut(index - 1)
+    }
+
+    pub fn iter_items(&self) -> std::slice::Iter<'_, DicomObject<'a>> {
         self.items.iter()
     }
 
     /// Get a child node with the given `TagNode`.
-    pub fn get_child_by_tagnode(&self, tag_node: &TagNode) -> Option<&DicomObject> {
+    pub fn get_child_by_tagnode(&self, tag_node: &TagNode) -> Option<&DicomObject<'a>> {
         self.get_child_by_tag(tag_node.tag())
             .and_then(|o| match tag_node.item() {
                 None => Some(o),
@@ -327,7 +796,7 @@ impl DicomObject {
     }
 
     /// Get a child node with the given `TagPath`.
-    pub fn get_child_by_tagpath(&self, tagpath: &TagPath) -> Option<&DicomObject> {
+    pub fn get_child_by_tagpath(&self, tagpath: &TagPath) -> Option<&DicomObject<'a>> {
         let mut target = tagpath
             .nodes
             .first()
@@ -338,11 +807,329 @@ impl DicomObject {
         target
     }
 
+    /// Get a mutable child node with the given `TagNode`.
+    pub fn get_child_by_tagnode_mut(&mut self, tag_node: &TagNode) -> Option<&mut DicomObject<'a>> {
+        self.get_child_by_tag_mut(tag_node.tag())
+            .and_then(|o| match tag_node.item() {
+                None => Some(o),
+                Some(item_num) => o.get_item_by_index_mut(item_num),
+            })
+    }
+
+    /// Get a mutable child node with the given `TagPath`.
+    pub fn get_child_by_tagpath_mut(&mut self, tagpath: &TagPath) -> Option<&mut DicomObject<'a>> {
+        let mut target: &mut DicomObject<'a> = self;
+        for node in tagpath.nodes.iter() {
+            target = target.get_child_by_tagnode_mut(node)?;
+        }
+        if tagpath.is_empty() {
+            None
+        } else {
+            Some(target)
+        }
+    }
+
+    /// Merges the children of `other` into this object's children, see `DicomRoot::merge()`.
+    fn merge_children(
+        &mut self,
+        other: &DicomObject<'_>,
+        target: &MergeTarget<'_>,
+    ) -> Result<usize, WriteError> {
+        let mut merged: usize = 0;
+        for (tag, other_child) in other.iter_child_nodes() {
+            if *tag <= tags::FILE_META_GROUP_END
+                || *tag == tags::SPECIFIC_CHARACTER_SET
+                || is_delimiter(*tag)
+            {
+                continue;
+            }
+
+            let Some(existing) = self.child_nodes.get_mut(tag) else {
+                let mut copy: DicomObject<'a> = DicomObject::copy_from(other_child, target)?;
+                if target.track_provenance {
+                    copy.set_tracked(Provenance::synthesized(target.stage));
+                }
+                self.insert_child(copy);
+                merged += 1;
+                continue;
+            };
+
+            if existing.element.is_seq_like() && other_child.element.is_seq_like() {
+                merged += existing.merge_items(other_child, target)?;
+                continue;
+            }
+            let replace: bool = match target.policy {
+                MergePolicy::Overwrite => true,
+                MergePolicy::KeepExisting => false,
+                MergePolicy::FillEmpty => existing.element.is_empty(),
+            };
+            if replace {
+                let mut copy: DicomObject<'a> = DicomObject::copy_from(other_child, target)?;
+                if target.track_provenance {
+                    copy.set_tracked(Provenance::modified(target.stage));
+                }
+                *existing = copy;
+                merged += 1;
+            }
+        }
+        Ok(merged)
+    }
+
+    /// Merges the items of the sequence `other` into the items of this sequence, appending those
+    /// beyond this sequence's items.
+    fn merge_items(
+        &mut self,
+        other: &DicomObject<'_>,
+        target: &MergeTarget<'_>,
+    ) -> Result<usize, WriteError> {
+        let mut merged: usize = 0;
+        // Elements within the items have this sequence at this depth of their sequence path.
+        let depth: usize = self.element.sequence_path().len();
+        for (index, other_item) in other.iter_items().enumerate() {
+            if let Some(item) = self.items.get_mut(index) {
+                merged += item.merge_children(other_item, target)?;
+                continue;
+            }
+            let mut copy: DicomObject<'a> = DicomObject::copy_from(other_item, target)?;
+            copy.renumber_item(depth, index + 1);
+            if target.track_provenance {
+                copy.mark_untracked(&Provenance::synthesized(target.stage));
+            }
+            self.items.push(copy);
+            merged += 1;
+        }
+        Ok(merged)
+    }
+
+    /// Copies `other` along with its children and items, re-encoding the elements for `target`.
+    fn copy_from(
+        other: &DicomObject<'_>,
+        target: &MergeTarget<'_>,
+    ) -> Result<DicomObject<'static>, WriteError> {
+        let mut child_nodes: BTreeMap<u32, DicomObject<'static>> = BTreeMap::new();
+        for (tag, child) in other.iter_child_nodes() {
+            child_nodes.insert(*tag, DicomObject::copy_from(child, target)?);
+        }
+        let items: Vec<DicomObject<'static>> = other
+            .iter_items()
+            .map(|item| DicomObject::copy_from(item, target))
+            .collect::<Result<_, _>>()?;
+        Ok(DicomObject::new_with_children(
+            copy_element(other.element(), target)?,
+            child_nodes,
+            items,
+        ))
+    }
+
+    /// Sets the item number of the sequence at `depth` in the sequence path of this object's
+    /// element and all elements within it.
+    fn renumber_item(&mut self, depth: usize, item: usize) {
+        self.element.set_sequence_item(depth, item);
+        for child in self.items.iter_mut().chain(self.child_nodes.values_mut()) {
+            child.renumber_item(depth, item);
+        }
+    }
+
+    /// Sets the provenance of this object, and of all elements within it.
+    fn set_tracked(&mut self, provenance: Provenance) {
+        self.mark_untracked(&provenance);
+        self.provenance = Some(provenance);
+    }
+
+    /// Refers the deferred values of this object's items and children to the file, recursively.
+    fn reference_deferred(&mut self, path: &Path) {
+        self.element.refer_deferred_value(path);
+        for item in self.items.iter_mut() {
+            item.reference_deferred(path);
+        }
+        for child in self.child_nodes.values_mut() {
+            child.reference_deferred(path);
+        }
+    }
+
+    /// Resolves the deferred values of this object's items and children, recursively.
+    fn resolve_deferred(
+        &mut self,
+        source: &'a [u8],
+        dictionary: &dyn DicomDictionary,
+    ) -> Result<(), ParseError> {
+        if !self.element.resolve_value(source) {
+            return Err(ParseErrorInfo(
+                &self.element,
+                "deferred value lies outside of the source",
+                Some(dictionary),
+            )
+            .into());
+        }
+        for item in self.items.iter_mut() {
+            item.resolve_deferred(source, dictionary)?;
+        }
+        for child in self.child_nodes.values_mut() {
+            child.resolve_deferred(source, dictionary)?;
+        }
+        Ok(())
+    }
+
+    /// Re-encodes this object's items and children into the transfer syntax `to`, recursively.
+    /// Elements should first be checked with `check_transcodable()`.
+    fn transcode_contents(&mut self, to: TSRef) {
+        for obj in self.items.iter_mut().chain(self.child_nodes.values_mut()) {
+            obj.element = transcode_element(&obj.element, to);
+            obj.transcode_contents(to);
+        }
+    }
+
+    /// Canonicalizes the encoding of this object's items and children, recursively, see
+    /// `DicomRoot::canonicalize()`. Value lengths of sequences and items are updated afterwards.
+    fn canonicalize_contents(&mut self) -> Result<(), WriteError> {
+        self.child_nodes.retain(|tag, _child| {
+            !is_delimiter(*tag)
+                && (!Tag::is_group_length(*tag) || *tag == tags::FILE_META_INFORMATION_GROUP_LENGTH)
+        });
+        for obj in self.items.iter_mut().chain(self.child_nodes.values_mut()) {
+            let element: &mut DicomElement<'a> = &mut obj.element;
+            if element.is_deferred() {
+                return Err(WriteError::DeferredValue);
+            }
+            if element.is_seq_like() && element.vr() != &vr::SQ {
+                // Encapsulated Pixel Data, and sequences with VR of UN, are only parsed as such
+                // with an undefined length.
+                continue;
+            }
+            if element.is_seq_like() || element.tag() == tags::ITEM {
+                element.set_vl(ValueLength::Explicit(0));
+                obj.canonicalize_contents()?;
+            } else if let (None, ValueLength::Explicit(length)) =
+                (element.bulk_data(), element.vl())
+            {
+                let value: &[u8] = &element.data()[..element.data().len().min(length as usize)];
+                if padding::check(element.vr(), value).is_some() {
+                    let normalized: Vec<u8> = padding::normalize(element.vr(), value);
+                    element.set_data(normalized);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Updates the value lengths of this object's items and children, recursively, returning the
+    /// total encoded size of them.
+    fn update_contents_len(&mut self) -> u64 {
+        let items_len: u64 = self
+            .items
+            .iter_mut()
+            .map(DicomObject::update_encoded_len)
+            .sum();
+        let children_len: u64 = self
+            .child_nodes
+            .values_mut()
+            .map(DicomObject::update_encoded_len)
+            .sum();
+        items_len + children_len
+    }
+
+    /// Updates the value length of this object's element, if it's a sequence or item with an
+    /// explicit value length, returning the encoded size of the element and its contents.
+    fn update_encoded_len(&mut self) -> u64 {
+        let value_len: u64 = self.element.value_len() as u64 + self.update_contents_len();
+        let has_contents: bool = self.element.is_seq_like() || self.element.tag() == tags::ITEM;
+        if has_contents && matches!(self.element.vl(), ValueLength::Explicit(_)) {
+            self.element.set_vl(ValueLength::Explicit(value_len as u32));
+        }
+        encoded_header_len(self.element.vr(), self.element.ts()) + value_len
+    }
+
+    /// The number of bytes this object's element and its contents occupy when transcoded into the
+    /// given transfer syntax and written, see `transcode()`. This is the length prior to any
+    /// deflate, and includes the delimiters of sequences and items which have them.
+    pub fn encoded_len(&self, ts: TSRef) -> u64 {
+        let (vr, elem_ts) = transcoded_vr_ts(&self.element, ts);
+        let delimiters_len: u64 = self
+            .iter_child_nodes()
+            .filter(|(tag, _child)| is_delimiter(**tag))
+            .map(|(_tag, child)| child.encoded_len(ts))
+            .sum();
+        encoded_header_len(vr, elem_ts)
+            + self.element.value_len() as u64
+            + self.contents_len(ts)
+            + delimiters_len
+    }
+
+    /// The number of bytes this object's items and children occupy when transcoded into the given
+    /// transfer syntax and written. For a sequence or item this is the value length it would have
+    /// if encoded with an explicit length, excluding any delimiters.
+    pub fn contents_len(&self, ts: TSRef) -> u64 {
+        let items_len: u64 = self.iter_items().map(|item| item.encoded_len(ts)).sum();
+        let children_len: u64 = self
+            .iter_child_nodes()
+            .filter(|(tag, _child)| !is_delimiter(**tag))
+            .map(|(_tag, child)| child.encoded_len(ts))
+            .sum();
+        items_len + children_len
+    }
+
+    /// Sets the given provenance on this object's children and items, recursively, which do not
+    /// already have provenance.
+    pub(crate) fn mark_untracked(&mut self, provenance: &Provenance) {
+        for item in self.items.iter_mut() {
+            item.mark_untracked(provenance);
+        }
+        for child in self.child_nodes.values_mut() {
+            if child.provenance.is_none() {
+                child.provenance = Some(provenance.clone());
+            }
+            child.mark_untracked(provenance);
+        }
+    }
+
+    /// Gets the object which elements with the given sequence path are parsed into. Elements
+    /// within an item have the sequence's path node followed by an `Item` node, while the items
+    /// and delimiter of a sequence are parsed into the sequence itself.
+    fn get_parent_mut(&mut self, sq_path: &[SequenceElement]) -> Option<&mut DicomObject<'a>> {
+        let mut parent: &mut DicomObject<'a> = self;
+        for (index, sq_elem) in sq_path.iter().enumerate() {
+            if sq_elem.seq_tag() == tags::ITEM {
+                continue;
+            }
+            parent = parent.child_nodes.get_mut(&sq_elem.seq_tag())?;
+            let in_item: bool = sq_path
+                .get(index + 1)
+                .is_some_and(|next| next.seq_tag() == tags::ITEM);
+            if in_item {
+                let item_index: usize = sq_elem.item()?.checked_sub(1)?;
+                parent = parent.items.get_mut(item_index)?;
+            }
+        }
+        Some(parent)
+    }
+
+    /// Collects the provenance of all children of this object, recursively, with `path` being the
+    /// `TagPath` of this object.
+    fn collect_provenance<'p>(
+        &'p self,
+        path: &TagPath,
+        collected: &mut Vec<(TagPath, &'p Provenance)>,
+    ) {
+        for (index, item) in self.iter_items().enumerate() {
+            // Items are addressed through the path node of their sequence.
+            item.collect_provenance(&item_path(path, index), collected);
+        }
+        for (tag, child) in self.iter_child_nodes() {
+            let child_path: TagPath = child_path(path, *tag);
+            if !is_item_or_delimiter(*tag) {
+                if let Some(provenance) = child.provenance() {
+                    collected.push((child_path.clone(), provenance));
+                }
+            }
+            child.collect_provenance(&child_path, collected);
+        }
+    }
+
     /// Flattens this object into an ordered list of elements as they would appear in a dataset.
-    pub fn flatten(&self) -> Result<Vec<&DicomElement>, WriteError> {
+    pub fn flatten(&self) -> Result<Vec<&DicomElement<'a>>, WriteError> {
         // TODO: Can this instead return an iterator?
 
-        let mut elements: Vec<&DicomElement> = Vec::new();
+        let mut elements: Vec<&DicomElement<'a>> = Vec::new();
 
         // List items + contents first, as SQ objects will include both items for its contents as
         // well as the sequence delimiter as a child node.
@@ -359,7 +1146,158 @@ impl DicomObject {
     }
 }
 
-impl fmt::Debug for DicomObject {
+/// Copies the element, re-encoding it into the transfer syntax and character set of `target`.
+fn copy_element(
+    element: &DicomElement<'_>,
+    target: &MergeTarget<'_>,
+) -> Result<DicomElement<'static>, WriteError> {
+    check_transcodable(element, target.ts)?;
+    let copy: DicomElement<'static> = transcode_element(element, target.ts);
+    let cs: CSRef = copy.vr().get_proper_cs(target.cs);
+    if copy.is_empty() || copy.bulk_data().is_some() || copy.cs().name() == cs.name() {
+        return Ok(copy);
+    }
+
+    let mut recoded: DicomElement<'static> = DicomElement::new(
+        copy.tag(),
+        copy.vr(),
+        copy.vl(),
+        copy.ts(),
+        cs,
+        Vec::new(),
+        copy.sequence_path().clone(),
+    );
+    recoded.encode_value(copy.parse_value()?, None)?;
+    Ok(recoded)
+}
+
+/// Whether the tag is of an item or of an element which ends an item or sequence, which aren't
+/// attributes of the dataset themselves.
+fn is_item_or_delimiter(tag: u32) -> bool {
+    tag == tags::ITEM || is_delimiter(tag)
+}
+
+/// The path of the item at the given index within the sequence at `path`.
+fn item_path(path: &TagPath, index: usize) -> TagPath {
+    let mut item_path: TagPath = path.clone();
+    if let Some(last) = item_path.nodes.last_mut() {
+        *last.item_mut() = Some(index + 1);
+    }
+    item_path
+}
+
+/// The path of the child with the given tag within the object at `path`.
+fn child_path(path: &TagPath, tag: u32) -> TagPath {
+    let mut child_path: TagPath = path.clone();
+    child_path.nodes.push(TagNode::from(tag));
+    child_path
+}
+
+/// The iterator returned by `DicomRoot::iter_elements()`.
+pub struct ElementIter<'r, 'a> {
+    stack: Vec<ElementIterFrame<'r, 'a>>,
+}
+
+/// The items and children remaining to be visited of an object.
+struct ElementIterFrame<'r, 'a> {
+    path: TagPath,
+    items: std::iter::Enumerate<std::slice::Iter<'r, DicomObject<'a>>>,
+    children: btree_map::Iter<'r, u32, DicomObject<'a>>,
+}
+
+impl<'r, 'a> ElementIterFrame<'r, 'a> {
+    fn new(path: TagPath, obj: &'r DicomObject<'a>) -> ElementIterFrame<'r, 'a> {
+        ElementIterFrame {
+            path,
+            items: obj.items.iter().enumerate(),
+            children: obj.child_nodes.iter(),
+        }
+    }
+}
+
+impl<'r, 'a> Iterator for ElementIter<'r, 'a> {
+    type Item = (TagPath, &'r DicomElement<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let frame: &mut ElementIterFrame<'r, 'a> = self.stack.last_mut()?;
+            // Items are visited before children, as the children of a sequence are only its
+            // delimiter.
+            if let Some((index, item)) = frame.items.next() {
+                let path: TagPath = item_path(&frame.path, index);
+                self.stack.push(ElementIterFrame::new(path, item));
+                continue;
+            }
+            let Some((tag, child)) = frame.children.next() else {
+                self.stack.pop();
+                continue;
+            };
+            let path: TagPath = child_path(&frame.path, *tag);
+            self.stack.push(ElementIterFrame::new(path.clone(), child));
+            if !is_item_or_delimiter(*tag) {
+                return Some((path, child.element()));
+            }
+        }
+    }
+}
+
+/// The iterator returned by `DicomRoot::iter_elements_mut()`.
+pub struct ElementIterMut<'r, 'a> {
+    stack: Vec<ElementIterMutFrame<'r, 'a>>,
+}
+
+/// The items and children remaining to be visited of an object.
+struct ElementIterMutFrame<'r, 'a> {
+    path: TagPath,
+    items: std::iter::Enumerate<std::slice::IterMut<'r, DicomObject<'a>>>,
+    children: btree_map::IterMut<'r, u32, DicomObject<'a>>,
+}
+
+impl<'r, 'a> Iterator for ElementIterMut<'r, 'a> {
+    type Item = (TagPath, &'r mut DicomElement<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let frame: &mut ElementIterMutFrame<'r, 'a> = self.stack.last_mut()?;
+            if let Some((index, item)) = frame.items.next() {
+                let path: TagPath = item_path(&frame.path, index);
+                self.stack.push(ElementIterMutFrame {
+                    path,
+                    items: item.items.iter_mut().enumerate(),
+                    children: item.child_nodes.iter_mut(),
+                });
+                continue;
+            }
+            let Some((tag, child)) = frame.children.next() else {
+                self.stack.pop();
+                continue;
+            };
+            let path: TagPath = child_path(&frame.path, *tag);
+            // The element is borrowed apart from the items and children still to be visited.
+            let DicomObject {
+                element,
+                child_nodes,
+                items,
+                ..
+            } = child;
+            self.stack.push(ElementIterMutFrame {
+                path: path.clone(),
+                items: items.iter_mut().enumerate(),
+                children: child_nodes.iter_mut(),
+            });
+            if !is_item_or_delimiter(*tag) {
+                return Some((path, element));
+            }
+        }
+    }
+}
+
+/// Whether the tag is of an element which ends an item or sequence of undefined length.
+fn is_delimiter(tag: u32) -> bool {
+    tag == tags::ITEM_DELIMITATION_ITEM || tag == tags::SEQUENCE_DELIMITATION_ITEM
+}
+
+impl fmt::Debug for DicomObject<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.element.tag() == 0 {
             write!(f, "<sentinel>")
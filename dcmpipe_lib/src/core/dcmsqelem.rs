@@ -41,6 +41,10 @@ pub struct SequenceElement {
     /// included in an encapsulated Data Set, then the Specific Character Set value of the
     /// encapsulating Data Set applies.
     cs: CSRef,
+
+    /// Whether the sequence was encoded as `UN` and its VR resolved from the dictionary while
+    /// parsing, see `ParserBuilder::resolve_un()`. Its contents are encoded as IVRLE.
+    resolved_from_un: bool,
 }
 
 impl SequenceElement {
@@ -57,6 +61,7 @@ impl SequenceElement {
             vr,
             vl,
             cs,
+            resolved_from_un: false,
         }
     }
 
@@ -92,6 +97,14 @@ impl SequenceElement {
         self.cs = cs;
     }
 
+    pub fn is_resolved_from_un(&self) -> bool {
+        self.resolved_from_un
+    }
+
+    pub(crate) fn set_resolved_from_un(&mut self) {
+        self.resolved_from_un = true;
+    }
+
     pub fn increment_item(&mut self) {
         match self.node.item() {
             None => {
@@ -104,6 +117,11 @@ impl SequenceElement {
         }
     }
 
+    /// Sets the item number, which starts at 1.
+    pub fn set_item(&mut self, item: usize) {
+        self.node.item_mut().replace(item);
+    }
+
     pub fn decrement_item(&mut self) {
         match self.node.item() {
             None => {}
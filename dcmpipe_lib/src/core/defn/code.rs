@@ -0,0 +1,114 @@
+//! Coded Concepts and Context Groups
+
+use std::{
+    fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
+};
+
+pub type CodeRef = &'static Code;
+pub type ContextGroupRef = &'static ContextGroup;
+
+/// Coded Concept Definition, as used in the items of Code Sequences.
+#[derive(Debug, Eq)]
+pub struct Code {
+    /// The Code Value, unique within the coding scheme.
+    pub value: &'static str,
+
+    /// The Coding Scheme Designator, such as `DCM`, `SCT`, or `UCUM`.
+    pub scheme: &'static str,
+
+    /// The Code Meaning, a human-readable text for the concept.
+    pub meaning: &'static str,
+}
+
+impl Code {
+    /// Get the code value.
+    pub fn value(&self) -> &'static str {
+        self.value
+    }
+
+    /// Get the coding scheme designator.
+    pub fn scheme(&self) -> &'static str {
+        self.scheme
+    }
+
+    /// Get the code meaning.
+    pub fn meaning(&self) -> &'static str {
+        self.meaning
+    }
+
+    /// Whether this is the code with the given coding scheme designator and code value.
+    pub fn is(&self, scheme: &str, value: &str) -> bool {
+        self.scheme == scheme && self.value == value
+    }
+}
+
+/// Codes are identified by their scheme and value, the meaning is only descriptive.
+impl PartialEq for Code {
+    fn eq(&self, other: &Code) -> bool {
+        self.is(other.scheme, other.value)
+    }
+}
+
+impl Hash for Code {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.scheme.hash(state);
+        self.value.hash(state);
+    }
+}
+
+/// Renders the code as `(value, scheme, "meaning")`, the notation used throughout the standard.
+impl Display for Code {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, \"{}\")", self.value, self.scheme, self.meaning)
+    }
+}
+
+/// Context Group Definition, the set of coded concepts which may be used in a given context,
+/// identified by its Context ID (CID).
+#[derive(Debug, Eq)]
+pub struct ContextGroup {
+    /// The Context ID, e.g. `4` for CID 4.
+    pub cid: u32,
+
+    /// The name of the context group.
+    pub name: &'static str,
+
+    /// The coded concepts of the context group.
+    pub codes: &'static [Code],
+}
+
+impl ContextGroup {
+    /// Get the context ID.
+    pub fn cid(&self) -> u32 {
+        self.cid
+    }
+
+    /// Get the name of the context group.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get the coded concepts of the context group.
+    pub fn codes(&self) -> &'static [Code] {
+        self.codes
+    }
+
+    /// Look up a code in this context group by its coding scheme designator and code value.
+    pub fn get_code(&self, scheme: &str, value: &str) -> Option<CodeRef> {
+        self.codes.iter().find(|code| code.is(scheme, value))
+    }
+
+    /// Look up a code in this context group by its code meaning, ignoring case.
+    pub fn get_code_by_meaning(&self, meaning: &str) -> Option<CodeRef> {
+        self.codes
+            .iter()
+            .find(|code| code.meaning.eq_ignore_ascii_case(meaning))
+    }
+}
+
+impl PartialEq for ContextGroup {
+    fn eq(&self, other: &ContextGroup) -> bool {
+        self.cid.eq(&other.cid)
+    }
+}
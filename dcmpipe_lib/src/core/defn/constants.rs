@@ -24,7 +24,7 @@ pub mod tags {
 pub mod ts {
     #![allow(non_upper_case_globals)]
 
-    use crate::core::defn::ts::TransferSyntax;
+    use crate::core::defn::ts::{Compression, TransferSyntax};
 
     /// Implicit VR Little Endian: Default Transfer Syntax for DICOM
     ///
@@ -35,6 +35,7 @@ pub mod ts {
         big_endian: false,
         deflated: false,
         encapsulated: false,
+        compression: Compression::None,
     };
 
     /// Explicit VR Little Endian
@@ -46,6 +47,7 @@ pub mod ts {
         big_endian: false,
         deflated: false,
         encapsulated: false,
+        compression: Compression::None,
     };
 
     /// Explicit VR Big Endian (Retired)
@@ -57,6 +59,7 @@ pub mod ts {
         big_endian: true,
         deflated: false,
         encapsulated: false,
+        compression: Compression::None,
     };
 
     /// Implicit VR Big Endian (Virtual)
@@ -68,6 +71,7 @@ pub mod ts {
         big_endian: true,
         deflated: false,
         encapsulated: false,
+        compression: Compression::None,
     };
 
     /// Deflated Explicit VR Little Endian
@@ -79,6 +83,7 @@ pub mod ts {
         big_endian: false,
         deflated: true,
         encapsulated: false,
+        compression: Compression::None,
     };
 }
 
@@ -86,7 +91,7 @@ pub mod ts {
 pub mod uids {
     #![allow(non_upper_case_globals)]
 
-    use crate::core::defn::uid::UID;
+    use crate::core::defn::uid::{UIDType, UID};
 
     /// Implicit VR Little Endian: Default Transfer Syntax for DICOM
     ///
@@ -96,6 +101,7 @@ pub mod uids {
         ident: "ImplicitVRLittleEndian",
         uid: "1.2.840.10008.1.2",
         name: "Implicit VR Little Endian: Default Transfer Syntax for DICOM",
+        uid_type: UIDType::TransferSyntax,
     };
 
     /// Explicit VR Little Endian
@@ -106,6 +112,7 @@ pub mod uids {
         ident: "ExplicitVRLittleEndian",
         uid: "1.2.840.10008.1.2.1",
         name: "Explicit VR Little Endian",
+        uid_type: UIDType::TransferSyntax,
     };
 
     /// Deflated Explicit VR Little Endian
@@ -116,6 +123,7 @@ pub mod uids {
         ident: "DeflatedExplicitVRLittleEndian",
         uid: "1.2.840.10008.1.2.1.99",
         name: "Deflated Explicit VR Little Endian",
+        uid_type: UIDType::TransferSyntax,
     };
 
     /// Explicit VR Big Endian (Retired)
@@ -126,6 +134,7 @@ pub mod uids {
         ident: "ExplicitVRBigEndian",
         uid: "1.2.840.10008.1.2.2",
         name: "Explicit VR Big Endian (Retired)",
+        uid_type: UIDType::TransferSyntax,
     };
 
     /// Implicit VR Big Endian (Virtual)
@@ -136,6 +145,7 @@ pub mod uids {
         ident: "ImplicitVRBigEndian",
         uid: "",
         name: "Implicit VR Big Endian (Virtual)",
+        uid_type: UIDType::TransferSyntax,
     };
 }
 
@@ -175,6 +185,10 @@ pub mod lookup {
             None
         }
 
+        fn get_tag_by_keyword(&self, _keyword: &str) -> Option<TagRef> {
+            None
+        }
+
         fn get_tag_by_number(&self, _number: u32) -> Option<TagRef> {
             None
         }
@@ -10,8 +10,16 @@ pub trait DicomDictionary {
 
     /// Look up a `Tag` definition by its name
     fn get_tag_by_name(&self, name: &str) -> Option<TagRef>;
+    /// Look up a `Tag` definition by its keyword as defined by the standard, e.g. `PatientName`
+    fn get_tag_by_keyword(&self, keyword: &str) -> Option<TagRef>;
     /// Look up a `Tag` definition by its tag number
     fn get_tag_by_number(&self, number: u32) -> Option<TagRef>;
+    /// Look up the keyword of a tag by its tag number
+    fn get_keyword_by_number(&self, number: u32) -> Option<&'static str> {
+        self.get_tag_by_number(number)
+            .map(|tag| tag.keyword)
+            .filter(|keyword| !keyword.is_empty())
+    }
 
     /// Look up a `UID` definition by its name
     fn get_uid_by_name(&self, name: &str) -> Option<UIDRef>;
@@ -56,6 +64,13 @@ impl<'dict> DicomDictionary for MultiDicomDictionary<'dict> {
             .nth(0)
     }
 
+    fn get_tag_by_keyword(&self, keyword: &str) -> Option<TagRef> {
+        self.dicts
+            .iter()
+            .flat_map(|d| d.get_tag_by_keyword(keyword))
+            .nth(0)
+    }
+
     fn get_tag_by_number(&self, number: u32) -> Option<TagRef> {
         self.dicts
             .iter()
@@ -1,3 +1,4 @@
+pub mod code;
 pub mod constants;
 pub mod dcmdict;
 pub mod tag;
@@ -1,6 +1,7 @@
 //! DICOM Data Element (Tag)
 
 use std::{
+    collections::BTreeSet,
     fmt::{Debug, Display, Formatter},
     hash::{Hash, Hasher},
 };
@@ -17,6 +18,10 @@ pub struct Tag {
     /// Identifier or name which can be used with a `DicomDictionary`.
     pub ident: &'static str,
 
+    /// The keyword defined for the tag by the standard, such as `PatientName`. This is empty for
+    /// tags which have no keyword.
+    pub keyword: &'static str,
+
     /// The tag number.
     pub tag: u32,
 
@@ -38,6 +43,11 @@ impl Tag {
         self.ident
     }
 
+    /// Get the tag's keyword as defined by the standard.
+    pub fn keyword(&self) -> &'static str {
+        self.keyword
+    }
+
     /// Get the tag's number.
     pub fn tag(&self) -> u32 {
         self.tag
@@ -53,6 +63,11 @@ impl Tag {
         self.vm
     }
 
+    /// Whether the tag has been retired from the DICOM standard.
+    pub fn is_retired(&self) -> bool {
+        self.ident.ends_with("_Retired")
+    }
+
     /// Detects if the given tag is a private creator, which is defined to be an odd-numbered group
     /// number with an element number between 0x0010-0x00FF.
     pub fn is_private_creator<T>(tag: T) -> bool
@@ -114,6 +129,46 @@ impl Tag {
         format!("({:04X},{:04X})", tag_group, tag_elem)
     }
 
+    /// Parses a tag number from the given string. The tag can be resolved by keyword or name if a
+    /// dictionary is supplied, or given in hex with or without parens and comma.
+    ///
+    /// The acceptable formats are:
+    /// ```text
+    /// "PatientName" => 0x0010_0010
+    /// "PatientsName" => 0x0010_0010
+    /// "(0010,0010)" => 0x0010_0010
+    /// "0010,0010" => 0x0010_0010
+    /// "00100010" => 0x0010_0010
+    /// ```
+    pub fn parse_tag(value: &str, dict: Option<&dyn DicomDictionary>) -> Result<u32, ParseError> {
+        let value: &str = value.trim();
+        let lookup: Option<TagRef> = dict.and_then(|d| {
+            d.get_tag_by_keyword(value)
+                .or_else(|| d.get_tag_by_name(value))
+        });
+        if let Some(tag) = lookup {
+            return Ok(tag.tag);
+        }
+
+        let is_hex = |part: &str| part.len() == 4 && part.chars().all(|c| c.is_ascii_hexdigit());
+        let (group, elem) = match value.strip_prefix('(').and_then(|v| v.strip_suffix(')')) {
+            Some(inner) => inner.split_once(',').unwrap_or(("", "")),
+            None => value
+                .split_once(',')
+                .or_else(|| value.is_char_boundary(4).then(|| value.split_at(4)))
+                .unwrap_or(("", "")),
+        };
+        if !is_hex(group) || !is_hex(elem) {
+            return Err(ParseError::InvalidTagPath {
+                string_path: value.to_string(),
+            });
+        }
+        // Both parts were checked to be four hex digits.
+        let group: u32 = u32::from_str_radix(group, 16).unwrap_or_default();
+        let elem: u32 = u32::from_str_radix(elem, 16).unwrap_or_default();
+        Ok((group << 16) | elem)
+    }
+
     /// Renders the tag number as `GGGGEEEE`.
     pub fn format_tag_to_path_display<T>(tag: T) -> String
     where
@@ -177,17 +232,17 @@ impl TagNode {
         &mut self.item
     }
 
-    /// Parses a `TagNode` from the given string. The tag can be resolved by name if a dictionary
-    /// is supplied, or by standard hexformat (parens are optional). For a `TagNode` which is in a
-    /// sequence path, an index can be supplied which must be at the end and contained within
-    /// square brackets. Supplying a dictionary is optional however must be supplied in order to
-    /// resolve tags by name.
+    /// Parses a `TagNode` from the given string. The tag is parsed by `Tag::parse_tag()`, so can be
+    /// resolved by keyword or name if a dictionary is supplied, or by standard hexformat (parens
+    /// and comma are optional). For a `TagNode` which is in a sequence path, an index can be
+    /// supplied which must be at the end and contained within square brackets.
     ///
     /// The acceptable formats are:
     /// ```text
     /// "PatientID" => (0x0010_0020, None)
     /// "(0010,0020)" => (0x0010_0020, None)
     /// "0010,0020" => (0x0010_0020, None)
+    /// "00100020" => (0x0010_0020, None)
     /// "ReferencedFrameOfReferenceSequence[1]" => (0x3006_0010, Some(1))
     /// "(3006,0010)[1]" => (0x3006_0010, Some(1))
     /// ```
@@ -204,23 +259,8 @@ impl TagNode {
             tag_id = name_part;
         }
 
-        let lookup = dict.and_then(|d| d.get_tag_by_name(tag_id));
-        if let Some(tag) = lookup {
-            Ok(TagNode::new(tag.tag, index))
-        } else if let Some((group, tag)) = value.trim_matches(&['(', ')']).split_once(',') {
-            let group = u16::from_str_radix(group, 16).map_err(|e| ParseError::InvalidTagPath {
-                string_path: e.to_string(),
-            })?;
-            let tag = u16::from_str_radix(tag, 16).map_err(|e| ParseError::InvalidTagPath {
-                string_path: e.to_string(),
-            })?;
-            let full_tag: u32 = ((group as u32) << 16) + ((tag as u32) & 0x0000_FFFF);
-            Ok(TagNode::new(full_tag, index))
-        } else {
-            Err(ParseError::InvalidTagPath {
-                string_path: value.to_string(),
-            })
-        }
+        let tag: u32 = Tag::parse_tag(tag_id, dict)?;
+        Ok(TagNode::new(tag, index))
     }
 }
 
@@ -488,3 +528,50 @@ impl From<&Vec<SequenceElement>> for TagPath {
             .into()
     }
 }
+
+/// A `TagSet` is an unordered collection of tag numbers, such as for specifying which elements
+/// some behavior applies to regardless of where they occur within the dataset.
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct TagSet {
+    tags: BTreeSet<u32>,
+}
+
+impl TagSet {
+    /// Creates a tag set with no tags.
+    pub fn new() -> TagSet {
+        TagSet::default()
+    }
+
+    /// Adds the tag to the set, returning whether it was not already present.
+    pub fn insert<T: Into<u32>>(&mut self, tag: T) -> bool {
+        self.tags.insert(tag.into())
+    }
+
+    /// Returns whether the tag is in the set.
+    pub fn contains<T: Into<u32>>(&self, tag: T) -> bool {
+        self.tags.contains(&tag.into())
+    }
+
+    /// Return whether there are any tags in this set.
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    /// The number of tags in this set.
+    pub fn len(&self) -> usize {
+        self.tags.len()
+    }
+
+    /// Iterates over the tags in this set, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        self.tags.iter().copied()
+    }
+}
+
+impl<T: Into<u32>> FromIterator<T> for TagSet {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        TagSet {
+            tags: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -6,6 +6,20 @@ use super::uid::UIDRef;
 
 pub type TSRef = &'static TransferSyntax;
 
+/// How Pixel Data is compressed by a transfer syntax. This is about the Pixel Data only, a
+/// deflated transfer syntax doesn't compress Pixel Data itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Compression {
+    /// Pixel Data is not compressed.
+    None,
+    /// Pixel Data is compressed without loss.
+    Lossless,
+    /// Pixel Data may be compressed with loss. Some of these transfer syntaxes, e.g. JPEG 2000,
+    /// also permit lossless compression, but whether a dataset's Pixel Data is lossy can only be
+    /// told from its Lossy Image Compression attribute.
+    Lossy,
+}
+
 /// Transfer Syntax Definition
 #[derive(Debug, Eq)]
 pub struct TransferSyntax {
@@ -28,6 +42,9 @@ pub struct TransferSyntax {
     /// implicit/explicit big/little endian), including all jpeg - the content of the PixelData
     /// segment is encoded in a different format from the rest of the dicom elements.
     pub encapsulated: bool,
+
+    /// How the Pixel Data is compressed.
+    pub compression: Compression,
 }
 
 impl PartialEq for TransferSyntax {
@@ -49,6 +66,7 @@ impl TransferSyntax {
         big_endian: bool,
         deflated: bool,
         encapsulated: bool,
+        compression: Compression,
     ) -> TransferSyntax {
         TransferSyntax {
             uid,
@@ -56,6 +74,7 @@ impl TransferSyntax {
             big_endian,
             deflated,
             encapsulated,
+            compression,
         }
     }
 
@@ -84,6 +103,16 @@ impl TransferSyntax {
         self.encapsulated
     }
 
+    /// Get how this transfer syntax compresses Pixel Data.
+    pub fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    /// Indicates whether this transfer syntax may compress Pixel Data with loss.
+    pub fn is_lossy(&self) -> bool {
+        self.compression == Compression::Lossy
+    }
+
     /// Indicates whether this transfer syntax uses standard uncompressed data encoding.
     pub fn is_uncompressed(&self) -> bool {
         !self.deflated && !self.encapsulated
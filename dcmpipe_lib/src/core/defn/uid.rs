@@ -4,6 +4,45 @@ use std::hash::{Hash, Hasher};
 
 pub type UIDRef = &'static UID;
 
+/// The kind of thing a UID identifies, from the UID Type column of Part 6, Table A-1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UIDType {
+    SOPClass(SOPClassCategory),
+    MetaSOPClass,
+    TransferSyntax,
+    WellKnownSOPInstance,
+    ServiceClass,
+    ApplicationContextName,
+    ApplicationHostingModel,
+    CodingScheme,
+    MappingResource,
+    SynchronizationFrameOfReference,
+    LDAPOID,
+    Other,
+}
+
+/// A broad grouping of SOP Classes by the kind of object or service they are for. The standard
+/// doesn't categorize SOP Classes itself, so the category is derived from their names.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SOPClassCategory {
+    /// Storage of images, including multi-frame, volume, and parametric images.
+    ImageStorage,
+    /// Storage of Structured Reports, including Key Object Selection documents.
+    StructuredReportStorage,
+    /// Storage of Presentation States, which describe how referenced images are displayed.
+    PresentationStateStorage,
+    /// Storage of waveforms such as ECGs and audio.
+    WaveformStorage,
+    /// Storage of documents encapsulated in a dataset, such as PDFs.
+    EncapsulatedDocumentStorage,
+    /// Storage of any other kind of composite object, e.g. Raw Data or RT Plans.
+    OtherStorage,
+    /// Query or retrieve of an information model, with C-FIND, C-MOVE, or C-GET.
+    QueryRetrieve,
+    /// Any other service, e.g. Verification, Storage Commitment, worklists, or printing.
+    Service,
+}
+
 /// Unique Identifier Definition
 #[derive(Debug, Eq)]
 pub struct UID {
@@ -15,11 +54,24 @@ pub struct UID {
 
     /// A longer name or description of the UID.
     pub name: &'static str,
+
+    /// What the UID identifies.
+    pub uid_type: UIDType,
 }
 
 impl UID {
-    pub fn new(uid: &'static str, ident: &'static str, name: &'static str) -> UID {
-        UID { ident, uid, name }
+    pub fn new(
+        uid: &'static str,
+        ident: &'static str,
+        name: &'static str,
+        uid_type: UIDType,
+    ) -> UID {
+        UID {
+            ident,
+            uid,
+            name,
+            uid_type,
+        }
     }
 
     /// Get the identifier or name for this UID.
@@ -36,6 +88,73 @@ impl UID {
     pub fn name(&self) -> &'static str {
         self.name
     }
+
+    /// Get what this UID identifies.
+    pub fn uid_type(&self) -> UIDType {
+        self.uid_type
+    }
+
+    /// Whether the UID has been retired from the DICOM standard.
+    pub fn is_retired(&self) -> bool {
+        self.name.contains("(Retired)")
+    }
+
+    /// Get the category of this UID if it's a SOP Class.
+    pub fn sop_class_category(&self) -> Option<SOPClassCategory> {
+        match self.uid_type {
+            UIDType::SOPClass(category) => Some(category),
+            _ => None,
+        }
+    }
+
+    /// Whether this UID is a SOP Class for storing composite objects.
+    pub fn is_storage_sop_class(&self) -> bool {
+        self.sop_class_category()
+            .is_some_and(SOPClassCategory::is_storage)
+    }
+}
+
+impl SOPClassCategory {
+    /// All categories, in the order they're declared.
+    pub const ALL: [SOPClassCategory; 8] = [
+        SOPClassCategory::ImageStorage,
+        SOPClassCategory::StructuredReportStorage,
+        SOPClassCategory::PresentationStateStorage,
+        SOPClassCategory::WaveformStorage,
+        SOPClassCategory::EncapsulatedDocumentStorage,
+        SOPClassCategory::OtherStorage,
+        SOPClassCategory::QueryRetrieve,
+        SOPClassCategory::Service,
+    ];
+
+    /// Whether SOP Classes of this category are for storing composite objects, with C-STORE.
+    pub fn is_storage(self) -> bool {
+        !matches!(
+            self,
+            SOPClassCategory::QueryRetrieve | SOPClassCategory::Service
+        )
+    }
+
+    /// A short lowercase name of the category, e.g. `image` or `sr`.
+    pub fn name(self) -> &'static str {
+        match self {
+            SOPClassCategory::ImageStorage => "image",
+            SOPClassCategory::StructuredReportStorage => "sr",
+            SOPClassCategory::PresentationStateStorage => "presentation-state",
+            SOPClassCategory::WaveformStorage => "waveform",
+            SOPClassCategory::EncapsulatedDocumentStorage => "document",
+            SOPClassCategory::OtherStorage => "other-storage",
+            SOPClassCategory::QueryRetrieve => "query-retrieve",
+            SOPClassCategory::Service => "service",
+        }
+    }
+
+    /// Gets the category with the given name, see `name()`, ignoring case.
+    pub fn from_name(name: &str) -> Option<SOPClassCategory> {
+        SOPClassCategory::ALL
+            .into_iter()
+            .find(|category| category.name().eq_ignore_ascii_case(name))
+    }
 }
 
 impl PartialEq for UID {
@@ -1,5 +1,7 @@
 //! Value Multiplicity
 
+use std::fmt::{Display, Formatter};
+
 pub type VMRef = &'static VM;
 
 /// Value Multiplicity Definition
@@ -24,3 +26,16 @@ pub enum VM {
     /// Single or multiple: 1 or 1-n
     OneOrMore,
 }
+
+impl Display for VM {
+    /// Formats the value multiplicity as it appears in the DICOM standard, e.g. "1-n".
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VM::Distinct(n) => write!(f, "{n}"),
+            VM::AtLeast(n) => write!(f, "{n}-n"),
+            VM::AtMost(n) => write!(f, "1-{n}"),
+            VM::MultipleOf(n) => write!(f, "{n}-{n}n"),
+            VM::OneOrMore => write!(f, "1 or 1-n"),
+        }
+    }
+}
@@ -0,0 +1,277 @@
+//! Batch modification of datasets by a template of attribute changes, applied alike to each
+//! instance of a study or series.
+//!
+//! A template sets or removes attributes by `TagPath`, and may reassign UIDs, e.g. giving a copy
+//! of a study a new `StudyInstanceUID`. Referential integrity is kept by replacing every reference
+//! to a reassigned UID, wherever it occurs within the datasets, with its new UID. As datasets
+//! reference the UIDs of others, the new UIDs for all datasets are assigned with
+//! `EditTemplate::assign_uids()` before any are edited with `EditTemplate::apply()`.
+//!
+//! ```
+//! use dcmpipe_lib::core::{
+//!     defn::{tag::TagPath, vr},
+//!     edit::{EditTemplate, UidMap},
+//!     RawValue,
+//! };
+//! use dcmpipe_lib::dict::tags;
+//!
+//! let template: EditTemplate = EditTemplate::default()
+//!     .set(
+//!         TagPath::from(&tags::StudyDescription),
+//!         &vr::LO,
+//!         RawValue::Strings(vec!["Research copy".to_owned()]),
+//!     )
+//!     .remove(TagPath::from(&tags::InstitutionName))
+//!     .reassign_uid(tags::StudyInstanceUID.tag);
+//! let mut uids: UidMap = UidMap::default();
+//! // For each dataset: template.assign_uids(&dcmroot, &mut uids, generate_uid)?;
+//! // Then for each dataset: template.apply(&mut dcmroot, &uids, "edit")?;
+//! ```
+
+use std::collections::{hash_map, HashMap};
+
+use crate::core::{
+    dcmelement::DicomElement,
+    dcmobject::{DicomObject, DicomRoot},
+    dcmsqelem::SequenceElement,
+    defn::{
+        constants::tags,
+        tag::TagPath,
+        vl::ValueLength,
+        vr::{self, VRRef},
+    },
+    read::ParseResult,
+    values::RawValue,
+};
+
+/// A change made to an attribute by an `EditTemplate`.
+#[derive(Clone, Debug)]
+pub enum Change {
+    /// Sets the value of the attribute, adding it if not present.
+    Set { vr: VRRef, value: RawValue },
+    /// Removes the attribute, along with the contents of a sequence.
+    Remove,
+}
+
+/// A set of attribute changes applied alike to datasets, see the module documentation.
+#[derive(Clone, Debug, Default)]
+pub struct EditTemplate {
+    changes: Vec<(TagPath, Change)>,
+    reassigned: Vec<u32>,
+}
+
+/// The new UIDs assigned in place of reassigned UIDs, by the reassigned UID.
+#[derive(Clone, Debug, Default)]
+pub struct UidMap {
+    uids: HashMap<String, String>,
+}
+
+impl UidMap {
+    /// The new UID assigned in place of the given UID, if it was reassigned.
+    pub fn get(&self, uid: &str) -> Option<&str> {
+        self.uids.get(uid).map(String::as_str)
+    }
+
+    /// Records `new` as the UID assigned in place of `old`, returning the UID previously assigned.
+    pub fn insert(&mut self, old: String, new: String) -> Option<String> {
+        self.uids.insert(old, new)
+    }
+
+    pub fn len(&self) -> usize {
+        self.uids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.uids.is_empty()
+    }
+
+    /// Iterates over pairs of reassigned UIDs and their new UID.
+    pub fn iter(&self) -> hash_map::Iter<'_, String, String> {
+        self.uids.iter()
+    }
+}
+
+impl EditTemplate {
+    /// Sets the value of the attribute at the path. Attributes within an item are only set in
+    /// datasets which have the item.
+    pub fn set(mut self, tagpath: TagPath, vr: VRRef, value: RawValue) -> Self {
+        self.changes.push((tagpath, Change::Set { vr, value }));
+        self
+    }
+
+    /// Removes the attribute at the path, if present.
+    pub fn remove(mut self, tagpath: TagPath) -> Self {
+        self.changes.push((tagpath, Change::Remove));
+        self
+    }
+
+    /// Reassigns the UID which is the value of the top-level attribute, e.g. `SOPInstanceUID`,
+    /// updating all references to it.
+    pub fn reassign_uid(mut self, tag: u32) -> Self {
+        self.reassigned.push(tag);
+        self
+    }
+
+    pub fn changes(&self) -> &[(TagPath, Change)] {
+        &self.changes
+    }
+
+    /// The tags of the attributes whose UIDs are reassigned.
+    pub fn reassigned_uids(&self) -> &[u32] {
+        &self.reassigned
+    }
+
+    /// Assigns new UIDs, from `generate`, to the dataset's values of the reassigned attributes,
+    /// other than those already assigned a new UID, e.g. the `StudyInstanceUID` shared with
+    /// another instance of the study. Returns the number of UIDs newly assigned.
+    pub fn assign_uids<F>(
+        &self,
+        dcmroot: &DicomRoot<'_>,
+        uids: &mut UidMap,
+        mut generate: F,
+    ) -> ParseResult<usize>
+    where
+        F: FnMut() -> String,
+    {
+        let mut assigned: usize = 0;
+        for tag in &self.reassigned {
+            let Some(obj) = dcmroot.get_child_by_tag(*tag) else {
+                continue;
+            };
+            for uid in uid_values(obj.element())? {
+                if !uid.is_empty() && uids.get(&uid).is_none() {
+                    uids.insert(uid, generate());
+                    assigned += 1;
+                }
+            }
+        }
+        Ok(assigned)
+    }
+
+    /// Edits the dataset, first replacing all UIDs which were reassigned and then making the
+    /// template's changes, in order. If provenance is being tracked, modified elements are marked
+    /// as such by the given pipeline stage. Value lengths are updated, see
+    /// `DicomRoot::update_value_lengths()`.
+    ///
+    /// Returns the number of elements set, removed, or whose UIDs were replaced.
+    pub fn apply(
+        &self,
+        dcmroot: &mut DicomRoot<'_>,
+        uids: &UidMap,
+        stage: &str,
+    ) -> ParseResult<usize> {
+        let mut edited: usize = replace_uids(dcmroot, uids, stage)?;
+        for (tagpath, change) in &self.changes {
+            let changed: bool = match change {
+                Change::Set { vr, value } => set_value(dcmroot, tagpath, vr, value.clone(), stage)?,
+                Change::Remove => dcmroot.remove_element(tagpath).is_some(),
+            };
+            if changed {
+                edited += 1;
+            }
+        }
+        dcmroot.update_value_lengths();
+        Ok(edited)
+    }
+}
+
+/// Replaces the reassigned UIDs within all UID values of the dataset, including those of the File
+/// Meta group and within sequences. Returns the number of elements modified.
+fn replace_uids(dcmroot: &mut DicomRoot<'_>, uids: &UidMap, stage: &str) -> ParseResult<usize> {
+    if uids.is_empty() {
+        return Ok(0);
+    }
+
+    let mut replacements: Vec<(TagPath, Vec<String>)> = Vec::new();
+    for (tagpath, element) in dcmroot.iter_elements() {
+        if element.vr() != &vr::UI || element.is_empty() {
+            continue;
+        }
+        let values: Vec<String> = uid_values(element)?;
+        let replaced: Vec<String> = values
+            .iter()
+            .map(|uid| uids.get(uid).unwrap_or(uid).to_owned())
+            .collect();
+        if replaced != values {
+            replacements.push((tagpath, replaced));
+        }
+    }
+
+    let replaced: usize = replacements.len();
+    for (tagpath, values) in replacements {
+        dcmroot
+            .modify_element(&tagpath, stage, |element| {
+                element.encode_value(RawValue::Strings(values), None)
+            })
+            .transpose()?;
+    }
+    Ok(replaced)
+}
+
+/// Sets the value of the element at the path. An existing element of the same VR is modified in
+/// place, otherwise a new element is inserted in the dataset's encoding. Returns `false` if the
+/// item the element is within doesn't exist.
+fn set_value(
+    dcmroot: &mut DicomRoot<'_>,
+    tagpath: &TagPath,
+    vr: VRRef,
+    value: RawValue,
+    stage: &str,
+) -> ParseResult<bool> {
+    let existing: Option<&DicomObject<'_>> = dcmroot.get_child_by_tagpath(tagpath);
+    if existing.is_some_and(|obj| obj.element().vr() == vr) {
+        dcmroot
+            .modify_element(tagpath, stage, |element| element.encode_value(value, None))
+            .transpose()?;
+        return Ok(true);
+    }
+
+    let Some((last, parent_nodes)) = tagpath.nodes.split_last() else {
+        return Ok(false);
+    };
+    let sq_path: Vec<SequenceElement> = match existing {
+        Some(obj) => obj.element().sequence_path().clone(),
+        None if parent_nodes.is_empty() => Vec::new(),
+        None => {
+            let parent_path: TagPath = parent_nodes.to_vec().into();
+            let Some(item) = dcmroot.get_child_by_tagpath(&parent_path) else {
+                return Ok(false);
+            };
+            item_sq_path(item.element())
+        }
+    };
+    let mut element: DicomElement<'static> = DicomElement::new(
+        last.tag(),
+        vr,
+        ValueLength::Explicit(0),
+        dcmroot.ts(),
+        vr.get_proper_cs(dcmroot.cs()),
+        Vec::new(),
+        sq_path,
+    );
+    element.encode_value(value, None)?;
+    dcmroot.insert_element(tagpath, element, stage)?;
+    Ok(true)
+}
+
+/// The sequence path of elements within the item.
+fn item_sq_path(item_elem: &DicomElement<'_>) -> Vec<SequenceElement> {
+    let mut sq_path: Vec<SequenceElement> = item_elem.sequence_path().clone();
+    sq_path.push(SequenceElement::new(
+        tags::ITEM,
+        None,
+        item_elem.vr(),
+        item_elem.vl(),
+        item_elem.cs(),
+    ));
+    sq_path
+}
+
+/// The UIDs of the element's value, split apart if it has multiple values.
+fn uid_values(element: &DicomElement<'_>) -> ParseResult<Vec<String>> {
+    Ok(match element.parse_value()? {
+        RawValue::Uid(uid) => uid.split('\\').map(str::to_owned).collect(),
+        RawValue::Strings(strings) => strings,
+        _ => Vec::new(),
+    })
+}
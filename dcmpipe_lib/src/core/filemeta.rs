@@ -0,0 +1,343 @@
+//! Validation and repair of the File Meta group, see `DicomRoot::check_file_meta()` and
+//! `DicomRoot::repair_file_meta()`.
+//!
+//! Part 10, Ch 7.1: the File Meta group is always encoded in Explicit VR Little Endian, starts with
+//! a `FileMetaInformationGroupLength` giving the length of the rest of the group, and identifies
+//! the SOP Class and Instance of the dataset along with the transfer syntax the rest of the dataset
+//! is encoded in. Files produced by homegrown tools often omit some of these elements, or encode
+//! the group in the transfer syntax of the dataset.
+
+use std::fmt;
+
+use crate::core::{
+    dcmelement::DicomElement,
+    dcmobject::{DicomObject, DicomRoot},
+    defn::{
+        constants::{tags, ts},
+        tag::{Tag, TagNode, TagPath},
+        ts::TSRef,
+        vl::ValueLength,
+        vr,
+    },
+    values::RawValue,
+    write::{transcode::swap_bytes, writer::WriteResult},
+};
+
+const FILE_META_INFORMATION_VERSION: u32 = 0x0002_0001;
+const MEDIA_STORAGE_SOP_CLASS_UID: u32 = 0x0002_0002;
+const MEDIA_STORAGE_SOP_INSTANCE_UID: u32 = 0x0002_0003;
+const SOP_CLASS_UID: u32 = 0x0008_0016;
+
+/// The value of `FileMetaInformationVersion` for this version of the standard.
+const FILE_META_VERSION: [u8; 2] = [0x00, 0x01];
+
+/// A problem with the File Meta group of a dataset, see `DicomRoot::check_file_meta()`.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FileMetaIssue {
+    /// A required element of the group is missing or empty. This is one of
+    /// `FileMetaInformationGroupLength`, `FileMetaInformationVersion`,
+    /// `MediaStorageSOPClassUID`, `MediaStorageSOPInstanceUID`, or `TransferSyntaxUID`.
+    Missing { tag: u32 },
+
+    /// `MediaStorageSOPClassUID` or `MediaStorageSOPInstanceUID` differs from the `SOPClassUID` or
+    /// `SOPInstanceUID` of the dataset.
+    Mismatched {
+        tag: u32,
+        file_meta: String,
+        dataset: String,
+    },
+
+    /// `TransferSyntaxUID` differs from the transfer syntax the dataset is encoded in.
+    WrongTransferSyntax { declared: String, actual: String },
+
+    /// `FileMetaInformationGroupLength` differs from the length of the rest of the group, as
+    /// encoded in Explicit VR Little Endian.
+    WrongGroupLength { declared: u32, actual: u32 },
+
+    /// Elements of the group are encoded in a transfer syntax other than Explicit VR Little Endian.
+    NotExplicitVRLittleEndian { ts: String },
+}
+
+impl fmt::Display for FileMetaIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FileMetaIssue::Missing { tag } => {
+                write!(f, "{} is missing", Tag::format_tag_to_display(*tag))
+            }
+            FileMetaIssue::Mismatched {
+                tag,
+                file_meta,
+                dataset,
+            } => write!(
+                f,
+                "{} is {} but the dataset's is {}",
+                Tag::format_tag_to_display(*tag),
+                file_meta,
+                dataset
+            ),
+            FileMetaIssue::WrongTransferSyntax { declared, actual } => write!(
+                f,
+                "transfer syntax is declared as {} but the dataset is encoded in {}",
+                declared, actual
+            ),
+            FileMetaIssue::WrongGroupLength { declared, actual } => write!(
+                f,
+                "group length is {} but the group is {} bytes",
+                declared, actual
+            ),
+            FileMetaIssue::NotExplicitVRLittleEndian { ts } => {
+                write!(
+                    f,
+                    "group is encoded in {} rather than explicit VR little endian",
+                    ts
+                )
+            }
+        }
+    }
+}
+
+/// Checks the File Meta group of the dataset, as it would be written.
+pub(crate) fn check(dcmroot: &DicomRoot<'_>) -> WriteResult<Vec<FileMetaIssue>> {
+    let mut issues: Vec<FileMetaIssue> = Vec::new();
+    if let Some(ts) = file_meta_elements(dcmroot)
+        .map(|(_tag, obj)| obj.element().ts())
+        .find(|ts| *ts != &ts::ExplicitVRLittleEndian)
+    {
+        issues.push(FileMetaIssue::NotExplicitVRLittleEndian {
+            ts: ts.uid().name().to_owned(),
+        });
+    }
+
+    if dcmroot
+        .get_child_by_tag(FILE_META_INFORMATION_VERSION)
+        .is_none()
+    {
+        issues.push(FileMetaIssue::Missing {
+            tag: FILE_META_INFORMATION_VERSION,
+        });
+    }
+    for (tag, dataset_tag) in [
+        (MEDIA_STORAGE_SOP_CLASS_UID, SOP_CLASS_UID),
+        (MEDIA_STORAGE_SOP_INSTANCE_UID, tags::SOP_INSTANCE_UID),
+    ] {
+        match (uid(dcmroot, tag)?, uid(dcmroot, dataset_tag)?) {
+            (None, _) => issues.push(FileMetaIssue::Missing { tag }),
+            (Some(file_meta), Some(dataset)) if file_meta != dataset => {
+                issues.push(FileMetaIssue::Mismatched {
+                    tag,
+                    file_meta,
+                    dataset,
+                });
+            }
+            _ => {}
+        }
+    }
+    match uid(dcmroot, tags::TRANSFER_SYNTAX_UID)? {
+        None => issues.push(FileMetaIssue::Missing {
+            tag: tags::TRANSFER_SYNTAX_UID,
+        }),
+        Some(declared) if declared != dcmroot.ts().uid().uid() => {
+            issues.push(FileMetaIssue::WrongTransferSyntax {
+                declared,
+                actual: dcmroot.ts().uid().uid().to_owned(),
+            });
+        }
+        _ => {}
+    }
+
+    match group_length(dcmroot)? {
+        None => issues.push(FileMetaIssue::Missing {
+            tag: tags::FILE_META_INFORMATION_GROUP_LENGTH,
+        }),
+        Some(declared) => {
+            let actual: u32 = file_meta_len(dcmroot);
+            if declared != actual {
+                issues.push(FileMetaIssue::WrongGroupLength { declared, actual });
+            }
+        }
+    }
+    Ok(issues)
+}
+
+/// Repairs the File Meta group of the dataset, returning the issues which were repaired.
+pub(crate) fn repair(dcmroot: &mut DicomRoot<'_>, stage: &str) -> WriteResult<Vec<FileMetaIssue>> {
+    let issues: Vec<FileMetaIssue> = check(dcmroot)?;
+    let mut repaired: Vec<FileMetaIssue> = Vec::new();
+    for issue in issues {
+        let is_repaired: bool = match &issue {
+            FileMetaIssue::NotExplicitVRLittleEndian { .. } => {
+                reencode_file_meta(dcmroot, stage);
+                true
+            }
+            FileMetaIssue::Missing {
+                tag: FILE_META_INFORMATION_VERSION,
+            } => {
+                let mut element: DicomElement<'static> = DicomElement::new_empty(
+                    FILE_META_INFORMATION_VERSION,
+                    &vr::OB,
+                    &ts::ExplicitVRLittleEndian,
+                );
+                element.encode_value(RawValue::Bytes(FILE_META_VERSION.to_vec()), None)?;
+                insert(dcmroot, element, stage)?;
+                true
+            }
+            FileMetaIssue::Missing {
+                tag: tag @ (MEDIA_STORAGE_SOP_CLASS_UID | MEDIA_STORAGE_SOP_INSTANCE_UID),
+            } => {
+                let dataset_tag: u32 = if *tag == MEDIA_STORAGE_SOP_CLASS_UID {
+                    SOP_CLASS_UID
+                } else {
+                    tags::SOP_INSTANCE_UID
+                };
+                match uid(dcmroot, dataset_tag)? {
+                    Some(dataset) => {
+                        insert_uid(dcmroot, *tag, dataset, stage)?;
+                        true
+                    }
+                    None => false,
+                }
+            }
+            FileMetaIssue::Mismatched { tag, dataset, .. } => {
+                insert_uid(dcmroot, *tag, dataset.clone(), stage)?;
+                true
+            }
+            FileMetaIssue::Missing {
+                tag: tags::TRANSFER_SYNTAX_UID,
+            }
+            | FileMetaIssue::WrongTransferSyntax { .. } => {
+                let ts_uid: String = dcmroot.ts().uid().uid().to_owned();
+                insert_uid(dcmroot, tags::TRANSFER_SYNTAX_UID, ts_uid, stage)?;
+                true
+            }
+            // The group length is recomputed after the rest of the group is repaired.
+            FileMetaIssue::Missing {
+                tag: tags::FILE_META_INFORMATION_GROUP_LENGTH,
+            }
+            | FileMetaIssue::WrongGroupLength { .. } => true,
+            FileMetaIssue::Missing { .. } => false,
+        };
+        if is_repaired {
+            repaired.push(issue);
+        }
+    }
+
+    let actual: u32 = file_meta_len(dcmroot);
+    if group_length(dcmroot)? != Some(actual) {
+        let mut element: DicomElement<'static> = DicomElement::new_empty(
+            tags::FILE_META_INFORMATION_GROUP_LENGTH,
+            &vr::UL,
+            &ts::ExplicitVRLittleEndian,
+        );
+        element.encode_value(RawValue::UnsignedIntegers(vec![actual]), None)?;
+        insert(dcmroot, element, stage)?;
+    }
+    Ok(repaired)
+}
+
+/// The elements of the File Meta group, other than `FileMetaInformationGroupLength`.
+fn file_meta_elements<'r, 'a>(
+    dcmroot: &'r DicomRoot<'a>,
+) -> impl Iterator<Item = (&'r u32, &'r DicomObject<'a>)> {
+    dcmroot
+        .iter_child_nodes()
+        .take_while(|(tag, _obj)| **tag <= tags::FILE_META_GROUP_END)
+        .filter(|(tag, _obj)| **tag != tags::FILE_META_INFORMATION_GROUP_LENGTH)
+}
+
+/// The length of the File Meta group following `FileMetaInformationGroupLength`, when encoded in
+/// Explicit VR Little Endian.
+fn file_meta_len(dcmroot: &DicomRoot<'_>) -> u32 {
+    file_meta_elements(dcmroot)
+        .map(|(_tag, obj)| {
+            let value_len: u64 = obj.element().value_len() as u64;
+            vr_header_len(obj.element()) + value_len + (value_len & 1)
+        })
+        .sum::<u64>() as u32
+}
+
+/// The length of the element's header when encoded in Explicit VR Little Endian.
+fn vr_header_len(element: &DicomElement<'_>) -> u64 {
+    if element.vr().has_explicit_2byte_pad {
+        12
+    } else {
+        8
+    }
+}
+
+/// The value of `FileMetaInformationGroupLength`, if present.
+fn group_length(dcmroot: &DicomRoot<'_>) -> WriteResult<Option<u32>> {
+    let Some(obj) = dcmroot.get_child_by_tag(tags::FILE_META_INFORMATION_GROUP_LENGTH) else {
+        return Ok(None);
+    };
+    match obj.element().parse_value()? {
+        RawValue::UnsignedIntegers(values) => Ok(values.first().copied()),
+        _ => Ok(None),
+    }
+}
+
+/// The UID value of the top-level element, if present and not empty.
+fn uid(dcmroot: &DicomRoot<'_>, tag: u32) -> WriteResult<Option<String>> {
+    let Some(obj) = dcmroot.get_child_by_tag(tag) else {
+        return Ok(None);
+    };
+    let uid: Option<String> = match obj.element().parse_value()? {
+        RawValue::Uid(uid) => Some(uid),
+        RawValue::Strings(values) => values.into_iter().next(),
+        _ => None,
+    };
+    Ok(uid
+        .map(|uid| uid.trim_end_matches(['\0', ' ']).to_owned())
+        .filter(|uid| !uid.is_empty()))
+}
+
+fn insert_uid(dcmroot: &mut DicomRoot<'_>, tag: u32, uid: String, stage: &str) -> WriteResult<()> {
+    let mut element: DicomElement<'static> =
+        DicomElement::new_empty(tag, &vr::UI, &ts::ExplicitVRLittleEndian);
+    element.encode_value(RawValue::Uid(uid), None)?;
+    insert(dcmroot, element, stage)
+}
+
+fn insert(
+    dcmroot: &mut DicomRoot<'_>,
+    element: DicomElement<'static>,
+    stage: &str,
+) -> WriteResult<()> {
+    let tagpath: TagPath = TagPath::from(vec![TagNode::from(element.tag())]);
+    dcmroot.insert_element(&tagpath, element, stage)?;
+    Ok(())
+}
+
+/// Re-encodes the elements of the File Meta group into Explicit VR Little Endian. The VR of
+/// elements parsed from implicit VR is the one looked up from the dictionary while parsing.
+fn reencode_file_meta(dcmroot: &mut DicomRoot<'_>, stage: &str) {
+    let tags: Vec<u32> = dcmroot
+        .iter_child_nodes()
+        .take_while(|(tag, _obj)| **tag <= tags::FILE_META_GROUP_END)
+        .filter(|(_tag, obj)| obj.element().ts() != &ts::ExplicitVRLittleEndian)
+        .map(|(tag, _obj)| *tag)
+        .collect();
+    for tag in tags {
+        let tagpath: TagPath = TagPath::from(vec![TagNode::from(tag)]);
+        dcmroot.modify_element(&tagpath, stage, |element| {
+            *element = reencoded(element);
+        });
+    }
+}
+
+fn reencoded<'a>(element: &DicomElement<'a>) -> DicomElement<'a> {
+    let to: TSRef = &ts::ExplicitVRLittleEndian;
+    let mut data: Vec<u8> = element.data().to_vec();
+    if element.ts().big_endian() {
+        swap_bytes(&mut data, element.vr());
+    }
+    DicomElement::new(
+        element.tag(),
+        element.vr(),
+        ValueLength::Explicit(data.len() as u32),
+        to,
+        element.cs(),
+        data,
+        element.sequence_path().clone(),
+    )
+}
@@ -0,0 +1,196 @@
+//! Content hashes of datasets, for identifying duplicates and auditing integrity.
+//!
+//! A hash covers either the entire dataset, its header (everything but Pixel Data), or only its
+//! Pixel Data. By default the elements are hashed as they would be written in the dataset's own
+//! transfer syntax. Normalizing instead hashes only the tags and values of elements, little endian,
+//! excluding the File Meta group and group lengths, so that a dataset has the same hash regardless
+//! of transfer syntax or whether its sequences have explicit or undefined length.
+
+use std::fmt;
+
+use sha2::{Digest, Sha256};
+
+use crate::core::{
+    dcmelement::DicomElement,
+    dcmobject::DicomRoot,
+    defn::{
+        constants::{tags, ts},
+        ts::TSRef,
+    },
+    write::{
+        builder::WriterBuilder,
+        error::WriteError,
+        transcode::transcode,
+        writer::{WriteResult, Writer, WriterState},
+    },
+};
+
+/// The elements of a dataset included in its hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashScope {
+    /// All elements, including the File Meta group unless normalizing.
+    Full,
+    /// All elements other than Pixel Data.
+    Header,
+    /// Only the value of the Pixel Data, including all fragments of encapsulated Pixel Data.
+    PixelData,
+}
+
+/// A SHA-256 hash of a dataset's content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ContentHash([u8; 32]);
+
+impl ContentHash {
+    pub fn bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl fmt::Display for ContentHash {
+    /// Formats the hash as lowercase hex.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Computes content hashes of datasets.
+#[derive(Debug, Clone)]
+pub struct DatasetHasher {
+    scope: HashScope,
+    normalize: bool,
+}
+
+impl DatasetHasher {
+    pub fn new(scope: HashScope) -> DatasetHasher {
+        DatasetHasher {
+            scope,
+            normalize: false,
+        }
+    }
+
+    /// Whether to normalize the encoding of the dataset prior to hashing, see the module
+    /// documentation.
+    pub fn normalize(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    pub fn scope(&self) -> HashScope {
+        self.scope
+    }
+
+    pub fn is_normalized(&self) -> bool {
+        self.normalize
+    }
+
+    /// Computes the hash of the dataset. Values deferred while parsing must have been resolved.
+    pub fn hash(&self, dcmroot: &DicomRoot<'_>) -> WriteResult<ContentHash> {
+        let mut hasher: Sha256 = Sha256::new();
+        if self.normalize {
+            self.hash_normalized(dcmroot, &mut hasher)?;
+        } else if self.scope == HashScope::PixelData {
+            // Pixel Data values are hashed without their headers, so the hash is unaffected by
+            // whether encapsulated fragments are in an explicit or implicit VR.
+            for element in dcmroot.flatten()? {
+                if self.includes(element) {
+                    hasher.update(value_bytes(element)?);
+                }
+            }
+        } else {
+            self.hash_encoded(dcmroot, &mut hasher)?;
+        }
+        Ok(ContentHash(hasher.finalize().into()))
+    }
+
+    /// Hashes the elements as written in the dataset's own transfer syntax.
+    fn hash_encoded(&self, dcmroot: &DicomRoot<'_>, hasher: &mut Sha256) -> WriteResult<()> {
+        let elements: Vec<&DicomElement> = dcmroot
+            .flatten()?
+            .into_iter()
+            .filter(|element| self.includes(element))
+            .collect();
+        let has_file_meta: bool = elements
+            .first()
+            .is_some_and(|element| element.tag() <= tags::FILE_META_GROUP_END);
+        // The hash is of the uncompressed encoding, the `Writer` deflates based on its own transfer
+        // syntax rather than that of each element.
+        let writer_ts: TSRef = if dcmroot.ts().deflated() {
+            &ts::ExplicitVRLittleEndian
+        } else {
+            dcmroot.ts()
+        };
+        let mut writer: Writer<&mut Sha256> = WriterBuilder::default()
+            .state(if has_file_meta {
+                WriterState::FileMeta
+            } else {
+                WriterState::Element
+            })
+            .ts(writer_ts)
+            .build(hasher);
+        writer.write_elements(elements.into_iter())?;
+        writer.into_dataset()?;
+        Ok(())
+    }
+
+    /// Hashes the tag and value of each element, in little endian.
+    fn hash_normalized(&self, dcmroot: &DicomRoot<'_>, hasher: &mut Sha256) -> WriteResult<()> {
+        let transcoded: Vec<DicomElement>;
+        let elements: Vec<&DicomElement> = if dcmroot.ts().big_endian() {
+            transcoded = transcode(dcmroot, &ts::ExplicitVRLittleEndian)?;
+            transcoded.iter().collect()
+        } else {
+            dcmroot.flatten()?
+        };
+
+        for element in elements {
+            let tag: u32 = element.tag();
+            let is_excluded: bool = tag <= tags::FILE_META_GROUP_END
+                || tag & 0x0000_FFFF == 0
+                || tag == tags::ITEM_DELIMITATION_ITEM
+                || tag == tags::SEQUENCE_DELIMITATION_ITEM;
+            if is_excluded || !self.includes(element) {
+                continue;
+            }
+            let value: &[u8] = value_bytes(element)?;
+            hasher.update(tag.to_le_bytes());
+            hasher.update((value.len() as u32).to_le_bytes());
+            hasher.update(value);
+        }
+        Ok(())
+    }
+
+    /// Whether the element is within the scope of the hash.
+    fn includes(&self, element: &DicomElement) -> bool {
+        let is_pixel_data: bool = is_pixel_data_tag(element.tag())
+            || element
+                .sequence_path()
+                .iter()
+                .any(|sq| is_pixel_data_tag(sq.seq_tag()));
+        match self.scope {
+            HashScope::Full => true,
+            HashScope::Header => !is_pixel_data,
+            HashScope::PixelData => is_pixel_data,
+        }
+    }
+}
+
+fn is_pixel_data_tag(tag: u32) -> bool {
+    tag == tags::PIXEL_DATA || tag == tags::FLOAT_PIXEL_DATA || tag == tags::DOUBLE_PIXEL_DATA
+}
+
+/// The value of the element, which can't be hashed if it was deferred and not resolved, or is a
+/// bulk data reference.
+fn value_bytes<'e>(element: &'e DicomElement) -> WriteResult<&'e [u8]> {
+    if element.is_deferred() {
+        return Err(WriteError::DeferredValue);
+    }
+    if element.bulk_data().is_some() {
+        return Err(WriteError::BulkDataValue {
+            reason: "bulk data can't be hashed",
+        });
+    }
+    Ok(element.data())
+}
@@ -0,0 +1,397 @@
+//! Verification of the references between datasets, e.g. of the instances of a study prior to
+//! importing it into a treatment planning system.
+//!
+//! Datasets are added to a `ReferenceChecker`, which records the instances, series, studies, and
+//! frames of reference they define along with the references within their sequences:
+//!
+//! - `ReferencedSOPInstanceUID` refers to an instance, or to a study within the Referenced Study
+//!   Sequence and RT Referenced Study Sequence. The `ReferencedSOPClassUID` alongside it must match
+//!   the referenced instance's SOP Class.
+//! - `SeriesInstanceUID` within a sequence refers to a series, e.g. the RT Referenced Series of an
+//!   RT Structure Set.
+//! - `FrameOfReferenceUID` within a sequence, and `ReferencedFrameOfReferenceUID`, refer to a
+//!   frame of reference. Instances referenced within an item which has a `FrameOfReferenceUID`,
+//!   e.g. the Contour Image Sequence of an RT Structure Set, must be in that frame of reference.
+//!
+//! Once all datasets are added, `ReferenceChecker::check()` reports the references which don't
+//! resolve to any of the datasets, along with series whose instances are in differing frames of
+//! reference and instances whose SOP Instance UID is shared by multiple datasets.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fmt,
+};
+
+use crate::core::{
+    dcmobject::{DicomObject, DicomRoot},
+    defn::{dcmdict::DicomDictionary, tag::TagPath},
+    read::ParseResult,
+    values::RawValue,
+};
+
+const SOP_CLASS_UID: u32 = 0x0008_0016;
+const SOP_INSTANCE_UID: u32 = 0x0008_0018;
+const REFERENCED_STUDY_SEQUENCE: u32 = 0x0008_1110;
+const REFERENCED_PPS_SEQUENCE: u32 = 0x0008_1111;
+const REFERENCED_PATIENT_SEQUENCE: u32 = 0x0008_1120;
+const REFERENCED_VISIT_SEQUENCE: u32 = 0x0008_1125;
+const REFERENCED_SOP_CLASS_UID: u32 = 0x0008_1150;
+const REFERENCED_SOP_INSTANCE_UID: u32 = 0x0008_1155;
+const STUDY_INSTANCE_UID: u32 = 0x0020_000D;
+const SERIES_INSTANCE_UID: u32 = 0x0020_000E;
+const FRAME_OF_REFERENCE_UID: u32 = 0x0020_0052;
+const RT_REFERENCED_STUDY_SEQUENCE: u32 = 0x3006_0012;
+const REFERENCED_FRAME_OF_REFERENCE_UID: u32 = 0x3006_0024;
+
+/// Sequences whose items reference something other than an instance or study, e.g. a patient or
+/// procedure step, which aren't datasets that can be checked.
+const UNCHECKED_SEQUENCES: [u32; 3] = [
+    REFERENCED_PPS_SEQUENCE,
+    REFERENCED_PATIENT_SEQUENCE,
+    REFERENCED_VISIT_SEQUENCE,
+];
+
+/// What a reference refers to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ReferenceKind {
+    Study,
+    Series,
+    Instance,
+    FrameOfReference,
+}
+
+impl fmt::Display for ReferenceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind: &str = match self {
+            ReferenceKind::Study => "study",
+            ReferenceKind::Series => "series",
+            ReferenceKind::Instance => "instance",
+            ReferenceKind::FrameOfReference => "frame of reference",
+        };
+        f.write_str(kind)
+    }
+}
+
+/// A problem with the references between datasets, see `ReferenceChecker::check()`.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntegrityIssue {
+    /// The reference doesn't resolve to any of the datasets.
+    Dangling {
+        source: String,
+        path: String,
+        kind: ReferenceKind,
+        uid: String,
+    },
+    /// The referenced SOP Class differs from that of the referenced instance.
+    SOPClassMismatch {
+        source: String,
+        path: String,
+        uid: String,
+        referenced: String,
+        actual: String,
+    },
+    /// The referenced instance isn't in the frame of reference of the item referencing it.
+    FrameOfReferenceMismatch {
+        source: String,
+        path: String,
+        uid: String,
+        expected: String,
+        actual: String,
+    },
+    /// The instances of the series are in differing frames of reference.
+    InconsistentFrameOfReference { series: String, frames: Vec<String> },
+    /// Multiple datasets have the same SOP Instance UID.
+    DuplicateInstance { uid: String, sources: Vec<String> },
+}
+
+impl fmt::Display for IntegrityIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntegrityIssue::Dangling {
+                source,
+                path,
+                kind,
+                uid,
+            } => write!(f, "{source}: {path} refers to unknown {kind} {uid}"),
+            IntegrityIssue::SOPClassMismatch {
+                source,
+                path,
+                uid,
+                referenced,
+                actual,
+            } => write!(
+                f,
+                "{source}: {path} refers to {uid} as SOP Class {referenced}, but it is {actual}"
+            ),
+            IntegrityIssue::FrameOfReferenceMismatch {
+                source,
+                path,
+                uid,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{source}: {path} refers to {uid} in frame of reference {expected}, but it is in {actual}"
+            ),
+            IntegrityIssue::InconsistentFrameOfReference { series, frames } => write!(
+                f,
+                "series {series} has instances in frames of reference {}",
+                frames.join(", ")
+            ),
+            IntegrityIssue::DuplicateInstance { uid, sources } => {
+                write!(f, "instance {uid} is in multiple files: {}", sources.join(", "))
+            }
+        }
+    }
+}
+
+/// The identifying UIDs of a dataset.
+#[derive(Debug)]
+struct Instance {
+    source: String,
+    sop_class: Option<String>,
+    series: Option<String>,
+    frame_of_reference: Option<String>,
+}
+
+/// A reference from within a dataset.
+#[derive(Debug)]
+struct Reference {
+    source: String,
+    path: String,
+    kind: ReferenceKind,
+    uid: String,
+    /// The `ReferencedSOPClassUID` of an instance reference.
+    sop_class: Option<String>,
+    /// The frame of reference of the item an instance is referenced within.
+    frame_of_reference: Option<String>,
+}
+
+/// Records datasets and the references within them, see the module documentation.
+#[derive(Debug, Default)]
+pub struct ReferenceChecker {
+    instances: HashMap<String, Instance>,
+    duplicates: BTreeMap<String, Vec<String>>,
+    studies: HashSet<String>,
+    series: HashSet<String>,
+    frames: HashSet<String>,
+    references: Vec<Reference>,
+}
+
+impl ReferenceChecker {
+    /// The number of distinct instances added.
+    pub fn instance_count(&self) -> usize {
+        self.instances.len()
+    }
+
+    /// The number of references recorded from the added datasets.
+    pub fn reference_count(&self) -> usize {
+        self.references.len()
+    }
+
+    /// Records the dataset and its references. The source, e.g. the dataset's path, identifies the
+    /// dataset in reported issues.
+    pub fn add(&mut self, source: &str, dcmroot: &DicomRoot<'_>) -> ParseResult<()> {
+        let obj: &DicomObject<'_> = dcmroot.as_obj();
+        if let Some(study) = uid(obj, STUDY_INSTANCE_UID)? {
+            self.studies.insert(study);
+        }
+        let series: Option<String> = uid(obj, SERIES_INSTANCE_UID)?;
+        if let Some(series) = &series {
+            self.series.insert(series.clone());
+        }
+        let frame_of_reference: Option<String> = uid(obj, FRAME_OF_REFERENCE_UID)?;
+        if let Some(frame) = &frame_of_reference {
+            self.frames.insert(frame.clone());
+        }
+        if let Some(sop_instance) = uid(obj, SOP_INSTANCE_UID)? {
+            let instance = Instance {
+                source: source.to_owned(),
+                sop_class: uid(obj, SOP_CLASS_UID)?,
+                series,
+                frame_of_reference,
+            };
+            if let Some(existing) = self.instances.get(&sop_instance) {
+                self.duplicates
+                    .entry(sop_instance)
+                    .or_insert_with(|| vec![existing.source.clone()])
+                    .push(source.to_owned());
+            } else {
+                self.instances.insert(sop_instance, instance);
+            }
+        }
+
+        let collector = Collector {
+            source,
+            dictionary: dcmroot.dictionary(),
+        };
+        for (_tag, child) in obj.iter_child_nodes() {
+            for item in child.iter_items() {
+                collector.collect(item, child.element().tag(), None, &mut self.references)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the references of all added datasets, returning the issues found. References are
+    /// reported in the order their datasets were added.
+    pub fn check(&self) -> Vec<IntegrityIssue> {
+        let mut issues: Vec<IntegrityIssue> = Vec::new();
+        for reference in &self.references {
+            self.check_reference(reference, &mut issues);
+        }
+
+        let mut series_frames: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+        for instance in self.instances.values() {
+            if let (Some(series), Some(frame)) = (&instance.series, &instance.frame_of_reference) {
+                series_frames.entry(series).or_default().insert(frame);
+            }
+        }
+        for (series, frames) in series_frames {
+            if frames.len() > 1 {
+                issues.push(IntegrityIssue::InconsistentFrameOfReference {
+                    series: series.to_owned(),
+                    frames: frames.into_iter().map(str::to_owned).collect(),
+                });
+            }
+        }
+
+        for (uid, sources) in &self.duplicates {
+            issues.push(IntegrityIssue::DuplicateInstance {
+                uid: uid.clone(),
+                sources: sources.clone(),
+            });
+        }
+        issues
+    }
+
+    fn check_reference(&self, reference: &Reference, issues: &mut Vec<IntegrityIssue>) {
+        let resolved: bool = match reference.kind {
+            ReferenceKind::Study => self.studies.contains(&reference.uid),
+            ReferenceKind::Series => self.series.contains(&reference.uid),
+            ReferenceKind::FrameOfReference => self.frames.contains(&reference.uid),
+            ReferenceKind::Instance => self.instances.contains_key(&reference.uid),
+        };
+        if !resolved {
+            issues.push(IntegrityIssue::Dangling {
+                source: reference.source.clone(),
+                path: reference.path.clone(),
+                kind: reference.kind,
+                uid: reference.uid.clone(),
+            });
+            return;
+        }
+
+        let Some(instance) = self.instances.get(&reference.uid) else {
+            return;
+        };
+        if let (Some(referenced), Some(actual)) = (&reference.sop_class, &instance.sop_class) {
+            if referenced != actual {
+                issues.push(IntegrityIssue::SOPClassMismatch {
+                    source: reference.source.clone(),
+                    path: reference.path.clone(),
+                    uid: reference.uid.clone(),
+                    referenced: referenced.clone(),
+                    actual: actual.clone(),
+                });
+            }
+        }
+        if let (Some(expected), Some(actual)) =
+            (&reference.frame_of_reference, &instance.frame_of_reference)
+        {
+            if expected != actual {
+                issues.push(IntegrityIssue::FrameOfReferenceMismatch {
+                    source: reference.source.clone(),
+                    path: reference.path.clone(),
+                    uid: reference.uid.clone(),
+                    expected: expected.clone(),
+                    actual: actual.clone(),
+                });
+            }
+        }
+    }
+}
+
+/// Collects the references within the items of a dataset.
+struct Collector<'c> {
+    source: &'c str,
+    dictionary: &'c dyn DicomDictionary,
+}
+
+impl Collector<'_> {
+    /// Collects the references within the item of the sequence `seq_tag`. `frame_of_reference` is
+    /// that of an enclosing item, if any.
+    fn collect(
+        &self,
+        item: &DicomObject<'_>,
+        seq_tag: u32,
+        frame_of_reference: Option<&str>,
+        references: &mut Vec<Reference>,
+    ) -> ParseResult<()> {
+        if UNCHECKED_SEQUENCES.contains(&seq_tag) {
+            return Ok(());
+        }
+        let item_frame: Option<String> = uid(item, FRAME_OF_REFERENCE_UID)?;
+        let frame_of_reference: Option<&str> = item_frame.as_deref().or(frame_of_reference);
+
+        for (tag, child) in item.iter_child_nodes() {
+            let kind: Option<ReferenceKind> = match *tag {
+                REFERENCED_SOP_INSTANCE_UID
+                    if seq_tag == REFERENCED_STUDY_SEQUENCE
+                        || seq_tag == RT_REFERENCED_STUDY_SEQUENCE =>
+                {
+                    Some(ReferenceKind::Study)
+                }
+                REFERENCED_SOP_INSTANCE_UID => Some(ReferenceKind::Instance),
+                SERIES_INSTANCE_UID => Some(ReferenceKind::Series),
+                FRAME_OF_REFERENCE_UID | REFERENCED_FRAME_OF_REFERENCE_UID => {
+                    Some(ReferenceKind::FrameOfReference)
+                }
+                _ => None,
+            };
+            if let Some(kind) = kind {
+                if let Some(value) = uid(item, *tag)? {
+                    let is_instance: bool = kind == ReferenceKind::Instance;
+                    references.push(Reference {
+                        source: self.source.to_owned(),
+                        path: TagPath::format_tagpath_to_display(
+                            &child.element().create_tagpath(),
+                            Some(self.dictionary),
+                        ),
+                        kind,
+                        uid: value,
+                        sop_class: if is_instance {
+                            uid(item, REFERENCED_SOP_CLASS_UID)?
+                        } else {
+                            None
+                        },
+                        frame_of_reference: frame_of_reference
+                            .filter(|_| is_instance)
+                            .map(str::to_owned),
+                    });
+                }
+            }
+
+            for nested in child.iter_items() {
+                self.collect(nested, *tag, frame_of_reference, references)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The UID value of the object's child element, if present and not empty.
+fn uid(obj: &DicomObject<'_>, tag: u32) -> ParseResult<Option<String>> {
+    let Some(child) = obj.get_child_by_tag(tag) else {
+        return Ok(None);
+    };
+    let uid: Option<String> = match child.element().parse_value()? {
+        RawValue::Uid(uid) => Some(uid),
+        RawValue::Strings(values) => values.into_iter().next(),
+        _ => None,
+    };
+    Ok(uid
+        .map(|uid| uid.trim_end_matches(['\0', ' ']).to_owned())
+        .filter(|uid| !uid.is_empty()))
+}
@@ -0,0 +1,378 @@
+//! Attribute matching, as for the keys of C-FIND queries. Part 4, Section C.2.2.2.
+//!
+//! A `Matcher` is created from the value of a key, with the kind of matching determined by the
+//! value and the key's VR:
+//!
+//! - An empty value, or `*` alone, is universal matching, which matches any value.
+//! - Values of `AE`, `CS`, `LO`, `LT`, `PN`, `SH`, `ST`, `UC`, `UR`, and `UT` containing `*` or
+//!   `?` are wildcard matching, where `*` matches any sequence of characters and `?` any single
+//!   character.
+//! - Values of `DA`, `TM`, and `DT` containing `-` are range matching, e.g. `20240101-20240131`,
+//!   where either end may be omitted. The end of a range includes all values it's a prefix of, so
+//!   the time range `0900-0930` matches `093045`. Offsets from UTC of `DT` values are ignored.
+//! - Multiple values, separated by `\`, are list matching, where each value is matched as above
+//!   and any one of them matching is a match. This is most commonly a list of UIDs.
+//! - Otherwise a value is single value matching, which must match exactly.
+//!
+//! Values are compared once decoded by the Specific Character Set of their dataset, so keys match
+//! values of datasets in other character sets. Leading and trailing spaces are insignificant.
+//! `PN` values are matched case-insensitively, trailing empty components are ignored, and a key
+//! without component groups matches any of a value's alphabetic, ideographic, or phonetic groups.
+//!
+//! ```
+//! use dcmpipe_lib::core::{defn::vr, matching::Matcher};
+//!
+//! let matcher: Matcher = Matcher::parse(&vr::PN, "doe^j*");
+//! assert!(matcher.matches("DOE^JOHN"));
+//! assert!(!matcher.matches("DOE^MARY"));
+//!
+//! let matcher: Matcher = Matcher::parse(&vr::DA, "20240101-");
+//! assert!(matcher.matches("20240315"));
+//! assert!(!matcher.matches("20231231"));
+//! ```
+
+use crate::core::{
+    dcmelement::DicomElement,
+    defn::vr::{self, VRRef},
+    read::ParseResult,
+};
+
+/// The separator of multiple values.
+const VALUE_SEPARATOR: char = '\\';
+
+/// The separator of the component groups of `PN` values.
+const GROUP_SEPARATOR: char = '=';
+
+/// The separator of the components of a `PN` component group.
+const COMPONENT_SEPARATOR: char = '^';
+
+/// The value with which each omitted part of a time is filled, for the start and end of a range.
+const TIME_START: &str = "000000.000000";
+const TIME_END: &str = "235959.999999";
+
+/// The value with which each omitted part of a date and time, after the year, is filled for the
+/// start and end of a range.
+const DATETIME_START: &str = "0101000000.000000";
+const DATETIME_END: &str = "1231235959.999999";
+
+/// The kind of matching of a key, see the module documentation. Values are as given in the key,
+/// with insignificant spaces removed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Matching {
+    /// Matches any value.
+    Universal,
+    /// Matches a value equal to this.
+    Single(String),
+    /// Matches a value by a pattern of `*` and `?` wildcards.
+    Wildcard(String),
+    /// Matches a value within the inclusive range, where either end may be omitted.
+    Range(Option<String>, Option<String>),
+    /// Matches a value matching any of these.
+    List(Vec<Matching>),
+}
+
+/// Matches values of an attribute against a key, see the module documentation.
+#[derive(Clone, Debug)]
+pub struct Matcher {
+    vr: VRRef,
+    matching: Matching,
+}
+
+impl Matcher {
+    /// Creates a matcher for the values of a key of the given VR. Without any values, or with a
+    /// single empty value, the key is universal matching.
+    pub fn new(vr: VRRef, values: &[String]) -> Matcher {
+        let mut matchings: Vec<Matching> = values
+            .iter()
+            .map(|value| Matching::parse(vr, value))
+            .collect();
+        let matching: Matching = match matchings.len() {
+            0 => Matching::Universal,
+            1 => matchings.remove(0),
+            _ if matchings.contains(&Matching::Universal) => Matching::Universal,
+            _ => Matching::List(matchings),
+        };
+        Matcher { vr, matching }
+    }
+
+    /// Creates a matcher for a key of the given VR, whose multiple values are separated by `\`.
+    pub fn parse(vr: VRRef, value: &str) -> Matcher {
+        let values: Vec<String> = if value.is_empty() {
+            Vec::new()
+        } else {
+            value.split(VALUE_SEPARATOR).map(str::to_owned).collect()
+        };
+        Matcher::new(vr, &values)
+    }
+
+    /// Creates a matcher for a key given as an element, e.g. of a C-FIND Identifier.
+    pub fn from_element(element: &DicomElement<'_>) -> ParseResult<Matcher> {
+        if element.is_empty() {
+            return Ok(Matcher::new(element.vr(), &[]));
+        }
+        let values: Vec<String> = Vec::<String>::try_from(element)?;
+        Ok(Matcher::new(element.vr(), &values))
+    }
+
+    pub fn vr(&self) -> VRRef {
+        self.vr
+    }
+
+    pub fn matching(&self) -> &Matching {
+        &self.matching
+    }
+
+    /// Whether this matches any value, in which case the key only requests the attribute's value.
+    pub fn is_universal(&self) -> bool {
+        self.matching == Matching::Universal
+    }
+
+    /// Whether values are compared case-insensitively, which is the case for `PN`.
+    pub fn is_case_insensitive(&self) -> bool {
+        self.vr == &vr::PN
+    }
+
+    /// Whether the value matches. For a value with multiple values, use `matches_any()`.
+    pub fn matches(&self, value: &str) -> bool {
+        if self.vr == &vr::PN {
+            let value: String = normalize_name(value);
+            if self.matching_name(&value) {
+                return true;
+            }
+            // A key of only one component group matches any of the value's groups.
+            return value.contains(GROUP_SEPARATOR)
+                && value
+                    .split(GROUP_SEPARATOR)
+                    .any(|group| !group.is_empty() && self.matching_name(group));
+        }
+        self.matching.matches(self.vr, trim_value(self.vr, value))
+    }
+
+    /// Whether any of the values match. With no values, only universal matching matches.
+    pub fn matches_any<S: AsRef<str>>(&self, values: &[S]) -> bool {
+        if values.is_empty() {
+            return self.is_universal();
+        }
+        values.iter().any(|value| self.matches(value.as_ref()))
+    }
+
+    /// Whether any of the values of the element match, decoded by the element's character set.
+    pub fn matches_element(&self, element: &DicomElement<'_>) -> ParseResult<bool> {
+        if self.is_universal() {
+            return Ok(true);
+        }
+        if element.is_empty() {
+            return Ok(false);
+        }
+        let values: Vec<String> = Vec::<String>::try_from(element)?;
+        Ok(self.matches_any(&values))
+    }
+
+    /// Matches a normalized `PN` value, or one of its groups.
+    fn matching_name(&self, value: &str) -> bool {
+        self.matching.matches(self.vr, value)
+    }
+}
+
+impl Matching {
+    /// The matching of a single value of a key.
+    fn parse(vr: VRRef, value: &str) -> Matching {
+        let value: String = if vr == &vr::PN {
+            normalize_name(value)
+        } else {
+            trim_value(vr, value).to_owned()
+        };
+        if value.is_empty() || (value == "*" && supports_wildcards(vr)) {
+            return Matching::Universal;
+        }
+        if supports_wildcards(vr) && value.contains(['*', '?']) {
+            return Matching::Wildcard(value);
+        }
+        if supports_ranges(vr) {
+            if let Some((start, end)) = split_range(vr, &value) {
+                let bound = |bound: &str| (!bound.is_empty()).then(|| bound.to_owned());
+                return Matching::Range(bound(start), bound(end));
+            }
+        }
+        Matching::Single(value)
+    }
+
+    /// Whether the value, trimmed or normalized as for the key, matches.
+    fn matches(&self, vr: VRRef, value: &str) -> bool {
+        let case_insensitive: bool = vr == &vr::PN;
+        match self {
+            Matching::Universal => true,
+            Matching::Single(key) if case_insensitive => key.to_lowercase() == value.to_lowercase(),
+            Matching::Single(key) => key == value,
+            Matching::Wildcard(pattern) if case_insensitive => {
+                wildcard_match(&pattern.to_lowercase(), &value.to_lowercase())
+            }
+            Matching::Wildcard(pattern) => wildcard_match(pattern, value),
+            Matching::Range(start, end) => {
+                if value.is_empty() {
+                    return false;
+                }
+                let value: String = range_start(vr, value);
+                start
+                    .as_ref()
+                    .is_none_or(|start| value >= range_start(vr, start))
+                    && end.as_ref().is_none_or(|end| value <= range_end(vr, end))
+            }
+            Matching::List(matchings) => matchings.iter().any(|m| m.matches(vr, value)),
+        }
+    }
+}
+
+/// Whether the value matches the pattern of `*` and `?` wildcards, comparing characters exactly.
+pub fn wildcard_match(pattern: &str, value: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let value: Vec<char> = value.chars().collect();
+
+    // The position in the pattern after the last `*`, and the position in the value it's matched
+    // up to, which is advanced when backtracking.
+    let mut star: Option<(usize, usize)> = None;
+    let (mut p, mut v) = (0, 0);
+    while v < value.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                p += 1;
+                star = Some((p, v));
+            }
+            Some(&c) if c == '?' || c == value[v] => {
+                p += 1;
+                v += 1;
+            }
+            _ => match star {
+                Some((star_p, star_v)) => {
+                    p = star_p;
+                    v = star_v + 1;
+                    star = Some((star_p, v));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// The value as the inclusive end of a range of the VR, with omitted parts of a time filled by
+/// their maximum, e.g. `0930` as `093059.999999`. Values of other VRs are returned as-is.
+///
+/// Values compare chronologically with the end of a range as strings, so this can be used to
+/// translate range matching into queries of other systems.
+pub fn range_end(vr: VRRef, value: &str) -> String {
+    fill_datetime(vr, value, TIME_END, DATETIME_END)
+}
+
+/// The value as the start of a range of the VR, or a value compared with a range, with omitted
+/// parts of a time filled by their minimum, e.g. `0930` as `093000.000000`.
+fn range_start(vr: VRRef, value: &str) -> String {
+    fill_datetime(vr, value, TIME_START, DATETIME_START)
+}
+
+fn fill_datetime(vr: VRRef, value: &str, time_fill: &str, datetime_fill: &str) -> String {
+    if vr == &vr::DA {
+        // Dates of ACR-NEMA may be formatted as `YYYY.MM.DD`.
+        value.replace('.', "")
+    } else if vr == &vr::TM {
+        // Times of ACR-NEMA may be formatted as `HH:MM:SS.frac`.
+        fill(&value.replace(':', ""), time_fill)
+    } else if vr == &vr::DT {
+        let value: &str = strip_offset(value);
+        let (year, rest) = value.split_at(value.len().min(4));
+        format!("{year}{}", fill(rest, datetime_fill))
+    } else {
+        value.to_owned()
+    }
+}
+
+/// Fills the omitted end of a value from the template of the same format.
+fn fill(value: &str, template: &str) -> String {
+    match template.get(value.len()..) {
+        Some(rest) => format!("{value}{rest}"),
+        None => value.to_owned(),
+    }
+}
+
+/// Splits a value of a range matching VR into the start and end of the range, if it's a range.
+fn split_range(vr: VRRef, value: &str) -> Option<(&str, &str)> {
+    if vr != &vr::DT {
+        return value.split_once('-');
+    }
+    // The `-` of a negative offset from UTC, `&ZZXX`, isn't the separator of a range.
+    value
+        .match_indices('-')
+        .map(|(index, _)| index)
+        .find(|index| !is_offset(value, *index))
+        .map(|index| (&value[..index], &value[index + 1..]))
+}
+
+/// Whether the sign at the index of a `DT` value begins an offset from UTC, i.e. it follows at
+/// least the hour and is followed by four digits ending the value or the start of the range.
+fn is_offset(value: &str, index: usize) -> bool {
+    let offset: &str = &value[index + 1..];
+    let digits: &str = offset.get(..4).unwrap_or_default();
+    index >= 10
+        && digits.len() == 4
+        && digits.bytes().all(|b| b.is_ascii_digit())
+        && (offset.len() == 4 || offset[4..].starts_with('-'))
+}
+
+/// The `DT` value without its offset from UTC, if any.
+fn strip_offset(value: &str) -> &str {
+    match value.rfind(['+', '-']) {
+        Some(index) if value[index..].starts_with('+') || is_offset(value, index) => {
+            &value[..index]
+        }
+        _ => value,
+    }
+}
+
+/// Removes the insignificant spaces, or for `UI` the null padding, from the ends of a value.
+fn trim_value(vr: VRRef, value: &str) -> &str {
+    if vr == &vr::UI {
+        value.trim_end_matches('\0').trim()
+    } else {
+        value.trim_matches(' ')
+    }
+}
+
+/// Removes insignificant spaces and trailing empty components and groups of a `PN` value.
+fn normalize_name(value: &str) -> String {
+    value
+        .trim_matches(' ')
+        .split(GROUP_SEPARATOR)
+        .map(|group| {
+            group
+                .split(COMPONENT_SEPARATOR)
+                .map(|component| component.trim_matches(' '))
+                .collect::<Vec<&str>>()
+                .join("^")
+                .trim_end_matches(COMPONENT_SEPARATOR)
+                .to_owned()
+        })
+        .collect::<Vec<String>>()
+        .join("=")
+        .trim_end_matches(GROUP_SEPARATOR)
+        .to_owned()
+}
+
+fn supports_wildcards(vr: VRRef) -> bool {
+    [
+        &vr::AE,
+        &vr::CS,
+        &vr::LO,
+        &vr::LT,
+        &vr::PN,
+        &vr::SH,
+        &vr::ST,
+        &vr::UC,
+        &vr::UR,
+        &vr::UT,
+    ]
+    .contains(&vr)
+}
+
+fn supports_ranges(vr: VRRef) -> bool {
+    vr == &vr::DA || vr == &vr::TM || vr == &vr::DT
+}
@@ -1,10 +1,29 @@
+pub mod bulkdata;
 pub mod charset;
+#[cfg(feature = "zstd")]
+pub mod compressed;
 pub mod dcmelement;
 pub mod dcmobject;
 pub mod dcmsqelem;
 pub mod defn;
+pub mod edit;
+pub mod filemeta;
+#[cfg(feature = "hash")]
+pub mod hash;
+pub mod integrity;
+pub mod matching;
+pub mod padding;
+pub mod pixeldata;
+pub mod private;
+pub mod provenance;
 pub mod read;
+#[cfg(feature = "serde")]
+pub mod repr;
+#[cfg(feature = "signature")]
+pub mod signature;
+pub mod sizereport;
 pub mod values;
+pub mod vendor;
 pub mod write;
 
 pub use values::Attribute;
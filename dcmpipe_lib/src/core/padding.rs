@@ -0,0 +1,105 @@
+//! Handling of values which aren't padded as the standard requires.
+//!
+//! Part 5, Ch 6.2 and 7.1.1: values are of even length, with a single trailing padding byte added
+//! when necessary. Character strings are padded with a SPACE, except for `UI` which, as with
+//! binary values, is padded with a NULL. Some datasets have values of odd length, values padded
+//! with the wrong byte, or UIDs followed by several NULLs.
+
+use std::fmt::{Display, Formatter};
+
+use crate::core::defn::vr::{self, VRRef};
+
+/// How the parser or writer handles values which aren't padded as the standard requires, see
+/// `PaddingIssue`. Only the trailing bytes of a value are ever considered padding, so that bytes
+/// within a value, such as a NULL in the middle of a malformed UID, are retained as they are.
+///
+/// Values deferred while parsing are only checked once written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PaddingPolicy {
+    /// Keep values as they're encoded. The parser retains the value length of values of odd
+    /// length, and the writer pads them to even length with a NULL.
+    #[default]
+    Preserve,
+
+    /// Rewrite the padding of values with the padding byte of their VR, see `normalize()`. Values
+    /// normalized while parsing are reported as a `ParseWarning::Padding`.
+    Normalize,
+
+    /// Fail parsing with `ParseError::InvalidPadding`, or writing with
+    /// `WriteError::InvalidPadding`.
+    Error,
+}
+
+/// A way in which a value isn't padded as the standard requires.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaddingIssue {
+    /// The value is of odd length.
+    OddLength,
+
+    /// A character string is padded with NULLs rather than SPACEs, or a UID with SPACEs rather
+    /// than a NULL.
+    WrongPadding,
+
+    /// A UID is followed by more than the single NULL needed to achieve even length.
+    ExtraNulls,
+}
+
+impl Display for PaddingIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PaddingIssue::OddLength => write!(f, "value is of odd length"),
+            PaddingIssue::WrongPadding => write!(f, "value is padded with the wrong byte"),
+            PaddingIssue::ExtraNulls => write!(f, "value is followed by several nulls"),
+        }
+    }
+}
+
+/// Checks the padding of a value of the given VR, as encoded, without any padding added to
+/// achieve even length.
+pub fn check(vr: VRRef, value: &[u8]) -> Option<PaddingIssue> {
+    if vr.is_character_string && vr.can_pad_end {
+        let trailing: &[u8] = trailing_padding(value);
+        if vr.padding == vr::NULL_PADDING {
+            if trailing.contains(&vr::SPACE_PADDING) {
+                return Some(PaddingIssue::WrongPadding);
+            }
+            if trailing.len() > 1 {
+                return Some(PaddingIssue::ExtraNulls);
+            }
+        } else if trailing.contains(&vr::NULL_PADDING) {
+            return Some(PaddingIssue::WrongPadding);
+        }
+    }
+    if !value.len().is_multiple_of(2) {
+        return Some(PaddingIssue::OddLength);
+    }
+    None
+}
+
+/// Pads the value to even length with the padding byte of its VR. For character strings the
+/// trailing padding is replaced with the padding byte of the VR, and UIDs are followed by no more
+/// than a single NULL.
+pub fn normalize(vr: VRRef, value: &[u8]) -> Vec<u8> {
+    let mut normalized: Vec<u8> = value.to_vec();
+    if vr.is_character_string && vr.can_pad_end {
+        let start: usize = value.len() - trailing_padding(value).len();
+        if vr.padding == vr::NULL_PADDING {
+            normalized.truncate(start);
+        } else {
+            normalized[start..].fill(vr::SPACE_PADDING);
+        }
+    }
+    if !normalized.len().is_multiple_of(2) {
+        normalized.push(vr.padding);
+    }
+    normalized
+}
+
+/// The trailing SPACE and NULL bytes of a value.
+fn trailing_padding(value: &[u8]) -> &[u8] {
+    let len: usize = value
+        .iter()
+        .rposition(|b| *b != vr::SPACE_PADDING && *b != vr::NULL_PADDING)
+        .map_or(0, |last| last + 1);
+    &value[len..]
+}
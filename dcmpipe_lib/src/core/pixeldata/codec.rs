@@ -0,0 +1,144 @@
+//! Codecs for the Pixel Data of encapsulated transfer syntaxes, provided by other crates.
+//!
+//! Pixel Data of encapsulated transfer syntaxes can't otherwise be decoded. A `PixelCodec` which
+//! decompresses, and optionally compresses, the frames of a transfer syntax is registered for the
+//! transfer syntax's UID in a `CodecRegistry`, so that proprietary or newly standardized codecs
+//! can be used without changes to this crate. The registry is given to
+//! `FrameDecoder::with_codecs()` to decode frames, or to `encode_with_codec()` to compress native
+//! Pixel Data.
+
+use std::{collections::HashMap, fmt::Debug, sync::Arc};
+
+use crate::core::{
+    dcmelement::DicomElement, dcmobject::DicomRoot, defn::constants::tags, defn::ts::TSRef,
+};
+
+use super::{
+    encapsulated::insert_encapsulated, pixel_data_tag, FrameLayout, ImagePixel, PixelDataError,
+    PixelDataResult,
+};
+
+/// Decompresses and compresses the frames of an encapsulated transfer syntax.
+///
+/// Native frames are as the frames of native Pixel Data: samples of Bits Allocated, little endian,
+/// laid out by Planar Configuration, with a length of `ImagePixel::frame_len()` of the attributes
+/// describing them. The frames of encapsulated Pixel Data are as extracted with
+/// `EncapsulatedPixelData::frame()`.
+pub trait PixelCodec: Debug + Send + Sync {
+    /// Decompresses a frame into a native frame described by `decoded_image()`.
+    fn decode(&self, frame: &[u8], image: &ImagePixel) -> PixelDataResult<Vec<u8>>;
+
+    /// Compresses a native frame described by `image`, the attributes of the dataset prior to
+    /// compression. Codecs which only decompress need not implement this.
+    fn encode(&self, _frame: &[u8], _image: &ImagePixel) -> PixelDataResult<Vec<u8>> {
+        Err(PixelDataError::UnsupportedEncoding(
+            "encoding with a codec which only decodes".to_owned(),
+        ))
+    }
+
+    /// The attributes describing the frames `decode()` produces, given those of the dataset. Some
+    /// codecs change the encoding of frames as they decompress them, e.g. JPEG decoders usually
+    /// convert `YBR_FULL_422` into `RGB`. By default these are the attributes of the dataset.
+    fn decoded_image(&self, image: &ImagePixel) -> ImagePixel {
+        image.clone()
+    }
+
+    /// The attributes of the dataset once compressed, given those prior to compression. By default
+    /// these are unchanged.
+    fn encoded_image(&self, image: &ImagePixel) -> ImagePixel {
+        image.clone()
+    }
+}
+
+/// A shared reference to a registered `PixelCodec`.
+pub type CodecRef = Arc<dyn PixelCodec>;
+
+/// The codecs available for decoding and encoding Pixel Data, by transfer syntax UID.
+#[derive(Clone, Debug, Default)]
+pub struct CodecRegistry {
+    codecs: HashMap<String, CodecRef>,
+}
+
+impl CodecRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> CodecRegistry {
+        CodecRegistry::default()
+    }
+
+    /// Registers the codec for the transfer syntax with the given UID, returning the codec it
+    /// replaces, if any.
+    pub fn register(&mut self, ts_uid: &str, codec: CodecRef) -> Option<CodecRef> {
+        self.codecs.insert(ts_uid.to_owned(), codec)
+    }
+
+    /// Removes the codec for the transfer syntax with the given UID, returning it if registered.
+    pub fn unregister(&mut self, ts_uid: &str) -> Option<CodecRef> {
+        self.codecs.remove(ts_uid)
+    }
+
+    /// Gets the codec for the transfer syntax with the given UID, if registered.
+    pub fn get(&self, ts_uid: &str) -> Option<&CodecRef> {
+        self.codecs.get(ts_uid)
+    }
+
+    /// Gets the codec for the given transfer syntax, if registered.
+    pub fn get_for_ts(&self, ts: TSRef) -> Option<&CodecRef> {
+        self.get(ts.uid().uid())
+    }
+
+    /// The UIDs of the transfer syntaxes with a registered codec, in no particular order.
+    pub fn transfer_syntaxes(&self) -> impl Iterator<Item = &str> {
+        self.codecs.keys().map(String::as_str)
+    }
+}
+
+/// Compresses the dataset's native Pixel Data with the codec registered for the transfer syntax
+/// `to`, re-encoding the dataset into that transfer syntax with each frame compressed into a
+/// fragment of encapsulated Pixel Data. The Image Pixel attributes are updated per
+/// `PixelCodec::encoded_image()`.
+///
+/// The attributes describing lossy compression aren't modified, so callers using a lossy codec
+/// should update them, and assign the dataset a new SOP Instance UID.
+pub fn encode_with_codec(
+    dcmroot: &mut DicomRoot<'_>,
+    to: TSRef,
+    codecs: &CodecRegistry,
+    stage: &str,
+) -> PixelDataResult<()> {
+    let codec: &CodecRef = codecs
+        .get_for_ts(to)
+        .ok_or(PixelDataError::UnsupportedTransferSyntax(to.uid().ident()))?;
+    if !to.encapsulated() {
+        return Err(PixelDataError::UnsupportedTransferSyntax(to.uid().ident()));
+    }
+    let tag: u32 = pixel_data_tag(dcmroot)?;
+    if tag != tags::PIXEL_DATA {
+        return Err(PixelDataError::UnsupportedEncoding(
+            "encoding float pixel data".to_owned(),
+        ));
+    }
+    let pixel_data: &DicomElement = dcmroot
+        .get_child_by_tag(tag)
+        .map(|obj| obj.element())
+        .ok_or(PixelDataError::MissingPixelData)?;
+    let layout: FrameLayout = FrameLayout::read(dcmroot, pixel_data)?;
+    let image: ImagePixel = ImagePixel::from_dataset(dcmroot)?;
+
+    let big_endian: bool = pixel_data.ts().big_endian();
+    let mut fragments: Vec<Vec<u8>> = Vec::with_capacity(layout.frames);
+    for frame in 0..layout.frames {
+        let mut data: Vec<u8> = layout.frame_bytes(pixel_data.data(), frame)?.to_vec();
+        if big_endian {
+            for sample in data.chunks_exact_mut(layout.bytes_per_sample()) {
+                sample.reverse();
+            }
+        }
+        fragments.push(codec.encode(&data, &image)?);
+    }
+
+    let encoded: ImagePixel = codec.encoded_image(&image);
+    dcmroot.transcode_in_place(to)?;
+    encoded.insert_into(dcmroot, stage)?;
+    let offset_table: bool = fragments.len() > 1;
+    insert_encapsulated(dcmroot, fragments, offset_table, stage)
+}
@@ -0,0 +1,275 @@
+//! Reassembly of multi-frame objects split into a concatenation, see Part 3, Ch C.7.6.16.2.2.4.
+//!
+//! An Enhanced multi-frame object too large for a single file may be split into the instances of
+//! a concatenation, which share the Concatenation UID and the SOP Instance UID of Concatenation
+//! Source while each holding consecutive frames. A `Concatenation` orders the instances and indexes
+//! their frames as those of the single object they were split from, so frames are accessed the same
+//! way whether or not the object was split. An instance which isn't part of a concatenation is
+//! indexed on its own.
+
+use std::{cell::OnceCell, collections::BTreeMap};
+
+use crate::core::dcmobject::{DicomObject, DicomRoot};
+
+use super::{
+    encapsulated::EncapsulatedPixelData, number_of_frames, FrameDecoder, PixelDataError,
+    PixelDataResult, PixelFrame,
+};
+
+const SOP_INSTANCE_UID: u32 = 0x0008_0018;
+const CONCATENATION_SOURCE: u32 = 0x0020_0242;
+const CONCATENATION_UID: u32 = 0x0020_9161;
+const IN_CONCATENATION_NUMBER: u32 = 0x0020_9162;
+const IN_CONCATENATION_TOTAL_NUMBER: u32 = 0x0020_9163;
+const CONCATENATION_FRAME_OFFSET: u32 = 0x0020_9228;
+const PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE: u32 = 0x5200_9230;
+
+/// The instance and zero-based frame within it holding a frame of a `Concatenation`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FrameLocation {
+    /// The index of the instance within `Concatenation::instances()`.
+    pub instance: usize,
+    pub frame: usize,
+}
+
+/// An instance of a concatenation, with the decoding of its Pixel Data prepared on first use.
+#[derive(Debug)]
+struct ConcatenatedInstance<'d> {
+    dcmroot: &'d DicomRoot<'d>,
+    /// The number of frames in prior instances of the concatenation.
+    frame_offset: usize,
+    frames: usize,
+    decoder: OnceCell<FrameDecoder<'d>>,
+    encapsulated: OnceCell<EncapsulatedPixelData<'d>>,
+}
+
+/// The instances of a concatenation, indexing their frames as those of a single object.
+#[derive(Debug)]
+pub struct Concatenation<'d> {
+    uid: String,
+    concatenated: bool,
+    instances: Vec<ConcatenatedInstance<'d>>,
+}
+
+impl<'d> Concatenation<'d> {
+    /// Groups the instances into concatenations by their Concatenation UID. Instances which aren't
+    /// part of a concatenation are each returned on their own. Concatenations are ordered by their
+    /// SOP Instance UID, see `uid()`.
+    pub fn group<I>(instances: I) -> PixelDataResult<Vec<Concatenation<'d>>>
+    where
+        I: IntoIterator<Item = &'d DicomRoot<'d>>,
+    {
+        let mut grouped: BTreeMap<String, Vec<&'d DicomRoot<'d>>> = BTreeMap::new();
+        for (index, dcmroot) in instances.into_iter().enumerate() {
+            // Instances without any UID are kept apart from all others.
+            let key: String = match get_uid(dcmroot, CONCATENATION_UID)? {
+                Some(uid) => uid,
+                None => get_uid(dcmroot, SOP_INSTANCE_UID)?.unwrap_or_else(|| format!("#{index}")),
+            };
+            grouped.entry(key).or_default().push(dcmroot);
+        }
+        let mut concatenations: Vec<Concatenation<'d>> = grouped
+            .into_values()
+            .map(Concatenation::new)
+            .collect::<PixelDataResult<Vec<Concatenation<'d>>>>()?;
+        concatenations.sort_by(|a, b| a.uid.cmp(&b.uid));
+        Ok(concatenations)
+    }
+
+    /// Orders the instances of a single concatenation by their In-concatenation Number, checking
+    /// that all instances are present and that their frames are contiguous. A single instance
+    /// which isn't part of a concatenation is also accepted.
+    pub fn new(instances: Vec<&'d DicomRoot<'d>>) -> PixelDataResult<Concatenation<'d>> {
+        let Some(first) = instances.first().copied() else {
+            return Err(PixelDataError::InvalidConcatenation(
+                "no instances".to_owned(),
+            ));
+        };
+        let concatenation_uid: Option<String> = get_uid(first, CONCATENATION_UID)?;
+        if concatenation_uid.is_none() {
+            if instances.len() > 1 {
+                return Err(PixelDataError::InvalidConcatenation(
+                    "instances have no Concatenation UID".to_owned(),
+                ));
+            }
+            let uid: String = get_uid(first, SOP_INSTANCE_UID)?.unwrap_or_default();
+            return Ok(Concatenation {
+                uid,
+                concatenated: false,
+                instances: vec![ConcatenatedInstance::new(first, 0)?],
+            });
+        }
+
+        let mut numbered: Vec<(u16, &'d DicomRoot<'d>)> = Vec::with_capacity(instances.len());
+        for dcmroot in instances {
+            if get_uid(dcmroot, CONCATENATION_UID)? != concatenation_uid {
+                return Err(PixelDataError::InvalidConcatenation(
+                    "instances have differing Concatenation UIDs".to_owned(),
+                ));
+            }
+            let number: u16 = match dcmroot.get_child_by_tag(IN_CONCATENATION_NUMBER) {
+                Some(obj) => u16::try_from(obj.element())?,
+                None => return Err(PixelDataError::MissingAttribute("InConcatenationNumber")),
+            };
+            numbered.push((number, dcmroot));
+        }
+        numbered.sort_by_key(|(number, _)| *number);
+
+        let total: Option<u16> = first
+            .get_child_by_tag(IN_CONCATENATION_TOTAL_NUMBER)
+            .map(|obj| u16::try_from(obj.element()))
+            .transpose()?;
+        let numbers: Vec<u16> = numbered.iter().map(|(number, _)| *number).collect();
+        let expected: u16 = total.unwrap_or(numbers.len() as u16);
+        if !numbers.iter().copied().eq(1..=expected) {
+            return Err(PixelDataError::InvalidConcatenation(format!(
+                "expected instances 1 to {expected} but found {numbers:?}"
+            )));
+        }
+
+        let mut concatenated: Vec<ConcatenatedInstance<'d>> = Vec::with_capacity(numbered.len());
+        let mut frame_offset: usize = 0;
+        for (number, dcmroot) in numbered {
+            if let Some(obj) = dcmroot.get_child_by_tag(CONCATENATION_FRAME_OFFSET) {
+                let offset: usize = u32::try_from(obj.element())? as usize;
+                if offset != frame_offset {
+                    return Err(PixelDataError::InvalidConcatenation(format!(
+                        "instance {number} has frame offset {offset} but follows {frame_offset} frames"
+                    )));
+                }
+            }
+            let instance: ConcatenatedInstance<'d> =
+                ConcatenatedInstance::new(dcmroot, frame_offset)?;
+            frame_offset += instance.frames;
+            concatenated.push(instance);
+        }
+
+        let uid: String = match get_uid(first, CONCATENATION_SOURCE)? {
+            Some(uid) => uid,
+            None => {
+                return Err(PixelDataError::MissingAttribute(
+                    "SOPInstanceUIDofConcatenationSource",
+                ))
+            }
+        };
+        Ok(Concatenation {
+            uid,
+            concatenated: true,
+            instances: concatenated,
+        })
+    }
+
+    /// The SOP Instance UID of Concatenation Source, i.e. of the object the instances were split
+    /// from, or the SOP Instance UID of an instance which isn't part of a concatenation.
+    pub fn uid(&self) -> &str {
+        &self.uid
+    }
+
+    /// Whether the object is split across the instances of a concatenation.
+    pub fn is_concatenated(&self) -> bool {
+        self.concatenated
+    }
+
+    /// The instances, in order of their frames.
+    pub fn instances(&self) -> impl Iterator<Item = &'d DicomRoot<'d>> + '_ {
+        self.instances.iter().map(|instance| instance.dcmroot)
+    }
+
+    /// The first instance, whose attributes other than those of its frames are shared by all
+    /// instances of the concatenation.
+    pub fn dataset(&self) -> &'d DicomRoot<'d> {
+        self.instances[0].dcmroot
+    }
+
+    /// The number of frames across all instances.
+    pub fn frame_count(&self) -> usize {
+        self.instances
+            .last()
+            .map_or(0, |instance| instance.frame_offset + instance.frames)
+    }
+
+    /// The instance and frame within it holding the frame at the given zero-based index, if within
+    /// range.
+    pub fn locate(&self, frame: usize) -> Option<FrameLocation> {
+        let index: usize = self
+            .instances
+            .partition_point(|instance| instance.frame_offset + instance.frames <= frame);
+        let instance: &ConcatenatedInstance<'d> = self.instances.get(index)?;
+        Some(FrameLocation {
+            instance: index,
+            frame: frame - instance.frame_offset,
+        })
+    }
+
+    /// The item of the Per-frame Functional Groups Sequence describing the frame, if present.
+    pub fn per_frame_groups(&self, frame: usize) -> Option<&'d DicomObject<'d>> {
+        let location: FrameLocation = self.locate(frame)?;
+        self.instances[location.instance]
+            .dcmroot
+            .get_child_by_tag(PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE)?
+            .get_item_by_index(location.frame + 1)
+    }
+
+    /// Decodes the frame at the given zero-based index. The decoding of an instance's Pixel Data
+    /// is prepared when its first frame is decoded, so frames are cheap to decode one at a time.
+    pub fn decode(&self, frame: usize) -> PixelDataResult<PixelFrame> {
+        let (instance, location) = self.instance_of(frame)?;
+        let decoder: &FrameDecoder<'d> = match instance.decoder.get() {
+            Some(decoder) => decoder,
+            None => {
+                let decoder = FrameDecoder::new(instance.dcmroot)?;
+                instance.decoder.get_or_init(|| decoder)
+            }
+        };
+        decoder.decode(location.frame)
+    }
+
+    /// Gets the compressed bytes of the frame at the given zero-based index, see
+    /// `EncapsulatedPixelData::frame()`. The instance's Pixel Data must be encapsulated.
+    pub fn compressed_frame(&self, frame: usize) -> PixelDataResult<Vec<u8>> {
+        let (instance, location) = self.instance_of(frame)?;
+        let encapsulated: &EncapsulatedPixelData<'d> = match instance.encapsulated.get() {
+            Some(encapsulated) => encapsulated,
+            None => {
+                let encapsulated = EncapsulatedPixelData::read(instance.dcmroot)?;
+                instance.encapsulated.get_or_init(|| encapsulated)
+            }
+        };
+        encapsulated.frame(location.frame)
+    }
+
+    fn instance_of(
+        &self,
+        frame: usize,
+    ) -> PixelDataResult<(&ConcatenatedInstance<'d>, FrameLocation)> {
+        let location: FrameLocation =
+            self.locate(frame)
+                .ok_or_else(|| PixelDataError::FrameOutOfRange {
+                    frame,
+                    frames: self.frame_count(),
+                })?;
+        Ok((&self.instances[location.instance], location))
+    }
+}
+
+impl<'d> ConcatenatedInstance<'d> {
+    fn new(dcmroot: &'d DicomRoot<'d>, frame_offset: usize) -> PixelDataResult<Self> {
+        Ok(ConcatenatedInstance {
+            dcmroot,
+            frame_offset,
+            frames: number_of_frames(dcmroot)?,
+            decoder: OnceCell::new(),
+            encapsulated: OnceCell::new(),
+        })
+    }
+}
+
+/// Gets the value of a UI attribute, if present and not empty.
+pub(crate) fn get_uid(dcmroot: &DicomRoot<'_>, tag: u32) -> PixelDataResult<Option<String>> {
+    let Some(obj) = dcmroot.get_child_by_tag(tag) else {
+        return Ok(None);
+    };
+    let uid: String = String::try_from(obj.element())?;
+    let uid: &str = uid.trim_end_matches(['\0', ' ']);
+    Ok((!uid.is_empty()).then(|| uid.to_owned()))
+}
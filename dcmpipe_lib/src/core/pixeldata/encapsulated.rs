@@ -0,0 +1,285 @@
+//! Access to the compressed frames of encapsulated Pixel Data, see Part 5, Ch A.4.
+//!
+//! Encapsulated Pixel Data is a sequence of fragments, the first of which is the Basic Offset
+//! Table. A frame may span several fragments, in which case the offset table, or the Extended
+//! Offset Table, gives the position of each frame's first fragment. The compressed bytes of each
+//! frame can be extracted without decoding them, e.g. to pass to a codec this library doesn't
+//! provide or to serve them as-is.
+
+use crate::core::{
+    charset::DEFAULT_CHARACTER_SET,
+    dcmelement::DicomElement,
+    dcmobject::{DicomObject, DicomRoot},
+    dcmsqelem::SequenceElement,
+    defn::{
+        constants::{tags, ts},
+        tag::{TagNode, TagPath},
+        ts::TSRef,
+        vl::ValueLength,
+        vr,
+    },
+};
+
+use super::{number_of_frames, PixelDataError, PixelDataResult};
+
+const EXTENDED_OFFSET_TABLE: u32 = 0x7FE0_0001;
+
+/// The number of bytes of the item tag and value length preceding each fragment.
+const ITEM_HEADER_LEN: u64 = 8;
+
+/// The fragments of a dataset's encapsulated Pixel Data.
+#[derive(Debug)]
+pub struct EncapsulatedPixelData<'d> {
+    basic_offset_table: &'d [u8],
+    fragments: Vec<&'d [u8]>,
+    extended_offset_table: Option<Vec<u64>>,
+    frames: usize,
+}
+
+impl<'d> EncapsulatedPixelData<'d> {
+    /// Reads the fragments of the dataset's Pixel Data, which must be encapsulated.
+    pub fn read(dcmroot: &'d DicomRoot<'_>) -> PixelDataResult<EncapsulatedPixelData<'d>> {
+        let pixel_data = dcmroot
+            .get_child_by_tag(tags::PIXEL_DATA)
+            .ok_or(PixelDataError::MissingPixelData)?;
+        if !dcmroot.ts().encapsulated() || !pixel_data.element().is_seq_like() {
+            return Err(PixelDataError::UnsupportedTransferSyntax(
+                dcmroot.ts().uid().ident(),
+            ));
+        }
+
+        // Fragments may be parsed as items nested within the prior fragment, so the flattened
+        // elements are used to list them in order regardless.
+        let mut items = pixel_data
+            .flatten()?
+            .into_iter()
+            .filter(|element| element.tag() == tags::ITEM)
+            .map(DicomElement::data);
+        let basic_offset_table: &[u8] = items.next().unwrap_or_default();
+        let fragments: Vec<&[u8]> = items.collect();
+
+        let extended_offset_table: Option<Vec<u64>> = dcmroot
+            .get_child_by_tag(EXTENDED_OFFSET_TABLE)
+            .map(|obj| Vec::<u64>::try_from(obj.element()))
+            .transpose()?;
+
+        Ok(EncapsulatedPixelData {
+            basic_offset_table,
+            fragments,
+            extended_offset_table,
+            frames: number_of_frames(dcmroot)?,
+        })
+    }
+
+    /// The value of the Basic Offset Table, which is empty if the offsets aren't given.
+    pub fn basic_offset_table(&self) -> &'d [u8] {
+        self.basic_offset_table
+    }
+
+    /// The fragments following the Basic Offset Table.
+    pub fn fragments(&self) -> &[&'d [u8]] {
+        &self.fragments
+    }
+
+    /// The number of frames, per the Number of Frames attribute.
+    pub fn frame_count(&self) -> usize {
+        self.frames
+    }
+
+    /// Gets the compressed bytes of the frame at the given zero-based index, joining the fragments
+    /// it spans.
+    ///
+    /// The fragments of each frame are determined by the Extended Offset Table or the Basic
+    /// Offset Table, if present. Otherwise a single frame spans all fragments, and multiple frames
+    /// are only supported with one fragment per frame.
+    pub fn frame(&self, frame: usize) -> PixelDataResult<Vec<u8>> {
+        if frame >= self.frames {
+            return Err(PixelDataError::FrameOutOfRange {
+                frame,
+                frames: self.frames,
+            });
+        }
+        Ok(self.join_frame(&self.frame_starts()?, frame))
+    }
+
+    /// Gets the compressed bytes of every frame, see `frame()`.
+    pub fn frames(&self) -> PixelDataResult<Vec<Vec<u8>>> {
+        let starts: Vec<usize> = self.frame_starts()?;
+        Ok((0..self.frames)
+            .map(|frame| self.join_frame(&starts, frame))
+            .collect())
+    }
+
+    /// Joins the fragments of the frame, given the index of each frame's first fragment.
+    fn join_frame(&self, starts: &[usize], frame: usize) -> Vec<u8> {
+        let end: usize = starts
+            .get(frame + 1)
+            .copied()
+            .unwrap_or(self.fragments.len());
+        self.fragments[starts[frame]..end].concat()
+    }
+
+    /// Determines the index of the first fragment of each frame.
+    fn frame_starts(&self) -> PixelDataResult<Vec<usize>> {
+        let offsets: Vec<u64> = match &self.extended_offset_table {
+            Some(offsets) if !offsets.is_empty() => offsets.clone(),
+            _ => self
+                .basic_offset_table
+                .chunks_exact(4)
+                .map(|offset| {
+                    u64::from(u32::from_le_bytes([
+                        offset[0], offset[1], offset[2], offset[3],
+                    ]))
+                })
+                .collect(),
+        };
+
+        if offsets.is_empty() {
+            return if self.frames == 1 {
+                Ok(vec![0])
+            } else if self.frames == self.fragments.len() {
+                Ok((0..self.frames).collect())
+            } else {
+                Err(PixelDataError::UnsupportedEncoding(format!(
+                    "{} frames in {} fragments without an offset table",
+                    self.frames,
+                    self.fragments.len()
+                )))
+            };
+        }
+        if offsets.len() != self.frames {
+            return Err(PixelDataError::UnsupportedEncoding(format!(
+                "offset table has {} entries for {} frames",
+                offsets.len(),
+                self.frames
+            )));
+        }
+
+        // Offsets are from the first byte of the first fragment's item, following the Basic Offset
+        // Table, so are matched against the position of each fragment's item.
+        let mut position: u64 = 0;
+        let mut fragment_positions: Vec<u64> = Vec::with_capacity(self.fragments.len());
+        for fragment in &self.fragments {
+            fragment_positions.push(position);
+            position += ITEM_HEADER_LEN + fragment.len() as u64;
+        }
+        let starts: Vec<usize> = offsets
+            .iter()
+            .map(|offset| {
+                fragment_positions.binary_search(offset).map_err(|_| {
+                    PixelDataError::UnsupportedEncoding(format!(
+                        "offset {offset} is not the start of a fragment"
+                    ))
+                })
+            })
+            .collect::<PixelDataResult<Vec<usize>>>()?;
+        if starts.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(PixelDataError::UnsupportedEncoding(
+                "offset table is not in increasing order".to_owned(),
+            ));
+        }
+        Ok(starts)
+    }
+}
+
+/// Sets the dataset's Pixel Data to encapsulate the given fragments, replacing any existing Pixel
+/// Data. The dataset must already be in an encapsulated transfer syntax. Fragments of odd length
+/// are padded with a trailing zero. When `offset_table` is set the Basic Offset Table gives the
+/// position of each fragment, which is then a frame, otherwise it's left empty.
+pub(crate) fn insert_encapsulated(
+    dcmroot: &mut DicomRoot<'_>,
+    mut fragments: Vec<Vec<u8>>,
+    offset_table: bool,
+    stage: &str,
+) -> PixelDataResult<()> {
+    // Fragments have an even length, see Part 5, Ch A.4.
+    for fragment in &mut fragments {
+        if !fragment.len().is_multiple_of(2) {
+            fragment.push(0);
+        }
+    }
+
+    let ts: TSRef = dcmroot.ts();
+    let tagpath: TagPath = TagPath::from(vec![TagNode::from(tags::PIXEL_DATA)]);
+    // The element is inserted to record its provenance, then replaced along with its contents.
+    dcmroot.insert_element(&tagpath, encapsulated_element(ts), stage)?;
+    let obj: &mut DicomObject = dcmroot
+        .get_child_by_tagpath_mut(&tagpath)
+        .ok_or(PixelDataError::MissingPixelData)?;
+    let (items, delimiter) = encapsulate(fragments, offset_table);
+    let mut encapsulated: DicomObject = DicomObject::new_with_children(
+        encapsulated_element(ts),
+        [(tags::SEQUENCE_DELIMITATION_ITEM, delimiter)].into(),
+        items,
+    );
+    encapsulated.set_provenance(obj.provenance().cloned());
+    *obj = encapsulated;
+    Ok(())
+}
+
+/// Creates an encapsulated Pixel Data element, whose value is its items.
+fn encapsulated_element<'a>(ts: TSRef) -> DicomElement<'a> {
+    DicomElement::new(
+        tags::PIXEL_DATA,
+        &vr::OB,
+        ValueLength::UndefinedLength,
+        ts,
+        DEFAULT_CHARACTER_SET,
+        Vec::new(),
+        Vec::new(),
+    )
+}
+
+/// Creates the items and sequence delimiter of encapsulated Pixel Data for the given fragments. The
+/// first item is the Basic Offset Table, see Part 5, Ch A.4.
+fn encapsulate<'a>(
+    fragments: Vec<Vec<u8>>,
+    offset_table: bool,
+) -> (Vec<DicomObject<'a>>, DicomObject<'a>) {
+    let sq_elem = |item: usize| -> Vec<SequenceElement> {
+        let mut sq_elem: SequenceElement = SequenceElement::new(
+            tags::PIXEL_DATA,
+            None,
+            &vr::OB,
+            ValueLength::UndefinedLength,
+            DEFAULT_CHARACTER_SET,
+        );
+        sq_elem.set_item(item);
+        vec![sq_elem]
+    };
+    let item = |index: usize, data: Vec<u8>| -> DicomObject<'a> {
+        DicomObject::new(DicomElement::new(
+            tags::ITEM,
+            &vr::UN,
+            ValueLength::Explicit(data.len() as u32),
+            &ts::ImplicitVRLittleEndian,
+            DEFAULT_CHARACTER_SET,
+            data,
+            sq_elem(index + 1),
+        ))
+    };
+
+    let mut offsets: Vec<u8> = Vec::new();
+    if offset_table {
+        let mut offset: u32 = 0;
+        for fragment in &fragments {
+            offsets.extend_from_slice(&offset.to_le_bytes());
+            offset += ITEM_HEADER_LEN as u32 + fragment.len() as u32;
+        }
+    }
+    let items: Vec<DicomObject> = std::iter::once(offsets)
+        .chain(fragments)
+        .enumerate()
+        .map(|(index, data)| item(index, data))
+        .collect();
+    let delimiter: DicomObject = DicomObject::new(DicomElement::new(
+        tags::SEQUENCE_DELIMITATION_ITEM,
+        &vr::UN,
+        ValueLength::Explicit(0),
+        &ts::ImplicitVRLittleEndian,
+        DEFAULT_CHARACTER_SET,
+        Vec::new(),
+        sq_elem(items.len()),
+    ));
+    (items, delimiter)
+}
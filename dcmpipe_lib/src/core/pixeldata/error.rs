@@ -0,0 +1,68 @@
+use thiserror::Error;
+
+use crate::core::{read::ParseError, write::error::WriteError};
+
+#[derive(Error, Debug)]
+/// Errors that can occur when decoding or encoding Pixel Data.
+pub enum PixelDataError {
+    /// The dataset does not contain Pixel Data, Float Pixel Data, or Double Float Pixel Data.
+    #[error("dataset has no pixel data")]
+    MissingPixelData,
+
+    /// An attribute of the Image Pixel module required for decoding is missing.
+    #[error("missing required attribute: {0}")]
+    MissingAttribute(&'static str),
+
+    /// Pixel Data is encapsulated in a transfer syntax which can't be decoded.
+    #[error("decoding pixel data of {0} is not supported")]
+    UnsupportedTransferSyntax(&'static str),
+
+    /// The Image Pixel attributes describe an encoding which can't be decoded.
+    #[error("unsupported pixel encoding: {0}")]
+    UnsupportedEncoding(String),
+
+    /// The Image Pixel attributes are inconsistent with each other, see `ImagePixel::validate()`.
+    #[error("invalid image pixel attributes: {0}")]
+    InvalidImagePixel(String),
+
+    /// The instances of a concatenation are incomplete or inconsistent with each other.
+    #[error("invalid concatenation: {0}")]
+    InvalidConcatenation(String),
+
+    /// The frames of a volume can't be placed in a regular grid, or its geometry is invalid.
+    #[error("invalid volume geometry: {0}")]
+    InvalidGeometry(String),
+
+    /// A NIfTI or MetaImage file is invalid or uses features which aren't supported.
+    #[error("invalid volume file: {0}")]
+    InvalidVolumeFile(String),
+
+    /// The requested frame is beyond the number of frames in the dataset.
+    #[error("frame {frame} is out of range, the dataset has {frames} frames")]
+    FrameOutOfRange { frame: usize, frames: usize },
+
+    /// Pixel Data is shorter than required by the Image Pixel attributes.
+    #[error("pixel data has {actual} bytes but {expected} are needed")]
+    InsufficientData { expected: usize, actual: usize },
+
+    /// The quality to encode with is outside of 1 to 100.
+    #[error("encoding quality {0} is not between 1 and 100")]
+    InvalidQuality(u8),
+
+    /// A compressed frame isn't a valid codestream for its transfer syntax.
+    #[error("invalid codestream: {0}")]
+    InvalidCodestream(String),
+
+    /// The encoder failed to compress a frame.
+    #[error("failed encoding frame {frame}: {reason}")]
+    EncodeFailed { frame: usize, reason: String },
+
+    /// Wrapper around `ParseError` for attribute values which can't be parsed.
+    #[error("failed parsing image pixel attribute")]
+    ParseError(#[from] ParseError),
+
+    /// Wrapper around `WriteError` for elements which can't be re-encoded into the transfer syntax
+    /// of the encoded Pixel Data.
+    #[error("failed re-encoding dataset")]
+    WriteError(#[from] WriteError),
+}
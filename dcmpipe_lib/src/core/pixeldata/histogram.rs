@@ -0,0 +1,154 @@
+//! Histograms of decoded sample values, and windows suggested from them.
+//!
+//! A window spanning the full range of values is often a poor choice for display, as a few outlying
+//! values, such as the padding around a CT reconstruction, compress the values of interest into a
+//! narrow range of grays. Windows are instead suggested from percentiles of the histogram, which
+//! ignore the outliers. This is useful for datasets with no VOI attributes, or whose window doesn't
+//! cover their values.
+
+use super::{PixelFrame, Window};
+
+/// The number of bins of histograms used to suggest windows, enough to resolve each value of 12-bit
+/// samples.
+pub const AUTO_WINDOW_BINS: usize = 4096;
+
+/// The percentiles of values spanned by a suggested window.
+pub const AUTO_WINDOW_PERCENTILES: (f64, f64) = (0.5, 99.5);
+
+/// The number of values in each of a number of equal-width bins spanning a range of values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Histogram {
+    min: f64,
+    max: f64,
+    counts: Vec<u64>,
+    total: u64,
+}
+
+impl Histogram {
+    /// Creates an empty histogram of the given number of bins spanning `min` to `max`. There's
+    /// always at least one bin, and the range is swapped if `max` is less than `min`.
+    pub fn new(min: f64, max: f64, bins: usize) -> Histogram {
+        Histogram {
+            min: min.min(max),
+            max: max.max(min),
+            counts: vec![0; bins.max(1)],
+            total: 0,
+        }
+    }
+
+    /// Creates a histogram of the values, spanning their range. Returns `None` if there are no
+    /// values other than NaN.
+    pub fn of_values(values: &[f64], bins: usize) -> Option<Histogram> {
+        let (min, max) = min_max(values.iter().copied())?;
+        let mut histogram: Histogram = Histogram::new(min, max, bins);
+        histogram.add(values);
+        Some(histogram)
+    }
+
+    /// Creates a histogram of the values of all frames, e.g. of a volume, spanning their range.
+    /// Returns `None` if the frames have no values other than NaN.
+    pub fn of_frames(frames: &[PixelFrame], bins: usize) -> Option<Histogram> {
+        let (min, max) = frames.iter().filter_map(PixelFrame::min_max).reduce(
+            |(min, max), (frame_min, frame_max)| (min.min(frame_min), max.max(frame_max)),
+        )?;
+        let mut histogram: Histogram = Histogram::new(min, max, bins);
+        for frame in frames {
+            histogram.add(frame.values());
+        }
+        Some(histogram)
+    }
+
+    /// Counts the values. Values outside of the histogram's range are counted in the first or last
+    /// bin, while NaN values are ignored.
+    pub fn add(&mut self, values: &[f64]) {
+        let bins: usize = self.counts.len();
+        let scale: f64 = if self.max > self.min {
+            bins as f64 / (self.max - self.min)
+        } else {
+            0.0
+        };
+        for value in values.iter().filter(|value| !value.is_nan()) {
+            let bin: f64 = ((value - self.min) * scale).floor();
+            self.counts[(bin.max(0.0) as usize).min(bins - 1)] += 1;
+            self.total += 1;
+        }
+    }
+
+    /// Adds the counts of another histogram with the same range and number of bins, e.g. to combine
+    /// the histograms of frames decoded separately. Returns `false`, leaving this histogram
+    /// unchanged, if the histograms aren't compatible.
+    pub fn merge(&mut self, other: &Histogram) -> bool {
+        if self.min != other.min || self.max != other.max || self.counts.len() != other.counts.len()
+        {
+            return false;
+        }
+        for (count, other) in self.counts.iter_mut().zip(&other.counts) {
+            *count += other;
+        }
+        self.total += other.total;
+        true
+    }
+
+    /// The lowest value of the first bin.
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// The highest value of the last bin.
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    /// The number of values in each bin, from lowest to highest.
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// The total number of values counted.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// The width of each bin.
+    pub fn bin_width(&self) -> f64 {
+        (self.max - self.min) / self.counts.len() as f64
+    }
+
+    /// The value below which the given percentage of values fall, from 0 to 100. The position of
+    /// the value within its bin is interpolated, assuming values are evenly distributed within the
+    /// bin. Returns `None` if the histogram is empty.
+    pub fn percentile(&self, percent: f64) -> Option<f64> {
+        if self.total == 0 {
+            return None;
+        }
+        let target: f64 = percent.clamp(0.0, 100.0) / 100.0 * self.total as f64;
+        let mut below: u64 = 0;
+        for (bin, count) in self.counts.iter().enumerate() {
+            if *count > 0 && (below + count) as f64 >= target {
+                let fraction: f64 = (target - below as f64) / *count as f64;
+                let value: f64 = self.min + (bin as f64 + fraction) * self.bin_width();
+                return Some(value.clamp(self.min, self.max));
+            }
+            below += count;
+        }
+        Some(self.max)
+    }
+
+    /// Suggests a window spanning the values between the given percentiles, see `percentile()`.
+    /// Returns `None` if the histogram is empty.
+    pub fn auto_window(&self, low: f64, high: f64) -> Option<Window> {
+        let low: f64 = self.percentile(low)?;
+        let high: f64 = self.percentile(high)?;
+        Some(Window::new((low + high) / 2.0 + 0.5, high - low + 1.0))
+    }
+}
+
+/// The minimum and maximum of the values, ignoring NaN, or `None` if there are no such values.
+pub(super) fn min_max(values: impl Iterator<Item = f64>) -> Option<(f64, f64)> {
+    values
+        .filter(|value| !value.is_nan())
+        .fold(None, |range, value| match range {
+            None => Some((value, value)),
+            Some((min, max)) => Some((min.min(value), max.max(value))),
+        })
+}
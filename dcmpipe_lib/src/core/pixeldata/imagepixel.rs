@@ -0,0 +1,218 @@
+//! The attributes of the Image Pixel module, see Part 3, Ch C.7.6.3.
+
+use crate::core::{
+    dcmelement::DicomElement,
+    dcmobject::DicomRoot,
+    defn::{
+        constants::tags,
+        tag::{TagNode, TagPath},
+        vr::{self, VRRef},
+    },
+    read::ParseError,
+    values::RawValue,
+};
+
+use super::{
+    get_u16, number_of_frames, PixelDataError, PixelDataResult, BITS_ALLOCATED, BITS_STORED,
+    COLUMNS, HIGH_BIT, NUMBER_OF_FRAMES, PHOTOMETRIC_INTERPRETATION, PIXEL_REPRESENTATION,
+    PLANAR_CONFIGURATION, ROWS, SAMPLES_PER_PIXEL,
+};
+
+/// The attributes of the Image Pixel module which describe how Pixel Data is encoded, along with
+/// the Number of Frames.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImagePixel {
+    pub rows: u16,
+    pub columns: u16,
+    pub samples_per_pixel: u16,
+    /// The Photometric Interpretation, without padding, e.g. `MONOCHROME2` or `RGB`.
+    pub photometric_interpretation: String,
+    /// 0 if the samples of each pixel are interleaved or 1 if samples are stored by plane. Only
+    /// present when there's more than one sample per pixel.
+    pub planar_configuration: Option<u16>,
+    pub bits_allocated: u16,
+    pub bits_stored: u16,
+    pub high_bit: u16,
+    /// 0 for unsigned integer samples or 1 for two's complement.
+    pub pixel_representation: u16,
+    pub number_of_frames: usize,
+}
+
+impl ImagePixel {
+    /// Reads the Image Pixel attributes of the dataset. The attributes are not validated, see
+    /// `validate()`.
+    ///
+    /// High Bit defaults to one less than Bits Stored if missing. Datasets with Float Pixel Data or
+    /// Double Float Pixel Data don't have Bits Stored, High Bit, or Pixel Representation, which are
+    /// then treated as storing all bits allocated, unsigned.
+    pub fn from_dataset(dcmroot: &DicomRoot<'_>) -> PixelDataResult<ImagePixel> {
+        let is_float: bool = [tags::FLOAT_PIXEL_DATA, tags::DOUBLE_PIXEL_DATA]
+            .into_iter()
+            .any(|tag| dcmroot.get_child_by_tag(tag).is_some());
+
+        let bits_allocated: u16 = get_u16(dcmroot, BITS_ALLOCATED, "BitsAllocated")?;
+        let (bits_stored, pixel_representation) = if is_float {
+            (bits_allocated, 0)
+        } else {
+            (
+                get_u16(dcmroot, BITS_STORED, "BitsStored")?,
+                get_u16(dcmroot, PIXEL_REPRESENTATION, "PixelRepresentation")?,
+            )
+        };
+        let high_bit: u16 = match dcmroot.get_child_by_tag(HIGH_BIT) {
+            Some(obj) if !is_float => u16::try_from(obj.element())?,
+            _ => bits_stored.saturating_sub(1),
+        };
+        let photometric_interpretation: String = dcmroot
+            .get_child_by_tag(PHOTOMETRIC_INTERPRETATION)
+            .ok_or(PixelDataError::MissingAttribute(
+                "PhotometricInterpretation",
+            ))
+            .and_then(|obj| Ok(String::try_from(obj.element())?))?
+            .trim()
+            .to_owned();
+        let planar_configuration: Option<u16> = dcmroot
+            .get_child_by_tag(PLANAR_CONFIGURATION)
+            .map(|obj| u16::try_from(obj.element()))
+            .transpose()?;
+
+        Ok(ImagePixel {
+            rows: get_u16(dcmroot, ROWS, "Rows")?,
+            columns: get_u16(dcmroot, COLUMNS, "Columns")?,
+            samples_per_pixel: get_u16(dcmroot, SAMPLES_PER_PIXEL, "SamplesPerPixel")?,
+            photometric_interpretation,
+            planar_configuration,
+            bits_allocated,
+            bits_stored,
+            high_bit,
+            pixel_representation,
+            number_of_frames: number_of_frames(dcmroot)?,
+        })
+    }
+
+    /// Checks the attributes are consistent with each other as required by Part 3,
+    /// Ch C.7.6.3.1. This doesn't check whether the encoding can be decoded.
+    pub fn validate(&self) -> PixelDataResult<()> {
+        let invalid = |reason: String| Err(PixelDataError::InvalidImagePixel(reason));
+        if self.rows == 0 || self.columns == 0 {
+            return invalid(format!("{}x{} pixels", self.columns, self.rows));
+        }
+        if self.number_of_frames == 0 {
+            return invalid("no frames".to_owned());
+        }
+        let expected_samples: Option<u16> = match self.photometric_interpretation.as_str() {
+            "MONOCHROME1" | "MONOCHROME2" | "PALETTE COLOR" => Some(1),
+            "RGB" | "HSV" | "YBR_FULL" | "YBR_FULL_422" | "YBR_PARTIAL_422" | "YBR_PARTIAL_420"
+            | "YBR_ICT" | "YBR_RCT" => Some(3),
+            "ARGB" | "CMYK" => Some(4),
+            _ => None,
+        };
+        match expected_samples {
+            None => {
+                return invalid(format!(
+                    "unknown photometric interpretation {}",
+                    self.photometric_interpretation
+                ))
+            }
+            Some(samples) if samples != self.samples_per_pixel => {
+                return invalid(format!(
+                    "{} with {} samples per pixel",
+                    self.photometric_interpretation, self.samples_per_pixel
+                ))
+            }
+            Some(_) => {}
+        }
+        match (self.samples_per_pixel, self.planar_configuration) {
+            (1, _) | (_, Some(0 | 1)) => {}
+            (_, None) => return invalid("missing planar configuration".to_owned()),
+            (_, Some(other)) => return invalid(format!("planar configuration {other}")),
+        }
+        if self.bits_allocated != 1 && !self.bits_allocated.is_multiple_of(8) {
+            return invalid(format!("{} bits allocated", self.bits_allocated));
+        }
+        if self.bits_stored == 0 || self.bits_stored > self.bits_allocated {
+            return invalid(format!(
+                "{} bits stored in {} bits allocated",
+                self.bits_stored, self.bits_allocated
+            ));
+        }
+        if u32::from(self.high_bit) + 1 != u32::from(self.bits_stored) {
+            return invalid(format!(
+                "high bit {} with {} bits stored",
+                self.high_bit, self.bits_stored
+            ));
+        }
+        if self.pixel_representation > 1 {
+            return invalid(format!(
+                "pixel representation {}",
+                self.pixel_representation
+            ));
+        }
+        Ok(())
+    }
+
+    /// Whether samples are signed, in two's complement.
+    pub fn is_signed(&self) -> bool {
+        self.pixel_representation == 1
+    }
+
+    /// Whether samples are stored by plane rather than interleaved by pixel.
+    pub fn is_planar(&self) -> bool {
+        self.samples_per_pixel > 1 && self.planar_configuration == Some(1)
+    }
+
+    /// The number of bytes of Pixel Data for each frame.
+    pub fn frame_len(&self) -> usize {
+        let samples: usize = usize::from(self.rows)
+            * usize::from(self.columns)
+            * usize::from(self.samples_per_pixel);
+        (samples * usize::from(self.bits_allocated)).div_ceil(8)
+    }
+
+    /// Sets the Image Pixel attributes of the dataset, replacing any existing values. Number of
+    /// Frames is only set if there's more than one frame or the dataset already has it, and
+    /// Planar Configuration is removed if not present here.
+    pub fn insert_into(&self, dcmroot: &mut DicomRoot<'_>, stage: &str) -> Result<(), ParseError> {
+        let us = |value: u16| RawValue::UnsignedShorts(vec![value]);
+        let mut elements: Vec<(u32, VRRef, RawValue)> = vec![
+            (SAMPLES_PER_PIXEL, &vr::US, us(self.samples_per_pixel)),
+            (
+                PHOTOMETRIC_INTERPRETATION,
+                &vr::CS,
+                RawValue::Strings(vec![self.photometric_interpretation.clone()]),
+            ),
+            (ROWS, &vr::US, us(self.rows)),
+            (COLUMNS, &vr::US, us(self.columns)),
+            (BITS_ALLOCATED, &vr::US, us(self.bits_allocated)),
+            (BITS_STORED, &vr::US, us(self.bits_stored)),
+            (HIGH_BIT, &vr::US, us(self.high_bit)),
+            (PIXEL_REPRESENTATION, &vr::US, us(self.pixel_representation)),
+        ];
+        match self.planar_configuration {
+            Some(planar_configuration) => {
+                elements.push((PLANAR_CONFIGURATION, &vr::US, us(planar_configuration)));
+            }
+            None => {
+                dcmroot.remove_element(&tagpath(PLANAR_CONFIGURATION));
+            }
+        }
+        if self.number_of_frames > 1 || dcmroot.get_child_by_tag(NUMBER_OF_FRAMES).is_some() {
+            elements.push((
+                NUMBER_OF_FRAMES,
+                &vr::IS,
+                RawValue::Strings(vec![self.number_of_frames.to_string()]),
+            ));
+        }
+
+        for (tag, vr, value) in elements {
+            let mut element: DicomElement = DicomElement::new_empty(tag, vr, dcmroot.ts());
+            element.encode_value(value, None)?;
+            dcmroot.insert_element(&tagpath(tag), element, stage)?;
+        }
+        Ok(())
+    }
+}
+
+fn tagpath(tag: u32) -> TagPath {
+    TagPath::from(vec![TagNode::from(tag)])
+}
@@ -0,0 +1,259 @@
+//! Inspection of JPEG 2000 codestreams, including High-Throughput JPEG 2000 (HTJ2K), see ISO/IEC
+//! 15444-1 Annex A and ISO/IEC 15444-15.
+//!
+//! The main header of a codestream describes the image it encodes, which is read here without
+//! decoding the image. This identifies whether a frame uses the HT block coder, and allows checking
+//! a frame is consistent with the dataset's Image Pixel attributes before handing it to a decoder.
+//!
+//! Decoding and encoding JPEG 2000, including HTJ2K, is not supported by this library. Frames of
+//! these transfer syntaxes can only be decoded by registering a `PixelCodec` for them from another
+//! crate, e.g. one wrapping OpenJPEG, see `codec`.
+
+use crate::core::defn::ts::TSRef;
+
+use super::{ImagePixel, PixelDataError, PixelDataResult};
+
+/// Start of codestream.
+const SOC: u16 = 0xFF4F;
+/// Image and tile size.
+const SIZ: u16 = 0xFF51;
+/// Extended capabilities, which identifies HTJ2K codestreams.
+const CAP: u16 = 0xFF50;
+/// Coding style default.
+const COD: u16 = 0xFF52;
+/// Start of tile-part, ending the main header.
+const SOT: u16 = 0xFF90;
+
+/// The bit of `Pcap` in the CAP marker segment indicating the codestream uses capabilities of
+/// Part 15, the HT block coder.
+const PCAP_PART_15: u32 = 1 << (32 - 15);
+
+/// The bit of the code-block style in the COD marker segment indicating HT code-blocks.
+const CODE_BLOCK_STYLE_HT: u8 = 0x40;
+
+/// The UIDs of the HTJ2K transfer syntaxes: Lossless, Lossless RPCL, and lossy.
+static HTJ2K_TS_UIDS: [&str; 3] = [
+    "1.2.840.10008.1.2.4.201",
+    "1.2.840.10008.1.2.4.202",
+    "1.2.840.10008.1.2.4.203",
+];
+
+/// The UIDs of the JPEG 2000 transfer syntaxes of Part 1 and Part 2 (Multi-component), each
+/// Lossless Only and lossy.
+static J2K_TS_UIDS: [&str; 4] = [
+    "1.2.840.10008.1.2.4.90",
+    "1.2.840.10008.1.2.4.91",
+    "1.2.840.10008.1.2.4.92",
+    "1.2.840.10008.1.2.4.93",
+];
+
+/// Whether the transfer syntax is one of the HTJ2K transfer syntaxes, whose frames are encoded as
+/// HTJ2K codestreams.
+pub fn is_htj2k(ts: TSRef) -> bool {
+    HTJ2K_TS_UIDS.contains(&ts.uid().uid())
+}
+
+/// Whether the transfer syntax encodes frames as JPEG 2000 codestreams, including HTJ2K.
+pub fn is_j2k(ts: TSRef) -> bool {
+    is_htj2k(ts) || J2K_TS_UIDS.contains(&ts.uid().uid())
+}
+
+/// A component of the image encoded by a codestream.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct J2kComponent {
+    /// The number of bits of each sample.
+    pub bits: u8,
+    /// Whether samples are signed.
+    pub signed: bool,
+    /// The horizontal subsampling of the component.
+    pub dx: u8,
+    /// The vertical subsampling of the component.
+    pub dy: u8,
+}
+
+/// The properties of an image read from the main header of a JPEG 2000 codestream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct J2kHeader {
+    /// The width of the image, in pixels.
+    pub width: u32,
+    /// The height of the image, in pixels.
+    pub height: u32,
+    pub components: Vec<J2kComponent>,
+    /// Whether code-blocks use the HT block coder of Part 15.
+    pub high_throughput: bool,
+    /// Whether the reversible 5-3 wavelet transform is used, which is required for lossless
+    /// encoding, rather than the irreversible 9-7 transform.
+    pub reversible: bool,
+    /// Whether a multiple component transform is applied to the first three components.
+    pub multiple_component_transform: bool,
+    /// The number of wavelet decomposition levels.
+    pub decomposition_levels: u8,
+    /// The number of quality layers.
+    pub layers: u16,
+}
+
+impl J2kHeader {
+    /// Reads the main header of the codestream, which must start with the SOC marker.
+    pub fn parse(codestream: &[u8]) -> PixelDataResult<J2kHeader> {
+        let mut reader = MarkerReader {
+            data: codestream,
+            pos: 0,
+        };
+        if reader.u16()? != SOC {
+            return Err(invalid("missing start of codestream marker"));
+        }
+
+        let mut siz: Option<(u32, u32, Vec<J2kComponent>)> = None;
+        let mut cod: Option<(u16, bool, u8, u8, u8)> = None;
+        let mut pcap: u32 = 0;
+        loop {
+            let marker: u16 = reader.u16()?;
+            if marker == SOT {
+                break;
+            }
+            if marker >> 8 != 0xFF {
+                return Err(invalid(&format!("expected marker at {}", reader.pos - 2)));
+            }
+            let len: usize = usize::from(reader.u16()?);
+            // The length includes the two bytes of the length itself.
+            let segment_len: usize = len
+                .checked_sub(2)
+                .ok_or_else(|| invalid(&format!("marker {marker:04X} has length {len}")))?;
+            let mut segment = MarkerReader {
+                data: reader.take(segment_len)?,
+                pos: 0,
+            };
+            match marker {
+                SIZ => siz = Some(parse_siz(&mut segment)?),
+                COD => {
+                    let _scod: u8 = segment.u8()?;
+                    let _progression: u8 = segment.u8()?;
+                    let layers: u16 = segment.u16()?;
+                    let mct: bool = segment.u8()? != 0;
+                    let levels: u8 = segment.u8()?;
+                    let _cb_width: u8 = segment.u8()?;
+                    let _cb_height: u8 = segment.u8()?;
+                    let cb_style: u8 = segment.u8()?;
+                    let transform: u8 = segment.u8()?;
+                    cod = Some((layers, mct, levels, cb_style, transform));
+                }
+                CAP => pcap = segment.u32()?,
+                _ => {}
+            }
+        }
+
+        let (width, height, components) = siz.ok_or_else(|| invalid("missing SIZ marker"))?;
+        let (layers, mct, levels, cb_style, transform) =
+            cod.ok_or_else(|| invalid("missing COD marker"))?;
+        Ok(J2kHeader {
+            width,
+            height,
+            components,
+            high_throughput: pcap & PCAP_PART_15 != 0 || cb_style & CODE_BLOCK_STYLE_HT != 0,
+            reversible: transform == 1,
+            multiple_component_transform: mct,
+            decomposition_levels: levels,
+            layers,
+        })
+    }
+
+    /// Checks the codestream encodes an image as described by the Image Pixel attributes, i.e. its
+    /// dimensions, number of samples, and their bits and sign.
+    pub fn check_image_pixel(&self, image: &ImagePixel) -> PixelDataResult<()> {
+        let mismatch = |what: String| Err(PixelDataError::InvalidImagePixel(what));
+        if self.width != u32::from(image.columns) || self.height != u32::from(image.rows) {
+            return mismatch(format!(
+                "codestream is {}x{} but the image is {}x{}",
+                self.width, self.height, image.columns, image.rows
+            ));
+        }
+        if self.components.len() != usize::from(image.samples_per_pixel) {
+            return mismatch(format!(
+                "codestream has {} components but the image has {} samples per pixel",
+                self.components.len(),
+                image.samples_per_pixel
+            ));
+        }
+        for component in &self.components {
+            if u16::from(component.bits) != image.bits_stored
+                || component.signed != image.is_signed()
+            {
+                return mismatch(format!(
+                    "codestream has {} bit{} samples but the image has {} bits stored{}",
+                    component.bits,
+                    if component.signed { " signed" } else { "" },
+                    image.bits_stored,
+                    if image.is_signed() { ", signed" } else { "" }
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses the SIZ marker segment following its length, returning the image width, height, and
+/// components. The image area is the reference grid less its offset.
+fn parse_siz(segment: &mut MarkerReader<'_>) -> PixelDataResult<(u32, u32, Vec<J2kComponent>)> {
+    let _rsiz: u16 = segment.u16()?;
+    let xsiz: u32 = segment.u32()?;
+    let ysiz: u32 = segment.u32()?;
+    let x_offset: u32 = segment.u32()?;
+    let y_offset: u32 = segment.u32()?;
+    // Tile size and offset.
+    segment.take(16)?;
+    let count: u16 = segment.u16()?;
+    let components: Vec<J2kComponent> = (0..count)
+        .map(|_| {
+            let ssiz: u8 = segment.u8()?;
+            Ok(J2kComponent {
+                bits: (ssiz & 0x7F) + 1,
+                signed: ssiz & 0x80 != 0,
+                dx: segment.u8()?,
+                dy: segment.u8()?,
+            })
+        })
+        .collect::<PixelDataResult<Vec<J2kComponent>>>()?;
+    Ok((
+        xsiz.saturating_sub(x_offset),
+        ysiz.saturating_sub(y_offset),
+        components,
+    ))
+}
+
+/// Reads big-endian values from a codestream.
+struct MarkerReader<'d> {
+    data: &'d [u8],
+    pos: usize,
+}
+
+impl<'d> MarkerReader<'d> {
+    fn take(&mut self, len: usize) -> PixelDataResult<&'d [u8]> {
+        let bytes: &[u8] =
+            self.data
+                .get(self.pos..self.pos + len)
+                .ok_or(PixelDataError::InsufficientData {
+                    expected: self.pos + len,
+                    actual: self.data.len(),
+                })?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> PixelDataResult<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> PixelDataResult<u16> {
+        let bytes: &[u8] = self.take(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> PixelDataResult<u32> {
+        let bytes: &[u8] = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
+
+fn invalid(reason: &str) -> PixelDataError {
+    PixelDataError::InvalidCodestream(reason.to_owned())
+}
@@ -0,0 +1,216 @@
+//! Encoding of native Pixel Data as JPEG Baseline (Process 1), a lossy compression, see Part 5,
+//! Ch 8.2.1 and Ch A.4.1.
+//!
+//! The dataset is re-encoded in place into the JPEG Baseline transfer syntax, with each frame
+//! compressed into a fragment of encapsulated Pixel Data. The attributes describing lossy
+//! compression are updated so the dataset records that it's no longer the original pixel values,
+//! see Part 3, Ch C.7.6.1.1.5.
+
+use jpeg_encoder::{ColorType, Encoder, SamplingFactor};
+
+use crate::{
+    core::{
+        dcmelement::DicomElement,
+        dcmobject::DicomRoot,
+        defn::{
+            constants::tags as coretags,
+            tag::{TagNode, TagPath},
+            ts::TSRef,
+            vr::{self, VRRef},
+        },
+        values::RawValue,
+    },
+    dict::{tags, transfer_syntaxes as ts},
+};
+
+use super::{
+    encapsulated::insert_encapsulated, interleave_planes, pixel_data_tag, FrameLayout, ImagePixel,
+    Photometric, PixelDataError, PixelDataResult,
+};
+
+/// The value of Lossy Image Compression Method for JPEG Baseline, see Part 3, Ch C.7.6.1.1.5.1.
+const JPEG_BASELINE_METHOD: &str = "ISO_10918_1";
+
+/// Options for encoding Pixel Data with `encode_jpeg_baseline()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JpegOptions {
+    /// The quality of the compressed image from 1 to 100, where higher values retain more detail
+    /// at the cost of a lower compression ratio.
+    pub quality: u8,
+
+    /// Whether the chroma of color images is subsampled horizontally, encoding them as
+    /// `YBR_FULL_422` rather than `YBR_FULL`. This has no effect on monochrome images.
+    pub subsample_chroma: bool,
+}
+
+impl Default for JpegOptions {
+    fn default() -> Self {
+        JpegOptions {
+            quality: 90,
+            subsample_chroma: true,
+        }
+    }
+}
+
+/// Compresses the dataset's Pixel Data as JPEG Baseline, re-encoding the dataset into the JPEG
+/// Baseline (Process 1) transfer syntax. Only 8-bit unsigned MONOCHROME1, MONOCHROME2, RGB, and
+/// YBR_FULL Pixel Data of a native transfer syntax can be encoded.
+///
+/// Photometric Interpretation of color images becomes `YBR_FULL_422` or `YBR_FULL`, depending on
+/// `JpegOptions::subsample_chroma`, with Planar Configuration of 0. Lossy Image Compression is set
+/// to `01`, with the compression ratio and method appended to those of any prior lossy
+/// compression. When the Pixel Data wasn't previously lossy compressed, the compression is
+/// described in Derivation Description, following any prior description.
+///
+/// As the pixel values change, the caller should also assign the dataset a new SOP Instance UID.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip(dcmroot), fields(ts = dcmroot.ts().uid().name()))
+)]
+pub fn encode_jpeg_baseline(
+    dcmroot: &mut DicomRoot<'_>,
+    options: &JpegOptions,
+    stage: &str,
+) -> PixelDataResult<()> {
+    if !(1..=100).contains(&options.quality) {
+        return Err(PixelDataError::InvalidQuality(options.quality));
+    }
+    let tag: u32 = pixel_data_tag(dcmroot)?;
+    if tag != coretags::PIXEL_DATA {
+        return Err(PixelDataError::UnsupportedEncoding(
+            "encoding float pixel data as JPEG".to_owned(),
+        ));
+    }
+    let pixel_data: &DicomElement = dcmroot
+        .get_child_by_tag(tag)
+        .map(|obj| obj.element())
+        .ok_or(PixelDataError::MissingPixelData)?;
+    let layout: FrameLayout = FrameLayout::read(dcmroot, pixel_data)?;
+    if layout.bits_allocated != 8 || layout.bits_stored != 8 || layout.is_signed {
+        return Err(PixelDataError::UnsupportedEncoding(format!(
+            "encoding {} bits stored in {} bits allocated{} as JPEG Baseline",
+            layout.bits_stored,
+            layout.bits_allocated,
+            if layout.is_signed { ", signed," } else { "" }
+        )));
+    }
+
+    let is_color: bool = layout.photometric == Photometric::Rgb;
+    let color_type: ColorType = match (is_color, layout.is_ybr) {
+        (false, _) => ColorType::Luma,
+        (true, false) => ColorType::Rgb,
+        (true, true) => ColorType::Ycbcr,
+    };
+    let sampling_factor: SamplingFactor = if is_color && options.subsample_chroma {
+        SamplingFactor::F_2_1
+    } else {
+        SamplingFactor::F_1_1
+    };
+
+    let mut fragments: Vec<Vec<u8>> = Vec::with_capacity(layout.frames);
+    for frame in 0..layout.frames {
+        let data: &[u8] = layout.frame_bytes(pixel_data.data(), frame)?;
+        let interleaved: Vec<u8>;
+        let data: &[u8] = if layout.is_planar {
+            interleaved = interleave_planes(data, layout.samples_per_pixel);
+            &interleaved
+        } else {
+            data
+        };
+
+        let mut fragment: Vec<u8> = Vec::new();
+        let mut encoder = Encoder::new(&mut fragment, options.quality);
+        encoder.set_sampling_factor(sampling_factor);
+        encoder
+            .encode(data, layout.columns as u16, layout.rows as u16, color_type)
+            .map_err(|e| PixelDataError::EncodeFailed {
+                frame,
+                reason: e.to_string(),
+            })?;
+        fragments.push(fragment);
+    }
+
+    let native_len: usize = layout.frames * layout.frame_len();
+    let compressed_len: usize = fragments.iter().map(Vec::len).sum();
+    let ratio: f64 = native_len as f64 / compressed_len.max(1) as f64;
+
+    let mut image: ImagePixel = ImagePixel::from_dataset(dcmroot)?;
+    if is_color {
+        image.photometric_interpretation = if options.subsample_chroma {
+            "YBR_FULL_422"
+        } else {
+            "YBR_FULL"
+        }
+        .to_owned();
+        image.planar_configuration = Some(0);
+    }
+    let was_lossy: bool = get_strings(dcmroot, tags::LossyImageCompression.tag)
+        .first()
+        .is_some_and(|value| value == "01");
+    let (mut ratios, mut methods) = if was_lossy {
+        (
+            get_strings(dcmroot, tags::LossyImageCompressionRatio.tag),
+            get_strings(dcmroot, tags::LossyImageCompressionMethod.tag),
+        )
+    } else {
+        (Vec::new(), Vec::new())
+    };
+    let derivation: Vec<String> = get_strings(dcmroot, tags::DerivationDescription.tag);
+    ratios.push(format_ratio(ratio));
+    methods.push(JPEG_BASELINE_METHOD.to_owned());
+
+    let to: TSRef = &ts::JPEGBaselineProcess1;
+    dcmroot.transcode_in_place(to)?;
+    image.insert_into(dcmroot, stage)?;
+
+    let mut attributes: Vec<(u32, VRRef, Vec<String>)> = vec![
+        (
+            tags::LossyImageCompression.tag,
+            &vr::CS,
+            vec!["01".to_owned()],
+        ),
+        (tags::LossyImageCompressionRatio.tag, &vr::DS, ratios),
+        (tags::LossyImageCompressionMethod.tag, &vr::CS, methods),
+    ];
+    if !was_lossy {
+        let mut description: String = format!(
+            "Lossy compressed as JPEG Baseline at quality {}, ratio {}",
+            options.quality,
+            format_ratio(ratio)
+        );
+        // Any prior derivation, e.g. from another image, is retained.
+        if let Some(prior) = derivation.first() {
+            description = format!("{prior}; {description}");
+        }
+        attributes.push((tags::DerivationDescription.tag, &vr::ST, vec![description]));
+    }
+    for (tag, vr, values) in attributes {
+        let mut element: DicomElement = DicomElement::new_empty(tag, vr, to);
+        element.encode_value(RawValue::Strings(values), None)?;
+        dcmroot.insert_element(&tagpath(tag), element, stage)?;
+    }
+
+    let offset_table: bool = fragments.len() > 1;
+    insert_encapsulated(dcmroot, fragments, offset_table, stage)
+}
+
+/// Gets the trimmed values of an optional string attribute, or none if it's absent or invalid.
+fn get_strings(dcmroot: &DicomRoot<'_>, tag: u32) -> Vec<String> {
+    dcmroot
+        .get_child_by_tag(tag)
+        .and_then(|obj| Vec::<String>::try_from(obj.element()).ok())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|value| value.trim().to_owned())
+        .filter(|value| !value.is_empty())
+        .collect()
+}
+
+/// Formats a compression ratio as a DS value.
+fn format_ratio(ratio: f64) -> String {
+    format!("{ratio:.2}")
+}
+
+fn tagpath(tag: u32) -> TagPath {
+    TagPath::from(vec![TagNode::from(tag)])
+}
@@ -0,0 +1,181 @@
+//! Encoding and decoding of volumes as MetaImage, a text header (`.mhd`) naming a file of raw voxel
+//! data (`.raw`), as read by ITK and its tools.
+//!
+//! MetaImage positions voxels in the DICOM patient coordinate system (LPS), so the geometry of the
+//! volume is written unchanged.
+
+use super::{
+    volume::{decode_voxels, StoredType, Volume, VoxelType},
+    PixelDataError, PixelDataResult,
+};
+
+/// Creates the header of the volume, whose voxel data is in the given file, relative to the
+/// header. The voxel data is encoded with `encode_raw()`.
+pub fn header(volume: &Volume, data_file: &str) -> String {
+    let element_type: &str = match volume.voxel_type() {
+        VoxelType::I16 => "MET_SHORT",
+        VoxelType::I32 => "MET_INT",
+        VoxelType::F32 => "MET_FLOAT",
+    };
+    let join = |values: &[f64]| {
+        values
+            .iter()
+            .map(f64::to_string)
+            .collect::<Vec<String>>()
+            .join(" ")
+    };
+    let direction: Vec<f64> = volume.direction().into_iter().flatten().collect();
+    let dimensions: Vec<String> = volume.dimensions().iter().map(usize::to_string).collect();
+
+    [
+        "ObjectType = Image".to_owned(),
+        "NDims = 3".to_owned(),
+        "BinaryData = True".to_owned(),
+        "BinaryDataByteOrderMSB = False".to_owned(),
+        "CompressedData = False".to_owned(),
+        // The direction of each axis in turn.
+        format!("TransformMatrix = {}", join(&direction)),
+        format!("Offset = {}", join(&volume.origin())),
+        "CenterOfRotation = 0 0 0".to_owned(),
+        format!("AnatomicalOrientation = {}", orientation(volume)),
+        format!("ElementSpacing = {}", join(&volume.spacing())),
+        format!("DimSize = {}", dimensions.join(" ")),
+        format!("ElementType = {element_type}"),
+        format!("ElementDataFile = {data_file}"),
+    ]
+    .map(|line| line + "\n")
+    .concat()
+}
+
+/// Encodes the voxel data of the volume, in little endian with voxels of its
+/// `Volume::voxel_type()`.
+pub fn encode_raw(volume: &Volume) -> Vec<u8> {
+    volume.encode_values(volume.voxel_type())
+}
+
+/// The file of the voxel data named by a header, relative to the header.
+pub fn data_file(header: &str) -> PixelDataResult<String> {
+    fields(header)
+        .find(|(key, _)| *key == "ElementDataFile")
+        .map(|(_, value)| value.to_owned())
+        .filter(|value| value != "LOCAL" && !value.is_empty())
+        .ok_or_else(|| {
+            PixelDataError::InvalidVolumeFile(
+                "MetaImage header must name a separate ElementDataFile".to_owned(),
+            )
+        })
+}
+
+/// Decodes a 3D MetaImage volume from its header and uncompressed voxel data, see `data_file()`.
+pub fn decode(header: &str, raw: &[u8]) -> PixelDataResult<Volume> {
+    let invalid = |reason: String| PixelDataError::InvalidVolumeFile(format!("MetaImage {reason}"));
+    let field = |key: &str| {
+        fields(header)
+            .find(|(k, _)| *k == key)
+            .map(|(_, value)| value)
+    };
+    let numbers = |key: &str, len: usize, default: Vec<f64>| -> PixelDataResult<Vec<f64>> {
+        let Some(value) = field(key) else {
+            return Ok(default);
+        };
+        let numbers: Vec<f64> = value
+            .split_whitespace()
+            .map(str::parse::<f64>)
+            .collect::<Result<_, _>>()
+            .map_err(|_| invalid(format!("{key} {value}")))?;
+        if numbers.len() != len {
+            return Err(invalid(format!("{key} {value}")));
+        }
+        Ok(numbers)
+    };
+    let is_true = |key: &str| field(key).is_some_and(|value| value.eq_ignore_ascii_case("True"));
+
+    if field("NDims") != Some("3") {
+        return Err(invalid("volume must have 3 dimensions".to_owned()));
+    }
+    if is_true("CompressedData") {
+        return Err(invalid("compressed data is not supported".to_owned()));
+    }
+    if field("ElementNumberOfChannels").is_some_and(|channels| channels != "1") {
+        return Err(invalid("multiple channels are not supported".to_owned()));
+    }
+    let stored: StoredType = match field("ElementType") {
+        Some("MET_UCHAR") => StoredType::U8,
+        Some("MET_CHAR") => StoredType::I8,
+        Some("MET_USHORT") => StoredType::U16,
+        Some("MET_SHORT") => StoredType::I16,
+        Some("MET_UINT") => StoredType::U32,
+        Some("MET_INT") => StoredType::I32,
+        Some("MET_FLOAT") => StoredType::F32,
+        Some("MET_DOUBLE") => StoredType::F64,
+        element_type => return Err(invalid(format!("ElementType {element_type:?}"))),
+    };
+    let big_endian: bool = is_true("BinaryDataByteOrderMSB") || is_true("ElementByteOrderMSB");
+
+    let sizes: Vec<f64> = numbers("DimSize", 3, Vec::new())?;
+    if sizes.is_empty() {
+        return Err(invalid("DimSize is missing".to_owned()));
+    }
+    let mut dimensions: [usize; 3] = [0; 3];
+    for (dimension, size) in dimensions.iter_mut().zip(sizes) {
+        if !(size >= 1.0 && size.fract() == 0.0) {
+            return Err(invalid(format!("DimSize {size}")));
+        }
+        *dimension = size as usize;
+    }
+    let spacing: Vec<f64> = numbers("ElementSpacing", 3, vec![1.0; 3])?;
+    let offset: Vec<f64> = match field("Offset") {
+        Some(_) => numbers("Offset", 3, Vec::new())?,
+        None => numbers("Position", 3, vec![0.0; 3])?,
+    };
+    let identity: Vec<f64> = vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+    let matrix: Vec<f64> = match field("TransformMatrix") {
+        Some(_) => numbers("TransformMatrix", 9, Vec::new())?,
+        None => numbers("Orientation", 9, identity)?,
+    };
+
+    let values: Vec<f64> = decode_voxels(raw, stored, big_endian, dimensions.iter().product())?;
+    // The matrix holds the direction of each axis in turn.
+    let affine: [[f64; 4]; 3] = std::array::from_fn(|row| {
+        [
+            matrix[row] * spacing[0],
+            matrix[3 + row] * spacing[1],
+            matrix[6 + row] * spacing[2],
+            offset[row],
+        ]
+    });
+    Volume::from_affine(dimensions, affine, values)
+}
+
+/// The `key = value` fields of a header.
+fn fields(header: &str) -> impl Iterator<Item = (&str, &str)> {
+    header.lines().filter_map(|line| {
+        line.split_once('=')
+            .map(|(key, value)| (key.trim(), value.trim()))
+    })
+}
+
+/// The anatomical direction each axis most closely points from, as written by ITK, e.g. `RAI`
+/// when columns increase towards the patient's left, rows towards posterior, and slices towards
+/// the head.
+fn orientation(volume: &Volume) -> String {
+    volume
+        .direction()
+        .iter()
+        .map(|axis| {
+            let (index, component) = axis
+                .iter()
+                .enumerate()
+                .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+                .unwrap_or((0, &0.0));
+            match (index, *component >= 0.0) {
+                (0, true) => 'R',
+                (0, false) => 'L',
+                (1, true) => 'A',
+                (1, false) => 'P',
+                (_, true) => 'I',
+                (_, false) => 'S',
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,755 @@
+//! Decoding of native (uncompressed) Pixel Data into sample values.
+//!
+//! Frames are decoded using the attributes of the Image Pixel module, see Part 3, Ch C.7.6.3.
+//! Monochrome samples have the rescale slope and intercept of the Modality LUT applied, which
+//! Enhanced multi-frame objects share between frames in a functional group, and can be
+//! mapped to display values with a VOI `Window`. Many frames are decoded with a `FrameDecoder`,
+//! which decodes them in parallel with the `parallel` feature. Windows suited to the values of a
+//! frame or volume can be suggested from their histogram, see `histogram`. Regions of each frame
+//! can also be redacted, e.g. to remove identifying information burned into the image. Pixel Data
+//! of encapsulated (compressed) transfer syntaxes can only be decoded with a codec provided by
+//! another crate, see `codec`, though the compressed frames can be extracted, see `encapsulated`,
+//! and JPEG 2000 frames inspected, see `j2k`. Encapsulated video can be extracted or ingested
+//! without decoding it, see `video`. Tiles of whole slide images are located and decoded one at a
+//! time, see `wsi`, as are the frames of objects split into a concatenation, see `concatenation`.
+//! Native Pixel Data can be compressed as JPEG Baseline with the `jpeg` feature, see `jpeg`, or
+//! with a provided codec. Monochrome frames can also be displayed through the dataset's VOI LUTs,
+//! see `voilut`, with its overlay planes burned in, see `overlay`. The frames of a series can be
+//! assembled into a volume, see `volume`, and written as NIfTI, see `nifti`, or MetaImage, see
+//! `metaimage`.
+//!
+//! Functions which update the dataset take the name of the pipeline `stage` making the change.
+//! Each element they add or modify is attributed to that stage, see `DicomRoot::insert_element()`.
+
+use crate::core::{
+    dcmelement::DicomElement,
+    dcmobject::DicomRoot,
+    defn::{
+        constants::tags,
+        tag::{TagNode, TagPath},
+        vl::ValueLength,
+    },
+    values::RawValue,
+};
+
+use codec::{CodecRef, CodecRegistry};
+use encapsulated::EncapsulatedPixelData;
+use histogram::{AUTO_WINDOW_BINS, AUTO_WINDOW_PERCENTILES};
+
+pub use error::PixelDataError;
+pub use histogram::Histogram;
+pub use imagepixel::ImagePixel;
+pub use overlay::Overlay;
+pub use voilut::VoiLut;
+pub use volume::Volume;
+
+pub mod codec;
+pub mod concatenation;
+pub mod encapsulated;
+pub mod error;
+pub mod histogram;
+pub mod imagepixel;
+pub mod j2k;
+#[cfg(feature = "jpeg")]
+pub mod jpeg;
+pub mod metaimage;
+pub mod nifti;
+pub mod overlay;
+mod transform;
+pub mod video;
+pub mod voilut;
+pub mod volume;
+pub mod wsi;
+
+const SAMPLES_PER_PIXEL: u32 = 0x0028_0002;
+const PHOTOMETRIC_INTERPRETATION: u32 = 0x0028_0004;
+const PLANAR_CONFIGURATION: u32 = 0x0028_0006;
+const NUMBER_OF_FRAMES: u32 = 0x0028_0008;
+const ROWS: u32 = 0x0028_0010;
+const COLUMNS: u32 = 0x0028_0011;
+const BITS_ALLOCATED: u32 = 0x0028_0100;
+const BITS_STORED: u32 = 0x0028_0101;
+const HIGH_BIT: u32 = 0x0028_0102;
+const PIXEL_REPRESENTATION: u32 = 0x0028_0103;
+const WINDOW_CENTER: u32 = 0x0028_1050;
+const WINDOW_WIDTH: u32 = 0x0028_1051;
+const RESCALE_INTERCEPT: u32 = 0x0028_1052;
+const RESCALE_SLOPE: u32 = 0x0028_1053;
+const FRAME_VOI_LUT_SEQUENCE: u32 = 0x0028_9132;
+const PIXEL_VALUE_TRANSFORMATION_SEQUENCE: u32 = 0x0028_9145;
+const SHARED_FUNCTIONAL_GROUPS_SEQUENCE: u32 = 0x5200_9229;
+
+pub type PixelDataResult<T> = Result<T, PixelDataError>;
+
+/// How the samples of a decoded frame are to be interpreted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Photometric {
+    /// Grayscale, where the minimum value is intended to be displayed as white.
+    Monochrome1,
+    /// Grayscale, where the minimum value is intended to be displayed as black.
+    Monochrome2,
+    /// Red, green, and blue samples. Frames encoded as YBR_FULL are converted to RGB.
+    Rgb,
+}
+
+/// A VOI window which maps a range of values onto the range of display values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Window {
+    pub center: f64,
+    pub width: f64,
+}
+
+/// A rectangular region of a frame, in pixels from the top-left corner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Region {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+/// A single decoded frame of Pixel Data.
+#[derive(Debug)]
+pub struct PixelFrame {
+    rows: usize,
+    columns: usize,
+    photometric: Photometric,
+    bits_stored: u16,
+    /// The samples of each pixel in row-major order, with samples of a pixel interleaved.
+    values: Vec<f64>,
+    /// The first window specified by the dataset, if any.
+    window: Option<Window>,
+}
+
+impl Window {
+    /// Creates a window with the given center and width. The width is at least 1.
+    pub fn new(center: f64, width: f64) -> Window {
+        Window {
+            center,
+            width: width.max(1.0),
+        }
+    }
+
+    /// The lowest and highest values mapped to display values between the minimum and maximum,
+    /// exclusive of the lowest.
+    pub fn bounds(&self) -> (f64, f64) {
+        (
+            self.center - 0.5 - (self.width - 1.0) / 2.0,
+            self.center - 0.5 + (self.width - 1.0) / 2.0,
+        )
+    }
+
+    /// Maps a value to a display value using the LINEAR VOI LUT function, see Part 3,
+    /// Ch C.11.2.1.2.1.
+    pub fn apply(&self, value: f64) -> u8 {
+        let (lower, upper) = self.bounds();
+        if value <= lower {
+            u8::MIN
+        } else if value > upper {
+            u8::MAX
+        } else {
+            let scaled: f64 = ((value - (self.center - 0.5)) / (self.width - 1.0) + 0.5) * 255.0;
+            scaled.round().clamp(0.0, 255.0) as u8
+        }
+    }
+}
+
+impl PixelFrame {
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn photometric(&self) -> Photometric {
+        self.photometric
+    }
+
+    /// The number of samples for each pixel, 1 for monochrome or 3 for RGB.
+    pub fn samples_per_pixel(&self) -> usize {
+        match self.photometric {
+            Photometric::Monochrome1 | Photometric::Monochrome2 => 1,
+            Photometric::Rgb => 3,
+        }
+    }
+
+    /// The samples of each pixel in row-major order. Samples of RGB pixels are interleaved.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// The window specified by the dataset, if any. Only the first is used when the dataset
+    /// specifies multiple windows.
+    pub fn window(&self) -> Option<Window> {
+        self.window
+    }
+
+    /// The minimum and maximum sample values, or `None` if the frame has no values.
+    pub fn min_max(&self) -> Option<(f64, f64)> {
+        histogram::min_max(self.values.iter().copied())
+    }
+
+    /// A histogram of the sample values with the given number of bins, spanning their range, or
+    /// `None` if the frame has no values. Histograms of a volume are created with
+    /// `Histogram::of_frames()`.
+    pub fn histogram(&self, bins: usize) -> Option<Histogram> {
+        Histogram::of_values(&self.values, bins)
+    }
+
+    /// A window spanning the middle of the sample values, ignoring outlying values, see
+    /// `histogram`. The dataset's window is ignored.
+    pub fn auto_window(&self) -> Window {
+        let (low, high) = AUTO_WINDOW_PERCENTILES;
+        self.histogram(AUTO_WINDOW_BINS)
+            .and_then(|histogram| histogram.auto_window(low, high))
+            .unwrap_or_else(|| Window::new(0.5, 1.0))
+    }
+
+    /// The window specified by the dataset if it covers any of the sample values, otherwise the
+    /// automatic window, see `auto_window()`. This suits display of datasets whose VOI attributes
+    /// are missing or wrong, e.g. left unchanged when the values were rescaled.
+    pub fn suggested_window(&self) -> Window {
+        match (self.window, self.min_max()) {
+            (Some(window), Some((min, max))) => {
+                let (lower, upper) = window.bounds();
+                if lower < max && upper >= min {
+                    window
+                } else {
+                    self.auto_window()
+                }
+            }
+            (Some(window), None) => window,
+            (None, _) => self.auto_window(),
+        }
+    }
+
+    /// The window specified by the dataset, or a window spanning the range of sample values if the
+    /// dataset does not specify one.
+    pub fn default_window(&self) -> Window {
+        if let Some(window) = self.window {
+            return window;
+        }
+        let (min, max) = self.min_max().unwrap_or((0.0, 0.0));
+        Window::new((min + max) / 2.0 + 0.5, max - min + 1.0)
+    }
+
+    /// Copies a region of the frame into a new frame, e.g. a tile of a mosaic, see
+    /// `vendor::siemens`. The region is clipped to the bounds of the frame.
+    pub fn crop(&self, region: &Region) -> PixelFrame {
+        let samples: usize = self.samples_per_pixel();
+        let rows = region.y.min(self.rows)..(region.y + region.height).min(self.rows);
+        let columns = region.x.min(self.columns)..(region.x + region.width).min(self.columns);
+        let values: Vec<f64> = rows
+            .clone()
+            .flat_map(|row| {
+                let start: usize = (row * self.columns + columns.start) * samples;
+                let end: usize = (row * self.columns + columns.end) * samples;
+                self.values[start..end].iter().copied()
+            })
+            .collect();
+        PixelFrame {
+            rows: rows.len(),
+            columns: columns.len(),
+            photometric: self.photometric,
+            bits_stored: self.bits_stored,
+            values,
+            window: self.window,
+        }
+    }
+
+    /// Converts the frame into 8-bit RGB pixels in row-major order. Monochrome values are mapped
+    /// through the given window, while RGB samples are scaled from their stored bit depth.
+    pub fn to_rgb8(&self, window: &Window) -> Vec<[u8; 3]> {
+        match self.photometric {
+            Photometric::Monochrome1 | Photometric::Monochrome2 => {
+                let invert: bool = self.photometric == Photometric::Monochrome1;
+                transform::apply_window(window, &self.values, invert)
+                    .into_iter()
+                    .map(|gray| [gray; 3])
+                    .collect()
+            }
+            Photometric::Rgb => self.rgb_to_rgb8(),
+        }
+    }
+
+    /// Converts the frame into 8-bit RGB pixels in row-major order, as `to_rgb8()` though mapping
+    /// monochrome values through the given VOI LUT.
+    pub fn to_rgb8_with_lut(&self, lut: &VoiLut) -> Vec<[u8; 3]> {
+        match self.photometric {
+            Photometric::Monochrome1 | Photometric::Monochrome2 => {
+                let invert: bool = self.photometric == Photometric::Monochrome1;
+                self.values
+                    .iter()
+                    .map(|value| lut.apply(*value))
+                    .map(|gray| [if invert { u8::MAX - gray } else { gray }; 3])
+                    .collect()
+            }
+            Photometric::Rgb => self.rgb_to_rgb8(),
+        }
+    }
+
+    /// Scales the samples of an RGB frame from their stored bit depth to 8 bits.
+    fn rgb_to_rgb8(&self) -> Vec<[u8; 3]> {
+        let scale: f64 = 255.0 / ((1u64 << self.bits_stored) - 1) as f64;
+        self.values
+            .chunks_exact(3)
+            .map(|rgb| {
+                [rgb[0], rgb[1], rgb[2]]
+                    .map(|sample| (sample * scale).round().clamp(0.0, 255.0) as u8)
+            })
+            .collect()
+    }
+}
+
+/// Gets the number of frames in the dataset's Pixel Data.
+pub fn number_of_frames(dcmroot: &DicomRoot<'_>) -> PixelDataResult<usize> {
+    match dcmroot.get_child_by_tag(NUMBER_OF_FRAMES) {
+        Some(obj) => Ok(i32::try_from(obj.element())?.max(0) as usize),
+        None => Ok(1),
+    }
+}
+
+/// The layout of samples in Pixel Data, for an encoding which can be decoded.
+#[derive(Debug)]
+struct FrameLayout {
+    rows: usize,
+    columns: usize,
+    samples_per_pixel: usize,
+    bits_allocated: u16,
+    bits_stored: u16,
+    is_signed: bool,
+    is_float: bool,
+    photometric: Photometric,
+    is_ybr: bool,
+    is_planar: bool,
+    frames: usize,
+}
+
+impl FrameLayout {
+    /// Reads the Image Pixel attributes, checking they describe a supported encoding of the given
+    /// Pixel Data element.
+    fn read(dcmroot: &DicomRoot<'_>, pixel_data: &DicomElement) -> PixelDataResult<FrameLayout> {
+        if dcmroot.ts().encapsulated() || pixel_data.vl() == ValueLength::UndefinedLength {
+            return Err(PixelDataError::UnsupportedTransferSyntax(
+                dcmroot.ts().uid().ident(),
+            ));
+        }
+        let is_float: bool = pixel_data.tag() != tags::PIXEL_DATA;
+        FrameLayout::from_image(&ImagePixel::from_dataset(dcmroot)?, is_float)
+    }
+
+    /// Checks the Image Pixel attributes describe a supported encoding of native frames.
+    fn from_image(image: &ImagePixel, is_float: bool) -> PixelDataResult<FrameLayout> {
+        let samples_per_pixel: usize = image.samples_per_pixel.into();
+        let bits_allocated: u16 = image.bits_allocated;
+        let bits_stored: u16 = image.bits_stored;
+
+        let (photometric, is_ybr) =
+            match (image.photometric_interpretation.as_str(), samples_per_pixel) {
+                ("MONOCHROME1", 1) => (Photometric::Monochrome1, false),
+                ("MONOCHROME2", 1) => (Photometric::Monochrome2, false),
+                ("RGB", 3) => (Photometric::Rgb, false),
+                ("YBR_FULL", 3) => (Photometric::Rgb, true),
+                (other, samples) => {
+                    return Err(PixelDataError::UnsupportedEncoding(format!(
+                        "{other} with {samples} samples per pixel"
+                    )))
+                }
+            };
+        let supported_bits: &[u16] = if is_float { &[32, 64] } else { &[8, 16, 32] };
+        if !supported_bits.contains(&bits_allocated)
+            || bits_stored == 0
+            || bits_stored > bits_allocated
+        {
+            return Err(PixelDataError::UnsupportedEncoding(format!(
+                "{bits_stored} bits stored in {bits_allocated} bits allocated"
+            )));
+        }
+
+        Ok(FrameLayout {
+            rows: image.rows.into(),
+            columns: image.columns.into(),
+            samples_per_pixel,
+            bits_allocated,
+            bits_stored,
+            is_signed: !is_float && image.is_signed(),
+            is_float,
+            photometric,
+            is_ybr,
+            is_planar: image.is_planar(),
+            frames: image.number_of_frames,
+        })
+    }
+
+    fn bytes_per_sample(&self) -> usize {
+        usize::from(self.bits_allocated / 8)
+    }
+
+    fn frame_len(&self) -> usize {
+        self.rows * self.columns * self.samples_per_pixel * self.bytes_per_sample()
+    }
+
+    /// Gets the bytes of the frame at the given zero-based index.
+    fn frame_bytes<'d>(&self, data: &'d [u8], frame: usize) -> PixelDataResult<&'d [u8]> {
+        if frame >= self.frames {
+            return Err(PixelDataError::FrameOutOfRange {
+                frame,
+                frames: self.frames,
+            });
+        }
+        let start: usize = frame * self.frame_len();
+        data.get(start..start + self.frame_len())
+            .ok_or(PixelDataError::InsufficientData {
+                expected: start + self.frame_len(),
+                actual: data.len(),
+            })
+    }
+
+    /// The stored value of each sample of a pixel which is displayed as black. This is the lowest
+    /// value for MONOCHROME2 and the highest for MONOCHROME1, while for YBR_FULL the chroma samples
+    /// are at their midpoint. Values are in two's complement when signed.
+    fn black(&self) -> PixelDataResult<Vec<u64>> {
+        if self.is_float {
+            return Err(PixelDataError::UnsupportedEncoding(
+                "redacting float pixel data".to_owned(),
+            ));
+        }
+        let bits: u16 = self.bits_stored;
+        let (min, max): (i64, i64) = if self.is_signed {
+            (-(1i64 << (bits - 1)), (1i64 << (bits - 1)) - 1)
+        } else {
+            (0, (1i64 << bits) - 1)
+        };
+        let mid: i64 = 1i64 << (bits - 1);
+        let samples: Vec<i64> = match (self.photometric, self.is_ybr) {
+            (Photometric::Monochrome1, _) => vec![max],
+            (Photometric::Monochrome2, _) => vec![min],
+            (Photometric::Rgb, false) => vec![0, 0, 0],
+            (Photometric::Rgb, true) => vec![0, mid, mid],
+        };
+        Ok(samples.into_iter().map(|sample| sample as u64).collect())
+    }
+}
+
+/// Gets the Pixel Data, Float Pixel Data, or Double Float Pixel Data element of the dataset.
+fn pixel_data_tag(dcmroot: &DicomRoot<'_>) -> PixelDataResult<u32> {
+    [
+        tags::PIXEL_DATA,
+        tags::FLOAT_PIXEL_DATA,
+        tags::DOUBLE_PIXEL_DATA,
+    ]
+    .into_iter()
+    .find(|tag| dcmroot.get_child_by_tag(*tag).is_some())
+    .ok_or(PixelDataError::MissingPixelData)
+}
+
+/// Decodes frames of a dataset's Pixel Data. The Image Pixel attributes and other attributes
+/// affecting decoding are read once, so it's cheaper than `decode_frame()` for decoding many
+/// frames. The decoder only borrows the Pixel Data value, so frames can be decoded concurrently
+/// from multiple threads.
+#[derive(Debug)]
+pub struct FrameDecoder<'d> {
+    layout: FrameLayout,
+    data: &'d [u8],
+    /// The compressed frames and their codec, if the Pixel Data is encapsulated.
+    compressed: Option<CompressedFrames<'d>>,
+    big_endian: bool,
+    /// The rescale slope and intercept, if to be applied.
+    rescale: Option<(f64, f64)>,
+    window: Option<Window>,
+}
+
+/// The frames of encapsulated Pixel Data, decompressed with a registered codec.
+#[derive(Debug)]
+struct CompressedFrames<'d> {
+    encapsulated: EncapsulatedPixelData<'d>,
+    codec: CodecRef,
+    /// The attributes of the dataset, as given to the codec.
+    image: ImagePixel,
+}
+
+impl<'d> FrameDecoder<'d> {
+    /// Reads the attributes for decoding the dataset's Pixel Data, failing if it can't be decoded.
+    pub fn new(dcmroot: &'d DicomRoot<'_>) -> PixelDataResult<FrameDecoder<'d>> {
+        FrameDecoder::with_codecs(dcmroot, &CodecRegistry::default())
+    }
+
+    /// Reads the attributes for decoding the dataset's Pixel Data, as `new()`, though Pixel Data of
+    /// an encapsulated transfer syntax is decoded with the codec registered for it, if any.
+    pub fn with_codecs(
+        dcmroot: &'d DicomRoot<'_>,
+        codecs: &CodecRegistry,
+    ) -> PixelDataResult<FrameDecoder<'d>> {
+        let pixel_data: &'d DicomElement = dcmroot
+            .get_child_by_tag(pixel_data_tag(dcmroot)?)
+            .map(|obj| obj.element())
+            .ok_or(PixelDataError::MissingPixelData)?;
+        let codec: Option<&CodecRef> = codecs
+            .get_for_ts(dcmroot.ts())
+            .filter(|_codec| dcmroot.ts().encapsulated());
+        let (layout, compressed) = match codec {
+            Some(codec) => {
+                let image: ImagePixel = ImagePixel::from_dataset(dcmroot)?;
+                let layout: FrameLayout =
+                    FrameLayout::from_image(&codec.decoded_image(&image), false)?;
+                let compressed: CompressedFrames<'d> = CompressedFrames {
+                    encapsulated: EncapsulatedPixelData::read(dcmroot)?,
+                    codec: codec.clone(),
+                    image,
+                };
+                (layout, Some(compressed))
+            }
+            None => (FrameLayout::read(dcmroot, pixel_data)?, None),
+        };
+
+        let rescale: Option<(f64, f64)> = if !layout.is_float && layout.samples_per_pixel == 1 {
+            let transformation = PIXEL_VALUE_TRANSFORMATION_SEQUENCE;
+            let slope: f64 = get_shared_f64(dcmroot, transformation, RESCALE_SLOPE).unwrap_or(1.0);
+            let intercept: f64 =
+                get_shared_f64(dcmroot, transformation, RESCALE_INTERCEPT).unwrap_or(0.0);
+            (slope != 1.0 || intercept != 0.0).then_some((slope, intercept))
+        } else {
+            None
+        };
+        let window: Option<Window> = get_shared_f64(dcmroot, FRAME_VOI_LUT_SEQUENCE, WINDOW_CENTER)
+            .zip(get_shared_f64(
+                dcmroot,
+                FRAME_VOI_LUT_SEQUENCE,
+                WINDOW_WIDTH,
+            ))
+            .map(|(center, width)| Window::new(center, width));
+
+        // Decompressed frames are always little endian.
+        let big_endian: bool = compressed.is_none() && pixel_data.ts().big_endian();
+        Ok(FrameDecoder {
+            layout,
+            data: pixel_data.data(),
+            compressed,
+            big_endian,
+            rescale,
+            window,
+        })
+    }
+
+    /// The number of frames, per the Number of Frames attribute.
+    pub fn frame_count(&self) -> usize {
+        self.layout.frames
+    }
+
+    /// Decodes the frame at the given zero-based index.
+    pub fn decode(&self, frame: usize) -> PixelDataResult<PixelFrame> {
+        let image: &FrameLayout = &self.layout;
+        let decompressed: Vec<u8>;
+        let data: &[u8] = match &self.compressed {
+            Some(compressed) => {
+                if frame >= image.frames {
+                    return Err(PixelDataError::FrameOutOfRange {
+                        frame,
+                        frames: image.frames,
+                    });
+                }
+                let codestream: Vec<u8> = compressed.encapsulated.frame(frame)?;
+                decompressed = compressed.codec.decode(&codestream, &compressed.image)?;
+                // Checks the decompressed frame is of the length the attributes describe.
+                image.frame_bytes(&decompressed, 0)?
+            }
+            None => image.frame_bytes(self.data, frame)?,
+        };
+
+        let mut values: Vec<f64> = if image.is_float {
+            transform::unpack_floats(data, image.bytes_per_sample(), self.big_endian)
+        } else {
+            transform::unpack_integers(
+                data,
+                image.bytes_per_sample(),
+                image.bits_stored,
+                image.is_signed,
+                self.big_endian,
+            )
+        };
+
+        if image.is_planar {
+            values = interleave_planes(&values, image.samples_per_pixel);
+        }
+        if image.is_ybr {
+            transform::ybr_to_rgb(&mut values, image.bits_stored);
+        }
+        if let Some((slope, intercept)) = self.rescale {
+            transform::rescale(&mut values, slope, intercept);
+        }
+
+        Ok(PixelFrame {
+            rows: image.rows,
+            columns: image.columns,
+            photometric: image.photometric,
+            bits_stored: image.bits_stored,
+            values,
+            window: self.window,
+        })
+    }
+
+    /// Decodes every frame in order, one at a time as the iterator is advanced.
+    pub fn frames(&self) -> impl Iterator<Item = PixelDataResult<PixelFrame>> + '_ {
+        (0..self.frame_count()).map(|frame| self.decode(frame))
+    }
+
+    /// Decodes every frame in order, decoding batches of frames in parallel on the current rayon
+    /// thread pool. Each batch has a frame for every thread of the pool, and is decoded once the
+    /// prior batch has been consumed, bounding the number of decoded frames held in memory. Run
+    /// within `rayon::ThreadPool::install()` to decode on a pool other than the global one.
+    #[cfg(feature = "parallel")]
+    pub fn par_frames(&self) -> ParallelFrames<'_, 'd> {
+        ParallelFrames {
+            decoder: self,
+            next: 0,
+            batch: std::collections::VecDeque::new(),
+        }
+    }
+}
+
+/// An iterator which decodes frames in parallel while yielding them in order, see
+/// `FrameDecoder::par_frames()`.
+#[cfg(feature = "parallel")]
+#[derive(Debug)]
+pub struct ParallelFrames<'a, 'd> {
+    decoder: &'a FrameDecoder<'d>,
+    next: usize,
+    batch: std::collections::VecDeque<PixelDataResult<PixelFrame>>,
+}
+
+#[cfg(feature = "parallel")]
+impl Iterator for ParallelFrames<'_, '_> {
+    type Item = PixelDataResult<PixelFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+        if self.batch.is_empty() && self.next < self.decoder.frame_count() {
+            let end: usize =
+                (self.next + rayon::current_num_threads()).min(self.decoder.frame_count());
+            // Collecting an indexed parallel iterator retains the order of frames.
+            let decoded: Vec<PixelDataResult<PixelFrame>> = (self.next..end)
+                .into_par_iter()
+                .map(|frame| self.decoder.decode(frame))
+                .collect();
+            self.batch.extend(decoded);
+            self.next = end;
+        }
+        self.batch.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining: usize = self.batch.len() + self.decoder.frame_count() - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+/// Decodes the frame at the given zero-based index from the dataset's Pixel Data. To decode many
+/// frames, see `FrameDecoder`.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip(dcmroot), fields(ts = dcmroot.ts().uid().name()))
+)]
+pub fn decode_frame(dcmroot: &DicomRoot<'_>, frame: usize) -> PixelDataResult<PixelFrame> {
+    FrameDecoder::new(dcmroot)?.decode(frame)
+}
+
+/// Blacks out the given regions of every frame of the dataset's Pixel Data, re-encoding its value.
+/// Regions are clipped to the bounds of the frame. The Pixel Data is modified by the given pipeline
+/// stage, see `DicomRoot::modify_element()`. Float Pixel Data can't be redacted, as there is no
+/// value which is always displayed as black.
+pub fn redact_regions(
+    dcmroot: &mut DicomRoot<'_>,
+    regions: &[Region],
+    stage: &str,
+) -> PixelDataResult<()> {
+    let tag: u32 = pixel_data_tag(dcmroot)?;
+    let pixel_data: &DicomElement = dcmroot
+        .get_child_by_tag(tag)
+        .map(|obj| obj.element())
+        .ok_or(PixelDataError::MissingPixelData)?;
+    let image: FrameLayout = FrameLayout::read(dcmroot, pixel_data)?;
+    let black: Vec<u64> = image.black()?;
+    // Validates the Pixel Data holds all frames before modifying any.
+    image.frame_bytes(pixel_data.data(), image.frames.saturating_sub(1))?;
+
+    let big_endian: bool = pixel_data.ts().big_endian();
+    let bytes_per_sample: usize = image.bytes_per_sample();
+    let plane_len: usize = image.rows * image.columns;
+    let mut data: Vec<u8> = pixel_data.data().to_vec();
+    for frame in 0..image.frames {
+        let frame_start: usize = frame * image.frame_len();
+        for region in regions {
+            let rows = region.y.min(image.rows)..(region.y + region.height).min(image.rows);
+            let columns = region.x.min(image.columns)..(region.x + region.width).min(image.columns);
+            for row in rows {
+                for column in columns.clone() {
+                    let pixel: usize = row * image.columns + column;
+                    for (sample, value) in black.iter().enumerate() {
+                        let index: usize = if image.is_planar {
+                            sample * plane_len + pixel
+                        } else {
+                            pixel * image.samples_per_pixel + sample
+                        };
+                        let start: usize = frame_start + index * bytes_per_sample;
+                        let bytes: &mut [u8] = &mut data[start..start + bytes_per_sample];
+                        if big_endian {
+                            bytes.copy_from_slice(&value.to_be_bytes()[8 - bytes_per_sample..]);
+                        } else {
+                            bytes.copy_from_slice(&value.to_le_bytes()[..bytes_per_sample]);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    dcmroot
+        .modify_element(&TagPath::from(vec![TagNode::from(tag)]), stage, |element| {
+            element.encode_value(RawValue::Bytes(data), None)
+        })
+        .ok_or(PixelDataError::MissingPixelData)??;
+    Ok(())
+}
+
+/// Gets a required US attribute of the Image Pixel module.
+fn get_u16(dcmroot: &DicomRoot<'_>, tag: u32, name: &'static str) -> PixelDataResult<u16> {
+    let obj = dcmroot
+        .get_child_by_tag(tag)
+        .ok_or(PixelDataError::MissingAttribute(name))?;
+    Ok(u16::try_from(obj.element())?)
+}
+
+/// Gets the first value of an optional DS attribute, if present and valid.
+fn get_f64(dcmroot: &DicomRoot<'_>, tag: u32) -> Option<f64> {
+    dcmroot
+        .get_child_by_tag(tag)
+        .and_then(|obj| Vec::<f64>::try_from(obj.element()).ok())
+        .and_then(|values| values.into_iter().next())
+}
+
+/// Gets the first value of an optional DS attribute of the dataset, or otherwise of the given
+/// functional group of the Shared Functional Groups Sequence, as in Enhanced multi-frame objects.
+fn get_shared_f64(dcmroot: &DicomRoot<'_>, group: u32, tag: u32) -> Option<f64> {
+    get_f64(dcmroot, tag).or_else(|| {
+        let obj = dcmroot
+            .get_child_by_tag(SHARED_FUNCTIONAL_GROUPS_SEQUENCE)?
+            .get_item_by_index(1)?
+            .get_child_by_tag(group)?
+            .get_item_by_index(1)?
+            .get_child_by_tag(tag)?;
+        Vec::<f64>::try_from(obj.element()).ok()?.into_iter().next()
+    })
+}
+
+/// Converts samples stored by plane, e.g. all red then all green then all blue, into samples
+/// interleaved by pixel.
+fn interleave_planes<T: Copy>(values: &[T], samples_per_pixel: usize) -> Vec<T> {
+    let plane_len: usize = values.len() / samples_per_pixel;
+    (0..plane_len)
+        .flat_map(|pixel| (0..samples_per_pixel).map(move |plane| plane * plane_len + pixel))
+        .map(|i| values[i])
+        .collect()
+}
@@ -0,0 +1,334 @@
+//! Encoding and decoding of volumes as single-file NIfTI-1 images (`.nii`), for research tooling.
+//!
+//! NIfTI positions voxels in a coordinate system whose axes point to the patient's right,
+//! anterior, and head (RAS), so the first two axes of the DICOM patient coordinate system are
+//! negated. The orientation is given both as a quaternion (`qform`) and as an affine matrix
+//! (`sform`), each with the scanner coordinate code, see the NIfTI-1 header `nifti1.h`.
+
+use super::{
+    volume::{decode_voxels, StoredType, Volume, VoxelType},
+    PixelDataError, PixelDataResult,
+};
+
+/// The size of the header, after which a 4-byte extension marker precedes the voxel data.
+const HEADER_LEN: usize = 348;
+const VOXEL_OFFSET: usize = HEADER_LEN + 4;
+
+/// The `qform_code` and `sform_code` of coordinates in the scanner's patient space.
+const NIFTI_XFORM_SCANNER_ANAT: i16 = 1;
+/// The `xyzt_units` of distances in millimeters.
+const NIFTI_UNITS_MM: u8 = 2;
+
+const DT_UINT8: i16 = 2;
+const DT_INT16: i16 = 4;
+const DT_INT32: i16 = 8;
+const DT_FLOAT32: i16 = 16;
+const DT_FLOAT64: i16 = 64;
+const DT_INT8: i16 = 256;
+const DT_UINT16: i16 = 512;
+const DT_UINT32: i16 = 768;
+
+/// Encodes the volume as a single-file NIfTI-1 image, in little endian, with voxels of its
+/// `Volume::voxel_type()`. NIfTI-1 volumes have at most 32767 voxels along each axis.
+pub fn encode(volume: &Volume) -> PixelDataResult<Vec<u8>> {
+    let voxel_type: VoxelType = volume.voxel_type();
+    let (datatype, bitpix): (i16, i16) = match voxel_type {
+        VoxelType::I16 => (DT_INT16, 16),
+        VoxelType::I32 => (DT_INT32, 32),
+        VoxelType::F32 => (DT_FLOAT32, 32),
+    };
+    let dimensions: [usize; 3] = volume.dimensions();
+    let spacing: [f64; 3] = volume.spacing();
+    let affine: [[f64; 4]; 3] = affine(volume);
+    let (quaternion, qfac) = quaternion(&affine, spacing);
+
+    let mut header: Header = Header(vec![0; VOXEL_OFFSET]);
+    header.i32(0, HEADER_LEN as i32);
+    header.bytes(38, b"r");
+    let mut dim: [i16; 8] = [3, 1, 1, 1, 1, 1, 1, 1];
+    for (axis, size) in dimensions.iter().enumerate() {
+        dim[axis + 1] = i16::try_from(*size).map_err(|_| {
+            PixelDataError::UnsupportedEncoding(format!("NIfTI-1 volume of {size} voxels"))
+        })?;
+    }
+    for (i, value) in dim.iter().enumerate() {
+        header.i16(40 + i * 2, *value);
+    }
+    header.i16(70, datatype);
+    header.i16(72, bitpix);
+    let pixdim: [f64; 8] = [qfac, spacing[0], spacing[1], spacing[2], 0.0, 0.0, 0.0, 0.0];
+    for (i, value) in pixdim.iter().enumerate() {
+        header.f32(76 + i * 4, *value);
+    }
+    header.f32(108, VOXEL_OFFSET as f64);
+    header.f32(112, 1.0);
+    header.bytes(123, &[NIFTI_UNITS_MM]);
+    header.bytes(148, b"dcmpipe");
+    header.i16(252, NIFTI_XFORM_SCANNER_ANAT);
+    header.i16(254, NIFTI_XFORM_SCANNER_ANAT);
+    for (i, value) in quaternion.iter().enumerate() {
+        header.f32(256 + i * 4, *value);
+    }
+    for (i, value) in affine.iter().map(|row| row[3]).enumerate() {
+        header.f32(268 + i * 4, value);
+    }
+    for (row, values) in affine.iter().enumerate() {
+        for (column, value) in values.iter().enumerate() {
+            header.f32(280 + row * 16 + column * 4, *value);
+        }
+    }
+    header.bytes(344, b"n+1\0");
+
+    let mut data: Vec<u8> = header.0;
+    data.extend(volume.encode_values(voxel_type));
+    Ok(data)
+}
+
+/// Decodes a single-file NIfTI-1 image of a 3D volume, in either byte order. Values are scaled by
+/// `scl_slope` and `scl_inter`, and positioned by the `sform` if given, otherwise by the `qform`,
+/// otherwise by the voxel spacing alone.
+pub fn decode(data: &[u8]) -> PixelDataResult<Volume> {
+    let invalid = |reason: &str| PixelDataError::InvalidVolumeFile(format!("NIfTI-1 {reason}"));
+    if data.len() < HEADER_LEN {
+        return Err(invalid("header is truncated"));
+    }
+    let size: [u8; 4] = [data[0], data[1], data[2], data[3]];
+    let big_endian: bool = if i32::from_le_bytes(size) == HEADER_LEN as i32 {
+        false
+    } else if i32::from_be_bytes(size) == HEADER_LEN as i32 {
+        true
+    } else {
+        return Err(invalid("header size"));
+    };
+    if &data[344..348] != b"n+1\0" {
+        return Err(invalid("magic, only single-file images are supported"));
+    }
+    let reader: Reader<'_> = Reader { data, big_endian };
+
+    let dim: Vec<i16> = (0..8).map(|i| reader.i16(40 + i * 2)).collect();
+    let ndim: usize = usize::try_from(dim[0]).unwrap_or(0);
+    if !(1..=7).contains(&ndim) {
+        return Err(invalid("dimensions"));
+    }
+    let mut dimensions: [usize; 3] = [1; 3];
+    for (axis, size) in dim[1..=ndim].iter().enumerate() {
+        let size: usize = usize::try_from(*size)
+            .ok()
+            .filter(|size| *size > 0)
+            .ok_or_else(|| invalid("dimensions"))?;
+        match dimensions.get_mut(axis) {
+            Some(dimension) => *dimension = size,
+            None if size == 1 => {}
+            None => return Err(invalid("image has more than three dimensions")),
+        }
+    }
+
+    let stored: StoredType = match reader.i16(70) {
+        DT_UINT8 => StoredType::U8,
+        DT_INT8 => StoredType::I8,
+        DT_UINT16 => StoredType::U16,
+        DT_INT16 => StoredType::I16,
+        DT_UINT32 => StoredType::U32,
+        DT_INT32 => StoredType::I32,
+        DT_FLOAT32 => StoredType::F32,
+        DT_FLOAT64 => StoredType::F64,
+        datatype => return Err(invalid(&format!("datatype {datatype}"))),
+    };
+    let offset: usize = reader.f32(108).max(VOXEL_OFFSET as f64) as usize;
+    let count: usize = dimensions.iter().product();
+    let mut values: Vec<f64> =
+        decode_voxels(data.get(offset..).unwrap_or(&[]), stored, big_endian, count)?;
+    let (slope, intercept) = (reader.f32(112), reader.f32(116));
+    if slope.is_finite() && slope != 0.0 {
+        for value in &mut values {
+            *value = *value * slope + intercept;
+        }
+    }
+
+    let pixdim: [f64; 4] = std::array::from_fn(|i| reader.f32(76 + i * 4));
+    let spacing: [f64; 3] = std::array::from_fn(|axis| match pixdim[axis + 1] {
+        spacing if spacing > 0.0 => spacing,
+        _ => 1.0,
+    });
+    let affine: [[f64; 4]; 3] = if reader.i16(254) > 0 {
+        std::array::from_fn(|row| {
+            std::array::from_fn(|column| reader.f32(280 + row * 16 + column * 4))
+        })
+    } else if reader.i16(252) > 0 {
+        let quaternion: [f64; 3] = std::array::from_fn(|i| reader.f32(256 + i * 4));
+        let offset: [f64; 3] = std::array::from_fn(|i| reader.f32(268 + i * 4));
+        let qfac: f64 = if pixdim[0] < 0.0 { -1.0 } else { 1.0 };
+        from_quaternion(quaternion, qfac, offset, spacing)
+    } else {
+        std::array::from_fn(|row| {
+            std::array::from_fn(|column| if row == column { spacing[row] } else { 0.0 })
+        })
+    };
+    // RAS to LPS negates the first two axes.
+    let affine: [[f64; 4]; 3] = std::array::from_fn(|axis| {
+        let sign: f64 = if axis < 2 { -1.0 } else { 1.0 };
+        affine[axis].map(|value| sign * value)
+    });
+    Volume::from_affine(dimensions, affine, values)
+}
+
+/// Reads the fields of a NIfTI header in its byte order.
+struct Reader<'d> {
+    data: &'d [u8],
+    big_endian: bool,
+}
+
+impl Reader<'_> {
+    fn bytes<const N: usize>(&self, offset: usize) -> [u8; N] {
+        let mut bytes: [u8; N] = std::array::from_fn(|i| self.data[offset + i]);
+        if self.big_endian {
+            bytes.reverse();
+        }
+        bytes
+    }
+
+    fn i16(&self, offset: usize) -> i16 {
+        i16::from_le_bytes(self.bytes(offset))
+    }
+
+    fn f32(&self, offset: usize) -> f64 {
+        f64::from(f32::from_le_bytes(self.bytes(offset)))
+    }
+}
+
+/// The NIfTI header, as bytes in little endian at the offsets of its fields.
+struct Header(Vec<u8>);
+
+impl Header {
+    fn bytes(&mut self, offset: usize, value: &[u8]) {
+        self.0[offset..offset + value.len()].copy_from_slice(value);
+    }
+
+    fn i16(&mut self, offset: usize, value: i16) {
+        self.bytes(offset, &value.to_le_bytes());
+    }
+
+    fn i32(&mut self, offset: usize, value: i32) {
+        self.bytes(offset, &value.to_le_bytes());
+    }
+
+    fn f32(&mut self, offset: usize, value: f64) {
+        self.bytes(offset, &(value as f32).to_le_bytes());
+    }
+}
+
+/// The rows of the matrix mapping a voxel's column, row, and slice to its position in RAS.
+fn affine(volume: &Volume) -> [[f64; 4]; 3] {
+    let spacing: [f64; 3] = volume.spacing();
+    let direction: [[f64; 3]; 3] = volume.direction();
+    let origin: [f64; 3] = volume.origin();
+    std::array::from_fn(|axis| {
+        // LPS to RAS negates the first two axes.
+        let sign: f64 = if axis < 2 { -1.0 } else { 1.0 };
+        [
+            sign * direction[0][axis] * spacing[0],
+            sign * direction[1][axis] * spacing[1],
+            sign * direction[2][axis] * spacing[2],
+            sign * origin[axis],
+        ]
+    })
+}
+
+/// The rows of the affine of the quaternion parameters, see `nifti_quatern_to_mat44()` of
+/// `nifti1_io.c`.
+fn from_quaternion(
+    [b, c, d]: [f64; 3],
+    qfac: f64,
+    offset: [f64; 3],
+    spacing: [f64; 3],
+) -> [[f64; 4]; 3] {
+    let a: f64 = (1.0 - (b * b + c * c + d * d)).max(0.0).sqrt();
+    let r: [[f64; 3]; 3] = [
+        [
+            a * a + b * b - c * c - d * d,
+            2.0 * (b * c - a * d),
+            2.0 * (b * d + a * c),
+        ],
+        [
+            2.0 * (b * c + a * d),
+            a * a + c * c - b * b - d * d,
+            2.0 * (c * d - a * b),
+        ],
+        [
+            2.0 * (b * d - a * c),
+            2.0 * (c * d + a * b),
+            a * a + d * d - c * c - b * b,
+        ],
+    ];
+    let scale: [f64; 3] = [spacing[0], spacing[1], qfac * spacing[2]];
+    std::array::from_fn(|row| {
+        [
+            r[row][0] * scale[0],
+            r[row][1] * scale[1],
+            r[row][2] * scale[2],
+            offset[row],
+        ]
+    })
+}
+
+/// The quaternion parameters `b`, `c`, and `d` of the rotation of the affine, and `qfac`, which
+/// is -1 when the third axis is reflected, see `nifti_mat44_to_quatern()` of `nifti1_io.c`.
+fn quaternion(affine: &[[f64; 4]; 3], spacing: [f64; 3]) -> ([f64; 3], f64) {
+    let mut r: [[f64; 3]; 3] = std::array::from_fn(|row| {
+        std::array::from_fn(|column| affine[row][column] / spacing[column])
+    });
+    let determinant: f64 = r[0][0] * (r[1][1] * r[2][2] - r[1][2] * r[2][1])
+        - r[0][1] * (r[1][0] * r[2][2] - r[1][2] * r[2][0])
+        + r[0][2] * (r[1][0] * r[2][1] - r[1][1] * r[2][0]);
+    let qfac: f64 = if determinant < 0.0 {
+        for row in &mut r {
+            row[2] = -row[2];
+        }
+        -1.0
+    } else {
+        1.0
+    };
+
+    let trace: f64 = r[0][0] + r[1][1] + r[2][2] + 1.0;
+    let (a, b, c, d) = if trace > 0.5 {
+        let a: f64 = 0.5 * trace.sqrt();
+        (
+            a,
+            0.25 * (r[2][1] - r[1][2]) / a,
+            0.25 * (r[0][2] - r[2][0]) / a,
+            0.25 * (r[1][0] - r[0][1]) / a,
+        )
+    } else {
+        let xd: f64 = 1.0 + r[0][0] - (r[1][1] + r[2][2]);
+        let yd: f64 = 1.0 + r[1][1] - (r[0][0] + r[2][2]);
+        let zd: f64 = 1.0 + r[2][2] - (r[0][0] + r[1][1]);
+        if xd > 1.0 {
+            let b: f64 = 0.5 * xd.sqrt();
+            (
+                0.25 * (r[2][1] - r[1][2]) / b,
+                b,
+                0.25 * (r[0][1] + r[1][0]) / b,
+                0.25 * (r[0][2] + r[2][0]) / b,
+            )
+        } else if yd > 1.0 {
+            let c: f64 = 0.5 * yd.sqrt();
+            (
+                0.25 * (r[0][2] - r[2][0]) / c,
+                0.25 * (r[0][1] + r[1][0]) / c,
+                c,
+                0.25 * (r[1][2] + r[2][1]) / c,
+            )
+        } else {
+            let d: f64 = 0.5 * zd.sqrt();
+            (
+                0.25 * (r[1][0] - r[0][1]) / d,
+                0.25 * (r[0][2] + r[2][0]) / d,
+                0.25 * (r[1][2] + r[2][1]) / d,
+                d,
+            )
+        }
+    };
+    // The quaternion is stored with a non-negative `a`, which is implied.
+    let sign: f64 = if a < 0.0 { -1.0 } else { 1.0 };
+    ([sign * b, sign * c, sign * d], qfac)
+}
@@ -0,0 +1,202 @@
+//! Overlay planes, see Part 3, Ch C.9.2.
+//!
+//! Each of the repeating groups 6000-601E may hold a bitmap of graphics or regions of interest,
+//! e.g. annotations, to be displayed over the image. Only overlays stored in Overlay Data are read,
+//! as overlays stored in the unused high bits of Pixel Data were retired.
+
+use crate::core::{
+    dcmobject::DicomRoot,
+    defn::{vl::ValueLength, vr},
+    values::RawValue,
+};
+
+use super::{PixelDataError, PixelDataResult};
+
+/// The first and last group of the repeating overlay groups.
+const OVERLAY_GROUPS: (u16, u16) = (0x6000, 0x601E);
+
+const OVERLAY_ROWS: u16 = 0x0010;
+const OVERLAY_COLUMNS: u16 = 0x0011;
+const NUMBER_OF_FRAMES_IN_OVERLAY: u16 = 0x0015;
+const OVERLAY_TYPE: u16 = 0x0040;
+const OVERLAY_ORIGIN: u16 = 0x0050;
+const IMAGE_FRAME_ORIGIN: u16 = 0x0051;
+const OVERLAY_BITS_ALLOCATED: u16 = 0x0100;
+const OVERLAY_DATA: u16 = 0x3000;
+
+/// An overlay plane.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Overlay {
+    group: u16,
+    rows: usize,
+    columns: usize,
+    /// The row and column of the image the overlay's top-left pixel is at, from 1.
+    origin: (i32, i32),
+    /// The overlay type, `G` for graphics or `R` for a region of interest.
+    overlay_type: String,
+    /// The zero-based frame of the image the overlay's first frame applies to.
+    first_frame: usize,
+    frames: usize,
+    /// The bits of each frame in row-major order, the first bit in the lowest bit of the first
+    /// byte.
+    data: Vec<u8>,
+}
+
+impl Overlay {
+    /// Reads the overlay planes of the dataset which have Overlay Data, in order of their group.
+    pub fn read_all(dcmroot: &DicomRoot<'_>) -> PixelDataResult<Vec<Overlay>> {
+        (OVERLAY_GROUPS.0..=OVERLAY_GROUPS.1)
+            .step_by(2)
+            .filter(|group| {
+                dcmroot
+                    .get_child_by_tag(tag(*group, OVERLAY_DATA))
+                    .is_some()
+            })
+            .map(|group| Overlay::read(dcmroot, group))
+            .collect()
+    }
+
+    /// Reads the overlay plane of the given group, e.g. `0x6000`.
+    pub fn read(dcmroot: &DicomRoot<'_>, group: u16) -> PixelDataResult<Overlay> {
+        let get_u16 = |element: u16, name: &'static str| -> PixelDataResult<u16> {
+            let obj = dcmroot
+                .get_child_by_tag(tag(group, element))
+                .ok_or(PixelDataError::MissingAttribute(name))?;
+            Ok(u16::try_from(obj.element())?)
+        };
+        let rows: usize = get_u16(OVERLAY_ROWS, "OverlayRows")?.into();
+        let columns: usize = get_u16(OVERLAY_COLUMNS, "OverlayColumns")?.into();
+        let bits_allocated: u16 = get_u16(OVERLAY_BITS_ALLOCATED, "OverlayBitsAllocated")?;
+        if bits_allocated != 1 {
+            return Err(PixelDataError::UnsupportedEncoding(format!(
+                "overlay with {bits_allocated} bits allocated"
+            )));
+        }
+        let frames: usize = match dcmroot.get_child_by_tag(tag(group, NUMBER_OF_FRAMES_IN_OVERLAY))
+        {
+            Some(obj) => i32::try_from(obj.element())?.max(0) as usize,
+            None => 1,
+        };
+        let first_frame: usize = match dcmroot.get_child_by_tag(tag(group, IMAGE_FRAME_ORIGIN)) {
+            Some(obj) => usize::from(u16::try_from(obj.element())?).saturating_sub(1),
+            None => 0,
+        };
+
+        let origin: (i32, i32) = match dcmroot.get_child_by_tag(tag(group, OVERLAY_ORIGIN)) {
+            Some(obj) => match obj.element().parse_value()? {
+                RawValue::Shorts(origin) if origin.len() == 2 => {
+                    (i32::from(origin[0]), i32::from(origin[1]))
+                }
+                RawValue::UnsignedShorts(origin) if origin.len() == 2 => {
+                    (i32::from(origin[0]), i32::from(origin[1]))
+                }
+                _ => {
+                    return Err(PixelDataError::InvalidImagePixel(
+                        "OverlayOrigin".to_owned(),
+                    ))
+                }
+            },
+            None => return Err(PixelDataError::MissingAttribute("OverlayOrigin")),
+        };
+        let overlay_type: String = match dcmroot.get_child_by_tag(tag(group, OVERLAY_TYPE)) {
+            Some(obj) => String::try_from(obj.element())?.trim().to_owned(),
+            None => "G".to_owned(),
+        };
+
+        let element = dcmroot
+            .get_child_by_tag(tag(group, OVERLAY_DATA))
+            .ok_or(PixelDataError::MissingAttribute("OverlayData"))?
+            .element();
+        if element.vl() == ValueLength::UndefinedLength {
+            return Err(PixelDataError::UnsupportedEncoding(
+                "encapsulated overlay data".to_owned(),
+            ));
+        }
+        let mut data: Vec<u8> = element.data().to_vec();
+        // Bits are packed into words, whose bytes are swapped in big endian.
+        if element.ts().big_endian() && element.vr() == &vr::OW {
+            data.chunks_exact_mut(2).for_each(|word| word.swap(0, 1));
+        }
+        let expected: usize = (rows * columns * frames).div_ceil(8);
+        if data.len() < expected {
+            return Err(PixelDataError::InsufficientData {
+                expected,
+                actual: data.len(),
+            });
+        }
+
+        Ok(Overlay {
+            group,
+            rows,
+            columns,
+            origin,
+            overlay_type,
+            first_frame,
+            frames,
+            data,
+        })
+    }
+
+    /// The group the overlay is in, e.g. `0x6000`.
+    pub fn group(&self) -> u16 {
+        self.group
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// The row and column of the image the overlay's top-left pixel is at, from 1.
+    pub fn origin(&self) -> (i32, i32) {
+        self.origin
+    }
+
+    /// The overlay type, `G` for graphics or `R` for a region of interest.
+    pub fn overlay_type(&self) -> &str {
+        &self.overlay_type
+    }
+
+    /// Whether the overlay applies to the image frame at the given zero-based index.
+    pub fn applies_to(&self, frame: usize) -> bool {
+        (self.first_frame..self.first_frame + self.frames).contains(&frame)
+    }
+
+    /// Whether the overlay is set at the given zero-based row and column of the image frame.
+    /// Pixels beyond the overlay, or frames it doesn't apply to, are not set.
+    pub fn is_set(&self, frame: usize, row: usize, column: usize) -> bool {
+        if !self.applies_to(frame) {
+            return false;
+        }
+        let row: i64 = row as i64 - i64::from(self.origin.0 - 1);
+        let column: i64 = column as i64 - i64::from(self.origin.1 - 1);
+        if row < 0 || column < 0 || row >= self.rows as i64 || column >= self.columns as i64 {
+            return false;
+        }
+        let bit: usize = (frame - self.first_frame) * self.rows * self.columns
+            + row as usize * self.columns
+            + column as usize;
+        self.data[bit / 8] & (1 << (bit % 8)) != 0
+    }
+
+    /// Sets the pixels of the image frame at the given zero-based index to the given color
+    /// wherever the overlay is set. The pixels are in row-major order, with the given number of
+    /// columns.
+    pub fn burn_in(&self, frame: usize, pixels: &mut [[u8; 3]], columns: usize, color: [u8; 3]) {
+        if !self.applies_to(frame) || columns == 0 {
+            return;
+        }
+        for (index, pixel) in pixels.iter_mut().enumerate() {
+            if self.is_set(frame, index / columns, index % columns) {
+                *pixel = color;
+            }
+        }
+    }
+}
+
+fn tag(group: u16, element: u16) -> u32 {
+    (u32::from(group) << 16) | u32::from(element)
+}
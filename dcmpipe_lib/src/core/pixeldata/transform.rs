@@ -0,0 +1,170 @@
+//! The per-sample transforms of decoding and displaying frames, which dominate the time taken to
+//! render large series.
+//!
+//! With the `simd` feature, unpacking samples and applying the rescale slope and intercept process
+//! several samples at once using SIMD instructions, with any remaining samples processed one at a
+//! time. The results are identical either way, as each sample undergoes the same operations in the
+//! same order. The effect of each transform can be measured with the `pixeldata` benchmark.
+
+#[cfg(feature = "simd")]
+use wide::{f64x4, i32x4};
+
+use super::Window;
+
+/// The number of samples processed at once with the `simd` feature.
+#[cfg(feature = "simd")]
+const LANES: usize = 4;
+
+/// Unpacks the integer samples of a frame, ignoring any bits above those stored and
+/// sign-extending signed values. The high bit is assumed to be one less than the bits stored.
+pub(super) fn unpack_integers(
+    data: &[u8],
+    bytes_per_sample: usize,
+    bits_stored: u16,
+    is_signed: bool,
+    big_endian: bool,
+) -> Vec<f64> {
+    match bytes_per_sample {
+        1 => return unpack_narrow::<1>(data, bits_stored, is_signed, big_endian),
+        2 => return unpack_narrow::<2>(data, bits_stored, is_signed, big_endian),
+        _ => {}
+    }
+    data.chunks_exact(bytes_per_sample)
+        .map(|bytes| decode_integer(read_sample(bytes, big_endian), bits_stored, is_signed))
+        .collect()
+}
+
+/// Unpacks floating point samples of Float Pixel Data or Double Float Pixel Data.
+pub(super) fn unpack_floats(data: &[u8], bytes_per_sample: usize, big_endian: bool) -> Vec<f64> {
+    data.chunks_exact(bytes_per_sample)
+        .map(|bytes| {
+            let raw: u64 = read_sample(bytes, big_endian);
+            if bytes_per_sample == 4 {
+                f64::from(f32::from_bits(raw as u32))
+            } else {
+                f64::from_bits(raw)
+            }
+        })
+        .collect()
+}
+
+/// Unpacks samples with 8 or 16 bits allocated, the common cases, e.g. CT and MR with 12 bits
+/// stored. Stored bits are extracted with shifts rather than by treating each sample generically.
+fn unpack_narrow<const BYTES: usize>(
+    data: &[u8],
+    bits_stored: u16,
+    is_signed: bool,
+    big_endian: bool,
+) -> Vec<f64> {
+    let shift: u32 = 32 - u32::from(bits_stored);
+    let mask: i32 = ((1u32 << bits_stored) - 1) as i32;
+    let sample = |bytes: &[u8]| -> i32 {
+        if BYTES == 1 {
+            i32::from(bytes[0])
+        } else if big_endian {
+            i32::from(u16::from_be_bytes([bytes[0], bytes[1]]))
+        } else {
+            i32::from(u16::from_le_bytes([bytes[0], bytes[1]]))
+        }
+    };
+
+    let mut values: Vec<f64> = Vec::with_capacity(data.len() / BYTES);
+    #[cfg(feature = "simd")]
+    let data: &[u8] = {
+        let mut chunks = data.chunks_exact(BYTES * LANES);
+        for chunk in &mut chunks {
+            let lane = |lane: usize| sample(&chunk[lane * BYTES..]);
+            let samples: i32x4 = i32x4::from([lane(0), lane(1), lane(2), lane(3)]);
+            let samples: i32x4 = if is_signed {
+                (samples << shift) >> shift
+            } else {
+                samples & i32x4::splat(mask)
+            };
+            values.extend(f64x4::from_i32x4(samples).to_array());
+        }
+        chunks.remainder()
+    };
+    values.extend(data.chunks_exact(BYTES).map(|bytes| {
+        let sample: i32 = sample(bytes);
+        let value: i32 = if is_signed {
+            (sample << shift) >> shift
+        } else {
+            sample & mask
+        };
+        f64::from(value)
+    }));
+    values
+}
+
+/// Applies the rescale slope and intercept of the Modality LUT to each value.
+pub(super) fn rescale(values: &mut [f64], slope: f64, intercept: f64) {
+    #[cfg(feature = "simd")]
+    let values: &mut [f64] = {
+        let (slopes, intercepts) = (f64x4::splat(slope), f64x4::splat(intercept));
+        let mut chunks = values.chunks_exact_mut(LANES);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&(load(chunk) * slopes + intercepts).to_array());
+        }
+        chunks.into_remainder()
+    };
+    for value in values {
+        *value = *value * slope + intercept;
+    }
+}
+
+/// Converts interleaved YBR_FULL pixels to RGB, see Part 3, Ch C.7.6.3.1.2.
+///
+/// This isn't explicitly vectorized, as the compiler already vectorizes it and gathering the
+/// interleaved samples into vectors was measured to be slower.
+pub(super) fn ybr_to_rgb(values: &mut [f64], bits_stored: u16) {
+    let max: f64 = ((1u64 << bits_stored) - 1) as f64;
+    let mid: f64 = (1u64 << (bits_stored - 1)) as f64;
+    for pixel in values.chunks_exact_mut(3) {
+        let (y, cb, cr) = (pixel[0], pixel[1] - mid, pixel[2] - mid);
+        pixel[0] = (y + 1.402 * cr).clamp(0.0, max);
+        pixel[1] = (y - 0.344136 * cb - 0.714136 * cr).clamp(0.0, max);
+        pixel[2] = (y + 1.772 * cb).clamp(0.0, max);
+    }
+}
+
+/// Maps each value to a display value through the window, see `Window::apply()`. With `invert`
+/// the display values are inverted, as for MONOCHROME1.
+///
+/// This isn't explicitly vectorized, as most values of a typical frame are outside of a window and
+/// mapped without any arithmetic, so branching per value was measured to be faster than computing
+/// every value at once.
+pub(super) fn apply_window(window: &Window, values: &[f64], invert: bool) -> Vec<u8> {
+    values
+        .iter()
+        .map(|value| window.apply(*value))
+        .map(|value| if invert { u8::MAX - value } else { value })
+        .collect()
+}
+
+#[cfg(feature = "simd")]
+fn load(values: &[f64]) -> f64x4 {
+    f64x4::from([values[0], values[1], values[2], values[3]])
+}
+
+/// Reads a sample of up to 8 bytes as an unsigned integer.
+fn read_sample(bytes: &[u8], big_endian: bool) -> u64 {
+    let mut sample: [u8; 8] = [0u8; 8];
+    if big_endian {
+        sample[8 - bytes.len()..].copy_from_slice(bytes);
+        sample.reverse();
+    } else {
+        sample[..bytes.len()].copy_from_slice(bytes);
+    }
+    u64::from_le_bytes(sample)
+}
+
+/// Decodes an integer sample, ignoring any bits above those stored and sign-extending signed
+/// values.
+fn decode_integer(raw: u64, bits_stored: u16, is_signed: bool) -> f64 {
+    let value: u64 = raw & ((1u64 << bits_stored) - 1);
+    if is_signed && value >> (bits_stored - 1) == 1 {
+        (value as i64 - (1i64 << bits_stored)) as f64
+    } else {
+        value as f64
+    }
+}
@@ -0,0 +1,228 @@
+//! Pass-through of video encapsulated in Pixel Data, for the MPEG-2, MPEG-4 AVC/H.264, and
+//! HEVC/H.265 transfer syntaxes, see Part 5, Ch 8.2.5 to 8.2.7 and Ch A.4.
+//!
+//! The fragments of encapsulated video are a single stream split at arbitrary points, so joining
+//! them gives the stream as it was encoded, typically an MPEG transport stream or an elementary
+//! stream, which can be saved as-is and played. Likewise, an existing stream can be encapsulated
+//! into a dataset. The video is never decoded, so the caller is responsible for describing it.
+
+use crate::core::{
+    dcmelement::DicomElement,
+    dcmobject::DicomRoot,
+    defn::{
+        tag::{TagNode, TagPath},
+        ts::TSRef,
+        vr::{self, VRRef},
+    },
+    values::{Attribute, RawValue},
+};
+
+use super::{
+    encapsulated::{insert_encapsulated, EncapsulatedPixelData},
+    ImagePixel, PixelDataError, PixelDataResult,
+};
+
+const FRAME_TIME: u32 = 0x0018_1063;
+const FRAME_INCREMENT_POINTER: u32 = 0x0028_0009;
+const LOSSY_IMAGE_COMPRESSION: u32 = 0x0028_2110;
+const LOSSY_IMAGE_COMPRESSION_METHOD: u32 = 0x0028_2114;
+
+/// The length at which video is split into fragments when encapsulated. Fragments are limited to
+/// under 4GB by their value length, though smaller fragments are easier on readers.
+const FRAGMENT_LEN: usize = 1 << 24;
+
+/// The length of each packet of an MPEG transport stream, which starts with a sync byte.
+const TS_PACKET_LEN: usize = 188;
+const TS_SYNC_BYTE: u8 = 0x47;
+
+/// The start code of an MPEG program stream pack header.
+const PACK_START_CODE: [u8; 4] = [0x00, 0x00, 0x01, 0xBA];
+
+/// The codec a video transfer syntax is encoded with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VideoCodec {
+    /// MPEG-2 Video, ISO/IEC 13818-2.
+    Mpeg2,
+    /// MPEG-4 AVC/H.264, ISO/IEC 14496-10.
+    H264,
+    /// HEVC/H.265, ISO/IEC 23008-2.
+    Hevc,
+}
+
+/// The container of a video stream, as detected from its first bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VideoFormat {
+    /// An MPEG transport stream of 188 byte packets, ISO/IEC 13818-1.
+    TransportStream,
+    /// An MPEG program stream, ISO/IEC 13818-1.
+    ProgramStream,
+    /// A stream of the codec without any container.
+    Elementary(VideoCodec),
+}
+
+/// The attributes describing video which is encapsulated with `encapsulate_video()`, which can't
+/// be determined from the stream without decoding it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VideoAttributes {
+    pub rows: u16,
+    pub columns: u16,
+    /// The number of frames of the whole stream.
+    pub frames: usize,
+    /// The nominal time between frames, in milliseconds.
+    pub frame_time: f64,
+}
+
+impl VideoCodec {
+    /// Gets the codec of a video transfer syntax, or `None` if the transfer syntax isn't video.
+    pub fn of(ts: TSRef) -> Option<VideoCodec> {
+        let uid: &str = ts.uid().uid();
+        // The Fragmentable variants append `.1` to the UID of the equivalent transfer syntax.
+        let uid: &str = uid.strip_suffix(".1").unwrap_or(uid);
+        match uid.strip_prefix("1.2.840.10008.1.2.4.")? {
+            "100" | "101" => Some(VideoCodec::Mpeg2),
+            "102" | "103" | "104" | "105" | "106" => Some(VideoCodec::H264),
+            "107" | "108" => Some(VideoCodec::Hevc),
+            _ => None,
+        }
+    }
+
+    /// The value of Lossy Image Compression Method for the codec, see Part 3,
+    /// Ch C.7.6.1.1.5.1.
+    pub fn lossy_method(&self) -> &'static str {
+        match self {
+            VideoCodec::Mpeg2 => "ISO_13818_2",
+            VideoCodec::H264 => "ISO_14496_10",
+            VideoCodec::Hevc => "ISO_23008_2",
+        }
+    }
+}
+
+impl VideoFormat {
+    /// Detects the container of the stream, which is assumed to be an elementary stream of the
+    /// given codec if it's neither a transport stream nor a program stream.
+    pub fn detect(stream: &[u8], codec: VideoCodec) -> VideoFormat {
+        let is_transport_stream: bool = !stream.is_empty()
+            && stream
+                .iter()
+                .step_by(TS_PACKET_LEN)
+                .take(4)
+                .all(|byte| *byte == TS_SYNC_BYTE);
+        if is_transport_stream {
+            VideoFormat::TransportStream
+        } else if stream.starts_with(&PACK_START_CODE) {
+            VideoFormat::ProgramStream
+        } else {
+            VideoFormat::Elementary(codec)
+        }
+    }
+
+    /// The conventional file extension for a stream of this format, without the leading period.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            VideoFormat::TransportStream => "ts",
+            VideoFormat::ProgramStream => "mpg",
+            VideoFormat::Elementary(VideoCodec::Mpeg2) => "m2v",
+            VideoFormat::Elementary(VideoCodec::H264) => "h264",
+            VideoFormat::Elementary(VideoCodec::Hevc) => "h265",
+        }
+    }
+}
+
+/// Whether the transfer syntax encapsulates video, see `VideoCodec::of()`.
+pub fn is_video(ts: TSRef) -> bool {
+    VideoCodec::of(ts).is_some()
+}
+
+/// Extracts the video stream of the dataset's Pixel Data by joining its fragments, along with the
+/// detected format of the stream. The dataset must be in a video transfer syntax.
+///
+/// Fragments are padded to an even length, so the stream may have a trailing zero byte not
+/// present in the original, which players ignore.
+pub fn extract_video(dcmroot: &DicomRoot<'_>) -> PixelDataResult<(VideoFormat, Vec<u8>)> {
+    let codec: VideoCodec = VideoCodec::of(dcmroot.ts()).ok_or(
+        PixelDataError::UnsupportedTransferSyntax(dcmroot.ts().uid().ident()),
+    )?;
+    let stream: Vec<u8> = EncapsulatedPixelData::read(dcmroot)?.fragments().concat();
+    Ok((VideoFormat::detect(&stream, codec), stream))
+}
+
+/// Encapsulates the video stream as the dataset's Pixel Data, re-encoding the dataset into the
+/// given video transfer syntax. The stream must be encoded as required by the transfer syntax,
+/// which isn't checked beyond the stream being non-empty.
+///
+/// The Image Pixel attributes are set to describe 8-bit `YBR_PARTIAL_420` frames of the given
+/// dimensions, along with Frame Time and the Frame Increment Pointer referencing it. Lossy Image
+/// Compression is set to `01` with the method of the codec.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip(dcmroot, stream), fields(ts = ts.uid().name()))
+)]
+pub fn encapsulate_video(
+    dcmroot: &mut DicomRoot<'_>,
+    stream: &[u8],
+    ts: TSRef,
+    attributes: &VideoAttributes,
+    stage: &str,
+) -> PixelDataResult<()> {
+    let codec: VideoCodec =
+        VideoCodec::of(ts).ok_or(PixelDataError::UnsupportedTransferSyntax(ts.uid().ident()))?;
+    let image: ImagePixel = ImagePixel {
+        rows: attributes.rows,
+        columns: attributes.columns,
+        samples_per_pixel: 3,
+        photometric_interpretation: "YBR_PARTIAL_420".to_owned(),
+        planar_configuration: Some(0),
+        bits_allocated: 8,
+        bits_stored: 8,
+        high_bit: 7,
+        pixel_representation: 0,
+        number_of_frames: attributes.frames,
+    };
+    image.validate()?;
+    if stream.is_empty() {
+        return Err(PixelDataError::InsufficientData {
+            expected: 1,
+            actual: 0,
+        });
+    }
+    if !attributes.frame_time.is_finite() || attributes.frame_time <= 0.0 {
+        return Err(PixelDataError::InvalidImagePixel(format!(
+            "frame time of {}ms",
+            attributes.frame_time
+        )));
+    }
+
+    dcmroot.transcode_in_place(ts)?;
+    image.insert_into(dcmroot, stage)?;
+    let elements: Vec<(u32, VRRef, RawValue)> = vec![
+        (
+            FRAME_TIME,
+            &vr::DS,
+            RawValue::Doubles(vec![attributes.frame_time]),
+        ),
+        (
+            FRAME_INCREMENT_POINTER,
+            &vr::AT,
+            RawValue::Attribute(vec![Attribute(FRAME_TIME)]),
+        ),
+        (
+            LOSSY_IMAGE_COMPRESSION,
+            &vr::CS,
+            RawValue::Strings(vec!["01".to_owned()]),
+        ),
+        (
+            LOSSY_IMAGE_COMPRESSION_METHOD,
+            &vr::CS,
+            RawValue::Strings(vec![codec.lossy_method().to_owned()]),
+        ),
+    ];
+    for (tag, vr, value) in elements {
+        let mut element: DicomElement = DicomElement::new_empty(tag, vr, ts);
+        element.encode_value(value, None)?;
+        dcmroot.insert_element(&TagPath::from(vec![TagNode::from(tag)]), element, stage)?;
+    }
+
+    let fragments: Vec<Vec<u8>> = stream.chunks(FRAGMENT_LEN).map(<[u8]>::to_vec).collect();
+    // Frames aren't aligned to fragments, so the Basic Offset Table is left empty.
+    insert_encapsulated(dcmroot, fragments, false, stage)
+}
@@ -0,0 +1,129 @@
+//! VOI LUTs, which map values to display values through a lookup table rather than a linear
+//! `Window`, see Part 3, Ch C.11.2.1.1.
+
+use crate::core::{
+    dcmelement::DicomElement,
+    dcmobject::{DicomObject, DicomRoot},
+    values::RawValue,
+};
+
+use super::{PixelDataError, PixelDataResult};
+
+const PIXEL_REPRESENTATION: u32 = 0x0028_0103;
+const LUT_DESCRIPTOR: u32 = 0x0028_3002;
+const LUT_EXPLANATION: u32 = 0x0028_3003;
+const LUT_DATA: u32 = 0x0028_3006;
+const VOI_LUT_SEQUENCE: u32 = 0x0028_3010;
+
+/// A lookup table from the items of the VOI LUT Sequence.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VoiLut {
+    /// The value mapped to the first entry. Lower values map to the first entry.
+    first_mapped: i32,
+    /// The number of bits of each entry which are used.
+    bits: u16,
+    entries: Vec<u16>,
+    explanation: Option<String>,
+}
+
+impl VoiLut {
+    /// Reads the LUTs of the dataset's VOI LUT Sequence, if any. The first value mapped is signed
+    /// when the Pixel Representation is, see Part 3, Ch C.11.2.1.1.
+    pub fn read_all(dcmroot: &DicomRoot<'_>) -> PixelDataResult<Vec<VoiLut>> {
+        let Some(sequence) = dcmroot.get_child_by_tag(VOI_LUT_SEQUENCE) else {
+            return Ok(Vec::new());
+        };
+        let is_signed: bool = match dcmroot.get_child_by_tag(PIXEL_REPRESENTATION) {
+            Some(obj) => u16::try_from(obj.element())? == 1,
+            None => false,
+        };
+        sequence
+            .iter_items()
+            .map(|item| VoiLut::read(item, is_signed))
+            .collect()
+    }
+
+    fn read(item: &DicomObject<'_>, is_signed: bool) -> PixelDataResult<VoiLut> {
+        let descriptor: Vec<i32> = match item.get_child_by_tag(LUT_DESCRIPTOR) {
+            Some(obj) => match obj.element().parse_value()? {
+                RawValue::UnsignedShorts(values) if values.len() == 3 => vec![
+                    i32::from(values[0]),
+                    if is_signed {
+                        i32::from(values[1] as i16)
+                    } else {
+                        i32::from(values[1])
+                    },
+                    i32::from(values[2]),
+                ],
+                RawValue::Shorts(values) if values.len() == 3 => vec![
+                    i32::from(values[0] as u16),
+                    i32::from(values[1]),
+                    i32::from(values[2] as u16),
+                ],
+                _ => {
+                    return Err(PixelDataError::InvalidImagePixel(
+                        "LUTDescriptor".to_owned(),
+                    ))
+                }
+            },
+            None => return Err(PixelDataError::MissingAttribute("LUTDescriptor")),
+        };
+        // A count of 0 means 65536 entries.
+        let count: usize = match descriptor[0] {
+            0 => 65536,
+            count => count as usize,
+        };
+        let bits: u16 = descriptor[2] as u16;
+        if !(1..=16).contains(&bits) {
+            return Err(PixelDataError::UnsupportedEncoding(format!(
+                "LUT with {bits} bits per entry"
+            )));
+        }
+
+        let element: &DicomElement = item
+            .get_child_by_tag(LUT_DATA)
+            .ok_or(PixelDataError::MissingAttribute("LUTData"))?
+            .element();
+        let entries: Vec<u16> = match element.parse_value()? {
+            RawValue::UnsignedShorts(entries) | RawValue::Words(entries) => entries,
+            RawValue::Bytes(bytes) => bytes
+                .chunks_exact(2)
+                .map(|entry| u16::from_le_bytes([entry[0], entry[1]]))
+                .collect(),
+            _ => return Err(PixelDataError::InvalidImagePixel("LUTData".to_owned())),
+        };
+        if entries.len() < count {
+            return Err(PixelDataError::InsufficientData {
+                expected: count * 2,
+                actual: entries.len() * 2,
+            });
+        }
+        let explanation: Option<String> = item
+            .get_child_by_tag(LUT_EXPLANATION)
+            .map(|obj| String::try_from(obj.element()))
+            .transpose()?
+            .map(|explanation| explanation.trim().to_owned());
+
+        Ok(VoiLut {
+            first_mapped: descriptor[1],
+            bits,
+            entries: entries.into_iter().take(count).collect(),
+            explanation,
+        })
+    }
+
+    /// The LUT Explanation, e.g. `SOFT TISSUE`, if any.
+    pub fn explanation(&self) -> Option<&str> {
+        self.explanation.as_deref()
+    }
+
+    /// Maps a value to a display value through the LUT. Values beyond the range of the LUT map to
+    /// its first or last entry.
+    pub fn apply(&self, value: f64) -> u8 {
+        let index: f64 = (value.round() - f64::from(self.first_mapped))
+            .clamp(0.0, (self.entries.len() - 1) as f64);
+        let max: f64 = ((1u32 << self.bits) - 1) as f64;
+        let entry: f64 = f64::from(self.entries[index as usize]).min(max);
+        (entry / max * 255.0).round() as u8
+    }
+}
@@ -0,0 +1,884 @@
+//! Assembly of the frames of a series into a volume with its geometry in patient space, see
+//! Part 3, Ch C.7.6.2.
+//!
+//! Frames are placed by their Image Position (Patient) and Image Orientation (Patient), read from
+//! the Plane Position and Plane Orientation functional groups of Enhanced multi-frame objects, see
+//! Part 3, Ch C.7.6.16, or otherwise from the attributes of the dataset. Frames are ordered along
+//! the normal of their orientation, which all frames must share, and must be evenly spaced. The
+//! volume is in the DICOM patient coordinate system, whose axes point to the patient's left,
+//! posterior, and head (LPS), and can be read from or written as NIfTI, see `nifti`, or
+//! MetaImage, see `metaimage`.
+//!
+//! A volume can also be written back into datasets, either a slice at a time as single-frame
+//! images with `insert_slice()`, or as the frames of an Enhanced multi-frame object with
+//! `insert_frames()`. Values are stored as 16-bit samples, rescaled by the Modality LUT when they
+//! aren't integers in range, see `SampleEncoding`.
+
+use crate::core::{
+    dcmelement::DicomElement,
+    dcmobject::{DicomObject, DicomRoot},
+    defn::{
+        constants::{tags, ts},
+        tag::{TagNode, TagPath},
+        vl::ValueLength,
+        vr::{self, VRRef},
+    },
+    values::RawValue,
+    write::transcode::transcode_element,
+};
+
+use super::{
+    FrameDecoder, ImagePixel, Photometric, PixelDataError, PixelDataResult, PixelFrame,
+    NUMBER_OF_FRAMES, RESCALE_INTERCEPT, RESCALE_SLOPE, WINDOW_CENTER, WINDOW_WIDTH,
+};
+
+const SLICE_THICKNESS: u32 = 0x0018_0050;
+const IMAGE_POSITION_PATIENT: u32 = 0x0020_0032;
+const IMAGE_ORIENTATION_PATIENT: u32 = 0x0020_0037;
+const SLICE_LOCATION: u32 = 0x0020_1041;
+const STACK_ID: u32 = 0x0020_9056;
+const IN_STACK_POSITION_NUMBER: u32 = 0x0020_9057;
+const FRAME_CONTENT_SEQUENCE: u32 = 0x0020_9111;
+const PLANE_POSITION_SEQUENCE: u32 = 0x0020_9113;
+const PLANE_ORIENTATION_SEQUENCE: u32 = 0x0020_9116;
+const DIMENSION_ORGANIZATION_SEQUENCE: u32 = 0x0020_9221;
+const DIMENSION_INDEX_SEQUENCE: u32 = 0x0020_9222;
+const PIXEL_SPACING: u32 = 0x0028_0030;
+const WINDOW_CENTER_WIDTH_EXPLANATION: u32 = 0x0028_1055;
+const RESCALE_TYPE: u32 = 0x0028_1054;
+const MODALITY_LUT_SEQUENCE: u32 = 0x0028_3000;
+const VOI_LUT_SEQUENCE: u32 = 0x0028_3010;
+const PIXEL_MEASURES_SEQUENCE: u32 = 0x0028_9110;
+const PIXEL_VALUE_TRANSFORMATION_SEQUENCE: u32 = 0x0028_9145;
+const SHARED_FUNCTIONAL_GROUPS_SEQUENCE: u32 = 0x5200_9229;
+const PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE: u32 = 0x5200_9230;
+
+/// Attributes of a template dataset describing its own Pixel Data, which are removed when a volume
+/// is inserted into it.
+const TEMPLATE_PIXEL_ATTRIBUTES: [u32; 18] = [
+    SLICE_THICKNESS,
+    IMAGE_POSITION_PATIENT,
+    IMAGE_ORIENTATION_PATIENT,
+    SLICE_LOCATION,
+    DIMENSION_ORGANIZATION_SEQUENCE,
+    DIMENSION_INDEX_SEQUENCE,
+    NUMBER_OF_FRAMES,
+    PIXEL_SPACING,
+    WINDOW_CENTER,
+    WINDOW_WIDTH,
+    RESCALE_INTERCEPT,
+    RESCALE_SLOPE,
+    RESCALE_TYPE,
+    WINDOW_CENTER_WIDTH_EXPLANATION,
+    MODALITY_LUT_SEQUENCE,
+    VOI_LUT_SEQUENCE,
+    SHARED_FUNCTIONAL_GROUPS_SEQUENCE,
+    PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE,
+];
+
+/// The difference in direction cosines below which frames share an orientation.
+const ORIENTATION_TOLERANCE: f64 = 1e-3;
+/// The fraction of the slice spacing by which the spacing between any two slices may differ.
+const SPACING_TOLERANCE: f64 = 0.01;
+
+/// The smallest type which holds every voxel value of a volume without loss, for writing it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VoxelType {
+    I16,
+    I32,
+    F32,
+}
+
+/// The type of the voxels of a volume file being read, see `decode_voxels()`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum StoredType {
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    F32,
+    F64,
+}
+
+impl StoredType {
+    fn size(self) -> usize {
+        match self {
+            StoredType::U8 | StoredType::I8 => 1,
+            StoredType::U16 | StoredType::I16 => 2,
+            StoredType::U32 | StoredType::I32 | StoredType::F32 => 4,
+            StoredType::F64 => 8,
+        }
+    }
+}
+
+/// How the voxel values of a volume are stored as 16-bit samples, where the value of a sample is
+/// `sample * slope + intercept`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SampleEncoding {
+    /// Whether samples are signed, i.e. the Pixel Representation is 1.
+    pub signed: bool,
+    pub slope: f64,
+    pub intercept: f64,
+}
+
+/// A volume of monochrome voxels in the patient coordinate system.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Volume {
+    /// The number of columns, rows, and slices.
+    dimensions: [usize; 3],
+    /// The distance between the centers of voxels along each axis, in mm.
+    spacing: [f64; 3],
+    /// The position of the center of the first voxel.
+    origin: [f64; 3],
+    /// The unit vectors along which columns, rows, and slices increase.
+    direction: [[f64; 3]; 3],
+    /// The voxel values, with columns varying fastest and then rows.
+    values: Vec<f64>,
+}
+
+/// The geometry and values of a frame, for placing it in a volume.
+struct Slice {
+    position: [f64; 3],
+    orientation: [[f64; 3]; 2],
+    /// The spacing between columns and between rows.
+    spacing: [f64; 2],
+    thickness: Option<f64>,
+    frame: PixelFrame,
+}
+
+impl Volume {
+    /// Creates a volume of the given geometry, failing if the number of values doesn't match the
+    /// dimensions.
+    pub fn new(
+        dimensions: [usize; 3],
+        spacing: [f64; 3],
+        origin: [f64; 3],
+        direction: [[f64; 3]; 3],
+        values: Vec<f64>,
+    ) -> PixelDataResult<Volume> {
+        let expected: usize = dimensions.iter().product();
+        if values.len() != expected {
+            return Err(PixelDataError::InvalidGeometry(format!(
+                "{} values for a volume of {} voxels",
+                values.len(),
+                expected
+            )));
+        }
+        if spacing
+            .iter()
+            .any(|spacing| !(spacing.is_finite() && *spacing > 0.0))
+        {
+            return Err(PixelDataError::InvalidGeometry(format!(
+                "voxel spacing {spacing:?}"
+            )));
+        }
+        Ok(Volume {
+            dimensions,
+            spacing,
+            origin,
+            direction,
+            values,
+        })
+    }
+
+    /// Creates a volume from the matrix mapping a voxel's column, row, and slice to its position
+    /// in LPS, as read from a volume file.
+    pub(crate) fn from_affine(
+        dimensions: [usize; 3],
+        affine: [[f64; 4]; 3],
+        values: Vec<f64>,
+    ) -> PixelDataResult<Volume> {
+        let mut spacing: [f64; 3] = [0.0; 3];
+        let mut direction: [[f64; 3]; 3] = [[0.0; 3]; 3];
+        for axis in 0..3 {
+            let column: [f64; 3] = std::array::from_fn(|row| affine[row][axis]);
+            spacing[axis] = dot(column, column).sqrt();
+            direction[axis] = normalize(column)?;
+        }
+        let origin: [f64; 3] = std::array::from_fn(|row| affine[row][3]);
+        Volume::new(dimensions, spacing, origin, direction, values)
+    }
+
+    /// Assembles the frames of the instances of a series into a volume. Every frame must be
+    /// monochrome, of the same size, spacing, and orientation, and evenly spaced along the normal
+    /// of its orientation. The values are those of the Modality LUT, e.g. Hounsfield units.
+    pub fn from_instances<'d, I>(instances: I) -> PixelDataResult<Volume>
+    where
+        I: IntoIterator<Item = &'d DicomRoot<'d>>,
+    {
+        let mut slices: Vec<Slice> = Vec::new();
+        for dcmroot in instances {
+            let decoder: FrameDecoder = FrameDecoder::new(dcmroot)?;
+            for index in 0..decoder.frame_count() {
+                let frame: PixelFrame = decoder.decode(index)?;
+                if frame.photometric() == Photometric::Rgb {
+                    return Err(PixelDataError::UnsupportedEncoding(
+                        "volumes of color frames".to_owned(),
+                    ));
+                }
+                slices.push(Slice::read(dcmroot, index, frame)?);
+            }
+        }
+        let Some(first) = slices.first() else {
+            return Err(PixelDataError::MissingPixelData);
+        };
+
+        let (rows, columns) = (first.frame.rows(), first.frame.columns());
+        let [row_direction, column_direction] = first.orientation;
+        let normal: [f64; 3] = normalize(cross(row_direction, column_direction))?;
+        let pixel_spacing: [f64; 2] = first.spacing;
+        for slice in &slices {
+            if (slice.frame.rows(), slice.frame.columns()) != (rows, columns) {
+                return Err(PixelDataError::InvalidGeometry(
+                    "frames differ in rows or columns".to_owned(),
+                ));
+            }
+            let differs = |a: [f64; 3], b: [f64; 3]| {
+                a.iter()
+                    .zip(b)
+                    .any(|(a, b)| (a - b).abs() > ORIENTATION_TOLERANCE)
+            };
+            if differs(slice.orientation[0], row_direction)
+                || differs(slice.orientation[1], column_direction)
+            {
+                return Err(PixelDataError::InvalidGeometry(
+                    "frames differ in orientation".to_owned(),
+                ));
+            }
+            if slice.spacing != pixel_spacing {
+                return Err(PixelDataError::InvalidGeometry(
+                    "frames differ in pixel spacing".to_owned(),
+                ));
+            }
+        }
+
+        slices.sort_by(|a, b| dot(a.position, normal).total_cmp(&dot(b.position, normal)));
+        let distances: Vec<f64> = slices
+            .windows(2)
+            .map(|pair| dot(pair[1].position, normal) - dot(pair[0].position, normal))
+            .collect();
+        let slice_spacing: f64 = if distances.is_empty() {
+            slices[0].thickness.unwrap_or(1.0)
+        } else {
+            distances.iter().sum::<f64>() / distances.len() as f64
+        };
+        if distances.iter().any(|distance| {
+            *distance <= 0.0 || (distance - slice_spacing).abs() > slice_spacing * SPACING_TOLERANCE
+        }) {
+            return Err(PixelDataError::InvalidGeometry(
+                "frames are not evenly spaced or share a position".to_owned(),
+            ));
+        }
+
+        let origin: [f64; 3] = slices[0].position;
+        let depth: usize = slices.len();
+        let values: Vec<f64> = slices
+            .into_iter()
+            .flat_map(|slice| slice.frame.values().to_vec())
+            .collect();
+        Volume::new(
+            [columns, rows, depth],
+            [pixel_spacing[0], pixel_spacing[1], slice_spacing],
+            origin,
+            [row_direction, column_direction, normal],
+            values,
+        )
+    }
+
+    /// The number of columns, rows, and slices.
+    pub fn dimensions(&self) -> [usize; 3] {
+        self.dimensions
+    }
+
+    /// The distance between the centers of voxels along columns, rows, and slices, in mm.
+    pub fn spacing(&self) -> [f64; 3] {
+        self.spacing
+    }
+
+    /// The position of the center of the first voxel, in mm.
+    pub fn origin(&self) -> [f64; 3] {
+        self.origin
+    }
+
+    /// The unit vectors along which columns, rows, and slices increase. The first two are the
+    /// Image Orientation (Patient) of each slice.
+    pub fn direction(&self) -> [[f64; 3]; 3] {
+        self.direction
+    }
+
+    /// The voxel values, with columns varying fastest and then rows.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// The values of the slice at the given zero-based index, if within range.
+    pub fn slice(&self, slice: usize) -> Option<&[f64]> {
+        let len: usize = self.dimensions[0] * self.dimensions[1];
+        self.values.get(slice * len..(slice + 1) * len)
+    }
+
+    /// The position of the center of the voxel at the given column, row, and slice, in mm.
+    pub fn position(&self, column: f64, row: f64, slice: f64) -> [f64; 3] {
+        let steps: [f64; 3] = [column, row, slice];
+        std::array::from_fn(|axis| {
+            self.origin[axis]
+                + (0..3)
+                    .map(|i| steps[i] * self.spacing[i] * self.direction[i][axis])
+                    .sum::<f64>()
+        })
+    }
+
+    /// The smallest type which holds every value without loss.
+    pub fn voxel_type(&self) -> VoxelType {
+        let integral: bool = self.values.iter().all(|value| value.fract() == 0.0);
+        let in_range = |min: f64, max: f64| self.values.iter().all(|v| (min..=max).contains(v));
+        if !integral {
+            VoxelType::F32
+        } else if in_range(f64::from(i16::MIN), f64::from(i16::MAX)) {
+            VoxelType::I16
+        } else if in_range(f64::from(i32::MIN), f64::from(i32::MAX)) {
+            VoxelType::I32
+        } else {
+            VoxelType::F32
+        }
+    }
+
+    /// How the values are stored as 16-bit samples. Integer values which fit are stored as they
+    /// are, while others are scaled to span the range of unsigned samples. The slope and intercept
+    /// are those written as DS values, so are slightly rounded.
+    pub fn sample_encoding(&self) -> SampleEncoding {
+        let integral: bool = self.values.iter().all(|value| value.fract() == 0.0);
+        let (min, max) =
+            super::histogram::min_max(self.values.iter().copied()).unwrap_or((0.0, 0.0));
+        let fits = |low: i32, high: i32| min >= f64::from(low) && max <= f64::from(high);
+        if integral && fits(i32::from(i16::MIN), i32::from(i16::MAX)) {
+            SampleEncoding {
+                signed: true,
+                slope: 1.0,
+                intercept: 0.0,
+            }
+        } else if integral && fits(0, i32::from(u16::MAX)) {
+            SampleEncoding {
+                signed: false,
+                slope: 1.0,
+                intercept: 0.0,
+            }
+        } else {
+            let slope: f64 = if max > min {
+                (max - min) / f64::from(u16::MAX)
+            } else {
+                1.0
+            };
+            let as_ds = |value: f64| format_ds(value).parse::<f64>().unwrap_or(value);
+            SampleEncoding {
+                signed: false,
+                slope: as_ds(slope),
+                intercept: as_ds(min),
+            }
+        }
+    }
+
+    /// Stores the values of the given slices as samples with the given encoding.
+    fn encode_samples(
+        &self,
+        slices: std::ops::Range<usize>,
+        encoding: &SampleEncoding,
+    ) -> Vec<u16> {
+        let len: usize = self.dimensions[0] * self.dimensions[1];
+        let (low, high) = if encoding.signed {
+            (f64::from(i16::MIN), f64::from(i16::MAX))
+        } else {
+            (0.0, f64::from(u16::MAX))
+        };
+        self.values[slices.start * len..slices.end * len]
+            .iter()
+            .map(|value| {
+                let sample: f64 = ((value - encoding.intercept) / encoding.slope)
+                    .round()
+                    .clamp(low, high);
+                if encoding.signed {
+                    sample as i16 as u16
+                } else {
+                    sample as u16
+                }
+            })
+            .collect()
+    }
+
+    /// Encodes the values as the given type in little endian, in the order of `values()`.
+    pub(crate) fn encode_values(&self, voxel_type: VoxelType) -> Vec<u8> {
+        let size: usize = match voxel_type {
+            VoxelType::I16 => 2,
+            VoxelType::I32 | VoxelType::F32 => 4,
+        };
+        let mut data: Vec<u8> = Vec::with_capacity(self.values.len() * size);
+        for value in &self.values {
+            match voxel_type {
+                VoxelType::I16 => data.extend((*value as i16).to_le_bytes()),
+                VoxelType::I32 => data.extend((*value as i32).to_le_bytes()),
+                VoxelType::F32 => data.extend((*value as f32).to_le_bytes()),
+            }
+        }
+        data
+    }
+}
+
+/// Decodes the given number of voxels of a volume file.
+pub(crate) fn decode_voxels(
+    data: &[u8],
+    stored: StoredType,
+    big_endian: bool,
+    count: usize,
+) -> PixelDataResult<Vec<f64>> {
+    let size: usize = stored.size();
+    let len: usize = count.saturating_mul(size);
+    if data.len() < len {
+        return Err(PixelDataError::InvalidVolumeFile(format!(
+            "{} bytes of voxel data, expected {len}",
+            data.len()
+        )));
+    }
+    let values = data[..len].chunks_exact(size).map(|bytes| {
+        let mut bytes: [u8; 8] = std::array::from_fn(|i| bytes.get(i).copied().unwrap_or(0));
+        if big_endian {
+            bytes[..size].reverse();
+        }
+        let [b0, b1, b2, b3, ..] = bytes;
+        match stored {
+            StoredType::U8 => f64::from(b0),
+            StoredType::I8 => f64::from(b0 as i8),
+            StoredType::U16 => f64::from(u16::from_le_bytes([b0, b1])),
+            StoredType::I16 => f64::from(i16::from_le_bytes([b0, b1])),
+            StoredType::U32 => f64::from(u32::from_le_bytes([b0, b1, b2, b3])),
+            StoredType::I32 => f64::from(i32::from_le_bytes([b0, b1, b2, b3])),
+            StoredType::F32 => f64::from(f32::from_le_bytes([b0, b1, b2, b3])),
+            StoredType::F64 => f64::from_le_bytes(bytes),
+        }
+    });
+    Ok(values.collect())
+}
+
+/// Replaces the Pixel Data of a template dataset, e.g. an instance of the series a volume was
+/// derived from, with the slice of the volume at the given zero-based index, along with its Image
+/// Pixel and Image Plane attributes and Modality LUT. Attributes describing the template's own
+/// Pixel Data, such as its window or functional groups, are removed. The dataset is transcoded to
+/// Explicit VR Little Endian if its transfer syntax is encapsulated.
+pub fn insert_slice(
+    dcmroot: &mut DicomRoot<'_>,
+    volume: &Volume,
+    slice: usize,
+    stage: &str,
+) -> PixelDataResult<()> {
+    if slice >= volume.dimensions[2] {
+        return Err(PixelDataError::FrameOutOfRange {
+            frame: slice,
+            frames: volume.dimensions[2],
+        });
+    }
+    let encoding: SampleEncoding = volume.sample_encoding();
+    prepare_template(dcmroot, volume, 1, &encoding, stage)?;
+
+    let [row_direction, column_direction, _] = volume.direction;
+    let orientation: Vec<f64> = row_direction.into_iter().chain(column_direction).collect();
+    let mut elements: Vec<DicomElement<'static>> = vec![
+        new_element(
+            dcmroot,
+            IMAGE_POSITION_PATIENT,
+            &vr::DS,
+            ds(&volume.position(0.0, 0.0, slice as f64)),
+        )?,
+        new_element(
+            dcmroot,
+            IMAGE_ORIENTATION_PATIENT,
+            &vr::DS,
+            ds(&orientation),
+        )?,
+        new_element(dcmroot, SLICE_THICKNESS, &vr::DS, ds(&[volume.spacing[2]]))?,
+    ];
+    elements.extend(pixel_spacing(dcmroot, volume)?);
+    elements.extend(rescale(dcmroot, &encoding)?);
+    elements.push(new_element(
+        dcmroot,
+        tags::PIXEL_DATA,
+        &vr::OW,
+        RawValue::Words(volume.encode_samples(slice..slice + 1, &encoding)),
+    )?);
+    for element in elements {
+        let tagpath: TagPath = TagPath::from(vec![TagNode::from(element.tag())]);
+        dcmroot.insert_element(&tagpath, element, stage)?;
+    }
+    Ok(())
+}
+
+/// Replaces the Pixel Data of a template dataset with every slice of the volume as the frames of
+/// an Enhanced multi-frame object, as `insert_slice()`. The pixel spacing, orientation, and
+/// Modality LUT are shared by all frames, while the position of each frame is in its Plane
+/// Position functional group, see Part 3, Ch C.7.6.16. The SOP Class is left to the caller.
+pub fn insert_frames(
+    dcmroot: &mut DicomRoot<'_>,
+    volume: &Volume,
+    stage: &str,
+) -> PixelDataResult<()> {
+    let encoding: SampleEncoding = volume.sample_encoding();
+    let depth: usize = volume.dimensions[2];
+    prepare_template(dcmroot, volume, depth, &encoding, stage)?;
+
+    let [row_direction, column_direction, _] = volume.direction;
+    let orientation: Vec<f64> = row_direction.into_iter().chain(column_direction).collect();
+    let mut measures: Vec<DicomElement<'static>> = pixel_spacing(dcmroot, volume)?;
+    measures.push(new_element(
+        dcmroot,
+        SLICE_THICKNESS,
+        &vr::DS,
+        ds(&[volume.spacing[2]]),
+    )?);
+    let shared: Vec<DicomObject<'static>> = vec![
+        sequence(dcmroot, PIXEL_MEASURES_SEQUENCE, vec![measures]),
+        sequence(
+            dcmroot,
+            PLANE_ORIENTATION_SEQUENCE,
+            vec![vec![new_element(
+                dcmroot,
+                IMAGE_ORIENTATION_PATIENT,
+                &vr::DS,
+                ds(&orientation),
+            )?]],
+        ),
+        sequence(
+            dcmroot,
+            PIXEL_VALUE_TRANSFORMATION_SEQUENCE,
+            vec![rescale(dcmroot, &encoding)?],
+        ),
+    ];
+    let mut per_frame: Vec<Vec<DicomObject<'static>>> = Vec::with_capacity(depth);
+    for slice in 0..depth {
+        let content: Vec<DicomElement<'static>> = vec![
+            new_element(
+                dcmroot,
+                STACK_ID,
+                &vr::SH,
+                RawValue::Strings(vec!["1".to_owned()]),
+            )?,
+            new_element(
+                dcmroot,
+                IN_STACK_POSITION_NUMBER,
+                &vr::UL,
+                RawValue::UnsignedIntegers(vec![slice as u32 + 1]),
+            )?,
+        ];
+        let position: DicomElement<'static> = new_element(
+            dcmroot,
+            IMAGE_POSITION_PATIENT,
+            &vr::DS,
+            ds(&volume.position(0.0, 0.0, slice as f64)),
+        )?;
+        per_frame.push(vec![
+            sequence(dcmroot, FRAME_CONTENT_SEQUENCE, vec![content]),
+            sequence(dcmroot, PLANE_POSITION_SEQUENCE, vec![vec![position]]),
+        ]);
+    }
+
+    insert_sequence(
+        dcmroot,
+        SHARED_FUNCTIONAL_GROUPS_SEQUENCE,
+        vec![shared],
+        stage,
+    )?;
+    insert_sequence(
+        dcmroot,
+        PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE,
+        per_frame,
+        stage,
+    )?;
+    let pixel_data: DicomElement<'static> = new_element(
+        dcmroot,
+        tags::PIXEL_DATA,
+        &vr::OW,
+        RawValue::Words(volume.encode_samples(0..depth, &encoding)),
+    )?;
+    dcmroot.insert_element(
+        &TagPath::from(vec![TagNode::from(tags::PIXEL_DATA)]),
+        pixel_data,
+        stage,
+    )?;
+    dcmroot.update_value_lengths();
+    Ok(())
+}
+
+/// Removes the attributes of the template's own Pixel Data, transcodes it if encapsulated, and
+/// inserts the Image Pixel attributes of 16-bit samples.
+fn prepare_template(
+    dcmroot: &mut DicomRoot<'_>,
+    volume: &Volume,
+    frames: usize,
+    encoding: &SampleEncoding,
+    stage: &str,
+) -> PixelDataResult<()> {
+    let (columns, rows) = (volume.dimensions[0], volume.dimensions[1]);
+    let size = |size: usize| {
+        u16::try_from(size).map_err(|_| {
+            PixelDataError::UnsupportedEncoding(format!("frames of {size} rows or columns"))
+        })
+    };
+    let image: ImagePixel = ImagePixel {
+        rows: size(rows)?,
+        columns: size(columns)?,
+        samples_per_pixel: 1,
+        photometric_interpretation: "MONOCHROME2".to_owned(),
+        planar_configuration: None,
+        bits_allocated: 16,
+        bits_stored: 16,
+        high_bit: 15,
+        pixel_representation: u16::from(encoding.signed),
+        number_of_frames: frames,
+    };
+
+    let pixel_data_tags = [
+        tags::PIXEL_DATA,
+        tags::FLOAT_PIXEL_DATA,
+        tags::DOUBLE_PIXEL_DATA,
+    ];
+    for tag in TEMPLATE_PIXEL_ATTRIBUTES.into_iter().chain(pixel_data_tags) {
+        dcmroot.remove_element(&TagPath::from(vec![TagNode::from(tag)]));
+    }
+    if dcmroot.ts().encapsulated() {
+        dcmroot.transcode_in_place(&ts::ExplicitVRLittleEndian)?;
+    }
+    image.insert_into(dcmroot, stage)?;
+    Ok(())
+}
+
+/// The Pixel Spacing of the volume, the spacing between rows then between columns.
+fn pixel_spacing(
+    dcmroot: &DicomRoot<'_>,
+    volume: &Volume,
+) -> PixelDataResult<Vec<DicomElement<'static>>> {
+    Ok(vec![new_element(
+        dcmroot,
+        PIXEL_SPACING,
+        &vr::DS,
+        ds(&[volume.spacing[1], volume.spacing[0]]),
+    )?])
+}
+
+/// The attributes of the Modality LUT of the sample encoding.
+fn rescale(
+    dcmroot: &DicomRoot<'_>,
+    encoding: &SampleEncoding,
+) -> PixelDataResult<Vec<DicomElement<'static>>> {
+    Ok(vec![
+        new_element(
+            dcmroot,
+            RESCALE_INTERCEPT,
+            &vr::DS,
+            ds(&[encoding.intercept]),
+        )?,
+        new_element(dcmroot, RESCALE_SLOPE, &vr::DS, ds(&[encoding.slope]))?,
+        new_element(
+            dcmroot,
+            RESCALE_TYPE,
+            &vr::LO,
+            RawValue::Strings(vec!["US".to_owned()]),
+        )?,
+    ])
+}
+
+fn new_element(
+    dcmroot: &DicomRoot<'_>,
+    tag: u32,
+    vr: VRRef,
+    value: RawValue,
+) -> PixelDataResult<DicomElement<'static>> {
+    let mut element: DicomElement<'static> = DicomElement::new_empty(tag, vr, dcmroot.ts());
+    element.encode_value(value, None)?;
+    Ok(element)
+}
+
+/// Creates a sequence whose items hold the given elements, to be nested within an item of another
+/// sequence. Value lengths are set by `DicomRoot::update_value_lengths()`.
+fn sequence(
+    dcmroot: &DicomRoot<'_>,
+    tag: u32,
+    items: Vec<Vec<DicomElement<'static>>>,
+) -> DicomObject<'static> {
+    let items: Vec<Vec<DicomObject<'static>>> = items
+        .into_iter()
+        .map(|elements| elements.into_iter().map(DicomObject::new).collect())
+        .collect();
+    DicomObject::new_with_children(
+        empty_element(dcmroot, tag, &vr::SQ),
+        Default::default(),
+        new_items(dcmroot, items),
+    )
+}
+
+/// Inserts a sequence at the top level of the dataset whose items hold the given objects.
+fn insert_sequence(
+    dcmroot: &mut DicomRoot<'_>,
+    tag: u32,
+    items: Vec<Vec<DicomObject<'static>>>,
+    stage: &str,
+) -> PixelDataResult<()> {
+    let tagpath: TagPath = TagPath::from(vec![TagNode::from(tag)]);
+    let element: DicomElement<'static> = empty_element(dcmroot, tag, &vr::SQ);
+    let items: Vec<DicomObject<'static>> = new_items(dcmroot, items);
+    dcmroot.insert_element(&tagpath, element, stage)?;
+    if let Some(sequence) = dcmroot.get_child_by_tagpath_mut(&tagpath) {
+        for item in items {
+            sequence.push_item(item);
+        }
+    }
+    Ok(())
+}
+
+fn new_items(
+    dcmroot: &DicomRoot<'_>,
+    items: Vec<Vec<DicomObject<'static>>>,
+) -> Vec<DicomObject<'static>> {
+    items
+        .into_iter()
+        .map(|children| {
+            // Items are always encoded in implicit VR.
+            let item: DicomElement<'static> =
+                transcode_element(&empty_element(dcmroot, tags::ITEM, &vr::UN), dcmroot.ts());
+            let children = children
+                .into_iter()
+                .map(|child| (child.element().tag(), child))
+                .collect();
+            DicomObject::new_with_children(item, children, Vec::new())
+        })
+        .collect()
+}
+
+fn empty_element(dcmroot: &DicomRoot<'_>, tag: u32, vr: VRRef) -> DicomElement<'static> {
+    DicomElement::new(
+        tag,
+        vr,
+        ValueLength::Explicit(0),
+        dcmroot.ts(),
+        dcmroot.cs(),
+        Vec::new(),
+        Vec::new(),
+    )
+}
+
+/// Formats values as DS, see `format_ds()`.
+fn ds(values: &[f64]) -> RawValue {
+    RawValue::Strings(values.iter().copied().map(format_ds).collect())
+}
+
+/// Formats a value as a DS of at most 16 characters, with as much precision as fits.
+fn format_ds(value: f64) -> String {
+    let value: f64 = if value == 0.0 { 0.0 } else { value };
+    let text: String = value.to_string();
+    if text.len() <= 16 {
+        return text;
+    }
+    (0..=15)
+        .rev()
+        .map(|precision| format!("{value:.precision$}"))
+        .map(|text| {
+            if text.contains('.') {
+                text.trim_end_matches('0').trim_end_matches('.').to_owned()
+            } else {
+                text
+            }
+        })
+        .find(|text| text.len() <= 16)
+        .unwrap_or_else(|| format!("{value:.6e}"))
+}
+
+impl Slice {
+    fn read(dcmroot: &DicomRoot<'_>, frame: usize, pixels: PixelFrame) -> PixelDataResult<Slice> {
+        let position: Vec<f64> = frame_values(
+            dcmroot,
+            frame,
+            PLANE_POSITION_SEQUENCE,
+            IMAGE_POSITION_PATIENT,
+        )?
+        .ok_or(PixelDataError::MissingAttribute("ImagePositionPatient"))?;
+        let orientation: Vec<f64> = frame_values(
+            dcmroot,
+            frame,
+            PLANE_ORIENTATION_SEQUENCE,
+            IMAGE_ORIENTATION_PATIENT,
+        )?
+        .ok_or(PixelDataError::MissingAttribute("ImageOrientationPatient"))?;
+        let spacing: Vec<f64> =
+            frame_values(dcmroot, frame, PIXEL_MEASURES_SEQUENCE, PIXEL_SPACING)?
+                .ok_or(PixelDataError::MissingAttribute("PixelSpacing"))?;
+        let thickness: Option<f64> =
+            frame_values(dcmroot, frame, PIXEL_MEASURES_SEQUENCE, SLICE_THICKNESS)?
+                .and_then(|values| values.first().copied());
+
+        let (&[px, py, pz], &[rx, ry, rz, cx, cy, cz], &[row_spacing, column_spacing]) =
+            (&position[..], &orientation[..], &spacing[..])
+        else {
+            return Err(PixelDataError::InvalidGeometry(format!(
+                "frame {} has {} position, {} orientation, and {} pixel spacing values",
+                frame + 1,
+                position.len(),
+                orientation.len(),
+                spacing.len()
+            )));
+        };
+        Ok(Slice {
+            position: [px, py, pz],
+            orientation: [normalize([rx, ry, rz])?, normalize([cx, cy, cz])?],
+            // Pixel Spacing is the spacing between rows, then between columns.
+            spacing: [column_spacing, row_spacing],
+            thickness,
+            frame: pixels,
+        })
+    }
+}
+
+/// Gets the values of an attribute of the frame, from the functional group sequence of the
+/// frame's item of the Per-frame Functional Groups Sequence, then of the Shared Functional Groups
+/// Sequence, then the dataset itself.
+fn frame_values(
+    dcmroot: &DicomRoot<'_>,
+    frame: usize,
+    group: u32,
+    tag: u32,
+) -> PixelDataResult<Option<Vec<f64>>> {
+    let in_group = |sequence: u32, index: usize| -> Option<&DicomObject<'_>> {
+        dcmroot
+            .get_child_by_tag(sequence)?
+            .get_item_by_index(index)?
+            .get_child_by_tag(group)?
+            .get_item_by_index(1)?
+            .get_child_by_tag(tag)
+    };
+    let obj: Option<&DicomObject<'_>> = in_group(PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE, frame + 1)
+        .or_else(|| in_group(SHARED_FUNCTIONAL_GROUPS_SEQUENCE, 1))
+        .or_else(|| dcmroot.get_child_by_tag(tag));
+    match obj {
+        Some(obj) => Ok(Some(Vec::<f64>::try_from(obj.element())?)),
+        None => Ok(None),
+    }
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn normalize(vector: [f64; 3]) -> PixelDataResult<[f64; 3]> {
+    let length: f64 = dot(vector, vector).sqrt();
+    if !(length.is_finite() && length > 1e-6) {
+        return Err(PixelDataError::InvalidGeometry(format!(
+            "direction {vector:?} has no length"
+        )));
+    }
+    Ok(vector.map(|component| component / length))
+}
@@ -0,0 +1,243 @@
+//! Access to the tiles of VL Whole Slide Microscopy images, see Part 3, Ch C.8.12.4.
+//!
+//! A slide is scanned at several resolutions, each a separate instance whose frames are tiles of a
+//! Total Pixel Matrix. An instance too large for a single file is split into a concatenation, see
+//! `concatenation`. A `WholeSlide` groups the instances of a slide into levels of the resolution
+//! pyramid, mapping each tile of a level to the instance and frame holding it. Tiles are only
+//! decoded, or their compressed bytes extracted, when requested.
+//!
+//! Frames of TILED_FULL instances are the tiles in row-major order, followed by those of further
+//! focal planes and optical paths, see Part 3, Ch C.7.6.17.3. Otherwise, the position of each frame
+//! is given by its Plane Position (Slide) functional group. Only tiles of the first focal plane and
+//! optical path are mapped, and of sparse tiles the first frame at a position.
+
+use std::collections::HashMap;
+
+use crate::core::dcmobject::DicomRoot;
+
+use super::{
+    concatenation::{Concatenation, FrameLocation},
+    get_u16, PixelDataError, PixelDataResult, PixelFrame, COLUMNS, ROWS,
+};
+
+const IMAGE_TYPE: u32 = 0x0008_0008;
+const DIMENSION_ORGANIZATION_TYPE: u32 = 0x0020_9311;
+const TOTAL_PIXEL_MATRIX_COLUMNS: u32 = 0x0048_0006;
+const TOTAL_PIXEL_MATRIX_ROWS: u32 = 0x0048_0007;
+const PLANE_POSITION_SLIDE_SEQUENCE: u32 = 0x0048_021A;
+const COLUMN_POSITION: u32 = 0x0048_021E;
+const ROW_POSITION: u32 = 0x0048_021F;
+
+/// The levels of the resolution pyramid of a slide.
+#[derive(Debug)]
+pub struct WholeSlide<'d> {
+    levels: Vec<WsiLevel<'d>>,
+}
+
+impl<'d> WholeSlide<'d> {
+    /// Groups the instances of a slide into levels, ordered from the highest resolution to the
+    /// lowest. The instances of a concatenation become a single level. Thumbnail, label, and
+    /// overview images, per the third value of Image Type, aren't levels and are ignored.
+    pub fn new<I>(instances: I) -> PixelDataResult<WholeSlide<'d>>
+    where
+        I: IntoIterator<Item = &'d DicomRoot<'d>>,
+    {
+        let mut volumes: Vec<&'d DicomRoot<'d>> = Vec::new();
+        for dcmroot in instances {
+            let image_type: Vec<String> = match dcmroot.get_child_by_tag(IMAGE_TYPE) {
+                Some(obj) => Vec::<String>::try_from(obj.element())?,
+                None => Vec::new(),
+            };
+            if image_type
+                .get(2)
+                .is_none_or(|flavor| flavor.trim() == "VOLUME")
+            {
+                volumes.push(dcmroot);
+            }
+        }
+
+        let mut levels: Vec<WsiLevel<'d>> = Concatenation::group(volumes)?
+            .into_iter()
+            .map(WsiLevel::new)
+            .collect::<PixelDataResult<Vec<WsiLevel<'d>>>>()?;
+        levels.sort_by_key(|level| std::cmp::Reverse(level.total_columns * level.total_rows));
+        Ok(WholeSlide { levels })
+    }
+
+    /// The levels, from the highest resolution to the lowest.
+    pub fn levels(&self) -> &[WsiLevel<'d>] {
+        &self.levels
+    }
+
+    /// The level at the given index, where 0 is the highest resolution.
+    pub fn level(&self, level: usize) -> Option<&WsiLevel<'d>> {
+        self.levels.get(level)
+    }
+
+    /// The instance and frame holding the tile of the level, if present.
+    pub fn tile_location(&self, level: usize, row: usize, column: usize) -> Option<FrameLocation> {
+        self.level(level)?.tile_location(row, column)
+    }
+
+    /// Decodes the tile of the level, if present, see `WsiLevel::decode_tile()`.
+    pub fn decode_tile(
+        &self,
+        level: usize,
+        row: usize,
+        column: usize,
+    ) -> PixelDataResult<Option<PixelFrame>> {
+        match self.level(level) {
+            Some(level) => level.decode_tile(row, column),
+            None => Ok(None),
+        }
+    }
+}
+
+/// A level of the resolution pyramid, made of one instance or the instances of a concatenation.
+#[derive(Debug)]
+pub struct WsiLevel<'d> {
+    frames: Concatenation<'d>,
+    total_rows: usize,
+    total_columns: usize,
+    tile_rows: usize,
+    tile_columns: usize,
+    /// The frame of each tile, by row and column, unless the tiles are TILED_FULL.
+    sparse_tiles: Option<HashMap<(usize, usize), usize>>,
+}
+
+impl<'d> WsiLevel<'d> {
+    fn new(frames: Concatenation<'d>) -> PixelDataResult<WsiLevel<'d>> {
+        let dcmroot: &DicomRoot = frames.dataset();
+        let mut level = WsiLevel {
+            total_rows: get_u32(dcmroot, TOTAL_PIXEL_MATRIX_ROWS, "TotalPixelMatrixRows")?,
+            total_columns: get_u32(
+                dcmroot,
+                TOTAL_PIXEL_MATRIX_COLUMNS,
+                "TotalPixelMatrixColumns",
+            )?,
+            tile_rows: get_u16(dcmroot, ROWS, "Rows")?.into(),
+            tile_columns: get_u16(dcmroot, COLUMNS, "Columns")?.into(),
+            frames,
+            sparse_tiles: None,
+        };
+        if level.tile_rows == 0 || level.tile_columns == 0 {
+            return Err(PixelDataError::InvalidImagePixel(
+                "tiles have no rows or columns".to_owned(),
+            ));
+        }
+
+        let organization: Option<String> = dcmroot
+            .get_child_by_tag(DIMENSION_ORGANIZATION_TYPE)
+            .map(|obj| String::try_from(obj.element()))
+            .transpose()?;
+        if organization.as_deref().map(str::trim) != Some("TILED_FULL") {
+            level.sparse_tiles = Some(level.read_positions()?);
+        }
+        Ok(level)
+    }
+
+    /// Reads the position of each frame from the Plane Position (Slide) functional group.
+    fn read_positions(&self) -> PixelDataResult<HashMap<(usize, usize), usize>> {
+        let mut tiles: HashMap<(usize, usize), usize> = HashMap::new();
+        for frame in 0..self.frames.frame_count() {
+            let Some(item) = self.frames.per_frame_groups(frame) else {
+                return Err(PixelDataError::MissingAttribute(
+                    "PerFrameFunctionalGroupsSequence",
+                ));
+            };
+            let Some(position) = item
+                .get_child_by_tag(PLANE_POSITION_SLIDE_SEQUENCE)
+                .and_then(|seq| seq.get_item_by_index(1))
+            else {
+                continue;
+            };
+            let get_position = |tag: u32| -> PixelDataResult<Option<i32>> {
+                position
+                    .get_child_by_tag(tag)
+                    .map(|obj| i32::try_from(obj.element()))
+                    .transpose()
+                    .map_err(PixelDataError::from)
+            };
+            // Positions are of the top-left pixel of the tile, from 1.
+            let (Some(row), Some(column)) =
+                (get_position(ROW_POSITION)?, get_position(COLUMN_POSITION)?)
+            else {
+                continue;
+            };
+            if row < 1 || column < 1 {
+                continue;
+            }
+            let tile: (usize, usize) = (
+                (row as usize - 1) / self.tile_rows,
+                (column as usize - 1) / self.tile_columns,
+            );
+            tiles.entry(tile).or_insert(frame);
+        }
+        Ok(tiles)
+    }
+
+    /// The frames of the level, across the instances of a concatenation.
+    pub fn frames(&self) -> &Concatenation<'d> {
+        &self.frames
+    }
+
+    /// The rows and columns of the Total Pixel Matrix.
+    pub fn size(&self) -> (usize, usize) {
+        (self.total_rows, self.total_columns)
+    }
+
+    /// The rows and columns of each tile.
+    pub fn tile_size(&self) -> (usize, usize) {
+        (self.tile_rows, self.tile_columns)
+    }
+
+    /// The number of rows and columns of tiles covering the Total Pixel Matrix.
+    pub fn tile_grid(&self) -> (usize, usize) {
+        (
+            self.total_rows.div_ceil(self.tile_rows),
+            self.total_columns.div_ceil(self.tile_columns),
+        )
+    }
+
+    /// The zero-based frame of the level holding the tile at the given zero-based row and
+    /// column, if present.
+    pub fn tile_frame(&self, row: usize, column: usize) -> Option<usize> {
+        let (rows, columns) = self.tile_grid();
+        if row >= rows || column >= columns {
+            return None;
+        }
+        match &self.sparse_tiles {
+            Some(tiles) => tiles.get(&(row, column)).copied(),
+            None => Some(row * columns + column).filter(|frame| *frame < self.frames.frame_count()),
+        }
+    }
+
+    /// The instance and frame holding the tile at the given zero-based row and column, if present.
+    pub fn tile_location(&self, row: usize, column: usize) -> Option<FrameLocation> {
+        self.frames.locate(self.tile_frame(row, column)?)
+    }
+
+    /// Decodes the tile at the given row and column, if present, see `Concatenation::decode()`.
+    /// Tiles of encapsulated Pixel Data can't be decoded, see `compressed_tile()`.
+    pub fn decode_tile(&self, row: usize, column: usize) -> PixelDataResult<Option<PixelFrame>> {
+        self.tile_frame(row, column)
+            .map(|frame| self.frames.decode(frame))
+            .transpose()
+    }
+
+    /// Gets the compressed bytes of the tile at the given row and column, if present. The
+    /// instance's Pixel Data must be encapsulated.
+    pub fn compressed_tile(&self, row: usize, column: usize) -> PixelDataResult<Option<Vec<u8>>> {
+        self.tile_frame(row, column)
+            .map(|frame| self.frames.compressed_frame(frame))
+            .transpose()
+    }
+}
+
+/// Gets a required UL attribute.
+fn get_u32(dcmroot: &DicomRoot<'_>, tag: u32, name: &'static str) -> PixelDataResult<usize> {
+    let obj = dcmroot
+        .get_child_by_tag(tag)
+        .ok_or(PixelDataError::MissingAttribute(name))?;
+    Ok(u32::try_from(obj.element())? as usize)
+}
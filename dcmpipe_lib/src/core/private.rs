@@ -0,0 +1,127 @@
+//! Resolution of private attributes by their Private Creator, see Part 5, Ch 7.8.1.
+//!
+//! Private attributes are identified by the group, the Private Creator reserving a block of the
+//! group, and the element's offset within the block. The block reserved by a creator varies
+//! between datasets, so the tag of a private attribute must be resolved against each dataset
+//! rather than hardcoded. Attributes are defined for resolution with a `PrivateTag`, which also
+//! records their VR, as private attributes of datasets in Implicit VR are read as `UN`.
+
+use crate::core::{
+    dcmelement::DicomElement,
+    dcmobject::DicomObject,
+    defn::{
+        tag::Tag,
+        vr::{self, VRRef},
+    },
+    read::{error::ParseErrorInfo, ParseResult},
+    values::RawValue,
+};
+
+/// The definition of a private attribute, identified by its creator and offset within the group.
+#[derive(Clone, Copy, Debug)]
+pub struct PrivateTag {
+    pub group: u16,
+    pub creator: &'static str,
+    /// The element's offset within the block reserved by the creator, e.g. `0x10` for `(gggg,xx10)`.
+    pub offset: u8,
+    /// The VR of the attribute, used to parse its value if read as `UN`.
+    pub vr: VRRef,
+    pub name: &'static str,
+}
+
+impl PrivateTag {
+    /// Resolves the tag of the attribute within the dataset, see `private_tag()`.
+    pub fn resolve(&self, obj: &DicomObject<'_>) -> Option<u32> {
+        private_tag(obj, self.group, self.creator, self.offset)
+    }
+
+    /// Gets the attribute within the dataset, see `get_private()`.
+    pub fn get<'o, 'a>(&self, obj: &'o DicomObject<'a>) -> Option<&'o DicomObject<'a>> {
+        get_private(obj, self.group, self.creator, self.offset)
+    }
+
+    /// Parses the value of the attribute, using the defined VR if the attribute is `UN`. Returns
+    /// `None` if the dataset doesn't have the attribute.
+    pub fn value(&self, obj: &DicomObject<'_>) -> ParseResult<Option<RawValue>> {
+        self.get(obj)
+            .map(|child| self.parse(child.element()))
+            .transpose()
+    }
+
+    /// Parses the numeric values of the attribute as floating point numbers, whether encoded in
+    /// binary or as strings. Returns `None` if the dataset doesn't have the attribute.
+    pub fn floats(&self, obj: &DicomObject<'_>) -> ParseResult<Option<Vec<f64>>> {
+        let Some(element) = self.get(obj).map(|child| child.element()) else {
+            return Ok(None);
+        };
+        let floats: Vec<f64> = match self.parse(element)? {
+            RawValue::Floats(values) => values.into_iter().map(f64::from).collect(),
+            RawValue::Doubles(values) => values,
+            RawValue::Integers(values) => values.into_iter().map(f64::from).collect(),
+            RawValue::UnsignedIntegers(values) => values.into_iter().map(f64::from).collect(),
+            RawValue::Shorts(values) => values.into_iter().map(f64::from).collect(),
+            RawValue::UnsignedShorts(values) => values.into_iter().map(f64::from).collect(),
+            RawValue::Bytes(bytes) if bytes.is_empty() => Vec::new(),
+            _ => return Err(ParseErrorInfo(element, "value is not numeric", None).into()),
+        };
+        Ok(Some(floats))
+    }
+
+    /// Parses the first numeric value of the attribute, see `floats()`. Returns `None` if the
+    /// dataset doesn't have the attribute or it has no values.
+    pub fn float(&self, obj: &DicomObject<'_>) -> ParseResult<Option<f64>> {
+        Ok(self.floats(obj)?.and_then(|values| values.first().copied()))
+    }
+
+    fn parse(&self, element: &DicomElement<'_>) -> ParseResult<RawValue> {
+        if element.vr() == &vr::UN {
+            // The value is re-interpreted by a copy of the element with the defined VR, as parsing
+            // binary values depends on the element's VR.
+            DicomElement::new(
+                element.tag(),
+                self.vr,
+                element.vl(),
+                element.ts(),
+                element.cs(),
+                element.data().to_vec(),
+                Vec::new(),
+            )
+            .parse_value()
+        } else {
+            element.parse_value()
+        }
+    }
+}
+
+/// Finds the tag of the Private Creator element `(gggg,00xx)` whose value is the given creator, in
+/// the given private group. Trailing padding of the creator's value is ignored.
+pub fn private_creator_tag(obj: &DicomObject<'_>, group: u16, creator: &str) -> Option<u32> {
+    (0x10u32..=0xFF)
+        .map(|block| (u32::from(group) << 16) | block)
+        .filter(|tag| Tag::is_private_creator(*tag))
+        .find(|tag| {
+            obj.get_child_by_tag(*tag)
+                .and_then(|child| String::try_from(child.element()).ok())
+                .is_some_and(|value| value.trim_end_matches([' ', '\0']) == creator)
+        })
+}
+
+/// Resolves the tag of the private attribute at the given offset of the block reserved by the
+/// creator in the given group, e.g. offset `0x10` of the block reserved by `(0029,0011)` is
+/// `(0029,1110)`. Returns `None` if the creator hasn't reserved a block of the group.
+pub fn private_tag(obj: &DicomObject<'_>, group: u16, creator: &str, offset: u8) -> Option<u32> {
+    let creator_tag: u32 = private_creator_tag(obj, group, creator)?;
+    let block: u32 = creator_tag & 0xFF;
+    Some((u32::from(group) << 16) | (block << 8) | u32::from(offset))
+}
+
+/// Gets the private attribute at the given offset of the block reserved by the creator in the
+/// given group, see `private_tag()`.
+pub fn get_private<'o, 'a>(
+    obj: &'o DicomObject<'a>,
+    group: u16,
+    creator: &str,
+    offset: u8,
+) -> Option<&'o DicomObject<'a>> {
+    obj.get_child_by_tag(private_tag(obj, group, creator, offset)?)
+}
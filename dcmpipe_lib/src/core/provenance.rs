@@ -0,0 +1,79 @@
+//! Element provenance, recording how an element within a `DicomRoot` came to be. This is useful
+//! for auditing which elements a pipeline (anonymization, coercion, etc.) actually touched.
+
+use std::fmt;
+
+/// How an element came to be in a `DicomRoot`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProvenanceKind {
+    /// The element was read from a dataset and has not been changed since.
+    Parsed,
+    /// The element did not exist in the dataset and was created.
+    Synthesized,
+    /// The element was read from a dataset and its value has since been changed.
+    Modified,
+}
+
+impl fmt::Display for ProvenanceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProvenanceKind::Parsed => write!(f, "parsed"),
+            ProvenanceKind::Synthesized => write!(f, "synthesized"),
+            ProvenanceKind::Modified => write!(f, "modified"),
+        }
+    }
+}
+
+/// The provenance of a single element, including the pipeline stage responsible for the most
+/// recent change to it. Only the most recent change is retained.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Provenance {
+    kind: ProvenanceKind,
+    stage: Option<String>,
+}
+
+impl Provenance {
+    /// Provenance for an element read from a dataset.
+    pub fn parsed() -> Provenance {
+        Provenance {
+            kind: ProvenanceKind::Parsed,
+            stage: None,
+        }
+    }
+
+    /// Provenance for an element created by the given pipeline stage.
+    pub fn synthesized(stage: &str) -> Provenance {
+        Provenance {
+            kind: ProvenanceKind::Synthesized,
+            stage: Some(stage.to_owned()),
+        }
+    }
+
+    /// Provenance for an element changed by the given pipeline stage.
+    pub fn modified(stage: &str) -> Provenance {
+        Provenance {
+            kind: ProvenanceKind::Modified,
+            stage: Some(stage.to_owned()),
+        }
+    }
+
+    /// Get how the element came to be.
+    pub fn kind(&self) -> ProvenanceKind {
+        self.kind
+    }
+
+    /// Get the pipeline stage which synthesized or modified the element. This is `None` for
+    /// elements which were parsed.
+    pub fn stage(&self) -> Option<&str> {
+        self.stage.as_deref()
+    }
+}
+
+impl fmt::Display for Provenance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.stage {
+            None => write!(f, "{}", self.kind),
+            Some(stage) => write!(f, "{} by {}", self.kind, stage),
+        }
+    }
+}
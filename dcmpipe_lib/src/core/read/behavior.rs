@@ -1,5 +1,12 @@
 //! Specify behavior while parsing.
 
+use std::{
+    fmt::{Debug, Formatter},
+    sync::Arc,
+};
+
+use crate::core::{defn::ts::TSRef, padding::PaddingPolicy};
+
 use super::stop::ParseStop;
 
 #[derive(Clone, Debug)]
@@ -18,6 +25,76 @@ pub struct ParseBehavior {
     ///
     /// Default: `false`.
     allow_partial_object: bool,
+
+    /// Specifies whether the parser should skip over element values rather than reading them,
+    /// deferring them to be resolved later from the buffer the dataset is parsed from. Only values
+    /// at least this many bytes long are deferred.
+    ///
+    /// Refer to documentation on `DicomRoot::resolve_deferred()`.
+    ///
+    /// Default: `None`.
+    defer_values: Option<u32>,
+
+    /// Specifies how the parser handles an element in an Explicit VR dataset whose VR differs from
+    /// the VR the dictionary defines for its tag.
+    ///
+    /// Refer to documentation on `VRMismatchPolicy`.
+    ///
+    /// Default: `VRMismatchPolicy::TrustFile`.
+    vr_mismatch: VRMismatchPolicy,
+
+    /// Specifies whether the parser should resolve the VR of elements read as `UN` from the
+    /// dictionary, interpreting their values with the resolved VR.
+    ///
+    /// Refer to documentation on `ParserBuilder::resolve_un()`.
+    ///
+    /// Default: `false`.
+    resolve_un: bool,
+
+    /// Specifies how the parser handles values of odd length or which are padded with the wrong
+    /// byte.
+    ///
+    /// Refer to documentation on `PaddingPolicy`.
+    ///
+    /// Default: `PaddingPolicy::Preserve`.
+    padding: PaddingPolicy,
+}
+
+/// How the parser handles an element in an Explicit VR dataset whose VR differs from the VR the
+/// dictionary defines for its tag. Mismatches other than for `Error` are reported as a
+/// `ParseWarning::VRMismatch`.
+///
+/// VRs are not considered mismatched if either is `UN`, or if both are alternatives the standard
+/// allows for some attributes, such as `US` and `SS`, or `OB` and `OW`, as the dictionary records
+/// only the first VR of such attributes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VRMismatchPolicy {
+    /// Parse the element with the VR encoded in the dataset.
+    #[default]
+    TrustFile,
+
+    /// Parse the element's value with the dictionary's VR. The element's header is still read
+    /// according to the VR encoded in the dataset. Elements where either VR is `SQ` are parsed with
+    /// the VR encoded in the dataset, as their contents would otherwise be misread.
+    TrustDictionary,
+
+    /// Fail parsing with `ParseError::VRMismatch`.
+    Error,
+}
+
+/// Resolves a `TransferSyntaxUID` which isn't in the dictionary to the transfer syntax the dataset
+/// should be parsed with, see `ParserBuilder::unknown_ts_handler()`. Private transfer syntaxes can
+/// be defined as a `static` `TransferSyntax` with the characteristics of their encoding.
+pub type UnknownTSHandler = Arc<dyn Fn(&str) -> Option<TSRef> + Send + Sync>;
+
+/// Wraps an `UnknownTSHandler` so that builders and parsers remain `Debug`.
+#[derive(Clone)]
+pub(crate) struct UnknownTSHandlerRef(pub(crate) UnknownTSHandler);
+
+impl Debug for UnknownTSHandlerRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "UnknownTSHandler")
+    }
 }
 
 impl ParseBehavior {
@@ -29,6 +106,22 @@ impl ParseBehavior {
         self.allow_partial_object
     }
 
+    pub fn defer_values(&self) -> Option<u32> {
+        self.defer_values
+    }
+
+    pub fn vr_mismatch(&self) -> VRMismatchPolicy {
+        self.vr_mismatch
+    }
+
+    pub fn resolve_un(&self) -> bool {
+        self.resolve_un
+    }
+
+    pub fn padding(&self) -> PaddingPolicy {
+        self.padding
+    }
+
     pub fn set_stop(&mut self, stop: ParseStop) {
         self.stop = stop;
     }
@@ -36,6 +129,22 @@ impl ParseBehavior {
     pub fn set_allow_partial_object(&mut self, allow_partial_object: bool) {
         self.allow_partial_object = allow_partial_object;
     }
+
+    pub fn set_defer_values(&mut self, defer_values: Option<u32>) {
+        self.defer_values = defer_values;
+    }
+
+    pub fn set_vr_mismatch(&mut self, vr_mismatch: VRMismatchPolicy) {
+        self.vr_mismatch = vr_mismatch;
+    }
+
+    pub fn set_resolve_un(&mut self, resolve_un: bool) {
+        self.resolve_un = resolve_un;
+    }
+
+    pub fn set_padding(&mut self, padding: PaddingPolicy) {
+        self.padding = padding;
+    }
 }
 
 impl Default for ParseBehavior {
@@ -43,6 +152,10 @@ impl Default for ParseBehavior {
         Self {
             stop: ParseStop::EndOfDataset,
             allow_partial_object: false,
+            defer_values: None,
+            vr_mismatch: VRMismatchPolicy::TrustFile,
+            resolve_un: false,
+            padding: PaddingPolicy::Preserve,
         }
     }
 }
@@ -1,9 +1,10 @@
 //! Configurable builder to create a DICOM parser.
 
-use std::io::Read;
+use std::{io::Read, sync::Arc};
 
 use super::{
-    behavior::ParseBehavior,
+    behavior::{ParseBehavior, UnknownTSHandlerRef, VRMismatchPolicy},
+    observer::{ObserverRef, SharedObserver},
     parser::{Parser, ParserState},
 };
 
@@ -14,11 +15,12 @@ use crate::core::{
         dcmdict::DicomDictionary,
         ts::TSRef,
     },
+    padding::PaddingPolicy,
     read::{ds::dataset::Dataset, stop::ParseStop},
 };
 
 /// A builder for constructing a `Parser`.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ParserBuilder<'dict> {
     /// Initial parse state. Default is `ParseState::DetectTransferSyntax`.
     state: Option<ParserState>,
@@ -35,6 +37,12 @@ pub struct ParserBuilder<'dict> {
 
     /// The dataset will be wrapped in a `BufReader`, this lets the buffer size be set.
     buffsize: usize,
+
+    /// Notified of each element parsed by every parser built. Default is `None`.
+    observer: Option<ObserverRef>,
+
+    /// Resolves transfer syntax UIDs which aren't in the dictionary. Default is `None`.
+    unknown_ts_handler: Option<UnknownTSHandlerRef>,
 }
 
 impl<'dict> ParserBuilder<'dict> {
@@ -57,6 +65,44 @@ impl<'dict> ParserBuilder<'dict> {
         self
     }
 
+    /// Skip over values at least `min_len` bytes long rather than reading them, so they can later
+    /// be borrowed from the buffer the dataset is parsed from with `DicomRoot::resolve_deferred()`,
+    /// or referred to within the file it's parsed from with `DicomRoot::reference_deferred()`.
+    /// Values within a deflated dataset, and values the parser needs, are always read.
+    pub fn defer_values(mut self, min_len: u32) -> Self {
+        self.behavior.set_defer_values(Some(min_len));
+        self
+    }
+
+    /// Sets how elements whose explicit VR differs from the dictionary are handled, see
+    /// `VRMismatchPolicy`.
+    pub fn vr_mismatch(mut self, policy: VRMismatchPolicy) -> Self {
+        self.behavior.set_vr_mismatch(policy);
+        self
+    }
+
+    /// Resolve the VR of elements read as `UN`, either from an Implicit VR dataset or encoded as `UN`
+    /// in an Explicit VR dataset, so their values are interpreted rather than left as bytes. VRs
+    /// are resolved from the dictionary, and Group Length and Private Creator elements are always
+    /// `UL` and `LO`. See Part 5, Ch 6.2.2.
+    ///
+    /// Sequences encoded as `UN` have their contents parsed as IVRLE, including private sequences
+    /// whose value begins with an item. The contents are otherwise treated as encoded with the
+    /// dataset's transfer syntax, so `DicomRoot::update_value_lengths()` should be called before
+    /// writing a dataset with such sequences. Sequences are only resolved within little endian
+    /// datasets.
+    pub fn resolve_un(mut self, resolve_un: bool) -> Self {
+        self.behavior.set_resolve_un(resolve_un);
+        self
+    }
+
+    /// Sets how values of odd length, values padded with the wrong byte, and UIDs followed by
+    /// several NULLs are handled, see `PaddingPolicy`.
+    pub fn padding(mut self, policy: PaddingPolicy) -> Self {
+        self.behavior.set_padding(policy);
+        self
+    }
+
     /// Sets the transfer syntax of the dataset, if known.
     pub fn dataset_ts(mut self, dataset_ts: TSRef) -> Self {
         self.dataset_ts = Some(dataset_ts);
@@ -78,6 +124,30 @@ impl<'dict> ParserBuilder<'dict> {
         self
     }
 
+    /// Sets the observer notified of each element parsed. The same observer is shared by all
+    /// parsers built, so statistics such as `ParseStats` accumulate across datasets.
+    pub fn observer(mut self, observer: SharedObserver) -> Self {
+        self.observer = Some(ObserverRef(observer));
+        self
+    }
+
+    /// Sets the handler which resolves a `TransferSyntaxUID` that isn't in the dictionary, such as
+    /// a private transfer syntax, to the transfer syntax to parse the rest of the dataset with. The
+    /// handler is given the UID, and returns `None` if it doesn't recognize it either, which is
+    /// reported as a `ParseWarning::UnknownTransferSyntax`.
+    ///
+    /// Without a handler, or if the handler returns `None`, the parser guesses the encoding of the
+    /// rest of the dataset: it detects explicit or implicit VR and the byte order from the first
+    /// element after the File Meta group, but can't detect whether the dataset is deflated or its
+    /// Pixel Data encapsulated. A handler which always returns `None` only reports the warning.
+    pub fn unknown_ts_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&str) -> Option<TSRef> + Send + Sync + 'static,
+    {
+        self.unknown_ts_handler = Some(UnknownTSHandlerRef(Arc::new(handler)));
+        self
+    }
+
     /// Constructs a `Parser` from this builder.
     pub fn build<DatasetType: Read>(&self, dataset: DatasetType) -> Parser<'dict, DatasetType> {
         Parser {
@@ -103,6 +173,14 @@ impl<'dict> ParserBuilder<'dict> {
             cs: DEFAULT_CHARACTER_SET,
             current_path: Vec::new(),
             iterator_ended: false,
+            stopped: false,
+            skipped_seq: None,
+            element_start: 0,
+            observer: self.observer.clone(),
+            unknown_ts_handler: self.unknown_ts_handler.clone(),
+            warnings: Vec::new(),
+            pending_item: None,
+            resolved_seq: false,
         }
     }
 }
@@ -116,6 +194,8 @@ impl<'dict> Default for ParserBuilder<'dict> {
             dictionary: &MINIMAL_DICOM_DICTIONARY,
             // BufReader's current default buffer size is 8k.
             buffsize: 8 * 1024,
+            observer: None,
+            unknown_ts_handler: None,
         }
     }
 }
@@ -1,14 +1,24 @@
 //! A buffered DICOM reader
+//!
+//! The parser reads element headers a few bytes at a time, which are served from the buffer by
+//! way of `BufRead` rather than each being a read from the underlying dataset.
 
 #[cfg(feature = "compress")]
 pub(crate) mod dataset {
     use libflate::deflate::Decoder;
-    use std::io::{BufReader, Read, Result};
+    use std::io::{BufRead, BufReader, Read, Result};
 
     #[derive(Debug)]
     pub(crate) struct Dataset<DatasetType: Read> {
         decoder: Decoder<BufReader<DatasetType>>,
         read_deflated: bool,
+
+        /// Buffer of inflated bytes, as the decoder has no `BufRead` implementation of its own.
+        inflated: Vec<u8>,
+        /// The position of the next byte in `inflated` to be read.
+        pos: usize,
+        /// The number of bytes in `inflated` which were filled by the decoder.
+        filled: usize,
     }
 
     impl<DatasetType: Read> Dataset<DatasetType> {
@@ -16,6 +26,9 @@ pub(crate) mod dataset {
             Dataset {
                 decoder: Decoder::new(BufReader::with_capacity(buffsize, dataset)),
                 read_deflated: false,
+                inflated: vec![0u8; buffsize.max(1)],
+                pos: 0,
+                filled: 0,
             }
         }
 
@@ -26,10 +39,38 @@ pub(crate) mod dataset {
 
     impl<DatasetType: Read> Read for Dataset<DatasetType> {
         fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
-            if self.read_deflated {
-                self.decoder.read(buf)
+            if !self.read_deflated {
+                return self.decoder.as_inner_mut().read(buf);
+            }
+            // Large reads bypass the buffer if it's empty, the same as `BufReader`.
+            if self.pos == self.filled && buf.len() >= self.inflated.len() {
+                return self.decoder.read(buf);
+            }
+            let available: &[u8] = self.fill_buf()?;
+            let num_read: usize = available.len().min(buf.len());
+            buf[..num_read].copy_from_slice(&available[..num_read]);
+            self.consume(num_read);
+            Ok(num_read)
+        }
+    }
+
+    impl<DatasetType: Read> BufRead for Dataset<DatasetType> {
+        fn fill_buf(&mut self) -> Result<&[u8]> {
+            if !self.read_deflated {
+                return self.decoder.as_inner_mut().fill_buf();
+            }
+            if self.pos == self.filled {
+                self.filled = self.decoder.read(&mut self.inflated)?;
+                self.pos = 0;
+            }
+            Ok(&self.inflated[self.pos..self.filled])
+        }
+
+        fn consume(&mut self, amt: usize) {
+            if !self.read_deflated {
+                self.decoder.as_inner_mut().consume(amt);
             } else {
-                self.decoder.as_inner_mut().read(buf)
+                self.pos = (self.pos + amt).min(self.filled);
             }
         }
     }
@@ -37,7 +78,7 @@ pub(crate) mod dataset {
 
 #[cfg(not(feature = "compress"))]
 pub(crate) mod dataset {
-    use std::io::{BufReader, Read, Result};
+    use std::io::{BufRead, BufReader, Read, Result};
 
     #[derive(Debug)]
     pub(crate) struct Dataset<DatasetType: Read> {
@@ -57,4 +98,14 @@ pub(crate) mod dataset {
             self.dataset.read(buf)
         }
     }
+
+    impl<DatasetType: Read> BufRead for Dataset<DatasetType> {
+        fn fill_buf(&mut self) -> Result<&[u8]> {
+            self.dataset.fill_buf()
+        }
+
+        fn consume(&mut self, amt: usize) {
+            self.dataset.consume(amt)
+        }
+    }
 }
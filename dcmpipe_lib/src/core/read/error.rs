@@ -4,6 +4,7 @@ use crate::core::{
     charset::{CSError, CSRef},
     dcmelement::DicomElement,
     defn::{dcmdict::DicomDictionary, tag::TagPath, vr::VRRef},
+    padding::PaddingIssue,
     DICOM_PREFIX_LENGTH,
 };
 
@@ -13,7 +14,7 @@ const MAX_BYTES_IN_ERROR: usize = 16;
 
 #[derive(Debug)]
 pub(crate) struct ParseErrorInfo<'a>(
-    pub &'a DicomElement,
+    pub &'a DicomElement<'a>,
     pub &'a str,
     pub Option<&'a dyn DicomDictionary>,
 );
@@ -21,9 +22,9 @@ impl<'a> From<ParseErrorInfo<'a>> for ParseError {
     fn from(value: ParseErrorInfo<'a>) -> Self {
         let elem = value.0;
         let message = value.1;
+        let dict = value.2;
 
-        // TODO: How to get a dicom dictionary here for better error messages?
-        let tagstring = TagPath::format_tagpath_to_display(&elem.create_tagpath(), None);
+        let tagstring = TagPath::format_tagpath_to_display(&elem.create_tagpath(), dict);
         ParseError::DecodeValueError {
             message: message.to_owned(),
             tagstring,
@@ -79,6 +80,10 @@ pub enum ParseError {
         #[source]
         source: Box<ParseError>,
         detail: String,
+        /// The byte position in the dataset at which the error occurred.
+        offset: u64,
+        /// The path to the element being parsed when the error occurred.
+        tagpath: TagPath,
     },
 
     /// An error occurs while parsing the value of a DICOM element.
@@ -93,6 +98,31 @@ pub enum ParseError {
         bytes: Vec<u8>,
     },
 
+    /// A value is not valid for the VR of the DICOM element it's being encoded into.
+    #[error("error encoding element value: {message}\n\ttagpath: {tagstring}\n\tvr:{vr:?}")]
+    EncodeValueError {
+        message: String,
+        tagstring: String,
+        vr: VRRef,
+    },
+
+    /// The VR of an element in an Explicit VR dataset differs from the VR the dictionary defines
+    /// for its tag, and the parser is configured with `VRMismatchPolicy::Error`.
+    #[error("vr {} differs from dictionary vr {}\n\ttagpath: {tagstring}", file_vr.ident, dictionary_vr.ident)]
+    VRMismatch {
+        tagstring: String,
+        file_vr: VRRef,
+        dictionary_vr: VRRef,
+    },
+
+    /// A value isn't padded as the standard requires, and the parser is configured with
+    /// `PaddingPolicy::Error`.
+    #[error("{issue}\n\ttagpath: {tagstring}")]
+    InvalidPadding {
+        tagstring: String,
+        issue: PaddingIssue,
+    },
+
     /// An error when a text/string representation of a tagpath is unable to be parsed/resolved.
     #[error("unable to resolve tagpath: {string_path}")]
     InvalidTagPath { string_path: String },
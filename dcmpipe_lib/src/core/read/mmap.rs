@@ -0,0 +1,77 @@
+//! A memory-mapped source for parsing DICOM files.
+//!
+//! Parsing from a mapped file reads from memory rather than issuing a system call for each buffer
+//! fill, and the value field of any parsed element can be accessed as a slice of the mapping
+//! using the element's `value_offset()`, without copying it. Using `MappedFile::parse()` the
+//! elements' values borrow from the mapping directly.
+
+use std::{fs::File, io, path::Path};
+
+use memmap2::Mmap;
+
+use crate::core::{
+    dcmelement::DicomElement,
+    dcmobject::DicomRoot,
+    defn::vl::ValueLength,
+    read::{ParseResult, Parser, ParserBuilder},
+};
+
+/// A DICOM file mapped into memory.
+pub struct MappedFile {
+    mmap: Mmap,
+}
+
+impl MappedFile {
+    /// Opens and maps the file at the given path.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<MappedFile> {
+        MappedFile::map(&File::open(path)?)
+    }
+
+    /// Maps the given file. The file should not be modified or truncated while mapped, which
+    /// would change the bytes of values already parsed or cause reads to fault.
+    pub fn map(file: &File) -> io::Result<MappedFile> {
+        // SAFETY: The mapping is read-only. Modification of the underlying file by another process
+        // is outside the control of this library, as documented above.
+        let mmap: Mmap = unsafe { Mmap::map(file)? };
+        Ok(MappedFile { mmap })
+    }
+
+    /// The bytes of the mapped file.
+    pub fn bytes(&self) -> &[u8] {
+        &self.mmap
+    }
+
+    pub fn len(&self) -> usize {
+        self.mmap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mmap.is_empty()
+    }
+
+    /// Constructs a `Parser` from the builder which reads from the start of the mapped file.
+    pub fn parser<'dict>(&self, builder: &ParserBuilder<'dict>) -> Parser<'dict, &[u8]> {
+        builder.build(self.bytes())
+    }
+
+    /// Parses the mapped file into a `DicomRoot` whose element values borrow from the mapping
+    /// rather than being copied, apart from values of odd length and those within a deflated
+    /// dataset. See `DicomRoot::parse()`.
+    pub fn parse<'a>(&'a self, builder: &ParserBuilder<'a>) -> ParseResult<Option<DicomRoot<'a>>> {
+        let mut parser: Parser<'a, &[u8]> = builder.clone().defer_values(0).build(self.bytes());
+        DicomRoot::parse(&mut parser)?
+            .map(|dcmroot| dcmroot.resolve_deferred(self.bytes()))
+            .transpose()
+    }
+
+    /// The value field of an element parsed from this file, as a slice of the mapping. Unlike
+    /// `DicomElement::data()` this is not padded to even length. Returns `None` if the element
+    /// has no known position within the file, or its position lies outside the file.
+    pub fn value(&self, element: &DicomElement) -> Option<&[u8]> {
+        let ValueLength::Explicit(len) = element.vl() else {
+            return None;
+        };
+        let start: usize = usize::try_from(element.value_offset()?).ok()?;
+        self.mmap.get(start..start.checked_add(len as usize)?)
+    }
+}
@@ -12,5 +12,9 @@ pub(crate) mod valdecode;
 pub mod behavior;
 pub mod builder;
 pub mod error;
+#[cfg(feature = "mmap")]
+pub mod mmap;
+pub mod observer;
 pub mod parser;
 pub mod stop;
+pub mod warning;
@@ -0,0 +1,219 @@
+//! Hooks for observing the progress of a parser, for profiling, progress reporting, and debugging
+//! slow or malformed datasets.
+//!
+//! A `ParseObserver` is given to `ParserBuilder::observer()` and is notified as each element is
+//! parsed. `ParseStats` is an observer which accumulates statistics about the elements parsed.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Formatter},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+use std::time::Instant;
+
+use crate::core::{
+    dcmelement::DicomElement,
+    defn::tag::TagPath,
+    read::{warning::ParseWarning, ParseError},
+};
+
+/// The number of elements retained by `ParseStats::largest()`.
+pub const LARGEST_ELEMENTS: usize = 10;
+
+/// A parsed element along with where it was parsed from in the dataset and how long it took.
+#[derive(Debug)]
+pub struct ElementEvent<'e> {
+    /// The element parsed.
+    pub element: &'e DicomElement<'static>,
+
+    /// The byte position in the dataset at which the element's tag starts.
+    pub offset: u64,
+
+    /// The number of bytes of the dataset read for the element, including its tag, VR, and value
+    /// length. Sequence and item elements include only their headers, as their contents are parsed
+    /// as separate elements.
+    pub len: u64,
+
+    /// The time taken to parse the element, including reading its bytes from the dataset. This is
+    /// always zero on `wasm32-unknown-unknown`, which has no clock.
+    pub elapsed: Duration,
+}
+
+/// Measures the time taken to parse an element, see `ElementEvent::elapsed`.
+#[derive(Clone, Copy)]
+pub(crate) struct Stopwatch {
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    started: Instant,
+}
+
+impl Stopwatch {
+    pub(crate) fn start() -> Stopwatch {
+        Stopwatch {
+            #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+            started: Instant::now(),
+        }
+    }
+
+    pub(crate) fn elapsed(&self) -> Duration {
+        #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+        return self.started.elapsed();
+        #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+        return Duration::ZERO;
+    }
+}
+
+/// Notified by the parser at element boundaries. This is implemented for closures which accept an
+/// `ElementEvent`.
+pub trait ParseObserver: Send {
+    /// Called after each element is parsed, in the order they're parsed from the dataset.
+    fn element_parsed(&mut self, event: &ElementEvent);
+
+    /// Called when parsing fails, with the byte position in the dataset the failure occurred at.
+    fn parse_failed(&mut self, _offset: u64, _error: &ParseError) {}
+
+    /// Called when the parser recovers from a problem in the dataset.
+    fn warning(&mut self, _warning: &ParseWarning) {}
+}
+
+impl<F: FnMut(&ElementEvent) + Send> ParseObserver for F {
+    fn element_parsed(&mut self, event: &ElementEvent) {
+        self(event)
+    }
+}
+
+/// An observer shared between a `ParserBuilder`, each `Parser` it builds, and the caller which
+/// retains it to inspect after parsing.
+pub type SharedObserver = Arc<Mutex<dyn ParseObserver>>;
+
+/// Wraps a `SharedObserver` so that builders and parsers remain `Debug`.
+#[derive(Clone)]
+pub(crate) struct ObserverRef(pub(crate) SharedObserver);
+
+impl ObserverRef {
+    pub(crate) fn element_parsed(&self, event: &ElementEvent) {
+        // An observer which panicked previously is no longer notified.
+        if let Ok(mut observer) = self.0.lock() {
+            observer.element_parsed(event);
+        }
+    }
+
+    pub(crate) fn parse_failed(&self, offset: u64, error: &ParseError) {
+        if let Ok(mut observer) = self.0.lock() {
+            observer.parse_failed(offset, error);
+        }
+    }
+
+    pub(crate) fn warning(&self, warning: &ParseWarning) {
+        if let Ok(mut observer) = self.0.lock() {
+            observer.warning(warning);
+        }
+    }
+}
+
+impl Debug for ObserverRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ParseObserver")
+    }
+}
+
+/// An element recorded by `ParseStats`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElementStat {
+    pub tagpath: TagPath,
+    pub offset: u64,
+    pub len: u64,
+    pub elapsed: Duration,
+}
+
+/// Statistics accumulated over all elements parsed by parsers it observes.
+#[derive(Debug, Clone, Default)]
+pub struct ParseStats {
+    elements: usize,
+    bytes: u64,
+    elapsed: Duration,
+    failures: usize,
+    group_counts: BTreeMap<u16, usize>,
+    largest: Vec<ElementStat>,
+    warnings: Vec<ParseWarning>,
+}
+
+impl ParseStats {
+    /// Creates statistics to be shared with a `ParserBuilder`.
+    pub fn shared() -> Arc<Mutex<ParseStats>> {
+        Arc::new(Mutex::new(ParseStats::default()))
+    }
+
+    /// The number of elements parsed.
+    pub fn elements(&self) -> usize {
+        self.elements
+    }
+
+    /// The number of bytes of the dataset read for the elements parsed.
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// The total time taken to parse the elements.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// The number of times parsing failed.
+    pub fn failures(&self) -> usize {
+        self.failures
+    }
+
+    /// The number of elements parsed per group, by group number.
+    pub fn group_counts(&self) -> &BTreeMap<u16, usize> {
+        &self.group_counts
+    }
+
+    /// The largest elements parsed, largest first, up to `LARGEST_ELEMENTS`.
+    pub fn largest(&self) -> &[ElementStat] {
+        &self.largest
+    }
+
+    /// The problems in datasets the parsers recovered from, in the order encountered.
+    pub fn warnings(&self) -> &[ParseWarning] {
+        &self.warnings
+    }
+}
+
+impl ParseObserver for ParseStats {
+    fn element_parsed(&mut self, event: &ElementEvent) {
+        self.elements += 1;
+        self.bytes += event.len;
+        self.elapsed += event.elapsed;
+        *self
+            .group_counts
+            .entry((event.element.tag() >> 16) as u16)
+            .or_default() += 1;
+
+        let is_large: bool = self.largest.len() < LARGEST_ELEMENTS
+            || self.largest.last().is_some_and(|stat| event.len > stat.len);
+        if is_large {
+            let index: usize = self.largest.partition_point(|stat| stat.len >= event.len);
+            self.largest.insert(
+                index,
+                ElementStat {
+                    tagpath: event.element.create_tagpath(),
+                    offset: event.offset,
+                    len: event.len,
+                    elapsed: event.elapsed,
+                },
+            );
+            self.largest.truncate(LARGEST_ELEMENTS);
+        }
+    }
+
+    fn parse_failed(&mut self, _offset: u64, _error: &ParseError) {
+        self.failures += 1;
+    }
+
+    fn warning(&mut self, warning: &ParseWarning) {
+        self.warnings.push(warning.clone());
+    }
+}
@@ -12,7 +12,14 @@ use crate::core::{
         vl::ValueLength,
         vr::VRRef,
     },
-    read::{behavior::ParseBehavior, ds::dataset::Dataset, error::ParseError, stop::ParseStop},
+    read::{
+        behavior::{ParseBehavior, UnknownTSHandlerRef},
+        ds::dataset::Dataset,
+        error::ParseError,
+        observer::ObserverRef,
+        stop::ParseStop,
+        warning::ParseWarning,
+    },
     DICOM_PREFIX, DICOM_PREFIX_LENGTH, FILE_PREAMBLE_LENGTH,
 };
 
@@ -166,6 +173,35 @@ pub struct Parser<'dict, DatasetType: Read> {
     /// to read from the dataset. This is used to track when the iterator should be considered fully
     /// consumed in those cases and prevent further attempts at reading from the dataset.
     pub(super) iterator_ended: bool,
+
+    /// Whether the iterator ended due to reaching the `ParseStop`, in which case the tag last read
+    /// is retained in `self.partial_tag` and parsing can be continued with `resume()`.
+    pub(super) stopped: bool,
+
+    /// The index into `self.current_path` of the sequence whose contents are being skipped, per
+    /// `ParseStop::MaxSequenceDepth` or `ParseStop::SkipSequences`. Elements read while this is
+    /// `Some` are not returned from the iterator, except for the delimiter ending the sequence.
+    pub(super) skipped_seq: Option<usize>,
+
+    /// The byte position in the dataset at which the tag of the element being parsed starts.
+    pub(super) element_start: u64,
+
+    /// Notified of each element parsed, if configured.
+    pub(super) observer: Option<ObserverRef>,
+
+    /// Resolves transfer syntax UIDs which aren't in the dictionary, if configured.
+    pub(super) unknown_ts_handler: Option<UnknownTSHandlerRef>,
+
+    /// Problems in the dataset recovered from while parsing, in the order encountered.
+    pub(super) warnings: Vec<ParseWarning>,
+
+    /// The byte position of an item tag which was read ahead while resolving the VR of the `UN`
+    /// element containing it, per `ParseBehavior::resolve_un()`. The next tag read is this item.
+    pub(super) pending_item: Option<u64>,
+
+    /// Whether the element last read is a sequence whose VR was resolved from `UN`, which is
+    /// recorded on its `SequenceElement` once added to `self.current_path`.
+    pub(super) resolved_seq: bool,
 }
 
 impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
@@ -217,12 +253,52 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
         &self.dicom_prefix
     }
 
+    /// Whether parsing ended due to reaching the `ParseStop`, rather than the end of the dataset or
+    /// an error.
+    pub fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+
+    /// Get the problems in the dataset the parser has recovered from, in the order encountered.
+    pub fn warnings(&self) -> &[ParseWarning] {
+        &self.warnings
+    }
+
+    /// Continues parsing a parser which ended due to reaching its `ParseStop`, from its current
+    /// position in the dataset up to the new `stop`. This allows reading only the header of a
+    /// dataset, such as to check its SOP Class, before deciding whether to read the remainder
+    /// without re-reading the dataset from the start.
+    ///
+    /// Returns `false`, leaving the parser unchanged, if parsing had not ended due to reaching the
+    /// `ParseStop`.
+    pub fn resume(&mut self, stop: ParseStop) -> bool {
+        if !self.stopped {
+            return false;
+        }
+        self.behavior.set_stop(stop);
+        self.stopped = false;
+        self.iterator_ended = false;
+        true
+    }
+
+    /// Records a problem in the dataset which was recovered from, notifying the observer.
+    pub(super) fn warn(&mut self, warning: ParseWarning) {
+        #[cfg(feature = "tracing")]
+        tracing::warn!(warning = %warning, "recovered from problem in dataset");
+        if let Some(observer) = &self.observer {
+            observer.warning(&warning);
+        }
+        self.warnings.push(warning);
+    }
+
     /// Checks if the stream should stop being parsed based on `self.stop`. This should be checked
     /// after parsing a tag number from the dataset.
     fn is_at_parse_stop(&self) -> bool {
         match &self.behavior.stop() {
             // If the entire dataset is intended to be read then never indicate to stop.
-            ParseStop::EndOfDataset => false,
+            ParseStop::EndOfDataset
+            | ParseStop::MaxSequenceDepth(_)
+            | ParseStop::SkipSequences(_) => false,
 
             // Check whether the parsing has surpassed the desired stopping byte position.
             ParseStop::AfterBytePos(byte_pos) => self.bytes_read > *byte_pos,
@@ -240,6 +316,14 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
         }
     }
 
+    /// Checks if the current path is within a sequence whose VR was resolved from `UN`, whose
+    /// contents are encoded as IVRLE.
+    pub(super) fn is_in_resolved_seq(&self) -> bool {
+        self.current_path
+            .iter()
+            .any(SequenceElement::is_resolved_from_un)
+    }
+
     /// Checks if the current path is within a pixeldata tag.
     fn is_in_pixeldata(&self) -> bool {
         for seq_elem in self.current_path.iter().rev() {
@@ -277,11 +361,27 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
         }
     }
 
-    /// Parses the value of the given element as the transfer syntax return. If the transfer syntax
-    /// cannot be resolved then this sets it to the default DICOM transfer syntax which is IVRLE.
+    /// Parses the value of the given element as the transfer syntax, resolving it with the
+    /// dictionary or otherwise the `unknown_ts_handler`. A transfer syntax which the handler
+    /// doesn't resolve is reported as a `ParseWarning::UnknownTransferSyntax`. The caller decides
+    /// what to parse the dataset with if it can't be resolved.
     fn parse_transfer_syntax(&mut self, element: &DicomElement) -> ParseResult<Option<TSRef>> {
         let ts_uid: String = String::try_from(element)?;
-        Ok(self.dictionary.get_ts_by_uid(ts_uid.as_ref()))
+        if let Some(ts) = self.dictionary.get_ts_by_uid(ts_uid.as_ref()) {
+            return Ok(Some(ts));
+        }
+        let ts_uid: &str = ts_uid.trim_end_matches(['\0', ' ']);
+        let Some(handler) = self.unknown_ts_handler.clone() else {
+            return Ok(None);
+        };
+        let handled: Option<TSRef> = (handler.0)(ts_uid);
+        if handled.is_none() {
+            self.warn(ParseWarning::UnknownTransferSyntax {
+                uid: ts_uid.to_owned(),
+                offset: self.element_start,
+            });
+        }
+        Ok(handled)
     }
 
     /// Parses the value of the given element as the specific character set and sets the `cs` value
@@ -300,6 +400,13 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
             .unwrap_or(charset::DEFAULT_CHARACTER_SET))
     }
 
+    /// The path to the element last read, within the sequences currently being parsed.
+    pub(super) fn current_tagpath(&self) -> TagPath {
+        let mut full_path: TagPath = (&self.current_path).into();
+        full_path.nodes.push(self.tag_last_read.into());
+        full_path
+    }
+
     /// Builds a string containing debug state of parsing, for errors and spurious output while
     /// debugging. Format is multiple lines, first line no indent, each other single-tab indent.
     /// ```text
@@ -308,12 +415,8 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
     ///     tagpath: ReferenceSequence[1].(00A1,0000)
     /// ```
     pub(super) fn current_debug_str(&self) -> String {
-        // Render the full tag path
-        let tag = self.tag_last_read;
-        let mut full_path: TagPath = (&self.current_path).into();
-        full_path.nodes.push(tag.into());
         let tagpath_display: String =
-            TagPath::format_tagpath_to_display(&full_path, Some(self.dictionary));
+            TagPath::format_tagpath_to_display(&self.current_tagpath(), Some(self.dictionary));
 
         let vr_display = if let Some(vr) = self.vr_last_used {
             vr.ident
@@ -349,7 +452,7 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
 
     /// Performs the primary iteration for the parser but the return type is consistent for error
     /// handling and not iteration. This should be called once for each invocation of `next()`.
-    pub(super) fn iterate(&mut self) -> ParseResult<Option<DicomElement>> {
+    pub(super) fn iterate(&mut self) -> ParseResult<Option<DicomElement<'static>>> {
         // The earlier parse states will read non-elements from the dataset and move to another
         // state. A loop is used so once those succeed they continue the loop and move to next
         // states which will eventually return a dicom element.
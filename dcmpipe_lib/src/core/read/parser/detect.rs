@@ -87,6 +87,7 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
             if already_read_preamble {
                 self.detected_ts = &ts::ImplicitVRLittleEndian;
                 self.partial_tag = Some(tag);
+                self.element_start = self.bytes_read;
                 self.bytes_read += bytes_read as u64;
                 self.state = ParserState::Element;
                 return Ok(());
@@ -113,6 +114,7 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
                 if already_read_preamble {
                     self.detected_ts = &ts::ImplicitVRLittleEndian;
                     self.partial_tag = Some(tag);
+                    self.element_start = self.bytes_read;
                     self.bytes_read += bytes_read as u64;
                     self.state = ParserState::Element;
                     return Ok(());
@@ -145,6 +147,7 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
             // testing tag in either endian didn't seem to work, set as DICOM default
             self.detected_ts = &ts::ImplicitVRLittleEndian;
             self.partial_tag = Some(tag);
+            self.element_start = self.bytes_read;
             self.bytes_read += bytes_read as u64;
             self.state = ParserState::Element;
             return Ok(());
@@ -218,6 +221,7 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
             if len < MAX_VALUE_LENGTH_IN_DETECT {
                 self.detected_ts = ts;
                 self.partial_tag = Some(tag);
+                self.element_start = self.bytes_read;
                 self.partial_vl = Some(vl);
                 self.bytes_read += bytes_read as u64;
                 // FileMeta is coded to read as ExplicitVRLittleEndian and since we've determined
@@ -238,6 +242,7 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
         if already_read_preamble {
             self.detected_ts = &ts::ImplicitVRLittleEndian;
             self.partial_tag = Some(tag);
+            self.element_start = self.bytes_read;
             self.partial_vl = Some(vl);
             self.bytes_read += bytes_read as u64;
             self.state = ParserState::Element;
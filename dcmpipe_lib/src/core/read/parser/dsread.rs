@@ -10,7 +10,7 @@
 //! the value field, and it does not validate the structure of sequences (i.e. the proper uses of
 //! the Item, ItemDelimitationItem, and SequenceDelimitationItem elements.
 
-use std::io::{ErrorKind, Read};
+use std::io::{self, ErrorKind, Read};
 
 use crate::core::{
     charset::CSRef,
@@ -18,26 +18,40 @@ use crate::core::{
     dcmsqelem::SequenceElement,
     defn::{
         constants::{tags, ts},
-        tag::Tag,
+        tag::{Tag, TagPath},
         ts::TSRef,
         vl::ValueLength,
         vr::{self, VRRef},
     },
+    padding::{self, PaddingPolicy},
     read::{
         self,
+        behavior::VRMismatchPolicy,
         parser::{ParseResult, Parser},
         util::is_non_standard_seq,
+        warning::ParseWarning,
         ParseError,
     },
+    write::transcode::swap_bytes,
 };
 
+/// The largest buffer allocated ahead of reading a value, beyond which the buffer grows as the value
+/// is read.
+const MAX_VALUE_PREALLOC: usize = 1024 * 1024;
+
 impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
     /// Reads a tag attribute from the dataset, unless `self.partial_tag` is `Some`.
     pub(super) fn read_tag(&mut self, ts: TSRef) -> ParseResult<u32> {
         let tag: u32 = if let Some(partial_tag) = self.partial_tag {
             partial_tag
+        } else if let Some(item_start) = self.pending_item.take() {
+            // The item's tag was read while resolving the VR of the sequence containing it.
+            self.element_start = item_start;
+            self.partial_tag.replace(tags::ITEM);
+            tags::ITEM
         } else {
             let tag: u32 = read::util::read_tag_from_dataset(&mut self.dataset, ts.big_endian())?;
+            self.element_start = self.bytes_read;
             self.bytes_read += 4;
             self.partial_tag.replace(tag);
             tag
@@ -52,7 +66,7 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
         &mut self,
         tag: u32,
         elem_ts: TSRef,
-    ) -> ParseResult<DicomElement> {
+    ) -> ParseResult<DicomElement<'static>> {
         // Part 5, Section 7.5
         // There are three special SQ related Data Elements that are not ruled by the VR encoding
         // rules conveyed by the Transfer Syntax. They shall be encoded as Implicit VR. These
@@ -73,8 +87,12 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
             Tag::is_private(sq_el.seq_tag())
                 && is_non_standard_seq(sq_el.seq_tag(), sq_el.vr(), sq_el.vl())
         });
+        // Elements within a sequence encoded as UN are IVRLE regardless of the transfer syntax.
+        let is_parent_resolved_seq: bool = self.is_in_resolved_seq();
 
-        let ts: TSRef = if is_seq_delim || is_parent_priv_seq {
+        let ts: TSRef = if is_parent_resolved_seq {
+            &ts::ImplicitVRLittleEndian
+        } else if is_seq_delim || is_parent_priv_seq {
             if elem_ts.big_endian() {
                 &ts::ImplicitVRBigEndian
             } else {
@@ -116,7 +134,37 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
         };
         self.vl_last_used.replace(vl);
 
+        // The value length is read according to the VR encoded in the dataset, after which the
+        // value may be interpreted with the dictionary's VR.
+        let vr: VRRef = if ts.explicit_vr {
+            self.resolve_vr_mismatch(tag, vr)?
+        } else {
+            vr
+        };
+        self.vr_last_used.replace(vr);
+        let header_len: u64 = self.bytes_read - self.element_start;
+
+        let (vr, value_prefix): (VRRef, Vec<u8>) =
+            if vr == &vr::UN && !is_seq_delim && self.behavior.resolve_un() {
+                self.resolve_un_vr(tag, vl)?
+            } else {
+                (vr, Vec::with_capacity(0))
+            };
+        self.vr_last_used.replace(vr);
+
         let parse_as_seq: bool = read::util::is_non_standard_seq(tag, vr, vl);
+        // Once a sequence's VR is resolved from UN its contents are treated as if encoded in the
+        // dataset's transfer syntax, with values byte-swapped for big endian.
+        let header_ts: TSRef = if !is_parent_resolved_seq {
+            ts
+        } else if !is_seq_delim && !is_parent_priv_seq {
+            elem_ts
+        } else if elem_ts.big_endian() {
+            &ts::ImplicitVRBigEndian
+        } else {
+            &ts::ImplicitVRLittleEndian
+        };
+        let swap_value: bool = is_parent_resolved_seq && elem_ts.big_endian();
         let ts: TSRef = if parse_as_seq {
             if !ts.big_endian() {
                 &ts::ImplicitVRLittleEndian
@@ -140,11 +188,36 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
 
         //eprintln!("{}", &self.get_debug_str(ts, tag, vr, vl));
 
-        let bytes: Vec<u8> = if skip_bytes {
+        // Offsets into a deflated dataset would refer to the inflated bytes, not the source.
+        let value_offset: Option<u64> = if skip_bytes || elem_ts.deflated() || swap_value {
+            None
+        } else {
+            Some(self.bytes_read - value_prefix.len() as u64)
+        };
+        let defer_value: bool =
+            value_offset.is_some() && value_prefix.is_empty() && self.should_defer_value(tag, vl);
+        let mut bytes: Vec<u8> = if skip_bytes {
+            Vec::with_capacity(0)
+        } else if defer_value {
+            self.skip_value_field(tag, vl)?;
             Vec::with_capacity(0)
+        } else if let (false, ValueLength::Explicit(len)) = (value_prefix.is_empty(), vl) {
+            let remaining: ValueLength = ValueLength::Explicit(len - value_prefix.len() as u32);
+            let mut bytes: Vec<u8> = value_prefix;
+            bytes.extend(self.read_value_field(tag, remaining)?);
+            bytes
         } else {
             self.read_value_field(tag, vl)?
         };
+        if swap_value {
+            swap_bytes(&mut bytes, vr);
+        }
+        let read_vl: ValueLength = vl;
+        let vl: ValueLength = if skip_bytes || defer_value {
+            vl
+        } else {
+            self.apply_padding_policy(vr, vl, &mut bytes)?
+        };
 
         let ancestors: Vec<SequenceElement> = self.current_path.clone();
 
@@ -154,7 +227,33 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
             self.cs
         };
 
-        Ok(DicomElement::new(tag, vr, vl, ts, cs, bytes, ancestors))
+        let mut element: DicomElement =
+            DicomElement::new(tag, vr, vl, header_ts, cs, bytes, ancestors);
+        element.set_value_offset(value_offset);
+        if !elem_ts.deflated() {
+            let encoded_len: Option<u64> = match read_vl {
+                ValueLength::Explicit(len) => Some(header_len + u64::from(len)),
+                ValueLength::UndefinedLength => None,
+            };
+            element.set_encoded_range(self.element_start, encoded_len);
+        }
+        if let (true, Some(value_offset)) = (defer_value, value_offset) {
+            element.defer_value(value_offset);
+        }
+        Ok(element)
+    }
+
+    /// Whether the value of the element should be skipped over rather than read, per
+    /// `ParseBehavior::defer_values()`. Values the parser itself needs to interpret are never
+    /// deferred.
+    fn should_defer_value(&self, tag: u32, vl: ValueLength) -> bool {
+        let (Some(min_len), ValueLength::Explicit(len)) = (self.behavior.defer_values(), vl) else {
+            return false;
+        };
+        len > 0
+            && len >= min_len
+            && tag > tags::FILE_META_GROUP_END
+            && tag != tags::SPECIFIC_CHARACTER_SET
     }
 
     /// Reads VR from the dataset. This should only be done for ExplicitVR transfer syntaxes.
@@ -184,6 +283,127 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
             .and_then(|read_tag: &Tag| read_tag.implicit_vr())
     }
 
+    /// Compares the VR read from an Explicit VR dataset with the dictionary's VR for the tag,
+    /// returning the VR to parse the element with according to the `VRMismatchPolicy`.
+    fn resolve_vr_mismatch(&mut self, tag: u32, file_vr: VRRef) -> ParseResult<VRRef> {
+        if file_vr == &vr::UN || file_vr == &vr::INVALID {
+            return Ok(file_vr);
+        }
+        let dictionary_vr: VRRef = match self.lookup_vr(tag) {
+            Some(dictionary_vr) if !is_equivalent_vr(file_vr, dictionary_vr) => dictionary_vr,
+            _ => return Ok(file_vr),
+        };
+
+        let used_vr: VRRef = match self.behavior.vr_mismatch() {
+            VRMismatchPolicy::TrustFile => file_vr,
+            VRMismatchPolicy::TrustDictionary => {
+                if file_vr == &vr::SQ || dictionary_vr == &vr::SQ {
+                    file_vr
+                } else {
+                    dictionary_vr
+                }
+            }
+            VRMismatchPolicy::Error => {
+                return Err(ParseError::VRMismatch {
+                    tagstring: TagPath::format_tagpath_to_display(
+                        &self.current_tagpath(),
+                        Some(self.dictionary),
+                    ),
+                    file_vr,
+                    dictionary_vr,
+                });
+            }
+        };
+        self.warn(ParseWarning::VRMismatch {
+            tagpath: self.current_tagpath(),
+            offset: self.element_start,
+            file_vr,
+            dictionary_vr,
+            used_vr,
+        });
+        Ok(used_vr)
+    }
+
+    /// Checks the padding of a value read from the dataset, returning the value length of the
+    /// element according to the `PaddingPolicy`. The value has been padded to even length with a
+    /// NULL by `read_value_field()`, which normalizing replaces.
+    fn apply_padding_policy(
+        &mut self,
+        vr: VRRef,
+        vl: ValueLength,
+        bytes: &mut Vec<u8>,
+    ) -> ParseResult<ValueLength> {
+        let ValueLength::Explicit(length) = vl else {
+            return Ok(vl);
+        };
+        let policy: PaddingPolicy = self.behavior.padding();
+        if policy == PaddingPolicy::Preserve {
+            return Ok(vl);
+        }
+        let value: &[u8] = &bytes[..bytes.len().min(length as usize)];
+        let Some(issue) = padding::check(vr, value) else {
+            return Ok(vl);
+        };
+        if policy == PaddingPolicy::Error {
+            return Err(ParseError::InvalidPadding {
+                tagstring: TagPath::format_tagpath_to_display(
+                    &self.current_tagpath(),
+                    Some(self.dictionary),
+                ),
+                issue,
+            });
+        }
+        *bytes = padding::normalize(vr, value);
+        self.warn(ParseWarning::Padding {
+            tagpath: self.current_tagpath(),
+            offset: self.element_start,
+            issue,
+        });
+        Ok(ValueLength::Explicit(bytes.len() as u32))
+    }
+
+    /// Resolves the VR of an element read as `UN`, per `ParseBehavior::resolve_un()`. The VR is
+    /// resolved from the dictionary, or for Group Length and Private Creator elements which aren't
+    /// in the dictionary, their VR defined by the standard.
+    ///
+    /// Elements which remain unresolved and have an explicit value length are checked for being a
+    /// sequence by reading the first tag of their value, which for a sequence is an item. If it's
+    /// not an item, the bytes read are returned to be prepended to the value.
+    fn resolve_un_vr(&mut self, tag: u32, vl: ValueLength) -> ParseResult<(VRRef, Vec<u8>)> {
+        let resolved: Option<VRRef> =
+            self.lookup_vr(tag).filter(|vr| *vr != &vr::UN).or_else(|| {
+                if Tag::is_group_length(tag) {
+                    Some(&vr::UL)
+                } else if Tag::is_private_creator(tag) {
+                    Some(&vr::LO)
+                } else {
+                    None
+                }
+            });
+
+        match resolved {
+            Some(vr) => {
+                self.resolved_seq = vr == &vr::SQ;
+                Ok((vr, Vec::with_capacity(0)))
+            }
+            None => match vl {
+                ValueLength::Explicit(len) if len >= 8 && !self.is_in_pixeldata() => {
+                    let item_start: u64 = self.bytes_read;
+                    let lead: [u8; 4] = read::util::read_bytes(&mut self.dataset)?;
+                    self.bytes_read += 4;
+                    if u32::from_le_bytes(lead).rotate_left(16) == tags::ITEM {
+                        self.pending_item = Some(item_start);
+                        self.resolved_seq = true;
+                        Ok((&vr::SQ, Vec::with_capacity(0)))
+                    } else {
+                        Ok((&vr::UN, lead.to_vec()))
+                    }
+                }
+                _ => Ok((&vr::UN, Vec::with_capacity(0))),
+            },
+        }
+    }
+
     /// Reads a Value Length attribute from the dataset using the given transfer syntax. The number
     /// of bytes representing the value length depends on transfer syntax. If the VR has a 2-byte
     /// padding then those bytes are also read from the dataset.
@@ -202,6 +422,28 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
         result
     }
 
+    /// Skips over the value field of the element without reading it into memory, for deferring
+    /// the value. This handles a truncated value field the same as `read_value_field()`.
+    pub(super) fn skip_value_field(&mut self, tag: u32, vl: ValueLength) -> ParseResult<()> {
+        let ValueLength::Explicit(value_length) = vl else {
+            return Ok(());
+        };
+        let skipped: u64 = io::copy(
+            &mut (&mut self.dataset).take(u64::from(value_length)),
+            &mut io::sink(),
+        )?;
+        self.bytes_read += u64::from(value_length);
+        if skipped == u64::from(value_length) {
+            Ok(())
+        } else if tag == 0 || tag == tags::DATASET_TRAILING_PADDING {
+            Err(ParseError::ExpectedEOF)
+        } else {
+            Err(ParseError::IOError {
+                source: io::Error::from(ErrorKind::UnexpectedEof),
+            })
+        }
+    }
+
     /// Reads the value field of the dicom element into a byte array. If the `ValueLength` is
     /// undefined then this returns an empty array as elements with undefined length should have
     /// their contents parsed as dicom elements.
@@ -219,28 +461,40 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
                 } else {
                     value_length as usize
                 };
-                let mut buffer: Vec<u8> = vec![0; buffer_size];
-                let buffer_slice: &mut [u8] = &mut buffer.as_mut_slice()[0..value_length as usize];
-                let result: ParseResult<()> = self.dataset.read_exact(buffer_slice).map_err(|e| {
-                    // Some datasets may end with this DataSetTrailingPadding tag (or just all
-                    // zeroes) and also have value length which does not match the actual value
-                    // field's size. The standard indicates that the content of the value field
-                    // should hold no significance - consider this not an error.
-                    // See Part 10, Section 7.2
-                    if (tag == 0 || tag == tags::DATASET_TRAILING_PADDING)
-                        && e.kind() == ErrorKind::UnexpectedEof
-                    {
-                        // TODO: Take what values were read and return that as a byte array, so the
-                        //       original contents of the dataset are retained if needed.
-                        ParseError::ExpectedEOF
-                    } else {
-                        ParseError::IOError { source: e }
-                    }
-                });
+                // The buffer grows as the value is read rather than being allocated up front, so
+                // that a corrupt value length doesn't allocate far more than the dataset holds.
+                let mut buffer: Vec<u8> = Vec::with_capacity(buffer_size.min(MAX_VALUE_PREALLOC));
+                let result: ParseResult<()> = (&mut self.dataset)
+                    .take(u64::from(value_length))
+                    .read_to_end(&mut buffer)
+                    .and_then(|read| {
+                        if read < value_length as usize {
+                            Err(io::Error::from(ErrorKind::UnexpectedEof))
+                        } else {
+                            Ok(())
+                        }
+                    })
+                    .map_err(|e| {
+                        // Some datasets may end with this DataSetTrailingPadding tag (or just all
+                        // zeroes) and also have value length which does not match the actual value
+                        // field's size. The standard indicates that the content of the value field
+                        // should hold no significance - consider this not an error.
+                        // See Part 10, Section 7.2
+                        if (tag == 0 || tag == tags::DATASET_TRAILING_PADDING)
+                            && e.kind() == ErrorKind::UnexpectedEof
+                        {
+                            // TODO: Take what values were read and return that as a byte array, so the
+                            //       original contents of the dataset are retained if needed.
+                            ParseError::ExpectedEOF
+                        } else {
+                            ParseError::IOError { source: e }
+                        }
+                    });
 
                 match result {
                     Ok(_) => {
                         self.bytes_read += u64::from(value_length);
+                        buffer.resize(buffer_size, 0);
                         Ok(buffer)
                     }
                     Err(ParseError::ExpectedEOF) => {
@@ -253,3 +507,15 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
         }
     }
 }
+
+/// Whether the VR read from the dataset agrees with the dictionary's VR. The dictionary records only
+/// the first VR of attributes which allow several, such as "US or SS or OW" of LUT Data, so VRs
+/// which commonly substitute for one another are considered equivalent.
+fn is_equivalent_vr(file_vr: VRRef, dictionary_vr: VRRef) -> bool {
+    const ALTERNATIVES: [&[VRRef]; 2] = [&[&vr::US, &vr::SS, &vr::OW], &[&vr::OB, &vr::OW]];
+    file_vr == dictionary_vr
+        || dictionary_vr == &vr::UN
+        || ALTERNATIVES
+            .iter()
+            .any(|group| group.contains(&file_vr) && group.contains(&dictionary_vr))
+}
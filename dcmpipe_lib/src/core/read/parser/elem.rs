@@ -19,7 +19,19 @@ use crate::core::{
 
 impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
     /// Performs the `ParserState::Element` iteration
-    pub(crate) fn iterate_element(&mut self) -> ParseResult<Option<DicomElement>> {
+    pub(crate) fn iterate_element(&mut self) -> ParseResult<Option<DicomElement<'static>>> {
+        loop {
+            let Some(element) = self.read_next_element()? else {
+                return Ok(None);
+            };
+            if !self.is_in_skipped_seq(&element) {
+                return Ok(Some(element));
+            }
+        }
+    }
+
+    /// Reads the next element from the dataset, updating the current sequence path.
+    fn read_next_element(&mut self) -> ParseResult<Option<DicomElement<'static>>> {
         // use the transfer syntax defined by the dataset, or if not specified or not yet seen then
         // use the one initialized/detected.
         let ts: TSRef = self.dataset_ts.unwrap_or(self.detected_ts);
@@ -29,8 +41,15 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
             self.dataset.set_read_deflated(ts.deflated());
         }
 
-        let tag: u32 = self.read_tag(ts)?;
+        // The tags of elements within a sequence resolved from UN are little endian, as IVRLE.
+        let tag_ts: TSRef = if self.is_in_resolved_seq() {
+            &ts::ImplicitVRLittleEndian
+        } else {
+            ts
+        };
+        let tag: u32 = self.read_tag(tag_ts)?;
         if self.is_at_parse_stop() {
+            self.stopped = true;
             return Ok(None);
         }
 
@@ -63,6 +82,8 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
             self.dataset_ts = self
                 .parse_transfer_syntax(&element)?
                 .or(Some(&ts::ImplicitVRLittleEndian));
+            #[cfg(feature = "tracing")]
+            tracing::debug!(ts = self.ts().uid().name(), "dataset transfer syntax");
         } else if element.tag() == tags::SPECIFIC_CHARACTER_SET {
             let cs: CSRef = self.parse_specific_character_set(&element)?;
             if element.sequence_path().is_empty() {
@@ -91,10 +112,34 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
         }
 
         self.pop_sequence_items_base_on_byte_pos();
+        if self
+            .skipped_seq
+            .is_some_and(|seq_index| self.current_path.len() <= seq_index)
+        {
+            self.skipped_seq = None;
+        }
 
+        let resolved_seq: bool = std::mem::take(&mut self.resolved_seq);
         if element.is_seq_like() || tag == tags::ITEM {
+            if self.should_skip_contents(&element) {
+                if let ValueLength::Explicit(len) = element.vl() {
+                    // The tag of the first item may have been read already, if resolved from UN.
+                    let read_ahead: u32 = if self.pending_item.take().is_some() {
+                        4
+                    } else {
+                        0
+                    };
+                    // The contents can be skipped over entirely, so it's never added to the path.
+                    self.skip_value_field(tag, ValueLength::Explicit(len - read_ahead))?;
+                    return Ok(Some(element));
+                }
+                // Otherwise the contents have to be read to find the delimiter ending them.
+                self.skipped_seq.get_or_insert(self.current_path.len());
+            }
+
             let seq_end_pos: Option<u64> = if let ValueLength::Explicit(len) = element.vl() {
-                Some(self.bytes_read + u64::from(len))
+                let value_start: u64 = self.pending_item.unwrap_or(self.bytes_read);
+                Some(value_start + u64::from(len))
             } else {
                 None
             };
@@ -105,15 +150,49 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
                 self.cs
             };
 
-            self.current_path.push(SequenceElement::new(
-                tag,
-                seq_end_pos,
-                element.vr(),
-                element.vl(),
-                sq_cs,
-            ));
+            let mut seq_elem: SequenceElement =
+                SequenceElement::new(tag, seq_end_pos, element.vr(), element.vl(), sq_cs);
+            if resolved_seq {
+                seq_elem.set_resolved_from_un();
+            }
+            self.current_path.push(seq_elem);
         }
 
         Ok(Some(element))
     }
+
+    /// Whether the element is within a sequence whose contents are being skipped. The delimiter
+    /// ending the skipped sequence is not within it, so that the sequence is still well-formed.
+    fn is_in_skipped_seq(&self, element: &DicomElement) -> bool {
+        self.skipped_seq
+            .is_some_and(|seq_index| element.sequence_path().len() > seq_index)
+    }
+
+    /// Whether the contents of the given sequence or item element should be skipped, per the
+    /// `ParseStop`. Everything within a skipped sequence is skipped, except encapsulated pixel
+    /// data fragments whose values have already been read.
+    fn should_skip_contents(&self, element: &DicomElement) -> bool {
+        let tag: u32 = element.tag();
+        if tag == tags::ITEM {
+            return self.skipped_seq.is_some() && !self.is_in_pixeldata();
+        }
+        if self.skipped_seq.is_some() {
+            return true;
+        }
+        if tag == tags::PIXEL_DATA
+            || tag == tags::FLOAT_PIXEL_DATA
+            || tag == tags::DOUBLE_PIXEL_DATA
+        {
+            return false;
+        }
+        let depth: usize = self
+            .current_path
+            .iter()
+            .filter(|sq_el| sq_el.seq_tag() != tags::ITEM)
+            .count()
+            + 1;
+        self.behavior
+            .stop()
+            .skips_sequence(tag, u32::try_from(depth).unwrap_or(u32::MAX))
+    }
 }
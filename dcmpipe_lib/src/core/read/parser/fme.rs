@@ -42,12 +42,13 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
     }
 
     /// Performs the `ParserState::GroupLength` iteration
-    pub(super) fn iterate_group_length(&mut self) -> ParseResult<Option<DicomElement>> {
+    pub(super) fn iterate_group_length(&mut self) -> ParseResult<Option<DicomElement<'static>>> {
         // See comment on `detected_ts` for further details on why this is being used as to
         // hard-coded to ExplicitVRLittleEndian, as the standard defines File Meta to use.
         let ts: TSRef = self.detected_ts;
         let tag: u32 = self.read_tag(ts)?;
         if self.is_at_parse_stop() {
+            self.stopped = true;
             return Ok(None);
         }
 
@@ -71,7 +72,7 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
     }
 
     /// Performs the `ParserState::FileMeta` iteration
-    pub(super) fn iterate_file_meta(&mut self) -> ParseResult<Option<DicomElement>> {
+    pub(super) fn iterate_file_meta(&mut self) -> ParseResult<Option<DicomElement<'static>>> {
         // check if we're about to read an element which is outside the file meta section, if so
         // then change states outside of this one.
         if self.fmi_grouplength > 0
@@ -93,6 +94,7 @@ impl<'dict, DatasetType: Read> Parser<'dict, DatasetType> {
 
         let tag: u32 = self.read_tag(ts)?;
         if self.is_at_parse_stop() {
+            self.stopped = true;
             return Ok(None);
         }
 
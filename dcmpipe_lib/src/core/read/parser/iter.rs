@@ -1,16 +1,19 @@
 use std::io::Read;
 
+#[cfg(feature = "tracing")]
+use crate::core::defn::tag::Tag;
 use crate::core::{
     dcmelement::DicomElement,
     read::{
         error::ParseError,
+        observer::{ElementEvent, Stopwatch},
         parser::{ParseResult, Parser},
     },
 };
 
 /// The implementation for `Parser` which is the core iteration loop.
 impl<'dict, DatasetType: Read> Iterator for Parser<'dict, DatasetType> {
-    type Item = ParseResult<DicomElement>;
+    type Item = ParseResult<DicomElement<'static>>;
 
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
         // Once an error occurs, or the first `None` is returned then do not
@@ -19,16 +22,48 @@ impl<'dict, DatasetType: Read> Iterator for Parser<'dict, DatasetType> {
             return None;
         }
 
-        match self.iterate() {
+        let started: Option<Stopwatch> = self.observer.as_ref().map(|_| Stopwatch::start());
+        let result: ParseResult<Option<DicomElement<'static>>> = self.iterate();
+        if let (Some(observer), Some(started)) = (&self.observer, started) {
+            match &result {
+                Ok(Some(element)) => observer.element_parsed(&ElementEvent {
+                    element,
+                    offset: self.element_start,
+                    len: self.bytes_read - self.element_start,
+                    elapsed: started.elapsed(),
+                }),
+                Err(ParseError::ExpectedEOF) | Ok(None) => {}
+                Err(e) => observer.parse_failed(self.bytes_read, e),
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(Some(element)) => tracing::trace!(
+                tag = %Tag::format_tag_to_display(element.tag()),
+                vr = element.vr().ident,
+                offset = self.element_start,
+                "parsed element"
+            ),
+            Err(ParseError::ExpectedEOF) | Ok(None) => {}
+            Err(e) => tracing::warn!(
+                error = %e,
+                offset = self.bytes_read,
+                detail = %self.current_debug_str(),
+                "parsing failed"
+            ),
+        }
+
+        match result {
             Err(ParseError::ExpectedEOF) => {
                 self.iterator_ended = true;
                 None
             }
             // This function should be the only place that creates DetailedErrors, but as
             // precaution check and propagate without wrapping.
-            Err(ParseError::DetailedError { source, detail }) => {
+            Err(e @ ParseError::DetailedError { .. }) => {
                 self.iterator_ended = true;
-                Some(Err(ParseError::DetailedError { source, detail }))
+                Some(Err(e))
             }
             Err(e) => {
                 self.iterator_ended = true;
@@ -36,6 +71,8 @@ impl<'dict, DatasetType: Read> Iterator for Parser<'dict, DatasetType> {
                 Some(Err(ParseError::DetailedError {
                     source: Box::new(e),
                     detail,
+                    offset: self.bytes_read,
+                    tagpath: self.current_tagpath(),
                 }))
             }
             Ok(None) => {
@@ -1,4 +1,4 @@
-use crate::core::defn::tag::{TagNode, TagPath};
+use crate::core::defn::tag::{TagNode, TagPath, TagSet};
 
 /// ParseStop specifies the stopping point at which parsing of a DICOM dataset should end.
 #[derive(Clone, Debug)]
@@ -20,12 +20,29 @@ pub enum ParseStop {
     /// is in the middle of an element then bytes from that dataset will continue to be read until
     /// the elment is fully parsed.
     AfterBytePos(u64),
+
+    /// Read the entire dataset, but skip the contents of sequences nested deeper than the given
+    /// depth. Sequences at the root of the dataset have a depth of 1, so a depth of 0 skips the
+    /// contents of all sequences. Skipped sequence elements are still parsed, though will have no
+    /// items, and the elements following them are read as normal.
+    ///
+    /// Encapsulated pixel data is not considered a sequence and is never skipped.
+    MaxSequenceDepth(u32),
+
+    /// Read the entire dataset, but skip the contents of any sequence with one of the given tags,
+    /// wherever it occurs within the dataset. This avoids parsing sequences which can be very large
+    /// but are irrelevant, such as the `ReferencedImageSequence` of dose reports. Skipped sequence
+    /// elements are still parsed, though will have no items, and the elements following them are
+    /// read as normal.
+    SkipSequences(TagSet),
 }
 
 impl ParseStop {
     /// Evaluates the given `TagPath` against this `ParseStop`'s defined stopping point, assuming
     /// this is `ParseStop::BeforeTagValue` or `ParseStop::AfterTagValue`. If this is neither
     /// `BeforeTagValue` nor `AfterTagValue` then this returns false.
+    ///
+    /// `MaxSequenceDepth` and `SkipSequences` never stop parsing, refer to `skips_sequence()`.
     pub fn evaluate(&self, current: &TagPath) -> bool {
         match self {
             ParseStop::BeforeTagValue(target) => target
@@ -42,6 +59,17 @@ impl ParseStop {
         }
     }
 
+    /// Evaluates whether the contents of the sequence with the given tag should be skipped, where
+    /// `depth` is the number of sequences it's nested within plus one. This is only ever true for
+    /// `ParseStop::MaxSequenceDepth` and `ParseStop::SkipSequences`.
+    pub fn skips_sequence(&self, seq_tag: u32, depth: u32) -> bool {
+        match self {
+            ParseStop::MaxSequenceDepth(max_depth) => depth > *max_depth,
+            ParseStop::SkipSequences(skipped) => skipped.contains(seq_tag),
+            _ => false,
+        }
+    }
+
     fn is_before_tag_value((target, current): (&TagNode, &TagNode)) -> bool {
         let target_tag = target.tag();
         match current.tag() {
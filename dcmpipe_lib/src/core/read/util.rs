@@ -1,4 +1,4 @@
-use std::io::{ErrorKind, Read};
+use std::io::{BufRead, ErrorKind};
 
 use crate::core::{
     defn::{
@@ -24,9 +24,31 @@ where
         && vl == ValueLength::UndefinedLength
 }
 
+/// Reads exactly `N` bytes from the dataset. Element headers are read a few bytes at a time, so
+/// these are copied directly out of the dataset's buffer when it holds enough bytes, only falling
+/// back to `Read::read_exact` when the bytes span a refill of the buffer.
+pub(crate) fn read_bytes<const N: usize>(dataset: &mut impl BufRead) -> std::io::Result<[u8; N]> {
+    let mut buf: [u8; N] = [0; N];
+    let available: &[u8] = dataset.fill_buf()?;
+    if available.len() >= N {
+        buf.copy_from_slice(&available[..N]);
+        dataset.consume(N);
+    } else {
+        dataset.read_exact(&mut buf)?;
+    }
+    Ok(buf)
+}
+
 /// This is a variation of `Read::read_exact` however if zero bytes are read instead of returning
 /// an error with `ErrorKind::UnexpectedEof` it will return an error with `ParseError::ExpectedEOF`.
-fn read_exact_expect_eof(dataset: &mut impl Read, mut buf: &mut [u8]) -> ParseResult<()> {
+fn read_exact_expect_eof(dataset: &mut impl BufRead, mut buf: &mut [u8]) -> ParseResult<()> {
+    let available: &[u8] = dataset.fill_buf()?;
+    if available.len() >= buf.len() {
+        buf.copy_from_slice(&available[..buf.len()]);
+        dataset.consume(buf.len());
+        return Ok(());
+    }
+
     let mut bytes_read: usize = 0;
     while !buf.is_empty() {
         match dataset.read(buf) {
@@ -57,7 +79,10 @@ fn read_exact_expect_eof(dataset: &mut impl Read, mut buf: &mut [u8]) -> ParseRe
 }
 
 /// Reads a tag attribute from a given dataset
-pub(crate) fn read_tag_from_dataset(dataset: &mut impl Read, big_endian: bool) -> ParseResult<u32> {
+pub(crate) fn read_tag_from_dataset(
+    dataset: &mut impl BufRead,
+    big_endian: bool,
+) -> ParseResult<u32> {
     let mut buf: [u8; 2] = [0; 2];
 
     read_exact_expect_eof(dataset, &mut buf)?;
@@ -67,7 +92,7 @@ pub(crate) fn read_tag_from_dataset(dataset: &mut impl Read, big_endian: bool) -
         u32::from(u16::from_le_bytes(buf)) << 16
     };
 
-    dataset.read_exact(&mut buf)?;
+    let buf: [u8; 2] = read_bytes(dataset)?;
     let element_number: u32 = if big_endian {
         u32::from(u16::from_be_bytes(buf))
     } else {
@@ -79,9 +104,8 @@ pub(crate) fn read_tag_from_dataset(dataset: &mut impl Read, big_endian: bool) -
 }
 
 /// Reads a VR from a given dataset.
-pub(crate) fn read_vr_from_dataset(dataset: &mut impl Read) -> ParseResult<VRRef> {
-    let mut buf: [u8; 2] = [0; 2];
-    dataset.read_exact(&mut buf)?;
+pub(crate) fn read_vr_from_dataset(dataset: &mut impl BufRead) -> ParseResult<VRRef> {
+    let buf: [u8; 2] = read_bytes(dataset)?;
     let first_char: u8 = buf[0];
     let second_char: u8 = buf[1];
 
@@ -89,7 +113,7 @@ pub(crate) fn read_vr_from_dataset(dataset: &mut impl Read) -> ParseResult<VRRef
     let vr: VRRef = match VR::from_code(code) {
         Some(found_vr) => {
             if found_vr.has_explicit_2byte_pad {
-                dataset.read_exact(&mut buf)?;
+                read_bytes::<2>(dataset)?;
             }
             found_vr
         }
@@ -104,21 +128,19 @@ pub(crate) fn read_vr_from_dataset(dataset: &mut impl Read) -> ParseResult<VRRef
 /// `ts` The transfer syntax of the element being read from.
 /// `vr` The VR of the current element the value length is being read for.
 pub(crate) fn read_value_length_from_dataset(
-    dataset: &mut impl Read,
+    dataset: &mut impl BufRead,
     ts: TSRef,
     vr: VRRef,
 ) -> ParseResult<ValueLength> {
     let value_length: u32 = if !ts.explicit_vr() || vr.has_explicit_2byte_pad {
-        let mut buf: [u8; 4] = [0; 4];
-        dataset.read_exact(&mut buf)?;
+        let buf: [u8; 4] = read_bytes(dataset)?;
         if ts.big_endian() {
             u32::from_be_bytes(buf)
         } else {
             u32::from_le_bytes(buf)
         }
     } else {
-        let mut buf: [u8; 2] = [0; 2];
-        dataset.read_exact(&mut buf)?;
+        let buf: [u8; 2] = read_bytes(dataset)?;
         if ts.big_endian() {
             u16::from_be_bytes(buf) as u32
         } else {
@@ -15,12 +15,28 @@ use crate::core::{
 
 use super::error::ParseErrorInfo;
 
+/// Values deferred during parsing have no bytes to decode until resolved, and bulk data has no
+/// bytes at all, which should not be mistaken for an empty value.
+fn check_resolved(elem: &DicomElement) -> ParseResult<()> {
+    if elem.is_deferred() {
+        return Err(ParseErrorInfo(elem, "value was deferred and not resolved", None).into());
+    }
+    if elem.bulk_data().is_some() {
+        return Err(ParseErrorInfo(elem, "value is a bulk data reference", None).into());
+    }
+    Ok(())
+}
+
 impl<'elem> TryFrom<ElementWithVr<'elem>> for RawValue {
     type Error = ParseError;
 
     fn try_from(value: ElementWithVr<'elem>) -> Result<Self, Self::Error> {
         let elem = value.0;
         let vr = value.1;
+        if let Some(bulk_data) = elem.bulk_data() {
+            return Ok(RawValue::BulkData(bulk_data.clone()));
+        }
+        check_resolved(elem)?;
         if elem.data().is_empty() {
             Ok(RawValue::Bytes(Vec::with_capacity(0)))
         } else if vr == &vr::AT {
@@ -69,15 +85,15 @@ impl<'elem> TryFrom<ElementWithVr<'elem>> for RawValue {
             if let Ok(uid) = possible_uid {
                 Ok(RawValue::Uid(uid))
             } else {
-                Ok(RawValue::Bytes(elem.data().clone()))
+                Ok(RawValue::Bytes(elem.data().to_vec()))
             }
         } else {
-            Ok(RawValue::Bytes(elem.data().clone()))
+            Ok(RawValue::Bytes(elem.data().to_vec()))
         }
     }
 }
 
-impl TryFrom<&DicomElement> for RawValue {
+impl TryFrom<&DicomElement<'_>> for RawValue {
     type Error = ParseError;
 
     /// Based on the VR of this element, parses the binary data into a RawValue.
@@ -86,14 +102,14 @@ impl TryFrom<&DicomElement> for RawValue {
     }
 }
 
-impl TryFrom<&DicomElement> for Vec<Attribute> {
+impl TryFrom<&DicomElement<'_>> for Vec<Attribute> {
     type Error = ParseError;
 
     /// Parses the value for this element as an attribute (aka a tag)
     /// Associated VRs: AT
     fn try_from(value: &DicomElement) -> ParseResult<Self> {
         let shorts: Vec<u16> = value.try_into()?;
-        if shorts.len() % 2 != 0 {
+        if !shorts.len().is_multiple_of(2) {
             return Err(ParseErrorInfo(value, "value is not a multiple of 4 bytes", None).into());
         }
 
@@ -107,7 +123,7 @@ impl TryFrom<&DicomElement> for Vec<Attribute> {
     }
 }
 
-impl TryFrom<&DicomElement> for String {
+impl TryFrom<&DicomElement<'_>> for String {
     type Error = ParseError;
 
     /// Parses the value of this element as a string using the element's encoding and VR
@@ -130,6 +146,7 @@ impl<'elem> TryFrom<ElementWithVr<'elem>> for String {
     /// AE, AS, CS, DA, DS, DT, IS, LO, LT, PN, SH, ST, TM, UC, UI, UR, UT
     fn try_from(value: ElementWithVr<'_>) -> ParseResult<Self> {
         let element: &DicomElement = value.0;
+        check_resolved(element)?;
         let data: &[u8] = BytesWithoutPadding::from(value).0;
         element
             .cs()
@@ -138,7 +155,7 @@ impl<'elem> TryFrom<ElementWithVr<'elem>> for String {
     }
 }
 
-impl TryFrom<&DicomElement> for Vec<String> {
+impl TryFrom<&DicomElement<'_>> for Vec<String> {
     type Error = ParseError;
 
     /// Parses the value of this element as a list of strings using the element's encoding and vr.
@@ -161,6 +178,7 @@ impl<'elem> TryFrom<ElementWithVr<'elem>> for Vec<String> {
     /// AE, AS, CS, DA, DS, DT, IS, LO, LT, PN, SH, ST, TM, UC, UI, UR, UT
     fn try_from(value: ElementWithVr<'_>) -> ParseResult<Self> {
         let element: &DicomElement = value.0;
+        check_resolved(element)?;
         let vr: VRRef = value.1;
         let data: &[u8] = BytesWithoutPadding::from(value).0;
         element
@@ -168,13 +186,20 @@ impl<'elem> TryFrom<ElementWithVr<'elem>> for Vec<String> {
             .decode(data)
             .map_err(|e| ParseError::CharsetError { source: e })
             .map(|multivalue: String| {
-                if !vr.allows_backslash_text_value {
+                if vr.is_single_valued_text() {
+                    vec![multivalue]
+                } else if vr == &vr::UC {
+                    // Each value of UC may be padded with trailing spaces, while leading spaces are
+                    // significant.
                     multivalue
                         .split(CS_SEPARATOR)
-                        .map(str::to_owned)
+                        .map(|value| value.trim_end_matches(' ').to_owned())
                         .collect::<Vec<String>>()
                 } else {
-                    vec![multivalue]
+                    multivalue
+                        .split(CS_SEPARATOR)
+                        .map(str::to_owned)
+                        .collect::<Vec<String>>()
                 }
             })
     }
@@ -188,7 +213,7 @@ impl<'elem> From<ElementWithVr<'elem>> for BytesWithoutPadding<'elem> {
         // grab the position to start reading bytes from prior to computing the new bytes_read
         let mut lindex: usize = 0;
 
-        let data: &[u8] = value.0.data().as_slice();
+        let data: &[u8] = value.0.data();
         if data.is_empty() {
             return BytesWithoutPadding(data);
         }
@@ -206,8 +231,9 @@ impl<'elem> From<ElementWithVr<'elem>> for BytesWithoutPadding<'elem> {
                     }
                 }
             } else if value.1.padding == vr::NULL_PADDING {
-                // null byte padding is only singular and only if used to achieve even length
-                if data.len() % 2 == 0 && data[rindex] == vr::NULL_PADDING {
+                // null byte padding should be singular, though some datasets follow UIDs with
+                // several. Only trailing nulls are stripped, any within the value are retained.
+                while rindex > lindex && data[rindex] == vr::NULL_PADDING {
                     rindex -= 1;
                 }
             }
@@ -267,13 +293,14 @@ where
     FLE: Fn(&[u8]) -> ParseResult<T>,
     FBE: Fn(&[u8]) -> ParseResult<T>,
 {
+    check_resolved(value)?;
     let num_bytes: usize = value.data().len();
     if num_bytes == 0 {
         return Ok(Vec::with_capacity(0));
     }
 
     let t_size = size_of::<T>();
-    if num_bytes % t_size != 0 {
+    if !num_bytes.is_multiple_of(t_size) {
         let t_name = type_name::<T>();
         return Err(ParseErrorInfo(
             value,
@@ -303,7 +330,7 @@ where
     Ok(result)
 }
 
-impl TryFrom<&DicomElement> for i16 {
+impl TryFrom<&DicomElement<'_>> for i16 {
     type Error = ParseError;
 
     /// Parses the value for this element as a signed 16bit integer
@@ -316,7 +343,7 @@ impl TryFrom<&DicomElement> for i16 {
     }
 }
 
-impl TryFrom<&DicomElement> for Vec<i16> {
+impl TryFrom<&DicomElement<'_>> for Vec<i16> {
     type Error = ParseError;
 
     /// Parses the value for this element as a list of signed 16bit integer values
@@ -341,7 +368,7 @@ impl TryFrom<&DicomElement> for Vec<i16> {
     }
 }
 
-impl TryFrom<&DicomElement> for u16 {
+impl TryFrom<&DicomElement<'_>> for u16 {
     type Error = ParseError;
 
     /// Parses the value for this element as an unsigned 16bit integer
@@ -354,7 +381,7 @@ impl TryFrom<&DicomElement> for u16 {
     }
 }
 
-impl TryFrom<&DicomElement> for Vec<u16> {
+impl TryFrom<&DicomElement<'_>> for Vec<u16> {
     type Error = ParseError;
 
     /// Parses the value for this element as a list of unsigned 16bit integer values
@@ -379,7 +406,7 @@ impl TryFrom<&DicomElement> for Vec<u16> {
     }
 }
 
-impl TryFrom<&DicomElement> for i32 {
+impl TryFrom<&DicomElement<'_>> for i32 {
     type Error = ParseError;
 
     /// Parses the value for this element as a signed 32bit integer
@@ -392,7 +419,7 @@ impl TryFrom<&DicomElement> for i32 {
     }
 }
 
-impl TryFrom<&DicomElement> for Vec<i32> {
+impl TryFrom<&DicomElement<'_>> for Vec<i32> {
     type Error = ParseError;
 
     /// Parses the value for this element as a list of signed 32bit integer values
@@ -417,7 +444,7 @@ impl TryFrom<&DicomElement> for Vec<i32> {
     }
 }
 
-impl TryFrom<&DicomElement> for u32 {
+impl TryFrom<&DicomElement<'_>> for u32 {
     type Error = ParseError;
 
     /// Parses the value for this element as an unsigned 32bit integer
@@ -430,7 +457,7 @@ impl TryFrom<&DicomElement> for u32 {
     }
 }
 
-impl TryFrom<&DicomElement> for Vec<u32> {
+impl TryFrom<&DicomElement<'_>> for Vec<u32> {
     type Error = ParseError;
 
     /// Parses the value for this element as a list of unsigned 32bit integer values
@@ -455,7 +482,7 @@ impl TryFrom<&DicomElement> for Vec<u32> {
     }
 }
 
-impl TryFrom<&DicomElement> for Vec<i64> {
+impl TryFrom<&DicomElement<'_>> for Vec<i64> {
     type Error = ParseError;
 
     /// Parses the value for this element as a list of signed 64bit integer values
@@ -480,7 +507,7 @@ impl TryFrom<&DicomElement> for Vec<i64> {
     }
 }
 
-impl TryFrom<&DicomElement> for Vec<u64> {
+impl TryFrom<&DicomElement<'_>> for Vec<u64> {
     type Error = ParseError;
 
     /// Parses the value for this element as a list of unsigned 64bit integer values
@@ -505,7 +532,7 @@ impl TryFrom<&DicomElement> for Vec<u64> {
     }
 }
 
-impl TryFrom<&DicomElement> for f32 {
+impl TryFrom<&DicomElement<'_>> for f32 {
     type Error = ParseError;
 
     /// Parses the value for this element as a 32bit floating point
@@ -518,7 +545,7 @@ impl TryFrom<&DicomElement> for f32 {
     }
 }
 
-impl TryFrom<&DicomElement> for Vec<f32> {
+impl TryFrom<&DicomElement<'_>> for Vec<f32> {
     type Error = ParseError;
 
     /// Parses the value for this element as a list of 32bit floating point values
@@ -543,7 +570,7 @@ impl TryFrom<&DicomElement> for Vec<f32> {
     }
 }
 
-impl TryFrom<&DicomElement> for f64 {
+impl TryFrom<&DicomElement<'_>> for f64 {
     type Error = ParseError;
 
     /// Parses the value for this element as a 64bit floating point
@@ -556,7 +583,7 @@ impl TryFrom<&DicomElement> for f64 {
     }
 }
 
-impl TryFrom<&DicomElement> for Vec<f64> {
+impl TryFrom<&DicomElement<'_>> for Vec<f64> {
     type Error = ParseError;
 
     /// Parses the value for this element as a list of 64bit floating point values
@@ -0,0 +1,80 @@
+//! Problems in a dataset which the parser recovers from rather than failing.
+//!
+//! Warnings are retained by the `Parser`, see `Parser::warnings()`, and passed to the
+//! `ParseObserver` if one is configured.
+
+use std::fmt::{Display, Formatter};
+
+use crate::core::{
+    defn::{tag::TagPath, vr::VRRef},
+    padding::PaddingIssue,
+};
+
+/// A problem in the dataset which the parser recovered from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseWarning {
+    /// The VR of an element in an Explicit VR dataset differs from the VR the dictionary defines
+    /// for its tag. The element was parsed with `used_vr`, per the `VRMismatchPolicy`.
+    VRMismatch {
+        tagpath: TagPath,
+        /// The byte position in the dataset at which the element's tag starts.
+        offset: u64,
+        file_vr: VRRef,
+        dictionary_vr: VRRef,
+        used_vr: VRRef,
+    },
+
+    /// A value isn't padded as the standard requires. Its padding was rewritten, per the
+    /// `PaddingPolicy`.
+    Padding {
+        tagpath: TagPath,
+        /// The byte position in the dataset at which the element's tag starts.
+        offset: u64,
+        issue: PaddingIssue,
+    },
+
+    /// The `TransferSyntaxUID` isn't in the dictionary, and wasn't resolved by the configured
+    /// `UnknownTSHandler`. The rest of the dataset was parsed with a transfer
+    /// syntax detected from its encoding, or Implicit VR Little Endian if the File Meta group was
+    /// not parsed as such.
+    UnknownTransferSyntax {
+        uid: String,
+        /// The byte position in the dataset at which the `TransferSyntaxUID` element starts.
+        offset: u64,
+    },
+}
+
+impl Display for ParseWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseWarning::VRMismatch {
+                tagpath,
+                offset,
+                file_vr,
+                dictionary_vr,
+                used_vr,
+            } => write!(
+                f,
+                "vr {} of element {} at byte pos {offset:#X} differs from dictionary vr {}, using {}",
+                file_vr.ident,
+                TagPath::format_tagpath_to_display(tagpath, None),
+                dictionary_vr.ident,
+                used_vr.ident,
+            ),
+            ParseWarning::Padding {
+                tagpath,
+                offset,
+                issue,
+            } => write!(
+                f,
+                "{issue} for element {} at byte pos {offset:#X}, normalized its padding",
+                TagPath::format_tagpath_to_display(tagpath, None),
+            ),
+            ParseWarning::UnknownTransferSyntax { uid, offset } => write!(
+                f,
+                "transfer syntax {uid} at byte pos {offset:#X} is unknown, guessing its encoding",
+            ),
+        }
+    }
+}
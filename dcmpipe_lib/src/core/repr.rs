@@ -0,0 +1,312 @@
+//! A structured representation of datasets for use with serde, enabled by the `serde` feature.
+//!
+//! Datasets are represented as a tree of elements, each with its tag, VR, and parsed `RawValue`,
+//! which can be serialized with any serde format, e.g. for configuration of tooling or snapshots of
+//! datasets in tests. This is not the DICOM JSON Model of Part 18, Annex F, but is lossless, such
+//! that a deserialized dataset writes the same bytes as the dataset it was serialized from:
+//!
+//! - Values of `DS` and `IS` are kept as strings, rather than parsed as numbers.
+//! - Values which can't be decoded, e.g. text not valid in the character set, are kept as bytes.
+//! - Whether sequences and items have undefined length is kept, while their delimiters are omitted
+//!   and recreated when deserialized.
+//!
+//! `DicomRoot` and `RawValue` implement `Serialize` and `Deserialize` directly, where deserialized
+//! datasets use the standard dictionary. Use `DatasetRepr::to_dcmroot()` for other dictionaries.
+
+use std::collections::BTreeMap;
+
+use encoding_rs::Encoding;
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+
+use crate::{
+    core::{
+        charset::CSRef,
+        dcmelement::DicomElement,
+        dcmobject::{DicomObject, DicomRoot},
+        dcmsqelem::SequenceElement,
+        defn::{
+            constants::tags,
+            dcmdict::DicomDictionary,
+            tag::Tag,
+            ts::TSRef,
+            vl::ValueLength,
+            vr::{self, VRRef, VR},
+        },
+        read::{ParseError, ParseResult},
+        values::RawValue,
+        write::transcode::transcode_element,
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, transfer_syntaxes::ExplicitVRLittleEndian},
+};
+
+/// The group of the File Meta elements, which are always encoded in Explicit VR Little Endian.
+const FILE_META_GROUP: u32 = 0x0002;
+
+/// Errors that can occur creating a dataset from its representation.
+#[derive(Error, Debug)]
+pub enum ReprError {
+    #[error("unknown transfer syntax: {0}")]
+    UnknownTransferSyntax(String),
+
+    #[error("unknown character set: {0}")]
+    UnknownCharacterSet(String),
+
+    #[error("unknown vr {vr} of element {tag}")]
+    UnknownVR { tag: String, vr: String },
+
+    #[error(transparent)]
+    ParseError(#[from] ParseError),
+}
+
+/// A dataset, see the module documentation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatasetRepr {
+    /// The UID of the transfer syntax the dataset is encoded in.
+    pub ts: String,
+    /// The name of the character set of the dataset's text, e.g. `windows-1252`.
+    pub cs: String,
+    pub elements: Vec<ElementRepr>,
+}
+
+/// An element along with the contents of sequences and items.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ElementRepr {
+    /// The tag as eight hexadecimal digits, e.g. `00100010`.
+    #[serde(with = "hex_tag")]
+    pub tag: u32,
+    /// The VR, e.g. `PN`.
+    pub vr: String,
+    /// The value, or `None` if the value is empty.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<RawValue>,
+    /// Whether the element is a sequence or item with undefined length.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub undefined_length: bool,
+    /// The elements within an item.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub elements: Vec<ElementRepr>,
+    /// The items of a sequence, or the fragments of encapsulated pixel data.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub items: Vec<ElementRepr>,
+}
+
+impl TryFrom<&DicomRoot<'_>> for DatasetRepr {
+    type Error = ParseError;
+
+    /// Creates the representation of the dataset. Fails if a value hasn't been read, see
+    /// `DicomRoot::resolve_deferred()`.
+    fn try_from(dcmroot: &DicomRoot<'_>) -> ParseResult<Self> {
+        Ok(DatasetRepr {
+            ts: dcmroot.ts().uid().uid().to_owned(),
+            cs: dcmroot.cs().name().to_owned(),
+            elements: children_repr(dcmroot.as_obj())?,
+        })
+    }
+}
+
+impl TryFrom<&DicomObject<'_>> for ElementRepr {
+    type Error = ParseError;
+
+    fn try_from(obj: &DicomObject<'_>) -> ParseResult<Self> {
+        let element: &DicomElement<'_> = obj.element();
+        let value: Option<RawValue> = if element.is_empty() && element.bulk_data().is_none() {
+            None
+        } else {
+            Some(value_repr(element)?)
+        };
+        Ok(ElementRepr {
+            tag: element.tag(),
+            vr: element.vr().ident.to_owned(),
+            value,
+            undefined_length: element.vl() == ValueLength::UndefinedLength,
+            elements: children_repr(obj)?,
+            items: obj
+                .iter_items()
+                .map(ElementRepr::try_from)
+                .collect::<ParseResult<Vec<ElementRepr>>>()?,
+        })
+    }
+}
+
+impl DatasetRepr {
+    /// Creates the dataset this represents, using the given dictionary. The value lengths of
+    /// sequences and items are computed from their contents.
+    pub fn to_dcmroot<'d>(
+        &self,
+        dictionary: &'d dyn DicomDictionary,
+    ) -> Result<DicomRoot<'d>, ReprError> {
+        let ts: TSRef = dictionary
+            .get_ts_by_uid(&self.ts)
+            .ok_or_else(|| ReprError::UnknownTransferSyntax(self.ts.clone()))?;
+        let cs: CSRef = Encoding::for_label(self.cs.as_bytes())
+            .map(CSRef::of)
+            .ok_or_else(|| ReprError::UnknownCharacterSet(self.cs.clone()))?;
+        let target = Target { ts, cs };
+        let child_nodes: BTreeMap<u32, DicomObject<'static>> =
+            target.children(&self.elements, &[])?;
+        let mut dcmroot: DicomRoot<'d> =
+            DicomRoot::new(ts, cs, dictionary, child_nodes, Vec::new());
+        dcmroot.update_value_lengths();
+        Ok(dcmroot)
+    }
+}
+
+impl Serialize for DicomRoot<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        DatasetRepr::try_from(self)
+            .map_err(ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DicomRoot<'static> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        DatasetRepr::deserialize(deserializer)?
+            .to_dcmroot(&STANDARD_DICOM_DICTIONARY)
+            .map_err(de::Error::custom)
+    }
+}
+
+/// The encoding of the dataset being created from its representation.
+struct Target {
+    ts: TSRef,
+    cs: CSRef,
+}
+
+impl Target {
+    fn children(
+        &self,
+        elements: &[ElementRepr],
+        sq_path: &[SequenceElement],
+    ) -> Result<BTreeMap<u32, DicomObject<'static>>, ReprError> {
+        elements
+            .iter()
+            .map(|element| {
+                let obj: DicomObject<'static> = self.object(element, sq_path)?;
+                Ok((element.tag, obj))
+            })
+            .collect()
+    }
+
+    fn object(
+        &self,
+        repr: &ElementRepr,
+        sq_path: &[SequenceElement],
+    ) -> Result<DicomObject<'static>, ReprError> {
+        let vr: VRRef = <[u8; 2]>::try_from(repr.vr.as_bytes())
+            .ok()
+            .and_then(|code| VR::from_code(u16::from_be_bytes(code)))
+            .ok_or_else(|| ReprError::UnknownVR {
+                tag: Tag::format_tag_to_display(repr.tag),
+                vr: repr.vr.clone(),
+            })?;
+        let vl: ValueLength = if repr.undefined_length {
+            ValueLength::UndefinedLength
+        } else {
+            ValueLength::Explicit(0)
+        };
+        let ts: TSRef = if repr.tag >> 16 == FILE_META_GROUP {
+            &ExplicitVRLittleEndian
+        } else {
+            self.ts
+        };
+        let mut element: DicomElement<'static> = DicomElement::new(
+            repr.tag,
+            vr,
+            vl,
+            ts,
+            vr.get_proper_cs(self.cs),
+            Vec::new(),
+            sq_path.to_vec(),
+        );
+        if let Some(value) = &repr.value {
+            element.encode_value(value.clone(), Some(vl))?;
+        }
+        // Items, delimiters, and the contents of non-standard sequences are implicit VR.
+        let element: DicomElement<'static> = transcode_element(&element, self.ts);
+
+        let mut item_path: Vec<SequenceElement> = sq_path.to_vec();
+        item_path.push(SequenceElement::new(repr.tag, None, vr, vl, self.cs));
+        let mut child_nodes: BTreeMap<u32, DicomObject<'static>> =
+            self.children(&repr.elements, &item_path)?;
+        let items: Vec<DicomObject<'static>> = repr
+            .items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let mut seq_path: Vec<SequenceElement> = sq_path.to_vec();
+                let mut seq: SequenceElement =
+                    SequenceElement::new(repr.tag, None, vr, vl, self.cs);
+                seq.set_item(index + 1);
+                seq_path.push(seq);
+                self.object(item, &seq_path)
+            })
+            .collect::<Result<Vec<DicomObject<'static>>, ReprError>>()?;
+
+        if repr.undefined_length {
+            let delimiter: u32 = if repr.tag == tags::ITEM {
+                tags::ITEM_DELIMITATION_ITEM
+            } else {
+                tags::SEQUENCE_DELIMITATION_ITEM
+            };
+            let delimiter: DicomElement<'static> = transcode_element(
+                &DicomElement::new(
+                    delimiter,
+                    &vr::UN,
+                    ValueLength::Explicit(0),
+                    ts,
+                    self.cs,
+                    Vec::new(),
+                    item_path,
+                ),
+                self.ts,
+            );
+            child_nodes.insert(delimiter.tag(), DicomObject::new(delimiter));
+        }
+        Ok(DicomObject::new_with_children(element, child_nodes, items))
+    }
+}
+
+/// The representations of the object's child elements, omitting delimiters.
+fn children_repr(obj: &DicomObject<'_>) -> ParseResult<Vec<ElementRepr>> {
+    obj.iter_child_nodes()
+        .filter(|(tag, _)| {
+            **tag != tags::ITEM_DELIMITATION_ITEM && **tag != tags::SEQUENCE_DELIMITATION_ITEM
+        })
+        .map(|(_, child)| ElementRepr::try_from(child))
+        .collect()
+}
+
+/// Parses the element's value, keeping values of `DS` and `IS` as strings and falling back to
+/// bytes for values which can't be parsed.
+fn value_repr(element: &DicomElement<'_>) -> ParseResult<RawValue> {
+    if element.is_deferred() {
+        // Fails with the details of the element.
+        element.parse_value()?;
+    }
+    let value: ParseResult<RawValue> = if element.vr() == &vr::DS || element.vr() == &vr::IS {
+        Vec::<String>::try_from(element).map(RawValue::Strings)
+    } else {
+        element.parse_value()
+    };
+    Ok(value.unwrap_or_else(|_| RawValue::Bytes(element.data().to_vec())))
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+/// Serializes tags as eight hexadecimal digits.
+mod hex_tag {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(tag: &u32, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{tag:08X}"))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+        let tag: String = String::deserialize(deserializer)?;
+        u32::from_str_radix(&tag, 16).map_err(|_| de::Error::custom(format!("invalid tag: {tag}")))
+    }
+}
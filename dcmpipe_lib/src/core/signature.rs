@@ -0,0 +1,656 @@
+//! Verification of Digital Signatures, see Part 15, Annex C and Part 3, Ch C.12.1.1.3.
+//!
+//! A signature is recorded as an item of the Digital Signatures Sequence, which signs a selection
+//! of the elements in the same dataset or sequence item as the sequence itself. The MAC Parameters
+//! Sequence item with the same MAC ID Number lists the signed elements and how the MAC is computed.
+//! The MAC is a hash of the signed elements encoded in the MAC Calculation Transfer Syntax, in
+//! ascending order, followed by the elements of the MAC Parameters item and the Digital Signature
+//! UID and DateTime of the signature. Within the signed elements:
+//!
+//! - Sequences and items are encoded without their value length, and are always followed by their
+//!   delimiter.
+//! - Fragments of encapsulated Pixel Data are encoded with their value length, as items within a
+//!   sequence.
+//!
+//! The Signature is the MAC signed with the private key of the certificate in Certificate of
+//! Signer, using RSASSA-PKCS1-v1_5 as in the Base RSA Digital Signature Profile. Only X.509
+//! certificates with RSA keys are supported. The certificate's validity and chain of trust are not
+//! checked, though its details are made available.
+
+use std::time::SystemTime;
+
+use rsa::{pkcs8::DecodePublicKey, Pkcs1v15Sign, RsaPublicKey};
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use thiserror::Error;
+use x509_cert::{
+    der::{Decode, Encode, SliceReader},
+    Certificate,
+};
+
+use crate::core::{
+    dcmelement::DicomElement,
+    dcmobject::{DicomObject, DicomRoot},
+    defn::{
+        constants::tags,
+        tag::{Tag, TagNode, TagPath},
+        ts::TSRef,
+        vl::ValueLength,
+        vr,
+    },
+    read::ParseError,
+    values::Attribute,
+    write::{
+        error::WriteError,
+        transcode::{transcode_element, transcoded_vr_ts},
+    },
+};
+
+/// MAC ID Number (0400,0005), US.
+const MAC_ID_NUMBER: u32 = 0x0400_0005;
+/// MAC Calculation Transfer Syntax UID (0400,0010), UI.
+const MAC_CALCULATION_TRANSFER_SYNTAX_UID: u32 = 0x0400_0010;
+/// MAC Algorithm (0400,0015), CS.
+const MAC_ALGORITHM: u32 = 0x0400_0015;
+/// Data Elements Signed (0400,0020), AT.
+const DATA_ELEMENTS_SIGNED: u32 = 0x0400_0020;
+/// Digital Signature UID (0400,0100), UI.
+const DIGITAL_SIGNATURE_UID: u32 = 0x0400_0100;
+/// Digital Signature DateTime (0400,0105), DT.
+const DIGITAL_SIGNATURE_DATETIME: u32 = 0x0400_0105;
+/// Certificate Type (0400,0110), CS.
+const CERTIFICATE_TYPE: u32 = 0x0400_0110;
+/// Certificate of Signer (0400,0115), OB.
+const CERTIFICATE_OF_SIGNER: u32 = 0x0400_0115;
+/// Signature (0400,0120), OB.
+const SIGNATURE: u32 = 0x0400_0120;
+/// MAC Parameters Sequence (4FFE,0001), SQ.
+const MAC_PARAMETERS_SEQUENCE: u32 = 0x4FFE_0001;
+/// Digital Signatures Sequence (FFFA,FFFA), SQ.
+const DIGITAL_SIGNATURES_SEQUENCE: u32 = 0xFFFA_FFFA;
+
+/// The Certificate Type of X.509 certificates.
+const X509_CERTIFICATE_TYPE: &str = "X509_1993_SIG";
+
+pub type SignatureResult<T> = core::result::Result<T, SignatureError>;
+
+#[derive(Error, Debug)]
+/// Errors that can occur reading the Digital Signatures of a dataset.
+pub enum SignatureError {
+    /// An element required to read or verify a signature is missing.
+    #[error("signature is missing required element {}", Tag::format_tag_to_display(*.0))]
+    MissingElement(u32),
+
+    /// No MAC Parameters Sequence item has the MAC ID Number referenced by a signature.
+    #[error("no MAC parameters found for MAC ID Number {0}")]
+    MissingMacParameters(u16),
+
+    #[error("failed decoding signature element value")]
+    DecodeValueError(#[from] ParseError),
+
+    #[error("failed encoding signed elements")]
+    EncodeValueError(#[from] WriteError),
+}
+
+/// The outcome of verifying a signature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureStatus {
+    /// The signature matches the MAC of the signed elements.
+    Valid,
+    /// The signature does not match the MAC of the signed elements, which have been modified since
+    /// signing, or the signature was not created with the certificate's key.
+    Invalid,
+    /// The signature can't be verified, e.g. due to an unsupported algorithm or certificate, with
+    /// the reason why.
+    Unverifiable(String),
+}
+
+/// The hash algorithms which may be used to compute a MAC, see Part 15, Ch C.1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MacAlgorithm {
+    Sha1,
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl MacAlgorithm {
+    /// Parses the value of MAC Algorithm, returning `None` for algorithms which aren't supported,
+    /// i.e. `RIPEMD160` and `MD5`.
+    pub fn parse(value: &str) -> Option<MacAlgorithm> {
+        match value.trim() {
+            "SHA1" => Some(MacAlgorithm::Sha1),
+            "SHA256" => Some(MacAlgorithm::Sha256),
+            "SHA384" => Some(MacAlgorithm::Sha384),
+            "SHA512" => Some(MacAlgorithm::Sha512),
+            _ => None,
+        }
+    }
+
+    fn digest(&self, bytes: &[u8]) -> Vec<u8> {
+        match self {
+            MacAlgorithm::Sha1 => Sha1::digest(bytes).to_vec(),
+            MacAlgorithm::Sha256 => Sha256::digest(bytes).to_vec(),
+            MacAlgorithm::Sha384 => Sha384::digest(bytes).to_vec(),
+            MacAlgorithm::Sha512 => Sha512::digest(bytes).to_vec(),
+        }
+    }
+
+    fn pkcs1v15(&self) -> Pkcs1v15Sign {
+        match self {
+            MacAlgorithm::Sha1 => Pkcs1v15Sign::new::<Sha1>(),
+            MacAlgorithm::Sha256 => Pkcs1v15Sign::new::<Sha256>(),
+            MacAlgorithm::Sha384 => Pkcs1v15Sign::new::<Sha384>(),
+            MacAlgorithm::Sha512 => Pkcs1v15Sign::new::<Sha512>(),
+        }
+    }
+}
+
+/// The details of an X.509 certificate from Certificate of Signer.
+#[derive(Debug, Clone)]
+pub struct SignerCertificate {
+    subject: String,
+    issuer: String,
+    serial_number: String,
+    not_before: SystemTime,
+    not_after: SystemTime,
+    public_key: Option<RsaPublicKey>,
+}
+
+impl SignerCertificate {
+    /// Parses a DER-encoded X.509 certificate. Trailing bytes are ignored, as the value of
+    /// Certificate of Signer is padded to an even length.
+    pub fn parse(der: &[u8]) -> Option<SignerCertificate> {
+        let cert: Certificate = Certificate::decode(&mut SliceReader::new(der).ok()?).ok()?;
+        let tbs = &cert.tbs_certificate;
+        let public_key: Option<RsaPublicKey> = tbs
+            .subject_public_key_info
+            .to_der()
+            .ok()
+            .and_then(|spki| RsaPublicKey::from_public_key_der(&spki).ok());
+        Some(SignerCertificate {
+            subject: tbs.subject.to_string(),
+            issuer: tbs.issuer.to_string(),
+            serial_number: tbs
+                .serial_number
+                .as_bytes()
+                .iter()
+                .map(|byte| format!("{byte:02X}"))
+                .collect(),
+            not_before: tbs.validity.not_before.to_system_time(),
+            not_after: tbs.validity.not_after.to_system_time(),
+            public_key,
+        })
+    }
+
+    /// The subject's distinguished name, as in RFC 4514.
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    /// The issuer's distinguished name, as in RFC 4514.
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    /// The serial number, as uppercase hex.
+    pub fn serial_number(&self) -> &str {
+        &self.serial_number
+    }
+
+    pub fn not_before(&self) -> SystemTime {
+        self.not_before
+    }
+
+    pub fn not_after(&self) -> SystemTime {
+        self.not_after
+    }
+
+    /// Whether the certificate has an RSA public key, which is required for verification.
+    pub fn is_rsa(&self) -> bool {
+        self.public_key.is_some()
+    }
+}
+
+/// A signature read from an item of a Digital Signatures Sequence, along with the MAC Parameters it
+/// references.
+#[derive(Debug, Clone)]
+pub struct DigitalSignature {
+    path: TagPath,
+    item: usize,
+    mac_id: u16,
+    uid: String,
+    datetime: String,
+    mac_ts_uid: String,
+    mac_algorithm: String,
+    data_elements_signed: Vec<u32>,
+    certificate_type: String,
+    certificate: Option<SignerCertificate>,
+    signature: Vec<u8>,
+}
+
+impl DigitalSignature {
+    /// The path to the sequence item containing the Digital Signatures Sequence, which is empty
+    /// for signatures of the top-level dataset.
+    pub fn path(&self) -> &TagPath {
+        &self.path
+    }
+
+    /// The 1-based index of the signature's item within the Digital Signatures Sequence.
+    pub fn item(&self) -> usize {
+        self.item
+    }
+
+    pub fn mac_id(&self) -> u16 {
+        self.mac_id
+    }
+
+    pub fn uid(&self) -> &str {
+        &self.uid
+    }
+
+    pub fn datetime(&self) -> &str {
+        &self.datetime
+    }
+
+    pub fn mac_ts_uid(&self) -> &str {
+        &self.mac_ts_uid
+    }
+
+    pub fn mac_algorithm(&self) -> &str {
+        &self.mac_algorithm
+    }
+
+    /// The tags of the elements covered by the signature, in ascending order.
+    pub fn data_elements_signed(&self) -> &[u32] {
+        &self.data_elements_signed
+    }
+
+    pub fn certificate_type(&self) -> &str {
+        &self.certificate_type
+    }
+
+    /// The parsed Certificate of Signer, if it's an X.509 certificate which could be parsed.
+    pub fn certificate(&self) -> Option<&SignerCertificate> {
+        self.certificate.as_ref()
+    }
+
+    pub fn signature(&self) -> &[u8] {
+        &self.signature
+    }
+
+    /// Computes the MAC of the signed elements in `dcmroot`, which must be the dataset this
+    /// signature was read from. Returns `None` if the MAC Algorithm or MAC Calculation Transfer
+    /// Syntax are not supported. Values deferred while parsing must have been resolved.
+    pub fn compute_mac(&self, dcmroot: &DicomRoot<'_>) -> SignatureResult<Option<Vec<u8>>> {
+        let Some(algorithm) = MacAlgorithm::parse(&self.mac_algorithm) else {
+            return Ok(None);
+        };
+        let Some(mac_ts) = self.mac_ts(dcmroot) else {
+            return Ok(None);
+        };
+        let obj: &DicomObject<'_> = signed_object(dcmroot, &self.path)
+            .ok_or(SignatureError::MissingElement(DIGITAL_SIGNATURES_SEQUENCE))?;
+        let mac_params: &DicomObject<'_> = mac_parameters(obj, self.mac_id)?;
+        let signature_item: &DicomObject<'_> = obj
+            .get_child_by_tag(DIGITAL_SIGNATURES_SEQUENCE)
+            .and_then(|sq| sq.get_item_by_index(self.item))
+            .ok_or(SignatureError::MissingElement(DIGITAL_SIGNATURES_SEQUENCE))?;
+
+        let mut bytes: Vec<u8> = Vec::new();
+        for tag in &self.data_elements_signed {
+            if let Some(child) = obj.get_child_by_tag(*tag) {
+                encode_object(child, mac_ts, &mut bytes)?;
+            }
+        }
+        for tag in [
+            MAC_ID_NUMBER,
+            MAC_CALCULATION_TRANSFER_SYNTAX_UID,
+            MAC_ALGORITHM,
+            DATA_ELEMENTS_SIGNED,
+        ] {
+            let child: &DicomObject<'_> = mac_params
+                .get_child_by_tag(tag)
+                .ok_or(SignatureError::MissingElement(tag))?;
+            encode_object(child, mac_ts, &mut bytes)?;
+        }
+        for tag in [DIGITAL_SIGNATURE_UID, DIGITAL_SIGNATURE_DATETIME] {
+            let child: &DicomObject<'_> = signature_item
+                .get_child_by_tag(tag)
+                .ok_or(SignatureError::MissingElement(tag))?;
+            encode_object(child, mac_ts, &mut bytes)?;
+        }
+        Ok(Some(algorithm.digest(&bytes)))
+    }
+
+    /// Verifies the signature against the signed elements in `dcmroot`, which must be the dataset
+    /// this signature was read from.
+    pub fn verify(&self, dcmroot: &DicomRoot<'_>) -> SignatureResult<SignatureVerification> {
+        let missing_elements: Vec<u32> = signed_object(dcmroot, &self.path)
+            .map(|obj| {
+                self.data_elements_signed
+                    .iter()
+                    .filter(|tag| obj.get_child_by_tag(**tag).is_none())
+                    .copied()
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let status: SignatureStatus = if self.certificate_type.trim() != X509_CERTIFICATE_TYPE {
+            SignatureStatus::Unverifiable(format!(
+                "unsupported Certificate Type: {}",
+                self.certificate_type
+            ))
+        } else if let Some(reason) = self.unverifiable_reason(dcmroot) {
+            SignatureStatus::Unverifiable(reason)
+        } else {
+            match (
+                self.compute_mac(dcmroot)?,
+                self.certificate
+                    .as_ref()
+                    .and_then(|cert| cert.public_key.as_ref()),
+                MacAlgorithm::parse(&self.mac_algorithm),
+            ) {
+                (Some(mac), Some(public_key), Some(algorithm)) => {
+                    match public_key.verify(algorithm.pkcs1v15(), &mac, &self.signature) {
+                        Ok(()) => SignatureStatus::Valid,
+                        Err(_) => SignatureStatus::Invalid,
+                    }
+                }
+                _ => SignatureStatus::Unverifiable("unable to compute MAC".to_owned()),
+            }
+        };
+
+        Ok(SignatureVerification {
+            signature: self.clone(),
+            missing_elements,
+            status,
+        })
+    }
+
+    /// Checks for the reasons this signature can't be verified, prior to computing its MAC.
+    fn unverifiable_reason(&self, dcmroot: &DicomRoot<'_>) -> Option<String> {
+        let Some(certificate) = &self.certificate else {
+            return Some("unable to parse Certificate of Signer".to_owned());
+        };
+        if !certificate.is_rsa() {
+            return Some("Certificate of Signer does not have an RSA key".to_owned());
+        }
+        if MacAlgorithm::parse(&self.mac_algorithm).is_none() {
+            return Some(format!("unsupported MAC Algorithm: {}", self.mac_algorithm));
+        }
+        if self.mac_ts(dcmroot).is_none() {
+            return Some(format!(
+                "unsupported MAC Calculation Transfer Syntax: {}",
+                self.mac_ts_uid
+            ));
+        }
+        None
+    }
+
+    /// Resolves the MAC Calculation Transfer Syntax, which must be neither deflated nor
+    /// encapsulated.
+    fn mac_ts(&self, dcmroot: &DicomRoot<'_>) -> Option<TSRef> {
+        dcmroot
+            .dictionary()
+            .get_ts_by_uid(self.mac_ts_uid.trim_end_matches('\0'))
+            .filter(|ts| !ts.deflated() && !ts.encapsulated())
+    }
+}
+
+/// The result of verifying a signature.
+#[derive(Debug, Clone)]
+pub struct SignatureVerification {
+    signature: DigitalSignature,
+    missing_elements: Vec<u32>,
+    status: SignatureStatus,
+}
+
+impl SignatureVerification {
+    pub fn signature(&self) -> &DigitalSignature {
+        &self.signature
+    }
+
+    /// The signed elements which are no longer present in the dataset.
+    pub fn missing_elements(&self) -> &[u32] {
+        &self.missing_elements
+    }
+
+    pub fn status(&self) -> &SignatureStatus {
+        &self.status
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.status == SignatureStatus::Valid
+    }
+}
+
+/// Reads all Digital Signatures in the dataset, including those within sequence items.
+pub fn read_signatures(dcmroot: &DicomRoot<'_>) -> SignatureResult<Vec<DigitalSignature>> {
+    let mut signatures: Vec<DigitalSignature> = Vec::new();
+    collect_signatures(dcmroot.as_obj(), &TagPath::empty(), &mut signatures)?;
+    Ok(signatures)
+}
+
+/// Reads and verifies all Digital Signatures in the dataset, including those within sequence
+/// items. Values deferred while parsing must have been resolved.
+pub fn verify_signatures(dcmroot: &DicomRoot<'_>) -> SignatureResult<Vec<SignatureVerification>> {
+    read_signatures(dcmroot)?
+        .iter()
+        .map(|signature| signature.verify(dcmroot))
+        .collect()
+}
+
+/// Reads the signatures of `obj`, recursing into its sequences, with `path` being the `TagPath` of
+/// `obj`.
+fn collect_signatures(
+    obj: &DicomObject<'_>,
+    path: &TagPath,
+    signatures: &mut Vec<DigitalSignature>,
+) -> SignatureResult<()> {
+    if let Some(sq) = obj.get_child_by_tag(DIGITAL_SIGNATURES_SEQUENCE) {
+        for (index, item) in sq.iter_items().enumerate() {
+            signatures.push(read_signature(obj, item, path, index + 1)?);
+        }
+    }
+
+    for (tag, child) in obj.iter_child_nodes() {
+        if !child.element().is_seq_like() || *tag == DIGITAL_SIGNATURES_SEQUENCE {
+            continue;
+        }
+        for (index, item) in child.iter_items().enumerate() {
+            let mut nodes: Vec<TagNode> = path.nodes.clone();
+            nodes.push(TagNode::new(*tag, Some(index + 1)));
+            collect_signatures(item, &TagPath::from(nodes), signatures)?;
+        }
+    }
+    Ok(())
+}
+
+/// Reads a single signature from an item of the Digital Signatures Sequence of `obj`.
+fn read_signature(
+    obj: &DicomObject<'_>,
+    item: &DicomObject<'_>,
+    path: &TagPath,
+    index: usize,
+) -> SignatureResult<DigitalSignature> {
+    let mac_id: u16 = u16::try_from(required(item, MAC_ID_NUMBER)?)?;
+    let mac_params: &DicomObject<'_> = mac_parameters(obj, mac_id)?;
+
+    let mut data_elements_signed: Vec<u32> =
+        Vec::<Attribute>::try_from(required(mac_params, DATA_ELEMENTS_SIGNED)?)?
+            .into_iter()
+            .map(|attr| attr.0)
+            .collect();
+    data_elements_signed.sort_unstable();
+    data_elements_signed.dedup();
+
+    let certificate_type: String = optional_string(item, CERTIFICATE_TYPE)?;
+    let certificate: Option<SignerCertificate> = item
+        .get_child_by_tag(CERTIFICATE_OF_SIGNER)
+        .filter(|_| certificate_type.trim() == X509_CERTIFICATE_TYPE)
+        .and_then(|cert| SignerCertificate::parse(cert.element().data()));
+
+    Ok(DigitalSignature {
+        path: path.clone(),
+        item: index,
+        mac_id,
+        uid: String::try_from(required(item, DIGITAL_SIGNATURE_UID)?)?,
+        datetime: optional_string(item, DIGITAL_SIGNATURE_DATETIME)?,
+        mac_ts_uid: String::try_from(required(mac_params, MAC_CALCULATION_TRANSFER_SYNTAX_UID)?)?,
+        mac_algorithm: String::try_from(required(mac_params, MAC_ALGORITHM)?)?,
+        data_elements_signed,
+        certificate_type,
+        certificate,
+        signature: required(item, SIGNATURE)?.data().to_vec(),
+    })
+}
+
+/// Gets the object the Digital Signatures Sequence at `path` is a child of.
+fn signed_object<'o, 'a>(
+    dcmroot: &'o DicomRoot<'a>,
+    path: &TagPath,
+) -> Option<&'o DicomObject<'a>> {
+    if path.is_empty() {
+        Some(dcmroot.as_obj())
+    } else {
+        dcmroot.get_child_by_tagpath(path)
+    }
+}
+
+/// Finds the item of the MAC Parameters Sequence of `obj` with the given MAC ID Number.
+fn mac_parameters<'o, 'a>(
+    obj: &'o DicomObject<'a>,
+    mac_id: u16,
+) -> SignatureResult<&'o DicomObject<'a>> {
+    obj.get_child_by_tag(MAC_PARAMETERS_SEQUENCE)
+        .and_then(|sq| {
+            sq.iter_items().find(|item| {
+                item.get_child_by_tag(MAC_ID_NUMBER)
+                    .and_then(|id| u16::try_from(id.element()).ok())
+                    == Some(mac_id)
+            })
+        })
+        .ok_or(SignatureError::MissingMacParameters(mac_id))
+}
+
+fn required<'o, 'a>(obj: &'o DicomObject<'a>, tag: u32) -> SignatureResult<&'o DicomElement<'a>> {
+    obj.get_child_by_tag(tag)
+        .map(DicomObject::element)
+        .ok_or(SignatureError::MissingElement(tag))
+}
+
+fn optional_string(obj: &DicomObject<'_>, tag: u32) -> SignatureResult<String> {
+    match obj.get_child_by_tag(tag) {
+        Some(child) => Ok(String::try_from(child.element())?),
+        None => Ok(String::new()),
+    }
+}
+
+/// Encodes the object's element and contents into `bytes` for computing a MAC, see the module
+/// documentation.
+fn encode_object(obj: &DicomObject<'_>, mac_ts: TSRef, bytes: &mut Vec<u8>) -> SignatureResult<()> {
+    let element: &DicomElement<'_> = obj.element();
+    if element.is_seq_like() {
+        let (vr, elem_ts) = transcoded_vr_ts(element, mac_ts);
+        encode_tag(element.tag(), elem_ts.big_endian(), bytes);
+        if elem_ts.explicit_vr() {
+            bytes.extend_from_slice(vr.ident.as_bytes());
+            if vr.has_explicit_2byte_pad {
+                bytes.extend_from_slice(&[0u8, 0u8]);
+            }
+        }
+        // Encapsulated Pixel Data is the only sequence-like element whose items are fragments
+        // rather than datasets.
+        let has_fragments: bool = element.vr() != &vr::SQ && element.vr() != &vr::UN;
+        for item in obj.iter_items() {
+            if has_fragments {
+                encode_element(item.element(), mac_ts, bytes)?;
+            } else {
+                encode_tag(tags::ITEM, mac_ts.big_endian(), bytes);
+                for (tag, child) in item.iter_child_nodes() {
+                    if !is_delimiter(*tag) {
+                        encode_object(child, mac_ts, bytes)?;
+                    }
+                }
+                encode_delimiter(tags::ITEM_DELIMITATION_ITEM, mac_ts.big_endian(), bytes);
+            }
+        }
+        encode_delimiter(tags::SEQUENCE_DELIMITATION_ITEM, mac_ts.big_endian(), bytes);
+        Ok(())
+    } else {
+        encode_element(element, mac_ts, bytes)
+    }
+}
+
+/// Encodes a single element with its value into `bytes`, transcoded into `mac_ts`.
+fn encode_element(
+    element: &DicomElement<'_>,
+    mac_ts: TSRef,
+    bytes: &mut Vec<u8>,
+) -> SignatureResult<()> {
+    if element.is_deferred() {
+        return Err(WriteError::DeferredValue.into());
+    }
+    if element.bulk_data().is_some() {
+        return Err(WriteError::BulkDataValue {
+            reason: "bulk data can't be signed",
+        }
+        .into());
+    }
+    let element: DicomElement<'static> = transcode_element(element, mac_ts);
+    let big_endian: bool = element.ts().big_endian();
+    let len: u32 = match element.vl() {
+        ValueLength::Explicit(len) => len,
+        ValueLength::UndefinedLength => element.value_len() as u32,
+    };
+
+    encode_tag(element.tag(), big_endian, bytes);
+    if element.ts().explicit_vr() {
+        bytes.extend_from_slice(element.vr().ident.as_bytes());
+        if element.vr().has_explicit_2byte_pad {
+            bytes.extend_from_slice(&[0u8, 0u8]);
+            encode_u32(len, big_endian, bytes);
+        } else {
+            let len: u16 = (len & 0x0000_FFFF) as u16;
+            if big_endian {
+                bytes.extend_from_slice(&len.to_be_bytes());
+            } else {
+                bytes.extend_from_slice(&len.to_le_bytes());
+            }
+        }
+    } else {
+        encode_u32(len, big_endian, bytes);
+    }
+    bytes.extend_from_slice(element.data());
+    Ok(())
+}
+
+fn encode_tag(tag: u32, big_endian: bool, bytes: &mut Vec<u8>) {
+    let group: u16 = (tag >> 16 & 0x0000_FFFF) as u16;
+    let elem: u16 = (tag & 0x0000_FFFF) as u16;
+    if big_endian {
+        bytes.extend_from_slice(&group.to_be_bytes());
+        bytes.extend_from_slice(&elem.to_be_bytes());
+    } else {
+        bytes.extend_from_slice(&group.to_le_bytes());
+        bytes.extend_from_slice(&elem.to_le_bytes());
+    }
+}
+
+fn encode_delimiter(tag: u32, big_endian: bool, bytes: &mut Vec<u8>) {
+    encode_tag(tag, big_endian, bytes);
+    encode_u32(0, big_endian, bytes);
+}
+
+fn encode_u32(value: u32, big_endian: bool, bytes: &mut Vec<u8>) {
+    if big_endian {
+        bytes.extend_from_slice(&value.to_be_bytes());
+    } else {
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+fn is_delimiter(tag: u32) -> bool {
+    tag == tags::ITEM_DELIMITATION_ITEM || tag == tags::SEQUENCE_DELIMITATION_ITEM
+}
@@ -0,0 +1,177 @@
+//! Accounting of the bytes a dataset occupies, to diagnose why a dataset is unexpectedly large,
+//! see `DicomRoot::size_report()`.
+//!
+//! Bytes are counted as the dataset is encoded, split between the headers of elements (their tag,
+//! VR, and value length) and their values. The headers of items and of the delimiters of
+//! sequences and items are counted along with the sequence they're within.
+
+use std::collections::BTreeMap;
+
+use crate::core::{
+    dcmobject::{DicomObject, DicomRoot},
+    defn::{
+        constants::tags,
+        tag::{TagNode, TagPath},
+        vr::VRRef,
+    },
+    write::transcode::encoded_header_len,
+};
+
+/// The bytes occupied by the elements of a dataset, by group and by element.
+#[derive(Clone, Debug, Default)]
+pub struct SizeReport {
+    /// The top-level groups of the dataset, in order of group number. The size of a group includes
+    /// the contents of its sequences, whatever their group.
+    pub groups: Vec<GroupSize>,
+    /// Every element of the dataset, including those within sequences, in the order they're
+    /// encoded.
+    pub elements: Vec<ElementSize>,
+}
+
+/// The bytes occupied by the elements of a top-level group and their contents.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GroupSize {
+    pub group: u16,
+    /// The number of elements of the group, including those within its sequences.
+    pub elements: usize,
+    pub header_len: u64,
+    pub value_len: u64,
+    /// The deepest nesting of sequences within the group, zero if it has no sequences.
+    pub max_depth: usize,
+}
+
+/// The bytes occupied by an element.
+#[derive(Clone, Debug)]
+pub struct ElementSize {
+    pub tagpath: TagPath,
+    pub vr: VRRef,
+    /// The length of the element's header, along with its delimiter if it has one.
+    pub header_len: u64,
+    /// The length of the element's own value, excluding any items.
+    pub value_len: u64,
+    /// The length of the element's items and their contents, headers included, and delimiters.
+    pub contents_len: u64,
+    /// The number of sequences the element is within.
+    pub depth: usize,
+}
+
+impl SizeReport {
+    pub(crate) fn new(dcmroot: &DicomRoot<'_>) -> SizeReport {
+        let mut report: SizeReport = SizeReport::default();
+        let mut groups: BTreeMap<u16, GroupSize> = BTreeMap::new();
+        for (tag, child) in dcmroot.iter_child_nodes() {
+            let first: usize = report.elements.len();
+            let (header_len, value_len) =
+                report.visit(child, TagPath::from(vec![TagNode::from(*tag)]), 0);
+            let group_number: u16 = (*tag >> 16) as u16;
+            let group: &mut GroupSize = groups.entry(group_number).or_insert(GroupSize {
+                group: group_number,
+                ..GroupSize::default()
+            });
+            group.header_len += header_len;
+            group.value_len += value_len;
+            group.elements += report.elements.len() - first;
+            group.max_depth = report.elements[first..]
+                .iter()
+                .map(|element| element.depth)
+                .fold(group.max_depth, usize::max);
+        }
+        report.groups = groups.into_values().collect();
+        report
+    }
+
+    /// The total length of the dataset's elements, which excludes the File Preamble and `DICM`
+    /// prefix.
+    pub fn total_len(&self) -> u64 {
+        self.header_len() + self.value_len()
+    }
+
+    /// The total length of the headers of the dataset's elements, items, and delimiters.
+    pub fn header_len(&self) -> u64 {
+        self.groups.iter().map(|group| group.header_len).sum()
+    }
+
+    /// The total length of the values of the dataset's elements.
+    pub fn value_len(&self) -> u64 {
+        self.groups.iter().map(|group| group.value_len).sum()
+    }
+
+    /// The deepest nesting of sequences within the dataset, zero if it has no sequences.
+    pub fn max_depth(&self) -> usize {
+        self.groups
+            .iter()
+            .map(|group| group.max_depth)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The largest elements along with their contents, largest first, up to the given count. A
+    /// sequence is listed along with the large elements within it.
+    pub fn largest(&self, count: usize) -> Vec<&ElementSize> {
+        let mut largest: Vec<&ElementSize> = self.elements.iter().collect();
+        largest.sort_by_key(|element| std::cmp::Reverse(element.total_len()));
+        largest.truncate(count);
+        largest
+    }
+
+    /// Records the element at `tagpath` and everything within it, returning the total length of
+    /// their headers and of their values.
+    fn visit(&mut self, obj: &DicomObject<'_>, tagpath: TagPath, depth: usize) -> (u64, u64) {
+        let element = obj.element();
+        let delimiters_len: u64 = obj
+            .iter_child_nodes()
+            .filter(|(tag, _)| is_delimiter(**tag))
+            .map(|(_, delimiter)| header_len(delimiter))
+            .sum();
+        let index: usize = self.elements.len();
+        self.elements.push(ElementSize {
+            tagpath: tagpath.clone(),
+            vr: element.vr(),
+            header_len: header_len(obj) + delimiters_len,
+            value_len: element.value_len() as u64,
+            contents_len: 0,
+            depth,
+        });
+
+        let mut total_header_len: u64 = header_len(obj) + delimiters_len;
+        let mut total_value_len: u64 = element.value_len() as u64;
+        for (number, item) in obj.iter_items().enumerate() {
+            let mut item_path: TagPath = tagpath.clone();
+            if let Some(last) = item_path.nodes.last_mut() {
+                *last.item_mut() = Some(number + 1);
+            }
+            // The item's own value holds e.g. a fragment of encapsulated Pixel Data.
+            total_header_len += header_len(item);
+            total_value_len += item.element().value_len() as u64;
+            for (tag, child) in item.iter_child_nodes() {
+                if is_delimiter(*tag) {
+                    total_header_len += header_len(child);
+                    continue;
+                }
+                let mut child_path: TagPath = item_path.clone();
+                child_path.nodes.push(TagNode::from(*tag));
+                let (header_len, value_len) = self.visit(child, child_path, depth + 1);
+                total_header_len += header_len;
+                total_value_len += value_len;
+            }
+        }
+        let own_len: u64 = self.elements[index].header_len + self.elements[index].value_len;
+        self.elements[index].contents_len = total_header_len + total_value_len - own_len;
+        (total_header_len, total_value_len)
+    }
+}
+
+impl ElementSize {
+    /// The length of the element along with its contents.
+    pub fn total_len(&self) -> u64 {
+        self.header_len + self.value_len + self.contents_len
+    }
+}
+
+fn header_len(obj: &DicomObject<'_>) -> u64 {
+    encoded_header_len(obj.element().vr(), obj.element().ts())
+}
+
+fn is_delimiter(tag: u32) -> bool {
+    tag == tags::ITEM_DELIMITATION_ITEM || tag == tags::SEQUENCE_DELIMITATION_ITEM
+}
@@ -1,4 +1,4 @@
-use crate::{core::dcmelement::DicomElement, core::defn::vr::VRRef};
+use crate::core::{bulkdata::BulkDataRef, dcmelement::DicomElement, defn::vr::VRRef};
 
 /// Wrapper around `&[u8]` for getting a slice of the element value without the padding values.
 #[derive(Debug)]
@@ -6,28 +6,52 @@ pub(crate) struct BytesWithoutPadding<'bytes>(pub &'bytes [u8]);
 
 /// For parsing an element value as a string with a specific VR.
 #[derive(Debug)]
-pub struct ElementWithVr<'elem>(pub &'elem DicomElement, pub VRRef);
+pub struct ElementWithVr<'elem>(pub &'elem DicomElement<'elem>, pub VRRef);
 
 /// Wrapper around `u32` for parsing DICOM Attributes.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Attribute(pub u32);
 
 /// Wrapper around an element's value parsed into a native/raw type.
+///
+/// The VRs each variant is parsed from are listed below. When encoding, numeric values are
+/// encoded as text for a character string VR such as IS or DS, otherwise as binary.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RawValue {
+    /// AT
     Attribute(Vec<Attribute>),
+    /// UI
     Uid(String),
+    /// All other character string VRs.
     Strings(Vec<String>),
+    /// SS
     Shorts(Vec<i16>),
+    /// US
     UnsignedShorts(Vec<u16>),
+    /// SL, IS
     Integers(Vec<i32>),
+    /// UL
     UnsignedIntegers(Vec<u32>),
+    /// SV
     Longs(Vec<i64>),
+    /// UV
     UnsignedLongs(Vec<u64>),
+    /// FL, OF
     Floats(Vec<f32>),
+    /// FD, OD, DS
     Doubles(Vec<f64>),
+    /// OB, UN, and empty values of any VR.
     Bytes(Vec<u8>),
+    /// OW
     Words(Vec<u16>),
+    /// OL
     DoubleWords(Vec<u32>),
+    /// OV
     QuadWords(Vec<u64>),
+    /// A value held outside of the dataset, see `bulkdata`. Elements whose value is a bulk data
+    /// reference parse to this regardless of VR, and encoding it makes the element refer to the
+    /// bytes rather than hold them.
+    BulkData(BulkDataRef),
 }
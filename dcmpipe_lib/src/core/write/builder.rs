@@ -1,13 +1,16 @@
-use std::io::Write;
+use std::{io::Write, sync::Arc};
 
 use crate::core::{
+    bulkdata::{BulkDataSource, FileBulkDataSource},
     charset::{CSRef, DEFAULT_CHARACTER_SET},
     defn::{constants::ts, ts::TSRef},
+    padding::PaddingPolicy,
     FILE_PREAMBLE_LENGTH,
 };
 
 use super::{
     ds::dataset::Dataset,
+    grouplength::GroupLengthPolicy,
     writer::{Writer, WriterState},
 };
 
@@ -28,6 +31,19 @@ pub struct WriterBuilder {
 
     /// The dataset will be wrapped in a `BufWriter`, this lets the buffer size be set.
     bufsize: usize,
+
+    /// Opens the bytes of bulk data references. Defaults to `FileBulkDataSource`.
+    bulk_data: Option<Arc<dyn BulkDataSource>>,
+
+    /// Whether elements are written exactly as they were parsed. Defaults to `false`.
+    preserve_encoding: bool,
+
+    /// How values of odd length or which are padded with the wrong byte are written. Defaults to
+    /// `PaddingPolicy::Preserve`.
+    padding: PaddingPolicy,
+
+    /// How Group Length elements are written. Defaults to `GroupLengthPolicy::Preserve`.
+    group_length: GroupLengthPolicy,
 }
 
 impl WriterBuilder {
@@ -62,6 +78,44 @@ impl WriterBuilder {
         self
     }
 
+    /// Sets the source bulk data references are streamed from when writing, which is needed to
+    /// write values referring to URIs.
+    pub fn bulk_data_source<S: BulkDataSource + 'static>(mut self, source: S) -> Self {
+        self.bulk_data = Some(Arc::new(source));
+        self
+    }
+
+    /// Sets whether elements are written with exactly the encoding they were parsed with, so that
+    /// writing an unmodified dataset reproduces it byte-for-byte. This writes values of odd length
+    /// without padding and writes a given FileMetaInformationGroupLength element as-is rather than
+    /// computing it. The file preamble should be set to the one read by the parser, see
+    /// `Parser::file_preamble()`.
+    ///
+    /// Parsed elements retain their value length, including whether it's undefined, so this
+    /// requires no option when parsing. Datasets which can't be reproduced include those which are
+    /// deflated, have elements out of tag order, or have non-zero reserved bytes following an
+    /// explicit VR.
+    pub fn preserve_encoding(mut self, preserve_encoding: bool) -> Self {
+        self.preserve_encoding = preserve_encoding;
+        self
+    }
+
+    /// Sets how values of odd length, values padded with the wrong byte, and UIDs followed by
+    /// several NULLs are written, see `PaddingPolicy`. Normalized values are written with their
+    /// new value length even when preserving the original encoding.
+    pub fn padding(mut self, padding: PaddingPolicy) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets how the retired Group Length elements of datasets written with
+    /// `Writer::write_dcmroot()` are handled, see `GroupLengthPolicy`. The
+    /// FileMetaInformationGroupLength element is unaffected.
+    pub fn group_length(mut self, group_length: GroupLengthPolicy) -> Self {
+        self.group_length = group_length;
+        self
+    }
+
     /// Constructs a `Writer` from this builder.
     pub fn build<DatasetType: Write>(&self, dataset: DatasetType) -> Writer<DatasetType> {
         Writer {
@@ -71,6 +125,14 @@ impl WriterBuilder {
             ts: self.ts.unwrap_or(&ts::ExplicitVRLittleEndian),
             cs: self.cs.unwrap_or(DEFAULT_CHARACTER_SET),
             file_preamble: self.file_preamble,
+            bulk_data: self
+                .bulk_data
+                .clone()
+                .unwrap_or_else(|| Arc::new(FileBulkDataSource)),
+            preserve_encoding: self.preserve_encoding,
+            padding: self.padding,
+            group_length: self.group_length,
+            chunked: None,
         }
     }
 }
@@ -84,6 +146,10 @@ impl Default for WriterBuilder {
             file_preamble: None,
             // BufWriter's current default buffer size is 8k.
             bufsize: 8 * 1024,
+            bulk_data: None,
+            preserve_encoding: false,
+            padding: PaddingPolicy::Preserve,
+            group_length: GroupLengthPolicy::Preserve,
         }
     }
 }
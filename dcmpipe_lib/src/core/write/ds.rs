@@ -9,6 +9,10 @@ pub(crate) mod dataset {
     pub(crate) struct Dataset<DatasetType: Write> {
         encoder: Encoder<BufWriter<DatasetType>>,
         write_deflated: bool,
+        /// Whether any bytes have been written through the encoder. The deflate stream is only
+        /// finished if it was started, otherwise an empty final block would be appended to the
+        /// dataset.
+        deflate_started: bool,
     }
 
     impl<DatasetType: Write> Dataset<DatasetType> {
@@ -16,6 +20,7 @@ pub(crate) mod dataset {
             Dataset {
                 encoder: Encoder::new(BufWriter::with_capacity(buffsize, dataset)),
                 write_deflated: false,
+                deflate_started: false,
             }
         }
 
@@ -24,16 +29,19 @@ pub(crate) mod dataset {
         }
 
         pub fn into_inner(self) -> Result<DatasetType> {
-            self.encoder
-                .into_inner()
-                .into_inner()
-                .map_err(|err| err.into())
+            let bufwriter: BufWriter<DatasetType> = if self.deflate_started {
+                self.encoder.finish().into_result()?
+            } else {
+                self.encoder.into_inner()
+            };
+            bufwriter.into_inner().map_err(|err| err.into())
         }
     }
 
     impl<DatasetType: Write> Write for Dataset<DatasetType> {
         fn write(&mut self, buf: &[u8]) -> Result<usize> {
             if self.write_deflated {
+                self.deflate_started = true;
                 self.encoder.write(buf)
             } else {
                 self.encoder.as_inner_mut().write(buf)
@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-use crate::core::read::ParseError;
+use crate::core::{padding::PaddingIssue, read::ParseError};
 
 #[derive(Error, Debug)]
 /// Errors that can occur during writing of a DICOM dataset.
@@ -11,6 +11,42 @@ pub enum WriteError {
     #[error("value length of undefined cannot be used with implicit VR")]
     InvalidValueLength,
 
+    /// A value isn't padded as the standard requires, and the writer is configured with
+    /// `PaddingPolicy::Error`.
+    #[error("{issue}\n\ttagpath: {tagstring}")]
+    InvalidPadding {
+        tagstring: String,
+        issue: PaddingIssue,
+    },
+
+    /// The element's value was deferred during parsing and has not been resolved.
+    #[error("element value was deferred and not resolved")]
+    DeferredValue,
+
+    /// The bytes of a bulk data reference couldn't be read while writing.
+    #[error("failed reading bulk data: {reference}")]
+    BulkDataError {
+        reference: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The element's value is a bulk data reference, whose bytes are only read when written.
+    #[error("element value is a bulk data reference: {reason}")]
+    BulkDataValue { reason: &'static str },
+
+    /// A value written in chunks was begun, written, or ended out of order, or its chunks didn't
+    /// add up to its length.
+    #[error("invalid chunked value: {reason}")]
+    ChunkedValue { reason: &'static str },
+
+    /// Transcoding between the transfer syntaxes would require encoding or decoding Pixel Data.
+    #[error("transcoding from {from} to {to} is not supported")]
+    UnsupportedTranscode {
+        from: &'static str,
+        to: &'static str,
+    },
+
     /// Wrapper around `std::io::Error`.
     #[error("i/o error writing to stream")]
     IOError {
@@ -0,0 +1,161 @@
+//! Handling of Group Length elements when writing, see `GroupLengthPolicy`.
+//!
+//! Group Length elements, `(gggg,0000)`, are retired other than `FileMetaInformationGroupLength`,
+//! which the `Writer` always generates. See Part 5, Ch 7.2. Datasets may include them for some
+//! groups but not others, or with values which no longer agree with the elements of their group.
+
+use std::collections::BTreeMap;
+
+use crate::core::{
+    dcmelement::DicomElement,
+    dcmobject::{DicomObject, DicomRoot},
+    defn::{constants::tags, tag::Tag, ts::TSRef, vl::ValueLength, vr},
+    values::RawValue,
+    write::{error::WriteError, writer::WriteResult},
+};
+
+/// How the `Writer` handles Group Length elements, other than `FileMetaInformationGroupLength`,
+/// of datasets written with `Writer::write_dcmroot()`. Elements written by other means are written
+/// as given.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GroupLengthPolicy {
+    /// Write Group Length elements unchanged.
+    #[default]
+    Preserve,
+
+    /// Omit Group Length elements. The value lengths of sequences and items of explicit length
+    /// are reduced by the length of those omitted from within them.
+    Strip,
+
+    /// Write Group Length elements with values computed from the elements of their group, as
+    /// written. Groups without a Group Length element aren't given one.
+    Recompute,
+}
+
+/// An element of the flattened dataset, as written.
+enum Written<'e, 'a> {
+    Unchanged(&'e DicomElement<'a>),
+    Omitted,
+    /// An index into the replaced elements.
+    Replaced(usize),
+}
+
+/// Flattens the dataset as with `DicomRoot::flatten()`, applying the policy to its Group Length
+/// elements. Elements which are changed are kept in `replaced`, to which the returned elements may
+/// refer.
+pub(crate) fn flatten<'e, 'a>(
+    dcmroot: &'e DicomRoot<'a>,
+    policy: GroupLengthPolicy,
+    replaced: &'e mut Vec<DicomElement<'static>>,
+) -> WriteResult<Vec<&'e DicomElement<'a>>> {
+    let elements: Vec<&'e DicomElement<'a>> = dcmroot.flatten()?;
+    if policy == GroupLengthPolicy::Preserve {
+        return Ok(elements);
+    }
+
+    let mut flattener: Flattener<'e, 'a> = Flattener {
+        policy,
+        ts: dcmroot.ts(),
+        written: Vec::with_capacity(elements.len()),
+        replaced: Vec::new(),
+    };
+    flattener.visit_contents(dcmroot.sentinel())?;
+    *replaced = flattener.replaced;
+    let replaced: &'e Vec<DicomElement<'static>> = replaced;
+    Ok(flattener
+        .written
+        .into_iter()
+        .filter_map(|written| match written {
+            Written::Unchanged(element) => Some(element),
+            Written::Omitted => None,
+            Written::Replaced(index) => Some(&replaced[index]),
+        })
+        .collect())
+}
+
+struct Flattener<'e, 'a> {
+    policy: GroupLengthPolicy,
+    ts: TSRef,
+    written: Vec<Written<'e, 'a>>,
+    replaced: Vec<DicomElement<'static>>,
+}
+
+impl<'e, 'a> Flattener<'e, 'a> {
+    /// Visits the items and children of the object in the order `DicomObject::flatten()` lists
+    /// them, returning the number of bytes omitted from within the object.
+    fn visit_contents(&mut self, obj: &'e DicomObject<'a>) -> WriteResult<u64> {
+        let mut omitted_len: u64 = 0;
+        for item in obj.iter_items() {
+            omitted_len += self.visit(item, 0)?;
+        }
+        let group_lengths: BTreeMap<u16, u64> = if self.policy == GroupLengthPolicy::Recompute {
+            self.group_lengths(obj)
+        } else {
+            BTreeMap::new()
+        };
+        for (tag, child) in obj.iter_child_nodes() {
+            let group_length: Option<u64> = group_lengths.get(&((*tag >> 16) as u16)).copied();
+            omitted_len += self.visit(child, group_length.unwrap_or(0))?;
+        }
+        Ok(omitted_len)
+    }
+
+    /// Visits the object and its contents, returning the number of bytes omitted.
+    fn visit(&mut self, obj: &'e DicomObject<'a>, group_length: u64) -> WriteResult<u64> {
+        let element: &'e DicomElement<'a> = obj.element();
+        if is_retired_group_length(element.tag()) {
+            match self.policy {
+                GroupLengthPolicy::Strip => {
+                    self.written.push(Written::Omitted);
+                    return Ok(obj.encoded_len(self.ts));
+                }
+                _ => {
+                    let mut recomputed: DicomElement<'static> =
+                        DicomElement::new_empty(element.tag(), &vr::UL, element.ts());
+                    recomputed
+                        .encode_value(RawValue::UnsignedIntegers(vec![group_length as u32]), None)
+                        .map_err(WriteError::from)?;
+                    self.written.push(Written::Replaced(self.replaced.len()));
+                    self.replaced.push(recomputed);
+                }
+            }
+            return Ok(0);
+        }
+
+        let index: usize = self.written.len();
+        self.written.push(Written::Unchanged(element));
+        let omitted_len: u64 = self.visit_contents(obj)?;
+        if let (true, ValueLength::Explicit(length)) = (omitted_len > 0, element.vl()) {
+            // The contents of sequences and items follow them, their data is always empty.
+            let shortened: DicomElement<'static> = DicomElement::new(
+                element.tag(),
+                element.vr(),
+                ValueLength::Explicit(length - omitted_len as u32),
+                element.ts(),
+                element.cs(),
+                Vec::new(),
+                element.sequence_path().clone(),
+            );
+            self.written[index] = Written::Replaced(self.replaced.len());
+            self.replaced.push(shortened);
+        }
+        Ok(omitted_len)
+    }
+
+    /// The length of each group of the object's children, other than its Group Length element.
+    fn group_lengths(&self, obj: &DicomObject<'_>) -> BTreeMap<u16, u64> {
+        let mut group_lengths: BTreeMap<u16, u64> = BTreeMap::new();
+        for (tag, child) in obj.iter_child_nodes() {
+            if !Tag::is_group_length(*tag) {
+                *group_lengths.entry((*tag >> 16) as u16).or_default() +=
+                    child.encoded_len(self.ts);
+            }
+        }
+        group_lengths
+    }
+}
+
+/// Whether the tag is of a Group Length element other than `FileMetaInformationGroupLength`.
+fn is_retired_group_length(tag: u32) -> bool {
+    Tag::is_group_length(tag) && tag != tags::FILE_META_INFORMATION_GROUP_LENGTH
+}
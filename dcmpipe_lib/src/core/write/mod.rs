@@ -4,4 +4,6 @@ pub(crate) mod valencode;
 
 pub mod builder;
 pub mod error;
+pub mod grouplength;
+pub mod transcode;
 pub mod writer;
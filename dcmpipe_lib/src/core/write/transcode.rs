@@ -0,0 +1,186 @@
+//! Re-encoding a dataset into a different transfer syntax.
+//!
+//! Only the encoding of elements is changed, between implicit/explicit VR and little/big endian.
+//! Deflate is applied by the `Writer` when writing with a deflated transfer syntax. Pixel Data is
+//! not decoded or encoded, so transcoding to or from an encapsulated (compressed) transfer syntax
+//! is not supported.
+
+use crate::core::{
+    dcmelement::DicomElement,
+    dcmobject::DicomRoot,
+    defn::{
+        constants::{tags, ts},
+        tag::Tag,
+        ts::TSRef,
+        vl::ValueLength,
+        vr::{self, VRRef},
+    },
+    read::util::is_non_standard_seq,
+    values::RawValue,
+};
+
+use super::{error::WriteError, writer::WriteResult};
+
+/// Re-encodes the elements of `dcmroot` into the transfer syntax `to`, returning the flattened
+/// elements which can be written with a `Writer` configured with the same transfer syntax. The
+/// `TransferSyntaxUID` of the File Meta group, if present, is updated to `to`.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip(dcmroot), fields(from = dcmroot.ts().uid().name(), to = to.uid().name()))
+)]
+pub fn transcode(dcmroot: &DicomRoot<'_>, to: TSRef) -> WriteResult<Vec<DicomElement<'static>>> {
+    let from: TSRef = dcmroot.ts();
+    if from.uid() != to.uid() && (from.encapsulated() || to.encapsulated()) {
+        return Err(WriteError::UnsupportedTranscode {
+            from: from.uid().ident(),
+            to: to.uid().ident(),
+        });
+    }
+
+    let mut elements: Vec<DicomElement> = Vec::new();
+    for element in dcmroot.flatten()? {
+        if element.tag() == tags::TRANSFER_SYNTAX_UID {
+            let mut ts_elem: DicomElement =
+                DicomElement::new_empty(tags::TRANSFER_SYNTAX_UID, element.vr(), element.ts());
+            ts_elem.encode_value(RawValue::Uid(to.uid().uid().to_owned()), None)?;
+            elements.push(ts_elem);
+        } else {
+            check_transcodable(element, to)?;
+            elements.push(transcode_element(element, to));
+        }
+    }
+    Ok(elements)
+}
+
+/// Checks that the value of the element can be re-encoded into the transfer syntax `to`. Values
+/// deferred while parsing have no bytes to re-encode until resolved, and bulk data can't be
+/// byte-swapped.
+pub(crate) fn check_transcodable(element: &DicomElement, to: TSRef) -> WriteResult<()> {
+    if element.is_deferred() {
+        return Err(WriteError::DeferredValue);
+    }
+    if element.bulk_data().is_some()
+        && element.ts().big_endian() != transcoded_vr_ts(element, to).1.big_endian()
+        && swap_size(element.vr()).is_some()
+    {
+        return Err(WriteError::BulkDataValue {
+            reason: "bulk data can't be byte-swapped",
+        });
+    }
+    Ok(())
+}
+
+/// Re-encodes a single element into the transfer syntax `to`, which should first be checked with
+/// `check_transcodable()`.
+pub(crate) fn transcode_element(element: &DicomElement, to: TSRef) -> DicomElement<'static> {
+    let (vr, elem_ts) = transcoded_vr_ts(element, to);
+    let mut data: Vec<u8> = element.data().to_vec();
+    if element.ts().big_endian() != elem_ts.big_endian() {
+        swap_bytes(&mut data, element.vr());
+    }
+
+    let vl: ValueLength = match element.vl() {
+        ValueLength::Explicit(_) => ValueLength::Explicit(data.len() as u32),
+        ValueLength::UndefinedLength => ValueLength::UndefinedLength,
+    };
+
+    let mut transcoded: DicomElement<'static> = DicomElement::new(
+        element.tag(),
+        vr,
+        vl,
+        elem_ts,
+        element.cs(),
+        data,
+        element.sequence_path().clone(),
+    );
+    // Bulk data is referenced as-is, as `check_transcodable()` rejects bulk data which needs swapping.
+    if let Some(bulk_data) = element.bulk_data() {
+        transcoded.set_bulk_data(bulk_data.clone());
+    }
+    transcoded
+}
+
+/// Determines the VR and transfer syntax an element is encoded with when transcoded into `to`.
+pub(crate) fn transcoded_vr_ts(element: &DicomElement, to: TSRef) -> (VRRef, TSRef) {
+    let elem_ts: TSRef = element_ts(element, to);
+
+    // Private creators aren't in the dictionary so are read as UN from implicit VR, but are always
+    // LO, see Part 5, Ch 7.8.1. Explicit VR with a 16-bit value length can't hold larger values,
+    // which may be present when the element was originally encoded with implicit VR. Part 5,
+    // Ch 6.2.2 permits UN here.
+    let vr: VRRef = if elem_ts.explicit_vr()
+        && element.vr() == &vr::UN
+        && Tag::is_private_creator(element.tag())
+    {
+        &vr::LO
+    } else if elem_ts.explicit_vr()
+        && !element.vr().has_explicit_2byte_pad
+        && element.value_len() > u16::MAX as usize
+    {
+        &vr::UN
+    } else {
+        element.vr()
+    };
+    (vr, elem_ts)
+}
+
+/// The number of bytes of an element's tag, VR, and value length when encoded with the given VR
+/// and transfer syntax. That is the tag and a u32 value length for implicit VR, otherwise the VR
+/// followed by either a u16 value length, or two reserved bytes and a u32 value length. See
+/// Part 5, Ch 7.1.
+pub(crate) fn encoded_header_len(vr: VRRef, ts: TSRef) -> u64 {
+    if !ts.explicit_vr() || !vr.has_explicit_2byte_pad {
+        8
+    } else {
+        12
+    }
+}
+
+/// Determines the transfer syntax an element should be encoded with. The File Meta group is always
+/// Explicit VR Little Endian, while item and delimiter elements, and the contents of non-standard
+/// sequences, are always implicit VR. See Part 5, Ch 7.5 and Ch 6.2.2.
+fn element_ts(element: &DicomElement, to: TSRef) -> TSRef {
+    if element.tag() <= tags::FILE_META_GROUP_END {
+        return element.ts();
+    }
+
+    let is_implicit: bool = element.tag() == tags::ITEM
+        || element.tag() == tags::ITEM_DELIMITATION_ITEM
+        || element.tag() == tags::SEQUENCE_DELIMITATION_ITEM
+        || element
+            .sequence_path()
+            .iter()
+            .any(|sq| is_non_standard_seq(sq.seq_tag(), sq.vr(), sq.vl()));
+    if !is_implicit {
+        to
+    } else if to.big_endian() {
+        &ts::ImplicitVRBigEndian
+    } else {
+        &ts::ImplicitVRLittleEndian
+    }
+}
+
+/// Swaps the byte order of each value in `data`, based on the size of values for the VR.
+pub(crate) fn swap_bytes(data: &mut [u8], vr: VRRef) {
+    let Some(size) = swap_size(vr) else {
+        return;
+    };
+
+    for value in data.chunks_exact_mut(size) {
+        value.reverse();
+    }
+}
+
+/// The size of each value whose byte order depends on the transfer syntax, for the VR. Values of
+/// other VRs are encoded as bytes or text.
+fn swap_size(vr: VRRef) -> Option<usize> {
+    if vr == &vr::AT || vr == &vr::OW || vr == &vr::SS || vr == &vr::US {
+        Some(2)
+    } else if vr == &vr::FL || vr == &vr::OF || vr == &vr::OL || vr == &vr::SL || vr == &vr::UL {
+        Some(4)
+    } else if vr == &vr::FD || vr == &vr::OD || vr == &vr::OV || vr == &vr::SV || vr == &vr::UV {
+        Some(8)
+    } else {
+        None
+    }
+}
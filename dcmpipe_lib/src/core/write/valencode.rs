@@ -5,14 +5,17 @@ use std::{iter::once, mem::size_of};
 
 use crate::core::{
     dcmelement::DicomElement,
-    defn::vr::{CS_SEPARATOR, CS_SEPARATOR_BYTE},
+    defn::{
+        tag::TagPath,
+        vr::{self, CS_SEPARATOR, CS_SEPARATOR_BYTE},
+    },
     read::{ParseError, ParseResult},
     values::{Attribute, RawValue},
 };
 
 /// Encodes a RawValue into the binary data for the given element, based on the element's currently
 /// set Value Representation, Character Set, and Transfer Syntax.
-pub struct ElemAndRawValue<'a>(pub &'a DicomElement, pub RawValue);
+pub struct ElemAndRawValue<'a>(pub &'a DicomElement<'a>, pub RawValue);
 impl<'a> TryFrom<ElemAndRawValue<'a>> for Vec<u8> {
     type Error = ParseError;
 
@@ -36,6 +39,12 @@ impl<'a> TryFrom<ElemAndRawValue<'a>> for Vec<u8> {
             RawValue::Words(words) => ElemAndWords(elem, words).into(),
             RawValue::DoubleWords(dwords) => ElemAndDoubleWords(elem, dwords).into(),
             RawValue::QuadWords(qwords) => ElemAndQuadWords(elem, qwords).into(),
+            RawValue::BulkData(_) => {
+                return Err(encode_error(
+                    elem,
+                    "bulk data references are not encoded as bytes",
+                ))
+            }
         };
 
         // All fields are required to be of even length, with padding added as necessary. Note
@@ -48,7 +57,7 @@ impl<'a> TryFrom<ElemAndRawValue<'a>> for Vec<u8> {
         // length. If padding is required to make the Value Field of even length, a single padding
         // character shall be applied to the end of the Value Field (to the last Value), in which
         // case the length of the last Value may exceed the Length of Value by 1.
-        if bytes.len() % 2 != 0 {
+        if !bytes.len().is_multiple_of(2) {
             bytes.push(elem.vr().padding);
         }
 
@@ -56,7 +65,7 @@ impl<'a> TryFrom<ElemAndRawValue<'a>> for Vec<u8> {
     }
 }
 
-struct ElemAndAttributes<'a>(&'a DicomElement, Vec<Attribute>);
+struct ElemAndAttributes<'a>(&'a DicomElement<'a>, Vec<Attribute>);
 impl<'a> From<ElemAndAttributes<'a>> for Vec<u8> {
     fn from(value: ElemAndAttributes<'a>) -> Self {
         let elem = value.0;
@@ -82,7 +91,7 @@ impl<'a> From<ElemAndAttributes<'a>> for Vec<u8> {
     }
 }
 
-struct ElemAndUid<'a>(&'a DicomElement, String);
+struct ElemAndUid<'a>(&'a DicomElement<'a>, String);
 impl<'a> TryFrom<ElemAndUid<'a>> for Vec<u8> {
     type Error = ParseError;
 
@@ -95,7 +104,7 @@ impl<'a> TryFrom<ElemAndUid<'a>> for Vec<u8> {
     }
 }
 
-struct ElemAndStrings<'a>(&'a DicomElement, Vec<String>);
+struct ElemAndStrings<'a>(&'a DicomElement<'a>, Vec<String>);
 impl<'a> TryFrom<ElemAndStrings<'a>> for Vec<u8> {
     type Error = ParseError;
 
@@ -103,6 +112,24 @@ impl<'a> TryFrom<ElemAndStrings<'a>> for Vec<u8> {
         let elem = value.0;
         let strings = value.1;
 
+        // Encoding multiple values, or a value containing the separator, would result in a value
+        // that parses differently than what was given.
+        if elem.vr().is_single_valued_text() {
+            if strings.len() > 1 {
+                return Err(encode_error(elem, "vr does not allow multiple values"));
+            }
+        } else if strings.iter().any(|s| s.contains(CS_SEPARATOR)) {
+            return Err(encode_error(
+                elem,
+                "value contains a backslash, which separates multiple values",
+            ));
+        }
+        if elem.vr() == &vr::UR {
+            if let Some(uri) = strings.first() {
+                validate_uri(elem, uri)?;
+            }
+        }
+
         type MaybeBytes = Vec<ParseResult<Vec<u8>>>;
         let (values, errs): (MaybeBytes, MaybeBytes) = strings
             .iter()
@@ -134,7 +161,44 @@ impl<'a> TryFrom<ElemAndStrings<'a>> for Vec<u8> {
     }
 }
 
-struct ElemAndShorts<'a>(&'a DicomElement, Vec<i16>);
+/// Validates that the value is made of only the characters permitted in a URI, see RFC3986
+/// Section 2, with any `%` beginning a percent-encoded octet.
+fn validate_uri(elem: &DicomElement, uri: &str) -> ParseResult<()> {
+    if uri.starts_with(' ') {
+        return Err(encode_error(elem, "URI has leading spaces"));
+    }
+    let uri: &str = uri.trim_end_matches(' ');
+    let bytes: &[u8] = uri.as_bytes();
+    for (i, byte) in bytes.iter().enumerate() {
+        let is_valid: bool = match byte {
+            b'%' => {
+                bytes.len() > i + 2
+                    && bytes[i + 1].is_ascii_hexdigit()
+                    && bytes[i + 2].is_ascii_hexdigit()
+            }
+            b if b.is_ascii_alphanumeric() => true,
+            // Unreserved, general delimiter, and sub-delimiter characters.
+            b => b"-._~:/?#[]@!$&'()*+,;=".contains(b),
+        };
+        if !is_valid {
+            return Err(encode_error(
+                elem,
+                &format!("URI has invalid character at index {i}: {uri}"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn encode_error(elem: &DicomElement, message: &str) -> ParseError {
+    ParseError::EncodeValueError {
+        message: message.to_owned(),
+        tagstring: TagPath::format_tagpath_to_display(&elem.create_tagpath(), None),
+        vr: elem.vr(),
+    }
+}
+
+struct ElemAndShorts<'a>(&'a DicomElement<'a>, Vec<i16>);
 impl<'a> From<ElemAndShorts<'a>> for Vec<u8> {
     fn from(value: ElemAndShorts<'a>) -> Self {
         let elem = value.0;
@@ -167,7 +231,7 @@ impl<'a> From<ElemAndShorts<'a>> for Vec<u8> {
     }
 }
 
-struct ElemAndUnsignedShorts<'a>(&'a DicomElement, Vec<u16>);
+struct ElemAndUnsignedShorts<'a>(&'a DicomElement<'a>, Vec<u16>);
 impl<'a> From<ElemAndUnsignedShorts<'a>> for Vec<u8> {
     fn from(value: ElemAndUnsignedShorts<'a>) -> Self {
         let elem = value.0;
@@ -200,7 +264,7 @@ impl<'a> From<ElemAndUnsignedShorts<'a>> for Vec<u8> {
     }
 }
 
-struct ElemAndIntegers<'a>(&'a DicomElement, Vec<i32>);
+struct ElemAndIntegers<'a>(&'a DicomElement<'a>, Vec<i32>);
 impl<'a> From<ElemAndIntegers<'a>> for Vec<u8> {
     fn from(value: ElemAndIntegers<'a>) -> Self {
         let elem = value.0;
@@ -232,7 +296,7 @@ impl<'a> From<ElemAndIntegers<'a>> for Vec<u8> {
     }
 }
 
-struct ElemAndUnsignedIntegers<'a>(&'a DicomElement, Vec<u32>);
+struct ElemAndUnsignedIntegers<'a>(&'a DicomElement<'a>, Vec<u32>);
 impl<'a> From<ElemAndUnsignedIntegers<'a>> for Vec<u8> {
     fn from(value: ElemAndUnsignedIntegers<'a>) -> Self {
         let elem = value.0;
@@ -266,7 +330,7 @@ impl<'a> From<ElemAndUnsignedIntegers<'a>> for Vec<u8> {
     }
 }
 
-struct ElemAndLongs<'a>(&'a DicomElement, Vec<i64>);
+struct ElemAndLongs<'a>(&'a DicomElement<'a>, Vec<i64>);
 impl<'a> From<ElemAndLongs<'a>> for Vec<u8> {
     fn from(value: ElemAndLongs<'a>) -> Self {
         let elem = value.0;
@@ -284,7 +348,7 @@ impl<'a> From<ElemAndLongs<'a>> for Vec<u8> {
             encoded.pop();
             encoded
         } else {
-            // This should only be the case for a VR of SL.
+            // This should only be the case for a VR of SV.
             longs
                 .into_iter()
                 .flat_map(|long: i64| {
@@ -299,7 +363,7 @@ impl<'a> From<ElemAndLongs<'a>> for Vec<u8> {
     }
 }
 
-struct ElemAndUnsignedLongs<'a>(&'a DicomElement, Vec<u64>);
+struct ElemAndUnsignedLongs<'a>(&'a DicomElement<'a>, Vec<u64>);
 impl<'a> From<ElemAndUnsignedLongs<'a>> for Vec<u8> {
     fn from(value: ElemAndUnsignedLongs<'a>) -> Self {
         let elem = value.0;
@@ -318,7 +382,7 @@ impl<'a> From<ElemAndUnsignedLongs<'a>> for Vec<u8> {
             encoded.pop();
             encoded
         } else {
-            // This should only be the case for a VR of UL.
+            // This should only be the case for a VR of UV.
             ulongs
                 .into_iter()
                 .flat_map(|ulong: u64| {
@@ -333,7 +397,7 @@ impl<'a> From<ElemAndUnsignedLongs<'a>> for Vec<u8> {
     }
 }
 
-struct ElemAndFloats<'a>(&'a DicomElement, Vec<f32>);
+struct ElemAndFloats<'a>(&'a DicomElement<'a>, Vec<f32>);
 impl<'a> From<ElemAndFloats<'a>> for Vec<u8> {
     fn from(value: ElemAndFloats<'a>) -> Self {
         let elem = value.0;
@@ -359,10 +423,10 @@ impl<'a> From<ElemAndFloats<'a>> for Vec<u8> {
             encoded.pop();
             encoded
         } else {
-            // This should only be the case for a VR of FL.
+            // This should only be the case for a VR of FL or OF. Unlike DS, binary values can
+            // represent NaN and infinities, which are kept so values round-trip losslessly.
             floats
                 .into_iter()
-                .filter(|float: &f32| float.is_finite())
                 .flat_map(|float: f32| {
                     if elem.ts().big_endian() {
                         float.to_be_bytes()
@@ -375,7 +439,7 @@ impl<'a> From<ElemAndFloats<'a>> for Vec<u8> {
     }
 }
 
-struct ElemAndDoubles<'a>(&'a DicomElement, Vec<f64>);
+struct ElemAndDoubles<'a>(&'a DicomElement<'a>, Vec<f64>);
 impl<'a> From<ElemAndDoubles<'a>> for Vec<u8> {
     fn from(value: ElemAndDoubles<'a>) -> Self {
         let elem = value.0;
@@ -401,10 +465,10 @@ impl<'a> From<ElemAndDoubles<'a>> for Vec<u8> {
             encoded.pop();
             encoded
         } else {
-            // This should only be the case for a VR of FL.
+            // This should only be the case for a VR of FD or OD. Unlike DS, binary values can
+            // represent NaN and infinities, which are kept so values round-trip losslessly.
             doubles
                 .into_iter()
-                .filter(|double: &f64| double.is_finite())
                 .flat_map(|double: f64| {
                     if elem.ts().big_endian() {
                         double.to_be_bytes()
@@ -417,7 +481,7 @@ impl<'a> From<ElemAndDoubles<'a>> for Vec<u8> {
     }
 }
 
-struct ElemAndWords<'a>(&'a DicomElement, Vec<u16>);
+struct ElemAndWords<'a>(&'a DicomElement<'a>, Vec<u16>);
 impl<'a> From<ElemAndWords<'a>> for Vec<u8> {
     fn from(value: ElemAndWords<'a>) -> Self {
         let elem = value.0;
@@ -436,7 +500,7 @@ impl<'a> From<ElemAndWords<'a>> for Vec<u8> {
     }
 }
 
-struct ElemAndDoubleWords<'a>(&'a DicomElement, Vec<u32>);
+struct ElemAndDoubleWords<'a>(&'a DicomElement<'a>, Vec<u32>);
 impl<'a> From<ElemAndDoubleWords<'a>> for Vec<u8> {
     fn from(value: ElemAndDoubleWords<'a>) -> Self {
         let elem = value.0;
@@ -455,7 +519,7 @@ impl<'a> From<ElemAndDoubleWords<'a>> for Vec<u8> {
     }
 }
 
-struct ElemAndQuadWords<'a>(&'a DicomElement, Vec<u64>);
+struct ElemAndQuadWords<'a>(&'a DicomElement<'a>, Vec<u64>);
 impl<'a> From<ElemAndQuadWords<'a>> for Vec<u8> {
     fn from(value: ElemAndQuadWords<'a>) -> Self {
         let elem = value.0;
@@ -1,18 +1,28 @@
-use std::io::Write;
+use std::{
+    io::{self, Read, Write},
+    sync::Arc,
+};
 
 use crate::core::{
+    bulkdata::{BulkDataRef, BulkDataSource},
     charset::CSRef,
     dcmelement::DicomElement,
     dcmobject::DicomRoot,
     defn::{
         constants::{tags, ts},
+        tag::TagPath,
         ts::TSRef,
         vl::{ValueLength, UNDEFINED_LENGTH},
         vr::{self, VRRef},
     },
+    padding::{self, PaddingPolicy},
     read::ParseError,
     values::RawValue,
-    write::{ds::dataset::Dataset, error::WriteError},
+    write::{
+        ds::dataset::Dataset,
+        error::WriteError,
+        grouplength::{self, GroupLengthPolicy},
+    },
     DICOM_PREFIX, FILE_PREAMBLE_LENGTH,
 };
 
@@ -26,6 +36,17 @@ pub enum WriterState {
     Element,
 }
 
+/// The progress of a value being written in chunks, see `Writer::begin_value()`.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub(crate) enum ChunkedValue {
+    /// A value of explicit length, with the number of bytes yet to be written. Whether it's a
+    /// fragment of a value of undefined length is recorded so the value can be resumed once the
+    /// fragment ends.
+    Explicit { remaining: u64, fragment: bool },
+    /// A value of undefined length, written as fragments.
+    Fragments,
+}
+
 #[derive(Debug)]
 pub struct Writer<DatasetType: Write> {
     pub(crate) dataset: Dataset<DatasetType>,
@@ -42,6 +63,21 @@ pub struct Writer<DatasetType: Write> {
     /// If a file preamble is specified then the `"DICM"` prefix will be written immediately
     /// after the file preamble is written.
     pub(crate) file_preamble: Option<[u8; FILE_PREAMBLE_LENGTH]>,
+
+    /// Opens the bytes of elements whose value is a bulk data reference.
+    pub(crate) bulk_data: Arc<dyn BulkDataSource>,
+
+    /// Whether elements are written with exactly the encoding they were parsed with.
+    pub(crate) preserve_encoding: bool,
+
+    /// How values of odd length or which are padded with the wrong byte are written.
+    pub(crate) padding: PaddingPolicy,
+
+    /// How Group Length elements are written by `write_dcmroot()`.
+    pub(crate) group_length: GroupLengthPolicy,
+
+    /// The value being written in chunks, if any.
+    pub(crate) chunked: Option<ChunkedValue>,
 }
 
 impl<DatasetType: Write> Writer<DatasetType> {
@@ -66,7 +102,12 @@ impl<DatasetType: Write> Writer<DatasetType> {
     }
 
     /// Creates a new `DicomElement` with the given value encoded with the given VR.
-    pub fn create_element<T>(&self, tag: T, vr: VRRef, value: RawValue) -> WriteResult<DicomElement>
+    pub fn create_element<T>(
+        &self,
+        tag: T,
+        vr: VRRef,
+        value: RawValue,
+    ) -> WriteResult<DicomElement<'static>>
     where
         T: Into<u32>,
     {
@@ -83,42 +124,41 @@ impl<DatasetType: Write> Writer<DatasetType> {
     }
 
     /// Flattens the given `DicomRoot` elements into a stream of `DicomElement` and writes the
-    /// resulting elements into the dataset.
+    /// resulting elements into the dataset, with its Group Length elements handled according to
+    /// the `GroupLengthPolicy`.
     pub fn write_dcmroot(&mut self, dcmroot: &DicomRoot) -> WriteResult<usize> {
-        let elements = dcmroot.flatten()?;
+        let mut replaced: Vec<DicomElement<'static>> = Vec::new();
+        let elements = grouplength::flatten(dcmroot, self.group_length, &mut replaced)?;
         self.write_elements(elements.into_iter())
     }
 
     /// Write the iterator of `DicomElement` to the dataset. If the `WriteState` is set to any
     /// valid state for file media, this will handle appropriate encoding for file meta group.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub fn write_elements<'a, E>(&mut self, elements: E) -> WriteResult<usize>
     where
-        E: Iterator<Item = &'a DicomElement>,
+        E: Iterator<Item = &'a DicomElement<'a>>,
     {
-        let mut bytes_written: usize = 0;
-
-        if self.state == WriterState::Preamble {
-            if let Some(preamble) = self.file_preamble {
-                bytes_written += self.dataset.write(&preamble)?;
-            }
-            bytes_written += self.dataset.write(DICOM_PREFIX)?;
-            self.state = WriterState::FileMeta;
-        }
+        let mut bytes_written: usize = self.begin_write()?;
 
         let mut fm_elements: Vec<&DicomElement> = Vec::new();
+        let mut fm_group_length: Option<&DicomElement> = None;
         for element in elements {
             // Collect all the FileMeta elements to write them in one go, as their total byte
             // length is needed for the first element, FileMetaInformationGroupLength.
             if self.state == WriterState::FileMeta {
                 if element.tag() <= tags::FILE_META_GROUP_END {
-                    // Ignore FileMetaInformationGroupLength in place of one made below.
+                    // Ignore FileMetaInformationGroupLength in place of one made below, unless
+                    // preserving the original.
                     if element.tag() != tags::FILE_META_INFORMATION_GROUP_LENGTH {
                         fm_elements.push(element);
+                    } else if self.preserve_encoding {
+                        fm_group_length = Some(element);
                     }
                     continue;
                 }
 
-                bytes_written += self.write_fm_elements(fm_elements.as_slice())?;
+                bytes_written += self.write_fm_elements(fm_group_length, fm_elements.as_slice())?;
                 // The list of FileMeta elements are no longer needed.
                 fm_elements.clear();
 
@@ -126,41 +166,309 @@ impl<DatasetType: Write> Writer<DatasetType> {
                 // getting to this state the `element` value is non-FileMeta and hasn't been
                 // written out yet.
                 self.state = WriterState::Element;
+                self.begin_dataset_body();
             }
 
-            bytes_written += Writer::write_element(&mut self.dataset, element)?;
+            bytes_written += Writer::write_element(
+                &mut self.dataset,
+                &*self.bulk_data,
+                self.preserve_encoding,
+                self.padding,
+                element,
+            )?;
         }
 
         // If the input elements only consist of FileMeta elements then the above loop will never
         // result in writing any elements as they're being collected into `fm_elements`.
         if self.state == WriterState::FileMeta && !fm_elements.is_empty() {
-            bytes_written += self.write_fm_elements(fm_elements.as_slice())?;
+            bytes_written += self.write_fm_elements(fm_group_length, fm_elements.as_slice())?;
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(bytes_written, ts = self.ts().uid().name(), "wrote elements");
+        Ok(bytes_written)
+    }
+
+    /// Writes elements as they're produced, e.g. by a transcoder or while receiving a dataset,
+    /// rather than once the whole dataset is in memory. The elements may be given by a
+    /// `std::sync::mpsc::Receiver`, in which case this returns once all senders are dropped.
+    ///
+    /// As with `write_elements()` the FileMeta elements are collected to write them in one go,
+    /// while every other element is written and flushed to the dataset as it arrives. Deflated
+    /// datasets aren't flushed, as each flush would end a block of the deflate stream, and are
+    /// only complete once finished by `into_dataset()`. Elements are as flattened from a
+    /// `DicomRoot`, in order, with the items and delimiters of sequences as elements of their own.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn write_stream<'e, E>(&mut self, elements: E) -> WriteResult<usize>
+    where
+        E: IntoIterator<Item = DicomElement<'e>>,
+    {
+        let mut bytes_written: usize = self.begin_write()?;
+
+        let mut fm_elements: Vec<DicomElement<'e>> = Vec::new();
+        let mut fm_group_length: Option<DicomElement<'e>> = None;
+        for element in elements {
+            if self.state == WriterState::FileMeta {
+                if element.tag() <= tags::FILE_META_GROUP_END {
+                    if element.tag() != tags::FILE_META_INFORMATION_GROUP_LENGTH {
+                        fm_elements.push(element);
+                    } else if self.preserve_encoding {
+                        fm_group_length = Some(element);
+                    }
+                    continue;
+                }
+
+                let fm_refs: Vec<&DicomElement> = fm_elements.iter().collect();
+                bytes_written += self.write_fm_elements(fm_group_length.as_ref(), &fm_refs)?;
+                fm_elements.clear();
+                self.state = WriterState::Element;
+                self.begin_dataset_body();
+            }
+
+            bytes_written += Writer::write_element(
+                &mut self.dataset,
+                &*self.bulk_data,
+                self.preserve_encoding,
+                self.padding,
+                &element,
+            )?;
+            if !self.ts.deflated() {
+                self.dataset.flush()?;
+            }
+        }
+
+        if self.state == WriterState::FileMeta && !fm_elements.is_empty() {
+            let fm_refs: Vec<&DicomElement> = fm_elements.iter().collect();
+            bytes_written += self.write_fm_elements(fm_group_length.as_ref(), &fm_refs)?;
+        }
+        if !self.ts.deflated() {
+            self.dataset.flush()?;
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(bytes_written, ts = self.ts().uid().name(), "wrote stream");
+        Ok(bytes_written)
+    }
+
+    /// Writes the header of an element whose value is then written in chunks, with
+    /// `write_value_chunk()`, so that huge values such as the Pixel Data of whole-slide images
+    /// needn't be held in memory.
+    ///
+    /// A value of explicit length, which must be even, is ended with `end_value()` once all its
+    /// bytes are written. A value of undefined length, e.g. encapsulated Pixel Data, is written as
+    /// fragments each begun with `begin_fragment()` and ended with `end_value()`, after which
+    /// another `end_value()` ends the value itself.
+    ///
+    /// The FileMeta group, if any, must be written beforehand with `write_elements()`. Elements
+    /// can't otherwise be written until the value is ended.
+    pub fn begin_value<T>(&mut self, tag: T, vr: VRRef, vl: ValueLength) -> WriteResult<usize>
+    where
+        T: Into<u32>,
+    {
+        let tag: u32 = tag.into();
+        let mut bytes_written: usize = self.begin_write()?;
+        if tag <= tags::FILE_META_GROUP_END {
+            return Err(WriteError::ChunkedValue {
+                reason: "FileMeta elements can't be written in chunks",
+            });
+        }
+        if self.state == WriterState::FileMeta {
+            self.state = WriterState::Element;
+            self.begin_dataset_body();
+        }
+
+        let chunked: ChunkedValue = match vl {
+            ValueLength::Explicit(length) if length % 2 == 1 => {
+                return Err(WriteError::ChunkedValue {
+                    reason: "value length must be even",
+                });
+            }
+            ValueLength::Explicit(length) => ChunkedValue::Explicit {
+                remaining: u64::from(length),
+                fragment: false,
+            },
+            ValueLength::UndefinedLength => ChunkedValue::Fragments,
+        };
+        let header: DicomElement =
+            DicomElement::new(tag, vr, vl, self.ts, self.cs, Vec::new(), Vec::new());
+        bytes_written += Writer::write_header(&mut self.dataset, &header)?;
+        self.chunked = Some(chunked);
+        Ok(bytes_written)
+    }
+
+    /// Begins a fragment of a value of undefined length, of the given length which must be even.
+    pub fn begin_fragment(&mut self, length: u32) -> WriteResult<usize> {
+        if self.chunked != Some(ChunkedValue::Fragments) {
+            return Err(WriteError::ChunkedValue {
+                reason: "fragments are only written within a value of undefined length",
+            });
+        }
+        if length % 2 == 1 {
+            return Err(WriteError::ChunkedValue {
+                reason: "fragment length must be even",
+            });
+        }
+        let item: DicomElement = self.delimiter(tags::ITEM, length);
+        let bytes_written: usize = Writer::write_header(&mut self.dataset, &item)?;
+        self.chunked = Some(ChunkedValue::Explicit {
+            remaining: u64::from(length),
+            fragment: true,
+        });
+        Ok(bytes_written)
+    }
+
+    /// Writes the next bytes of the value, or fragment, begun with `begin_value()` or
+    /// `begin_fragment()`. The chunk must not exceed the bytes remaining of its length.
+    pub fn write_value_chunk(&mut self, chunk: &[u8]) -> WriteResult<usize> {
+        let Some(ChunkedValue::Explicit {
+            remaining,
+            fragment,
+        }) = self.chunked
+        else {
+            return Err(WriteError::ChunkedValue {
+                reason: "no value of explicit length has been begun",
+            });
+        };
+        let remaining: u64 =
+            remaining
+                .checked_sub(chunk.len() as u64)
+                .ok_or(WriteError::ChunkedValue {
+                    reason: "chunk exceeds the length of the value",
+                })?;
+        self.dataset.write_all(chunk)?;
+        self.chunked = Some(ChunkedValue::Explicit {
+            remaining,
+            fragment,
+        });
+        Ok(chunk.len())
+    }
+
+    /// Ends the value or fragment most recently begun. The value of a fragment is then resumed,
+    /// while ending a value of undefined length writes its Sequence Delimitation Item.
+    pub fn end_value(&mut self) -> WriteResult<usize> {
+        match self.chunked {
+            Some(ChunkedValue::Explicit {
+                remaining: 0,
+                fragment,
+            }) => {
+                self.chunked = fragment.then_some(ChunkedValue::Fragments);
+                Ok(0)
+            }
+            Some(ChunkedValue::Explicit { .. }) => Err(WriteError::ChunkedValue {
+                reason: "value ended before all its bytes were written",
+            }),
+            Some(ChunkedValue::Fragments) => {
+                let delimiter: DicomElement = self.delimiter(tags::SEQUENCE_DELIMITATION_ITEM, 0);
+                let bytes_written: usize = Writer::write_header(&mut self.dataset, &delimiter)?;
+                self.chunked = None;
+                Ok(bytes_written)
+            }
+            None => Err(WriteError::ChunkedValue {
+                reason: "no value has been begun",
+            }),
         }
+    }
 
+    /// Creates an item or delimiter, which are always encoded as Implicit VR, see Part 5,
+    /// Section 7.5.
+    fn delimiter(&self, tag: u32, length: u32) -> DicomElement<'static> {
+        let ts: TSRef = if self.ts.big_endian() {
+            &ts::ImplicitVRBigEndian
+        } else {
+            &ts::ImplicitVRLittleEndian
+        };
+        DicomElement::new(
+            tag,
+            &vr::UN,
+            ValueLength::Explicit(length),
+            ts,
+            self.cs,
+            Vec::new(),
+            Vec::new(),
+        )
+    }
+
+    /// Writes the preamble and `"DICM"` prefix if they've yet to be written, returning the number
+    /// of bytes written.
+    fn begin_write(&mut self) -> WriteResult<usize> {
+        if self.chunked.is_some() {
+            return Err(WriteError::ChunkedValue {
+                reason: "a value being written in chunks hasn't been ended",
+            });
+        }
+        let mut bytes_written: usize = 0;
+
+        if self.state == WriterState::Preamble {
+            if let Some(preamble) = self.file_preamble {
+                bytes_written += self.dataset.write(&preamble)?;
+            }
+            bytes_written += self.dataset.write(DICOM_PREFIX)?;
+            self.state = WriterState::FileMeta;
+        }
+
+        if self.state == WriterState::Element {
+            self.begin_dataset_body();
+        }
         Ok(bytes_written)
     }
 
+    /// Prepares the dataset for writing elements following the FileMeta group. For deflated
+    /// transfer syntaxes everything after the FileMeta group is deflated, including the tag, VR,
+    /// and value length of each element, so this is toggled once rather than per element.
+    fn begin_dataset_body(&mut self) {
+        #[cfg(feature = "compress")]
+        {
+            self.dataset.set_write_deflated(self.ts.deflated());
+        }
+    }
+
     /// Writes all the given FileMeta elements to an in-memory buffer, computes the length of the
     /// resulting bytes, and generates a FileMetaInformationGroupLength element, writes it to the
     /// dataset, then writes the in-memory buffer to the dataset as well.
     ///
+    /// `fm_group_length`: The FileMetaInformationGroupLength element to write rather than
+    /// generating one, when preserving the original encoding.
+    ///
     /// `fm_elements`: Slice of `&DicomElement`s which should all be elements with tag numbers in
     /// the range for FileMeta, and SHOULD NOT include a FileMetaInformationGroupLength element.
-    fn write_fm_elements(&mut self, fm_elements: &[&DicomElement]) -> WriteResult<usize> {
+    fn write_fm_elements(
+        &mut self,
+        fm_group_length: Option<&DicomElement>,
+        fm_elements: &[&DicomElement],
+    ) -> WriteResult<usize> {
         let mut bytes_written: usize = 0;
         let mut fm_dataset: Dataset<Vec<u8>> = Dataset::new(Vec::new(), 8 * 1024);
         for fme in fm_elements {
-            Writer::write_element(&mut fm_dataset, fme)?;
+            Writer::write_element(
+                &mut fm_dataset,
+                &*self.bulk_data,
+                self.preserve_encoding,
+                self.padding,
+                fme,
+            )?;
         }
         let fm_bytes: Vec<u8> = fm_dataset.into_inner()?;
 
-        let fm_group_length = Writer::<DatasetType>::new_fme(
-            tags::FILE_META_INFORMATION_GROUP_LENGTH,
-            &vr::UL,
-            RawValue::UnsignedIntegers(vec![fm_bytes.len() as u32]),
+        let generated: DicomElement;
+        let fm_group_length: &DicomElement = match fm_group_length {
+            Some(fm_group_length) => fm_group_length,
+            None => {
+                generated = Writer::<DatasetType>::new_fme(
+                    tags::FILE_META_INFORMATION_GROUP_LENGTH,
+                    &vr::UL,
+                    RawValue::UnsignedIntegers(vec![fm_bytes.len() as u32]),
+                )?;
+                &generated
+            }
+        };
+
+        bytes_written += Writer::write_element(
+            &mut self.dataset,
+            &*self.bulk_data,
+            self.preserve_encoding,
+            self.padding,
+            fm_group_length,
         )?;
-
-        bytes_written += Writer::write_element(&mut self.dataset, &fm_group_length)?;
         // The FileMeta elements have already been encoded, write the resulting bytes to
         // the Writer's dataset.
         bytes_written += self.dataset.write(&fm_bytes)?;
@@ -168,7 +476,7 @@ impl<DatasetType: Write> Writer<DatasetType> {
         Ok(bytes_written)
     }
 
-    fn new_fme(tag: u32, vr: VRRef, value: RawValue) -> WriteResult<DicomElement> {
+    fn new_fme(tag: u32, vr: VRRef, value: RawValue) -> WriteResult<DicomElement<'static>> {
         let mut element = DicomElement::new_empty(tag, vr, &ts::ExplicitVRLittleEndian);
 
         element
@@ -179,6 +487,66 @@ impl<DatasetType: Write> Writer<DatasetType> {
     }
 
     fn write_element(
+        dataset: &mut Dataset<DatasetType>,
+        bulk_data: &dyn BulkDataSource,
+        preserve_encoding: bool,
+        padding: PaddingPolicy,
+        element: &DicomElement,
+    ) -> WriteResult<usize> {
+        let padded: Option<DicomElement> =
+            Writer::<DatasetType>::pad_value(preserve_encoding, padding, element)?;
+        let element: &DicomElement = padded.as_ref().unwrap_or(element);
+        let mut bytes_written: usize = Writer::write_header(dataset, element)?;
+        bytes_written += Writer::write_data(dataset, bulk_data, preserve_encoding, element)?;
+        Ok(bytes_written)
+    }
+
+    /// Checks the padding of the element's value according to the `PaddingPolicy`, returning a copy
+    /// of the element if its value or value length needs to be written differently.
+    ///
+    /// Values of odd length are padded with a NULL when parsed, so unless preserving the original
+    /// encoding they're written with the padded length.
+    fn pad_value(
+        preserve_encoding: bool,
+        padding: PaddingPolicy,
+        element: &DicomElement,
+    ) -> WriteResult<Option<DicomElement<'static>>> {
+        let ValueLength::Explicit(length) = element.vl() else {
+            return Ok(None);
+        };
+        if element.is_deferred() || element.bulk_data().is_some() {
+            return Ok(None);
+        }
+        let data: &[u8] = element.data();
+        let value: &[u8] = &data[..data.len().min(length as usize)];
+        let normalized: Vec<u8> = match (padding, padding::check(element.vr(), value)) {
+            (PaddingPolicy::Preserve, _) | (_, None) => {
+                if preserve_encoding || length % 2 == 0 || data.len() != length as usize + 1 {
+                    return Ok(None);
+                }
+                data.to_vec()
+            }
+            (PaddingPolicy::Normalize, Some(_)) => padding::normalize(element.vr(), value),
+            (PaddingPolicy::Error, Some(issue)) => {
+                return Err(WriteError::InvalidPadding {
+                    tagstring: TagPath::format_tagpath_to_display(&element.create_tagpath(), None),
+                    issue,
+                });
+            }
+        };
+        Ok(Some(DicomElement::new(
+            element.tag(),
+            element.vr(),
+            ValueLength::Explicit(normalized.len() as u32),
+            element.ts(),
+            element.cs(),
+            normalized,
+            element.sequence_path().clone(),
+        )))
+    }
+
+    /// Writes the tag, VR, and value length of the element.
+    fn write_header(
         dataset: &mut Dataset<DatasetType>,
         element: &DicomElement,
     ) -> WriteResult<usize> {
@@ -187,7 +555,6 @@ impl<DatasetType: Write> Writer<DatasetType> {
         bytes_written += Writer::write_tag(dataset, element)?;
         bytes_written += Writer::write_vr(dataset, element)?;
         bytes_written += Writer::write_vl(dataset, element)?;
-        bytes_written += Writer::write_data(dataset, element)?;
 
         Ok(bytes_written)
     }
@@ -273,16 +640,57 @@ impl<DatasetType: Write> Writer<DatasetType> {
 
     fn write_data(
         dataset: &mut Dataset<DatasetType>,
+        bulk_data: &dyn BulkDataSource,
+        preserve_encoding: bool,
         element: &DicomElement,
     ) -> WriteResult<usize> {
-        let mut bytes_written: usize = 0;
+        if element.is_deferred() {
+            return Err(WriteError::DeferredValue);
+        }
+        if let Some(bulk_data_ref) = element.bulk_data() {
+            return Writer::write_bulk_data(dataset, bulk_data, preserve_encoding, bulk_data_ref);
+        }
+        let mut data: &[u8] = element.data();
+        // Values of odd length are padded when parsed, the padding wasn't in the original dataset.
+        if let (true, ValueLength::Explicit(length)) = (preserve_encoding, element.vl()) {
+            if length % 2 == 1 && data.len() == length as usize + 1 {
+                data = &data[..length as usize];
+            }
+        }
+        let bytes_written: usize = dataset.write(data)?;
+        Ok(bytes_written)
+    }
 
-        #[cfg(feature = "compress")]
-        {
-            dataset.set_write_deflated(element.ts().deflated());
+    /// Streams the bytes referenced by `bulk_data_ref` into the dataset, padding them to even
+    /// length unless preserving the original encoding.
+    fn write_bulk_data(
+        dataset: &mut Dataset<DatasetType>,
+        bulk_data: &dyn BulkDataSource,
+        preserve_encoding: bool,
+        bulk_data_ref: &BulkDataRef,
+    ) -> WriteResult<usize> {
+        let length: u64 = u64::from(bulk_data_ref.length());
+        let copied: u64 = bulk_data
+            .open(bulk_data_ref)
+            .and_then(|reader| io::copy(&mut reader.take(length), dataset))
+            .map_err(|source| WriteError::BulkDataError {
+                reference: bulk_data_ref.to_string(),
+                source,
+            })?;
+        if copied < length {
+            return Err(WriteError::BulkDataError {
+                reference: bulk_data_ref.to_string(),
+                source: io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    format!("read {copied} of {length} bytes"),
+                ),
+            });
         }
 
-        bytes_written += dataset.write(element.data().as_slice())?;
+        let mut bytes_written: usize = copied as usize;
+        if length % 2 == 1 && !preserve_encoding {
+            bytes_written += dataset.write(&[0u8])?;
+        }
         Ok(bytes_written)
     }
 }
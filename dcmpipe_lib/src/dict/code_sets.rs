@@ -0,0 +1,181 @@
+//! Commonly used Context Groups from Part 16 of the standard.
+//!
+//! Unlike the other dictionary modules these are not generated from the whole of the standard,
+//! as Part 16 defines thousands of context groups. Only a subset of frequently used groups and
+//! codes is included, which is enough to construct simple Structured Reports and to display the
+//! meaning of common codes.
+
+#![allow(non_upper_case_globals)]
+
+use crate::core::defn::code::{Code, CodeRef, ContextGroup, ContextGroupRef};
+
+/// CID 4 Anatomic Region
+pub static AnatomicRegion: ContextGroup = ContextGroup {
+    cid: 4,
+    name: "Anatomic Region",
+    codes: &[
+        Code { value: "69536005", scheme: "SCT", meaning: "Head" },
+        Code { value: "89546000", scheme: "SCT", meaning: "Skull" },
+        Code { value: "12738006", scheme: "SCT", meaning: "Brain" },
+        Code { value: "45048000", scheme: "SCT", meaning: "Neck" },
+        Code { value: "69748006", scheme: "SCT", meaning: "Thyroid" },
+        Code { value: "51185008", scheme: "SCT", meaning: "Chest" },
+        Code { value: "76752008", scheme: "SCT", meaning: "Breast" },
+        Code { value: "80891009", scheme: "SCT", meaning: "Heart" },
+        Code { value: "39607008", scheme: "SCT", meaning: "Lung" },
+        Code { value: "10200004", scheme: "SCT", meaning: "Liver" },
+        Code { value: "15776009", scheme: "SCT", meaning: "Pancreas" },
+        Code { value: "78961009", scheme: "SCT", meaning: "Spleen" },
+        Code { value: "64033007", scheme: "SCT", meaning: "Kidney" },
+        Code { value: "12921003", scheme: "SCT", meaning: "Pelvis" },
+        Code { value: "89837001", scheme: "SCT", meaning: "Urinary bladder" },
+        Code { value: "41216001", scheme: "SCT", meaning: "Prostate" },
+        Code { value: "122494005", scheme: "SCT", meaning: "Cervical spine" },
+        Code { value: "122495006", scheme: "SCT", meaning: "Thoracic spine" },
+        Code { value: "122496007", scheme: "SCT", meaning: "Lumbar spine" },
+        Code { value: "16982005", scheme: "SCT", meaning: "Shoulder" },
+        Code { value: "85562004", scheme: "SCT", meaning: "Hand" },
+        Code { value: "72696002", scheme: "SCT", meaning: "Knee" },
+        Code { value: "56459004", scheme: "SCT", meaning: "Foot" },
+    ],
+};
+
+/// CID 29 Acquisition Modality
+pub static AcquisitionModality: ContextGroup = ContextGroup {
+    cid: 29,
+    name: "Acquisition Modality",
+    codes: &[
+        Code { value: "CR", scheme: "DCM", meaning: "Computed Radiography" },
+        Code { value: "CT", scheme: "DCM", meaning: "Computed Tomography" },
+        Code { value: "DX", scheme: "DCM", meaning: "Digital Radiography" },
+        Code { value: "MG", scheme: "DCM", meaning: "Mammography" },
+        Code { value: "MR", scheme: "DCM", meaning: "Magnetic Resonance" },
+        Code { value: "NM", scheme: "DCM", meaning: "Nuclear Medicine" },
+        Code { value: "PT", scheme: "DCM", meaning: "Positron emission tomography" },
+        Code { value: "RF", scheme: "DCM", meaning: "Radio Fluoroscopy" },
+        Code { value: "US", scheme: "DCM", meaning: "Ultrasound" },
+        Code { value: "XA", scheme: "DCM", meaning: "X-Ray Angiography" },
+    ],
+};
+
+/// CID 244 Laterality
+pub static Laterality: ContextGroup = ContextGroup {
+    cid: 244,
+    name: "Laterality",
+    codes: &[
+        Code { value: "24028007", scheme: "SCT", meaning: "Right" },
+        Code { value: "7771000", scheme: "SCT", meaning: "Left" },
+        Code { value: "51440002", scheme: "SCT", meaning: "Right and left" },
+        Code { value: "66459002", scheme: "SCT", meaning: "Unilateral" },
+    ],
+};
+
+/// CID 270 Observer Type
+pub static ObserverType: ContextGroup = ContextGroup {
+    cid: 270,
+    name: "Observer Type",
+    codes: &[
+        Code { value: "121006", scheme: "DCM", meaning: "Person" },
+        Code { value: "121007", scheme: "DCM", meaning: "Device" },
+    ],
+};
+
+/// CID 7001 Diagnostic Imaging Report Heading
+pub static DiagnosticImagingReportHeading: ContextGroup = ContextGroup {
+    cid: 7001,
+    name: "Diagnostic Imaging Report Heading",
+    codes: &[
+        Code { value: "121060", scheme: "DCM", meaning: "History" },
+        Code { value: "121062", scheme: "DCM", meaning: "Request" },
+        Code { value: "121064", scheme: "DCM", meaning: "Current Procedure Descriptions" },
+        Code { value: "121066", scheme: "DCM", meaning: "Prior Procedure Descriptions" },
+        Code { value: "121068", scheme: "DCM", meaning: "Previous Findings" },
+        Code { value: "121070", scheme: "DCM", meaning: "Findings" },
+        Code { value: "121072", scheme: "DCM", meaning: "Impressions" },
+        Code { value: "121074", scheme: "DCM", meaning: "Recommendations" },
+        Code { value: "121076", scheme: "DCM", meaning: "Conclusions" },
+        Code { value: "121078", scheme: "DCM", meaning: "Addendum" },
+        Code { value: "121109", scheme: "DCM", meaning: "Indications for Procedure" },
+        Code { value: "121111", scheme: "DCM", meaning: "Summary" },
+    ],
+};
+
+/// CID 7005 Contributing Equipment Purposes of Reference
+pub static ContributingEquipmentPurposeofReference: ContextGroup = ContextGroup {
+    cid: 7005,
+    name: "Contributing Equipment Purposes of Reference",
+    codes: &[
+        Code { value: "109101", scheme: "DCM", meaning: "Acquisition Equipment" },
+        Code { value: "109102", scheme: "DCM", meaning: "Processing Equipment" },
+        Code { value: "109103", scheme: "DCM", meaning: "Modifying Equipment" },
+        Code { value: "109104", scheme: "DCM", meaning: "De-identifying Equipment" },
+        Code { value: "109105", scheme: "DCM", meaning: "Frame Extracting Equipment" },
+        Code { value: "109106", scheme: "DCM", meaning: "Enhanced Multi-frame Conversion Equipment" },
+    ],
+};
+
+/// CID 7460 Units of Linear Measurement
+pub static UnitsofLinearMeasurement: ContextGroup = ContextGroup {
+    cid: 7460,
+    name: "Units of Linear Measurement",
+    codes: &[
+        Code { value: "um", scheme: "UCUM", meaning: "micrometer" },
+        Code { value: "mm", scheme: "UCUM", meaning: "millimeter" },
+        Code { value: "cm", scheme: "UCUM", meaning: "centimeter" },
+        Code { value: "m", scheme: "UCUM", meaning: "meter" },
+    ],
+};
+
+/// CID 7461 Units of Area Measurement
+pub static UnitsofAreaMeasurement: ContextGroup = ContextGroup {
+    cid: 7461,
+    name: "Units of Area Measurement",
+    codes: &[
+        Code { value: "um2", scheme: "UCUM", meaning: "square micrometer" },
+        Code { value: "mm2", scheme: "UCUM", meaning: "square millimeter" },
+        Code { value: "cm2", scheme: "UCUM", meaning: "square centimeter" },
+        Code { value: "m2", scheme: "UCUM", meaning: "square meter" },
+    ],
+};
+
+/// CID 7462 Units of Volume Measurement
+pub static UnitsofVolumeMeasurement: ContextGroup = ContextGroup {
+    cid: 7462,
+    name: "Units of Volume Measurement",
+    codes: &[
+        Code { value: "um3", scheme: "UCUM", meaning: "cubic micrometer" },
+        Code { value: "mm3", scheme: "UCUM", meaning: "cubic millimeter" },
+        Code { value: "cm3", scheme: "UCUM", meaning: "cubic centimeter" },
+        Code { value: "m3", scheme: "UCUM", meaning: "cubic meter" },
+    ],
+};
+
+/// All context groups defined in this module, ordered by context ID.
+pub static CONTEXT_GROUPS: [ContextGroupRef; 9] = [
+    &AnatomicRegion,
+    &AcquisitionModality,
+    &Laterality,
+    &ObserverType,
+    &DiagnosticImagingReportHeading,
+    &ContributingEquipmentPurposeofReference,
+    &UnitsofLinearMeasurement,
+    &UnitsofAreaMeasurement,
+    &UnitsofVolumeMeasurement,
+];
+
+/// Look up a context group by its context ID.
+pub fn get_context_group(cid: u32) -> Option<ContextGroupRef> {
+    CONTEXT_GROUPS.iter().copied().find(|group| group.cid == cid)
+}
+
+/// Look up a code from any of the context groups by its coding scheme designator and code value.
+pub fn get_code(scheme: &str, value: &str) -> Option<CodeRef> {
+    CONTEXT_GROUPS
+        .iter()
+        .find_map(|group| group.get_code(scheme, value))
+}
+
+/// Look up the code meaning of a code from any of the context groups.
+pub fn get_code_meaning(scheme: &str, value: &str) -> Option<&'static str> {
+    get_code(scheme, value).map(Code::meaning)
+}
@@ -12117,6 +12117,6062 @@ pub static TAG_BY_VALUE: phf::Map<u32, TagRef> = ::phf::Map {
     ],
 };
 
+pub static TAG_BY_KEYWORD: phf::Map<&'static str, TagRef> = ::phf::Map {
+    key: 12913932095322966823,
+    disps: &[
+        (0, 2),
+        (0, 11),
+        (0, 233),
+        (0, 56),
+        (0, 27),
+        (0, 0),
+        (0, 376),
+        (0, 72),
+        (0, 2),
+        (0, 56),
+        (0, 61),
+        (0, 105),
+        (0, 2921),
+        (0, 1),
+        (0, 22),
+        (0, 401),
+        (0, 88),
+        (0, 4),
+        (0, 612),
+        (0, 224),
+        (0, 1),
+        (0, 45),
+        (0, 141),
+        (0, 35),
+        (0, 6),
+        (0, 1),
+        (0, 5),
+        (0, 37),
+        (0, 5),
+        (0, 1118),
+        (0, 408),
+        (0, 730),
+        (0, 341),
+        (0, 1),
+        (0, 163),
+        (0, 1),
+        (0, 52),
+        (0, 11),
+        (0, 164),
+        (0, 593),
+        (0, 31),
+        (0, 511),
+        (0, 123),
+        (0, 41),
+        (0, 646),
+        (0, 1),
+        (0, 42),
+        (0, 180),
+        (0, 1),
+        (0, 212),
+        (0, 34),
+        (0, 0),
+        (0, 10),
+        (0, 48),
+        (0, 72),
+        (0, 0),
+        (0, 53),
+        (0, 124),
+        (0, 4),
+        (0, 8),
+        (0, 1064),
+        (0, 75),
+        (0, 118),
+        (0, 99),
+        (0, 173),
+        (0, 413),
+        (0, 13),
+        (0, 19),
+        (0, 166),
+        (0, 23),
+        (0, 150),
+        (0, 11),
+        (0, 470),
+        (0, 4),
+        (0, 2),
+        (0, 0),
+        (0, 18),
+        (0, 12),
+        (0, 3),
+        (0, 275),
+        (0, 9),
+        (0, 1587),
+        (0, 1598),
+        (0, 106),
+        (0, 171),
+        (0, 108),
+        (0, 254),
+        (0, 10),
+        (0, 2),
+        (0, 121),
+        (0, 168),
+        (0, 104),
+        (0, 63),
+        (0, 83),
+        (0, 5),
+        (0, 2),
+        (0, 1942),
+        (0, 51),
+        (0, 5),
+        (0, 173),
+        (0, 1),
+        (0, 16),
+        (0, 1085),
+        (0, 461),
+        (0, 1),
+        (0, 3012),
+        (0, 0),
+        (0, 49),
+        (0, 0),
+        (0, 118),
+        (0, 850),
+        (0, 1),
+        (0, 25),
+        (0, 1),
+        (0, 152),
+        (0, 2),
+        (0, 25),
+        (0, 35),
+        (0, 0),
+        (0, 125),
+        (0, 38),
+        (0, 1),
+        (0, 114),
+        (0, 31),
+        (0, 22),
+        (0, 521),
+        (0, 0),
+        (0, 10),
+        (0, 10),
+        (0, 52),
+        (0, 0),
+        (0, 1145),
+        (0, 1227),
+        (0, 331),
+        (0, 5),
+        (0, 1253),
+        (0, 48),
+        (0, 68),
+        (0, 0),
+        (0, 106),
+        (0, 122),
+        (0, 20),
+        (0, 416),
+        (0, 1746),
+        (0, 86),
+        (0, 5),
+        (0, 92),
+        (0, 22),
+        (0, 44),
+        (0, 865),
+        (0, 375),
+        (0, 16),
+        (0, 17),
+        (0, 222),
+        (0, 151),
+        (0, 62),
+        (0, 0),
+        (0, 70),
+        (0, 1795),
+        (0, 103),
+        (0, 225),
+        (0, 6),
+        (0, 467),
+        (0, 0),
+        (0, 16),
+        (0, 1),
+        (0, 605),
+        (0, 4),
+        (0, 15),
+        (0, 838),
+        (0, 1),
+        (0, 217),
+        (0, 4),
+        (0, 193),
+        (0, 145),
+        (0, 76),
+        (0, 1463),
+        (0, 45),
+        (0, 1117),
+        (0, 187),
+        (0, 1431),
+        (0, 242),
+        (0, 736),
+        (0, 24),
+        (0, 148),
+        (0, 150),
+        (0, 174),
+        (0, 60),
+        (0, 6),
+        (0, 167),
+        (0, 9),
+        (0, 10),
+        (0, 1258),
+        (0, 0),
+        (0, 2),
+        (0, 0),
+        (0, 3190),
+        (0, 141),
+        (0, 653),
+        (0, 126),
+        (0, 133),
+        (0, 295),
+        (0, 2),
+        (0, 118),
+        (0, 14),
+        (0, 667),
+        (0, 1956),
+        (0, 665),
+        (0, 26),
+        (1, 72),
+        (0, 2),
+        (0, 373),
+        (0, 385),
+        (0, 5),
+        (0, 43),
+        (0, 34),
+        (0, 10),
+        (0, 55),
+        (0, 14),
+        (0, 8),
+        (0, 0),
+        (0, 0),
+        (0, 32),
+        (0, 29),
+        (0, 6),
+        (0, 1948),
+        (0, 0),
+        (0, 53),
+        (0, 96),
+        (0, 96),
+        (0, 5),
+        (0, 1307),
+        (0, 50),
+        (0, 153),
+        (0, 22),
+        (0, 82),
+        (0, 22),
+        (0, 170),
+        (0, 425),
+        (0, 25),
+        (0, 21),
+        (0, 567),
+        (0, 9),
+        (0, 84),
+        (0, 34),
+        (0, 2968),
+        (0, 86),
+        (0, 321),
+        (0, 3741),
+        (0, 0),
+        (0, 18),
+        (0, 352),
+        (0, 64),
+        (0, 12),
+        (0, 13),
+        (0, 133),
+        (0, 6),
+        (0, 66),
+        (0, 1),
+        (0, 2318),
+        (0, 1),
+        (0, 846),
+        (0, 18),
+        (0, 1),
+        (0, 3),
+        (0, 208),
+        (0, 2),
+        (0, 4),
+        (0, 92),
+        (0, 56),
+        (0, 5),
+        (0, 318),
+        (0, 308),
+        (0, 364),
+        (0, 261),
+        (0, 67),
+        (0, 69),
+        (0, 408),
+        (0, 53),
+        (0, 3),
+        (0, 74),
+        (0, 1),
+        (0, 10),
+        (0, 4),
+        (0, 3),
+        (0, 12),
+        (0, 2832),
+        (0, 4),
+        (0, 18),
+        (0, 41),
+        (0, 106),
+        (0, 10),
+        (0, 424),
+        (0, 172),
+        (0, 51),
+        (0, 704),
+        (0, 0),
+        (0, 3),
+        (0, 0),
+        (0, 25),
+        (0, 455),
+        (0, 182),
+        (0, 452),
+        (0, 0),
+        (0, 244),
+        (0, 452),
+        (0, 387),
+        (0, 71),
+        (0, 0),
+        (0, 63),
+        (0, 2167),
+        (0, 2),
+        (0, 1),
+        (0, 456),
+        (0, 124),
+        (0, 2005),
+        (0, 2),
+        (0, 0),
+        (0, 97),
+        (0, 924),
+        (0, 951),
+        (0, 298),
+        (0, 0),
+        (0, 4),
+        (0, 16),
+        (0, 412),
+        (0, 147),
+        (0, 22),
+        (0, 259),
+        (0, 66),
+        (0, 10),
+        (0, 203),
+        (0, 109),
+        (0, 278),
+        (0, 55),
+        (0, 1),
+        (0, 297),
+        (0, 2843),
+        (0, 593),
+        (0, 878),
+        (0, 17),
+        (0, 1016),
+        (0, 163),
+        (0, 1),
+        (0, 63),
+        (0, 185),
+        (0, 188),
+        (0, 314),
+        (0, 21),
+        (0, 1),
+        (0, 80),
+        (0, 76),
+        (0, 0),
+        (0, 3379),
+        (0, 24),
+        (0, 1339),
+        (0, 1743),
+        (0, 92),
+        (0, 1233),
+        (0, 639),
+        (0, 9),
+        (0, 384),
+        (0, 25),
+        (0, 8),
+        (0, 2699),
+        (0, 19),
+        (0, 1),
+        (0, 7),
+        (0, 48),
+        (0, 199),
+        (0, 44),
+        (0, 1242),
+        (1, 3070),
+        (0, 3),
+        (0, 54),
+        (0, 1600),
+        (0, 5),
+        (0, 30),
+        (0, 110),
+        (0, 1885),
+        (0, 2673),
+        (0, 884),
+        (0, 1033),
+        (0, 9),
+        (0, 38),
+        (0, 5),
+        (0, 1021),
+        (1, 518),
+        (0, 163),
+        (0, 250),
+        (0, 1747),
+        (0, 335),
+        (0, 3),
+        (0, 506),
+        (0, 689),
+        (0, 380),
+        (0, 28),
+        (0, 231),
+        (0, 131),
+        (0, 22),
+        (0, 0),
+        (0, 192),
+        (0, 223),
+        (0, 2),
+        (0, 19),
+        (0, 1),
+        (0, 11),
+        (0, 5),
+        (0, 245),
+        (0, 6),
+        (0, 6),
+        (0, 62),
+        (0, 260),
+        (0, 3),
+        (0, 89),
+        (0, 50),
+        (0, 3),
+        (0, 853),
+        (0, 768),
+        (0, 2476),
+        (0, 6),
+        (0, 63),
+        (0, 1),
+        (0, 264),
+        (0, 6),
+        (0, 0),
+        (0, 190),
+        (0, 54),
+        (0, 146),
+        (0, 854),
+        (0, 1705),
+        (0, 211),
+        (0, 0),
+        (0, 3),
+        (0, 197),
+        (0, 94),
+        (0, 73),
+        (0, 236),
+        (0, 29),
+        (0, 0),
+        (0, 230),
+        (0, 3787),
+        (0, 1782),
+        (0, 117),
+        (0, 445),
+        (0, 111),
+        (0, 38),
+        (0, 135),
+        (0, 121),
+        (0, 536),
+        (0, 76),
+        (0, 202),
+        (0, 0),
+        (0, 0),
+        (0, 2),
+        (0, 196),
+        (0, 3),
+        (0, 0),
+        (0, 4),
+        (0, 1),
+        (0, 0),
+        (0, 158),
+        (0, 99),
+        (0, 0),
+        (0, 8),
+        (0, 589),
+        (0, 54),
+        (0, 11),
+        (0, 75),
+        (0, 645),
+        (0, 0),
+        (0, 5),
+        (0, 12),
+        (0, 86),
+        (0, 139),
+        (0, 388),
+        (0, 445),
+        (0, 21),
+        (0, 131),
+        (0, 54),
+        (0, 2),
+        (0, 1),
+        (0, 27),
+        (0, 197),
+        (0, 187),
+        (0, 6),
+        (0, 34),
+        (0, 2),
+        (0, 45),
+        (0, 2870),
+        (0, 1265),
+        (0, 403),
+        (1, 435),
+        (0, 23),
+        (0, 15),
+        (0, 455),
+        (0, 136),
+        (0, 1739),
+        (0, 3),
+        (0, 3),
+        (0, 137),
+        (0, 33),
+        (0, 85),
+        (0, 21),
+        (0, 18),
+        (0, 567),
+        (0, 3578),
+        (0, 94),
+        (0, 26),
+        (0, 94),
+        (0, 2),
+        (0, 0),
+        (0, 0),
+        (0, 59),
+        (1, 91),
+        (0, 0),
+        (0, 15),
+        (0, 1996),
+        (0, 3),
+        (0, 7),
+        (0, 193),
+        (0, 2),
+        (0, 230),
+        (0, 204),
+        (0, 0),
+        (0, 0),
+        (0, 0),
+        (0, 7),
+        (0, 52),
+        (0, 2292),
+        (0, 115),
+        (0, 1230),
+        (1, 4108),
+        (0, 54),
+        (0, 104),
+        (0, 966),
+        (0, 1),
+        (0, 256),
+        (0, 2462),
+        (0, 30),
+        (0, 179),
+        (0, 57),
+        (0, 382),
+        (0, 1205),
+        (0, 5),
+        (0, 607),
+        (0, 820),
+        (0, 523),
+        (0, 19),
+        (0, 2420),
+        (0, 245),
+        (0, 47),
+        (0, 1025),
+        (0, 4190),
+        (0, 64),
+        (0, 1),
+        (0, 47),
+        (0, 239),
+        (0, 101),
+        (0, 120),
+        (0, 6),
+        (0, 647),
+        (0, 756),
+        (0, 240),
+        (0, 64),
+        (0, 7),
+        (0, 4),
+        (0, 7),
+        (0, 989),
+        (0, 8),
+        (0, 6),
+        (0, 5),
+        (0, 134),
+        (0, 212),
+        (0, 10),
+        (0, 0),
+        (0, 88),
+        (0, 64),
+        (0, 4),
+        (0, 1),
+        (0, 1),
+        (0, 1),
+        (0, 8),
+        (0, 5),
+        (0, 27),
+        (0, 5),
+        (0, 4953),
+        (0, 911),
+        (0, 333),
+        (0, 4),
+        (0, 490),
+        (0, 1881),
+        (0, 3479),
+        (0, 867),
+        (0, 289),
+        (0, 3245),
+        (0, 19),
+        (0, 3267),
+        (0, 283),
+        (0, 1401),
+        (0, 29),
+        (0, 2),
+        (0, 354),
+        (0, 4),
+        (0, 1766),
+        (0, 64),
+        (0, 192),
+        (0, 60),
+        (0, 6),
+        (0, 332),
+        (0, 712),
+        (0, 1360),
+        (0, 2008),
+        (0, 971),
+        (0, 575),
+        (0, 240),
+        (0, 2743),
+        (0, 68),
+        (0, 640),
+        (0, 2155),
+        (0, 235),
+        (0, 1413),
+        (0, 100),
+        (0, 3),
+        (0, 2742),
+        (0, 16),
+        (0, 29),
+        (0, 227),
+        (0, 2451),
+        (0, 0),
+        (0, 28),
+        (0, 328),
+        (0, 155),
+        (0, 0),
+        (0, 127),
+        (0, 813),
+        (1, 2150),
+        (0, 4083),
+        (0, 135),
+        (0, 70),
+        (0, 109),
+        (0, 40),
+        (0, 2),
+        (0, 892),
+        (0, 3160),
+        (0, 187),
+        (0, 392),
+        (0, 162),
+        (0, 82),
+        (0, 4),
+        (0, 37),
+        (0, 239),
+        (0, 351),
+        (0, 283),
+        (2, 2236),
+        (0, 45),
+        (0, 407),
+        (0, 239),
+        (0, 806),
+        (0, 242),
+        (0, 100),
+        (0, 115),
+        (0, 165),
+        (0, 2489),
+        (0, 1356),
+        (0, 2),
+        (0, 534),
+        (0, 27),
+        (0, 623),
+        (0, 1210),
+        (0, 2038),
+        (0, 1376),
+        (0, 341),
+        (0, 14),
+        (0, 3962),
+        (0, 231),
+        (0, 8),
+        (0, 273),
+        (0, 27),
+        (0, 61),
+        (0, 130),
+        (0, 32),
+        (0, 639),
+        (0, 66),
+        (0, 168),
+        (0, 1),
+        (0, 20),
+        (0, 744),
+        (0, 0),
+        (0, 18),
+        (0, 12),
+        (0, 39),
+        (0, 101),
+        (0, 0),
+        (0, 43),
+        (0, 113),
+        (0, 30),
+        (0, 2789),
+        (0, 39),
+        (0, 3),
+        (0, 2604),
+        (0, 10),
+        (0, 20),
+        (0, 1267),
+        (0, 231),
+        (0, 3460),
+        (1, 4054),
+        (1, 1716),
+        (1, 1),
+        (0, 11),
+        (0, 490),
+        (0, 3383),
+        (0, 4694),
+        (0, 44),
+        (0, 12),
+        (0, 2),
+        (0, 2113),
+        (0, 732),
+        (0, 1339),
+        (0, 0),
+        (1, 3859),
+        (2, 763),
+        (0, 20),
+        (0, 0),
+        (0, 360),
+        (0, 0),
+        (0, 320),
+        (0, 377),
+        (0, 1),
+        (0, 11),
+        (0, 2792),
+        (0, 1521),
+        (0, 2774),
+        (0, 42),
+        (0, 0),
+        (0, 266),
+        (0, 390),
+        (0, 448),
+        (0, 255),
+        (0, 0),
+        (0, 59),
+        (0, 3512),
+        (0, 1),
+        (0, 131),
+        (0, 9),
+        (0, 312),
+        (0, 167),
+        (0, 22),
+        (0, 6),
+        (0, 276),
+        (0, 837),
+        (0, 1064),
+        (0, 3),
+        (0, 7),
+        (0, 51),
+        (0, 3),
+        (0, 1796),
+        (0, 1),
+        (0, 73),
+        (0, 866),
+        (0, 48),
+        (0, 1021),
+        (0, 1),
+        (0, 1494),
+        (0, 970),
+        (0, 526),
+        (0, 77),
+        (0, 14),
+        (0, 2),
+        (0, 3977),
+        (0, 386),
+        (0, 622),
+        (0, 1772),
+        (0, 302),
+        (0, 44),
+        (0, 11),
+        (0, 7),
+        (0, 27),
+        (0, 691),
+        (0, 28),
+        (0, 1712),
+        (0, 0),
+        (0, 1881),
+        (0, 63),
+        (0, 2662),
+        (0, 23),
+        (1, 2357),
+        (0, 17),
+        (0, 2645),
+        (2, 1441),
+        (0, 10),
+        (1, 2190),
+        (0, 3344),
+        (0, 568),
+        (0, 129),
+        (0, 2201),
+        (0, 0),
+        (0, 23),
+        (0, 37),
+        (0, 8),
+        (0, 4284),
+        (0, 518),
+        (0, 289),
+        (0, 412),
+        (0, 28),
+        (0, 176),
+        (0, 1155),
+        (1, 1811),
+        (0, 21),
+        (0, 1378),
+        (0, 56),
+        (0, 4038),
+        (0, 1266),
+        (0, 1222),
+        (0, 1506),
+        (0, 317),
+        (0, 620),
+        (0, 3),
+        (0, 139),
+        (0, 916),
+        (0, 602),
+        (3, 2560),
+        (0, 1484),
+        (0, 1972),
+        (0, 762),
+        (0, 0),
+        (0, 2572),
+        (0, 493),
+        (0, 3119),
+        (0, 0),
+        (0, 15),
+        (0, 5),
+        (0, 4245),
+        (0, 6),
+        (0, 718),
+        (0, 57),
+        (1, 3244),
+        (0, 4860),
+        (1, 1795),
+        (0, 184),
+        (1, 3655),
+        (0, 34),
+        (0, 2336),
+        (0, 3169),
+        (0, 1254),
+        (0, 129),
+        (0, 3200),
+        (0, 58),
+        (0, 15),
+        (0, 873),
+        (0, 1041),
+        (0, 1876),
+        (0, 903),
+        (0, 77),
+        (0, 13),
+        (1, 4607),
+        (0, 92),
+        (0, 7),
+        (0, 751),
+        (0, 9),
+        (0, 1),
+        (0, 658),
+        (0, 1045),
+        (7, 2839),
+        (0, 3654),
+        (0, 0),
+        (1, 764),
+        (0, 0),
+        (0, 0),
+        (0, 34),
+        (0, 1),
+        (1, 3149),
+        (0, 68),
+        (0, 727),
+        (0, 3),
+        (0, 3121),
+        (0, 1083),
+        (0, 125),
+        (0, 580),
+        (0, 4),
+        (0, 2),
+        (0, 8),
+        (0, 92),
+        (0, 122),
+        (0, 180),
+        (0, 0),
+        (0, 768),
+        (0, 1028),
+        (0, 349),
+        (0, 17),
+        (0, 2151),
+        (1, 191),
+        (0, 1591),
+        (0, 110),
+        (0, 287),
+        (4, 4062),
+        (0, 2),
+        (0, 70),
+        (0, 155),
+        (0, 3043),
+        (0, 576),
+        (5, 2452),
+        (1, 4516),
+        (0, 8),
+        (0, 10),
+        (0, 686),
+        (0, 821),
+        (0, 82),
+        (0, 21),
+        (2, 4403),
+        (1, 2251),
+        (0, 2),
+        (0, 3957),
+        (0, 0),
+        (0, 55),
+        (0, 737),
+        (0, 206),
+        (0, 3041),
+        (0, 212),
+        (0, 1),
+        (0, 3001),
+        (0, 1117),
+        (1, 4482),
+        (0, 68),
+        (0, 23),
+        (0, 286),
+        (0, 990),
+        (3, 4860),
+        (0, 218),
+        (0, 40),
+        (0, 0),
+        (3, 4053),
+        (0, 1316),
+        (0, 852),
+        (0, 1649),
+        (0, 110),
+        (0, 1817),
+        (0, 87),
+        (1, 1357),
+        (0, 8),
+        (6, 4537),
+        (1, 2540),
+        (0, 1847),
+        (0, 68),
+        (0, 0),
+        (0, 41),
+        (0, 2667),
+        (0, 19),
+        (0, 4967),
+        (0, 18),
+        (0, 507),
+        (0, 2),
+        (0, 509),
+        (0, 11),
+        (4, 1523),
+        (0, 1),
+        (0, 584),
+        (0, 2836),
+        (0, 51),
+        (0, 1464),
+        (0, 2),
+        (0, 20),
+        (1, 271),
+        (0, 978),
+        (0, 38),
+        (0, 591),
+        (0, 78),
+        (0, 165),
+        (0, 10),
+        (0, 228),
+        (0, 10),
+        (0, 2),
+        (1, 1567),
+        (1, 2769),
+        (0, 3152),
+        (1, 2406),
+        (0, 33),
+        (3, 1670),
+        (0, 0),
+        (3, 572),
+        (1, 3649),
+        (0, 528),
+        (0, 964),
+        (0, 30),
+        (0, 2640),
+        (0, 11),
+        (0, 33),
+        (0, 0),
+        (0, 399),
+        (0, 359),
+        (1, 625),
+        (1, 4988),
+        (0, 25),
+        (0, 3496),
+        (0, 56),
+        (0, 874),
+        (3, 1071),
+        (0, 8),
+        (0, 59),
+        (0, 4446),
+        (0, 41),
+        (0, 211),
+        (0, 107),
+        (5, 4621),
+        (0, 3058),
+        (0, 9),
+        (0, 29),
+        (0, 0),
+    ],
+    entries: &[
+        ("RegistrationMethodCodeSequence", &tags::RegistrationMethodCodeSequence),
+        ("VelocityEncodingMinimumValue", &tags::VelocityEncodingMinimumValue),
+        ("RescaleSlope", &tags::RescaleSlope),
+        ("MultienergyAcquisitionDescription", &tags::MultienergyAcquisitionDescription),
+        ("ImageOrientationSlide", &tags::ImageOrientationSlide),
+        ("InterventionDrugStartTime", &tags::InterventionDrugStartTime),
+        ("SourceApplicatorWallNominalThickness", &tags::SourceApplicatorWallNominalThickness),
+        ("CollimatorUpperHorizontalEdge", &tags::CollimatorUpperHorizontalEdge),
+        ("DerivationDescription", &tags::DerivationDescription),
+        ("DisplaySubsystemDescription", &tags::DisplaySubsystemDescription),
+        ("InternationalRouteSegment", &tags::InternationalRouteSegment),
+        ("ASLSlabOrientation", &tags::ASLSlabOrientation),
+        ("SourceOfRefractiveMeasurementsCodeSequence", &tags::SourceofRefractiveMeasurementsCodeSequence),
+        ("ChannelBaseline", &tags::ChannelBaseline),
+        ("MRArterialSpinLabelingSequence", &tags::MRArterialSpinLabelingSequence),
+        ("FluenceMapSequence", &tags::FluenceMapSequence),
+        ("TableColumnNumber", &tags::TableColumnNumber),
+        ("DeliveryVerificationImageSequence", &tags::DeliveryVerificationImageSequence),
+        ("ReasonForOmissionCodeSequence", &tags::ReasonforOmissionCodeSequence),
+        ("TableAngle", &tags::TableAngle),
+        ("WarningReason", &tags::WarningReason),
+        ("RTRadiationPhysicalAndGeometricContentDetailFlag", &tags::RTRadiationPhysicalandGeometricContentDetailFlag),
+        ("RelevantOPTAttributesSequence", &tags::RelevantOPTAttributesSequence),
+        ("DopplerSampleVolumeYPositionRetired", &tags::DopplerSampleVolumeYPosition_Retired),
+        ("MultiCoilConfiguration", &tags::MultiCoilConfiguration),
+        ("MechanicalIndex", &tags::MechanicalIndex),
+        ("RandomsCorrectionMethod", &tags::RandomsCorrectionMethod),
+        ("TDRType", &tags::TDRType),
+        ("PresentationPixelSpacing", &tags::PresentationPixelSpacing),
+        ("PatientTreatmentPreparationProcedureCodeSequence", &tags::PatientTreatmentPreparationProcedureCodeSequence),
+        ("StudyReadDate", &tags::StudyReadDate),
+        ("NumberOfTomosynthesisSourceImages", &tags::NumberofTomosynthesisSourceImages),
+        ("EntityDescription", &tags::EntityDescription),
+        ("RequestedStatusInterval", &tags::RequestedStatusInterval),
+        ("NumberOfEventTimers", &tags::NumberofEventTimers),
+        ("LocalNamespaceEntityID", &tags::LocalNamespaceEntityID),
+        ("TotalGain", &tags::TotalGain),
+        ("ThreeDRenderingType", &tags::Tag_3DRenderingType),
+        ("TopLeftHandCornerOfLocalizerArea", &tags::TopLeftHandCornerofLocalizerArea),
+        ("PositioningLandmarkSequence", &tags::PositioningLandmarkSequence),
+        ("FluoroscopyPersistenceFlag", &tags::FluoroscopyPersistenceFlag),
+        ("ObjectBinaryIdentifierTrial", &tags::ObjectBinaryIdentifier_Trial),
+        ("AnatomicalOrientationType", &tags::AnatomicalOrientationType),
+        ("TrackSetNumber", &tags::TrackSetNumber),
+        ("ControlPoint3DPosition", &tags::ControlPoint3DPosition),
+        ("IntensifierActiveDimensions", &tags::IntensifierActiveDimensions),
+        ("BlockTrayID", &tags::BlockTrayID),
+        ("SpecifiedTreatmentTime", &tags::SpecifiedTreatmentTime),
+        ("ControlPointIndex", &tags::ControlPointIndex),
+        ("LightPathFilterPassThroughWavelength", &tags::LightPathFilterPassThroughWavelength),
+        ("MRTransmitCoilSequence", &tags::MRTransmitCoilSequence),
+        ("AcquisitionMode", &tags::AcquisitionMode),
+        ("EchoPulseSequence", &tags::EchoPulseSequence),
+        ("RequestedSubsequentWorkitemCodeSequence", &tags::RequestedSubsequentWorkitemCodeSequence),
+        ("StudyID", &tags::StudyID),
+        ("ReconstructionTargetCenterLocationSequence", &tags::ReconstructionTargetCenterLocationSequence),
+        ("BlendingLUT2Sequence", &tags::BlendingLUT2Sequence),
+        ("ContextGroupLocalVersion", &tags::ContextGroupLocalVersion),
+        ("FixationEye", &tags::FixationEye),
+        ("DoseComment", &tags::DoseComment),
+        ("FrameIncrementPointer", &tags::FrameIncrementPointer),
+        ("SelectorUVValue", &tags::SelectorUVValue),
+        ("SelectorULValue", &tags::SelectorULValue),
+        ("IOLPowerForExactEmmetropia", &tags::IOLPowerForExactEmmetropia),
+        ("RTImageFrameGeneralContentSequence", &tags::RTImageFrameGeneralContentSequence),
+        ("ScheduledSpecimenSequence", &tags::ScheduledSpecimenSequence),
+        ("DischargeDiagnosisDescription", &tags::DischargeDiagnosisDescription),
+        ("ModifyingDeviceManufacturer", &tags::ModifyingDeviceManufacturer),
+        ("DetectorYPositionToIsocenter", &tags::DetectorYPositiontoIsocenter),
+        ("IsocenterToWedgeTrayDistance", &tags::IsocentertoWedgeTrayDistance),
+        ("RTImageScopeSequence", &tags::RTImageScopeSequence),
+        ("RedPaletteColorLookupTableData", &tags::RedPaletteColorLookupTableData),
+        ("RTAccessorySlotDistance", &tags::RTAccessorySlotDistance),
+        ("MinimumKeratometricSequence", &tags::MinimumKeratometricSequence),
+        ("SelectorOLValue", &tags::SelectorOLValue),
+        ("CreationDate", &tags::CreationDate),
+        ("GeometryOfKSpaceTraversal", &tags::GeometryofkSpaceTraversal),
+        ("DeliveredChannelTotalTime", &tags::DeliveredChannelTotalTime),
+        ("TerminationRelativeDensityThreshold", &tags::TerminationRelativeDensityThreshold),
+        ("PatientMotherBirthName", &tags::PatientsMothersBirthName),
+        ("AnimationStepSize", &tags::AnimationStepSize),
+        ("MeasurementUnitsCodeSequence", &tags::MeasurementUnitsCodeSequence),
+        ("ThreeDMatingAxes", &tags::Tag_3DMatingAxes),
+        ("PyramidDescription", &tags::PyramidDescription),
+        ("MultiPlanarReconstructionStyle", &tags::MultiPlanarReconstructionStyle),
+        ("Acceleration", &tags::Acceleration),
+        ("ThreeDDegreeOfFreedomAxis", &tags::Tag_3DDegreeofFreedomAxis),
+        ("ReferencedPTOSequence", &tags::ReferencedPTOSequence),
+        ("ColorFilterArrayPatternValues", &tags::ColorFilterArrayPatternValues),
+        ("PatientGantryRelationshipCodeSequence", &tags::PatientGantryRelationshipCodeSequence),
+        ("DiffusionDirectionality", &tags::DiffusionDirectionality),
+        ("ApplicatorShapeReferencedROINumber", &tags::ApplicatorShapeReferencedROINumber),
+        ("XRaySourceIsocenterSecondaryAngle", &tags::XRaySourceIsocenterSecondaryAngle),
+        ("BlockSlabThickness", &tags::BlockSlabThickness),
+        ("RelatedRTTreatmentPhaseIndex", &tags::RelatedRTTreatmentPhaseIndex),
+        ("GraphicType", &tags::GraphicType),
+        ("TemplateExtensionFlag", &tags::TemplateExtensionFlag),
+        ("ReasonForVisit", &tags::ReasonforVisit),
+        ("ReasonForVisitCodeSequence", &tags::ReasonforVisitCodeSequence),
+        ("AcquisitionsInSeries", &tags::AcquisitionsinSeries),
+        ("LinePattern", &tags::LinePattern),
+        ("CTDIPhantomTypeCodeSequence", &tags::CTDIPhantomTypeCodeSequence),
+        ("DirectoryRecordType", &tags::DirectoryRecordType),
+        ("MaskingImage", &tags::MaskingImage),
+        ("GPSDestLongitudeRef", &tags::GPSDestLongitudeRef),
+        ("CumulativeTimeWeight", &tags::CumulativeTimeWeight),
+        ("HangingProtocolName", &tags::HangingProtocolName),
+        ("ScheduledProtocolCodeSequence", &tags::ScheduledProtocolCodeSequence),
+        ("CouplingVelocity", &tags::CouplingVelocity),
+        ("SmallestValidPixelValue", &tags::SmallestValidPixelValue),
+        ("TableTopPitchAngle", &tags::TableTopPitchAngle),
+        ("AnnotationDisplayFormatID", &tags::AnnotationDisplayFormatID),
+        ("FrameOfReferenceRelationshipSequence", &tags::FrameofReferenceRelationshipSequence),
+        ("PatientSpeciesCodeSequence", &tags::PatientSpeciesCodeSequence),
+        ("TickPosition", &tags::TickPosition),
+        ("ScheduledAdmissionTime", &tags::ScheduledAdmissionTime),
+        ("WideFieldOphthalmicPhotographyThresholdQualityRating", &tags::WideFieldOphthalmicPhotographyThresholdQualityRating),
+        ("LensConstantSequence", &tags::LensConstantSequence),
+        ("RecordedSourceApplicatorSequence", &tags::RecordedSourceApplicatorSequence),
+        ("PersonTelephoneNumbers", &tags::PersonsTelephoneNumbers),
+        ("AnnotationPropertyCategoryCodeSequence", &tags::AnnotationPropertyCategoryCodeSequence),
+        ("PlanningInputInformationSequence", &tags::PlanningInputInformationSequence),
+        ("PixelValueMappingToCodedConceptSequence", &tags::PixelValueMappingtoCodedConceptSequence),
+        ("DateOfLastDetectorCalibration", &tags::DateofLastDetectorCalibration),
+        ("ReferencedFilmBoxSequence", &tags::ReferencedFilmBoxSequence),
+        ("SpectralWidth", &tags::SpectralWidth),
+        ("FiducialsPropertyCategoryCodeSequence", &tags::FiducialsPropertyCategoryCodeSequence),
+        ("ComponentShape", &tags::ComponentShape),
+        ("ActualCardiacTriggerTimePriorToRPeak", &tags::ActualCardiacTriggerTimePriorToRPeak),
+        ("OverlayPixelDataSequence", &tags::OverlayPixelDataSequence),
+        ("TableTopLateralPosition", &tags::TableTopLateralPosition),
+        ("ReformattingThickness", &tags::ReformattingThickness),
+        ("ChannelSampleSkew", &tags::ChannelSampleSkew),
+        ("ChannelDeliveryOrderIndex", &tags::ChannelDeliveryOrderIndex),
+        ("RTAccessoryHolderDefinitionSequence", &tags::RTAccessoryHolderDefinitionSequence),
+        ("TimeBasedImageSetsSequence", &tags::TimeBasedImageSetsSequence),
+        ("ImagedVolumeHeight", &tags::ImagedVolumeHeight),
+        ("CroppingSpecificationIndex", &tags::CroppingSpecificationIndex),
+        ("HL7StructuredDocumentReferenceSequence", &tags::HL7StructuredDocumentReferenceSequence),
+        ("PlanningLandmarkPointSequence", &tags::PlanningLandmarkPointSequence),
+        ("BasisMaterialsCodeSequence", &tags::BasisMaterialsCodeSequence),
+        ("MaximumDepthDistortion", &tags::MaximumDepthDistortion),
+        ("TargetMinimumLuminance", &tags::TargetMinimumLuminance),
+        ("ReferencedImageNavigationSequence", &tags::ReferencedImageNavigationSequence),
+        ("RadiationBeamWedgeThinEdgeDistance", &tags::RadiationBeamWedgeThinEdgeDistance),
+        ("PresentationLUTContentSequence", &tags::PresentationLUTContentSequence),
+        ("NumberOfIterations", &tags::NumberofIterations),
+        ("CompoundGraphicSequence", &tags::CompoundGraphicSequence),
+        ("CumulativeDoseToDoseReference", &tags::CumulativeDosetoDoseReference),
+        ("SetupDeviceDescription", &tags::SetupDeviceDescription),
+        ("AbsoluteChannelDisplayScale", &tags::AbsoluteChannelDisplayScale),
+        ("MachineSpecificTreatmentTerminationCodeSequence", &tags::MachineSpecificTreatmentTerminationCodeSequence),
+        ("AcquisitionProtocolDescription", &tags::AcquisitionProtocolDescription),
+        ("ObservationDateTime", &tags::ObservationDateTime),
+        ("InterpretationID", &tags::InterpretationID),
+        ("ComponentTypeCodeSequence", &tags::ComponentTypeCodeSequence),
+        ("RawDataHandling", &tags::RawDataHandling),
+        ("ReferencedImageBoxSequenceRetired", &tags::ReferencedImageBoxSequence_Retired),
+        ("CalculatedAnatomyThickness", &tags::CalculatedAnatomyThickness),
+        ("WedgeChamferHeight", &tags::WedgeChamferHeight),
+        ("Occupation", &tags::Occupation),
+        ("PixelComponentRangeStart", &tags::PixelComponentRangeStart),
+        ("PatientInsurancePlanCodeSequence", &tags::PatientsInsurancePlanCodeSequence),
+        ("Units", &tags::Units),
+        ("SurfaceMeshPrimitivesSequence", &tags::SurfaceMeshPrimitivesSequence),
+        ("NumberOfPhases", &tags::NumberofPhases),
+        ("RecommendedRotationPoint", &tags::RecommendedRotationPoint),
+        ("GPSImgDirection", &tags::GPSImgDirection),
+        ("MultiPlanarExcitation", &tags::MultiplanarExcitation),
+        ("MaterialCodeSequence", &tags::MaterialCodeSequence),
+        ("AcquisitionInitiationSequence", &tags::AcquisitionInitiationSequence),
+        ("GPSDestDistance", &tags::GPSDestDistance),
+        ("TransactionStatus", &tags::TransactionStatus),
+        ("RadiusOfCircularCollimator", &tags::RadiusofCircularCollimator),
+        ("VerticalOffsetOfSensor", &tags::VerticalOffsetofSensor),
+        ("FovealPointNormativeDataFlag", &tags::FovealPointNormativeDataFlag),
+        ("NormalizationPoint", &tags::NormalizationPoint),
+        ("EndMeterset", &tags::EndMeterset),
+        ("GPSSatellites", &tags::GPSSatellites),
+        ("ScopeOfInventorySequence", &tags::ScopeofInventorySequence),
+        ("PatientTreatmentPreparationProcedureParameterSequence", &tags::PatientTreatmentPreparationProcedureParameterSequence),
+        ("SurfaceModelLabel", &tags::SurfaceModelLabel),
+        ("OphthalmicAxialMeasurementsLeftEyeSequence", &tags::OphthalmicAxialMeasurementsLeftEyeSequence),
+        ("ProductTypeCodeSequence", &tags::ProductTypeCodeSequence),
+        ("RTPhysicianIntentSequence", &tags::RTPhysicianIntentSequence),
+        ("LowerCutoffFrequency", &tags::LowerCutoffFrequency),
+        ("CondenserLensPower", &tags::CondenserLensPower),
+        ("DataSetName", &tags::DataSetName),
+        ("DetectorPositioningType", &tags::DetectorPositioningType),
+        ("TemporalPositionIndex", &tags::TemporalPositionIndex),
+        ("DiffusionAnisotropyType", &tags::DiffusionAnisotropyType),
+        ("SelectorCodeSequenceValue", &tags::SelectorCodeSequenceValue),
+        ("ServiceEpisodeDescription", &tags::ServiceEpisodeDescription),
+        ("CalculatedDoseReferenceNumber", &tags::CalculatedDoseReferenceNumber),
+        ("SurfacePointsNormalsSequence", &tags::SurfacePointsNormalsSequence),
+        ("WaterDepth", &tags::WaterDepth),
+        ("OphthalmicImageTypeDescription", &tags::OphthalmicImageTypeDescription),
+        ("BscanRate", &tags::BscanRate),
+        ("HumanPerformerCodeSequence", &tags::HumanPerformerCodeSequence),
+        ("SeriesInstanceUID", &tags::SeriesInstanceUID),
+        ("DocumentClassCodeSequence", &tags::DocumentClassCodeSequence),
+        ("DopplerSampleVolumeXPosition", &tags::DopplerSampleVolumeXPosition),
+        ("SelectorAttributeVR", &tags::SelectorAttributeVR),
+        ("CompensatorDescription", &tags::CompensatorDescription),
+        ("XRaySourceIsocenterPrimaryAngle", &tags::XRaySourceIsocenterPrimaryAngle),
+        ("ScheduledProcedureStepModificationDateTime", &tags::ScheduledProcedureStepModificationDateTime),
+        ("PositionerIsocenterPrimaryAngle", &tags::PositionerIsocenterPrimaryAngle),
+        ("AnatomicStructureSpaceOrRegionSequence", &tags::AnatomicStructureSpaceorRegionSequence),
+        ("ReceiverEquipmentSequence", &tags::ReceiverEquipmentSequence),
+        ("ContainerComponentWidth", &tags::ContainerComponentWidth),
+        ("DetectorElementSpacing", &tags::DetectorElementSpacing),
+        ("PhaseInformationSequence", &tags::PhaseInformationSequence),
+        ("DarkCurrentSequence", &tags::DarkCurrentSequence),
+        ("ReferencedRTRadiationRecordSequence", &tags::ReferencedRTRadiationRecordSequence),
+        ("ReferencedXRaySourceIndex", &tags::ReferencedXRaySourceIndex),
+        ("AnnotationGroupUID", &tags::AnnotationGroupUID),
+        ("ApplicatorDescription", &tags::ApplicatorDescription),
+        ("MemoryBitDepth", &tags::MemoryBitDepth),
+        ("ReferencedPatientSequence", &tags::ReferencedPatientSequence),
+        ("ThreatSequence", &tags::ThreatSequence),
+        ("RadiationDoseSourceToExternalContourDistance", &tags::RadiationDoseSourcetoExternalContourDistance),
+        ("InterventionDrugInformationSequence", &tags::InterventionDrugInformationSequence),
+        ("FrameDelay", &tags::FrameDelay),
+        ("PatientTelecomInformation", &tags::PatientsTelecomInformation),
+        ("OriginatingSOPInstanceReferenceSequence", &tags::OriginatingSOPInstanceReferenceSequence),
+        ("InterpretationApprovalTime", &tags::InterpretationApprovalTime),
+        ("PrivateInformation", &tags::PrivateInformation),
+        ("SelfTimerMode", &tags::SelfTimerMode),
+        ("VelocityOfSound", &tags::VelocityofSound),
+        ("MagnifyToNumberOfColumns", &tags::MagnifytoNumberofColumns),
+        ("VOIType", &tags::VOIType),
+        ("FolderAccessURI", &tags::FolderAccessURI),
+        ("ClinicalTrialSeriesDescription", &tags::ClinicalTrialSeriesDescription),
+        ("RTVFlowRTPSamplingRate", &tags::RTVFlowRTPSamplingRate),
+        ("BeamHoldOriginatingDeviceSequence", &tags::BeamHoldOriginatingDeviceSequence),
+        ("RelativeXRayExposure", &tags::RelativeXRayExposure),
+        ("MPRViewWidth", &tags::MPRViewWidth),
+        ("AttributeIdentifierList", &tags::AttributeIdentifierList),
+        ("ASLCrusherFlowLimit", &tags::ASLCrusherFlowLimit),
+        ("RadiationDoseIdentificationSequence", &tags::RadiationDoseIdentificationSequence),
+        ("ReferencedDoseSequence", &tags::ReferencedDoseSequence),
+        ("DateOfDocumentOrVerbalTransactionTrial", &tags::DateofDocumentorVerbalTransaction_Trial),
+        ("StartAcquisitionDateTime", &tags::StartAcquisitionDateTime),
+        ("RadiationOrderIndex", &tags::RadiationOrderIndex),
+        ("SpecularReflectionIntensity", &tags::SpecularReflectionIntensity),
+        ("AmbientReflectionIntensity", &tags::AmbientReflectionIntensity),
+        ("DeadTimeCorrected", &tags::DeadTimeCorrected),
+        ("ConfocalMicroscopyImageFrameTypeSequence", &tags::ConfocalMicroscopyImageFrameTypeSequence),
+        ("ChairHeadFramePosition", &tags::ChairHeadFramePosition),
+        ("TreatmentToleranceViolationCauseCodeSequence", &tags::TreatmentToleranceViolationCauseCodeSequence),
+        ("PrintingBitDepth", &tags::PrintingBitDepth),
+        ("ItineraryIDAssigningAuthority", &tags::ItineraryIDAssigningAuthority),
+        ("OrderPlacerIdentifierSequence", &tags::OrderPlacerIdentifierSequence),
+        ("GPSMapDatum", &tags::GPSMapDatum),
+        ("ConceptualVolumeSequence", &tags::ConceptualVolumeSequence),
+        ("TableTopLongitudinalPositionTolerance", &tags::TableTopLongitudinalPositionTolerance),
+        ("SelectorAEValue", &tags::SelectorAEValue),
+        ("ReferringPhysicianName", &tags::ReferringPhysiciansName),
+        ("PatientTreatmentPreparationProcedureSequence", &tags::PatientTreatmentPreparationProcedureSequence),
+        ("RecordedLateralSpreadingDeviceSequence", &tags::RecordedLateralSpreadingDeviceSequence),
+        ("SpectrallySelectedExcitation", &tags::SpectrallySelectedExcitation),
+        ("PhysicalDeltaY", &tags::PhysicalDeltaY),
+        ("SourceApplicatorID", &tags::SourceApplicatorID),
+        ("TreatmentPositionGroupSequence", &tags::TreatmentPositionGroupSequence),
+        ("ReferencedPositionReferenceInstanceSequence", &tags::ReferencedPositionReferenceInstanceSequence),
+        ("RoboticNodeIdentifier", &tags::RoboticNodeIdentifier),
+        ("AbortReason", &tags::AbortReason),
+        ("ExcludedIntervalsSequence", &tags::ExcludedIntervalsSequence),
+        ("RTPhysicianIntentNarrative", &tags::RTPhysicianIntentNarrative),
+        ("InStackPositionNumber", &tags::InStackPositionNumber),
+        ("ContourData", &tags::ContourData),
+        ("MeasuredLateralDimension", &tags::MeasuredLateralDimension),
+        ("ConfirmationSequence", &tags::ConfirmationSequence),
+        ("FindingsSequenceTrial", &tags::FindingsSequence_Trial),
+        ("ConcatenationUID", &tags::ConcatenationUID),
+        ("NumberOfWaveformChannels", &tags::NumberofWaveformChannels),
+        ("DarkCurrentCounts", &tags::DarkCurrentCounts),
+        ("ASLPulseTrainDuration", &tags::ASLPulseTrainDuration),
+        ("SpecialNeeds", &tags::SpecialNeeds),
+        ("TableFrameOfReferenceUID", &tags::TableFrameofReferenceUID),
+        ("SpectroscopyAcquisitionPhaseColumns", &tags::SpectroscopyAcquisitionPhaseColumns),
+        ("DICOMStorageSequence", &tags::DICOMStorageSequence),
+        ("UnassignedPerFrameConvertedAttributesSequence", &tags::UnassignedPerFrameConvertedAttributesSequence),
+        ("OphthalmicAxialLengthDataSourceCodeSequence", &tags::OphthalmicAxialLengthDataSourceCodeSequence),
+        ("RightLensSequence", &tags::RightLensSequence),
+        ("TreatmentDeliveryType", &tags::TreatmentDeliveryType),
+        ("AlgorithmRoutingCodeSequence", &tags::AlgorithmRoutingCodeSequence),
+        ("TerminationCountsThreshold", &tags::TerminationCountsThreshold),
+        ("IOLFormulaDetail", &tags::IOLFormulaDetail),
+        ("CTGeometrySequence", &tags::CTGeometrySequence),
+        ("IsocenterReferenceSystemSequence", &tags::IsocenterReferenceSystemSequence),
+        ("ModelSpecificationSequence", &tags::ModelSpecificationSequence),
+        ("BeamType", &tags::BeamType),
+        ("OphthalmicAxialLength", &tags::OphthalmicAxialLength),
+        ("EquivalentCDADocumentSequence", &tags::EquivalentCDADocumentSequence),
+        ("NumberOfFramesIntegrated", &tags::NumberofFramesIntegrated),
+        ("ReferenceAirKermaRate", &tags::ReferenceAirKermaRate),
+        ("DataElementMaximumCharacters", &tags::DataElementMaximumCharacters),
+        ("ROIElementalCompositionAtomicNumber", &tags::ROIElementalCompositionAtomicNumber),
+        ("SourceStartDateTime", &tags::SourceStartDateTime),
+        ("BlendingLUT2TransferFunction", &tags::BlendingLUT2TransferFunction),
+        ("ModifiedImageTime", &tags::ModifiedImageTime),
+        ("DisplayDeviceTypeCodeSequence", &tags::DisplayDeviceTypeCodeSequence),
+        ("PhotographicExposureIndex", &tags::PhotographicExposureIndex),
+        ("AdditionalScreeningPerformed", &tags::AdditionalScreeningPerformed),
+        ("ImageBoxSynchronizationSequence", &tags::ImageBoxSynchronizationSequence),
+        ("ActiveSourceDiameter", &tags::ActiveSourceDiameter),
+        ("IdentifyingPrivateElements", &tags::IdentifyingPrivateElements),
+        ("ThresholdSequence", &tags::ThresholdSequence),
+        ("AcquisitionStatus", &tags::AcquisitionStatus),
+        ("TotalNumberOfPiecesOfMediaCreated", &tags::TotalNumberofPiecesofMediaCreated),
+        ("TransducerOrientationModifierSequence", &tags::TransducerOrientationModifierSequence),
+        ("RequestedProcedureID", &tags::RequestedProcedureID),
+        ("WaveformFilterDescription", &tags::WaveformFilterDescription),
+        ("PositionOfIsocenterProjection", &tags::PositionofIsocenterProjection),
+        ("SourceSequence", &tags::SourceSequence),
+        ("ControlPointOrientation", &tags::ControlPointOrientation),
+        ("AssessmentSetID", &tags::AssessmentSetID),
+        ("SelectorISValue", &tags::SelectorISValue),
+        ("PerformedStationGeographicLocationCodeSequence", &tags::PerformedStationGeographicLocationCodeSequence),
+        ("ScheduledProcedureStepEndTime", &tags::ScheduledProcedureStepEndTime),
+        ("InterpretationDiagnosisDescription", &tags::InterpretationDiagnosisDescription),
+        ("ReferencedPathIndex", &tags::ReferencedPathIndex),
+        ("VerbalSourceTrial", &tags::VerbalSource_Trial),
+        ("CreationTime", &tags::CreationTime),
+        ("ZEffective", &tags::ZEffective),
+        ("OphthalmicVolumetricPropertiesFlag", &tags::OphthalmicVolumetricPropertiesFlag),
+        ("LargeGreenPaletteColorLookupTableDescriptor", &tags::LargeGreenPaletteColorLookupTableDescriptor),
+        ("ReconstructionMethod", &tags::ReconstructionMethod),
+        ("AcquisitionUID", &tags::AcquisitionUID),
+        ("ReconstructionPixelSpacing", &tags::ReconstructionPixelSpacing),
+        ("Grid", &tags::Grid),
+        ("ObservationBasisCodeSequence", &tags::ObservationBasisCodeSequence),
+        ("LargeRedPaletteColorLookupTableData", &tags::LargeRedPaletteColorLookupTableData),
+        ("InstanceAvailability", &tags::InstanceAvailability),
+        ("ReferencedControlPointSequence", &tags::ReferencedControlPointSequence),
+        ("SimpleFrameList", &tags::SimpleFrameList),
+        ("PresentationStateClassificationComponentSequence", &tags::PresentationStateClassificationComponentSequence),
+        ("SpecificCharacterSet", &tags::SpecificCharacterSet),
+        ("SegmentedPropertyTypeCodeSequence", &tags::SegmentedPropertyTypeCodeSequence),
+        ("ShadowOpacity", &tags::ShadowOpacity),
+        ("InterpretationAuthor", &tags::InterpretationAuthor),
+        ("TableRowNumber", &tags::TableRowNumber),
+        ("CertifiedTimestampType", &tags::CertifiedTimestampType),
+        ("ReplacedProcedureStepSequence", &tags::ReplacedProcedureStepSequence),
+        ("SegmentedPropertyTypeModifierCodeSequence", &tags::SegmentedPropertyTypeModifierCodeSequence),
+        ("XRayTubeCurrentInuA", &tags::XRayTubeCurrentinmicroA),
+        ("TimeOfSecondaryCapture", &tags::TimeofSecondaryCapture),
+        ("BlendingPosition", &tags::BlendingPosition),
+        ("ListOfUIDMatchingSequence", &tags::ListofUIDMatchingSequence),
+        ("ThresholdDensity", &tags::ThresholdDensity),
+        ("ReferencedAssertionUID", &tags::ReferencedAssertionUID),
+        ("ControlPointDeliverySequence", &tags::ControlPointDeliverySequence),
+        ("TimeOfFrameGroupSequence", &tags::TimeofFrameGroupSequence),
+        ("TestPatternCodeSequence", &tags::TestPatternCodeSequence),
+        ("ProbeCenterLocationZ", &tags::ProbeCenterLocationZ),
+        ("OECFValues", &tags::OECFValues),
+        ("BlendingLookupTableData", &tags::BlendingLookupTableData),
+        ("SegmentAnnotationTypeModifierCodeSequence", &tags::SegmentAnnotationTypeModifierCodeSequence),
+        ("IndicationSequence", &tags::IndicationSequence),
+        ("PregnancyStatus", &tags::PregnancyStatus),
+        ("ResultsIDIssuer", &tags::ResultsIDIssuer),
+        ("GradientOutputType", &tags::GradientOutputType),
+        ("DACGainPoints", &tags::DACGainPoints),
+        ("ScreeningTestModeCodeSequence", &tags::ScreeningTestModeCodeSequence),
+        ("OffendingElement", &tags::OffendingElement),
+        ("RequestedSOPClassUID", &tags::RequestedSOPClassUID),
+        ("ScanProgressionDirection", &tags::ScanProgressionDirection),
+        ("PrimaryFluenceModeSequence", &tags::PrimaryFluenceModeSequence),
+        ("InventoryLevel", &tags::InventoryLevel),
+        ("RadiotherapyTreatmentType", &tags::RadiotherapyTreatmentType),
+        ("ActualFrameDuration", &tags::ActualFrameDuration),
+        ("ReferencedDefinedProtocolSequence", &tags::ReferencedDefinedProtocolSequence),
+        ("AssessedSOPInstanceSequence", &tags::AssessedSOPInstanceSequence),
+        ("ZoomFactor", &tags::ZoomFactor),
+        ("ChannelNumber", &tags::ChannelNumber),
+        ("FlowIdentifier", &tags::FlowIdentifier),
+        ("ClinicalTrialSubjectID", &tags::ClinicalTrialSubjectID),
+        ("RTTreatmentIntentType", &tags::RTTreatmentIntentType),
+        ("TomotherapeuticLeafOpenDurations", &tags::TomotherapeuticLeafOpenDurations),
+        ("AffectedSOPInstanceUID", &tags::AffectedSOPInstanceUID),
+        ("InstructionSequence", &tags::InstructionSequence),
+        ("GPSDestDistanceRef", &tags::GPSDestDistanceRef),
+        ("RadiationDoseValue", &tags::RadiationDoseValue),
+        ("AnnotationAppliesToAllOpticalPaths", &tags::AnnotationAppliestoAllOpticalPaths),
+        ("ConfigurationDescription", &tags::ConfigurationDescription),
+        ("ClinicalTrialSeriesID", &tags::ClinicalTrialSeriesID),
+        ("TriangleStripSequence", &tags::TriangleStripSequence),
+        ("Component1ReferencedID", &tags::Component1ReferencedID),
+        ("HardcopyCreationDeviceID", &tags::HardcopyCreationDeviceID),
+        ("RecordedBrachyAccessoryDeviceSequence", &tags::RecordedBrachyAccessoryDeviceSequence),
+        ("MultienergySourceTechnique", &tags::MultienergySourceTechnique),
+        ("ImageOrientationPatient", &tags::ImageOrientationPatient),
+        ("VisualAcuityLeftEyeSequence", &tags::VisualAcuityLeftEyeSequence),
+        ("ContrastBolusTotalDose", &tags::ContrastBolusTotalDose),
+        ("ReferencedTextureSequence", &tags::ReferencedTextureSequence),
+        ("PatientSupportRotationDirection", &tags::PatientSupportRotationDirection),
+        ("OtherPatientNames", &tags::OtherPatientNames),
+        ("SelectorUSValue", &tags::SelectorUSValue),
+        ("BatteryLevel", &tags::BatteryLevel),
+        ("OverlayOrImageMagnification", &tags::OverlayorImageMagnification),
+        ("RegisteredLocalizerBottomRightHandCorner", &tags::RegisteredLocalizerBottomRightHandCorner),
+        ("RangeMatchingSequence", &tags::RangeMatchingSequence),
+        ("CompressionPressure", &tags::CompressionPressure),
+        ("AcquisitionTaskWorkitemCodeSequence", &tags::AcquisitionTaskWorkitemCodeSequence),
+        ("OphthalmicEnFaceImageQualityRatingSequence", &tags::OphthalmicEnFaceImageQualityRatingSequence),
+        ("BottomRightHandCornerOfLocalizerArea", &tags::BottomRightHandCornerofLocalizerArea),
+        ("IssuerOfTheSpecimenIdentifierSequence", &tags::IssueroftheSpecimenIdentifierSequence),
+        ("MinimumNominalEnergy", &tags::MinimumNominalEnergy),
+        ("ConceptualVolumeUID", &tags::ConceptualVolumeUID),
+        ("NavigationIndicatorSequence", &tags::NavigationIndicatorSequence),
+        ("MagnetizationTransfer", &tags::MagnetizationTransfer),
+        ("StudyStatusID", &tags::StudyStatusID),
+        ("ContainerFileType", &tags::ContainerFileType),
+        ("TriggerTime", &tags::TriggerTime),
+        ("StructuredDisplayBackgroundCIELabValue", &tags::StructuredDisplayBackgroundCIELabValue),
+        ("ImagingApertureSpecificationType", &tags::ImagingApertureSpecificationType),
+        ("ModifiedImageID", &tags::ModifiedImageID),
+        ("AirCounts", &tags::AirCounts),
+        ("FilterByCategory", &tags::FilterbyCategory),
+        ("DetectorActiveTime", &tags::DetectorActiveTime),
+        ("RTImageFrameRadiationAcquisitionSequence", &tags::RTImageFrameRadiationAcquisitionSequence),
+        ("LuminanceResponseDescription", &tags::LuminanceResponseDescription),
+        ("ConceptualVolumeTypeModifierCodeSequence", &tags::ConceptualVolumeTypeModifierCodeSequence),
+        ("CTReconstructionSequence", &tags::CTReconstructionSequence),
+        ("LargeGreenPaletteColorLookupTableData", &tags::LargeGreenPaletteColorLookupTableData),
+        ("AcquisitionTaskSequence", &tags::AcquisitionTaskSequence),
+        ("ExposureTime", &tags::ExposureTime),
+        ("ContainerComponentDiameter", &tags::ContainerComponentDiameter),
+        ("RTROIIdentificationCodeSequence", &tags::RTROIIdentificationCodeSequence),
+        ("StartTrim", &tags::StartTrim),
+        ("RadiationBeamBlockThickness", &tags::RadiationBeamBlockThickness),
+        ("SourceConceptualVolumeUID", &tags::SourceConceptualVolumeUID),
+        ("RTImageFrameImagingDevicePositionSequence", &tags::RTImageFrameImagingDevicePositionSequence),
+        ("TreatmentSessionApplicationSetupSequence", &tags::TreatmentSessionApplicationSetupSequence),
+        ("DoseValueInterpretation", &tags::DoseValueInterpretation),
+        ("Temperature", &tags::Temperature),
+        ("ReferencedStoredPrintSequence", &tags::ReferencedStoredPrintSequence),
+        ("DevicePositionParameterSequence", &tags::DevicePositionParameterSequence),
+        ("ReferencedDigitalSignatureSequence", &tags::ReferencedDigitalSignatureSequence),
+        ("BulkMotionStatus", &tags::BulkMotionStatus),
+        ("DVHMinimumDose", &tags::DVHMinimumDose),
+        ("CodeValue", &tags::CodeValue),
+        ("QRMeasurementsSequence", &tags::QRMeasurementsSequence),
+        ("Exposure", &tags::Exposure),
+        ("SegmentDescription", &tags::SegmentDescription),
+        ("CornealWavefront", &tags::CornealWavefront),
+        ("SwitchingPhaseTransitionDuration", &tags::SwitchingPhaseTransitionDuration),
+        ("OutputInformationSequence", &tags::OutputInformationSequence),
+        ("DeletionLock", &tags::DeletionLock),
+        ("StimulusPresentationTime", &tags::StimulusPresentationTime),
+        ("EthnicGroup", &tags::EthnicGroup),
+        ("PupilDilated", &tags::PupilDilated),
+        ("ImagePathFilterTypeStackCodeSequence", &tags::ImagePathFilterTypeStackCodeSequence),
+        ("CouplingTechnique", &tags::CouplingTechnique),
+        ("GatedInformationSequence", &tags::GatedInformationSequence),
+        ("MandatoryComponentType", &tags::MandatoryComponentType),
+        ("DoseReferencePointCoordinates", &tags::DoseReferencePointCoordinates),
+        ("ChannelOverlap", &tags::ChannelOverlap),
+        ("ExposureProgram", &tags::ExposureProgram),
+        ("XDSRetrievalSequence", &tags::XDSRetrievalSequence),
+        ("EquipmentCoordinateSystemIdentification", &tags::EquipmentCoordinateSystemIdentification),
+        ("PositionAcquisitionTemplateIdentificationSequence", &tags::PositionAcquisitionTemplateIdentificationSequence),
+        ("SegmentAlgorithmName", &tags::SegmentAlgorithmName),
+        ("PresentationSequenceCollectionUID", &tags::PresentationSequenceCollectionUID),
+        ("InversionRecovery", &tags::InversionRecovery),
+        ("CodingSchemeIdentificationSequence", &tags::CodingSchemeIdentificationSequence),
+        ("ScreeningBaselineMeasured", &tags::ScreeningBaselineMeasured),
+        ("LongDeviceDescription", &tags::LongDeviceDescription),
+        ("BlockDivergence", &tags::BlockDivergence),
+        ("ImagedVolumeDepth", &tags::ImagedVolumeDepth),
+        ("ProjectionEponymousNameCodeSequence", &tags::ProjectionEponymousNameCodeSequence),
+        ("ROIElementalCompositionAtomicMassFraction", &tags::ROIElementalCompositionAtomicMassFraction),
+        ("WindowWidth", &tags::WindowWidth),
+        ("DerivationImageSequence", &tags::DerivationImageSequence),
+        ("RegionLocationMaxX1", &tags::RegionLocationMaxX1),
+        ("SetupTechnique", &tags::SetupTechnique),
+        ("MappingResource", &tags::MappingResource),
+        ("XAPlaneDetailsSequence", &tags::XAPlaneDetailsSequence),
+        ("PixelMeasuresSequence", &tags::PixelMeasuresSequence),
+        ("ValueType", &tags::ValueType),
+        ("WedgeOffsetZ", &tags::WedgeOffsetZ),
+        ("UID", &tags::UID),
+        ("BillingSuppliesAndDevicesSequence", &tags::BillingSuppliesandDevicesSequence),
+        ("OpticalTransmittance", &tags::OpticalTransmittance),
+        ("BoundingBoxCrop", &tags::BoundingBoxCrop),
+        ("TransformationAlgorithmSequence", &tags::TransformationAlgorithmSequence),
+        ("EntranceDoseDerivation", &tags::EntranceDoseDerivation),
+        ("DiffusionGradientDirectionSequence", &tags::DiffusionGradientDirectionSequence),
+        ("FluenceModeID", &tags::FluenceModeID),
+        ("WaveformOriginality", &tags::WaveformOriginality),
+        ("TriggerSourceOrType", &tags::TriggerSourceorType),
+        ("VolumetricProperties", &tags::VolumetricProperties),
+        ("TreatmentSessionIonBeamSequence", &tags::TreatmentSessionIonBeamSequence),
+        ("ParallelRTBeamDelimiterBoundaries", &tags::ParallelRTBeamDelimiterBoundaries),
+        ("ChannelSensitivity", &tags::ChannelSensitivity),
+        ("Trim", &tags::Trim),
+        ("AutoKVPLowerBound", &tags::AutoKVPLowerBound),
+        ("SamplesPerPixel", &tags::SamplesperPixel),
+        ("ImageDataTypeCodeSequence", &tags::ImageDataTypeCodeSequence),
+        ("PupilCentroidXCoordinate", &tags::PupilCentroidXCoordinate),
+        ("SendingApplicationEntityTitle", &tags::SendingApplicationEntityTitle),
+        ("ScanArc", &tags::ScanArc),
+        ("NumberOfSurfacePoints", &tags::NumberofSurfacePoints),
+        ("NumericValue", &tags::NumericValue),
+        ("ExposureControlMode", &tags::ExposureControlMode),
+        ("WaveformAmplifierType", &tags::WaveformAmplifierType),
+        ("ChannelMinimumValue", &tags::ChannelMinimumValue),
+        ("DirectoryRecordSequence", &tags::DirectoryRecordSequence),
+        ("MaterialAttenuationSequence", &tags::MaterialAttenuationSequence),
+        ("DepthDoseParametersSequence", &tags::DepthDoseParametersSequence),
+        ("TotalPixelMatrixRows", &tags::TotalPixelMatrixRows),
+        ("FixationDeviceType", &tags::FixationDeviceType),
+        ("TreatmentControlPointDate", &tags::TreatmentControlPointDate),
+        ("ChannelOffset", &tags::ChannelOffset),
+        ("RadiopharmaceuticalCodeSequence", &tags::RadiopharmaceuticalCodeSequence),
+        ("BoundingBoxAnnotationUnits", &tags::BoundingBoxAnnotationUnits),
+        ("OutlineLeftVerticalEdge", &tags::OutlineLeftVerticalEdge),
+        ("TableTopLongitudinalSetupDisplacement", &tags::TableTopLongitudinalSetupDisplacement),
+        ("FailedSOPSequence", &tags::FailedSOPSequence),
+        ("ImageOverlayBoxContentSequence", &tags::ImageOverlayBoxContentSequence),
+        ("SourcePixelPlanesCharacteristicsSequence", &tags::SourcePixelPlanesCharacteristicsSequence),
+        ("PulseRepetitionInterval", &tags::PulseRepetitionInterval),
+        ("IntraOcularPressure", &tags::IntraOcularPressure),
+        ("ProbeOrientationAngle", &tags::ProbeOrientationAngle),
+        ("Component2ReferencedMatingFeatureID", &tags::Component2ReferencedMatingFeatureID),
+        ("ImplementationVersionName", &tags::ImplementationVersionName),
+        ("HeadFixationAngleTolerance", &tags::HeadFixationAngleTolerance),
+        ("MydriaticAgentSequence", &tags::MydriaticAgentSequence),
+        ("MeasurementsSequence", &tags::MeasurementsSequence),
+        ("NumberOfPatientRelatedInstances", &tags::NumberofPatientRelatedInstances),
+        ("CompensatorDivergence", &tags::CompensatorDivergence),
+        ("YOffsetInSlideCoordinateSystem", &tags::YOffsetinSlideCoordinateSystem),
+        ("ContinuationEndTotalReferenceAirKerma", &tags::ContinuationEndTotalReferenceAirKerma),
+        ("SeriesDescription", &tags::SeriesDescription),
+        ("MediaStorageSOPInstanceUID", &tags::MediaStorageSOPInstanceUID),
+        ("ModulationType", &tags::ModulationType),
+        ("GeneralAccessoryID", &tags::GeneralAccessoryID),
+        ("ImplantAssemblyTemplateTargetAnatomySequence", &tags::ImplantAssemblyTemplateTargetAnatomySequence),
+        ("ArchiveRequested", &tags::ArchiveRequested),
+        ("ReferenceDoseDefinition", &tags::ReferenceDoseDefinition),
+        ("KeratometryLeftEyeSequence", &tags::KeratometryLeftEyeSequence),
+        ("ChannelPosition", &tags::ChannelPosition),
+        ("ActiveImageAreaOverlayGroup", &tags::ActiveImageAreaOverlayGroup),
+        ("ProcedureContextSequenceTrial", &tags::ProcedureContextSequence_Trial),
+        ("NumberOfDetectors", &tags::NumberofDetectors),
+        ("AngioFlag", &tags::AngioFlag),
+        ("DeviceSerialNumber", &tags::DeviceSerialNumber),
+        ("HorizontalFieldOfView", &tags::HorizontalFieldofView),
+        ("RangeModulatorNumber", &tags::RangeModulatorNumber),
+        ("SpecifiedSecondaryMeterset", &tags::SpecifiedSecondaryMeterset),
+        ("MoveOriginatorApplicationEntityTitle", &tags::MoveOriginatorApplicationEntityTitle),
+        ("DetectorActiveOrigin", &tags::DetectorActiveOrigin),
+        ("TableSpeed", &tags::TableSpeed),
+        ("ImageRotationRetired", &tags::ImageRotation_Retired),
+        ("BrachyControlPointDeliveredSequence", &tags::BrachyControlPointDeliveredSequence),
+        ("NumberOfPriorsReferenced", &tags::NumberofPriorsReferenced),
+        ("ASLCrusherFlag", &tags::ASLCrusherFlag),
+        ("KVImagingGenerationParametersSequence", &tags::KVImagingGenerationParametersSequence),
+        ("AnchorPointVisibility", &tags::AnchorPointVisibility),
+        ("CertifiedTimestamp", &tags::CertifiedTimestamp),
+        ("WaveformBitsStored", &tags::WaveformBitsStored),
+        ("GantryPitchAngle", &tags::GantryPitchAngle),
+        ("VisitStatusID", &tags::VisitStatusID),
+        ("AnatomicPortalOfEntranceCodeSequenceTrial", &tags::AnatomicPortalOfEntranceCodeSequence_Trial),
+        ("DecimateCropResult", &tags::DecimateCropResult),
+        ("ProcedureContextFlagTrial", &tags::ProcedureContextFlag_Trial),
+        ("TransformLabel", &tags::TransformLabel),
+        ("PatientClinicalTrialParticipationSequence", &tags::PatientClinicalTrialParticipationSequence),
+        ("PartialViewDescription", &tags::PartialViewDescription),
+        ("ImageBoxLargeScrollAmount", &tags::ImageBoxLargeScrollAmount),
+        ("SlabOrientation", &tags::SlabOrientation),
+        ("InboundArrivalType", &tags::InboundArrivalType),
+        ("ReasonForTheRequestedProcedure", &tags::ReasonfortheRequestedProcedure),
+        ("AcquisitionTaskIndex", &tags::AcquisitionTaskIndex),
+        ("InventoriedSeriesSequence", &tags::InventoriedSeriesSequence),
+        ("StoredValueColorRangeSequence", &tags::StoredValueColorRangeSequence),
+        ("CompressionRecognitionCode", &tags::CompressionRecognitionCode),
+        ("ControlPointSequence", &tags::ControlPointSequence),
+        ("QuantifiedDefect", &tags::QuantifiedDefect),
+        ("RTVFlowIdentifier", &tags::RTVFlowIdentifier),
+        ("InterventionDrugStopTime", &tags::InterventionDrugStopTime),
+        ("AsserterIdentificationSequence", &tags::AsserterIdentificationSequence),
+        ("CalibrationDateTime", &tags::CalibrationDateTime),
+        ("NumberOfBrachyApplicationSetups", &tags::NumberofBrachyApplicationSetups),
+        ("DeviceDiameterUnits", &tags::DeviceDiameterUnits),
+        ("BeamMeterset", &tags::BeamMeterset),
+        ("AffectedSOPClassUID", &tags::AffectedSOPClassUID),
+        ("NotchFilterCharacteristicsSequence", &tags::NotchFilterCharacteristicsSequence),
+        ("RecommendedDisplayFrameRateInFloat", &tags::RecommendedDisplayFrameRateinFloat),
+        ("DistancePupillaryDistance", &tags::DistancePupillaryDistance),
+        ("RequestedProcedureComments", &tags::RequestedProcedureComments),
+        ("ScheduledProcedureStepExpirationDateTime", &tags::ScheduledProcedureStepExpirationDateTime),
+        ("TransducerPositionSequence", &tags::TransducerPositionSequence),
+        ("CurrentFractionNumber", &tags::CurrentFractionNumber),
+        ("TwoDPointCoordinates", &tags::Tag_2DPointCoordinates),
+        ("ReferencedImplantTemplateGroupMemberID", &tags::ReferencedImplantTemplateGroupMemberID),
+        ("DiffusionBValue", &tags::Diffusionbvalue),
+        ("SurfaceComments", &tags::SurfaceComments),
+        ("PixelSpacingCalibrationDescription", &tags::PixelSpacingCalibrationDescription),
+        ("SubjectRelativePositionInImage", &tags::SubjectRelativePositioninImage),
+        ("CArmPhotonElectronControlPointSequence", &tags::CArmPhotonElectronControlPointSequence),
+        ("StructureSetTime", &tags::StructureSetTime),
+        ("UrgencyOrPriorityAlertsTrial", &tags::UrgencyorPriorityAlerts_Trial),
+        ("QuadratureReceiveCoil", &tags::QuadratureReceiveCoil),
+        ("TableTopVerticalSetupDisplacement", &tags::TableTopVerticalSetupDisplacement),
+        ("DetectorActiveDimensions", &tags::DetectorActiveDimensions),
+        ("EncapsulatedDocument", &tags::EncapsulatedDocument),
+        ("DosimetricObjectiveUID", &tags::DosimetricObjectiveUID),
+        ("DetectorNormalizationCorrection", &tags::DetectorNormalizationCorrection),
+        ("TagThickness", &tags::TagThickness),
+        ("IonBeamLimitingDeviceSequence", &tags::IonBeamLimitingDeviceSequence),
+        ("Plane", &tags::Plane),
+        ("DiffusionBValueXY", &tags::DiffusionbvalueXY),
+        ("RTRadiationSetIntent", &tags::RTRadiationSetIntent),
+        ("SpatialFrequencyResponseColumnNames", &tags::SpatialFrequencyResponseColumnNames),
+        ("SynchronizationTrigger", &tags::SynchronizationTrigger),
+        ("FieldOfViewShape", &tags::FieldofViewShape),
+        ("RespiratoryIntervalTime", &tags::RespiratoryIntervalTime),
+        ("RetrieveURL", &tags::RetrieveURL),
+        ("SOPClassesSupported", &tags::SOPClassesSupported),
+        ("OverlayForegroundDensity", &tags::OverlayForegroundDensity),
+        ("CompressionLabel", &tags::CompressionLabel),
+        ("NumberOfCopies", &tags::NumberofCopies),
+        ("EffectiveDuration", &tags::EffectiveDuration),
+        ("FocusDepth", &tags::FocusDepth),
+        ("DecayCorrectionDateTime", &tags::DecayCorrectionDateTime),
+        ("ResponsiblePerson", &tags::ResponsiblePerson),
+        ("BlendingDisplaySequence", &tags::BlendingDisplaySequence),
+        ("NumberOfAverages", &tags::NumberofAverages),
+        ("NumberOfRTAccessoryHolders", &tags::NumberofRTAccessoryHolders),
+        ("ReferenceLocationDescription", &tags::ReferenceLocationDescription),
+        ("GlobalDeviationProbabilityNormalsFlag", &tags::GlobalDeviationProbabilityNormalsFlag),
+        ("ASLSlabThickness", &tags::ASLSlabThickness),
+        ("IndexProbability", &tags::IndexProbability),
+        ("ApplicationSetupNumber", &tags::ApplicationSetupNumber),
+        ("TableTopVerticalPosition", &tags::TableTopVerticalPosition),
+        ("ActualRespiratoryTriggerDelayTime", &tags::ActualRespiratoryTriggerDelayTime),
+        ("TransactionUID", &tags::TransactionUID),
+        ("PixelAspectRatio", &tags::PixelAspectRatio),
+        ("WedgeInContactWidth", &tags::WedgeInContactWidth),
+        ("CumulativeDoseReferenceCoefficient", &tags::CumulativeDoseReferenceCoefficient),
+        ("EntranceDose", &tags::EntranceDose),
+        ("BlindSpotXCoordinate", &tags::BlindSpotXCoordinate),
+        ("ViewNumber", &tags::ViewNumber),
+        ("RotationVector", &tags::RotationVector),
+        ("ImageDisplayFormat", &tags::ImageDisplayFormat),
+        ("RTControlPointIndex", &tags::RTControlPointIndex),
+        ("ChannelImpedanceSequence", &tags::ChannelImpedanceSequence),
+        ("NominalRangeModulationFractions", &tags::NominalRangeModulationFractions),
+        ("NumberOfDisplaySubsystems", &tags::NumberofDisplaySubsystems),
+        ("DiffusionModelCodeSequence", &tags::DiffusionModelCodeSequence),
+        ("BackgroundColor", &tags::BackgroundColor),
+        ("ShadingStyle", &tags::ShadingStyle),
+        ("CurrentPatientLocation", &tags::CurrentPatientLocation),
+        ("NumberOfLuminancePoints", &tags::NumberofLuminancePoints),
+        ("TransformNumberOfAxes", &tags::TransformNumberofAxes),
+        ("PatientTreatmentPreparationProcedureParameterDescription", &tags::PatientTreatmentPreparationProcedureParameterDescription),
+        ("TextValue", &tags::TextValue),
+        ("InterventionDescription", &tags::InterventionDescription),
+        ("FluenceMode", &tags::FluenceMode),
+        ("RefractiveErrorBeforeRefractiveSurgeryCodeSequence", &tags::RefractiveErrorBeforeRefractiveSurgeryCodeSequence),
+        ("OriginalImageSequence", &tags::OriginalImageSequence),
+        ("TotalPixelMatrixOriginSequence", &tags::TotalPixelMatrixOriginSequence),
+        ("PixelBandwidth", &tags::PixelBandwidth),
+        ("SpecimenDetailedDescription", &tags::SpecimenDetailedDescription),
+        ("ScheduledProcedureStepStartTime", &tags::ScheduledProcedureStepStartTime),
+        ("PlanningLandmarkLineSequence", &tags::PlanningLandmarkLineSequence),
+        ("BeltHeight", &tags::BeltHeight),
+        ("ClinicalTrialSponsorName", &tags::ClinicalTrialSponsorName),
+        ("FractionPattern", &tags::FractionPattern),
+        ("DVHReferencedROISequence", &tags::DVHReferencedROISequence),
+        ("GradientOutput", &tags::GradientOutput),
+        ("Date", &tags::Date),
+        ("CodingSchemeName", &tags::CodingSchemeName),
+        ("TransformVersionNumber", &tags::TransformVersionNumber),
+        ("SpectrallySelectedSuppression", &tags::SpectrallySelectedSuppression),
+        ("DeviceDiameter", &tags::DeviceDiameter),
+        ("PaletteColorLookupTableSequence", &tags::PaletteColorLookupTableSequence),
+        ("AssertionComments", &tags::AssertionComments),
+        ("PixelComponentOrganization", &tags::PixelComponentOrganization),
+        ("BlockName", &tags::BlockName),
+        ("PercentPhaseFieldOfView", &tags::PercentPhaseFieldofView),
+        ("PulseRepetitionFrequency", &tags::PulseRepetitionFrequency),
+        ("OphthalmicAnatomicReferencePointXCoordinate", &tags::OphthalmicAnatomicReferencePointXCoordinate),
+        ("PartialView", &tags::PartialView),
+        ("DoseReferenceSequence", &tags::DoseReferenceSequence),
+        ("ObjectPixelSpacingInCenterOfBeam", &tags::ObjectPixelSpacinginCenterofBeam),
+        ("RTImagePosition", &tags::RTImagePosition),
+        ("CTPositionSequence", &tags::CTPositionSequence),
+        ("CompensatorRows", &tags::CompensatorRows),
+        ("BlendingMode", &tags::BlendingMode),
+        ("OphthalmicUltrasoundMethodCodeSequence", &tags::OphthalmicUltrasoundMethodCodeSequence),
+        ("CommentsOnRadiationDose", &tags::CommentsonRadiationDose),
+        ("FilterMaterial", &tags::FilterMaterial),
+        ("ComponentWelderIDs", &tags::ComponentWelderIDs),
+        ("StrainDescription", &tags::StrainDescription),
+        ("LabelStyleSelection", &tags::LabelStyleSelection),
+        ("DetectorTemperature", &tags::DetectorTemperature),
+        ("PseudoColorPaletteInstanceReferenceSequence", &tags::PseudoColorPaletteInstanceReferenceSequence),
+        ("ProtocolContextSequence", &tags::ProtocolContextSequence),
+        ("RTPlanGeometry", &tags::RTPlanGeometry),
+        ("StorageMediaFileSetUID", &tags::StorageMediaFilesetUID),
+        ("DetectorPrimaryAngle", &tags::DetectorPrimaryAngle),
+        ("DynamicRange", &tags::DynamicRange),
+        ("AnatomicLocationOfExaminingInstrumentCodeSequenceTrial", &tags::AnatomicLocationOfExaminingInstrumentCodeSequence_Trial),
+        ("ReasonForPerformedProcedureCodeSequence", &tags::ReasonForPerformedProcedureCodeSequence),
+        ("PerformedStationClassCodeSequence", &tags::PerformedStationClassCodeSequence),
+        ("ScheduledStationClassCodeSequence", &tags::ScheduledStationClassCodeSequence),
+        ("ScanProcedure", &tags::ScanProcedure),
+        ("ImageCenterPointCoordinatesSequence", &tags::ImageCenterPointCoordinatesSequence),
+        ("RecordedWedgeSequence", &tags::RecordedWedgeSequence),
+        ("CompressionCode", &tags::CompressionCode),
+        ("ModalitiesInStudy", &tags::ModalitiesinStudy),
+        ("RouteSegmentLocationIDType", &tags::RouteSegmentLocationIDType),
+        ("RecordedRangeShifterSequence", &tags::RecordedRangeShifterSequence),
+        ("EventTimerNames", &tags::EventTimerNames),
+        ("DeidentificationAction", &tags::DeidentificationAction),
+        ("DefaultSmoothingType", &tags::DefaultSmoothingType),
+        ("SubjectDistanceRange", &tags::SubjectDistanceRange),
+        ("DisplayedAreaBottomRightHandCorner", &tags::DisplayedAreaBottomRightHandCorner),
+        ("PatientBirthName", &tags::PatientsBirthName),
+        ("LeafJawPositions", &tags::LeafJawPositions),
+        ("ScheduledStudyLocationAETitle", &tags::ScheduledStudyLocationAETitle),
+        ("FloatingPointValues", &tags::FloatingPointValues),
+        ("GPSSpeedRef", &tags::GPSSpeedRef),
+        ("VisualFieldShape", &tags::VisualFieldShape),
+        ("DiaphragmPosition", &tags::DiaphragmPosition),
+        ("TwoDMatingFeatureCoordinatesSequence", &tags::Tag_2DMatingFeatureCoordinatesSequence),
+        ("GraphicGroupID", &tags::GraphicGroupID),
+        ("DisplayedAreaBottomRightHandCornerTrial", &tags::DisplayedAreaBottomRightHandCorner_Trial),
+        ("DataSetVersion", &tags::DataSetVersion),
+        ("AcquisitionDuration", &tags::AcquisitionDuration),
+        ("WedgeType", &tags::WedgeType),
+        ("ReferencedSOPInstanceUID", &tags::ReferencedSOPInstanceUID),
+        ("ReferenceToRecordedSound", &tags::ReferencetoRecordedSound),
+        ("FixedRTBeamDelimiterDeviceSequence", &tags::FixedRTBeamDelimiterDeviceSequence),
+        ("Time", &tags::Time),
+        ("RequestingService", &tags::RequestingService),
+        ("SpecificAbsorptionRateValue", &tags::SpecificAbsorptionRateValue),
+        ("ChannelLength", &tags::ChannelLength),
+        ("ConsultingPhysicianName", &tags::ConsultingPhysiciansName),
+        ("ObliqueCroppingPlaneSequence", &tags::ObliqueCroppingPlaneSequence),
+        ("DataPathID", &tags::DataPathID),
+        ("RelatedSeriesSequence", &tags::RelatedSeriesSequence),
+        ("RequestPriority", &tags::RequestPriority),
+        ("CoordinateSystemTransformRotationAndScaleMatrix", &tags::CoordinateSystemTransformRotationandScaleMatrix),
+        ("PrivateDataElementNumberOfItems", &tags::PrivateDataElementNumberofItems),
+        ("LongPrimitivePointIndexList", &tags::LongPrimitivePointIndexList),
+        ("AdmittingDiagnosesCodeSequence", &tags::AdmittingDiagnosesCodeSequence),
+        ("ReferencedStructureSetSequence", &tags::ReferencedStructureSetSequence),
+        ("ImplantTargetAnatomySequence", &tags::ImplantTargetAnatomySequence),
+        ("IsocenterPosition", &tags::IsocenterPosition),
+        ("VitreousStatusCodeSequence", &tags::VitreousStatusCodeSequence),
+        ("IncidentAngle", &tags::IncidentAngle),
+        ("SamplingStepSize", &tags::SamplingStepSize),
+        ("SourceIsotopeName", &tags::SourceIsotopeName),
+        ("RadiopharmaceuticalUsageSequence", &tags::RadiopharmaceuticalUsageSequence),
+        ("InstanceOriginStatus", &tags::InstanceOriginStatus),
+        ("SubtractionItemID", &tags::SubtractionItemID),
+        ("SpecimenLabelInImage", &tags::SpecimenLabelinImage),
+        ("VisualFieldTestReliabilityGlobalIndexSequence", &tags::VisualFieldTestReliabilityGlobalIndexSequence),
+        ("DisplayWindowLabelVector", &tags::DisplayWindowLabelVector),
+        ("SynchronizedScrollingSequence", &tags::SynchronizedScrollingSequence),
+        ("LocalizingCursorPosition", &tags::LocalizingCursorPosition),
+        ("OrderEntererLocation", &tags::OrderEnterersLocation),
+        ("PerformedProcedureStepStartTime", &tags::PerformedProcedureStepStartTime),
+        ("RadiationMassNumber", &tags::RadiationMassNumber),
+        ("DigitalFilterOrder", &tags::DigitalFilterOrder),
+        ("ExclusionStartDateTime", &tags::ExclusionStartDateTime),
+        ("IlluminationBandwidth", &tags::IlluminationBandwidth),
+        ("MaskOperationExplanation", &tags::MaskOperationExplanation),
+        ("AttenuationCorrected", &tags::AttenuationCorrected),
+        ("OphthalmicAxialLengthMeasurementsTotalLengthSequence", &tags::OphthalmicAxialLengthMeasurementsTotalLengthSequence),
+        ("ContrastBolusAgentDetected", &tags::ContrastBolusAgentDetected),
+        ("ReceiveTransducerSettingsSequence", &tags::ReceiveTransducerSettingsSequence),
+        ("NumberOfRTBeamLimitingDevices", &tags::NumberofRTBeamLimitingDevices),
+        ("ContentQualification", &tags::ContentQualification),
+        ("ModifiedAttributesSequence", &tags::ModifiedAttributesSequence),
+        ("NumberOfSurfaces", &tags::NumberofSurfaces),
+        ("PresentationSequencePositionIndex", &tags::PresentationSequencePositionIndex),
+        ("ApertureElevation", &tags::ApertureElevation),
+        ("InterpretationRecordedTime", &tags::InterpretationRecordedTime),
+        ("ScatterCorrectionMethod", &tags::ScatterCorrectionMethod),
+        ("SourceToCompensatorTrayDistance", &tags::SourcetoCompensatorTrayDistance),
+        ("ImplantRegulatoryDisapprovalCodeSequence", &tags::ImplantRegulatoryDisapprovalCodeSequence),
+        ("GantryRotationDirection", &tags::GantryRotationDirection),
+        ("SegmentCharacteristicsSequence", &tags::SegmentCharacteristicsSequence),
+        ("FrameSecondaryAngleVector", &tags::FrameSecondaryAngleVector),
+        ("OverlayTime", &tags::OverlayTime),
+        ("SelectorAttributeKeyword", &tags::SelectorAttributeKeyword),
+        ("BeamDose", &tags::BeamDose),
+        ("UserSelectedGainY", &tags::UserSelectedGainY),
+        ("ViewOrientationCodeSequence", &tags::ViewOrientationCodeSequence),
+        ("RangeShifterSequence", &tags::RangeShifterSequence),
+        ("MaterialThickness", &tags::MaterialThickness),
+        ("AmplifierType", &tags::AmplifierType),
+        ("RealWorldValueIntercept", &tags::RealWorldValueIntercept),
+        ("ConceptualVolumeDerivationAlgorithmSequence", &tags::ConceptualVolumeDerivationAlgorithmSequence),
+        ("SpectroscopyAcquisitionOutOfPlanePhaseSteps", &tags::SpectroscopyAcquisitionOutofplanePhaseSteps),
+        ("FileMetaInformationGroupLength", &tags::FileMetaInformationGroupLength),
+        ("CompressionOriginator", &tags::CompressionOriginator),
+        ("RenderingMethod", &tags::RenderingMethod),
+        ("DVHVolumeUnits", &tags::DVHVolumeUnits),
+        ("UsedFiducialsSequence", &tags::UsedFiducialsSequence),
+        ("ThreatROIBase", &tags::ThreatROIBase),
+        ("MaterialPropertiesFileFormatRetired", &tags::MaterialPropertiesFileFormat),
+        ("SubjectDistance", &tags::SubjectDistance),
+        ("RefractiveSurgeryTypeCodeSequence", &tags::RefractiveSurgeryTypeCodeSequence),
+        ("CompensatorDefinitionSequence", &tags::CompensatorDefinitionSequence),
+        ("EffectiveDoseCalculationMethodCodeSequence", &tags::EffectiveDoseCalculationMethodCodeSequence),
+        ("AcquisitionProtocolElementSequence", &tags::AcquisitionProtocolElementSequence),
+        ("RouteSegmentEndTime", &tags::RouteSegmentEndTime),
+        ("ScanSpotMetersetWeights", &tags::ScanSpotMetersetWeights),
+        ("SubscriptionListStatus", &tags::SubscriptionListStatus),
+        ("CassetteID", &tags::CassetteID),
+        ("NominalRangeModulatedRegionDepths", &tags::NominalRangeModulatedRegionDepths),
+        ("BitsStored", &tags::BitsStored),
+        ("CollationFlag", &tags::CollationFlag),
+        ("StageCodeSequence", &tags::StageCodeSequence),
+        ("NumberOfPatientRelatedSeries", &tags::NumberofPatientRelatedSeries),
+        ("InformationIssueDateTime", &tags::InformationIssueDateTime),
+        ("ScatterCorrected", &tags::ScatterCorrected),
+        ("FrameReferenceTime", &tags::FrameReferenceTime),
+        ("MainLobeAngle", &tags::MainLobeAngle),
+        ("TreatmentTime", &tags::TreatmentTime),
+        ("TreatmentTerminationCode", &tags::TreatmentTerminationCode),
+        ("PredecessorDocumentsSequence", &tags::PredecessorDocumentsSequence),
+        ("GapLength", &tags::GapLength),
+        ("NumberOfWedges", &tags::NumberofWedges),
+        ("WedgeRearHeight", &tags::WedgeRearHeight),
+        ("TriggerVector", &tags::TriggerVector),
+        ("CorrectionValue", &tags::CorrectionValue),
+        ("ManufacturerModelVersion", &tags::ManufacturersModelVersion),
+        ("TransducerApplicationCodeSequence", &tags::TransducerApplicationCodeSequence),
+        ("SoftTissueThermalIndex", &tags::SoftTissueThermalIndex),
+        ("ReferencePixelX0", &tags::ReferencePixelX0),
+        ("RadialPosition", &tags::RadialPosition),
+        ("SegmentAnnotationCategoryCodeSequence", &tags::SegmentAnnotationCategoryCodeSequence),
+        ("ScheduledWorkitemCodeSequence", &tags::ScheduledWorkitemCodeSequence),
+        ("MultienergyCTXRaySourceSequence", &tags::MultienergyCTXRaySourceSequence),
+        ("CumulativeMeterset", &tags::CumulativeMeterset),
+        ("BasicColorImageSequence", &tags::BasicColorImageSequence),
+        ("Impressions", &tags::Impressions),
+        ("AxisOfRotation", &tags::AxisofRotation),
+        ("RepeatInterval", &tags::RepeatInterval),
+        ("RadiationGenerationModeDescription", &tags::RadiationGenerationModeDescription),
+        ("DisplaySetScrollingGroup", &tags::DisplaySetScrollingGroup),
+        ("VerificationImageTiming", &tags::VerificationImageTiming),
+        ("ThresholdValueSequence", &tags::ThresholdValueSequence),
+        ("MVImagingGenerationParametersSequence", &tags::MVImagingGenerationParametersSequence),
+        ("RTAccessoryHolderSlotID", &tags::RTAccessoryHolderSlotID),
+        ("KeratometricPower", &tags::KeratometricPower),
+        ("OmittedBeamTaskSequence", &tags::OmittedBeamTaskSequence),
+        ("MultienergyCTCharacteristicsSequence", &tags::MultienergyCTCharacteristicsSequence),
+        ("DetectorTimeSinceLastExposure", &tags::DetectorTimeSinceLastExposure),
+        ("DistanceSourceToIsocenter", &tags::DistanceSourcetoIsocenter),
+        ("CylinderAxis", &tags::CylinderAxis),
+        ("RTToleranceSetSequence", &tags::RTToleranceSetSequence),
+        ("PertinentSOPClassesInStudy", &tags::PertinentSOPClassesinStudy),
+        ("StudyReadTime", &tags::StudyReadTime),
+        ("ContourGeometricType", &tags::ContourGeometricType),
+        ("MinimumSensitivityValue", &tags::MinimumSensitivityValue),
+        ("ReconstructionTargetCenterPatient", &tags::ReconstructionTargetCenterPatient),
+        ("ClinicalTrialProtocolName", &tags::ClinicalTrialProtocolName),
+        ("RTImageDescription", &tags::RTImageDescription),
+        ("ChannelTotalTime", &tags::ChannelTotalTime),
+        ("WaveformChannelNumber", &tags::WaveformChannelNumber),
+        ("DevicePositionToEquipmentMappingMatrix", &tags::DevicePositiontoEquipmentMappingMatrix),
+        ("RadiationMachineName", &tags::RadiationMachineName),
+        ("SpatialFrequencyResponseRows", &tags::SpatialFrequencyResponseRows),
+        ("RectifierSmoothing", &tags::RectifierSmoothing),
+        ("ReasonForTheImagingServiceRequest", &tags::ReasonfortheImagingServiceRequest),
+        ("CornealPointLocation", &tags::CornealPointLocation),
+        ("RadiopharmaceuticalSpecificActivity", &tags::RadiopharmaceuticalSpecificActivity),
+        ("AcquisitionDeviceProcessingCode", &tags::AcquisitionDeviceProcessingCode),
+        ("DisplaySubsystemConfigurationSequence", &tags::DisplaySubsystemConfigurationSequence),
+        ("BreastSupportIsocenterPrimaryAngle", &tags::BreastSupportIsocenterPrimaryAngle),
+        ("FindingsSourceCategoryCodeSequenceTrial", &tags::FindingsSourceCategoryCodeSequence_Trial),
+        ("GridSpacingMaterial", &tags::GridSpacingMaterial),
+        ("PertinentDocumentsSequence", &tags::PertinentDocumentsSequence),
+        ("DoublePointCoordinatesData", &tags::DoublePointCoordinatesData),
+        ("IVUSAcquisition", &tags::IVUSAcquisition),
+        ("SteepCornealAxisSequence", &tags::SteepCornealAxisSequence),
+        ("FailedSOPInstanceUIDList", &tags::FailedSOPInstanceUIDList),
+        ("AlgorithmDescriptionTrial", &tags::AlgorithmDescription_Trial),
+        ("SegmentedAlphaPaletteColorLookupTableData", &tags::SegmentedAlphaPaletteColorLookupTableData),
+        ("TransducerResponseSequence", &tags::TransducerResponseSequence),
+        ("PositionerPrimaryAngle", &tags::PositionerPrimaryAngle),
+        ("IntraocularLensCalculationsLeftEyeSequence", &tags::IntraocularLensCalculationsLeftEyeSequence),
+        ("FilterLowFrequencyCharacteristicsSequence", &tags::FilterLowFrequencyCharacteristicsSequence),
+        ("TreatmentTerminationDescription", &tags::TreatmentTerminationDescription),
+        ("UserSelectedPhase", &tags::UserSelectedPhase),
+        ("SequenceName", &tags::SequenceName),
+        ("ReferencedRequestSequence", &tags::ReferencedRequestSequence),
+        ("HistogramData", &tags::HistogramData),
+        ("InterlockOriginDescription", &tags::InterlockOriginDescription),
+        ("FlipAngle", &tags::FlipAngle),
+        ("AlphaPaletteColorLookupTableData", &tags::AlphaPaletteColorLookupTableData),
+        ("OCTAcquisitionDomain", &tags::OCTAcquisitionDomain),
+        ("SelectorSTValue", &tags::SelectorSTValue),
+        ("ElementShape", &tags::ElementShape),
+        ("NumberOfPaddedALines", &tags::NumberofPaddedAlines),
+        ("ImageFormat", &tags::ImageFormat),
+        ("CompoundGraphicInstanceID", &tags::CompoundGraphicInstanceID),
+        ("RequestedSeriesDescriptionCodeSequence", &tags::RequestedSeriesDescriptionCodeSequence),
+        ("AbortFlag", &tags::AbortFlag),
+        ("CranialThermalIndex", &tags::CranialThermalIndex),
+        ("UsedSegmentsSequence", &tags::UsedSegmentsSequence),
+        ("DosimetricObjectiveTypeCodeSequence", &tags::DosimetricObjectiveTypeCodeSequence),
+        ("NumberOfFractionsPlanned", &tags::NumberofFractionsPlanned),
+        ("AcquisitionDeviceSequence", &tags::AcquisitionDeviceSequence),
+        ("MRSpectroscopyFOVGeometrySequence", &tags::MRSpectroscopyFOVGeometrySequence),
+        ("PhaseNumber", &tags::PhaseNumber),
+        ("ContainerComponentLength", &tags::ContainerComponentLength),
+        ("StudyCompletionTime", &tags::StudyCompletionTime),
+        ("DataCollectionDiameter", &tags::DataCollectionDiameter),
+        ("ContrastBolusRoute", &tags::ContrastBolusRoute),
+        ("DoseRateSet", &tags::DoseRateSet),
+        ("BlockTransmission", &tags::BlockTransmission),
+        ("SelectedFrameFunctionalGroupsSequence", &tags::SelectedFrameFunctionalGroupsSequence),
+        ("SecondaryCaptureDeviceManufacturerModelName", &tags::SecondaryCaptureDeviceManufacturersModelName),
+        ("NumberOfSubsets", &tags::NumberofSubsets),
+        ("GlobalDeviationFromNormal", &tags::GlobalDeviationFromNormal),
+        ("OpticalPathSequence", &tags::OpticalPathSequence),
+        ("ToleranceTableLabel", &tags::ToleranceTableLabel),
+        ("VerbalSourceIdentifierCodeSequenceTrial", &tags::VerbalSourceIdentifierCodeSequence_Trial),
+        ("PlaneOrientationVolumeSequence", &tags::PlaneOrientationVolumeSequence),
+        ("ShutterRightVerticalEdge", &tags::ShutterRightVerticalEdge),
+        ("ModalityLUTSequence", &tags::ModalityLUTSequence),
+        ("BeamHardeningCorrectionTechnique", &tags::BeamHardeningCorrectionTechnique),
+        ("ImplantAssemblyTemplateName", &tags::ImplantAssemblyTemplateName),
+        ("RTVCommunicationSOPInstanceUID", &tags::RTVCommunicationSOPInstanceUID),
+        ("ScreeningBaselineMeasuredSequence", &tags::ScreeningBaselineMeasuredSequence),
+        ("ReasonForSuperseding", &tags::ReasonforSuperseding),
+        ("ExpectedInVivoMeasurementValuesSequence", &tags::ExpectedInVivoMeasurementValuesSequence),
+        ("CalculatedDoseReferenceSequence", &tags::CalculatedDoseReferenceSequence),
+        ("ScanType", &tags::ScanType),
+        ("IssuerOfTheContainerIdentifierSequence", &tags::IssueroftheContainerIdentifierSequence),
+        ("ComponentTypesSequence", &tags::ComponentTypesSequence),
+        ("SegmentSequence", &tags::SegmentSequence),
+        ("BeamNumber", &tags::BeamNumber),
+        ("SourceWaveformSequence", &tags::SourceWaveformSequence),
+        ("CommandDataSetType", &tags::CommandDataSetType),
+        ("NominalEnergy", &tags::NominalEnergy),
+        ("ApplicationSetupName", &tags::ApplicationSetupName),
+        ("ScanSpotMetersetsDelivered", &tags::ScanSpotMetersetsDelivered),
+        ("NumberOfSlices", &tags::NumberofSlices),
+        ("ObjectiveLensPower", &tags::ObjectiveLensPower),
+        ("SelectorFDValue", &tags::SelectorFDValue),
+        ("DecayFactor", &tags::DecayFactor),
+        ("BreedRegistrationSequence", &tags::BreedRegistrationSequence),
+        ("LensMake", &tags::LensMake),
+        ("RelatedProcedureStepSequence", &tags::RelatedProcedureStepSequence),
+        ("ModeOfPercutaneousAccessSequence", &tags::ModeofPercutaneousAccessSequence),
+        ("DVHMaximumDose", &tags::DVHMaximumDose),
+        ("CardiacSynchronizationSequence", &tags::CardiacSynchronizationSequence),
+        ("EnergyWindowNumber", &tags::EnergyWindowNumber),
+        ("MeasuringUnitsSequence", &tags::MeasuringUnitsSequence),
+        ("EventTimerSequence", &tags::EventTimerSequence),
+        ("TransverseDetectorSeparation", &tags::TransverseDetectorSeparation),
+        ("PixelSpacing", &tags::PixelSpacing),
+        ("FrameAcquisitionSequence", &tags::FrameAcquisitionSequence),
+        ("FractionPatternSequence", &tags::FractionPatternSequence),
+        ("ConversionType", &tags::ConversionType),
+        ("TangentialPower", &tags::TangentialPower),
+        ("SamplingFrequency", &tags::SamplingFrequency),
+        ("ReferencedLateralSpreadingDeviceNumber", &tags::ReferencedLateralSpreadingDeviceNumber),
+        ("IntendedPhaseEndDate", &tags::IntendedPhaseEndDate),
+        ("ChannelSequence", &tags::ChannelSequence),
+        ("FlashEnergy", &tags::FlashEnergy),
+        ("DepthOfScanField", &tags::DepthofScanField),
+        ("CurvatureType", &tags::CurvatureType),
+        ("NumberOfScreens", &tags::NumberofScreens),
+        ("FrameLabel", &tags::FrameLabel),
+        ("StudyInstanceUID", &tags::StudyInstanceUID),
+        ("MaterialsCodeSequence", &tags::MaterialsCodeSequence),
+        ("DisplacementReferenceLocationCodeSequence", &tags::DisplacementReferenceLocationCodeSequence),
+        ("OuterDiameter", &tags::OuterDiameter),
+        ("EquipmentAdministratorSequence", &tags::EquipmentAdministratorSequence),
+        ("SyringeCounts", &tags::SyringeCounts),
+        ("NumberOfTableRows", &tags::NumberofTableRows),
+        ("BluePaletteColorLookupTableDescriptor", &tags::BluePaletteColorLookupTableDescriptor),
+        ("ReferencedPlanOverviewIndex", &tags::ReferencedPlanOverviewIndex),
+        ("PatientAddress", &tags::PatientsAddress),
+        ("NotificationFromManufacturerSequence", &tags::NotificationFromManufacturerSequence),
+        ("AttenuationCorrectionTemporalRelationship", &tags::AttenuationCorrectionTemporalRelationship),
+        ("FiniteVolume", &tags::FiniteVolume),
+        ("TableHorizontalRotationAngle", &tags::TableHorizontalRotationAngle),
+        ("DetectorActivationOffsetFromExposure", &tags::DetectorActivationOffsetFromExposure),
+        ("InterpretationRecordedDate", &tags::InterpretationRecordedDate),
+        ("DataElement", &tags::DataElement),
+        ("SegmentAnnotationTypeCodeSequence", &tags::SegmentAnnotationTypeCodeSequence),
+        ("ApprovalStatusDateTime", &tags::ApprovalStatusDateTime),
+        ("GantryAngleTolerance", &tags::GantryAngleTolerance),
+        ("SurfaceEntryPoint", &tags::SurfaceEntryPoint),
+        ("ScatterFractionFactor", &tags::ScatterFractionFactor),
+        ("BreastSupportYPositionToIsocenter", &tags::BreastSupportYPositiontoIsocenter),
+        ("RadiopharmaceuticalInformationSequence", &tags::RadiopharmaceuticalInformationSequence),
+        ("WedgeFrontGap", &tags::WedgeFrontGap),
+        ("TransmitTransducerSettingsSequence", &tags::TransmitTransducerSettingsSequence),
+        ("ContactURI", &tags::ContactURI),
+        ("Originator", &tags::Originator),
+        ("RefractiveStateSequence", &tags::RefractiveStateSequence),
+        ("FrequencyEncodingCodeSequence", &tags::FrequencyEncodingCodeSequence),
+        ("NumberOfFramesInPhase", &tags::NumberofFramesinPhase),
+        ("SourceApplicatorWallNominalTransmission", &tags::SourceApplicatorWallNominalTransmission),
+        ("AcquisitionsInStudy", &tags::AcquisitionsinStudy),
+        ("PatientBreedDescription", &tags::PatientBreedDescription),
+        ("ReferencedStereometricInstanceSequence", &tags::ReferencedStereometricInstanceSequence),
+        ("Arbitrary", &tags::Arbitrary),
+        ("MeasurementValuesSequence", &tags::MeasurementValuesSequence),
+        ("MeasurementPrecisionDescriptionTrial", &tags::MeasurementPrecisionDescription_Trial),
+        ("DateOfSecondaryCapture", &tags::DateofSecondaryCapture),
+        ("ManufacturerRelatedModelGroup", &tags::ManufacturersRelatedModelGroup),
+        ("ReceivingApplicationEntityTitle", &tags::ReceivingApplicationEntityTitle),
+        ("ResonantNucleus", &tags::ResonantNucleus),
+        ("DeviceID", &tags::DeviceID),
+        ("ScheduledProcedureStepEndDate", &tags::ScheduledProcedureStepEndDate),
+        ("PatientTreatmentPreparationProcedureIndex", &tags::PatientTreatmentPreparationProcedureIndex),
+        ("InstructionIndex", &tags::InstructionIndex),
+        ("VisualFieldTestPointXCoordinate", &tags::VisualFieldTestPointXCoordinate),
+        ("TreatmentMachineSequence", &tags::TreatmentMachineSequence),
+        ("NumberOfFramesInRotation", &tags::NumberofFramesinRotation),
+        ("ChannelStatus", &tags::ChannelStatus),
+        ("ChemicalShiftReference", &tags::ChemicalShiftReference),
+        ("ApplicatorSequence", &tags::ApplicatorSequence),
+        ("CenterOfMass", &tags::CenterofMass),
+        ("BlendingSequence", &tags::BlendingSequence),
+        ("SeamLineLocation", &tags::SeamLineLocation),
+        ("PatientAge", &tags::PatientsAge),
+        ("VisualFieldTestNormalsFlag", &tags::VisualFieldTestNormalsFlag),
+        ("IVUSGatedRate", &tags::IVUSGatedRate),
+        ("ApplicationVersion", &tags::ApplicationVersion),
+        ("TrackSetSequence", &tags::TrackSetSequence),
+        ("RRIntervalVector", &tags::RRIntervalVector),
+        ("DetectorIsocenterSecondaryAngle", &tags::DetectorIsocenterSecondaryAngle),
+        ("ApprovalStatus", &tags::ApprovalStatus),
+        ("ConfidentialityConstraintOnPatientDataDescription", &tags::ConfidentialityConstraintonPatientDataDescription),
+        ("RetinalThicknessDefinitionCodeSequence", &tags::RetinalThicknessDefinitionCodeSequence),
+        ("ReferencedStudySequence", &tags::ReferencedStudySequence),
+        ("ScreenMinimumColorBitDepth", &tags::ScreenMinimumColorBitDepth),
+        ("STOWRSStorageSequence", &tags::STOWRSStorageSequence),
+        ("RadiationSourceCoordinateSystemPitchAngle", &tags::RadiationSourceCoordinateSystemPitchAngle),
+        ("Decoupling", &tags::Decoupling),
+        ("AnatomicApproachDirectionCodeSequenceTrial", &tags::AnatomicApproachDirectionCodeSequence_Trial),
+        ("SourceOfLensThicknessDataCodeSequence", &tags::SourceofLensThicknessDataCodeSequence),
+        ("FrameVOILUTSequence", &tags::FrameVOILUTSequence),
+        ("RecordedBlockSequence", &tags::RecordedBlockSequence),
+        ("NumberOfOpticalPaths", &tags::NumberofOpticalPaths),
+        ("PerformedStationAETitle", &tags::PerformedStationAETitle),
+        ("RTVFlowActualFrameDuration", &tags::RTVFlowActualFrameDuration),
+        ("MultienergyCTPathSequence", &tags::MultienergyCTPathSequence),
+        ("UnformattedTextValue", &tags::UnformattedTextValue),
+        ("ConceptualVolumeConstituentSequence", &tags::ConceptualVolumeConstituentSequence),
+        ("QualityThreshold", &tags::QualityThreshold),
+        ("OtherSecondaryApprovalStatus", &tags::OtherSecondaryApprovalStatus),
+        ("OperatingMode", &tags::OperatingMode),
+        ("ConceptCodeSequence", &tags::ConceptCodeSequence),
+        ("ObservationSubjectTypeCodeSequenceTrial", &tags::ObservationSubjectTypeCodeSequence_Trial),
+        ("FileOffsetInContainer", &tags::FileOffsetinContainer),
+        ("SOPInstanceUIDOfConcatenationSource", &tags::SOPInstanceUIDofConcatenationSource),
+        ("AliasedDataType", &tags::AliasedDataType),
+        ("NumberOfTotalObjects", &tags::NumberofTotalObjects),
+        ("DosimetricObjectiveParameterSequence", &tags::DosimetricObjectiveParameterSequence),
+        ("BrachytherapySourceType", &tags::BrachytherapySourceType),
+        ("CompensatorTrayID", &tags::CompensatorTrayID),
+        ("DeviationIndex", &tags::DeviationIndex),
+        ("SignalToNoiseRatio", &tags::SignaltoNoiseRatio),
+        ("RightImageSequence", &tags::RightImageSequence),
+        ("ProbeInductance", &tags::ProbeInductance),
+        ("QualityControlImage", &tags::QualityControlImage),
+        ("WedgeOriginOffsetX", &tags::WedgeOriginOffsetX),
+        ("SynchronizationChannel", &tags::SynchronizationChannel),
+        ("SelectorSSValue", &tags::SelectorSSValue),
+        ("InstructionPerformanceComment", &tags::InstructionPerformanceComment),
+        ("PresentationLUTFlag", &tags::PresentationLUTFlag),
+        ("TomotherapeuticControlPointSequence", &tags::TomotherapeuticControlPointSequence),
+        ("ReferencedPatientSetupProcedureIndex", &tags::ReferencedPatientSetupProcedureIndex),
+        ("MeteringMode", &tags::MeteringMode),
+        ("PlanePositionVolumeSequence", &tags::PlanePositionVolumeSequence),
+        ("PatientSupportPositionDeviceParameterSequence", &tags::PatientSupportPositionDeviceParameterSequence),
+        ("ImageVolumeGeometry", &tags::ImageVolumeGeometry),
+        ("DecimalPotentialVisualAcuity", &tags::DecimalPotentialVisualAcuity),
+        ("CompletionFlag", &tags::CompletionFlag),
+        ("ReferencedBolusSequence", &tags::ReferencedBolusSequence),
+        ("PreDeformationMatrixRegistrationSequence", &tags::PreDeformationMatrixRegistrationSequence),
+        ("ReportNumber", &tags::ReportNumber),
+        ("LightPathFilterTypeStackCodeSequence", &tags::LightPathFilterTypeStackCodeSequence),
+        ("MeasuredBandwidth", &tags::MeasuredBandwidth),
+        ("CountsSource", &tags::CountsSource),
+        ("PixelPaddingValue", &tags::PixelPaddingValue),
+        ("ImagingFrequency", &tags::ImagingFrequency),
+        ("TestPointNormalsSequence", &tags::TestPointNormalsSequence),
+        ("ConfigurationInformation", &tags::ConfigurationInformation),
+        ("PlanePositionSlideSequence", &tags::PlanePositionSlideSequence),
+        ("GrayLookupTableDescriptor", &tags::GrayLookupTableDescriptor),
+        ("RadiopharmaceuticalAdministrationEventUID", &tags::RadiopharmaceuticalAdministrationEventUID),
+        ("CompensatorID", &tags::CompensatorID),
+        ("RTAccessoryHolderSlotSequence", &tags::RTAccessoryHolderSlotSequence),
+        ("PresentationGroupNumber", &tags::PresentationGroupNumber),
+        ("ProbeDriveEquipmentSequence", &tags::ProbeDriveEquipmentSequence),
+        ("HorizontalAlignment", &tags::HorizontalAlignment),
+        ("RTAccessoryDeviceSlotID", &tags::RTAccessoryDeviceSlotID),
+        ("ObservationDateTrial", &tags::ObservationDate_Trial),
+        ("NumberOfEnergyWindows", &tags::NumberofEnergyWindows),
+        ("ManufacturerModelName", &tags::ManufacturersModelName),
+        ("FractionalBandwidth", &tags::FractionalBandwidth),
+        ("PrintJobDescriptionSequence", &tags::PrintJobDescriptionSequence),
+        ("SnoutID", &tags::SnoutID),
+        ("LateralSpreadingDeviceDescription", &tags::LateralSpreadingDeviceDescription),
+        ("ChannelShieldNumber", &tags::ChannelShieldNumber),
+        ("SpecimenUID", &tags::SpecimenUID),
+        ("InsurancePlanIdentification", &tags::InsurancePlanIdentification),
+        ("ConceptualVolumeCombinationExpression", &tags::ConceptualVolumeCombinationExpression),
+        ("PhysicalUnitsYDirection", &tags::PhysicalUnitsYDirection),
+        ("PrivateDataElementValueMultiplicity", &tags::PrivateDataElementValueMultiplicity),
+        ("TemplateNumber", &tags::TemplateNumber),
+        ("QualityControlSubjectTypeCodeSequence", &tags::QualityControlSubjectTypeCodeSequence),
+        ("NominalScannedPixelSpacing", &tags::NominalScannedPixelSpacing),
+        ("FrameOfReferenceTransformationMatrixType", &tags::FrameofReferenceTransformationMatrixType),
+        ("DeliveredPrimaryMeterset", &tags::DeliveredPrimaryMeterset),
+        ("TotalPixelMatrixFocalPlanes", &tags::TotalPixelMatrixFocalPlanes),
+        ("DoubleExposureFieldDelta", &tags::DoubleExposureFieldDelta),
+        ("FractionGroupDescription", &tags::FractionGroupDescription),
+        ("StartCumulativeTimeWeight", &tags::StartCumulativeTimeWeight),
+        ("AddNearSequence", &tags::AddNearSequence),
+        ("DecayCorrection", &tags::DecayCorrection),
+        ("InterpretationTranscriptionTime", &tags::InterpretationTranscriptionTime),
+        ("CompensatorShapeFabricationCodeSequence", &tags::CompensatorShapeFabricationCodeSequence),
+        ("MPRViewWidthDirection", &tags::MPRViewWidthDirection),
+        ("IntermediatePupillaryDistance", &tags::IntermediatePupillaryDistance),
+        ("FlowCompensationDirection", &tags::FlowCompensationDirection),
+        ("DetectorBinning", &tags::DetectorBinning),
+        ("RTTreatmentApproachLabel", &tags::RTTreatmentApproachLabel),
+        ("TickLabelAlignment", &tags::TickLabelAlignment),
+        ("NumberOfWarningSuboperations", &tags::NumberofWarningSuboperations),
+        ("PerformingPhysicianIdentificationSequence", &tags::PerformingPhysicianIdentificationSequence),
+        ("FacetSequence", &tags::FacetSequence),
+        ("CSSFontName", &tags::CSSFontName),
+        ("TableTopEccentricAxisDistance", &tags::TableTopEccentricAxisDistance),
+        ("CineRelativeToRealTime", &tags::CineRelativetoRealTime),
+        ("ReferencedRelatedGeneralSOPClassUIDInFile", &tags::ReferencedRelatedGeneralSOPClassUIDinFile),
+        ("SpecimenPreparationSequence", &tags::SpecimenPreparationSequence),
+        ("InstanceNumber", &tags::InstanceNumber),
+        ("Reference", &tags::Reference),
+        ("OCTOpticalCenterWavelength", &tags::OCTOpticalCenterWavelength),
+        ("ShowGrayscaleInverted", &tags::ShowGrayscaleInverted),
+        ("SortingOperationsSequence", &tags::SortingOperationsSequence),
+        ("AnnotationIndexList", &tags::AnnotationIndexList),
+        ("Radiopharmaceutical", &tags::Radiopharmaceutical),
+        ("ReferencedRTInstanceSequence", &tags::ReferencedRTInstanceSequence),
+        ("AxialPower", &tags::AxialPower),
+        ("CTImageFrameTypeSequence", &tags::CTImageFrameTypeSequence),
+        ("PhosphorType", &tags::PhosphorType),
+        ("TableTopEccentricAngle", &tags::TableTopEccentricAngle),
+        ("RelatedSegmentCharacteristicsSequence", &tags::RelatedSegmentCharacteristicsSequence),
+        ("ContinuationStartTotalReferenceAirKerma", &tags::ContinuationStartTotalReferenceAirKerma),
+        ("SpecifiedPulseRepetitionInterval", &tags::SpecifiedPulseRepetitionInterval),
+        ("BlockSlabNumber", &tags::BlockSlabNumber),
+        ("CompensatorShapeSequence", &tags::CompensatorShapeSequence),
+        ("CurveTime", &tags::CurveTime),
+        ("ConceptualVolumeDescription", &tags::ConceptualVolumeDescription),
+        ("ChannelDerivationDescription", &tags::ChannelDerivationDescription),
+        ("EchoPeakPosition", &tags::EchoPeakPosition),
+        ("BasisRTTreatmentPhaseIndex", &tags::BasisRTTreatmentPhaseIndex),
+        ("RTSegmentAnnotationIndex", &tags::RTSegmentAnnotationIndex),
+        ("RelationshipTypeCodeSequenceTrial", &tags::RelationshipTypeCodeSequence_Trial),
+        ("AdditionalInspectionSelectionCriteria", &tags::AdditionalInspectionSelectionCriteria),
+        ("MultiFramePresentationSequence", &tags::MultiframePresentationSequence),
+        ("MetersetExposure", &tags::MetersetExposure),
+        ("CompensatorBasePlaneOffset", &tags::CompensatorBasePlaneOffset),
+        ("FramePrimaryAngleVector", &tags::FramePrimaryAngleVector),
+        ("BrachyReferencedDoseReferenceSequence", &tags::BrachyReferencedDoseReferenceSequence),
+        ("VolumetricPresentationInputIndex", &tags::VolumetricPresentationInputIndex),
+        ("MoveDestination", &tags::MoveDestination),
+        ("Columns", &tags::Columns),
+        ("InstanceCreationDate", &tags::InstanceCreationDate),
+        ("TableMotion", &tags::TableMotion),
+        ("BitsAllocated", &tags::BitsAllocated),
+        ("ScanSpotTuneID", &tags::ScanSpotTuneID),
+        ("ReferencedFrameNumbers", &tags::ReferencedFrameNumbers),
+        ("UVMappingSequence", &tags::UVMappingSequence),
+        ("FiducialDescription", &tags::FiducialDescription),
+        ("ReferencedSurfaceSequence", &tags::ReferencedSurfaceSequence),
+        ("InstanceCreatorUID", &tags::InstanceCreatorUID),
+        ("TableTopVerticalAdjustedPosition", &tags::TableTopVerticalAdjustedPosition),
+        ("SubstanceAdministrationDeviceID", &tags::SubstanceAdministrationDeviceID),
+        ("CameraOwnerName", &tags::CameraOwnerName),
+        ("ColorFilterArrayPatternColumns", &tags::ColorFilterArrayPatternColumns),
+        ("AveragePulseWidth", &tags::AveragePulseWidth),
+        ("WeightingLookupTableData", &tags::WeightingLookupTableData),
+        ("SecondaryCountsType", &tags::SecondaryCountsType),
+        ("RespiratoryTriggerType", &tags::RespiratoryTriggerType),
+        ("XRaySourceID", &tags::XRaySourceID),
+        ("RadiusOfCircularExposureControlSensingRegion", &tags::RadiusofCircularExposureControlSensingRegion),
+        ("AcquisitionProtocolElementSpecificationSequence", &tags::AcquisitionProtocolElementSpecificationSequence),
+        ("ExpiryDate", &tags::ExpiryDate),
+        ("ALinesPerFrame", &tags::AlinesPerFrame),
+        ("ImageTransformationMatrix", &tags::ImageTransformationMatrix),
+        ("CTDIvol", &tags::CTDIvol),
+        ("PrimaryDosimeterUnit", &tags::PrimaryDosimeterUnit),
+        ("AlternateContainerIdentifierSequence", &tags::AlternateContainerIdentifierSequence),
+        ("SubjectLocation", &tags::SubjectLocation),
+        ("DoseGridScaling", &tags::DoseGridScaling),
+        ("ApplicationSetupType", &tags::ApplicationSetupType),
+        ("QueryRetrieveLevel", &tags::QueryRetrieveLevel),
+        ("EquipmentReferencePointCodeSequence", &tags::EquipmentReferencePointCodeSequence),
+        ("SourceDescription", &tags::SourceDescription),
+        ("RadiopharmaceuticalStartTime", &tags::RadiopharmaceuticalStartTime),
+        ("NumberOfKSpaceTrajectories", &tags::NumberofkSpaceTrajectories),
+        ("ImagePathFilterPassBand", &tags::ImagePathFilterPassBand),
+        ("DisplaySetPresentationGroup", &tags::DisplaySetPresentationGroup),
+        ("PhotoacousticImageFrameTypeSequence", &tags::PhotoacousticImageFrameTypeSequence),
+        ("PerformedStationName", &tags::PerformedStationName),
+        ("NumberOfFractionsDelivered", &tags::NumberofFractionsDelivered),
+        ("StartingRespiratoryPhase", &tags::StartingRespiratoryPhase),
+        ("ASLBolusCutoffDelayTime", &tags::ASLBolusCutoffDelayTime),
+        ("ConceptualVolumeSegmentationReferenceSequence", &tags::ConceptualVolumeSegmentationReferenceSequence),
+        ("RadionuclideCodeSequence", &tags::RadionuclideCodeSequence),
+        ("AssertionCodeSequence", &tags::AssertionCodeSequence),
+        ("RTReferencedStudySequence", &tags::RTReferencedStudySequence),
+        ("MultiplexGroupTimeOffset", &tags::MultiplexGroupTimeOffset),
+        ("MostRecentTreatmentDate", &tags::MostRecentTreatmentDate),
+        ("PerimeterTable", &tags::PerimeterTable),
+        ("BlockNumber", &tags::BlockNumber),
+        ("ChannelSourceModifiersSequence", &tags::ChannelSourceModifiersSequence),
+        ("InConcatenationTotalNumber", &tags::InconcatenationTotalNumber),
+        ("RTAccessoryHolderSlotExistenceFlag", &tags::RTAccessoryHolderSlotExistenceFlag),
+        ("ClinicalTrialProtocolEthicsCommitteeApprovalNumber", &tags::ClinicalTrialProtocolEthicsCommitteeApprovalNumber),
+        ("TreatmentToleranceViolationIdentification", &tags::TreatmentToleranceViolationIdentification),
+        ("VerticesOfTheOutlineOfPupil", &tags::VerticesoftheOutlineofPupil),
+        ("DateOfInstallation", &tags::DateofInstallation),
+        ("BulkMotionCompensationTechnique", &tags::BulkMotionCompensationTechnique),
+        ("InterpretationApproverSequence", &tags::InterpretationApproverSequence),
+        ("RetrieveLocationUID", &tags::RetrieveLocationUID),
+        ("UniqueDeviceIdentifier", &tags::UniqueDeviceIdentifier),
+        ("ReferencedRadiationRTControlPointIndex", &tags::ReferencedRadiationRTControlPointIndex),
+        ("OffsetDirection", &tags::OffsetDirection),
+        ("ROIGenerationDescription", &tags::ROIGenerationDescription),
+        ("PartialFourierDirection", &tags::PartialFourierDirection),
+        ("ShieldingDeviceSequence", &tags::ShieldingDeviceSequence),
+        ("RefractiveParametersUsedOnPatientSequence", &tags::RefractiveParametersUsedonPatientSequence),
+        ("LossyImageCompressionRatio", &tags::LossyImageCompressionRatio),
+        ("FixationDeviceRollAngle", &tags::FixationDeviceRollAngle),
+        ("LensStatusCodeSequence", &tags::LensStatusCodeSequence),
+        ("SkipBeats", &tags::SkipBeats),
+        ("BlendingWeightConstant", &tags::BlendingWeightConstant),
+        ("RadionuclideTotalDose", &tags::RadionuclideTotalDose),
+        ("AcquisitionSubtaskIndex", &tags::AcquisitionSubtaskIndex),
+        ("MRTimingAndRelatedParametersSequence", &tags::MRTimingandRelatedParametersSequence),
+        ("NumberOfTimeSlices", &tags::NumberofTimeSlices),
+        ("OutputPower", &tags::OutputPower),
+        ("FrameAcquisitionNumber", &tags::FrameAcquisitionNumber),
+        ("MRAcquisitionFrequencyEncodingSteps", &tags::MRAcquisitionFrequencyEncodingSteps),
+        ("CodingSchemeResourcesSequence", &tags::CodingSchemeResourcesSequence),
+        ("XRayFilterDetailsSequence", &tags::XRayFilterDetailsSequence),
+        ("DVHROIContributionType", &tags::DVHROIContributionType),
+        ("DistributionType", &tags::DistributionType),
+        ("TreatmentSite", &tags::TreatmentSite),
+        ("ProtocolDesignRationale", &tags::ProtocolDesignRationale),
+        ("ViewingDistance", &tags::ViewingDistance),
+        ("TemplateType", &tags::TemplateType),
+        ("StorageProtocolElementSequence", &tags::StorageProtocolElementSequence),
+        ("FixationDeviceDescription", &tags::FixationDeviceDescription),
+        ("ZeroVelocityPixelValue", &tags::ZeroVelocityPixelValue),
+        ("TransferSyntaxUID", &tags::TransferSyntaxUID),
+        ("GeometryForDisplay", &tags::GeometryforDisplay),
+        ("SignalDomainColumns", &tags::SignalDomainColumns),
+        ("MaximumAcrossScanDistortion", &tags::MaximumAcrossscanDistortion),
+        ("ModifierCodeSequence", &tags::ModifierCodeSequence),
+        ("AppliedMaskSubtractionFlag", &tags::AppliedMaskSubtractionFlag),
+        ("ReferencedReferenceImageSequence", &tags::ReferencedReferenceImageSequence),
+        ("ChannelTimeSkew", &tags::ChannelTimeSkew),
+        ("OOISize", &tags::OOISize),
+        ("PixelComponentPhysicalUnits", &tags::PixelComponentPhysicalUnits),
+        ("CreatorVersionUID", &tags::CreatorVersionUID),
+        ("ReasonForCancellation", &tags::ReasonforCancellation),
+        ("ChannelWidth", &tags::ChannelWidth),
+        ("PerimeterValue", &tags::PerimeterValue),
+        ("ImageSetLabel", &tags::ImageSetLabel),
+        ("PulserType", &tags::PulserType),
+        ("SurfaceRegularityIndex", &tags::SurfaceRegularityIndex),
+        ("VitreousStatusDescription", &tags::VitreousStatusDescription),
+        ("MRAveragesSequence", &tags::MRAveragesSequence),
+        ("WedgeElement1Position", &tags::WedgeElement1Position),
+        ("FlashMode", &tags::FlashMode),
+        ("NumberOfTimeSlots", &tags::NumberofTimeSlots),
+        ("NumberOfBoli", &tags::NumberofBoli),
+        ("PrismSequence", &tags::PrismSequence),
+        ("PercentSampling", &tags::PercentSampling),
+        ("RowOverlap", &tags::RowOverlap),
+        ("BeamDoseMeaning", &tags::BeamDoseMeaning),
+        ("PredictorRows", &tags::PredictorRows),
+        ("MaskSubtractionSequence", &tags::MaskSubtractionSequence),
+        ("RadiationSetting", &tags::RadiationSetting),
+        ("ReferencedBrachyApplicationSetupNumber", &tags::ReferencedBrachyApplicationSetupNumber),
+        ("SourceOfOphthalmicAxialLengthCodeSequence", &tags::SourceofOphthalmicAxialLengthCodeSequence),
+        ("BeamLimitingDeviceAngleTolerance", &tags::BeamLimitingDeviceAngleTolerance),
+        ("BibliographicCitationTrial", &tags::BibliographicCitation_Trial),
+        ("SAR", &tags::SAR),
+        ("ItineraryIDType", &tags::ItineraryIDType),
+        ("ImagesInAcquisition", &tags::ImagesinAcquisition),
+        ("ReceiveCoilManufacturerName", &tags::ReceiveCoilManufacturerName),
+        ("PulserNotes", &tags::PulserNotes),
+        ("InterventionSequence", &tags::InterventionSequence),
+        ("PresentationInputType", &tags::PresentationInputType),
+        ("RecordedChannelSequence", &tags::RecordedChannelSequence),
+        ("SourceStrengthUnits", &tags::SourceStrengthUnits),
+        ("CalibrationImage", &tags::CalibrationImage),
+        ("ConventionalControlPointVerificationSequence", &tags::ConventionalControlPointVerificationSequence),
+        ("FilterThicknessMaximum", &tags::FilterThicknessMaximum),
+        ("AcousticCouplingMediumTemperature", &tags::AcousticCouplingMediumTemperature),
+        ("FractionNumber", &tags::FractionNumber),
+        ("XRayDetectorID", &tags::XRayDetectorID),
+        ("DateTime", &tags::DateTime),
+        ("PatientSupportPositionParameterSequence", &tags::PatientSupportPositionParameterSequence),
+        ("PerformedProtocolCodeSequence", &tags::PerformedProtocolCodeSequence),
+        ("EndCumulativeTimeWeight", &tags::EndCumulativeTimeWeight),
+        ("RegisteredLocalizerTopLeftHandCorner", &tags::RegisteredLocalizerTopLeftHandCorner),
+        ("RTProtocolCodeSequence", &tags::RTProtocolCodeSequence),
+        ("RemovedFromOperationalUse", &tags::RemovedfromOperationalUse),
+        ("Crop", &tags::Crop),
+        ("WhiteBalance", &tags::WhiteBalance),
+        ("RecordedCompensatorSequence", &tags::RecordedCompensatorSequence),
+        ("CorrectedLocalizedDeviationFromNormalCalculated", &tags::CorrectedLocalizedDeviationFromNormalCalculated),
+        ("DeliveredReferenceDoseDefinition", &tags::DeliveredReferenceDoseDefinition),
+        ("FlashReturnStatus", &tags::FlashReturnStatus),
+        ("UValueData", &tags::UValueData),
+        ("LocalizedDeviationFromNormal", &tags::LocalizedDeviationFromNormal),
+        ("SurfaceProcessingAlgorithmIdentificationSequence", &tags::SurfaceProcessingAlgorithmIdentificationSequence),
+        ("ReferencedContentItemIdentifier", &tags::ReferencedContentItemIdentifier),
+        ("HorizontalOffsetOfSensor", &tags::HorizontalOffsetofSensor),
+        ("AverageBeamDosePointEquivalentDepth", &tags::AverageBeamDosePointEquivalentDepth),
+        ("SynchronizationFrameOfReferenceUID", &tags::SynchronizationFrameofReferenceUID),
+        ("SupportedImageDisplayFormatsSequence", &tags::SupportedImageDisplayFormatsSequence),
+        ("FlatCornealAxisSequence", &tags::FlatCornealAxisSequence),
+        ("VerticesOfThePolygonalExposureControlSensingRegion", &tags::VerticesofthePolygonalExposureControlSensingRegion),
+        ("SequencingIndicatorTrial", &tags::SequencingIndicator_Trial),
+        ("VerticesOfThePolygonalCollimator", &tags::VerticesofthePolygonalCollimator),
+        ("ReferencedVisitSequence", &tags::ReferencedVisitSequence),
+        ("PatientEyeMovementCommandCodeSequence", &tags::PatientEyeMovementCommandCodeSequence),
+        ("BoundingBoxTopLeftHandCorner", &tags::BoundingBoxTopLeftHandCorner),
+        ("MaxApertureValue", &tags::MaxApertureValue),
+        ("DeadTimeCorrectionFlag", &tags::DeadTimeCorrectionFlag),
+        ("PaddleDescription", &tags::PaddleDescription),
+        ("RGBATransferFunctionDescription", &tags::RGBATransferFunctionDescription),
+        ("SegmentIdentificationSequence", &tags::SegmentIdentificationSequence),
+        ("PatientSupportPositionToleranceSequence", &tags::PatientSupportPositionToleranceSequence),
+        ("SoftwareVersions", &tags::SoftwareVersions),
+        ("PerformedProcedureStepStartDate", &tags::PerformedProcedureStepStartDate),
+        ("AttributeItemSelector", &tags::AttributeItemSelector),
+        ("SetupReferenceDescription", &tags::SetupReferenceDescription),
+        ("ApplicatorGeometrySequence", &tags::ApplicatorGeometrySequence),
+        ("BlindSpotYCoordinate", &tags::BlindSpotYCoordinate),
+        ("ReferencedFindingsGroupUIDTrial", &tags::ReferencedFindingsGroupUID_Trial),
+        ("BoundingBoxBottomRightHandCorner", &tags::BoundingBoxBottomRightHandCorner),
+        ("ISOSpeedLatitudezzz", &tags::ISOSpeedLatitudezzz),
+        ("ContentCreatorName", &tags::ContentCreatorsName),
+        ("ScheduledAdmissionDate", &tags::ScheduledAdmissionDate),
+        ("NumberOfTableColumns", &tags::NumberofTableColumns),
+        ("ContrastBolusAdministrationRouteSequence", &tags::ContrastBolusAdministrationRouteSequence),
+        ("GridPitch", &tags::GridPitch),
+        ("RTDeliveryStartPatientPositionSequence", &tags::RTDeliveryStartPatientPositionSequence),
+        ("RTRelatedROISequence", &tags::RTRelatedROISequence),
+        ("SpecimenShortDescription", &tags::SpecimenShortDescription),
+        ("PixelComponentMask", &tags::PixelComponentMask),
+        ("DataSetType", &tags::DataSetType),
+        ("SimulatedKeratometricCylinderSequence", &tags::SimulatedKeratometricCylinderSequence),
+        ("ResultsNormalsSequence", &tags::ResultsNormalsSequence),
+        ("DetectorZPositionToIsocenter", &tags::DetectorZPositiontoIsocenter),
+        ("CertificateOfSigner", &tags::CertificateofSigner),
+        ("Manufacturer", &tags::Manufacturer),
+        ("ExcitationEnergy", &tags::ExcitationEnergy),
+        ("RTPlanDescription", &tags::RTPlanDescription),
+        ("IVUSPullbackRate", &tags::IVUSPullbackRate),
+        ("CoordinatesSetGeometricTypeTrial", &tags::CoordinatesSetGeometricType_Trial),
+        ("MultienergyCTProcessingSequence", &tags::MultienergyCTProcessingSequence),
+        ("DACSequence", &tags::DACSequence),
+        ("Bold", &tags::Bold),
+        ("TreatmentMachineName", &tags::TreatmentMachineName),
+        ("ReferencedRTPhysicianIntentSequence", &tags::ReferencedRTPhysicianIntentSequence),
+        ("MeasuredBeamDimensionA", &tags::MeasuredBeamDimensionA),
+        ("DeviceIndex", &tags::DeviceIndex),
+        ("SubstanceAdministrationDateTime", &tags::SubstanceAdministrationDateTime),
+        ("ParticipantSequence", &tags::ParticipantSequence),
+        ("VolumetricPresentationInputSetUID", &tags::VolumetricPresentationInputSetUID),
+        ("AnomalyLocatorIndicatorSequence", &tags::AnomalyLocatorIndicatorSequence),
+        ("DetectorConfiguration", &tags::DetectorConfiguration),
+        ("ContainerComponentDescription", &tags::ContainerComponentDescription),
+        ("DeliveredDistalDepth", &tags::DeliveredDistalDepth),
+        ("AcquisitionStartConditionData", &tags::AcquisitionStartConditionData),
+        ("TerminationDensityThreshold", &tags::TerminationDensityThreshold),
+        ("WedgeInContactLength", &tags::WedgeInContactLength),
+        ("CompensatorDistalThicknessMap", &tags::CompensatorDistalThicknessMap),
+        ("ContextGroupIdentificationSequence", &tags::ContextGroupIdentificationSequence),
+        ("AlternateRepresentationSequence", &tags::AlternateRepresentationSequence),
+        ("GlobalDeviationProbabilitySequence", &tags::GlobalDeviationProbabilitySequence),
+        ("TemporalRelationshipIntervalAnchor", &tags::TemporalRelationshipIntervalAnchor),
+        ("DetectorVector", &tags::DetectorVector),
+        ("GPSDestLatitudeRef", &tags::GPSDestLatitudeRef),
+        ("HPGLPenNumber", &tags::HPGLPenNumber),
+        ("DataSetTrailingPadding", &tags::DataSetTrailingPadding),
+        ("ThreeDLineCoordinates", &tags::Tag_3DLineCoordinates),
+        ("DeidentificationMethodCodeSequence", &tags::DeidentificationMethodCodeSequence),
+        ("IndexProbabilitySequence", &tags::IndexProbabilitySequence),
+        ("ReferencedVerificationImageSequence", &tags::ReferencedVerificationImageSequence),
+        ("PauseBetweenFrames", &tags::PauseBetweenFrames),
+        ("DeidentificationMethod", &tags::DeidentificationMethod),
+        ("ReferencedRTPrescriptionIndex", &tags::ReferencedRTPrescriptionIndex),
+        ("DisplayedAreaSelectionSequence", &tags::DisplayedAreaSelectionSequence),
+        ("PreliminaryFlag", &tags::PreliminaryFlag),
+        ("SnoutSequence", &tags::SnoutSequence),
+        ("CouplingMedium", &tags::CouplingMedium),
+        ("BeamStopperPosition", &tags::BeamStopperPosition),
+        ("DistanceSourceToEntrance", &tags::DistanceSourcetoEntrance),
+        ("NumberOfStages", &tags::NumberofStages),
+        ("LinearityCorrectionTechnique", &tags::LinearityCorrectionTechnique),
+        ("ScheduledDischargeTime", &tags::ScheduledDischargeTime),
+        ("NumberOfAssessmentObservations", &tags::NumberofAssessmentObservations),
+        ("BridgeResistors", &tags::BridgeResistors),
+        ("QualityControlSubject", &tags::QualityControlSubject),
+        ("InterpretationTypeID", &tags::InterpretationTypeID),
+        ("TableTopRollRotationDirection", &tags::TableTopRollRotationDirection),
+        ("ItineraryID", &tags::ItineraryID),
+        ("TotalProcessingTime", &tags::TotalProcessingTime),
+        ("ContrastAdministrationProfileSequence", &tags::ContrastAdministrationProfileSequence),
+        ("ColorSpace", &tags::ColorSpace),
+        ("MappingResourceUID", &tags::MappingResourceUID),
+        ("PatientSizeCodeSequence", &tags::PatientsSizeCodeSequence),
+        ("MetersetRate", &tags::MetersetRate),
+        ("CatchTrialsDataFlag", &tags::CatchTrialsDataFlag),
+        ("ContainerComponentID", &tags::ContainerComponentID),
+        ("RadiationMachineSAD", &tags::RadiationMachineSAD),
+        ("ChannelDeliveryOrderSequence", &tags::ChannelDeliveryOrderSequence),
+        ("XAXRFFrameCharacteristicsSequence", &tags::XAXRFFrameCharacteristicsSequence),
+        ("Priority", &tags::Priority),
+        ("PresentationDisplayCollectionUID", &tags::PresentationDisplayCollectionUID),
+        ("PETFrameCorrectionFactorsSequence", &tags::PETFrameCorrectionFactorsSequence),
+        ("CountLossNormalizationCorrected", &tags::CountLossNormalizationCorrected),
+        ("DVHSequence", &tags::DVHSequence),
+        ("NumberOfRotations", &tags::NumberofRotations),
+        ("XRayGeometrySequence", &tags::XRayGeometrySequence),
+        ("StudiesContainingOtherReferencedInstancesSequence", &tags::StudiesContainingOtherReferencedInstancesSequence),
+        ("PixelDataAreaRotationAngleRelativeToFOV", &tags::PixelDataAreaRotationAngleRelativeToFOV),
+        ("RespiratoryCyclePosition", &tags::RespiratoryCyclePosition),
+        ("HistogramExplanation", &tags::HistogramExplanation),
+        ("TransformOrderOfAxes", &tags::TransformOrderofAxes),
+        ("InstitutionalDepartmentTypeCodeSequence", &tags::InstitutionalDepartmentTypeCodeSequence),
+        ("MeasurementAutomationTrial", &tags::MeasurementAutomation_Trial),
+        ("EvaluatorNumber", &tags::EvaluatorNumber),
+        ("InventoryInstanceDescription", &tags::InventoryInstanceDescription),
+        ("DecompositionMethod", &tags::DecompositionMethod),
+        ("TMLinePositionY1", &tags::TMLinePositionY1),
+        ("RecognizableVisualFeatures", &tags::RecognizableVisualFeatures),
+        ("IlluminatorTypeCodeSequence", &tags::IlluminatorTypeCodeSequence),
+        ("TypeOfSynchronization", &tags::TypeofSynchronization),
+        ("RadiusAlongWedge", &tags::RadiusAlongtheWedge),
+        ("DisplacementReferenceLabel", &tags::DisplacementReferenceLabel),
+        ("IssuerOfAdmissionID", &tags::IssuerofAdmissionID),
+        ("NumberOfZeroFills", &tags::NumberofZeroFills),
+        ("ImagesInStudy", &tags::ImagesinStudy),
+        ("TestPointNormalsDataFlag", &tags::TestPointNormalsDataFlag),
+        ("AxialAcceptance", &tags::AxialAcceptance),
+        ("CornealVertexLocation", &tags::CornealVertexLocation),
+        ("SOPAuthorizationDateTime", &tags::SOPAuthorizationDateTime),
+        ("WaveformPaddingValue", &tags::WaveformPaddingValue),
+        ("CalculationComment", &tags::CalculationComment),
+        ("DeviceMotionParameterCodeSequence", &tags::DeviceMotionParameterCodeSequence),
+        ("ReferencedStopControlPointIndex", &tags::ReferencedStopControlPointIndex),
+        ("IndexNormalsFlag", &tags::IndexNormalsFlag),
+        ("ContextIdentifier", &tags::ContextIdentifier),
+        ("ObservationNumber", &tags::ObservationNumber),
+        ("AcquisitionTerminationConditionData", &tags::AcquisitionTerminationConditionData),
+        ("RangeShifterWaterEquivalentThickness", &tags::RangeShifterWaterEquivalentThickness),
+        ("ASLBolusCutoffTimingSequence", &tags::ASLBolusCutoffTimingSequence),
+        ("ParallelReductionFactorSecondInPlane", &tags::ParallelReductionFactorSecondInplane),
+        ("ConstraintValueSequence", &tags::ConstraintValueSequence),
+        ("FontNameType", &tags::FontNameType),
+        ("SliceThickness", &tags::SliceThickness),
+        ("TransactionStatusComment", &tags::TransactionStatusComment),
+        ("AbsorbedDoseToMetersetRatio", &tags::AbsorbedDosetoMetersetRatio),
+        ("WaveformAnnotationSequence", &tags::WaveformAnnotationSequence),
+        ("SetupDeviceParameter", &tags::SetupDeviceParameter),
+        ("VolumeToTableMappingMatrix", &tags::VolumetoTableMappingMatrix),
+        ("GeneralPurposeScheduledProcedureStepStatus", &tags::GeneralPurposeScheduledProcedureStepStatus),
+        ("IsocenterToRangeShifterDistance", &tags::IsocentertoRangeShifterDistance),
+        ("CompensatorMillingToolDiameter", &tags::CompensatorMillingToolDiameter),
+        ("SpecificAbsorptionRateDefinition", &tags::SpecificAbsorptionRateDefinition),
+        ("ImplantType", &tags::ImplantType),
+        ("DoseType", &tags::DoseType),
+        ("PyramidUID", &tags::PyramidUID),
+        ("ShortTermFluctuationProbability", &tags::ShortTermFluctuationProbability),
+        ("ChannelDisplaySequence", &tags::ChannelDisplaySequence),
+        ("AcousticCouplingMediumSoundSpeed", &tags::AcousticCouplingMediumSoundSpeed),
+        ("PatientOrientationCodeSequence", &tags::PatientOrientationCodeSequence),
+        ("UltrasoundSelectedOphthalmicAxialLengthSequence", &tags::UltrasoundSelectedOphthalmicAxialLengthSequence),
+        ("XRayDetectorIndex", &tags::XRayDetectorIndex),
+        ("ContainerComponentThickness", &tags::ContainerComponentThickness),
+        ("OriginalAttributesSequence", &tags::OriginalAttributesSequence),
+        ("CalculatedDoseReferenceDoseValue", &tags::CalculatedDoseReferenceDoseValue),
+        ("AlternateBeamDoseType", &tags::AlternateBeamDoseType),
+        ("InterpolationType", &tags::InterpolationType),
+        ("AssessmentSummary", &tags::AssessmentSummary),
+        ("BreedRegistrationNumber", &tags::BreedRegistrationNumber),
+        ("VerificationFlag", &tags::VerificationFlag),
+        ("FrameDetectorParametersSequence", &tags::FrameDetectorParametersSequence),
+        ("AfterloaderChannelID", &tags::AfterloaderChannelID),
+        ("PresentationCreationTime", &tags::PresentationCreationTime),
+        ("MeasuredCharacteristics", &tags::MeasuredCharacteristics),
+        ("ReferencedFractionNumber", &tags::ReferencedFractionNumber),
+        ("InstitutionAddress", &tags::InstitutionAddress),
+        ("DisplayedZValue", &tags::DisplayedZValue),
+        ("NetworkID", &tags::NetworkID),
+        ("ImagesInSeries", &tags::ImagesinSeries),
+        ("RegistrationTypeCodeSequence", &tags::RegistrationTypeCodeSequence),
+        ("Polarity", &tags::Polarity),
+        ("ReferencedImageOverlayBoxSequence", &tags::ReferencedImageOverlayBoxSequence),
+        ("AnimationCurveSequence", &tags::AnimationCurveSequence),
+        ("DoseValuePurpose", &tags::DoseValuePurpose),
+        ("BlockSlabSequence", &tags::BlockSlabSequence),
+        ("SpatialResolution", &tags::SpatialResolution),
+        ("TargetUnderdoseVolumeFraction", &tags::TargetUnderdoseVolumeFraction),
+        ("CTAcquisitionTypeSequence", &tags::CTAcquisitionTypeSequence),
+        ("CylinderLensPower", &tags::CylinderLensPower),
+        ("PhotonEnergy", &tags::PhotonEnergy),
+        ("ApplicableFrameRange", &tags::ApplicableFrameRange),
+        ("PatientAlternativeCalendar", &tags::PatientsAlternativeCalendar),
+        ("ReferenceImageNumber", &tags::ReferenceImageNumber),
+        ("ImplantTemplateGroupVersion", &tags::ImplantTemplateGroupVersion),
+        ("EnhancedRTBeamLimitingDeviceDefinitionFlag", &tags::EnhancedRTBeamLimitingDeviceDefinitionFlag),
+        ("PrimaryPromptsCountsAccumulated", &tags::PrimaryPromptsCountsAccumulated),
+        ("FixationLightPolarAngleTolerance", &tags::FixationLightPolarAngleTolerance),
+        ("RTVSourceIdentifier", &tags::RTVSourceIdentifier),
+        ("PrintPriority", &tags::PrintPriority),
+        ("ViewCodeSequence", &tags::ViewCodeSequence),
+        ("TMLinePositionX1", &tags::TMLinePositionX1),
+        ("Status", &tags::Status),
+        ("SecondaryPositionerIncrementSign", &tags::SecondaryPositionerIncrementSign),
+        ("ImplantTemplateGroupTargetAnatomySequence", &tags::ImplantTemplateGroupTargetAnatomySequence),
+        ("EnergyUnitCodeSequence", &tags::EnergyUnitCodeSequence),
+        ("EffectiveDoseCalculationMethodCategoryCodeSequence", &tags::EffectiveDoseCalculationMethodCategoryCodeSequence),
+        ("OverallTemplateSpatialTolerance", &tags::OverallTemplateSpatialTolerance),
+        ("ReferencedDirectSegmentInstanceSequence", &tags::ReferencedDirectSegmentInstanceSequence),
+        ("ParticipationDateTime", &tags::ParticipationDateTime),
+        ("TopicAuthor", &tags::TopicAuthor),
+        ("RequestedImageSize", &tags::RequestedImageSize),
+        ("GainControl", &tags::GainControl),
+        ("CalibrationNotes", &tags::CalibrationNotes),
+        ("AvailableTransferSyntaxUID", &tags::AvailableTransferSyntaxUID),
+        ("NominalMinEnergy", &tags::NominalMinEnergy),
+        ("HistogramSequence", &tags::HistogramSequence),
+        ("CombinationSegmentReferenceSequence", &tags::CombinationSegmentReferenceSequence),
+        ("NumberOfRemainingSuboperations", &tags::NumberofRemainingSuboperations),
+        ("ASLTechniqueDescription", &tags::ASLTechniqueDescription),
+        ("MaxDensity", &tags::MaxDensity),
+        ("ProposedStudySequence", &tags::ProposedStudySequence),
+        ("DeviceSettingDescription", &tags::DeviceSettingDescription),
+        ("CommonZCoordinateValue", &tags::CommonZCoordinateValue),
+        ("SmokingStatus", &tags::SmokingStatus),
+        ("ShutterOverlayGroup", &tags::ShutterOverlayGroup),
+        ("DataElementName", &tags::DataElementName),
+        ("ReferenceBasisCodeSequence", &tags::ReferenceBasisCodeSequence),
+        ("CTExposureSequence", &tags::CTExposureSequence),
+        ("AcquiredSubtractionMaskFlag", &tags::AcquiredSubtractionMaskFlag),
+        ("FixationDevicePitchAngle", &tags::FixationDevicePitchAngle),
+        ("LUTData", &tags::LUTData),
+        ("RetainInstances", &tags::RetainInstances),
+        ("WedgeThinEdgePosition", &tags::WedgeThinEdgePosition),
+        ("DetectorLinesOfResponseUsed", &tags::DetectorLinesofResponseUsed),
+        ("ExpirationDateTime", &tags::ExpirationDateTime),
+        ("PlacerOrderNumberProcedure", &tags::PlacerOrderNumberProcedure),
+        ("ExposureControlSensingRegionLowerHorizontalEdge", &tags::ExposureControlSensingRegionLowerHorizontalEdge),
+        ("ContraindicationsCodeSequence", &tags::ContraindicationsCodeSequence),
+        ("StartCumulativeMeterset", &tags::StartCumulativeMeterset),
+        ("TreatmentSessionBeamSequence", &tags::TreatmentSessionBeamSequence),
+        ("PulserEquipmentSequence", &tags::PulserEquipmentSequence),
+        ("ATDAssessmentSequence", &tags::ATDAssessmentSequence),
+        ("HangingProtocolCreator", &tags::HangingProtocolCreator),
+        ("BrachyAccessoryDeviceName", &tags::BrachyAccessoryDeviceName),
+        ("SecondaryPositionerScanArc", &tags::SecondaryPositionerScanArc),
+        ("ShadowColorCIELabValue", &tags::ShadowColorCIELabValue),
+        ("CalibrationTime", &tags::CalibrationTime),
+        ("WedgeName", &tags::WedgeName),
+        ("PreAmplifierSettingsSequence", &tags::PreAmplifierSettingsSequence),
+        ("DefaultMagnificationType", &tags::DefaultMagnificationType),
+        ("ZonalMapLocation", &tags::ZonalMapLocation),
+        ("SensitivityType", &tags::SensitivityType),
+        ("RespiratorySignalSourceID", &tags::RespiratorySignalSourceID),
+        ("RectificationType", &tags::RectificationType),
+        ("DosimetricObjectiveWeight", &tags::DosimetricObjectiveWeight),
+        ("ReferencedGeneralPurposeScheduledProcedureStepSequence", &tags::ReferencedGeneralPurposeScheduledProcedureStepSequence),
+        ("RedPaletteColorLookupTableDescriptor", &tags::RedPaletteColorLookupTableDescriptor),
+        ("AxialDetectorDimension", &tags::AxialDetectorDimension),
+        ("MultipleCopiesFlag", &tags::MultipleCopiesFlag),
+        ("NumberOfFocalPlanes", &tags::NumberofFocalPlanes),
+        ("ProbeCenterLocationX", &tags::ProbeCenterLocationX),
+        ("DeadTimeFactor", &tags::DeadTimeFactor),
+        ("LensSpecification", &tags::LensSpecification),
+        ("ReferencedDosimetricObjectiveUID", &tags::ReferencedDosimetricObjectiveUID),
+        ("TemporalRangeType", &tags::TemporalRangeType),
+        ("ImmersionMedia", &tags::ImmersionMedia),
+        ("VisualAcuityTypeCodeSequence", &tags::VisualAcuityTypeCodeSequence),
+        ("PatientOrientation", &tags::PatientOrientation),
+        ("HighRRValue", &tags::HighRRValue),
+        ("RegistrationToLocalizerSequence", &tags::RegistrationtoLocalizerSequence),
+        ("ExpectedInVivoMeasurementValueIndex", &tags::ExpectedInVivoMeasurementValueIndex),
+        ("ComponentID", &tags::ComponentID),
+        ("PriorRecordKey", &tags::PriorRecordKey),
+        ("OECFColumnNames", &tags::OECFColumnNames),
+        ("RegionFlags", &tags::RegionFlags),
+        ("SignalDomainRows", &tags::SignalDomainRows),
+        ("CornealSize", &tags::CornealSize),
+        ("GeneralPurposeScheduledProcedureStepPriority", &tags::GeneralPurposeScheduledProcedureStepPriority),
+        ("HighDoseTechniqueType", &tags::HighDoseTechniqueType),
+        ("MAC", &tags::MAC),
+        ("SourceFrameOfReferenceUID", &tags::SourceFrameofReferenceUID),
+        ("GridFrameOffsetVector", &tags::GridFrameOffsetVector),
+        ("Signature", &tags::Signature),
+        ("TransformationMethodCodeSequence", &tags::TransformationMethodCodeSequence),
+        ("TemporalPositionIdentifier", &tags::TemporalPositionIdentifier),
+        ("ReceiveProbeSequence", &tags::ReceiveProbeSequence),
+        ("NormalizationFactorFormat", &tags::NormalizationFactorFormat),
+        ("CompensatorMapOrientation", &tags::CompensatorMapOrientation),
+        ("DisplaySetVerticalJustification", &tags::DisplaySetVerticalJustification),
+        ("PixelOriginInterpretation", &tags::PixelOriginInterpretation),
+        ("DataBlock", &tags::DataBlock),
+        ("ChannelMaximumValue", &tags::ChannelMaximumValue),
+        ("RTBeamLimitingDeviceProximalDistance", &tags::RTBeamLimitingDeviceProximalDistance),
+        ("RecordedRTControlPointDateTime", &tags::RecordedRTControlPointDateTime),
+        ("CalculationCommentSequence", &tags::CalculationCommentSequence),
+        ("RangeModulatorSequence", &tags::RangeModulatorSequence),
+        ("XAAcquisitionPhaseDetailsSequence", &tags::XAAcquisitionPhaseDetailsSequence),
+        ("BodyPartThickness", &tags::BodyPartThickness),
+        ("RadiationChargeState", &tags::RadiationChargeState),
+        ("RangeModulatorGatingStopValue", &tags::RangeModulatorGatingStopValue),
+        ("NonUniformRadialSamplingCorrected", &tags::NonuniformRadialSamplingCorrected),
+        ("OmittedChannelSequence", &tags::OmittedChannelSequence),
+        ("ReferencedRefractiveMeasurementsSequence", &tags::ReferencedRefractiveMeasurementsSequence),
+        ("PulseNumber", &tags::PulseNumber),
+        ("AssigningAgencyOrDepartmentCodeSequence", &tags::AssigningAgencyorDepartmentCodeSequence),
+        ("ComponentSequence", &tags::ComponentSequence),
+        ("PhysicianApprovingInterpretation", &tags::PhysicianApprovingInterpretation),
+        ("OrderEnteredBy", &tags::OrderEnteredBy),
+        ("TextString", &tags::TextString),
+        ("NumberOfControlPoints", &tags::NumberofControlPoints),
+        ("TrackingAlgorithmIdentificationSequence", &tags::TrackingAlgorithmIdentificationSequence),
+        ("CoordinateSystemAxisNumber", &tags::CoordinateSystemAxisNumber),
+        ("CompressionSequence", &tags::CompressionSequence),
+        ("RadiationAtomicNumber", &tags::RadiationAtomicNumber),
+        ("AcquisitionStartCondition", &tags::AcquisitionStartCondition),
+        ("PatientSupportPositionDeviceToleranceSequence", &tags::PatientSupportPositionDeviceToleranceSequence),
+        ("OphthalmicAxialLengthMeasurementsType", &tags::OphthalmicAxialLengthMeasurementsType),
+        ("ImplantTemplateVersion", &tags::ImplantTemplateVersion),
+        ("SelectorSequencePointerPrivateCreator", &tags::SelectorSequencePointerPrivateCreator),
+        ("StartRespiratoryTriggerCountThreshold", &tags::StartRespiratoryTriggerCountThreshold),
+        ("BreastSupportIsocenterSecondaryAngle", &tags::BreastSupportIsocenterSecondaryAngle),
+        ("ProcedureExpirationDate", &tags::ProcedureExpirationDate),
+        ("ImpedanceValue", &tags::ImpedanceValue),
+        ("AcrossScanSpatialResolution", &tags::AcrossscanSpatialResolution),
+        ("InterlockOriginatingDeviceSequence", &tags::InterlockOriginatingDeviceSequence),
+        ("PresentationAnimationStyle", &tags::PresentationAnimationStyle),
+        ("EnergyWindowInformationSequence", &tags::EnergyWindowInformationSequence),
+        ("ATDAssessmentProbability", &tags::ATDAssessmentProbability),
+        ("MatingFeatureDegreeOfFreedomSequence", &tags::MatingFeatureDegreeofFreedomSequence),
+        ("DisplayFilterPercentage", &tags::DisplayFilterPercentage),
+        ("CorrectedImage", &tags::CorrectedImage),
+        ("ScheduledDischargeDate", &tags::ScheduledDischargeDate),
+        ("DiffusionBMatrixSequence", &tags::DiffusionbmatrixSequence),
+        ("AnalogFilterRollOff", &tags::AnalogFilterRollOff),
+        ("AnnotationPropertyTypeCodeSequence", &tags::AnnotationPropertyTypeCodeSequence),
+        ("SourceAcquisitionBeamNumber", &tags::SourceAcquisitionBeamNumber),
+        ("ScanSpotSizesDelivered", &tags::ScanSpotSizesDelivered),
+        ("TargetLuminanceCharacteristicsSequence", &tags::TargetLuminanceCharacteristicsSequence),
+        ("WindowCenter", &tags::WindowCenter),
+        ("RequestedLateralityCodeSequence", &tags::RequestedLateralityCodeSequence),
+        ("ExcessiveFalseNegatives", &tags::ExcessiveFalseNegatives),
+        ("PatientSetupLabel", &tags::PatientSetupLabel),
+        ("SetupDeviceLabel", &tags::SetupDeviceLabel),
+        ("ContrastBolusInjectionDelay", &tags::ContrastBolusInjectionDelay),
+        ("OtherPupillaryDistance", &tags::OtherPupillaryDistance),
+        ("TargetPositionReferenceIndicator", &tags::TargetPositionReferenceIndicator),
+        ("TextColorCIELabValue", &tags::TextColorCIELabValue),
+        ("CenterOfPTO", &tags::CenterofPTO),
+        ("ConstituentConceptualVolumeUID", &tags::ConstituentConceptualVolumeUID),
+        ("VisualFieldGlobalResultsIndexSequence", &tags::VisualFieldGlobalResultsIndexSequence),
+        ("OECFRows", &tags::OECFRows),
+        ("MakerNote", &tags::MakerNote),
+        ("VOILUTSequence", &tags::VOILUTSequence),
+        ("SOPClassUID", &tags::SOPClassUID),
+        ("ReferencedNonImageCompositeSOPInstanceSequence", &tags::ReferencedNonImageCompositeSOPInstanceSequence),
+        ("ScanLength", &tags::ScanLength),
+        ("BlockedPixels", &tags::BlockedPixels),
+        ("RetestSensitivityValue", &tags::RetestSensitivityValue),
+        ("ReconstructionAngle", &tags::ReconstructionAngle),
+        ("UnspecifiedLateralityLensSequence", &tags::UnspecifiedLateralityLensSequence),
+        ("RTBeamLimitingDeviceOffset", &tags::RTBeamLimitingDeviceOffset),
+        ("RadiationDoseSequence", &tags::RadiationDoseSequence),
+        ("SurfaceAsymmetryIndex", &tags::SurfaceAsymmetryIndex),
+        ("SelectorOVValue", &tags::SelectorOVValue),
+        ("TableTopLateralPositionTolerance", &tags::TableTopLateralPositionTolerance),
+        ("CommandGroupLength", &tags::CommandGroupLength),
+        ("PerformedProcedureStepDiscontinuationReasonCodeSequence", &tags::PerformedProcedureStepDiscontinuationReasonCodeSequence),
+        ("ParameterValueNumber", &tags::ParameterValueNumber),
+        ("StimulusArea", &tags::StimulusArea),
+        ("MPRThicknessType", &tags::MPRThicknessType),
+        ("CornealTopographyMappingNormalsSequence", &tags::CornealTopographyMappingNormalsSequence),
+        ("Humidity", &tags::Humidity),
+        ("IonControlPointSequence", &tags::IonControlPointSequence),
+        ("IssueDateOfImagingServiceRequest", &tags::IssueDateofImagingServiceRequest),
+        ("PhysiciansOfRecord", &tags::PhysiciansofRecord),
+        ("PlacerOrderNumberImagingServiceRequestRetired", &tags::PlacerOrderNumberImagingServiceRequest_Retired),
+        ("DimensionOrganizationUID", &tags::DimensionOrganizationUID),
+        ("RequestedProcedureDescriptionTrial", &tags::RequestedProcedureDescription_Trial),
+        ("TotalPixelMatrixColumns", &tags::TotalPixelMatrixColumns),
+        ("FixationDeviceSequence", &tags::FixationDeviceSequence),
+        ("SurfaceMeshZPixelOffset", &tags::SurfaceMeshZPixelOffset),
+        ("RouteSegmentEndLocationID", &tags::RouteSegmentEndLocationID),
+        ("ReferencedRadiationGenerationModeIndex", &tags::ReferencedRadiationGenerationModeIndex),
+        ("FixationCheckedQuantity", &tags::FixationCheckedQuantity),
+        ("ImageScaleRepresentation", &tags::ImageScaleRepresentation),
+        ("ApprovalStatusFurtherDescription", &tags::ApprovalStatusFurtherDescription),
+        ("DistanceBetweenFocalPlanes", &tags::DistanceBetweenFocalPlanes),
+        ("DosimetricObjectivePurpose", &tags::DosimetricObjectivePurpose),
+        ("IntraocularLensCalculationsRightEyeSequence", &tags::IntraocularLensCalculationsRightEyeSequence),
+        ("RequestingServiceCodeSequence", &tags::RequestingServiceCodeSequence),
+        ("OverlayDate", &tags::OverlayDate),
+        ("OtherStudyNumbers", &tags::OtherStudyNumbers),
+        ("SegmentsOverlap", &tags::SegmentsOverlap),
+        ("SegmentedRedPaletteColorLookupTableData", &tags::SegmentedRedPaletteColorLookupTableData),
+        ("SourceType", &tags::SourceType),
+        ("ROIPhysicalPropertiesSequence", &tags::ROIPhysicalPropertiesSequence),
+        ("AttenuationCorrectionSource", &tags::AttenuationCorrectionSource),
+        ("ImageDataTypeSequence", &tags::ImageDataTypeSequence),
+        ("HighBit", &tags::HighBit),
+        ("ReferencedMeasuredDoseReferenceSequence", &tags::ReferencedMeasuredDoseReferenceSequence),
+        ("ASLMidSlabPosition", &tags::ASLMidSlabPosition),
+        ("FrameType", &tags::FrameType),
+        ("ExposureMode", &tags::ExposureMode),
+        ("RepositoryUniqueID", &tags::RepositoryUniqueID),
+        ("SegmentSurfaceGenerationAlgorithmIdentificationSequence", &tags::SegmentSurfaceGenerationAlgorithmIdentificationSequence),
+        ("SelectorUNValue", &tags::SelectorUNValue),
+        ("PixelRepresentation", &tags::PixelRepresentation),
+        ("NavigationDisplaySet", &tags::NavigationDisplaySet),
+        ("PredictedRefractiveError", &tags::PredictedRefractiveError),
+        ("HangingProtocolDefinitionSequence", &tags::HangingProtocolDefinitionSequence),
+        ("PaletteColorLookupTableUID", &tags::PaletteColorLookupTableUID),
+        ("GPSAreaInformation", &tags::GPSAreaInformation),
+        ("NumberOfStudyRelatedInstances", &tags::NumberofStudyRelatedInstances),
+        ("Location", &tags::Location),
+        ("StudyCompletionDate", &tags::StudyCompletionDate),
+        ("UnifiedProcedureStepListStatus", &tags::UnifiedProcedureStepListStatus),
+        ("RenderedImageReferenceSequence", &tags::RenderedImageReferenceSequence),
+        ("CalibrationProcedure", &tags::CalibrationProcedure),
+        ("DischargeDate", &tags::DischargeDate),
+        ("SourceOfCornealSizeDataCodeSequence", &tags::SourceofCornealSizeDataCodeSequence),
+        ("ChannelThreshold", &tags::ChannelThreshold),
+        ("CornealTopographyMapTypeCodeSequence", &tags::CornealTopographyMapTypeCodeSequence),
+        ("EthicsCommitteeApprovalEffectivenessEndDate", &tags::EthicsCommitteeApprovalEffectivenessEndDate),
+        ("ExcitationPulseDuration", &tags::ExcitationPulseDuration),
+        ("SelectorSVValue", &tags::SelectorSVValue),
+        ("SurfaceModelDescriptionSequence", &tags::SurfaceModelDescriptionSequence),
+        ("ManufacturerDeviceIdentifier", &tags::ManufacturersDeviceIdentifier),
+        ("SpectroscopyData", &tags::SpectroscopyData),
+        ("GantryDetectorTilt", &tags::GantryDetectorTilt),
+        ("CoincidenceWindowWidth", &tags::CoincidenceWindowWidth),
+        ("IntervalsRejected", &tags::IntervalsRejected),
+        ("IsocenterToGeneralAccessoryDistance", &tags::IsocentertoGeneralAccessoryDistance),
+        ("BlockEdgeDataSequence", &tags::BlockEdgeDataSequence),
+        ("SourceToExternalContourDistance", &tags::SourcetoExternalContourDistance),
+        ("SpecifiedMeterset", &tags::SpecifiedMeterset),
+        ("DeviceMotionObservationMode", &tags::DeviceMotionObservationMode),
+        ("ROIDerivationAlgorithmIdentificationSequence", &tags::ROIDerivationAlgorithmIdentificationSequence),
+        ("DelineatedRadiationFieldSize", &tags::DelineatedRadiationFieldSize),
+        ("DiffusionGradientOrientation", &tags::DiffusionGradientOrientation),
+        ("MonoenergeticEnergyEquivalent", &tags::MonoenergeticEnergyEquivalent),
+        ("LineThickness", &tags::LineThickness),
+        ("BeamSpotSize", &tags::BeamSpotSize),
+        ("AcquisitionTimeSynchronized", &tags::AcquisitionTimeSynchronized),
+        ("OutlineShapeType", &tags::OutlineShapeType),
+        ("ModalityLUTType", &tags::ModalityLUTType),
+        ("MatingFeatureSetLabel", &tags::MatingFeatureSetLabel),
+        ("ContrastBolusAgent", &tags::ContrastBolusAgent),
+        ("VerticalPrismBase", &tags::VerticalPrismBase),
+        ("ExposuresOnPlate", &tags::ExposuresonPlate),
+        ("PerformedProcedureStepStatus", &tags::PerformedProcedureStepStatus),
+        ("PixelIntensityRelationshipLUTSequence", &tags::PixelIntensityRelationshipLUTSequence),
+        ("DimensionIndexValues", &tags::DimensionIndexValues),
+        ("TargetPrescriptionDose", &tags::TargetPrescriptionDose),
+        ("IntendedDeliveryDuration", &tags::IntendedDeliveryDuration),
+        ("RangeOfFreedom", &tags::RangeofFreedom),
+        ("RTTreatmentFractionCompletionStatus", &tags::RTTreatmentFractionCompletionStatus),
+        ("PreferredPlaybackSequencing", &tags::PreferredPlaybackSequencing),
+        ("RescaleIntercept", &tags::RescaleIntercept),
+        ("BscanCycleTimeVector", &tags::BscanCycleTimeVector),
+        ("ManufacturerDeviceClassUID", &tags::ManufacturersDeviceClassUID),
+        ("ReplacedImplantAssemblyTemplateSequence", &tags::ReplacedImplantAssemblyTemplateSequence),
+        ("IsocenterToRangeModulatorDistance", &tags::IsocentertoRangeModulatorDistance),
+        ("SOPInstanceUID", &tags::SOPInstanceUID),
+        ("EffectiveDateTime", &tags::EffectiveDateTime),
+        ("DiffusionBValueXZ", &tags::DiffusionbvalueXZ),
+        ("StudyAccessEndPointsSequence", &tags::StudyAccessEndPointsSequence),
+        ("PositionerPrimaryAngleDirection", &tags::PositionerPrimaryAngleDirection),
+        ("RangeShifterType", &tags::RangeShifterType),
+        ("BeamDoseType", &tags::BeamDoseType),
+        ("PulserSettingsSequence", &tags::PulserSettingsSequence),
+        ("DriveType", &tags::DriveType),
+        ("ProtocolName", &tags::ProtocolName),
+        ("AutorefractionRightEyeSequence", &tags::AutorefractionRightEyeSequence),
+        ("ParticipationType", &tags::ParticipationType),
+        ("PatientBirthDateInAlternativeCalendar", &tags::PatientsBirthDateinAlternativeCalendar),
+        ("DeviceMotionControlSequence", &tags::DeviceMotionControlSequence),
+        ("AddIntermediateSequence", &tags::AddIntermediateSequence),
+        ("WedgeMaterial", &tags::WedgeMaterial),
+        ("RequestedImageSizeFlag", &tags::RequestedImageSizeFlag),
+        ("ThreatROIBitmap", &tags::ThreatROIBitmap),
+        ("RecordInUseFlag", &tags::RecordInuseFlag),
+        ("MultiplexedAudioChannelsDescriptionCodeSequence", &tags::MultiplexedAudioChannelsDescriptionCodeSequence),
+        ("PyramidLabel", &tags::PyramidLabel),
+        ("DoseValuesSequence", &tags::DoseValuesSequence),
+        ("PreserveCompositeInstancesAfterMediaCreation", &tags::PreserveCompositeInstancesAfterMediaCreation),
+        ("CalciumScoringMassFactorDevice", &tags::CalciumScoringMassFactorDevice),
+        ("LocalDeviationProbabilityNormalsFlag", &tags::LocalDeviationProbabilityNormalsFlag),
+        ("MultiCoilDefinitionSequence", &tags::MultiCoilDefinitionSequence),
+        ("TargetMaximumLuminance", &tags::TargetMaximumLuminance),
+        ("AddPower", &tags::AddPower),
+        ("CTDIvolNotificationTrigger", &tags::CTDIvolNotificationTrigger),
+        ("LateralSpreadingDeviceSequence", &tags::LateralSpreadingDeviceSequence),
+        ("NumberOfBoluses", &tags::NumberofBoluses),
+        ("LateralSpreadingDeviceNumber", &tags::LateralSpreadingDeviceNumber),
+        ("IlluminationTranslationFlag", &tags::IlluminationTranslationFlag),
+        ("PointCoordinatesData", &tags::PointCoordinatesData),
+        ("OperatorIdentificationSequence", &tags::OperatorIdentificationSequence),
+        ("DecayCorrected", &tags::DecayCorrected),
+        ("ReferencedTreatmentPositionIndex", &tags::ReferencedTreatmentPositionIndex),
+        ("DistributionName", &tags::DistributionName),
+        ("FNumber", &tags::FNumber),
+        ("IlluminationColorCodeSequence", &tags::IlluminationColorCodeSequence),
+        ("DigitalSignatureDateTime", &tags::DigitalSignatureDateTime),
+        ("EquivalentConceptualVolumeInstanceReferenceSequence", &tags::EquivalentConceptualVolumeInstanceReferenceSequence),
+        ("DeformableRegistrationSequence", &tags::DeformableRegistrationSequence),
+        ("CADFileFormat", &tags::CADFileFormat),
+        ("OphthalmicAxialLengthMeasurementsSegmentNameCodeSequence", &tags::OphthalmicAxialLengthMeasurementsSegmentNameCodeSequence),
+        ("AcquisitionDate", &tags::AcquisitionDate),
+        ("BrachyTaskSequence", &tags::BrachyTaskSequence),
+        ("BlendingInputNumber", &tags::BlendingInputNumber),
+        ("PositionerSecondaryAngle", &tags::PositionerSecondaryAngle),
+        ("ContrastBolusIngredient", &tags::ContrastBolusIngredient),
+        ("ATDAssessmentFlag", &tags::ATDAssessmentFlag),
+        ("DoubleFloatRealWorldValueLastValueMapped", &tags::DoubleFloatRealWorldValueLastValueMapped),
+        ("SoundSpeedCorrectionMechanismCodeSequence", &tags::SoundSpeedCorrectionMechanismCodeSequence),
+        ("ElementDimensionB", &tags::ElementDimensionB),
+        ("TherapyType", &tags::TherapyType),
+        ("TransformDescription", &tags::TransformDescription),
+        ("ConsentForDistributionFlag", &tags::ConsentforDistributionFlag),
+        ("ScreeningBaselineValue", &tags::ScreeningBaselineValue),
+        ("CalculatedTargetPosition", &tags::CalculatedTargetPosition),
+        ("IssuerOfAdmissionIDSequence", &tags::IssuerofAdmissionIDSequence),
+        ("DigitalSignaturePurposeCodeSequence", &tags::DigitalSignaturePurposeCodeSequence),
+        ("CoordinateSystemTransformTranslationMatrix", &tags::CoordinateSystemTransformTranslationMatrix),
+        ("TherapyDescription", &tags::TherapyDescription),
+        ("DecompositionMaterialSequence", &tags::DecompositionMaterialSequence),
+        ("TreatmentDeliveryContinuationFlag", &tags::TreatmentDeliveryContinuationFlag),
+        ("PatientSetupSequence", &tags::PatientSetupSequence),
+        ("EffectiveDoseCalculationMethodDescription", &tags::EffectiveDoseCalculationMethodDescription),
+        ("InversionTimes", &tags::InversionTimes),
+        ("TreatmentToleranceViolationSequence", &tags::TreatmentToleranceViolationSequence),
+        ("DoubleExposureMetersetTrial", &tags::DoubleExposureMeterset_Trial),
+        ("DataFrameAssignmentSequence", &tags::DataFrameAssignmentSequence),
+        ("Damping", &tags::Damping),
+        ("PresentationPixelAspectRatio", &tags::PresentationPixelAspectRatio),
+        ("AllowLossyCompression", &tags::AllowLossyCompression),
+        ("VariableModalityLUTSequence", &tags::VariableModalityLUTSequence),
+        ("SecondaryReviewTime", &tags::SecondaryReviewTime),
+        ("MRAcquisitionPhaseEncodingStepsOutOfPlane", &tags::MRAcquisitionPhaseEncodingStepsoutofplane),
+        ("DeformableRegistrationGridSequence", &tags::DeformableRegistrationGridSequence),
+        ("TwoDPlaneCoordinatesSequence", &tags::Tag_2DPlaneCoordinatesSequence),
+        ("SourceEncapsulationNominalTransmission", &tags::SourceEncapsulationNominalTransmission),
+        ("ThreatROIExtents", &tags::ThreatROIExtents),
+        ("CardiacRRIntervalSpecified", &tags::CardiacRRIntervalSpecified),
+        ("AdditionalRTROIIdentificationCodeSequence", &tags::AdditionalRTROIIdentificationCodeSequence),
+        ("FilterLookupTableData", &tags::FilterLookupTableData),
+        ("PositionAcquisitionTemplateName", &tags::PositionAcquisitionTemplateName),
+        ("TreatmentSessionUID", &tags::TreatmentSessionUID),
+        ("BeamDosePointDepth", &tags::BeamDosePointDepth),
+        ("SelectorOWValue", &tags::SelectorOWValue),
+        ("AssigningJurisdictionCodeSequence", &tags::AssigningJurisdictionCodeSequence),
+        ("PTORegionSequence", &tags::PTORegionSequence),
+        ("ProcedureStepProgressInformationSequence", &tags::ProcedureStepProgressInformationSequence),
+        ("PrivateRecordUID", &tags::PrivateRecordUID),
+        ("PertinentResourcesSequence", &tags::PertinentResourcesSequence),
+        ("ExtendedCodeMeaning", &tags::ExtendedCodeMeaning),
+        ("ScanSpotPrescribedIndices", &tags::ScanSpotPrescribedIndices),
+        ("ScheduledProcedureStepLocation", &tags::ScheduledProcedureStepLocation),
+        ("ReportProductionStatusTrial", &tags::ReportProductionStatus_Trial),
+        ("CoordinateSystemAxisValues", &tags::CoordinateSystemAxisValues),
+        ("ImageLocation", &tags::ImageLocation),
+        ("NumberOfGraphicPoints", &tags::NumberofGraphicPoints),
+        ("RecommendedViewingMode", &tags::RecommendedViewingMode),
+        ("DVHNormalizationPoint", &tags::DVHNormalizationPoint),
+        ("TomoLayerHeight", &tags::TomoLayerHeight),
+        ("ThreeDPointCoordinates", &tags::Tag_3DPointCoordinates),
+        ("MetersetRateDelivered", &tags::MetersetRateDelivered),
+        ("ImpedanceMeasurementDateTime", &tags::ImpedanceMeasurementDateTime),
+        ("ReferencedBrachyAccessoryDeviceNumber", &tags::ReferencedBrachyAccessoryDeviceNumber),
+        ("PhysicalDeltaX", &tags::PhysicalDeltaX),
+        ("EndAcquisitionDateTime", &tags::EndAcquisitionDateTime),
+        ("IconImageSequence", &tags::IconImageSequence),
+        ("ExposedArea", &tags::ExposedArea),
+        ("PotentialDiagnosticTasks", &tags::PotentialDiagnosticTasks),
+        ("DetectorGeometry", &tags::DetectorGeometry),
+        ("OriginalImageIdentification", &tags::OriginalImageIdentification),
+        ("FieldOfViewHorizontalFlip", &tags::FieldofViewHorizontalFlip),
+        ("FrameExtractionSequence", &tags::FrameExtractionSequence),
+        ("PositionerPrimaryAngleIncrement", &tags::PositionerPrimaryAngleIncrement),
+        ("FloatPixelPaddingRangeLimit", &tags::FloatPixelPaddingRangeLimit),
+        ("OffsetOfTheNextDirectoryRecord", &tags::OffsetoftheNextDirectoryRecord),
+        ("SelectorSequencePointer", &tags::SelectorSequencePointer),
+        ("ROIInterpreter", &tags::ROIInterpreter),
+        ("BeamOrderIndex", &tags::BeamOrderIndex),
+        ("AlternateBeamDose", &tags::AlternateBeamDose),
+        ("SendingPresentationAddress", &tags::SendingPresentationAddress),
+        ("ReferencedObservationClassTrial", &tags::ReferencedObservationClass_Trial),
+        ("TomoAngle", &tags::TomoAngle),
+        ("OphthalmicFOV", &tags::OphthalmicFOV),
+        ("PredecessorProtocolSequence", &tags::PredecessorProtocolSequence),
+        ("PlanesInAcquisition", &tags::PlanesinAcquisition),
+        ("MRReceiveCoilSequence", &tags::MRReceiveCoilSequence),
+        ("DataElementLabelItemSequence", &tags::DataElementLabelItemSequence),
+        ("WaterEquivalentDiameterCalculationMethodCodeSequence", &tags::WaterEquivalentDiameterCalculationMethodCodeSequence),
+        ("ROIObservationLabel", &tags::ROIObservationLabel),
+        ("FrameAcquisitionDuration", &tags::FrameAcquisitionDuration),
+        ("AcquisitionTaskApplicabilitySequence", &tags::AcquisitionTaskApplicabilitySequence),
+        ("InstitutionalDepartmentName", &tags::InstitutionalDepartmentName),
+        ("ALinePixelSpacing", &tags::AlinePixelSpacing),
+        ("FrameOfReferenceTransformationComment", &tags::FrameofReferenceTransformationComment),
+        ("ReferencedRTTreatmentPreparationSequence", &tags::ReferencedRTTreatmentPreparationSequence),
+        ("ProbeDriveSettingsSequence", &tags::ProbeDriveSettingsSequence),
+        ("NotchFilterFrequency", &tags::NotchFilterFrequency),
+        ("FiducialUID", &tags::FiducialUID),
+        ("ReferencedExpectedInVivoMeasurementValueIndex", &tags::ReferencedExpectedInVivoMeasurementValueIndex),
+        ("DisplaySetHorizontalJustification", &tags::DisplaySetHorizontalJustification),
+        ("ExcessiveFixationLossesDataFlag", &tags::ExcessiveFixationLossesDataFlag),
+        ("ColorImagePrintingFlag", &tags::ColorImagePrintingFlag),
+        ("BarcodeSymbology", &tags::BarcodeSymbology),
+        ("DICOSVersion", &tags::DICOSVersion),
+        ("CustodialOrganizationSequence", &tags::CustodialOrganizationSequence),
+        ("ScheduledStudyStopDate", &tags::ScheduledStudyStopDate),
+        ("PseudoColorType", &tags::PseudoColorType),
+        ("GraphicCoordinatesDataSequence", &tags::GraphicCoordinatesDataSequence),
+        ("AnatomicPerspectiveDescriptionTrial", &tags::AnatomicPerspectiveDescription_Trial),
+        ("AnatomicRegionModifierSequence", &tags::AnatomicRegionModifierSequence),
+        ("PatientIdentityRemoved", &tags::PatientIdentityRemoved),
+        ("HPGLDocumentID", &tags::HPGLDocumentID),
+        ("ASLContext", &tags::ASLContext),
+        ("PointPositionAccuracy", &tags::PointPositionAccuracy),
+        ("SelectorAttribute", &tags::SelectorAttribute),
+        ("MIMETypeOfEncapsulatedDocument", &tags::MIMETypeofEncapsulatedDocument),
+        ("DeviceLabel", &tags::DeviceLabel),
+        ("AlternateContentDescriptionSequence", &tags::AlternateContentDescriptionSequence),
+        ("EffectiveBinEnergy", &tags::EffectiveBinEnergy),
+        ("FileSource", &tags::FileSource),
+        ("AssessmentSummaryDescription", &tags::AssessmentSummaryDescription),
+        ("TransmitCoilName", &tags::TransmitCoilName),
+        ("ConstraintViolationCondition", &tags::ConstraintViolationCondition),
+        ("ImageToEquipmentMappingMatrix", &tags::ImagetoEquipmentMappingMatrix),
+        ("ProcedureLastModifiedDate", &tags::ProcedureLastModifiedDate),
+        ("TomoClass", &tags::TomoClass),
+        ("UserSelectedOffsetX", &tags::UserSelectedOffsetX),
+        ("WADORSRetrievalSequence", &tags::WADORSRetrievalSequence),
+        ("RegisteredLocalizerUnits", &tags::RegisteredLocalizerUnits),
+        ("TableFeedPerRotation", &tags::TableFeedperRotation),
+        ("TotalReferenceAirKerma", &tags::TotalReferenceAirKerma),
+        ("SourceOfAnteriorChamberDepthDataCodeSequence", &tags::SourceofAnteriorChamberDepthDataCodeSequence),
+        ("ComponentReferenceSystem", &tags::ComponentReferenceSystem),
+        ("LargePaletteColorLookupTableUID", &tags::LargePaletteColorLookupTableUID),
+        ("MultienergyCTAcquisitionSequence", &tags::MultienergyCTAcquisitionSequence),
+        ("SmallestPixelValueInSeries", &tags::SmallestPixelValueinSeries),
+        ("AlongScanSpatialResolution", &tags::AlongscanSpatialResolution),
+        ("AnnotationGroupAlgorithmIdentificationSequence", &tags::AnnotationGroupAlgorithmIdentificationSequence),
+        ("InputSequencePositionIndex", &tags::InputSequencePositionIndex),
+        ("CurrentTreatmentStatus", &tags::CurrentTreatmentStatus),
+        ("AdmittingDate", &tags::AdmittingDate),
+        ("AssessmentRequesterSequence", &tags::AssessmentRequesterSequence),
+        ("TubeAngle", &tags::TubeAngle),
+        ("PulseSequenceName", &tags::PulseSequenceName),
+        ("RangeModulatorGatingStartValue", &tags::RangeModulatorGatingStartValue),
+        ("ConceptualVolumeCombinationFlag", &tags::ConceptualVolumeCombinationFlag),
+        ("TimeDomainFiltering", &tags::TimeDomainFiltering),
+        ("TransverseMash", &tags::TransverseMash),
+        ("TotalCompensatorTrayWaterEquivalentThickness", &tags::TotalCompensatorTrayWaterEquivalentThickness),
+        ("SpectralSensitivity", &tags::SpectralSensitivity),
+        ("DoubleFloatPixelPaddingValue", &tags::DoubleFloatPixelPaddingValue),
+        ("GeneralAccessoryNumber", &tags::GeneralAccessoryNumber),
+        ("ExcitationFrequency", &tags::ExcitationFrequency),
+        ("SecondaryCountsAccumulated", &tags::SecondaryCountsAccumulated),
+        ("StructureSetROISequence", &tags::StructureSetROISequence),
+        ("PhotometricInterpretation", &tags::PhotometricInterpretation),
+        ("XRayImageReceptorTranslation", &tags::XRayImageReceptorTranslation),
+        ("ImplantAssemblyTemplateIssuer", &tags::ImplantAssemblyTemplateIssuer),
+        ("SubtaskWorkitemCodeSequence", &tags::SubtaskWorkitemCodeSequence),
+        ("SurgicalTechnique", &tags::SurgicalTechnique),
+        ("PersonName", &tags::PersonName),
+        ("QueueStatus", &tags::QueueStatus),
+        ("MPRViewHeight", &tags::MPRViewHeight),
+        ("CoordinateSystemAxesSequence", &tags::CoordinateSystemAxesSequence),
+        ("IdenticalDocumentsSequence", &tags::IdenticalDocumentsSequence),
+        ("RouteSegmentStartLocationID", &tags::RouteSegmentStartLocationID),
+        ("MilitaryRank", &tags::MilitaryRank),
+        ("DetectorTemperatureSequence", &tags::DetectorTemperatureSequence),
+        ("TreatmentStatusComment", &tags::TreatmentStatusComment),
+        ("ReferencedRTPhysicianIntentIndex", &tags::ReferencedRTPhysicianIntentIndex),
+        ("TwoDImplantTemplateGroupMemberMatchingPoint", &tags::Tag_2DImplantTemplateGroupMemberMatchingPoint),
+        ("ContentDescription", &tags::ContentDescription),
+        ("AuthorObserverSequence", &tags::AuthorObserverSequence),
+        ("BrachyAccessoryDeviceSequence", &tags::BrachyAccessoryDeviceSequence),
+        ("DistanceSourceToSupport", &tags::DistanceSourcetoSupport),
+        ("AnteriorChamberDepth", &tags::AnteriorChamberDepth),
+        ("ExposureControlSensingRegionUpperHorizontalEdge", &tags::ExposureControlSensingRegionUpperHorizontalEdge),
+        ("ExtendedOffsetTable", &tags::ExtendedOffsetTable),
+        ("ChemicalShiftMaximumIntegrationLimitInHz", &tags::ChemicalShiftMaximumIntegrationLimitinHz),
+        ("ReferencedParentRTPrescriptionIndex", &tags::ReferencedParentRTPrescriptionIndex),
+        ("FrameOfInterestDescription", &tags::FrameofInterestDescription),
+        ("HumanPerformerOrganization", &tags::HumanPerformersOrganization),
+        ("GPSTrackRef", &tags::GPSTrackRef),
+        ("RationalDenominatorValue", &tags::RationalDenominatorValue),
+        ("SwivelRange", &tags::SwivelRange),
+        ("ShutterPresentationColorCIELabValue", &tags::ShutterPresentationColorCIELabValue),
+        ("DerivationCodeSequence", &tags::DerivationCodeSequence),
+        ("NumberOfStudyRecordsInInstance", &tags::NumberofStudyRecordsinInstance),
+        ("DICOMMediaRetrievalSequence", &tags::DICOMMediaRetrievalSequence),
+        ("MRAcquisitionPhaseEncodingStepsInPlane", &tags::MRAcquisitionPhaseEncodingStepsinplane),
+        ("SliceVector", &tags::SliceVector),
+        ("TreatmentProtocols", &tags::TreatmentProtocols),
+        ("ExposureIndex", &tags::ExposureIndex),
+        ("RecordedBlockSlabSequence", &tags::RecordedBlockSlabSequence),
+        ("RadiobiologicalDoseEffectFlag", &tags::RadiobiologicalDoseEffectFlag),
+        ("IssuerOfServiceEpisodeIDSequence", &tags::IssuerofServiceEpisodeIDSequence),
+        ("TMLinePositionY1Retired", &tags::TMLinePositionY1_Retired),
+        ("PatientBreedCodeSequence", &tags::PatientBreedCodeSequence),
+        ("RTTreatmentSourceCoordinates", &tags::RTTreatmentSourceCoordinates),
+        ("ImagePlanePixelSpacing", &tags::ImagePlanePixelSpacing),
+        ("InterpretationRecorder", &tags::InterpretationRecorder),
+        ("GeneratorID", &tags::GeneratorID),
+        ("ChemicalShiftMinimumIntegrationLimitInHz", &tags::ChemicalShiftMinimumIntegrationLimitinHz),
+        ("ItemNumber", &tags::ItemNumber),
+        ("ReasonForOmission", &tags::ReasonforOmission),
+        ("ConceptNameCodeSequence", &tags::ConceptNameCodeSequence),
+        ("ScheduledPerformingPhysicianIdentificationSequence", &tags::ScheduledPerformingPhysicianIdentificationSequence),
+        ("ReferencedControlPointIndex", &tags::ReferencedControlPointIndex),
+        ("ContourImageSequence", &tags::ContourImageSequence),
+        ("DataElementConditionality", &tags::DataElementConditionality),
+        ("DefaultPrinterResolutionID", &tags::DefaultPrinterResolutionID),
+        ("ReferencedHPGLDocumentID", &tags::ReferencedHPGLDocumentID),
+        ("StartAngle", &tags::StartAngle),
+        ("AlarmDecisionTime", &tags::AlarmDecisionTime),
+        ("ApplicatorOpeningX", &tags::ApplicatorOpeningX),
+        ("SpectroscopyAcquisitionPhaseRows", &tags::SpectroscopyAcquisitionPhaseRows),
+        ("MediaDisposition", &tags::MediaDisposition),
+        ("UpperLimitNumberOfPersistentFluoroscopyFrames", &tags::UpperLimitNumberOfPersistentFluoroscopyFrames),
+        ("EnergyWindowCenterline", &tags::EnergyWindowCenterline),
+        ("DetectorActiveShape", &tags::DetectorActiveShape),
+        ("NumberOfWaveformSamples", &tags::NumberofWaveformSamples),
+        ("LateralSpreadingDeviceID", &tags::LateralSpreadingDeviceID),
+        ("CodingSchemeURLType", &tags::CodingSchemeURLType),
+        ("MydriaticAgentConcentration", &tags::MydriaticAgentConcentration),
+        ("PlanOverviewIndex", &tags::PlanOverviewIndex),
+        ("BeamDoseSpecificationPoint", &tags::BeamDoseSpecificationPoint),
+        ("BrachyAccessoryDeviceType", &tags::BrachyAccessoryDeviceType),
+        ("KeratometerIndex", &tags::KeratometerIndex),
+        ("SpecifiedPrimaryMeterset", &tags::SpecifiedPrimaryMeterset),
+        ("FalsePositivesEstimate", &tags::FalsePositivesEstimate),
+        ("NumberOfRTBeamLimitingDeviceOpenings", &tags::NumberofRTBeamLimitingDeviceOpenings),
+        ("RespiratorySignalSource", &tags::RespiratorySignalSource),
+        ("CoordinateSystemAxisType", &tags::CoordinateSystemAxisType),
+        ("BeamLimitingDevicePositionSequence", &tags::BeamLimitingDevicePositionSequence),
+        ("DataType", &tags::DataType),
+        ("ShortTermFluctuationCalculated", &tags::ShortTermFluctuationCalculated),
+        ("StartingRespiratoryAmplitude", &tags::StartingRespiratoryAmplitude),
+        ("ThreatDetectionAlgorithmAndVersion", &tags::ThreatDetectionAlgorithmandVersion),
+        ("ModifyingSystem", &tags::ModifyingSystem),
+        ("EnergyWindowName", &tags::EnergyWindowName),
+        ("ClinicalTrialTimePointDescription", &tags::ClinicalTrialTimePointDescription),
+        ("TreatmentSiteModifierCodeSequence", &tags::TreatmentSiteModifierCodeSequence),
+        ("RTTreatmentPhaseUID", &tags::RTTreatmentPhaseUID),
+        ("IndicationNumber", &tags::IndicationNumber),
+        ("OphthalmicThicknessMappingNormalsSequence", &tags::OphthalmicThicknessMappingNormalsSequence),
+        ("PerformedProtocolType", &tags::PerformedProtocolType),
+        ("DepthSpatialResolution", &tags::DepthSpatialResolution),
+        ("CompensatorProximalThicknessMap", &tags::CompensatorProximalThicknessMap),
+        ("SensingMethod", &tags::SensingMethod),
+        ("CTAdditionalXRaySourceSequence", &tags::CTAdditionalXRaySourceSequence),
+        ("BeatRejectionFlag", &tags::BeatRejectionFlag),
+        ("ClinicalTrialSubjectReadingID", &tags::ClinicalTrialSubjectReadingID),
+        ("FixationSequence", &tags::FixationSequence),
+        ("BoundingBoxTextHorizontalJustification", &tags::BoundingBoxTextHorizontalJustification),
+        ("RecommendedDisplayFrameRate", &tags::RecommendedDisplayFrameRate),
+        ("QAResultsSequence", &tags::QAResultsSequence),
+        ("VisualFieldTestPointSequence", &tags::VisualFieldTestPointSequence),
+        ("SurfacePointPresentationValueData", &tags::SurfacePointPresentationValueData),
+        ("HighEnergyDetectors", &tags::HighEnergyDetectors),
+        ("EdgePointIndexList", &tags::EdgePointIndexList),
+        ("SourceStrength", &tags::SourceStrength),
+        ("BeamLimitingDeviceSequence", &tags::BeamLimitingDeviceSequence),
+        ("SpectroscopyAcquisitionDataColumns", &tags::SpectroscopyAcquisitionDataColumns),
+        ("BoundingRectangle", &tags::BoundingRectangle),
+        ("RTRadiationUsage", &tags::RTRadiationUsage),
+        ("CustomRendered", &tags::CustomRendered),
+        ("TrackSetLabel", &tags::TrackSetLabel),
+        ("ToleranceTableNumber", &tags::ToleranceTableNumber),
+        ("RangeShifterID", &tags::RangeShifterID),
+        ("IntravascularOCTFrameTypeSequence", &tags::IntravascularOCTFrameTypeSequence),
+        ("DisplayedAreaTopLeftHandCornerTrial", &tags::DisplayedAreaTopLeftHandCorner_Trial),
+        ("FractionGroupType", &tags::FractionGroupType),
+        ("RepeatFractionCycleLength", &tags::RepeatFractionCycleLength),
+        ("NumberOfLateralSpreadingDevices", &tags::NumberofLateralSpreadingDevices),
+        ("BackgroundIlluminationColorCodeSequence", &tags::BackgroundIlluminationColorCodeSequence),
+        ("AlphaPaletteColorLookupTableDescriptor", &tags::AlphaPaletteColorLookupTableDescriptor),
+        ("FunctionalGroupPrivateCreator", &tags::FunctionalGroupPrivateCreator),
+        ("TimeSource", &tags::TimeSource),
+        ("KeratoconusPredictionIndex", &tags::KeratoconusPredictionIndex),
+        ("TransmitCoilManufacturerName", &tags::TransmitCoilManufacturerName),
+        ("HardcopyDeviceManufacturerModelName", &tags::HardcopyDeviceManufacturersModelName),
+        ("OriginalImageIdentificationNomenclature", &tags::OriginalImageIdentificationNomenclature),
+        ("ReferencedCalculatedDoseReferenceSequence", &tags::ReferencedCalculatedDoseReferenceSequence),
+        ("SegmentationCreationTemplateLabel", &tags::SegmentationCreationTemplateLabel),
+        ("TrackStatisticsSequence", &tags::TrackStatisticsSequence),
+        ("ImplantName", &tags::ImplantName),
+        ("PerformedStationNameCodeSequence", &tags::PerformedStationNameCodeSequence),
+        ("PixelSpacingCalibrationType", &tags::PixelSpacingCalibrationType),
+        ("MultiplexGroupLabel", &tags::MultiplexGroupLabel),
+        ("AcquisitionTerminationCondition", &tags::AcquisitionTerminationCondition),
+        ("OutlineRightVerticalEdge", &tags::OutlineRightVerticalEdge),
+        ("TreatmentToleranceViolationAttributeSequence", &tags::TreatmentToleranceViolationAttributeSequence),
+        ("VolumeCroppingSequence", &tags::VolumeCroppingSequence),
+        ("DetectorMode", &tags::DetectorMode),
+        ("VolumeCroppingMethod", &tags::VolumeCroppingMethod),
+        ("PrinterResolutionID", &tags::PrinterResolutionID),
+        ("DoseCalibrationConditionsSequence", &tags::DoseCalibrationConditionsSequence),
+        ("AssertionExpirationDateTime", &tags::AssertionExpirationDateTime),
+        ("SourceOfPreviousValues", &tags::SourceofPreviousValues),
+        ("FilenameInContainer", &tags::FilenameinContainer),
+        ("SourcePatientGroupIdentificationSequence", &tags::SourcePatientGroupIdentificationSequence),
+        ("ObserverContextFlagTrial", &tags::ObserverContextFlag_Trial),
+        ("DICOMRetrievalSequence", &tags::DICOMRetrievalSequence),
+        ("TreatmentToleranceViolationDescription", &tags::TreatmentToleranceViolationDescription),
+        ("PerformedProcedureStepID", &tags::PerformedProcedureStepID),
+        ("FiducialIdentifierCodeSequence", &tags::FiducialIdentifierCodeSequence),
+        ("ImagingSourcePositionSequence", &tags::ImagingSourcePositionSequence),
+        ("IonToleranceTableSequence", &tags::IonToleranceTableSequence),
+        ("PrinterCharacteristicsSequence", &tags::PrinterCharacteristicsSequence),
+        ("TwoDPointCoordinatesSequence", &tags::Tag_2DPointCoordinatesSequence),
+        ("WedgePosition", &tags::WedgePosition),
+        ("ProcedureStepProgress", &tags::ProcedureStepProgress),
+        ("BlendingLookupTableDescriptor", &tags::BlendingLookupTableDescriptor),
+        ("SelectorLTValue", &tags::SelectorLTValue),
+        ("AttenuationCorrectionMethod", &tags::AttenuationCorrectionMethod),
+        ("ExposureTimeInms", &tags::ExposureTimeinms),
+        ("FunctionalSettlingPhaseFramesPresent", &tags::FunctionalSettlingPhaseFramesPresent),
+        ("DataBlockDescription", &tags::DataBlockDescription),
+        ("FrameLaterality", &tags::FrameLaterality),
+        ("ROIGenerationAlgorithm", &tags::ROIGenerationAlgorithm),
+        ("AnnotationCoordinateType", &tags::AnnotationCoordinateType),
+        ("PatientSetupUID", &tags::PatientSetupUID),
+        ("ImagingServiceRequestComments", &tags::ImagingServiceRequestComments),
+        ("LateralSpreadingDeviceSetting", &tags::LateralSpreadingDeviceSetting),
+        ("BlockThickness", &tags::BlockThickness),
+        ("ConfidentialityCode", &tags::ConfidentialityCode),
+        ("RecordedSourceSequence", &tags::RecordedSourceSequence),
+        ("FalseNegativesEstimate", &tags::FalseNegativesEstimate),
+        ("VolumetricPresentationStateInputSequence", &tags::VolumetricPresentationStateInputSequence),
+        ("ReportStatusCommentTrial", &tags::ReportStatusComment_Trial),
+        ("CorneaMeasurementMethodCodeSequence", &tags::CorneaMeasurementMethodCodeSequence),
+        ("InterpretationIDIssuer", &tags::InterpretationIDIssuer),
+        ("IntendedStartDayOfWeek", &tags::IntendedStartDayofWeek),
+        ("XRayTubeCurrent", &tags::XRayTubeCurrent),
+        ("RTImageSID", &tags::RTImageSID),
+        ("VerifyingObserverIdentificationCodeSequence", &tags::VerifyingObserverIdentificationCodeSequence),
+        ("DeliveryTimeStructureCodeSequence", &tags::DeliveryTimeStructureCodeSequence),
+        ("GPSTimeStamp", &tags::GPSTimeStamp),
+        ("CompensatorNumber", &tags::CompensatorNumber),
+        ("TableTopLateralAdjustedPosition", &tags::TableTopLateralAdjustedPosition),
+        ("VerifyingObserverSequence", &tags::VerifyingObserverSequence),
+        ("CompensatorColumnOffset", &tags::CompensatorColumnOffset),
+        ("RTPlanLabel", &tags::RTPlanLabel),
+        ("TreatmentPositionIndex", &tags::TreatmentPositionIndex),
+        ("SetupImageComment", &tags::SetupImageComment),
+        ("SelectorFLValue", &tags::SelectorFLValue),
+        ("PertinentOtherEvidenceSequence", &tags::PertinentOtherEvidenceSequence),
+        ("PatternOnColorCIELabValue", &tags::PatternOnColorCIELabValue),
+        ("RecommendedDefaultValueSequence", &tags::RecommendedDefaultValueSequence),
+        ("BlockType", &tags::BlockType),
+        ("MRMetaboliteMapSequence", &tags::MRMetaboliteMapSequence),
+        ("PatientInstitutionResidence", &tags::PatientsInstitutionResidence),
+        ("ProcedureStepCancellationDateTime", &tags::ProcedureStepCancellationDateTime),
+        ("CollimatorLowerHorizontalEdge", &tags::CollimatorLowerHorizontalEdge),
+        ("EstimatedRadiographicMagnificationFactor", &tags::EstimatedRadiographicMagnificationFactor),
+        ("BillingItemSequence", &tags::BillingItemSequence),
+        ("ImagingDeviceLocationParameterSequence", &tags::ImagingDeviceLocationParameterSequence),
+        ("BiopsyTargetSequence", &tags::BiopsyTargetSequence),
+        ("RTDoseROISequence", &tags::RTDoseROISequence),
+        ("PrescriptionNotes", &tags::PrescriptionNotes),
+        ("ReferencedPerformedProtocolSequence", &tags::ReferencedPerformedProtocolSequence),
+        ("SecondaryInspectionMethodSequence", &tags::SecondaryInspectionMethodSequence),
+        ("SubstanceAdministrationNotes", &tags::SubstanceAdministrationNotes),
+        ("TableTopLongitudinalPosition", &tags::TableTopLongitudinalPosition),
+        ("SourceIrradiationEventSequence", &tags::SourceIrradiationEventSequence),
+        ("FilmSessionLabel", &tags::FilmSessionLabel),
+        ("ProtocolDefinedPatientPosition", &tags::ProtocolDefinedPatientPosition),
+        ("TransducerTechnologySequence", &tags::TransducerTechnologySequence),
+        ("PatientSupportPositionSequence", &tags::PatientSupportPositionSequence),
+        ("GateThreshold", &tags::GateThreshold),
+        ("VisualFieldMeanSensitivity", &tags::VisualFieldMeanSensitivity),
+        ("ObservationStartDateTime", &tags::ObservationStartDateTime),
+        ("NumberOfBlocks", &tags::NumberofBlocks),
+        ("AcquisitionMethodCodeSequence", &tags::AcquisitionMethodCodeSequence),
+        ("RequestingAE", &tags::RequestingAE),
+        ("LensThicknessSequence", &tags::LensThicknessSequence),
+        ("TableRowDefinitionSequence", &tags::TableRowDefinitionSequence),
+        ("DisplayCalibrationResultSequence", &tags::DisplayCalibrationResultSequence),
+        ("TimeSlotTime", &tags::TimeSlotTime),
+        ("XRaySourceIndex", &tags::XRaySourceIndex),
+        ("PatientLocationCoordinatesSequence", &tags::PatientLocationCoordinatesSequence),
+        ("HL7DocumentEffectiveTime", &tags::HL7DocumentEffectiveTime),
+        ("EquipmentFrameOfReferenceUID", &tags::EquipmentFrameofReferenceUID),
+        ("ReferencedSurfaceMeshIdentificationSequence", &tags::ReferencedSurfaceMeshIdentificationSequence),
+        ("TMLinePositionY0Retired", &tags::TMLinePositionY0_Retired),
+        ("RelativeElevation", &tags::RelativeElevation),
+        ("BlendingLUT1Sequence", &tags::BlendingLUT1Sequence),
+        ("DetailsOfCoefficients", &tags::DetailsofCoefficients),
+        ("SurfaceSequence", &tags::SurfaceSequence),
+        ("GateSettingsSequence", &tags::GateSettingsSequence),
+        ("CorrectedLocalizedDeviationFromNormalProbability", &tags::CorrectedLocalizedDeviationFromNormalProbability),
+        ("RegionOfResidence", &tags::RegionofResidence),
+        ("RangeModulatorSettingsSequence", &tags::RangeModulatorSettingsSequence),
+        ("MaximumAlongScanDistortion", &tags::MaximumAlongscanDistortion),
+        ("SubjectiveRefractionLeftEyeSequence", &tags::SubjectiveRefractionLeftEyeSequence),
+        ("DataPathAssignment", &tags::DataPathAssignment),
+        ("TimeSeriesBlending", &tags::TimeSeriesBlending),
+        ("SourcePresentationAddress", &tags::SourcePresentationAddress),
+        ("PatternOffColorCIELabValue", &tags::PatternOffColorCIELabValue),
+        ("SubjectiveRefractionRightEyeSequence", &tags::SubjectiveRefractionRightEyeSequence),
+        ("PatientSupportAdjustedAngle", &tags::PatientSupportAdjustedAngle),
+        ("TranslationRateY", &tags::TranslationRateY),
+        ("PartialFourier", &tags::PartialFourier),
+        ("PreAmplifierEquipmentSequence", &tags::PreAmplifierEquipmentSequence),
+        ("EvaluatorSequence", &tags::EvaluatorSequence),
+        ("SourceApplicatorTipLength", &tags::SourceApplicatorTipLength),
+        ("DisplaySetLabel", &tags::DisplaySetLabel),
+        ("BoundingPolygon", &tags::BoundingPolygon),
+        ("EnergyWindowLowerLimit", &tags::EnergyWindowLowerLimit),
+        ("FillerOrderNumberImagingServiceRequestRetired", &tags::FillerOrderNumberImagingServiceRequest_Retired),
+        ("EnergyWeightingFactor", &tags::EnergyWeightingFactor),
+        ("BeamLimitingDeviceAngle", &tags::BeamLimitingDeviceAngle),
+        ("ReferencedObjectObservationClassTrial", &tags::ReferencedObjectObservationClass_Trial),
+        ("CollimatorType", &tags::CollimatorType),
+        ("WedgeModelNumber", &tags::WedgeModelNumber),
+        ("RealWorldValueFirstValueMapped", &tags::RealWorldValueFirstValueMapped),
+        ("MatingFeatureSetsSequence", &tags::MatingFeatureSetsSequence),
+        ("CompositingMethod", &tags::CompositingMethod),
+        ("WedgeID", &tags::WedgeID),
+        ("PlanningLandmarkDescription", &tags::PlanningLandmarkDescription),
+        ("LightDirection", &tags::LightDirection),
+        ("Component1ReferencedMatingFeatureID", &tags::Component1ReferencedMatingFeatureID),
+        ("ChannelSettingsSequence", &tags::ChannelSettingsSequence),
+        ("OCTFocalDistance", &tags::OCTFocalDistance),
+        ("NumericValueQualifierCodeSequence", &tags::NumericValueQualifierCodeSequence),
+        ("ReferenceDisplaySets", &tags::ReferenceDisplaySets),
+        ("CIExyWhitePoint", &tags::CIExyWhitePoint),
+        ("GroupOfPatientsIdentificationSequence", &tags::GroupofPatientsIdentificationSequence),
+        ("AnatomicStructureSpaceOrRegionCodeSequenceTrial", &tags::AnatomicStructureSpaceOrRegionCodeSequence_Trial),
+        ("ReferencedPrintJobSequence", &tags::ReferencedPrintJobSequence),
+        ("WorklistLabel", &tags::WorklistLabel),
+        ("PageNumberVector", &tags::PageNumberVector),
+        ("SegmentedGreenPaletteColorLookupTableData", &tags::SegmentedGreenPaletteColorLookupTableData),
+        ("ExposureInmAs", &tags::ExposureinmAs),
+        ("MaskOperation", &tags::MaskOperation),
+        ("OptotypePresentation", &tags::OptotypePresentation),
+        ("SelectorValueNumber", &tags::SelectorValueNumber),
+        ("VisitComments", &tags::VisitComments),
+        ("ReferencedSetupImageSequence", &tags::ReferencedSetupImageSequence),
+        ("SaturationRecovery", &tags::SaturationRecovery),
+        ("NumberOfPatientSupportDevices", &tags::NumberofPatientSupportDevices),
+        ("PatientBirthTime", &tags::PatientsBirthTime),
+        ("TableTopEccentricRotationDirection", &tags::TableTopEccentricRotationDirection),
+        ("GeneralAccessorySequence", &tags::GeneralAccessorySequence),
+        ("RefractiveIndexOfCornea", &tags::RefractiveIndexofCornea),
+        ("NumberOfBeams", &tags::NumberofBeams),
+        ("RangeShifterSetting", &tags::RangeShifterSetting),
+        ("ReferencedSamplePositions", &tags::ReferencedSamplePositions),
+        ("GrayScale", &tags::GrayScale),
+        ("CompletionFlagDescription", &tags::CompletionFlagDescription),
+        ("ContrastBolusStopTime", &tags::ContrastBolusStopTime),
+        ("ScheduledStationGeographicLocationCodeSequence", &tags::ScheduledStationGeographicLocationCodeSequence),
+        ("ApplicatorOpening", &tags::ApplicatorOpening),
+        ("DosimetricObjectiveEvaluationScope", &tags::DosimetricObjectiveEvaluationScope),
+        ("InitialCineRunState", &tags::InitialCineRunState),
+        ("KeratometryRightEyeSequence", &tags::KeratometryRightEyeSequence),
+        ("RangeShifterNumber", &tags::RangeShifterNumber),
+        ("VectorAccuracy", &tags::VectorAccuracy),
+        ("InputInformationSequence", &tags::InputInformationSequence),
+        ("ImplantTemplateGroupMemberID", &tags::ImplantTemplateGroupMemberID),
+        ("StructureSetDescription", &tags::StructureSetDescription),
+        ("ContentCreatorIdentificationCodeSequence", &tags::ContentCreatorsIdentificationCodeSequence),
+        ("LongEdgePointIndexList", &tags::LongEdgePointIndexList),
+        ("AgeCorrectedSensitivityDeviationValue", &tags::AgeCorrectedSensitivityDeviationValue),
+        ("CodingSchemeURL", &tags::CodingSchemeURL),
+        ("ViewpointUpDirection", &tags::ViewpointUpDirection),
+        ("PatientSetupPhotoDescription", &tags::PatientSetupPhotoDescription),
+        ("PixelIntensityRelationship", &tags::PixelIntensityRelationship),
+        ("LensSegmentType", &tags::LensSegmentType),
+        ("ChannelInnerLength", &tags::ChannelInnerLength),
+        ("RouteSegmentID", &tags::RouteSegmentID),
+        ("TerminationTimeThreshold", &tags::TerminationTimeThreshold),
+        ("Density", &tags::Density),
+        ("RequestAttributesSequence", &tags::RequestAttributesSequence),
+        ("MultienergyCTAcquisition", &tags::MultienergyCTAcquisition),
+        ("CoordinateSystemDataSetMapping", &tags::CoordinateSystemDataSetMapping),
+        ("RTVMetaInformationVersion", &tags::RTVMetaInformationVersion),
+        ("FindingsFlagTrial", &tags::FindingsFlag_Trial),
+        ("OutlineUpperHorizontalEdge", &tags::OutlineUpperHorizontalEdge),
+        ("SwitchingPhaseNominalDuration", &tags::SwitchingPhaseNominalDuration),
+        ("ViewingDistanceType", &tags::ViewingDistanceType),
+        ("CoefficientsSDHN", &tags::CoefficientsSDHN),
+        ("PatientSupportAngle", &tags::PatientSupportAngle),
+        ("SelectorDTValue", &tags::SelectorDTValue),
+        ("EstimatedDoseSaving", &tags::EstimatedDoseSaving),
+        ("PlanePositionSequence", &tags::PlanePositionSequence),
+        ("XRayAcquisitionDoseSequence", &tags::XRayAcquisitionDoseSequence),
+        ("MeasurementEquipmentType", &tags::MeasurementEquipmentType),
+        ("FloatingPointValue", &tags::FloatingPointValue),
+        ("CenterOfCircularOutline", &tags::CenterofCircularOutline),
+        ("ReferencedWedgeNumber", &tags::ReferencedWedgeNumber),
+        ("TwoDLineCoordinates", &tags::Tag_2DLineCoordinates),
+        ("ReferencedStorageMediaSequence", &tags::ReferencedStorageMediaSequence),
+        ("LocationOfMeasuredBeamDiameter", &tags::LocationofMeasuredBeamDiameter),
+        ("ImplantTemplateGroupVariationDimensionRank", &tags::ImplantTemplateGroupVariationDimensionRank),
+        ("StrainNomenclature", &tags::StrainNomenclature),
+        ("OperatingModeSequence", &tags::OperatingModeSequence),
+        ("RadionuclidePositronFraction", &tags::RadionuclidePositronFraction),
+        ("TestImageValidation", &tags::TestImageValidation),
+        ("MRFOVGeometrySequence", &tags::MRFOVGeometrySequence),
+        ("DisplaySubsystemQAResultsSequence", &tags::DisplaySubsystemQAResultsSequence),
+        ("NominalRespiratoryTriggerDelayTime", &tags::NominalRespiratoryTriggerDelayTime),
+        ("ReferencedPerformedProcedureStepSequence", &tags::ReferencedPerformedProcedureStepSequence),
+        ("OtherPatientIDs", &tags::OtherPatientIDs),
+        ("ScannerSettingsSequence", &tags::ScannerSettingsSequence),
+        ("TMLinePositionX0Retired", &tags::TMLinePositionX0_Retired),
+        ("RTImageLabel", &tags::RTImageLabel),
+        ("VirtualSourceAxisDistances", &tags::VirtualSourceAxisDistances),
+        ("AssessmentTypeCodeSequence", &tags::AssessmentTypeCodeSequence),
+        ("ShutterLowerHorizontalEdge", &tags::ShutterLowerHorizontalEdge),
+        ("ZonalMapFormat", &tags::ZonalMapFormat),
+        ("OphthalmicThicknessMapTypeCodeSequence", &tags::OphthalmicThicknessMapTypeCodeSequence),
+        ("SafePositionReturnTime", &tags::SafePositionReturnTime),
+        ("ConstantVolumeFlag", &tags::ConstantVolumeFlag),
+        ("PETPositionSequence", &tags::PETPositionSequence),
+        ("SourceMovementType", &tags::SourceMovementType),
+        ("DataSetSource", &tags::DataSetSource),
+        ("CellValuesSequence", &tags::CellValuesSequence),
+        ("ZoomCenter", &tags::ZoomCenter),
+        ("GPSAltitudeRef", &tags::GPSAltitudeRef),
+        ("RouteSegmentSequence", &tags::RouteSegmentSequence),
+        ("DeviceDescription", &tags::DeviceDescription),
+        ("ChannelShieldNominalThickness", &tags::ChannelShieldNominalThickness),
+        ("VValueData", &tags::VValueData),
+        ("Pressure", &tags::Pressure),
+        ("TypeOfOpticalCorrection", &tags::TypeofOpticalCorrection),
+        ("BlockSequence", &tags::BlockSequence),
+        ("RequestedProcedureDescription", &tags::RequestedProcedureDescription),
+        ("ObservationCategoryCodeSequenceTrial", &tags::ObservationCategoryCodeSequence_Trial),
+        ("DisplaySetPatientOrientation", &tags::DisplaySetPatientOrientation),
+        ("RecognitionCode", &tags::RecognitionCode),
+        ("ImplantTemplateGroupMembersSequence", &tags::ImplantTemplateGroupMembersSequence),
+        ("BrachyAccessoryDeviceNumber", &tags::BrachyAccessoryDeviceNumber),
+        ("ConstraintWeight", &tags::ConstraintWeight),
+        ("MaximumPointDistance", &tags::MaximumPointDistance),
+        ("RecommendedDisplayGrayscaleValue", &tags::RecommendedDisplayGrayscaleValue),
+        ("LargestImagePixelValueInPlane", &tags::LargestImagePixelValueinPlane),
+        ("SharedFunctionalGroupsSequence", &tags::SharedFunctionalGroupsSequence),
+        ("RelativeTime", &tags::RelativeTime),
+        ("CompressionForce", &tags::CompressionForce),
+        ("CameraPositionGroupSequence", &tags::CameraPositionGroupSequence),
+        ("NominalFrequency", &tags::NominalFrequency),
+        ("SegmentLabel", &tags::SegmentLabel),
+        ("RadiationSourceCoordinateSystemYawAngle", &tags::RadiationSourceCoordinateSystemYawAngle),
+        ("StrainStockNumber", &tags::StrainStockNumber),
+        ("FlashFiringStatus", &tags::FlashFiringStatus),
+        ("PatientNotProperlyFixatedQuantity", &tags::PatientNotProperlyFixatedQuantity),
+        ("PerformedProcedureStepEndTime", &tags::PerformedProcedureStepEndTime),
+        ("ReferencedOverlayPlaneSequence", &tags::ReferencedOverlayPlaneSequence),
+        ("PhaseVector", &tags::PhaseVector),
+        ("GeneticModificationsSequence", &tags::GeneticModificationsSequence),
+        ("TMLinePositionX0", &tags::TMLinePositionX0),
+        ("RadiationBeamCompensatorMillingToolDiameter", &tags::RadiationBeamCompensatorMillingToolDiameter),
+        ("ReferencePixelPhysicalValueX", &tags::ReferencePixelPhysicalValueX),
+        ("DeviceAlternateIdentifierType", &tags::DeviceAlternateIdentifierType),
+        ("SourceApplicatorManufacturer", &tags::SourceApplicatorManufacturer),
+        ("IntendedRecipientsOfResultsIdentificationSequence", &tags::IntendedRecipientsofResultsIdentificationSequence),
+        ("CardiacSynchronizationTechnique", &tags::CardiacSynchronizationTechnique),
+        ("FileSetDescriptorFileID", &tags::FilesetDescriptorFileID),
+        ("PriorTreatmentDoseDescription", &tags::PriorTreatmentDoseDescription),
+        ("LensModel", &tags::LensModel),
+        ("OphthalmicAxialLengthSelectionMethodCodeSequence", &tags::OphthalmicAxialLengthSelectionMethodCodeSequence),
+        ("SpatialFrequencyResponseValues", &tags::SpatialFrequencyResponseValues),
+        ("PupilSize", &tags::PupilSize),
+        ("ReceivingPresentationAddress", &tags::ReceivingPresentationAddress),
+        ("ContributingSourcesSequence", &tags::ContributingSourcesSequence),
+        ("HangingProtocolLevel", &tags::HangingProtocolLevel),
+        ("StereoHorizontalPixelOffset", &tags::StereoHorizontalPixelOffset),
+        ("FrameOfReferenceTransformationMatrix", &tags::FrameofReferenceTransformationMatrix),
+        ("RepresentativeFrameNumber", &tags::RepresentativeFrameNumber),
+        ("DeviceVolume", &tags::DeviceVolume),
+        ("EnergyWindowUpperLimit", &tags::EnergyWindowUpperLimit),
+        ("FieldOfViewDimensions", &tags::FieldofViewDimensions),
+        ("RecordedSnoutSequence", &tags::RecordedSnoutSequence),
+        ("AssignedLocation", &tags::AssignedLocation),
+        ("ImplantTemplateGroupVariationDimensionName", &tags::ImplantTemplateGroupVariationDimensionName),
+        ("EntityName", &tags::EntityName),
+        ("DisplaySetsSequence", &tags::DisplaySetsSequence),
+        ("OriginalImplantAssemblyTemplateSequence", &tags::OriginalImplantAssemblyTemplateSequence),
+        ("VolumeFrameOfReferenceUID", &tags::VolumeFrameofReferenceUID),
+        ("TableHeight", &tags::TableHeight),
+        ("MagneticFieldStrength", &tags::MagneticFieldStrength),
+        ("ModifiableConstraintFlag", &tags::ModifiableConstraintFlag),
+        ("LongitudinalTemporalEventType", &tags::LongitudinalTemporalEventType),
+        ("ReferencedAccessionSequenceTrial", &tags::ReferencedAccessionSequence_Trial),
+        ("InteroperabilityVersion", &tags::InteroperabilityVersion),
+        ("FlowIdentifierSequence", &tags::FlowIdentifierSequence),
+        ("TreatmentControlPointTime", &tags::TreatmentControlPointTime),
+        ("InterpretationDiagnosisCodeSequence", &tags::InterpretationDiagnosisCodeSequence),
+        ("AnnotationAppliesToAllZPlanes", &tags::AnnotationAppliestoAllZPlanes),
+        ("OverrideReason", &tags::OverrideReason),
+        ("ScanPatternTypeCodeSequence", &tags::ScanPatternTypeCodeSequence),
+        ("LanguageCodeSequenceTrial", &tags::LanguageCodeSequence_Trial),
+        ("GPSLatitude", &tags::GPSLatitude),
+        ("ActualHumanPerformersSequence", &tags::ActualHumanPerformersSequence),
+        ("ReferencedSurfaceNumber", &tags::ReferencedSurfaceNumber),
+        ("AnnotationGroupNumber", &tags::AnnotationGroupNumber),
+        ("RotationPoint", &tags::RotationPoint),
+        ("OphthalmicAxialLengthMeasurementsSegmentalLengthSequence", &tags::OphthalmicAxialLengthMeasurementsSegmentalLengthSequence),
+        ("GraphicLayerDescription", &tags::GraphicLayerDescription),
+        ("BranchOfService", &tags::BranchofService),
+        ("AcquisitionMethodAlgorithmSequence", &tags::AcquisitionMethodAlgorithmSequence),
+        ("RTPhysicianIntentInputInstanceSequence", &tags::RTPhysicianIntentInputInstanceSequence),
+        ("NameOfPhysiciansReadingStudy", &tags::NameofPhysiciansReadingStudy),
+        ("CorrectedParameterSequence", &tags::CorrectedParameterSequence),
+        ("TableTopRollAngle", &tags::TableTopRollAngle),
+        ("IonWedgeSequence", &tags::IonWedgeSequence),
+        ("TwoDMatingAxes", &tags::Tag_2DMatingAxes),
+        ("ShortTermFluctuationProbabilityCalculated", &tags::ShortTermFluctuationProbabilityCalculated),
+        ("MediaInstalledSequence", &tags::MediaInstalledSequence),
+        ("PatientTransportArrangements", &tags::PatientTransportArrangements),
+        ("ExcitationWavelengthSequence", &tags::ExcitationWavelengthSequence),
+        ("ScheduledProcedureStepPriority", &tags::ScheduledProcedureStepPriority),
+        ("WaveformFilterType", &tags::WaveformFilterType),
+        ("RTPlanTime", &tags::RTPlanTime),
+        ("BlindSpotLocalized", &tags::BlindSpotLocalized),
+        ("WaveformPresentationGroupSequence", &tags::WaveformPresentationGroupSequence),
+        ("SamplesPerPixelUsed", &tags::SamplesperPixelUsed),
+        ("NumberOfVisualStimuli", &tags::NumberofVisualStimuli),
+        ("AllowMediaSplitting", &tags::AllowMediaSplitting),
+        ("TemplateVersion", &tags::TemplateVersion),
+        ("PersonIdentificationCodeSequence", &tags::PersonIdentificationCodeSequence),
+        ("RealWorldValueMappingSequence", &tags::RealWorldValueMappingSequence),
+        ("ProcedureCreationDate", &tags::ProcedureCreationDate),
+        ("ContextGroupExtensionFlag", &tags::ContextGroupExtensionFlag),
+        ("StudyDate", &tags::StudyDate),
+        ("StorageMediaFileSetID", &tags::StorageMediaFilesetID),
+        ("PotentialThreatObjectID", &tags::PotentialThreatObjectID),
+        ("ReferencedPatientSetupPhotoSequence", &tags::ReferencedPatientSetupPhotoSequence),
+        ("BeamDosePointSourceToExternalContourDistance", &tags::BeamDosePointSourcetoExternalContourDistance),
+        ("CardiacFramingType", &tags::CardiacFramingType),
+        ("EchoTrainLength", &tags::EchoTrainLength),
+        ("FailedAttributesSequence", &tags::FailedAttributesSequence),
+        ("HPGLDocumentLabel", &tags::HPGLDocumentLabel),
+        ("ContrastBolusVolume", &tags::ContrastBolusVolume),
+        ("MultienergyDetectorType", &tags::MultienergyDetectorType),
+        ("PresentationIntentType", &tags::PresentationIntentType),
+        ("SequenceOfUltrasoundRegions", &tags::SequenceofUltrasoundRegions),
+        ("CompressionContactArea", &tags::CompressionContactArea),
+        ("RTBeamLimitingDeviceDefinitionSequence", &tags::RTBeamLimitingDeviceDefinitionSequence),
+        ("StartMeterset", &tags::StartMeterset),
+        ("PresentationStateCompositorComponentSequence", &tags::PresentationStateCompositorComponentSequence),
+        ("GeometricMaximumDistortion", &tags::GeometricMaximumDistortion),
+        ("ExposureControlModeDescription", &tags::ExposureControlModeDescription),
+        ("XRayReceptorType", &tags::XRayReceptorType),
+        ("PatientBodyMassIndex", &tags::PatientsBodyMassIndex),
+        ("PixelDataAreaOriginRelativeToFOV", &tags::PixelDataAreaOriginRelativeToFOV),
+        ("UltrasoundAcquisitionGeometry", &tags::UltrasoundAcquisitionGeometry),
+        ("ViewModifierCodeSequence", &tags::ViewModifierCodeSequence),
+        ("MatingFeatureSequence", &tags::MatingFeatureSequence),
+        ("XRayGridSequence", &tags::XRayGridSequence),
+        ("DateTimeOfLastCalibration", &tags::DateTimeofLastCalibration),
+        ("RespiratoryTriggerDelayThreshold", &tags::RespiratoryTriggerDelayThreshold),
+        ("StructureSetDate", &tags::StructureSetDate),
+        ("VectorCoordinateData", &tags::VectorCoordinateData),
+        ("ReportingPriority", &tags::ReportingPriority),
+        ("TopicTitle", &tags::TopicTitle),
+        ("RTPrescriptionIndex", &tags::RTPrescriptionIndex),
+        ("DACAmplitude", &tags::DACAmplitude),
+        ("CassetteOrientation", &tags::CassetteOrientation),
+        ("ImageLaterality", &tags::ImageLaterality),
+        ("TemplateExtensionCreatorUID", &tags::TemplateExtensionCreatorUID),
+        ("ActiveAperture", &tags::ActiveAperture),
+        ("TriangleFanSequence", &tags::TriangleFanSequence),
+        ("ReferencedGeneralPurposeScheduledProcedureStepTransactionUID", &tags::ReferencedGeneralPurposeScheduledProcedureStepTransactionUID),
+        ("DiffusionAcquisitionCodeSequence", &tags::DiffusionAcquisitionCodeSequence),
+        ("GeneticModificationsCodeSequence", &tags::GeneticModificationsCodeSequence),
+        ("SourceToApplicatorMountingPositionDistance", &tags::SourcetoApplicatorMountingPositionDistance),
+        ("ReferencedComparisonSOPInstanceSequence", &tags::ReferencedComparisonSOPInstanceSequence),
+        ("TableTopPitchAngleTolerance", &tags::TableTopPitchAngleTolerance),
+        ("EchoNumbers", &tags::EchoNumbers),
+        ("FixationMethodCodeSequence", &tags::FixationMethodCodeSequence),
+        ("RadiationTypeCodeSequence", &tags::RadiationTypeCodeSequence),
+        ("ROIDisplayColor", &tags::ROIDisplayColor),
+        ("ScheduledProcessingApplicationsCodeSequence", &tags::ScheduledProcessingApplicationsCodeSequence),
+        ("PositionAcquisitionTemplateID", &tags::PositionAcquisitionTemplateID),
+        ("ImageAcquisitionDepth", &tags::ImageAcquisitionDepth),
+        ("BlockOrientation", &tags::BlockOrientation),
+        ("PrimaryPositionerScanStartAngle", &tags::PrimaryPositionerScanStartAngle),
+        ("BurnedInAnnotation", &tags::BurnedInAnnotation),
+        ("AverageBeamDosePointSSD", &tags::AverageBeamDosePointSSD),
+        ("AuthorizationEquipmentCertificationNumber", &tags::AuthorizationEquipmentCertificationNumber),
+        ("BrachyApplicationSetupDoseSpecificationPoint", &tags::BrachyApplicationSetupDoseSpecificationPoint),
+        ("TemplateName", &tags::TemplateName),
+        ("PositionAcquisitionTemplateDescription", &tags::PositionAcquisitionTemplateDescription),
+        ("TreatmentDate", &tags::TreatmentDate),
+        ("SelectedFrameNumber", &tags::SelectedFrameNumber),
+        ("IntendedNumberOfFractions", &tags::IntendedNumberofFractions),
+        ("ObservationSubjectContextFlagTrial", &tags::ObservationSubjectContextFlag_Trial),
+        ("ImagedNucleus", &tags::ImagedNucleus),
+        ("GPSVersionID", &tags::GPSVersionID),
+        ("Saturation", &tags::Saturation),
+        ("IdentifierCodeSequenceTrial", &tags::IdentifierCodeSequence_Trial),
+        ("FilterOperationsSequence", &tags::FilterOperationsSequence),
+        ("PhotoacousticExcitationCharacteristicsSequence", &tags::PhotoacousticExcitationCharacteristicsSequence),
+        ("ParallelRTBeamDelimiterPositions", &tags::ParallelRTBeamDelimiterPositions),
+        ("ImageBoxContentSequence", &tags::ImageBoxContentSequence),
+        ("PrimaryPositionerIncrement", &tags::PrimaryPositionerIncrement),
+        ("TissueHeterogeneityCorrection", &tags::TissueHeterogeneityCorrection),
+        ("RequestedProcedureLocation", &tags::RequestedProcedureLocation),
+        ("PatientOrientationModifierCodeSequence", &tags::PatientOrientationModifierCodeSequence),
+        ("ImagePresentationComments", &tags::ImagePresentationComments),
+        ("ImageBoxLayoutType", &tags::ImageBoxLayoutType),
+        ("RTVCommunicationSOPClassUID", &tags::RTVCommunicationSOPClassUID),
+        ("VolumetricCurvePoints", &tags::VolumetricCurvePoints),
+        ("RTRadiationSalvageRecordControlPointSequence", &tags::RTRadiationSalvageRecordControlPointSequence),
+        ("RadiationGenerationModeIndex", &tags::RadiationGenerationModeIndex),
+        ("MRSpatialSaturationSequence", &tags::MRSpatialSaturationSequence),
+        ("ImplantTemplateGroupIssuer", &tags::ImplantTemplateGroupIssuer),
+        ("MeasuredDoseReferenceNumber", &tags::MeasuredDoseReferenceNumber),
+        ("AlgorithmName", &tags::AlgorithmName),
+        ("SliceLocationVector", &tags::SliceLocationVector),
+        ("CornealEccentricityIndex", &tags::CornealEccentricityIndex),
+        ("EncapsulatedDocumentLength", &tags::EncapsulatedDocumentLength),
+        ("FillMode", &tags::FillMode),
+        ("ThreeDPlaneOrigin", &tags::Tag_3DPlaneOrigin),
+        ("ReconstructionAlgorithm", &tags::ReconstructionAlgorithm),
+        ("BluePaletteColorLookupTableData", &tags::BluePaletteColorLookupTableData),
+        ("TypeOfInstances", &tags::TypeofInstances),
+        ("ReferencedInterpretationSequence", &tags::ReferencedInterpretationSequence),
+        ("NominalCardiacTriggerTimePriorToRPeak", &tags::NominalCardiacTriggerTimePriorToRPeak),
+        ("FileSetID", &tags::FilesetID),
+        ("OphthalmicAxialLengthQualityMetricTypeCodeSequence", &tags::OphthalmicAxialLengthQualityMetricTypeCodeSequence),
+        ("LUTExplanation", &tags::LUTExplanation),
+        ("DigitalSignatureUID", &tags::DigitalSignatureUID),
+        ("PatientPrimaryLanguageModifierCodeSequence", &tags::PatientsPrimaryLanguageModifierCodeSequence),
+        ("TrianglePointIndexList", &tags::TrianglePointIndexList),
+        ("RTBeamLimitingDeviceDistalDistance", &tags::RTBeamLimitingDeviceDistalDistance),
+        ("DateOfLastCalibration", &tags::DateofLastCalibration),
+        ("ContrastFrameAveraging", &tags::ContrastFrameAveraging),
+        ("EffectiveWedgeAngle", &tags::EffectiveWedgeAngle),
+        ("Laterality", &tags::Laterality),
+        ("ScanSpotReorderingAllowed", &tags::ScanSpotReorderingAllowed),
+        ("PatientPhysiologicalStateSequence", &tags::PatientPhysiologicalStateSequence),
+        ("RTROIInterpretedType", &tags::RTROIInterpretedType),
+        ("ScheduledStudyStartDate", &tags::ScheduledStudyStartDate),
+        ("SensorName", &tags::SensorName),
+        ("ReconstructionProtocolElementSpecificationSequence", &tags::ReconstructionProtocolElementSpecificationSequence),
+        ("MaskSubPixelShift", &tags::MaskSubpixelShift),
+        ("StrainCodeSequence", &tags::StrainCodeSequence),
+        ("InterpretationText", &tags::InterpretationText),
+        ("OtherApprovalStatus", &tags::OtherApprovalStatus),
+        ("RTPatientPositionDisplacementSequence", &tags::RTPatientPositionDisplacementSequence),
+        ("ImplantTemplateGroupDescription", &tags::ImplantTemplateGroupDescription),
+        ("TextureLabel", &tags::TextureLabel),
+        ("ContrastBolusIngredientOpaque", &tags::ContrastBolusIngredientOpaque),
+        ("PrivateDataElementKeyword", &tags::PrivateDataElementKeyword),
+        ("FixationDevicePosition", &tags::FixationDevicePosition),
+        ("ReflectedAmbientLight", &tags::ReflectedAmbientLight),
+        ("ExposureControlSensingRegionLeftVerticalEdge", &tags::ExposureControlSensingRegionLeftVerticalEdge),
+        ("RespiratorySynchronizationSequence", &tags::RespiratorySynchronizationSequence),
+        ("PreAmplifierNotes", &tags::PreAmplifierNotes),
+        ("AddressTrial", &tags::Address_Trial),
+        ("TeletherapyRadiationType", &tags::TeletherapyRadiationType),
+        ("OphthalmicAxialMeasurementsRightEyeSequence", &tags::OphthalmicAxialMeasurementsRightEyeSequence),
+        ("LuminanceResponseSequence", &tags::LuminanceResponseSequence),
+        ("SmallestImagePixelValueInPlane", &tags::SmallestImagePixelValueinPlane),
+        ("ScheduledStudyLocation", &tags::ScheduledStudyLocation),
+        ("AxialMash", &tags::AxialMash),
+        ("ContributionDateTime", &tags::ContributionDateTime),
+        ("TomoType", &tags::TomoType),
+        ("SceneType", &tags::SceneType),
+        ("TableType", &tags::TableType),
+        ("FocalLengthIn35mmFilm", &tags::FocalLengthIn35mmFilm),
+        ("SummarizedFilterLookupTable", &tags::SummarizedFilterLookupTableSequence),
+        ("ImageTriggerDelay", &tags::ImageTriggerDelay),
+        ("ViewName", &tags::ViewName),
+        ("ShowAcquisitionTechniquesFlag", &tags::ShowAcquisitionTechniquesFlag),
+        ("RTPhysicianIntentPredecessorSequence", &tags::RTPhysicianIntentPredecessorSequence),
+        ("PatientState", &tags::PatientState),
+        ("ImagePathFilterPassThroughWavelength", &tags::ImagePathFilterPassThroughWavelength),
+        ("IntensifierActiveShape", &tags::IntensifierActiveShape),
+        ("CodeMeaning", &tags::CodeMeaning),
+        ("TargetMaximumDose", &tags::TargetMaximumDose),
+        ("SpacingBetweenSlices", &tags::SpacingBetweenSlices),
+        ("PresentationPixelMagnificationRatio", &tags::PresentationPixelMagnificationRatio),
+        ("PatientSetupNumber", &tags::PatientSetupNumber),
+        ("ReferencedFileID", &tags::ReferencedFileID),
+        ("BillingProcedureStepSequence", &tags::BillingProcedureStepSequence),
+        ("InstanceLevelReferencedPerformedProcedureStepSequence", &tags::InstanceLevelReferencedPerformedProcedureStepSequence),
+        ("ImplantTemplateGroupVariationDimensionSequence", &tags::ImplantTemplateGroupVariationDimensionSequence),
+        ("TransducerData", &tags::TransducerData),
+        ("PersonAddress", &tags::PersonsAddress),
+        ("EncryptedContent", &tags::EncryptedContent),
+        ("DelayLawIdentifier", &tags::DelayLawIdentifier),
+        ("ReferencedTimeOffsets", &tags::ReferencedTimeOffsets),
+        ("PresentedVisualStimuliDataFlag", &tags::PresentedVisualStimuliDataFlag),
+        ("SpecificCharacterSetOfFileSetDescriptorFile", &tags::SpecificCharacterSetofFilesetDescriptorFile),
+        ("RangeShifterDescription", &tags::RangeShifterDescription),
+        ("ExclusionDuration", &tags::ExclusionDuration),
+        ("StudyVerifiedTime", &tags::StudyVerifiedTime),
+        ("RTTreatmentTerminationStatus", &tags::RTTreatmentTerminationStatus),
+        ("ImageQualityIndicatorType", &tags::ImageQualityIndicatorType),
+        ("RepairID", &tags::RepairID),
+        ("MaximumCornealCurvatureLocation", &tags::MaximumCornealCurvatureLocation),
+        ("ApplicationName", &tags::ApplicationName),
+        ("RTTreatmentTechniqueCodeSequence", &tags::RTTreatmentTechniqueCodeSequence),
+        ("GeneralMatchingSequence", &tags::GeneralMatchingSequence),
+        ("OrganAtRiskMaximumDose", &tags::OrganatRiskMaximumDose),
+        ("PatientSupportType", &tags::PatientSupportType),
+        ("GridThickness", &tags::GridThickness),
+        ("HPGLDocumentSequence", &tags::HPGLDocumentSequence),
+        ("ContainerTypeCodeSequence", &tags::ContainerTypeCodeSequence),
+        ("ModifiedImageDate", &tags::ModifiedImageDate),
+        ("RoboticPathNodeSetCodeSequence", &tags::RoboticPathNodeSetCodeSequence),
+        ("ConceptualVolumeTypeCodeSequence", &tags::ConceptualVolumeTypeCodeSequence),
+        ("DocumentAuthorIdentifierCodeSequenceTrial", &tags::DocumentAuthorIdentifierCodeSequence_Trial),
+        ("DisplayEnvironmentSpatialPosition", &tags::DisplayEnvironmentSpatialPosition),
+        ("ProductExpirationDateTime", &tags::ProductExpirationDateTime),
+        ("ShowTickLabel", &tags::ShowTickLabel),
+        ("TickAlignment", &tags::TickAlignment),
+        ("RadiobiologicalDoseEffectSequence", &tags::RadiobiologicalDoseEffectSequence),
+        ("CassetteSize", &tags::CassetteSize),
+        ("PatientSpecificationSequence", &tags::PatientSpecificationSequence),
+        ("TriggerTimeOffset", &tags::TriggerTimeOffset),
+        ("SliceProgressionDirection", &tags::SliceProgressionDirection),
+        ("RadiationDoseValuesParametersSequence", &tags::RadiationDoseValuesParametersSequence),
+        ("ChannelSensitivityCorrectionFactor", &tags::ChannelSensitivityCorrectionFactor),
+        ("NumberOfTemporalPositions", &tags::NumberofTemporalPositions),
+        ("ImplantTemplateGroupName", &tags::ImplantTemplateGroupName),
+        ("GatingBeamHoldTransitionSequence", &tags::GatingBeamHoldTransitionSequence),
+        ("CTTableDynamicsSequence", &tags::CTTableDynamicsSequence),
+        ("FramePixelShiftSequence", &tags::FramePixelShiftSequence),
+        ("ShotOffsetTime", &tags::ShotOffsetTime),
+        ("FixationLightAzimuthalAngleTolerance", &tags::FixationLightAzimuthalAngleTolerance),
+        ("ImageHorizontalFlip", &tags::ImageHorizontalFlip),
+        ("HeadFixationAngle", &tags::HeadFixationAngle),
+        ("ReferencedPresentationLUTSequence", &tags::ReferencedPresentationLUTSequence),
+        ("OphthalmicPatientClinicalInformationLeftEyeSequence", &tags::OphthalmicPatientClinicalInformationLeftEyeSequence),
+        ("DecompositionDescription", &tags::DecompositionDescription),
+        ("LUTFrameRange", &tags::LUTFrameRange),
+        ("AnalogFilterCharacteristicsSequence", &tags::AnalogFilterCharacteristicsSequence),
+        ("OphthalmicAxialLengthMeasurementModified", &tags::OphthalmicAxialLengthMeasurementModified),
+        ("DisplaySetPresentationGroupDescription", &tags::DisplaySetPresentationGroupDescription),
+        ("CompressionStepPointers", &tags::CompressionStepPointers),
+        ("OtherMagnificationTypesAvailable", &tags::OtherMagnificationTypesAvailable),
+        ("FractionalChannelDisplayScale", &tags::FractionalChannelDisplayScale),
+        ("SelectorLOValue", &tags::SelectorLOValue),
+        ("TotalBlockTrayWaterEquivalentThickness", &tags::TotalBlockTrayWaterEquivalentThickness),
+        ("WedgeTotalHeight", &tags::WedgeTotalHeight),
+        ("RTBeamModifierDefinitionDistance", &tags::RTBeamModifierDefinitionDistance),
+        ("RelativeTimeUnits", &tags::RelativeTimeUnits),
+        ("PatientSupportDevicesSequence", &tags::PatientSupportDevicesSequence),
+        ("BeamHoldTransitionDateTime", &tags::BeamHoldTransitionDateTime),
+        ("ImplantPartNumber", &tags::ImplantPartNumber),
+        ("SoftcopyVOILUTSequence", &tags::SoftcopyVOILUTSequence),
+        ("NominalPercentageOfRespiratoryPhase", &tags::NominalPercentageofRespiratoryPhase),
+        ("PETFrameTypeSequence", &tags::PETFrameTypeSequence),
+        ("XRay3DFrameTypeSequence", &tags::XRay3DFrameTypeSequence),
+        ("PatientBirthDate", &tags::PatientsBirthDate),
+        ("OriginalImplantTemplateSequence", &tags::OriginalImplantTemplateSequence),
+        ("IonRangeCompensatorSequence", &tags::IonRangeCompensatorSequence),
+        ("URNCodeValue", &tags::URNCodeValue),
+        ("RouteSegmentStartTime", &tags::RouteSegmentStartTime),
+        ("PixelValueTransformationSequence", &tags::PixelValueTransformationSequence),
+        ("ImageFilter", &tags::ImageFilter),
+        ("CodingSchemeUID", &tags::CodingSchemeUID),
+        ("FieldOfViewDimensionsInFloat", &tags::FieldofViewDimensionsinFloat),
+        ("FloatPixelData", &tags::FloatPixelData),
+        ("ImageBoxPresentationLUTFlag", &tags::ImageBoxPresentationLUTFlag),
+        ("ReferenceGeometryCodeSequence", &tags::ReferenceGeometryCodeSequence),
+        ("Tagging", &tags::Tagging),
+        ("BlockColumns", &tags::BlockColumns),
+        ("ReconstructionStartLocationSequence", &tags::ReconstructionStartLocationSequence),
+        ("GPSMeasureMode", &tags::GPSMeasureMode),
+        ("MinimumNumberOfIntervalDays", &tags::MinimumNumberofIntervalDays),
+        ("SourceInstanceSequence", &tags::SourceInstanceSequence),
+        ("MeanPointDistance", &tags::MeanPointDistance),
+        ("VolumetricPresentationInputAnnotationSequence", &tags::VolumetricPresentationInputAnnotationSequence),
+        ("PrintJobID", &tags::PrintJobID),
+        ("IntendedFractionStartTime", &tags::IntendedFractionStartTime),
+        ("CarrierIDAssigningAuthority", &tags::CarrierIDAssigningAuthority),
+        ("SelectorSHValue", &tags::SelectorSHValue),
+        ("PointsBoundingBoxCoordinates", &tags::PointsBoundingBoxCoordinates),
+        ("ASLSlabSequence", &tags::ASLSlabSequence),
+        ("ReferencedReferenceImageNumber", &tags::ReferencedReferenceImageNumber),
+        ("DirectSegmentReferenceSequence", &tags::DirectSegmentReferenceSequence),
+        ("SurfacePointColorCIELabValueData", &tags::SurfacePointColorCIELabValueData),
+        ("RadiationGenerationModeMachineCodeSequence", &tags::RadiationGenerationModeMachineCodeSequence),
+        ("CArmPositionerTabletopRelationship", &tags::CarmPositionerTabletopRelationship),
+        ("StudyArrivalTime", &tags::StudyArrivalTime),
+        ("ComplexImageComponent", &tags::ComplexImageComponent),
+        ("PatientName", &tags::PatientsName),
+        ("TreatmentVerificationStatus", &tags::TreatmentVerificationStatus),
+        ("DeliveredNominalRangeModulatedRegionDepths", &tags::DeliveredNominalRangeModulatedRegionDepths),
+        ("OtherPatientIDsSequence", &tags::OtherPatientIDsSequence),
+        ("StartCardiacTriggerCountThreshold", &tags::StartCardiacTriggerCountThreshold),
+        ("SurfaceNumber", &tags::SurfaceNumber),
+        ("SpecimenReferenceSequence", &tags::SpecimenReferenceSequence),
+        ("RecommendedPresentationOpacity", &tags::RecommendedPresentationOpacity),
+        ("ReferencedRTPrescriptionSequence", &tags::ReferencedRTPrescriptionSequence),
+        ("OrganAtRiskFullVolumeDose", &tags::OrganatRiskFullvolumeDose),
+        ("BreedRegistryCodeSequence", &tags::BreedRegistryCodeSequence),
+        ("PatientWeight", &tags::PatientsWeight),
+        ("AnatomicRegionsInStudyCodeSequence", &tags::AnatomicRegionsinStudyCodeSequence),
+        ("TransducerBeamSteeringCodeSequence", &tags::TransducerBeamSteeringCodeSequence),
+        ("AnnotationContentSequence", &tags::AnnotationContentSequence),
+        ("VerticesOfThePolygonalShutter", &tags::VerticesofthePolygonalShutter),
+        ("TransportClassification", &tags::TransportClassification),
+        ("CurveNumber", &tags::CurveNumber),
+        ("LINACOutput", &tags::LINACOutput),
+        ("AnatomicLocationOfExaminingInstrumentDescriptionTrial", &tags::AnatomicLocationOfExaminingInstrumentDescription_Trial),
+        ("LateralSpreadingDeviceSettingsSequence", &tags::LateralSpreadingDeviceSettingsSequence),
+        ("YFocusCenter", &tags::YFocusCenter),
+        ("PrinterStatus", &tags::PrinterStatus),
+        ("PotentialRequestedProcedureCodeSequence", &tags::PotentialRequestedProcedureCodeSequence),
+        ("InterlockCodeSequence", &tags::InterlockCodeSequence),
+        ("AlgorithmSource", &tags::AlgorithmSource),
+        ("FailureAttributes", &tags::FailureAttributes),
+        ("ToleranceTableSequence", &tags::ToleranceTableSequence),
+        ("EndingRespiratoryAmplitude", &tags::EndingRespiratoryAmplitude),
+        ("TIPType", &tags::TIPType),
+        ("TreatmentTimeLimit", &tags::TreatmentTimeLimit),
+        ("PlaneNormal", &tags::PlaneNormal),
+        ("ReferencedOtherPlaneSequence", &tags::ReferencedOtherPlaneSequence),
+        ("ImplantSize", &tags::ImplantSize),
+        ("ResultingGeneralPurposePerformedProcedureStepsSequence", &tags::ResultingGeneralPurposePerformedProcedureStepsSequence),
+        ("RadiopharmaceuticalStartDateTime", &tags::RadiopharmaceuticalStartDateTime),
+        ("DerivationImplantAssemblyTemplateSequence", &tags::DerivationImplantAssemblyTemplateSequence),
+        ("RTImageFrameContextSequence", &tags::RTImageFrameContextSequence),
+        ("TransmitTransducerSequence", &tags::TransmitTransducerSequence),
+        ("TrackSequence", &tags::TrackSequence),
+        ("SelectorTMValue", &tags::SelectorTMValue),
+        ("RectilinearPhaseEncodeReordering", &tags::RectilinearPhaseEncodeReordering),
+        ("SpecimenDescriptionSequence", &tags::SpecimenDescriptionSequence),
+        ("OffsetOfTheFirstDirectoryRecordOfTheRootDirectoryEntity", &tags::OffsetoftheFirstDirectoryRecordoftheRootDirectoryEntity),
+        ("DVHNumberOfBins", &tags::DVHNumberofBins),
+        ("IndicationDescription", &tags::IndicationDescription),
+        ("ContrastBolusAgentPhase", &tags::ContrastBolusAgentPhase),
+        ("GraphicDimensions", &tags::GraphicDimensions),
+        ("ColumnPositionInTotalImagePixelMatrix", &tags::ColumnPositionInTotalImagePixelMatrix),
+        ("Sensitivity", &tags::Sensitivity),
+        ("WedgeOrientation", &tags::WedgeOrientation),
+        ("PrivateInformationCreatorUID", &tags::PrivateInformationCreatorUID),
+        ("FilmConsumptionSequence", &tags::FilmConsumptionSequence),
+        ("PlaneOrientationSequence", &tags::PlaneOrientationSequence),
+        ("ReplacedImplantTemplateSequence", &tags::ReplacedImplantTemplateSequence),
+        ("TwoDPlaneIntersection", &tags::Tag_2DPlaneIntersection),
+        ("AcquisitionIndex", &tags::AcquisitionIndex),
+        ("SpecimenDescriptionTrial", &tags::SpecimenDescription_Trial),
+        ("TableTopPitchAdjustedAngle", &tags::TableTopPitchAdjustedAngle),
+        ("InterpretationTranscriber", &tags::InterpretationTranscriber),
+        ("HL7InstanceIdentifier", &tags::HL7InstanceIdentifier),
+        ("IonBlockSequence", &tags::IonBlockSequence),
+        ("TwoDDegreeOfFreedomAxis", &tags::Tag_2DDegreeofFreedomAxis),
+        ("ASLBolusCutoffTechnique", &tags::ASLBolusCutoffTechnique),
+        ("InterMarkerDistance", &tags::InterMarkerDistance),
+        ("ReferencedColorPaletteInstanceUID", &tags::ReferencedColorPaletteInstanceUID),
+        ("MedicalRecordLocator", &tags::MedicalRecordLocator),
+        ("DetectorConditionsNominalFlag", &tags::DetectorConditionsNominalFlag),
+        ("ScanStartPositionSequence", &tags::ScanStartPositionSequence),
+        ("TableTopPitchRotationDirection", &tags::TableTopPitchRotationDirection),
+        ("EXIFVersion", &tags::EXIFVersion),
+        ("FixationDeviceLabel", &tags::FixationDeviceLabel),
+        ("Spoiling", &tags::Spoiling),
+        ("SkipFrameRangeFlag", &tags::SkipFrameRangeFlag),
+        ("RouteIDAssigningAuthority", &tags::RouteIDAssigningAuthority),
+        ("ContentDate", &tags::ContentDate),
+        ("MatingFeatureSetID", &tags::MatingFeatureSetID),
+        ("IntervalsAcquired", &tags::IntervalsAcquired),
+        ("ExecutionStatus", &tags::ExecutionStatus),
+        ("UltrasoundColorDataPresent", &tags::UltrasoundColorDataPresent),
+        ("NonconformingDataElementValue", &tags::NonconformingDataElementValue),
+        ("OphthalmicAxialLengthQualityMetricSequence", &tags::OphthalmicAxialLengthQualityMetricSequence),
+        ("TreatmentToleranceViolationCategory", &tags::TreatmentToleranceViolationCategory),
+        ("ThreeDImplantTemplateGroupMemberMatchingAxes", &tags::Tag_3DImplantTemplateGroupMemberMatchingAxes),
+        ("DetectorPositionSequence", &tags::DetectorPositionSequence),
+        ("ContainerDescription", &tags::ContainerDescription),
+        ("MeasuredMetersetToDoseMappingSequence", &tags::MeasuredMetersettoDoseMappingSequence),
+        ("PositiveCatchTrialsQuantity", &tags::PositiveCatchTrialsQuantity),
+        ("ImageBoxSmallScrollType", &tags::ImageBoxSmallScrollType),
+        ("ReferencedFractionGroupSequence", &tags::ReferencedFractionGroupSequence),
+        ("NominalInterval", &tags::NominalInterval),
+        ("NumberOfSeriesRelatedInstances", &tags::NumberofSeriesRelatedInstances),
+        ("PerformedProcedureStepStartDateTime", &tags::PerformedProcedureStepStartDateTime),
+        ("CurrentConfigurationID", &tags::CurrentConfigurationID),
+        ("PositioningMethodCodeSequence", &tags::PositioningMethodCodeSequence),
+        ("SourceIsotopeHalfLife", &tags::SourceIsotopeHalfLife),
+        ("FileLengthInContainer", &tags::FileLengthinContainer),
+        ("ParametersSpecificationSequence", &tags::ParametersSpecificationSequence),
+        ("DistanceSourceToPatient", &tags::DistanceSourcetoPatient),
+        ("XAAcquisitionDuration", &tags::XAAcquisitionDuration),
+        ("CalibrationDataSequence", &tags::CalibrationDataSequence),
+        ("WaveformDisplayBackgroundCIELabValue", &tags::WaveformDisplayBackgroundCIELabValue),
+        ("TransferTubeLength", &tags::TransferTubeLength),
+        ("BeamDoseVerificationControlPointSequence", &tags::BeamDoseVerificationControlPointSequence),
+        ("RTImageFramekVRadiationAcquisitionSequence", &tags::RTImageFramekVRadiationAcquisitionSequence),
+        ("ObservationUID", &tags::ObservationUID),
+        ("LargeBluePaletteColorLookupTableData", &tags::LargeBluePaletteColorLookupTableData),
+        ("FunctionalSyncPulse", &tags::FunctionalSyncPulse),
+        ("EventCodeSequence", &tags::EventCodeSequence),
+        ("ContributionDescription", &tags::ContributionDescription),
+        ("ZOffsetInSlideCoordinateSystem", &tags::ZOffsetinSlideCoordinateSystem),
+        ("SOPInstanceStatus", &tags::SOPInstanceStatus),
+        ("PixelPresentation", &tags::PixelPresentation),
+        ("MetersetRateSet", &tags::MetersetRateSet),
+        ("StartCumulativeMetersetWeight", &tags::StartCumulativeMetersetWeight),
+        ("NumberOfFrames", &tags::NumberofFrames),
+        ("SurfaceCount", &tags::SurfaceCount),
+        ("RecommendedPointRadius", &tags::RecommendedPointRadius),
+        ("SelectorUTValue", &tags::SelectorUTValue),
+        ("ItemDelimitationItem", &tags::ItemDelimitationItem),
+        ("AnnotationClipping", &tags::AnnotationClipping),
+        ("TablePositionSequence", &tags::TablePositionSequence),
+        ("TableCradleTiltAngle", &tags::TableCradleTiltAngle),
+        ("ColumnAngulation", &tags::ColumnAngulation),
+        ("ASLSlabNumber", &tags::ASLSlabNumber),
+        ("OtherMediaAvailableSequence", &tags::OtherMediaAvailableSequence),
+        ("BeamAngle", &tags::BeamAngle),
+        ("ApplicatorOpeningY", &tags::ApplicatorOpeningY),
+        ("RelationshipType", &tags::RelationshipType),
+        ("DeviceSpecificAcquisitionParameterSequence", &tags::DeviceSpecificAcquisitionParameterSequence),
+        ("ReferencedMeasuredDoseReferenceNumber", &tags::ReferencedMeasuredDoseReferenceNumber),
+        ("CoordinateSystemAxisDescription", &tags::CoordinateSystemAxisDescription),
+        ("WaveformData", &tags::WaveformData),
+        ("BolusDescription", &tags::BolusDescription),
+        ("RTPhysicianIntentIndex", &tags::RTPhysicianIntentIndex),
+        ("DoseCalibrationFactor", &tags::DoseCalibrationFactor),
+        ("RangeModulatorID", &tags::RangeModulatorID),
+        ("RTROIRelationship", &tags::RTROIRelationship),
+        ("SortByCategory", &tags::SortbyCategory),
+        ("StudyVerifiedDate", &tags::StudyVerifiedDate),
+        ("TreatmentPositionGroupUID", &tags::TreatmentPositionGroupUID),
+        ("FirstTreatmentDate", &tags::FirstTreatmentDate),
+        ("NumberOfVerticalPixels", &tags::NumberofVerticalPixels),
+        ("BeamCurrentModulationID", &tags::BeamCurrentModulationID),
+        ("EndCumulativeMetersetWeight", &tags::EndCumulativeMetersetWeight),
+        ("OrderCallbackTelecomInformation", &tags::OrderCallbackTelecomInformation),
+        ("BlendingOperationType", &tags::BlendingOperationType),
+        ("ReferencedProcedureStepSequence", &tags::ReferencedProcedureStepSequence),
+        ("ReferencedChannelShieldNumber", &tags::ReferencedChannelShieldNumber),
+        ("OperatingModeType", &tags::OperatingModeType),
+        ("DetectorGeometrySequence", &tags::DetectorGeometrySequence),
+        ("ObservationTimeTrial", &tags::ObservationTime_Trial),
+        ("ContextGroupExtensionCreatorUID", &tags::ContextGroupExtensionCreatorUID),
+        ("ModulatedScanModeType", &tags::ModulatedScanModeType),
+        ("GraphicLayerRecommendedDisplayRGBValue", &tags::GraphicLayerRecommendedDisplayRGBValue),
+        ("AlgorithmDescription", &tags::AlgorithmDescription),
+        ("GraphicGroupLabel", &tags::GraphicGroupLabel),
+        ("BolusID", &tags::BolusID),
+        ("SpecimenPreparationStepContentItemSequence", &tags::SpecimenPreparationStepContentItemSequence),
+        ("FrameOfInterestType", &tags::FrameofInterestType),
+        ("InterpretationApprovalDate", &tags::InterpretationApprovalDate),
+        ("BlendingDisplayInputSequence", &tags::BlendingDisplayInputSequence),
+        ("DCTLabel", &tags::DCTLabel),
+        ("ScheduledPerformingPhysicianName", &tags::ScheduledPerformingPhysiciansName),
+        ("PatientTreatmentPreparationMethodDescription", &tags::PatientTreatmentPreparationMethodDescription),
+        ("PresentationCreationDate", &tags::PresentationCreationDate),
+        ("SelectedTotalOphthalmicAxialLengthSequence", &tags::SelectedTotalOphthalmicAxialLengthSequence),
+        ("WaveformBitsAllocated", &tags::WaveformBitsAllocated),
+        ("ReferencedStartControlPointIndex", &tags::ReferencedStartControlPointIndex),
+        ("TreatmentMachineSpecialModeCodeSequence", &tags::TreatmentMachineSpecialModeCodeSequence),
+        ("BlockData", &tags::BlockData),
+        ("TotalNumberOfStudyRecords", &tags::TotalNumberofStudyRecords),
+        ("ConnectorType", &tags::ConnectorType),
+        ("PixelShiftFrameRange", &tags::PixelShiftFrameRange),
+        ("ScheduledStudyStopTime", &tags::ScheduledStudyStopTime),
+        ("AnnotationGroupSequence", &tags::AnnotationGroupSequence),
+        ("ImageTranslationVector", &tags::ImageTranslationVector),
+        ("SourcePosition", &tags::SourcePosition),
+        ("TimeSlotInformationSequence", &tags::TimeSlotInformationSequence),
+        ("AnomalyLocatorIndicator", &tags::AnomalyLocatorIndicator),
+        ("Mass", &tags::Mass),
+        ("ConceptualVolumeCombinationDescription", &tags::ConceptualVolumeCombinationDescription),
+        ("EquipmentModality", &tags::EquipmentModality),
+        ("DVHNormalizationDoseValue", &tags::DVHNormalizationDoseValue),
+        ("ImagingApertureSequence", &tags::ImagingApertureSequence),
+        ("AcquiredImageAreaDoseProduct", &tags::AcquiredImageAreaDoseProduct),
+        ("DisplayShadingFlag", &tags::DisplayShadingFlag),
+        ("CompensatorType", &tags::CompensatorType),
+        ("TimeOfLastCalibration", &tags::TimeofLastCalibration),
+        ("CineRate", &tags::CineRate),
+        ("BlockNumberOfPoints", &tags::BlockNumberofPoints),
+        ("TwoDMatingPoint", &tags::Tag_2DMatingPoint),
+        ("BadPixelImage", &tags::BadPixelImage),
+        ("VisualAcuityBothEyesOpenSequence", &tags::VisualAcuityBothEyesOpenSequence),
+        ("TomoTime", &tags::TomoTime),
+        ("XRayImageReceptorAngle", &tags::XRayImageReceptorAngle),
+        ("SpecifiedChannelTotalTime", &tags::SpecifiedChannelTotalTime),
+        ("ClinicalTrialProtocolID", &tags::ClinicalTrialProtocolID),
+        ("ImageBoxTileHorizontalDimension", &tags::ImageBoxTileHorizontalDimension),
+        ("SeriesInStudy", &tags::SeriesinStudy),
+        ("ReferencedConceptualVolumeUID", &tags::ReferencedConceptualVolumeUID),
+        ("ThresholdValue", &tags::ThresholdValue),
+        ("IOLPowerForExactTargetRefraction", &tags::IOLPowerForExactTargetRefraction),
+        ("ConceptualVolumeBlockingConstraint", &tags::ConceptualVolumeBlockingConstraint),
+        ("InterventionDrugName", &tags::InterventionDrugName),
+        ("VertexPointIndexList", &tags::VertexPointIndexList),
+        ("TableTopLongitudinalAdjustedPosition", &tags::TableTopLongitudinalAdjustedPosition),
+        ("FloatPixelPaddingValue", &tags::FloatPixelPaddingValue),
+        ("ShutterPresentationValue", &tags::ShutterPresentationValue),
+        ("ThreatCategory", &tags::ThreatCategory),
+        ("ReferenceCoordinates", &tags::ReferenceCoordinates),
+        ("SegmentReferenceSequence", &tags::SegmentReferenceSequence),
+        ("UserContentLabel", &tags::UserContentLabel),
+        ("CommandField", &tags::CommandField),
+        ("CylinderSequence", &tags::CylinderSequence),
+        ("InteroperabilityIndex", &tags::InteroperabilityIndex),
+        ("StudyArrivalDate", &tags::StudyArrivalDate),
+        ("ReferencedOverlayPlaneGroups", &tags::ReferencedOverlayPlaneGroups),
+        ("NumberOfAnnotations", &tags::NumberofAnnotations),
+        ("RadiusOfCircularShutter", &tags::RadiusofCircularShutter),
+        ("HPGLDocument", &tags::HPGLDocument),
+        ("ReferencedRadiationDoseIdentificationIndex", &tags::ReferencedRadiationDoseIdentificationIndex),
+        ("RTBeamDelimiterGeometrySequence", &tags::RTBeamDelimiterGeometrySequence),
+        ("WedgeNumber", &tags::WedgeNumber),
+        ("ObjectiveLensNumericalAperture", &tags::ObjectiveLensNumericalAperture),
+        ("FileSetConsistencyFlag", &tags::FilesetConsistencyFlag),
+        ("ContentSequence", &tags::ContentSequence),
+        ("InterventionDrugCodeSequence", &tags::InterventionDrugCodeSequence),
+        ("BeamModifierOrientationAngle", &tags::BeamModifierOrientationAngle),
+        ("TableTopEccentricAdjustedAngle", &tags::TableTopEccentricAdjustedAngle),
+        ("CenterOfCircularExposureControlSensingRegion", &tags::CenterofCircularExposureControlSensingRegion),
+        ("ProductDescription", &tags::ProductDescription),
+        ("CalculatedDoseReferenceDescription", &tags::CalculatedDoseReferenceDescription),
+        ("MaximumMemoryAllocation", &tags::MaximumMemoryAllocation),
+        ("HistogramLastBinValue", &tags::HistogramLastBinValue),
+        ("ImageSetSelectorUsageFlag", &tags::ImageSetSelectorUsageFlag),
+        ("DataSetDescription", &tags::DataSetDescription),
+        ("ContributingEquipmentSequence", &tags::ContributingEquipmentSequence),
+        ("FileSetAccessSequence", &tags::FileSetAccessSequence),
+        ("ToricIOLPowerForExactEmmetropiaSequence", &tags::ToricIOLPowerforExactEmmetropiaSequence),
+        ("ReferencedOverlaySequence", &tags::ReferencedOverlaySequence),
+        ("KeratometryMeasurementTypeCodeSequence", &tags::KeratometryMeasurementTypeCodeSequence),
+        ("AssessmentLabel", &tags::AssessmentLabel),
+        ("DoubleExposureFieldDeltaTrial", &tags::DoubleExposureFieldDelta_Trial),
+        ("RTImagePlane", &tags::RTImagePlane),
+        ("IndicationPhysicalPropertySequence", &tags::IndicationPhysicalPropertySequence),
+        ("DDLValue", &tags::DDLValue),
+        ("PrivateDataElementDefinitionSequence", &tags::PrivateDataElementDefinitionSequence),
+        ("OOITypeDescriptor", &tags::OOITypeDescriptor),
+        ("PrimaryPositionerIncrementSign", &tags::PrimaryPositionerIncrementSign),
+        ("AddOtherSequence", &tags::AddOtherSequence),
+        ("LuminanceValue", &tags::LuminanceValue),
+        ("AttributeOccurrenceSequence", &tags::AttributeOccurrenceSequence),
+        ("ReasonForChannelOmissionDescription", &tags::ReasonforChannelOmissionDescription),
+        ("PrinterName", &tags::PrinterName),
+        ("GraphicAnnotationUnits", &tags::GraphicAnnotationUnits),
+        ("IsocenterToBeamLimitingDeviceDistance", &tags::IsocentertoBeamLimitingDeviceDistance),
+        ("NumberOfRadiationGenerationModes", &tags::NumberofRadiationGenerationModes),
+        ("BrachyControlPointSequence", &tags::BrachyControlPointSequence),
+        ("CornealTopographySurface", &tags::CornealTopographySurface),
+        ("ROIDescription", &tags::ROIDescription),
+        ("AttributeOccurrencePrivateCreator", &tags::AttributeOccurrencePrivateCreator),
+        ("FilterBeamPathLengthMinimum", &tags::FilterBeamPathLengthMinimum),
+        ("UDISequence", &tags::UDISequence),
+        ("ContinuationPulseNumber", &tags::ContinuationPulseNumber),
+        ("SourceToBlockTrayDistance", &tags::SourcetoBlockTrayDistance),
+        ("OOIOwnerType", &tags::OOIOwnerType),
+        ("InformationFromManufacturerSequence", &tags::InformationFromManufacturerSequence),
+        ("FrameContentSequence", &tags::FrameContentSequence),
+        ("ShutterSpeedValue", &tags::ShutterSpeedValue),
+        ("ConceptualVolumeConstituentIndex", &tags::ConceptualVolumeConstituentIndex),
+        ("RelativeURIReferenceWithinEncapsulatedDocument", &tags::RelativeURIReferenceWithinEncapsulatedDocument),
+        ("CorneaMeasurementsSequence", &tags::CorneaMeasurementsSequence),
+        ("SourceToSurfaceDistance", &tags::SourcetoSurfaceDistance),
+        ("TelephoneNumberTrial", &tags::TelephoneNumber_Trial),
+        ("ImageBoxOverlapPriority", &tags::ImageBoxOverlapPriority),
+        ("ShieldingDeviceType", &tags::ShieldingDeviceType),
+        ("OverlayMode", &tags::OverlayMode),
+        ("LensThickness", &tags::LensThickness),
+        ("ContrastBolusAutoInjectionTriggerFlag", &tags::ContrastBolusAutoInjectionTriggerFlag),
+        ("FrequencyCorrection", &tags::FrequencyCorrection),
+        ("SetupDeviceType", &tags::SetupDeviceType),
+        ("GraphicGroupSequence", &tags::GraphicGroupSequence),
+        ("RegionLocationMaxY1", &tags::RegionLocationMaxY1),
+        ("ManipulatedImage", &tags::ManipulatedImage),
+        ("MeasurementEquipmentSequence", &tags::MeasurementEquipmentSequence),
+        ("ReconstructionIndex", &tags::ReconstructionIndex),
+        ("OperatorsName", &tags::OperatorsName),
+        ("AcquisitionDateTime", &tags::AcquisitionDateTime),
+        ("HistogramNumberOfBins", &tags::HistogramNumberofBins),
+        ("FrameOfReferenceToDisplayedCoordinateSystemTransformationMatrix", &tags::FrameofReferencetoDisplayedCoordinateSystemTransformationMatrix),
+        ("SegmentNumber", &tags::SegmentNumber),
+        ("EffectiveRefractiveIndex", &tags::EffectiveRefractiveIndex),
+        ("AcquisitionMatrix", &tags::AcquisitionMatrix),
+        ("ReconstructionProtocolElementSequence", &tags::ReconstructionProtocolElementSequence),
+        ("ConventionalMachineVerificationSequence", &tags::ConventionalMachineVerificationSequence),
+        ("LargestMonochromePixelValue", &tags::LargestMonochromePixelValue),
+        ("SelectorAttributeName", &tags::SelectorAttributeName),
+        ("ContentLabel", &tags::ContentLabel),
+        ("VelocityEncodingAcquisitionSequence", &tags::VelocityEncodingAcquisitionSequence),
+        ("InformationSummary", &tags::InformationSummary),
+        ("CurveDate", &tags::CurveDate),
+        ("GeometricalProperties", &tags::GeometricalProperties),
+        ("LossyImageCompressionRetired", &tags::LossyImageCompression_Retired),
+        ("WholeBodyTechnique", &tags::WholeBodyTechnique),
+        ("PrinterStatusInfo", &tags::PrinterStatusInfo),
+        ("LineSequence", &tags::LineSequence),
+        ("DecouplingChemicalShiftReference", &tags::DecouplingChemicalShiftReference),
+        ("FilterByOperator", &tags::FilterbyOperator),
+        ("PurposeOfReferenceCodeSequence", &tags::PurposeofReferenceCodeSequence),
+        ("DeviceAlternateIdentifier", &tags::DeviceAlternateIdentifier),
+        ("RouteOfAdmissions", &tags::RouteofAdmissions),
+        ("ConfigurationName", &tags::ConfigurationName),
+        ("NumberOfRRIntervals", &tags::NumberofRRIntervals),
+        ("CTAcquisitionDetailsSequence", &tags::CTAcquisitionDetailsSequence),
+        ("SynchronizedImageBoxList", &tags::SynchronizedImageBoxList),
+        ("FunctionalMRSequence", &tags::FunctionalMRSequence),
+        ("RWavePointer", &tags::RWavePointer),
+        ("InventoriedInstancesSequence", &tags::InventoriedInstancesSequence),
+        ("ScheduledPatientInstitutionResidence", &tags::ScheduledPatientInstitutionResidence),
+        ("EvaluationAttempt", &tags::EvaluationAttempt),
+        ("NumberOfAlarmObjects", &tags::NumberofAlarmObjects),
+        ("ImplantTypeCodeSequence", &tags::ImplantTypeCodeSequence),
+        ("CenterFrequency", &tags::CenterFrequency),
+        ("RTPlanName", &tags::RTPlanName),
+        ("HardcopyDeviceSoftwareVersion", &tags::HardcopyDeviceSoftwareVersion),
+        ("FillStyleSequence", &tags::FillStyleSequence),
+        ("PatientReligiousPreference", &tags::PatientsReligiousPreference),
+        ("TransformedAxisUnits", &tags::TransformedAxisUnits),
+        ("ReconstructionDescription", &tags::ReconstructionDescription),
+        ("ConfocalMode", &tags::ConfocalMode),
+        ("OptotypeDetailedDefinition", &tags::OptotypeDetailedDefinition),
+        ("DeliveredMeterset", &tags::DeliveredMeterset),
+        ("PerformedProcessingParametersSequence", &tags::PerformedProcessingParametersSequence),
+        ("ContrastBolusUsageSequence", &tags::ContrastBolusUsageSequence),
+        ("RTPlanDate", &tags::RTPlanDate),
+        ("OverrideParameterPointer", &tags::OverrideParameterPointer),
+        ("ShadowStyle", &tags::ShadowStyle),
+        ("RequestedDecimateCropBehavior", &tags::RequestedDecimateCropBehavior),
+        ("FunctionalGroupPointer", &tags::FunctionalGroupPointer),
+        ("CoordinateSystemAxisCodeSequence", &tags::CoordinateSystemAxisCodeSequence),
+        ("WedgeOffsetY", &tags::WedgeOffsetY),
+        ("ConceptualVolumeOptimizationPrecedence", &tags::ConceptualVolumeOptimizationPrecedence),
+        ("DistanceSourceToDataCollectionCenter", &tags::DistanceSourcetoDataCollectionCenter),
+        ("RepetitionTime", &tags::RepetitionTime),
+        ("BeamName", &tags::BeamName),
+        ("HorizontalPrismBase", &tags::HorizontalPrismBase),
+        ("SourceManufacturer", &tags::SourceManufacturer),
+        ("MinimumStoredValueMapped", &tags::MinimumStoredValueMapped),
+        ("WedgeMaterialVelocity", &tags::WedgeMaterialVelocity),
+        ("TableLateralIncrement", &tags::TableLateralIncrement),
+        ("ReferencedChannelNumber", &tags::ReferencedChannelNumber),
+        ("IlluminationWaveLength", &tags::IlluminationWaveLength),
+        ("TableOfPixelValues", &tags::TableofPixelValues),
+        ("HalfValueLayer", &tags::HalfValueLayer),
+        ("ConsentForClinicalTrialUseSequence", &tags::ConsentforClinicalTrialUseSequence),
+        ("OrderFillerIdentifierSequence", &tags::OrderFillerIdentifierSequence),
+        ("ReferenceDoseType", &tags::ReferenceDoseType),
+        ("MatrixSequence", &tags::MatrixSequence),
+        ("DistributionAddress", &tags::DistributionAddress),
+        ("VisualAcuityRightEyeSequence", &tags::VisualAcuityRightEyeSequence),
+        ("BlockIdentifyingInformationStatus", &tags::BlockIdentifyingInformationStatus),
+        ("DoseCalibrationConditionsVerifiedFlag", &tags::DoseCalibrationConditionsVerifiedFlag),
+        ("GPSImgDirectionRef", &tags::GPSImgDirectionRef),
+        ("VisualEvaluationResultSequence", &tags::VisualEvaluationResultSequence),
+        ("ReferenceDosePointCoordinates", &tags::ReferenceDosePointCoordinates),
+        ("RelatedAssertionSequence", &tags::RelatedAssertionSequence),
+        ("HardcopyDeviceManufacturer", &tags::HardcopyDeviceManufacturer),
+        ("PositionerIsocenterSecondaryAngle", &tags::PositionerIsocenterSecondaryAngle),
+        ("RoboticBaseLocationIndicator", &tags::RoboticBaseLocationIndicator),
+        ("TypeOfDetectorMotion", &tags::TypeofDetectorMotion),
+        ("UserContentLongLabel", &tags::UserContentLongLabel),
+        ("LargestValidPixelValue", &tags::LargestValidPixelValue),
+        ("DopplerCorrectionAngle", &tags::DopplerCorrectionAngle),
+        ("InterpretationStatusID", &tags::InterpretationStatusID),
+        ("PatientSex", &tags::PatientsSex),
+        ("LensConstantDescription", &tags::LensConstantDescription),
+        ("NotchFilterBandwidth", &tags::NotchFilterBandwidth),
+        ("PlateID", &tags::PlateID),
+        ("Illumination", &tags::Illumination),
+        ("TreatmentRecordContentOrigin", &tags::TreatmentRecordContentOrigin),
+        ("IOLManufacturer", &tags::IOLManufacturer),
+        ("PartialViewCodeSequence", &tags::PartialViewCodeSequence),
+        ("DeviceLength", &tags::DeviceLength),
+        ("RTTreatmentPhaseIndex", &tags::RTTreatmentPhaseIndex),
+        ("FrameAnatomySequence", &tags::FrameAnatomySequence),
+        ("MydriaticAgentConcentrationUnitsSequence", &tags::MydriaticAgentConcentrationUnitsSequence),
+        ("AutosequenceFlag", &tags::AutosequenceFlag),
+        ("StereoPairsSequence", &tags::StereoPairsSequence),
+        ("ProtocolPlanningInformation", &tags::ProtocolPlanningInformation),
+        ("OrganizationalRoleCodeSequence", &tags::OrganizationalRoleCodeSequence),
+        ("LUTLabel", &tags::LUTLabel),
+        ("MotionSynchronizationSequence", &tags::MotionSynchronizationSequence),
+        ("DimensionOrganizationType", &tags::DimensionOrganizationType),
+        ("OpticalPathIdentifier", &tags::OpticalPathIdentifier),
+        ("GeneralAccessoryDescription", &tags::GeneralAccessoryDescription),
+        ("DimensionIndexPointer", &tags::DimensionIndexPointer),
+        ("ReferencedInstanceSequence", &tags::ReferencedInstanceSequence),
+        ("MRSpectroscopyAcquisitionType", &tags::MRSpectroscopyAcquisitionType),
+        ("StereoBaselineAngle", &tags::StereoBaselineAngle),
+        ("ReferencedPatientSetupNumber", &tags::ReferencedPatientSetupNumber),
+        ("RefractiveIndexApplied", &tags::RefractiveIndexApplied),
+        ("ProtocolElementCharacteristicsSummary", &tags::ProtocolElementCharacteristicsSummary),
+        ("ApplicationSetupCheck", &tags::ApplicationSetupCheck),
+        ("RequestedProcedurePriority", &tags::RequestedProcedurePriority),
+        ("SelectorASValue", &tags::SelectorASValue),
+        ("RecommendedExposureIndex", &tags::RecommendedExposureIndex),
+        ("HPGLPenLabel", &tags::HPGLPenLabel),
+        ("ProbeDriveNotes", &tags::ProbeDriveNotes),
+        ("ReportStatusIDTrial", &tags::ReportStatusID_Trial),
+        ("TMLinePositionY0", &tags::TMLinePositionY0),
+        ("CommentsOnPatientPerformanceOfVisualField", &tags::CommentsonPatientsPerformanceofVisualField),
+        ("FillerOrderNumberImagingServiceRequest", &tags::FillerOrderNumberImagingServiceRequest),
+        ("SoundPathLength", &tags::SoundPathLength),
+        ("SCPStatus", &tags::SCPStatus),
+        ("TransducerType", &tags::TransducerType),
+        ("AngularViewVector", &tags::AngularViewVector),
+        ("AccessoryCode", &tags::AccessoryCode),
+        ("RTPrescriptionLabel", &tags::RTPrescriptionLabel),
+        ("CTXRayDetailsSequence", &tags::CTXRayDetailsSequence),
+        ("LocalizedDeviationProbabilitySequence", &tags::LocalizedDeviationProbabilitySequence),
+        ("StrainSourceRegistryCodeSequence", &tags::StrainSourceRegistryCodeSequence),
+        ("ThreatROIVoxelSequence", &tags::ThreatROIVoxelSequence),
+        ("SourceToReferenceObjectDistance", &tags::SourcetoReferenceObjectDistance),
+        ("PatternOnOpacity", &tags::PatternOnOpacity),
+        ("ReferencedROINumber", &tags::ReferencedROINumber),
+        ("DecouplingMethod", &tags::DecouplingMethod),
+        ("AbstractPriorValue", &tags::AbstractPriorValue),
+        ("RouteID", &tags::RouteID),
+        ("FileAccessSequence", &tags::FileAccessSequence),
+        ("BloodSignalNulling", &tags::BloodSignalNulling),
+        ("ReferencedBeamSequence", &tags::ReferencedBeamSequence),
+        ("DistanceBetweenBscanSlabs", &tags::DistanceBetweenBscanSlabs),
+        ("PhototimerSetting", &tags::PhototimerSetting),
+        ("NominalPercentageOfCardiacPhase", &tags::NominalPercentageofCardiacPhase),
+        ("ResidualSyringeCounts", &tags::ResidualSyringeCounts),
+        ("IsocenterToCompensatorDistances", &tags::IsocentertoCompensatorDistances),
+        ("PETTableDynamicsSequence", &tags::PETTableDynamicsSequence),
+        ("BrachyPulseControlPointDeliveredSequence", &tags::BrachyPulseControlPointDeliveredSequence),
+        ("RegionSpatialFormat", &tags::RegionSpatialFormat),
+        ("SwitchingPhaseNumber", &tags::SwitchingPhaseNumber),
+        ("GantryType", &tags::GantryType),
+        ("RTBeamLimitingDeviceAngle", &tags::RTBeamLimitingDeviceAngle),
+        ("TestResult", &tags::TestResult),
+        ("NominalCardiacTriggerDelayTime", &tags::NominalCardiacTriggerDelayTime),
+        ("OverlayNumber", &tags::OverlayNumber),
+        ("LuminanceResultSequence", &tags::LuminanceResultSequence),
+        ("ActualCardiacTriggerDelayTime", &tags::ActualCardiacTriggerDelayTime),
+        ("DisplacementMatrix", &tags::DisplacementMatrix),
+        ("StartRelativeDensityDifferenceThreshold", &tags::StartRelativeDensityDifferenceThreshold),
+        ("FiducialSetSequence", &tags::FiducialSetSequence),
+        ("TemplateLocalVersion", &tags::TemplateLocalVersion),
+        ("BeamLimitingDeviceLeafPairsSequence", &tags::BeamLimitingDeviceLeafPairsSequence),
+        ("IlluminationTypeCodeSequence", &tags::IlluminationTypeCodeSequence),
+        ("OverlaySmoothingType", &tags::OverlaySmoothingType),
+        ("DiffusionBValueXX", &tags::DiffusionbvalueXX),
+        ("ConceptualVolumeIdentificationSequence", &tags::ConceptualVolumeIdentificationSequence),
+        ("ContourSlabThickness", &tags::ContourSlabThickness),
+        ("DetectorIsocenterPrimaryAngle", &tags::DetectorIsocenterPrimaryAngle),
+        ("IlluminationPower", &tags::IlluminationPower),
+        ("PowerlineFrequency", &tags::PowerlineFrequency),
+        ("ImplantAssemblyTemplateType", &tags::ImplantAssemblyTemplateType),
+        ("FixationLightPolarAngle", &tags::FixationLightPolarAngle),
+        ("ResultsID", &tags::ResultsID),
+        ("TimeOfFlightInformationUsed", &tags::TimeofFlightInformationUsed),
+        ("PresentationLUTSequence", &tags::PresentationLUTSequence),
+        ("RadiopharmaceuticalVolume", &tags::RadiopharmaceuticalVolume),
+        ("LINACEnergy", &tags::LINACEnergy),
+        ("ContinuityOfContent", &tags::ContinuityOfContent),
+        ("SettlingPhaseFrame", &tags::SettlingPhaseFrame),
+        ("RenderProjection", &tags::RenderProjection),
+        ("GridID", &tags::GridID),
+        ("TransducerGeometryCodeSequence", &tags::TransducerGeometryCodeSequence),
+        ("PerformedProcedureStepDescription", &tags::PerformedProcedureStepDescription),
+        ("ListOfMIMETypes", &tags::ListofMIMETypes),
+        ("DataRepresentation", &tags::DataRepresentation),
+        ("FocalLength", &tags::FocalLength),
+        ("PrimaryChromaticities", &tags::PrimaryChromaticities),
+        ("BeamDeliveryDurationLimit", &tags::BeamDeliveryDurationLimit),
+        ("SensitivityValue", &tags::SensitivityValue),
+        ("IOLFormulaCodeSequence", &tags::IOLFormulaCodeSequence),
+        ("RadiationGenerationModeLabel", &tags::RadiationGenerationModeLabel),
+        ("ChannelEffectiveLength", &tags::ChannelEffectiveLength),
+        ("ShutterLeftVerticalEdge", &tags::ShutterLeftVerticalEdge),
+        ("ReportDetailSequenceTrial", &tags::ReportDetailSequence_Trial),
+        ("SlabThickness", &tags::SlabThickness),
+        ("CTImagingAcquisitionParameterSequence", &tags::CTImagingAcquisitionParameterSequence),
+        ("OOIType", &tags::OOIType),
+        ("TimezoneOffsetFromUTC", &tags::TimezoneOffsetFromUTC),
+        ("PixelValueMappingCodeSequence", &tags::PixelValueMappingCodeSequence),
+        ("RetrieveAETitle", &tags::RetrieveAETitle),
+        ("ContentItemModifierSequence", &tags::ContentItemModifierSequence),
+        ("HangingProtocolUserIdentificationCodeSequence", &tags::HangingProtocolUserIdentificationCodeSequence),
+        ("RequestedSOPInstanceUID", &tags::RequestedSOPInstanceUID),
+        ("RTBeamLimitingDeviceOpeningSequence", &tags::RTBeamLimitingDeviceOpeningSequence),
+        ("SecondaryReviewerName", &tags::SecondaryReviewerName),
+        ("LensDescription", &tags::LensDescription),
+        ("ImageType", &tags::ImageType),
+        ("Allergies", &tags::Allergies),
+        ("IVUSPullbackStartFrameNumber", &tags::IVUSPullbackStartFrameNumber),
+        ("DigitalZoomRatio", &tags::DigitalZoomRatio),
+        ("OECFColumns", &tags::OECFColumns),
+        ("FillerOrderNumberProcedure", &tags::FillerOrderNumberProcedure),
+        ("SelectorSLValue", &tags::SelectorSLValue),
+        ("ParameterPointer", &tags::ParameterPointer),
+        ("IntendedRTTreatmentPhaseSequence", &tags::IntendedRTTreatmentPhaseSequence),
+        ("PerProjectionAcquisitionSequence", &tags::PerProjectionAcquisitionSequence),
+        ("NumberOfTableBreakPoints", &tags::NumberofTableBreakPoints),
+        ("PhaseDelay", &tags::PhaseDelay),
+        ("SourceReconstructionProtocolElementNumber", &tags::SourceReconstructionProtocolElementNumber),
+        ("StereoVerticalPixelOffset", &tags::StereoVerticalPixelOffset),
+        ("AutorefractionLeftEyeSequence", &tags::AutorefractionLeftEyeSequence),
+        ("WhitePointFlag", &tags::WhitePointFlag),
+        ("BeamDosePointSSD", &tags::BeamDosePointSSD),
+        ("LargeRedPaletteColorLookupTableDescriptor", &tags::LargeRedPaletteColorLookupTableDescriptor),
+        ("RecommendedLineThickness", &tags::RecommendedLineThickness),
+        ("ReasonForOmissionDescription", &tags::ReasonforOmissionDescription),
+        ("PriorTreatmentReferenceSequence", &tags::PriorTreatmentReferenceSequence),
+        ("AssessedAttributeValueSequence", &tags::AssessedAttributeValueSequence),
+        ("ASLCrusherDescription", &tags::ASLCrusherDescription),
+        ("ExposureDoseSequence", &tags::ExposureDoseSequence),
+        ("ColorFilterArrayPatternRows", &tags::ColorFilterArrayPatternRows),
+        ("PatientMotionCorrected", &tags::PatientMotionCorrected),
+        ("ImpedanceMeasurementCurrentType", &tags::ImpedanceMeasurementCurrentType),
+        ("WedgeAngleFloat", &tags::WedgeAngleFloat),
+        ("MeasuredCenterFrequency", &tags::MeasuredCenterFrequency),
+        ("ShowGraphicAnnotationFlag", &tags::ShowGraphicAnnotationFlag),
+        ("FindingsGroupRecordingDateTrial", &tags::FindingsGroupRecordingDate_Trial),
+        ("ParallelRTBeamDelimiterDeviceOrientationLabelCodeSequence", &tags::ParallelRTBeamDelimiterDeviceOrientationLabelCodeSequence),
+        ("TotalNumberOfExposures", &tags::TotalNumberofExposures),
+        ("ElementDimensionA", &tags::ElementDimensionA),
+        ("WhitePoint", &tags::WhitePoint),
+        ("AcquisitionNumber", &tags::AcquisitionNumber),
+        ("UniversalEntityID", &tags::UniversalEntityID),
+        ("ReferencedDateTime", &tags::ReferencedDateTime),
+        ("AnnotationGroupLabel", &tags::AnnotationGroupLabel),
+        ("MappingResourceIdentificationSequence", &tags::MappingResourceIdentificationSequence),
+        ("AcquisitionType", &tags::AcquisitionType),
+        ("EffectiveEchoTime", &tags::EffectiveEchoTime),
+        ("PerformedProcedureCodeSequence", &tags::PerformedProcedureCodeSequence),
+        ("MultipleComponentApprovalSequence", &tags::MultipleComponentApprovalSequence),
+        ("IssuerOfPatientIDQualifiersSequence", &tags::IssuerofPatientIDQualifiersSequence),
+        ("FrameLabelVector", &tags::FrameLabelVector),
+        ("PerformedProcedureTypeDescription", &tags::PerformedProcedureTypeDescription),
+        ("EndingRespiratoryPhase", &tags::EndingRespiratoryPhase),
+        ("NumberOfLeafJawPairs", &tags::NumberofLeafJawPairs),
+        ("OtherFailuresSequence", &tags::OtherFailuresSequence),
+        ("AttachedContours", &tags::AttachedContours),
+        ("MinimumHoursBetweenFractions", &tags::MinimumHoursbetweenFractions),
+        ("FalseNegativesQuantity", &tags::FalseNegativesQuantity),
+        ("RangeModulatorGatingStopWaterEquivalentThickness", &tags::RangeModulatorGatingStopWaterEquivalentThickness),
+        ("IVUSPullbackStopFrameNumber", &tags::IVUSPullbackStopFrameNumber),
+        ("ReferencedWaveformChannels", &tags::ReferencedWaveformChannels),
+        ("EntranceDoseInmGy", &tags::EntranceDoseinmGy),
+        ("ClinicalTrialSiteName", &tags::ClinicalTrialSiteName),
+        ("UnassignedSharedConvertedAttributesSequence", &tags::UnassignedSharedConvertedAttributesSequence),
+        ("AnatomicStructureReferencePoint", &tags::AnatomicStructureReferencePoint),
+        ("NumberOfFractions", &tags::NumberofFractions),
+        ("RadiationDoseIdentificationLabel", &tags::RadiationDoseIdentificationLabel),
+        ("EncryptedAttributesSequence", &tags::EncryptedAttributesSequence),
+        ("FlatKeratometricAxisSequence", &tags::FlatKeratometricAxisSequence),
+        ("StandardOutputSensitivity", &tags::StandardOutputSensitivity),
+        ("PatientReliabilityIndicator", &tags::PatientReliabilityIndicator),
+        ("FractionGroupSequence", &tags::FractionGroupSequence),
+        ("EquivalentCodeSequence", &tags::EquivalentCodeSequence),
+        ("NumberOfScanSpotPositions", &tags::NumberofScanSpotPositions),
+        ("IonMachineVerificationSequence", &tags::IonMachineVerificationSequence),
+        ("TableTopEccentricAngleTolerance", &tags::TableTopEccentricAngleTolerance),
+        ("ImageFilterDetailsSequence", &tags::ImageFilterDetailsSequence),
+        ("PatientID", &tags::PatientID),
+        ("AcquisitionMethod", &tags::AcquisitionMethod),
+        ("StructureSetLabel", &tags::StructureSetLabel),
+        ("ReferencedRTPlanSequence", &tags::ReferencedRTPlanSequence),
+        ("FlowRTPSamplingRate", &tags::FlowRTPSamplingRate),
+        ("ProcedureVersion", &tags::ProcedureVersion),
+        ("OrganDose", &tags::OrganDose),
+        ("GantryDetectorSlew", &tags::GantryDetectorSlew),
+        ("MRDRDirectoryRecordOffset", &tags::MRDRDirectoryRecordOffset),
+        ("CalibrationSettingsSequence", &tags::CalibrationSettingsSequence),
+        ("FileAccessURI", &tags::FileAccessURI),
+        ("RequestingPhysicianIdentificationSequence", &tags::RequestingPhysicianIdentificationSequence),
+        ("ThreeDPlaneNormal", &tags::Tag_3DPlaneNormal),
+        ("HeartRate", &tags::HeartRate),
+        ("SystemStatus", &tags::SystemStatus),
+        ("DeliveredDepthDoseParametersSequence", &tags::DeliveredDepthDoseParametersSequence),
+        ("ROIContourSequence", &tags::ROIContourSequence),
+        ("RequestedMediaApplicationProfile", &tags::RequestedMediaApplicationProfile),
+        ("IndicationLabel", &tags::IndicationLabel),
+        ("DimensionIndexSequence", &tags::DimensionIndexSequence),
+        ("ContextUID", &tags::ContextUID),
+        ("DiameterOfVisibility", &tags::DiameterofVisibility),
+        ("ReviewTime", &tags::ReviewTime),
+        ("GraphicLayerSequence", &tags::GraphicLayerSequence),
+        ("BitsGrouped", &tags::BitsGrouped),
+        ("DecoupledNucleus", &tags::DecoupledNucleus),
+        ("ModelUsageCodeSequence", &tags::ModelUsageCodeSequence),
+        ("ROIName", &tags::ROIName),
+        ("ApertureValue", &tags::ApertureValue),
+        ("BrachyAccessoryDeviceID", &tags::BrachyAccessoryDeviceID),
+        ("ReferencedImageSequence", &tags::ReferencedImageSequence),
+        ("ViewPosition", &tags::ViewPosition),
+        ("ProtocolElementPurpose", &tags::ProtocolElementPurpose),
+        ("DoubleFloatRealWorldValueFirstValueMapped", &tags::DoubleFloatRealWorldValueFirstValueMapped),
+        ("IntendedPhaseStartDate", &tags::IntendedPhaseStartDate),
+        ("MatrixRegistrationSequence", &tags::MatrixRegistrationSequence),
+        ("ProjectionPixelCalibrationSequence", &tags::ProjectionPixelCalibrationSequence),
+        ("ChannelShieldSequence", &tags::ChannelShieldSequence),
+        ("Sharpness", &tags::Sharpness),
+        ("ScanStopPositionSequence", &tags::ScanStopPositionSequence),
+        ("MultipleSpinEcho", &tags::MultipleSpinEcho),
+        ("IntensifierSize", &tags::IntensifierSize),
+        ("RadiationDoseInVivoMeasurementLabel", &tags::RadiationDoseInVivoMeasurementLabel),
+        ("PertinentSOPClassesInSeries", &tags::PertinentSOPClassesinSeries),
+        ("ReconstructionType", &tags::ReconstructionType),
+        ("ImageQualityIndicatorMaterial", &tags::ImageQualityIndicatorMaterial),
+        ("VerificationDateTime", &tags::VerificationDateTime),
+        ("TypeOfPatientID", &tags::TypeofPatientID),
+        ("PatientPosition", &tags::PatientPosition),
+        ("CodingSchemeResponsibleOrganization", &tags::CodingSchemeResponsibleOrganization),
+        ("ReferencedPatientPhotoSequence", &tags::ReferencedPatientPhotoSequence),
+        ("FrameAcquisitionDateTime", &tags::FrameAcquisitionDateTime),
+        ("AlgorithmParameters", &tags::AlgorithmParameters),
+        ("ChannelIdentificationCode", &tags::ChannelIdentificationCode),
+        ("IOLPowerSequence", &tags::IOLPowerSequence),
+        ("BrachyAccessoryDeviceNominalTransmission", &tags::BrachyAccessoryDeviceNominalTransmission),
+        ("AssigningFacilitySequence", &tags::AssigningFacilitySequence),
+        ("ReferencedImageBoxSequence", &tags::ReferencedImageBoxSequence),
+        ("ScanningSpotSize", &tags::ScanningSpotSize),
+        ("MultienergyCTPathIndex", &tags::MultienergyCTPathIndex),
+        ("DetectorDescription", &tags::DetectorDescription),
+        ("TableHeadTiltAngle", &tags::TableHeadTiltAngle),
+        ("VolumeLocalizationTechnique", &tags::VolumeLocalizationTechnique),
+        ("NegativeCatchTrialsQuantity", &tags::NegativeCatchTrialsQuantity),
+        ("DiffusionBValueYZ", &tags::DiffusionbvalueYZ),
+        ("TerminationRespiratoryTriggerCountThreshold", &tags::TerminationRespiratoryTriggerCountThreshold),
+        ("SourceApplicatorStepSize", &tags::SourceApplicatorStepSize),
+        ("TwoDLineCoordinatesSequence", &tags::Tag_2DLineCoordinatesSequence),
+        ("InterventionStatus", &tags::InterventionStatus),
+        ("DataElementDescription", &tags::DataElementDescription),
+        ("SourceToPatientSurfaceDistance", &tags::SourcetoPatientSurfaceDistance),
+        ("ReferencedTransferSyntaxUIDInFile", &tags::ReferencedTransferSyntaxUIDinFile),
+        ("DegreeOfFreedomID", &tags::DegreeofFreedomID),
+        ("ParallelRTBeamDelimiterLeafMountingSide", &tags::ParallelRTBeamDelimiterLeafMountingSide),
+        ("VisualFieldTestDuration", &tags::VisualFieldTestDuration),
+        ("SecondaryApprovalStatus", &tags::SecondaryApprovalStatus),
+        ("ReferencedSOPInstanceUIDInFile", &tags::ReferencedSOPInstanceUIDinFile),
+        ("HistogramBinWidth", &tags::HistogramBinWidth),
+        ("SpecimenSequence", &tags::SpecimenSequence),
+        ("AITDeviceType", &tags::AITDeviceType),
+        ("Shininess", &tags::Shininess),
+        ("WaterReferencedPhaseCorrection", &tags::WaterReferencedPhaseCorrection),
+        ("PrinterPixelSpacing", &tags::PrinterPixelSpacing),
+        ("UpperLowerPixelValues", &tags::UpperLowerPixelValues),
+        ("ContrastBolusAgentAdministered", &tags::ContrastBolusAgentAdministered),
+        ("SurfaceProcessingRatio", &tags::SurfaceProcessingRatio),
+        ("LeftLensSequence", &tags::LeftLensSequence),
+        ("ImagingDeviceLocationMatrixSequence", &tags::ImagingDeviceLocationMatrixSequence),
+        ("OphthalmicAxialLengthSequence", &tags::OphthalmicAxialLengthSequence),
+        ("NumberOfBlockSlabItems", &tags::NumberofBlockSlabItems),
+        ("ParallelReductionFactorOutOfPlane", &tags::ParallelReductionFactoroutofplane),
+        ("ReferencedSegmentNumber", &tags::ReferencedSegmentNumber),
+        ("MACCalculationTransferSyntaxUID", &tags::MACCalculationTransferSyntaxUID),
+        ("ParallelAcquisition", &tags::ParallelAcquisition),
+        ("ImageSetSelectorCategory", &tags::ImageSetSelectorCategory),
+        ("NumberOfBscansPerFrame", &tags::NumberofBscansPerFrame),
+        ("AcousticCouplingMediumFlag", &tags::AcousticCouplingMediumFlag),
+        ("StudyComponentStatusID", &tags::StudyComponentStatusID),
+        ("ProbeResistance", &tags::ProbeResistance),
+        ("BrachyTreatmentType", &tags::BrachyTreatmentType),
+        ("SourceToGeneralAccessoryDistance", &tags::SourcetoGeneralAccessoryDistance),
+        ("RangingDepth", &tags::RangingDepth),
+        ("RangeModulatorDescription", &tags::RangeModulatorDescription),
+        ("FilterLookupTableSequence", &tags::FilterLookupTableSequence),
+        ("ChannelSourceSequence", &tags::ChannelSourceSequence),
+        ("ExcitationSpectralWidth", &tags::ExcitationSpectralWidth),
+        ("TreatmentSummaryMeasuredDoseReferenceSequence", &tags::TreatmentSummaryMeasuredDoseReferenceSequence),
+        ("PrescriptionOverviewSequence", &tags::PrescriptionOverviewSequence),
+        ("DecouplingFrequency", &tags::DecouplingFrequency),
+        ("NumberOfReferences", &tags::NumberofReferences),
+        ("CorrectedLocalizedDeviationFromNormalProbabilityCalculated", &tags::CorrectedLocalizedDeviationFromNormalProbabilityCalculated),
+        ("ReconstructionDiameter", &tags::ReconstructionDiameter),
+        ("PTOLocationDescription", &tags::PTOLocationDescription),
+        ("GeneralizedDefectSensitivityDeviationAlgorithmSequence", &tags::GeneralizedDefectSensitivityDeviationAlgorithmSequence),
+        ("InstanceCoercionDateTime", &tags::InstanceCoercionDateTime),
+        ("IterativeReconstructionMethod", &tags::IterativeReconstructionMethod),
+        ("PatientTreatmentPreparationDeviceSequence", &tags::PatientTreatmentPreparationDeviceSequence),
+        ("ProjectionImagingAcquisitionParameterSequence", &tags::ProjectionImagingAcquisitionParameterSequence),
+        ("SourceSerialNumber", &tags::SourceSerialNumber),
+        ("GrayLookupTableData", &tags::GrayLookupTableData),
+        ("BiPlaneAcquisitionSequence", &tags::BiPlaneAcquisitionSequence),
+        ("SpecimenTypeCodeSequence", &tags::SpecimenTypeCodeSequence),
+        ("GantryPitchAngleTolerance", &tags::GantryPitchAngleTolerance),
+        ("ProcedureStepDiscontinuationReasonCodeSequence", &tags::ProcedureStepDiscontinuationReasonCodeSequence),
+        ("PatientPrimaryLanguageCodeSequence", &tags::PatientsPrimaryLanguageCodeSequence),
+        ("SliceLocation", &tags::SliceLocation),
+        ("CatheterRotationalRate", &tags::CatheterRotationalRate),
+        ("VerifyingObserverName", &tags::VerifyingObserverName),
+        ("BeamLimitingDeviceRotationDirection", &tags::BeamLimitingDeviceRotationDirection),
+        ("CardiacBeatRejectionTechnique", &tags::CardiacBeatRejectionTechnique),
+        ("ChannelRecommendedDisplayCIELabValue", &tags::ChannelRecommendedDisplayCIELabValue),
+        ("BscanCycleTime", &tags::BscanCycleTime),
+        ("TimeOfDocumentCreationOrVerbalTransactionTrial", &tags::TimeofDocumentCreationorVerbalTransaction_Trial),
+        ("ExcitationWavelength", &tags::ExcitationWavelength),
+        ("MaskFrameNumbers", &tags::MaskFrameNumbers),
+        ("PatientDeathDateInAlternativeCalendar", &tags::PatientsDeathDateinAlternativeCalendar),
+        ("StrainStockSequence", &tags::StrainStockSequence),
+        ("RealTimeBulkDataFlowSequence", &tags::RealTimeBulkDataFlowSequence),
+        ("AutoKVPSelectionType", &tags::AutoKVPSelectionType),
+        ("FilterHighFrequency", &tags::FilterHighFrequency),
+        ("KVP", &tags::KVP),
+        ("IntravascularLongitudinalDistance", &tags::IntravascularLongitudinalDistance),
+        ("ObjectDirectoryBinaryIdentifierTrial", &tags::ObjectDirectoryBinaryIdentifier_Trial),
+        ("StimulusColorCodeSequence", &tags::StimulusColorCodeSequence),
+        ("TwoDimensionalToThreeDimensionalMapSequence", &tags::TwoDimensionaltoThreeDimensionalMapSequence),
+        ("AcquisitionDeviceTypeCodeSequence", &tags::AcquisitionDeviceTypeCodeSequence),
+        ("AlternateValueSequence", &tags::AlternateValueSequence),
+        ("ReferencedDoseReferenceNumber", &tags::ReferencedDoseReferenceNumber),
+        ("ReferenceLocationLabel", &tags::ReferenceLocationLabel),
+        ("MaterialIsolationDiameter", &tags::MaterialIsolationDiameter),
+        ("VectorGridData", &tags::VectorGridData),
+        ("BoardingPassID", &tags::BoardingPassID),
+        ("PatientTreatmentPreparationMethodCodeSequence", &tags::PatientTreatmentPreparationMethodCodeSequence),
+        ("AcousticCouplingMediumCodeSequence", &tags::AcousticCouplingMediumCodeSequence),
+        ("PixelComponentDataType", &tags::PixelComponentDataType),
+        ("T2Preparation", &tags::T2Preparation),
+        ("HumanPerformerName", &tags::HumanPerformersName),
+        ("ServiceEpisodeID", &tags::ServiceEpisodeID),
+        ("EnhancedRTBeamLimitingOpeningSequence", &tags::EnhancedRTBeamLimitingOpeningSequence),
+        ("SteeringAngle", &tags::SteeringAngle),
+        ("ParametricMapFrameTypeSequence", &tags::ParametricMapFrameTypeSequence),
+        ("DataCollectionCenterPatient", &tags::DataCollectionCenterPatient),
+        ("AgeCorrectedSensitivityDeviationAlgorithmSequence", &tags::AgeCorrectedSensitivityDeviationAlgorithmSequence),
+        ("TrackingID", &tags::TrackingID),
+        ("SensorTemperature", &tags::SensorTemperature),
+        ("ShowImageTrueSizeFlag", &tags::ShowImageTrueSizeFlag),
+        ("BreastImplantPresent", &tags::BreastImplantPresent),
+        ("PRCSToRCSOrientation", &tags::PRCStoRCSOrientation),
+        ("RandomsCorrected", &tags::RandomsCorrected),
+        ("TimeDistributionProtocol", &tags::TimeDistributionProtocol),
+        ("NonidentifyingPrivateElements", &tags::NonidentifyingPrivateElements),
+        ("AnteriorChamberDepthDefinitionCodeSequence", &tags::AnteriorChamberDepthDefinitionCodeSequence),
+        ("IntravascularOCTFrameContentSequence", &tags::IntravascularOCTFrameContentSequence),
+        ("ImageSetSelectorSequence", &tags::ImageSetSelectorSequence),
+        ("ExposuresOnDetectorSinceManufactured", &tags::ExposuresonDetectorSinceManufactured),
+        ("ISOSpeedLatitudeyyy", &tags::ISOSpeedLatitudeyyy),
+        ("ImageIndex", &tags::ImageIndex),
+        ("ReferencedDeviceIndex", &tags::ReferencedDeviceIndex),
+        ("MeasurementLaterality", &tags::MeasurementLaterality),
+        ("MPRTopLeftHandCorner", &tags::MPRTopLeftHandCorner),
+        ("ProductParameterSequence", &tags::ProductParameterSequence),
+        ("RTDeviceDistanceReferenceLocationCodeSequence", &tags::RTDeviceDistanceReferenceLocationCodeSequence),
+        ("FirstOrderPhaseCorrection", &tags::FirstOrderPhaseCorrection),
+        ("ContrastBolusStartTime", &tags::ContrastBolusStartTime),
+        ("DocumentIdentifierCodeSequenceTrial", &tags::DocumentIdentifierCodeSequence_Trial),
+        ("OrganAtRiskLimitDose", &tags::OrganatRiskLimitDose),
+        ("DACType", &tags::DACType),
+        ("EnvironmentalConditions", &tags::EnvironmentalConditions),
+        ("ComponentAssemblySequence", &tags::ComponentAssemblySequence),
+        ("FrameOfReferenceUID", &tags::FrameofReferenceUID),
+        ("SourceAxisDistance", &tags::SourceAxisDistance),
+        ("SeriesNumber", &tags::SeriesNumber),
+        ("FrameOriginTimestamp", &tags::FrameOriginTimestamp),
+        ("FilmBoxContentSequence", &tags::FilmBoxContentSequence),
+        ("WedgeTimeDelay", &tags::WedgeTimeDelay),
+        ("ProcedureStepCommunicationsURISequence", &tags::ProcedureStepCommunicationsURISequence),
+        ("ImageProcessingApplied", &tags::ImageProcessingApplied),
+        ("RadiationMode", &tags::RadiationMode),
+        ("ErrorID", &tags::ErrorID),
+        ("AdvancedBlendingSequence", &tags::AdvancedBlendingSequence),
+        ("AuthorIdentificationSequence", &tags::AuthorIdentificationSequence),
+        ("RelatedReferenceRTImageSequence", &tags::RelatedReferenceRTImageSequence),
+        ("SelectorDAValue", &tags::SelectorDAValue),
+        ("PotentialReasonsForProcedureCodeSequence", &tags::PotentialReasonsforProcedureCodeSequence),
+        ("UpperCutoffFrequency", &tags::UpperCutoffFrequency),
+        ("ImagePositionPatient", &tags::ImagePositionPatient),
+        ("LuminanceUniformityResultSequence", &tags::LuminanceUniformityResultSequence),
+        ("ImageOrientationVolume", &tags::ImageOrientationVolume),
+        ("KSpaceFiltering", &tags::KspaceFiltering),
+        ("ActualEnvironmentalConditions", &tags::ActualEnvironmentalConditions),
+        ("SourceRollAngle", &tags::SourceRollAngle),
+        ("TotalCompensatorTrayFactor", &tags::TotalCompensatorTrayFactor),
+        ("DeidentificationActionSequence", &tags::DeidentificationActionSequence),
+        ("EnergyDerivationCodeSequence", &tags::EnergyDerivationCodeSequence),
+        ("PixelComponentRangeStop", &tags::PixelComponentRangeStop),
+        ("PositionerType", &tags::PositionerType),
+        ("DisplayedAreaTopLeftHandCorner", &tags::DisplayedAreaTopLeftHandCorner),
+        ("InputReadinessState", &tags::InputReadinessState),
+        ("LeftImageSequence", &tags::LeftImageSequence),
+        ("TransferTubeNumber", &tags::TransferTubeNumber),
+        ("RegionLocationMinX0", &tags::RegionLocationMinX0),
+        ("PartialDataDisplayHandling", &tags::PartialDataDisplayHandling),
+        ("MeasuredDoseReferenceSequence", &tags::MeasuredDoseReferenceSequence),
+        ("NumberOfTableEntries", &tags::NumberofTableEntries),
+        ("PersonTelecomInformation", &tags::PersonsTelecomInformation),
+        ("OphthalmicAxialLengthMeasurementsLengthSummationSequence", &tags::OphthalmicAxialLengthMeasurementsLengthSummationSequence),
+        ("ComponentManufacturingProcedure", &tags::ComponentManufacturingProcedure),
+        ("ViewpointPosition", &tags::ViewpointPosition),
+        ("GPSDifferential", &tags::GPSDifferential),
+        ("CalibrationReferencePointDepth", &tags::CalibrationReferencePointDepth),
+        ("CenterOfRotationOffset", &tags::CenterofRotationOffset),
+        ("ApprovalSubjectSequence", &tags::ApprovalSubjectSequence),
+        ("RegionLocationMinY0", &tags::RegionLocationMinY0),
+        ("SingleCollimationWidth", &tags::SingleCollimationWidth),
+        ("CornealPointEstimated", &tags::CornealPointEstimated),
+        ("RelativeOpacity", &tags::RelativeOpacity),
+        ("ClinicalTrialTimePointTypeCodeSequence", &tags::ClinicalTrialTimePointTypeCodeSequence),
+        ("PixelCoordinatesSetTrial", &tags::PixelCoordinatesSet_Trial),
+        ("ExcessiveFalseNegativesDataFlag", &tags::ExcessiveFalseNegativesDataFlag),
+        ("TimeOfFlightContrast", &tags::TimeofFlightContrast),
+        ("RefractiveIndexOfAqueousHumor", &tags::RefractiveIndexofAqueousHumor),
+        ("DoubleExposureOrdering", &tags::DoubleExposureOrdering),
+        ("TableTopVerticalPositionTolerance", &tags::TableTopVerticalPositionTolerance),
+        ("BeamHoldTransition", &tags::BeamHoldTransition),
+        ("ShapeType", &tags::ShapeType),
+        ("IssueTimeOfImagingServiceRequest", &tags::IssueTimeofImagingServiceRequest),
+        ("TagAngleFirstAxis", &tags::TagAngleFirstAxis),
+        ("PhysiciansOfRecordIdentificationSequence", &tags::PhysiciansofRecordIdentificationSequence),
+        ("ChannelShieldID", &tags::ChannelShieldID),
+        ("InventoryPurpose", &tags::InventoryPurpose),
+        ("ReferencedSOPSequence", &tags::ReferencedSOPSequence),
+        ("ExposureModulationType", &tags::ExposureModulationType),
+        ("IrradiationEventUID", &tags::IrradiationEventUID),
+        ("MultiCoilElementUsed", &tags::MultiCoilElementUsed),
+        ("ThresholdType", &tags::ThresholdType),
+        ("BaselineCorrection", &tags::BaselineCorrection),
+        ("TemplateExtensionOrganizationUID", &tags::TemplateExtensionOrganizationUID),
+        ("ComponentType", &tags::ComponentType),
+        ("SegmentedBluePaletteColorLookupTableData", &tags::SegmentedBluePaletteColorLookupTableData),
+        ("IdentifyingComments", &tags::IdentifyingComments),
+        ("AcquisitionMotion", &tags::AcquisitionMotion),
+        ("MaximumNominalEnergy", &tags::MaximumNominalEnergy),
+        ("ResponsibleGroupCodeSequence", &tags::ResponsibleGroupCodeSequence),
+        ("VolumeToTransducerMappingMatrix", &tags::VolumetoTransducerMappingMatrix),
+        ("VerticesOfTheRegion", &tags::VerticesoftheRegion),
+        ("Optotype", &tags::Optotype),
+        ("AlarmDecision", &tags::AlarmDecision),
+        ("ScheduledStationAETitle", &tags::ScheduledStationAETitle),
+        ("GlobalCroppingSpecificationIndex", &tags::GlobalCroppingSpecificationIndex),
+        ("BeamTaskSequence", &tags::BeamTaskSequence),
+        ("SnoutPosition", &tags::SnoutPosition),
+        ("VitalStainCodeSequenceTrial", &tags::VitalStainCodeSequence_Trial),
+        ("FluoroscopyLastImageHoldPersistenceFlag", &tags::FluoroscopyLastImageHoldPersistenceFlag),
+        ("PostprocessingFunction", &tags::PostprocessingFunction),
+        ("XRayMassAttenuationCoefficient", &tags::XRayMassAttenuationCoefficient),
+        ("RadiationGenerationModeSequence", &tags::RadiationGenerationModeSequence),
+        ("EmitterColorTemperature", &tags::EmitterColorTemperature),
+        ("ExposureControlSensingRegionRightVerticalEdge", &tags::ExposureControlSensingRegionRightVerticalEdge),
+        ("DetectorActiveAreaTLHCPosition", &tags::DetectorActiveAreaTLHCPosition),
+        ("RecommendedAbsentPixelCIELabValue", &tags::RecommendedAbsentPixelCIELabValue),
+        ("SpiralPitchFactor", &tags::SpiralPitchFactor),
+        ("ExposureStatus", &tags::ExposureStatus),
+        ("LongVertexPointIndexList", &tags::LongVertexPointIndexList),
+        ("LineStyleSequence", &tags::LineStyleSequence),
+        ("FlowCompensation", &tags::FlowCompensation),
+        ("RegistrationSequence", &tags::RegistrationSequence),
+        ("SpatialPresaturation", &tags::SpatialPresaturation),
+        ("AcquisitionTime", &tags::AcquisitionTime),
+        ("StructuredDisplayImageBoxSequence", &tags::StructuredDisplayImageBoxSequence),
+        ("TargetMinimumDose", &tags::TargetMinimumDose),
+        ("IncorporatedInventoryInstanceSequence", &tags::IncorporatedInventoryInstanceSequence),
+        ("EnhancedRTBeamLimitingDeviceSequence", &tags::EnhancedRTBeamLimitingDeviceSequence),
+        ("StrainAdditionalInformation", &tags::StrainAdditionalInformation),
+        ("TableTraverse", &tags::TableTraverse),
+        ("ActionTypeID", &tags::ActionTypeID),
+        ("LightSourcePolarization", &tags::LightSourcePolarization),
+        ("NonconformingModifiedAttributesSequence", &tags::NonconformingModifiedAttributesSequence),
+        ("VolumeStreamSequence", &tags::VolumeStreamSequence),
+        ("PVCRejection", &tags::PVCRejection),
+        ("WeekdayFractionPatternSequence", &tags::WeekdayFractionPatternSequence),
+        ("DoseModeName", &tags::DoseModeName),
+        ("RealWorldValueSlope", &tags::RealWorldValueSlope),
+        ("ImpedanceMeasurementFrequency", &tags::ImpedanceMeasurementFrequency),
+        ("CarrierID", &tags::CarrierID),
+        ("PerformedProcedureStepEndDateTime", &tags::PerformedProcedureStepEndDateTime),
+        ("TreatmentToleranceViolationTypeCodeSequence", &tags::TreatmentToleranceViolationTypeCodeSequence),
+        ("OphthalmicAxialLengthMeasurementsSequence", &tags::OphthalmicAxialLengthMeasurementsSequence),
+        ("MeasuredValueSequence", &tags::MeasuredValueSequence),
+        ("SegmentedPropertyCategoryCodeSequence", &tags::SegmentedPropertyCategoryCodeSequence),
+        ("ReferencedDosimetricObjectivesSequence", &tags::ReferencedDosimetricObjectivesSequence),
+        ("TreatmentSites", &tags::TreatmentSites),
+        ("PatientSexNeutered", &tags::PatientsSexNeutered),
+        ("ReferencedRTPatientSetupSequence", &tags::ReferencedRTPatientSetupSequence),
+        ("NumberOfRangeShifters", &tags::NumberofRangeShifters),
+        ("RTRecordFlag", &tags::RTRecordFlag),
+        ("StartDensityThreshold", &tags::StartDensityThreshold),
+        ("ColumnAngulationPatient", &tags::ColumnAngulationPatient),
+        ("DisplaySubsystemName", &tags::DisplaySubsystemName),
+        ("GainCorrectionReferenceSequence", &tags::GainCorrectionReferenceSequence),
+        ("PerFrameFunctionalGroupsSequence", &tags::PerFrameFunctionalGroupsSequence),
+        ("CollimatorLeftVerticalEdge", &tags::CollimatorLeftVerticalEdge),
+        ("PlanningLandmarkIdentificationCodeSequence", &tags::PlanningLandmarkIdentificationCodeSequence),
+        ("DataSetSubtype", &tags::DataSetSubtype),
+        ("NumberOfVolumetricCurvePoints", &tags::NumberofVolumetricCurvePoints),
+        ("AlgorithmType", &tags::AlgorithmType),
+        ("DischargeDiagnosisCodeSequence", &tags::DischargeDiagnosisCodeSequence),
+        ("ImageOrientation", &tags::ImageOrientation),
+        ("GeneratorPower", &tags::GeneratorPower),
+        ("PlanIntent", &tags::PlanIntent),
+        ("SpatialLocationsPreserved", &tags::SpatialLocationsPreserved),
+        ("GeneticModificationsDescription", &tags::GeneticModificationsDescription),
+        ("DetectorInformationSequence", &tags::DetectorInformationSequence),
+        ("TwoDimensionalToThreeDimensionalMapData", &tags::TwoDimensionaltoThreeDimensionalMapData),
+        ("LossyImageCompressionMethod", &tags::LossyImageCompressionMethod),
+        ("IncludeDisplayApplication", &tags::IncludeDisplayApplication),
+        ("MRVelocityEncodingSequence", &tags::MRVelocityEncodingSequence),
+        ("ShotDurationTime", &tags::ShotDurationTime),
+        ("FontName", &tags::FontName),
+        ("InventoriedStudiesSequence", &tags::InventoriedStudiesSequence),
+        ("GraphicLayerRecommendedDisplayGrayscaleValue", &tags::GraphicLayerRecommendedDisplayGrayscaleValue),
+        ("ImagingSourceToBeamModifierDefinitionPlaneDistance", &tags::ImagingSourcetoBeamModifierDefinitionPlaneDistance),
+        ("OphthalmicPatientClinicalInformationRightEyeSequence", &tags::OphthalmicPatientClinicalInformationRightEyeSequence),
+        ("PrivateDataElementValueRepresentation", &tags::PrivateDataElementValueRepresentation),
+        ("RadiationSourceCoordinateSystemRollAngle", &tags::RadiationSourceCoordinateSystemRollAngle),
+        ("RTPatientPositionScopeSequence", &tags::RTPatientPositionScopeSequence),
+        ("SpecimenIdentifier", &tags::SpecimenIdentifier),
+        ("ScanningSequence", &tags::ScanningSequence),
+        ("EventTimeOffset", &tags::EventTimeOffset),
+        ("MediumType", &tags::MediumType),
+        ("SNRThreshold", &tags::SNRThreshold),
+        ("ScheduledProcedureStepStatus", &tags::ScheduledProcedureStepStatus),
+        ("DeliveryMaximumDose", &tags::DeliveryMaximumDose),
+        ("PredictorConstants", &tags::PredictorConstants),
+        ("PETReconstructionSequence", &tags::PETReconstructionSequence),
+        ("AdditionalDrugSequence", &tags::AdditionalDrugSequence),
+        ("TestResultComment", &tags::TestResultComment),
+        ("LongitudinalTemporalOffsetFromEvent", &tags::LongitudinalTemporalOffsetfromEvent),
+        ("EventElapsedTimes", &tags::EventElapsedTimes),
+        ("SourceApplicatorNumber", &tags::SourceApplicatorNumber),
+        ("EchoTime", &tags::EchoTime),
+        ("PlanarConfiguration", &tags::PlanarConfiguration),
+        ("NumberOfElements", &tags::NumberofElements),
+        ("TableZPositionToIsocenter", &tags::TableZPositiontoIsocenter),
+        ("ReferringPhysicianTelephoneNumbers", &tags::ReferringPhysiciansTelephoneNumbers),
+        ("WedgeSequence", &tags::WedgeSequence),
+        ("ReasonForChannelOmission", &tags::ReasonforChannelOmission),
+        ("FalsePositivesEstimateFlag", &tags::FalsePositivesEstimateFlag),
+        ("PresentationSizeMode", &tags::PresentationSizeMode),
+        ("TrackSetAnatomicalTypeCodeSequence", &tags::TrackSetAnatomicalTypeCodeSequence),
+        ("DataElementsSigned", &tags::DataElementsSigned),
+        ("NumberOfPaintings", &tags::NumberofPaintings),
+        ("NumberOfCompensators", &tags::NumberofCompensators),
+        ("PositionerPositionSequence", &tags::PositionerPositionSequence),
+        ("PrivateDataElement", &tags::PrivateDataElement),
+        ("PlateType", &tags::PlateType),
+        ("ImageBoxScrollDirection", &tags::ImageBoxScrollDirection),
+        ("HorizontalPrismPower", &tags::HorizontalPrismPower),
+        ("TopicSubject", &tags::TopicSubject),
+        ("PatternOffOpacity", &tags::PatternOffOpacity),
+        ("RTRadiationSequence", &tags::RTRadiationSequence),
+        ("EncryptedContentTransferSyntaxUID", &tags::EncryptedContentTransferSyntaxUID),
+        ("ReferencedImageRealWorldValueMappingSequence", &tags::ReferencedImageRealWorldValueMappingSequence),
+        ("FocusMethod", &tags::FocusMethod),
+        ("ModifyingDeviceID", &tags::ModifyingDeviceID),
+        ("TotalBlockTrayFactor", &tags::TotalBlockTrayFactor),
+        ("InConcatenationNumber", &tags::InconcatenationNumber),
+        ("RecommendedPresentationType", &tags::RecommendedPresentationType),
+        ("ReferencedToleranceTableNumber", &tags::ReferencedToleranceTableNumber),
+        ("AttributeOccurrencePointer", &tags::AttributeOccurrencePointer),
+        ("VOILUTFunction", &tags::VOILUTFunction),
+        ("AscanRate", &tags::AscanRate),
+        ("VisualEvaluationTestSequence", &tags::VisualEvaluationTestSequence),
+        ("InstructionPerformedDateTime", &tags::InstructionPerformedDateTime),
+        ("TableYPositionToIsocenter", &tags::TableYPositiontoIsocenter),
+        ("GPSLongitude", &tags::GPSLongitude),
+        ("PhysicalDetectorSize", &tags::PhysicalDetectorSize),
+        ("XFocusCenter", &tags::XFocusCenter),
+        ("ContrastFlowRate", &tags::ContrastFlowRate),
+        ("TextComments", &tags::TextComments),
+        ("LowEnergyDetectors", &tags::LowEnergyDetectors),
+        ("SeriesDate", &tags::SeriesDate),
+        ("StoredInstanceBaseURI", &tags::StoredInstanceBaseURI),
+        ("CompensatorMountingPosition", &tags::CompensatorMountingPosition),
+        ("ATDAbilityAssessment", &tags::ATDAbilityAssessment),
+        ("CardiacSignalSource", &tags::CardiacSignalSource),
+        ("InventoryAccessEndPointsSequence", &tags::InventoryAccessEndPointsSequence),
+        ("SegmentedRTAccessoryDeviceSequence", &tags::SegmentedRTAccessoryDeviceSequence),
+        ("SourceApplicatorLength", &tags::SourceApplicatorLength),
+        ("ToleranceValue", &tags::ToleranceValue),
+        ("TimeRange", &tags::TimeRange),
+        ("DoseValue", &tags::DoseValue),
+        ("RTDiagnosisCodeSequence", &tags::RTDiagnosisCodeSequence),
+        ("ParallelRTBeamDelimiterOpeningExtents", &tags::ParallelRTBeamDelimiterOpeningExtents),
+        ("ExposureControlSensingRegionShape", &tags::ExposureControlSensingRegionShape),
+        ("AdministrationRouteCodeSequence", &tags::AdministrationRouteCodeSequence),
+        ("ScreeningBaselineType", &tags::ScreeningBaselineType),
+        ("TriggerSamplePosition", &tags::TriggerSamplePosition),
+        ("StudyUpdateDateTime", &tags::StudyUpdateDateTime),
+        ("AcquisitionFieldOfViewLabel", &tags::AcquisitionFieldofViewLabel),
+        ("NumberOfTriggersInPhase", &tags::NumberofTriggersinPhase),
+        ("XRayTubeCurrentInmA", &tags::XRayTubeCurrentinmA),
+        ("DistanceSourceToDetector", &tags::DistanceSourcetoDetector),
+        ("MinDensity", &tags::MinDensity),
+        ("AxialResolution", &tags::AxialResolution),
+        ("XAAcquisitionFrameRate", &tags::XAAcquisitionFrameRate),
+        ("TargetUID", &tags::TargetUID),
+        ("FrameTimeVector", &tags::FrameTimeVector),
+        ("RangeShifterSettingsSequence", &tags::RangeShifterSettingsSequence),
+        ("ReferencedPresentationStateSequence", &tags::ReferencedPresentationStateSequence),
+        ("VisualFieldCatchTrialSequence", &tags::VisualFieldCatchTrialSequence),
+        ("NumberOfContourPoints", &tags::NumberofContourPoints),
+        ("ChannelSensitivityUnitsSequence", &tags::ChannelSensitivityUnitsSequence),
+        ("ReferencedSurfaceDataSequence", &tags::ReferencedSurfaceDataSequence),
+        ("OutputDestinationSequence", &tags::OutputDestinationSequence),
+        ("Rows", &tags::Rows),
+        ("NumberOfPulses", &tags::NumberofPulses),
+        ("MessageID", &tags::MessageID),
+        ("RefractivePower", &tags::RefractivePower),
+        ("PulseWidth", &tags::PulseWidth),
+        ("BeamSequence", &tags::BeamSequence),
+        ("ChannelDeliveryContinuationSequence", &tags::ChannelDeliveryContinuationSequence),
+        ("ResultsComments", &tags::ResultsComments),
+        ("GridAspectRatio", &tags::GridAspectRatio),
+        ("BeamHoldTransitionTriggerSource", &tags::BeamHoldTransitionTriggerSource),
+        ("RequestedProcedureCodeSequence", &tags::RequestedProcedureCodeSequence),
+        ("NumberOfHorizontalPixels", &tags::NumberofHorizontalPixels),
+        ("MemoryAllocation", &tags::MemoryAllocation),
+        ("DoseSummationType", &tags::DoseSummationType),
+        ("PatientSupportPositionParameterOrderIndex", &tags::PatientSupportPositionParameterOrderIndex),
+        ("RelevantInformationSequence", &tags::RelevantInformationSequence),
+        ("DiffuseReflectionIntensity", &tags::DiffuseReflectionIntensity),
+        ("SetupTechniqueDescription", &tags::SetupTechniqueDescription),
+        ("Contrast", &tags::Contrast),
+        ("ContentTemplateSequence", &tags::ContentTemplateSequence),
+        ("ReferencedCalculatedDoseReferenceNumber", &tags::ReferencedCalculatedDoseReferenceNumber),
+        ("GPSLongitudeRef", &tags::GPSLongitudeRef),
+        ("SelectorATValue", &tags::SelectorATValue),
+        ("PatientSpeciesDescription", &tags::PatientSpeciesDescription),
+        ("ReferencedSegmentReferenceIndex", &tags::ReferencedSegmentReferenceIndex),
+        ("UniversalEntityIDType", &tags::UniversalEntityIDType),
+        ("RecordKey", &tags::RecordKey),
+        ("MRModifierSequence", &tags::MRModifierSequence),
+        ("OphthalmicFrameLocationSequence", &tags::OphthalmicFrameLocationSequence),
+        ("RespiratoryMotionCompensationTechnique", &tags::RespiratoryMotionCompensationTechnique),
+        ("PatientTelephoneNumbers", &tags::PatientsTelephoneNumbers),
+        ("TrayAccessoryCode", &tags::TrayAccessoryCode),
+        ("TableTopRollAngleTolerance", &tags::TableTopRollAngleTolerance),
+        ("MetaboliteMapCodeSequence", &tags::MetaboliteMapCodeSequence),
+        ("ObjectSoundSpeed", &tags::ObjectSoundSpeed),
+        ("ROIPhysicalPropertyValue", &tags::ROIPhysicalPropertyValue),
+        ("NuclearMedicineSeriesType", &tags::NuclearMedicineSeriesType),
+        ("OffsetOfReferencedLowerLevelDirectoryEntity", &tags::OffsetofReferencedLowerLevelDirectoryEntity),
+        ("MRDiffusionSequence", &tags::MRDiffusionSequence),
+        ("TagAngleSecondAxis", &tags::TagAngleSecondAxis),
+        ("TextStyleSequence", &tags::TextStyleSequence),
+        ("PatientSupportDisplacementSequence", &tags::PatientSupportDisplacementSequence),
+        ("ReferencedSeriesSequence", &tags::ReferencedSeriesSequence),
+        ("DopplerSampleVolumeYPosition", &tags::DopplerSampleVolumeYPosition),
+        ("SourceImageEvidenceSequence", &tags::SourceImageEvidenceSequence),
+        ("RadiationDoseMeasurementPointCoordinates", &tags::RadiationDoseMeasurementPointCoordinates),
+        ("XOffsetInSlideCoordinateSystem", &tags::XOffsetinSlideCoordinateSystem),
+        ("MaskPointers", &tags::MaskPointers),
+        ("OphthalmicAxialLengthQualityMetricTypeDescription", &tags::OphthalmicAxialLengthQualityMetricTypeDescription),
+        ("DerivationAlgorithmSequence", &tags::DerivationAlgorithmSequence),
+        ("FilterLowFrequency", &tags::FilterLowFrequency),
+        ("RadiopharmaceuticalRoute", &tags::RadiopharmaceuticalRoute),
+        ("FractionationNotes", &tags::FractionationNotes),
+        ("AlgorithmCodeSequenceTrial", &tags::AlgorithmCodeSequence_Trial),
+        ("SafePositionReturnDate", &tags::SafePositionReturnDate),
+        ("RelatedFrameOfReferenceUID", &tags::RelatedFrameofReferenceUID),
+        ("MaskVisibilityPercentage", &tags::MaskVisibilityPercentage),
+        ("TransducerScanPatternCodeSequence", &tags::TransducerScanPatternCodeSequence),
+        ("ReferencedRangeModulatorNumber", &tags::ReferencedRangeModulatorNumber),
+        ("ProcedureStepState", &tags::ProcedureStepState),
+        ("WeightingLookupTableDescriptor", &tags::WeightingLookupTableDescriptor),
+        ("ChannelDescriptionCodeSequence", &tags::ChannelDescriptionCodeSequence),
+        ("RelatedRTROIObservationsSequence", &tags::RelatedRTROIObservationsSequence),
+        ("RTTreatmentPhaseIntentPresenceFlag", &tags::RTTreatmentPhaseIntentPresenceFlag),
+        ("SeriesTime", &tags::SeriesTime),
+        ("AnatomicStructureSpaceOrRegionModifierCodeSequenceTrial", &tags::AnatomicStructureSpaceOrRegionModifierCodeSequence_Trial),
+        ("CoatingMaterialsCodeSequence", &tags::CoatingMaterialsCodeSequence),
+        ("OverrideSequence", &tags::OverrideSequence),
+        ("OverriddenAttributesSequence", &tags::OverriddenAttributesSequence),
+        ("ReferencedDoseReferenceSequence", &tags::ReferencedDoseReferenceSequence),
+        ("UltrasoundOphthalmicAxialLengthMeasurementsSequence", &tags::UltrasoundOphthalmicAxialLengthMeasurementsSequence),
+        ("ObserverType", &tags::ObserverType),
+        ("ReferencedCurveSequence", &tags::ReferencedCurveSequence),
+        ("ReferringPhysicianAddress", &tags::ReferringPhysiciansAddress),
+        ("DopplerSampleVolumeXPositionRetired", &tags::DopplerSampleVolumeXPosition_Retired),
+        ("OriginalSpecializedSOPClassUID", &tags::OriginalSpecializedSOPClassUID),
+        ("StructuredDisplayTextBoxSequence", &tags::StructuredDisplayTextBoxSequence),
+        ("WholeSlideMicroscopyImageFrameTypeSequence", &tags::WholeSlideMicroscopyImageFrameTypeSequence),
+        ("DoubleFloatPixelData", &tags::DoubleFloatPixelData),
+        ("ChemicalShiftSequence", &tags::ChemicalShiftSequence),
+        ("PatientSize", &tags::PatientsSize),
+        ("ApplicatorType", &tags::ApplicatorType),
+        ("TargetRefraction", &tags::TargetRefraction),
+        ("ProcedureStepRelationshipType", &tags::ProcedureStepRelationshipType),
+        ("AnchorPointAnnotationUnits", &tags::AnchorPointAnnotationUnits),
+        ("IntravascularFrameContentSequence", &tags::IntravascularFrameContentSequence),
+        ("ColumnOverlap", &tags::ColumnOverlap),
+        ("CompensatorThicknessData", &tags::CompensatorThicknessData),
+        ("ScanSpotReordered", &tags::ScanSpotReordered),
+        ("ReferencePixelY0", &tags::ReferencePixelY0),
+        ("MRImageFrameTypeSequence", &tags::MRImageFrameTypeSequence),
+        ("EmmetropicMagnification", &tags::EmmetropicMagnification),
+        ("RTToleranceSetLabel", &tags::RTToleranceSetLabel),
+        ("ParallelRTBeamDelimiterOpeningMode", &tags::ParallelRTBeamDelimiterOpeningMode),
+        ("SourceNumber", &tags::SourceNumber),
+        ("DistalDepthFraction", &tags::DistalDepthFraction),
+        ("InstructionText", &tags::InstructionText),
+        ("RequestedResolutionID", &tags::RequestedResolutionID),
+        ("SecondaryCaptureDeviceID", &tags::SecondaryCaptureDeviceID),
+        ("ReferencedPatientAliasSequence", &tags::ReferencedPatientAliasSequence),
+        ("TableXPositionToIsocenter", &tags::TableXPositiontoIsocenter),
+        ("ObservationSubjectUIDTrial", &tags::ObservationSubjectUID_Trial),
+        ("LightSource", &tags::LightSource),
+        ("RTTreatmentTerminationReasonCodeSequence", &tags::RTTreatmentTerminationReasonCodeSequence),
+        ("VerticalAlignment", &tags::VerticalAlignment),
+        ("AcquisitionEndLocationSequence", &tags::AcquisitionEndLocationSequence),
+        ("CommentsOnThePerformedProcedureStep", &tags::CommentsonthePerformedProcedureStep),
+        ("CollimatorShapeSequence", &tags::CollimatorShapeSequence),
+        ("XDSStorageSequence", &tags::XDSStorageSequence),
+        ("AttributeModificationDateTime", &tags::AttributeModificationDateTime),
+        ("DoseReferenceUID", &tags::DoseReferenceUID),
+        ("ScheduledProcessingParametersSequence", &tags::ScheduledProcessingParametersSequence),
+        ("IsocenterToBlockTrayDistance", &tags::IsocentertoBlockTrayDistance),
+        ("ContrastFlowDuration", &tags::ContrastFlowDuration),
+        ("BrachyTreatmentTechnique", &tags::BrachyTreatmentTechnique),
+        ("RTSegmentAnnotationSequence", &tags::RTSegmentAnnotationSequence),
+        ("VolumetricCurveUpDirections", &tags::VolumetricCurveUpDirections),
+        ("CenterOfRotation", &tags::CenterofRotation),
+        ("ScheduledStationName", &tags::ScheduledStationName),
+        ("FailureReason", &tags::FailureReason),
+        ("GlobalDeviationProbability", &tags::GlobalDeviationProbability),
+        ("DisplaySetNumber", &tags::DisplaySetNumber),
+        ("PixelDataProviderURL", &tags::PixelDataProviderURL),
+        ("DocumentingOrganizationIdentifierCodeSequenceTrial", &tags::DocumentingOrganizationIdentifierCodeSequence_Trial),
+        ("ReferencedSOPClassUID", &tags::ReferencedSOPClassUID),
+        ("ReferencedFrameOfReferenceSequence", &tags::ReferencedFrameofReferenceSequence),
+        ("ContrastBolusT1Relaxivity", &tags::ContrastBolusT1Relaxivity),
+        ("GridPeriod", &tags::GridPeriod),
+        ("FractionStatusSummarySequence", &tags::FractionStatusSummarySequence),
+        ("FieldOfViewOrigin", &tags::FieldofViewOrigin),
+        ("PatientEquipmentRelationshipCodeSequence", &tags::PatientEquipmentRelationshipCodeSequence),
+        ("CalciumScoringMassFactorPatient", &tags::CalciumScoringMassFactorPatient),
+        ("AutoKVPUpperBound", &tags::AutoKVPUpperBound),
+        ("FieldOfViewSequence", &tags::FieldofViewSequence),
+        ("ImageOverlayFlag", &tags::ImageOverlayFlag),
+        ("SurfacePointsSequence", &tags::SurfacePointsSequence),
+        ("RegionDataType", &tags::RegionDataType),
+        ("OphthalmicImageOrientation", &tags::OphthalmicImageOrientation),
+        ("ReasonForStudy", &tags::ReasonforStudy),
+        ("TransducerIdentificationSequence", &tags::TransducerIdentificationSequence),
+        ("OphthalmicThicknessMapQualityRatingSequence", &tags::OphthalmicThicknessMapQualityRatingSequence),
+        ("DoseReferenceNumber", &tags::DoseReferenceNumber),
+        ("ModelGroupUID", &tags::ModelGroupUID),
+        ("DosimetricObjectiveSequence", &tags::DosimetricObjectiveSequence),
+        ("AgeCorrectedSensitivityDeviationProbabilityValue", &tags::AgeCorrectedSensitivityDeviationProbabilityValue),
+        ("AnatomicPerspectiveCodeSequenceTrial", &tags::AnatomicPerspectiveCodeSequence_Trial),
+        ("OverlayMagnificationType", &tags::OverlayMagnificationType),
+        ("ChannelMode", &tags::ChannelMode),
+        ("PatientFrameOfReferenceSource", &tags::PatientFrameofReferenceSource),
+        ("PrivateGroupReference", &tags::PrivateGroupReference),
+        ("EnergyWindowRangeSequence", &tags::EnergyWindowRangeSequence),
+        ("TableOfParameterValues", &tags::TableofParameterValues),
+        ("CompensatorPixelSpacing", &tags::CompensatorPixelSpacing),
+        ("ReferencedTDRInstanceSequence", &tags::ReferencedTDRInstanceSequence),
+        ("SecondaryReviewDate", &tags::SecondaryReviewDate),
+        ("PatientSupportAccessoryCode", &tags::PatientSupportAccessoryCode),
+        ("FocalDistance", &tags::FocalDistance),
+        ("IonWedgePositionSequence", &tags::IonWedgePositionSequence),
+        ("ItemInventoryDateTime", &tags::ItemInventoryDateTime),
+        ("ChannelLabel", &tags::ChannelLabel),
+        ("TotalPrescriptionDose", &tags::TotalPrescriptionDose),
+        ("ContrastBolusIngredientPercentByVolume", &tags::ContrastBolusIngredientPercentbyVolume),
+        ("StageName", &tags::StageName),
+        ("NominalMaxEnergy", &tags::NominalMaxEnergy),
+        ("RelativeImagePositionCodeSequence", &tags::RelativeImagePositionCodeSequence),
+        ("ReferencedFilmSessionSequence", &tags::ReferencedFilmSessionSequence),
+        ("ProcedureStepLabel", &tags::ProcedureStepLabel),
+        ("CountRate", &tags::CountRate),
+        ("EnergyWindowTotalWidth", &tags::EnergyWindowTotalWidth),
+        ("B1rms", &tags::B1rms),
+        ("MappedPixelValue", &tags::MappedPixelValue),
+        ("Manifold", &tags::Manifold),
+        ("ContactDisplayName", &tags::ContactDisplayName),
+        ("ThreatCategoryDescription", &tags::ThreatCategoryDescription),
+        ("PETDetectorMotionDetailsSequence", &tags::PETDetectorMotionDetailsSequence),
+        ("VolumeToTransducerRelationship", &tags::VolumetoTransducerRelationship),
+        ("ConstraintViolationSignificance", &tags::ConstraintViolationSignificance),
+        ("ResponsiblePersonRole", &tags::ResponsiblePersonRole),
+        ("ThreeDMatingPoint", &tags::Tag_3DMatingPoint),
+        ("PredecessorStructureSetSequence", &tags::PredecessorStructureSetSequence),
+        ("MaximumFractionalValue", &tags::MaximumFractionalValue),
+        ("NumberOfVectors", &tags::NumberofVectors),
+        ("TemplateIdentifier", &tags::TemplateIdentifier),
+        ("AcquisitionContextDescription", &tags::AcquisitionContextDescription),
+        ("BasicGrayscaleImageSequence", &tags::BasicGrayscaleImageSequence),
+        ("ObservationDescription", &tags::ObservationDescription),
+        ("ContourSequence", &tags::ContourSequence),
+        ("RotationOffset", &tags::RotationOffset),
+        ("NumberOfParallelRTBeamDelimiters", &tags::NumberofParallelRTBeamDelimiters),
+        ("VelocityEncodingDirection", &tags::VelocityEncodingDirection),
+        ("LensStatusDescription", &tags::LensStatusDescription),
+        ("GeneticModificationsNomenclature", &tags::GeneticModificationsNomenclature),
+        ("ConvolutionKernel", &tags::ConvolutionKernel),
+        ("InputAvailabilityFlag", &tags::InputAvailabilityFlag),
+        ("SequenceDelimitationItem", &tags::SequenceDelimitationItem),
+        ("FrameReferenceDateTime", &tags::FrameReferenceDateTime),
+        ("DimensionIndexPrivateCreator", &tags::DimensionIndexPrivateCreator),
+        ("MaximumCornealCurvature", &tags::MaximumCornealCurvature),
+        ("SourceOfRefractiveMeasurementsSequence", &tags::SourceofRefractiveMeasurementsSequence),
+        ("SurfaceModelScalingFactor", &tags::SurfaceModelScalingFactor),
+        ("PatientPositioningInstructionSequence", &tags::PatientPositioningInstructionSequence),
+        ("ImageRotation", &tags::ImageRotation),
+        ("ContrastBolusIngredientCodeSequence", &tags::ContrastBolusIngredientCodeSequence),
+        ("RecommendedAnimationRate", &tags::RecommendedAnimationRate),
+        ("ThreeDImplantTemplateGroupMemberMatchingPoint", &tags::Tag_3DImplantTemplateGroupMemberMatchingPoint),
+        ("ReferencedWaveformSequence", &tags::ReferencedWaveformSequence),
+        ("DeliveredNumberOfPulses", &tags::DeliveredNumberofPulses),
+        ("PrimitivePointIndexList", &tags::PrimitivePointIndexList),
+        ("Radionuclide", &tags::Radionuclide),
+        ("ImagingDeviceSpecificAcquisitionParameters", &tags::ImagingDeviceSpecificAcquisitionParameters),
+        ("OphthalmicMappingDeviceType", &tags::OphthalmicMappingDeviceType),
+        ("PatientEyeMovementCommanded", &tags::PatientEyeMovementCommanded),
+        ("TickLabel", &tags::TickLabel),
+        ("PerformedSeriesSequence", &tags::PerformedSeriesSequence),
+        ("ApplicatorID", &tags::ApplicatorID),
+        ("PreMedication", &tags::PreMedication),
+        ("ReferencedDefinedDeviceIndex", &tags::ReferencedDefinedDeviceIndex),
+        ("LineDashingStyle", &tags::LineDashingStyle),
+        ("OrganAtRiskOverdoseVolumeFraction", &tags::OrganatRiskOverdoseVolumeFraction),
+        ("PrimaryPositionerScanArc", &tags::PrimaryPositionerScanArc),
+        ("DoubleExposureFlag", &tags::DoubleExposureFlag),
+        ("DVHType", &tags::DVHType),
+        ("PatientSupportAngleTolerance", &tags::PatientSupportAngleTolerance),
+        ("PlacerOrderNumberImagingServiceRequest", &tags::PlacerOrderNumberImagingServiceRequest),
+        ("GPSDOP", &tags::GPSDOP),
+        ("ContourUncertaintyRadius", &tags::ContourUncertaintyRadius),
+        ("TableOfYBreakPoints", &tags::TableofYBreakPoints),
+        ("NumberOfFractionsIncluded", &tags::NumberofFractionsIncluded),
+        ("InterlockDateTime", &tags::InterlockDateTime),
+        ("ReferencedTreatmentPositionGroupUID", &tags::ReferencedTreatmentPositionGroupUID),
+        ("ShadowOffsetY", &tags::ShadowOffsetY),
+        ("NumberOfRTControlPoints", &tags::NumberofRTControlPoints),
+        ("CurrentRequestedProcedureEvidenceSequence", &tags::CurrentRequestedProcedureEvidenceSequence),
+        ("ReviewDate", &tags::ReviewDate),
+        ("PositionAcquisitionTemplateCodeSequence", &tags::PositionAcquisitionTemplateCodeSequence),
+        ("TotalAperture", &tags::TotalAperture),
+        ("StageNumber", &tags::StageNumber),
+        ("BarcodeValue", &tags::BarcodeValue),
+        ("FluenceDataScale", &tags::FluenceDataScale),
+        ("IndicationDisposition", &tags::IndicationDisposition),
+        ("EntityLongLabel", &tags::EntityLongLabel),
+        ("UserSelectedOffsetY", &tags::UserSelectedOffsetY),
+        ("RadiationDeviceConfigurationAndCommissioningKeySequence", &tags::RadiationDeviceConfigurationandCommissioningKeySequence),
+        ("ALineRate", &tags::AlineRate),
+        ("StudyIDIssuer", &tags::StudyIDIssuer),
+        ("NumberOfTransformSteps", &tags::NumberofTransformSteps),
+        ("PatientComments", &tags::PatientComments),
+        ("BlockEdgeData", &tags::BlockEdgeData),
+        ("EmptyValueMatchingSequence", &tags::EmptyValueMatchingSequence),
+        ("AnatomicRegionSequence", &tags::AnatomicRegionSequence),
+        ("OrganExposed", &tags::OrganExposed),
+        ("TaggingDelay", &tags::TaggingDelay),
+        ("NumberOfGeneralAccessories", &tags::NumberofGeneralAccessories),
+        ("MAUsedInGainCalibration", &tags::MAUsedinGainCalibration),
+        ("PrintQueueID", &tags::PrintQueueID),
+        ("SelectorCSValue", &tags::SelectorCSValue),
+        ("StudyDescription", &tags::StudyDescription),
+        ("IsotopeNumber", &tags::IsotopeNumber),
+        ("VisualFieldHorizontalExtent", &tags::VisualFieldHorizontalExtent),
+        ("FractionBasedRelationshipIntervalAnchor", &tags::FractionBasedRelationshipIntervalAnchor),
+        ("SegmentationTemplateUID", &tags::SegmentationTemplateUID),
+        ("InstanceCreationTime", &tags::InstanceCreationTime),
+        ("ReferencedXRayDetectorIndex", &tags::ReferencedXRayDetectorIndex),
+        ("EvaluatorName", &tags::EvaluatorName),
+        ("AlgorithmVersion", &tags::AlgorithmVersion),
+        ("CornealSizeSequence", &tags::CornealSizeSequence),
+        ("DigitalFilterCharacteristicsSequence", &tags::DigitalFilterCharacteristicsSequence),
+        ("BeamTaskType", &tags::BeamTaskType),
+        ("CompressionDescription", &tags::CompressionDescription),
+        ("GridDimensions", &tags::GridDimensions),
+        ("FlashRedEyeMode", &tags::FlashRedEyeMode),
+        ("DetectorElementSize", &tags::DetectorElementSize),
+        ("OphthalmicAxialLengthVelocity", &tags::OphthalmicAxialLengthVelocity),
+        ("ImagedVolumeWidth", &tags::ImagedVolumeWidth),
+        ("LesionNumber", &tags::LesionNumber),
+        ("SmallestImagePixelValue", &tags::SmallestImagePixelValue),
+        ("ReferencedOpticalPathIdentifier", &tags::ReferencedOpticalPathIdentifier),
+        ("ReferencedTreatmentRecordSequence", &tags::ReferencedTreatmentRecordSequence),
+        ("PrivateCreatorReference", &tags::PrivateCreatorReference),
+        ("VisualFieldVerticalExtent", &tags::VisualFieldVerticalExtent),
+        ("DataElementLabelSequence", &tags::DataElementLabelSequence),
+        ("EquipmentFrameOfReferenceDescription", &tags::EquipmentFrameofReferenceDescription),
+        ("ReferencedFiducialUID", &tags::ReferencedFiducialUID),
+        ("OCTZOffsetApplied", &tags::OCTZOffsetApplied),
+        ("BlockDefinitionSequence", &tags::BlockDefinitionSequence),
+        ("InspectionSelectionCriteria", &tags::InspectionSelectionCriteria),
+        ("WideFieldOphthalmicPhotographyQualityRatingSequence", &tags::WideFieldOphthalmicPhotographyQualityRatingSequence),
+        ("PostDeformationMatrixRegistrationSequence", &tags::PostDeformationMatrixRegistrationSequence),
+        ("ContentTime", &tags::ContentTime),
+        ("TrackSetDescription", &tags::TrackSetDescription),
+        ("ContrastBolusAgentNumber", &tags::ContrastBolusAgentNumber),
+        ("ReferencedSourceNumber", &tags::ReferencedSourceNumber),
+        ("ReceiveCoilType", &tags::ReceiveCoilType),
+        ("ApplicationManufacturer", &tags::ApplicationManufacturer),
+        ("HPGLPenSequence", &tags::HPGLPenSequence),
+        ("ReceiverNotes", &tags::ReceiverNotes),
+        ("GPSDestLongitude", &tags::GPSDestLongitude),
+        ("FrameDisplayShutterSequence", &tags::FrameDisplayShutterSequence),
+        ("ImagePositionVolume", &tags::ImagePositionVolume),
+        ("FirstALineLocation", &tags::FirstAlineLocation),
+        ("GPSTrack", &tags::GPSTrack),
+        ("ReferencedRTAccessoryHolderDeviceIndex", &tags::ReferencedRTAccessoryHolderDeviceIndex),
+        ("OversamplingPhase", &tags::OversamplingPhase),
+        ("AdditionalPatientHistory", &tags::AdditionalPatientHistory),
+        ("MaximumStoredValueMapped", &tags::MaximumStoredValueMapped),
+        ("AbsoluteDosimetricObjectiveFlag", &tags::AbsoluteDosimetricObjectiveFlag),
+        ("LanguageCodeSequence", &tags::LanguageCodeSequence),
+        ("HPGLDocumentScaling", &tags::HPGLDocumentScaling),
+        ("ScanSpotPositionMap", &tags::ScanSpotPositionMap),
+        ("ScheduledStudyStartTime", &tags::ScheduledStudyStartTime),
+        ("TextObjectSequence", &tags::TextObjectSequence),
+        ("AnnotationGroupDescription", &tags::AnnotationGroupDescription),
+        ("XRayDetectorLabel", &tags::XRayDetectorLabel),
+        ("PhaseDescription", &tags::PhaseDescription),
+        ("DisplaySubsystemID", &tags::DisplaySubsystemID),
+        ("BolusDefinitionSequence", &tags::BolusDefinitionSequence),
+        ("DeliveryRateUnitSequence", &tags::DeliveryRateUnitSequence),
+        ("ErrorComment", &tags::ErrorComment),
+        ("ReferencedResultsSequence", &tags::ReferencedResultsSequence),
+        ("StereoPairsPresent", &tags::StereoPairsPresent),
+        ("ReferencedFrameOfReferenceUID", &tags::ReferencedFrameofReferenceUID),
+        ("FlowTransferSyntaxUID", &tags::FlowTransferSyntaxUID),
+        ("SourceApplicationEntityTitle", &tags::SourceApplicationEntityTitle),
+        ("FrameOfReferenceTransformationType", &tags::FrameofReferenceTransformationType),
+        ("RFEchoTrainLength", &tags::RFEchoTrainLength),
+        ("LabelText", &tags::LabelText),
+        ("PrivateDataElementCharacteristicsSequence", &tags::PrivateDataElementCharacteristicsSequence),
+        ("ScanOptions", &tags::ScanOptions),
+        ("FinalCumulativeMetersetWeight", &tags::FinalCumulativeMetersetWeight),
+        ("OphthalmicAnatomicReferencePointYCoordinate", &tags::OphthalmicAnatomicReferencePointYCoordinate),
+        ("RTTreatmentPreparationPatientPositionSequence", &tags::RTTreatmentPreparationPatientPositionSequence),
+        ("TranslationRateX", &tags::TranslationRateX),
+        ("SourceEncapsulationNominalThickness", &tags::SourceEncapsulationNominalThickness),
+        ("ImageQualityIndicatorSize", &tags::ImageQualityIndicatorSize),
+        ("EntityLabel", &tags::EntityLabel),
+        ("SpatialFrequencyResponseColumns", &tags::SpatialFrequencyResponseColumns),
+        ("TransducerOrientationSequence", &tags::TransducerOrientationSequence),
+        ("PrinterConfigurationSequence", &tags::PrinterConfigurationSequence),
+        ("TotalTimeOfFluoroscopy", &tags::TotalTimeofFluoroscopy),
+        ("PreSelectedForImplantation", &tags::PreSelectedforImplantation),
+        ("ImageBoxLargeScrollType", &tags::ImageBoxLargeScrollType),
+        ("FilterLookupTableDescription", &tags::FilterLookupTableDescription),
+        ("TerminationCardiacTriggerCountThreshold", &tags::TerminationCardiacTriggerCountThreshold),
+        ("CameraAngleOfView", &tags::CameraAngleofView),
+        ("DeliveryWarningDose", &tags::DeliveryWarningDose),
+        ("FindingsGroupRecordingTimeTrial", &tags::FindingsGroupRecordingTime_Trial),
+        ("WedgeTotalWidth", &tags::WedgeTotalWidth),
+        ("TagSpacingSecondDimension", &tags::TagSpacingSecondDimension),
+        ("MultiFrameSourceSOPInstanceUID", &tags::MultiframeSourceSOPInstanceUID),
+        ("CodingSchemeDesignator", &tags::CodingSchemeDesignator),
+        ("BeamModifierCoordinatesPresenceFlag", &tags::BeamModifierCoordinatesPresenceFlag),
+        ("DACTimePoints", &tags::DACTimePoints),
+        ("RTReferencedSeriesSequence", &tags::RTReferencedSeriesSequence),
+        ("DataPointColumns", &tags::DataPointColumns),
+        ("DeliveredNominalRangeModulationFractions", &tags::DeliveredNominalRangeModulationFractions),
+        ("WedgeFrontHeight", &tags::WedgeFrontHeight),
+        ("TableVerticalIncrement", &tags::TableVerticalIncrement),
+        ("ObjectThicknessSequence", &tags::ObjectThicknessSequence),
+        ("ReferencedOphthalmicAxialLengthMeasurementQCImageSequence", &tags::ReferencedOphthalmicAxialLengthMeasurementQCImageSequence),
+        ("ProcedureStepProgressDescription", &tags::ProcedureStepProgressDescription),
+        ("Component1ReferencedMatingFeatureSetID", &tags::Component1ReferencedMatingFeatureSetID),
+        ("DeliveredSecondaryMeterset", &tags::DeliveredSecondaryMeterset),
+        ("WedgeOffsetX", &tags::WedgeOffsetX),
+        ("MainRoofAngle", &tags::MainRoofAngle),
+        ("ReformattingOperationInitialViewDirection", &tags::ReformattingOperationInitialViewDirection),
+        ("ShutterUpperHorizontalEdge", &tags::ShutterUpperHorizontalEdge),
+        ("StorageURL", &tags::StorageURL),
+        ("CompensatorColumns", &tags::CompensatorColumns),
+        ("ToricIOLPowerForExactTargetRefractionSequence", &tags::ToricIOLPowerforExactTargetRefractionSequence),
+        ("CoefficientsSDDN", &tags::CoefficientsSDDN),
+        ("ReceiveCoilName", &tags::ReceiveCoilName),
+        ("BoneThermalIndex", &tags::BoneThermalIndex),
+        ("SourceConceptualVolumeSequence", &tags::SourceConceptualVolumeSequence),
+        ("CoefficientsSDVN", &tags::CoefficientsSDVN),
+        ("FilterHighFrequencyCharacteristicsSequence", &tags::FilterHighFrequencyCharacteristicsSequence),
+        ("SetupDeviceSequence", &tags::SetupDeviceSequence),
+        ("AcquisitionProtocolName", &tags::AcquisitionProtocolName),
+        ("RTPatientPositionSequence", &tags::RTPatientPositionSequence),
+        ("IntervalNumber", &tags::IntervalNumber),
+        ("MedicalAlerts", &tags::MedicalAlerts),
+        ("RoboticPathControlPointSequence", &tags::RoboticPathControlPointSequence),
+        ("SlideIdentifier", &tags::SlideIdentifier),
+        ("SourceImageCornealProcessedDataSequence", &tags::SourceImageCornealProcessedDataSequence),
+        ("DischargeTime", &tags::DischargeTime),
+        ("RTRadiationTaskSequence", &tags::RTRadiationTaskSequence),
+        ("NumberOfStudyRelatedSeries", &tags::NumberofStudyRelatedSeries),
+        ("RadiopharmaceuticalStopTime", &tags::RadiopharmaceuticalStopTime),
+        ("TreatmentPositionSequence", &tags::TreatmentPositionSequence),
+        ("GridFocalDistance", &tags::GridFocalDistance),
+        ("ShadowOffsetX", &tags::ShadowOffsetX),
+        ("GridResolution", &tags::GridResolution),
+        ("SourceStrengthReferenceTime", &tags::SourceStrengthReferenceTime),
+        ("NTPSourceAddress", &tags::NTPSourceAddress),
+        ("ReferencedCompensatorNumber", &tags::ReferencedCompensatorNumber),
+        ("WaveformSampleInterpretation", &tags::WaveformSampleInterpretation),
+        ("DisplaySubsystemSequence", &tags::DisplaySubsystemSequence),
+        ("DoseReferenceStructureType", &tags::DoseReferenceStructureType),
+        ("OOIOwnerSequence", &tags::OOIOwnerSequence),
+        ("RevolutionTime", &tags::RevolutionTime),
+        ("NumberOfMapPoints", &tags::NumberofMapPoints),
+        ("FixationLightAzimuthalAngle", &tags::FixationLightAzimuthalAngle),
+        ("Planes", &tags::Planes),
+        ("BeamDescription", &tags::BeamDescription),
+        ("MultiplexGroupUID", &tags::MultiplexGroupUID),
+        ("BrachyAccessoryDeviceNominalThickness", &tags::BrachyAccessoryDeviceNominalThickness),
+        ("OverrideDateTime", &tags::OverrideDateTime),
+        ("DestinationAE", &tags::DestinationAE),
+        ("SelectorOFValue", &tags::SelectorOFValue),
+        ("TransducerOrientation", &tags::TransducerOrientation),
+        ("RetestStimulusSeen", &tags::RetestStimulusSeen),
+        ("SecondaryCaptureDeviceManufacturer", &tags::SecondaryCaptureDeviceManufacturer),
+        ("EmptyImageDensity", &tags::EmptyImageDensity),
+        ("VariableFlipAngleFlag", &tags::VariableFlipAngleFlag),
+        ("TwoDDegreeOfFreedomSequence", &tags::Tag_2DDegreeofFreedomSequence),
+        ("TransmitterFrequency", &tags::TransmitterFrequency),
+        ("SourceOrientation", &tags::SourceOrientation),
+        ("ExtendedCodeValue", &tags::ExtendedCodeValue),
+        ("GraphicLayer", &tags::GraphicLayer),
+        ("ConceptualVolumeSegmentationDefinedFlag", &tags::ConceptualVolumeSegmentationDefinedFlag),
+        ("StructuredConstraintObservationSequence", &tags::StructuredConstraintObservationSequence),
+        ("TransducerPosition", &tags::TransducerPosition),
+        ("RTRadiationSetUsage", &tags::RTRadiationSetUsage),
+        ("NumberOfFailedSuboperations", &tags::NumberofFailedSuboperations),
+        ("ReferencedRealWorldValueMappingInstanceSequence", &tags::ReferencedRealWorldValueMappingInstanceSequence),
+        ("StackID", &tags::StackID),
+        ("TherapeuticRoleTypeCodeSequence", &tags::TherapeuticRoleTypeCodeSequence),
+        ("OffsetDistance", &tags::OffsetDistance),
+        ("GPSDateStamp", &tags::GPSDateStamp),
+        ("ISOSpeed", &tags::ISOSpeed),
+        ("MagnificationType", &tags::MagnificationType),
+        ("ReferencedObservationUIDTrial", &tags::ReferencedObservationUID_Trial),
+        ("ImagerPixelSpacing", &tags::ImagerPixelSpacing),
+        ("MultiCoilElementName", &tags::MultiCoilElementName),
+        ("SourceOfCorneaMeasurementDataCodeSequence", &tags::SourceofCorneaMeasurementDataCodeSequence),
+        ("DocumentTitle", &tags::DocumentTitle),
+        ("LUTFunction", &tags::LUTFunction),
+        ("TransducerFrequency", &tags::TransducerFrequency),
+        ("FovealPointProbabilityValue", &tags::FovealPointProbabilityValue),
+        ("RTTreatmentPhaseIntervalSequence", &tags::RTTreatmentPhaseIntervalSequence),
+        ("VerticalPrismPower", &tags::VerticalPrismPower),
+        ("AcquisitionComments", &tags::AcquisitionComments),
+        ("AnnotationPropertyTypeModifierCodeSequence", &tags::AnnotationPropertyTypeModifierCodeSequence),
+        ("ApplicatorApertureShape", &tags::ApplicatorApertureShape),
+        ("TreatmentPositionGroupLabel", &tags::TreatmentPositionGroupLabel),
+        ("WedgeFactor", &tags::WedgeFactor),
+        ("NominalBeamAngle", &tags::NominalBeamAngle),
+        ("AxialLengthOfTheEye", &tags::AxialLengthoftheEye),
+        ("TimeSlotVector", &tags::TimeSlotVector),
+        ("WaterEquivalentDiameter", &tags::WaterEquivalentDiameter),
+        ("RadiationFluenceModifierCodeSequence", &tags::RadiationFluenceModifierCodeSequence),
+        ("ImageBoxPosition", &tags::ImageBoxPosition),
+        ("GantryPitchRotationDirection", &tags::GantryPitchRotationDirection),
+        ("InterlockSequence", &tags::InterlockSequence),
+        ("TagSpacingFirstDimension", &tags::TagSpacingFirstDimension),
+        ("OphthalmicThicknessMapQualityThresholdSequence", &tags::OphthalmicThicknessMapQualityThresholdSequence),
+        ("OCTBscanAnalysisAcquisitionParametersSequence", &tags::OCTBscanAnalysisAcquisitionParametersSequence),
+        ("ImageBoxNumber", &tags::ImageBoxNumber),
+        ("ReformattingOperationType", &tags::ReformattingOperationType),
+        ("BeamLimitingDeviceToleranceSequence", &tags::BeamLimitingDeviceToleranceSequence),
+        ("YCoordinatesCenterPixelViewAngle", &tags::YCoordinatesCenterPixelViewAngle),
+        ("DeviceTypeCodeSequence", &tags::DeviceTypeCodeSequence),
+        ("OpticalSelectedOphthalmicAxialLengthSequence", &tags::OpticalSelectedOphthalmicAxialLengthSequence),
+        ("ExclusiveComponentType", &tags::ExclusiveComponentType),
+        ("OphthalmicAxialLengthMethod", &tags::OphthalmicAxialLengthMethod),
+        ("GeneralAccessoryDefinitionSequence", &tags::GeneralAccessoryDefinitionSequence),
+        ("GreenPaletteColorLookupTableData", &tags::GreenPaletteColorLookupTableData),
+        ("DoseRateDelivered", &tags::DoseRateDelivered),
+        ("RangeModulatorType", &tags::RangeModulatorType),
+        ("LowRRValue", &tags::LowRRValue),
+        ("LUTDescriptor", &tags::LUTDescriptor),
+        ("DisplayFunctionType", &tags::DisplayFunctionType),
+        ("ReconstructionAlgorithmSequence", &tags::ReconstructionAlgorithmSequence),
+        ("FiducialIdentifier", &tags::FiducialIdentifier),
+        ("SelectorOBValue", &tags::SelectorOBValue),
+        ("LuminanceCharacteristicsID", &tags::LuminanceCharacteristicsID),
+        ("SafePositionExitDate", &tags::SafePositionExitDate),
+        ("DetectorManufacturerModelName", &tags::DetectorManufacturersModelName),
+        ("BodyPartExamined", &tags::BodyPartExamined),
+        ("SOPClassesInStudy", &tags::SOPClassesinStudy),
+        ("TriggerWindow", &tags::TriggerWindow),
+        ("SafePositionExitTime", &tags::SafePositionExitTime),
+        ("MPRSlabThickness", &tags::MPRSlabThickness),
+        ("NominalScreenDefinitionSequence", &tags::NominalScreenDefinitionSequence),
+        ("SelectorPNValue", &tags::SelectorPNValue),
+        ("PrimaryAnatomicStructureModifierSequence", &tags::PrimaryAnatomicStructureModifierSequence),
+        ("TopicKeywords", &tags::TopicKeywords),
+        ("IssuerOfAccessionNumberSequence", &tags::IssuerofAccessionNumberSequence),
+        ("MaskSelectionMode", &tags::MaskSelectionMode),
+        ("FractionBasedRelationshipSequence", &tags::FractionBasedRelationshipSequence),
+        ("CalculationCommentType", &tags::CalculationCommentType),
+        ("DistalDepth", &tags::DistalDepth),
+        ("SteadyStatePulseSequence", &tags::SteadyStatePulseSequence),
+        ("NominalPriorDose", &tags::NominalPriorDose),
+        ("FirstOrderPhaseCorrectionAngle", &tags::FirstOrderPhaseCorrectionAngle),
+        ("ScheduledStationNameCodeSequence", &tags::ScheduledStationNameCodeSequence),
+        ("SUVType", &tags::SUVType),
+        ("SegmentSurfaceSourceInstanceSequence", &tags::SegmentSurfaceSourceInstanceSequence),
+        ("OOIOwnerCreationTime", &tags::OOIOwnerCreationTime),
+        ("ContainerComponentSequence", &tags::ContainerComponentSequence),
+        ("UniformResourceLocatorTrial", &tags::UniformResourceLocator_Trial),
+        ("IssuerOfPatientID", &tags::IssuerofPatientID),
+        ("FalseNegativesEstimateFlag", &tags::FalseNegativesEstimateFlag),
+        ("ExposuresOnDetectorSinceLastCalibration", &tags::ExposuresonDetectorSinceLastCalibration),
+        ("PotentialReasonsForProcedure", &tags::PotentialReasonsforProcedure),
+        ("AcquisitionContextSequence", &tags::AcquisitionContextSequence),
+        ("GeneralMachineVerificationSequence", &tags::GeneralMachineVerificationSequence),
+        ("RTPlanRelationship", &tags::RTPlanRelationship),
+        ("ReplacedImplantTemplateGroupSequence", &tags::ReplacedImplantTemplateGroupSequence),
+        ("StoredInstanceTransferSyntaxUID", &tags::StoredInstanceTransferSyntaxUID),
+        ("ExcessiveFixationLosses", &tags::ExcessiveFixationLosses),
+        ("VolumeLocalizationSequence", &tags::VolumeLocalizationSequence),
+        ("SpecimenLocalizationContentItemSequence", &tags::SpecimenLocalizationContentItemSequence),
+        ("TransducerPositionModifierSequence", &tags::TransducerPositionModifierSequence),
+        ("FilmOrientation", &tags::FilmOrientation),
+        ("ContinuationStartMeterset", &tags::ContinuationStartMeterset),
+        ("RadionuclideHalfLife", &tags::RadionuclideHalfLife),
+        ("AdmissionID", &tags::AdmissionID),
+        ("VolumeBasedCalculationTechnique", &tags::VolumeBasedCalculationTechnique),
+        ("SourceToWedgeTrayDistance", &tags::SourcetoWedgeTrayDistance),
+        ("EncapsulatedPixelDataValueTotalLength", &tags::EncapsulatedPixelDataValueTotalLength),
+        ("MRSpectroscopyFrameTypeSequence", &tags::MRSpectroscopyFrameTypeSequence),
+        ("SelectedSegmentalOphthalmicAxialLengthSequence", &tags::SelectedSegmentalOphthalmicAxialLengthSequence),
+        ("LUTNumber", &tags::LUTNumber),
+        ("SecondaryPositionerIncrement", &tags::SecondaryPositionerIncrement),
+        ("CodingSchemeExternalID", &tags::CodingSchemeExternalID),
+        ("TypeOfFilters", &tags::TypeofFilters),
+        ("PrescriptionNotesSequence", &tags::PrescriptionNotesSequence),
+        ("GridAbsorbingMaterial", &tags::GridAbsorbingMaterial),
+        ("SequenceVariant", &tags::SequenceVariant),
+        ("FrameUsefulnessGroupSequence", &tags::FrameUsefulnessGroupSequence),
+        ("SpherePower", &tags::SpherePower),
+        ("IdentificationDescriptionTrial", &tags::IdentificationDescription_Trial),
+        ("LeafPositionBoundaries", &tags::LeafPositionBoundaries),
+        ("PlanningLandmarkID", &tags::PlanningLandmarkID),
+        ("EnhancedPaletteColorLookupTableSequence", &tags::EnhancedPaletteColorLookupTableSequence),
+        ("NumberOfWedgePositions", &tags::NumberofWedgePositions),
+        ("SourceImageSequence", &tags::SourceImageSequence),
+        ("PixelShiftSequence", &tags::PixelShiftSequence),
+        ("DetectorCalibrationData", &tags::DetectorCalibrationData),
+        ("ConvolutionKernelGroup", &tags::ConvolutionKernelGroup),
+        ("RealWorldValueLUTData", &tags::RealWorldValueLUTData),
+        ("CardiacCyclePosition", &tags::CardiacCyclePosition),
+        ("PupilCentroidYCoordinate", &tags::PupilCentroidYCoordinate),
+        ("AssessmentObservationsSequence", &tags::AssessmentObservationsSequence),
+        ("ParallelRTBeamDelimiterDeviceSequence", &tags::ParallelRTBeamDelimiterDeviceSequence),
+        ("EquipmentReferencePointCoordinatesSequence", &tags::EquipmentReferencePointCoordinatesSequence),
+        ("StationAETitle", &tags::StationAETitle),
+        ("BlockMountingPosition", &tags::BlockMountingPosition),
+        ("ModelMirroring", &tags::ModelMirroring),
+        ("PositionerIsocenterDetectorRotationAngle", &tags::PositionerIsocenterDetectorRotationAngle),
+        ("TableColumnDefinitionSequence", &tags::TableColumnDefinitionSequence),
+        ("DepthsOfFocus", &tags::DepthsofFocus),
+        ("ImplantAssemblyTemplateVersion", &tags::ImplantAssemblyTemplateVersion),
+        ("IssuerOfServiceEpisodeID", &tags::IssuerofServiceEpisodeID),
+        ("MeasuredAPDimension", &tags::MeasuredAPDimension),
+        ("BeamOrderIndexTrial", &tags::BeamOrderIndex_Trial),
+        ("StereoRotation", &tags::StereoRotation),
+        ("MatingFeatureID", &tags::MatingFeatureID),
+        ("SourceEndDateTime", &tags::SourceEndDateTime),
+        ("SpecimenDescriptionSequenceTrial", &tags::SpecimenDescriptionSequence_Trial),
+        ("StorageProtocolElementSpecificationSequence", &tags::StorageProtocolElementSpecificationSequence),
+        ("DimensionOrganizationSequence", &tags::DimensionOrganizationSequence),
+        ("CompensatorRelativeStoppingPowerRatio", &tags::CompensatorRelativeStoppingPowerRatio),
+        ("ParallelReductionFactorInPlane", &tags::ParallelReductionFactorInplane),
+        ("SourceApplicatorName", &tags::SourceApplicatorName),
+        ("MetersetToDoseMappingSequence", &tags::MetersettoDoseMappingSequence),
+        ("FractionGroupSummarySequence", &tags::FractionGroupSummarySequence),
+        ("AnatomicStructure", &tags::AnatomicStructure),
+        ("TreatmentSummaryCalculatedDoseReferenceSequence", &tags::TreatmentSummaryCalculatedDoseReferenceSequence),
+        ("DefinitionSourceSequence", &tags::DefinitionSourceSequence),
+        ("MaximumStimulusLuminance", &tags::MaximumStimulusLuminance),
+        ("ComponentInputSequence", &tags::ComponentInputSequence),
+        ("ElementPitchB", &tags::ElementPitchB),
+        ("ImageSetsSequence", &tags::ImageSetsSequence),
+        ("MeasuredDoseType", &tags::MeasuredDoseType),
+        ("RotationAngle", &tags::RotationAngle),
+        ("AccessionNumber", &tags::AccessionNumber),
+        ("AnalogFilterType", &tags::AnalogFilterTypeCodeSequence),
+        ("HomeCommunityID", &tags::HomeCommunityID),
+        ("SourceIdentifier", &tags::SourceIdentifier),
+        ("UsedRTStructureSetROISequence", &tags::UsedRTStructureSetROISequence),
+        ("ContrastBolusIngredientConcentration", &tags::ContrastBolusIngredientConcentration),
+        ("CompensatorSequence", &tags::CompensatorSequence),
+        ("ConstraintType", &tags::ConstraintType),
+        ("ChannelShieldNominalTransmission", &tags::ChannelShieldNominalTransmission),
+        ("ImageReceptorPositionSequence", &tags::ImageReceptorPositionSequence),
+        ("ConcatenationFrameOffsetNumber", &tags::ConcatenationFrameOffsetNumber),
+        ("DeliveredDistalDepthFraction", &tags::DeliveredDistalDepthFraction),
+        ("CodingSchemeVersion", &tags::CodingSchemeVersion),
+        ("CompoundGraphicType", &tags::CompoundGraphicType),
+        ("PerformingPhysicianName", &tags::PerformingPhysiciansName),
+        ("ProductLotIdentifier", &tags::ProductLotIdentifier),
+        ("TabulatedValuesSequence", &tags::TabulatedValuesSequence),
+        ("SpecifiedNumberOfPulses", &tags::SpecifiedNumberofPulses),
+        ("PatientAdditionalPosition", &tags::PatientAdditionalPosition),
+        ("AdditionalInspectionMethodSequence", &tags::AdditionalInspectionMethodSequence),
+        ("ScanVelocity", &tags::ScanVelocity),
+        ("PhaseContrast", &tags::PhaseContrast),
+        ("ApplicationMaximumRepaintTime", &tags::ApplicationMaximumRepaintTime),
+        ("ReferencedRTTreatmentPhaseIndex", &tags::ReferencedRTTreatmentPhaseIndex),
+        ("AcquiredSoundpathLength", &tags::AcquiredSoundpathLength),
+        ("XRayOutput", &tags::XRayOutput),
+        ("SegmentationAlgorithmIdentificationSequence", &tags::SegmentationAlgorithmIdentificationSequence),
+        ("VerifyingOrganization", &tags::VerifyingOrganization),
+        ("InterlockDescription", &tags::InterlockDescription),
+        ("PhysiciansReadingStudyIdentificationSequence", &tags::PhysiciansReadingStudyIdentificationSequence),
+        ("SurgicallyInducedAstigmatismSequence", &tags::SurgicallyInducedAstigmatismSequence),
+        ("EchoPlanarPulseSequence", &tags::EchoPlanarPulseSequence),
+        ("ExpectedCompletionDateTime", &tags::ExpectedCompletionDateTime),
+        ("ObservationSignificance", &tags::ObservationSignificance),
+        ("ShieldingDeviceLabel", &tags::ShieldingDeviceLabel),
+        ("TIDOffset", &tags::TIDOffset),
+        ("ShortTermFluctuation", &tags::ShortTermFluctuation),
+        ("ExposureSequence", &tags::ExposureSequence),
+        ("ProcedureStepProgressParametersSequence", &tags::ProcedureStepProgressParametersSequence),
+        ("RotationDirection", &tags::RotationDirection),
+        ("StartingMetersetValueKnownFlag", &tags::StartingMetersetValueKnownFlag),
+        ("PositionMeasuringDeviceUsed", &tags::PositionMeasuringDeviceUsed),
+        ("AcquisitionSampleSize", &tags::AcquisitionSampleSize),
+        ("StudyTime", &tags::StudyTime),
+        ("ReferencedFractionGroupNumber", &tags::ReferencedFractionGroupNumber),
+        ("RescaleType", &tags::RescaleType),
+        ("ImageFilterDescription", &tags::ImageFilterDescription),
+        ("MPRViewHeightDirection", &tags::MPRViewHeightDirection),
+        ("CurrentObserverTrial", &tags::CurrentObserver_Trial),
+        ("OnAxisBackgroundAnatomicStructureCodeSequenceTrial", &tags::OnAxisBackgroundAnatomicStructureCodeSequence_Trial),
+        ("ModelModification", &tags::ModelModification),
+        ("SystemStatusComment", &tags::SystemStatusComment),
+        ("WaterReferenceAcquisition", &tags::WaterReferenceAcquisition),
+        ("RTBeamLimitingDeviceType", &tags::RTBeamLimitingDeviceType),
+        ("ScheduledProcedureStepSequence", &tags::ScheduledProcedureStepSequence),
+        ("AdmittingTime", &tags::AdmittingTime),
+        ("DeviceSequence", &tags::DeviceSequence),
+        ("RTRadiationSetDeliveryUsage", &tags::RTRadiationSetDeliveryUsage),
+        ("MediaStorageSOPClassUID", &tags::MediaStorageSOPClassUID),
+        ("AcquisitionContrast", &tags::AcquisitionContrast),
+        ("ComponentManufacturer", &tags::ComponentManufacturer),
+        ("TreatmentSiteCodeSequence", &tags::TreatmentSiteCodeSequence),
+        ("StopTrim", &tags::StopTrim),
+        ("ClinicalTrialProtocolEthicsCommitteeName", &tags::ClinicalTrialProtocolEthicsCommitteeName),
+        ("RowPositionInTotalImagePixelMatrix", &tags::RowPositionInTotalImagePixelMatrix),
+        ("CodingSchemeRegistry", &tags::CodingSchemeRegistry),
+        ("ScanMode", &tags::ScanMode),
+        ("ApprovalSequence", &tags::ApprovalSequence),
+        ("NonDICOMOutputCodeSequence", &tags::NonDICOMOutputCodeSequence),
+        ("TimeSliceVector", &tags::TimeSliceVector),
+        ("GeneralizedDefectCorrectedSensitivityDeviationProbabilityValue", &tags::GeneralizedDefectCorrectedSensitivityDeviationProbabilityValue),
+        ("PotentialScheduledProtocolCodeSequence", &tags::PotentialScheduledProtocolCodeSequence),
+        ("InterlockResolutionCodeSequence", &tags::InterlockResolutionCodeSequence),
+        ("AverageBeamDosePointDepth", &tags::AverageBeamDosePointDepth),
+        ("ZonalMapNumberFormat", &tags::ZonalMapNumberFormat),
+        ("Component2ReferencedMatingFeatureSetID", &tags::Component2ReferencedMatingFeatureSetID),
+        ("ImageBoxTileVerticalDimension", &tags::ImageBoxTileVerticalDimension),
+        ("WaveformDataDisplayScale", &tags::WaveformDataDisplayScale),
+        ("WideFieldOphthalmicPhotographyQualityThresholdSequence", &tags::WideFieldOphthalmicPhotographyQualityThresholdSequence),
+        ("PerformedWorkitemCodeSequence", &tags::PerformedWorkitemCodeSequence),
+        ("PerformedProcessingApplicationsCodeSequence", &tags::PerformedProcessingApplicationsCodeSequence),
+        ("ReferencedRTRadiationSequence", &tags::ReferencedRTRadiationSequence),
+        ("ContextGroupVersion", &tags::ContextGroupVersion),
+        ("RecordedRangeModulatorSequence", &tags::RecordedRangeModulatorSequence),
+        ("ProductName", &tags::ProductName),
+        ("ReferencedDoseReferenceUID", &tags::ReferencedDoseReferenceUID),
+        ("VerticesOfThePolygonalOutline", &tags::VerticesofthePolygonalOutline),
+        ("ExposureBiasValue", &tags::ExposureBiasValue),
+        ("NumberOfPhaseEncodingSteps", &tags::NumberofPhaseEncodingSteps),
+        ("ExtendedDepthOfField", &tags::ExtendedDepthofField),
+        ("BlockRows", &tags::BlockRows),
+        ("GantryAngle", &tags::GantryAngle),
+        ("InstitutionCodeSequence", &tags::InstitutionCodeSequence),
+        ("SegmentedKSpaceTraversal", &tags::SegmentedkSpaceTraversal),
+        ("RegionPixelShiftSequence", &tags::RegionPixelShiftSequence),
+        ("Item", &tags::Item),
+        ("TargetMaterialSequence", &tags::TargetMaterialSequence),
+        ("BreastSupportXPositionToIsocenter", &tags::BreastSupportXPositiontoIsocenter),
+        ("ClinicalTrialCoordinatingCenterName", &tags::ClinicalTrialCoordinatingCenterName),
+        ("OtherSmoothingTypesAvailable", &tags::OtherSmoothingTypesAvailable),
+        ("RTImageFrameMVRadiationAcquisitionSequence", &tags::RTImageFrameMVRadiationAcquisitionSequence),
+        ("CollimatorGridName", &tags::CollimatorgridName),
+        ("RTAcquisitionPatientPositionSequence", &tags::RTAcquisitionPatientPositionSequence),
+        ("MRAcquisitionType", &tags::MRAcquisitionType),
+        ("AssertionUID", &tags::AssertionUID),
+        ("InPlanePhaseEncodingDirection", &tags::InplanePhaseEncodingDirection),
+        ("CountsIncluded", &tags::CountsIncluded),
+        ("VisualEvaluationMethodCodeSequence", &tags::VisualEvaluationMethodCodeSequence),
+        ("NumberOfCompletedSuboperations", &tags::NumberofCompletedSuboperations),
+        ("AttributeToleranceValuesSequence", &tags::AttributeToleranceValuesSequence),
+        ("AcquisitionSubtaskSequence", &tags::AcquisitionSubtaskSequence),
+        ("RealWorldValueLastValueMapped", &tags::RealWorldValueLastValueMapped),
+        ("ROIElementalCompositionSequence", &tags::ROIElementalCompositionSequence),
+        ("SubstanceAdministrationApproval", &tags::SubstanceAdministrationApproval),
+        ("RRIntervalTimeNominal", &tags::RRIntervalTimeNominal),
+        ("ImageSetNumber", &tags::ImageSetNumber),
+        ("SegmentationType", &tags::SegmentationType),
+        ("ReferencedVOILUTBoxSequence", &tags::ReferencedVOILUTBoxSequence),
+        ("RefractiveProcedureOccurred", &tags::RefractiveProcedureOccurred),
+        ("ReceiveTransducerSequence", &tags::ReceiveTransducerSequence),
+        ("ExposureInuAs", &tags::ExposureinmicroAs),
+        ("DistanceReceptorPlaneToDetectorHousing", &tags::DistanceReceptorPlanetoDetectorHousing),
+        ("RadiationMachineSSD", &tags::RadiationMachineSSD),
+        ("XRay3DReconstructionSequence", &tags::XRay3DReconstructionSequence),
+        ("MeasurementFunctions", &tags::MeasurementFunctions),
+        ("ScheduledProcedureStepStartDateTime", &tags::ScheduledProcedureStepStartDateTime),
+        ("GraphicObjectSequence", &tags::GraphicObjectSequence),
+        ("OphthalmicThicknessMapThresholdQualityRating", &tags::OphthalmicThicknessMapThresholdQualityRating),
+        ("IOLPower", &tags::IOLPower),
+        ("TreatmentTechniqueNotes", &tags::TreatmentTechniqueNotes),
+        ("MetaboliteMapDescription", &tags::MetaboliteMapDescription),
+        ("DeviceOrderIndex", &tags::DeviceOrderIndex),
+        ("ShowPatientDemographicsFlag", &tags::ShowPatientDemographicsFlag),
+        ("FilterThicknessUsedInGainCalibration", &tags::FilterThicknessUsedinGainCalibration),
+        ("SelectorODValue", &tags::SelectorODValue),
+        ("VisualFieldTestPointNormalsSequence", &tags::VisualFieldTestPointNormalsSequence),
+        ("GraphicAnnotationSequence", &tags::GraphicAnnotationSequence),
+        ("DecompositionAlgorithmIdentificationSequence", &tags::DecompositionAlgorithmIdentificationSequence),
+        ("SOPAuthorizationComment", &tags::SOPAuthorizationComment),
+        ("MREchoSequence", &tags::MREchoSequence),
+        ("NumberOfRangeModulators", &tags::NumberofRangeModulators),
+        ("XRayFilterSequence", &tags::XRayFilterSequence),
+        ("TimeSlotNumber", &tags::TimeSlotNumber),
+        ("LocalizedDeviationProbability", &tags::LocalizedDeviationProbability),
+        ("ShieldingDeviceDescription", &tags::ShieldingDeviceDescription),
+        ("ExtendedMatchingMechanisms", &tags::ExtendedMatchingMechanisms),
+        ("RadiationBeamBlockSlabThickness", &tags::RadiationBeamBlockSlabThickness),
+        ("TargetFrameOfReferenceUID", &tags::TargetFrameofReferenceUID),
+        ("GammaValue", &tags::GammaValue),
+        ("ApexPosition", &tags::ApexPosition),
+        ("MeasuredDoseValue", &tags::MeasuredDoseValue),
+        ("GeneralizedDefectCorrectedSensitivityDeviationFlag", &tags::GeneralizedDefectCorrectedSensitivityDeviationFlag),
+        ("SequenceOfCompressedData", &tags::SequenceofCompressedData),
+        ("MaterialPropertiesDescription", &tags::MaterialPropertiesDescription),
+        ("AnteriorChamberDepthSequence", &tags::AnteriorChamberDepthSequence),
+        ("MaximumCumulativeMetersetExposure", &tags::MaximumCumulativeMetersetExposure),
+        ("GeneralPurposePerformedProcedureStepStatus", &tags::GeneralPurposePerformedProcedureStepStatus),
+        ("RadiationDosimeterUnitSequence", &tags::RadiationDosimeterUnitSequence),
+        ("ApplicationSetupSequence", &tags::ApplicationSetupSequence),
+        ("LengthToEnd", &tags::LengthtoEnd),
+        ("InterpretationTranscriptionDate", &tags::InterpretationTranscriptionDate),
+        ("PredictorColumns", &tags::PredictorColumns),
+        ("FilmSizeID", &tags::FilmSizeID),
+        ("DerivationImplantTemplateSequence", &tags::DerivationImplantTemplateSequence),
+        ("SecondaryCaptureDeviceSoftwareVersions", &tags::SecondaryCaptureDeviceSoftwareVersions),
+        ("PositionerMotion", &tags::PositionerMotion),
+        ("DeliveredTreatmentTime", &tags::DeliveredTreatmentTime),
+        ("FieldOfViewRotation", &tags::FieldofViewRotation),
+        ("PrescriptionDescription", &tags::PrescriptionDescription),
+        ("PositionerSecondaryAngleIncrement", &tags::PositionerSecondaryAngleIncrement),
+        ("IdentifierTypeCode", &tags::IdentifierTypeCode),
+        ("InterlockResolutionUserSequence", &tags::InterlockResolutionUserSequence),
+        ("TomotherapeuticLeafInitialClosedDurations", &tags::TomotherapeuticLeafInitialClosedDurations),
+        ("SoftTissueFocusThermalIndex", &tags::SoftTissuefocusThermalIndex),
+        ("IonControlPointVerificationSequence", &tags::IonControlPointVerificationSequence),
+        ("AverageBeamDosePointSourceToExternalContourDistance", &tags::AverageBeamDosePointSourcetoExternalContourDistance),
+        ("ReferencedBlockNumber", &tags::ReferencedBlockNumber),
+        ("ROINumber", &tags::ROINumber),
+        ("SourceApplicatorType", &tags::SourceApplicatorType),
+        ("AnchorPoint", &tags::AnchorPoint),
+        ("ASLBolusCutoffFlag", &tags::ASLBolusCutoffFlag),
+        ("WedgePositionSequence", &tags::WedgePositionSequence),
+        ("SeriesType", &tags::SeriesType),
+        ("DVHDoseScaling", &tags::DVHDoseScaling),
+        ("SceneCaptureType", &tags::SceneCaptureType),
+        ("LargestImagePixelValue", &tags::LargestImagePixelValue),
+        ("ImplantTemplateGroupMemberMatching2DCoordinatesSequence", &tags::ImplantTemplateGroupMemberMatching2DCoordinatesSequence),
+        ("MACAlgorithm", &tags::MACAlgorithm),
+        ("DoubleFloatPixelPaddingRangeLimit", &tags::DoubleFloatPixelPaddingRangeLimit),
+        ("CornealPower", &tags::CornealPower),
+        ("SegmentationFractionalType", &tags::SegmentationFractionalType),
+        ("DoseReferenceType", &tags::DoseReferenceType),
+        ("ReasonForRequestedProcedureCodeSequence", &tags::ReasonforRequestedProcedureCodeSequence),
+        ("ExaminedBodyThickness", &tags::ExaminedBodyThickness),
+        ("ImageGeometryType", &tags::ImageGeometryType),
+        ("SelectorURValue", &tags::SelectorURValue),
+        ("GreenPaletteColorLookupTableDescriptor", &tags::GreenPaletteColorLookupTableDescriptor),
+        ("SeamLineIndex", &tags::SeamLineIndex),
+        ("RelationshipSequenceTrial", &tags::RelationshipSequence_Trial),
+        ("LightPathFilterPassBand", &tags::LightPathFilterPassBand),
+        ("HPGLContourPenNumber", &tags::HPGLContourPenNumber),
+        ("SliceSensitivityFactor", &tags::SliceSensitivityFactor),
+        ("ReferencedRTTreatmentPhaseSequence", &tags::ReferencedRTTreatmentPhaseSequence),
+        ("FrameDisplaySequence", &tags::FrameDisplaySequence),
+        ("SpecificAbsorptionRateSequence", &tags::SpecificAbsorptionRateSequence),
+        ("ProcedureCodeSequence", &tags::ProcedureCodeSequence),
+        ("StopCumulativeMeterset", &tags::StopCumulativeMeterset),
+        ("OpticalOphthalmicAxialLengthMeasurementsSequence", &tags::OpticalOphthalmicAxialLengthMeasurementsSequence),
+        ("SecondaryPositionerScanStartAngle", &tags::SecondaryPositionerScanStartAngle),
+        ("OphthalmicAxialLengthAcquisitionMethodCodeSequence", &tags::OphthalmicAxialLengthAcquisitionMethodCodeSequence),
+        ("CumulativeMetersetWeight", &tags::CumulativeMetersetWeight),
+        ("PixelValueMappingExplanation", &tags::PixelValueMappingExplanation),
+        ("AngleNumber", &tags::AngleNumber),
+        ("MultienergyCTXRayDetectorSequence", &tags::MultienergyCTXRayDetectorSequence),
+        ("FrameComments", &tags::FrameComments),
+        ("FilterThicknessMinimum", &tags::FilterThicknessMinimum),
+        ("CardiacNumberOfImages", &tags::CardiacNumberofImages),
+        ("CorrectedLocalizedDeviationFromNormal", &tags::CorrectedLocalizedDeviationFromNormal),
+        ("MydriaticAgentCodeSequence", &tags::MydriaticAgentCodeSequence),
+        ("WADORetrievalSequence", &tags::WADORetrievalSequence),
+        ("ContributingSOPInstancesReferenceSequence", &tags::ContributingSOPInstancesReferenceSequence),
+        ("DiffusionBValueYY", &tags::DiffusionbvalueYY),
+        ("HangingProtocolCreationDateTime", &tags::HangingProtocolCreationDateTime),
+        ("RadiationSourceAxisDistance", &tags::RadiationSourceAxisDistance),
+        ("QuantityDefinitionSequence", &tags::QuantityDefinitionSequence),
+        ("VolumetricAnnotationSequence", &tags::VolumetricAnnotationSequence),
+        ("PropertyLabel", &tags::PropertyLabel),
+        ("BlendingLUT1TransferFunction", &tags::BlendingLUT1TransferFunction),
+        ("BreastSupportZPositionToIsocenter", &tags::BreastSupportZPositiontoIsocenter),
+        ("MoveOriginatorMessageID", &tags::MoveOriginatorMessageID),
+        ("ContourNumber", &tags::ContourNumber),
+        ("TrackSetStatisticsSequence", &tags::TrackSetStatisticsSequence),
+        ("ExcessiveFalsePositivesDataFlag", &tags::ExcessiveFalsePositivesDataFlag),
+        ("PerformedLocation", &tags::PerformedLocation),
+        ("DoseMeasurementDeviceCodeSequence", &tags::DoseMeasurementDeviceCodeSequence),
+        ("SeriesDescriptionCodeSequence", &tags::SeriesDescriptionCodeSequence),
+        ("NumberOfFractionPatternDigitsPerDay", &tags::NumberofFractionPatternDigitsPerDay),
+        ("ProcessingFunction", &tags::ProcessingFunction),
+        ("ReferencedOphthalmicAxialMeasurementsSequence", &tags::ReferencedOphthalmicAxialMeasurementsSequence),
+        ("PhotographicSensitivity", &tags::PhotographicSensitivity),
+        ("GantryID", &tags::GantryID),
+        ("CollimatorRightVerticalEdge", &tags::CollimatorRightVerticalEdge),
+        ("ParameterSequencePointer", &tags::ParameterSequencePointer),
+        ("BeamLimitingDevicePositionTolerance", &tags::BeamLimitingDevicePositionTolerance),
+        ("Underlined", &tags::Underlined),
+        ("AcquisitionDeviceProcessingDescription", &tags::AcquisitionDeviceProcessingDescription),
+        ("RadiopharmaceuticalStopDateTime", &tags::RadiopharmaceuticalStopDateTime),
+        ("MagnitudeEncodingCodeSequence", &tags::MagnitudeEncodingCodeSequence),
+        ("ApplicableSafetyStandardDescription", &tags::ApplicableSafetyStandardDescription),
+        ("AngularStep", &tags::AngularStep),
+        ("PatientOrientationInFrameSequence", &tags::PatientOrientationinFrameSequence),
+        ("SegmentAlgorithmType", &tags::SegmentAlgorithmType),
+        ("ReferencedRangeShifterNumber", &tags::ReferencedRangeShifterNumber),
+        ("ReferencedTargetLuminanceCharacteristicsID", &tags::ReferencedTargetLuminanceCharacteristicsID),
+        ("MRImagingModifierSequence", &tags::MRImagingModifierSequence),
+        ("CollimatorShape", &tags::CollimatorShape),
+        ("OCTZOffsetCorrection", &tags::OCTZOffsetCorrection),
+        ("DigitizingDeviceTransportDirection", &tags::DigitizingDeviceTransportDirection),
+        ("ImageID", &tags::ImageID),
+        ("CalculatedFrameList", &tags::CalculatedFrameList),
+        ("AlgorithmNameCodeSequence", &tags::AlgorithmNameCodeSequence),
+        ("IrradiationEventIdentificationSequence", &tags::IrradiationEventIdentificationSequence),
+        ("EthicsCommitteeApprovalEffectivenessStartDate", &tags::EthicsCommitteeApprovalEffectivenessStartDate),
+        ("FalsePositivesQuantity", &tags::FalsePositivesQuantity),
+        ("ReferencedBaselineParametersRTRadiationInstanceSequence", &tags::ReferencedBaselineParametersRTRadiationInstanceSequence),
+        ("ReferencedBasicAnnotationBoxSequence", &tags::ReferencedBasicAnnotationBoxSequence),
+        ("ContactMethod", &tags::ContactMethod),
+        ("SnoutPositionTolerance", &tags::SnoutPositionTolerance),
+        ("CompensatorPosition", &tags::CompensatorPosition),
+        ("DoseReferenceDescription", &tags::DoseReferenceDescription),
+        ("FixationMonitoringCodeSequence", &tags::FixationMonitoringCodeSequence),
+        ("GPSLatitudeRef", &tags::GPSLatitudeRef),
+        ("SensitivityCalibrated", &tags::SensitivityCalibrated),
+        ("PrintManagementCapabilitiesSequence", &tags::PrintManagementCapabilitiesSequence),
+        ("ImagingSourceLocationSpecificationType", &tags::ImagingSourceLocationSpecificationType),
+        ("ExcessiveFalsePositives", &tags::ExcessiveFalsePositives),
+        ("MidSlabPosition", &tags::MidSlabPosition),
+        ("PatientSupportPositionSpecificationMethod", &tags::PatientSupportPositionSpecificationMethod),
+        ("FinalCumulativeTimeWeight", &tags::FinalCumulativeTimeWeight),
+        ("MACParametersSequence", &tags::MACParametersSequence),
+        ("InternalDetectorFrameTime", &tags::InternalDetectorFrameTime),
+        ("WedgeRoofAngle", &tags::WedgeRoofAngle),
+        ("TemporalPositionTimeOffset", &tags::TemporalPositionTimeOffset),
+        ("CountsAccumulated", &tags::CountsAccumulated),
+        ("RationalNumeratorValue", &tags::RationalNumeratorValue),
+        ("IonControlPointDeliverySequence", &tags::IonControlPointDeliverySequence),
+        ("ProtocolElementName", &tags::ProtocolElementName),
+        ("TreatmentToleranceViolationDateTime", &tags::TreatmentToleranceViolationDateTime),
+        ("ExternalContourEntryPoint", &tags::ExternalContourEntryPoint),
+        ("BorderDensity", &tags::BorderDensity),
+        ("DateOfManufacture", &tags::DateofManufacture),
+        ("GPSAltitude", &tags::GPSAltitude),
+        ("FluenceDataSource", &tags::FluenceDataSource),
+        ("FovealSensitivityMeasured", &tags::FovealSensitivityMeasured),
+        ("AverageCornealPower", &tags::AverageCornealPower),
+        ("PatientSupportPositionToleranceOrderIndex", &tags::PatientSupportPositionToleranceOrderIndex),
+        ("TableLongitudinalIncrement", &tags::TableLongitudinalIncrement),
+        ("ReasonForTheAttributeModification", &tags::ReasonfortheAttributeModification),
+        ("ScheduledProcedureStepDescription", &tags::ScheduledProcedureStepDescription),
+        ("ExposureTimeInuS", &tags::ExposureTimeinmicroS),
+        ("CalibrationDate", &tags::CalibrationDate),
+        ("MaximumNumberOfIntervalDays", &tags::MaximumNumberofIntervalDays),
+        ("FrameNumbersOfInterest", &tags::FrameNumbersofInterestFOI),
+        ("ReferencedSourceApplicatorNumber", &tags::ReferencedSourceApplicatorNumber),
+        ("StudyComments", &tags::StudyComments),
+        ("FieldOfViewDescription", &tags::FieldofViewDescription),
+        ("ReferencedContentItem", &tags::ReferencedContentItem),
+        ("IncludesImagingSubject", &tags::IncludesImagingSubject),
+        ("ScreenMinimumGrayscaleBitDepth", &tags::ScreenMinimumGrayscaleBitDepth),
+        ("DegreeOfDilation", &tags::DegreeofDilation),
+        ("OpticalPathIdentificationSequence", &tags::OpticalPathIdentificationSequence),
+        ("OverlayBackgroundDensity", &tags::OverlayBackgroundDensity),
+        ("USImageDescriptionSequence", &tags::USImageDescriptionSequence),
+        ("ProductPackageIdentifier", &tags::ProductPackageIdentifier),
+        ("CylinderPower", &tags::CylinderPower),
+        ("InstructionDescription", &tags::InstructionDescription),
+        ("SelectorUIValue", &tags::SelectorUIValue),
+        ("SurfaceProcessingDescription", &tags::SurfaceProcessingDescription),
+        ("StimuliRetestingQuantity", &tags::StimuliRetestingQuantity),
+        ("CertificateType", &tags::CertificateType),
+        ("SourceToBeamLimitingDeviceDistance", &tags::SourcetoBeamLimitingDeviceDistance),
+        ("FovealSensitivity", &tags::FovealSensitivity),
+        ("ControlPointRelativePosition", &tags::ControlPointRelativePosition),
+        ("BrachyApplicationSetupDose", &tags::BrachyApplicationSetupDose),
+        ("SurfaceScanModeCodeSequence", &tags::SurfaceScanModeCodeSequence),
+        ("FillPattern", &tags::FillPattern),
+        ("CodeNumberFormat", &tags::CodeNumberFormat),
+        ("ReferencedBrachyApplicationSetupSequence", &tags::ReferencedBrachyApplicationSetupSequence),
+        ("CompoundGraphicUnits", &tags::CompoundGraphicUnits),
+        ("TrackPointIndexList", &tags::TrackPointIndexList),
+        ("MeasurementPatternCodeSequence", &tags::MeasurementPatternCodeSequence),
+        ("VisualFieldTestPointYCoordinate", &tags::VisualFieldTestPointYCoordinate),
+        ("ExtendedOffsetTableLengths", &tags::ExtendedOffsetTableLengths),
+        ("PlanOverviewSequence", &tags::PlanOverviewSequence),
+        ("SelectorSequencePointerItems", &tags::SelectorSequencePointerItems),
+        ("DigitalImageFormatAcquired", &tags::DigitalImageFormatAcquired),
+        ("IsocenterToLateralSpreadingDeviceDistance", &tags::IsocentertoLateralSpreadingDeviceDistance),
+        ("VectorDimensionality", &tags::VectorDimensionality),
+        ("ParallelReductionFactorInPlaneRetired", &tags::ParallelReductionFactorInplane_Retired),
+        ("DetectorActiveAreaOrientation", &tags::DetectorActiveAreaOrientation),
+        ("DeviceUID", &tags::DeviceUID),
+        ("NumberOfAcquisitionDevices", &tags::NumberofAcquisitionDevices),
+        ("SurfaceProcessing", &tags::SurfaceProcessing),
+        ("DoubleExposureMeterset", &tags::DoubleExposureMeterset),
+        ("DoseUnits", &tags::DoseUnits),
+        ("PhantomType", &tags::PhantomType),
+        ("PrimaryAnatomicStructureSequence", &tags::PrimaryAnatomicStructureSequence),
+        ("StimulusResults", &tags::StimulusResults),
+        ("ImagePosition", &tags::ImagePosition),
+        ("AlphaLUTTransferFunction", &tags::AlphaLUTTransferFunction),
+        ("BitsMappedToColorLookupTable", &tags::BitsMappedtoColorLookupTable),
+        ("DeviceMotionExecutionMode", &tags::DeviceMotionExecutionMode),
+        ("EquivalentConceptualVolumesSequence", &tags::EquivalentConceptualVolumesSequence),
+        ("AssertionDateTime", &tags::AssertionDateTime),
+        ("SourceToCompensatorDistance", &tags::SourcetoCompensatorDistance),
+        ("ClinicalTrialSiteID", &tags::ClinicalTrialSiteID),
+        ("IncludesInformation", &tags::IncludesInformation),
+        ("RTAnatomicPrescriptionSequence", &tags::RTAnatomicPrescriptionSequence),
+        ("CatheterDirectionOfRotation", &tags::CatheterDirectionofRotation),
+        ("ReceiverSettingsSequence", &tags::ReceiverSettingsSequence),
+        ("IsocenterToCompensatorTrayDistance", &tags::IsocentertoCompensatorTrayDistance),
+        ("HistogramFirstBinValue", &tags::HistogramFirstBinValue),
+        ("UnifiedProcedureStepPerformedProcedureSequence", &tags::UnifiedProcedureStepPerformedProcedureSequence),
+        ("FluoroscopyFlag", &tags::FluoroscopyFlag),
+        ("CountryOfResidence", &tags::CountryofResidence),
+        ("MaximumCollatedFilms", &tags::MaximumCollatedFilms),
+        ("TransmitCoilType", &tags::TransmitCoilType),
+        ("PositionReferenceIndicator", &tags::PositionReferenceIndicator),
+        ("RelatedGeneralSOPClassUID", &tags::RelatedGeneralSOPClassUID),
+        ("FrameTime", &tags::FrameTime),
+        ("MaterialID", &tags::MaterialID),
+        ("WedgeTotalLength", &tags::WedgeTotalLength),
+        ("ReportedValuesOrigin", &tags::ReportedValuesOrigin),
+        ("PlaneIdentification", &tags::PlaneIdentification),
+        ("ScanSpotTimeOffset", &tags::ScanSpotTimeOffset),
+        ("PixelIntensityRelationshipSign", &tags::PixelIntensityRelationshipSign),
+        ("ImageDimensions", &tags::ImageDimensions),
+        ("GeneralAccessoryType", &tags::GeneralAccessoryType),
+        ("MACIDNumber", &tags::MACIDNumber),
+        ("ActiveSourceLength", &tags::ActiveSourceLength),
+        ("DataInformationSequence", &tags::DataInformationSequence),
+        ("OrderCallbackPhoneNumber", &tags::OrderCallbackPhoneNumber),
+        ("MappingResourceName", &tags::MappingResourceName),
+        ("SegmentCharacteristicsPrecedence", &tags::SegmentCharacteristicsPrecedence),
+        ("ConfigurationInformationDescription", &tags::ConfigurationInformationDescription),
+        ("TimeOfGainCalibration", &tags::TimeofGainCalibration),
+        ("ContainerIdentifier", &tags::ContainerIdentifier),
+        ("QuantitySequence", &tags::QuantitySequence),
+        ("PredictedToricErrorSequence", &tags::PredictedToricErrorSequence),
+        ("KVUsedInGainCalibration", &tags::KVUsedinGainCalibration),
+        ("VelocityEncodingMaximumValue", &tags::VelocityEncodingMaximumValue),
+        ("IonBeamSequence", &tags::IonBeamSequence),
+        ("RequestingPhysician", &tags::RequestingPhysician),
+        ("ReferencedFirstFrameSequence", &tags::ReferencedFirstFrameSequence),
+        ("ReferringPhysicianIdentificationSequence", &tags::ReferringPhysicianIdentificationSequence),
+        ("RadiationDoseCentralAxisDisplacement", &tags::RadiationDoseCentralAxisDisplacement),
+        ("CommentsOnTheScheduledProcedureStep", &tags::CommentsontheScheduledProcedureStep),
+        ("MessageIDBeingRespondedTo", &tags::MessageIDBeingRespondedTo),
+        ("PrivateDataElementName", &tags::PrivateDataElementName),
+        ("RadiationDoseSourceToSkinDistance", &tags::RadiationDoseSourcetoSkinDistance),
+        ("GantryMotionCorrected", &tags::GantryMotionCorrected),
+        ("OpticalPathDescription", &tags::OpticalPathDescription),
+        ("AnnotationPosition", &tags::AnnotationPosition),
+        ("NamesOfIntendedRecipientsOfResults", &tags::NamesofIntendedRecipientsofResults),
+        ("FileMetaInformationVersion", &tags::FileMetaInformationVersion),
+        ("CroppingSpecificationNumber", &tags::CroppingSpecificationNumber),
+        ("EquivalentPupilRadius", &tags::EquivalentPupilRadius),
+        ("PatientTreatmentOrientationSequence", &tags::PatientTreatmentOrientationSequence),
+        ("RecommendedDisplayCIELabValueList", &tags::RecommendedDisplayCIELabValueList),
+        ("RTPrescriptionSequence", &tags::RTPrescriptionSequence),
+        ("ImplantTemplateGroupVariationDimensionRankSequence", &tags::ImplantTemplateGroupVariationDimensionRankSequence),
+        ("ImplantTemplate3DModelSurfaceNumber", &tags::ImplantTemplate3DModelSurfaceNumber),
+        ("DLPNotificationTrigger", &tags::DLPNotificationTrigger),
+        ("RequestedContrastAgent", &tags::RequestedContrastAgent),
+        ("ProtocolElementNumber", &tags::ProtocolElementNumber),
+        ("RadiationBeamWedgeAngle", &tags::RadiationBeamWedgeAngle),
+        ("SortingDirection", &tags::SortingDirection),
+        ("DVHData", &tags::DVHData),
+        ("FractionGroupNumber", &tags::FractionGroupNumber),
+        ("ResponsibleOrganization", &tags::ResponsibleOrganization),
+        ("CalibrationSequence", &tags::CalibrationSequence),
+        ("WedgeManufacturerName", &tags::WedgeManufacturerName),
+        ("LateralSpreadingDeviceWaterEquivalentThickness", &tags::LateralSpreadingDeviceWaterEquivalentThickness),
+        ("Quantity", &tags::Quantity),
+        ("PETFrameAcquisitionSequence", &tags::PETFrameAcquisitionSequence),
+        ("EmptyImageBoxCIELabValue", &tags::EmptyImageBoxCIELabValue),
+        ("SubstanceAdministrationParameterSequence", &tags::SubstanceAdministrationParameterSequence),
+        ("ExposureTimeInSeconds", &tags::ExposureTimeinSeconds),
+        ("DateOfGainCalibration", &tags::DateofGainCalibration),
+        ("ExecutionStatusInfo", &tags::ExecutionStatusInfo),
+        ("DataPointRows", &tags::DataPointRows),
+        ("ParallelAcquisitionTechnique", &tags::ParallelAcquisitionTechnique),
+        ("QueryRetrieveView", &tags::QueryRetrieveView),
+        ("GradientEchoTrainLength", &tags::GradientEchoTrainLength),
+        ("ImageAndFluoroscopyAreaDoseProduct", &tags::ImageandFluoroscopyAreaDoseProduct),
+        ("OphthalmicAxialLengthDataSourceDescription", &tags::OphthalmicAxialLengthDataSourceDescription),
+        ("Component2ReferencedID", &tags::Component2ReferencedID),
+        ("TableTopLateralSetupDisplacement", &tags::TableTopLateralSetupDisplacement),
+        ("BeamAreaLimitSequence", &tags::BeamAreaLimitSequence),
+        ("OpticalMagnificationFactor", &tags::OpticalMagnificationFactor),
+        ("VisualAcuityMeasurementSequence", &tags::VisualAcuityMeasurementSequence),
+        ("CameraElevationAngle", &tags::CameraElevationAngle),
+        ("ICCProfile", &tags::ICCProfile),
+        ("NominalBeamEnergy", &tags::NominalBeamEnergy),
+        ("AcquisitionSignalType", &tags::AcquisitionSignalType),
+        ("TemporalResolution", &tags::TemporalResolution),
+        ("WeightingTransferFunctionSequence", &tags::WeightingTransferFunctionSequence),
+        ("GPSProcessingMethod", &tags::GPSProcessingMethod),
+        ("MeasuredBeamDimensionB", &tags::MeasuredBeamDimensionB),
+        ("DVHMeanDose", &tags::DVHMeanDose),
+        ("DocumentingObserverIdentifierCodeSequenceTrial", &tags::DocumentingObserverIdentifierCodeSequence_Trial),
+        ("TemporalPositionSequence", &tags::TemporalPositionSequence),
+        ("DetectorSecondaryAngle", &tags::DetectorSecondaryAngle),
+        ("ConceptualVolumeConstituentSegmentationReferenceSequence", &tags::ConceptualVolumeConstituentSegmentationReferenceSequence),
+        ("ChemicalShiftMaximumIntegrationLimitInppm", &tags::ChemicalShiftMaximumIntegrationLimitinppm),
+        ("PTORepresentationSequence", &tags::PTORepresentationSequence),
+        ("PrivateDataElementDescription", &tags::PrivateDataElementDescription),
+        ("DeviceAlternateIdentifierFormat", &tags::DeviceAlternateIdentifierFormat),
+        ("NumberOfPolygonalVertices", &tags::NumberofPolygonalVertices),
+        ("GPSSpeed", &tags::GPSSpeed),
+        ("RetrieveURI", &tags::RetrieveURI),
+        ("TargetExposureIndex", &tags::TargetExposureIndex),
+        ("DeliveryRate", &tags::DeliveryRate),
+        ("NumberOfPatientRelatedStudies", &tags::NumberofPatientRelatedStudies),
+        ("ImplementationClassUID", &tags::ImplementationClassUID),
+        ("OutlineLowerHorizontalEdge", &tags::OutlineLowerHorizontalEdge),
+        ("InstructionPerformedFlag", &tags::InstructionPerformedFlag),
+        ("AmbientLightValueSource", &tags::AmbientLightValueSource),
+        ("Modality", &tags::Modality),
+        ("IncludeNonDICOMObjects", &tags::IncludeNonDICOMObjects),
+        ("ToricIOLPowerSequence", &tags::ToricIOLPowerSequence),
+        ("DegreeOfFreedomType", &tags::DegreeofFreedomType),
+        ("BrightnessValue", &tags::BrightnessValue),
+        ("dBdt", &tags::DBdt),
+        ("LongTrianglePointIndexList", &tags::LongTrianglePointIndexList),
+        ("InterventionDrugDose", &tags::InterventionDrugDose),
+        ("VisualAcuityModifiers", &tags::VisualAcuityModifiers),
+        ("PulseSpecificBrachyControlPointDeliveredSequence", &tags::PulseSpecificBrachyControlPointDeliveredSequence),
+        ("VolumetricPresentationInputSetSequence", &tags::VolumetricPresentationInputSetSequence),
+        ("GraphicFilled", &tags::GraphicFilled),
+        ("TotalCollimationWidth", &tags::TotalCollimationWidth),
+        ("SubjectArea", &tags::SubjectArea),
+        ("SourceModelID", &tags::SourceModelID),
+        ("ContainerComponentMaterial", &tags::ContainerComponentMaterial),
+        ("LongitudinalTemporalInformationModified", &tags::LongitudinalTemporalInformationModified),
+        ("VideoImageFormatAcquired", &tags::VideoImageFormatAcquired),
+        ("TMLinePositionX1Retired", &tags::TMLinePositionX1_Retired),
+        ("LossyImageCompression", &tags::LossyImageCompression),
+        ("ReprojectionMethod", &tags::ReprojectionMethod),
+        ("GraphicLayerOrder", &tags::GraphicLayerOrder),
+        ("ROIVolume", &tags::ROIVolume),
+        ("ReferencedBeamNumber", &tags::ReferencedBeamNumber),
+        ("PatientSupportID", &tags::PatientSupportID),
+        ("ImageBoxesSequence", &tags::ImageBoxesSequence),
+        ("SelectorDSValue", &tags::SelectorDSValue),
+        ("SoftTissueSurfaceThermalIndex", &tags::SoftTissuesurfaceThermalIndex),
+        ("FocalSpots", &tags::FocalSpots),
+        ("LabelUsingInformationExtractedFromInstances", &tags::LabelUsingInformationExtractedFromInstances),
+        ("GeneralizedDefectCorrectedSensitivityDeviationValue", &tags::GeneralizedDefectCorrectedSensitivityDeviationValue),
+        ("PixelData", &tags::PixelData),
+        ("AbstractPriorCodeSequence", &tags::AbstractPriorCodeSequence),
+        ("RotationOfScannedFilm", &tags::RotationofScannedFilm),
+        ("WedgeAngle", &tags::WedgeAngle),
+        ("IndicationType", &tags::IndicationType),
+        ("ViewOrientationModifierCodeSequence", &tags::ViewOrientationModifierCodeSequence),
+        ("DetectorXPositionToIsocenter", &tags::DetectorXPositiontoIsocenter),
+        ("DataObservationSequence", &tags::DataObservationSequence),
+        ("LensSerialNumber", &tags::LensSerialNumber),
+        ("MetadataSequence", &tags::MetadataSequence),
+        ("DigitalSignaturesSequence", &tags::DigitalSignaturesSequence),
+        ("SpatialTransformOfDose", &tags::SpatialTransformofDose),
+        ("ArterialSpinLabelingContrast", &tags::ArterialSpinLabelingContrast),
+        ("AdditionalRTAccessoryDeviceSequence", &tags::AdditionalRTAccessoryDeviceSequence),
+        ("ReferencedRawDataSequence", &tags::ReferencedRawDataSequence),
+        ("ReferencedStructuredContextSequence", &tags::ReferencedStructuredContextSequence),
+        ("ApplicationSetupManufacturer", &tags::ApplicationSetupManufacturer),
+        ("SelectorAttributePrivateCreator", &tags::SelectorAttributePrivateCreator),
+        ("ReferencedPrintJobSequencePullStoredPrint", &tags::ReferencedPrintJobSequencePullStoredPrint),
+        ("CenterOfCircularCollimator", &tags::CenterofCircularCollimator),
+        ("ReceivingAE", &tags::ReceivingAE),
+        ("CenterOfCircularShutter", &tags::CenterofCircularShutter),
+        ("AnalyzedArea", &tags::AnalyzedArea),
+        ("UpdatedMetadataSequence", &tags::UpdatedMetadataSequence),
+        ("RTAccessoryHolderSlotDistance", &tags::RTAccessoryHolderSlotDistance),
+        ("MajorTicksSequence", &tags::MajorTicksSequence),
+        ("ResultsDistributionListSequence", &tags::ResultsDistributionListSequence),
+        ("XCoordinatesCenterPixelViewAngle", &tags::XCoordinatesCenterPixelViewAngle),
+        ("PatientLocationCoordinatesCodeSequence", &tags::PatientLocationCoordinatesCodeSequence),
+        ("OphthalmicAxialMeasurementsDeviceType", &tags::OphthalmicAxialMeasurementsDeviceType),
+        ("FlashFunctionPresent", &tags::FlashFunctionPresent),
+        ("BackgroundLuminance", &tags::BackgroundLuminance),
+        ("ClinicalFractionNumber", &tags::ClinicalFractionNumber),
+        ("IndicationROISequence", &tags::IndicationROISequence),
+        ("ReferencedImageEvidenceSequence", &tags::ReferencedImageEvidenceSequence),
+        ("ObservationSubjectClassTrial", &tags::ObservationSubjectClass_Trial),
+        ("NearPupillaryDistance", &tags::NearPupillaryDistance),
+        ("DepthOfTransverseImage", &tags::DepthofTransverseImage),
+        ("MaximumNumberOfRecords", &tags::MaximumNumberofRecords),
+        ("SpecificationSelectionGuidance", &tags::SpecificationSelectionGuidance),
+        ("ReconstructionEndLocationSequence", &tags::ReconstructionEndLocationSequence),
+        ("SourceStrengthReferenceDate", &tags::SourceStrengthReferenceDate),
+        ("PlanningLandmarkPlaneSequence", &tags::PlanningLandmarkPlaneSequence),
+        ("LargestPixelValueInSeries", &tags::LargestPixelValueinSeries),
+        ("FrameDimensionPointer", &tags::FrameDimensionPointer),
+        ("PixelPaddingRangeLimit", &tags::PixelPaddingRangeLimit),
+        ("AngularPosition", &tags::AngularPosition),
+        ("FindingsGroupUIDTrial", &tags::FindingsGroupUID_Trial),
+        ("RGBLUTTransferFunction", &tags::RGBLUTTransferFunction),
+        ("DecimalVisualAcuity", &tags::DecimalVisualAcuity),
+        ("HPGLPenDescription", &tags::HPGLPenDescription),
+        ("LateralSpreadingDeviceType", &tags::LateralSpreadingDeviceType),
+        ("StrainSource", &tags::StrainSource),
+        ("ContourOffsetVector", &tags::ContourOffsetVector),
+        ("AdmittingDiagnosesDescription", &tags::AdmittingDiagnosesDescription),
+        ("ReviewerName", &tags::ReviewerName),
+        ("DetectorID", &tags::DetectorID),
+        ("FilmDestination", &tags::FilmDestination),
+        ("StationName", &tags::StationName),
+        ("OffsetOfTheLastDirectoryRecordOfTheRootDirectoryEntity", &tags::OffsetoftheLastDirectoryRecordoftheRootDirectoryEntity),
+        ("LensesCodeSequence", &tags::LensesCodeSequence),
+        ("DerivationConceptualVolumeSequence", &tags::DerivationConceptualVolumeSequence),
+        ("MaterialPipeDiameter", &tags::MaterialPipeDiameter),
+        ("ElementPitchA", &tags::ElementPitchA),
+        ("HangingProtocolDescription", &tags::HangingProtocolDescription),
+        ("AnodeTargetMaterial", &tags::AnodeTargetMaterial),
+        ("ShieldingDevicePosition", &tags::ShieldingDevicePosition),
+        ("SteepKeratometricAxisSequence", &tags::SteepKeratometricAxisSequence),
+        ("RespiratoryMotionCompensationTechniqueDescription", &tags::RespiratoryMotionCompensationTechniqueDescription),
+        ("MaximumCornealCurvatureSequence", &tags::MaximumCornealCurvatureSequence),
+        ("CoordinateSystemAxisUnits", &tags::CoordinateSystemAxisUnits),
+        ("RadiusOfCurvature", &tags::RadiusofCurvature),
+        ("TablePosition", &tags::TablePosition),
+        ("EventTypeID", &tags::EventTypeID),
+        ("OmittedRadiationSequence", &tags::OmittedRadiationSequence),
+        ("DiffusionBValueZZ", &tags::DiffusionbvalueZZ),
+        ("ReferencedSpatialRegistrationSequence", &tags::ReferencedSpatialRegistrationSequence),
+        ("FilterBeamPathLengthMaximum", &tags::FilterBeamPathLengthMaximum),
+        ("ChannelDefinitionSequence", &tags::ChannelDefinitionSequence),
+        ("ChannelShieldName", &tags::ChannelShieldName),
+        ("SpecimenAccessionNumber", &tags::SpecimenAccessionNumber),
+        ("GPSDestLatitude", &tags::GPSDestLatitude),
+        ("ReferencedFrameNumber", &tags::ReferencedFrameNumber),
+        ("PrivateDataElementEncoding", &tags::PrivateDataElementEncoding),
+        ("DetectorElementPhysicalSize", &tags::DetectorElementPhysicalSize),
+        ("DimensionDescriptionLabel", &tags::DimensionDescriptionLabel),
+        ("ChemicalShiftMinimumIntegrationLimitInppm", &tags::ChemicalShiftMinimumIntegrationLimitinppm),
+        ("NumberOfIntervalFractions", &tags::NumberofIntervalFractions),
+        ("TwoDImplantTemplateGroupMemberMatchingAxes", &tags::Tag_2DImplantTemplateGroupMemberMatchingAxes),
+        ("ContrastBolusAgentSequence", &tags::ContrastBolusAgentSequence),
+        ("ScheduledProcedureStepID", &tags::ScheduledProcedureStepID),
+        ("RTImageOrientation", &tags::RTImageOrientation),
+        ("NumberOfFramesUsedForIntegration", &tags::NumberofFramesUsedforIntegration),
+        ("TrackingUID", &tags::TrackingUID),
+        ("TimeOfLastDetectorCalibration", &tags::TimeofLastDetectorCalibration),
+        ("InversionTime", &tags::InversionTime),
+        ("CoordinateSystemTransformSequence", &tags::CoordinateSystemTransformSequence),
+        ("EnergyWindowVector", &tags::EnergyWindowVector),
+        ("PlannedVerificationImageSequence", &tags::PlannedVerificationImageSequence),
+        ("WedgeDefinitionSequence", &tags::WedgeDefinitionSequence),
+        ("ScheduledStepAttributesSequence", &tags::ScheduledStepAttributesSequence),
+        ("TissueLocation", &tags::TissueLocation),
+        ("DigitalFilterTypeCodeSequence", &tags::DigitalFilterTypeCodeSequence),
+        ("RecordedChannelShieldSequence", &tags::RecordedChannelShieldSequence),
+        ("ResourceDescription", &tags::ResourceDescription),
+        ("ReferencedRTRadiationSetSequence", &tags::ReferencedRTRadiationSetSequence),
+        ("RadiopharmaceuticalAgentNumber", &tags::RadiopharmaceuticalAgentNumber),
+        ("MaterialNotes", &tags::MaterialNotes),
+        ("DiameterOfCircularOutline", &tags::DiameterofCircularOutline),
+        ("ReconstructionPipelineType", &tags::ReconstructionPipelineType),
+        ("MeasuredDoseDescription", &tags::MeasuredDoseDescription),
+        ("RTImageName", &tags::RTImageName),
+        ("FiducialSequence", &tags::FiducialSequence),
+        ("NumberOfViewsInStage", &tags::NumberofViewsinStage),
+        ("FilterType", &tags::FilterType),
+        ("GraphicGroupDescription", &tags::GraphicGroupDescription),
+        ("ProcedureTypeCodeSequence", &tags::ProcedureTypeCodeSequence),
+        ("LargeBluePaletteColorLookupTableDescriptor", &tags::LargeBluePaletteColorLookupTableDescriptor),
+        ("OwnerID", &tags::OwnerID),
+        ("ExposureControlSensingRegionsSequence", &tags::ExposureControlSensingRegionsSequence),
+        ("WaveformSequence", &tags::WaveformSequence),
+        ("SurfaceScanAcquisitionTypeCodeSequence", &tags::SurfaceScanAcquisitionTypeCodeSequence),
+        ("ViewpointLookAtPoint", &tags::ViewpointLookAtPoint),
+        ("ReformattingInterval", &tags::ReformattingInterval),
+        ("DocumentAuthorTrial", &tags::DocumentAuthor_Trial),
+        ("ParameterItemIndex", &tags::ParameterItemIndex),
+        ("TableOfXBreakPoints", &tags::TableofXBreakPoints),
+        ("AdditionalParameterRecordingInstanceSequence", &tags::AdditionalParameterRecordingInstanceSequence),
+        ("SelectorUCValue", &tags::SelectorUCValue),
+        ("RTROIObservationsSequence", &tags::RTROIObservationsSequence),
+        ("CornealISValue", &tags::CornealISValue),
+        ("ConfigurationQAResultsSequence", &tags::ConfigurationQAResultsSequence),
+        ("SphericalLensPower", &tags::SphericalLensPower),
+        ("RTRadiationSetDeliveryNumber", &tags::RTRadiationSetDeliveryNumber),
+        ("ReferencedFiducialsUID", &tags::ReferencedFiducialsUID),
+        ("AlgorithmFamilyCodeSequence", &tags::AlgorithmFamilyCodeSequence),
+        ("PrimaryDoseValueIndicator", &tags::PrimaryDoseValueIndicator),
+        ("ShutterShape", &tags::ShutterShape),
+        ("TargetLabel", &tags::TargetLabel),
+        ("ReferencedSOPClassUIDInFile", &tags::ReferencedSOPClassUIDinFile),
+        ("ReferencePixelPhysicalValueY", &tags::ReferencePixelPhysicalValueY),
+        ("ROIPhysicalProperty", &tags::ROIPhysicalProperty),
+        ("RenderFieldOfView", &tags::RenderFieldofView),
+        ("AcquisitionCompressionType", &tags::AcquisitionCompressionType),
+        ("DataElementMinimumCharacters", &tags::DataElementMinimumCharacters),
+        ("RadiationType", &tags::RadiationType),
+        ("SourceHangingProtocolSequence", &tags::SourceHangingProtocolSequence),
+        ("SmoothingType", &tags::SmoothingType),
+        ("ModifiedImageDescription", &tags::ModifiedImageDescription),
+        ("GraphicData", &tags::GraphicData),
+        ("AnnotationGroupGenerationType", &tags::AnnotationGroupGenerationType),
+        ("DetectorType", &tags::DetectorType),
+        ("StereoBaselineDisplacement", &tags::StereoBaselineDisplacement),
+        ("NominalBeamEnergyUnit", &tags::NominalBeamEnergyUnit),
+        ("SourceAcquisitionProtocolElementNumber", &tags::SourceAcquisitionProtocolElementNumber),
+        ("BeamDosePointEquivalentDepth", &tags::BeamDosePointEquivalentDepth),
+        ("RecommendedDisplayCIELabValue", &tags::RecommendedDisplayCIELabValue),
+        ("VolumetricPresentationInputNumber", &tags::VolumetricPresentationInputNumber),
+        ("WindowCenterWidthExplanation", &tags::WindowCenter_and_WidthExplanation),
+        ("LongCodeValue", &tags::LongCodeValue),
+        ("VolumeOfPTO", &tags::VolumeofPTO),
+        ("CompensatorTransmissionData", &tags::CompensatorTransmissionData),
+        ("PixelSpacingSequence", &tags::PixelSpacingSequence),
+        ("GraphicLayerRecommendedDisplayCIELabValue", &tags::GraphicLayerRecommendedDisplayCIELabValue),
+        ("ScheduledProcedureStepStartDate", &tags::ScheduledProcedureStepStartDate),
+        ("HangingProtocolUserGroupName", &tags::HangingProtocolUserGroupName),
+        ("GPSDestBearing", &tags::GPSDestBearing),
+        ("AcquisitionStartLocationSequence", &tags::AcquisitionStartLocationSequence),
+        ("DriveProbeSequence", &tags::DriveProbeSequence),
+        ("RWaveTimeVector", &tags::RWaveTimeVector),
+        ("RequestedSeriesDescription", &tags::RequestedSeriesDescription),
+        ("StructureSetName", &tags::StructureSetName),
+        ("AnnotationFlag", &tags::AnnotationFlag),
+        ("SegmentReferenceIndex", &tags::SegmentReferenceIndex),
+        ("ReferencedSOPInstanceMACSequence", &tags::ReferencedSOPInstanceMACSequence),
+        ("GPSDestBearingRef", &tags::GPSDestBearingRef),
+        ("TherapeuticRoleCategoryCodeSequence", &tags::TherapeuticRoleCategoryCodeSequence),
+        ("WedgeDescription", &tags::WedgeDescription),
+        ("ROIObservationDescription", &tags::ROIObservationDescription),
+        ("BulkMotionSignalSource", &tags::BulkMotionSignalSource),
+        ("TreatmentTerminationStatus", &tags::TreatmentTerminationStatus),
+        ("ImageBoxSmallScrollAmount", &tags::ImageBoxSmallScrollAmount),
+        ("RangeModulatorGatingStartWaterEquivalentThickness", &tags::RangeModulatorGatingStartWaterEquivalentThickness),
+        ("OmittedApplicationSetupSequence", &tags::OmittedApplicationSetupSequence),
+        ("InstitutionName", &tags::InstitutionName),
+        ("FramePixelDataPropertiesSequence", &tags::FramePixelDataPropertiesSequence),
+        ("PresentationLUTShape", &tags::PresentationLUTShape),
+        ("FilterByAttributePresence", &tags::FilterbyAttributePresence),
+        ("TableTopRollAdjustedAngle", &tags::TableTopRollAdjustedAngle),
+        ("PerformedProcedureStepEndDate", &tags::PerformedProcedureStepEndDate),
+        ("GPSStatus", &tags::GPSStatus),
+        ("HL7DocumentTypeCodeSequence", &tags::HL7DocumentTypeCodeSequence),
+        ("ScheduledHumanPerformersSequence", &tags::ScheduledHumanPerformersSequence),
+        ("KeratometricAxis", &tags::KeratometricAxis),
+        ("RTAccessoryHolderWaterEquivalentThickness", &tags::RTAccessoryHolderWaterEquivalentThickness),
+        ("DepthValueAveragingFlag", &tags::DepthValueAveragingFlag),
+        ("ContainerComponentTypeCodeSequence", &tags::ContainerComponentTypeCodeSequence),
+        ("TotalWedgeTrayWaterEquivalentThickness", &tags::TotalWedgeTrayWaterEquivalentThickness),
+        ("OphthalmicImageTypeCodeSequence", &tags::OphthalmicImageTypeCodeSequence),
+        ("GlobalCrop", &tags::GlobalCrop),
+        ("ProcedureIdentifierCodeSequenceTrial", &tags::ProcedureIdentifierCodeSequence_Trial),
+        ("FilterMaterialUsedInGainCalibration", &tags::FilterMaterialUsedinGainCalibration),
+        ("CornealTopographyMapQualityEvaluation", &tags::CornealTopographyMapQualityEvaluation),
+        ("ScanArcType", &tags::ScanArcType),
+        ("RadiationDoseIdentificationIndex", &tags::RadiationDoseIdentificationIndex),
+        ("ImageComments", &tags::ImageComments),
+        ("PhysicalUnitsXDirection", &tags::PhysicalUnitsXDirection),
+        ("ReasonForRemovalCodeSequence", &tags::ReasonforRemovalCodeSequence),
+        ("RotationInformationSequence", &tags::RotationInformationSequence),
+        ("LastMenstrualDate", &tags::LastMenstrualDate),
+        ("ApplicableSafetyStandardAgency", &tags::ApplicableSafetyStandardAgency),
+        ("ContinuationEndMeterset", &tags::ContinuationEndMeterset),
+        ("ConceptualVolumeCategoryCodeSequence", &tags::ConceptualVolumeCategoryCodeSequence),
+        ("ConsultingPhysicianIdentificationSequence", &tags::ConsultingPhysicianIdentificationSequence),
+        ("CornealAxis", &tags::CornealAxis),
+        ("Italic", &tags::Italic),
+        ("CoverageOfKSpace", &tags::CoverageofkSpace),
+        ("PatientPhysiologicalStateCodeSequence", &tags::PatientPhysiologicalStateCodeSequence),
+        ("DetectorManufacturerName", &tags::DetectorManufacturerName),
+        ("MaterialGrade", &tags::MaterialGrade),
+        ("CoordinateSystemNumberOfAxes", &tags::CoordinateSystemNumberofAxes),
+        ("RadiationBeamEffectiveWedgeAngle", &tags::RadiationBeamEffectiveWedgeAngle),
+        ("ConversionSourceAttributesSequence", &tags::ConversionSourceAttributesSequence),
+        ("StudyPriorityID", &tags::StudyPriorityID),
+        ("ClinicalTrialTimePointID", &tags::ClinicalTrialTimePointID),
+        ("BscanSlabThickness", &tags::BscanSlabThickness),
+        ("NumberOfFilms", &tags::NumberofFilms),
+        ("InventoryCompletionStatus", &tags::InventoryCompletionStatus),
+        ("XRay3DAcquisitionSequence", &tags::XRay3DAcquisitionSequence),
+        ("ReconstructionFieldOfView", &tags::ReconstructionFieldofView),
+        ("AdaptiveMapFormat", &tags::AdaptiveMapFormat),
+        ("ConfigurationID", &tags::ConfigurationID),
+        ("DistanceObjectToTableTop", &tags::DistanceObjecttoTableTop),
+        ("InnerDiameter", &tags::InnerDiameter),
+        ("DeliveredPulseRepetitionInterval", &tags::DeliveredPulseRepetitionInterval),
+        ("ChairHeadFramePositionTolerance", &tags::ChairHeadFramePositionTolerance),
+        ("WedgeCurve", &tags::WedgeCurve),
+        ("TreatmentDeviceIdentificationSequence", &tags::TreatmentDeviceIdentificationSequence),
+    ],
+};
+
 pub static TS_BY_IDENT: phf::Map<&'static str, TSRef> = ::phf::Map {
     key: 15467950696543387533,
     disps: &[
@@ -1,7 +1,12 @@
+// The code tables are laid out one code per line, which `fmt` would spread over several lines.
+#[rustfmt::skip]
+pub mod code_sets;
+
 #[rustfmt::skip]
 pub mod lookup;
 
-// This module is not auto-generated so allow `fmt` to process it.
+// These modules are not auto-generated so allow `fmt` to process them.
+pub mod registry;
 pub mod stdlookup;
 
 #[rustfmt::skip]
@@ -0,0 +1,57 @@
+//! Queries of the standard dictionary's SOP Classes and transfer syntaxes by what they are for,
+//! e.g. to accept every storage SOP Class or to find which transfer syntaxes are lossy.
+
+use crate::{
+    core::defn::{
+        ts::{Compression, TSRef},
+        uid::{SOPClassCategory, UIDRef},
+    },
+    dict::lookup::{TS_BY_UID, UID_BY_UID},
+};
+
+/// Gets the SOP Classes of the standard dictionary, ordered by UID.
+pub fn sop_classes() -> Vec<UIDRef> {
+    let mut sop_classes: Vec<UIDRef> = UID_BY_UID
+        .values()
+        .copied()
+        .filter(|uid| uid.sop_class_category().is_some())
+        .collect();
+    sop_classes.sort_by_key(|uid| uid.uid());
+    sop_classes
+}
+
+/// Gets the SOP Classes of the given category, ordered by UID.
+pub fn sop_classes_in(category: SOPClassCategory) -> Vec<UIDRef> {
+    sop_classes()
+        .into_iter()
+        .filter(|uid| uid.sop_class_category() == Some(category))
+        .collect()
+}
+
+/// Gets the SOP Classes for storing composite objects with C-STORE, ordered by UID. Retired SOP
+/// Classes are included, as datasets of them are still found in archives.
+pub fn storage_sop_classes() -> Vec<UIDRef> {
+    sop_classes()
+        .into_iter()
+        .filter(|uid| uid.is_storage_sop_class())
+        .collect()
+}
+
+/// Gets the category of the SOP Class with the given UID, e.g. a dataset's SOP Class UID. Returns
+/// `None` if the UID isn't a SOP Class of the standard dictionary.
+pub fn sop_class_category(uid: &str) -> Option<SOPClassCategory> {
+    UID_BY_UID
+        .get(uid.trim_end_matches(['\0', ' ']))
+        .and_then(|uid| uid.sop_class_category())
+}
+
+/// Gets the transfer syntaxes which compress Pixel Data in the given way, ordered by UID.
+pub fn transfer_syntaxes_with(compression: Compression) -> Vec<TSRef> {
+    let mut transfer_syntaxes: Vec<TSRef> = TS_BY_UID
+        .values()
+        .copied()
+        .filter(|ts| ts.compression() == compression)
+        .collect();
+    transfer_syntaxes.sort_by_key(|ts| ts.uid().uid());
+    transfer_syntaxes
+}
@@ -1,6 +1,9 @@
 use crate::{
     core::defn::{dcmdict::DicomDictionary, tag::TagRef, ts::TSRef, uid::UIDRef},
-    dict::lookup::{TAG_BY_IDENT, TAG_BY_VALUE, TS_BY_IDENT, TS_BY_UID, UID_BY_IDENT, UID_BY_UID},
+    dict::lookup::{
+        TAG_BY_IDENT, TAG_BY_KEYWORD, TAG_BY_VALUE, TS_BY_IDENT, TS_BY_UID, UID_BY_IDENT,
+        UID_BY_UID,
+    },
 };
 
 /// The Standard DICOM Dictionary
@@ -26,6 +29,10 @@ impl DicomDictionary for StandardDicomDictionary {
         TAG_BY_IDENT.get(name).copied()
     }
 
+    fn get_tag_by_keyword(&self, keyword: &str) -> Option<TagRef> {
+        TAG_BY_KEYWORD.get(keyword).copied()
+    }
+
     fn get_uid_by_uid(&self, uid: &str) -> Option<UIDRef> {
         UID_BY_UID.get(uid).copied()
     }
@@ -2,7 +2,7 @@
 
 #![allow(non_upper_case_globals)]
 
-use crate::core::defn::ts::TransferSyntax;
+use crate::core::defn::ts::{Compression, TransferSyntax};
 use crate::dict::uids;
 
 /// Implicit VR Little Endian: Default Transfer Syntax for DICOM
@@ -14,6 +14,7 @@ pub static ImplicitVRLittleEndian: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: false,
+    compression: Compression::None,
 };
 
 /// Explicit VR Little Endian
@@ -25,6 +26,7 @@ pub static ExplicitVRLittleEndian: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: false,
+    compression: Compression::None,
 };
 
 /// Encapsulated Uncompressed Explicit VR Little Endian
@@ -36,6 +38,7 @@ pub static EncapsulatedUncompressedExplicitVRLittleEndian: TransferSyntax = Tran
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::None,
 };
 
 /// Deflated Explicit VR Little Endian
@@ -47,6 +50,7 @@ pub static DeflatedExplicitVRLittleEndian: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: true,
     encapsulated: false,
+    compression: Compression::None,
 };
 
 /// Explicit VR Big Endian (Retired)
@@ -58,6 +62,7 @@ pub static ExplicitVRBigEndian: TransferSyntax = TransferSyntax {
     big_endian: true,
     deflated: false,
     encapsulated: false,
+    compression: Compression::None,
 };
 
 /// JPEG Baseline (Process 1): Default Transfer Syntax for Lossy JPEG 8 Bit Image Compression
@@ -69,6 +74,7 @@ pub static JPEGBaselineProcess1: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// JPEG Extended (Process 2 & 4): Default Transfer Syntax for Lossy JPEG 12 Bit Image Compression (Process 4 only)
@@ -80,6 +86,7 @@ pub static JPEGExtendedProcess2_and_4: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// JPEG Extended (Process 3 & 5) (Retired)
@@ -91,6 +98,7 @@ pub static JPEGExtendedProcess3_and_5: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// JPEG Spectral Selection, Non-Hierarchical (Process 6 & 8) (Retired)
@@ -102,6 +110,7 @@ pub static JPEGSpectralSelectionNonHierarchicalProcess6_and_8: TransferSyntax =
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// JPEG Spectral Selection, Non-Hierarchical (Process 7 & 9) (Retired)
@@ -113,6 +122,7 @@ pub static JPEGSpectralSelectionNonHierarchicalProcess7_and_9: TransferSyntax =
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// JPEG Full Progression, Non-Hierarchical (Process 10 & 12) (Retired)
@@ -124,6 +134,7 @@ pub static JPEGFullProgressionNonHierarchicalProcess10_and_12: TransferSyntax =
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// JPEG Full Progression, Non-Hierarchical (Process 11 & 13) (Retired)
@@ -135,6 +146,7 @@ pub static JPEGFullProgressionNonHierarchicalProcess11_and_13: TransferSyntax =
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// JPEG Lossless, Non-Hierarchical (Process 14)
@@ -146,6 +158,7 @@ pub static JPEGLosslessNonHierarchicalProcess14: TransferSyntax = TransferSyntax
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossless,
 };
 
 /// JPEG Lossless, Non-Hierarchical (Process 15) (Retired)
@@ -157,6 +170,7 @@ pub static JPEGLosslessNonHierarchicalProcess15: TransferSyntax = TransferSyntax
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossless,
 };
 
 /// JPEG Extended, Hierarchical (Process 16 & 18) (Retired)
@@ -168,6 +182,7 @@ pub static JPEGExtendedHierarchicalProcess16_and_18: TransferSyntax = TransferSy
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// JPEG Extended, Hierarchical (Process 17 & 19) (Retired)
@@ -179,6 +194,7 @@ pub static JPEGExtendedHierarchicalProcess17_and_19: TransferSyntax = TransferSy
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// JPEG Spectral Selection, Hierarchical (Process 20 & 22) (Retired)
@@ -190,6 +206,7 @@ pub static JPEGSpectralSelectionHierarchicalProcess20_and_22: TransferSyntax = T
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// JPEG Spectral Selection, Hierarchical (Process 21 & 23) (Retired)
@@ -201,6 +218,7 @@ pub static JPEGSpectralSelectionHierarchicalProcess21_and_23: TransferSyntax = T
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// JPEG Full Progression, Hierarchical (Process 24 & 26) (Retired)
@@ -212,6 +230,7 @@ pub static JPEGFullProgressionHierarchicalProcess24_and_26: TransferSyntax = Tra
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// JPEG Full Progression, Hierarchical (Process 25 & 27) (Retired)
@@ -223,6 +242,7 @@ pub static JPEGFullProgressionHierarchicalProcess25_and_27: TransferSyntax = Tra
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// JPEG Lossless, Hierarchical (Process 28) (Retired)
@@ -234,6 +254,7 @@ pub static JPEGLosslessHierarchicalProcess28: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossless,
 };
 
 /// JPEG Lossless, Hierarchical (Process 29) (Retired)
@@ -245,6 +266,7 @@ pub static JPEGLosslessHierarchicalProcess29: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossless,
 };
 
 /// JPEG Lossless, Non-Hierarchical, First-Order Prediction (Process 14 [Selection Value 1]): Default Transfer Syntax for Lossless JPEG Image Compression
@@ -256,6 +278,7 @@ pub static JPEGLosslessNonHierarchicalFirstOrderPredictionProcess14SelectionValu
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossless,
 };
 
 /// JPEG-LS Lossless Image Compression
@@ -267,6 +290,7 @@ pub static JPEGLSLosslessImageCompression: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossless,
 };
 
 /// JPEG-LS Lossy (Near-Lossless) Image Compression
@@ -278,6 +302,7 @@ pub static JPEGLSLossyNearLosslessImageCompression: TransferSyntax = TransferSyn
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// JPEG 2000 Image Compression (Lossless Only)
@@ -289,6 +314,7 @@ pub static JPEG2000ImageCompressionLosslessOnly: TransferSyntax = TransferSyntax
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossless,
 };
 
 /// JPEG 2000 Image Compression
@@ -300,6 +326,7 @@ pub static JPEG2000ImageCompression: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// JPEG 2000 Part 2 Multi-component Image Compression (Lossless Only)
@@ -311,6 +338,7 @@ pub static JPEG2000Part2MulticomponentImageCompressionLosslessOnly: TransferSynt
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossless,
 };
 
 /// JPEG 2000 Part 2 Multi-component Image Compression
@@ -322,6 +350,7 @@ pub static JPEG2000Part2MulticomponentImageCompression: TransferSyntax = Transfe
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// JPIP Referenced
@@ -333,6 +362,7 @@ pub static JPIPReferenced: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: false,
+    compression: Compression::Lossy,
 };
 
 /// JPIP Referenced Deflate
@@ -344,6 +374,7 @@ pub static JPIPReferencedDeflate: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: true,
     encapsulated: false,
+    compression: Compression::Lossy,
 };
 
 /// MPEG2 Main Profile / Main Level
@@ -355,6 +386,7 @@ pub static MPEG2MainProfileMainLevel: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// Fragmentable MPEG2 Main Profile / Main Level
@@ -366,6 +398,7 @@ pub static FragmentableMPEG2MainProfileMainLevel: TransferSyntax = TransferSynta
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// MPEG2 Main Profile / High Level
@@ -377,6 +410,7 @@ pub static MPEG2MainProfileHighLevel: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// Fragmentable MPEG2 Main Profile / High Level
@@ -388,6 +422,7 @@ pub static FragmentableMPEG2MainProfileHighLevel: TransferSyntax = TransferSynta
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// MPEG-4 AVC/H.264 High Profile / Level 4.1
@@ -399,6 +434,7 @@ pub static MPEG4AVCH264HighProfileLevel41: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// Fragmentable MPEG-4 AVC/H.264 High Profile / Level 4.1
@@ -410,6 +446,7 @@ pub static FragmentableMPEG4AVCH264HighProfileLevel41: TransferSyntax = Transfer
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// MPEG-4 AVC/H.264 BD-compatible High Profile / Level 4.1
@@ -421,6 +458,7 @@ pub static MPEG4AVCH264BDcompatibleHighProfileLevel41: TransferSyntax = Transfer
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// Fragmentable MPEG-4 AVC/H.264 BD-compatible High Profile / Level 4.1
@@ -432,6 +470,7 @@ pub static FragmentableMPEG4AVCH264BDcompatibleHighProfileLevel41: TransferSynta
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// MPEG-4 AVC/H.264 High Profile / Level 4.2 For 2D Video
@@ -443,6 +482,7 @@ pub static MPEG4AVCH264HighProfileLevel42For2DVideo: TransferSyntax = TransferSy
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// Fragmentable MPEG-4 AVC/H.264 High Profile / Level 4.2 For 2D Video
@@ -454,6 +494,7 @@ pub static FragmentableMPEG4AVCH264HighProfileLevel42For2DVideo: TransferSyntax
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// MPEG-4 AVC/H.264 High Profile / Level 4.2 For 3D Video
@@ -465,6 +506,7 @@ pub static MPEG4AVCH264HighProfileLevel42For3DVideo: TransferSyntax = TransferSy
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// Fragmentable MPEG-4 AVC/H.264 High Profile / Level 4.2 For 3D Video
@@ -476,6 +518,7 @@ pub static FragmentableMPEG4AVCH264HighProfileLevel42For3DVideo: TransferSyntax
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// MPEG-4 AVC/H.264 Stereo High Profile / Level 4.2
@@ -487,6 +530,7 @@ pub static MPEG4AVCH264StereoHighProfileLevel42: TransferSyntax = TransferSyntax
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// Fragmentable MPEG-4 AVC/H.264 Stereo High Profile / Level 4.2
@@ -498,6 +542,7 @@ pub static FragmentableMPEG4AVCH264StereoHighProfileLevel42: TransferSyntax = Tr
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// HEVC/H.265 Main Profile / Level 5.1
@@ -509,6 +554,7 @@ pub static HEVCH265MainProfileLevel51: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// HEVC/H.265 Main 10 Profile / Level 5.1
@@ -520,6 +566,7 @@ pub static HEVCH265Main10ProfileLevel51: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// High-Throughput JPEG 2000 Image Compression (Lossless Only)
@@ -531,6 +578,7 @@ pub static HighThroughputJPEG2000ImageCompressionLosslessOnly: TransferSyntax =
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossless,
 };
 
 /// High-Throughput JPEG 2000 with RPCL Options Image Compression (Lossless Only)
@@ -542,6 +590,7 @@ pub static HighThroughputJPEG2000withRPCLOptionsImageCompressionLosslessOnly: Tr
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossless,
 };
 
 /// High-Throughput JPEG 2000 Image Compression
@@ -553,6 +602,7 @@ pub static HighThroughputJPEG2000ImageCompression: TransferSyntax = TransferSynt
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossy,
 };
 
 /// JPIP HTJ2K Referenced
@@ -564,6 +614,7 @@ pub static JPIPHTJ2KReferenced: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: false,
+    compression: Compression::Lossy,
 };
 
 /// JPIP HTJ2K Referenced Deflate
@@ -575,6 +626,7 @@ pub static JPIPHTJ2KReferencedDeflate: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: true,
     encapsulated: false,
+    compression: Compression::Lossy,
 };
 
 /// RLE Lossless
@@ -586,6 +638,7 @@ pub static RLELossless: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: true,
+    compression: Compression::Lossless,
 };
 
 /// RFC 2557 MIME encapsulation (Retired)
@@ -597,6 +650,7 @@ pub static RFC2557MIMEencapsulation: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: false,
+    compression: Compression::None,
 };
 
 /// XML Encoding (Retired)
@@ -608,6 +662,7 @@ pub static XMLEncoding: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: false,
+    compression: Compression::None,
 };
 
 /// SMPTE ST 2110-20 Uncompressed Progressive Active Video
@@ -619,6 +674,7 @@ pub static SMPTEST211020UncompressedProgressiveActiveVideo: TransferSyntax = Tra
     big_endian: false,
     deflated: false,
     encapsulated: false,
+    compression: Compression::None,
 };
 
 /// SMPTE ST 2110-20 Uncompressed Interlaced Active Video
@@ -630,6 +686,7 @@ pub static SMPTEST211020UncompressedInterlacedActiveVideo: TransferSyntax = Tran
     big_endian: false,
     deflated: false,
     encapsulated: false,
+    compression: Compression::None,
 };
 
 /// SMPTE ST 2110-30 PCM Digital Audio
@@ -641,6 +698,7 @@ pub static SMPTEST211030PCMDigitalAudio: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: false,
+    compression: Compression::None,
 };
 
 /// Papyrus 3 Implicit VR Little Endian (Retired)
@@ -652,4 +710,5 @@ pub static Papyrus3ImplicitVRLittleEndian: TransferSyntax = TransferSyntax {
     big_endian: false,
     deflated: false,
     encapsulated: false,
+    compression: Compression::None,
 };
@@ -2,7 +2,7 @@
 
 #![allow(non_upper_case_globals)]
 
-use crate::core::defn::uid::UID;
+use crate::core::defn::uid::{SOPClassCategory, UIDType, UID};
 
 /// Verification SOP Class
 ///
@@ -12,6 +12,7 @@ pub static VerificationSOPClass: UID = UID {
     ident: "VerificationSOPClass",
     uid: "1.2.840.10008.1.1",
     name: "Verification SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Implicit VR Little Endian: Default Transfer Syntax for DICOM
@@ -22,6 +23,7 @@ pub static ImplicitVRLittleEndian: UID = UID {
     ident: "ImplicitVRLittleEndian",
     uid: "1.2.840.10008.1.2",
     name: "Implicit VR Little Endian: Default Transfer Syntax for DICOM",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// Explicit VR Little Endian
@@ -32,6 +34,7 @@ pub static ExplicitVRLittleEndian: UID = UID {
     ident: "ExplicitVRLittleEndian",
     uid: "1.2.840.10008.1.2.1",
     name: "Explicit VR Little Endian",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// Encapsulated Uncompressed Explicit VR Little Endian
@@ -42,6 +45,7 @@ pub static EncapsulatedUncompressedExplicitVRLittleEndian: UID = UID {
     ident: "EncapsulatedUncompressedExplicitVRLittleEndian",
     uid: "1.2.840.10008.1.2.1.98",
     name: "Encapsulated Uncompressed Explicit VR Little Endian",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// Deflated Explicit VR Little Endian
@@ -52,6 +56,7 @@ pub static DeflatedExplicitVRLittleEndian: UID = UID {
     ident: "DeflatedExplicitVRLittleEndian",
     uid: "1.2.840.10008.1.2.1.99",
     name: "Deflated Explicit VR Little Endian",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// Explicit VR Big Endian (Retired)
@@ -62,6 +67,7 @@ pub static ExplicitVRBigEndian: UID = UID {
     ident: "ExplicitVRBigEndian",
     uid: "1.2.840.10008.1.2.2",
     name: "Explicit VR Big Endian (Retired)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG Baseline (Process 1): Default Transfer Syntax for Lossy JPEG 8 Bit Image Compression
@@ -72,6 +78,7 @@ pub static JPEGBaselineProcess1: UID = UID {
     ident: "JPEGBaselineProcess1",
     uid: "1.2.840.10008.1.2.4.50",
     name: "JPEG Baseline (Process 1): Default Transfer Syntax for Lossy JPEG 8 Bit Image Compression",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG Extended (Process 2 & 4): Default Transfer Syntax for Lossy JPEG 12 Bit Image Compression (Process 4 only)
@@ -82,6 +89,7 @@ pub static JPEGExtendedProcess2_and_4: UID = UID {
     ident: "JPEGExtendedProcess2_and_4",
     uid: "1.2.840.10008.1.2.4.51",
     name: "JPEG Extended (Process 2 & 4): Default Transfer Syntax for Lossy JPEG 12 Bit Image Compression (Process 4 only)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG Extended (Process 3 & 5) (Retired)
@@ -92,6 +100,7 @@ pub static JPEGExtendedProcess3_and_5: UID = UID {
     ident: "JPEGExtendedProcess3_and_5",
     uid: "1.2.840.10008.1.2.4.52",
     name: "JPEG Extended (Process 3 & 5) (Retired)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG Spectral Selection, Non-Hierarchical (Process 6 & 8) (Retired)
@@ -102,6 +111,7 @@ pub static JPEGSpectralSelectionNonHierarchicalProcess6_and_8: UID = UID {
     ident: "JPEGSpectralSelectionNonHierarchicalProcess6_and_8",
     uid: "1.2.840.10008.1.2.4.53",
     name: "JPEG Spectral Selection, Non-Hierarchical (Process 6 & 8) (Retired)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG Spectral Selection, Non-Hierarchical (Process 7 & 9) (Retired)
@@ -112,6 +122,7 @@ pub static JPEGSpectralSelectionNonHierarchicalProcess7_and_9: UID = UID {
     ident: "JPEGSpectralSelectionNonHierarchicalProcess7_and_9",
     uid: "1.2.840.10008.1.2.4.54",
     name: "JPEG Spectral Selection, Non-Hierarchical (Process 7 & 9) (Retired)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG Full Progression, Non-Hierarchical (Process 10 & 12) (Retired)
@@ -122,6 +133,7 @@ pub static JPEGFullProgressionNonHierarchicalProcess10_and_12: UID = UID {
     ident: "JPEGFullProgressionNonHierarchicalProcess10_and_12",
     uid: "1.2.840.10008.1.2.4.55",
     name: "JPEG Full Progression, Non-Hierarchical (Process 10 & 12) (Retired)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG Full Progression, Non-Hierarchical (Process 11 & 13) (Retired)
@@ -132,6 +144,7 @@ pub static JPEGFullProgressionNonHierarchicalProcess11_and_13: UID = UID {
     ident: "JPEGFullProgressionNonHierarchicalProcess11_and_13",
     uid: "1.2.840.10008.1.2.4.56",
     name: "JPEG Full Progression, Non-Hierarchical (Process 11 & 13) (Retired)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG Lossless, Non-Hierarchical (Process 14)
@@ -142,6 +155,7 @@ pub static JPEGLosslessNonHierarchicalProcess14: UID = UID {
     ident: "JPEGLosslessNonHierarchicalProcess14",
     uid: "1.2.840.10008.1.2.4.57",
     name: "JPEG Lossless, Non-Hierarchical (Process 14)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG Lossless, Non-Hierarchical (Process 15) (Retired)
@@ -152,6 +166,7 @@ pub static JPEGLosslessNonHierarchicalProcess15: UID = UID {
     ident: "JPEGLosslessNonHierarchicalProcess15",
     uid: "1.2.840.10008.1.2.4.58",
     name: "JPEG Lossless, Non-Hierarchical (Process 15) (Retired)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG Extended, Hierarchical (Process 16 & 18) (Retired)
@@ -162,6 +177,7 @@ pub static JPEGExtendedHierarchicalProcess16_and_18: UID = UID {
     ident: "JPEGExtendedHierarchicalProcess16_and_18",
     uid: "1.2.840.10008.1.2.4.59",
     name: "JPEG Extended, Hierarchical (Process 16 & 18) (Retired)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG Extended, Hierarchical (Process 17 & 19) (Retired)
@@ -172,6 +188,7 @@ pub static JPEGExtendedHierarchicalProcess17_and_19: UID = UID {
     ident: "JPEGExtendedHierarchicalProcess17_and_19",
     uid: "1.2.840.10008.1.2.4.60",
     name: "JPEG Extended, Hierarchical (Process 17 & 19) (Retired)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG Spectral Selection, Hierarchical (Process 20 & 22) (Retired)
@@ -182,6 +199,7 @@ pub static JPEGSpectralSelectionHierarchicalProcess20_and_22: UID = UID {
     ident: "JPEGSpectralSelectionHierarchicalProcess20_and_22",
     uid: "1.2.840.10008.1.2.4.61",
     name: "JPEG Spectral Selection, Hierarchical (Process 20 & 22) (Retired)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG Spectral Selection, Hierarchical (Process 21 & 23) (Retired)
@@ -192,6 +210,7 @@ pub static JPEGSpectralSelectionHierarchicalProcess21_and_23: UID = UID {
     ident: "JPEGSpectralSelectionHierarchicalProcess21_and_23",
     uid: "1.2.840.10008.1.2.4.62",
     name: "JPEG Spectral Selection, Hierarchical (Process 21 & 23) (Retired)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG Full Progression, Hierarchical (Process 24 & 26) (Retired)
@@ -202,6 +221,7 @@ pub static JPEGFullProgressionHierarchicalProcess24_and_26: UID = UID {
     ident: "JPEGFullProgressionHierarchicalProcess24_and_26",
     uid: "1.2.840.10008.1.2.4.63",
     name: "JPEG Full Progression, Hierarchical (Process 24 & 26) (Retired)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG Full Progression, Hierarchical (Process 25 & 27) (Retired)
@@ -212,6 +232,7 @@ pub static JPEGFullProgressionHierarchicalProcess25_and_27: UID = UID {
     ident: "JPEGFullProgressionHierarchicalProcess25_and_27",
     uid: "1.2.840.10008.1.2.4.64",
     name: "JPEG Full Progression, Hierarchical (Process 25 & 27) (Retired)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG Lossless, Hierarchical (Process 28) (Retired)
@@ -222,6 +243,7 @@ pub static JPEGLosslessHierarchicalProcess28: UID = UID {
     ident: "JPEGLosslessHierarchicalProcess28",
     uid: "1.2.840.10008.1.2.4.65",
     name: "JPEG Lossless, Hierarchical (Process 28) (Retired)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG Lossless, Hierarchical (Process 29) (Retired)
@@ -232,6 +254,7 @@ pub static JPEGLosslessHierarchicalProcess29: UID = UID {
     ident: "JPEGLosslessHierarchicalProcess29",
     uid: "1.2.840.10008.1.2.4.66",
     name: "JPEG Lossless, Hierarchical (Process 29) (Retired)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG Lossless, Non-Hierarchical, First-Order Prediction (Process 14 [Selection Value 1]): Default Transfer Syntax for Lossless JPEG Image Compression
@@ -242,6 +265,7 @@ pub static JPEGLosslessNonHierarchicalFirstOrderPredictionProcess14SelectionValu
     ident: "JPEGLosslessNonHierarchicalFirstOrderPredictionProcess14SelectionValue1",
     uid: "1.2.840.10008.1.2.4.70",
     name: "JPEG Lossless, Non-Hierarchical, First-Order Prediction (Process 14 [Selection Value 1]): Default Transfer Syntax for Lossless JPEG Image Compression",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG-LS Lossless Image Compression
@@ -252,6 +276,7 @@ pub static JPEGLSLosslessImageCompression: UID = UID {
     ident: "JPEGLSLosslessImageCompression",
     uid: "1.2.840.10008.1.2.4.80",
     name: "JPEG-LS Lossless Image Compression",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG-LS Lossy (Near-Lossless) Image Compression
@@ -262,6 +287,7 @@ pub static JPEGLSLossyNearLosslessImageCompression: UID = UID {
     ident: "JPEGLSLossyNearLosslessImageCompression",
     uid: "1.2.840.10008.1.2.4.81",
     name: "JPEG-LS Lossy (Near-Lossless) Image Compression",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG 2000 Image Compression (Lossless Only)
@@ -272,6 +298,7 @@ pub static JPEG2000ImageCompressionLosslessOnly: UID = UID {
     ident: "JPEG2000ImageCompressionLosslessOnly",
     uid: "1.2.840.10008.1.2.4.90",
     name: "JPEG 2000 Image Compression (Lossless Only)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG 2000 Image Compression
@@ -282,6 +309,7 @@ pub static JPEG2000ImageCompression: UID = UID {
     ident: "JPEG2000ImageCompression",
     uid: "1.2.840.10008.1.2.4.91",
     name: "JPEG 2000 Image Compression",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG 2000 Part 2 Multi-component Image Compression (Lossless Only)
@@ -292,6 +320,7 @@ pub static JPEG2000Part2MulticomponentImageCompressionLosslessOnly: UID = UID {
     ident: "JPEG2000Part2MulticomponentImageCompressionLosslessOnly",
     uid: "1.2.840.10008.1.2.4.92",
     name: "JPEG 2000 Part 2 Multi-component Image Compression (Lossless Only)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPEG 2000 Part 2 Multi-component Image Compression
@@ -302,6 +331,7 @@ pub static JPEG2000Part2MulticomponentImageCompression: UID = UID {
     ident: "JPEG2000Part2MulticomponentImageCompression",
     uid: "1.2.840.10008.1.2.4.93",
     name: "JPEG 2000 Part 2 Multi-component Image Compression",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPIP Referenced
@@ -312,6 +342,7 @@ pub static JPIPReferenced: UID = UID {
     ident: "JPIPReferenced",
     uid: "1.2.840.10008.1.2.4.94",
     name: "JPIP Referenced",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPIP Referenced Deflate
@@ -322,6 +353,7 @@ pub static JPIPReferencedDeflate: UID = UID {
     ident: "JPIPReferencedDeflate",
     uid: "1.2.840.10008.1.2.4.95",
     name: "JPIP Referenced Deflate",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// MPEG2 Main Profile / Main Level
@@ -332,6 +364,7 @@ pub static MPEG2MainProfileMainLevel: UID = UID {
     ident: "MPEG2MainProfileMainLevel",
     uid: "1.2.840.10008.1.2.4.100",
     name: "MPEG2 Main Profile / Main Level",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// Fragmentable MPEG2 Main Profile / Main Level
@@ -342,6 +375,7 @@ pub static FragmentableMPEG2MainProfileMainLevel: UID = UID {
     ident: "FragmentableMPEG2MainProfileMainLevel",
     uid: "1.2.840.10008.1.2.4.100.1",
     name: "Fragmentable MPEG2 Main Profile / Main Level",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// MPEG2 Main Profile / High Level
@@ -352,6 +386,7 @@ pub static MPEG2MainProfileHighLevel: UID = UID {
     ident: "MPEG2MainProfileHighLevel",
     uid: "1.2.840.10008.1.2.4.101",
     name: "MPEG2 Main Profile / High Level",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// Fragmentable MPEG2 Main Profile / High Level
@@ -362,6 +397,7 @@ pub static FragmentableMPEG2MainProfileHighLevel: UID = UID {
     ident: "FragmentableMPEG2MainProfileHighLevel",
     uid: "1.2.840.10008.1.2.4.101.1",
     name: "Fragmentable MPEG2 Main Profile / High Level",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// MPEG-4 AVC/H.264 High Profile / Level 4.1
@@ -372,6 +408,7 @@ pub static MPEG4AVCH264HighProfileLevel41: UID = UID {
     ident: "MPEG4AVCH264HighProfileLevel41",
     uid: "1.2.840.10008.1.2.4.102",
     name: "MPEG-4 AVC/H.264 High Profile / Level 4.1",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// Fragmentable MPEG-4 AVC/H.264 High Profile / Level 4.1
@@ -382,6 +419,7 @@ pub static FragmentableMPEG4AVCH264HighProfileLevel41: UID = UID {
     ident: "FragmentableMPEG4AVCH264HighProfileLevel41",
     uid: "1.2.840.10008.1.2.4.102.1",
     name: "Fragmentable MPEG-4 AVC/H.264 High Profile / Level 4.1",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// MPEG-4 AVC/H.264 BD-compatible High Profile / Level 4.1
@@ -392,6 +430,7 @@ pub static MPEG4AVCH264BDcompatibleHighProfileLevel41: UID = UID {
     ident: "MPEG4AVCH264BDcompatibleHighProfileLevel41",
     uid: "1.2.840.10008.1.2.4.103",
     name: "MPEG-4 AVC/H.264 BD-compatible High Profile / Level 4.1",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// Fragmentable MPEG-4 AVC/H.264 BD-compatible High Profile / Level 4.1
@@ -402,6 +441,7 @@ pub static FragmentableMPEG4AVCH264BDcompatibleHighProfileLevel41: UID = UID {
     ident: "FragmentableMPEG4AVCH264BDcompatibleHighProfileLevel41",
     uid: "1.2.840.10008.1.2.4.103.1",
     name: "Fragmentable MPEG-4 AVC/H.264 BD-compatible High Profile / Level 4.1",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// MPEG-4 AVC/H.264 High Profile / Level 4.2 For 2D Video
@@ -412,6 +452,7 @@ pub static MPEG4AVCH264HighProfileLevel42For2DVideo: UID = UID {
     ident: "MPEG4AVCH264HighProfileLevel42For2DVideo",
     uid: "1.2.840.10008.1.2.4.104",
     name: "MPEG-4 AVC/H.264 High Profile / Level 4.2 For 2D Video",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// Fragmentable MPEG-4 AVC/H.264 High Profile / Level 4.2 For 2D Video
@@ -422,6 +463,7 @@ pub static FragmentableMPEG4AVCH264HighProfileLevel42For2DVideo: UID = UID {
     ident: "FragmentableMPEG4AVCH264HighProfileLevel42For2DVideo",
     uid: "1.2.840.10008.1.2.4.104.1",
     name: "Fragmentable MPEG-4 AVC/H.264 High Profile / Level 4.2 For 2D Video",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// MPEG-4 AVC/H.264 High Profile / Level 4.2 For 3D Video
@@ -432,6 +474,7 @@ pub static MPEG4AVCH264HighProfileLevel42For3DVideo: UID = UID {
     ident: "MPEG4AVCH264HighProfileLevel42For3DVideo",
     uid: "1.2.840.10008.1.2.4.105",
     name: "MPEG-4 AVC/H.264 High Profile / Level 4.2 For 3D Video",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// Fragmentable MPEG-4 AVC/H.264 High Profile / Level 4.2 For 3D Video
@@ -442,6 +485,7 @@ pub static FragmentableMPEG4AVCH264HighProfileLevel42For3DVideo: UID = UID {
     ident: "FragmentableMPEG4AVCH264HighProfileLevel42For3DVideo",
     uid: "1.2.840.10008.1.2.4.105.1",
     name: "Fragmentable MPEG-4 AVC/H.264 High Profile / Level 4.2 For 3D Video",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// MPEG-4 AVC/H.264 Stereo High Profile / Level 4.2
@@ -452,6 +496,7 @@ pub static MPEG4AVCH264StereoHighProfileLevel42: UID = UID {
     ident: "MPEG4AVCH264StereoHighProfileLevel42",
     uid: "1.2.840.10008.1.2.4.106",
     name: "MPEG-4 AVC/H.264 Stereo High Profile / Level 4.2",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// Fragmentable MPEG-4 AVC/H.264 Stereo High Profile / Level 4.2
@@ -462,6 +507,7 @@ pub static FragmentableMPEG4AVCH264StereoHighProfileLevel42: UID = UID {
     ident: "FragmentableMPEG4AVCH264StereoHighProfileLevel42",
     uid: "1.2.840.10008.1.2.4.106.1",
     name: "Fragmentable MPEG-4 AVC/H.264 Stereo High Profile / Level 4.2",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// HEVC/H.265 Main Profile / Level 5.1
@@ -472,6 +518,7 @@ pub static HEVCH265MainProfileLevel51: UID = UID {
     ident: "HEVCH265MainProfileLevel51",
     uid: "1.2.840.10008.1.2.4.107",
     name: "HEVC/H.265 Main Profile / Level 5.1",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// HEVC/H.265 Main 10 Profile / Level 5.1
@@ -482,6 +529,7 @@ pub static HEVCH265Main10ProfileLevel51: UID = UID {
     ident: "HEVCH265Main10ProfileLevel51",
     uid: "1.2.840.10008.1.2.4.108",
     name: "HEVC/H.265 Main 10 Profile / Level 5.1",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// High-Throughput JPEG 2000 Image Compression (Lossless Only)
@@ -492,6 +540,7 @@ pub static HighThroughputJPEG2000ImageCompressionLosslessOnly: UID = UID {
     ident: "HighThroughputJPEG2000ImageCompressionLosslessOnly",
     uid: "1.2.840.10008.1.2.4.201",
     name: "High-Throughput JPEG 2000 Image Compression (Lossless Only)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// High-Throughput JPEG 2000 with RPCL Options Image Compression (Lossless Only)
@@ -502,6 +551,7 @@ pub static HighThroughputJPEG2000withRPCLOptionsImageCompressionLosslessOnly: UI
     ident: "HighThroughputJPEG2000withRPCLOptionsImageCompressionLosslessOnly",
     uid: "1.2.840.10008.1.2.4.202",
     name: "High-Throughput JPEG 2000 with RPCL Options Image Compression (Lossless Only)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// High-Throughput JPEG 2000 Image Compression
@@ -512,6 +562,7 @@ pub static HighThroughputJPEG2000ImageCompression: UID = UID {
     ident: "HighThroughputJPEG2000ImageCompression",
     uid: "1.2.840.10008.1.2.4.203",
     name: "High-Throughput JPEG 2000 Image Compression",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPIP HTJ2K Referenced
@@ -522,6 +573,7 @@ pub static JPIPHTJ2KReferenced: UID = UID {
     ident: "JPIPHTJ2KReferenced",
     uid: "1.2.840.10008.1.2.4.204",
     name: "JPIP HTJ2K Referenced",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// JPIP HTJ2K Referenced Deflate
@@ -532,6 +584,7 @@ pub static JPIPHTJ2KReferencedDeflate: UID = UID {
     ident: "JPIPHTJ2KReferencedDeflate",
     uid: "1.2.840.10008.1.2.4.205",
     name: "JPIP HTJ2K Referenced Deflate",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// RLE Lossless
@@ -542,6 +595,7 @@ pub static RLELossless: UID = UID {
     ident: "RLELossless",
     uid: "1.2.840.10008.1.2.5",
     name: "RLE Lossless",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// RFC 2557 MIME encapsulation (Retired)
@@ -552,6 +606,7 @@ pub static RFC2557MIMEencapsulation: UID = UID {
     ident: "RFC2557MIMEencapsulation",
     uid: "1.2.840.10008.1.2.6.1",
     name: "RFC 2557 MIME encapsulation (Retired)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// XML Encoding (Retired)
@@ -562,6 +617,7 @@ pub static XMLEncoding: UID = UID {
     ident: "XMLEncoding",
     uid: "1.2.840.10008.1.2.6.2",
     name: "XML Encoding (Retired)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// SMPTE ST 2110-20 Uncompressed Progressive Active Video
@@ -572,6 +628,7 @@ pub static SMPTEST211020UncompressedProgressiveActiveVideo: UID = UID {
     ident: "SMPTEST211020UncompressedProgressiveActiveVideo",
     uid: "1.2.840.10008.1.2.7.1",
     name: "SMPTE ST 2110-20 Uncompressed Progressive Active Video",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// SMPTE ST 2110-20 Uncompressed Interlaced Active Video
@@ -582,6 +639,7 @@ pub static SMPTEST211020UncompressedInterlacedActiveVideo: UID = UID {
     ident: "SMPTEST211020UncompressedInterlacedActiveVideo",
     uid: "1.2.840.10008.1.2.7.2",
     name: "SMPTE ST 2110-20 Uncompressed Interlaced Active Video",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// SMPTE ST 2110-30 PCM Digital Audio
@@ -592,6 +650,7 @@ pub static SMPTEST211030PCMDigitalAudio: UID = UID {
     ident: "SMPTEST211030PCMDigitalAudio",
     uid: "1.2.840.10008.1.2.7.3",
     name: "SMPTE ST 2110-30 PCM Digital Audio",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// Media Storage Directory Storage
@@ -602,6 +661,7 @@ pub static MediaStorageDirectoryStorage: UID = UID {
     ident: "MediaStorageDirectoryStorage",
     uid: "1.2.840.10008.1.3.10",
     name: "Media Storage Directory Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Hot Iron Color Palette SOP Instance
@@ -612,6 +672,7 @@ pub static HotIronColorPaletteSOPInstance: UID = UID {
     ident: "HotIronColorPaletteSOPInstance",
     uid: "1.2.840.10008.1.5.1",
     name: "Hot Iron Color Palette SOP Instance",
+    uid_type: UIDType::WellKnownSOPInstance,
 };
 
 /// PET Color Palette SOP Instance
@@ -622,6 +683,7 @@ pub static PETColorPaletteSOPInstance: UID = UID {
     ident: "PETColorPaletteSOPInstance",
     uid: "1.2.840.10008.1.5.2",
     name: "PET Color Palette SOP Instance",
+    uid_type: UIDType::WellKnownSOPInstance,
 };
 
 /// Hot Metal Blue Color Palette SOP Instance
@@ -632,6 +694,7 @@ pub static HotMetalBlueColorPaletteSOPInstance: UID = UID {
     ident: "HotMetalBlueColorPaletteSOPInstance",
     uid: "1.2.840.10008.1.5.3",
     name: "Hot Metal Blue Color Palette SOP Instance",
+    uid_type: UIDType::WellKnownSOPInstance,
 };
 
 /// PET 20 Step Color Palette SOP Instance
@@ -642,6 +705,7 @@ pub static PET20StepColorPaletteSOPInstance: UID = UID {
     ident: "PET20StepColorPaletteSOPInstance",
     uid: "1.2.840.10008.1.5.4",
     name: "PET 20 Step Color Palette SOP Instance",
+    uid_type: UIDType::WellKnownSOPInstance,
 };
 
 /// Spring Color Palette SOP Instance
@@ -652,6 +716,7 @@ pub static SpringColorPaletteSOPInstance: UID = UID {
     ident: "SpringColorPaletteSOPInstance",
     uid: "1.2.840.10008.1.5.5",
     name: "Spring Color Palette SOP Instance",
+    uid_type: UIDType::WellKnownSOPInstance,
 };
 
 /// Summer Color Palette SOP Instance
@@ -662,6 +727,7 @@ pub static SummerColorPaletteSOPInstance: UID = UID {
     ident: "SummerColorPaletteSOPInstance",
     uid: "1.2.840.10008.1.5.6",
     name: "Summer Color Palette SOP Instance",
+    uid_type: UIDType::WellKnownSOPInstance,
 };
 
 /// Fall Color Palette SOP Instance
@@ -672,6 +738,7 @@ pub static FallColorPaletteSOPInstance: UID = UID {
     ident: "FallColorPaletteSOPInstance",
     uid: "1.2.840.10008.1.5.7",
     name: "Fall Color Palette SOP Instance",
+    uid_type: UIDType::WellKnownSOPInstance,
 };
 
 /// Winter Color Palette SOP Instance
@@ -682,6 +749,7 @@ pub static WinterColorPaletteSOPInstance: UID = UID {
     ident: "WinterColorPaletteSOPInstance",
     uid: "1.2.840.10008.1.5.8",
     name: "Winter Color Palette SOP Instance",
+    uid_type: UIDType::WellKnownSOPInstance,
 };
 
 /// Basic Study Content Notification SOP Class (Retired)
@@ -692,6 +760,7 @@ pub static BasicStudyContentNotificationSOPClass: UID = UID {
     ident: "BasicStudyContentNotificationSOPClass",
     uid: "1.2.840.10008.1.9",
     name: "Basic Study Content Notification SOP Class (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Papyrus 3 Implicit VR Little Endian (Retired)
@@ -702,6 +771,7 @@ pub static Papyrus3ImplicitVRLittleEndian: UID = UID {
     ident: "Papyrus3ImplicitVRLittleEndian",
     uid: "1.2.840.10008.1.20",
     name: "Papyrus 3 Implicit VR Little Endian (Retired)",
+    uid_type: UIDType::TransferSyntax,
 };
 
 /// Storage Commitment Push Model SOP Class
@@ -712,6 +782,7 @@ pub static StorageCommitmentPushModelSOPClass: UID = UID {
     ident: "StorageCommitmentPushModelSOPClass",
     uid: "1.2.840.10008.1.20.1",
     name: "Storage Commitment Push Model SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Storage Commitment Push Model SOP Instance
@@ -722,6 +793,7 @@ pub static StorageCommitmentPushModelSOPInstance: UID = UID {
     ident: "StorageCommitmentPushModelSOPInstance",
     uid: "1.2.840.10008.1.20.1.1",
     name: "Storage Commitment Push Model SOP Instance",
+    uid_type: UIDType::WellKnownSOPInstance,
 };
 
 /// Storage Commitment Pull Model SOP Class (Retired)
@@ -732,6 +804,7 @@ pub static StorageCommitmentPullModelSOPClass: UID = UID {
     ident: "StorageCommitmentPullModelSOPClass",
     uid: "1.2.840.10008.1.20.2",
     name: "Storage Commitment Pull Model SOP Class (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Storage Commitment Pull Model SOP Instance (Retired)
@@ -742,6 +815,7 @@ pub static StorageCommitmentPullModelSOPInstance: UID = UID {
     ident: "StorageCommitmentPullModelSOPInstance",
     uid: "1.2.840.10008.1.20.2.1",
     name: "Storage Commitment Pull Model SOP Instance (Retired)",
+    uid_type: UIDType::WellKnownSOPInstance,
 };
 
 /// Procedural Event Logging SOP Class
@@ -752,6 +826,7 @@ pub static ProceduralEventLoggingSOPClass: UID = UID {
     ident: "ProceduralEventLoggingSOPClass",
     uid: "1.2.840.10008.1.40",
     name: "Procedural Event Logging SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Procedural Event Logging SOP Instance
@@ -762,6 +837,7 @@ pub static ProceduralEventLoggingSOPInstance: UID = UID {
     ident: "ProceduralEventLoggingSOPInstance",
     uid: "1.2.840.10008.1.40.1",
     name: "Procedural Event Logging SOP Instance",
+    uid_type: UIDType::WellKnownSOPInstance,
 };
 
 /// Substance Administration Logging SOP Class
@@ -772,6 +848,7 @@ pub static SubstanceAdministrationLoggingSOPClass: UID = UID {
     ident: "SubstanceAdministrationLoggingSOPClass",
     uid: "1.2.840.10008.1.42",
     name: "Substance Administration Logging SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Substance Administration Logging SOP Instance
@@ -782,6 +859,7 @@ pub static SubstanceAdministrationLoggingSOPInstance: UID = UID {
     ident: "SubstanceAdministrationLoggingSOPInstance",
     uid: "1.2.840.10008.1.42.1",
     name: "Substance Administration Logging SOP Instance",
+    uid_type: UIDType::WellKnownSOPInstance,
 };
 
 /// DICOM UID Registry
@@ -792,6 +870,7 @@ pub static DICOMUIDRegistry: UID = UID {
     ident: "DICOMUIDRegistry",
     uid: "1.2.840.10008.2.6.1",
     name: "DICOM UID Registry",
+    uid_type: UIDType::CodingScheme,
 };
 
 /// DICOM Controlled Terminology
@@ -802,6 +881,7 @@ pub static DICOMControlledTerminology: UID = UID {
     ident: "DICOMControlledTerminology",
     uid: "1.2.840.10008.2.16.4",
     name: "DICOM Controlled Terminology",
+    uid_type: UIDType::CodingScheme,
 };
 
 /// Adult Mouse Anatomy Ontology
@@ -812,6 +892,7 @@ pub static AdultMouseAnatomyOntology: UID = UID {
     ident: "AdultMouseAnatomyOntology",
     uid: "1.2.840.10008.2.16.5",
     name: "Adult Mouse Anatomy Ontology",
+    uid_type: UIDType::CodingScheme,
 };
 
 /// Uberon Ontology
@@ -822,6 +903,7 @@ pub static UberonOntology: UID = UID {
     ident: "UberonOntology",
     uid: "1.2.840.10008.2.16.6",
     name: "Uberon Ontology",
+    uid_type: UIDType::CodingScheme,
 };
 
 /// Integrated Taxonomic Information System (ITIS) Taxonomic Serial Number (TSN)
@@ -832,6 +914,7 @@ pub static IntegratedTaxonomicInformationSystemITISTaxonomicSerialNumberTSN: UID
     ident: "IntegratedTaxonomicInformationSystemITISTaxonomicSerialNumberTSN",
     uid: "1.2.840.10008.2.16.7",
     name: "Integrated Taxonomic Information System (ITIS) Taxonomic Serial Number (TSN)",
+    uid_type: UIDType::CodingScheme,
 };
 
 /// Mouse Genome Initiative (MGI)
@@ -842,6 +925,7 @@ pub static MouseGenomeInitiativeMGI: UID = UID {
     ident: "MouseGenomeInitiativeMGI",
     uid: "1.2.840.10008.2.16.8",
     name: "Mouse Genome Initiative (MGI)",
+    uid_type: UIDType::CodingScheme,
 };
 
 /// PubChem Compound CID
@@ -852,6 +936,7 @@ pub static PubChemCompoundCID: UID = UID {
     ident: "PubChemCompoundCID",
     uid: "1.2.840.10008.2.16.9",
     name: "PubChem Compound CID",
+    uid_type: UIDType::CodingScheme,
 };
 
 /// Dublin Core
@@ -862,6 +947,7 @@ pub static DublinCore: UID = UID {
     ident: "DublinCore",
     uid: "1.2.840.10008.2.16.10",
     name: "Dublin Core",
+    uid_type: UIDType::CodingScheme,
 };
 
 /// New York University Melanoma Clinical Cooperative Group
@@ -872,6 +958,7 @@ pub static NewYorkUniversityMelanomaClinicalCooperativeGroup: UID = UID {
     ident: "NewYorkUniversityMelanomaClinicalCooperativeGroup",
     uid: "1.2.840.10008.2.16.11",
     name: "New York University Melanoma Clinical Cooperative Group",
+    uid_type: UIDType::CodingScheme,
 };
 
 /// Mayo Clinic Non-radiological Images Specific Body Structure Anatomical Surface Region Guide
@@ -882,6 +969,7 @@ pub static MayoClinicNonradiologicalImagesSpecificBodyStructureAnatomicalSurface
     ident: "MayoClinicNonradiologicalImagesSpecificBodyStructureAnatomicalSurfaceRegionGuide",
     uid: "1.2.840.10008.2.16.12",
     name: "Mayo Clinic Non-radiological Images Specific Body Structure Anatomical Surface Region Guide",
+    uid_type: UIDType::CodingScheme,
 };
 
 /// Image Biomarker Standardisation Initiative
@@ -892,6 +980,7 @@ pub static ImageBiomarkerStandardisationInitiative: UID = UID {
     ident: "ImageBiomarkerStandardisationInitiative",
     uid: "1.2.840.10008.2.16.13",
     name: "Image Biomarker Standardisation Initiative",
+    uid_type: UIDType::CodingScheme,
 };
 
 /// Radiomics Ontology
@@ -902,6 +991,7 @@ pub static RadiomicsOntology: UID = UID {
     ident: "RadiomicsOntology",
     uid: "1.2.840.10008.2.16.14",
     name: "Radiomics Ontology",
+    uid_type: UIDType::CodingScheme,
 };
 
 /// RadElement
@@ -912,6 +1002,7 @@ pub static RadElement: UID = UID {
     ident: "RadElement",
     uid: "1.2.840.10008.2.16.15",
     name: "RadElement",
+    uid_type: UIDType::CodingScheme,
 };
 
 /// ICD-11
@@ -922,6 +1013,7 @@ pub static ICD11: UID = UID {
     ident: "ICD11",
     uid: "1.2.840.10008.2.16.16",
     name: "ICD-11",
+    uid_type: UIDType::CodingScheme,
 };
 
 /// Unified numbering system (UNS) for metals and alloys
@@ -932,6 +1024,7 @@ pub static UnifiednumberingsystemUNSformetalsandalloys: UID = UID {
     ident: "UnifiednumberingsystemUNSformetalsandalloys",
     uid: "1.2.840.10008.2.16.17",
     name: "Unified numbering system (UNS) for metals and alloys",
+    uid_type: UIDType::CodingScheme,
 };
 
 /// Research Resource Identification
@@ -942,6 +1035,7 @@ pub static ResearchResourceIdentification: UID = UID {
     ident: "ResearchResourceIdentification",
     uid: "1.2.840.10008.2.16.18",
     name: "Research Resource Identification",
+    uid_type: UIDType::CodingScheme,
 };
 
 /// DICOM Application Context Name
@@ -952,6 +1046,7 @@ pub static DICOMApplicationContextName: UID = UID {
     ident: "DICOMApplicationContextName",
     uid: "1.2.840.10008.3.1.1.1",
     name: "DICOM Application Context Name",
+    uid_type: UIDType::ApplicationContextName,
 };
 
 /// Detached Patient Management SOP Class (Retired)
@@ -962,6 +1057,7 @@ pub static DetachedPatientManagementSOPClass: UID = UID {
     ident: "DetachedPatientManagementSOPClass",
     uid: "1.2.840.10008.3.1.2.1.1",
     name: "Detached Patient Management SOP Class (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Detached Patient Management Meta SOP Class (Retired)
@@ -972,6 +1068,7 @@ pub static DetachedPatientManagementMetaSOPClass: UID = UID {
     ident: "DetachedPatientManagementMetaSOPClass",
     uid: "1.2.840.10008.3.1.2.1.4",
     name: "Detached Patient Management Meta SOP Class (Retired)",
+    uid_type: UIDType::MetaSOPClass,
 };
 
 /// Detached Visit Management SOP Class (Retired)
@@ -982,6 +1079,7 @@ pub static DetachedVisitManagementSOPClass: UID = UID {
     ident: "DetachedVisitManagementSOPClass",
     uid: "1.2.840.10008.3.1.2.2.1",
     name: "Detached Visit Management SOP Class (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Detached Study Management SOP Class (Retired)
@@ -992,6 +1090,7 @@ pub static DetachedStudyManagementSOPClass: UID = UID {
     ident: "DetachedStudyManagementSOPClass",
     uid: "1.2.840.10008.3.1.2.3.1",
     name: "Detached Study Management SOP Class (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Study Component Management SOP Class (Retired)
@@ -1002,6 +1101,7 @@ pub static StudyComponentManagementSOPClass: UID = UID {
     ident: "StudyComponentManagementSOPClass",
     uid: "1.2.840.10008.3.1.2.3.2",
     name: "Study Component Management SOP Class (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Modality Performed Procedure Step SOP Class
@@ -1012,6 +1112,7 @@ pub static ModalityPerformedProcedureStepSOPClass: UID = UID {
     ident: "ModalityPerformedProcedureStepSOPClass",
     uid: "1.2.840.10008.3.1.2.3.3",
     name: "Modality Performed Procedure Step SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Modality Performed Procedure Step Retrieve SOP Class
@@ -1022,6 +1123,7 @@ pub static ModalityPerformedProcedureStepRetrieveSOPClass: UID = UID {
     ident: "ModalityPerformedProcedureStepRetrieveSOPClass",
     uid: "1.2.840.10008.3.1.2.3.4",
     name: "Modality Performed Procedure Step Retrieve SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Modality Performed Procedure Step Notification SOP Class
@@ -1032,6 +1134,7 @@ pub static ModalityPerformedProcedureStepNotificationSOPClass: UID = UID {
     ident: "ModalityPerformedProcedureStepNotificationSOPClass",
     uid: "1.2.840.10008.3.1.2.3.5",
     name: "Modality Performed Procedure Step Notification SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Detached Results Management SOP Class (Retired)
@@ -1042,6 +1145,7 @@ pub static DetachedResultsManagementSOPClass: UID = UID {
     ident: "DetachedResultsManagementSOPClass",
     uid: "1.2.840.10008.3.1.2.5.1",
     name: "Detached Results Management SOP Class (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Detached Results Management Meta SOP Class (Retired)
@@ -1052,6 +1156,7 @@ pub static DetachedResultsManagementMetaSOPClass: UID = UID {
     ident: "DetachedResultsManagementMetaSOPClass",
     uid: "1.2.840.10008.3.1.2.5.4",
     name: "Detached Results Management Meta SOP Class (Retired)",
+    uid_type: UIDType::MetaSOPClass,
 };
 
 /// Detached Study Management Meta SOP Class (Retired)
@@ -1062,6 +1167,7 @@ pub static DetachedStudyManagementMetaSOPClass: UID = UID {
     ident: "DetachedStudyManagementMetaSOPClass",
     uid: "1.2.840.10008.3.1.2.5.5",
     name: "Detached Study Management Meta SOP Class (Retired)",
+    uid_type: UIDType::MetaSOPClass,
 };
 
 /// Detached Interpretation Management SOP Class (Retired)
@@ -1072,6 +1178,7 @@ pub static DetachedInterpretationManagementSOPClass: UID = UID {
     ident: "DetachedInterpretationManagementSOPClass",
     uid: "1.2.840.10008.3.1.2.6.1",
     name: "Detached Interpretation Management SOP Class (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Storage Service Class
@@ -1082,6 +1189,7 @@ pub static StorageServiceClass: UID = UID {
     ident: "StorageServiceClass",
     uid: "1.2.840.10008.4.2",
     name: "Storage Service Class",
+    uid_type: UIDType::ServiceClass,
 };
 
 /// Basic Film Session SOP Class
@@ -1092,6 +1200,7 @@ pub static BasicFilmSessionSOPClass: UID = UID {
     ident: "BasicFilmSessionSOPClass",
     uid: "1.2.840.10008.5.1.1.1",
     name: "Basic Film Session SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Basic Film Box SOP Class
@@ -1102,6 +1211,7 @@ pub static BasicFilmBoxSOPClass: UID = UID {
     ident: "BasicFilmBoxSOPClass",
     uid: "1.2.840.10008.5.1.1.2",
     name: "Basic Film Box SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Basic Grayscale Image Box SOP Class
@@ -1112,6 +1222,7 @@ pub static BasicGrayscaleImageBoxSOPClass: UID = UID {
     ident: "BasicGrayscaleImageBoxSOPClass",
     uid: "1.2.840.10008.5.1.1.4",
     name: "Basic Grayscale Image Box SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Basic Color Image Box SOP Class
@@ -1122,6 +1233,7 @@ pub static BasicColorImageBoxSOPClass: UID = UID {
     ident: "BasicColorImageBoxSOPClass",
     uid: "1.2.840.10008.5.1.1.4.1",
     name: "Basic Color Image Box SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Referenced Image Box SOP Class (Retired)
@@ -1132,6 +1244,7 @@ pub static ReferencedImageBoxSOPClass: UID = UID {
     ident: "ReferencedImageBoxSOPClass",
     uid: "1.2.840.10008.5.1.1.4.2",
     name: "Referenced Image Box SOP Class (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Basic Grayscale Print Management Meta SOP Class
@@ -1142,6 +1255,7 @@ pub static BasicGrayscalePrintManagementMetaSOPClass: UID = UID {
     ident: "BasicGrayscalePrintManagementMetaSOPClass",
     uid: "1.2.840.10008.5.1.1.9",
     name: "Basic Grayscale Print Management Meta SOP Class",
+    uid_type: UIDType::MetaSOPClass,
 };
 
 /// Referenced Grayscale Print Management Meta SOP Class (Retired)
@@ -1152,6 +1266,7 @@ pub static ReferencedGrayscalePrintManagementMetaSOPClass: UID = UID {
     ident: "ReferencedGrayscalePrintManagementMetaSOPClass",
     uid: "1.2.840.10008.5.1.1.9.1",
     name: "Referenced Grayscale Print Management Meta SOP Class (Retired)",
+    uid_type: UIDType::MetaSOPClass,
 };
 
 /// Print Job SOP Class
@@ -1162,6 +1277,7 @@ pub static PrintJobSOPClass: UID = UID {
     ident: "PrintJobSOPClass",
     uid: "1.2.840.10008.5.1.1.14",
     name: "Print Job SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Basic Annotation Box SOP Class
@@ -1172,6 +1288,7 @@ pub static BasicAnnotationBoxSOPClass: UID = UID {
     ident: "BasicAnnotationBoxSOPClass",
     uid: "1.2.840.10008.5.1.1.15",
     name: "Basic Annotation Box SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Printer SOP Class
@@ -1182,6 +1299,7 @@ pub static PrinterSOPClass: UID = UID {
     ident: "PrinterSOPClass",
     uid: "1.2.840.10008.5.1.1.16",
     name: "Printer SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Printer Configuration Retrieval SOP Class
@@ -1192,6 +1310,7 @@ pub static PrinterConfigurationRetrievalSOPClass: UID = UID {
     ident: "PrinterConfigurationRetrievalSOPClass",
     uid: "1.2.840.10008.5.1.1.16.376",
     name: "Printer Configuration Retrieval SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Printer SOP Instance
@@ -1202,6 +1321,7 @@ pub static PrinterSOPInstance: UID = UID {
     ident: "PrinterSOPInstance",
     uid: "1.2.840.10008.5.1.1.17",
     name: "Printer SOP Instance",
+    uid_type: UIDType::WellKnownSOPInstance,
 };
 
 /// Printer Configuration Retrieval SOP Instance
@@ -1212,6 +1332,7 @@ pub static PrinterConfigurationRetrievalSOPInstance: UID = UID {
     ident: "PrinterConfigurationRetrievalSOPInstance",
     uid: "1.2.840.10008.5.1.1.17.376",
     name: "Printer Configuration Retrieval SOP Instance",
+    uid_type: UIDType::WellKnownSOPInstance,
 };
 
 /// Basic Color Print Management Meta SOP Class
@@ -1222,6 +1343,7 @@ pub static BasicColorPrintManagementMetaSOPClass: UID = UID {
     ident: "BasicColorPrintManagementMetaSOPClass",
     uid: "1.2.840.10008.5.1.1.18",
     name: "Basic Color Print Management Meta SOP Class",
+    uid_type: UIDType::MetaSOPClass,
 };
 
 /// Referenced Color Print Management Meta SOP Class (Retired)
@@ -1232,6 +1354,7 @@ pub static ReferencedColorPrintManagementMetaSOPClass: UID = UID {
     ident: "ReferencedColorPrintManagementMetaSOPClass",
     uid: "1.2.840.10008.5.1.1.18.1",
     name: "Referenced Color Print Management Meta SOP Class (Retired)",
+    uid_type: UIDType::MetaSOPClass,
 };
 
 /// VOI LUT Box SOP Class
@@ -1242,6 +1365,7 @@ pub static VOILUTBoxSOPClass: UID = UID {
     ident: "VOILUTBoxSOPClass",
     uid: "1.2.840.10008.5.1.1.22",
     name: "VOI LUT Box SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Presentation LUT SOP Class
@@ -1252,6 +1376,7 @@ pub static PresentationLUTSOPClass: UID = UID {
     ident: "PresentationLUTSOPClass",
     uid: "1.2.840.10008.5.1.1.23",
     name: "Presentation LUT SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Image Overlay Box SOP Class (Retired)
@@ -1262,6 +1387,7 @@ pub static ImageOverlayBoxSOPClass: UID = UID {
     ident: "ImageOverlayBoxSOPClass",
     uid: "1.2.840.10008.5.1.1.24",
     name: "Image Overlay Box SOP Class (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Basic Print Image Overlay Box SOP Class (Retired)
@@ -1272,6 +1398,7 @@ pub static BasicPrintImageOverlayBoxSOPClass: UID = UID {
     ident: "BasicPrintImageOverlayBoxSOPClass",
     uid: "1.2.840.10008.5.1.1.24.1",
     name: "Basic Print Image Overlay Box SOP Class (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Print Queue SOP Instance (Retired)
@@ -1282,6 +1409,7 @@ pub static PrintQueueSOPInstance: UID = UID {
     ident: "PrintQueueSOPInstance",
     uid: "1.2.840.10008.5.1.1.25",
     name: "Print Queue SOP Instance (Retired)",
+    uid_type: UIDType::WellKnownSOPInstance,
 };
 
 /// Print Queue Management SOP Class (Retired)
@@ -1292,6 +1420,7 @@ pub static PrintQueueManagementSOPClass: UID = UID {
     ident: "PrintQueueManagementSOPClass",
     uid: "1.2.840.10008.5.1.1.26",
     name: "Print Queue Management SOP Class (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Stored Print Storage SOP Class (Retired)
@@ -1302,6 +1431,7 @@ pub static StoredPrintStorageSOPClass: UID = UID {
     ident: "StoredPrintStorageSOPClass",
     uid: "1.2.840.10008.5.1.1.27",
     name: "Stored Print Storage SOP Class (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Hardcopy Grayscale Image Storage SOP Class (Retired)
@@ -1312,6 +1442,7 @@ pub static HardcopyGrayscaleImageStorageSOPClass: UID = UID {
     ident: "HardcopyGrayscaleImageStorageSOPClass",
     uid: "1.2.840.10008.5.1.1.29",
     name: "Hardcopy Grayscale Image Storage SOP Class (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Hardcopy Color Image Storage SOP Class (Retired)
@@ -1322,6 +1453,7 @@ pub static HardcopyColorImageStorageSOPClass: UID = UID {
     ident: "HardcopyColorImageStorageSOPClass",
     uid: "1.2.840.10008.5.1.1.30",
     name: "Hardcopy Color Image Storage SOP Class (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Pull Print Request SOP Class (Retired)
@@ -1332,6 +1464,7 @@ pub static PullPrintRequestSOPClass: UID = UID {
     ident: "PullPrintRequestSOPClass",
     uid: "1.2.840.10008.5.1.1.31",
     name: "Pull Print Request SOP Class (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Pull Stored Print Management Meta SOP Class (Retired)
@@ -1342,6 +1475,7 @@ pub static PullStoredPrintManagementMetaSOPClass: UID = UID {
     ident: "PullStoredPrintManagementMetaSOPClass",
     uid: "1.2.840.10008.5.1.1.32",
     name: "Pull Stored Print Management Meta SOP Class (Retired)",
+    uid_type: UIDType::MetaSOPClass,
 };
 
 /// Media Creation Management SOP Class UID
@@ -1352,6 +1486,7 @@ pub static MediaCreationManagementSOPClassUID: UID = UID {
     ident: "MediaCreationManagementSOPClassUID",
     uid: "1.2.840.10008.5.1.1.33",
     name: "Media Creation Management SOP Class UID",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Display System SOP Class
@@ -1362,6 +1497,7 @@ pub static DisplaySystemSOPClass: UID = UID {
     ident: "DisplaySystemSOPClass",
     uid: "1.2.840.10008.5.1.1.40",
     name: "Display System SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Display System SOP Instance
@@ -1372,6 +1508,7 @@ pub static DisplaySystemSOPInstance: UID = UID {
     ident: "DisplaySystemSOPInstance",
     uid: "1.2.840.10008.5.1.1.40.1",
     name: "Display System SOP Instance",
+    uid_type: UIDType::WellKnownSOPInstance,
 };
 
 /// Computed Radiography Image Storage
@@ -1382,6 +1519,7 @@ pub static ComputedRadiographyImageStorage: UID = UID {
     ident: "ComputedRadiographyImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.1",
     name: "Computed Radiography Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Digital X-Ray Image Storage - For Presentation
@@ -1392,6 +1530,7 @@ pub static DigitalXRayImageStorageForPresentation: UID = UID {
     ident: "DigitalXRayImageStorageForPresentation",
     uid: "1.2.840.10008.5.1.4.1.1.1.1",
     name: "Digital X-Ray Image Storage - For Presentation",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Digital X-Ray Image Storage - For Processing
@@ -1402,6 +1541,7 @@ pub static DigitalXRayImageStorageForProcessing: UID = UID {
     ident: "DigitalXRayImageStorageForProcessing",
     uid: "1.2.840.10008.5.1.4.1.1.1.1.1",
     name: "Digital X-Ray Image Storage - For Processing",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Digital Mammography X-Ray Image Storage - For Presentation
@@ -1412,6 +1552,7 @@ pub static DigitalMammographyXRayImageStorageForPresentation: UID = UID {
     ident: "DigitalMammographyXRayImageStorageForPresentation",
     uid: "1.2.840.10008.5.1.4.1.1.1.2",
     name: "Digital Mammography X-Ray Image Storage - For Presentation",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Digital Mammography X-Ray Image Storage - For Processing
@@ -1422,6 +1563,7 @@ pub static DigitalMammographyXRayImageStorageForProcessing: UID = UID {
     ident: "DigitalMammographyXRayImageStorageForProcessing",
     uid: "1.2.840.10008.5.1.4.1.1.1.2.1",
     name: "Digital Mammography X-Ray Image Storage - For Processing",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Digital Intra-Oral X-Ray Image Storage - For Presentation
@@ -1432,6 +1574,7 @@ pub static DigitalIntraOralXRayImageStorageForPresentation: UID = UID {
     ident: "DigitalIntraOralXRayImageStorageForPresentation",
     uid: "1.2.840.10008.5.1.4.1.1.1.3",
     name: "Digital Intra-Oral X-Ray Image Storage - For Presentation",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Digital Intra-Oral X-Ray Image Storage - For Processing
@@ -1442,6 +1585,7 @@ pub static DigitalIntraOralXRayImageStorageForProcessing: UID = UID {
     ident: "DigitalIntraOralXRayImageStorageForProcessing",
     uid: "1.2.840.10008.5.1.4.1.1.1.3.1",
     name: "Digital Intra-Oral X-Ray Image Storage - For Processing",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// CT Image Storage
@@ -1452,6 +1596,7 @@ pub static CTImageStorage: UID = UID {
     ident: "CTImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.2",
     name: "CT Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Enhanced CT Image Storage
@@ -1462,6 +1607,7 @@ pub static EnhancedCTImageStorage: UID = UID {
     ident: "EnhancedCTImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.2.1",
     name: "Enhanced CT Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Legacy Converted Enhanced CT Image Storage
@@ -1472,6 +1618,7 @@ pub static LegacyConvertedEnhancedCTImageStorage: UID = UID {
     ident: "LegacyConvertedEnhancedCTImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.2.2",
     name: "Legacy Converted Enhanced CT Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Ultrasound Multi-frame Image Storage (Retired)
@@ -1482,6 +1629,7 @@ pub static UltrasoundMultiframeImageStorage_Retired: UID = UID {
     ident: "UltrasoundMultiframeImageStorage_Retired",
     uid: "1.2.840.10008.5.1.4.1.1.3",
     name: "Ultrasound Multi-frame Image Storage (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Ultrasound Multi-frame Image Storage
@@ -1492,6 +1640,7 @@ pub static UltrasoundMultiframeImageStorage: UID = UID {
     ident: "UltrasoundMultiframeImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.3.1",
     name: "Ultrasound Multi-frame Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// MR Image Storage
@@ -1502,6 +1651,7 @@ pub static MRImageStorage: UID = UID {
     ident: "MRImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.4",
     name: "MR Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Enhanced MR Image Storage
@@ -1512,6 +1662,7 @@ pub static EnhancedMRImageStorage: UID = UID {
     ident: "EnhancedMRImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.4.1",
     name: "Enhanced MR Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// MR Spectroscopy Storage
@@ -1522,6 +1673,7 @@ pub static MRSpectroscopyStorage: UID = UID {
     ident: "MRSpectroscopyStorage",
     uid: "1.2.840.10008.5.1.4.1.1.4.2",
     name: "MR Spectroscopy Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Enhanced MR Color Image Storage
@@ -1532,6 +1684,7 @@ pub static EnhancedMRColorImageStorage: UID = UID {
     ident: "EnhancedMRColorImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.4.3",
     name: "Enhanced MR Color Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Legacy Converted Enhanced MR Image Storage
@@ -1542,6 +1695,7 @@ pub static LegacyConvertedEnhancedMRImageStorage: UID = UID {
     ident: "LegacyConvertedEnhancedMRImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.4.4",
     name: "Legacy Converted Enhanced MR Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Nuclear Medicine Image Storage (Retired)
@@ -1552,6 +1706,7 @@ pub static NuclearMedicineImageStorage_Retired: UID = UID {
     ident: "NuclearMedicineImageStorage_Retired",
     uid: "1.2.840.10008.5.1.4.1.1.5",
     name: "Nuclear Medicine Image Storage (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Ultrasound Image Storage (Retired)
@@ -1562,6 +1717,7 @@ pub static UltrasoundImageStorage_Retired: UID = UID {
     ident: "UltrasoundImageStorage_Retired",
     uid: "1.2.840.10008.5.1.4.1.1.6",
     name: "Ultrasound Image Storage (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Ultrasound Image Storage
@@ -1572,6 +1728,7 @@ pub static UltrasoundImageStorage: UID = UID {
     ident: "UltrasoundImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.6.1",
     name: "Ultrasound Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Enhanced US Volume Storage
@@ -1582,6 +1739,7 @@ pub static EnhancedUSVolumeStorage: UID = UID {
     ident: "EnhancedUSVolumeStorage",
     uid: "1.2.840.10008.5.1.4.1.1.6.2",
     name: "Enhanced US Volume Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Photoacoustic Image Storage
@@ -1592,6 +1750,7 @@ pub static PhotoacousticImageStorage: UID = UID {
     ident: "PhotoacousticImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.6.3",
     name: "Photoacoustic Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Secondary Capture Image Storage
@@ -1602,6 +1761,7 @@ pub static SecondaryCaptureImageStorage: UID = UID {
     ident: "SecondaryCaptureImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.7",
     name: "Secondary Capture Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Multi-frame Single Bit Secondary Capture Image Storage
@@ -1612,6 +1772,7 @@ pub static MultiframeSingleBitSecondaryCaptureImageStorage: UID = UID {
     ident: "MultiframeSingleBitSecondaryCaptureImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.7.1",
     name: "Multi-frame Single Bit Secondary Capture Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Multi-frame Grayscale Byte Secondary Capture Image Storage
@@ -1622,6 +1783,7 @@ pub static MultiframeGrayscaleByteSecondaryCaptureImageStorage: UID = UID {
     ident: "MultiframeGrayscaleByteSecondaryCaptureImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.7.2",
     name: "Multi-frame Grayscale Byte Secondary Capture Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Multi-frame Grayscale Word Secondary Capture Image Storage
@@ -1632,6 +1794,7 @@ pub static MultiframeGrayscaleWordSecondaryCaptureImageStorage: UID = UID {
     ident: "MultiframeGrayscaleWordSecondaryCaptureImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.7.3",
     name: "Multi-frame Grayscale Word Secondary Capture Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Multi-frame True Color Secondary Capture Image Storage
@@ -1642,6 +1805,7 @@ pub static MultiframeTrueColorSecondaryCaptureImageStorage: UID = UID {
     ident: "MultiframeTrueColorSecondaryCaptureImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.7.4",
     name: "Multi-frame True Color Secondary Capture Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Standalone Overlay Storage (Retired)
@@ -1652,6 +1816,7 @@ pub static StandaloneOverlayStorage: UID = UID {
     ident: "StandaloneOverlayStorage",
     uid: "1.2.840.10008.5.1.4.1.1.8",
     name: "Standalone Overlay Storage (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Standalone Curve Storage (Retired)
@@ -1662,6 +1827,7 @@ pub static StandaloneCurveStorage: UID = UID {
     ident: "StandaloneCurveStorage",
     uid: "1.2.840.10008.5.1.4.1.1.9",
     name: "Standalone Curve Storage (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Waveform Storage - Trial (Retired)
@@ -1672,6 +1838,7 @@ pub static WaveformStorageTrial: UID = UID {
     ident: "WaveformStorageTrial",
     uid: "1.2.840.10008.5.1.4.1.1.9.1",
     name: "Waveform Storage - Trial (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::WaveformStorage),
 };
 
 /// 12-lead ECG Waveform Storage
@@ -1682,6 +1849,7 @@ pub static Tag_12leadECGWaveformStorage: UID = UID {
     ident: "Tag_12leadECGWaveformStorage",
     uid: "1.2.840.10008.5.1.4.1.1.9.1.1",
     name: "12-lead ECG Waveform Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::WaveformStorage),
 };
 
 /// General ECG Waveform Storage
@@ -1692,6 +1860,7 @@ pub static GeneralECGWaveformStorage: UID = UID {
     ident: "GeneralECGWaveformStorage",
     uid: "1.2.840.10008.5.1.4.1.1.9.1.2",
     name: "General ECG Waveform Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::WaveformStorage),
 };
 
 /// Ambulatory ECG Waveform Storage
@@ -1702,6 +1871,7 @@ pub static AmbulatoryECGWaveformStorage: UID = UID {
     ident: "AmbulatoryECGWaveformStorage",
     uid: "1.2.840.10008.5.1.4.1.1.9.1.3",
     name: "Ambulatory ECG Waveform Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::WaveformStorage),
 };
 
 /// General 32-bit ECG Waveform Storage
@@ -1712,6 +1882,7 @@ pub static General32bitECGWaveformStorage: UID = UID {
     ident: "General32bitECGWaveformStorage",
     uid: "1.2.840.10008.5.1.4.1.1.9.1.4",
     name: "General 32-bit ECG Waveform Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::WaveformStorage),
 };
 
 /// Hemodynamic Waveform Storage
@@ -1722,6 +1893,7 @@ pub static HemodynamicWaveformStorage: UID = UID {
     ident: "HemodynamicWaveformStorage",
     uid: "1.2.840.10008.5.1.4.1.1.9.2.1",
     name: "Hemodynamic Waveform Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::WaveformStorage),
 };
 
 /// Cardiac Electrophysiology Waveform Storage
@@ -1732,6 +1904,7 @@ pub static CardiacElectrophysiologyWaveformStorage: UID = UID {
     ident: "CardiacElectrophysiologyWaveformStorage",
     uid: "1.2.840.10008.5.1.4.1.1.9.3.1",
     name: "Cardiac Electrophysiology Waveform Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::WaveformStorage),
 };
 
 /// Basic Voice Audio Waveform Storage
@@ -1742,6 +1915,7 @@ pub static BasicVoiceAudioWaveformStorage: UID = UID {
     ident: "BasicVoiceAudioWaveformStorage",
     uid: "1.2.840.10008.5.1.4.1.1.9.4.1",
     name: "Basic Voice Audio Waveform Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::WaveformStorage),
 };
 
 /// General Audio Waveform Storage
@@ -1752,6 +1926,7 @@ pub static GeneralAudioWaveformStorage: UID = UID {
     ident: "GeneralAudioWaveformStorage",
     uid: "1.2.840.10008.5.1.4.1.1.9.4.2",
     name: "General Audio Waveform Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::WaveformStorage),
 };
 
 /// Arterial Pulse Waveform Storage
@@ -1762,6 +1937,7 @@ pub static ArterialPulseWaveformStorage: UID = UID {
     ident: "ArterialPulseWaveformStorage",
     uid: "1.2.840.10008.5.1.4.1.1.9.5.1",
     name: "Arterial Pulse Waveform Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::WaveformStorage),
 };
 
 /// Respiratory Waveform Storage
@@ -1772,6 +1948,7 @@ pub static RespiratoryWaveformStorage: UID = UID {
     ident: "RespiratoryWaveformStorage",
     uid: "1.2.840.10008.5.1.4.1.1.9.6.1",
     name: "Respiratory Waveform Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::WaveformStorage),
 };
 
 /// Multi-channel Respiratory Waveform Storage
@@ -1782,6 +1959,7 @@ pub static MultichannelRespiratoryWaveformStorage: UID = UID {
     ident: "MultichannelRespiratoryWaveformStorage",
     uid: "1.2.840.10008.5.1.4.1.1.9.6.2",
     name: "Multi-channel Respiratory Waveform Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::WaveformStorage),
 };
 
 /// Routine Scalp Electroencephalogram Waveform Storage
@@ -1792,6 +1970,7 @@ pub static RoutineScalpElectroencephalogramWaveformStorage: UID = UID {
     ident: "RoutineScalpElectroencephalogramWaveformStorage",
     uid: "1.2.840.10008.5.1.4.1.1.9.7.1",
     name: "Routine Scalp Electroencephalogram Waveform Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::WaveformStorage),
 };
 
 /// Electromyogram Waveform Storage
@@ -1802,6 +1981,7 @@ pub static ElectromyogramWaveformStorage: UID = UID {
     ident: "ElectromyogramWaveformStorage",
     uid: "1.2.840.10008.5.1.4.1.1.9.7.2",
     name: "Electromyogram Waveform Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::WaveformStorage),
 };
 
 /// Electrooculogram Waveform Storage
@@ -1812,6 +1992,7 @@ pub static ElectrooculogramWaveformStorage: UID = UID {
     ident: "ElectrooculogramWaveformStorage",
     uid: "1.2.840.10008.5.1.4.1.1.9.7.3",
     name: "Electrooculogram Waveform Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::WaveformStorage),
 };
 
 /// Sleep Electroencephalogram Waveform Storage
@@ -1822,6 +2003,7 @@ pub static SleepElectroencephalogramWaveformStorage: UID = UID {
     ident: "SleepElectroencephalogramWaveformStorage",
     uid: "1.2.840.10008.5.1.4.1.1.9.7.4",
     name: "Sleep Electroencephalogram Waveform Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::WaveformStorage),
 };
 
 /// Body Position Waveform Storage
@@ -1832,6 +2014,7 @@ pub static BodyPositionWaveformStorage: UID = UID {
     ident: "BodyPositionWaveformStorage",
     uid: "1.2.840.10008.5.1.4.1.1.9.8.1",
     name: "Body Position Waveform Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::WaveformStorage),
 };
 
 /// Standalone Modality LUT Storage (Retired)
@@ -1842,6 +2025,7 @@ pub static StandaloneModalityLUTStorage: UID = UID {
     ident: "StandaloneModalityLUTStorage",
     uid: "1.2.840.10008.5.1.4.1.1.10",
     name: "Standalone Modality LUT Storage (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Standalone VOI LUT Storage (Retired)
@@ -1852,6 +2036,7 @@ pub static StandaloneVOILUTStorage: UID = UID {
     ident: "StandaloneVOILUTStorage",
     uid: "1.2.840.10008.5.1.4.1.1.11",
     name: "Standalone VOI LUT Storage (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Grayscale Softcopy Presentation State Storage
@@ -1862,6 +2047,7 @@ pub static GrayscaleSoftcopyPresentationStateStorage: UID = UID {
     ident: "GrayscaleSoftcopyPresentationStateStorage",
     uid: "1.2.840.10008.5.1.4.1.1.11.1",
     name: "Grayscale Softcopy Presentation State Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::PresentationStateStorage),
 };
 
 /// Color Softcopy Presentation State Storage
@@ -1872,6 +2058,7 @@ pub static ColorSoftcopyPresentationStateStorage: UID = UID {
     ident: "ColorSoftcopyPresentationStateStorage",
     uid: "1.2.840.10008.5.1.4.1.1.11.2",
     name: "Color Softcopy Presentation State Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::PresentationStateStorage),
 };
 
 /// Pseudo-Color Softcopy Presentation State Storage
@@ -1882,6 +2069,7 @@ pub static PseudoColorSoftcopyPresentationStateStorage: UID = UID {
     ident: "PseudoColorSoftcopyPresentationStateStorage",
     uid: "1.2.840.10008.5.1.4.1.1.11.3",
     name: "Pseudo-Color Softcopy Presentation State Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::PresentationStateStorage),
 };
 
 /// Blending Softcopy Presentation State Storage
@@ -1892,6 +2080,7 @@ pub static BlendingSoftcopyPresentationStateStorage: UID = UID {
     ident: "BlendingSoftcopyPresentationStateStorage",
     uid: "1.2.840.10008.5.1.4.1.1.11.4",
     name: "Blending Softcopy Presentation State Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::PresentationStateStorage),
 };
 
 /// XA/XRF Grayscale Softcopy Presentation State Storage
@@ -1902,6 +2091,7 @@ pub static XAXRFGrayscaleSoftcopyPresentationStateStorage: UID = UID {
     ident: "XAXRFGrayscaleSoftcopyPresentationStateStorage",
     uid: "1.2.840.10008.5.1.4.1.1.11.5",
     name: "XA/XRF Grayscale Softcopy Presentation State Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::PresentationStateStorage),
 };
 
 /// Grayscale Planar MPR Volumetric Presentation State Storage
@@ -1912,6 +2102,7 @@ pub static GrayscalePlanarMPRVolumetricPresentationStateStorage: UID = UID {
     ident: "GrayscalePlanarMPRVolumetricPresentationStateStorage",
     uid: "1.2.840.10008.5.1.4.1.1.11.6",
     name: "Grayscale Planar MPR Volumetric Presentation State Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::PresentationStateStorage),
 };
 
 /// Compositing Planar MPR Volumetric Presentation State Storage
@@ -1922,6 +2113,7 @@ pub static CompositingPlanarMPRVolumetricPresentationStateStorage: UID = UID {
     ident: "CompositingPlanarMPRVolumetricPresentationStateStorage",
     uid: "1.2.840.10008.5.1.4.1.1.11.7",
     name: "Compositing Planar MPR Volumetric Presentation State Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::PresentationStateStorage),
 };
 
 /// Advanced Blending Presentation State Storage
@@ -1932,6 +2124,7 @@ pub static AdvancedBlendingPresentationStateStorage: UID = UID {
     ident: "AdvancedBlendingPresentationStateStorage",
     uid: "1.2.840.10008.5.1.4.1.1.11.8",
     name: "Advanced Blending Presentation State Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::PresentationStateStorage),
 };
 
 /// Volume Rendering Volumetric Presentation State Storage
@@ -1942,6 +2135,7 @@ pub static VolumeRenderingVolumetricPresentationStateStorage: UID = UID {
     ident: "VolumeRenderingVolumetricPresentationStateStorage",
     uid: "1.2.840.10008.5.1.4.1.1.11.9",
     name: "Volume Rendering Volumetric Presentation State Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::PresentationStateStorage),
 };
 
 /// Segmented Volume Rendering Volumetric Presentation State Storage
@@ -1952,6 +2146,7 @@ pub static SegmentedVolumeRenderingVolumetricPresentationStateStorage: UID = UID
     ident: "SegmentedVolumeRenderingVolumetricPresentationStateStorage",
     uid: "1.2.840.10008.5.1.4.1.1.11.10",
     name: "Segmented Volume Rendering Volumetric Presentation State Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::PresentationStateStorage),
 };
 
 /// Multiple Volume Rendering Volumetric Presentation State Storage
@@ -1962,6 +2157,7 @@ pub static MultipleVolumeRenderingVolumetricPresentationStateStorage: UID = UID
     ident: "MultipleVolumeRenderingVolumetricPresentationStateStorage",
     uid: "1.2.840.10008.5.1.4.1.1.11.11",
     name: "Multiple Volume Rendering Volumetric Presentation State Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::PresentationStateStorage),
 };
 
 /// Variable Modality LUT Softcopy Presentation State Storage
@@ -1972,6 +2168,7 @@ pub static VariableModalityLUTSoftcopyPresentationStateStorage: UID = UID {
     ident: "VariableModalityLUTSoftcopyPresentationStateStorage",
     uid: "1.2.840.10008.5.1.4.1.1.11.12",
     name: "Variable Modality LUT Softcopy Presentation State Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::PresentationStateStorage),
 };
 
 /// X-Ray Angiographic Image Storage
@@ -1982,6 +2179,7 @@ pub static XRayAngiographicImageStorage: UID = UID {
     ident: "XRayAngiographicImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.12.1",
     name: "X-Ray Angiographic Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Enhanced XA Image Storage
@@ -1992,6 +2190,7 @@ pub static EnhancedXAImageStorage: UID = UID {
     ident: "EnhancedXAImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.12.1.1",
     name: "Enhanced XA Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// X-Ray Radiofluoroscopic Image Storage
@@ -2002,6 +2201,7 @@ pub static XRayRadiofluoroscopicImageStorage: UID = UID {
     ident: "XRayRadiofluoroscopicImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.12.2",
     name: "X-Ray Radiofluoroscopic Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Enhanced XRF Image Storage
@@ -2012,6 +2212,7 @@ pub static EnhancedXRFImageStorage: UID = UID {
     ident: "EnhancedXRFImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.12.2.1",
     name: "Enhanced XRF Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// X-Ray Angiographic Bi-Plane Image Storage (Retired)
@@ -2022,6 +2223,7 @@ pub static XRayAngiographicBiPlaneImageStorage: UID = UID {
     ident: "XRayAngiographicBiPlaneImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.12.3",
     name: "X-Ray Angiographic Bi-Plane Image Storage (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// X-Ray 3D Angiographic Image Storage
@@ -2032,6 +2234,7 @@ pub static XRay3DAngiographicImageStorage: UID = UID {
     ident: "XRay3DAngiographicImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.13.1.1",
     name: "X-Ray 3D Angiographic Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// X-Ray 3D Craniofacial Image Storage
@@ -2042,6 +2245,7 @@ pub static XRay3DCraniofacialImageStorage: UID = UID {
     ident: "XRay3DCraniofacialImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.13.1.2",
     name: "X-Ray 3D Craniofacial Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Breast Tomosynthesis Image Storage
@@ -2052,6 +2256,7 @@ pub static BreastTomosynthesisImageStorage: UID = UID {
     ident: "BreastTomosynthesisImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.13.1.3",
     name: "Breast Tomosynthesis Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Breast Projection X-Ray Image Storage - For Presentation
@@ -2062,6 +2267,7 @@ pub static BreastProjectionXRayImageStorageForPresentation: UID = UID {
     ident: "BreastProjectionXRayImageStorageForPresentation",
     uid: "1.2.840.10008.5.1.4.1.1.13.1.4",
     name: "Breast Projection X-Ray Image Storage - For Presentation",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Breast Projection X-Ray Image Storage - For Processing
@@ -2072,6 +2278,7 @@ pub static BreastProjectionXRayImageStorageForProcessing: UID = UID {
     ident: "BreastProjectionXRayImageStorageForProcessing",
     uid: "1.2.840.10008.5.1.4.1.1.13.1.5",
     name: "Breast Projection X-Ray Image Storage - For Processing",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Intravascular Optical Coherence Tomography Image Storage - For Presentation
@@ -2082,6 +2289,7 @@ pub static IntravascularOpticalCoherenceTomographyImageStorageForPresentation: U
     ident: "IntravascularOpticalCoherenceTomographyImageStorageForPresentation",
     uid: "1.2.840.10008.5.1.4.1.1.14.1",
     name: "Intravascular Optical Coherence Tomography Image Storage - For Presentation",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Intravascular Optical Coherence Tomography Image Storage - For Processing
@@ -2092,6 +2300,7 @@ pub static IntravascularOpticalCoherenceTomographyImageStorageForProcessing: UID
     ident: "IntravascularOpticalCoherenceTomographyImageStorageForProcessing",
     uid: "1.2.840.10008.5.1.4.1.1.14.2",
     name: "Intravascular Optical Coherence Tomography Image Storage - For Processing",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Nuclear Medicine Image Storage
@@ -2102,6 +2311,7 @@ pub static NuclearMedicineImageStorage: UID = UID {
     ident: "NuclearMedicineImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.20",
     name: "Nuclear Medicine Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Parametric Map Storage
@@ -2112,6 +2322,7 @@ pub static ParametricMapStorage: UID = UID {
     ident: "ParametricMapStorage",
     uid: "1.2.840.10008.5.1.4.1.1.30",
     name: "Parametric Map Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Raw Data Storage
@@ -2122,6 +2333,7 @@ pub static RawDataStorage: UID = UID {
     ident: "RawDataStorage",
     uid: "1.2.840.10008.5.1.4.1.1.66",
     name: "Raw Data Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Spatial Registration Storage
@@ -2132,6 +2344,7 @@ pub static SpatialRegistrationStorage: UID = UID {
     ident: "SpatialRegistrationStorage",
     uid: "1.2.840.10008.5.1.4.1.1.66.1",
     name: "Spatial Registration Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Spatial Fiducials Storage
@@ -2142,6 +2355,7 @@ pub static SpatialFiducialsStorage: UID = UID {
     ident: "SpatialFiducialsStorage",
     uid: "1.2.840.10008.5.1.4.1.1.66.2",
     name: "Spatial Fiducials Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Deformable Spatial Registration Storage
@@ -2152,6 +2366,7 @@ pub static DeformableSpatialRegistrationStorage: UID = UID {
     ident: "DeformableSpatialRegistrationStorage",
     uid: "1.2.840.10008.5.1.4.1.1.66.3",
     name: "Deformable Spatial Registration Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Segmentation Storage
@@ -2162,6 +2377,7 @@ pub static SegmentationStorage: UID = UID {
     ident: "SegmentationStorage",
     uid: "1.2.840.10008.5.1.4.1.1.66.4",
     name: "Segmentation Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Surface Segmentation Storage
@@ -2172,6 +2388,7 @@ pub static SurfaceSegmentationStorage: UID = UID {
     ident: "SurfaceSegmentationStorage",
     uid: "1.2.840.10008.5.1.4.1.1.66.5",
     name: "Surface Segmentation Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Tractography Results Storage
@@ -2182,6 +2399,7 @@ pub static TractographyResultsStorage: UID = UID {
     ident: "TractographyResultsStorage",
     uid: "1.2.840.10008.5.1.4.1.1.66.6",
     name: "Tractography Results Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Real World Value Mapping Storage
@@ -2192,6 +2410,7 @@ pub static RealWorldValueMappingStorage: UID = UID {
     ident: "RealWorldValueMappingStorage",
     uid: "1.2.840.10008.5.1.4.1.1.67",
     name: "Real World Value Mapping Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Surface Scan Mesh Storage
@@ -2202,6 +2421,7 @@ pub static SurfaceScanMeshStorage: UID = UID {
     ident: "SurfaceScanMeshStorage",
     uid: "1.2.840.10008.5.1.4.1.1.68.1",
     name: "Surface Scan Mesh Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Surface Scan Point Cloud Storage
@@ -2212,6 +2432,7 @@ pub static SurfaceScanPointCloudStorage: UID = UID {
     ident: "SurfaceScanPointCloudStorage",
     uid: "1.2.840.10008.5.1.4.1.1.68.2",
     name: "Surface Scan Point Cloud Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// VL Image Storage - Trial (Retired)
@@ -2222,6 +2443,7 @@ pub static VLImageStorageTrial: UID = UID {
     ident: "VLImageStorageTrial",
     uid: "1.2.840.10008.5.1.4.1.1.77.1",
     name: "VL Image Storage - Trial (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// VL Multi-frame Image Storage - Trial (Retired)
@@ -2232,6 +2454,7 @@ pub static VLMultiframeImageStorageTrial: UID = UID {
     ident: "VLMultiframeImageStorageTrial",
     uid: "1.2.840.10008.5.1.4.1.1.77.2",
     name: "VL Multi-frame Image Storage - Trial (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// VL Endoscopic Image Storage
@@ -2242,6 +2465,7 @@ pub static VLEndoscopicImageStorage: UID = UID {
     ident: "VLEndoscopicImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.77.1.1",
     name: "VL Endoscopic Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Video Endoscopic Image Storage
@@ -2252,6 +2476,7 @@ pub static VideoEndoscopicImageStorage: UID = UID {
     ident: "VideoEndoscopicImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.77.1.1.1",
     name: "Video Endoscopic Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// VL Microscopic Image Storage
@@ -2262,6 +2487,7 @@ pub static VLMicroscopicImageStorage: UID = UID {
     ident: "VLMicroscopicImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.77.1.2",
     name: "VL Microscopic Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Video Microscopic Image Storage
@@ -2272,6 +2498,7 @@ pub static VideoMicroscopicImageStorage: UID = UID {
     ident: "VideoMicroscopicImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.77.1.2.1",
     name: "Video Microscopic Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// VL Slide-Coordinates Microscopic Image Storage
@@ -2282,6 +2509,7 @@ pub static VLSlideCoordinatesMicroscopicImageStorage: UID = UID {
     ident: "VLSlideCoordinatesMicroscopicImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.77.1.3",
     name: "VL Slide-Coordinates Microscopic Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// VL Photographic Image Storage
@@ -2292,6 +2520,7 @@ pub static VLPhotographicImageStorage: UID = UID {
     ident: "VLPhotographicImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.77.1.4",
     name: "VL Photographic Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Video Photographic Image Storage
@@ -2302,6 +2531,7 @@ pub static VideoPhotographicImageStorage: UID = UID {
     ident: "VideoPhotographicImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.77.1.4.1",
     name: "Video Photographic Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Ophthalmic Photography 8 Bit Image Storage
@@ -2312,6 +2542,7 @@ pub static OphthalmicPhotography8BitImageStorage: UID = UID {
     ident: "OphthalmicPhotography8BitImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.77.1.5.1",
     name: "Ophthalmic Photography 8 Bit Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Ophthalmic Photography 16 Bit Image Storage
@@ -2322,6 +2553,7 @@ pub static OphthalmicPhotography16BitImageStorage: UID = UID {
     ident: "OphthalmicPhotography16BitImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.77.1.5.2",
     name: "Ophthalmic Photography 16 Bit Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Stereometric Relationship Storage
@@ -2332,6 +2564,7 @@ pub static StereometricRelationshipStorage: UID = UID {
     ident: "StereometricRelationshipStorage",
     uid: "1.2.840.10008.5.1.4.1.1.77.1.5.3",
     name: "Stereometric Relationship Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Ophthalmic Tomography Image Storage
@@ -2342,6 +2575,7 @@ pub static OphthalmicTomographyImageStorage: UID = UID {
     ident: "OphthalmicTomographyImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.77.1.5.4",
     name: "Ophthalmic Tomography Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Wide Field Ophthalmic Photography Stereographic Projection Image Storage
@@ -2352,6 +2586,7 @@ pub static WideFieldOphthalmicPhotographyStereographicProjectionImageStorage: UI
     ident: "WideFieldOphthalmicPhotographyStereographicProjectionImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.77.1.5.5",
     name: "Wide Field Ophthalmic Photography Stereographic Projection Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Wide Field Ophthalmic Photography 3D Coordinates Image Storage
@@ -2362,6 +2597,7 @@ pub static WideFieldOphthalmicPhotography3DCoordinatesImageStorage: UID = UID {
     ident: "WideFieldOphthalmicPhotography3DCoordinatesImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.77.1.5.6",
     name: "Wide Field Ophthalmic Photography 3D Coordinates Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Ophthalmic Optical Coherence Tomography En Face Image Storage
@@ -2372,6 +2608,7 @@ pub static OphthalmicOpticalCoherenceTomographyEnFaceImageStorage: UID = UID {
     ident: "OphthalmicOpticalCoherenceTomographyEnFaceImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.77.1.5.7",
     name: "Ophthalmic Optical Coherence Tomography En Face Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Ophthalmic Optical Coherence Tomography B-scan Volume Analysis Storage
@@ -2382,6 +2619,7 @@ pub static OphthalmicOpticalCoherenceTomographyBscanVolumeAnalysisStorage: UID =
     ident: "OphthalmicOpticalCoherenceTomographyBscanVolumeAnalysisStorage",
     uid: "1.2.840.10008.5.1.4.1.1.77.1.5.8",
     name: "Ophthalmic Optical Coherence Tomography B-scan Volume Analysis Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// VL Whole Slide Microscopy Image Storage
@@ -2392,6 +2630,7 @@ pub static VLWholeSlideMicroscopyImageStorage: UID = UID {
     ident: "VLWholeSlideMicroscopyImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.77.1.6",
     name: "VL Whole Slide Microscopy Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Dermoscopic Photography Image Storage
@@ -2402,6 +2641,7 @@ pub static DermoscopicPhotographyImageStorage: UID = UID {
     ident: "DermoscopicPhotographyImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.77.1.7",
     name: "Dermoscopic Photography Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Confocal Microscopy Image Storage
@@ -2412,6 +2652,7 @@ pub static ConfocalMicroscopyImageStorage: UID = UID {
     ident: "ConfocalMicroscopyImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.77.1.8",
     name: "Confocal Microscopy Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Confocal Microscopy Tiled Pyramidal Image Storage
@@ -2422,6 +2663,7 @@ pub static ConfocalMicroscopyTiledPyramidalImageStorage: UID = UID {
     ident: "ConfocalMicroscopyTiledPyramidalImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.77.1.9",
     name: "Confocal Microscopy Tiled Pyramidal Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Lensometry Measurements Storage
@@ -2432,6 +2674,7 @@ pub static LensometryMeasurementsStorage: UID = UID {
     ident: "LensometryMeasurementsStorage",
     uid: "1.2.840.10008.5.1.4.1.1.78.1",
     name: "Lensometry Measurements Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Autorefraction Measurements Storage
@@ -2442,6 +2685,7 @@ pub static AutorefractionMeasurementsStorage: UID = UID {
     ident: "AutorefractionMeasurementsStorage",
     uid: "1.2.840.10008.5.1.4.1.1.78.2",
     name: "Autorefraction Measurements Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Keratometry Measurements Storage
@@ -2452,6 +2696,7 @@ pub static KeratometryMeasurementsStorage: UID = UID {
     ident: "KeratometryMeasurementsStorage",
     uid: "1.2.840.10008.5.1.4.1.1.78.3",
     name: "Keratometry Measurements Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Subjective Refraction Measurements Storage
@@ -2462,6 +2707,7 @@ pub static SubjectiveRefractionMeasurementsStorage: UID = UID {
     ident: "SubjectiveRefractionMeasurementsStorage",
     uid: "1.2.840.10008.5.1.4.1.1.78.4",
     name: "Subjective Refraction Measurements Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Visual Acuity Measurements Storage
@@ -2472,6 +2718,7 @@ pub static VisualAcuityMeasurementsStorage: UID = UID {
     ident: "VisualAcuityMeasurementsStorage",
     uid: "1.2.840.10008.5.1.4.1.1.78.5",
     name: "Visual Acuity Measurements Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Spectacle Prescription Report Storage
@@ -2482,6 +2729,7 @@ pub static SpectaclePrescriptionReportStorage: UID = UID {
     ident: "SpectaclePrescriptionReportStorage",
     uid: "1.2.840.10008.5.1.4.1.1.78.6",
     name: "Spectacle Prescription Report Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Ophthalmic Axial Measurements Storage
@@ -2492,6 +2740,7 @@ pub static OphthalmicAxialMeasurementsStorage: UID = UID {
     ident: "OphthalmicAxialMeasurementsStorage",
     uid: "1.2.840.10008.5.1.4.1.1.78.7",
     name: "Ophthalmic Axial Measurements Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Intraocular Lens Calculations Storage
@@ -2502,6 +2751,7 @@ pub static IntraocularLensCalculationsStorage: UID = UID {
     ident: "IntraocularLensCalculationsStorage",
     uid: "1.2.840.10008.5.1.4.1.1.78.8",
     name: "Intraocular Lens Calculations Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Macular Grid Thickness and Volume Report Storage
@@ -2512,6 +2762,7 @@ pub static MacularGridThicknessandVolumeReportStorage: UID = UID {
     ident: "MacularGridThicknessandVolumeReportStorage",
     uid: "1.2.840.10008.5.1.4.1.1.79.1",
     name: "Macular Grid Thickness and Volume Report Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Ophthalmic Visual Field Static Perimetry Measurements Storage
@@ -2522,6 +2773,7 @@ pub static OphthalmicVisualFieldStaticPerimetryMeasurementsStorage: UID = UID {
     ident: "OphthalmicVisualFieldStaticPerimetryMeasurementsStorage",
     uid: "1.2.840.10008.5.1.4.1.1.80.1",
     name: "Ophthalmic Visual Field Static Perimetry Measurements Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Ophthalmic Thickness Map Storage
@@ -2532,6 +2784,7 @@ pub static OphthalmicThicknessMapStorage: UID = UID {
     ident: "OphthalmicThicknessMapStorage",
     uid: "1.2.840.10008.5.1.4.1.1.81.1",
     name: "Ophthalmic Thickness Map Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Corneal Topography Map Storage
@@ -2542,6 +2795,7 @@ pub static CornealTopographyMapStorage: UID = UID {
     ident: "CornealTopographyMapStorage",
     uid: "1.2.840.10008.5.1.4.1.1.82.1",
     name: "Corneal Topography Map Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Text SR Storage - Trial (Retired)
@@ -2552,6 +2806,7 @@ pub static TextSRStorageTrial: UID = UID {
     ident: "TextSRStorageTrial",
     uid: "1.2.840.10008.5.1.4.1.1.88.1",
     name: "Text SR Storage - Trial (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Audio SR Storage - Trial (Retired)
@@ -2562,6 +2817,7 @@ pub static AudioSRStorageTrial: UID = UID {
     ident: "AudioSRStorageTrial",
     uid: "1.2.840.10008.5.1.4.1.1.88.2",
     name: "Audio SR Storage - Trial (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Detail SR Storage - Trial (Retired)
@@ -2572,6 +2828,7 @@ pub static DetailSRStorageTrial: UID = UID {
     ident: "DetailSRStorageTrial",
     uid: "1.2.840.10008.5.1.4.1.1.88.3",
     name: "Detail SR Storage - Trial (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Comprehensive SR Storage - Trial (Retired)
@@ -2582,6 +2839,7 @@ pub static ComprehensiveSRStorageTrial: UID = UID {
     ident: "ComprehensiveSRStorageTrial",
     uid: "1.2.840.10008.5.1.4.1.1.88.4",
     name: "Comprehensive SR Storage - Trial (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Basic Text SR Storage
@@ -2592,6 +2850,7 @@ pub static BasicTextSRStorage: UID = UID {
     ident: "BasicTextSRStorage",
     uid: "1.2.840.10008.5.1.4.1.1.88.11",
     name: "Basic Text SR Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Enhanced SR Storage
@@ -2602,6 +2861,7 @@ pub static EnhancedSRStorage: UID = UID {
     ident: "EnhancedSRStorage",
     uid: "1.2.840.10008.5.1.4.1.1.88.22",
     name: "Enhanced SR Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Comprehensive SR Storage
@@ -2612,6 +2872,7 @@ pub static ComprehensiveSRStorage: UID = UID {
     ident: "ComprehensiveSRStorage",
     uid: "1.2.840.10008.5.1.4.1.1.88.33",
     name: "Comprehensive SR Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Comprehensive 3D SR Storage
@@ -2622,6 +2883,7 @@ pub static Comprehensive3DSRStorage: UID = UID {
     ident: "Comprehensive3DSRStorage",
     uid: "1.2.840.10008.5.1.4.1.1.88.34",
     name: "Comprehensive 3D SR Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Extensible SR Storage
@@ -2632,6 +2894,7 @@ pub static ExtensibleSRStorage: UID = UID {
     ident: "ExtensibleSRStorage",
     uid: "1.2.840.10008.5.1.4.1.1.88.35",
     name: "Extensible SR Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Procedure Log Storage
@@ -2642,6 +2905,7 @@ pub static ProcedureLogStorage: UID = UID {
     ident: "ProcedureLogStorage",
     uid: "1.2.840.10008.5.1.4.1.1.88.40",
     name: "Procedure Log Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Mammography CAD SR Storage
@@ -2652,6 +2916,7 @@ pub static MammographyCADSRStorage: UID = UID {
     ident: "MammographyCADSRStorage",
     uid: "1.2.840.10008.5.1.4.1.1.88.50",
     name: "Mammography CAD SR Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Key Object Selection Document Storage
@@ -2662,6 +2927,7 @@ pub static KeyObjectSelectionDocumentStorage: UID = UID {
     ident: "KeyObjectSelectionDocumentStorage",
     uid: "1.2.840.10008.5.1.4.1.1.88.59",
     name: "Key Object Selection Document Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Chest CAD SR Storage
@@ -2672,6 +2938,7 @@ pub static ChestCADSRStorage: UID = UID {
     ident: "ChestCADSRStorage",
     uid: "1.2.840.10008.5.1.4.1.1.88.65",
     name: "Chest CAD SR Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// X-Ray Radiation Dose SR Storage
@@ -2682,6 +2949,7 @@ pub static XRayRadiationDoseSRStorage: UID = UID {
     ident: "XRayRadiationDoseSRStorage",
     uid: "1.2.840.10008.5.1.4.1.1.88.67",
     name: "X-Ray Radiation Dose SR Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Radiopharmaceutical Radiation Dose SR Storage
@@ -2692,6 +2960,7 @@ pub static RadiopharmaceuticalRadiationDoseSRStorage: UID = UID {
     ident: "RadiopharmaceuticalRadiationDoseSRStorage",
     uid: "1.2.840.10008.5.1.4.1.1.88.68",
     name: "Radiopharmaceutical Radiation Dose SR Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Colon CAD SR Storage
@@ -2702,6 +2971,7 @@ pub static ColonCADSRStorage: UID = UID {
     ident: "ColonCADSRStorage",
     uid: "1.2.840.10008.5.1.4.1.1.88.69",
     name: "Colon CAD SR Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Implantation Plan SR Storage
@@ -2712,6 +2982,7 @@ pub static ImplantationPlanSRStorage: UID = UID {
     ident: "ImplantationPlanSRStorage",
     uid: "1.2.840.10008.5.1.4.1.1.88.70",
     name: "Implantation Plan SR Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Acquisition Context SR Storage
@@ -2722,6 +2993,7 @@ pub static AcquisitionContextSRStorage: UID = UID {
     ident: "AcquisitionContextSRStorage",
     uid: "1.2.840.10008.5.1.4.1.1.88.71",
     name: "Acquisition Context SR Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Simplified Adult Echo SR Storage
@@ -2732,6 +3004,7 @@ pub static SimplifiedAdultEchoSRStorage: UID = UID {
     ident: "SimplifiedAdultEchoSRStorage",
     uid: "1.2.840.10008.5.1.4.1.1.88.72",
     name: "Simplified Adult Echo SR Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Patient Radiation Dose SR Storage
@@ -2742,6 +3015,7 @@ pub static PatientRadiationDoseSRStorage: UID = UID {
     ident: "PatientRadiationDoseSRStorage",
     uid: "1.2.840.10008.5.1.4.1.1.88.73",
     name: "Patient Radiation Dose SR Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Planned Imaging Agent Administration SR Storage
@@ -2752,6 +3026,7 @@ pub static PlannedImagingAgentAdministrationSRStorage: UID = UID {
     ident: "PlannedImagingAgentAdministrationSRStorage",
     uid: "1.2.840.10008.5.1.4.1.1.88.74",
     name: "Planned Imaging Agent Administration SR Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Performed Imaging Agent Administration SR Storage
@@ -2762,6 +3037,7 @@ pub static PerformedImagingAgentAdministrationSRStorage: UID = UID {
     ident: "PerformedImagingAgentAdministrationSRStorage",
     uid: "1.2.840.10008.5.1.4.1.1.88.75",
     name: "Performed Imaging Agent Administration SR Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Enhanced X-Ray Radiation Dose SR Storage
@@ -2772,6 +3048,7 @@ pub static EnhancedXRayRadiationDoseSRStorage: UID = UID {
     ident: "EnhancedXRayRadiationDoseSRStorage",
     uid: "1.2.840.10008.5.1.4.1.1.88.76",
     name: "Enhanced X-Ray Radiation Dose SR Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::StructuredReportStorage),
 };
 
 /// Content Assessment Results Storage
@@ -2782,6 +3059,7 @@ pub static ContentAssessmentResultsStorage: UID = UID {
     ident: "ContentAssessmentResultsStorage",
     uid: "1.2.840.10008.5.1.4.1.1.90.1",
     name: "Content Assessment Results Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Microscopy Bulk Simple Annotations Storage
@@ -2792,6 +3070,7 @@ pub static MicroscopyBulkSimpleAnnotationsStorage: UID = UID {
     ident: "MicroscopyBulkSimpleAnnotationsStorage",
     uid: "1.2.840.10008.5.1.4.1.1.91.1",
     name: "Microscopy Bulk Simple Annotations Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Encapsulated PDF Storage
@@ -2802,6 +3081,7 @@ pub static EncapsulatedPDFStorage: UID = UID {
     ident: "EncapsulatedPDFStorage",
     uid: "1.2.840.10008.5.1.4.1.1.104.1",
     name: "Encapsulated PDF Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::EncapsulatedDocumentStorage),
 };
 
 /// Encapsulated CDA Storage
@@ -2812,6 +3092,7 @@ pub static EncapsulatedCDAStorage: UID = UID {
     ident: "EncapsulatedCDAStorage",
     uid: "1.2.840.10008.5.1.4.1.1.104.2",
     name: "Encapsulated CDA Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::EncapsulatedDocumentStorage),
 };
 
 /// Encapsulated STL Storage
@@ -2822,6 +3103,7 @@ pub static EncapsulatedSTLStorage: UID = UID {
     ident: "EncapsulatedSTLStorage",
     uid: "1.2.840.10008.5.1.4.1.1.104.3",
     name: "Encapsulated STL Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::EncapsulatedDocumentStorage),
 };
 
 /// Encapsulated OBJ Storage
@@ -2832,6 +3114,7 @@ pub static EncapsulatedOBJStorage: UID = UID {
     ident: "EncapsulatedOBJStorage",
     uid: "1.2.840.10008.5.1.4.1.1.104.4",
     name: "Encapsulated OBJ Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::EncapsulatedDocumentStorage),
 };
 
 /// Encapsulated MTL Storage
@@ -2842,6 +3125,7 @@ pub static EncapsulatedMTLStorage: UID = UID {
     ident: "EncapsulatedMTLStorage",
     uid: "1.2.840.10008.5.1.4.1.1.104.5",
     name: "Encapsulated MTL Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::EncapsulatedDocumentStorage),
 };
 
 /// Positron Emission Tomography Image Storage
@@ -2852,6 +3136,7 @@ pub static PositronEmissionTomographyImageStorage: UID = UID {
     ident: "PositronEmissionTomographyImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.128",
     name: "Positron Emission Tomography Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Legacy Converted Enhanced PET Image Storage
@@ -2862,6 +3147,7 @@ pub static LegacyConvertedEnhancedPETImageStorage: UID = UID {
     ident: "LegacyConvertedEnhancedPETImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.128.1",
     name: "Legacy Converted Enhanced PET Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Standalone PET Curve Storage (Retired)
@@ -2872,6 +3158,7 @@ pub static StandalonePETCurveStorage: UID = UID {
     ident: "StandalonePETCurveStorage",
     uid: "1.2.840.10008.5.1.4.1.1.129",
     name: "Standalone PET Curve Storage (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Enhanced PET Image Storage
@@ -2882,6 +3169,7 @@ pub static EnhancedPETImageStorage: UID = UID {
     ident: "EnhancedPETImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.130",
     name: "Enhanced PET Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Basic Structured Display Storage
@@ -2892,6 +3180,7 @@ pub static BasicStructuredDisplayStorage: UID = UID {
     ident: "BasicStructuredDisplayStorage",
     uid: "1.2.840.10008.5.1.4.1.1.131",
     name: "Basic Structured Display Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// CT Defined Procedure Protocol Storage
@@ -2902,6 +3191,7 @@ pub static CTDefinedProcedureProtocolStorage: UID = UID {
     ident: "CTDefinedProcedureProtocolStorage",
     uid: "1.2.840.10008.5.1.4.1.1.200.1",
     name: "CT Defined Procedure Protocol Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// CT Performed Procedure Protocol Storage
@@ -2912,6 +3202,7 @@ pub static CTPerformedProcedureProtocolStorage: UID = UID {
     ident: "CTPerformedProcedureProtocolStorage",
     uid: "1.2.840.10008.5.1.4.1.1.200.2",
     name: "CT Performed Procedure Protocol Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Protocol Approval Storage
@@ -2922,6 +3213,7 @@ pub static ProtocolApprovalStorage: UID = UID {
     ident: "ProtocolApprovalStorage",
     uid: "1.2.840.10008.5.1.4.1.1.200.3",
     name: "Protocol Approval Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Protocol Approval Information Model - FIND
@@ -2932,6 +3224,7 @@ pub static ProtocolApprovalInformationModelFIND: UID = UID {
     ident: "ProtocolApprovalInformationModelFIND",
     uid: "1.2.840.10008.5.1.4.1.1.200.4",
     name: "Protocol Approval Information Model - FIND",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Protocol Approval Information Model - MOVE
@@ -2942,6 +3235,7 @@ pub static ProtocolApprovalInformationModelMOVE: UID = UID {
     ident: "ProtocolApprovalInformationModelMOVE",
     uid: "1.2.840.10008.5.1.4.1.1.200.5",
     name: "Protocol Approval Information Model - MOVE",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Protocol Approval Information Model - GET
@@ -2952,6 +3246,7 @@ pub static ProtocolApprovalInformationModelGET: UID = UID {
     ident: "ProtocolApprovalInformationModelGET",
     uid: "1.2.840.10008.5.1.4.1.1.200.6",
     name: "Protocol Approval Information Model - GET",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// XA Defined Procedure Protocol Storage
@@ -2962,6 +3257,7 @@ pub static XADefinedProcedureProtocolStorage: UID = UID {
     ident: "XADefinedProcedureProtocolStorage",
     uid: "1.2.840.10008.5.1.4.1.1.200.7",
     name: "XA Defined Procedure Protocol Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// XA Performed Procedure Protocol Storage
@@ -2972,6 +3268,7 @@ pub static XAPerformedProcedureProtocolStorage: UID = UID {
     ident: "XAPerformedProcedureProtocolStorage",
     uid: "1.2.840.10008.5.1.4.1.1.200.8",
     name: "XA Performed Procedure Protocol Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Inventory Storage
@@ -2982,6 +3279,7 @@ pub static InventoryStorage: UID = UID {
     ident: "InventoryStorage",
     uid: "1.2.840.10008.5.1.4.1.1.201.1",
     name: "Inventory Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Inventory - FIND
@@ -2992,6 +3290,7 @@ pub static InventoryFIND: UID = UID {
     ident: "InventoryFIND",
     uid: "1.2.840.10008.5.1.4.1.1.201.2",
     name: "Inventory - FIND",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Inventory - MOVE
@@ -3002,6 +3301,7 @@ pub static InventoryMOVE: UID = UID {
     ident: "InventoryMOVE",
     uid: "1.2.840.10008.5.1.4.1.1.201.3",
     name: "Inventory - MOVE",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Inventory - GET
@@ -3012,6 +3312,7 @@ pub static InventoryGET: UID = UID {
     ident: "InventoryGET",
     uid: "1.2.840.10008.5.1.4.1.1.201.4",
     name: "Inventory - GET",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Inventory Creation
@@ -3022,6 +3323,7 @@ pub static InventoryCreation: UID = UID {
     ident: "InventoryCreation",
     uid: "1.2.840.10008.5.1.4.1.1.201.5",
     name: "Inventory Creation",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Repository Query
@@ -3032,6 +3334,7 @@ pub static RepositoryQuery: UID = UID {
     ident: "RepositoryQuery",
     uid: "1.2.840.10008.5.1.4.1.1.201.6",
     name: "Repository Query",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Storage Management SOP Instance
@@ -3042,6 +3345,7 @@ pub static StorageManagementSOPInstance: UID = UID {
     ident: "StorageManagementSOPInstance",
     uid: "1.2.840.10008.5.1.4.1.1.201.1.1",
     name: "Storage Management SOP Instance",
+    uid_type: UIDType::WellKnownSOPInstance,
 };
 
 /// RT Image Storage
@@ -3052,6 +3356,7 @@ pub static RTImageStorage: UID = UID {
     ident: "RTImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.1",
     name: "RT Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// RT Dose Storage
@@ -3062,6 +3367,7 @@ pub static RTDoseStorage: UID = UID {
     ident: "RTDoseStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.2",
     name: "RT Dose Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// RT Structure Set Storage
@@ -3072,6 +3378,7 @@ pub static RTStructureSetStorage: UID = UID {
     ident: "RTStructureSetStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.3",
     name: "RT Structure Set Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// RT Beams Treatment Record Storage
@@ -3082,6 +3389,7 @@ pub static RTBeamsTreatmentRecordStorage: UID = UID {
     ident: "RTBeamsTreatmentRecordStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.4",
     name: "RT Beams Treatment Record Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// RT Plan Storage
@@ -3092,6 +3400,7 @@ pub static RTPlanStorage: UID = UID {
     ident: "RTPlanStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.5",
     name: "RT Plan Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// RT Brachy Treatment Record Storage
@@ -3102,6 +3411,7 @@ pub static RTBrachyTreatmentRecordStorage: UID = UID {
     ident: "RTBrachyTreatmentRecordStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.6",
     name: "RT Brachy Treatment Record Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// RT Treatment Summary Record Storage
@@ -3112,6 +3422,7 @@ pub static RTTreatmentSummaryRecordStorage: UID = UID {
     ident: "RTTreatmentSummaryRecordStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.7",
     name: "RT Treatment Summary Record Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// RT Ion Plan Storage
@@ -3122,6 +3433,7 @@ pub static RTIonPlanStorage: UID = UID {
     ident: "RTIonPlanStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.8",
     name: "RT Ion Plan Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// RT Ion Beams Treatment Record Storage
@@ -3132,6 +3444,7 @@ pub static RTIonBeamsTreatmentRecordStorage: UID = UID {
     ident: "RTIonBeamsTreatmentRecordStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.9",
     name: "RT Ion Beams Treatment Record Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// RT Physician Intent Storage
@@ -3142,6 +3455,7 @@ pub static RTPhysicianIntentStorage: UID = UID {
     ident: "RTPhysicianIntentStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.10",
     name: "RT Physician Intent Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// RT Segment Annotation Storage
@@ -3152,6 +3466,7 @@ pub static RTSegmentAnnotationStorage: UID = UID {
     ident: "RTSegmentAnnotationStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.11",
     name: "RT Segment Annotation Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// RT Radiation Set Storage
@@ -3162,6 +3477,7 @@ pub static RTRadiationSetStorage: UID = UID {
     ident: "RTRadiationSetStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.12",
     name: "RT Radiation Set Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// C-Arm Photon-Electron Radiation Storage
@@ -3172,6 +3488,7 @@ pub static CArmPhotonElectronRadiationStorage: UID = UID {
     ident: "CArmPhotonElectronRadiationStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.13",
     name: "C-Arm Photon-Electron Radiation Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Tomotherapeutic Radiation Storage
@@ -3182,6 +3499,7 @@ pub static TomotherapeuticRadiationStorage: UID = UID {
     ident: "TomotherapeuticRadiationStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.14",
     name: "Tomotherapeutic Radiation Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Robotic-Arm Radiation Storage
@@ -3192,6 +3510,7 @@ pub static RoboticArmRadiationStorage: UID = UID {
     ident: "RoboticArmRadiationStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.15",
     name: "Robotic-Arm Radiation Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// RT Radiation Record Set Storage
@@ -3202,6 +3521,7 @@ pub static RTRadiationRecordSetStorage: UID = UID {
     ident: "RTRadiationRecordSetStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.16",
     name: "RT Radiation Record Set Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// RT Radiation Salvage Record Storage
@@ -3212,6 +3532,7 @@ pub static RTRadiationSalvageRecordStorage: UID = UID {
     ident: "RTRadiationSalvageRecordStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.17",
     name: "RT Radiation Salvage Record Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Tomotherapeutic Radiation Record Storage
@@ -3222,6 +3543,7 @@ pub static TomotherapeuticRadiationRecordStorage: UID = UID {
     ident: "TomotherapeuticRadiationRecordStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.18",
     name: "Tomotherapeutic Radiation Record Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// C-Arm Photon-Electron Radiation Record Storage
@@ -3232,6 +3554,7 @@ pub static CArmPhotonElectronRadiationRecordStorage: UID = UID {
     ident: "CArmPhotonElectronRadiationRecordStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.19",
     name: "C-Arm Photon-Electron Radiation Record Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Robotic Radiation Record Storage
@@ -3242,6 +3565,7 @@ pub static RoboticRadiationRecordStorage: UID = UID {
     ident: "RoboticRadiationRecordStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.20",
     name: "Robotic Radiation Record Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// RT Radiation Set Delivery Instruction Storage
@@ -3252,6 +3576,7 @@ pub static RTRadiationSetDeliveryInstructionStorage: UID = UID {
     ident: "RTRadiationSetDeliveryInstructionStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.21",
     name: "RT Radiation Set Delivery Instruction Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// RT Treatment Preparation Storage
@@ -3262,6 +3587,7 @@ pub static RTTreatmentPreparationStorage: UID = UID {
     ident: "RTTreatmentPreparationStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.22",
     name: "RT Treatment Preparation Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Enhanced RT Image Storage
@@ -3272,6 +3598,7 @@ pub static EnhancedRTImageStorage: UID = UID {
     ident: "EnhancedRTImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.23",
     name: "Enhanced RT Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Enhanced Continuous RT Image Storage
@@ -3282,6 +3609,7 @@ pub static EnhancedContinuousRTImageStorage: UID = UID {
     ident: "EnhancedContinuousRTImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.24",
     name: "Enhanced Continuous RT Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// RT Patient Position Acquisition Instruction Storage
@@ -3292,6 +3620,7 @@ pub static RTPatientPositionAcquisitionInstructionStorage: UID = UID {
     ident: "RTPatientPositionAcquisitionInstructionStorage",
     uid: "1.2.840.10008.5.1.4.1.1.481.25",
     name: "RT Patient Position Acquisition Instruction Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// DICOS CT Image Storage
@@ -3302,6 +3631,7 @@ pub static DICOSCTImageStorage: UID = UID {
     ident: "DICOSCTImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.501.1",
     name: "DICOS CT Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// DICOS Digital X-Ray Image Storage - For Presentation
@@ -3312,6 +3642,7 @@ pub static DICOSDigitalXRayImageStorageForPresentation: UID = UID {
     ident: "DICOSDigitalXRayImageStorageForPresentation",
     uid: "1.2.840.10008.5.1.4.1.1.501.2.1",
     name: "DICOS Digital X-Ray Image Storage - For Presentation",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// DICOS Digital X-Ray Image Storage - For Processing
@@ -3322,6 +3653,7 @@ pub static DICOSDigitalXRayImageStorageForProcessing: UID = UID {
     ident: "DICOSDigitalXRayImageStorageForProcessing",
     uid: "1.2.840.10008.5.1.4.1.1.501.2.2",
     name: "DICOS Digital X-Ray Image Storage - For Processing",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// DICOS Threat Detection Report Storage
@@ -3332,6 +3664,7 @@ pub static DICOSThreatDetectionReportStorage: UID = UID {
     ident: "DICOSThreatDetectionReportStorage",
     uid: "1.2.840.10008.5.1.4.1.1.501.3",
     name: "DICOS Threat Detection Report Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// DICOS 2D AIT Storage
@@ -3342,6 +3675,7 @@ pub static DICOS2DAITStorage: UID = UID {
     ident: "DICOS2DAITStorage",
     uid: "1.2.840.10008.5.1.4.1.1.501.4",
     name: "DICOS 2D AIT Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// DICOS 3D AIT Storage
@@ -3352,6 +3686,7 @@ pub static DICOS3DAITStorage: UID = UID {
     ident: "DICOS3DAITStorage",
     uid: "1.2.840.10008.5.1.4.1.1.501.5",
     name: "DICOS 3D AIT Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// DICOS Quadrupole Resonance (QR) Storage
@@ -3362,6 +3697,7 @@ pub static DICOSQuadrupoleResonanceQRStorage: UID = UID {
     ident: "DICOSQuadrupoleResonanceQRStorage",
     uid: "1.2.840.10008.5.1.4.1.1.501.6",
     name: "DICOS Quadrupole Resonance (QR) Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Eddy Current Image Storage
@@ -3372,6 +3708,7 @@ pub static EddyCurrentImageStorage: UID = UID {
     ident: "EddyCurrentImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.601.1",
     name: "Eddy Current Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Eddy Current Multi-frame Image Storage
@@ -3382,6 +3719,7 @@ pub static EddyCurrentMultiframeImageStorage: UID = UID {
     ident: "EddyCurrentMultiframeImageStorage",
     uid: "1.2.840.10008.5.1.4.1.1.601.2",
     name: "Eddy Current Multi-frame Image Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::ImageStorage),
 };
 
 /// Patient Root Query/Retrieve Information Model - FIND
@@ -3392,6 +3730,7 @@ pub static PatientRootQueryRetrieveInformationModelFIND: UID = UID {
     ident: "PatientRootQueryRetrieveInformationModelFIND",
     uid: "1.2.840.10008.5.1.4.1.2.1.1",
     name: "Patient Root Query/Retrieve Information Model - FIND",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Patient Root Query/Retrieve Information Model - MOVE
@@ -3402,6 +3741,7 @@ pub static PatientRootQueryRetrieveInformationModelMOVE: UID = UID {
     ident: "PatientRootQueryRetrieveInformationModelMOVE",
     uid: "1.2.840.10008.5.1.4.1.2.1.2",
     name: "Patient Root Query/Retrieve Information Model - MOVE",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Patient Root Query/Retrieve Information Model - GET
@@ -3412,6 +3752,7 @@ pub static PatientRootQueryRetrieveInformationModelGET: UID = UID {
     ident: "PatientRootQueryRetrieveInformationModelGET",
     uid: "1.2.840.10008.5.1.4.1.2.1.3",
     name: "Patient Root Query/Retrieve Information Model - GET",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Study Root Query/Retrieve Information Model - FIND
@@ -3422,6 +3763,7 @@ pub static StudyRootQueryRetrieveInformationModelFIND: UID = UID {
     ident: "StudyRootQueryRetrieveInformationModelFIND",
     uid: "1.2.840.10008.5.1.4.1.2.2.1",
     name: "Study Root Query/Retrieve Information Model - FIND",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Study Root Query/Retrieve Information Model - MOVE
@@ -3432,6 +3774,7 @@ pub static StudyRootQueryRetrieveInformationModelMOVE: UID = UID {
     ident: "StudyRootQueryRetrieveInformationModelMOVE",
     uid: "1.2.840.10008.5.1.4.1.2.2.2",
     name: "Study Root Query/Retrieve Information Model - MOVE",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Study Root Query/Retrieve Information Model - GET
@@ -3442,6 +3785,7 @@ pub static StudyRootQueryRetrieveInformationModelGET: UID = UID {
     ident: "StudyRootQueryRetrieveInformationModelGET",
     uid: "1.2.840.10008.5.1.4.1.2.2.3",
     name: "Study Root Query/Retrieve Information Model - GET",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Patient/Study Only Query/Retrieve Information Model - FIND (Retired)
@@ -3452,6 +3796,7 @@ pub static PatientStudyOnlyQueryRetrieveInformationModelFIND: UID = UID {
     ident: "PatientStudyOnlyQueryRetrieveInformationModelFIND",
     uid: "1.2.840.10008.5.1.4.1.2.3.1",
     name: "Patient/Study Only Query/Retrieve Information Model - FIND (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Patient/Study Only Query/Retrieve Information Model - MOVE (Retired)
@@ -3462,6 +3807,7 @@ pub static PatientStudyOnlyQueryRetrieveInformationModelMOVE: UID = UID {
     ident: "PatientStudyOnlyQueryRetrieveInformationModelMOVE",
     uid: "1.2.840.10008.5.1.4.1.2.3.2",
     name: "Patient/Study Only Query/Retrieve Information Model - MOVE (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Patient/Study Only Query/Retrieve Information Model - GET (Retired)
@@ -3472,6 +3818,7 @@ pub static PatientStudyOnlyQueryRetrieveInformationModelGET: UID = UID {
     ident: "PatientStudyOnlyQueryRetrieveInformationModelGET",
     uid: "1.2.840.10008.5.1.4.1.2.3.3",
     name: "Patient/Study Only Query/Retrieve Information Model - GET (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Composite Instance Root Retrieve - MOVE
@@ -3482,6 +3829,7 @@ pub static CompositeInstanceRootRetrieveMOVE: UID = UID {
     ident: "CompositeInstanceRootRetrieveMOVE",
     uid: "1.2.840.10008.5.1.4.1.2.4.2",
     name: "Composite Instance Root Retrieve - MOVE",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Composite Instance Root Retrieve - GET
@@ -3492,6 +3840,7 @@ pub static CompositeInstanceRootRetrieveGET: UID = UID {
     ident: "CompositeInstanceRootRetrieveGET",
     uid: "1.2.840.10008.5.1.4.1.2.4.3",
     name: "Composite Instance Root Retrieve - GET",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Composite Instance Retrieve Without Bulk Data - GET
@@ -3502,6 +3851,7 @@ pub static CompositeInstanceRetrieveWithoutBulkDataGET: UID = UID {
     ident: "CompositeInstanceRetrieveWithoutBulkDataGET",
     uid: "1.2.840.10008.5.1.4.1.2.5.3",
     name: "Composite Instance Retrieve Without Bulk Data - GET",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Defined Procedure Protocol Information Model - FIND
@@ -3512,6 +3862,7 @@ pub static DefinedProcedureProtocolInformationModelFIND: UID = UID {
     ident: "DefinedProcedureProtocolInformationModelFIND",
     uid: "1.2.840.10008.5.1.4.20.1",
     name: "Defined Procedure Protocol Information Model - FIND",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Defined Procedure Protocol Information Model - MOVE
@@ -3522,6 +3873,7 @@ pub static DefinedProcedureProtocolInformationModelMOVE: UID = UID {
     ident: "DefinedProcedureProtocolInformationModelMOVE",
     uid: "1.2.840.10008.5.1.4.20.2",
     name: "Defined Procedure Protocol Information Model - MOVE",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Defined Procedure Protocol Information Model - GET
@@ -3532,6 +3884,7 @@ pub static DefinedProcedureProtocolInformationModelGET: UID = UID {
     ident: "DefinedProcedureProtocolInformationModelGET",
     uid: "1.2.840.10008.5.1.4.20.3",
     name: "Defined Procedure Protocol Information Model - GET",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Modality Worklist Information Model - FIND
@@ -3542,6 +3895,7 @@ pub static ModalityWorklistInformationModelFIND: UID = UID {
     ident: "ModalityWorklistInformationModelFIND",
     uid: "1.2.840.10008.5.1.4.31",
     name: "Modality Worklist Information Model - FIND",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// General Purpose Worklist Management Meta SOP Class (Retired)
@@ -3552,6 +3906,7 @@ pub static GeneralPurposeWorklistManagementMetaSOPClass: UID = UID {
     ident: "GeneralPurposeWorklistManagementMetaSOPClass",
     uid: "1.2.840.10008.5.1.4.32",
     name: "General Purpose Worklist Management Meta SOP Class (Retired)",
+    uid_type: UIDType::MetaSOPClass,
 };
 
 /// General Purpose Worklist Information Model - FIND (Retired)
@@ -3562,6 +3917,7 @@ pub static GeneralPurposeWorklistInformationModelFIND: UID = UID {
     ident: "GeneralPurposeWorklistInformationModelFIND",
     uid: "1.2.840.10008.5.1.4.32.1",
     name: "General Purpose Worklist Information Model - FIND (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// General Purpose Scheduled Procedure Step SOP Class (Retired)
@@ -3572,6 +3928,7 @@ pub static GeneralPurposeScheduledProcedureStepSOPClass: UID = UID {
     ident: "GeneralPurposeScheduledProcedureStepSOPClass",
     uid: "1.2.840.10008.5.1.4.32.2",
     name: "General Purpose Scheduled Procedure Step SOP Class (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// General Purpose Performed Procedure Step SOP Class (Retired)
@@ -3582,6 +3939,7 @@ pub static GeneralPurposePerformedProcedureStepSOPClass: UID = UID {
     ident: "GeneralPurposePerformedProcedureStepSOPClass",
     uid: "1.2.840.10008.5.1.4.32.3",
     name: "General Purpose Performed Procedure Step SOP Class (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Instance Availability Notification SOP Class
@@ -3592,6 +3950,7 @@ pub static InstanceAvailabilityNotificationSOPClass: UID = UID {
     ident: "InstanceAvailabilityNotificationSOPClass",
     uid: "1.2.840.10008.5.1.4.33",
     name: "Instance Availability Notification SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// RT Beams Delivery Instruction Storage - Trial (Retired)
@@ -3602,6 +3961,7 @@ pub static RTBeamsDeliveryInstructionStorageTrial: UID = UID {
     ident: "RTBeamsDeliveryInstructionStorageTrial",
     uid: "1.2.840.10008.5.1.4.34.1",
     name: "RT Beams Delivery Instruction Storage - Trial (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// RT Conventional Machine Verification - Trial (Retired)
@@ -3612,6 +3972,7 @@ pub static RTConventionalMachineVerificationTrial: UID = UID {
     ident: "RTConventionalMachineVerificationTrial",
     uid: "1.2.840.10008.5.1.4.34.2",
     name: "RT Conventional Machine Verification - Trial (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// RT Ion Machine Verification - Trial (Retired)
@@ -3622,6 +3983,7 @@ pub static RTIonMachineVerificationTrial: UID = UID {
     ident: "RTIonMachineVerificationTrial",
     uid: "1.2.840.10008.5.1.4.34.3",
     name: "RT Ion Machine Verification - Trial (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Unified Worklist and Procedure Step Service Class - Trial (Retired)
@@ -3632,6 +3994,7 @@ pub static UnifiedWorklistandProcedureStepServiceClassTrial: UID = UID {
     ident: "UnifiedWorklistandProcedureStepServiceClassTrial",
     uid: "1.2.840.10008.5.1.4.34.4",
     name: "Unified Worklist and Procedure Step Service Class - Trial (Retired)",
+    uid_type: UIDType::ServiceClass,
 };
 
 /// Unified Procedure Step - Push SOP Class - Trial (Retired)
@@ -3642,6 +4005,7 @@ pub static UnifiedProcedureStepPushSOPClassTrial: UID = UID {
     ident: "UnifiedProcedureStepPushSOPClassTrial",
     uid: "1.2.840.10008.5.1.4.34.4.1",
     name: "Unified Procedure Step - Push SOP Class - Trial (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Unified Procedure Step - Watch SOP Class - Trial (Retired)
@@ -3652,6 +4016,7 @@ pub static UnifiedProcedureStepWatchSOPClassTrial: UID = UID {
     ident: "UnifiedProcedureStepWatchSOPClassTrial",
     uid: "1.2.840.10008.5.1.4.34.4.2",
     name: "Unified Procedure Step - Watch SOP Class - Trial (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Unified Procedure Step - Pull SOP Class - Trial (Retired)
@@ -3662,6 +4027,7 @@ pub static UnifiedProcedureStepPullSOPClassTrial: UID = UID {
     ident: "UnifiedProcedureStepPullSOPClassTrial",
     uid: "1.2.840.10008.5.1.4.34.4.3",
     name: "Unified Procedure Step - Pull SOP Class - Trial (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Unified Procedure Step - Event SOP Class - Trial (Retired)
@@ -3672,6 +4038,7 @@ pub static UnifiedProcedureStepEventSOPClassTrial: UID = UID {
     ident: "UnifiedProcedureStepEventSOPClassTrial",
     uid: "1.2.840.10008.5.1.4.34.4.4",
     name: "Unified Procedure Step - Event SOP Class - Trial (Retired)",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// UPS Global Subscription SOP Instance
@@ -3682,6 +4049,7 @@ pub static UPSGlobalSubscriptionSOPInstance: UID = UID {
     ident: "UPSGlobalSubscriptionSOPInstance",
     uid: "1.2.840.10008.5.1.4.34.5",
     name: "UPS Global Subscription SOP Instance",
+    uid_type: UIDType::WellKnownSOPInstance,
 };
 
 /// UPS Filtered Global Subscription SOP Instance
@@ -3692,6 +4060,7 @@ pub static UPSFilteredGlobalSubscriptionSOPInstance: UID = UID {
     ident: "UPSFilteredGlobalSubscriptionSOPInstance",
     uid: "1.2.840.10008.5.1.4.34.5.1",
     name: "UPS Filtered Global Subscription SOP Instance",
+    uid_type: UIDType::WellKnownSOPInstance,
 };
 
 /// Unified Worklist and Procedure Step Service Class
@@ -3702,6 +4071,7 @@ pub static UnifiedWorklistandProcedureStepServiceClass: UID = UID {
     ident: "UnifiedWorklistandProcedureStepServiceClass",
     uid: "1.2.840.10008.5.1.4.34.6",
     name: "Unified Worklist and Procedure Step Service Class",
+    uid_type: UIDType::ServiceClass,
 };
 
 /// Unified Procedure Step - Push SOP Class
@@ -3712,6 +4082,7 @@ pub static UnifiedProcedureStepPushSOPClass: UID = UID {
     ident: "UnifiedProcedureStepPushSOPClass",
     uid: "1.2.840.10008.5.1.4.34.6.1",
     name: "Unified Procedure Step - Push SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Unified Procedure Step - Watch SOP Class
@@ -3722,6 +4093,7 @@ pub static UnifiedProcedureStepWatchSOPClass: UID = UID {
     ident: "UnifiedProcedureStepWatchSOPClass",
     uid: "1.2.840.10008.5.1.4.34.6.2",
     name: "Unified Procedure Step - Watch SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Unified Procedure Step - Pull SOP Class
@@ -3732,6 +4104,7 @@ pub static UnifiedProcedureStepPullSOPClass: UID = UID {
     ident: "UnifiedProcedureStepPullSOPClass",
     uid: "1.2.840.10008.5.1.4.34.6.3",
     name: "Unified Procedure Step - Pull SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Unified Procedure Step - Event SOP Class
@@ -3742,6 +4115,7 @@ pub static UnifiedProcedureStepEventSOPClass: UID = UID {
     ident: "UnifiedProcedureStepEventSOPClass",
     uid: "1.2.840.10008.5.1.4.34.6.4",
     name: "Unified Procedure Step - Event SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Unified Procedure Step - Query SOP Class
@@ -3752,6 +4126,7 @@ pub static UnifiedProcedureStepQuerySOPClass: UID = UID {
     ident: "UnifiedProcedureStepQuerySOPClass",
     uid: "1.2.840.10008.5.1.4.34.6.5",
     name: "Unified Procedure Step - Query SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// RT Beams Delivery Instruction Storage
@@ -3762,6 +4137,7 @@ pub static RTBeamsDeliveryInstructionStorage: UID = UID {
     ident: "RTBeamsDeliveryInstructionStorage",
     uid: "1.2.840.10008.5.1.4.34.7",
     name: "RT Beams Delivery Instruction Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// RT Conventional Machine Verification
@@ -3772,6 +4148,7 @@ pub static RTConventionalMachineVerification: UID = UID {
     ident: "RTConventionalMachineVerification",
     uid: "1.2.840.10008.5.1.4.34.8",
     name: "RT Conventional Machine Verification",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// RT Ion Machine Verification
@@ -3782,6 +4159,7 @@ pub static RTIonMachineVerification: UID = UID {
     ident: "RTIonMachineVerification",
     uid: "1.2.840.10008.5.1.4.34.9",
     name: "RT Ion Machine Verification",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// RT Brachy Application Setup Delivery Instruction Storage
@@ -3792,6 +4170,7 @@ pub static RTBrachyApplicationSetupDeliveryInstructionStorage: UID = UID {
     ident: "RTBrachyApplicationSetupDeliveryInstructionStorage",
     uid: "1.2.840.10008.5.1.4.34.10",
     name: "RT Brachy Application Setup Delivery Instruction Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// General Relevant Patient Information Query
@@ -3802,6 +4181,7 @@ pub static GeneralRelevantPatientInformationQuery: UID = UID {
     ident: "GeneralRelevantPatientInformationQuery",
     uid: "1.2.840.10008.5.1.4.37.1",
     name: "General Relevant Patient Information Query",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Breast Imaging Relevant Patient Information Query
@@ -3812,6 +4192,7 @@ pub static BreastImagingRelevantPatientInformationQuery: UID = UID {
     ident: "BreastImagingRelevantPatientInformationQuery",
     uid: "1.2.840.10008.5.1.4.37.2",
     name: "Breast Imaging Relevant Patient Information Query",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Cardiac Relevant Patient Information Query
@@ -3822,6 +4203,7 @@ pub static CardiacRelevantPatientInformationQuery: UID = UID {
     ident: "CardiacRelevantPatientInformationQuery",
     uid: "1.2.840.10008.5.1.4.37.3",
     name: "Cardiac Relevant Patient Information Query",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Hanging Protocol Storage
@@ -3832,6 +4214,7 @@ pub static HangingProtocolStorage: UID = UID {
     ident: "HangingProtocolStorage",
     uid: "1.2.840.10008.5.1.4.38.1",
     name: "Hanging Protocol Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Hanging Protocol Information Model - FIND
@@ -3842,6 +4225,7 @@ pub static HangingProtocolInformationModelFIND: UID = UID {
     ident: "HangingProtocolInformationModelFIND",
     uid: "1.2.840.10008.5.1.4.38.2",
     name: "Hanging Protocol Information Model - FIND",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Hanging Protocol Information Model - MOVE
@@ -3852,6 +4236,7 @@ pub static HangingProtocolInformationModelMOVE: UID = UID {
     ident: "HangingProtocolInformationModelMOVE",
     uid: "1.2.840.10008.5.1.4.38.3",
     name: "Hanging Protocol Information Model - MOVE",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Hanging Protocol Information Model - GET
@@ -3862,6 +4247,7 @@ pub static HangingProtocolInformationModelGET: UID = UID {
     ident: "HangingProtocolInformationModelGET",
     uid: "1.2.840.10008.5.1.4.38.4",
     name: "Hanging Protocol Information Model - GET",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Color Palette Storage
@@ -3872,6 +4258,7 @@ pub static ColorPaletteStorage: UID = UID {
     ident: "ColorPaletteStorage",
     uid: "1.2.840.10008.5.1.4.39.1",
     name: "Color Palette Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Color Palette Query/Retrieve Information Model - FIND
@@ -3882,6 +4269,7 @@ pub static ColorPaletteQueryRetrieveInformationModelFIND: UID = UID {
     ident: "ColorPaletteQueryRetrieveInformationModelFIND",
     uid: "1.2.840.10008.5.1.4.39.2",
     name: "Color Palette Query/Retrieve Information Model - FIND",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Color Palette Query/Retrieve Information Model - MOVE
@@ -3892,6 +4280,7 @@ pub static ColorPaletteQueryRetrieveInformationModelMOVE: UID = UID {
     ident: "ColorPaletteQueryRetrieveInformationModelMOVE",
     uid: "1.2.840.10008.5.1.4.39.3",
     name: "Color Palette Query/Retrieve Information Model - MOVE",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Color Palette Query/Retrieve Information Model - GET
@@ -3902,6 +4291,7 @@ pub static ColorPaletteQueryRetrieveInformationModelGET: UID = UID {
     ident: "ColorPaletteQueryRetrieveInformationModelGET",
     uid: "1.2.840.10008.5.1.4.39.4",
     name: "Color Palette Query/Retrieve Information Model - GET",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Product Characteristics Query SOP Class
@@ -3912,6 +4302,7 @@ pub static ProductCharacteristicsQuerySOPClass: UID = UID {
     ident: "ProductCharacteristicsQuerySOPClass",
     uid: "1.2.840.10008.5.1.4.41",
     name: "Product Characteristics Query SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Substance Approval Query SOP Class
@@ -3922,6 +4313,7 @@ pub static SubstanceApprovalQuerySOPClass: UID = UID {
     ident: "SubstanceApprovalQuerySOPClass",
     uid: "1.2.840.10008.5.1.4.42",
     name: "Substance Approval Query SOP Class",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Generic Implant Template Storage
@@ -3932,6 +4324,7 @@ pub static GenericImplantTemplateStorage: UID = UID {
     ident: "GenericImplantTemplateStorage",
     uid: "1.2.840.10008.5.1.4.43.1",
     name: "Generic Implant Template Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Generic Implant Template Information Model - FIND
@@ -3942,6 +4335,7 @@ pub static GenericImplantTemplateInformationModelFIND: UID = UID {
     ident: "GenericImplantTemplateInformationModelFIND",
     uid: "1.2.840.10008.5.1.4.43.2",
     name: "Generic Implant Template Information Model - FIND",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Generic Implant Template Information Model - MOVE
@@ -3952,6 +4346,7 @@ pub static GenericImplantTemplateInformationModelMOVE: UID = UID {
     ident: "GenericImplantTemplateInformationModelMOVE",
     uid: "1.2.840.10008.5.1.4.43.3",
     name: "Generic Implant Template Information Model - MOVE",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Generic Implant Template Information Model - GET
@@ -3962,6 +4357,7 @@ pub static GenericImplantTemplateInformationModelGET: UID = UID {
     ident: "GenericImplantTemplateInformationModelGET",
     uid: "1.2.840.10008.5.1.4.43.4",
     name: "Generic Implant Template Information Model - GET",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Implant Assembly Template Storage
@@ -3972,6 +4368,7 @@ pub static ImplantAssemblyTemplateStorage: UID = UID {
     ident: "ImplantAssemblyTemplateStorage",
     uid: "1.2.840.10008.5.1.4.44.1",
     name: "Implant Assembly Template Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Implant Assembly Template Information Model - FIND
@@ -3982,6 +4379,7 @@ pub static ImplantAssemblyTemplateInformationModelFIND: UID = UID {
     ident: "ImplantAssemblyTemplateInformationModelFIND",
     uid: "1.2.840.10008.5.1.4.44.2",
     name: "Implant Assembly Template Information Model - FIND",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Implant Assembly Template Information Model - MOVE
@@ -3992,6 +4390,7 @@ pub static ImplantAssemblyTemplateInformationModelMOVE: UID = UID {
     ident: "ImplantAssemblyTemplateInformationModelMOVE",
     uid: "1.2.840.10008.5.1.4.44.3",
     name: "Implant Assembly Template Information Model - MOVE",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Implant Assembly Template Information Model - GET
@@ -4002,6 +4401,7 @@ pub static ImplantAssemblyTemplateInformationModelGET: UID = UID {
     ident: "ImplantAssemblyTemplateInformationModelGET",
     uid: "1.2.840.10008.5.1.4.44.4",
     name: "Implant Assembly Template Information Model - GET",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Implant Template Group Storage
@@ -4012,6 +4412,7 @@ pub static ImplantTemplateGroupStorage: UID = UID {
     ident: "ImplantTemplateGroupStorage",
     uid: "1.2.840.10008.5.1.4.45.1",
     name: "Implant Template Group Storage",
+    uid_type: UIDType::SOPClass(SOPClassCategory::OtherStorage),
 };
 
 /// Implant Template Group Information Model - FIND
@@ -4022,6 +4423,7 @@ pub static ImplantTemplateGroupInformationModelFIND: UID = UID {
     ident: "ImplantTemplateGroupInformationModelFIND",
     uid: "1.2.840.10008.5.1.4.45.2",
     name: "Implant Template Group Information Model - FIND",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Implant Template Group Information Model - MOVE
@@ -4032,6 +4434,7 @@ pub static ImplantTemplateGroupInformationModelMOVE: UID = UID {
     ident: "ImplantTemplateGroupInformationModelMOVE",
     uid: "1.2.840.10008.5.1.4.45.3",
     name: "Implant Template Group Information Model - MOVE",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Implant Template Group Information Model - GET
@@ -4042,6 +4445,7 @@ pub static ImplantTemplateGroupInformationModelGET: UID = UID {
     ident: "ImplantTemplateGroupInformationModelGET",
     uid: "1.2.840.10008.5.1.4.45.4",
     name: "Implant Template Group Information Model - GET",
+    uid_type: UIDType::SOPClass(SOPClassCategory::QueryRetrieve),
 };
 
 /// Native DICOM Model
@@ -4052,6 +4456,7 @@ pub static NativeDICOMModel: UID = UID {
     ident: "NativeDICOMModel",
     uid: "1.2.840.10008.7.1.1",
     name: "Native DICOM Model",
+    uid_type: UIDType::ApplicationHostingModel,
 };
 
 /// Abstract Multi-Dimensional Image Model
@@ -4062,6 +4467,7 @@ pub static AbstractMultiDimensionalImageModel: UID = UID {
     ident: "AbstractMultiDimensionalImageModel",
     uid: "1.2.840.10008.7.1.2",
     name: "Abstract Multi-Dimensional Image Model",
+    uid_type: UIDType::ApplicationHostingModel,
 };
 
 /// DICOM Content Mapping Resource
@@ -4072,6 +4478,7 @@ pub static DICOMContentMappingResource: UID = UID {
     ident: "DICOMContentMappingResource",
     uid: "1.2.840.10008.8.1.1",
     name: "DICOM Content Mapping Resource",
+    uid_type: UIDType::MappingResource,
 };
 
 /// Video Endoscopic Image Real-Time Communication
@@ -4082,6 +4489,7 @@ pub static VideoEndoscopicImageRealTimeCommunication: UID = UID {
     ident: "VideoEndoscopicImageRealTimeCommunication",
     uid: "1.2.840.10008.10.1",
     name: "Video Endoscopic Image Real-Time Communication",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Video Photographic Image Real-Time Communication
@@ -4092,6 +4500,7 @@ pub static VideoPhotographicImageRealTimeCommunication: UID = UID {
     ident: "VideoPhotographicImageRealTimeCommunication",
     uid: "1.2.840.10008.10.2",
     name: "Video Photographic Image Real-Time Communication",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Audio Waveform Real-Time Communication
@@ -4102,6 +4511,7 @@ pub static AudioWaveformRealTimeCommunication: UID = UID {
     ident: "AudioWaveformRealTimeCommunication",
     uid: "1.2.840.10008.10.3",
     name: "Audio Waveform Real-Time Communication",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// Rendition Selection Document Real-Time Communication
@@ -4112,6 +4522,7 @@ pub static RenditionSelectionDocumentRealTimeCommunication: UID = UID {
     ident: "RenditionSelectionDocumentRealTimeCommunication",
     uid: "1.2.840.10008.10.4",
     name: "Rendition Selection Document Real-Time Communication",
+    uid_type: UIDType::SOPClass(SOPClassCategory::Service),
 };
 
 /// dicomDeviceName
@@ -4122,6 +4533,7 @@ pub static DicomDeviceName: UID = UID {
     ident: "DicomDeviceName",
     uid: "1.2.840.10008.15.0.3.1",
     name: "dicomDeviceName",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomDescription
@@ -4132,6 +4544,7 @@ pub static DicomDescription: UID = UID {
     ident: "DicomDescription",
     uid: "1.2.840.10008.15.0.3.2",
     name: "dicomDescription",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomManufacturer
@@ -4142,6 +4555,7 @@ pub static DicomManufacturer: UID = UID {
     ident: "DicomManufacturer",
     uid: "1.2.840.10008.15.0.3.3",
     name: "dicomManufacturer",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomManufacturerModelName
@@ -4152,6 +4566,7 @@ pub static DicomManufacturerModelName: UID = UID {
     ident: "DicomManufacturerModelName",
     uid: "1.2.840.10008.15.0.3.4",
     name: "dicomManufacturerModelName",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomSoftwareVersion
@@ -4162,6 +4577,7 @@ pub static DicomSoftwareVersion: UID = UID {
     ident: "DicomSoftwareVersion",
     uid: "1.2.840.10008.15.0.3.5",
     name: "dicomSoftwareVersion",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomVendorData
@@ -4172,6 +4588,7 @@ pub static DicomVendorData: UID = UID {
     ident: "DicomVendorData",
     uid: "1.2.840.10008.15.0.3.6",
     name: "dicomVendorData",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomAETitle
@@ -4182,6 +4599,7 @@ pub static DicomAETitle: UID = UID {
     ident: "DicomAETitle",
     uid: "1.2.840.10008.15.0.3.7",
     name: "dicomAETitle",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomNetworkConnectionReference
@@ -4192,6 +4610,7 @@ pub static DicomNetworkConnectionReference: UID = UID {
     ident: "DicomNetworkConnectionReference",
     uid: "1.2.840.10008.15.0.3.8",
     name: "dicomNetworkConnectionReference",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomApplicationCluster
@@ -4202,6 +4621,7 @@ pub static DicomApplicationCluster: UID = UID {
     ident: "DicomApplicationCluster",
     uid: "1.2.840.10008.15.0.3.9",
     name: "dicomApplicationCluster",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomAssociationInitiator
@@ -4212,6 +4632,7 @@ pub static DicomAssociationInitiator: UID = UID {
     ident: "DicomAssociationInitiator",
     uid: "1.2.840.10008.15.0.3.10",
     name: "dicomAssociationInitiator",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomAssociationAcceptor
@@ -4222,6 +4643,7 @@ pub static DicomAssociationAcceptor: UID = UID {
     ident: "DicomAssociationAcceptor",
     uid: "1.2.840.10008.15.0.3.11",
     name: "dicomAssociationAcceptor",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomHostname
@@ -4232,6 +4654,7 @@ pub static DicomHostname: UID = UID {
     ident: "DicomHostname",
     uid: "1.2.840.10008.15.0.3.12",
     name: "dicomHostname",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomPort
@@ -4242,6 +4665,7 @@ pub static DicomPort: UID = UID {
     ident: "DicomPort",
     uid: "1.2.840.10008.15.0.3.13",
     name: "dicomPort",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomSOPClass
@@ -4252,6 +4676,7 @@ pub static DicomSOPClass: UID = UID {
     ident: "DicomSOPClass",
     uid: "1.2.840.10008.15.0.3.14",
     name: "dicomSOPClass",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomTransferRole
@@ -4262,6 +4687,7 @@ pub static DicomTransferRole: UID = UID {
     ident: "DicomTransferRole",
     uid: "1.2.840.10008.15.0.3.15",
     name: "dicomTransferRole",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomTransferSyntax
@@ -4272,6 +4698,7 @@ pub static DicomTransferSyntax: UID = UID {
     ident: "DicomTransferSyntax",
     uid: "1.2.840.10008.15.0.3.16",
     name: "dicomTransferSyntax",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomPrimaryDeviceType
@@ -4282,6 +4709,7 @@ pub static DicomPrimaryDeviceType: UID = UID {
     ident: "DicomPrimaryDeviceType",
     uid: "1.2.840.10008.15.0.3.17",
     name: "dicomPrimaryDeviceType",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomRelatedDeviceReference
@@ -4292,6 +4720,7 @@ pub static DicomRelatedDeviceReference: UID = UID {
     ident: "DicomRelatedDeviceReference",
     uid: "1.2.840.10008.15.0.3.18",
     name: "dicomRelatedDeviceReference",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomPreferredCalledAETitle
@@ -4302,6 +4731,7 @@ pub static DicomPreferredCalledAETitle: UID = UID {
     ident: "DicomPreferredCalledAETitle",
     uid: "1.2.840.10008.15.0.3.19",
     name: "dicomPreferredCalledAETitle",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomTLSCyphersuite
@@ -4312,6 +4742,7 @@ pub static DicomTLSCyphersuite: UID = UID {
     ident: "DicomTLSCyphersuite",
     uid: "1.2.840.10008.15.0.3.20",
     name: "dicomTLSCyphersuite",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomAuthorizedNodeCertificateReference
@@ -4322,6 +4753,7 @@ pub static DicomAuthorizedNodeCertificateReference: UID = UID {
     ident: "DicomAuthorizedNodeCertificateReference",
     uid: "1.2.840.10008.15.0.3.21",
     name: "dicomAuthorizedNodeCertificateReference",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomThisNodeCertificateReference
@@ -4332,6 +4764,7 @@ pub static DicomThisNodeCertificateReference: UID = UID {
     ident: "DicomThisNodeCertificateReference",
     uid: "1.2.840.10008.15.0.3.22",
     name: "dicomThisNodeCertificateReference",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomInstalled
@@ -4342,6 +4775,7 @@ pub static DicomInstalled: UID = UID {
     ident: "DicomInstalled",
     uid: "1.2.840.10008.15.0.3.23",
     name: "dicomInstalled",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomStationName
@@ -4352,6 +4786,7 @@ pub static DicomStationName: UID = UID {
     ident: "DicomStationName",
     uid: "1.2.840.10008.15.0.3.24",
     name: "dicomStationName",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomDeviceSerialNumber
@@ -4362,6 +4797,7 @@ pub static DicomDeviceSerialNumber: UID = UID {
     ident: "DicomDeviceSerialNumber",
     uid: "1.2.840.10008.15.0.3.25",
     name: "dicomDeviceSerialNumber",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomInstitutionName
@@ -4372,6 +4808,7 @@ pub static DicomInstitutionName: UID = UID {
     ident: "DicomInstitutionName",
     uid: "1.2.840.10008.15.0.3.26",
     name: "dicomInstitutionName",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomInstitutionAddress
@@ -4382,6 +4819,7 @@ pub static DicomInstitutionAddress: UID = UID {
     ident: "DicomInstitutionAddress",
     uid: "1.2.840.10008.15.0.3.27",
     name: "dicomInstitutionAddress",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomInstitutionDepartmentName
@@ -4392,6 +4830,7 @@ pub static DicomInstitutionDepartmentName: UID = UID {
     ident: "DicomInstitutionDepartmentName",
     uid: "1.2.840.10008.15.0.3.28",
     name: "dicomInstitutionDepartmentName",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomIssuerOfPatientID
@@ -4402,6 +4841,7 @@ pub static DicomIssuerOfPatientID: UID = UID {
     ident: "DicomIssuerOfPatientID",
     uid: "1.2.840.10008.15.0.3.29",
     name: "dicomIssuerOfPatientID",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomPreferredCallingAETitle
@@ -4412,6 +4852,7 @@ pub static DicomPreferredCallingAETitle: UID = UID {
     ident: "DicomPreferredCallingAETitle",
     uid: "1.2.840.10008.15.0.3.30",
     name: "dicomPreferredCallingAETitle",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomSupportedCharacterSet
@@ -4422,6 +4863,7 @@ pub static DicomSupportedCharacterSet: UID = UID {
     ident: "DicomSupportedCharacterSet",
     uid: "1.2.840.10008.15.0.3.31",
     name: "dicomSupportedCharacterSet",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomConfigurationRoot
@@ -4432,6 +4874,7 @@ pub static DicomConfigurationRoot: UID = UID {
     ident: "DicomConfigurationRoot",
     uid: "1.2.840.10008.15.0.4.1",
     name: "dicomConfigurationRoot",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomDevicesRoot
@@ -4442,6 +4885,7 @@ pub static DicomDevicesRoot: UID = UID {
     ident: "DicomDevicesRoot",
     uid: "1.2.840.10008.15.0.4.2",
     name: "dicomDevicesRoot",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomUniqueAETitlesRegistryRoot
@@ -4452,6 +4896,7 @@ pub static DicomUniqueAETitlesRegistryRoot: UID = UID {
     ident: "DicomUniqueAETitlesRegistryRoot",
     uid: "1.2.840.10008.15.0.4.3",
     name: "dicomUniqueAETitlesRegistryRoot",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomDevice
@@ -4462,6 +4907,7 @@ pub static DicomDevice: UID = UID {
     ident: "DicomDevice",
     uid: "1.2.840.10008.15.0.4.4",
     name: "dicomDevice",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomNetworkAE
@@ -4472,6 +4918,7 @@ pub static DicomNetworkAE: UID = UID {
     ident: "DicomNetworkAE",
     uid: "1.2.840.10008.15.0.4.5",
     name: "dicomNetworkAE",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomNetworkConnection
@@ -4482,6 +4929,7 @@ pub static DicomNetworkConnection: UID = UID {
     ident: "DicomNetworkConnection",
     uid: "1.2.840.10008.15.0.4.6",
     name: "dicomNetworkConnection",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomUniqueAETitle
@@ -4492,6 +4940,7 @@ pub static DicomUniqueAETitle: UID = UID {
     ident: "DicomUniqueAETitle",
     uid: "1.2.840.10008.15.0.4.7",
     name: "dicomUniqueAETitle",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// dicomTransferCapability
@@ -4502,6 +4951,7 @@ pub static DicomTransferCapability: UID = UID {
     ident: "DicomTransferCapability",
     uid: "1.2.840.10008.15.0.4.8",
     name: "dicomTransferCapability",
+    uid_type: UIDType::LDAPOID,
 };
 
 /// Universal Coordinated Time
@@ -4512,4 +4962,5 @@ pub static UniversalCoordinatedTime: UID = UID {
     ident: "UniversalCoordinatedTime",
     uid: "1.2.840.10008.15.1.1",
     name: "Universal Coordinated Time",
+    uid_type: UIDType::SynchronizationFrameOfReference,
 };
@@ -0,0 +1,1028 @@
+//! Association establishment and message exchange. Part 8, Chapter 7 and Part 7, Chapter 9.
+//!
+//! An `AssociationBuilder` configures what this AE supports and then either accepts an association
+//! requested by a remote AE (acting as SCP) or requests one from a remote AE (acting as SCU). The
+//! resulting `Association` is used to send and receive messages over the stream.
+
+use std::io::{Read, Write};
+
+use crate::{
+    core::{
+        dcmobject::DicomRoot,
+        defn::{constants::ts, ts::TSRef},
+    },
+    dict::uids,
+};
+
+use super::{
+    commands::{command_has_dataset, decode_dataset, encode_dataset, Message},
+    constants::{
+        DEFAULT_MAX_MESSAGE_LENGTH, DEFAULT_MAX_PDU_LENGTH, IMPLEMENTATION_CLASS_UID,
+        IMPLEMENTATION_VERSION_NAME,
+    },
+    error::DimseError,
+    pdus::{
+        ae_title_from_bytes, uid_from_bytes, Abort, AbstractSyntaxItem, ApplicationContextItem,
+        AssocAC, AssocACPresentationContext, AssocRJ, AssocRQ, AssocRQPresentationContext,
+        AsyncOperationsWindowItem, ImplementationClassUIDItem, ImplementationVersionNameItem,
+        MaxLengthItem, Pdu, PresentationDataItem, PresentationDataValue, ReleaseRP, ReleaseRQ,
+        RoleSelectionItem, SOPClassCommonExtendedNegotiationItem, SOPClassExtendedNegotiationItem,
+        TransferSyntaxItem, UserInformationItem,
+    },
+};
+
+/// Result/Reason of an accepted presentation context.
+const PRES_CTX_ACCEPTANCE: u8 = 0;
+/// Result/Reason of a presentation context whose abstract syntax is not supported.
+const PRES_CTX_ABSTRACT_SYNTAX_NOT_SUPPORTED: u8 = 3;
+/// Result/Reason of a presentation context where none of the proposed transfer syntaxes are
+/// supported.
+const PRES_CTX_TRANSFER_SYNTAXES_NOT_SUPPORTED: u8 = 4;
+
+/// AssocRJ Result, rejected-permanent.
+const RJ_RESULT_PERMANENT: u8 = 1;
+/// AssocRJ Source, DICOM UL service-user.
+const RJ_SOURCE_SERVICE_USER: u8 = 1;
+/// AssocRJ Reason, application-context-name-not-supported.
+const RJ_REASON_APP_CTX_NOT_SUPPORTED: u8 = 2;
+/// AssocRJ Reason, calling-AE-title-not-recognized.
+const RJ_REASON_CALLING_AE_NOT_RECOGNIZED: u8 = 3;
+/// AssocRJ Reason, called-AE-title-not-recognized.
+const RJ_REASON_CALLED_AE_NOT_RECOGNIZED: u8 = 7;
+
+/// The most presentation contexts which can be proposed, as their IDs are odd numbers 1-255.
+const MAX_PRES_CTXS: usize = 128;
+
+/// The Asynchronous Operations Window used when none is negotiated, where operations are performed
+/// synchronously.
+const DEFAULT_ASYNC_OPS_WINDOW: (u16, u16) = (1, 1);
+
+/// Abort Source, DICOM UL service-provider.
+const ABORT_SOURCE_SERVICE_PROVIDER: u8 = 2;
+/// Abort Reason, unexpected-PDU.
+const ABORT_REASON_UNEXPECTED_PDU: u8 = 2;
+
+/// A presentation context which was accepted during association negotiation.
+#[derive(Debug, Clone)]
+pub struct PresentationContext {
+    ctx_id: u8,
+    abstract_syntax: String,
+    ts: TSRef,
+}
+
+impl PresentationContext {
+    /// The presentation context ID.
+    pub fn ctx_id(&self) -> u8 {
+        self.ctx_id
+    }
+
+    /// The abstract syntax UID, usually a SOP Class UID.
+    pub fn abstract_syntax(&self) -> &str {
+        &self.abstract_syntax
+    }
+
+    /// The negotiated transfer syntax used for Data Sets sent over this presentation context.
+    pub fn ts(&self) -> TSRef {
+        self.ts
+    }
+}
+
+/// The roles the association-requestor takes for a SOP Class, as negotiated with SCP/SCU Role
+/// Selection. Without negotiation the requestor is the SCU and the acceptor is the SCP.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoleSelection {
+    sop_class_uid: String,
+    scu_role: bool,
+    scp_role: bool,
+}
+
+impl RoleSelection {
+    /// The SOP Class UID the roles apply to.
+    pub fn sop_class_uid(&self) -> &str {
+        &self.sop_class_uid
+    }
+
+    /// Whether the association-requestor may act as the SCU.
+    pub fn scu_role(&self) -> bool {
+        self.scu_role
+    }
+
+    /// Whether the association-requestor may act as the SCP.
+    pub fn scp_role(&self) -> bool {
+        self.scp_role
+    }
+}
+
+impl From<&RoleSelectionItem> for RoleSelection {
+    fn from(value: &RoleSelectionItem) -> Self {
+        RoleSelection {
+            sop_class_uid: uid_from_bytes(value.sop_class_uid()),
+            scu_role: value.scu_role() == 1,
+            scp_role: value.scp_role() == 1,
+        }
+    }
+}
+
+/// A builder for negotiating an `Association`.
+#[derive(Debug)]
+pub struct AssociationBuilder {
+    /// The AE Title of this application entity. Default is `"DCMPIPE"`.
+    ae_title: String,
+
+    /// The AE Titles which may request associations with this AE. If empty then any AE may.
+    allowed_calling_aes: Vec<String>,
+
+    /// The abstract syntaxes this AE supports, usually SOP Class UIDs.
+    abstract_syntaxes: Vec<String>,
+
+    /// The transfer syntaxes this AE supports, in order of preference. Default is
+    /// `ExplicitVRLittleEndian` then `ImplicitVRLittleEndian`.
+    transfer_syntaxes: Vec<TSRef>,
+
+    /// Presentation contexts proposed with their own transfer syntaxes when requesting an
+    /// association, in addition to one for each of `abstract_syntaxes`.
+    proposed_pres_ctxs: Vec<(String, Vec<TSRef>)>,
+
+    /// The maximum length of the variable field of P-DATA-TF PDUs this AE will receive.
+    max_pdu_length: u32,
+
+    /// The maximum combined length of the command and dataset of messages this AE will receive,
+    /// where zero indicates no limit.
+    max_message_length: u64,
+
+    /// The roles of the association-requestor for SOP Classes, proposed when requesting an
+    /// association and permitted when accepting one.
+    roles: Vec<RoleSelection>,
+
+    /// The Asynchronous Operations Window as maximum operations invoked and performed, which is
+    /// only negotiated if set.
+    async_ops_window: Option<(u16, u16)>,
+
+    /// Service-class application information for SOP Class Extended Negotiation.
+    ext_negs: Vec<(String, Vec<u8>)>,
+
+    /// The SOP Class, Service Class, and Related General SOP Classes proposed with SOP Class
+    /// Common Extended Negotiation when requesting an association.
+    common_ext_negs: Vec<(String, String, Vec<String>)>,
+}
+
+impl AssociationBuilder {
+    /// Sets the AE Title of this application entity.
+    pub fn ae_title(mut self, ae_title: &str) -> Self {
+        self.ae_title = ae_title.trim().to_owned();
+        self
+    }
+
+    /// Adds an AE Title which may request associations with this AE. Once any are added,
+    /// associations requested by other AEs are rejected.
+    pub fn allow_calling_ae(mut self, ae_title: &str) -> Self {
+        self.allowed_calling_aes.push(ae_title.trim().to_owned());
+        self
+    }
+
+    /// Adds an abstract syntax which this AE supports.
+    pub fn abstract_syntax(mut self, abstract_syntax: &str) -> Self {
+        self.abstract_syntaxes.push(abstract_syntax.to_owned());
+        self
+    }
+
+    /// Sets the transfer syntaxes which this AE supports, in order of preference.
+    pub fn transfer_syntaxes(mut self, transfer_syntaxes: Vec<TSRef>) -> Self {
+        self.transfer_syntaxes = transfer_syntaxes;
+        self
+    }
+
+    /// Adds a presentation context to propose when requesting an association, with the given
+    /// transfer syntaxes rather than all supported transfer syntaxes. The same abstract syntax may
+    /// be proposed more than once, such as to offer a dataset's own transfer syntax separately
+    /// from those it can be converted to.
+    pub fn presentation_context(
+        mut self,
+        abstract_syntax: &str,
+        transfer_syntaxes: Vec<TSRef>,
+    ) -> Self {
+        self.proposed_pres_ctxs
+            .push((abstract_syntax.to_owned(), transfer_syntaxes));
+        self
+    }
+
+    /// Sets the maximum length of the variable field of P-DATA-TF PDUs this AE will receive.
+    pub fn max_pdu_length(mut self, max_pdu_length: u32) -> Self {
+        self.max_pdu_length = max_pdu_length;
+        self
+    }
+
+    /// Sets the maximum combined length of the command and dataset of messages this AE will
+    /// receive, where zero indicates no limit. The association is aborted if a message exceeds it.
+    pub fn max_message_length(mut self, max_message_length: u64) -> Self {
+        self.max_message_length = max_message_length;
+        self
+    }
+
+    /// Adds SCP/SCU Role Selection for the SOP Class, as the roles of the association-requestor.
+    /// When requesting an association these roles are proposed, such as the SCP role for Storage
+    /// SOP Classes when retrieving with C-GET. When accepting an association the proposed roles
+    /// are limited to these, and proposals for other SOP Classes are left unanswered so the
+    /// default roles apply.
+    pub fn role_selection(mut self, sop_class_uid: &str, scu_role: bool, scp_role: bool) -> Self {
+        self.roles.push(RoleSelection {
+            sop_class_uid: sop_class_uid.to_owned(),
+            scu_role,
+            scp_role,
+        });
+        self
+    }
+
+    /// Sets the Asynchronous Operations Window, where a value of zero indicates no limit. When
+    /// requesting an association this is proposed. When accepting an association the proposed
+    /// window is limited to this, and if not set proposals are left unanswered so operations are
+    /// synchronous.
+    pub fn async_ops_window(mut self, max_ops_invoked: u16, max_ops_performed: u16) -> Self {
+        self.async_ops_window = Some((max_ops_invoked, max_ops_performed));
+        self
+    }
+
+    /// Adds SOP Class Extended Negotiation for the SOP Class, whose application information is
+    /// defined by its Service Class, such as the flags for relational queries with Query/Retrieve.
+    /// When requesting an association this is proposed. When accepting an association each byte of
+    /// the proposed information is limited to the value here, which suits the flags used by most
+    /// Service Classes.
+    pub fn extended_negotiation(
+        mut self,
+        sop_class_uid: &str,
+        service_class_app_info: Vec<u8>,
+    ) -> Self {
+        self.ext_negs
+            .push((sop_class_uid.to_owned(), service_class_app_info));
+        self
+    }
+
+    /// Adds SOP Class Common Extended Negotiation for the SOP Class, proposed when requesting an
+    /// association to identify the Service Class and the Related General SOP Classes of a
+    /// specialized SOP Class. When accepting an association a proposed SOP Class which is not
+    /// supported is accepted if one of its Related General SOP Classes is.
+    pub fn common_extended_negotiation(
+        mut self,
+        sop_class_uid: &str,
+        service_class_uid: &str,
+        rel_gen_sop_classes: &[&str],
+    ) -> Self {
+        self.common_ext_negs.push((
+            sop_class_uid.to_owned(),
+            service_class_uid.to_owned(),
+            rel_gen_sop_classes
+                .iter()
+                .map(|uid| (*uid).to_owned())
+                .collect(),
+        ));
+        self
+    }
+
+    /// Acts as the SCP, reading an association request from the stream and responding with either
+    /// acceptance or rejection. Presentation contexts are accepted for the supported abstract
+    /// syntaxes using the most preferred transfer syntax that was proposed.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn accept<S: Read + Write>(&self, stream: &mut S) -> Result<Association, DimseError> {
+        let rq: AssocRQ = match Pdu::read_max(stream, self.max_pdu_length)? {
+            Pdu::AssocRQ(rq) => rq,
+            other => {
+                Pdu::Abort(Abort::new(
+                    ABORT_SOURCE_SERVICE_PROVIDER,
+                    ABORT_REASON_UNEXPECTED_PDU,
+                ))
+                .write(stream)?;
+                return Err(DimseError::UnexpectedPdu(other.pdu_type()));
+            }
+        };
+
+        let called_ae: String = ae_title_from_bytes(rq.called_ae());
+        let calling_ae: String = ae_title_from_bytes(rq.calling_ae());
+        if !self.ae_title.is_empty() && called_ae != self.ae_title {
+            return Self::reject(stream, RJ_REASON_CALLED_AE_NOT_RECOGNIZED);
+        }
+        if !self.allowed_calling_aes.is_empty() && !self.allowed_calling_aes.contains(&calling_ae) {
+            return Self::reject(stream, RJ_REASON_CALLING_AE_NOT_RECOGNIZED);
+        }
+        if uid_from_bytes(rq.app_ctx().app_context_name()) != uids::DICOMApplicationContextName.uid
+        {
+            return Self::reject(stream, RJ_REASON_APP_CTX_NOT_SUPPORTED);
+        }
+
+        // Specialized SOP Classes may be accepted in place of their related general SOP Classes.
+        let common_ext_negs: Vec<SOPClassCommonExtendedNegotiationItem> =
+            rq.user_info().sop_class_common_ext_negs();
+        let is_supported = |abstract_syntax: &String| -> bool {
+            self.abstract_syntaxes.contains(abstract_syntax)
+                || common_ext_negs
+                    .iter()
+                    .filter(|common_ext_neg| {
+                        uid_from_bytes(common_ext_neg.sop_class_uid()) == *abstract_syntax
+                    })
+                    .flat_map(|common_ext_neg| common_ext_neg.rel_gen_sop_classes())
+                    .any(|rel_gen| {
+                        self.abstract_syntaxes
+                            .contains(&uid_from_bytes(rel_gen.rel_gen_sop_class()))
+                    })
+        };
+
+        let mut pres_ctxs: Vec<PresentationContext> = Vec::new();
+        let mut ac_pres_ctxs: Vec<AssocACPresentationContext> = Vec::new();
+        for rq_pres_ctx in rq.pres_ctxs() {
+            let abstract_syntax: String =
+                uid_from_bytes(rq_pres_ctx.abstract_syntax().abstract_syntax());
+            let proposed_ts: Vec<String> = rq_pres_ctx
+                .transfer_syntaxes()
+                .iter()
+                .map(|ts| uid_from_bytes(ts.transfer_syntaxes()))
+                .collect();
+
+            let selected_ts: Option<TSRef> = self
+                .transfer_syntaxes
+                .iter()
+                .find(|ts| proposed_ts.iter().any(|uid| uid == ts.uid().uid()))
+                .copied();
+
+            let (result, ts_uid) = if !is_supported(&abstract_syntax) {
+                (
+                    PRES_CTX_ABSTRACT_SYNTAX_NOT_SUPPORTED,
+                    proposed_ts.first().cloned().unwrap_or_default(),
+                )
+            } else if let Some(ts) = selected_ts {
+                pres_ctxs.push(PresentationContext {
+                    ctx_id: rq_pres_ctx.ctx_id(),
+                    abstract_syntax,
+                    ts,
+                });
+                (PRES_CTX_ACCEPTANCE, ts.uid().uid().to_owned())
+            } else {
+                (
+                    PRES_CTX_TRANSFER_SYNTAXES_NOT_SUPPORTED,
+                    proposed_ts.first().cloned().unwrap_or_default(),
+                )
+            };
+            ac_pres_ctxs.push(AssocACPresentationContext::new(
+                rq_pres_ctx.ctx_id(),
+                result,
+                TransferSyntaxItem::new(&ts_uid),
+            ));
+        }
+
+        // Proposed roles are limited to those permitted, and proposals for SOP Classes without
+        // permitted roles are left unanswered.
+        let roles: Vec<RoleSelection> = rq
+            .user_info()
+            .role_selections()
+            .iter()
+            .map(RoleSelection::from)
+            .filter_map(|proposed| {
+                self.roles
+                    .iter()
+                    .find(|permitted| permitted.sop_class_uid == proposed.sop_class_uid)
+                    .map(|permitted| RoleSelection {
+                        scu_role: proposed.scu_role && permitted.scu_role,
+                        scp_role: proposed.scp_role && permitted.scp_role,
+                        sop_class_uid: proposed.sop_class_uid,
+                    })
+            })
+            .collect();
+
+        let async_ops_window: Option<(u16, u16)> = rq
+            .user_info()
+            .async_ops_window()
+            .zip(self.async_ops_window)
+            .map(|(proposed, (max_ops_invoked, max_ops_performed))| {
+                (
+                    min_ops(proposed.max_ops_invoked(), max_ops_invoked),
+                    min_ops(proposed.max_ops_performed(), max_ops_performed),
+                )
+            });
+
+        let ext_negs: Vec<(String, Vec<u8>)> = rq
+            .user_info()
+            .sop_class_ext_negs()
+            .iter()
+            .filter_map(|proposed| {
+                let sop_class_uid: String = uid_from_bytes(proposed.sop_class_uid());
+                let (_, supported) = self
+                    .ext_negs
+                    .iter()
+                    .find(|(uid, _)| *uid == sop_class_uid)?;
+                let app_info: Vec<u8> = proposed
+                    .service_class_app_info()
+                    .iter()
+                    .zip(supported)
+                    .map(|(proposed, supported)| *proposed.min(supported))
+                    .collect();
+                Some((sop_class_uid, app_info))
+            })
+            .collect();
+
+        let ac: AssocAC = AssocAC::new(
+            &rq,
+            ApplicationContextItem::new(uids::DICOMApplicationContextName.uid),
+            ac_pres_ctxs,
+            self.user_info(async_ops_window, &roles, &ext_negs, false),
+        );
+        Pdu::AssocAC(ac).write(stream)?;
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            calling_ae,
+            called_ae,
+            pres_ctxs = pres_ctxs.len(),
+            "association accepted"
+        );
+
+        Ok(Association {
+            this_ae: called_ae,
+            peer_ae: calling_ae,
+            is_requestor: false,
+            pres_ctxs,
+            max_pdu_length: self.max_pdu_length,
+            max_message_length: self.max_message_length,
+            peer_max_pdu_length: rq.user_info().max_length().unwrap_or(0),
+            peer_impl_class_uid: rq.user_info().impl_class_uid(),
+            peer_impl_ver_name: rq.user_info().impl_ver_name(),
+            roles,
+            async_ops_window: async_ops_window.unwrap_or(DEFAULT_ASYNC_OPS_WINDOW),
+            ext_negs,
+            next_msg_id: 1,
+        })
+    }
+
+    /// Acts as the SCU, requesting an association with the remote AE over the stream. A
+    /// presentation context is proposed for each of the supported abstract syntaxes, each
+    /// proposing all supported transfer syntaxes, followed by those added with
+    /// `presentation_context`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, stream))
+    )]
+    pub fn request<S: Read + Write>(
+        &self,
+        called_ae: &str,
+        stream: &mut S,
+    ) -> Result<Association, DimseError> {
+        let proposals: Vec<(&String, &Vec<TSRef>)> =
+            self.abstract_syntaxes
+                .iter()
+                .map(|abstract_syntax| (abstract_syntax, &self.transfer_syntaxes))
+                .chain(self.proposed_pres_ctxs.iter().map(
+                    |(abstract_syntax, transfer_syntaxes)| (abstract_syntax, transfer_syntaxes),
+                ))
+                .collect();
+        if proposals.len() > MAX_PRES_CTXS {
+            return Err(DimseError::TooManyPresentationContexts(proposals.len()));
+        }
+
+        let rq_pres_ctxs: Vec<AssocRQPresentationContext> = proposals
+            .iter()
+            .enumerate()
+            .map(|(index, (abstract_syntax, transfer_syntaxes))| {
+                // Presentation context IDs are odd numbers.
+                AssocRQPresentationContext::new(
+                    (index * 2 + 1) as u8,
+                    AbstractSyntaxItem::new(abstract_syntax),
+                    transfer_syntaxes
+                        .iter()
+                        .map(|ts| TransferSyntaxItem::new(ts.uid().uid()))
+                        .collect(),
+                )
+            })
+            .collect();
+
+        let rq: AssocRQ = AssocRQ::new(
+            called_ae,
+            &self.ae_title,
+            ApplicationContextItem::new(uids::DICOMApplicationContextName.uid),
+            rq_pres_ctxs,
+            self.user_info(self.async_ops_window, &self.roles, &self.ext_negs, true),
+        );
+        Pdu::AssocRQ(rq).write(stream)?;
+
+        let ac: AssocAC = match Pdu::read_max(stream, self.max_pdu_length)? {
+            Pdu::AssocAC(ac) => ac,
+            Pdu::AssocRJ(rj) => {
+                return Err(DimseError::AssociationRejected {
+                    result: rj.result(),
+                    rj_source: rj.source(),
+                    reason: rj.reason(),
+                })
+            }
+            Pdu::Abort(abort) => {
+                return Err(DimseError::AssociationAborted {
+                    abort_source: abort.source(),
+                    reason: abort.reason(),
+                })
+            }
+            other => return Err(DimseError::UnexpectedPdu(other.pdu_type())),
+        };
+
+        let mut pres_ctxs: Vec<PresentationContext> = Vec::new();
+        for ac_pres_ctx in ac.pres_ctxs() {
+            if ac_pres_ctx.result() != PRES_CTX_ACCEPTANCE {
+                continue;
+            }
+            let index: usize = (ac_pres_ctx.ctx_id() as usize) / 2;
+            let ts_uid: String = uid_from_bytes(ac_pres_ctx.transfer_syntax().transfer_syntaxes());
+            let proposal: Option<&(&String, &Vec<TSRef>)> = proposals.get(index);
+            let ts: Option<TSRef> = proposal.and_then(|(_, transfer_syntaxes)| {
+                transfer_syntaxes
+                    .iter()
+                    .find(|ts| ts.uid().uid() == ts_uid)
+                    .copied()
+            });
+            match (proposal, ts) {
+                (Some((abstract_syntax, _)), Some(ts)) => pres_ctxs.push(PresentationContext {
+                    ctx_id: ac_pres_ctx.ctx_id(),
+                    abstract_syntax: (*abstract_syntax).clone(),
+                    ts,
+                }),
+                _ => {
+                    return Err(DimseError::InvalidPdu(format!(
+                        "accepted presentation context {} does not match proposal, transfer syntax: {ts_uid}",
+                        ac_pres_ctx.ctx_id()
+                    )))
+                }
+            }
+        }
+
+        // Roles and extended negotiation which the acceptor didn't answer aren't in effect.
+        let roles: Vec<RoleSelection> = ac
+            .user_info()
+            .role_selections()
+            .iter()
+            .map(RoleSelection::from)
+            .collect();
+        let ext_negs: Vec<(String, Vec<u8>)> = ac
+            .user_info()
+            .sop_class_ext_negs()
+            .iter()
+            .map(|ext_neg| {
+                (
+                    uid_from_bytes(ext_neg.sop_class_uid()),
+                    ext_neg.service_class_app_info().clone(),
+                )
+            })
+            .collect();
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            called_ae,
+            pres_ctxs = pres_ctxs.len(),
+            "association established"
+        );
+        Ok(Association {
+            this_ae: self.ae_title.clone(),
+            peer_ae: called_ae.trim().to_owned(),
+            is_requestor: true,
+            pres_ctxs,
+            max_pdu_length: self.max_pdu_length,
+            max_message_length: self.max_message_length,
+            peer_max_pdu_length: ac.user_info().max_length().unwrap_or(0),
+            peer_impl_class_uid: ac.user_info().impl_class_uid(),
+            peer_impl_ver_name: ac.user_info().impl_ver_name(),
+            roles,
+            async_ops_window: ac
+                .user_info()
+                .async_ops_window()
+                .map_or(DEFAULT_ASYNC_OPS_WINDOW, |window| {
+                    (window.max_ops_invoked(), window.max_ops_performed())
+                }),
+            ext_negs,
+            next_msg_id: 1,
+        })
+    }
+
+    /// Creates the User Information item with its sub-items ordered by item type. SOP Class Common
+    /// Extended Negotiation is only sent when requesting an association.
+    fn user_info(
+        &self,
+        async_ops_window: Option<(u16, u16)>,
+        roles: &[RoleSelection],
+        ext_negs: &[(String, Vec<u8>)],
+        is_request: bool,
+    ) -> UserInformationItem {
+        let mut sub_items: Vec<Vec<u8>> = vec![
+            (&MaxLengthItem::new(self.max_pdu_length)).into(),
+            (&ImplementationClassUIDItem::new(IMPLEMENTATION_CLASS_UID)).into(),
+        ];
+        if let Some((max_ops_invoked, max_ops_performed)) = async_ops_window {
+            sub_items
+                .push((&AsyncOperationsWindowItem::new(max_ops_invoked, max_ops_performed)).into());
+        }
+        for role in roles {
+            sub_items.push(
+                (&RoleSelectionItem::new(&role.sop_class_uid, role.scu_role, role.scp_role)).into(),
+            );
+        }
+        sub_items.push((&ImplementationVersionNameItem::new(IMPLEMENTATION_VERSION_NAME)).into());
+        for (sop_class_uid, app_info) in ext_negs {
+            sub_items.push(
+                (&SOPClassExtendedNegotiationItem::new(sop_class_uid, app_info.clone())).into(),
+            );
+        }
+        if is_request {
+            for (sop_class_uid, service_class_uid, rel_gen_sop_classes) in &self.common_ext_negs {
+                let rel_gen_sop_classes: Vec<&str> =
+                    rel_gen_sop_classes.iter().map(String::as_str).collect();
+                sub_items.push(
+                    (&SOPClassCommonExtendedNegotiationItem::new(
+                        sop_class_uid,
+                        service_class_uid,
+                        &rel_gen_sop_classes,
+                    ))
+                        .into(),
+                );
+            }
+        }
+        UserInformationItem::from_sub_items(sub_items)
+    }
+
+    fn reject<S: Write>(stream: &mut S, reason: u8) -> Result<Association, DimseError> {
+        #[cfg(feature = "tracing")]
+        tracing::warn!(reason, "association rejected");
+        Pdu::AssocRJ(AssocRJ::new(
+            RJ_RESULT_PERMANENT,
+            RJ_SOURCE_SERVICE_USER,
+            reason,
+        ))
+        .write(stream)?;
+        Err(DimseError::AssociationRejected {
+            result: RJ_RESULT_PERMANENT,
+            rj_source: RJ_SOURCE_SERVICE_USER,
+            reason,
+        })
+    }
+}
+
+impl Default for AssociationBuilder {
+    fn default() -> Self {
+        AssociationBuilder {
+            ae_title: "DCMPIPE".to_owned(),
+            allowed_calling_aes: Vec::new(),
+            abstract_syntaxes: Vec::new(),
+            transfer_syntaxes: vec![&ts::ExplicitVRLittleEndian, &ts::ImplicitVRLittleEndian],
+            proposed_pres_ctxs: Vec::new(),
+            max_pdu_length: DEFAULT_MAX_PDU_LENGTH,
+            max_message_length: DEFAULT_MAX_MESSAGE_LENGTH,
+            roles: Vec::new(),
+            async_ops_window: None,
+            ext_negs: Vec::new(),
+            common_ext_negs: Vec::new(),
+        }
+    }
+}
+
+/// An established association.
+#[derive(Debug)]
+pub struct Association {
+    this_ae: String,
+    peer_ae: String,
+    is_requestor: bool,
+    pres_ctxs: Vec<PresentationContext>,
+    max_pdu_length: u32,
+    max_message_length: u64,
+    peer_max_pdu_length: u32,
+    peer_impl_class_uid: Option<String>,
+    peer_impl_ver_name: Option<String>,
+    roles: Vec<RoleSelection>,
+    async_ops_window: (u16, u16),
+    ext_negs: Vec<(String, Vec<u8>)>,
+    next_msg_id: u16,
+}
+
+impl Association {
+    /// The AE Title of this application entity.
+    pub fn this_ae(&self) -> &str {
+        &self.this_ae
+    }
+
+    /// The AE Title of the remote application entity.
+    pub fn peer_ae(&self) -> &str {
+        &self.peer_ae
+    }
+
+    /// The presentation contexts which were accepted.
+    pub fn pres_ctxs(&self) -> &Vec<PresentationContext> {
+        &self.pres_ctxs
+    }
+
+    /// The maximum length of the variable field of P-DATA-TF PDUs this AE receives.
+    pub fn max_pdu_length(&self) -> u32 {
+        self.max_pdu_length
+    }
+
+    /// The maximum combined length of the command and dataset of messages this AE receives. A
+    /// value of zero indicates no limit.
+    pub fn max_message_length(&self) -> u64 {
+        self.max_message_length
+    }
+
+    /// The maximum length of the variable field of P-DATA-TF PDUs the remote AE receives. A value
+    /// of zero indicates no limit.
+    pub fn peer_max_pdu_length(&self) -> u32 {
+        self.peer_max_pdu_length
+    }
+
+    /// The Implementation Class UID of the remote AE, if sent.
+    pub fn peer_impl_class_uid(&self) -> Option<&str> {
+        self.peer_impl_class_uid.as_deref()
+    }
+
+    /// The Implementation Version Name of the remote AE, if sent.
+    pub fn peer_impl_ver_name(&self) -> Option<&str> {
+        self.peer_impl_ver_name.as_deref()
+    }
+
+    /// Whether this AE requested the association, otherwise it accepted the association.
+    pub fn is_requestor(&self) -> bool {
+        self.is_requestor
+    }
+
+    /// The roles of the association-requestor negotiated for the SOP Class, if any were.
+    pub fn role_selection(&self, sop_class_uid: &str) -> Option<&RoleSelection> {
+        self.roles
+            .iter()
+            .find(|role| role.sop_class_uid == sop_class_uid)
+    }
+
+    /// Whether this AE may act as the SCU for the SOP Class over this association.
+    pub fn is_scu_for(&self, sop_class_uid: &str) -> bool {
+        let role: Option<&RoleSelection> = self.role_selection(sop_class_uid);
+        if self.is_requestor {
+            role.is_none_or(|role| role.scu_role)
+        } else {
+            role.is_some_and(|role| role.scp_role)
+        }
+    }
+
+    /// Whether this AE may act as the SCP for the SOP Class over this association.
+    pub fn is_scp_for(&self, sop_class_uid: &str) -> bool {
+        let role: Option<&RoleSelection> = self.role_selection(sop_class_uid);
+        if self.is_requestor {
+            role.is_some_and(|role| role.scp_role)
+        } else {
+            role.is_none_or(|role| role.scu_role)
+        }
+    }
+
+    /// The negotiated Asynchronous Operations Window as the maximum number of operations invoked
+    /// and performed, where zero indicates no limit. This is `(1, 1)` if not negotiated.
+    pub fn async_ops_window(&self) -> (u16, u16) {
+        self.async_ops_window
+    }
+
+    /// The service-class application information negotiated for the SOP Class with SOP Class
+    /// Extended Negotiation, if any was.
+    pub fn extended_negotiation(&self, sop_class_uid: &str) -> Option<&[u8]> {
+        self.ext_negs
+            .iter()
+            .find(|(uid, _)| uid == sop_class_uid)
+            .map(|(_, app_info)| app_info.as_slice())
+    }
+
+    /// Get the accepted presentation context with the given ID.
+    pub fn get_pres_ctx(&self, ctx_id: u8) -> Option<&PresentationContext> {
+        self.pres_ctxs.iter().find(|pc| pc.ctx_id == ctx_id)
+    }
+
+    /// Get the first accepted presentation context for the given abstract syntax.
+    pub fn get_pres_ctx_by_abstract_syntax(
+        &self,
+        abstract_syntax: &str,
+    ) -> Option<&PresentationContext> {
+        self.pres_ctxs
+            .iter()
+            .find(|pc| pc.abstract_syntax == abstract_syntax)
+    }
+
+    /// Get the next Message ID to use for a request sent over this association.
+    pub fn next_msg_id(&mut self) -> u16 {
+        let msg_id: u16 = self.next_msg_id;
+        self.next_msg_id = self.next_msg_id.wrapping_add(1).max(1);
+        msg_id
+    }
+
+    /// Reads the next message from the stream, assembling the command and dataset fragments.
+    ///
+    /// Returns `None` if the remote AE requested to release the association, in which case the
+    /// release is confirmed before returning. If the remote AE aborts the association this returns
+    /// `DimseError::AssociationAborted`. If the message grows longer than the maximum message
+    /// length the association is aborted and this returns `DimseError::MessageTooLong`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(peer_ae = %self.peer_ae))
+    )]
+    pub fn read_message<S: Read + Write>(
+        &self,
+        stream: &mut S,
+    ) -> Result<Option<Message>, DimseError> {
+        let mut ctx_id: Option<u8> = None;
+        let mut command: Option<DicomRoot<'static>> = None;
+        let mut command_bytes: Vec<u8> = Vec::new();
+        let mut dataset_bytes: Vec<u8> = Vec::new();
+
+        loop {
+            let pdata: PresentationDataItem = match Pdu::read_max(stream, self.max_pdu_length)? {
+                Pdu::PresentationDataItem(pdata) => pdata,
+                Pdu::ReleaseRQ(_rq) => {
+                    Pdu::ReleaseRP(ReleaseRP::new()).write(stream)?;
+                    #[cfg(feature = "tracing")]
+                    tracing::info!("association released by peer");
+                    return Ok(None);
+                }
+                Pdu::Abort(abort) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(
+                        source = abort.source(),
+                        reason = abort.reason(),
+                        "association aborted by peer"
+                    );
+                    return Err(DimseError::AssociationAborted {
+                        abort_source: abort.source(),
+                        reason: abort.reason(),
+                    });
+                }
+                other => {
+                    self.abort(stream)?;
+                    return Err(DimseError::UnexpectedPdu(other.pdu_type()));
+                }
+            };
+
+            for pdv in pdata.into_pres_data() {
+                if self.get_pres_ctx(pdv.ctx_id()).is_none() {
+                    return Err(DimseError::UnknownPresentationContext(pdv.ctx_id()));
+                }
+                let msg_ctx_id: u8 = *ctx_id.get_or_insert(pdv.ctx_id());
+                if msg_ctx_id != pdv.ctx_id() {
+                    return Err(DimseError::InvalidMessage(format!(
+                        "message fragments sent over multiple presentation contexts: {}, {}",
+                        msg_ctx_id,
+                        pdv.ctx_id()
+                    )));
+                }
+
+                let message_length: u64 =
+                    (command_bytes.len() + dataset_bytes.len()) as u64 + pdv.data().len() as u64;
+                if self.max_message_length != 0 && message_length > self.max_message_length {
+                    self.abort(stream)?;
+                    return Err(DimseError::MessageTooLong(self.max_message_length));
+                }
+
+                let is_last: bool = pdv.is_last_fragment();
+                if pdv.is_command() {
+                    if command.is_some() {
+                        return Err(DimseError::InvalidMessage(
+                            "command received while awaiting dataset".to_owned(),
+                        ));
+                    }
+                    command_bytes.extend(pdv.into_data());
+                    if !is_last {
+                        continue;
+                    }
+
+                    let decoded: DicomRoot<'static> =
+                        decode_dataset(&command_bytes, &ts::ImplicitVRLittleEndian)?.ok_or_else(
+                            || DimseError::InvalidMessage("empty command".to_owned()),
+                        )?;
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(ctx_id = msg_ctx_id, "received command");
+                    if !command_has_dataset(&decoded) {
+                        return Ok(Some(Message::new(msg_ctx_id, decoded, None)));
+                    }
+                    command = Some(decoded);
+                } else {
+                    let Some(decoded) = command.take() else {
+                        return Err(DimseError::InvalidMessage(
+                            "dataset received before command".to_owned(),
+                        ));
+                    };
+                    dataset_bytes.extend(pdv.into_data());
+                    if !is_last {
+                        command = Some(decoded);
+                        continue;
+                    }
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(
+                        ctx_id = msg_ctx_id,
+                        len = dataset_bytes.len(),
+                        "received dataset"
+                    );
+                    return Ok(Some(Message::new(
+                        msg_ctx_id,
+                        decoded,
+                        Some(std::mem::take(&mut dataset_bytes)),
+                    )));
+                }
+            }
+        }
+    }
+
+    /// Writes the message to the stream, fragmenting the command and dataset as needed to respect
+    /// the maximum PDU length of the remote AE.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(peer_ae = %self.peer_ae, ctx_id = message.ctx_id())
+        )
+    )]
+    pub fn write_message<W: Write>(
+        &self,
+        message: &Message,
+        stream: &mut W,
+    ) -> Result<(), DimseError> {
+        if self.get_pres_ctx(message.ctx_id()).is_none() {
+            return Err(DimseError::UnknownPresentationContext(message.ctx_id()));
+        }
+
+        let command: Vec<u8> = encode_dataset(message.command(), &ts::ImplicitVRLittleEndian)?;
+        self.write_fragments(
+            message.ctx_id(),
+            PresentationDataValue::MSG_HEADER_COMMAND,
+            &command,
+            stream,
+        )?;
+        if let Some(dataset) = message.dataset() {
+            self.write_fragments(message.ctx_id(), 0, dataset, stream)?;
+        }
+        Ok(())
+    }
+
+    /// Requests release of the association, waiting for the remote AE to confirm.
+    pub fn release<S: Read + Write>(&self, stream: &mut S) -> Result<(), DimseError> {
+        #[cfg(feature = "tracing")]
+        tracing::info!(peer_ae = %self.peer_ae, "releasing association");
+        Pdu::ReleaseRQ(ReleaseRQ::new()).write(stream)?;
+        loop {
+            match Pdu::read_max(stream, self.max_pdu_length)? {
+                Pdu::ReleaseRP(_rp) => return Ok(()),
+                Pdu::Abort(abort) => {
+                    return Err(DimseError::AssociationAborted {
+                        abort_source: abort.source(),
+                        reason: abort.reason(),
+                    })
+                }
+                // Any remaining responses in-flight when the release was requested are dropped.
+                Pdu::PresentationDataItem(_pdata) => continue,
+                other => return Err(DimseError::UnexpectedPdu(other.pdu_type())),
+            }
+        }
+    }
+
+    /// Aborts the association.
+    pub fn abort<W: Write>(&self, stream: &mut W) -> Result<(), DimseError> {
+        #[cfg(feature = "tracing")]
+        tracing::warn!(peer_ae = %self.peer_ae, "aborting association");
+        Pdu::Abort(Abort::new(
+            ABORT_SOURCE_SERVICE_PROVIDER,
+            ABORT_REASON_UNEXPECTED_PDU,
+        ))
+        .write(stream)
+    }
+
+    fn write_fragments<W: Write>(
+        &self,
+        ctx_id: u8,
+        msg_header: u8,
+        data: &[u8],
+        stream: &mut W,
+    ) -> Result<(), DimseError> {
+        // Each PDV within the P-DATA-TF has a 4-byte length, and 1-byte each for context ID and
+        // message header.
+        let max_fragment: usize = match self.peer_max_pdu_length {
+            0 => data.len().max(1),
+            max => (max as usize).saturating_sub(6).max(1),
+        };
+
+        let mut chunks = data.chunks(max_fragment).peekable();
+        if chunks.peek().is_none() {
+            let pdv = PresentationDataValue::new(
+                ctx_id,
+                msg_header | PresentationDataValue::MSG_HEADER_LAST,
+                Vec::new(),
+            );
+            return Pdu::PresentationDataItem(PresentationDataItem::new(vec![pdv])).write(stream);
+        }
+
+        while let Some(chunk) = chunks.next() {
+            let header: u8 = if chunks.peek().is_none() {
+                msg_header | PresentationDataValue::MSG_HEADER_LAST
+            } else {
+                msg_header
+            };
+            let pdv = PresentationDataValue::new(ctx_id, header, chunk.to_vec());
+            Pdu::PresentationDataItem(PresentationDataItem::new(vec![pdv])).write(stream)?;
+        }
+        Ok(())
+    }
+}
+
+/// The lesser of two operation limits, where zero indicates no limit.
+fn min_ops(a: u16, b: u16) -> u16 {
+    match (a, b) {
+        (0, limit) | (limit, 0) => limit,
+        (a, b) => a.min(b),
+    }
+}
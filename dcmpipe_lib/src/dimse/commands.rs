@@ -0,0 +1,540 @@
+//! DIMSE messages, a Command optionally followed by a Data Set. Part 7, Chapter 6.3
+//!
+//! Commands are always encoded with `ImplicitVRLittleEndian` while the Data Set is encoded using
+//! the transfer syntax negotiated for the presentation context the message is sent over.
+
+use std::{
+    collections::BTreeMap,
+    io::{Cursor, Read, Write},
+};
+
+use crate::{
+    core::{
+        charset::DEFAULT_CHARACTER_SET,
+        dcmelement::DicomElement,
+        dcmobject::{DicomObject, DicomRoot},
+        defn::{
+            constants::{
+                tags::{FILE_META_GROUP_END, FILE_META_INFORMATION_GROUP_LENGTH},
+                ts::ImplicitVRLittleEndian,
+            },
+            tag::TagRef,
+            ts::TSRef,
+            vl::ValueLength,
+            vr::{self, VRRef},
+        },
+        read::{Parser, ParserBuilder, ParserState},
+        write::{
+            builder::WriterBuilder,
+            writer::{Writer, WriterState},
+        },
+        RawValue,
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags},
+};
+
+use super::{
+    assoc::Association,
+    constants::{status, CommandField, Priority, Status, COMMAND_DATASET_TYPE_NONE},
+    error::DimseError,
+};
+
+/// A DIMSE message, received from or to be sent over an association.
+pub struct Message {
+    ctx_id: u8,
+    command: DicomRoot<'static>,
+    dataset: Option<Vec<u8>>,
+}
+
+impl Message {
+    /// Creates a message to be sent over the given presentation context. The `dataset` should
+    /// already be encoded in the transfer syntax of the presentation context, see
+    /// `encode_dataset()`.
+    pub fn new(ctx_id: u8, command: DicomRoot<'static>, dataset: Option<Vec<u8>>) -> Message {
+        Message {
+            ctx_id,
+            command,
+            dataset,
+        }
+    }
+
+    /// The presentation context ID the message was received on or is to be sent over.
+    pub fn ctx_id(&self) -> u8 {
+        self.ctx_id
+    }
+
+    /// The Command of the message.
+    pub fn command(&self) -> &DicomRoot<'static> {
+        &self.command
+    }
+
+    /// The encoded Data Set of the message, if present.
+    pub fn dataset(&self) -> Option<&Vec<u8>> {
+        self.dataset.as_ref()
+    }
+
+    /// Takes the encoded Data Set from this message, if present.
+    pub fn take_dataset(&mut self) -> Option<Vec<u8>> {
+        self.dataset.take()
+    }
+
+    /// The `CommandField` of the message. This is `None` if the command is missing the field or it
+    /// has an unknown value.
+    pub fn command_field(&self) -> Option<CommandField> {
+        self.get_ushort(&tags::CommandField)
+            .and_then(|field| CommandField::try_from(u32::from(field)).ok())
+    }
+
+    /// The `MessageID` of a request message.
+    pub fn message_id(&self) -> Option<u16> {
+        self.get_ushort(&tags::MessageID)
+    }
+
+    /// The `MessageIDBeingRespondedTo` of a response message.
+    pub fn message_id_responded_to(&self) -> Option<u16> {
+        self.get_ushort(&tags::MessageIDBeingRespondedTo)
+    }
+
+    /// The `Status` of a response message.
+    pub fn status(&self) -> Option<u16> {
+        self.get_ushort(&tags::Status)
+    }
+
+    /// The `AffectedSOPClassUID` of the message.
+    pub fn affected_sop_class_uid(&self) -> Option<String> {
+        self.get_string(&tags::AffectedSOPClassUID)
+    }
+
+    /// The `AffectedSOPInstanceUID` of the message.
+    pub fn affected_sop_instance_uid(&self) -> Option<String> {
+        self.get_string(&tags::AffectedSOPInstanceUID)
+    }
+
+    /// The `RequestedSOPClassUID` of the message.
+    pub fn requested_sop_class_uid(&self) -> Option<String> {
+        self.get_string(&tags::RequestedSOPClassUID)
+    }
+
+    /// The `RequestedSOPInstanceUID` of the message.
+    pub fn requested_sop_instance_uid(&self) -> Option<String> {
+        self.get_string(&tags::RequestedSOPInstanceUID)
+    }
+
+    /// The `ActionTypeID` of an N-ACTION message.
+    pub fn action_type_id(&self) -> Option<u16> {
+        self.get_ushort(&tags::ActionTypeID)
+    }
+
+    /// The `EventTypeID` of an N-EVENT-REPORT message.
+    pub fn event_type_id(&self) -> Option<u16> {
+        self.get_ushort(&tags::EventTypeID)
+    }
+
+    /// The `ErrorComment` of a response message.
+    pub fn error_comment(&self) -> Option<String> {
+        self.get_string(&tags::ErrorComment)
+    }
+
+    /// Whether the command indicates a Data Set follows it.
+    pub fn has_dataset(&self) -> bool {
+        command_has_dataset(&self.command)
+    }
+
+    /// Decodes the Data Set of this message using the given transfer syntax, which should be the
+    /// transfer syntax of the presentation context the message was received on.
+    pub fn decode_dataset(&self, ts: TSRef) -> Result<Option<DicomRoot<'static>>, DimseError> {
+        match &self.dataset {
+            None => Ok(None),
+            Some(dataset) => decode_dataset(dataset, ts),
+        }
+    }
+
+    fn get_ushort(&self, tag: TagRef) -> Option<u16> {
+        self.command
+            .get_child_by_tag(tag.tag)
+            .and_then(|obj| u16::try_from(obj.element()).ok())
+    }
+
+    fn get_string(&self, tag: TagRef) -> Option<String> {
+        self.command
+            .get_child_by_tag(tag.tag)
+            .and_then(|obj| String::try_from(obj.element()).ok())
+    }
+}
+
+/// Builds the Command portion of a DIMSE message. The `CommandGroupLength` is computed when built.
+pub struct CommandBuilder {
+    values: Vec<(TagRef, RawValue)>,
+    has_dataset: bool,
+}
+
+impl CommandBuilder {
+    pub fn new(command_field: CommandField) -> CommandBuilder {
+        CommandBuilder {
+            values: vec![(
+                &tags::CommandField,
+                RawValue::UnsignedShorts(vec![command_field as u16]),
+            )],
+            has_dataset: false,
+        }
+    }
+
+    /// Creates a C-ECHO-RQ command.
+    pub fn c_echo_rq(msg_id: u16, sop_class_uid: &str) -> CommandBuilder {
+        CommandBuilder::new(CommandField::CEchoReq)
+            .uid(&tags::AffectedSOPClassUID, sop_class_uid)
+            .ushort(&tags::MessageID, msg_id)
+    }
+
+    /// Creates a C-ECHO-RSP command, in response to the given C-ECHO-RQ.
+    pub fn c_echo_rsp(rq: &Message, status: u16) -> CommandBuilder {
+        CommandBuilder::rsp(CommandField::CEchoRsp, rq, status)
+    }
+
+    /// Creates a C-FIND-RQ command. The query Identifier is sent as the message Data Set.
+    pub fn c_find_rq(msg_id: u16, sop_class_uid: &str, priority: Priority) -> CommandBuilder {
+        CommandBuilder::new(CommandField::CFindReq)
+            .uid(&tags::AffectedSOPClassUID, sop_class_uid)
+            .ushort(&tags::MessageID, msg_id)
+            .ushort(&tags::Priority, priority as u16)
+            .dataset(true)
+    }
+
+    /// Creates a C-FIND-RSP command, in response to the given C-FIND-RQ. Pending responses should
+    /// include the matching Identifier as the message Data Set.
+    pub fn c_find_rsp(rq: &Message, status: u16) -> CommandBuilder {
+        CommandBuilder::rsp(CommandField::CFindRsp, rq, status)
+    }
+
+    /// Creates a C-STORE-RQ command. The SOP Instance to store is sent as the message Data Set.
+    pub fn c_store_rq(
+        msg_id: u16,
+        sop_class_uid: &str,
+        sop_instance_uid: &str,
+        priority: Priority,
+    ) -> CommandBuilder {
+        CommandBuilder::new(CommandField::CStoreReq)
+            .uid(&tags::AffectedSOPClassUID, sop_class_uid)
+            .ushort(&tags::MessageID, msg_id)
+            .ushort(&tags::Priority, priority as u16)
+            .uid(&tags::AffectedSOPInstanceUID, sop_instance_uid)
+            .dataset(true)
+    }
+
+    /// Creates a C-STORE-RSP command, in response to the given C-STORE-RQ.
+    pub fn c_store_rsp(rq: &Message, status: u16) -> CommandBuilder {
+        let builder: CommandBuilder = CommandBuilder::rsp(CommandField::CStoreRsp, rq, status);
+        match rq.affected_sop_instance_uid() {
+            Some(sop_instance_uid) => builder.uid(&tags::AffectedSOPInstanceUID, &sop_instance_uid),
+            None => builder,
+        }
+    }
+
+    /// Creates an N-CREATE-RQ command. The attributes of the SOP Instance to create are sent as
+    /// the message Data Set. If `sop_instance_uid` is `None` the remote AE assigns one, which is
+    /// returned in the N-CREATE-RSP.
+    pub fn n_create_rq(
+        msg_id: u16,
+        sop_class_uid: &str,
+        sop_instance_uid: Option<&str>,
+    ) -> CommandBuilder {
+        let builder: CommandBuilder = CommandBuilder::new(CommandField::NCreateReq)
+            .uid(&tags::AffectedSOPClassUID, sop_class_uid)
+            .ushort(&tags::MessageID, msg_id)
+            .dataset(true);
+        match sop_instance_uid {
+            Some(sop_instance_uid) => builder.uid(&tags::AffectedSOPInstanceUID, sop_instance_uid),
+            None => builder,
+        }
+    }
+
+    /// Creates an N-CREATE-RSP command, in response to the given N-CREATE-RQ. If the request did
+    /// not specify the SOP Instance UID then the one assigned should be added.
+    pub fn n_create_rsp(rq: &Message, status: u16) -> CommandBuilder {
+        let builder: CommandBuilder = CommandBuilder::rsp(CommandField::NCreateRsp, rq, status);
+        match rq.affected_sop_instance_uid() {
+            Some(sop_instance_uid) => builder.uid(&tags::AffectedSOPInstanceUID, &sop_instance_uid),
+            None => builder,
+        }
+    }
+
+    /// Creates an N-SET-RQ command. The modifications to the SOP Instance are sent as the message
+    /// Data Set.
+    pub fn n_set_rq(msg_id: u16, sop_class_uid: &str, sop_instance_uid: &str) -> CommandBuilder {
+        CommandBuilder::new(CommandField::NSetReq)
+            .uid(&tags::RequestedSOPClassUID, sop_class_uid)
+            .ushort(&tags::MessageID, msg_id)
+            .uid(&tags::RequestedSOPInstanceUID, sop_instance_uid)
+            .dataset(true)
+    }
+
+    /// Creates an N-SET-RSP command, in response to the given N-SET-RQ. The requested SOP Class
+    /// and Instance are returned as the affected ones.
+    pub fn n_set_rsp(rq: &Message, status: u16) -> CommandBuilder {
+        CommandBuilder::rsp(CommandField::NSetRsp, rq, status).requested_as_affected(rq)
+    }
+
+    /// Creates an N-ACTION-RQ command. Any information for the action is sent as the message Data
+    /// Set.
+    pub fn n_action_rq(
+        msg_id: u16,
+        sop_class_uid: &str,
+        sop_instance_uid: &str,
+        action_type_id: u16,
+    ) -> CommandBuilder {
+        CommandBuilder::new(CommandField::NActionReq)
+            .uid(&tags::RequestedSOPClassUID, sop_class_uid)
+            .ushort(&tags::MessageID, msg_id)
+            .uid(&tags::RequestedSOPInstanceUID, sop_instance_uid)
+            .ushort(&tags::ActionTypeID, action_type_id)
+            .dataset(true)
+    }
+
+    /// Creates an N-ACTION-RSP command, in response to the given N-ACTION-RQ. The requested SOP
+    /// Class and Instance are returned as the affected ones.
+    pub fn n_action_rsp(rq: &Message, status: u16) -> CommandBuilder {
+        let mut builder: CommandBuilder = CommandBuilder::rsp(CommandField::NActionRsp, rq, status);
+        if let Some(action_type_id) = rq.action_type_id() {
+            builder = builder.ushort(&tags::ActionTypeID, action_type_id);
+        }
+        builder.requested_as_affected(rq)
+    }
+
+    /// Creates an N-EVENT-REPORT-RQ command. Any information about the event is sent as the
+    /// message Data Set.
+    pub fn n_event_report_rq(
+        msg_id: u16,
+        sop_class_uid: &str,
+        sop_instance_uid: &str,
+        event_type_id: u16,
+    ) -> CommandBuilder {
+        CommandBuilder::new(CommandField::NEventReportReq)
+            .uid(&tags::AffectedSOPClassUID, sop_class_uid)
+            .ushort(&tags::MessageID, msg_id)
+            .uid(&tags::AffectedSOPInstanceUID, sop_instance_uid)
+            .ushort(&tags::EventTypeID, event_type_id)
+            .dataset(true)
+    }
+
+    /// Creates an N-EVENT-REPORT-RSP command, in response to the given N-EVENT-REPORT-RQ.
+    pub fn n_event_report_rsp(rq: &Message, status: u16) -> CommandBuilder {
+        let mut builder: CommandBuilder =
+            CommandBuilder::rsp(CommandField::NEventReportRsp, rq, status);
+        if let Some(event_type_id) = rq.event_type_id() {
+            builder = builder.ushort(&tags::EventTypeID, event_type_id);
+        }
+        match rq.affected_sop_instance_uid() {
+            Some(sop_instance_uid) => builder.uid(&tags::AffectedSOPInstanceUID, &sop_instance_uid),
+            None => builder,
+        }
+    }
+
+    /// Creates a response command for the given request, including the fields common to all
+    /// responses.
+    pub fn rsp(command_field: CommandField, rq: &Message, status: u16) -> CommandBuilder {
+        let mut builder: CommandBuilder = CommandBuilder::new(command_field);
+        if let Some(sop_class_uid) = rq.affected_sop_class_uid() {
+            builder = builder.uid(&tags::AffectedSOPClassUID, &sop_class_uid);
+        }
+        if let Some(msg_id) = rq.message_id() {
+            builder = builder.ushort(&tags::MessageIDBeingRespondedTo, msg_id);
+        }
+        builder.ushort(&tags::Status, status)
+    }
+
+    /// Adds the requested SOP Class and Instance of the request as the affected ones, for
+    /// responses to DIMSE-N requests which address an existing SOP Instance.
+    fn requested_as_affected(mut self, rq: &Message) -> Self {
+        if let Some(sop_class_uid) = rq.requested_sop_class_uid() {
+            self = self.uid(&tags::AffectedSOPClassUID, &sop_class_uid);
+        }
+        match rq.requested_sop_instance_uid() {
+            Some(sop_instance_uid) => self.uid(&tags::AffectedSOPInstanceUID, &sop_instance_uid),
+            None => self,
+        }
+    }
+
+    /// Adds a UID value to the command.
+    pub fn uid(mut self, tag: TagRef, uid: &str) -> Self {
+        self.values.push((tag, RawValue::Uid(uid.to_owned())));
+        self
+    }
+
+    /// Adds an unsigned short value to the command.
+    pub fn ushort(mut self, tag: TagRef, value: u16) -> Self {
+        self.values
+            .push((tag, RawValue::UnsignedShorts(vec![value])));
+        self
+    }
+
+    /// Adds a string value to the command.
+    pub fn string(mut self, tag: TagRef, value: &str) -> Self {
+        self.values
+            .push((tag, RawValue::Strings(vec![value.to_owned()])));
+        self
+    }
+
+    /// Sets whether a Data Set will follow the command.
+    pub fn dataset(mut self, has_dataset: bool) -> Self {
+        self.has_dataset = has_dataset;
+        self
+    }
+
+    /// Encodes the values into the Command.
+    pub fn build(self) -> Result<DicomRoot<'static>, DimseError> {
+        let ts: TSRef = &ImplicitVRLittleEndian;
+        let dataset_type: u16 = if self.has_dataset {
+            // Any value other than the sentinel indicates a Data Set is present.
+            0x0000
+        } else {
+            COMMAND_DATASET_TYPE_NONE as u16
+        };
+
+        let mut child_nodes: BTreeMap<u32, DicomObject> = BTreeMap::new();
+        let values = self.values.into_iter().chain(std::iter::once((
+            &tags::CommandDataSetType,
+            RawValue::UnsignedShorts(vec![dataset_type]),
+        )));
+        for (tag, value) in values {
+            let vr = tag.implicit_vr.ok_or_else(|| {
+                DimseError::InvalidMessage(format!("command element has no VR: {}", tag.ident))
+            })?;
+            let mut element: DicomElement = DicomElement::new_empty(tag, vr, ts);
+            element.encode_value(value, None)?;
+            child_nodes.insert(tag.tag, DicomObject::new(element));
+        }
+
+        // The group length is the number of bytes following the group length element, which for
+        // implicit VR is the tag, value length, and value of each element.
+        let group_length: u32 = child_nodes
+            .values()
+            .map(|obj| 8 + obj.element().data().len() as u32)
+            .sum::<u32>();
+        let mut group_length_elem: DicomElement =
+            DicomElement::new_empty(&tags::CommandGroupLength, &vr::UL, ts);
+        group_length_elem.encode_value(RawValue::UnsignedIntegers(vec![group_length]), None)?;
+        child_nodes.insert(
+            tags::CommandGroupLength.tag,
+            DicomObject::new(group_length_elem),
+        );
+
+        Ok(DicomRoot::new(
+            ts,
+            DEFAULT_CHARACTER_SET,
+            &STANDARD_DICOM_DICTIONARY,
+            child_nodes,
+            Vec::new(),
+        ))
+    }
+}
+
+/// Whether the `CommandDataSetType` of the command indicates a Data Set follows it.
+pub(crate) fn command_has_dataset(command: &DicomRoot<'_>) -> bool {
+    command
+        .get_child_by_tag(tags::CommandDataSetType.tag)
+        .and_then(|obj| u16::try_from(obj.element()).ok())
+        .is_some_and(|dataset_type| u32::from(dataset_type) != COMMAND_DATASET_TYPE_NONE)
+}
+
+/// Encodes a Command or Data Set into bytes for sending in a message. Unlike DICOM files this has
+/// no preamble or File Meta group. As with `Writer`, elements are written using their own transfer
+/// syntax so the elements should have been created with `ts`.
+pub fn encode_dataset(dcmroot: &DicomRoot<'_>, ts: TSRef) -> Result<Vec<u8>, DimseError> {
+    encode_elements(dcmroot.flatten()?.into_iter(), ts)
+}
+
+/// Encodes flattened elements into bytes for sending in a message, such as those produced by
+/// `transcode`. Elements of the File Meta group are skipped, so a dataset parsed from a file can be
+/// sent as-is.
+pub fn encode_elements<'a, E>(elements: E, ts: TSRef) -> Result<Vec<u8>, DimseError>
+where
+    E: Iterator<Item = &'a DicomElement<'a>>,
+{
+    let mut writer: Writer<Vec<u8>> = WriterBuilder::default()
+        .state(WriterState::Element)
+        .ts(ts)
+        .build(Vec::new());
+    let file_meta = FILE_META_INFORMATION_GROUP_LENGTH..=FILE_META_GROUP_END;
+    writer.write_elements(elements.filter(|element| !file_meta.contains(&element.tag())))?;
+    Ok(writer.into_dataset()?)
+}
+
+/// Decodes a Command or Data Set received in a message, which was encoded with the given transfer
+/// syntax. Returns `None` if the bytes contain no elements.
+pub fn decode_dataset(bytes: &[u8], ts: TSRef) -> Result<Option<DicomRoot<'static>>, DimseError> {
+    let mut parser: Parser<'static, Cursor<&[u8]>> = ParserBuilder::default()
+        .state(ParserState::Element)
+        .dataset_ts(ts)
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .build(Cursor::new(bytes));
+    Ok(DicomRoot::parse(&mut parser)?)
+}
+
+/// The ID and transfer syntax of the first presentation context accepted for the SOP Class, for
+/// sending requests of that SOP Class.
+pub(crate) fn pres_ctx_for(
+    assoc: &Association,
+    sop_class_uid: &str,
+) -> Result<(u8, TSRef), DimseError> {
+    assoc
+        .get_pres_ctx_by_abstract_syntax(sop_class_uid)
+        .map(|pres_ctx| (pres_ctx.ctx_id(), pres_ctx.ts()))
+        .ok_or_else(|| DimseError::AbstractSyntaxNotAccepted(sop_class_uid.to_owned()))
+}
+
+/// Sends a request and waits for its response, which must be of the expected command and not have
+/// a failure status.
+pub(crate) fn send_request<S: Read + Write>(
+    assoc: &Association,
+    stream: &mut S,
+    rq: Message,
+    rsp_field: CommandField,
+) -> Result<Message, DimseError> {
+    assoc.write_message(&rq, stream)?;
+    let rsp: Message = assoc.read_message(stream)?.ok_or_else(|| {
+        DimseError::InvalidMessage(format!("association released awaiting {rsp_field:?}"))
+    })?;
+    if rsp.command_field() != Some(rsp_field) || rsp.message_id_responded_to() != rq.message_id() {
+        return Err(DimseError::InvalidMessage(format!(
+            "unexpected response to {:?}: {:?}",
+            rq.command_field(),
+            rsp.command_field()
+        )));
+    }
+
+    let status: u16 = rsp.status().unwrap_or(status::UNABLE_TO_PROCESS);
+    match Status::try_from(u32::from(status)) {
+        Ok(Status::Success(_) | Status::Warning(_)) => Ok(rsp),
+        _ => Err(DimseError::FailureStatus {
+            status,
+            error_comment: rsp.error_comment(),
+        }),
+    }
+}
+
+/// Creates an element of a Data Set with the given string value, or empty if `None`.
+pub(crate) fn element(
+    tag: TagRef,
+    vr: VRRef,
+    value: Option<&str>,
+    ts: TSRef,
+) -> Result<DicomElement<'static>, DimseError> {
+    let mut elem: DicomElement = DicomElement::new(
+        tag,
+        vr,
+        ValueLength::Explicit(0),
+        ts,
+        DEFAULT_CHARACTER_SET,
+        Vec::new(),
+        Vec::new(),
+    );
+    if let Some(value) = value {
+        let value: RawValue = if vr == &vr::UI {
+            RawValue::Uid(value.to_owned())
+        } else {
+            RawValue::Strings(vec![value.to_owned()])
+        };
+        elem.encode_value(value, None)?;
+    }
+    Ok(elem)
+}
@@ -1,5 +1,24 @@
 //! Constants for DIMSE, DICOM Message Exchange
 
+/// The maximum PDU length this implementation will receive, unless otherwise configured. This is
+/// the length of the variable field of P-DATA-TF PDUs, sent in the Maximum Length sub-item.
+pub const DEFAULT_MAX_PDU_LENGTH: u32 = 16 * 1024;
+
+/// The maximum length of the A-ASSOCIATE-RQ and A-ASSOCIATE-AC PDUs this implementation will
+/// receive, whose length isn't negotiated. This is well above the length of a request proposing
+/// the maximum of 128 presentation contexts.
+pub const MAX_ASSOC_PDU_LENGTH: u32 = 1024 * 1024;
+
+/// The maximum length of a message this implementation will receive, unless otherwise configured.
+/// This is the combined length of the command and dataset assembled from P-DATA-TF fragments.
+pub const DEFAULT_MAX_MESSAGE_LENGTH: u64 = 2 * 1024 * 1024 * 1024;
+
+/// The Implementation Class UID sent during association negotiation.
+pub const IMPLEMENTATION_CLASS_UID: &str = "2.25.221314879678758397446766468716062916421";
+
+/// The Implementation Version Name sent during association negotiation.
+pub const IMPLEMENTATION_VERSION_NAME: &str = "DCMPIPE_0.1";
+
 /// Values of the `CommandField` (0000,0100) field of messages.
 ///
 /// See Part 7, Appendix E.
@@ -141,6 +160,44 @@ impl TryFrom<u32> for Status {
     }
 }
 
+/// Commonly used values of the `Status` (0000,0900) field of messages.
+///
+/// See Part 7, Appendix C.
+pub mod status {
+    /// Success.
+    pub const SUCCESS: u16 = 0x0000;
+    /// Matches are continuing, the response has a matching Identifier.
+    pub const PENDING: u16 = 0xFF00;
+    /// Matches are continuing, but one or more Optional Keys were not supported for matching.
+    pub const PENDING_WARNING: u16 = 0xFF01;
+    /// Sub-operations terminated due to a Cancel indication.
+    pub const CANCEL: u16 = 0xFE00;
+    /// Failure, no such attribute.
+    pub const NO_SUCH_ATTRIBUTE: u16 = 0x0105;
+    /// Failure, invalid attribute value.
+    pub const INVALID_ATTRIBUTE_VALUE: u16 = 0x0106;
+    /// Failure, processing failure.
+    pub const PROCESSING_FAILURE: u16 = 0x0110;
+    /// Failure, no such action type.
+    pub const NO_SUCH_ACTION_TYPE: u16 = 0x0123;
+    /// Failure, duplicate SOP Instance.
+    pub const DUPLICATE_SOP_INSTANCE: u16 = 0x0111;
+    /// Failure, no such SOP Instance.
+    pub const NO_SUCH_SOP_INSTANCE: u16 = 0x0112;
+    /// Failure, invalid SOP Instance.
+    pub const INVALID_SOP_INSTANCE: u16 = 0x0117;
+    /// Failure, missing attribute.
+    pub const MISSING_ATTRIBUTE: u16 = 0x0120;
+    /// Refused, SOP Class not supported.
+    pub const SOP_CLASS_NOT_SUPPORTED: u16 = 0x0122;
+    /// Refused, out of resources.
+    pub const OUT_OF_RESOURCES: u16 = 0xA700;
+    /// Error, Identifier does not match SOP Class.
+    pub const IDENTIFIER_DOES_NOT_MATCH: u16 = 0xA900;
+    /// Failed, unable to process.
+    pub const UNABLE_TO_PROCESS: u16 = 0xC000;
+}
+
 #[cfg(test)]
 mod tests {
     use super::{CommandField, Priority};
@@ -0,0 +1,77 @@
+//! Errors that can occur during DIMSE communication.
+
+use thiserror::Error;
+
+use crate::core::{read::ParseError, write::error::WriteError};
+
+use super::pdus::PduType;
+
+#[derive(Error, Debug)]
+/// Errors that can occur during DIMSE communication.
+pub enum DimseError {
+    /// The PDU type read from the stream is not a known PDU type.
+    #[error("invalid pdu type: {0:#04X}")]
+    InvalidPduType(u8),
+
+    /// A valid PDU was received but is not appropriate for the current state of the association.
+    #[error("unexpected pdu: {0:?}")]
+    UnexpectedPdu(PduType),
+
+    /// The PDU structure is invalid, such as a length that does not agree with its content.
+    #[error("invalid pdu: {0}")]
+    InvalidPdu(String),
+
+    /// The association request was rejected by the remote AE.
+    #[error("association rejected, result: {result}, source: {rj_source}, reason: {reason}")]
+    AssociationRejected {
+        result: u8,
+        rj_source: u8,
+        reason: u8,
+    },
+
+    /// The association was aborted by the remote AE.
+    #[error("association aborted, source: {abort_source}, reason: {reason}")]
+    AssociationAborted { abort_source: u8, reason: u8 },
+
+    /// More presentation contexts were to be proposed than can be identified in an association.
+    #[error("too many presentation contexts proposed: {0}, the maximum is 128")]
+    TooManyPresentationContexts(usize),
+
+    /// A message referred to a presentation context which was not negotiated.
+    #[error("no accepted presentation context with id: {0}")]
+    UnknownPresentationContext(u8),
+
+    /// The remote AE did not accept a presentation context for the SOP Class needed.
+    #[error("no presentation context accepted for abstract syntax: {0}")]
+    AbstractSyntaxNotAccepted(String),
+
+    /// The remote AE responded to a request with a failure status.
+    #[error("request failed with status: {status:#06X}, {}", error_comment.as_deref().unwrap_or("no error comment"))]
+    FailureStatus {
+        status: u16,
+        error_comment: Option<String>,
+    },
+
+    /// A message was received whose command and dataset are longer than the maximum message length.
+    #[error("message longer than the maximum length: {0}")]
+    MessageTooLong(u64),
+
+    /// A message was received whose command or dataset could not be interpreted.
+    #[error("invalid dimse message: {0}")]
+    InvalidMessage(String),
+
+    /// Wrapper around `ParseError` for errors decoding a command or dataset.
+    #[error("error decoding message")]
+    ParseError(#[from] ParseError),
+
+    /// Wrapper around `WriteError` for errors encoding a command or dataset.
+    #[error("error encoding message")]
+    WriteError(#[from] WriteError),
+
+    /// Wrapper around `std::io::Error`.
+    #[error("i/o error communicating with remote ae")]
+    IOError {
+        #[from]
+        source: std::io::Error,
+    },
+}
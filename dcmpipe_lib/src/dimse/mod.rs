@@ -1,2 +1,9 @@
+pub mod assoc;
+pub mod commands;
 pub mod constants;
+pub mod error;
+pub mod mpps;
+pub mod mwl;
 pub mod pdus;
+pub mod query;
+pub mod stgcmt;
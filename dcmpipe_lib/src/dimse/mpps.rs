@@ -0,0 +1,186 @@
+//! Modality Performed Procedure Step, reporting the progress of an acquisition with N-CREATE and
+//! N-SET. Part 4, Annex F.7.
+//!
+//! A modality creates the Performed Procedure Step with a status of `IN PROGRESS` when acquisition
+//! starts, then updates it as images are acquired and finally to `COMPLETED` or `DISCONTINUED`.
+//! After which it may no longer be updated.
+
+use std::io::{Read, Write};
+
+use crate::{
+    core::dcmobject::DicomRoot,
+    dict::{tags, uids},
+};
+
+use super::{
+    assoc::Association,
+    commands::{encode_dataset, pres_ctx_for, send_request, CommandBuilder, Message},
+    constants::{status, CommandField},
+    error::DimseError,
+};
+
+/// Values of the `PerformedProcedureStepStatus` (0040,0252) attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PerformedProcedureStepStatus {
+    InProgress,
+    Completed,
+    Discontinued,
+}
+
+impl PerformedProcedureStepStatus {
+    /// The value of the attribute as encoded.
+    pub fn value(&self) -> &'static str {
+        match self {
+            PerformedProcedureStepStatus::InProgress => "IN PROGRESS",
+            PerformedProcedureStepStatus::Completed => "COMPLETED",
+            PerformedProcedureStepStatus::Discontinued => "DISCONTINUED",
+        }
+    }
+
+    /// Whether the Performed Procedure Step may no longer be updated.
+    pub fn is_final(&self) -> bool {
+        *self != PerformedProcedureStepStatus::InProgress
+    }
+
+    /// The status within the given Performed Procedure Step or modifications to one.
+    pub fn of(dataset: &DicomRoot<'_>) -> Option<PerformedProcedureStepStatus> {
+        dataset
+            .get_child_by_tag(tags::PerformedProcedureStepStatus.tag)
+            .and_then(|obj| String::try_from(obj.element()).ok())
+            .and_then(|value| PerformedProcedureStepStatus::try_from(value.as_str()).ok())
+    }
+}
+
+impl TryFrom<&str> for PerformedProcedureStepStatus {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim() {
+            "IN PROGRESS" => Ok(PerformedProcedureStepStatus::InProgress),
+            "COMPLETED" => Ok(PerformedProcedureStepStatus::Completed),
+            "DISCONTINUED" => Ok(PerformedProcedureStepStatus::Discontinued),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Creates the Performed Procedure Step with the given SOP Instance UID over the association. The
+/// `dataset` should have a `PerformedProcedureStepStatus` of `IN PROGRESS`. The Modality Performed
+/// Procedure Step SOP Class must have been accepted for the association.
+///
+/// If the remote AE responds with a failure status this returns `DimseError::FailureStatus`.
+pub fn create_mpps<S: Read + Write>(
+    assoc: &mut Association,
+    stream: &mut S,
+    sop_instance_uid: &str,
+    dataset: &DicomRoot<'_>,
+) -> Result<(), DimseError> {
+    let sop_class_uid: &str = uids::ModalityPerformedProcedureStepSOPClass.uid;
+    let msg_id: u16 = assoc.next_msg_id();
+    let rq = CommandBuilder::n_create_rq(msg_id, sop_class_uid, Some(sop_instance_uid));
+    let (ctx_id, ts) = pres_ctx_for(assoc, sop_class_uid)?;
+    let rq = Message::new(ctx_id, rq.build()?, Some(encode_dataset(dataset, ts)?));
+    send_request(assoc, stream, rq, CommandField::NCreateRsp)?;
+    Ok(())
+}
+
+/// Updates the Performed Procedure Step with the given SOP Instance UID over the association. The
+/// `modifications` contain only the attributes being updated. The Modality Performed Procedure
+/// Step SOP Class must have been accepted for the association.
+///
+/// If the remote AE responds with a failure status this returns `DimseError::FailureStatus`.
+pub fn set_mpps<S: Read + Write>(
+    assoc: &mut Association,
+    stream: &mut S,
+    sop_instance_uid: &str,
+    modifications: &DicomRoot<'_>,
+) -> Result<(), DimseError> {
+    let sop_class_uid: &str = uids::ModalityPerformedProcedureStepSOPClass.uid;
+    let msg_id: u16 = assoc.next_msg_id();
+    let rq = CommandBuilder::n_set_rq(msg_id, sop_class_uid, sop_instance_uid);
+    let (ctx_id, ts) = pres_ctx_for(assoc, sop_class_uid)?;
+    let rq = Message::new(
+        ctx_id,
+        rq.build()?,
+        Some(encode_dataset(modifications, ts)?),
+    );
+    send_request(assoc, stream, rq, CommandField::NSetRsp)?;
+    Ok(())
+}
+
+/// Receives the Performed Procedure Steps reported to an MPPS SCP, see `handle_mpps()`.
+///
+/// Each function returns the status to respond with on failure, such as
+/// `status::NO_SUCH_SOP_INSTANCE` if the instance to update doesn't exist, or
+/// `status::PROCESSING_FAILURE` if its status is final and it may no longer be updated.
+pub trait MppsHandler {
+    /// Called for an N-CREATE of a new Performed Procedure Step, whose status is `IN PROGRESS`.
+    fn create(&mut self, sop_instance_uid: &str, dataset: DicomRoot<'static>) -> Result<(), u16>;
+
+    /// Called for an N-SET of an existing Performed Procedure Step, with only the attributes
+    /// being modified.
+    fn set(&mut self, sop_instance_uid: &str, modifications: DicomRoot<'static>)
+        -> Result<(), u16>;
+}
+
+/// Handles an N-CREATE-RQ or N-SET-RQ received for the Modality Performed Procedure Step SOP
+/// Class, passing it to the handler and responding with the resulting status. The request is
+/// refused without calling the handler if it's missing the SOP Instance UID or has an invalid
+/// `PerformedProcedureStepStatus`.
+pub fn handle_mpps<S: Read + Write, H: MppsHandler>(
+    assoc: &Association,
+    rq: Message,
+    stream: &mut S,
+    handler: &mut H,
+) -> Result<(), DimseError> {
+    let ts = assoc
+        .get_pres_ctx(rq.ctx_id())
+        .ok_or(DimseError::UnknownPresentationContext(rq.ctx_id()))?
+        .ts();
+    let dataset: Option<DicomRoot<'static>> = rq.decode_dataset(ts)?;
+    let pps_status: Option<PerformedProcedureStepStatus> =
+        dataset.as_ref().and_then(PerformedProcedureStepStatus::of);
+
+    let rsp = match rq.command_field() {
+        Some(CommandField::NCreateReq) => {
+            let status: u16 = match (rq.affected_sop_instance_uid(), dataset) {
+                (None, _) => status::INVALID_SOP_INSTANCE,
+                (Some(_), None) => status::MISSING_ATTRIBUTE,
+                (Some(_), Some(_))
+                    if pps_status != Some(PerformedProcedureStepStatus::InProgress) =>
+                {
+                    status::INVALID_ATTRIBUTE_VALUE
+                }
+                (Some(uid), Some(dataset)) => handler
+                    .create(&uid, dataset)
+                    .err()
+                    .unwrap_or(status::SUCCESS),
+            };
+            CommandBuilder::n_create_rsp(&rq, status)
+        }
+        Some(CommandField::NSetReq) => {
+            let has_status: bool = dataset.as_ref().is_some_and(|dataset| {
+                dataset
+                    .get_child_by_tag(tags::PerformedProcedureStepStatus.tag)
+                    .is_some()
+            });
+            let status: u16 = match (rq.requested_sop_instance_uid(), dataset) {
+                (None, _) => status::INVALID_SOP_INSTANCE,
+                (Some(_), None) => status::MISSING_ATTRIBUTE,
+                (Some(_), Some(_)) if has_status && pps_status.is_none() => {
+                    status::INVALID_ATTRIBUTE_VALUE
+                }
+                (Some(uid), Some(dataset)) => {
+                    handler.set(&uid, dataset).err().unwrap_or(status::SUCCESS)
+                }
+            };
+            CommandBuilder::n_set_rsp(&rq, status)
+        }
+        other => {
+            return Err(DimseError::InvalidMessage(format!(
+                "unexpected request for mpps: {other:?}"
+            )))
+        }
+    };
+    assoc.write_message(&Message::new(rq.ctx_id(), rsp.build()?, None), stream)
+}
@@ -0,0 +1,307 @@
+//! Modality Worklist queries as a C-FIND SCU. Part 4, Annex K.
+//!
+//! A `WorklistQuery` builds the C-FIND Identifier for the Modality Worklist Information Model,
+//! matching on the attributes a device typically filters by (the scheduled date, its own Station
+//! AE Title, and its modality) and requesting the attributes needed to populate acquired images.
+//! Each match is returned as a `WorklistItem`.
+
+use std::io::{Read, Write};
+
+use crate::{
+    core::{
+        dcmelement::{delimiter, structural, DicomElement},
+        dcmobject::DicomRoot,
+        defn::{
+            constants::{
+                tags::{ITEM, ITEM_DELIMITATION_ITEM, SEQUENCE_DELIMITATION_ITEM},
+                ts::ImplicitVRLittleEndian,
+            },
+            tag::{TagNode, TagPath, TagRef},
+            ts::TSRef,
+            vr::{self, VRRef},
+        },
+    },
+    dict::{tags, uids},
+};
+
+use super::{
+    assoc::Association,
+    commands::{element, encode_elements, pres_ctx_for, CommandBuilder, Message},
+    constants::{status, CommandField, Priority},
+    error::DimseError,
+};
+
+/// The attributes requested of each Scheduled Procedure Step, along with those matched on.
+static SPS_RETURN_KEYS: [(TagRef, VRRef); 6] = [
+    (&tags::ScheduledProcedureStepStartTime, &vr::TM),
+    (&tags::ScheduledPerformingPhysiciansName, &vr::PN),
+    (&tags::ScheduledProcedureStepDescription, &vr::LO),
+    (&tags::ScheduledStationName, &vr::SH),
+    (&tags::ScheduledProcedureStepLocation, &vr::SH),
+    (&tags::ScheduledProcedureStepID, &vr::SH),
+];
+
+/// The attributes requested of each worklist item, along with those matched on.
+static RETURN_KEYS: [(TagRef, VRRef); 8] = [
+    (&tags::PatientsBirthDate, &vr::DA),
+    (&tags::PatientsSex, &vr::CS),
+    (&tags::StudyInstanceUID, &vr::UI),
+    (&tags::RequestedProcedureID, &vr::SH),
+    (&tags::RequestedProcedureDescription, &vr::LO),
+    (&tags::ReferringPhysiciansName, &vr::PN),
+    (&tags::AdmissionID, &vr::LO),
+    (&tags::SpecificCharacterSet, &vr::CS),
+];
+
+/// A query of the Modality Worklist. Attributes which aren't matched on are still returned.
+#[derive(Debug, Clone, Default)]
+pub struct WorklistQuery {
+    patient_name: Option<String>,
+    patient_id: Option<String>,
+    accession_number: Option<String>,
+    scheduled_station_ae: Option<String>,
+    scheduled_date: Option<String>,
+    modality: Option<String>,
+}
+
+impl WorklistQuery {
+    /// Matches the Patient's Name, which may include the `*` and `?` wildcards.
+    pub fn patient_name(mut self, patient_name: &str) -> Self {
+        self.patient_name = Some(patient_name.to_owned());
+        self
+    }
+
+    /// Matches the Patient ID.
+    pub fn patient_id(mut self, patient_id: &str) -> Self {
+        self.patient_id = Some(patient_id.to_owned());
+        self
+    }
+
+    /// Matches the Accession Number.
+    pub fn accession_number(mut self, accession_number: &str) -> Self {
+        self.accession_number = Some(accession_number.to_owned());
+        self
+    }
+
+    /// Matches the Scheduled Station AE Title, usually the AE Title of the device querying.
+    pub fn scheduled_station_ae(mut self, ae_title: &str) -> Self {
+        self.scheduled_station_ae = Some(ae_title.to_owned());
+        self
+    }
+
+    /// Matches the Scheduled Procedure Step Start Date, either a single date as `YYYYMMDD` or a
+    /// range such as `20240101-20240107`, where either end of the range may be omitted.
+    pub fn scheduled_date(mut self, date: &str) -> Self {
+        self.scheduled_date = Some(date.to_owned());
+        self
+    }
+
+    /// Matches the Modality of the Scheduled Procedure Step.
+    pub fn modality(mut self, modality: &str) -> Self {
+        self.modality = Some(modality.to_owned());
+        self
+    }
+
+    /// Creates the elements of the C-FIND Identifier, in the order they're encoded. The Scheduled
+    /// Procedure Step Sequence is given an undefined length and contains a single item.
+    pub fn to_elements(&self, ts: TSRef) -> Result<Vec<DicomElement<'static>>, DimseError> {
+        let mut elements: Vec<DicomElement> = Vec::new();
+        for (tag, vr, value) in [
+            (&tags::PatientsName, &vr::PN, &self.patient_name),
+            (&tags::PatientID, &vr::LO, &self.patient_id),
+            (&tags::AccessionNumber, &vr::SH, &self.accession_number),
+        ] {
+            elements.push(element(tag, vr, value.as_deref(), ts)?);
+        }
+        for (tag, vr) in RETURN_KEYS {
+            elements.push(element(tag, vr, None, ts)?);
+        }
+        elements.sort_by_key(DicomElement::tag);
+
+        let mut sps: Vec<DicomElement> = Vec::new();
+        for (tag, vr, value) in [
+            (
+                &tags::ScheduledStationAETitle,
+                &vr::AE,
+                &self.scheduled_station_ae,
+            ),
+            (
+                &tags::ScheduledProcedureStepStartDate,
+                &vr::DA,
+                &self.scheduled_date,
+            ),
+            (&tags::Modality, &vr::CS, &self.modality),
+        ] {
+            sps.push(element(tag, vr, value.as_deref(), ts)?);
+        }
+        for (tag, vr) in SPS_RETURN_KEYS {
+            sps.push(element(tag, vr, None, ts)?);
+        }
+        sps.sort_by_key(DicomElement::tag);
+
+        let sq_tag: u32 = tags::ScheduledProcedureStepSequence.tag;
+        let sq_pos: usize = elements.partition_point(|elem| elem.tag() < sq_tag);
+        let sequence = [
+            structural(sq_tag, &vr::SQ, ts),
+            // Items and delimiters are always encoded as Implicit VR, see Part 5, Section 7.5.
+            structural(ITEM, &vr::UN, &ImplicitVRLittleEndian),
+        ]
+        .into_iter()
+        .chain(sps)
+        .chain([
+            delimiter(ITEM_DELIMITATION_ITEM, &ImplicitVRLittleEndian),
+            delimiter(SEQUENCE_DELIMITATION_ITEM, &ImplicitVRLittleEndian),
+        ]);
+        elements.splice(sq_pos..sq_pos, sequence);
+        Ok(elements)
+    }
+}
+
+/// A scheduled procedure returned from a Modality Worklist query.
+#[derive(Debug)]
+pub struct WorklistItem {
+    dataset: DicomRoot<'static>,
+}
+
+impl WorklistItem {
+    /// The Identifier returned for the match.
+    pub fn dataset(&self) -> &DicomRoot<'static> {
+        &self.dataset
+    }
+
+    /// Consumes this item, returning the Identifier returned for the match.
+    pub fn into_dataset(self) -> DicomRoot<'static> {
+        self.dataset
+    }
+
+    pub fn patient_name(&self) -> Option<String> {
+        self.value(&tags::PatientsName)
+    }
+
+    pub fn patient_id(&self) -> Option<String> {
+        self.value(&tags::PatientID)
+    }
+
+    pub fn patient_birth_date(&self) -> Option<String> {
+        self.value(&tags::PatientsBirthDate)
+    }
+
+    pub fn patient_sex(&self) -> Option<String> {
+        self.value(&tags::PatientsSex)
+    }
+
+    pub fn accession_number(&self) -> Option<String> {
+        self.value(&tags::AccessionNumber)
+    }
+
+    pub fn study_instance_uid(&self) -> Option<String> {
+        self.value(&tags::StudyInstanceUID)
+    }
+
+    pub fn requested_procedure_id(&self) -> Option<String> {
+        self.value(&tags::RequestedProcedureID)
+    }
+
+    pub fn requested_procedure_description(&self) -> Option<String> {
+        self.value(&tags::RequestedProcedureDescription)
+    }
+
+    /// The Scheduled Station AE Title of the first Scheduled Procedure Step.
+    pub fn scheduled_station_ae(&self) -> Option<String> {
+        self.sps_value(&tags::ScheduledStationAETitle)
+    }
+
+    /// The Scheduled Procedure Step Start Date of the first Scheduled Procedure Step.
+    pub fn scheduled_date(&self) -> Option<String> {
+        self.sps_value(&tags::ScheduledProcedureStepStartDate)
+    }
+
+    /// The Scheduled Procedure Step Start Time of the first Scheduled Procedure Step.
+    pub fn scheduled_time(&self) -> Option<String> {
+        self.sps_value(&tags::ScheduledProcedureStepStartTime)
+    }
+
+    /// The Modality of the first Scheduled Procedure Step.
+    pub fn modality(&self) -> Option<String> {
+        self.sps_value(&tags::Modality)
+    }
+
+    /// The Scheduled Procedure Step ID of the first Scheduled Procedure Step.
+    pub fn scheduled_procedure_step_id(&self) -> Option<String> {
+        self.sps_value(&tags::ScheduledProcedureStepID)
+    }
+
+    /// The Scheduled Procedure Step Description of the first Scheduled Procedure Step.
+    pub fn scheduled_procedure_step_description(&self) -> Option<String> {
+        self.sps_value(&tags::ScheduledProcedureStepDescription)
+    }
+
+    fn sps_value(&self, tag: TagRef) -> Option<String> {
+        let tagpath: TagPath = vec![
+            TagNode::from((&tags::ScheduledProcedureStepSequence, Some(1))),
+            TagNode::from(tag),
+        ]
+        .into();
+        self.value_at(&tagpath)
+    }
+
+    fn value(&self, tag: TagRef) -> Option<String> {
+        self.value_at(&TagPath::from(vec![TagNode::from(tag)]))
+    }
+
+    /// The string value at the path, if present and not empty.
+    fn value_at(&self, tagpath: &TagPath) -> Option<String> {
+        self.dataset
+            .get_child_by_tagpath(tagpath)
+            .and_then(|obj| String::try_from(obj.element()).ok())
+            .filter(|value| !value.is_empty())
+    }
+}
+
+/// Queries the Modality Worklist over the association, returning each match. The Modality
+/// Worklist Information Model - FIND SOP Class must have been accepted for the association.
+///
+/// If the remote AE responds with a failure status this returns `DimseError::FailureStatus`.
+pub fn find_worklist<S: Read + Write>(
+    assoc: &mut Association,
+    stream: &mut S,
+    query: &WorklistQuery,
+) -> Result<Vec<WorklistItem>, DimseError> {
+    let sop_class_uid: &str = uids::ModalityWorklistInformationModelFIND.uid;
+    let (ctx_id, ts) = pres_ctx_for(assoc, sop_class_uid)?;
+
+    let identifier: Vec<u8> = encode_elements(query.to_elements(ts)?.iter(), ts)?;
+    let msg_id: u16 = assoc.next_msg_id();
+    let rq = CommandBuilder::c_find_rq(msg_id, sop_class_uid, Priority::Medium).build()?;
+    assoc.write_message(&Message::new(ctx_id, rq, Some(identifier)), stream)?;
+
+    let mut items: Vec<WorklistItem> = Vec::new();
+    loop {
+        let rsp: Message = assoc.read_message(stream)?.ok_or_else(|| {
+            DimseError::InvalidMessage("association released during C-FIND".to_owned())
+        })?;
+        if rsp.command_field() != Some(CommandField::CFindRsp)
+            || rsp.message_id_responded_to() != Some(msg_id)
+        {
+            return Err(DimseError::InvalidMessage(format!(
+                "unexpected response to C-FIND: {:?}",
+                rsp.command_field()
+            )));
+        }
+
+        match rsp.status() {
+            Some(status::PENDING | status::PENDING_WARNING) => {
+                if let Some(dataset) = rsp.decode_dataset(ts)? {
+                    items.push(WorklistItem { dataset });
+                }
+            }
+            Some(status::SUCCESS | status::CANCEL) => return Ok(items),
+            other => {
+                return Err(DimseError::FailureStatus {
+                    status: other.unwrap_or(status::UNABLE_TO_PROCESS),
+                    error_comment: rsp.error_comment(),
+                })
+            }
+        }
+    }
+}
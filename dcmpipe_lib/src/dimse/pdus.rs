@@ -2,9 +2,26 @@
 //!
 //! PDU headers are encoded with Big Endian. The value fields are sent using the transfer syntax
 //! negotiated during establishment of the association.
+//!
+//! Each PDU/item's `read` function expects that the leading PDU type byte has already been read
+//! from the stream, as the type is needed to determine which PDU/item follows.
+
+use std::io::{self, ErrorKind, Read, Write};
+
+use super::{
+    constants::{DEFAULT_MAX_PDU_LENGTH, MAX_ASSOC_PDU_LENGTH},
+    error::DimseError,
+};
+
+/// The number of bytes of an AE Title field within PDUs.
+pub const AE_TITLE_LENGTH: usize = 16;
+
+/// The most bytes allocated up front when reading a field whose length is given by the peer. Longer
+/// fields grow as they're read, so the allocation is bounded by the bytes actually received.
+const READ_ALLOCATION_LIMIT: usize = 64 * 1024;
 
 #[repr(u8)]
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PduType {
     AssocRQ = 0x01,
     AssocAC = 0x02,
@@ -75,6 +92,77 @@ impl TryFrom<u8> for PduType {
     }
 }
 
+/// The top-level PDUs which are sent/received over an association.
+#[derive(Debug)]
+pub enum Pdu {
+    AssocRQ(AssocRQ),
+    AssocAC(AssocAC),
+    AssocRJ(AssocRJ),
+    PresentationDataItem(PresentationDataItem),
+    ReleaseRQ(ReleaseRQ),
+    ReleaseRP(ReleaseRP),
+    Abort(Abort),
+}
+
+impl Pdu {
+    /// Reads the next PDU from the stream, limiting P-DATA-TF PDUs to `DEFAULT_MAX_PDU_LENGTH`, see
+    /// `read_max()`.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<Pdu, DimseError> {
+        Pdu::read_max(dataset, DEFAULT_MAX_PDU_LENGTH)
+    }
+
+    /// Reads the next PDU from the stream. P-DATA-TF PDUs longer than `max_pdu_length`, the
+    /// maximum length sent to the peer during negotiation, and association PDUs longer than
+    /// `MAX_ASSOC_PDU_LENGTH` fail with `DimseError::InvalidPdu` before their contents are read. A
+    /// `max_pdu_length` of zero is unlimited.
+    pub fn read_max<R: Read>(dataset: &mut R, max_pdu_length: u32) -> Result<Pdu, DimseError> {
+        let pdu_type: u8 = read_u8(dataset)?;
+        let pdu_type: PduType =
+            PduType::try_from(pdu_type).map_err(|_| DimseError::InvalidPduType(pdu_type))?;
+        match pdu_type {
+            PduType::AssocRQ => Ok(Pdu::AssocRQ(AssocRQ::read(dataset)?)),
+            PduType::AssocAC => Ok(Pdu::AssocAC(AssocAC::read(dataset)?)),
+            PduType::AssocRJ => Ok(Pdu::AssocRJ(AssocRJ::read(dataset)?)),
+            PduType::PresentationDataItem => Ok(Pdu::PresentationDataItem(
+                PresentationDataItem::read_max(dataset, max_pdu_length)?,
+            )),
+            PduType::ReleaseRQ => Ok(Pdu::ReleaseRQ(ReleaseRQ::read(dataset)?)),
+            PduType::ReleaseRP => Ok(Pdu::ReleaseRP(ReleaseRP::read(dataset)?)),
+            PduType::Abort => Ok(Pdu::Abort(Abort::read(dataset)?)),
+            other => Err(DimseError::UnexpectedPdu(other)),
+        }
+    }
+
+    /// Writes this PDU to the stream, flushing the stream after.
+    pub fn write<W: Write>(&self, dataset: &mut W) -> Result<(), DimseError> {
+        let bytes: Vec<u8> = match self {
+            Pdu::AssocRQ(pdu) => pdu.into(),
+            Pdu::AssocAC(pdu) => pdu.into(),
+            Pdu::AssocRJ(pdu) => pdu.into(),
+            Pdu::PresentationDataItem(pdu) => pdu.into(),
+            Pdu::ReleaseRQ(pdu) => pdu.into(),
+            Pdu::ReleaseRP(pdu) => pdu.into(),
+            Pdu::Abort(pdu) => pdu.into(),
+        };
+        dataset.write_all(&bytes)?;
+        dataset.flush()?;
+        Ok(())
+    }
+
+    /// The type of this PDU.
+    pub fn pdu_type(&self) -> PduType {
+        match self {
+            Pdu::AssocRQ(_) => AssocRQ::pdu_type(),
+            Pdu::AssocAC(_) => AssocAC::pdu_type(),
+            Pdu::AssocRJ(_) => AssocRJ::pdu_type(),
+            Pdu::PresentationDataItem(_) => PresentationDataItem::pdu_type(),
+            Pdu::ReleaseRQ(_) => ReleaseRQ::pdu_type(),
+            Pdu::ReleaseRP(_) => ReleaseRP::pdu_type(),
+            Pdu::Abort(_) => Abort::pdu_type(),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct AssocRQ {
     length: u32,
@@ -93,6 +181,88 @@ pub struct AssocRQ {
 }
 
 impl AssocRQ {
+    pub fn new(
+        called_ae: &str,
+        calling_ae: &str,
+        app_ctx: ApplicationContextItem,
+        pres_ctxs: Vec<AssocRQPresentationContext>,
+        user_info: UserInformationItem,
+    ) -> AssocRQ {
+        let length: u32 = 68
+            + app_ctx.num_bytes() as u32
+            + pres_ctxs.iter().map(|p| p.num_bytes() as u32).sum::<u32>()
+            + user_info.num_bytes() as u32;
+        AssocRQ {
+            length,
+            reserved_1: 0,
+            version: 1,
+            reserved_2: [0u8; 2],
+            called_ae: ae_title_to_bytes(called_ae),
+            calling_ae: ae_title_to_bytes(calling_ae),
+            reserved_3: [0u8; 32],
+            app_ctx,
+            pres_ctxs,
+            user_info,
+        }
+    }
+
+    /// Reads an `AssocRQ` from the stream, after the PDU type byte.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<AssocRQ, DimseError> {
+        let reserved_1: u8 = read_u8(dataset)?;
+        let length: u32 = read_u32(dataset)?;
+        let version: u16 = read_u16(dataset)?;
+        let reserved_2: [u8; 2] = read_array(dataset)?;
+        let called_ae: [u8; AE_TITLE_LENGTH] = read_array(dataset)?;
+        let calling_ae: [u8; AE_TITLE_LENGTH] = read_array(dataset)?;
+        let reserved_3: [u8; 32] = read_array(dataset)?;
+
+        if length > MAX_ASSOC_PDU_LENGTH {
+            return Err(DimseError::InvalidPdu(format!(
+                "AssocRQ length too long: {length}"
+            )));
+        }
+        let var_len: usize = (length as usize)
+            .checked_sub(68)
+            .ok_or_else(|| DimseError::InvalidPdu(format!("AssocRQ length too short: {length}")))?;
+        let mut var_items: &[u8] = &read_bytes(dataset, var_len)?;
+
+        let mut app_ctx: Option<ApplicationContextItem> = None;
+        let mut pres_ctxs: Vec<AssocRQPresentationContext> = Vec::new();
+        let mut user_info: Option<UserInformationItem> = None;
+        while !var_items.is_empty() {
+            let item_type: u8 = read_u8(&mut var_items)?;
+            match PduType::try_from(item_type) {
+                Ok(PduType::ApplicationContextItem) => {
+                    app_ctx = Some(ApplicationContextItem::read(&mut var_items)?);
+                }
+                Ok(PduType::AssocRQPresentationContext) => {
+                    pres_ctxs.push(AssocRQPresentationContext::read(&mut var_items)?);
+                }
+                Ok(PduType::UserInformationItem) => {
+                    user_info = Some(UserInformationItem::read(&mut var_items)?);
+                }
+                _ => skip_item(&mut var_items)?,
+            }
+        }
+
+        Ok(AssocRQ {
+            length,
+            reserved_1,
+            version,
+            reserved_2,
+            called_ae,
+            calling_ae,
+            reserved_3,
+            app_ctx: app_ctx.ok_or_else(|| {
+                DimseError::InvalidPdu("AssocRQ missing Application Context".to_owned())
+            })?,
+            pres_ctxs,
+            user_info: user_info.ok_or_else(|| {
+                DimseError::InvalidPdu("AssocRQ missing User Information".to_owned())
+            })?,
+        })
+    }
+
     /// The type of this PDU, `PduType::AssocRQ`.
     pub fn pdu_type() -> PduType {
         PduType::AssocRQ
@@ -149,18 +319,15 @@ impl From<&AssocRQ> for Vec<u8> {
         let mut bytes: Vec<u8> = Vec::with_capacity(value.num_bytes());
         bytes.push(AssocRQ::pdu_type() as u8);
         bytes.push(value.reserved_1);
+        bytes.extend(value.length.to_be_bytes());
         bytes.extend(&value.version.to_be_bytes());
         bytes.extend(&value.reserved_2);
         bytes.extend(&value.called_ae);
         bytes.extend(&value.calling_ae);
         bytes.extend(&value.reserved_3);
         bytes.extend(Into::<Vec<u8>>::into(&value.app_ctx));
-        bytes.extend(
-            value
-                .pres_ctxs
-                .iter()
-                .flat_map(|p| Into::<Vec<u8>>::into(p)),
-        );
+        bytes.extend(value.pres_ctxs.iter().flat_map(Into::<Vec<u8>>::into));
+        bytes.extend(Into::<Vec<u8>>::into(&value.user_info));
         bytes
     }
 }
@@ -188,6 +355,89 @@ pub struct AssocAC {
 }
 
 impl AssocAC {
+    /// Creates an `AssocAC` in response to the given `AssocRQ`, whose reserved fields are echoed
+    /// back as recommended by the standard.
+    pub fn new(
+        rq: &AssocRQ,
+        app_ctx: ApplicationContextItem,
+        pres_ctxs: Vec<AssocACPresentationContext>,
+        user_info: UserInformationItem,
+    ) -> AssocAC {
+        let length: u32 = 68
+            + app_ctx.num_bytes() as u32
+            + pres_ctxs.iter().map(|p| p.num_bytes() as u32).sum::<u32>()
+            + user_info.num_bytes() as u32;
+        AssocAC {
+            reserved_1: 0,
+            length,
+            version: 1,
+            reserved_2: [0u8; 2],
+            reserved_3: rq.called_ae,
+            reserved_4: rq.calling_ae,
+            reserved_5: rq.reserved_3,
+            app_ctx,
+            pres_ctxs,
+            user_info,
+        }
+    }
+
+    /// Reads an `AssocAC` from the stream, after the PDU type byte.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<AssocAC, DimseError> {
+        let reserved_1: u8 = read_u8(dataset)?;
+        let length: u32 = read_u32(dataset)?;
+        let version: u16 = read_u16(dataset)?;
+        let reserved_2: [u8; 2] = read_array(dataset)?;
+        let reserved_3: [u8; AE_TITLE_LENGTH] = read_array(dataset)?;
+        let reserved_4: [u8; AE_TITLE_LENGTH] = read_array(dataset)?;
+        let reserved_5: [u8; 32] = read_array(dataset)?;
+
+        if length > MAX_ASSOC_PDU_LENGTH {
+            return Err(DimseError::InvalidPdu(format!(
+                "AssocAC length too long: {length}"
+            )));
+        }
+        let var_len: usize = (length as usize)
+            .checked_sub(68)
+            .ok_or_else(|| DimseError::InvalidPdu(format!("AssocAC length too short: {length}")))?;
+        let mut var_items: &[u8] = &read_bytes(dataset, var_len)?;
+
+        let mut app_ctx: Option<ApplicationContextItem> = None;
+        let mut pres_ctxs: Vec<AssocACPresentationContext> = Vec::new();
+        let mut user_info: Option<UserInformationItem> = None;
+        while !var_items.is_empty() {
+            let item_type: u8 = read_u8(&mut var_items)?;
+            match PduType::try_from(item_type) {
+                Ok(PduType::ApplicationContextItem) => {
+                    app_ctx = Some(ApplicationContextItem::read(&mut var_items)?);
+                }
+                Ok(PduType::AssocACPresentationContext) => {
+                    pres_ctxs.push(AssocACPresentationContext::read(&mut var_items)?);
+                }
+                Ok(PduType::UserInformationItem) => {
+                    user_info = Some(UserInformationItem::read(&mut var_items)?);
+                }
+                _ => skip_item(&mut var_items)?,
+            }
+        }
+
+        Ok(AssocAC {
+            reserved_1,
+            length,
+            version,
+            reserved_2,
+            reserved_3,
+            reserved_4,
+            reserved_5,
+            app_ctx: app_ctx.ok_or_else(|| {
+                DimseError::InvalidPdu("AssocAC missing Application Context".to_owned())
+            })?,
+            pres_ctxs,
+            user_info: user_info.ok_or_else(|| {
+                DimseError::InvalidPdu("AssocAC missing User Information".to_owned())
+            })?,
+        })
+    }
+
     /// The type of this PDU, `PduType::AssocAC`.
     pub fn pdu_type() -> PduType {
         PduType::AssocAC
@@ -221,7 +471,9 @@ impl AssocAC {
     }
 
     fn num_bytes(&self) -> usize {
-        74 + self.app_ctx.num_bytes() + self.pres_ctxs.iter().map(|p| p.num_bytes()).sum::<usize>()
+        74 + self.app_ctx.num_bytes()
+            + self.pres_ctxs.iter().map(|p| p.num_bytes()).sum::<usize>()
+            + self.user_info.num_bytes()
     }
 }
 
@@ -237,12 +489,7 @@ impl From<&AssocAC> for Vec<u8> {
         bytes.extend(value.reserved_4);
         bytes.extend(value.reserved_5);
         bytes.extend(Into::<Vec<u8>>::into(&value.app_ctx));
-        bytes.extend(
-            value
-                .pres_ctxs
-                .iter()
-                .flat_map(|p| Into::<Vec<u8>>::into(p)),
-        );
+        bytes.extend(value.pres_ctxs.iter().flat_map(Into::<Vec<u8>>::into));
         bytes.extend(Into::<Vec<u8>>::into(&value.user_info));
         bytes
     }
@@ -261,6 +508,29 @@ pub struct AssocRJ {
 }
 
 impl AssocRJ {
+    pub fn new(result: u8, source: u8, reason: u8) -> AssocRJ {
+        AssocRJ {
+            reserved_1: 0,
+            length: 4,
+            reserved_2: 0,
+            result,
+            source,
+            reason,
+        }
+    }
+
+    /// Reads an `AssocRJ` from the stream, after the PDU type byte.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<AssocRJ, DimseError> {
+        Ok(AssocRJ {
+            reserved_1: read_u8(dataset)?,
+            length: read_u32(dataset)?,
+            reserved_2: read_u8(dataset)?,
+            result: read_u8(dataset)?,
+            source: read_u8(dataset)?,
+            reason: read_u8(dataset)?,
+        })
+    }
+
     /// The type of this PDU, `PduType::AssocRJ`.
     pub fn pdu_type() -> PduType {
         PduType::AssocRJ
@@ -341,6 +611,23 @@ pub struct ReleaseRQ {
 }
 
 impl ReleaseRQ {
+    pub fn new() -> ReleaseRQ {
+        ReleaseRQ {
+            reserved_1: 0,
+            length: 4,
+            reserved_2: [0u8; 4],
+        }
+    }
+
+    /// Reads a `ReleaseRQ` from the stream, after the PDU type byte.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<ReleaseRQ, DimseError> {
+        Ok(ReleaseRQ {
+            reserved_1: read_u8(dataset)?,
+            length: read_u32(dataset)?,
+            reserved_2: read_array(dataset)?,
+        })
+    }
+
     /// The type of this PDU, `PduType::ReleaseRQ`.
     pub fn pdu_type() -> PduType {
         PduType::ReleaseRQ
@@ -357,6 +644,12 @@ impl ReleaseRQ {
     }
 }
 
+impl Default for ReleaseRQ {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl From<&ReleaseRQ> for Vec<u8> {
     fn from(value: &ReleaseRQ) -> Self {
         let mut bytes: Vec<u8> = Vec::with_capacity(value.num_bytes());
@@ -378,6 +671,23 @@ pub struct ReleaseRP {
 }
 
 impl ReleaseRP {
+    pub fn new() -> ReleaseRP {
+        ReleaseRP {
+            reserved_1: 0,
+            length: 4,
+            reserved_2: [0u8; 4],
+        }
+    }
+
+    /// Reads a `ReleaseRP` from the stream, after the PDU type byte.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<ReleaseRP, DimseError> {
+        Ok(ReleaseRP {
+            reserved_1: read_u8(dataset)?,
+            length: read_u32(dataset)?,
+            reserved_2: read_array(dataset)?,
+        })
+    }
+
     /// The type of this PDU, `PduType::ReleaseRP`.
     pub fn pdu_type() -> PduType {
         PduType::ReleaseRP
@@ -394,6 +704,12 @@ impl ReleaseRP {
     }
 }
 
+impl Default for ReleaseRP {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl From<&ReleaseRP> for Vec<u8> {
     fn from(value: &ReleaseRP) -> Self {
         let mut bytes: Vec<u8> = Vec::with_capacity(value.num_bytes());
@@ -419,6 +735,29 @@ pub struct Abort {
 }
 
 impl Abort {
+    pub fn new(source: u8, reason: u8) -> Abort {
+        Abort {
+            reserved_1: 0,
+            length: 4,
+            reserved_2: 0,
+            reserved_3: 0,
+            source,
+            reason,
+        }
+    }
+
+    /// Reads an `Abort` from the stream, after the PDU type byte.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<Abort, DimseError> {
+        Ok(Abort {
+            reserved_1: read_u8(dataset)?,
+            length: read_u32(dataset)?,
+            reserved_2: read_u8(dataset)?,
+            reserved_3: read_u8(dataset)?,
+            source: read_u8(dataset)?,
+            reason: read_u8(dataset)?,
+        })
+    }
+
     /// The type of this PDU, `PduType::Abort`.
     pub fn pdu_type() -> PduType {
         PduType::Abort
@@ -483,6 +822,50 @@ pub struct PresentationDataItem {
 }
 
 impl PresentationDataItem {
+    pub fn new(pres_data: Vec<PresentationDataValue>) -> PresentationDataItem {
+        let length: u32 = pres_data.iter().map(|p| p.num_bytes() as u32).sum::<u32>();
+        PresentationDataItem {
+            reserved: 0,
+            length,
+            pres_data,
+        }
+    }
+
+    /// Reads a `PresentationDataItem` from the stream, after the PDU type byte.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<PresentationDataItem, DimseError> {
+        PresentationDataItem::read_max(dataset, 0)
+    }
+
+    /// Reads a `PresentationDataItem` from the stream, after the PDU type byte, failing if its
+    /// length is over `max_length`. A `max_length` of zero is unlimited.
+    pub fn read_max<R: Read>(
+        dataset: &mut R,
+        max_length: u32,
+    ) -> Result<PresentationDataItem, DimseError> {
+        let reserved: u8 = read_u8(dataset)?;
+        let length: u32 = read_u32(dataset)?;
+        if max_length != 0 && length > max_length {
+            return Err(DimseError::InvalidPdu(format!(
+                "PresentationDataItem length {length} exceeds the maximum of {max_length}"
+            )));
+        }
+        let mut values: &[u8] = &read_bytes(dataset, length as usize)?;
+        let mut pres_data: Vec<PresentationDataValue> = Vec::new();
+        while !values.is_empty() {
+            pres_data.push(PresentationDataValue::read(&mut values)?);
+        }
+        Ok(PresentationDataItem {
+            reserved,
+            length,
+            pres_data,
+        })
+    }
+
+    /// Consumes this PDU, returning the Presentation Data values.
+    pub fn into_pres_data(self) -> Vec<PresentationDataValue> {
+        self.pres_data
+    }
+
     /// The type of this PDU, `PduType::PresentationDataItem`.
     pub fn pdu_type() -> PduType {
         PduType::PresentationDataItem
@@ -510,12 +893,7 @@ impl From<&PresentationDataItem> for Vec<u8> {
         bytes.push(PresentationDataItem::pdu_type() as u8);
         bytes.push(value.reserved);
         bytes.extend(value.length.to_be_bytes());
-        bytes.extend(
-            value
-                .pres_data
-                .iter()
-                .flat_map(|p| Into::<Vec<u8>>::into(p)),
-        );
+        bytes.extend(value.pres_data.iter().flat_map(Into::<Vec<u8>>::into));
         bytes
     }
 }
@@ -529,6 +907,51 @@ pub struct PresentationDataValue {
 }
 
 impl PresentationDataValue {
+    /// Bit of the Message Header indicating the value is a Command rather than Data Set.
+    pub const MSG_HEADER_COMMAND: u8 = 0b01;
+
+    /// Bit of the Message Header indicating the value is the last fragment of the message.
+    pub const MSG_HEADER_LAST: u8 = 0b10;
+
+    pub fn new(ctx_id: u8, msg_header: u8, data: Vec<u8>) -> PresentationDataValue {
+        PresentationDataValue {
+            length: 2 + data.len() as u32,
+            ctx_id,
+            msg_header,
+            data,
+        }
+    }
+
+    /// Reads a `PresentationDataValue` from the stream. Unlike other items this has no leading
+    /// type byte.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<PresentationDataValue, DimseError> {
+        let length: u32 = read_u32(dataset)?;
+        let data_len: usize = (length as usize).checked_sub(2).ok_or_else(|| {
+            DimseError::InvalidPdu(format!("PresentationDataValue length too short: {length}"))
+        })?;
+        Ok(PresentationDataValue {
+            length,
+            ctx_id: read_u8(dataset)?,
+            msg_header: read_u8(dataset)?,
+            data: read_bytes(dataset, data_len)?,
+        })
+    }
+
+    /// Whether this value is a fragment of a Command, otherwise it's a fragment of a Data Set.
+    pub fn is_command(&self) -> bool {
+        self.msg_header & Self::MSG_HEADER_COMMAND != 0
+    }
+
+    /// Whether this value is the last fragment of the Command or Data Set.
+    pub fn is_last_fragment(&self) -> bool {
+        self.msg_header & Self::MSG_HEADER_LAST != 0
+    }
+
+    /// Consumes this value, returning the presentation data.
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
     /// The number of bytes from the first byte of the following field to the last byte of the
     /// presentation data value field.
     pub fn length(&self) -> u32 {
@@ -585,6 +1008,27 @@ pub struct ApplicationContextItem {
 }
 
 impl ApplicationContextItem {
+    pub fn new(app_context_name: &str) -> ApplicationContextItem {
+        let app_context_name: Vec<u8> = app_context_name.as_bytes().to_vec();
+        ApplicationContextItem {
+            reserved: 0,
+            length: app_context_name.len() as u16,
+            app_context_name,
+        }
+    }
+
+    /// Reads an `ApplicationContextItem` from the stream, after the PDU type byte.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<ApplicationContextItem, DimseError> {
+        let reserved: u8 = read_u8(dataset)?;
+        let length: u16 = read_u16(dataset)?;
+        let app_context_name: Vec<u8> = read_bytes(dataset, length as usize)?;
+        Ok(ApplicationContextItem {
+            reserved,
+            length,
+            app_context_name,
+        })
+    }
+
     /// The type of this PDU, `PduType::ApplicationContextItem`.
     pub fn pdu_type() -> PduType {
         PduType::ApplicationContextItem
@@ -634,6 +1078,72 @@ pub struct AssocRQPresentationContext {
 }
 
 impl AssocRQPresentationContext {
+    pub fn new(
+        ctx_id: u8,
+        abstract_syntax: AbstractSyntaxItem,
+        transfer_syntaxes: Vec<TransferSyntaxItem>,
+    ) -> AssocRQPresentationContext {
+        let length: u16 = 4
+            + abstract_syntax.num_bytes() as u16
+            + transfer_syntaxes
+                .iter()
+                .map(|t| t.num_bytes() as u16)
+                .sum::<u16>();
+        AssocRQPresentationContext {
+            reserved_1: 0,
+            length,
+            ctx_id,
+            reserved_2: 0,
+            reserved_3: 0,
+            reserved_4: 0,
+            abstract_syntax,
+            transfer_syntaxes,
+        }
+    }
+
+    /// Reads an `AssocRQPresentationContext` from the stream, after the PDU type byte.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<AssocRQPresentationContext, DimseError> {
+        let reserved_1: u8 = read_u8(dataset)?;
+        let length: u16 = read_u16(dataset)?;
+        let ctx_id: u8 = read_u8(dataset)?;
+        let reserved_2: u8 = read_u8(dataset)?;
+        let reserved_3: u8 = read_u8(dataset)?;
+        let reserved_4: u8 = read_u8(dataset)?;
+
+        let var_len: usize = (length as usize).checked_sub(4).ok_or_else(|| {
+            DimseError::InvalidPdu(format!("Presentation Context length too short: {length}"))
+        })?;
+        let mut sub_items: &[u8] = &read_bytes(dataset, var_len)?;
+
+        let mut abstract_syntax: Option<AbstractSyntaxItem> = None;
+        let mut transfer_syntaxes: Vec<TransferSyntaxItem> = Vec::new();
+        while !sub_items.is_empty() {
+            let item_type: u8 = read_u8(&mut sub_items)?;
+            match PduType::try_from(item_type) {
+                Ok(PduType::AbstractSyntaxItem) => {
+                    abstract_syntax = Some(AbstractSyntaxItem::read(&mut sub_items)?);
+                }
+                Ok(PduType::TransferSyntaxItem) => {
+                    transfer_syntaxes.push(TransferSyntaxItem::read(&mut sub_items)?);
+                }
+                _ => skip_item(&mut sub_items)?,
+            }
+        }
+
+        Ok(AssocRQPresentationContext {
+            reserved_1,
+            length,
+            ctx_id,
+            reserved_2,
+            reserved_3,
+            reserved_4,
+            abstract_syntax: abstract_syntax.ok_or_else(|| {
+                DimseError::InvalidPdu("Presentation Context missing Abstract Syntax".to_owned())
+            })?,
+            transfer_syntaxes,
+        })
+    }
+
     /// The type of this PDU, `PduType::AssocRQPresentationContext`.
     pub fn pdu_type() -> PduType {
         PduType::AssocRQPresentationContext
@@ -681,6 +1191,12 @@ impl From<&AssocRQPresentationContext> for Vec<u8> {
         bytes.push(value.reserved_3);
         bytes.push(value.reserved_4);
         bytes.extend(Into::<Vec<u8>>::into(&value.abstract_syntax));
+        bytes.extend(
+            value
+                .transfer_syntaxes
+                .iter()
+                .flat_map(Into::<Vec<u8>>::into),
+        );
         bytes
     }
 }
@@ -700,6 +1216,60 @@ pub struct AssocACPresentationContext {
 }
 
 impl AssocACPresentationContext {
+    pub fn new(
+        ctx_id: u8,
+        result: u8,
+        transfer_syntax: TransferSyntaxItem,
+    ) -> AssocACPresentationContext {
+        AssocACPresentationContext {
+            reserved_1: 0,
+            length: 4 + transfer_syntax.num_bytes() as u16,
+            ctx_id,
+            reserved_2: 0,
+            result,
+            reserved_3: 0,
+            transfer_syntax,
+        }
+    }
+
+    /// Reads an `AssocACPresentationContext` from the stream, after the PDU type byte.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<AssocACPresentationContext, DimseError> {
+        let reserved_1: u8 = read_u8(dataset)?;
+        let length: u16 = read_u16(dataset)?;
+        let ctx_id: u8 = read_u8(dataset)?;
+        let reserved_2: u8 = read_u8(dataset)?;
+        let result: u8 = read_u8(dataset)?;
+        let reserved_3: u8 = read_u8(dataset)?;
+
+        let var_len: usize = (length as usize).checked_sub(4).ok_or_else(|| {
+            DimseError::InvalidPdu(format!("Presentation Context length too short: {length}"))
+        })?;
+        let mut sub_items: &[u8] = &read_bytes(dataset, var_len)?;
+
+        // The transfer syntax sub-item is not significant when the context is rejected, and some
+        // implementations leave it out entirely in that case.
+        let mut transfer_syntax: TransferSyntaxItem = TransferSyntaxItem::new("");
+        while !sub_items.is_empty() {
+            let item_type: u8 = read_u8(&mut sub_items)?;
+            match PduType::try_from(item_type) {
+                Ok(PduType::TransferSyntaxItem) => {
+                    transfer_syntax = TransferSyntaxItem::read(&mut sub_items)?;
+                }
+                _ => skip_item(&mut sub_items)?,
+            }
+        }
+
+        Ok(AssocACPresentationContext {
+            reserved_1,
+            length,
+            ctx_id,
+            reserved_2,
+            result,
+            reserved_3,
+            transfer_syntax,
+        })
+    }
+
     /// The type of this PDU, `PduType::AssocACPresentationContext`.
     pub fn pdu_type() -> PduType {
         PduType::AssocACPresentationContext
@@ -763,6 +1333,27 @@ pub struct AbstractSyntaxItem {
 }
 
 impl AbstractSyntaxItem {
+    pub fn new(abstract_syntax: &str) -> AbstractSyntaxItem {
+        let abstract_syntax: Vec<u8> = abstract_syntax.as_bytes().to_vec();
+        AbstractSyntaxItem {
+            reserved: 0,
+            length: abstract_syntax.len() as u16,
+            abstract_syntax,
+        }
+    }
+
+    /// Reads an `AbstractSyntaxItem` from the stream, after the PDU type byte.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<AbstractSyntaxItem, DimseError> {
+        let reserved: u8 = read_u8(dataset)?;
+        let length: u16 = read_u16(dataset)?;
+        let abstract_syntax: Vec<u8> = read_bytes(dataset, length as usize)?;
+        Ok(AbstractSyntaxItem {
+            reserved,
+            length,
+            abstract_syntax,
+        })
+    }
+
     /// The type of this PDU, `PduType::AbstractSyntaxItem`.
     pub fn pdu_type() -> PduType {
         PduType::AbstractSyntaxItem
@@ -804,6 +1395,27 @@ pub struct TransferSyntaxItem {
 }
 
 impl TransferSyntaxItem {
+    pub fn new(transfer_syntaxes: &str) -> TransferSyntaxItem {
+        let transfer_syntaxes: Vec<u8> = transfer_syntaxes.as_bytes().to_vec();
+        TransferSyntaxItem {
+            reserved: 0,
+            length: transfer_syntaxes.len() as u16,
+            transfer_syntaxes,
+        }
+    }
+
+    /// Reads a `TransferSyntaxItem` from the stream, after the PDU type byte.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<TransferSyntaxItem, DimseError> {
+        let reserved: u8 = read_u8(dataset)?;
+        let length: u16 = read_u16(dataset)?;
+        let transfer_syntaxes: Vec<u8> = read_bytes(dataset, length as usize)?;
+        Ok(TransferSyntaxItem {
+            reserved,
+            length,
+            transfer_syntaxes,
+        })
+    }
+
     /// The type of this PDU, `PduType::TransferSyntaxItem`.
     pub fn pdu_type() -> PduType {
         PduType::TransferSyntaxItem
@@ -845,6 +1457,117 @@ pub struct UserInformationItem {
 }
 
 impl UserInformationItem {
+    pub fn new(
+        max_length: Option<MaxLengthItem>,
+        impl_class_uid: Option<ImplementationClassUIDItem>,
+        impl_ver_name: Option<ImplementationVersionNameItem>,
+    ) -> UserInformationItem {
+        let mut sub_items: Vec<Vec<u8>> = Vec::new();
+        if let Some(max_length) = max_length {
+            sub_items.push((&max_length).into());
+        }
+        if let Some(impl_class_uid) = impl_class_uid {
+            sub_items.push((&impl_class_uid).into());
+        }
+        if let Some(impl_ver_name) = impl_ver_name {
+            sub_items.push((&impl_ver_name).into());
+        }
+        UserInformationItem::from_sub_items(sub_items)
+    }
+
+    /// Creates a `UserInformationItem` from already-encoded sub-items, which should be ordered by
+    /// their item type.
+    pub fn from_sub_items(sub_items: Vec<Vec<u8>>) -> UserInformationItem {
+        let user_data: Vec<u8> = sub_items.concat();
+        UserInformationItem {
+            reserved: 0,
+            length: user_data.len() as u16,
+            user_data,
+        }
+    }
+
+    /// Reads a `UserInformationItem` from the stream, after the PDU type byte.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<UserInformationItem, DimseError> {
+        let reserved: u8 = read_u8(dataset)?;
+        let length: u16 = read_u16(dataset)?;
+        let user_data: Vec<u8> = read_bytes(dataset, length as usize)?;
+        Ok(UserInformationItem {
+            reserved,
+            length,
+            user_data,
+        })
+    }
+
+    /// Finds the value of the Maximum Length sub-item, if present.
+    pub fn max_length(&self) -> Option<u32> {
+        self.find_sub_item(PduType::MaxLengthItem)
+            .filter(|value| value.len() == 4)
+            .map(|value| u32::from_be_bytes([value[0], value[1], value[2], value[3]]))
+    }
+
+    /// Finds the value of the Implementation Class UID sub-item, if present.
+    pub fn impl_class_uid(&self) -> Option<String> {
+        self.find_sub_item(PduType::ImplementationClassUIDItem)
+            .map(uid_from_bytes)
+    }
+
+    /// Finds the value of the Implementation Version Name sub-item, if present.
+    pub fn impl_ver_name(&self) -> Option<String> {
+        self.find_sub_item(PduType::ImplementationVersionNameItem)
+            .map(uid_from_bytes)
+    }
+
+    /// Finds the Asynchronous Operations Window sub-item, if present.
+    pub fn async_ops_window(&self) -> Option<AsyncOperationsWindowItem> {
+        self.sub_items(PduType::AsyncOperationsWindowItem)
+            .find_map(|mut item| AsyncOperationsWindowItem::read(&mut item).ok())
+    }
+
+    /// Finds all SCP/SCU Role Selection sub-items.
+    pub fn role_selections(&self) -> Vec<RoleSelectionItem> {
+        self.sub_items(PduType::RoleSelectionItem)
+            .filter_map(|mut item| RoleSelectionItem::read(&mut item).ok())
+            .collect()
+    }
+
+    /// Finds all SOP Class Extended Negotiation sub-items.
+    pub fn sop_class_ext_negs(&self) -> Vec<SOPClassExtendedNegotiationItem> {
+        self.sub_items(PduType::SOPClassExtendedNegotiationItem)
+            .filter_map(|mut item| SOPClassExtendedNegotiationItem::read(&mut item).ok())
+            .collect()
+    }
+
+    /// Finds all SOP Class Common Extended Negotiation sub-items.
+    pub fn sop_class_common_ext_negs(&self) -> Vec<SOPClassCommonExtendedNegotiationItem> {
+        self.sub_items(PduType::SOPClassCommonExtendedNegotiationItem)
+            .filter_map(|mut item| SOPClassCommonExtendedNegotiationItem::read(&mut item).ok())
+            .collect()
+    }
+
+    /// Finds the value field of the first sub-item of the given type.
+    fn find_sub_item(&self, pdu_type: PduType) -> Option<&[u8]> {
+        self.sub_items(pdu_type).next().map(|item| &item[3..])
+    }
+
+    /// Iterates over the sub-items of the given type, each starting after its type byte so that it
+    /// can be given to the item's `read` function. Sub-items all share the same header of
+    /// item-type, reserved, and 2-byte item-length.
+    fn sub_items(&self, pdu_type: PduType) -> impl Iterator<Item = &[u8]> {
+        let mut remaining: &[u8] = &self.user_data;
+        std::iter::from_fn(move || {
+            while remaining.len() >= 4 {
+                let item_type: u8 = remaining[0];
+                let length: usize = u16::from_be_bytes([remaining[2], remaining[3]]) as usize;
+                let item: &[u8] = remaining.get(1..4 + length)?;
+                remaining = &remaining[4 + length..];
+                if item_type == pdu_type as u8 {
+                    return Some(item);
+                }
+            }
+            None
+        })
+    }
+
     /// The type of this PDU, `PduType::UserInformationItem`.
     pub fn pdu_type() -> PduType {
         PduType::UserInformationItem
@@ -886,6 +1609,14 @@ pub struct MaxLengthItem {
 }
 
 impl MaxLengthItem {
+    pub fn new(max_length: u32) -> MaxLengthItem {
+        MaxLengthItem {
+            reserved: 0,
+            length: 4,
+            max_length,
+        }
+    }
+
     /// The type of this PDU, `PduType::MaxLengthItem`.
     pub fn pdu_type() -> PduType {
         PduType::MaxLengthItem
@@ -927,6 +1658,27 @@ pub struct ImplementationClassUIDItem {
 }
 
 impl ImplementationClassUIDItem {
+    pub fn new(impl_class_uid: &str) -> ImplementationClassUIDItem {
+        let impl_class_uid: Vec<u8> = impl_class_uid.as_bytes().to_vec();
+        ImplementationClassUIDItem {
+            reserved: 0,
+            length: impl_class_uid.len() as u16,
+            impl_class_uid,
+        }
+    }
+
+    /// Reads an `ImplementationClassUIDItem` from the stream, after the PDU type byte.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<ImplementationClassUIDItem, DimseError> {
+        let reserved: u8 = read_u8(dataset)?;
+        let length: u16 = read_u16(dataset)?;
+        let impl_class_uid: Vec<u8> = read_bytes(dataset, length as usize)?;
+        Ok(ImplementationClassUIDItem {
+            reserved,
+            length,
+            impl_class_uid,
+        })
+    }
+
     /// The type of this PDU, `PduType::ImplementationClassUIDItem`.
     pub fn pdu_type() -> PduType {
         PduType::ImplementationClassUIDItem
@@ -968,6 +1720,29 @@ pub struct AsyncOperationsWindowItem {
 }
 
 impl AsyncOperationsWindowItem {
+    pub fn new(max_ops_invoked: u16, max_ops_performed: u16) -> AsyncOperationsWindowItem {
+        AsyncOperationsWindowItem {
+            reserved: 0,
+            length: 4,
+            max_ops_invoked,
+            max_ops_performed,
+        }
+    }
+
+    /// Reads an `AsyncOperationsWindowItem` from the stream, after the PDU type byte.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<AsyncOperationsWindowItem, DimseError> {
+        let reserved: u8 = read_u8(dataset)?;
+        let length: u16 = read_u16(dataset)?;
+        let max_ops_invoked: u16 = read_u16(dataset)?;
+        let max_ops_performed: u16 = read_u16(dataset)?;
+        Ok(AsyncOperationsWindowItem {
+            reserved,
+            length,
+            max_ops_invoked,
+            max_ops_performed,
+        })
+    }
+
     /// The type of this PDU, `PduType::AsyncOperationsWindowItem`.
     pub fn pdu_type() -> PduType {
         PduType::AsyncOperationsWindowItem
@@ -979,12 +1754,12 @@ impl AsyncOperationsWindowItem {
         self.length
     }
 
-    /// Maximum Number of Operations Invoked.
+    /// Maximum Number of Operations Invoked. A value of zero indicates no limit.
     pub fn max_ops_invoked(&self) -> u16 {
         self.max_ops_invoked
     }
 
-    /// Maximum Number of Operations Performed.
+    /// Maximum Number of Operations Performed. A value of zero indicates no limit.
     pub fn max_ops_performed(&self) -> u16 {
         self.max_ops_performed
     }
@@ -1018,6 +1793,36 @@ pub struct RoleSelectionItem {
 }
 
 impl RoleSelectionItem {
+    pub fn new(sop_class_uid: &str, scu_role: bool, scp_role: bool) -> RoleSelectionItem {
+        let sop_class_uid: Vec<u8> = sop_class_uid.as_bytes().to_vec();
+        RoleSelectionItem {
+            reserved: 0,
+            length: 4 + sop_class_uid.len() as u16,
+            sop_class_uid_length: sop_class_uid.len() as u16,
+            sop_class_uid,
+            scu_role: u8::from(scu_role),
+            scp_role: u8::from(scp_role),
+        }
+    }
+
+    /// Reads a `RoleSelectionItem` from the stream, after the PDU type byte.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<RoleSelectionItem, DimseError> {
+        let reserved: u8 = read_u8(dataset)?;
+        let length: u16 = read_u16(dataset)?;
+        let sop_class_uid_length: u16 = read_u16(dataset)?;
+        let sop_class_uid: Vec<u8> = read_bytes(dataset, sop_class_uid_length as usize)?;
+        let scu_role: u8 = read_u8(dataset)?;
+        let scp_role: u8 = read_u8(dataset)?;
+        Ok(RoleSelectionItem {
+            reserved,
+            length,
+            sop_class_uid_length,
+            sop_class_uid,
+            scu_role,
+            scp_role,
+        })
+    }
+
     /// The type of this PDU, `PduType::RoleSelectionItem`.
     pub fn pdu_type() -> PduType {
         PduType::RoleSelectionItem
@@ -1083,6 +1888,27 @@ pub struct ImplementationVersionNameItem {
 }
 
 impl ImplementationVersionNameItem {
+    pub fn new(impl_ver_name: &str) -> ImplementationVersionNameItem {
+        let impl_ver_name: Vec<u8> = impl_ver_name.as_bytes().to_vec();
+        ImplementationVersionNameItem {
+            reserved: 0,
+            length: impl_ver_name.len() as u16,
+            impl_ver_name,
+        }
+    }
+
+    /// Reads an `ImplementationVersionNameItem` from the stream, after the PDU type byte.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<ImplementationVersionNameItem, DimseError> {
+        let reserved: u8 = read_u8(dataset)?;
+        let length: u16 = read_u16(dataset)?;
+        let impl_ver_name: Vec<u8> = read_bytes(dataset, length as usize)?;
+        Ok(ImplementationVersionNameItem {
+            reserved,
+            length,
+            impl_ver_name,
+        })
+    }
+
     /// The type of this PDU, `PduType::ImplementationVersionNameItem`.
     pub fn pdu_type() -> PduType {
         PduType::ImplementationVersionNameItem
@@ -1125,6 +1951,43 @@ pub struct SOPClassExtendedNegotiationItem {
 }
 
 impl SOPClassExtendedNegotiationItem {
+    pub fn new(
+        sop_class_uid: &str,
+        service_class_app_info: Vec<u8>,
+    ) -> SOPClassExtendedNegotiationItem {
+        let sop_class_uid: Vec<u8> = sop_class_uid.as_bytes().to_vec();
+        SOPClassExtendedNegotiationItem {
+            reserved: 0,
+            length: (2 + sop_class_uid.len() + service_class_app_info.len()) as u16,
+            sop_class_uid_length: sop_class_uid.len() as u16,
+            sop_class_uid,
+            service_class_app_info,
+        }
+    }
+
+    /// Reads a `SOPClassExtendedNegotiationItem` from the stream, after the PDU type byte.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<SOPClassExtendedNegotiationItem, DimseError> {
+        let reserved: u8 = read_u8(dataset)?;
+        let length: u16 = read_u16(dataset)?;
+        let sop_class_uid_length: u16 = read_u16(dataset)?;
+        let sop_class_uid: Vec<u8> = read_bytes(dataset, sop_class_uid_length as usize)?;
+        let app_info_length: usize = (length as usize)
+            .checked_sub(2 + sop_class_uid.len())
+            .ok_or_else(|| {
+                DimseError::InvalidPdu(format!(
+                    "SOP Class UID length {sop_class_uid_length} exceeds item length {length}"
+                ))
+            })?;
+        let service_class_app_info: Vec<u8> = read_bytes(dataset, app_info_length)?;
+        Ok(SOPClassExtendedNegotiationItem {
+            reserved,
+            length,
+            sop_class_uid_length,
+            sop_class_uid,
+            service_class_app_info,
+        })
+    }
+
     /// The type of this PDU, `PduType::SOPClassExtendedNegotiationItem`.
     pub fn pdu_type() -> PduType {
         PduType::SOPClassExtendedNegotiationItem
@@ -1183,6 +2046,77 @@ pub struct SOPClassCommonExtendedNegotiationItem {
 }
 
 impl SOPClassCommonExtendedNegotiationItem {
+    pub fn new(
+        sop_class_uid: &str,
+        service_class_uid: &str,
+        rel_gen_sop_classes: &[&str],
+    ) -> SOPClassCommonExtendedNegotiationItem {
+        let sop_class_uid: Vec<u8> = sop_class_uid.as_bytes().to_vec();
+        let service_class_uid: Vec<u8> = service_class_uid.as_bytes().to_vec();
+        let rel_gen_sop_classes: Vec<RelatedGeneralSOPClassUID> = rel_gen_sop_classes
+            .iter()
+            .map(|uid| RelatedGeneralSOPClassUID::new(uid))
+            .collect();
+        let rel_gen_sop_class_length: usize = rel_gen_sop_classes
+            .iter()
+            .map(RelatedGeneralSOPClassUID::num_bytes)
+            .sum();
+        SOPClassCommonExtendedNegotiationItem {
+            version: 0,
+            length: (6 + sop_class_uid.len() + service_class_uid.len() + rel_gen_sop_class_length)
+                as u16,
+            sop_class_length: sop_class_uid.len() as u16,
+            sop_class_uid,
+            service_class_length: service_class_uid.len() as u16,
+            service_class_uid,
+            rel_gen_sop_class_length: rel_gen_sop_class_length as u16,
+            rel_gen_sop_classes,
+            reserved: Vec::new(),
+        }
+    }
+
+    /// Reads a `SOPClassCommonExtendedNegotiationItem` from the stream, after the PDU type byte.
+    pub fn read<R: Read>(
+        dataset: &mut R,
+    ) -> Result<SOPClassCommonExtendedNegotiationItem, DimseError> {
+        let version: u8 = read_u8(dataset)?;
+        let length: u16 = read_u16(dataset)?;
+        let sop_class_length: u16 = read_u16(dataset)?;
+        let sop_class_uid: Vec<u8> = read_bytes(dataset, sop_class_length as usize)?;
+        let service_class_length: u16 = read_u16(dataset)?;
+        let service_class_uid: Vec<u8> = read_bytes(dataset, service_class_length as usize)?;
+        let rel_gen_sop_class_length: u16 = read_u16(dataset)?;
+
+        let mut rel_gen_sop_classes: Vec<RelatedGeneralSOPClassUID> = Vec::new();
+        let mut bytes_read: usize = 0;
+        while bytes_read < rel_gen_sop_class_length as usize {
+            let rel_gen_sop_class: RelatedGeneralSOPClassUID =
+                RelatedGeneralSOPClassUID::read(dataset)?;
+            bytes_read += rel_gen_sop_class.num_bytes();
+            rel_gen_sop_classes.push(rel_gen_sop_class);
+        }
+
+        let reserved_length: usize = (length as usize)
+            .checked_sub(6 + sop_class_uid.len() + service_class_uid.len() + bytes_read)
+            .ok_or_else(|| {
+                DimseError::InvalidPdu(format!(
+                    "SOP Class Common Extended Negotiation fields exceed item length {length}"
+                ))
+            })?;
+        let reserved: Vec<u8> = read_bytes(dataset, reserved_length)?;
+        Ok(SOPClassCommonExtendedNegotiationItem {
+            version,
+            length,
+            sop_class_length,
+            sop_class_uid,
+            service_class_length,
+            service_class_uid,
+            rel_gen_sop_class_length,
+            rel_gen_sop_classes,
+            reserved,
+        })
+    }
+
     /// The type of this PDU, `PduType::SOPClassCommonExtendedNegotiationItem`.
     pub fn pdu_type() -> PduType {
         PduType::SOPClassCommonExtendedNegotiationItem
@@ -1256,7 +2190,7 @@ impl From<&SOPClassCommonExtendedNegotiationItem> for Vec<u8> {
             value
                 .rel_gen_sop_classes
                 .iter()
-                .flat_map(|s| Into::<Vec<u8>>::into(s))
+                .flat_map(Into::<Vec<u8>>::into),
         );
         bytes.extend(&value.reserved);
         bytes
@@ -1270,6 +2204,24 @@ pub struct RelatedGeneralSOPClassUID {
 }
 
 impl RelatedGeneralSOPClassUID {
+    pub fn new(rel_gen_sop_class: &str) -> RelatedGeneralSOPClassUID {
+        let rel_gen_sop_class: Vec<u8> = rel_gen_sop_class.as_bytes().to_vec();
+        RelatedGeneralSOPClassUID {
+            length: rel_gen_sop_class.len() as u16,
+            rel_gen_sop_class,
+        }
+    }
+
+    /// Reads a `RelatedGeneralSOPClassUID` from the stream.
+    pub fn read<R: Read>(dataset: &mut R) -> Result<RelatedGeneralSOPClassUID, DimseError> {
+        let length: u16 = read_u16(dataset)?;
+        let rel_gen_sop_class: Vec<u8> = read_bytes(dataset, length as usize)?;
+        Ok(RelatedGeneralSOPClassUID {
+            length,
+            rel_gen_sop_class,
+        })
+    }
+
     /// The number of bytes in the Related General SOP Class UID field.
     pub fn length(&self) -> u16 {
         self.length
@@ -1429,6 +2381,69 @@ impl From<&UserIdentityNegotiationItem> for Vec<u8> {
     }
 }
 
+/// Encodes the AE Title into the fixed-length field used in PDUs, padded with trailing spaces.
+/// Values longer than the field are truncated.
+pub fn ae_title_to_bytes(ae: &str) -> [u8; AE_TITLE_LENGTH] {
+    let mut bytes: [u8; AE_TITLE_LENGTH] = [b' '; AE_TITLE_LENGTH];
+    for (dst, src) in bytes.iter_mut().zip(ae.as_bytes()) {
+        *dst = *src;
+    }
+    bytes
+}
+
+/// Decodes an AE Title from the fixed-length field used in PDUs, removing the non-significant
+/// leading and trailing spaces.
+pub fn ae_title_from_bytes(ae: &[u8; AE_TITLE_LENGTH]) -> String {
+    String::from_utf8_lossy(ae).trim().to_owned()
+}
+
+/// Decodes a UID from an item's value. UIDs in PDUs are not required to be padded however some
+/// implementations include a trailing NULL, which is removed.
+pub fn uid_from_bytes(uid: &[u8]) -> String {
+    String::from_utf8_lossy(uid)
+        .trim_end_matches(['\0', ' '])
+        .to_owned()
+}
+
+fn read_u8<R: Read>(dataset: &mut R) -> Result<u8, DimseError> {
+    let buf: [u8; 1] = read_array(dataset)?;
+    Ok(buf[0])
+}
+
+fn read_u16<R: Read>(dataset: &mut R) -> Result<u16, DimseError> {
+    Ok(u16::from_be_bytes(read_array(dataset)?))
+}
+
+fn read_u32<R: Read>(dataset: &mut R) -> Result<u32, DimseError> {
+    Ok(u32::from_be_bytes(read_array(dataset)?))
+}
+
+fn read_array<R: Read, const N: usize>(dataset: &mut R) -> Result<[u8; N], DimseError> {
+    let mut buf: [u8; N] = [0u8; N];
+    dataset.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+/// Reads a field of `len` bytes, which is usually given by the peer so isn't trusted to allocate
+/// up front, see `READ_ALLOCATION_LIMIT`.
+fn read_bytes<R: Read>(dataset: &mut R, len: usize) -> Result<Vec<u8>, DimseError> {
+    let mut buf: Vec<u8> = Vec::with_capacity(len.min(READ_ALLOCATION_LIMIT));
+    dataset.take(len as u64).read_to_end(&mut buf)?;
+    if buf.len() < len {
+        return Err(io::Error::from(ErrorKind::UnexpectedEof).into());
+    }
+    Ok(buf)
+}
+
+/// Skips over an unrecognized item, after its type byte. All items within the variable fields of
+/// PDUs share the same header of item-type, reserved, and 2-byte item-length.
+fn skip_item<R: Read>(dataset: &mut R) -> Result<(), DimseError> {
+    let _reserved: u8 = read_u8(dataset)?;
+    let length: u16 = read_u16(dataset)?;
+    read_bytes(dataset, length as usize)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::PduType;
@@ -0,0 +1,487 @@
+//! Validation of the Identifiers of C-FIND requests against the Patient Root and Study Root
+//! Query/Retrieve Information Models. Part 4, Section C.4.1 and C.6.
+//!
+//! `QueryIdentifier::validate()` checks the rules of hierarchical queries which an SCU is to follow
+//! and an SCP may rely on:
+//!
+//! - `QueryRetrieveLevel` is present and is a level of the information model.
+//! - The unique key of each level above the query level is present with a single value, e.g. the
+//!   `StudyInstanceUID` of a query for series.
+//! - The unique key of the query level is single value, universal, or list of UID matching.
+//! - There are no keys of levels below the query level.
+//! - Sequence keys have at most one item.
+//!
+//! A validated identifier is normalized into its level and keys, each with a `Matcher` for its
+//! value. The unique key of the query level is added as a universal key if it's missing, so that
+//! it's always returned. Keys of attributes not associated with a level, such as private
+//! attributes, are kept as optional keys. Only keys of character string VRs are matched, others
+//! are universal.
+
+use std::fmt;
+
+use thiserror::Error;
+
+use crate::{
+    core::{
+        dcmelement::DicomElement,
+        dcmobject::DicomRoot,
+        defn::{
+            dcmdict::DicomDictionary,
+            tag::{Tag, TagRef},
+            vr::{self, VRRef},
+        },
+        matching::{Matcher, Matching},
+        read::ParseResult,
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags, uids},
+};
+
+/// Keys of the patient level.
+static PATIENT_KEYS: [TagRef; 10] = [
+    &tags::PatientsName,
+    &tags::PatientID,
+    &tags::IssuerofPatientID,
+    &tags::OtherPatientIDsSequence,
+    &tags::PatientsBirthDate,
+    &tags::PatientsBirthTime,
+    &tags::PatientsSex,
+    &tags::OtherPatientNames,
+    &tags::EthnicGroup,
+    &tags::PatientComments,
+];
+
+/// Keys of the patient level of the Patient Root model only, as Study Root has no equivalent of
+/// the counts of a patient's studies, series, and instances.
+static PATIENT_ROOT_KEYS: [TagRef; 3] = [
+    &tags::NumberofPatientRelatedStudies,
+    &tags::NumberofPatientRelatedSeries,
+    &tags::NumberofPatientRelatedInstances,
+];
+
+/// Keys of the study level.
+static STUDY_KEYS: [TagRef; 18] = [
+    &tags::StudyDate,
+    &tags::StudyTime,
+    &tags::AccessionNumber,
+    &tags::StudyID,
+    &tags::StudyInstanceUID,
+    &tags::ReferringPhysiciansName,
+    &tags::StudyDescription,
+    &tags::ModalitiesinStudy,
+    &tags::SOPClassesinStudy,
+    &tags::NumberofStudyRelatedSeries,
+    &tags::NumberofStudyRelatedInstances,
+    &tags::PatientsAge,
+    &tags::PatientsSize,
+    &tags::PatientsWeight,
+    &tags::Occupation,
+    &tags::AdditionalPatientHistory,
+    &tags::NameofPhysiciansReadingStudy,
+    &tags::OtherStudyNumbers,
+];
+
+/// Keys of the series level.
+static SERIES_KEYS: [TagRef; 10] = [
+    &tags::Modality,
+    &tags::SeriesNumber,
+    &tags::SeriesInstanceUID,
+    &tags::NumberofSeriesRelatedInstances,
+    &tags::SeriesDescription,
+    &tags::SeriesDate,
+    &tags::SeriesTime,
+    &tags::BodyPartExamined,
+    &tags::PerformedProcedureStepStartDate,
+    &tags::PerformedProcedureStepStartTime,
+];
+
+/// Keys of the composite object instance level.
+static IMAGE_KEYS: [TagRef; 11] = [
+    &tags::InstanceNumber,
+    &tags::SOPInstanceUID,
+    &tags::SOPClassUID,
+    &tags::ContentDate,
+    &tags::ContentTime,
+    &tags::AcquisitionDate,
+    &tags::AcquisitionTime,
+    &tags::ImageType,
+    &tags::NumberofFrames,
+    &tags::Rows,
+    &tags::Columns,
+];
+
+/// Attributes which may be included in an Identifier at any level.
+static LEVEL_INDEPENDENT_KEYS: [TagRef; 5] = [
+    &tags::SpecificCharacterSet,
+    &tags::TimezoneOffsetFromUTC,
+    &tags::RetrieveAETitle,
+    &tags::InstanceAvailability,
+    &tags::QueryRetrieveView,
+];
+
+/// A Query/Retrieve Information Model.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QueryModel {
+    PatientRoot,
+    StudyRoot,
+}
+
+/// A level of the Query/Retrieve Information Models, the value of `QueryRetrieveLevel`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum QueryLevel {
+    Patient,
+    Study,
+    Series,
+    Image,
+}
+
+/// A rule of the information model which an Identifier breaks.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum QueryKeyError {
+    #[error("missing QueryRetrieveLevel")]
+    MissingLevel,
+
+    #[error("invalid QueryRetrieveLevel: {0:?}")]
+    InvalidLevel(String),
+
+    #[error("the {level} level is not part of the {model} information model")]
+    LevelNotInModel {
+        level: QueryLevel,
+        model: QueryModel,
+    },
+
+    #[error("missing {key}, the unique key of the {level} level")]
+    MissingUniqueKey { key: String, level: QueryLevel },
+
+    #[error("{key}, the unique key of the {level} level, must have a single value: {value:?}")]
+    UniqueKeyNotSingle {
+        key: String,
+        level: QueryLevel,
+        value: String,
+    },
+
+    #[error(
+        "{key}, the unique key of the query level, must be single value, universal, or list of \
+        UID matching: {value:?}"
+    )]
+    InvalidUniqueKeyMatching { key: String, value: String },
+
+    #[error("{key} is a key of the {key_level} level, below the query level of {level}")]
+    KeyBelowLevel {
+        key: String,
+        key_level: QueryLevel,
+        level: QueryLevel,
+    },
+
+    #[error("sequence key {key} must have at most one item, has {items}")]
+    SequenceItems { key: String, items: usize },
+
+    /// The value of a key couldn't be decoded.
+    #[error("invalid value of {key}: {reason}")]
+    InvalidValue { key: String, reason: String },
+}
+
+/// A key of a validated Identifier.
+#[derive(Clone, Debug)]
+pub struct QueryKey {
+    tag: u32,
+    level: Option<QueryLevel>,
+    matcher: Matcher,
+}
+
+/// An Identifier validated against its information model, see the module documentation.
+#[derive(Clone, Debug)]
+pub struct QueryIdentifier {
+    model: QueryModel,
+    level: QueryLevel,
+    keys: Vec<QueryKey>,
+}
+
+impl QueryModel {
+    /// The model of a C-FIND, C-MOVE, or C-GET SOP Class.
+    pub fn from_sop_class(uid: &str) -> Option<QueryModel> {
+        if [
+            &uids::PatientRootQueryRetrieveInformationModelFIND,
+            &uids::PatientRootQueryRetrieveInformationModelMOVE,
+            &uids::PatientRootQueryRetrieveInformationModelGET,
+        ]
+        .iter()
+        .any(|sop_class| sop_class.uid() == uid)
+        {
+            Some(QueryModel::PatientRoot)
+        } else if [
+            &uids::StudyRootQueryRetrieveInformationModelFIND,
+            &uids::StudyRootQueryRetrieveInformationModelMOVE,
+            &uids::StudyRootQueryRetrieveInformationModelGET,
+        ]
+        .iter()
+        .any(|sop_class| sop_class.uid() == uid)
+        {
+            Some(QueryModel::StudyRoot)
+        } else {
+            None
+        }
+    }
+
+    /// The C-FIND SOP Class of the model.
+    pub fn find_sop_class(&self) -> &'static str {
+        match self {
+            QueryModel::PatientRoot => uids::PatientRootQueryRetrieveInformationModelFIND.uid(),
+            QueryModel::StudyRoot => uids::StudyRootQueryRetrieveInformationModelFIND.uid(),
+        }
+    }
+
+    /// The levels of the model, from the top.
+    pub fn levels(&self) -> &'static [QueryLevel] {
+        match self {
+            QueryModel::PatientRoot => &[
+                QueryLevel::Patient,
+                QueryLevel::Study,
+                QueryLevel::Series,
+                QueryLevel::Image,
+            ],
+            QueryModel::StudyRoot => &[QueryLevel::Study, QueryLevel::Series, QueryLevel::Image],
+        }
+    }
+
+    /// The level the attribute is a key of, or `None` if it's not associated with a level. The
+    /// attributes of the patient are keys of the study level with Study Root.
+    pub fn key_level(&self, tag: u32) -> Option<QueryLevel> {
+        let is_key = |keys: &[TagRef]| keys.iter().any(|key| key.tag == tag);
+        if is_key(&PATIENT_ROOT_KEYS) {
+            (*self == QueryModel::PatientRoot).then_some(QueryLevel::Patient)
+        } else if is_key(&PATIENT_KEYS) {
+            Some(match self {
+                QueryModel::PatientRoot => QueryLevel::Patient,
+                QueryModel::StudyRoot => QueryLevel::Study,
+            })
+        } else if is_key(&STUDY_KEYS) {
+            Some(QueryLevel::Study)
+        } else if is_key(&SERIES_KEYS) {
+            Some(QueryLevel::Series)
+        } else if is_key(&IMAGE_KEYS) {
+            Some(QueryLevel::Image)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for QueryModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            QueryModel::PatientRoot => "Patient Root",
+            QueryModel::StudyRoot => "Study Root",
+        })
+    }
+}
+
+impl QueryLevel {
+    /// Parses a value of `QueryRetrieveLevel`.
+    pub fn parse(value: &str) -> Option<QueryLevel> {
+        match value.trim() {
+            "PATIENT" => Some(QueryLevel::Patient),
+            "STUDY" => Some(QueryLevel::Study),
+            "SERIES" => Some(QueryLevel::Series),
+            "IMAGE" => Some(QueryLevel::Image),
+            _ => None,
+        }
+    }
+
+    /// The value of `QueryRetrieveLevel` for the level.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QueryLevel::Patient => "PATIENT",
+            QueryLevel::Study => "STUDY",
+            QueryLevel::Series => "SERIES",
+            QueryLevel::Image => "IMAGE",
+        }
+    }
+
+    /// The attribute uniquely identifying an entity of the level.
+    pub fn unique_key(&self) -> TagRef {
+        match self {
+            QueryLevel::Patient => &tags::PatientID,
+            QueryLevel::Study => &tags::StudyInstanceUID,
+            QueryLevel::Series => &tags::SeriesInstanceUID,
+            QueryLevel::Image => &tags::SOPInstanceUID,
+        }
+    }
+}
+
+impl fmt::Display for QueryLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl QueryKey {
+    pub fn tag(&self) -> u32 {
+        self.tag
+    }
+
+    pub fn vr(&self) -> VRRef {
+        self.matcher.vr()
+    }
+
+    /// The level of the key, or `None` if it's not associated with a level.
+    pub fn level(&self) -> Option<QueryLevel> {
+        self.level
+    }
+
+    pub fn matcher(&self) -> &Matcher {
+        &self.matcher
+    }
+
+    fn matching(&self) -> &Matching {
+        self.matcher.matching()
+    }
+}
+
+impl QueryIdentifier {
+    /// Validates the Identifier against the model, returning all the rules it breaks.
+    pub fn validate(
+        model: QueryModel,
+        identifier: &DicomRoot<'_>,
+    ) -> Result<QueryIdentifier, Vec<QueryKeyError>> {
+        let level: QueryLevel = match query_level(identifier) {
+            Ok(level) if model.levels().contains(&level) => level,
+            Ok(level) => return Err(vec![QueryKeyError::LevelNotInModel { level, model }]),
+            Err(e) => return Err(vec![e]),
+        };
+
+        let mut errors: Vec<QueryKeyError> = Vec::new();
+        let mut keys: Vec<QueryKey> = Vec::new();
+        for (tag, obj) in identifier.iter_child_nodes() {
+            let tag: u32 = *tag;
+            if tag == tags::QueryRetrieveLevel.tag
+                || Tag::is_group_length(tag)
+                || LEVEL_INDEPENDENT_KEYS.iter().any(|key| key.tag == tag)
+            {
+                continue;
+            }
+
+            let key_level: Option<QueryLevel> = model.key_level(tag);
+            if let Some(key_level) = key_level.filter(|key_level| *key_level > level) {
+                errors.push(QueryKeyError::KeyBelowLevel {
+                    key: key_name(tag),
+                    key_level,
+                    level,
+                });
+                continue;
+            }
+            if obj.element().is_seq_like() && obj.item_count() > 1 {
+                errors.push(QueryKeyError::SequenceItems {
+                    key: key_name(tag),
+                    items: obj.item_count(),
+                });
+                continue;
+            }
+            match key_matcher(obj.element()) {
+                Ok(matcher) => keys.push(QueryKey {
+                    tag,
+                    level: key_level,
+                    matcher,
+                }),
+                Err(e) => errors.push(QueryKeyError::InvalidValue {
+                    key: key_name(tag),
+                    reason: e.to_string(),
+                }),
+            }
+        }
+
+        for unique_level in model.levels().iter().take_while(|l| **l <= level) {
+            let unique_key: TagRef = unique_level.unique_key();
+            let key: Option<&QueryKey> = keys.iter().find(|key| key.tag == unique_key.tag);
+            if *unique_level == level {
+                match key.map(QueryKey::matching) {
+                    Some(Matching::Universal | Matching::Single(_)) => {}
+                    Some(Matching::List(values))
+                        if unique_key.tag != tags::PatientID.tag
+                            && values.iter().all(|v| matches!(v, Matching::Single(_))) => {}
+                    Some(_) => errors.push(QueryKeyError::InvalidUniqueKeyMatching {
+                        key: key_name(unique_key.tag),
+                        value: key_value(identifier, unique_key.tag),
+                    }),
+                    None => keys.push(QueryKey {
+                        tag: unique_key.tag,
+                        level: Some(level),
+                        matcher: Matcher::new(unique_key_vr(unique_key), &[]),
+                    }),
+                }
+                continue;
+            }
+            match key.map(QueryKey::matching) {
+                Some(Matching::Single(_)) => {}
+                Some(_) => errors.push(QueryKeyError::UniqueKeyNotSingle {
+                    key: key_name(unique_key.tag),
+                    level: *unique_level,
+                    value: key_value(identifier, unique_key.tag),
+                }),
+                None => errors.push(QueryKeyError::MissingUniqueKey {
+                    key: key_name(unique_key.tag),
+                    level: *unique_level,
+                }),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        keys.sort_by_key(QueryKey::tag);
+        Ok(QueryIdentifier { model, level, keys })
+    }
+
+    pub fn model(&self) -> QueryModel {
+        self.model
+    }
+
+    pub fn level(&self) -> QueryLevel {
+        self.level
+    }
+
+    /// The keys of the Identifier, by tag, whose values are to be returned for each match.
+    pub fn keys(&self) -> &[QueryKey] {
+        &self.keys
+    }
+
+    /// The keys which aren't universal matching, which entities must match.
+    pub fn matching_keys(&self) -> impl Iterator<Item = &QueryKey> {
+        self.keys.iter().filter(|key| !key.matcher.is_universal())
+    }
+}
+
+fn query_level(identifier: &DicomRoot<'_>) -> Result<QueryLevel, QueryKeyError> {
+    let value: String = identifier
+        .get_child_by_tag(tags::QueryRetrieveLevel.tag)
+        .and_then(|obj| String::try_from(obj.element()).ok())
+        .ok_or(QueryKeyError::MissingLevel)?;
+    QueryLevel::parse(&value).ok_or(QueryKeyError::InvalidLevel(value))
+}
+
+fn key_matcher(element: &DicomElement<'_>) -> ParseResult<Matcher> {
+    if element.is_seq_like() || !element.vr().is_character_string {
+        return Ok(Matcher::new(element.vr(), &[]));
+    }
+    Matcher::from_element(element)
+}
+
+/// The VR of a unique key, which is `LO` for `PatientID` and otherwise `UI`.
+fn unique_key_vr(unique_key: TagRef) -> VRRef {
+    unique_key.implicit_vr.unwrap_or(&vr::UI)
+}
+
+/// The value of the key as given, for reporting.
+fn key_value(identifier: &DicomRoot<'_>, tag: u32) -> String {
+    identifier
+        .get_child_by_tag(tag)
+        .and_then(|obj| String::try_from(obj.element()).ok())
+        .unwrap_or_default()
+}
+
+/// The keyword of the key, otherwise its tag number.
+fn key_name(tag: u32) -> String {
+    STANDARD_DICOM_DICTIONARY
+        .get_tag_by_number(tag)
+        .map(|tag| tag.ident.to_owned())
+        .unwrap_or_else(|| Tag::format_tag_to_display(tag))
+}
@@ -0,0 +1,488 @@
+//! Storage Commitment Push Model, requesting a remote AE take responsibility for SOP Instances
+//! sent to it with N-ACTION and reporting the outcome with N-EVENT-REPORT. Part 4, Annex J.3.
+//!
+//! The result is reported asynchronously, either over the same association after the N-ACTION
+//! has been responded to, or over a separate association initiated by the SCP. A sender should
+//! only delete its own copies of SOP Instances once their commitment has been reported.
+
+use std::{
+    collections::BTreeMap,
+    io::{Read, Write},
+};
+
+use crate::{
+    core::{
+        charset::DEFAULT_CHARACTER_SET,
+        dcmelement::DicomElement,
+        dcmobject::{DicomObject, DicomRoot},
+        defn::{
+            constants::{tags::ITEM, ts::ImplicitVRLittleEndian},
+            tag::TagRef,
+            ts::TSRef,
+            vl::ValueLength,
+            vr,
+        },
+        RawValue,
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags, uids},
+};
+
+use super::{
+    assoc::Association,
+    commands::{element, encode_dataset, pres_ctx_for, send_request, CommandBuilder, Message},
+    constants::{status, CommandField},
+    error::DimseError,
+};
+
+/// The `ActionTypeID` of an N-ACTION requesting storage commitment.
+pub const REQUEST_COMMITMENT_ACTION_TYPE_ID: u16 = 1;
+
+/// The `EventTypeID` of an N-EVENT-REPORT in which all SOP Instances were committed.
+pub const COMMITTED_EVENT_TYPE_ID: u16 = 1;
+
+/// The `EventTypeID` of an N-EVENT-REPORT in which one or more SOP Instances failed to commit.
+pub const FAILURES_EXIST_EVENT_TYPE_ID: u16 = 2;
+
+/// Values of the `FailureReason` (0008,1197) of SOP Instances which failed to commit.
+///
+/// See Part 4, Annex J.3.3.1.
+pub mod failure_reason {
+    /// A general failure in processing the operation.
+    pub const PROCESSING_FAILURE: u16 = 0x0110;
+    /// The SOP Instance is not known to the SCP.
+    pub const NO_SUCH_OBJECT_INSTANCE: u16 = 0x0112;
+    /// The SCP does not have the resources to commit the SOP Instance.
+    pub const RESOURCE_LIMITATION: u16 = 0x0213;
+    /// The SCP does not support commitment of the SOP Class.
+    pub const REFERENCED_SOP_CLASS_NOT_SUPPORTED: u16 = 0x0122;
+    /// The SOP Class of the SOP Instance does not match the one known to the SCP.
+    pub const CLASS_INSTANCE_CONFLICT: u16 = 0x0119;
+    /// The Transaction UID was already used for another request.
+    pub const DUPLICATE_TRANSACTION_UID: u16 = 0x0131;
+}
+
+/// A reference to a SOP Instance by its SOP Class and SOP Instance UIDs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SopReference {
+    sop_class_uid: String,
+    sop_instance_uid: String,
+}
+
+impl SopReference {
+    pub fn new(sop_class_uid: &str, sop_instance_uid: &str) -> SopReference {
+        SopReference {
+            sop_class_uid: sop_class_uid.to_owned(),
+            sop_instance_uid: sop_instance_uid.to_owned(),
+        }
+    }
+
+    pub fn sop_class_uid(&self) -> &str {
+        &self.sop_class_uid
+    }
+
+    pub fn sop_instance_uid(&self) -> &str {
+        &self.sop_instance_uid
+    }
+
+    /// Reads the reference from an item of `ReferencedSOPSequence` or `FailedSOPSequence`.
+    fn from_item(item: &DicomObject) -> Result<SopReference, DimseError> {
+        Ok(SopReference {
+            sop_class_uid: item_string(item, &tags::ReferencedSOPClassUID)?,
+            sop_instance_uid: item_string(item, &tags::ReferencedSOPInstanceUID)?,
+        })
+    }
+
+    /// The attributes of an item of `ReferencedSOPSequence` or `FailedSOPSequence`.
+    fn to_item(&self, ts: TSRef) -> Result<BTreeMap<u32, DicomObject<'static>>, DimseError> {
+        let mut child_nodes: BTreeMap<u32, DicomObject<'static>> = BTreeMap::new();
+        for (tag, uid) in [
+            (&tags::ReferencedSOPClassUID, &self.sop_class_uid),
+            (&tags::ReferencedSOPInstanceUID, &self.sop_instance_uid),
+        ] {
+            child_nodes.insert(
+                tag.tag,
+                DicomObject::new(element(tag, &vr::UI, Some(uid), ts)?),
+            );
+        }
+        Ok(child_nodes)
+    }
+}
+
+/// A request for the SOP Instances to be committed, identified by its Transaction UID so the
+/// result can be matched to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitmentRequest {
+    transaction_uid: String,
+    references: Vec<SopReference>,
+}
+
+impl CommitmentRequest {
+    pub fn new(transaction_uid: &str, references: Vec<SopReference>) -> CommitmentRequest {
+        CommitmentRequest {
+            transaction_uid: transaction_uid.to_owned(),
+            references,
+        }
+    }
+
+    pub fn transaction_uid(&self) -> &str {
+        &self.transaction_uid
+    }
+
+    pub fn references(&self) -> &[SopReference] {
+        &self.references
+    }
+
+    /// Reads the request from the Data Set of an N-ACTION-RQ.
+    pub fn from_dataset(dataset: &DicomRoot<'_>) -> Result<CommitmentRequest, DimseError> {
+        Ok(CommitmentRequest {
+            transaction_uid: root_string(dataset, &tags::TransactionUID)?,
+            references: sequence_items(dataset, &tags::ReferencedSOPSequence)
+                .map(SopReference::from_item)
+                .collect::<Result<Vec<SopReference>, DimseError>>()?,
+        })
+    }
+
+    /// Creates the Data Set of an N-ACTION-RQ for this request.
+    pub fn to_dataset(&self, ts: TSRef) -> Result<DicomRoot<'static>, DimseError> {
+        let mut child_nodes: BTreeMap<u32, DicomObject<'static>> = BTreeMap::new();
+        insert_transaction_uid(&mut child_nodes, &self.transaction_uid, ts)?;
+        insert_sequence(
+            &mut child_nodes,
+            &tags::ReferencedSOPSequence,
+            self.references
+                .iter()
+                .map(|reference| reference.to_item(ts))
+                .collect::<Result<_, _>>()?,
+            ts,
+        );
+        Ok(new_root(child_nodes, ts))
+    }
+}
+
+/// The outcome of a `CommitmentRequest`, listing the SOP Instances committed and those which
+/// failed along with the reason.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitmentResult {
+    transaction_uid: String,
+    committed: Vec<SopReference>,
+    failed: Vec<(SopReference, u16)>,
+}
+
+impl CommitmentResult {
+    /// Creates an empty result for the request with the given Transaction UID.
+    pub fn new(transaction_uid: &str) -> CommitmentResult {
+        CommitmentResult {
+            transaction_uid: transaction_uid.to_owned(),
+            committed: Vec::new(),
+            failed: Vec::new(),
+        }
+    }
+
+    /// Adds a SOP Instance which was committed.
+    pub fn committed(mut self, reference: SopReference) -> Self {
+        self.committed.push(reference);
+        self
+    }
+
+    /// Adds a SOP Instance which failed to commit, with one of the `failure_reason` values.
+    pub fn failed(mut self, reference: SopReference, failure_reason: u16) -> Self {
+        self.failed.push((reference, failure_reason));
+        self
+    }
+
+    pub fn transaction_uid(&self) -> &str {
+        &self.transaction_uid
+    }
+
+    /// The SOP Instances which were committed.
+    pub fn committed_references(&self) -> &[SopReference] {
+        &self.committed
+    }
+
+    /// The SOP Instances which failed to commit, along with the `FailureReason`.
+    pub fn failed_references(&self) -> &[(SopReference, u16)] {
+        &self.failed
+    }
+
+    /// Whether every SOP Instance of the request was committed.
+    pub fn is_all_committed(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// The `EventTypeID` of the N-EVENT-REPORT reporting this result.
+    pub fn event_type_id(&self) -> u16 {
+        if self.is_all_committed() {
+            COMMITTED_EVENT_TYPE_ID
+        } else {
+            FAILURES_EXIST_EVENT_TYPE_ID
+        }
+    }
+
+    /// Reads the result from the Data Set of an N-EVENT-REPORT-RQ.
+    pub fn from_dataset(dataset: &DicomRoot<'_>) -> Result<CommitmentResult, DimseError> {
+        let committed: Vec<SopReference> = sequence_items(dataset, &tags::ReferencedSOPSequence)
+            .map(SopReference::from_item)
+            .collect::<Result<Vec<SopReference>, DimseError>>()?;
+        let failed: Vec<(SopReference, u16)> = sequence_items(dataset, &tags::FailedSOPSequence)
+            .map(|item| {
+                let failure_reason: u16 = item
+                    .get_child_by_tag(tags::FailureReason.tag)
+                    .and_then(|obj| u16::try_from(obj.element()).ok())
+                    .unwrap_or(failure_reason::PROCESSING_FAILURE);
+                Ok((SopReference::from_item(item)?, failure_reason))
+            })
+            .collect::<Result<Vec<(SopReference, u16)>, DimseError>>()?;
+        Ok(CommitmentResult {
+            transaction_uid: root_string(dataset, &tags::TransactionUID)?,
+            committed,
+            failed,
+        })
+    }
+
+    /// Creates the Data Set of an N-EVENT-REPORT-RQ for this result. Sequences without any items
+    /// are omitted.
+    pub fn to_dataset(&self, ts: TSRef) -> Result<DicomRoot<'static>, DimseError> {
+        let mut child_nodes: BTreeMap<u32, DicomObject<'static>> = BTreeMap::new();
+        insert_transaction_uid(&mut child_nodes, &self.transaction_uid, ts)?;
+        if !self.committed.is_empty() {
+            insert_sequence(
+                &mut child_nodes,
+                &tags::ReferencedSOPSequence,
+                self.committed
+                    .iter()
+                    .map(|reference| reference.to_item(ts))
+                    .collect::<Result<_, _>>()?,
+                ts,
+            );
+        }
+        if !self.failed.is_empty() {
+            let mut items: Vec<BTreeMap<u32, DicomObject<'static>>> = Vec::new();
+            for (reference, failure_reason) in &self.failed {
+                let mut item: BTreeMap<u32, DicomObject<'static>> = reference.to_item(ts)?;
+                let mut elem: DicomElement =
+                    DicomElement::new_empty(&tags::FailureReason, &vr::US, ts);
+                elem.encode_value(RawValue::UnsignedShorts(vec![*failure_reason]), None)?;
+                item.insert(elem.tag(), DicomObject::new(elem));
+                items.push(item);
+            }
+            insert_sequence(&mut child_nodes, &tags::FailedSOPSequence, items, ts);
+        }
+        Ok(new_root(child_nodes, ts))
+    }
+}
+
+/// Requests storage commitment of the SOP Instances over the association, as an SCU. The Storage
+/// Commitment Push Model SOP Class must have been accepted for the association. The result is
+/// reported later by the remote AE, see `receive_commitment_result()`.
+///
+/// If the remote AE responds with a failure status this returns `DimseError::FailureStatus`.
+pub fn request_commitment<S: Read + Write>(
+    assoc: &mut Association,
+    stream: &mut S,
+    request: &CommitmentRequest,
+) -> Result<(), DimseError> {
+    let sop_class_uid: &str = uids::StorageCommitmentPushModelSOPClass.uid;
+    let (ctx_id, ts) = pres_ctx_for(assoc, sop_class_uid)?;
+    let rq = CommandBuilder::n_action_rq(
+        assoc.next_msg_id(),
+        sop_class_uid,
+        uids::StorageCommitmentPushModelSOPInstance.uid,
+        REQUEST_COMMITMENT_ACTION_TYPE_ID,
+    )
+    .build()?;
+    let dataset: Vec<u8> = encode_dataset(&request.to_dataset(ts)?, ts)?;
+    send_request(
+        assoc,
+        stream,
+        Message::new(ctx_id, rq, Some(dataset)),
+        CommandField::NActionRsp,
+    )?;
+    Ok(())
+}
+
+/// Handles an N-EVENT-REPORT-RQ reporting the result of a storage commitment request, as an SCU.
+/// The report is acknowledged and its result returned.
+pub fn receive_commitment_result<S: Read + Write>(
+    assoc: &Association,
+    rq: Message,
+    stream: &mut S,
+) -> Result<CommitmentResult, DimseError> {
+    if rq.command_field() != Some(CommandField::NEventReportReq) {
+        return Err(DimseError::InvalidMessage(format!(
+            "expected storage commitment result: {:?}",
+            rq.command_field()
+        )));
+    }
+    let result: Result<CommitmentResult, DimseError> =
+        decode_request(assoc, &rq).and_then(|dataset| CommitmentResult::from_dataset(&dataset));
+    let status: u16 = match (&result, rq.event_type_id()) {
+        (Err(_), _) => status::PROCESSING_FAILURE,
+        (Ok(_), Some(COMMITTED_EVENT_TYPE_ID | FAILURES_EXIST_EVENT_TYPE_ID)) => status::SUCCESS,
+        (Ok(_), _) => status::INVALID_ATTRIBUTE_VALUE,
+    };
+    let rsp = CommandBuilder::n_event_report_rsp(&rq, status).build()?;
+    assoc.write_message(&Message::new(rq.ctx_id(), rsp, None), stream)?;
+    result
+}
+
+/// Handles an N-ACTION-RQ requesting storage commitment, as an SCP. The request is acknowledged
+/// and returned, after which the result should be reported with `send_commitment_result()` once
+/// the SOP Instances have been committed.
+pub fn receive_commitment_request<S: Read + Write>(
+    assoc: &Association,
+    rq: Message,
+    stream: &mut S,
+) -> Result<CommitmentRequest, DimseError> {
+    if rq.command_field() != Some(CommandField::NActionReq) {
+        return Err(DimseError::InvalidMessage(format!(
+            "expected storage commitment request: {:?}",
+            rq.command_field()
+        )));
+    }
+    let request: Result<CommitmentRequest, DimseError> =
+        decode_request(assoc, &rq).and_then(|dataset| CommitmentRequest::from_dataset(&dataset));
+    let status: u16 = match (&request, rq.action_type_id()) {
+        (_, action_type_id) if action_type_id != Some(REQUEST_COMMITMENT_ACTION_TYPE_ID) => {
+            status::NO_SUCH_ACTION_TYPE
+        }
+        (_, _)
+            if rq.requested_sop_instance_uid().as_deref()
+                != Some(uids::StorageCommitmentPushModelSOPInstance.uid) =>
+        {
+            status::NO_SUCH_SOP_INSTANCE
+        }
+        (Err(_), _) => status::PROCESSING_FAILURE,
+        (Ok(_), _) => status::SUCCESS,
+    };
+    let rsp = CommandBuilder::n_action_rsp(&rq, status).build()?;
+    assoc.write_message(&Message::new(rq.ctx_id(), rsp, None), stream)?;
+    if status != status::SUCCESS {
+        return Err(DimseError::FailureStatus {
+            status,
+            error_comment: None,
+        });
+    }
+    request
+}
+
+/// Reports the result of a storage commitment request over the association, as an SCP. The
+/// Storage Commitment Push Model SOP Class must have been accepted for the association.
+///
+/// If the remote AE responds with a failure status this returns `DimseError::FailureStatus`.
+pub fn send_commitment_result<S: Read + Write>(
+    assoc: &mut Association,
+    stream: &mut S,
+    result: &CommitmentResult,
+) -> Result<(), DimseError> {
+    let sop_class_uid: &str = uids::StorageCommitmentPushModelSOPClass.uid;
+    let (ctx_id, ts) = pres_ctx_for(assoc, sop_class_uid)?;
+    let rq = CommandBuilder::n_event_report_rq(
+        assoc.next_msg_id(),
+        sop_class_uid,
+        uids::StorageCommitmentPushModelSOPInstance.uid,
+        result.event_type_id(),
+    )
+    .build()?;
+    let dataset: Vec<u8> = encode_dataset(&result.to_dataset(ts)?, ts)?;
+    send_request(
+        assoc,
+        stream,
+        Message::new(ctx_id, rq, Some(dataset)),
+        CommandField::NEventReportRsp,
+    )?;
+    Ok(())
+}
+
+/// Decodes the Data Set of a request, which is required.
+fn decode_request(assoc: &Association, rq: &Message) -> Result<DicomRoot<'static>, DimseError> {
+    let ts: TSRef = assoc
+        .get_pres_ctx(rq.ctx_id())
+        .ok_or(DimseError::UnknownPresentationContext(rq.ctx_id()))?
+        .ts();
+    rq.decode_dataset(ts)?
+        .ok_or_else(|| DimseError::InvalidMessage("request has no data set".to_owned()))
+}
+
+/// The items of the sequence at the root of the dataset, if present.
+fn sequence_items<'a>(
+    dataset: &'a DicomRoot<'_>,
+    tag: TagRef,
+) -> impl Iterator<Item = &'a DicomObject<'a>> {
+    dataset
+        .get_child_by_tag(tag.tag)
+        .into_iter()
+        .flat_map(|obj| obj.iter_items())
+}
+
+fn root_string(dataset: &DicomRoot<'_>, tag: TagRef) -> Result<String, DimseError> {
+    dataset
+        .get_child_by_tag(tag.tag)
+        .and_then(|obj| String::try_from(obj.element()).ok())
+        .ok_or_else(|| DimseError::InvalidMessage(format!("missing attribute: {}", tag.ident)))
+}
+
+fn item_string(item: &DicomObject, tag: TagRef) -> Result<String, DimseError> {
+    item.get_child_by_tag(tag.tag)
+        .and_then(|obj| String::try_from(obj.element()).ok())
+        .ok_or_else(|| DimseError::InvalidMessage(format!("missing attribute: {}", tag.ident)))
+}
+
+fn insert_transaction_uid(
+    child_nodes: &mut BTreeMap<u32, DicomObject<'static>>,
+    transaction_uid: &str,
+    ts: TSRef,
+) -> Result<(), DimseError> {
+    let elem: DicomElement = element(&tags::TransactionUID, &vr::UI, Some(transaction_uid), ts)?;
+    child_nodes.insert(elem.tag(), DicomObject::new(elem));
+    Ok(())
+}
+
+/// Inserts a sequence with the given items. The value lengths are computed by `new_root()`.
+fn insert_sequence(
+    child_nodes: &mut BTreeMap<u32, DicomObject<'static>>,
+    tag: TagRef,
+    items: Vec<BTreeMap<u32, DicomObject<'static>>>,
+    ts: TSRef,
+) {
+    let items: Vec<DicomObject> = items
+        .into_iter()
+        .map(|item| {
+            // Items are always encoded as Implicit VR, see Part 5, Section 7.5.
+            let elem: DicomElement = DicomElement::new(
+                ITEM,
+                &vr::UN,
+                ValueLength::Explicit(0),
+                &ImplicitVRLittleEndian,
+                DEFAULT_CHARACTER_SET,
+                Vec::new(),
+                Vec::new(),
+            );
+            DicomObject::new_with_children(elem, item, Vec::new())
+        })
+        .collect();
+    let elem: DicomElement = DicomElement::new(
+        tag,
+        &vr::SQ,
+        ValueLength::Explicit(0),
+        ts,
+        DEFAULT_CHARACTER_SET,
+        Vec::new(),
+        Vec::new(),
+    );
+    child_nodes.insert(
+        elem.tag(),
+        DicomObject::new_with_children(elem, BTreeMap::new(), items),
+    );
+}
+
+fn new_root(child_nodes: BTreeMap<u32, DicomObject<'static>>, ts: TSRef) -> DicomRoot<'static> {
+    let mut dcmroot: DicomRoot<'static> = DicomRoot::new(
+        ts,
+        DEFAULT_CHARACTER_SET,
+        &STANDARD_DICOM_DICTIONARY,
+        child_nodes,
+        Vec::new(),
+    );
+    dcmroot.update_value_lengths();
+    dcmroot
+}
@@ -0,0 +1,297 @@
+//! A single error type for the library, for applications which handle errors from reading,
+//! writing, decoding, and communicating datasets in one place.
+//!
+//! Each area of the library reports failures with its own error type, such as `ParseError` or
+//! `WriteError`, which all convert into a `DicomError`. A `DicomError` classifies the failure with
+//! an `ErrorKind`, so that e.g. a truncated file can be told apart from an invalid VR or an
+//! unsupported transfer syntax without matching every variant of every error type, and carries the
+//! tag path and byte offset the failure occurred at when they're known.
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    io,
+};
+
+use crate::core::{
+    defn::tag::TagPath, pixeldata::PixelDataError, read::ParseError, write::error::WriteError,
+};
+#[cfg(feature = "dimse")]
+use crate::dimse::error::DimseError;
+
+/// A result whose error is a `DicomError`.
+pub type DicomResult<T> = Result<T, DicomError>;
+
+/// The category of a failure, for handling errors programmatically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The dataset isn't DICOM, such as not having the DICOM prefix.
+    NotDicom,
+
+    /// The dataset or stream ended before what was being read was complete, such as a file
+    /// truncated during a transfer.
+    Truncated,
+
+    /// An element has a VR which is unknown or can't be used for the element.
+    InvalidVR,
+
+    /// An element's value can't be decoded or encoded, or isn't available to be written.
+    InvalidValue,
+
+    /// Text can't be decoded or encoded in the dataset's character set.
+    Charset,
+
+    /// A tag path can't be parsed or resolved.
+    InvalidTagPath,
+
+    /// An attribute required for the operation is missing from the dataset.
+    MissingAttribute,
+
+    /// The operation isn't supported for the dataset's transfer syntax.
+    UnsupportedTransferSyntax,
+
+    /// The dataset uses an encoding or feature which isn't supported, other than its transfer
+    /// syntax.
+    Unsupported,
+
+    /// An argument to the operation is out of range or otherwise not valid.
+    InvalidArgument,
+
+    /// The remote AE sent something which doesn't follow the DICOM network protocol.
+    Protocol,
+
+    /// The remote AE rejected, aborted, or failed a request.
+    Remote,
+
+    /// Reading or writing failed for a reason other than the stream ending.
+    Io,
+}
+
+/// The error a `DicomError` was converted from.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ErrorSource {
+    Parse(ParseError),
+    Write(WriteError),
+    PixelData(PixelDataError),
+    #[cfg(feature = "dimse")]
+    Dimse(DimseError),
+    Io(io::Error),
+}
+
+/// An error from any area of the library, classified by `ErrorKind`.
+#[derive(Debug)]
+pub struct DicomError {
+    kind: ErrorKind,
+    tagpath: Option<TagPath>,
+    offset: Option<u64>,
+    source: ErrorSource,
+}
+
+impl DicomError {
+    /// The category of the failure.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The path to the element the failure occurred at, if known.
+    pub fn tagpath(&self) -> Option<&TagPath> {
+        self.tagpath.as_ref()
+    }
+
+    /// The byte position in the dataset the failure occurred at, if known.
+    pub fn offset(&self) -> Option<u64> {
+        self.offset
+    }
+
+    /// The error this was converted from.
+    pub fn error_source(&self) -> &ErrorSource {
+        &self.source
+    }
+
+    /// Consumes this, returning the error it was converted from.
+    pub fn into_error_source(self) -> ErrorSource {
+        self.source
+    }
+
+    /// Sets the path to the element the failure occurred at, if not already known.
+    pub fn with_tagpath(mut self, tagpath: TagPath) -> Self {
+        self.tagpath.get_or_insert(tagpath);
+        self
+    }
+
+    /// Sets the byte position the failure occurred at, if not already known.
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.offset.get_or_insert(offset);
+        self
+    }
+
+    fn new(kind: ErrorKind, context: ParseContext, source: ErrorSource) -> Self {
+        let (tagpath, offset) = context;
+        DicomError {
+            kind,
+            tagpath,
+            offset,
+            source,
+        }
+    }
+}
+
+impl Display for DicomError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.source {
+            ErrorSource::Parse(e) => write!(f, "{e}"),
+            ErrorSource::Write(e) => write!(f, "{e}"),
+            ErrorSource::PixelData(e) => write!(f, "{e}"),
+            #[cfg(feature = "dimse")]
+            ErrorSource::Dimse(e) => write!(f, "{e}"),
+            ErrorSource::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for DicomError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.source {
+            ErrorSource::Parse(e) => e.source(),
+            ErrorSource::Write(e) => e.source(),
+            ErrorSource::PixelData(e) => e.source(),
+            #[cfg(feature = "dimse")]
+            ErrorSource::Dimse(e) => e.source(),
+            ErrorSource::Io(e) => e.source(),
+        }
+    }
+}
+
+impl From<ParseError> for DicomError {
+    fn from(e: ParseError) -> Self {
+        let context: ParseContext = parse_context(Some(&e));
+        DicomError::new(parse_kind(&e), context, ErrorSource::Parse(e))
+    }
+}
+
+impl From<WriteError> for DicomError {
+    fn from(e: WriteError) -> Self {
+        let context: ParseContext = parse_context(match &e {
+            WriteError::EncodeValueError(e) => Some(e),
+            _ => None,
+        });
+        DicomError::new(write_kind(&e), context, ErrorSource::Write(e))
+    }
+}
+
+impl From<PixelDataError> for DicomError {
+    fn from(e: PixelDataError) -> Self {
+        let context: ParseContext = parse_context(match &e {
+            PixelDataError::ParseError(e) => Some(e),
+            PixelDataError::WriteError(WriteError::EncodeValueError(e)) => Some(e),
+            _ => None,
+        });
+        let kind: ErrorKind = match &e {
+            PixelDataError::MissingPixelData | PixelDataError::MissingAttribute(_) => {
+                ErrorKind::MissingAttribute
+            }
+            PixelDataError::UnsupportedTransferSyntax(_) => ErrorKind::UnsupportedTransferSyntax,
+            PixelDataError::UnsupportedEncoding(_) => ErrorKind::Unsupported,
+            PixelDataError::InvalidImagePixel(_)
+            | PixelDataError::InvalidConcatenation(_)
+            | PixelDataError::InvalidGeometry(_)
+            | PixelDataError::InvalidVolumeFile(_) => ErrorKind::InvalidValue,
+            PixelDataError::FrameOutOfRange { .. } | PixelDataError::InvalidQuality(_) => {
+                ErrorKind::InvalidArgument
+            }
+            PixelDataError::EncodeFailed { .. } | PixelDataError::InvalidCodestream(_) => {
+                ErrorKind::InvalidValue
+            }
+            PixelDataError::InsufficientData { .. } => ErrorKind::Truncated,
+            PixelDataError::ParseError(e) => parse_kind(e),
+            PixelDataError::WriteError(e) => write_kind(e),
+        };
+        DicomError::new(kind, context, ErrorSource::PixelData(e))
+    }
+}
+
+#[cfg(feature = "dimse")]
+impl From<DimseError> for DicomError {
+    fn from(e: DimseError) -> Self {
+        let context: ParseContext = parse_context(match &e {
+            DimseError::ParseError(e) => Some(e),
+            DimseError::WriteError(WriteError::EncodeValueError(e)) => Some(e),
+            _ => None,
+        });
+        let kind: ErrorKind = match &e {
+            DimseError::InvalidPduType(_)
+            | DimseError::UnexpectedPdu(_)
+            | DimseError::InvalidPdu(_)
+            | DimseError::UnknownPresentationContext(_)
+            | DimseError::MessageTooLong(_)
+            | DimseError::InvalidMessage(_) => ErrorKind::Protocol,
+            DimseError::AssociationRejected { .. }
+            | DimseError::AssociationAborted { .. }
+            | DimseError::AbstractSyntaxNotAccepted(_)
+            | DimseError::FailureStatus { .. } => ErrorKind::Remote,
+            DimseError::TooManyPresentationContexts(_) => ErrorKind::InvalidArgument,
+            DimseError::ParseError(e) => parse_kind(e),
+            DimseError::WriteError(e) => write_kind(e),
+            DimseError::IOError { source } => io_kind(source),
+        };
+        DicomError::new(kind, context, ErrorSource::Dimse(e))
+    }
+}
+
+impl From<io::Error> for DicomError {
+    fn from(e: io::Error) -> Self {
+        DicomError::new(io_kind(&e), (None, None), ErrorSource::Io(e))
+    }
+}
+
+/// The tag path and byte offset a failure occurred at.
+type ParseContext = (Option<TagPath>, Option<u64>);
+
+/// Gets the tag path and byte offset from the `ParseError` a failure originated from, if any.
+fn parse_context(e: Option<&ParseError>) -> ParseContext {
+    match e {
+        Some(ParseError::DetailedError {
+            offset, tagpath, ..
+        }) => (Some(tagpath.clone()), Some(*offset)),
+        _ => (None, None),
+    }
+}
+
+fn parse_kind(e: &ParseError) -> ErrorKind {
+    match e {
+        ParseError::BadDICOMPrefix(_) => ErrorKind::NotDicom,
+        ParseError::UnknownExplicitVR(_) | ParseError::VRMismatch { .. } => ErrorKind::InvalidVR,
+        ParseError::ExpectedEOF => ErrorKind::Truncated,
+        ParseError::CharsetError { .. } => ErrorKind::Charset,
+        ParseError::IOError { source } => io_kind(source),
+        ParseError::DetailedError { source, .. } => parse_kind(source),
+        ParseError::DecodeValueError { .. }
+        | ParseError::EncodeValueError { .. }
+        | ParseError::InvalidPadding { .. } => ErrorKind::InvalidValue,
+        ParseError::InvalidTagPath { .. } => ErrorKind::InvalidTagPath,
+    }
+}
+
+fn write_kind(e: &WriteError) -> ErrorKind {
+    match e {
+        WriteError::EncodeValueError(e) => parse_kind(e),
+        WriteError::InvalidValueLength
+        | WriteError::DeferredValue
+        | WriteError::InvalidPadding { .. }
+        | WriteError::BulkDataValue { .. } => ErrorKind::InvalidValue,
+        WriteError::UnsupportedTranscode { .. } => ErrorKind::UnsupportedTransferSyntax,
+        WriteError::ChunkedValue { .. } => ErrorKind::InvalidArgument,
+        WriteError::BulkDataError { source, .. }
+        | WriteError::IOError { source }
+        | WriteError::DetailedIOError { source, .. } => io_kind(source),
+    }
+}
+
+fn io_kind(e: &io::Error) -> ErrorKind {
+    match e.kind() {
+        io::ErrorKind::UnexpectedEof => ErrorKind::Truncated,
+        _ => ErrorKind::Io,
+    }
+}
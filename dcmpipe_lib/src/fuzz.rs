@@ -0,0 +1,428 @@
+//! Entry points for fuzzing the parser and writer, enabled by the `fuzz` feature.
+//!
+//! `parse_from_bytes()` and `write_to_bytes()` read and write complete DICOM files in memory using
+//! the standard dictionary, so that results only depend on the input. Fuzz targets can exercise the
+//! parser with arbitrary bytes through `parse_all()`, which should fail with errors rather than
+//! panic, or generate well-formed datasets with `ArbitraryDataset` and check that they survive
+//! being written and parsed again with `check_roundtrip()`.
+//!
+//! ```no_run
+//! use arbitrary::{Arbitrary, Unstructured};
+//! use dcmpipe_lib::fuzz::{check_roundtrip, parse_all, ArbitraryDataset};
+//!
+//! fn fuzz_target(data: &[u8]) {
+//!     parse_all(data);
+//!
+//!     let mut u = Unstructured::new(data);
+//!     if let Ok(dataset) = ArbitraryDataset::arbitrary(&mut u) {
+//!         check_roundtrip(&dataset.0).expect("dataset should round-trip");
+//!     }
+//! }
+//! ```
+
+use std::{collections::BTreeSet, fmt};
+
+use arbitrary::{Arbitrary, Unstructured};
+use thiserror::Error;
+
+use crate::{
+    core::{
+        charset::DEFAULT_CHARACTER_SET,
+        dcmelement::{delimiter, structural, DicomElement},
+        dcmobject::DicomRoot,
+        defn::{
+            constants::{tags as coretags, ts as corets},
+            tag::TagRef,
+            ts::TSRef,
+            vl::ValueLength,
+            vr::{self, VRRef},
+        },
+        read::{ParseError, ParseResult, ParserBuilder},
+        values::RawValue,
+        write::{
+            builder::WriterBuilder, error::WriteError, transcode::encoded_header_len,
+            writer::WriteResult,
+        },
+    },
+    dict::{
+        stdlookup::STANDARD_DICOM_DICTIONARY,
+        tags,
+        transfer_syntaxes::{ExplicitVRBigEndian, ExplicitVRLittleEndian, ImplicitVRLittleEndian},
+        uids,
+    },
+};
+
+/// The transfer syntaxes generated datasets are encoded in.
+const TRANSFER_SYNTAXES: [TSRef; 3] = [
+    &ExplicitVRLittleEndian,
+    &ImplicitVRLittleEndian,
+    &ExplicitVRBigEndian,
+];
+
+/// The attributes generated datasets may have, in ascending order, covering each kind of value.
+const ATTRIBUTES: [TagRef; 25] = [
+    &tags::ImageType,
+    &tags::SOPClassUID,
+    &tags::SOPInstanceUID,
+    &tags::StudyDate,
+    &tags::Modality,
+    &tags::ReferencedImageSequence,
+    &tags::SimpleFrameList,
+    &tags::RecommendedDisplayFrameRateinFloat,
+    &tags::PatientsName,
+    &tags::PatientID,
+    &tags::PatientsWeight,
+    &tags::SliceThickness,
+    &tags::ReferencePixelX0,
+    &tags::Diffusionbvalue,
+    &tags::TagAngleSecondAxis,
+    &tags::StudyInstanceUID,
+    &tags::InstanceNumber,
+    &tags::ImagePositionPatient,
+    &tags::SamplesperPixel,
+    &tags::Rows,
+    &tags::Columns,
+    &tags::PixelSpacing,
+    &tags::WindowCenter,
+    &tags::RedPaletteColorLookupTableData,
+    &tags::PixelData,
+];
+
+/// The private block of generated private attributes, `(0009,10xx)`.
+const PRIVATE_CREATOR_TAG: u32 = 0x0009_0010;
+const PRIVATE_CREATOR: &str = "DCMPIPE FUZZ";
+
+/// The deepest nesting of sequences within generated datasets.
+const MAX_DEPTH: usize = 2;
+
+/// The most values of multi-valued attributes, and the most items of sequences.
+const MAX_VALUES: usize = 4;
+
+/// Failures of `check_roundtrip()`.
+#[derive(Error, Debug)]
+pub enum RoundtripError {
+    #[error("failed writing dataset")]
+    WriteError(#[from] WriteError),
+
+    #[error("failed parsing written dataset")]
+    ParseError(#[from] ParseError),
+
+    #[error("written dataset was not parsed as DICOM")]
+    NotDicom,
+
+    #[error("dataset written after parsing differs at byte {offset}")]
+    Mismatch { offset: usize },
+}
+
+/// Parses a complete DICOM file, including the File Preamble, with the standard dictionary.
+pub fn parse_from_bytes(bytes: &[u8]) -> ParseResult<Option<DicomRoot<'static>>> {
+    let mut parser = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .build(bytes);
+    DicomRoot::parse(&mut parser)
+}
+
+/// Writes a dataset as a complete DICOM file, in its transfer syntax. A File Meta group is
+/// required.
+pub fn write_to_bytes(dcmroot: &DicomRoot<'_>) -> WriteResult<Vec<u8>> {
+    let mut writer = WriterBuilder::for_file().ts(dcmroot.ts()).build(Vec::new());
+    writer.write_dcmroot(dcmroot)?;
+    writer.into_dataset()
+}
+
+/// Parses the bytes, along with the value of every element, ignoring any errors. This is the
+/// target for fuzzing the parser with arbitrary bytes.
+pub fn parse_all(bytes: &[u8]) {
+    if let Ok(Some(dcmroot)) = parse_from_bytes(bytes) {
+        for (_tagpath, element) in dcmroot.iter_elements() {
+            let _ = element.parse_value();
+        }
+    }
+}
+
+/// Checks that the dataset writes the same bytes after being written and parsed again.
+pub fn check_roundtrip(dcmroot: &DicomRoot<'_>) -> Result<(), RoundtripError> {
+    let written: Vec<u8> = write_to_bytes(dcmroot)?;
+    let parsed: DicomRoot<'_> = parse_from_bytes(&written)?.ok_or(RoundtripError::NotDicom)?;
+    let rewritten: Vec<u8> = write_to_bytes(&parsed)?;
+    if written != rewritten {
+        let offset: usize = written
+            .iter()
+            .zip(rewritten.iter())
+            .position(|(a, b)| a != b)
+            .unwrap_or_else(|| written.len().min(rewritten.len()));
+        return Err(RoundtripError::Mismatch { offset });
+    }
+    Ok(())
+}
+
+/// A well-formed dataset generated from arbitrary bytes, with a File Meta group, attributes of
+/// most VRs, private attributes, and nested sequences of both explicit and undefined length.
+///
+/// The dataset is generated as a stream of elements which is written and then parsed, so it's as
+/// the parser would produce.
+pub struct ArbitraryDataset(pub DicomRoot<'static>);
+
+impl fmt::Debug for ArbitraryDataset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<'a> Arbitrary<'a> for ArbitraryDataset {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let ts: TSRef = *u.choose(&TRANSFER_SYNTAXES)?;
+        let mut generator = Generator { u, ts };
+        let mut elements: Vec<DicomElement<'static>> = generator.file_meta()?;
+        generator.attributes(&mut elements, 0)?;
+
+        let mut writer = WriterBuilder::for_file().ts(ts).build(Vec::new());
+        writer
+            .write_elements(elements.iter())
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        let bytes: Vec<u8> = writer
+            .into_dataset()
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        parse_from_bytes(&bytes)
+            .ok()
+            .flatten()
+            .map(ArbitraryDataset)
+            .ok_or(arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// Generates the elements of a dataset, in the order they're written.
+struct Generator<'u, 'a> {
+    u: &'u mut Unstructured<'a>,
+    ts: TSRef,
+}
+
+impl Generator<'_, '_> {
+    fn file_meta(&mut self) -> arbitrary::Result<Vec<DicomElement<'static>>> {
+        let fmts: TSRef = &ExplicitVRLittleEndian;
+        Ok(vec![
+            element(
+                tags::FileMetaInformationVersion.tag,
+                &vr::OB,
+                fmts,
+                RawValue::Bytes(vec![0x00, 0x01]),
+            )?,
+            element(
+                tags::MediaStorageSOPClassUID.tag,
+                &vr::UI,
+                fmts,
+                RawValue::Uid(uids::SecondaryCaptureImageStorage.uid().to_owned()),
+            )?,
+            element(
+                tags::MediaStorageSOPInstanceUID.tag,
+                &vr::UI,
+                fmts,
+                RawValue::Uid(self.uid()?),
+            )?,
+            element(
+                tags::TransferSyntaxUID.tag,
+                &vr::UI,
+                fmts,
+                RawValue::Uid(self.ts.uid().uid().to_owned()),
+            )?,
+        ])
+    }
+
+    /// Generates a subset of `ATTRIBUTES`, followed by private attributes.
+    fn attributes(
+        &mut self,
+        elements: &mut Vec<DicomElement<'static>>,
+        depth: usize,
+    ) -> arbitrary::Result<()> {
+        let mut chosen: BTreeSet<usize> = BTreeSet::new();
+        for _ in 0..self.u.int_in_range(0..=ATTRIBUTES.len())? {
+            chosen.insert(self.u.choose_index(ATTRIBUTES.len())?);
+        }
+        for index in chosen {
+            let tag: TagRef = ATTRIBUTES[index];
+            let vr: VRRef = tag.implicit_vr.unwrap_or(&vr::UN);
+            if vr == &vr::SQ {
+                if depth < MAX_DEPTH {
+                    self.sequence(elements, tag.tag, depth)?;
+                }
+            } else {
+                let value: RawValue = self.value(vr)?;
+                elements.push(element(tag.tag, vr, self.ts, value)?);
+            }
+        }
+
+        if self.u.arbitrary()? {
+            elements.push(element(
+                PRIVATE_CREATOR_TAG,
+                &vr::LO,
+                self.ts,
+                RawValue::Strings(vec![PRIVATE_CREATOR.to_owned()]),
+            )?);
+            for offset in 0..self.u.int_in_range(1..=3u32)? {
+                let value: RawValue = self.value(&vr::OB)?;
+                elements.push(element(0x0009_1000 | offset, &vr::OB, self.ts, value)?);
+            }
+        }
+        Ok(())
+    }
+
+    /// Generates a sequence whose items each have attributes, with the sequence and each item
+    /// having either explicit or undefined length.
+    fn sequence(
+        &mut self,
+        elements: &mut Vec<DicomElement<'static>>,
+        tag: u32,
+        depth: usize,
+    ) -> arbitrary::Result<()> {
+        let seq_index: usize = elements.len();
+        let seq_undefined: bool = self.u.arbitrary()?;
+        elements.push(structural(tag, &vr::SQ, self.ts));
+        for _ in 0..self.u.int_in_range(0..=MAX_VALUES)? {
+            let item_index: usize = elements.len();
+            let item_undefined: bool = self.u.arbitrary()?;
+            elements.push(structural(coretags::ITEM, &vr::UN, implicit(self.ts)));
+            self.attributes(elements, depth + 1)?;
+            if item_undefined {
+                elements.push(delimiter(
+                    coretags::ITEM_DELIMITATION_ITEM,
+                    implicit(self.ts),
+                ));
+            } else {
+                set_contents_len(elements, item_index);
+            }
+        }
+        if seq_undefined {
+            elements.push(delimiter(
+                coretags::SEQUENCE_DELIMITATION_ITEM,
+                implicit(self.ts),
+            ));
+        } else {
+            set_contents_len(elements, seq_index);
+        }
+        Ok(())
+    }
+
+    /// Generates a value valid for the VR.
+    fn value(&mut self, vr: VRRef) -> arbitrary::Result<RawValue> {
+        let count: usize = self.u.int_in_range(1..=MAX_VALUES)?;
+        let value: RawValue = if vr == &vr::UI {
+            RawValue::Uid(self.uid()?)
+        } else if vr == &vr::US {
+            RawValue::UnsignedShorts(self.values(count)?)
+        } else if vr == &vr::SS {
+            RawValue::Shorts(self.values(count)?)
+        } else if vr == &vr::UL {
+            RawValue::UnsignedIntegers(self.values(count)?)
+        } else if vr == &vr::SL {
+            RawValue::Integers(self.values(count)?)
+        } else if vr == &vr::FL {
+            RawValue::Floats(self.values(count)?)
+        } else if vr == &vr::FD {
+            RawValue::Doubles(self.values(count)?)
+        } else if vr == &vr::OW {
+            let len: usize = self.u.int_in_range(0..=32)?;
+            RawValue::Words(self.values(len)?)
+        } else if vr == &vr::DS {
+            let values: Vec<i32> = self.values(count)?;
+            RawValue::Strings(
+                values
+                    .iter()
+                    .map(|value| format!("{:.2}", f64::from(*value) / 100.0))
+                    .collect(),
+            )
+        } else if vr == &vr::IS {
+            let values: Vec<i32> = self.values(count)?;
+            RawValue::Strings(values.iter().map(i32::to_string).collect())
+        } else if vr == &vr::DA {
+            let (year, month, day) = (
+                self.u.int_in_range(1900..=2099u32)?,
+                self.u.int_in_range(1..=12u32)?,
+                self.u.int_in_range(1..=28u32)?,
+            );
+            RawValue::Strings(vec![format!("{year:04}{month:02}{day:02}")])
+        } else if vr == &vr::CS {
+            let values: Vec<String> = (0..count)
+                .map(|_| self.text(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789_", 16))
+                .collect::<arbitrary::Result<Vec<String>>>()?;
+            RawValue::Strings(values)
+        } else if vr.is_character_string {
+            RawValue::Strings(vec![
+                self.text(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ abcxyz^0189.-", 64)?
+            ])
+        } else {
+            let len: usize = self.u.int_in_range(0..=64)?;
+            RawValue::Bytes(self.u.bytes(len)?.to_vec())
+        };
+        Ok(value)
+    }
+
+    fn values<T>(&mut self, count: usize) -> arbitrary::Result<Vec<T>>
+    where
+        T: for<'b> Arbitrary<'b>,
+    {
+        (0..count).map(|_| T::arbitrary(self.u)).collect()
+    }
+
+    /// Generates text of up to `max_len` characters of the alphabet, without trailing spaces as
+    /// those are padding.
+    fn text(&mut self, alphabet: &[u8], max_len: usize) -> arbitrary::Result<String> {
+        let len: usize = self.u.int_in_range(1..=max_len)?;
+        let text: String = (0..len)
+            .map(|_| self.u.choose(alphabet).map(|c| char::from(*c)))
+            .collect::<arbitrary::Result<String>>()?;
+        Ok(text.trim_end().to_owned())
+    }
+
+    fn uid(&mut self) -> arbitrary::Result<String> {
+        let value: u128 = self.u.arbitrary()?;
+        Ok(format!("2.25.{value}"))
+    }
+}
+
+/// Creates an element with the given value.
+fn element(
+    tag: u32,
+    vr: VRRef,
+    ts: TSRef,
+    value: RawValue,
+) -> arbitrary::Result<DicomElement<'static>> {
+    let mut elem: DicomElement<'static> = DicomElement::new(
+        tag,
+        vr,
+        ValueLength::Explicit(0),
+        ts,
+        DEFAULT_CHARACTER_SET,
+        Vec::new(),
+        Vec::new(),
+    );
+    elem.encode_value(value, None)
+        .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+    Ok(elem)
+}
+
+/// Gives the sequence or item at `index` the explicit length of the elements following it.
+fn set_contents_len(elements: &mut [DicomElement<'static>], index: usize) {
+    let len: u64 = elements[index + 1..]
+        .iter()
+        .map(|element| encoded_header_len(element.vr(), element.ts()) + element.data().len() as u64)
+        .sum();
+    let structural: &DicomElement<'static> = &elements[index];
+    elements[index] = DicomElement::new(
+        structural.tag(),
+        structural.vr(),
+        ValueLength::Explicit(len as u32),
+        structural.ts(),
+        structural.cs(),
+        Vec::new(),
+        Vec::new(),
+    );
+}
+
+/// Items and delimiters are always encoded as Implicit VR, see Part 5, Section 7.5.
+fn implicit(ts: TSRef) -> TSRef {
+    if ts.big_endian() {
+        &corets::ImplicitVRBigEndian
+    } else {
+        &corets::ImplicitVRLittleEndian
+    }
+}
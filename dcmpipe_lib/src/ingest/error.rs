@@ -0,0 +1,29 @@
+//! Errors that can occur while ingesting datasets.
+
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+use crate::core::read::ParseError;
+
+use super::SinkError;
+
+#[derive(Error, Debug)]
+/// Errors that can occur while ingesting datasets.
+pub enum IngestError {
+    /// The watched folder or one of its files could not be read.
+    #[error("i/o error reading: {}", path.display())]
+    IOError {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// Wrapper around `ParseError` for files which are not valid DICOM.
+    #[error("error parsing dataset")]
+    ParseError(#[from] ParseError),
+
+    /// The sink failed to handle the dataset.
+    #[error("error dispatching dataset: {0}")]
+    SinkError(SinkError),
+}
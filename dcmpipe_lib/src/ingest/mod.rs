@@ -0,0 +1,32 @@
+//! Ingestion of datasets arriving in a folder.
+//!
+//! A `Watcher` monitors a folder for new files, parses each once it's been completely written, and
+//! dispatches the parsed dataset to a `Sink`. Sinks decide what becomes of each dataset, such as
+//! storing it elsewhere or forwarding it to a remote AE.
+
+use std::{error::Error, path::Path};
+
+use crate::core::dcmobject::DicomRoot;
+
+pub mod error;
+pub mod watcher;
+
+pub use watcher::Watcher;
+
+/// The error type returned by sinks for datasets which they fail to handle.
+pub type SinkError = Box<dyn Error + Send + Sync>;
+
+/// A destination for datasets ingested by a `Watcher`.
+pub trait Sink {
+    /// Handles a dataset which arrived in the watched folder, parsed from the file at `path`.
+    fn ingest(&mut self, path: &Path, dcmroot: &DicomRoot<'_>) -> Result<(), SinkError>;
+}
+
+impl<F> Sink for F
+where
+    F: FnMut(&Path, &DicomRoot<'_>) -> Result<(), SinkError>,
+{
+    fn ingest(&mut self, path: &Path, dcmroot: &DicomRoot<'_>) -> Result<(), SinkError> {
+        self(path, dcmroot)
+    }
+}
@@ -0,0 +1,213 @@
+//! Watches a folder for newly arrived datasets.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{self, File},
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::core::{dcmobject::DicomRoot, read::ParserBuilder};
+
+use super::{error::IngestError, Sink};
+
+/// The result of ingesting a single file.
+#[derive(Debug)]
+pub enum WatchEvent {
+    /// The file was parsed and handled by the sink.
+    Ingested(PathBuf),
+
+    /// The file is not DICOM, and was not passed to the sink.
+    NotDicom(PathBuf),
+
+    /// The file could not be parsed, or the sink failed to handle it.
+    Failed(PathBuf, IngestError),
+}
+
+/// The state of a file as of the last poll.
+struct FileState {
+    len: u64,
+    modified: Option<SystemTime>,
+    /// Whether the file was ingested, or was ignored, since it last changed.
+    handled: bool,
+}
+
+/// Watches a folder for newly arrived files, dispatching the dataset of each to a `Sink`.
+///
+/// The folder is polled rather than relying on notifications from the operating system, which
+/// aren't available on all file systems, such as network shares. A file is only ingested once its
+/// length and modification time are unchanged between two polls, so files which are still being
+/// written aren't parsed early. Files whose name starts with `.` are ignored, allowing files to be
+/// written under a hidden name and then renamed once complete.
+///
+/// Each file is ingested once, unless it's modified afterwards, regardless of whether it could be
+/// parsed or handled by the sink.
+pub struct Watcher<'dict> {
+    folder: PathBuf,
+    parser_builder: ParserBuilder<'dict>,
+    recursive: bool,
+    ingest_existing: bool,
+    files: HashMap<PathBuf, FileState>,
+    polled: bool,
+}
+
+impl<'dict> Watcher<'dict> {
+    /// Creates a watcher for `folder`, including its sub-folders.
+    pub fn new<P: Into<PathBuf>>(folder: P) -> Watcher<'dict> {
+        Watcher {
+            folder: folder.into(),
+            parser_builder: ParserBuilder::default(),
+            recursive: true,
+            ingest_existing: false,
+            files: HashMap::new(),
+            polled: false,
+        }
+    }
+
+    /// Sets the parser used for arrived files. Sinks which only need part of each dataset can
+    /// configure the parser to stop early.
+    pub fn parser_builder(mut self, parser_builder: ParserBuilder<'dict>) -> Self {
+        self.parser_builder = parser_builder;
+        self
+    }
+
+    /// Whether files in sub-folders are also watched. Defaults to `true`.
+    pub fn recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Whether files already in the folder when first polled are ingested, rather than only
+    /// those which arrive afterwards. Defaults to `false`.
+    pub fn ingest_existing(mut self, ingest_existing: bool) -> Self {
+        self.ingest_existing = ingest_existing;
+        self
+    }
+
+    /// Get the watched folder.
+    pub fn folder(&self) -> &Path {
+        &self.folder
+    }
+
+    /// Scans the folder once, ingesting files which have been completely written since the last
+    /// poll. Fails only if the folder itself can't be read.
+    pub fn poll(&mut self, sink: &mut dyn Sink) -> Result<Vec<WatchEvent>, IngestError> {
+        let mut found: Vec<(PathBuf, u64, Option<SystemTime>)> = Vec::new();
+        list_files(&self.folder, self.recursive, &mut found).map_err(|source| {
+            IngestError::IOError {
+                path: self.folder.clone(),
+                source,
+            }
+        })?;
+        found.sort_by(|a, b| a.0.cmp(&b.0));
+
+        // Files no longer present are forgotten, so a file of the same name arriving later is
+        // ingested.
+        let present: HashSet<&Path> = found.iter().map(|(path, _, _)| path.as_path()).collect();
+        self.files
+            .retain(|path, _| present.contains(path.as_path()));
+
+        let mut events: Vec<WatchEvent> = Vec::new();
+        for (path, len, modified) in found {
+            match self.files.get_mut(&path) {
+                None => {
+                    let handled: bool = !self.polled && !self.ingest_existing;
+                    self.files.insert(
+                        path,
+                        FileState {
+                            len,
+                            modified,
+                            handled,
+                        },
+                    );
+                }
+                Some(state) if state.len != len || state.modified != modified => {
+                    state.len = len;
+                    state.modified = modified;
+                    state.handled = false;
+                }
+                Some(state) if !state.handled => {
+                    state.handled = true;
+                    events.push(self.ingest(path, sink));
+                }
+                Some(_) => {}
+            }
+        }
+        self.polled = true;
+        Ok(events)
+    }
+
+    /// Polls the folder every `interval` until `should_stop` returns `true`, passing the result of
+    /// each ingested file to `on_event`.
+    pub fn watch<S, E>(
+        &mut self,
+        sink: &mut dyn Sink,
+        interval: Duration,
+        should_stop: S,
+        mut on_event: E,
+    ) -> Result<(), IngestError>
+    where
+        S: Fn() -> bool,
+        E: FnMut(WatchEvent),
+    {
+        while !should_stop() {
+            for event in self.poll(sink)? {
+                on_event(event);
+            }
+            thread::sleep(interval);
+        }
+        Ok(())
+    }
+
+    /// Parses the file and passes its dataset to the sink.
+    fn ingest(&self, path: PathBuf, sink: &mut dyn Sink) -> WatchEvent {
+        let file: File = match File::open(&path) {
+            Ok(file) => file,
+            Err(source) => {
+                let e = IngestError::IOError {
+                    path: path.clone(),
+                    source,
+                };
+                return WatchEvent::Failed(path, e);
+            }
+        };
+        let dcmroot: DicomRoot<'_> = match DicomRoot::parse(&mut self.parser_builder.build(file)) {
+            Ok(Some(dcmroot)) => dcmroot,
+            Ok(None) => return WatchEvent::NotDicom(path),
+            Err(e) => return WatchEvent::Failed(path, e.into()),
+        };
+        match sink.ingest(&path, &dcmroot) {
+            Ok(()) => WatchEvent::Ingested(path),
+            Err(e) => WatchEvent::Failed(path, IngestError::SinkError(e)),
+        }
+    }
+}
+
+/// Lists the files of the folder with their length and modification time, skipping hidden files
+/// and folders.
+fn list_files(
+    folder: &Path,
+    recursive: bool,
+    found: &mut Vec<(PathBuf, u64, Option<SystemTime>)>,
+) -> std::io::Result<()> {
+    for entry in fs::read_dir(folder)? {
+        let entry = entry?;
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        // Files may be removed between listing the folder and reading their metadata.
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_dir() {
+            if recursive {
+                // A sub-folder which can't be read is skipped rather than failing the poll.
+                let _ = list_files(&entry.path(), recursive, found);
+            }
+        } else if metadata.is_file() {
+            found.push((entry.path(), metadata.len(), metadata.modified().ok()));
+        }
+    }
+    Ok(())
+}
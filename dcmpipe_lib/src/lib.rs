@@ -5,3 +5,19 @@ pub mod dimse;
 
 #[cfg(feature = "stddicom")]
 pub mod dict;
+
+pub mod error;
+
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+
+#[cfg(feature = "ingest")]
+pub mod ingest;
+
+pub mod progress;
+
+#[cfg(feature = "source")]
+pub mod source;
+
+#[cfg(feature = "testkit")]
+pub mod testkit;
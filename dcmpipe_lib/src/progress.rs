@@ -0,0 +1,110 @@
+//! Progress of long-running operations over many files, such as scanning or archiving a folder.
+//!
+//! `Progress` counts the files and bytes processed and estimates the time remaining from the rate
+//! bytes are processed at. The operation reports it to a `ProgressReporter`, which may render it
+//! as a progress bar or log it.
+
+use std::time::{Duration, Instant};
+
+/// Receives the progress of an operation as it's made.
+pub trait ProgressReporter {
+    /// Called as progress is made, which may be frequent. Implementations rendering the progress
+    /// should limit how often they do so.
+    fn report(&mut self, progress: &Progress);
+
+    /// Called once the operation has ended, whether or not it completed.
+    fn finish(&mut self, _progress: &Progress) {}
+}
+
+/// Discards all progress reported.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoProgress;
+
+impl ProgressReporter for NoProgress {
+    fn report(&mut self, _progress: &Progress) {}
+}
+
+/// The files and bytes processed by an operation since it started.
+#[derive(Debug, Clone)]
+pub struct Progress {
+    started: Instant,
+    files: u64,
+    bytes: u64,
+    total_bytes: Option<u64>,
+}
+
+impl Progress {
+    /// Starts tracking progress of an operation which processes `total_bytes`, if known.
+    pub fn new(total_bytes: Option<u64>) -> Progress {
+        Progress {
+            started: Instant::now(),
+            files: 0,
+            bytes: 0,
+            total_bytes,
+        }
+    }
+
+    /// Records that another file has been processed.
+    pub fn add_file(&mut self) {
+        self.files += 1;
+    }
+
+    /// Records that more bytes have been processed. The bytes processed never exceed the total.
+    pub fn add_bytes(&mut self, bytes: u64) {
+        self.bytes = self.bytes.saturating_add(bytes);
+        if let Some(total_bytes) = self.total_bytes {
+            self.bytes = self.bytes.min(total_bytes);
+        }
+    }
+
+    /// The number of files processed.
+    pub fn files(&self) -> u64 {
+        self.files
+    }
+
+    /// The number of bytes processed.
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// The total number of bytes to be processed, if known.
+    pub fn total_bytes(&self) -> Option<u64> {
+        self.total_bytes
+    }
+
+    /// The time since the operation started.
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// The fraction of the total bytes processed, from 0 to 1, if the total is known.
+    pub fn fraction(&self) -> Option<f64> {
+        match self.total_bytes {
+            Some(0) => Some(1.0),
+            Some(total_bytes) => Some(self.bytes as f64 / total_bytes as f64),
+            None => None,
+        }
+    }
+
+    /// The average number of bytes processed per second.
+    pub fn bytes_per_sec(&self) -> f64 {
+        let secs: f64 = self.elapsed().as_secs_f64();
+        if secs > 0.0 {
+            self.bytes as f64 / secs
+        } else {
+            0.0
+        }
+    }
+
+    /// The estimated time remaining, at the average rate so far. This is `None` if the total isn't
+    /// known or before any bytes have been processed.
+    pub fn eta(&self) -> Option<Duration> {
+        let total_bytes: u64 = self.total_bytes?;
+        if self.bytes == 0 {
+            return None;
+        }
+        let remaining: f64 = total_bytes.saturating_sub(self.bytes) as f64;
+        let secs: f64 = self.elapsed().as_secs_f64() * remaining / self.bytes as f64;
+        Some(Duration::from_secs_f64(secs))
+    }
+}
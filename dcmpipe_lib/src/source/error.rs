@@ -0,0 +1,25 @@
+//! Errors that can occur while reading a source of DICOM files.
+
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+/// Errors that can occur while reading a source of DICOM files.
+pub enum SourceError {
+    /// A file, folder, or archive could not be read.
+    #[error("i/o error reading: {}", path.display())]
+    IOError {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// A zip archive is invalid or uses an unsupported feature, such as encryption.
+    #[error("error reading zip archive: {}", path.display())]
+    ZipError {
+        path: PathBuf,
+        #[source]
+        source: zip::result::ZipError,
+    },
+}
@@ -0,0 +1,473 @@
+//! Sources of DICOM files, reading the files of a folder and those within zip and tar archives
+//! alike.
+//!
+//! Patient CDs and exports from PACS are commonly delivered as zip or tar archives. A `Source`
+//! lists the files of a folder, including those within any archives it contains, as
+//! `SourceEntry`s which can be parsed without first extracting the archives. Archives are detected
+//! by their content rather than their name. Archives within archives are not expanded.
+//!
+//! Entries of archives are read into memory one at a time as the entries are iterated, while
+//! files of the folder are only opened when read. The `Progress` of iterating the entries is
+//! measured in bytes of the files on disk, with archives counted as they're read.
+
+use std::{
+    borrow::Cow,
+    collections::VecDeque,
+    fs::{self, File},
+    io::{self, ErrorKind, Read},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use libflate::gzip;
+use tar::{EntryType, Header, PaxExtensions};
+use zip::ZipArchive;
+
+use crate::progress::Progress;
+
+pub mod error;
+
+use error::SourceError;
+
+/// The length of the blocks tar archives are made up of, including their headers.
+const TAR_BLOCK_LEN: usize = 512;
+
+/// The offset and value of the magic field of a tar header in the POSIX ustar or GNU formats.
+const TAR_MAGIC_OFFSET: usize = 257;
+const TAR_MAGIC: &[u8] = b"ustar";
+
+const ZIP_MAGIC: &[u8] = b"PK\x03\x04";
+const EMPTY_ZIP_MAGIC: &[u8] = b"PK\x05\x06";
+const GZIP_MAGIC: &[u8] = &[0x1F, 0x8B];
+
+/// A reader of the contents of a `SourceEntry`.
+pub type EntryReader<'e> = Box<dyn Read + 'e>;
+
+/// The kinds of archive a `Source` reads the entries of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveKind {
+    Zip,
+    Tar,
+    /// A tar archive compressed with gzip, e.g. `.tar.gz` or `.tgz`.
+    TarGz,
+}
+
+/// A file, folder, or archive of DICOM files.
+#[derive(Debug, Clone)]
+pub struct Source {
+    path: PathBuf,
+}
+
+/// A file of a `Source`, either within its folder or within an archive.
+pub struct SourceEntry {
+    path: PathBuf,
+    /// The contents of entries within an archive, which are read when the entry is listed.
+    contents: Option<Vec<u8>>,
+}
+
+/// An iterator over the entries of a `Source`, in order of their path within each folder and in
+/// the order they're stored within archives.
+pub struct Entries {
+    /// The files remaining to be listed, which may be archives, along with their size.
+    files: VecDeque<(PathBuf, u64)>,
+    /// The archive whose entries are being listed.
+    archive: Option<ArchiveEntries>,
+    /// The size of the archive being listed, and how much of it has been added to `progress`.
+    archive_len: u64,
+    archive_reported: u64,
+    progress: Progress,
+}
+
+/// The entries of an archive being listed.
+enum ArchiveEntries {
+    Zip {
+        path: PathBuf,
+        archive: ZipArchive<File>,
+        index: usize,
+        /// The compressed size of the entries read so far.
+        position: u64,
+    },
+    Tar {
+        path: PathBuf,
+        reader: EntryReader<'static>,
+        /// The number of bytes read from the archive file so far.
+        position: Arc<AtomicU64>,
+    },
+}
+
+/// Counts the bytes read from the file, for the progress through an archive which is read as a
+/// stream.
+struct CountingReader {
+    file: File,
+    position: Arc<AtomicU64>,
+}
+
+impl Source {
+    /// Creates a source for the file or folder at `path`. A file may itself be an archive.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Source {
+        Source { path: path.into() }
+    }
+
+    /// Get the path of the file or folder.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Lists the files of the source, including sub-folders and the files within archives. Fails
+    /// if the file or folder of the source, or any of its sub-folders, can't be listed, while
+    /// errors reading archives are returned by the iterator.
+    pub fn entries(&self) -> Result<Entries, SourceError> {
+        let metadata: fs::Metadata =
+            fs::metadata(&self.path).map_err(|source| io_error(&self.path, source))?;
+        let mut files: Vec<(PathBuf, u64)> = Vec::new();
+        if metadata.is_dir() {
+            list_files(&self.path, &mut files).map_err(|source| io_error(&self.path, source))?;
+        } else {
+            files.push((self.path.clone(), metadata.len()));
+        }
+        let total_bytes: u64 = files.iter().map(|(_, len)| len).sum();
+        Ok(Entries {
+            files: files.into(),
+            archive: None,
+            archive_len: 0,
+            archive_reported: 0,
+            progress: Progress::new(Some(total_bytes)),
+        })
+    }
+}
+
+impl SourceEntry {
+    /// Get the path of the entry. For entries within an archive this is the path of the archive
+    /// joined with the path of the entry within it, e.g. `export.zip/DICOM/IM0001`.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether this entry is within an archive, in which case its path doesn't exist on disk.
+    pub fn is_archived(&self) -> bool {
+        self.contents.is_some()
+    }
+
+    /// The contents of an entry within an archive, or `None` for a file of a folder.
+    pub fn bytes(&self) -> Option<&[u8]> {
+        self.contents.as_deref()
+    }
+
+    /// Opens the entry for reading from its start. This may be called more than once. Files which
+    /// were compressed with `core::compressed` are decompressed while read.
+    pub fn open(&self) -> io::Result<EntryReader<'_>> {
+        if let Some(contents) = &self.contents {
+            return Ok(Box::new(contents.as_slice()));
+        }
+        #[cfg(feature = "zstd")]
+        let file = crate::core::compressed::DatasetFile::open(&self.path)?;
+        #[cfg(not(feature = "zstd"))]
+        let file: File = File::open(&self.path)?;
+        Ok(Box::new(file))
+    }
+}
+
+impl Entries {
+    /// The progress through the source, counting each entry listed as processed. The total is the
+    /// size of all files of the source, including archives, as of when it was listed.
+    pub fn progress(&self) -> &Progress {
+        &self.progress
+    }
+
+    /// Adds the progress through the archive being listed, up to its end once it's been read.
+    fn report_archive(&mut self, ended: bool) {
+        let position: u64 = match &self.archive {
+            Some(archive) if !ended => archive.position().min(self.archive_len),
+            _ => self.archive_len,
+        };
+        self.progress
+            .add_bytes(position.saturating_sub(self.archive_reported));
+        self.archive_reported = self.archive_reported.max(position);
+    }
+}
+
+impl Iterator for Entries {
+    type Item = Result<SourceEntry, SourceError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(archive) = &mut self.archive {
+                match archive.next_entry() {
+                    Some(Ok(entry)) => {
+                        self.progress.add_file();
+                        self.report_archive(false);
+                        return Some(Ok(entry));
+                    }
+                    // An archive can't be read past an error, so the rest of it is skipped.
+                    Some(Err(e)) => {
+                        self.report_archive(true);
+                        self.archive = None;
+                        return Some(Err(e));
+                    }
+                    None => {
+                        self.report_archive(true);
+                        self.archive = None;
+                    }
+                }
+            }
+
+            let (path, len) = self.files.pop_front()?;
+            match archive_kind(&path) {
+                Ok(Some(kind)) => match ArchiveEntries::open(path, kind) {
+                    Ok(archive) => {
+                        self.archive = Some(archive);
+                        self.archive_len = len;
+                        self.archive_reported = 0;
+                    }
+                    Err(e) => {
+                        self.progress.add_bytes(len);
+                        return Some(Err(e));
+                    }
+                },
+                Ok(None) => {
+                    self.progress.add_file();
+                    self.progress.add_bytes(len);
+                    return Some(Ok(SourceEntry {
+                        path,
+                        contents: None,
+                    }));
+                }
+                Err(source) => {
+                    self.progress.add_bytes(len);
+                    return Some(Err(io_error(&path, source)));
+                }
+            }
+        }
+    }
+}
+
+impl ArchiveEntries {
+    fn open(path: PathBuf, kind: ArchiveKind) -> Result<ArchiveEntries, SourceError> {
+        let file: File = File::open(&path).map_err(|source| io_error(&path, source))?;
+        Ok(match kind {
+            ArchiveKind::Zip => ArchiveEntries::Zip {
+                archive: ZipArchive::new(file).map_err(|source| SourceError::ZipError {
+                    path: path.clone(),
+                    source,
+                })?,
+                path,
+                index: 0,
+                position: 0,
+            },
+            ArchiveKind::Tar => {
+                let position: Arc<AtomicU64> = Arc::new(AtomicU64::new(0));
+                let file: CountingReader = CountingReader {
+                    file,
+                    position: position.clone(),
+                };
+                ArchiveEntries::Tar {
+                    path,
+                    reader: Box::new(file),
+                    position,
+                }
+            }
+            ArchiveKind::TarGz => {
+                let position: Arc<AtomicU64> = Arc::new(AtomicU64::new(0));
+                let file: CountingReader = CountingReader {
+                    file,
+                    position: position.clone(),
+                };
+                ArchiveEntries::Tar {
+                    reader: Box::new(
+                        gzip::Decoder::new(file).map_err(|source| io_error(&path, source))?,
+                    ),
+                    path,
+                    position,
+                }
+            }
+        })
+    }
+
+    /// The number of bytes of the archive file read so far.
+    fn position(&self) -> u64 {
+        match self {
+            ArchiveEntries::Zip { position, .. } => *position,
+            ArchiveEntries::Tar { position, .. } => position.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Reads the next file of the archive, skipping folders and links.
+    fn next_entry(&mut self) -> Option<Result<SourceEntry, SourceError>> {
+        match self {
+            ArchiveEntries::Zip {
+                path,
+                archive,
+                index,
+                position,
+            } => {
+                while *index < archive.len() {
+                    let mut file = match archive.by_index(*index) {
+                        Ok(file) => file,
+                        Err(source) => {
+                            let path: PathBuf = path.clone();
+                            return Some(Err(SourceError::ZipError { path, source }));
+                        }
+                    };
+                    *index += 1;
+                    *position += file.compressed_size();
+                    // Names which would escape the archive, such as absolute paths, are ignored.
+                    let Some(name) = file.enclosed_name().filter(|_| file.is_file()) else {
+                        continue;
+                    };
+                    let mut contents: Vec<u8> = Vec::new();
+                    if let Err(source) = file.read_to_end(&mut contents) {
+                        return Some(Err(io_error(&path.join(name), source)));
+                    }
+                    return Some(Ok(SourceEntry {
+                        path: path.join(name),
+                        contents: Some(contents),
+                    }));
+                }
+                None
+            }
+            ArchiveEntries::Tar { path, reader, .. } => read_tar_entry(reader.as_mut())
+                .map_err(|source| io_error(path, source))
+                .transpose()
+                .map(|entry| {
+                    entry.map(|(name, contents)| SourceEntry {
+                        path: path.join(name),
+                        contents: Some(contents),
+                    })
+                }),
+        }
+    }
+}
+
+impl Read for CountingReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read: usize = self.file.read(buf)?;
+        self.position.fetch_add(read as u64, Ordering::Relaxed);
+        Ok(read)
+    }
+}
+
+/// Determines whether the file is an archive by its content, returning `None` if it isn't.
+pub fn archive_kind(path: &Path) -> io::Result<Option<ArchiveKind>> {
+    let mut header: Vec<u8> = Vec::with_capacity(TAR_BLOCK_LEN);
+    File::open(path)?
+        .take(TAR_BLOCK_LEN as u64)
+        .read_to_end(&mut header)?;
+    if header.starts_with(ZIP_MAGIC) || header.starts_with(EMPTY_ZIP_MAGIC) {
+        return Ok(Some(ArchiveKind::Zip));
+    }
+    if is_tar_header(&header) {
+        return Ok(Some(ArchiveKind::Tar));
+    }
+    // Files compressed with gzip are only tar archives if what they decompress to is.
+    if header.starts_with(GZIP_MAGIC) {
+        let mut decompressed: Vec<u8> = Vec::with_capacity(TAR_BLOCK_LEN);
+        let decoder = gzip::Decoder::new(File::open(path)?);
+        if let Ok(decoder) = decoder {
+            // Invalid compressed data is left to be reported as a file which isn't DICOM.
+            let _ = decoder
+                .take(TAR_BLOCK_LEN as u64)
+                .read_to_end(&mut decompressed);
+            if is_tar_header(&decompressed) {
+                return Ok(Some(ArchiveKind::TarGz));
+            }
+        }
+    }
+    Ok(None)
+}
+
+fn is_tar_header(header: &[u8]) -> bool {
+    header.len() == TAR_BLOCK_LEN && header[TAR_MAGIC_OFFSET..].starts_with(TAR_MAGIC)
+}
+
+/// Reads the entries of the tar archive up to and including the next regular file, returning its
+/// path within the archive and its contents, or `None` at the end of the archive. Long paths
+/// given by GNU or PAX extension headers are applied to the file which follows them.
+fn read_tar_entry(reader: &mut dyn Read) -> io::Result<Option<(PathBuf, Vec<u8>)>> {
+    let mut long_name: Option<PathBuf> = None;
+    loop {
+        let mut block: [u8; TAR_BLOCK_LEN] = [0u8; TAR_BLOCK_LEN];
+        match reader.read_exact(&mut block) {
+            Ok(()) => {}
+            // Archives which end without the blocks marking their end are accepted.
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e),
+        }
+        if block.iter().all(|b| *b == 0) {
+            return Ok(None);
+        }
+
+        let header: &Header = Header::from_byte_slice(&block);
+        let size: u64 = header.entry_size()?;
+        let mut contents: Vec<u8> = Vec::new();
+        (&mut *reader).take(size).read_to_end(&mut contents)?;
+        if (contents.len() as u64) < size {
+            return Err(io::Error::new(
+                ErrorKind::UnexpectedEof,
+                "truncated tar entry",
+            ));
+        }
+        let padding: u64 =
+            (TAR_BLOCK_LEN as u64 - size % TAR_BLOCK_LEN as u64) % TAR_BLOCK_LEN as u64;
+        io::copy(&mut (&mut *reader).take(padding), &mut io::sink())?;
+
+        match header.entry_type() {
+            EntryType::GNULongName => {
+                let name: &[u8] = contents.split(|b| *b == 0).next().unwrap_or_default();
+                long_name = Some(PathBuf::from(String::from_utf8_lossy(name).into_owned()));
+            }
+            EntryType::XHeader => {
+                long_name = PaxExtensions::new(&contents)
+                    .filter_map(Result::ok)
+                    .find(|ext| ext.key() == Ok("path"))
+                    .and_then(|ext| ext.value().ok().map(PathBuf::from));
+            }
+            EntryType::Regular | EntryType::Continuous => {
+                let name: PathBuf = match long_name.take() {
+                    Some(name) => name,
+                    None => header.path().map(Cow::into_owned)?,
+                };
+                // Names which would escape the archive, such as absolute paths, are ignored.
+                if is_enclosed(&name) {
+                    return Ok(Some((name, contents)));
+                }
+            }
+            _ => long_name = None,
+        }
+    }
+}
+
+/// Whether the path is relative and doesn't refer to any parent folder.
+fn is_enclosed(path: &Path) -> bool {
+    path.components().all(|component| {
+        matches!(
+            component,
+            std::path::Component::Normal(_) | std::path::Component::CurDir
+        )
+    })
+}
+
+/// Lists the files of the folder and its sub-folders with their sizes, sorted by name within each
+/// folder.
+fn list_files(folder: &Path, files: &mut Vec<(PathBuf, u64)>) -> io::Result<()> {
+    let mut entries: Vec<fs::DirEntry> = fs::read_dir(folder)?.collect::<io::Result<_>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let file_type: fs::FileType = entry.file_type()?;
+        if file_type.is_dir() {
+            list_files(&entry.path(), files)?;
+        } else if file_type.is_file() {
+            files.push((entry.path(), entry.metadata()?.len()));
+        }
+    }
+    Ok(())
+}
+
+fn io_error(path: &Path, source: io::Error) -> SourceError {
+    SourceError::IOError {
+        path: path.to_path_buf(),
+        source,
+    }
+}
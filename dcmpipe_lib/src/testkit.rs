@@ -0,0 +1,395 @@
+//! Generators for small DICOM datasets which exercise encodings that are easy to get wrong when
+//! parsing. These are intended for testing integrations without needing to ship real DICOM files.
+//!
+//! Each `Fixture` produces a complete DICOM file, including the File Preamble and FileMeta group.
+//!
+//! ```
+//! use dcmpipe_lib::testkit::Fixture;
+//!
+//! for fixture in Fixture::ALL {
+//!     let bytes: Vec<u8> = fixture.to_bytes().expect("fixture should encode");
+//!     assert!(!bytes.is_empty());
+//! }
+//! ```
+
+use std::io::Write;
+
+use crate::{
+    core::{
+        charset::{self, CSRef, DEFAULT_CHARACTER_SET},
+        dcmelement::{delimiter, structural, DicomElement},
+        defn::{
+            constants::{tags as coretags, ts},
+            ts::TSRef,
+            vl::ValueLength,
+            vr::{self, VRRef},
+        },
+        values::RawValue,
+        write::{builder::WriterBuilder, writer::WriteResult},
+    },
+    dict::{
+        tags,
+        transfer_syntaxes::{DeflatedExplicitVRLittleEndian, ExplicitVRLittleEndian},
+        uids,
+    },
+};
+
+/// The Implementation Class UID written into the FileMeta group of generated fixtures.
+pub const FIXTURE_IMPLEMENTATION_CLASS_UID: &str = "2.25.258849209563014391307785428413441712373";
+
+/// The Implementation Version Name written into the FileMeta group of generated fixtures.
+pub const FIXTURE_IMPLEMENTATION_VERSION_NAME: &str = "DCMPIPE_TESTKIT";
+
+/// The Private Creator used for private elements in generated fixtures.
+pub const FIXTURE_PRIVATE_CREATOR: &str = "DCMPIPE TESTKIT";
+
+/// The private creator element reserving block `0x10` of group `0x0009`.
+pub const FIXTURE_PRIVATE_CREATOR_TAG: u32 = 0x0009_0010;
+
+/// The outer private sequence, encoded with VR of UN and undefined length.
+pub const FIXTURE_PRIVATE_UN_SEQ_TAG: u32 = 0x0009_1010;
+
+/// The inner private sequence, nested within the first item of the outer private sequence.
+pub const FIXTURE_PRIVATE_NESTED_SEQ_TAG: u32 = 0x0009_1011;
+
+/// A private leaf element within the items of the inner private sequence.
+pub const FIXTURE_PRIVATE_VALUE_TAG: u32 = 0x0009_1012;
+
+/// The Specific Character Set used by `Fixture::MultiCharsetPersonName`.
+pub const FIXTURE_MULTI_CHARSET_SCS: &str = "ISO 2022 IR 87";
+
+/// The Patient's Name used by `Fixture::MultiCharsetPersonName`, with alphabetic, ideographic, and
+/// phonetic component groups. Taken from Part 5, Annex H.
+pub const FIXTURE_MULTI_CHARSET_PN: &str = "Yamada^Tarou=山田^太郎=やまだ^たろう";
+
+/// A kind of fixture dataset which can be generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Fixture {
+    /// A dataset with an SQ element of undefined length, containing items of undefined length with
+    /// one item holding a further nested SQ of undefined length.
+    UndefinedLengthSequence,
+
+    /// A dataset with a private element of VR UN and undefined length, which must be parsed as a
+    /// sequence encoded in Implicit VR Little Endian. Its first item contains another private
+    /// sequence of undefined length.
+    NestedUnSequence,
+
+    /// A dataset encoded with Deflated Explicit VR Little Endian, where everything following the
+    /// FileMeta group is deflated.
+    DeflatedDataset,
+
+    /// A dataset with a Specific Character Set using ISO 2022 code extensions and a Patient's Name
+    /// with multiple component groups, each in a different character repertoire.
+    MultiCharsetPersonName,
+}
+
+impl Fixture {
+    /// All fixtures which can be generated.
+    pub const ALL: [Fixture; 4] = [
+        Fixture::UndefinedLengthSequence,
+        Fixture::NestedUnSequence,
+        Fixture::DeflatedDataset,
+        Fixture::MultiCharsetPersonName,
+    ];
+
+    /// A short name for the fixture, suitable as a file name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Fixture::UndefinedLengthSequence => "undefined_length_sq",
+            Fixture::NestedUnSequence => "nested_un_sq",
+            Fixture::DeflatedDataset => "deflated",
+            Fixture::MultiCharsetPersonName => "multi_charset_pn",
+        }
+    }
+
+    /// The transfer syntax the fixture dataset is encoded in.
+    pub fn ts(&self) -> TSRef {
+        match self {
+            Fixture::DeflatedDataset => &DeflatedExplicitVRLittleEndian,
+            _ => &ExplicitVRLittleEndian,
+        }
+    }
+
+    /// The SOP Instance UID of the generated fixture. This is stable across runs so tests can
+    /// reference it.
+    pub fn sop_instance_uid(&self) -> &'static str {
+        match self {
+            Fixture::UndefinedLengthSequence => "2.25.100000000000000000000000000000000001",
+            Fixture::NestedUnSequence => "2.25.100000000000000000000000000000000002",
+            Fixture::DeflatedDataset => "2.25.100000000000000000000000000000000003",
+            Fixture::MultiCharsetPersonName => "2.25.100000000000000000000000000000000004",
+        }
+    }
+
+    /// Creates the elements of the fixture dataset in the order they would be written, including
+    /// the FileMeta group. Sequence-like elements, items, and delimiters are included as
+    /// individual elements.
+    pub fn elements(&self) -> WriteResult<Vec<DicomElement<'static>>> {
+        let mut elements: Vec<DicomElement> = self.file_meta()?;
+        match self {
+            Fixture::UndefinedLengthSequence => self.undefined_length_sq(&mut elements)?,
+            Fixture::NestedUnSequence => self.nested_un_sq(&mut elements)?,
+            Fixture::DeflatedDataset => self.deflated(&mut elements)?,
+            Fixture::MultiCharsetPersonName => self.multi_charset_pn(&mut elements)?,
+        }
+        Ok(elements)
+    }
+
+    /// Writes the fixture as a DICOM file into the given dataset, returning the dataset.
+    pub fn write_to<DatasetType: Write>(&self, dataset: DatasetType) -> WriteResult<DatasetType> {
+        let elements: Vec<DicomElement> = self.elements()?;
+        let mut writer = WriterBuilder::for_file().ts(self.ts()).build(dataset);
+        writer.write_elements(elements.iter())?;
+        writer.into_dataset()
+    }
+
+    /// Writes the fixture as a DICOM file into an in-memory buffer.
+    pub fn to_bytes(&self) -> WriteResult<Vec<u8>> {
+        self.write_to(Vec::new())
+    }
+
+    fn file_meta(&self) -> WriteResult<Vec<DicomElement<'static>>> {
+        let fmts: TSRef = &ExplicitVRLittleEndian;
+        Ok(vec![
+            value(
+                &tags::FileMetaInformationVersion,
+                &vr::OB,
+                fmts,
+                RawValue::Bytes(vec![0x00, 0x01]),
+            )?,
+            value(
+                &tags::MediaStorageSOPClassUID,
+                &vr::UI,
+                fmts,
+                RawValue::Uid(uids::SecondaryCaptureImageStorage.uid().to_string()),
+            )?,
+            value(
+                &tags::MediaStorageSOPInstanceUID,
+                &vr::UI,
+                fmts,
+                RawValue::Uid(self.sop_instance_uid().to_string()),
+            )?,
+            value(
+                &tags::TransferSyntaxUID,
+                &vr::UI,
+                fmts,
+                RawValue::Uid(self.ts().uid().uid().to_string()),
+            )?,
+            value(
+                &tags::ImplementationClassUID,
+                &vr::UI,
+                fmts,
+                RawValue::Uid(FIXTURE_IMPLEMENTATION_CLASS_UID.to_string()),
+            )?,
+            value(
+                &tags::ImplementationVersionName,
+                &vr::SH,
+                fmts,
+                RawValue::Strings(vec![FIXTURE_IMPLEMENTATION_VERSION_NAME.to_string()]),
+            )?,
+        ])
+    }
+
+    /// The SOP Common elements which follow Specific Character Set, if present.
+    fn sop_common(&self, elements: &mut Vec<DicomElement<'static>>) -> WriteResult<()> {
+        let ts: TSRef = self.ts();
+        elements.push(value(
+            &tags::SOPClassUID,
+            &vr::UI,
+            ts,
+            RawValue::Uid(uids::SecondaryCaptureImageStorage.uid().to_string()),
+        )?);
+        elements.push(value(
+            &tags::SOPInstanceUID,
+            &vr::UI,
+            ts,
+            RawValue::Uid(self.sop_instance_uid().to_string()),
+        )?);
+        elements.push(value(
+            &tags::Modality,
+            &vr::CS,
+            ts,
+            RawValue::Strings(vec!["OT".to_string()]),
+        )?);
+        Ok(())
+    }
+
+    fn undefined_length_sq(&self, elements: &mut Vec<DicomElement<'static>>) -> WriteResult<()> {
+        let ts: TSRef = self.ts();
+        let ivrle: TSRef = &ts::ImplicitVRLittleEndian;
+        self.sop_common(elements)?;
+
+        elements.push(structural(&tags::ReferencedImageSequence, &vr::SQ, ts));
+        for item_num in 1..=2 {
+            elements.push(item());
+            elements.push(value(
+                &tags::ReferencedSOPClassUID,
+                &vr::UI,
+                ts,
+                RawValue::Uid(uids::SecondaryCaptureImageStorage.uid().to_string()),
+            )?);
+            elements.push(value(
+                &tags::ReferencedSOPInstanceUID,
+                &vr::UI,
+                ts,
+                RawValue::Uid(format!("{}.{item_num}", self.sop_instance_uid())),
+            )?);
+            if item_num == 1 {
+                // A nested sequence whose only item is empty.
+                elements.push(structural(&tags::SourceImageSequence, &vr::SQ, ts));
+                elements.push(item());
+                elements.push(delimiter(coretags::ITEM_DELIMITATION_ITEM, ivrle));
+                elements.push(delimiter(coretags::SEQUENCE_DELIMITATION_ITEM, ivrle));
+            }
+            elements.push(delimiter(coretags::ITEM_DELIMITATION_ITEM, ivrle));
+        }
+        elements.push(delimiter(coretags::SEQUENCE_DELIMITATION_ITEM, ivrle));
+
+        elements.push(value(
+            &tags::PatientID,
+            &vr::LO,
+            ts,
+            RawValue::Strings(vec!["TESTKIT".to_string()]),
+        )?);
+        Ok(())
+    }
+
+    fn nested_un_sq(&self, elements: &mut Vec<DicomElement<'static>>) -> WriteResult<()> {
+        let ts: TSRef = self.ts();
+        // Contents of a sequence with VR of UN are always encoded as Implicit VR Little Endian.
+        let ivrle: TSRef = &ts::ImplicitVRLittleEndian;
+        self.sop_common(elements)?;
+
+        elements.push(value(
+            FIXTURE_PRIVATE_CREATOR_TAG,
+            &vr::LO,
+            ts,
+            RawValue::Strings(vec![FIXTURE_PRIVATE_CREATOR.to_string()]),
+        )?);
+        elements.push(structural(FIXTURE_PRIVATE_UN_SEQ_TAG, &vr::UN, ts));
+
+        elements.push(item());
+        elements.push(structural(FIXTURE_PRIVATE_NESTED_SEQ_TAG, &vr::UN, ivrle));
+        for item_num in 1..=2u8 {
+            elements.push(item());
+            elements.push(value(
+                FIXTURE_PRIVATE_VALUE_TAG,
+                &vr::UN,
+                ivrle,
+                RawValue::Bytes(vec![item_num, 0x00]),
+            )?);
+            elements.push(delimiter(coretags::ITEM_DELIMITATION_ITEM, ivrle));
+        }
+        elements.push(delimiter(coretags::SEQUENCE_DELIMITATION_ITEM, ivrle));
+        elements.push(delimiter(coretags::ITEM_DELIMITATION_ITEM, ivrle));
+
+        elements.push(delimiter(coretags::SEQUENCE_DELIMITATION_ITEM, ivrle));
+
+        elements.push(value(
+            &tags::PatientID,
+            &vr::LO,
+            ts,
+            RawValue::Strings(vec!["TESTKIT".to_string()]),
+        )?);
+        Ok(())
+    }
+
+    fn deflated(&self, elements: &mut Vec<DicomElement<'static>>) -> WriteResult<()> {
+        let ts: TSRef = self.ts();
+        let ivrle: TSRef = &ts::ImplicitVRLittleEndian;
+        self.sop_common(elements)?;
+
+        elements.push(structural(&tags::ReferencedImageSequence, &vr::SQ, ts));
+        elements.push(item());
+        elements.push(value(
+            &tags::ReferencedSOPInstanceUID,
+            &vr::UI,
+            ts,
+            RawValue::Uid(format!("{}.1", self.sop_instance_uid())),
+        )?);
+        elements.push(delimiter(coretags::ITEM_DELIMITATION_ITEM, ivrle));
+        elements.push(delimiter(coretags::SEQUENCE_DELIMITATION_ITEM, ivrle));
+
+        elements.push(value(
+            &tags::PatientsName,
+            &vr::PN,
+            ts,
+            RawValue::Strings(vec!["Deflated^Dataset".to_string()]),
+        )?);
+        elements.push(value(
+            &tags::PatientID,
+            &vr::LO,
+            ts,
+            RawValue::Strings(vec!["TESTKIT".to_string()]),
+        )?);
+        Ok(())
+    }
+
+    fn multi_charset_pn(&self, elements: &mut Vec<DicomElement<'static>>) -> WriteResult<()> {
+        let ts: TSRef = self.ts();
+        // The first value is empty, indicating the default repertoire is used for the first
+        // component group, with code extensions for the others.
+        elements.push(value(
+            &tags::SpecificCharacterSet,
+            &vr::CS,
+            ts,
+            RawValue::Strings(vec![String::new(), FIXTURE_MULTI_CHARSET_SCS.to_string()]),
+        )?);
+        self.sop_common(elements)?;
+
+        let cs: CSRef =
+            charset::lookup_charset(FIXTURE_MULTI_CHARSET_SCS).unwrap_or(DEFAULT_CHARACTER_SET);
+        elements.push(value_with_cs(
+            &tags::PatientsName,
+            &vr::PN,
+            ts,
+            cs,
+            RawValue::Strings(vec![FIXTURE_MULTI_CHARSET_PN.to_string()]),
+        )?);
+        elements.push(value(
+            &tags::PatientID,
+            &vr::LO,
+            ts,
+            RawValue::Strings(vec!["TESTKIT".to_string()]),
+        )?);
+        Ok(())
+    }
+}
+
+/// Creates an element with the given value encoded using the default character set.
+fn value<T>(tag: T, vr: VRRef, ts: TSRef, value: RawValue) -> WriteResult<DicomElement<'static>>
+where
+    T: Into<u32>,
+{
+    value_with_cs(tag, vr, ts, DEFAULT_CHARACTER_SET, value)
+}
+
+/// Creates an element with the given value encoded using the given character set.
+fn value_with_cs<T>(
+    tag: T,
+    vr: VRRef,
+    ts: TSRef,
+    cs: CSRef,
+    value: RawValue,
+) -> WriteResult<DicomElement<'static>>
+where
+    T: Into<u32>,
+{
+    let mut elem = DicomElement::new(
+        tag,
+        vr,
+        ValueLength::Explicit(0),
+        ts,
+        cs,
+        Vec::new(),
+        Vec::new(),
+    );
+    elem.encode_value(value, None)?;
+    Ok(elem)
+}
+
+/// Creates an item element with undefined length. Items and delimiters are always encoded as
+/// Implicit VR regardless of the transfer syntax, see Part 5, Section 7.5.
+fn item() -> DicomElement<'static> {
+    structural(coretags::ITEM, &vr::UN, &ts::ImplicitVRLittleEndian)
+}
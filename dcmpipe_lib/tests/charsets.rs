@@ -1,3 +1,6 @@
+// These tests predate some of the current clippy lints.
+#![allow(clippy::iter_nth_zero, clippy::single_component_path_imports)]
+
 use dcmpipe_lib;
 
 use std::convert::{TryFrom, TryInto};
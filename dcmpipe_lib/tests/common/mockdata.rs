@@ -67,3 +67,29 @@ pub const NULL_ELEMENT: &'static [u8] = &[
     // INVALID              VR: INVALID VL: 0
     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
 ];
+
+#[rustfmt::skip]
+pub const VR_MISMATCH_ELEMENTS: &[u8] = &[
+    // PatientID            VR: SH      VL: 6
+    0x10, 0x00, 0x20, 0x00, 0x53, 0x48, 0x06, 0x00,
+    0x41, 0x42, 0x43, 0x31, 0x32, 0x33,
+
+    // Rows                 VR: UL      VL: 4
+    0x28, 0x00, 0x10, 0x00, 0x55, 0x4C, 0x04, 0x00,
+    0x00, 0x02, 0x00, 0x00,
+];
+
+#[rustfmt::skip]
+pub const PADDING_ELEMENTS: &[u8] = &[
+    // SOPInstanceUID       VR: UI      VL: 8, a NULL within the value and two trailing
+    0x08, 0x00, 0x18, 0x00, 0x55, 0x49, 0x08, 0x00,
+    0x31, 0x2E, 0x32, 0x2E, 0x00, 0x33, 0x00, 0x00,
+
+    // PatientName          VR: PN      VL: 6, padded with a NULL
+    0x10, 0x00, 0x10, 0x00, 0x50, 0x4E, 0x06, 0x00,
+    0x44, 0x6F, 0x65, 0x5E, 0x4A, 0x00,
+
+    // PatientID            VR: LO      VL: 5
+    0x10, 0x00, 0x20, 0x00, 0x4C, 0x4F, 0x05, 0x00,
+    0x41, 0x42, 0x43, 0x31, 0x32,
+];
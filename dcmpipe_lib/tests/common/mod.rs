@@ -1,21 +1,39 @@
 // The integreation tests in /tests folder are treated as individual crates, which results in the
 // rust compiler not detecting that this common crate is used, throwing up a bunch of warnings.
 #![allow(unused)]
+// These helpers predate some of the current clippy lints.
+#![allow(
+    clippy::assign_op_pattern,
+    clippy::char_lit_as_u8,
+    clippy::empty_line_after_outer_attr,
+    clippy::needless_return,
+    clippy::redundant_static_lifetimes,
+    clippy::single_match
+)]
 
 use std::{
+    collections::BTreeMap,
     fs::File,
-    io::Read,
+    io::{Cursor, Read},
     path::{Path, PathBuf},
 };
 
 use dcmpipe_lib::{
     core::{
+        charset::DEFAULT_CHARACTER_SET,
+        dcmelement::DicomElement,
         dcmobject::{DicomObject, DicomRoot},
-        defn::{constants::lookup::MINIMAL_DICOM_DICTIONARY, dcmdict::DicomDictionary},
+        defn::{
+            constants::lookup::MINIMAL_DICOM_DICTIONARY, dcmdict::DicomDictionary, ts::TSRef,
+            vr::VRRef,
+        },
         read::{ParseResult, Parser, ParserBuilder},
+        values::RawValue,
+        write::{builder::WriterBuilder, error::WriteError, transcode::transcode, writer::Writer},
         DICOM_PREFIX, DICOM_PREFIX_LENGTH, FILE_PREAMBLE_LENGTH,
     },
     dict::stdlookup::STANDARD_DICOM_DICTIONARY,
+    testkit::Fixture,
 };
 
 use walkdir::WalkDir;
@@ -49,6 +67,63 @@ pub fn parse_file(path: &str, with_std: bool) -> ParseResult<DicomRoot<'_>> {
     Ok(dcmroot)
 }
 
+/// Generates the testkit fixture and parses it back into a `DicomRoot`, parsing all element values.
+pub fn parse_fixture(fixture: Fixture, with_std: bool) -> ParseResult<DicomRoot<'static>> {
+    let dict: &dyn DicomDictionary = if with_std {
+        &STANDARD_DICOM_DICTIONARY
+    } else {
+        &MINIMAL_DICOM_DICTIONARY
+    };
+
+    let bytes: Vec<u8> = fixture.to_bytes().expect("Fixture should be writable");
+    let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+        .dictionary(dict)
+        .build(Cursor::new(bytes));
+    let dcmroot: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Fixture should be DICOM");
+    parse_all_dcmroot_values(&dcmroot)?;
+    Ok(dcmroot)
+}
+
+/// Parses the DICOM file bytes into a `DicomRoot`.
+pub fn parse_bytes(bytes: Vec<u8>) -> ParseResult<DicomRoot<'static>> {
+    let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .build(Cursor::new(bytes));
+    Ok(DicomRoot::parse(&mut parser)?.expect("Should parse as DICOM"))
+}
+
+/// Writes the dataset as a DICOM file transcoded to the given transfer syntax.
+pub fn write_transcoded(dcmroot: &DicomRoot<'_>, to: TSRef) -> Result<Vec<u8>, WriteError> {
+    let elements: Vec<DicomElement> = transcode(dcmroot, to)?;
+    let mut writer: Writer<Vec<u8>> = WriterBuilder::for_file().ts(to).build(Vec::new());
+    writer.write_elements(elements.iter())?;
+    writer.into_dataset()
+}
+
+/// Parses the DICOM file bytes and writes them back out transcoded to the given transfer syntax.
+pub fn transcode_bytes(bytes: Vec<u8>, to: TSRef) -> Result<Vec<u8>, WriteError> {
+    write_transcoded(&parse_bytes(bytes)?, to)
+}
+
+/// Builds a dataset of the given elements, each encoded with its VR in the transfer syntax.
+pub fn build_dataset(ts: TSRef, elements: Vec<(u32, VRRef, RawValue)>) -> DicomRoot<'static> {
+    let mut child_nodes: BTreeMap<u32, DicomObject> = BTreeMap::new();
+    for (tag, vr, value) in elements {
+        let mut element: DicomElement = DicomElement::new_empty(tag, vr, ts);
+        element
+            .encode_value(value, None)
+            .expect("Value should be encodable");
+        child_nodes.insert(tag, DicomObject::new(element));
+    }
+    DicomRoot::new(
+        ts,
+        DEFAULT_CHARACTER_SET,
+        &STANDARD_DICOM_DICTIONARY,
+        child_nodes,
+        Vec::new(),
+    )
+}
+
 /// Parses through all dicom files in the `fixtures` folder. The `use_std_dict` argument specifies
 /// whether the standard dicom dictionary should be reigstered with the parser.
 pub fn parse_all_dicom_files(with_std: bool) -> ParseResult<usize> {
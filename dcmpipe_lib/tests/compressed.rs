@@ -0,0 +1,72 @@
+use std::{
+    fs,
+    io::{Cursor, Read},
+    path::PathBuf,
+};
+
+use dcmpipe_lib::{
+    core::{
+        compressed::{compress, read_manifest, DatasetFile, Manifest, DEFAULT_LEVEL},
+        dcmobject::DicomRoot,
+        read::ParserBuilder,
+    },
+    dict::stdlookup::STANDARD_DICOM_DICTIONARY,
+    testkit::Fixture,
+};
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("dcmpipe_compressed_{name}"))
+}
+
+fn parse_file(path: &PathBuf) -> DicomRoot<'static> {
+    let file: DatasetFile = DatasetFile::open(path).expect("File should open");
+    let mut parser = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .build(file);
+    DicomRoot::parse(&mut parser)
+        .expect("Should parse")
+        .expect("Should be DICOM")
+}
+
+#[test]
+fn test_compressed_file_round_trip() {
+    for fixture in Fixture::ALL {
+        let bytes: Vec<u8> = fixture.to_bytes().expect("Fixture should be writable");
+        let plain: PathBuf = temp_path(&format!("{}.dcm", fixture.name()));
+        fs::write(&plain, &bytes).expect("File should be written");
+
+        let dcmroot: DicomRoot<'_> = parse_file(&plain);
+        let manifest: Manifest = Manifest::from_dataset(&dcmroot, bytes.len() as u64);
+        assert_eq!(fixture.sop_instance_uid(), manifest.sop_instance_uid);
+        assert_eq!(fixture.ts().uid().uid(), manifest.ts_uid);
+
+        let compressed: Vec<u8> =
+            compress(&manifest, Cursor::new(&bytes), Vec::new(), DEFAULT_LEVEL)
+                .expect("Should compress");
+        let zst: PathBuf = temp_path(&format!("{}.dcm.zst", fixture.name()));
+        fs::write(&zst, compressed).expect("File should be written");
+
+        // The manifest is read back without decompressing, and plain files have none.
+        assert_eq!(Some(&manifest), read_manifest(&zst).expect("Read").as_ref());
+        assert_eq!(None, read_manifest(&plain).expect("Read"));
+
+        let mut file: DatasetFile = DatasetFile::open(&zst).expect("File should open");
+        assert!(file.is_compressed());
+        let mut decompressed: Vec<u8> = Vec::new();
+        file.read_to_end(&mut decompressed)
+            .expect("Should decompress");
+        assert_eq!(bytes, decompressed);
+
+        let parsed: DicomRoot<'_> = parse_file(&zst);
+        assert_eq!(
+            Manifest::from_dataset(&parsed, bytes.len() as u64),
+            manifest
+        );
+        assert!(!DatasetFile::open(&plain)
+            .expect("File should open")
+            .is_compressed());
+
+        fs::remove_file(&plain).expect("File should be removed");
+        fs::remove_file(&zst).expect("File should be removed");
+    }
+}
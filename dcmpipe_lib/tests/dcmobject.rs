@@ -0,0 +1,937 @@
+use std::{collections::BTreeMap, io::Cursor};
+
+use dcmpipe_lib::{
+    core::{
+        charset::DEFAULT_CHARACTER_SET,
+        dcmelement::DicomElement,
+        dcmobject::{DicomObject, DicomRoot, MergePolicy},
+        defn::{
+            constants,
+            tag::{Tag, TagNode, TagPath},
+            vl::ValueLength,
+            vr::{self, VRRef},
+        },
+        edit::{EditTemplate, UidMap},
+        filemeta::FileMetaIssue,
+        provenance::{Provenance, ProvenanceKind},
+        read::{ParseError, ParseResult, Parser, ParserBuilder},
+        sizereport::{ElementSize, GroupSize, SizeReport},
+        values::RawValue,
+        write::{
+            builder::WriterBuilder,
+            error::WriteError,
+            transcode::transcode,
+            writer::{Writer, WriterState},
+        },
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags, transfer_syntaxes as ts},
+    testkit::Fixture,
+};
+
+mod common;
+
+use common::parse_fixture;
+
+#[test]
+fn test_provenance_not_tracked_by_default() -> ParseResult<()> {
+    let mut dcmroot: DicomRoot<'_> = parse_fixture(Fixture::UndefinedLengthSequence, true)?;
+    assert!(!dcmroot.track_provenance());
+
+    let path: TagPath = TagPath::from(&tags::PatientID);
+    dcmroot.modify_element(&path, "coerce", |e| {
+        e.encode_value(RawValue::Strings(vec!["COERCED".to_string()]), None)
+    });
+    assert!(dcmroot.get_provenance_by_tagpath(&path).is_none());
+    assert!(dcmroot.collect_provenance().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_provenance_tracking() -> ParseResult<()> {
+    let mut dcmroot: DicomRoot<'_> = parse_fixture(Fixture::UndefinedLengthSequence, true)?;
+    dcmroot.set_track_provenance(true);
+
+    let pid_path: TagPath = TagPath::from(&tags::PatientID);
+    assert_eq!(
+        Some(&Provenance::parsed()),
+        dcmroot.get_provenance_by_tagpath(&pid_path)
+    );
+
+    // Elements within sequence items are tracked as well.
+    let nested_path: TagPath = TagPath::parse(
+        "ReferencedImageSequence[2].ReferencedSOPInstanceUID",
+        Some(&STANDARD_DICOM_DICTIONARY),
+    )?;
+    assert_eq!(
+        Some(ProvenanceKind::Parsed),
+        dcmroot
+            .get_provenance_by_tagpath(&nested_path)
+            .map(|p| p.kind())
+    );
+
+    dcmroot
+        .modify_element(&pid_path, "coerce", |e| {
+            e.encode_value(RawValue::Strings(vec!["COERCED".to_string()]), None)
+        })
+        .expect("Element should exist")?;
+    assert_eq!(
+        Some(&Provenance::modified("coerce")),
+        dcmroot.get_provenance_by_tagpath(&pid_path)
+    );
+
+    let mut accession = DicomElement::new_empty(&tags::AccessionNumber, &vr::SH, dcmroot.ts());
+    accession.encode_value(RawValue::Strings(vec!["A1".to_string()]), None)?;
+    let accession_path: TagPath = TagPath::from(&tags::AccessionNumber);
+    dcmroot.insert_element(&accession_path, accession, "enrich")?;
+    assert_eq!(
+        Some(&Provenance::synthesized("enrich")),
+        dcmroot.get_provenance_by_tagpath(&accession_path)
+    );
+
+    // Modifying an element synthesized by the same stage keeps it marked as synthesized.
+    dcmroot.modify_element(&accession_path, "enrich", |e| {
+        e.encode_value(RawValue::Strings(vec!["A2".to_string()]), None)
+    });
+    assert_eq!(
+        Some(&Provenance::synthesized("enrich")),
+        dcmroot.get_provenance_by_tagpath(&accession_path)
+    );
+
+    let touched: Vec<(TagPath, &Provenance)> = dcmroot
+        .collect_provenance()
+        .into_iter()
+        .filter(|(_path, p)| p.kind() != ProvenanceKind::Parsed)
+        .collect();
+    assert_eq!(2, touched.len());
+    assert_eq!(accession_path, touched[0].0);
+    assert_eq!(pid_path, touched[1].0);
+    Ok(())
+}
+
+#[test]
+fn test_insert_into_missing_parent() -> ParseResult<()> {
+    let mut dcmroot: DicomRoot<'_> = parse_fixture(Fixture::UndefinedLengthSequence, true)?;
+    let path: TagPath = TagPath::parse(
+        "ReferencedImageSequence[3].ReferencedSOPInstanceUID",
+        Some(&STANDARD_DICOM_DICTIONARY),
+    )?;
+    let element = DicomElement::new_empty(&tags::ReferencedSOPInstanceUID, &vr::UI, dcmroot.ts());
+    assert!(dcmroot.insert_element(&path, element, "enrich").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_insert_invalid_path() -> ParseResult<()> {
+    let mut dcmroot: DicomRoot<'_> = parse_fixture(Fixture::UndefinedLengthSequence, true)?;
+
+    // A parent without an item index refers to the sequence rather than one of its items.
+    let path: TagPath = TagPath {
+        nodes: vec![
+            TagNode::new(tags::ReferencedImageSequence.tag, None),
+            TagNode::new(tags::ReferencedSOPInstanceUID.tag, None),
+        ],
+    };
+    let element = DicomElement::new_empty(&tags::ReferencedSOPInstanceUID, &vr::UI, dcmroot.ts());
+    assert!(matches!(
+        dcmroot.insert_element(&path, element, "enrich"),
+        Err(ParseError::InvalidTagPath { .. })
+    ));
+
+    // The last node of the path must be the element's tag.
+    let element = DicomElement::new_empty(&tags::AccessionNumber, &vr::SH, dcmroot.ts());
+    assert!(matches!(
+        dcmroot.insert_element(&TagPath::from(&tags::PatientID), element, "enrich"),
+        Err(ParseError::InvalidTagPath { .. })
+    ));
+    assert!(dcmroot
+        .get_child_by_tagpath(&TagPath::from(&tags::AccessionNumber))
+        .is_none());
+    Ok(())
+}
+
+#[test]
+fn test_insert_replaces_element() -> ParseResult<()> {
+    let mut dcmroot: DicomRoot<'_> = parse_fixture(Fixture::UndefinedLengthSequence, true)?;
+    dcmroot.set_track_provenance(true);
+
+    let path: TagPath = TagPath::from(&tags::PatientID);
+    let mut element = DicomElement::new_empty(&tags::PatientID, &vr::LO, dcmroot.ts());
+    element.encode_value(RawValue::Strings(vec!["REPLACED".to_string()]), None)?;
+    let replaced: DicomObject<'_> = dcmroot
+        .insert_element(&path, element, "enrich")?
+        .expect("Should replace the parsed element");
+    assert_eq!(Some(&Provenance::parsed()), replaced.provenance());
+
+    // Replacing an existing element modifies it rather than synthesizing it.
+    assert_eq!(
+        Some(&Provenance::modified("enrich")),
+        dcmroot.get_provenance_by_tagpath(&path)
+    );
+    let value: Option<String> = dcmroot
+        .get_child_by_tagpath(&path)
+        .and_then(|o| String::try_from(o.element()).ok());
+    assert_eq!(Some("REPLACED".to_string()), value);
+    Ok(())
+}
+
+#[test]
+fn test_remove_element() -> ParseResult<()> {
+    let mut dcmroot: DicomRoot<'_> = parse_fixture(Fixture::UndefinedLengthSequence, true)?;
+    let path: TagPath = TagPath::parse(
+        "ReferencedImageSequence[2].ReferencedSOPInstanceUID",
+        Some(&STANDARD_DICOM_DICTIONARY),
+    )?;
+    assert!(dcmroot.remove_element(&path).is_some());
+    assert!(dcmroot.get_child_by_tagpath(&path).is_none());
+    assert!(dcmroot.remove_element(&path).is_none());
+
+    // Items are not removed by their index.
+    let item_path: TagPath = TagPath::parse(
+        "ReferencedImageSequence[1]",
+        Some(&STANDARD_DICOM_DICTIONARY),
+    )?;
+    assert!(dcmroot.remove_element(&item_path).is_none());
+
+    let pid_path: TagPath = TagPath::from(&tags::PatientID);
+    assert!(dcmroot.remove_element(&pid_path).is_some());
+    assert!(dcmroot.get_child_by_tagpath(&pid_path).is_none());
+    Ok(())
+}
+
+#[test]
+fn test_update_value_lengths() -> Result<(), WriteError> {
+    let evrle = &ts::ExplicitVRLittleEndian;
+    let mut ts_uid = DicomElement::new_empty(&tags::TransferSyntaxUID, &vr::UI, evrle);
+    ts_uid.encode_value(RawValue::Uid(evrle.uid().uid().to_string()), None)?;
+
+    // A sequence and item with explicit value lengths which don't match their contents, as though
+    // the contents had been modified after parsing.
+    let mut ref_uid = DicomElement::new_empty(&tags::ReferencedSOPInstanceUID, &vr::UI, evrle);
+    ref_uid.encode_value(RawValue::Uid("1.2.3.4".to_string()), None)?;
+    let item = DicomElement::new(
+        constants::tags::ITEM,
+        &vr::UN,
+        ValueLength::Explicit(2),
+        &ts::ImplicitVRLittleEndian,
+        DEFAULT_CHARACTER_SET,
+        Vec::new(),
+        Vec::new(),
+    );
+    let sequence = DicomElement::new(
+        &tags::ReferencedImageSequence,
+        &vr::SQ,
+        ValueLength::Explicit(4),
+        evrle,
+        DEFAULT_CHARACTER_SET,
+        Vec::new(),
+        Vec::new(),
+    );
+    let item_obj = DicomObject::new_with_children(
+        item,
+        BTreeMap::from([(ref_uid.tag(), DicomObject::new(ref_uid))]),
+        Vec::new(),
+    );
+    let sequence_obj = DicomObject::new_with_children(sequence, BTreeMap::new(), vec![item_obj]);
+    let mut dcmroot: DicomRoot<'_> = DicomRoot::new(
+        evrle,
+        DEFAULT_CHARACTER_SET,
+        &STANDARD_DICOM_DICTIONARY,
+        BTreeMap::from([
+            (ts_uid.tag(), DicomObject::new(ts_uid)),
+            (sequence_obj.element().tag(), sequence_obj),
+        ]),
+        Vec::new(),
+    );
+
+    dcmroot.update_value_lengths();
+    let seq_path: TagPath = TagPath::from(&tags::ReferencedImageSequence);
+    let item_path: TagPath = TagPath::parse(
+        "ReferencedImageSequence[1]",
+        Some(&STANDARD_DICOM_DICTIONARY),
+    )?;
+    // The UI element is 8 bytes of header and 8 bytes of value, the item adds its own 8 bytes.
+    assert_eq!(
+        Some(ValueLength::Explicit(16)),
+        dcmroot
+            .get_child_by_tagpath(&item_path)
+            .map(|o| o.element().vl())
+    );
+    assert_eq!(
+        Some(ValueLength::Explicit(24)),
+        dcmroot
+            .get_child_by_tagpath(&seq_path)
+            .map(|o| o.element().vl())
+    );
+
+    // The written dataset can be parsed back with the sequence contents intact.
+    let mut writer: Writer<Vec<u8>> = WriterBuilder::for_file().ts(evrle).build(Vec::new());
+    writer.write_dcmroot(&dcmroot)?;
+    let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .build(Cursor::new(writer.into_dataset()?));
+    let reparsed: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Should be DICOM");
+    let ref_path: TagPath = TagPath::parse(
+        "ReferencedImageSequence[1].ReferencedSOPInstanceUID",
+        Some(&STANDARD_DICOM_DICTIONARY),
+    )?;
+    assert_eq!(
+        Some("1.2.3.4".to_string()),
+        reparsed
+            .get_child_by_tagpath(&ref_path)
+            .and_then(|o| String::try_from(o.element()).ok())
+    );
+    Ok(())
+}
+
+/// Parses the string value of the element at the given path.
+fn string_at(dcmroot: &DicomRoot<'_>, path: &str) -> Option<String> {
+    let tagpath: TagPath = TagPath::parse(path, Some(&STANDARD_DICOM_DICTIONARY)).ok()?;
+    dcmroot
+        .get_child_by_tagpath(&tagpath)
+        .and_then(|o| String::try_from(o.element()).ok())
+}
+
+/// Inserts a top-level element with the given string values.
+fn insert_strings(dcmroot: &mut DicomRoot<'_>, tag: &Tag, vr: VRRef, values: &[&str]) {
+    let mut element = DicomElement::new_empty(tag, vr, dcmroot.ts());
+    element
+        .encode_value(
+            RawValue::Strings(values.iter().map(|v| v.to_string()).collect()),
+            None,
+        )
+        .expect("Value should encode");
+    dcmroot
+        .insert_element(&TagPath::from(tag), element, "test")
+        .expect("Element should insert");
+}
+
+#[test]
+fn test_merge_policies() -> Result<(), WriteError> {
+    let mut worklist: DicomRoot<'_> = DicomRoot::new(
+        &ts::ExplicitVRLittleEndian,
+        DEFAULT_CHARACTER_SET,
+        &STANDARD_DICOM_DICTIONARY,
+        BTreeMap::new(),
+        Vec::new(),
+    );
+    insert_strings(&mut worklist, &tags::PatientID, &vr::LO, &["WL1"]);
+    insert_strings(&mut worklist, &tags::PatientsName, &vr::PN, &["Doe^Jane"]);
+    insert_strings(
+        &mut worklist,
+        &tags::PatientsBirthDate,
+        &vr::DA,
+        &["19700101"],
+    );
+
+    let expected = [
+        // The Patient's Name is absent so is always added.
+        (MergePolicy::KeepExisting, 1, "TESTKIT", ""),
+        (MergePolicy::FillEmpty, 2, "TESTKIT", "19700101"),
+        (MergePolicy::Overwrite, 3, "WL1", "19700101"),
+    ];
+    for (policy, count, patient_id, birth_date) in expected {
+        let mut dcmroot: DicomRoot<'_> = parse_fixture(Fixture::UndefinedLengthSequence, true)?;
+        insert_strings(&mut dcmroot, &tags::PatientsBirthDate, &vr::DA, &[]);
+        dcmroot.set_track_provenance(true);
+
+        assert_eq!(count, dcmroot.merge(&worklist, policy, "worklist")?);
+        assert_eq!(
+            Some(patient_id.to_string()),
+            string_at(&dcmroot, "PatientID")
+        );
+        assert_eq!(
+            Some("Doe^Jane".to_string()),
+            string_at(&dcmroot, "PatientsName")
+        );
+        assert_eq!(
+            Some(birth_date.to_string()),
+            string_at(&dcmroot, "PatientsBirthDate")
+        );
+        assert_eq!(
+            Some(&Provenance::synthesized("worklist")),
+            dcmroot.get_provenance_by_tagpath(&TagPath::from(&tags::PatientsName))
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merge_sequences() -> Result<(), WriteError> {
+    let fixture: DicomRoot<'_> = parse_fixture(Fixture::UndefinedLengthSequence, true)?;
+    let mut expected: Writer<Vec<u8>> =
+        WriterBuilder::for_file().ts(fixture.ts()).build(Vec::new());
+    expected.write_dcmroot(&fixture)?;
+
+    // Merging into an empty dataset copies every attribute and item, transcoding them.
+    let mut dcmroot: DicomRoot<'_> = DicomRoot::new(
+        fixture.ts(),
+        DEFAULT_CHARACTER_SET,
+        &STANDARD_DICOM_DICTIONARY,
+        BTreeMap::new(),
+        Vec::new(),
+    );
+    let big_endian: Vec<u8> = {
+        let elements = transcode(&fixture, &ts::ExplicitVRBigEndian)?;
+        let mut writer: Writer<Vec<u8>> = WriterBuilder::for_file()
+            .ts(&ts::ExplicitVRBigEndian)
+            .build(Vec::new());
+        writer.write_elements(elements.iter())?;
+        writer.into_dataset()?
+    };
+    let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .build(Cursor::new(big_endian));
+    let other: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Should be DICOM");
+    dcmroot.merge(&other, MergePolicy::KeepExisting, "merge")?;
+    // The File Meta group isn't merged.
+    assert!(dcmroot
+        .get_child_by_tag(tags::TransferSyntaxUID.tag)
+        .is_none());
+    let mut merged: Writer<Vec<u8>> = WriterBuilder::default()
+        .state(WriterState::Element)
+        .ts(dcmroot.ts())
+        .build(Vec::new());
+    merged.write_dcmroot(&dcmroot)?;
+    let expected: Vec<u8> = expected.into_dataset()?;
+    let merged: Vec<u8> = merged.into_dataset()?;
+    assert!(expected.ends_with(&merged));
+
+    // Items are merged with the existing items, and further items are appended.
+    let mut dcmroot: DicomRoot<'_> = parse_fixture(Fixture::UndefinedLengthSequence, true)?;
+    let mut other: DicomRoot<'_> = parse_fixture(Fixture::UndefinedLengthSequence, true)?;
+    let nested: TagPath = TagPath::parse(
+        "ReferencedImageSequence[1].ReferencedSOPInstanceUID",
+        Some(&STANDARD_DICOM_DICTIONARY),
+    )?;
+    other
+        .modify_element(&nested, "test", |e| {
+            e.encode_value(RawValue::Uid("1.2.3.4".to_string()), None)
+        })
+        .expect("Element should exist")?;
+    dcmroot.merge(&other, MergePolicy::Overwrite, "merge")?;
+    assert_eq!(
+        Some("1.2.3.4".to_string()),
+        string_at(
+            &dcmroot,
+            "ReferencedImageSequence[1].ReferencedSOPInstanceUID"
+        )
+    );
+
+    // A dataset with a single item, the second item of the fixture is appended.
+    let evrle = &ts::ExplicitVRLittleEndian;
+    let mut ref_uid = DicomElement::new_empty(&tags::ReferencedSOPInstanceUID, &vr::UI, evrle);
+    ref_uid.encode_value(RawValue::Uid("1.2.3.4".to_string()), None)?;
+    let item = DicomElement::new(
+        constants::tags::ITEM,
+        &vr::UN,
+        ValueLength::Explicit(0),
+        &ts::ImplicitVRLittleEndian,
+        DEFAULT_CHARACTER_SET,
+        Vec::new(),
+        Vec::new(),
+    );
+    let sequence = DicomElement::new(
+        &tags::ReferencedImageSequence,
+        &vr::SQ,
+        ValueLength::Explicit(0),
+        evrle,
+        DEFAULT_CHARACTER_SET,
+        Vec::new(),
+        Vec::new(),
+    );
+    let item_obj = DicomObject::new_with_children(
+        item,
+        BTreeMap::from([(ref_uid.tag(), DicomObject::new(ref_uid))]),
+        Vec::new(),
+    );
+    let sequence_obj = DicomObject::new_with_children(sequence, BTreeMap::new(), vec![item_obj]);
+    let mut dcmroot: DicomRoot<'_> = DicomRoot::new(
+        evrle,
+        DEFAULT_CHARACTER_SET,
+        &STANDARD_DICOM_DICTIONARY,
+        BTreeMap::from([(sequence_obj.element().tag(), sequence_obj)]),
+        Vec::new(),
+    );
+    let fixture: DicomRoot<'_> = parse_fixture(Fixture::UndefinedLengthSequence, true)?;
+    dcmroot.merge(&fixture, MergePolicy::KeepExisting, "merge")?;
+    assert_eq!(
+        Some(2),
+        dcmroot
+            .get_child_by_tag(tags::ReferencedImageSequence.tag)
+            .map(|o| o.item_count())
+    );
+    assert_eq!(
+        Some("1.2.3.4".to_string()),
+        string_at(
+            &dcmroot,
+            "ReferencedImageSequence[1].ReferencedSOPInstanceUID"
+        )
+    );
+    let appended_path: TagPath = TagPath::parse(
+        "ReferencedImageSequence[2].ReferencedSOPInstanceUID",
+        Some(&STANDARD_DICOM_DICTIONARY),
+    )?;
+    let appended = dcmroot
+        .get_child_by_tagpath(&appended_path)
+        .expect("Item should be appended")
+        .element();
+    assert_eq!(appended_path, appended.create_tagpath());
+    assert_eq!(
+        string_at(
+            &fixture,
+            "ReferencedImageSequence[2].ReferencedSOPInstanceUID"
+        ),
+        string_at(
+            &dcmroot,
+            "ReferencedImageSequence[2].ReferencedSOPInstanceUID"
+        )
+    );
+
+    // The sequence's value length covers the merged items, so it can be parsed back.
+    let mut writer: Writer<Vec<u8>> = WriterBuilder::for_file().ts(evrle).build(Vec::new());
+    writer.write_dcmroot(&dcmroot)?;
+    let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .build(Cursor::new(writer.into_dataset()?));
+    let reparsed: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Should be DICOM");
+    assert_eq!(
+        string_at(
+            &fixture,
+            "ReferencedImageSequence[2].ReferencedSOPInstanceUID"
+        ),
+        string_at(
+            &reparsed,
+            "ReferencedImageSequence[2].ReferencedSOPInstanceUID"
+        )
+    );
+    assert_eq!(
+        Some("TESTKIT".to_string()),
+        string_at(&reparsed, "PatientID")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_iter_elements() -> Result<(), WriteError> {
+    for fixture in [Fixture::UndefinedLengthSequence, Fixture::NestedUnSequence] {
+        let mut dcmroot: DicomRoot<'_> = parse_fixture(fixture, true)?;
+
+        // The same elements as flattening, in the same order, without items and delimiters.
+        let flattened: Vec<&DicomElement> = dcmroot
+            .flatten()?
+            .into_iter()
+            .filter(|e| {
+                e.tag() != constants::tags::ITEM
+                    && e.tag() != constants::tags::ITEM_DELIMITATION_ITEM
+                    && e.tag() != constants::tags::SEQUENCE_DELIMITATION_ITEM
+            })
+            .collect();
+        let iterated: Vec<(TagPath, &DicomElement)> = dcmroot.iter_elements().collect();
+        assert_eq!(flattened.len(), iterated.len());
+        for (element, (path, iter_element)) in flattened.iter().zip(iterated.iter()) {
+            assert!(std::ptr::eq(*element, *iter_element));
+            assert_eq!(element.create_tagpath(), *path);
+            let found = dcmroot
+                .get_child_by_tagpath(path)
+                .expect("Path should resolve");
+            assert!(std::ptr::eq(found.element(), *iter_element));
+        }
+
+        let uids: Vec<TagPath> = dcmroot
+            .iter_elements()
+            .filter(|(_path, e)| e.vr() == &vr::UI)
+            .map(|(path, _e)| path)
+            .collect();
+        assert!(!uids.is_empty());
+        for (_path, element) in dcmroot.iter_elements_mut() {
+            if element.vr() == &vr::UI {
+                element.encode_value(RawValue::Uid("1.2.3".to_string()), None)?;
+            }
+        }
+        for path in uids {
+            assert_eq!(
+                Some("1.2.3".to_string()),
+                dcmroot
+                    .get_child_by_tagpath(&path)
+                    .and_then(|o| String::try_from(o.element()).ok())
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Each parsed element locates its bytes within the dataset, including sequences and items of
+/// undefined length, whose length extends through their delimitation item.
+#[test]
+fn test_element_byte_ranges() -> ParseResult<()> {
+    for fixture in [
+        Fixture::UndefinedLengthSequence,
+        Fixture::NestedUnSequence,
+        Fixture::MultiCharsetPersonName,
+    ] {
+        let bytes: Vec<u8> = fixture.to_bytes().expect("Fixture should be writable");
+        let dcmroot: DicomRoot<'_> = parse_fixture(fixture, true)?;
+
+        let mut end: Option<u64> = None;
+        for (path, element) in dcmroot.iter_elements() {
+            let offset: u64 = element.offset().expect("Parsed element should have offset");
+            let len: u64 = element
+                .encoded_len()
+                .unwrap_or_else(|| panic!("{fixture:?} {path} should have length"));
+            let start: usize = offset as usize;
+            let tag: u32 = element.tag();
+            let tag_bytes: [u8; 4] = [
+                (tag >> 16) as u16 as u8,
+                ((tag >> 16) >> 8) as u8,
+                tag as u8,
+                (tag >> 8) as u8,
+            ];
+            assert_eq!(tag_bytes, bytes[start..start + 4], "{fixture:?} {path}");
+            assert!(start + len as usize <= bytes.len(), "{fixture:?} {path}");
+
+            // Top-level elements are contiguous. Delimitation items lie within the sequence they
+            // end.
+            let is_delimiter: bool = tag == constants::tags::SEQUENCE_DELIMITATION_ITEM
+                || tag == constants::tags::ITEM_DELIMITATION_ITEM;
+            if element.sequence_path().is_empty() && !is_delimiter {
+                if let Some(end) = end {
+                    assert_eq!(end, offset, "{fixture:?} {path}");
+                }
+                end = Some(offset + len);
+            }
+        }
+        assert_eq!(Some(bytes.len() as u64), end);
+    }
+
+    // Offsets into a deflated dataset would refer to the inflated bytes.
+    let dcmroot: DicomRoot<'_> = parse_fixture(Fixture::DeflatedDataset, true)?;
+    let patient_id: &DicomObject<'_> = dcmroot
+        .get_child_by_tag(tags::PatientID.tag)
+        .expect("PatientID");
+    assert_eq!(None, patient_id.element().offset());
+    assert_eq!(None, patient_id.element().encoded_len());
+    Ok(())
+}
+
+#[test]
+fn test_edit_template() -> ParseResult<()> {
+    let referenced: TagPath = TagPath::parse(
+        "ReferencedImageSequence[1].ReferencedSOPInstanceUID",
+        Some(&STANDARD_DICOM_DICTIONARY),
+    )?;
+    let frame_number: TagPath = TagPath::parse(
+        "ReferencedImageSequence[1].ReferencedFrameNumber",
+        Some(&STANDARD_DICOM_DICTIONARY),
+    )?;
+    let template: EditTemplate = EditTemplate::default()
+        .set(
+            TagPath::from(&tags::StudyDescription),
+            &vr::LO,
+            RawValue::Strings(vec!["Research".to_string()]),
+        )
+        .set(
+            frame_number.clone(),
+            &vr::IS,
+            RawValue::Strings(vec!["2".to_string()]),
+        )
+        .remove(TagPath::from(&tags::PatientID))
+        .reassign_uid(tags::SOPInstanceUID.tag);
+    // Attributes of items the dataset doesn't have are skipped.
+    let template: EditTemplate = template.set(
+        TagPath::parse(
+            "ReferencedImageSequence[5].ReferencedFrameNumber",
+            Some(&STANDARD_DICOM_DICTIONARY),
+        )?,
+        &vr::IS,
+        RawValue::Strings(vec!["5".to_string()]),
+    );
+
+    // The second dataset references the first, whose SOP Instance UID is reassigned.
+    let mut first: DicomRoot<'_> = parse_fixture(Fixture::UndefinedLengthSequence, true)?;
+    let mut second: DicomRoot<'_> = parse_fixture(Fixture::UndefinedLengthSequence, true)?;
+    let first_uid: String = string_at(&first, "SOPInstanceUID").expect("SOPInstanceUID");
+    second
+        .modify_element(&referenced, "test", |e| {
+            e.encode_value(RawValue::Uid(first_uid.clone()), None)
+        })
+        .expect("Element should exist")?;
+
+    let mut uids: UidMap = UidMap::default();
+    let mut generated: usize = 0;
+    let mut generate = || {
+        generated += 1;
+        format!("1.2.3.{generated}")
+    };
+    assert_eq!(1, template.assign_uids(&first, &mut uids, &mut generate)?);
+    // Both fixtures share the SOP Instance UID, which is assigned once.
+    assert_eq!(0, template.assign_uids(&second, &mut uids, &mut generate)?);
+    assert_eq!(Some("1.2.3.1"), uids.get(&first_uid));
+
+    first.set_track_provenance(true);
+    // SOPInstanceUID and MediaStorageSOPInstanceUID, then the template's three changes.
+    assert_eq!(5, template.apply(&mut first, &uids, "edit")?);
+    template.apply(&mut second, &uids, "edit")?;
+    for dcmroot in [&first, &second] {
+        assert_eq!(
+            Some("1.2.3.1".to_string()),
+            string_at(dcmroot, "SOPInstanceUID")
+        );
+        assert_eq!(
+            Some("1.2.3.1".to_string()),
+            string_at(dcmroot, "MediaStorageSOPInstanceUID")
+        );
+        assert_eq!(
+            Some("Research".to_string()),
+            string_at(dcmroot, "StudyDescription")
+        );
+        assert_eq!(
+            Some("2".to_string()),
+            string_at(dcmroot, "ReferencedImageSequence[1].ReferencedFrameNumber")
+        );
+        assert!(dcmroot.get_child_by_tag(tags::PatientID.tag).is_none());
+    }
+    assert_eq!(
+        Some("1.2.3.1".to_string()),
+        string_at(
+            &second,
+            "ReferencedImageSequence[1].ReferencedSOPInstanceUID"
+        )
+    );
+    assert_eq!(
+        Some(&Provenance::modified("edit")),
+        first.get_provenance_by_tagpath(&TagPath::from(&tags::SOPInstanceUID))
+    );
+
+    // The edited dataset can be written and parsed again.
+    let mut writer: Writer<Vec<u8>> = WriterBuilder::for_file().ts(first.ts()).build(Vec::new());
+    writer.write_dcmroot(&first).expect("Should write");
+    let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .build(Cursor::new(writer.into_dataset().expect("Should write")));
+    let parsed: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Should be DICOM");
+    assert_eq!(
+        Some("2".to_string()),
+        string_at(&parsed, "ReferencedImageSequence[1].ReferencedFrameNumber")
+    );
+    Ok(())
+}
+
+/// The size report accounts for every byte of the dataset as encoded, by group and by element.
+#[test]
+fn test_size_report() -> ParseResult<()> {
+    for fixture in [
+        Fixture::UndefinedLengthSequence,
+        Fixture::NestedUnSequence,
+        Fixture::MultiCharsetPersonName,
+    ] {
+        let bytes: Vec<u8> = fixture.to_bytes().expect("Fixture should be writable");
+        let dcmroot: DicomRoot<'_> = parse_fixture(fixture, true)?;
+        let report: SizeReport = dcmroot.size_report();
+
+        // The File Preamble and DICM prefix aren't part of the dataset.
+        assert_eq!(bytes.len() as u64 - 132, report.total_len(), "{fixture:?}");
+        assert_eq!(
+            report.total_len(),
+            report.header_len() + report.value_len(),
+            "{fixture:?}"
+        );
+        assert_eq!(
+            dcmroot.iter_elements().count(),
+            report.elements.len(),
+            "{fixture:?}"
+        );
+
+        // Top-level elements account for their contents.
+        let top_level_len: u64 = report
+            .elements
+            .iter()
+            .filter(|element| element.depth == 0)
+            .map(ElementSize::total_len)
+            .sum();
+        assert_eq!(report.total_len(), top_level_len, "{fixture:?}");
+
+        let largest: Vec<&ElementSize> = report.largest(3);
+        assert_eq!(3, largest.len());
+        assert!(largest[0].total_len() >= largest[1].total_len());
+        assert!(largest[1].total_len() >= largest[2].total_len());
+    }
+
+    let report: SizeReport = parse_fixture(Fixture::NestedUnSequence, true)?.size_report();
+    assert!(report.max_depth() >= 2);
+    let meta: &GroupSize = &report.groups[0];
+    assert_eq!(0x0002, meta.group);
+    assert_eq!(0, meta.max_depth);
+    Ok(())
+}
+
+/// Parses the bytes, then writes the dataset after canonicalizing it.
+fn canonical_bytes(bytes: Vec<u8>) -> Result<Vec<u8>, WriteError> {
+    let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .build(Cursor::new(bytes));
+    let mut dcmroot: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Should parse");
+    dcmroot.canonicalize()?;
+    let mut writer: Writer<Vec<u8>> = WriterBuilder::for_file().ts(dcmroot.ts()).build(Vec::new());
+    writer.write_dcmroot(&dcmroot)?;
+    writer.into_dataset()
+}
+
+#[test]
+fn test_canonicalize() -> Result<(), WriteError> {
+    for fixture in [Fixture::UndefinedLengthSequence, Fixture::NestedUnSequence] {
+        let dcmroot: DicomRoot<'_> = parse_fixture(fixture, true)?;
+        let canonical: Vec<u8> = canonical_bytes(fixture.to_bytes()?)?;
+
+        // The canonical encoding is unchanged by canonicalizing again. It has no delimiters other
+        // than within the sequence of VR UN, whose length remains undefined.
+        let mut parser: Parser<'_, Cursor<&[u8]>> = ParserBuilder::default()
+            .dictionary(&STANDARD_DICOM_DICTIONARY)
+            .build(Cursor::new(&canonical));
+        let canonical_root: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Should parse");
+        assert_eq!(&ts::ExplicitVRLittleEndian, canonical_root.ts());
+        let is_un_sequence: bool = matches!(fixture, Fixture::NestedUnSequence);
+        assert!(
+            is_un_sequence
+                || canonical_root.flatten()?.iter().all(|element| {
+                    element.vl() != ValueLength::UndefinedLength
+                        && element.tag() != tags::SequenceDelimitationItem.tag
+                })
+        );
+        assert_eq!(canonical, canonical_bytes(canonical.clone())?);
+
+        // The same content in other transfer syntaxes, along with a Group Length, has the same
+        // canonical encoding.
+        for to in [
+            &ts::ImplicitVRLittleEndian,
+            &ts::ExplicitVRBigEndian,
+            &ts::DeflatedExplicitVRLittleEndian,
+        ] {
+            let mut elements: Vec<DicomElement> = transcode(&dcmroot, to)?;
+            let mut group_length: DicomElement =
+                DicomElement::new_empty(0x0010_0000u32, &vr::UL, to);
+            group_length.encode_value(RawValue::UnsignedIntegers(vec![999]), None)?;
+            let position: usize = elements
+                .iter()
+                .position(|element| element.tag() >> 16 == 0x0010)
+                .expect("Should have patient elements");
+            elements.insert(position, group_length);
+            let mut writer: Writer<Vec<u8>> = WriterBuilder::for_file().ts(to).build(Vec::new());
+            writer.write_elements(elements.iter())?;
+            let transcoded: Vec<u8> = writer.into_dataset()?;
+            assert_ne!(canonical, transcoded);
+            assert_eq!(
+                canonical,
+                canonical_bytes(transcoded)?,
+                "{} via {:?}",
+                fixture.name(),
+                to.uid().ident()
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_repair_file_meta() -> Result<(), WriteError> {
+    let mut dcmroot: DicomRoot<'_> = parse_fixture(Fixture::UndefinedLengthSequence, true)?;
+    assert_eq!(Vec::<FileMetaIssue>::new(), dcmroot.check_file_meta()?);
+    let sop_class_uid: Vec<u8> = dcmroot
+        .get_child_by_tag(tags::SOPClassUID.tag)
+        .expect("Fixture should have a SOPClassUID")
+        .element()
+        .data()
+        .to_vec();
+
+    // Break the File Meta group in the ways homegrown tools do.
+    dcmroot.remove_element(&TagPath::from(&tags::MediaStorageSOPClassUID));
+    dcmroot.remove_element(&TagPath::from(&tags::TransferSyntaxUID));
+    let mut instance_uid: DicomElement<'static> = DicomElement::new_empty(
+        &tags::MediaStorageSOPInstanceUID,
+        &vr::UI,
+        &ts::ImplicitVRLittleEndian,
+    );
+    instance_uid.encode_value(RawValue::Uid("1.2.3.4".to_owned()), None)?;
+    dcmroot.insert_element(
+        &TagPath::from(&tags::MediaStorageSOPInstanceUID),
+        instance_uid,
+        "test",
+    )?;
+    dcmroot.modify_element(
+        &TagPath::from(&tags::FileMetaInformationGroupLength),
+        "test",
+        |element| element.encode_value(RawValue::UnsignedIntegers(vec![10]), None),
+    );
+
+    let issues: Vec<FileMetaIssue> = dcmroot.check_file_meta()?;
+    assert!(matches!(
+        issues[0],
+        FileMetaIssue::NotExplicitVRLittleEndian { .. }
+    ));
+    assert_eq!(
+        FileMetaIssue::Missing {
+            tag: tags::MediaStorageSOPClassUID.tag
+        },
+        issues[1]
+    );
+    assert!(matches!(
+        &issues[2],
+        FileMetaIssue::Mismatched { tag, file_meta, .. }
+            if *tag == tags::MediaStorageSOPInstanceUID.tag && file_meta == "1.2.3.4"
+    ));
+    assert_eq!(
+        FileMetaIssue::Missing {
+            tag: tags::TransferSyntaxUID.tag
+        },
+        issues[3]
+    );
+    assert!(matches!(
+        issues[4],
+        FileMetaIssue::WrongGroupLength { declared: 10, .. }
+    ));
+    assert_eq!(5, issues.len());
+
+    assert_eq!(issues, dcmroot.repair_file_meta("repair")?);
+    assert_eq!(Vec::<FileMetaIssue>::new(), dcmroot.check_file_meta()?);
+    assert_eq!(
+        sop_class_uid,
+        dcmroot
+            .get_child_by_tag(tags::MediaStorageSOPClassUID.tag)
+            .expect("MediaStorageSOPClassUID should be repaired")
+            .element()
+            .data()
+    );
+
+    // The repaired group is written as-is when preserving the original encoding, and parses back.
+    let mut writer: Writer<Vec<u8>> = WriterBuilder::for_file()
+        .ts(dcmroot.ts())
+        .preserve_encoding(true)
+        .build(Vec::new());
+    writer.write_dcmroot(&dcmroot)?;
+    let bytes: Vec<u8> = writer.into_dataset()?;
+    let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .build(Cursor::new(bytes));
+    let reparsed: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Should parse");
+    assert_eq!(Vec::<FileMetaIssue>::new(), reparsed.check_file_meta()?);
+    Ok(())
+}
+
+#[test]
+fn test_repair_file_meta_without_dataset_uids() -> Result<(), WriteError> {
+    let mut dcmroot: DicomRoot<'_> = parse_fixture(Fixture::UndefinedLengthSequence, true)?;
+    dcmroot.remove_element(&TagPath::from(&tags::MediaStorageSOPClassUID));
+    dcmroot.remove_element(&TagPath::from(&tags::SOPClassUID));
+
+    let missing: FileMetaIssue = FileMetaIssue::Missing {
+        tag: tags::MediaStorageSOPClassUID.tag,
+    };
+    let issues: Vec<FileMetaIssue> = dcmroot.check_file_meta()?;
+    assert_eq!(2, issues.len());
+    assert_eq!(missing, issues[0]);
+    assert!(matches!(issues[1], FileMetaIssue::WrongGroupLength { .. }));
+
+    // The group length is repaired, but the SOP Class can't be copied from the dataset.
+    assert_eq!(vec![issues[1].clone()], dcmroot.repair_file_meta("repair")?);
+    assert_eq!(vec![missing], dcmroot.check_file_meta()?);
+    Ok(())
+}
@@ -1,8 +1,26 @@
+// These tests predate some of the current clippy lints.
+#![allow(clippy::needless_borrow)]
+
 use dcmpipe_lib::{
     self,
-    core::defn::{tag::Tag, ts::TransferSyntax, uid::UIDRef},
+    core::defn::{
+        code::{CodeRef, ContextGroupRef},
+        dcmdict::DicomDictionary,
+        tag::{Tag, TagNode},
+        ts::{Compression, TransferSyntax},
+        uid::{SOPClassCategory, UIDRef, UIDType},
+    },
     dict::{
-        lookup::{TAG_BY_IDENT, TAG_BY_VALUE, TS_BY_IDENT, TS_BY_UID, UID_BY_IDENT, UID_BY_UID},
+        code_sets,
+        lookup::{
+            TAG_BY_IDENT, TAG_BY_KEYWORD, TAG_BY_VALUE, TS_BY_IDENT, TS_BY_UID, UID_BY_IDENT,
+            UID_BY_UID,
+        },
+        registry::{
+            sop_class_category, sop_classes, sop_classes_in, storage_sop_classes,
+            transfer_syntaxes_with,
+        },
+        stdlookup::STANDARD_DICOM_DICTIONARY,
         tags, transfer_syntaxes as ts, uids,
     },
 };
@@ -33,6 +51,91 @@ pub fn test_tags_lookup() {
     assert_eq!(&tags::TransferSyntaxUID, tsuid_by_tag);
 }
 
+#[test]
+pub fn test_tag_keyword_lookup() {
+    let pn_by_keyword: &Tag = TAG_BY_KEYWORD.get("PatientName").expect("Tag not found");
+    assert_eq!(&tags::PatientsName, pn_by_keyword);
+    assert_eq!("PatientName", tags::PatientsName.keyword());
+
+    let dict: &dyn DicomDictionary = &STANDARD_DICOM_DICTIONARY;
+    assert_eq!(
+        Some(&tags::PatientsName),
+        dict.get_tag_by_keyword("PatientName")
+    );
+    assert_eq!(None, dict.get_tag_by_keyword("PatientsName"));
+    assert_eq!(
+        Some("PatientName"),
+        dict.get_keyword_by_number(tags::PatientsName.tag)
+    );
+    assert_eq!(None, dict.get_keyword_by_number(0x0009_0010));
+}
+
+#[test]
+pub fn test_parse_tag() {
+    let dict: Option<&dyn DicomDictionary> = Some(&STANDARD_DICOM_DICTIONARY);
+    for value in [
+        "PatientName",
+        "PatientsName",
+        "(0010,0010)",
+        "0010,0010",
+        "00100010",
+        " (0010,0010) ",
+    ] {
+        assert_eq!(0x0010_0010, Tag::parse_tag(value, dict).expect(value));
+    }
+    assert_eq!(0x7FE0_0010, Tag::parse_tag("(7fe0,0010)", None).unwrap());
+    assert_eq!(0x7FE0_0010, Tag::parse_tag("7FE00010", None).unwrap());
+
+    assert!(Tag::parse_tag("PatientName", None).is_err());
+    for value in [
+        "",
+        "UnknownKeyword",
+        "(0010,001)",
+        "0010001",
+        "001000100",
+        "(00100010)",
+    ] {
+        assert!(Tag::parse_tag(value, dict).is_err(), "{value}");
+    }
+
+    let node: TagNode = TagNode::parse("(3006,0010)[2]", None).unwrap();
+    assert_eq!(0x3006_0010, node.tag());
+    assert_eq!(Some(2), node.item());
+    let node: TagNode = TagNode::parse("ReferencedFrameOfReferenceSequence[1]", dict).unwrap();
+    assert_eq!(0x3006_0010, node.tag());
+}
+
+#[test]
+pub fn test_code_sets_lookup() {
+    let regions: ContextGroupRef = code_sets::get_context_group(4).expect("CID not found");
+    assert_eq!("Anatomic Region", regions.name());
+    let head: CodeRef = regions.get_code("SCT", "69536005").expect("Code not found");
+    assert_eq!("Head", head.meaning());
+    assert_eq!("(69536005, SCT, \"Head\")", head.to_string());
+    assert_eq!(Some(head), regions.get_code_by_meaning("head"));
+    assert!(regions.get_code("DCM", "69536005").is_none());
+
+    assert_eq!(
+        Some("millimeter"),
+        code_sets::get_code_meaning("UCUM", "mm")
+    );
+    assert_eq!(
+        Some(&code_sets::DiagnosticImagingReportHeading.codes()[5]),
+        code_sets::get_code("DCM", "121070")
+    );
+    assert!(code_sets::get_code_meaning("UCUM", "furlong").is_none());
+    assert!(code_sets::get_context_group(1).is_none());
+
+    // Every code should be listed in only one of the context groups.
+    for (index, group) in code_sets::CONTEXT_GROUPS.iter().enumerate() {
+        assert_eq!(Some(*group), code_sets::get_context_group(group.cid()));
+        for code in group.codes() {
+            let mut other_groups = code_sets::CONTEXT_GROUPS.iter().skip(index + 1);
+            assert!(other_groups.all(|other| other.get_code(code.scheme, code.value).is_none()));
+        }
+    }
+}
+
 #[test]
 pub fn test_transfer_syntaxes_lookup() {
     let ivrle_by_ident: &TransferSyntax = TS_BY_IDENT
@@ -149,3 +252,95 @@ fn test_ts_name_vs_properties() {
         }
     }
 }
+
+#[test]
+pub fn test_retired_and_vm_display() {
+    assert!(!tags::PatientsName.is_retired());
+    assert!(tags::LossyImageCompression_Retired.is_retired());
+    assert!(!uids::ExplicitVRLittleEndian.is_retired());
+    assert!(uids::ExplicitVRBigEndian.is_retired());
+
+    assert_eq!("1", tags::PatientsName.vm().to_string());
+    assert_eq!("2-n", tags::ImageType.vm().to_string());
+}
+
+#[test]
+pub fn test_uid_registry() {
+    assert_eq!(
+        UIDType::TransferSyntax,
+        uids::JPEGBaselineProcess1.uid_type()
+    );
+    assert_eq!(
+        UIDType::MetaSOPClass,
+        uids::DetachedPatientManagementMetaSOPClass.uid_type()
+    );
+    let categories: [(UIDRef, SOPClassCategory); 8] = [
+        (&uids::CTImageStorage, SOPClassCategory::ImageStorage),
+        (&uids::SegmentationStorage, SOPClassCategory::ImageStorage),
+        (
+            &uids::KeyObjectSelectionDocumentStorage,
+            SOPClassCategory::StructuredReportStorage,
+        ),
+        (
+            &uids::GrayscaleSoftcopyPresentationStateStorage,
+            SOPClassCategory::PresentationStateStorage,
+        ),
+        (
+            &uids::GeneralECGWaveformStorage,
+            SOPClassCategory::WaveformStorage,
+        ),
+        (
+            &uids::EncapsulatedPDFStorage,
+            SOPClassCategory::EncapsulatedDocumentStorage,
+        ),
+        (
+            &uids::StudyRootQueryRetrieveInformationModelFIND,
+            SOPClassCategory::QueryRetrieve,
+        ),
+        (&uids::VerificationSOPClass, SOPClassCategory::Service),
+    ];
+    for (uid, category) in categories {
+        assert_eq!(Some(category), uid.sop_class_category(), "{}", uid.ident);
+        assert_eq!(Some(category), sop_class_category(uid.uid()));
+        assert_eq!(
+            category,
+            SOPClassCategory::from_name(category.name()).expect("Category")
+        );
+        assert!(sop_classes_in(category).contains(&uid));
+    }
+    assert_eq!(None, uids::ExplicitVRLittleEndian.sop_class_category());
+    assert_eq!(None, sop_class_category("1.2.3.4"));
+    // UIDs read from datasets may be padded.
+    assert_eq!(
+        Some(SOPClassCategory::ImageStorage),
+        sop_class_category("1.2.840.10008.5.1.4.1.1.2\0")
+    );
+
+    let sop_classes: Vec<UIDRef> = sop_classes();
+    assert!(sop_classes.is_sorted_by_key(|uid| uid.uid()));
+    let storage: Vec<UIDRef> = storage_sop_classes();
+    assert!(storage.contains(&&uids::CTImageStorage));
+    assert!(storage.contains(&&uids::HangingProtocolStorage));
+    assert!(!storage.contains(&&uids::VerificationSOPClass));
+    assert!(!storage.contains(&&uids::MediaStorageDirectoryStorage));
+    assert!(storage.iter().all(|uid| sop_classes.contains(uid)));
+
+    assert_eq!(Compression::None, ts::ExplicitVRLittleEndian.compression());
+    assert_eq!(
+        Compression::None,
+        ts::DeflatedExplicitVRLittleEndian.compression()
+    );
+    assert_eq!(Compression::Lossless, ts::RLELossless.compression());
+    assert_eq!(
+        Compression::Lossless,
+        ts::JPEGLSLosslessImageCompression.compression()
+    );
+    assert!(ts::JPEGBaselineProcess1.is_lossy());
+    assert!(ts::JPEGLSLossyNearLosslessImageCompression.is_lossy());
+    assert!(ts::JPEG2000ImageCompression.is_lossy());
+    assert!(!ts::JPEG2000ImageCompressionLosslessOnly.is_lossy());
+    let lossless: Vec<&TransferSyntax> = transfer_syntaxes_with(Compression::Lossless);
+    assert!(lossless
+        .contains(&&ts::JPEGLosslessNonHierarchicalFirstOrderPredictionProcess14SelectionValue1));
+    assert!(lossless.iter().all(|ts| ts.encapsulated));
+}
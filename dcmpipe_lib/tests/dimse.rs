@@ -0,0 +1,900 @@
+use std::{
+    collections::BTreeMap,
+    convert::TryFrom,
+    io::Cursor,
+    net::{TcpListener, TcpStream},
+    thread::{self, JoinHandle},
+};
+
+use dcmpipe_lib::{
+    core::{
+        charset::DEFAULT_CHARACTER_SET,
+        dcmelement::DicomElement,
+        dcmobject::{DicomObject, DicomRoot},
+        defn::{
+            constants::ts,
+            tag::{TagNode, TagPath, TagRef},
+            vr::{self, VRRef},
+        },
+        matching::Matching,
+        RawValue,
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags, uids},
+    dimse::{
+        assoc::{Association, AssociationBuilder},
+        commands::{encode_dataset, encode_elements, CommandBuilder, Message},
+        constants::{status, CommandField, Priority, DEFAULT_MAX_PDU_LENGTH, MAX_ASSOC_PDU_LENGTH},
+        error::DimseError,
+        mpps::{create_mpps, handle_mpps, set_mpps, MppsHandler, PerformedProcedureStepStatus},
+        mwl::{find_worklist, WorklistItem, WorklistQuery},
+        pdus::{
+            AbstractSyntaxItem, ApplicationContextItem, AssocRQ, AssocRQPresentationContext,
+            MaxLengthItem, Pdu, PduType, PresentationDataItem, PresentationDataValue,
+            TransferSyntaxItem, UserInformationItem,
+        },
+        query::{QueryIdentifier, QueryKeyError, QueryLevel, QueryModel},
+        stgcmt::{
+            failure_reason, receive_commitment_request, receive_commitment_result,
+            request_commitment, send_commitment_result, CommitmentRequest, CommitmentResult,
+            SopReference,
+        },
+    },
+};
+
+const SCP_AE: &str = "TEST_SCP";
+const SCU_AE: &str = "TEST_SCU";
+
+/// Spawns a thread which accepts a single association and handles messages with `handler` until
+/// the association is released.
+fn spawn_scp<F>(
+    builder: AssociationBuilder,
+    handler: F,
+) -> (u16, JoinHandle<Result<(), DimseError>>)
+where
+    F: Fn(&Association, Message, &mut TcpStream) -> Result<(), DimseError> + Send + 'static,
+{
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").expect("Should bind");
+    let port: u16 = listener.local_addr().expect("Should have address").port();
+    let handle = thread::spawn(move || {
+        let (mut stream, _addr) = listener.accept()?;
+        let assoc: Association = builder.accept(&mut stream)?;
+        while let Some(message) = assoc.read_message(&mut stream)? {
+            handler(&assoc, message, &mut stream)?;
+        }
+        Ok(())
+    });
+    (port, handle)
+}
+
+fn patient_name_identifier(name: &str) -> DicomRoot<'static> {
+    let mut child_nodes: BTreeMap<u32, DicomObject> = BTreeMap::new();
+    for (tag, vr, value) in [
+        (&tags::QueryRetrieveLevel, &vr::CS, "PATIENT"),
+        (&tags::PatientsName, &vr::PN, name),
+    ] {
+        let mut elem = DicomElement::new_empty(tag, vr, &ts::ExplicitVRLittleEndian);
+        elem.encode_value(RawValue::Strings(vec![value.to_owned()]), None)
+            .expect("Value should encode");
+        child_nodes.insert(tag.tag, DicomObject::new(elem));
+    }
+    DicomRoot::new(
+        &ts::ExplicitVRLittleEndian,
+        DEFAULT_CHARACTER_SET,
+        &STANDARD_DICOM_DICTIONARY,
+        child_nodes,
+        Vec::new(),
+    )
+}
+
+fn query_identifier(keys: &[(TagRef, VRRef, &str)]) -> DicomRoot<'static> {
+    let mut child_nodes: BTreeMap<u32, DicomObject> = BTreeMap::new();
+    for (tag, vr, value) in keys {
+        let mut elem = DicomElement::new_empty(*tag, vr, &ts::ExplicitVRLittleEndian);
+        let values: Vec<String> = value.split('\\').map(str::to_owned).collect();
+        elem.encode_value(RawValue::Strings(values), None)
+            .expect("Value should encode");
+        child_nodes.insert(tag.tag, DicomObject::new(elem));
+    }
+    DicomRoot::new(
+        &ts::ExplicitVRLittleEndian,
+        DEFAULT_CHARACTER_SET,
+        &STANDARD_DICOM_DICTIONARY,
+        child_nodes,
+        Vec::new(),
+    )
+}
+
+fn pps_status_dataset(pps_status: PerformedProcedureStepStatus) -> DicomRoot<'static> {
+    let mut elem = DicomElement::new_empty(
+        &tags::PerformedProcedureStepStatus,
+        &vr::CS,
+        &ts::ExplicitVRLittleEndian,
+    );
+    elem.encode_value(RawValue::Strings(vec![pps_status.value().to_owned()]), None)
+        .expect("Value should encode");
+    let child_nodes: BTreeMap<u32, DicomObject> =
+        BTreeMap::from([(elem.tag(), DicomObject::new(elem))]);
+    DicomRoot::new(
+        &ts::ExplicitVRLittleEndian,
+        DEFAULT_CHARACTER_SET,
+        &STANDARD_DICOM_DICTIONARY,
+        child_nodes,
+        Vec::new(),
+    )
+}
+
+/// Tracks the status of each Performed Procedure Step, refusing updates once final.
+#[derive(Default)]
+struct MppsTracker(BTreeMap<String, PerformedProcedureStepStatus>);
+
+impl MppsHandler for MppsTracker {
+    fn create(&mut self, sop_instance_uid: &str, dataset: DicomRoot<'static>) -> Result<(), u16> {
+        if self.0.contains_key(sop_instance_uid) {
+            return Err(status::DUPLICATE_SOP_INSTANCE);
+        }
+        let pps_status = PerformedProcedureStepStatus::of(&dataset).expect("Should have status");
+        self.0.insert(sop_instance_uid.to_owned(), pps_status);
+        Ok(())
+    }
+
+    fn set(
+        &mut self,
+        sop_instance_uid: &str,
+        modifications: DicomRoot<'static>,
+    ) -> Result<(), u16> {
+        let current = self
+            .0
+            .get_mut(sop_instance_uid)
+            .ok_or(status::NO_SUCH_SOP_INSTANCE)?;
+        if current.is_final() {
+            return Err(status::PROCESSING_FAILURE);
+        }
+        if let Some(pps_status) = PerformedProcedureStepStatus::of(&modifications) {
+            *current = pps_status;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_assoc_rq_roundtrip() -> Result<(), DimseError> {
+    let rq: AssocRQ = AssocRQ::new(
+        SCP_AE,
+        SCU_AE,
+        ApplicationContextItem::new(uids::DICOMApplicationContextName.uid),
+        vec![AssocRQPresentationContext::new(
+            1,
+            AbstractSyntaxItem::new(uids::VerificationSOPClass.uid),
+            vec![
+                TransferSyntaxItem::new(uids::ExplicitVRLittleEndian.uid),
+                TransferSyntaxItem::new(uids::ImplicitVRLittleEndian.uid),
+            ],
+        )],
+        UserInformationItem::new(Some(MaxLengthItem::new(4096)), None, None),
+    );
+    let bytes: Vec<u8> = (&rq).into();
+    // The length field excludes the 6-byte PDU header.
+    assert_eq!(bytes.len() as u32, rq.length() + 6);
+
+    let Pdu::AssocRQ(read_rq) = Pdu::read(&mut Cursor::new(bytes))? else {
+        panic!("Should read AssocRQ");
+    };
+    assert_eq!(rq.called_ae(), read_rq.called_ae());
+    assert_eq!(rq.calling_ae(), read_rq.calling_ae());
+    assert_eq!(1, read_rq.pres_ctxs().len());
+    assert_eq!(2, read_rq.pres_ctxs()[0].transfer_syntaxes().len());
+    assert_eq!(Some(4096), read_rq.user_info().max_length());
+    Ok(())
+}
+
+#[test]
+fn test_pdu_length_limits() {
+    // A P-DATA-TF longer than the maximum length is rejected before its contents are read.
+    let mut pdata: Vec<u8> = vec![PduType::PresentationDataItem as u8, 0];
+    pdata.extend(0xFFFF_FFF0u32.to_be_bytes());
+    pdata.extend([0u8; 16]);
+    let result = Pdu::read_max(&mut Cursor::new(&pdata), DEFAULT_MAX_PDU_LENGTH);
+    assert!(matches!(result, Err(DimseError::InvalidPdu(_))));
+
+    // Without a maximum the length is only read as far as the stream goes.
+    let result = Pdu::read_max(&mut Cursor::new(&pdata), 0);
+    assert!(matches!(result, Err(DimseError::IOError { .. })));
+
+    // Association PDUs have a fixed maximum.
+    let mut assoc_rq: Vec<u8> = vec![PduType::AssocRQ as u8, 0];
+    assoc_rq.extend((MAX_ASSOC_PDU_LENGTH + 1).to_be_bytes());
+    assoc_rq.extend([0u8; 68]);
+    let result = Pdu::read(&mut Cursor::new(&assoc_rq));
+    assert!(matches!(result, Err(DimseError::InvalidPdu(_))));
+}
+
+#[test]
+fn test_message_length_limit() -> Result<(), DimseError> {
+    let scp = AssociationBuilder::default()
+        .ae_title(SCP_AE)
+        .abstract_syntax(uids::VerificationSOPClass.uid)
+        .max_message_length(1024);
+    let (port, handle) = spawn_scp(scp, |_assoc, _rq, _stream| {
+        panic!("No message should be received")
+    });
+
+    let mut stream: TcpStream = TcpStream::connect(("127.0.0.1", port))?;
+    let assoc: Association = AssociationBuilder::default()
+        .ae_title(SCU_AE)
+        .abstract_syntax(uids::VerificationSOPClass.uid)
+        .request(SCP_AE, &mut stream)?;
+    let ctx_id: u8 = assoc.pres_ctxs()[0].ctx_id();
+
+    // Command fragments which never end, each within the maximum PDU length.
+    for _ in 0..17 {
+        let pdv = PresentationDataValue::new(
+            ctx_id,
+            PresentationDataValue::MSG_HEADER_COMMAND,
+            vec![0u8; 64],
+        );
+        Pdu::PresentationDataItem(PresentationDataItem::new(vec![pdv])).write(&mut stream)?;
+    }
+
+    assert!(matches!(Pdu::read(&mut stream)?, Pdu::Abort(_)));
+    let result = handle.join().expect("SCP should not panic");
+    assert!(matches!(result, Err(DimseError::MessageTooLong(1024))));
+    Ok(())
+}
+
+#[test]
+fn test_echo() -> Result<(), DimseError> {
+    let scp = AssociationBuilder::default()
+        .ae_title(SCP_AE)
+        .abstract_syntax(uids::VerificationSOPClass.uid);
+    let (port, handle) = spawn_scp(scp, |assoc, rq, stream| {
+        assert_eq!(Some(CommandField::CEchoReq), rq.command_field());
+        let rsp = CommandBuilder::c_echo_rsp(&rq, status::SUCCESS).build()?;
+        assoc.write_message(&Message::new(rq.ctx_id(), rsp, None), stream)
+    });
+
+    let mut stream: TcpStream = TcpStream::connect(("127.0.0.1", port))?;
+    let mut assoc: Association = AssociationBuilder::default()
+        .ae_title(SCU_AE)
+        .abstract_syntax(uids::VerificationSOPClass.uid)
+        .request(SCP_AE, &mut stream)?;
+    assert_eq!(SCP_AE, assoc.peer_ae());
+
+    let ctx_id: u8 = assoc
+        .get_pres_ctx_by_abstract_syntax(uids::VerificationSOPClass.uid)
+        .expect("Verification should be accepted")
+        .ctx_id();
+    let msg_id: u16 = assoc.next_msg_id();
+    let rq = CommandBuilder::c_echo_rq(msg_id, uids::VerificationSOPClass.uid).build()?;
+    assoc.write_message(&Message::new(ctx_id, rq, None), &mut stream)?;
+
+    let rsp: Message = assoc
+        .read_message(&mut stream)?
+        .expect("Should receive response");
+    assert_eq!(Some(CommandField::CEchoRsp), rsp.command_field());
+    assert_eq!(Some(msg_id), rsp.message_id_responded_to());
+    assert_eq!(Some(status::SUCCESS), rsp.status());
+    assert!(!rsp.has_dataset());
+
+    assoc.release(&mut stream)?;
+    handle.join().expect("SCP should not panic")
+}
+
+#[test]
+fn test_find_fragmented() -> Result<(), DimseError> {
+    let find_uid: &str = uids::PatientRootQueryRetrieveInformationModelFIND.uid;
+    // A small max PDU length forces the command and dataset to be sent in many fragments.
+    let scp = AssociationBuilder::default()
+        .ae_title(SCP_AE)
+        .abstract_syntax(find_uid)
+        .max_pdu_length(32);
+    let (port, handle) = spawn_scp(scp, |assoc, rq, stream| {
+        assert_eq!(Some(CommandField::CFindReq), rq.command_field());
+        let ts = assoc
+            .get_pres_ctx(rq.ctx_id())
+            .expect("Context should exist")
+            .ts();
+        let identifier: DicomRoot<'_> = rq.decode_dataset(ts)?.expect("Should have identifier");
+        let name: String = identifier
+            .get_child_by_tag(tags::PatientsName.tag)
+            .map(|obj| String::try_from(obj.element()))
+            .expect("Should have PatientsName")?;
+        assert_eq!("Doe^J*", name);
+
+        let matched: Vec<u8> = encode_dataset(&patient_name_identifier("Doe^John"), ts)?;
+        let pending = CommandBuilder::c_find_rsp(&rq, status::PENDING)
+            .dataset(true)
+            .build()?;
+        assoc.write_message(&Message::new(rq.ctx_id(), pending, Some(matched)), stream)?;
+        let success = CommandBuilder::c_find_rsp(&rq, status::SUCCESS).build()?;
+        assoc.write_message(&Message::new(rq.ctx_id(), success, None), stream)
+    });
+
+    let mut stream: TcpStream = TcpStream::connect(("127.0.0.1", port))?;
+    let mut assoc: Association = AssociationBuilder::default()
+        .ae_title(SCU_AE)
+        .abstract_syntax(find_uid)
+        .max_pdu_length(32)
+        .request(SCP_AE, &mut stream)?;
+    assert_eq!(32, assoc.peer_max_pdu_length());
+
+    let pres_ctx = assoc
+        .get_pres_ctx_by_abstract_syntax(find_uid)
+        .expect("FIND should be accepted")
+        .clone();
+    let query: Vec<u8> = encode_dataset(&patient_name_identifier("Doe^J*"), pres_ctx.ts())?;
+    let rq = CommandBuilder::c_find_rq(assoc.next_msg_id(), find_uid, Priority::Medium).build()?;
+    assoc.write_message(
+        &Message::new(pres_ctx.ctx_id(), rq, Some(query)),
+        &mut stream,
+    )?;
+
+    let pending: Message = assoc.read_message(&mut stream)?.expect("Should match");
+    assert_eq!(Some(status::PENDING), pending.status());
+    let matched: DicomRoot<'_> = pending
+        .decode_dataset(pres_ctx.ts())?
+        .expect("Should have identifier");
+    let name: String = matched
+        .get_child_by_tag(tags::PatientsName.tag)
+        .map(|obj| String::try_from(obj.element()))
+        .expect("Should have PatientsName")?;
+    assert_eq!("Doe^John", name);
+
+    let done: Message = assoc.read_message(&mut stream)?.expect("Should complete");
+    assert_eq!(Some(status::SUCCESS), done.status());
+
+    assoc.release(&mut stream)?;
+    handle.join().expect("SCP should not panic")
+}
+
+#[test]
+fn test_store() -> Result<(), DimseError> {
+    let ct_uid: &str = uids::CTImageStorage.uid;
+    const SOP_INSTANCE_UID: &str = "1.2.3.4.5";
+    let scp = AssociationBuilder::default()
+        .ae_title(SCP_AE)
+        .abstract_syntax(ct_uid);
+    let (port, handle) = spawn_scp(scp, |assoc, rq, stream| {
+        assert_eq!(Some(CommandField::CStoreReq), rq.command_field());
+        assert_eq!(
+            Some(SOP_INSTANCE_UID.to_owned()),
+            rq.affected_sop_instance_uid()
+        );
+        let ts = assoc
+            .get_pres_ctx(rq.ctx_id())
+            .expect("Context should exist")
+            .ts();
+        let dataset: DicomRoot<'_> = rq.decode_dataset(ts)?.expect("Should have dataset");
+        assert!(dataset
+            .get_child_by_tag(tags::MediaStorageSOPInstanceUID.tag)
+            .is_none());
+        let sop_instance_uid: String = dataset
+            .get_child_by_tag(tags::SOPInstanceUID.tag)
+            .map(|obj| String::try_from(obj.element()))
+            .expect("Should have SOPInstanceUID")?;
+        assert_eq!(SOP_INSTANCE_UID, sop_instance_uid);
+
+        let rsp = CommandBuilder::c_store_rsp(&rq, status::SUCCESS).build()?;
+        assoc.write_message(&Message::new(rq.ctx_id(), rsp, None), stream)
+    });
+
+    // The SCP doesn't support big endian so only the second proposal should be accepted.
+    let mut stream: TcpStream = TcpStream::connect(("127.0.0.1", port))?;
+    let mut assoc: Association = AssociationBuilder::default()
+        .ae_title(SCU_AE)
+        .presentation_context(ct_uid, vec![&ts::ExplicitVRBigEndian])
+        .presentation_context(ct_uid, vec![&ts::ImplicitVRLittleEndian])
+        .request(SCP_AE, &mut stream)?;
+    assert_eq!(1, assoc.pres_ctxs().len());
+    let pres_ctx = assoc
+        .get_pres_ctx_by_abstract_syntax(ct_uid)
+        .expect("CT should be accepted")
+        .clone();
+    assert_eq!(3, pres_ctx.ctx_id());
+    assert_eq!(uids::ImplicitVRLittleEndian.uid, pres_ctx.ts().uid().uid());
+
+    // File Meta elements should not be sent.
+    let mut elements: Vec<DicomElement> = Vec::new();
+    for (tag, value) in [
+        (&tags::MediaStorageSOPInstanceUID, SOP_INSTANCE_UID),
+        (&tags::SOPClassUID, ct_uid),
+        (&tags::SOPInstanceUID, SOP_INSTANCE_UID),
+    ] {
+        let mut elem = DicomElement::new_empty(tag, &vr::UI, pres_ctx.ts());
+        elem.encode_value(RawValue::Uid(value.to_owned()), None)
+            .expect("Value should encode");
+        elements.push(elem);
+    }
+    let dataset: Vec<u8> = encode_elements(elements.iter(), pres_ctx.ts())?;
+    let msg_id: u16 = assoc.next_msg_id();
+    let rq =
+        CommandBuilder::c_store_rq(msg_id, ct_uid, SOP_INSTANCE_UID, Priority::Medium).build()?;
+    assoc.write_message(
+        &Message::new(pres_ctx.ctx_id(), rq, Some(dataset)),
+        &mut stream,
+    )?;
+
+    let rsp: Message = assoc
+        .read_message(&mut stream)?
+        .expect("Should receive response");
+    assert_eq!(Some(CommandField::CStoreRsp), rsp.command_field());
+    assert_eq!(Some(msg_id), rsp.message_id_responded_to());
+    assert_eq!(Some(status::SUCCESS), rsp.status());
+    assert_eq!(
+        Some(SOP_INSTANCE_UID.to_owned()),
+        rsp.affected_sop_instance_uid()
+    );
+
+    assoc.release(&mut stream)?;
+    handle.join().expect("SCP should not panic")
+}
+
+#[test]
+fn test_reject_unknown_called_ae() -> Result<(), DimseError> {
+    let scp = AssociationBuilder::default()
+        .ae_title(SCP_AE)
+        .abstract_syntax(uids::VerificationSOPClass.uid);
+    let (port, handle) = spawn_scp(scp, |_assoc, _rq, _stream| Ok(()));
+
+    let mut stream: TcpStream = TcpStream::connect(("127.0.0.1", port))?;
+    let result = AssociationBuilder::default()
+        .ae_title(SCU_AE)
+        .abstract_syntax(uids::VerificationSOPClass.uid)
+        .request("SOMEONE_ELSE", &mut stream);
+    assert!(matches!(
+        result,
+        Err(DimseError::AssociationRejected { reason: 7, .. })
+    ));
+    assert!(matches!(
+        handle.join().expect("SCP should not panic"),
+        Err(DimseError::AssociationRejected { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_reject_unknown_calling_ae() -> Result<(), DimseError> {
+    let scp = AssociationBuilder::default()
+        .ae_title(SCP_AE)
+        .allow_calling_ae("SOMEONE_ELSE")
+        .abstract_syntax(uids::VerificationSOPClass.uid);
+    let (port, handle) = spawn_scp(scp, |_assoc, _rq, _stream| Ok(()));
+
+    let mut stream: TcpStream = TcpStream::connect(("127.0.0.1", port))?;
+    let result = AssociationBuilder::default()
+        .ae_title(SCU_AE)
+        .abstract_syntax(uids::VerificationSOPClass.uid)
+        .request(SCP_AE, &mut stream);
+    assert!(matches!(
+        result,
+        Err(DimseError::AssociationRejected { reason: 3, .. })
+    ));
+    assert!(matches!(
+        handle.join().expect("SCP should not panic"),
+        Err(DimseError::AssociationRejected { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_extended_negotiation() -> Result<(), DimseError> {
+    const SPECIALIZED_CT: &str = "1.2.826.0.1.3680043.2.1143.1";
+    let scp = AssociationBuilder::default()
+        .ae_title(SCP_AE)
+        .abstract_syntax(uids::VerificationSOPClass.uid)
+        .abstract_syntax(uids::StudyRootQueryRetrieveInformationModelGET.uid)
+        .abstract_syntax(uids::CTImageStorage.uid)
+        .role_selection(uids::CTImageStorage.uid, false, true)
+        .async_ops_window(2, 0)
+        .extended_negotiation(
+            uids::StudyRootQueryRetrieveInformationModelGET.uid,
+            vec![1, 0],
+        );
+    let (port, handle) = spawn_scp(scp, |assoc, rq, stream| {
+        // The requestor retrieving with C-GET is the SCP for storage, so the acceptor is the SCU.
+        assert!(assoc.is_scu_for(uids::CTImageStorage.uid));
+        assert!(!assoc.is_scp_for(uids::CTImageStorage.uid));
+        assert!(assoc.is_scp_for(uids::VerificationSOPClass.uid));
+        assert_eq!((2, 5), assoc.async_ops_window());
+        assert_eq!(
+            Some([1u8, 0].as_slice()),
+            assoc.extended_negotiation(uids::StudyRootQueryRetrieveInformationModelGET.uid)
+        );
+        let rsp = CommandBuilder::c_echo_rsp(&rq, status::SUCCESS).build()?;
+        assoc.write_message(&Message::new(rq.ctx_id(), rsp, None), stream)
+    });
+
+    let mut stream: TcpStream = TcpStream::connect(("127.0.0.1", port))?;
+    let mut assoc: Association = AssociationBuilder::default()
+        .ae_title(SCU_AE)
+        .abstract_syntax(uids::VerificationSOPClass.uid)
+        .abstract_syntax(uids::StudyRootQueryRetrieveInformationModelGET.uid)
+        .abstract_syntax(uids::CTImageStorage.uid)
+        .abstract_syntax(SPECIALIZED_CT)
+        .role_selection(uids::CTImageStorage.uid, true, true)
+        .role_selection(SPECIALIZED_CT, true, true)
+        .async_ops_window(5, 5)
+        .extended_negotiation(
+            uids::StudyRootQueryRetrieveInformationModelGET.uid,
+            vec![1, 1, 1],
+        )
+        .common_extended_negotiation(
+            SPECIALIZED_CT,
+            uids::StorageServiceClass.uid,
+            &[uids::CTImageStorage.uid],
+        )
+        .request(SCP_AE, &mut stream)?;
+
+    // Only the SCP role was permitted, and the roles for the specialized SOP Class weren't
+    // answered so the defaults apply.
+    let role = assoc
+        .role_selection(uids::CTImageStorage.uid)
+        .expect("Roles should be negotiated");
+    assert!(!role.scu_role() && role.scp_role());
+    assert!(assoc.is_scp_for(uids::CTImageStorage.uid));
+    assert!(!assoc.is_scu_for(uids::CTImageStorage.uid));
+    assert!(assoc.role_selection(SPECIALIZED_CT).is_none());
+    assert!(assoc.is_scu_for(SPECIALIZED_CT));
+    assert!(!assoc.is_scp_for(SPECIALIZED_CT));
+
+    assert_eq!((2, 5), assoc.async_ops_window());
+    assert_eq!(
+        Some([1u8, 0].as_slice()),
+        assoc.extended_negotiation(uids::StudyRootQueryRetrieveInformationModelGET.uid)
+    );
+    assert!(assoc
+        .get_pres_ctx_by_abstract_syntax(SPECIALIZED_CT)
+        .is_some());
+
+    let ctx_id: u8 = assoc
+        .get_pres_ctx_by_abstract_syntax(uids::VerificationSOPClass.uid)
+        .expect("Verification should be accepted")
+        .ctx_id();
+    let rq =
+        CommandBuilder::c_echo_rq(assoc.next_msg_id(), uids::VerificationSOPClass.uid).build()?;
+    assoc.write_message(&Message::new(ctx_id, rq, None), &mut stream)?;
+    let rsp: Message = assoc
+        .read_message(&mut stream)?
+        .expect("Should receive response");
+    assert_eq!(Some(status::SUCCESS), rsp.status());
+
+    assoc.release(&mut stream)?;
+    handle.join().expect("SCP should not panic")
+}
+
+#[test]
+fn test_find_worklist() -> Result<(), DimseError> {
+    let mwl_uid: &str = uids::ModalityWorklistInformationModelFIND.uid;
+    let scp = AssociationBuilder::default()
+        .ae_title(SCP_AE)
+        .abstract_syntax(mwl_uid);
+    let (port, handle) = spawn_scp(scp, |assoc, rq, stream| {
+        assert_eq!(Some(CommandField::CFindReq), rq.command_field());
+        let ts = assoc
+            .get_pres_ctx(rq.ctx_id())
+            .expect("Context should exist")
+            .ts();
+        let identifier: DicomRoot<'_> = rq.decode_dataset(ts)?.expect("Should have identifier");
+        let sps_value = |tag| {
+            let tagpath: TagPath = vec![
+                TagNode::from((&tags::ScheduledProcedureStepSequence, Some(1))),
+                TagNode::from(tag),
+            ]
+            .into();
+            identifier
+                .get_child_by_tagpath(&tagpath)
+                .map(|obj| String::try_from(obj.element()))
+                .expect("Should have key")
+        };
+        assert_eq!("CT01", sps_value(&tags::ScheduledStationAETitle)?);
+        assert_eq!(
+            "20240102",
+            sps_value(&tags::ScheduledProcedureStepStartDate)?
+        );
+        assert_eq!("CT", sps_value(&tags::Modality)?);
+        assert_eq!("", sps_value(&tags::ScheduledProcedureStepID)?);
+        assert!(identifier
+            .get_child_by_tag(tags::StudyInstanceUID.tag)
+            .is_some());
+
+        for (patient_id, pending_status) in
+            [("1234", status::PENDING), ("5678", status::PENDING_WARNING)]
+        {
+            let matched = WorklistQuery::default()
+                .patient_name("Doe^John")
+                .patient_id(patient_id)
+                .accession_number("A100")
+                .scheduled_station_ae("CT01")
+                .scheduled_date("20240102")
+                .modality("CT");
+            let matched: Vec<u8> = encode_elements(matched.to_elements(ts)?.iter(), ts)?;
+            let pending = CommandBuilder::c_find_rsp(&rq, pending_status)
+                .dataset(true)
+                .build()?;
+            assoc.write_message(&Message::new(rq.ctx_id(), pending, Some(matched)), stream)?;
+        }
+        let success = CommandBuilder::c_find_rsp(&rq, status::SUCCESS).build()?;
+        assoc.write_message(&Message::new(rq.ctx_id(), success, None), stream)
+    });
+
+    let mut stream: TcpStream = TcpStream::connect(("127.0.0.1", port))?;
+    let mut assoc: Association = AssociationBuilder::default()
+        .ae_title(SCU_AE)
+        .abstract_syntax(mwl_uid)
+        .request(SCP_AE, &mut stream)?;
+
+    let query = WorklistQuery::default()
+        .scheduled_station_ae("CT01")
+        .scheduled_date("20240102")
+        .modality("CT");
+    let items: Vec<WorklistItem> = find_worklist(&mut assoc, &mut stream, &query)?;
+    assert_eq!(2, items.len());
+    assert_eq!(Some("Doe^John".to_owned()), items[0].patient_name());
+    assert_eq!(Some("1234".to_owned()), items[0].patient_id());
+    assert_eq!(Some("5678".to_owned()), items[1].patient_id());
+    assert_eq!(Some("A100".to_owned()), items[0].accession_number());
+    assert_eq!(Some("CT01".to_owned()), items[0].scheduled_station_ae());
+    assert_eq!(Some("20240102".to_owned()), items[0].scheduled_date());
+    assert_eq!(Some("CT".to_owned()), items[0].modality());
+    assert_eq!(None, items[0].scheduled_procedure_step_id());
+
+    assoc.release(&mut stream)?;
+    handle.join().expect("SCP should not panic")
+}
+
+#[test]
+fn test_mpps() -> Result<(), DimseError> {
+    const PPS_UID: &str = "1.2.3.4.6";
+    let mpps_uid: &str = uids::ModalityPerformedProcedureStepSOPClass.uid;
+    let scp = AssociationBuilder::default()
+        .ae_title(SCP_AE)
+        .abstract_syntax(mpps_uid);
+    let tracker = std::sync::Mutex::new(MppsTracker::default());
+    let (port, handle) = spawn_scp(scp, move |assoc, rq, stream| {
+        let mut tracker = tracker.lock().expect("Should lock");
+        handle_mpps(assoc, rq, stream, &mut *tracker)
+    });
+
+    let mut stream: TcpStream = TcpStream::connect(("127.0.0.1", port))?;
+    let mut assoc: Association = AssociationBuilder::default()
+        .ae_title(SCU_AE)
+        .abstract_syntax(mpps_uid)
+        .request(SCP_AE, &mut stream)?;
+
+    // A Performed Procedure Step must be created as in progress.
+    let created = create_mpps(
+        &mut assoc,
+        &mut stream,
+        PPS_UID,
+        &pps_status_dataset(PerformedProcedureStepStatus::Completed),
+    );
+    assert!(matches!(
+        created,
+        Err(DimseError::FailureStatus {
+            status: status::INVALID_ATTRIBUTE_VALUE,
+            ..
+        })
+    ));
+
+    create_mpps(
+        &mut assoc,
+        &mut stream,
+        PPS_UID,
+        &pps_status_dataset(PerformedProcedureStepStatus::InProgress),
+    )?;
+    set_mpps(
+        &mut assoc,
+        &mut stream,
+        PPS_UID,
+        &pps_status_dataset(PerformedProcedureStepStatus::Completed),
+    )?;
+
+    // Once completed it may no longer be updated.
+    let updated = set_mpps(
+        &mut assoc,
+        &mut stream,
+        PPS_UID,
+        &pps_status_dataset(PerformedProcedureStepStatus::Discontinued),
+    );
+    assert!(matches!(
+        updated,
+        Err(DimseError::FailureStatus {
+            status: status::PROCESSING_FAILURE,
+            ..
+        })
+    ));
+
+    let unknown = set_mpps(
+        &mut assoc,
+        &mut stream,
+        "1.2.3.4.7",
+        &pps_status_dataset(PerformedProcedureStepStatus::Completed),
+    );
+    assert!(matches!(
+        unknown,
+        Err(DimseError::FailureStatus {
+            status: status::NO_SUCH_SOP_INSTANCE,
+            ..
+        })
+    ));
+
+    assoc.release(&mut stream)?;
+    handle.join().expect("SCP should not panic")
+}
+
+#[test]
+fn test_storage_commitment() -> Result<(), DimseError> {
+    const TRANSACTION_UID: &str = "1.2.3.4.8";
+    let stgcmt_uid: &str = uids::StorageCommitmentPushModelSOPClass.uid;
+    let ct_uid: &str = uids::CTImageStorage.uid;
+
+    // The SCP reports the result over the same association once the request is acknowledged,
+    // committing only the first SOP Instance.
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").expect("Should bind");
+    let port: u16 = listener.local_addr().expect("Should have address").port();
+    let handle = thread::spawn(move || -> Result<(), DimseError> {
+        let (mut stream, _addr) = listener.accept()?;
+        let mut assoc: Association = AssociationBuilder::default()
+            .ae_title(SCP_AE)
+            .abstract_syntax(stgcmt_uid)
+            .accept(&mut stream)?;
+        let rq: Message = assoc.read_message(&mut stream)?.expect("Should request");
+        let request: CommitmentRequest = receive_commitment_request(&assoc, rq, &mut stream)?;
+        let [committed, failed] = request.references() else {
+            panic!("Should reference two instances");
+        };
+        let result: CommitmentResult = CommitmentResult::new(request.transaction_uid())
+            .committed(committed.clone())
+            .failed(failed.clone(), failure_reason::NO_SUCH_OBJECT_INSTANCE);
+        send_commitment_result(&mut assoc, &mut stream, &result)?;
+        assert!(assoc.read_message(&mut stream)?.is_none());
+        Ok(())
+    });
+
+    let mut stream: TcpStream = TcpStream::connect(("127.0.0.1", port))?;
+    let mut assoc: Association = AssociationBuilder::default()
+        .ae_title(SCU_AE)
+        .abstract_syntax(stgcmt_uid)
+        .request(SCP_AE, &mut stream)?;
+    let references: Vec<SopReference> = vec![
+        SopReference::new(ct_uid, "1.2.3.4.5.1"),
+        SopReference::new(ct_uid, "1.2.3.4.5.2"),
+    ];
+    request_commitment(
+        &mut assoc,
+        &mut stream,
+        &CommitmentRequest::new(TRANSACTION_UID, references.clone()),
+    )?;
+
+    let report: Message = assoc.read_message(&mut stream)?.expect("Should report");
+    assert_eq!(Some(2), report.event_type_id());
+    let result: CommitmentResult = receive_commitment_result(&assoc, report, &mut stream)?;
+    assert_eq!(TRANSACTION_UID, result.transaction_uid());
+    assert!(!result.is_all_committed());
+    assert_eq!(&references[..1], result.committed_references());
+    assert_eq!(
+        &[(
+            references[1].clone(),
+            failure_reason::NO_SUCH_OBJECT_INSTANCE
+        )],
+        result.failed_references()
+    );
+
+    assoc.release(&mut stream)?;
+    handle.join().expect("SCP should not panic")
+}
+
+#[test]
+fn test_validate_query_identifier() {
+    assert_eq!(
+        Some(QueryModel::StudyRoot),
+        QueryModel::from_sop_class(uids::StudyRootQueryRetrieveInformationModelMOVE.uid())
+    );
+
+    let identifier: QueryIdentifier = QueryIdentifier::validate(
+        QueryModel::StudyRoot,
+        &query_identifier(&[
+            (&tags::QueryRetrieveLevel, &vr::CS, "SERIES"),
+            (&tags::SpecificCharacterSet, &vr::CS, "ISO_IR 100"),
+            (&tags::PatientsName, &vr::PN, "DOE^*"),
+            (&tags::StudyInstanceUID, &vr::UI, "1.2.3"),
+            (&tags::Modality, &vr::CS, "CT\\MR"),
+            (&tags::SeriesDescription, &vr::LO, ""),
+        ]),
+    )
+    .expect("Identifier should be valid");
+    assert_eq!(QueryLevel::Series, identifier.level());
+    // The unique key of the query level is added to be returned.
+    let keys: Vec<u32> = identifier.keys().iter().map(|key| key.tag()).collect();
+    assert_eq!(
+        vec![
+            tags::Modality.tag,
+            tags::SeriesDescription.tag,
+            tags::PatientsName.tag,
+            tags::StudyInstanceUID.tag,
+            tags::SeriesInstanceUID.tag,
+        ],
+        keys
+    );
+    let matching: Vec<&Matching> = identifier
+        .matching_keys()
+        .map(|key| key.matcher().matching())
+        .collect();
+    assert_eq!(
+        vec![
+            &Matching::List(vec![
+                Matching::Single("CT".to_owned()),
+                Matching::Single("MR".to_owned())
+            ]),
+            &Matching::Wildcard("DOE^*".to_owned()),
+            &Matching::Single("1.2.3".to_owned()),
+        ],
+        matching
+    );
+
+    let errors: Vec<QueryKeyError> = QueryIdentifier::validate(
+        QueryModel::PatientRoot,
+        &query_identifier(&[
+            (&tags::QueryRetrieveLevel, &vr::CS, "STUDY"),
+            (&tags::PatientID, &vr::LO, "ID*"),
+            (&tags::StudyInstanceUID, &vr::UI, "1.2.*"),
+            (&tags::Modality, &vr::CS, "CT"),
+        ]),
+    )
+    .expect_err("Identifier should be invalid");
+    assert_eq!(
+        vec![
+            QueryKeyError::KeyBelowLevel {
+                key: "Modality".to_owned(),
+                key_level: QueryLevel::Series,
+                level: QueryLevel::Study,
+            },
+            QueryKeyError::UniqueKeyNotSingle {
+                key: "PatientID".to_owned(),
+                level: QueryLevel::Patient,
+                value: "ID*".to_owned(),
+            },
+        ],
+        errors
+    );
+    assert_eq!(
+        "Modality is a key of the SERIES level, below the query level of STUDY",
+        errors[0].to_string()
+    );
+
+    let errors: Vec<QueryKeyError> = QueryIdentifier::validate(
+        QueryModel::StudyRoot,
+        &query_identifier(&[
+            (&tags::QueryRetrieveLevel, &vr::CS, "IMAGE"),
+            (&tags::SeriesInstanceUID, &vr::UI, "1.2.3.4\\1.2.3.5"),
+            (&tags::SOPInstanceUID, &vr::UI, "1.2.3.4.*"),
+        ]),
+    )
+    .expect_err("Identifier should be invalid");
+    assert_eq!(
+        vec![
+            QueryKeyError::MissingUniqueKey {
+                key: "StudyInstanceUID".to_owned(),
+                level: QueryLevel::Study,
+            },
+            QueryKeyError::UniqueKeyNotSingle {
+                key: "SeriesInstanceUID".to_owned(),
+                level: QueryLevel::Series,
+                value: "1.2.3.4\\1.2.3.5".to_owned(),
+            },
+        ],
+        errors
+    );
+
+    let errors: Vec<QueryKeyError> = QueryIdentifier::validate(
+        QueryModel::StudyRoot,
+        &query_identifier(&[(&tags::QueryRetrieveLevel, &vr::CS, "PATIENT")]),
+    )
+    .expect_err("Identifier should be invalid");
+    assert_eq!(
+        vec![QueryKeyError::LevelNotInModel {
+            level: QueryLevel::Patient,
+            model: QueryModel::StudyRoot,
+        }],
+        errors
+    );
+}
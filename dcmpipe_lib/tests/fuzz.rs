@@ -0,0 +1,74 @@
+use arbitrary::{Arbitrary, Unstructured};
+use dcmpipe_lib::{
+    core::dcmobject::DicomRoot,
+    dict::tags,
+    fuzz::{check_roundtrip, parse_all, parse_from_bytes, write_to_bytes, ArbitraryDataset},
+    testkit::Fixture,
+};
+
+/// Deterministic bytes for generating inputs, from a linear congruential generator.
+fn pseudo_random_bytes(seed: u64, len: usize) -> Vec<u8> {
+    let mut state: u64 = seed;
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (state >> 33) as u8
+        })
+        .collect()
+}
+
+/// Checks that truncated and corrupted fixtures fail to parse without panicking.
+#[test]
+fn test_parse_corrupt_fixtures() {
+    for fixture in Fixture::ALL {
+        let bytes: Vec<u8> = fixture.to_bytes().expect("Fixture should be writable");
+        for len in 0..bytes.len() {
+            parse_all(&bytes[..len]);
+        }
+
+        let noise: Vec<u8> = pseudo_random_bytes(bytes.len() as u64, 3000);
+        for corruption in noise.chunks_exact(3) {
+            let mut corrupted: Vec<u8> = bytes.clone();
+            let index: usize = usize::from(u16::from_le_bytes([corruption[0], corruption[1]]));
+            corrupted[index % bytes.len()] = corruption[2];
+            parse_all(&corrupted);
+        }
+    }
+}
+
+/// Checks that fixtures round-trip through the fuzzing entry points.
+#[test]
+fn test_fixture_roundtrip() {
+    for fixture in Fixture::ALL {
+        let bytes: Vec<u8> = fixture.to_bytes().expect("Fixture should be writable");
+        let dcmroot: DicomRoot<'_> = parse_from_bytes(&bytes)
+            .expect("Fixture should parse")
+            .expect("Fixture should be DICOM");
+        assert!(write_to_bytes(&dcmroot).is_ok());
+        check_roundtrip(&dcmroot).expect("Fixture should round-trip");
+    }
+}
+
+/// Checks that generated datasets round-trip, and that generation covers sequences.
+#[test]
+fn test_arbitrary_roundtrip() {
+    let mut with_sequences: usize = 0;
+    for seed in 0..200 {
+        let data: Vec<u8> = pseudo_random_bytes(seed, 4096);
+        let dataset: ArbitraryDataset = ArbitraryDataset::arbitrary(&mut Unstructured::new(&data))
+            .expect("Dataset should be generated");
+        if let Err(e) = check_roundtrip(&dataset.0) {
+            panic!("seed {seed} failed to round-trip: {e}\n{dataset:?}");
+        }
+        if dataset
+            .0
+            .get_child_by_tag(tags::ReferencedImageSequence.tag)
+            .is_some_and(|sq| sq.item_count() > 0)
+        {
+            with_sequences += 1;
+        }
+    }
+    assert!(with_sequences > 0);
+}
@@ -0,0 +1,98 @@
+use dcmpipe_lib::{
+    core::{
+        dcmelement::DicomElement,
+        dcmobject::DicomRoot,
+        defn::{tag::TagPath, vr},
+        hash::{ContentHash, DatasetHasher, HashScope},
+        values::RawValue,
+        write::{builder::WriterBuilder, error::WriteError},
+    },
+    dict::{tags, transfer_syntaxes as ts},
+    testkit::Fixture,
+};
+
+mod common;
+
+use common::{parse_bytes, write_transcoded};
+
+#[test]
+fn test_hash_is_stable() -> Result<(), WriteError> {
+    for fixture in Fixture::ALL {
+        let first: ContentHash =
+            DatasetHasher::new(HashScope::Full).hash(&parse_bytes(fixture.to_bytes()?)?)?;
+        let second: ContentHash =
+            DatasetHasher::new(HashScope::Full).hash(&parse_bytes(fixture.to_bytes()?)?)?;
+        assert_eq!(first, second);
+        assert_eq!(64, first.to_string().len());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_normalized_hash_ignores_transfer_syntax() -> Result<(), WriteError> {
+    for fixture in Fixture::ALL {
+        let dcmroot: DicomRoot<'_> = parse_bytes(fixture.to_bytes()?)?;
+        let hasher: DatasetHasher = DatasetHasher::new(HashScope::Full);
+        let normalized: DatasetHasher = DatasetHasher::new(HashScope::Full).normalize(true);
+        for to in [
+            &ts::ImplicitVRLittleEndian,
+            &ts::ExplicitVRLittleEndian,
+            &ts::ExplicitVRBigEndian,
+        ] {
+            if to.uid() == fixture.ts().uid() {
+                continue;
+            }
+            let transcoded: DicomRoot<'_> = parse_bytes(write_transcoded(&dcmroot, to)?)?;
+            assert_ne!(hasher.hash(&dcmroot)?, hasher.hash(&transcoded)?);
+            assert_eq!(
+                normalized.hash(&dcmroot)?,
+                normalized.hash(&transcoded)?,
+                "{} to {:?}",
+                fixture.name(),
+                to.uid().ident()
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_hash_scopes() -> Result<(), WriteError> {
+    let mut dcmroot: DicomRoot<'_> = parse_bytes(Fixture::UndefinedLengthSequence.to_bytes()?)?;
+    let pixel_data: DicomElement = WriterBuilder::default()
+        .ts(dcmroot.ts())
+        .build(Vec::new())
+        .create_element(
+            tags::PixelData.tag,
+            &vr::OW,
+            RawValue::Words(vec![0, 1, 2, 3]),
+        )?;
+    let pixel_data_path: TagPath = TagPath::from(&tags::PixelData);
+    dcmroot.insert_element(&pixel_data_path, pixel_data, "test")?;
+
+    let header: DatasetHasher = DatasetHasher::new(HashScope::Header);
+    let pixels: DatasetHasher = DatasetHasher::new(HashScope::PixelData);
+    let header_hash: ContentHash = header.hash(&dcmroot)?;
+    let pixels_hash: ContentHash = pixels.hash(&dcmroot)?;
+
+    // Changing the Pixel Data only changes the hash of the Pixel Data.
+    dcmroot
+        .modify_element(&pixel_data_path, "test", |element| {
+            element.encode_value(RawValue::Words(vec![3, 2, 1, 0]), None)
+        })
+        .expect("PixelData should exist")?;
+    assert_eq!(header_hash, header.hash(&dcmroot)?);
+    assert_ne!(pixels_hash, pixels.hash(&dcmroot)?);
+
+    // Changing the header only changes the hash of the header.
+    let pixels_hash: ContentHash = pixels.hash(&dcmroot)?;
+    let sop_uid_path: TagPath = TagPath::from(&tags::SOPInstanceUID);
+    dcmroot
+        .modify_element(&sop_uid_path, "test", |element| {
+            element.encode_value(RawValue::Uid("1.2.3.4".to_owned()), None)
+        })
+        .expect("SOPInstanceUID should exist")?;
+    assert_ne!(header_hash, header.hash(&dcmroot)?);
+    assert_eq!(pixels_hash, pixels.hash(&dcmroot)?);
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use dcmpipe_lib::{
+    core::{dcmobject::DicomRoot, read::ParserBuilder},
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags},
+    ingest::{
+        watcher::{WatchEvent, Watcher},
+        SinkError,
+    },
+    testkit::Fixture,
+};
+
+/// Creates an empty folder for a test to watch.
+fn watch_folder(name: &str) -> PathBuf {
+    let folder: PathBuf = std::env::temp_dir().join(format!("dcmpipe_ingest_{name}"));
+    let _ = fs::remove_dir_all(&folder);
+    fs::create_dir_all(&folder).expect("Watch folder should be created");
+    folder
+}
+
+fn ingested(events: &[WatchEvent]) -> Vec<&Path> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            WatchEvent::Ingested(path) => Some(path.as_path()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_watcher_ingests_arrived_files() {
+    let folder: PathBuf = watch_folder("arrived");
+    fs::write(folder.join("existing.dcm"), b"not dicom").expect("File should be written");
+
+    let mut sop_uids: Vec<String> = Vec::new();
+    let mut sink = |_path: &Path, dcmroot: &DicomRoot<'_>| -> Result<(), SinkError> {
+        let sop_uid: String = dcmroot
+            .get_child_by_tag(tags::SOPInstanceUID.tag)
+            .ok_or("missing SOPInstanceUID")?
+            .element()
+            .try_into()?;
+        sop_uids.push(sop_uid);
+        Ok(())
+    };
+    let mut watcher: Watcher<'_> = Watcher::new(&folder)
+        .parser_builder(ParserBuilder::default().dictionary(&STANDARD_DICOM_DICTIONARY));
+
+    // Files present before the first poll are ignored.
+    assert!(watcher.poll(&mut sink).expect("Poll").is_empty());
+
+    let mut paths: Vec<PathBuf> = Vec::new();
+    for fixture in Fixture::ALL {
+        let path: PathBuf = folder
+            .join(fixture.name())
+            .join(format!("{}.dcm", fixture.name()));
+        fs::create_dir_all(path.parent().expect("Parent folder")).expect("Folder");
+        fs::write(
+            &path,
+            fixture.to_bytes().expect("Fixture should be writable"),
+        )
+        .expect("File should be written");
+        paths.push(path);
+    }
+    fs::write(folder.join(".partial.dcm"), b"hidden").expect("File should be written");
+    fs::write(folder.join("notes.txt"), b"not dicom").expect("File should be written");
+
+    // Arrived files are only ingested once they're unchanged between polls.
+    assert!(watcher.poll(&mut sink).expect("Poll").is_empty());
+    let events: Vec<WatchEvent> = watcher.poll(&mut sink).expect("Poll");
+    paths.sort();
+    assert_eq!(paths, ingested(&events));
+    assert!(events.iter().any(|event| matches!(event,
+        WatchEvent::NotDicom(path) if path.ends_with("notes.txt"))));
+    assert_eq!(paths.len() + 1, events.len());
+
+    // Each file is ingested once, until it's modified.
+    assert!(watcher.poll(&mut sink).expect("Poll").is_empty());
+    OpenOptions::new()
+        .append(true)
+        .open(&paths[0])
+        .and_then(|mut file| file.write_all(&[0u8; 4]))
+        .expect("File should be appended to");
+    assert!(watcher.poll(&mut sink).expect("Poll").is_empty());
+    let events: Vec<WatchEvent> = watcher.poll(&mut sink).expect("Poll");
+    assert_eq!(vec![paths[0].as_path()], ingested(&events));
+
+    let expected: Vec<&str> = Fixture::ALL
+        .iter()
+        .map(|fixture| fixture.sop_instance_uid())
+        .collect();
+    assert_eq!(Fixture::ALL.len() + 1, sop_uids.len());
+    for sop_uid in expected {
+        assert!(sop_uids.iter().any(|ingested| ingested == sop_uid));
+    }
+
+    fs::remove_dir_all(&folder).expect("Watch folder should be removed");
+}
+
+#[test]
+fn test_watcher_ingest_existing() {
+    let folder: PathBuf = watch_folder("existing");
+    let fixture: Fixture = Fixture::UndefinedLengthSequence;
+    let path: PathBuf = folder.join("existing.dcm");
+    fs::write(
+        &path,
+        fixture.to_bytes().expect("Fixture should be writable"),
+    )
+    .expect("File should be written");
+
+    let mut failing_sink = |_path: &Path, _dcmroot: &DicomRoot<'_>| -> Result<(), SinkError> {
+        Err("rejected".into())
+    };
+    let mut watcher: Watcher<'_> = Watcher::new(&folder).ingest_existing(true);
+    assert!(watcher.poll(&mut failing_sink).expect("Poll").is_empty());
+    let events: Vec<WatchEvent> = watcher.poll(&mut failing_sink).expect("Poll");
+    assert!(matches!(events.as_slice(), [WatchEvent::Failed(failed, _)] if failed == &path));
+
+    // Failed files aren't retried unless they're modified, however they're forgotten once removed.
+    assert!(watcher.poll(&mut failing_sink).expect("Poll").is_empty());
+    fs::remove_file(&path).expect("File should be removed");
+    assert!(watcher.poll(&mut failing_sink).expect("Poll").is_empty());
+
+    fs::remove_dir_all(&folder).expect("Watch folder should be removed");
+}
@@ -0,0 +1,176 @@
+use dcmpipe_lib::{
+    core::{
+        dcmobject::DicomRoot,
+        defn::{constants, tag::Tag},
+        integrity::{IntegrityIssue, ReferenceChecker, ReferenceKind},
+        read::ParseResult,
+        repr::{DatasetRepr, ElementRepr},
+        values::RawValue,
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags, transfer_syntaxes as ts, uids},
+};
+
+const CT: &str = "1.2.840.10008.5.1.4.1.1.2";
+const MR: &str = "1.2.840.10008.5.1.4.1.1.4";
+
+fn element(tag: &Tag, vr: &str, value: Option<RawValue>) -> ElementRepr {
+    ElementRepr {
+        tag: tag.tag,
+        vr: vr.to_string(),
+        value,
+        undefined_length: false,
+        elements: Vec::new(),
+        items: Vec::new(),
+    }
+}
+
+fn uid(tag: &Tag, uid: &str) -> ElementRepr {
+    element(tag, "UI", Some(RawValue::Uid(uid.to_string())))
+}
+
+fn seq(tag: &Tag, items: Vec<Vec<ElementRepr>>) -> ElementRepr {
+    let mut seq: ElementRepr = element(tag, "SQ", None);
+    seq.items = items
+        .into_iter()
+        .map(|elements| ElementRepr {
+            tag: constants::tags::ITEM,
+            vr: "UN".to_string(),
+            value: None,
+            undefined_length: false,
+            elements,
+            items: Vec::new(),
+        })
+        .collect();
+    seq
+}
+
+/// A reference to an instance, as in the Contour Image Sequence.
+fn image_ref(sop_class: &str, sop_instance: &str) -> Vec<ElementRepr> {
+    vec![
+        uid(&tags::ReferencedSOPClassUID, sop_class),
+        uid(&tags::ReferencedSOPInstanceUID, sop_instance),
+    ]
+}
+
+fn dataset(elements: Vec<ElementRepr>) -> DicomRoot<'static> {
+    DatasetRepr {
+        ts: ts::ExplicitVRLittleEndian.uid().uid().to_string(),
+        cs: "windows-1252".to_string(),
+        elements,
+    }
+    .to_dcmroot(&STANDARD_DICOM_DICTIONARY)
+    .expect("Dataset should be created")
+}
+
+fn image(sop_instance: &str, frame: &str) -> DicomRoot<'static> {
+    dataset(vec![
+        uid(&tags::SOPClassUID, CT),
+        uid(&tags::SOPInstanceUID, sop_instance),
+        uid(&tags::StudyInstanceUID, "1.1"),
+        uid(&tags::SeriesInstanceUID, "1.1.10"),
+        uid(&tags::FrameofReferenceUID, frame),
+    ])
+}
+
+#[test]
+fn test_reference_checker() -> ParseResult<()> {
+    let structure_set: DicomRoot<'_> = dataset(vec![
+        uid(&tags::SOPClassUID, uids::RTStructureSetStorage.uid()),
+        uid(&tags::SOPInstanceUID, "1.1.20.1"),
+        uid(&tags::StudyInstanceUID, "1.1"),
+        uid(&tags::SeriesInstanceUID, "1.1.20"),
+        seq(
+            &tags::ReferencedFrameofReferenceSequence,
+            vec![vec![
+                uid(&tags::FrameofReferenceUID, "1.100"),
+                seq(
+                    &tags::RTReferencedStudySequence,
+                    vec![vec![
+                        uid(&tags::ReferencedSOPClassUID, "1.2.840.10008.3.1.2.3.1"),
+                        uid(&tags::ReferencedSOPInstanceUID, "1.1"),
+                        seq(
+                            &tags::RTReferencedSeriesSequence,
+                            vec![vec![
+                                uid(&tags::SeriesInstanceUID, "1.1.10"),
+                                seq(
+                                    &tags::ContourImageSequence,
+                                    vec![
+                                        image_ref(CT, "1.1.10.1"),
+                                        image_ref(CT, "1.1.10.2"),
+                                        image_ref(MR, "1.1.10.1"),
+                                        image_ref(CT, "1.1.10.9"),
+                                    ],
+                                ),
+                            ]],
+                        ),
+                    ]],
+                ),
+            ]],
+        ),
+        seq(
+            &tags::StructureSetROISequence,
+            vec![vec![uid(&tags::ReferencedFrameofReferenceUID, "1.999")]],
+        ),
+    ]);
+
+    let mut checker: ReferenceChecker = ReferenceChecker::default();
+    checker.add("ct1.dcm", &image("1.1.10.1", "1.100"))?;
+    checker.add("ct2.dcm", &image("1.1.10.2", "1.101"))?;
+    checker.add("rtstruct.dcm", &structure_set)?;
+    checker.add("copy.dcm", &image("1.1.10.1", "1.100"))?;
+    assert_eq!(3, checker.instance_count());
+    assert_eq!(8, checker.reference_count());
+
+    let contour_path = |index: usize| {
+        format!(
+            "ReferencedFrameofReferenceSequence[1].RTReferencedStudySequence[1]\
+            .RTReferencedSeriesSequence[1].ContourImageSequence[{index}].ReferencedSOPInstanceUID"
+        )
+    };
+    let issues: Vec<IntegrityIssue> = checker.check();
+    assert_eq!(
+        vec![
+            IntegrityIssue::FrameOfReferenceMismatch {
+                source: "rtstruct.dcm".to_string(),
+                path: contour_path(2),
+                uid: "1.1.10.2".to_string(),
+                expected: "1.100".to_string(),
+                actual: "1.101".to_string(),
+            },
+            IntegrityIssue::SOPClassMismatch {
+                source: "rtstruct.dcm".to_string(),
+                path: contour_path(3),
+                uid: "1.1.10.1".to_string(),
+                referenced: MR.to_string(),
+                actual: CT.to_string(),
+            },
+            IntegrityIssue::Dangling {
+                source: "rtstruct.dcm".to_string(),
+                path: contour_path(4),
+                kind: ReferenceKind::Instance,
+                uid: "1.1.10.9".to_string(),
+            },
+            IntegrityIssue::Dangling {
+                source: "rtstruct.dcm".to_string(),
+                path: "StructureSetROISequence[1].ReferencedFrameofReferenceUID".to_string(),
+                kind: ReferenceKind::FrameOfReference,
+                uid: "1.999".to_string(),
+            },
+            IntegrityIssue::InconsistentFrameOfReference {
+                series: "1.1.10".to_string(),
+                frames: vec!["1.100".to_string(), "1.101".to_string()],
+            },
+            IntegrityIssue::DuplicateInstance {
+                uid: "1.1.10.1".to_string(),
+                sources: vec!["ct1.dcm".to_string(), "copy.dcm".to_string()],
+            },
+        ],
+        issues
+    );
+    assert_eq!(
+        "rtstruct.dcm: StructureSetROISequence[1].ReferencedFrameofReferenceUID refers to \
+        unknown frame of reference 1.999",
+        issues[3].to_string()
+    );
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use dcmpipe_lib::{
+    core::{
+        charset::{self, CSRef},
+        dcmelement::DicomElement,
+        defn::{constants::ts::ExplicitVRLittleEndian, vl::ValueLength, vr},
+        matching::{wildcard_match, Matcher, Matching},
+        read::ParseResult,
+        values::RawValue,
+    },
+    dict::tags,
+};
+
+#[test]
+fn test_matching_kinds() {
+    assert_eq!(&Matching::Universal, Matcher::parse(&vr::LO, "").matching());
+    assert_eq!(
+        &Matching::Universal,
+        Matcher::parse(&vr::LO, "*").matching()
+    );
+    assert_eq!(
+        &Matching::Single("*".to_string()),
+        Matcher::parse(&vr::UI, "*").matching()
+    );
+    assert_eq!(
+        &Matching::Wildcard("CT*".to_string()),
+        Matcher::parse(&vr::CS, " CT* ").matching()
+    );
+    assert_eq!(
+        &Matching::Single("1.2-3".to_string()),
+        Matcher::parse(&vr::SH, "1.2-3").matching()
+    );
+    assert_eq!(
+        &Matching::Range(None, Some("20240131".to_string())),
+        Matcher::parse(&vr::DA, "-20240131").matching()
+    );
+    assert_eq!(
+        &Matching::List(vec![
+            Matching::Single("1.2.3".to_string()),
+            Matching::Single("1.2.4".to_string()),
+        ]),
+        Matcher::parse(&vr::UI, "1.2.3\\1.2.4").matching()
+    );
+    // A negative offset from UTC isn't the separator of a range.
+    assert_eq!(
+        &Matching::Range(
+            Some("20240101120000-0500".to_string()),
+            Some("20240102".to_string())
+        ),
+        Matcher::parse(&vr::DT, "20240101120000-0500-20240102").matching()
+    );
+    assert_eq!(
+        &Matching::Single("20240101120000-0500".to_string()),
+        Matcher::parse(&vr::DT, "20240101120000-0500").matching()
+    );
+}
+
+#[test]
+fn test_matching_values() {
+    assert!(wildcard_match("*A?C*", "xxAbCyy"));
+    assert!(wildcard_match("A**", "A"));
+    assert!(!wildcard_match("A?", "A"));
+    assert!(!wildcard_match("a*", "ABC"));
+
+    let names: Matcher = Matcher::parse(&vr::PN, "doe^j*");
+    assert!(names.is_case_insensitive());
+    assert!(names.matches("DOE^JOHN"));
+    assert!(names.matches("Doe^J"));
+    assert!(!names.matches("DOE^MARY"));
+    assert!(Matcher::parse(&vr::PN, "DOE^JOHN").matches("doe^john^^^"));
+    assert!(Matcher::parse(&vr::PN, "山田^太郎").matches("Yamada^Tarou=山田^太郎=やまだ^たろう"));
+
+    let codes: Matcher = Matcher::parse(&vr::CS, "CT");
+    assert!(codes.matches("CT "));
+    assert!(!codes.matches("ct"));
+    assert!(codes.matches_any(&["MR", "CT"]));
+    assert!(!codes.matches_any::<&str>(&[]));
+    assert!(Matcher::parse(&vr::CS, "").matches_any::<&str>(&[]));
+
+    let dates: Matcher = Matcher::parse(&vr::DA, "20240101-20240131");
+    assert!(dates.matches("20240101"));
+    assert!(dates.matches("2024.01.31"));
+    assert!(!dates.matches("20240201"));
+    assert!(!dates.matches(""));
+
+    let times: Matcher = Matcher::parse(&vr::TM, "0900-0930");
+    assert!(times.matches("09"));
+    assert!(times.matches("093059.5"));
+    assert!(times.matches("09:15"));
+    assert!(!times.matches("0931"));
+    assert!(!times.matches("085959"));
+
+    let datetimes: Matcher = Matcher::parse(&vr::DT, "2024-20240615");
+    assert!(datetimes.matches("20240615235959+0100"));
+    assert!(!datetimes.matches("2023123123"));
+    assert!(!datetimes.matches("20240616"));
+
+    let uids: Matcher = Matcher::parse(&vr::UI, "1.2.3\\1.2.4");
+    assert!(uids.matches("1.2.4\0"));
+    assert!(!uids.matches("1.2.5"));
+
+    let list: Matcher = Matcher::parse(&vr::LO, "A*\\B");
+    assert!(list.matches("ABC"));
+    assert!(list.matches("B"));
+    assert!(!list.matches("BC"));
+}
+
+#[test]
+fn test_matching_elements_across_charsets() -> ParseResult<()> {
+    let element = |cs: CSRef, name: &str| -> ParseResult<DicomElement<'static>> {
+        let mut element: DicomElement<'static> = DicomElement::new(
+            &tags::PatientsName,
+            &vr::PN,
+            ValueLength::Explicit(0),
+            &ExplicitVRLittleEndian,
+            cs,
+            Vec::new(),
+            Vec::new(),
+        );
+        element.encode_value(RawValue::Strings(vec![name.to_string()]), None)?;
+        Ok(element)
+    };
+    let latin1: CSRef = charset::lookup_charset("ISO_IR 100").expect("Latin-1 is supported");
+    let utf8: CSRef = charset::UTF8_CHARACTER_SET;
+
+    let key: Matcher = Matcher::from_element(&element(latin1, "MÜLLER^*")?)?;
+    assert_eq!(&Matching::Wildcard("MÜLLER^*".to_string()), key.matching());
+    assert!(key.matches_element(&element(utf8, "Müller^Anna")?)?);
+    assert!(!key.matches_element(&element(utf8, "Muller^Anna")?)?);
+
+    let universal: Matcher = Matcher::from_element(&element(utf8, "")?)?;
+    assert!(universal.is_universal());
+    assert!(universal.matches_element(&element(latin1, "")?)?);
+    Ok(())
+}
@@ -1,3 +1,10 @@
+// These tests predate some of the current clippy lints.
+#![allow(
+    clippy::bool_assert_comparison,
+    clippy::skip_while_next,
+    clippy::while_let_on_iterator
+)]
+
 use std::{
     convert::{TryFrom, TryInto},
     fs::File,
@@ -7,23 +14,33 @@ use std::{
 use dcmpipe_lib::{
     self,
     core::{
+        charset::DEFAULT_CHARACTER_SET,
         dcmelement::DicomElement,
         dcmobject::{DicomObject, DicomRoot},
         defn::{
             constants::lookup::MINIMAL_DICOM_DICTIONARY,
             dcmdict::DicomDictionary,
             tag::{Tag, TagNode, TagPath},
+            ts::{Compression, TSRef, TransferSyntax},
+            uid::{UIDType, UID},
             vl::ValueLength,
             vr,
         },
-        read::{stop::ParseStop, ParseError, ParseResult, Parser, ParserBuilder, ParserState},
+        padding::{PaddingIssue, PaddingPolicy},
+        read::{
+            behavior::VRMismatchPolicy, observer::ParseStats, stop::ParseStop,
+            warning::ParseWarning, ParseError, ParseResult, Parser, ParserBuilder, ParserState,
+        },
         values::{ElementWithVr, RawValue},
+        write::{builder::WriterBuilder, writer::Writer},
     },
     dict::{
         stdlookup::STANDARD_DICOM_DICTIONARY,
         tags::{self},
         transfer_syntaxes as ts, uids,
     },
+    error::{DicomError, ErrorKind as DicomErrorKind},
+    testkit::Fixture,
 };
 
 mod common;
@@ -31,7 +48,9 @@ mod common;
 use common::{
     fixture, is_standard_dcm_file,
     mock::MockDicomDataset,
-    mockdata::{INVALID_VR_ELEMENT, NULL_ELEMENT, STANDARD_HEADER},
+    mockdata::{
+        INVALID_VR_ELEMENT, NULL_ELEMENT, PADDING_ELEMENTS, STANDARD_HEADER, VR_MISMATCH_ELEMENTS,
+    },
     parse_all_dcmroot_values, parse_all_dicom_files, parse_file,
 };
 
@@ -74,7 +93,7 @@ fn test_bad_dicom_prefix_parser() {
 
     let parse_error: ParseError = result.err().unwrap();
     match parse_error {
-        ParseError::DetailedError { source, detail } => match *source {
+        ParseError::DetailedError { source, detail, .. } => match *source {
             ParseError::BadDICOMPrefix([68, 79, 67, 77]) => {}
             _ => panic!("{:?}", detail),
         },
@@ -93,6 +112,33 @@ fn test_invalid_dicom_prefix_is_none() {
     assert!(parse_result.is_none());
 }
 
+/// Test that parse errors are classified when converted to a `DicomError`, and carry where in the
+/// dataset they occurred.
+#[test]
+fn test_dicom_error_kinds() {
+    let mut parser: Parser<'_, MockDicomDataset> = MockDicomDataset::invalid_dicom_prefix();
+    let err: DicomError = parser
+        .next()
+        .expect("Should have returned Some(Err(_))")
+        .expect_err("Should have failed")
+        .into();
+    assert_eq!(DicomErrorKind::NotDicom, err.kind());
+
+    let mut bytes: Vec<u8> = Fixture::ALL[0]
+        .to_bytes()
+        .expect("Fixture should be written");
+    bytes.truncate(bytes.len() - 1);
+    let err: DicomError = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .build(Cursor::new(bytes))
+        .collect::<ParseResult<Vec<DicomElement>>>()
+        .expect_err("Truncated dataset should fail")
+        .into();
+    assert_eq!(DicomErrorKind::Truncated, err.kind());
+    assert!(err.offset().is_some_and(|offset| offset > 0));
+    assert!(err.tagpath().is_some_and(|tagpath| !tagpath.is_empty()));
+}
+
 #[test]
 #[should_panic(expected = "failed to fill whole buffer")]
 fn test_failure_to_read_preamble() {
@@ -131,6 +177,457 @@ fn test_unknown_explicit_vr_parses_as_invalid() {
     assert_eq!(&vr::INVALID, first_elem.vr());
 }
 
+/// Parses the mock dataset whose elements have VRs differing from the dictionary's.
+fn parse_vr_mismatch(
+    policy: VRMismatchPolicy,
+) -> (ParseResult<Option<DicomRoot<'static>>>, Vec<ParseWarning>) {
+    let bytes: Vec<u8> = [STANDARD_HEADER, VR_MISMATCH_ELEMENTS].concat();
+    let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .vr_mismatch(policy)
+        .build(Cursor::new(bytes));
+    let dcmroot = DicomRoot::parse(&mut parser);
+    (dcmroot, parser.warnings().to_vec())
+}
+
+#[test]
+fn test_vr_mismatch_trust_file() -> ParseResult<()> {
+    let (dcmroot, warnings) = parse_vr_mismatch(VRMismatchPolicy::TrustFile);
+    let dcmroot: DicomRoot<'_> = dcmroot?.expect("Should parse");
+
+    let rows: &DicomElement = dcmroot
+        .get_child_by_tag(tags::Rows.tag)
+        .expect("Should have Rows")
+        .element();
+    assert_eq!(&vr::UL, rows.vr());
+    assert!(matches!(rows.parse_value()?, RawValue::UnsignedIntegers(values) if values == [512]));
+
+    let patient_id: &DicomElement = dcmroot
+        .get_child_by_tag(tags::PatientID.tag)
+        .expect("Should have PatientID")
+        .element();
+    assert_eq!(&vr::SH, patient_id.vr());
+
+    assert_eq!(2, warnings.len());
+    assert_eq!(
+        ParseWarning::VRMismatch {
+            tagpath: TagPath::from(vec![TagNode::from(tags::Rows.tag)]),
+            offset: u64::try_from(STANDARD_HEADER.len() + 14).unwrap(),
+            file_vr: &vr::UL,
+            dictionary_vr: &vr::US,
+            used_vr: &vr::UL,
+        },
+        warnings[1]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_vr_mismatch_trust_dictionary() -> ParseResult<()> {
+    let (dcmroot, warnings) = parse_vr_mismatch(VRMismatchPolicy::TrustDictionary);
+    let dcmroot: DicomRoot<'_> = dcmroot?.expect("Should parse");
+
+    let rows: &DicomElement = dcmroot
+        .get_child_by_tag(tags::Rows.tag)
+        .expect("Should have Rows")
+        .element();
+    assert_eq!(&vr::US, rows.vr());
+    assert!(matches!(rows.parse_value()?, RawValue::UnsignedShorts(values) if values == [512, 0]));
+
+    let patient_id: &DicomElement = dcmroot
+        .get_child_by_tag(tags::PatientID.tag)
+        .expect("Should have PatientID")
+        .element();
+    assert_eq!(&vr::LO, patient_id.vr());
+    assert_eq!("ABC123", String::try_from(patient_id)?);
+
+    assert!(matches!(
+        warnings.as_slice(),
+        [
+            ParseWarning::VRMismatch { used_vr, .. },
+            ParseWarning::VRMismatch { used_vr: used_vr2, .. },
+        ] if *used_vr == &vr::LO && *used_vr2 == &vr::US
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_vr_mismatch_error() {
+    let (dcmroot, warnings) = parse_vr_mismatch(VRMismatchPolicy::Error);
+    let err: ParseError = dcmroot.expect_err("Should fail on the mismatched VR");
+    assert_eq!(DicomErrorKind::InvalidVR, DicomError::from(err).kind());
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_vr_mismatch_observed() -> ParseResult<()> {
+    let stats = ParseStats::shared();
+    let bytes: Vec<u8> = [STANDARD_HEADER, VR_MISMATCH_ELEMENTS].concat();
+    let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .observer(stats.clone())
+        .build(Cursor::new(bytes));
+    DicomRoot::parse(&mut parser)?.expect("Should parse");
+
+    // The minimal dictionary used by default doesn't define the elements, so has no mismatches.
+    let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+        .observer(stats.clone())
+        .build(Cursor::new(
+            [STANDARD_HEADER, VR_MISMATCH_ELEMENTS].concat(),
+        ));
+    DicomRoot::parse(&mut parser)?.expect("Should parse");
+    assert!(parser.warnings().is_empty());
+
+    let stats = stats.lock().expect("Stats");
+    assert_eq!(2, stats.warnings().len());
+    assert!(stats.warnings()[0]
+        .to_string()
+        .starts_with("vr SH of element (0010,0020)"));
+    Ok(())
+}
+
+/// Parses the mock dataset whose values are of odd length or padded with the wrong byte.
+fn parse_padding(
+    policy: PaddingPolicy,
+) -> (ParseResult<Option<DicomRoot<'static>>>, Vec<ParseWarning>) {
+    let bytes: Vec<u8> = [STANDARD_HEADER, PADDING_ELEMENTS].concat();
+    let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .padding(policy)
+        .build(Cursor::new(bytes));
+    let dcmroot = DicomRoot::parse(&mut parser);
+    (dcmroot, parser.warnings().to_vec())
+}
+
+#[test]
+fn test_padding_preserve() -> ParseResult<()> {
+    let (dcmroot, warnings) = parse_padding(PaddingPolicy::Preserve);
+    let dcmroot: DicomRoot<'_> = dcmroot?.expect("Should parse");
+    assert!(warnings.is_empty());
+
+    let get = |tag: u32| {
+        dcmroot
+            .get_child_by_tag(tag)
+            .expect("Should have element")
+            .element()
+    };
+    // Only the trailing nulls are padding, the null within the UID is retained.
+    assert_eq!(
+        "1.2.\u{0}3",
+        String::try_from(get(tags::SOPInstanceUID.tag))?
+    );
+    assert_eq!(ValueLength::Explicit(8), get(tags::SOPInstanceUID.tag).vl());
+    assert_eq!("Doe^J", String::try_from(get(tags::PatientsName.tag))?);
+    assert_eq!(ValueLength::Explicit(5), get(tags::PatientID.tag).vl());
+    assert_eq!("ABC12", String::try_from(get(tags::PatientID.tag))?);
+    Ok(())
+}
+
+#[test]
+fn test_padding_normalize() -> ParseResult<()> {
+    let (dcmroot, warnings) = parse_padding(PaddingPolicy::Normalize);
+    let dcmroot: DicomRoot<'_> = dcmroot?.expect("Should parse");
+
+    let get = |tag: u32| {
+        dcmroot
+            .get_child_by_tag(tag)
+            .expect("Should have element")
+            .element()
+    };
+    let uid: &DicomElement = get(tags::SOPInstanceUID.tag);
+    assert_eq!(b"1.2.\x003", uid.data());
+    assert_eq!(ValueLength::Explicit(6), uid.vl());
+    assert_eq!(b"Doe^J ", get(tags::PatientsName.tag).data());
+    let patient_id: &DicomElement = get(tags::PatientID.tag);
+    assert_eq!(b"ABC12 ", patient_id.data());
+    assert_eq!(ValueLength::Explicit(6), patient_id.vl());
+
+    let issues: Vec<PaddingIssue> = warnings
+        .iter()
+        .filter_map(|warning| match warning {
+            ParseWarning::Padding { issue, .. } => Some(*issue),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            PaddingIssue::ExtraNulls,
+            PaddingIssue::WrongPadding,
+            PaddingIssue::OddLength
+        ],
+        issues
+    );
+    Ok(())
+}
+
+#[test]
+fn test_padding_error() {
+    let (dcmroot, warnings) = parse_padding(PaddingPolicy::Error);
+    let err: ParseError = dcmroot.expect_err("Should fail on the padded UID");
+    assert_eq!(DicomErrorKind::InvalidValue, DicomError::from(err).kind());
+    assert!(warnings.is_empty());
+
+    // Values which are padded as the standard requires are accepted.
+    let bytes: Vec<u8> = [STANDARD_HEADER, VR_MISMATCH_ELEMENTS].concat();
+    let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+        .padding(PaddingPolicy::Error)
+        .build(Cursor::new(bytes));
+    assert!(DicomRoot::parse(&mut parser).is_ok());
+}
+
+/// A private transfer syntax, encoded as Deflated Explicit VR Little Endian.
+static PRIVATE_DEFLATED_UID: UID = UID {
+    ident: "PrivateDeflated",
+    uid: "1.2.3.4.5.6.7.8.9.10.1",
+    name: "Private Deflated",
+    uid_type: UIDType::TransferSyntax,
+};
+
+static PRIVATE_DEFLATED_TS: TransferSyntax = TransferSyntax {
+    uid: &PRIVATE_DEFLATED_UID,
+    explicit_vr: true,
+    big_endian: false,
+    deflated: true,
+    encapsulated: false,
+    compression: Compression::None,
+};
+
+#[test]
+fn test_unknown_ts_handler() -> ParseResult<()> {
+    // Declare the deflated fixture's transfer syntax with a private UID of the same length.
+    let standard_uid: &[u8] = uids::DeflatedExplicitVRLittleEndian.uid().as_bytes();
+    let private_uid: &[u8] = PRIVATE_DEFLATED_UID.uid().as_bytes();
+    assert_eq!(standard_uid.len(), private_uid.len());
+    let bytes: Vec<u8> = Fixture::DeflatedDataset
+        .to_bytes()
+        .expect("Fixture should be writable");
+    let mut private_bytes: Vec<u8> = bytes.clone();
+    let pos: usize = private_bytes
+        .windows(standard_uid.len())
+        .position(|window| window == standard_uid)
+        .expect("Fixture should declare its transfer syntax");
+    private_bytes[pos..pos + private_uid.len()].copy_from_slice(private_uid);
+
+    // A handler which doesn't recognize the UID only reports it, as the deflated contents can't be
+    // detected.
+    let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .unknown_ts_handler(|_uid| None)
+        .build(Cursor::new(private_bytes.clone()));
+    let _ = DicomRoot::parse(&mut parser);
+    assert!(matches!(
+        parser.warnings().first(),
+        Some(ParseWarning::UnknownTransferSyntax { uid, .. }) if uid == PRIVATE_DEFLATED_UID.uid()
+    ));
+
+    let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .unknown_ts_handler(|uid| {
+            (uid == PRIVATE_DEFLATED_UID.uid()).then_some(&PRIVATE_DEFLATED_TS)
+        })
+        .build(Cursor::new(private_bytes));
+    let private_root: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Should parse");
+    assert!(parser.warnings().is_empty());
+    assert_eq!(&PRIVATE_DEFLATED_TS, private_root.ts());
+
+    let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .build(Cursor::new(bytes));
+    let dcmroot: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Should parse");
+    let elements: Vec<(u32, Vec<u8>)> = dcmroot
+        .flatten()
+        .expect("Should flatten")
+        .into_iter()
+        .filter(|element| element.tag() != tags::TransferSyntaxUID.tag)
+        .map(|element| (element.tag(), element.data().to_vec()))
+        .collect();
+    let private_elements: Vec<(u32, Vec<u8>)> = private_root
+        .flatten()
+        .expect("Should flatten")
+        .into_iter()
+        .filter(|element| element.tag() != tags::TransferSyntaxUID.tag)
+        .map(|element| (element.tag(), element.data().to_vec()))
+        .collect();
+    assert_eq!(elements, private_elements);
+    Ok(())
+}
+
+/// Writes a dataset whose elements are encoded as `UN`, the contents of sequences being IVRLE: Rows,
+/// ReferencedImageSequence of explicit length, SourceImageSequence of undefined length, a private
+/// sequence, and a private element which isn't a sequence.
+fn write_un_dataset(ts: TSRef) -> Vec<u8> {
+    let un = |tag: u32, vl: ValueLength, data: Vec<u8>| {
+        DicomElement::new(
+            tag,
+            &vr::UN,
+            vl,
+            ts,
+            DEFAULT_CHARACTER_SET,
+            data,
+            Vec::new(),
+        )
+    };
+    let mut ts_uid: DicomElement = DicomElement::new_empty(
+        &tags::TransferSyntaxUID,
+        &vr::UI,
+        &ts::ExplicitVRLittleEndian,
+    );
+    ts_uid
+        .encode_value(RawValue::Uid(ts.uid().uid().to_owned()), None)
+        .expect("Encode transfer syntax");
+
+    #[rustfmt::skip]
+    let referenced_images: Vec<u8> = vec![
+        0xFE, 0xFF, 0x00, 0xE0, 0x0E, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x55, 0x11, 0x06, 0x00, 0x00, 0x00, b'1', b'.', b'2', b'.', b'3', 0x00,
+    ];
+    #[rustfmt::skip]
+    let source_images: Vec<u8> = vec![
+        0xFE, 0xFF, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF,
+        0x08, 0x00, 0x55, 0x11, 0x06, 0x00, 0x00, 0x00, b'1', b'.', b'2', b'.', b'4', 0x00,
+        0xFE, 0xFF, 0x0D, 0xE0, 0x00, 0x00, 0x00, 0x00,
+        0xFE, 0xFF, 0xDD, 0xE0, 0x00, 0x00, 0x00, 0x00,
+    ];
+    #[rustfmt::skip]
+    let private_seq: Vec<u8> = vec![
+        0xFE, 0xFF, 0x00, 0xE0, 0x0C, 0x00, 0x00, 0x00,
+        0x09, 0x00, 0x02, 0x10, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x02, 0x00,
+    ];
+    let rows: Vec<u8> = if ts.big_endian() {
+        512u16.to_be_bytes().to_vec()
+    } else {
+        512u16.to_le_bytes().to_vec()
+    };
+
+    let mut writer: Writer<Vec<u8>> = WriterBuilder::for_file().ts(ts).build(Vec::new());
+    let elements: Vec<DicomElement> = vec![
+        ts_uid,
+        un(
+            tags::ReferencedImageSequence.tag,
+            ValueLength::Explicit(22),
+            referenced_images,
+        ),
+        un(
+            tags::SourceImageSequence.tag,
+            ValueLength::UndefinedLength,
+            source_images,
+        ),
+        writer
+            .create_element(
+                0x0009_0010u32,
+                &vr::LO,
+                RawValue::Strings(vec!["DCMPIPE".to_string()]),
+            )
+            .expect("Create private creator"),
+        un(0x0009_1001, ValueLength::Explicit(20), private_seq),
+        un(0x0009_1003, ValueLength::Explicit(8), (1..=8).collect()),
+        un(tags::Rows.tag, ValueLength::Explicit(2), rows),
+    ];
+    writer
+        .write_elements(elements.iter())
+        .expect("Write dataset");
+    writer.into_dataset().expect("Dataset")
+}
+
+fn parse_un_dataset(bytes: &[u8], resolve_un: bool) -> ParseResult<DicomRoot<'static>> {
+    let mut parser: Parser<'_, &[u8]> = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .resolve_un(resolve_un)
+        .build(bytes);
+    Ok(DicomRoot::parse(&mut parser)?.expect("Should parse"))
+}
+
+/// The only element of the first item of the sequence.
+fn first_item_element<'o, 'a>(dcmroot: &'o DicomRoot<'a>, tag: u32) -> &'o DicomElement<'a> {
+    let seq: &DicomObject = dcmroot.get_child_by_tag(tag).expect("Should have sequence");
+    assert_eq!(&vr::SQ, seq.element().vr());
+    let item: &DicomObject = seq.get_item_by_index(1).expect("Should have item");
+    let (_tag, child) = item
+        .iter_child_nodes()
+        .find(|(tag, _child)| **tag != tags::ItemDelimitationItem.tag)
+        .expect("Should have element");
+    child.element()
+}
+
+#[test]
+fn test_resolve_un() -> ParseResult<()> {
+    for ts in [
+        &ts::ExplicitVRLittleEndian,
+        &ts::ExplicitVRBigEndian,
+        &ts::ImplicitVRLittleEndian,
+    ] {
+        let dcmroot: DicomRoot<'_> = parse_un_dataset(&write_un_dataset(ts), true)?;
+        check_resolved_un(&dcmroot, ts)?;
+        if !ts.explicit_vr() {
+            continue;
+        }
+
+        // Once resolved the dataset is written as though the elements were properly encoded.
+        let mut dcmroot: DicomRoot<'_> = dcmroot;
+        dcmroot.update_value_lengths();
+        let mut writer: Writer<Vec<u8>> = WriterBuilder::for_file().ts(ts).build(Vec::new());
+        writer.write_dcmroot(&dcmroot).expect("Write dataset");
+        let bytes: Vec<u8> = writer.into_dataset().expect("Dataset");
+        check_resolved_un(&parse_un_dataset(&bytes, false)?, ts)?;
+    }
+    Ok(())
+}
+
+fn check_resolved_un(dcmroot: &DicomRoot<'_>, ts: TSRef) -> ParseResult<()> {
+    let rows: &DicomElement = dcmroot
+        .get_child_by_tag(tags::Rows.tag)
+        .expect("Should have Rows")
+        .element();
+    assert_eq!(&vr::US, rows.vr());
+    assert!(matches!(rows.parse_value()?, RawValue::UnsignedShorts(values) if values == [512]));
+
+    let creator: &DicomElement = dcmroot
+        .get_child_by_tag(0x0009_0010)
+        .expect("Should have private creator")
+        .element();
+    assert_eq!(&vr::LO, creator.vr());
+
+    let referenced: &DicomElement = first_item_element(dcmroot, tags::ReferencedImageSequence.tag);
+    assert_eq!(&vr::UI, referenced.vr());
+    assert_eq!(ts, referenced.ts());
+    assert_eq!("1.2.3", String::try_from(referenced)?);
+
+    let source: &DicomElement = first_item_element(dcmroot, tags::SourceImageSequence.tag);
+    assert_eq!(ts, source.ts());
+    assert_eq!("1.2.4", String::try_from(source)?);
+
+    let private: &DicomElement = first_item_element(dcmroot, 0x0009_1001);
+    assert_eq!(&vr::UN, private.vr());
+    assert_eq!([0x01, 0x00, 0x02, 0x00], private.data());
+
+    let not_seq: &DicomElement = dcmroot
+        .get_child_by_tag(0x0009_1003)
+        .expect("Should have private element")
+        .element();
+    assert_eq!(&vr::UN, not_seq.vr());
+    assert_eq!((1..=8).collect::<Vec<u8>>(), not_seq.data());
+    Ok(())
+}
+
+#[test]
+fn test_unresolved_un() -> ParseResult<()> {
+    let bytes: Vec<u8> = write_un_dataset(&ts::ImplicitVRLittleEndian);
+    let dcmroot: DicomRoot<'_> = parse_un_dataset(&bytes, false)?;
+
+    let creator: &DicomElement = dcmroot
+        .get_child_by_tag(0x0009_0010)
+        .expect("Should have private creator")
+        .element();
+    assert_eq!(&vr::UN, creator.vr());
+
+    let private: &DicomObject = dcmroot
+        .get_child_by_tag(0x0009_1001)
+        .expect("Should have private element");
+    assert_eq!(&vr::UN, private.element().vr());
+    assert_eq!(20, private.element().data().len());
+    Ok(())
+}
+
 #[test]
 fn test_trailing_zeroes_does_not_error() {
     let parser: Parser<'_, MockDicomDataset> =
@@ -269,7 +766,7 @@ fn test_dicom_object_sequences(with_std: bool) -> ParseResult<()> {
         .expect("Should have StructureSetTime")
         .element();
     // pull value into local var so it can be typed properly, otherwise it defaults type to &Vec<u8>
-    let ss_time_bytes: &[u8] = ss_time.data().as_ref();
+    let ss_time_bytes: &[u8] = ss_time.data();
     assert_eq!("092108.000".as_bytes(), ss_time_bytes);
 
     // walk the depths of the first sequence to make sure the structure is setup as we expect
@@ -324,7 +821,7 @@ fn test_dicom_object_sequences(with_std: bool) -> ParseResult<()> {
         let item_foruid: &DicomObject = item_obj
             .get_child_by_tag(tags::FrameofReferenceUID.tag)
             .expect("Have FORUID");
-        let item_foruid_bytes: &[u8] = item_foruid.element().data().as_ref();
+        let item_foruid_bytes: &[u8] = item_foruid.element().data();
         assert_eq!(
             "1.2.246.352.91.0000217.20050503182534.1.1\0".as_bytes(),
             item_foruid_bytes,
@@ -353,14 +850,14 @@ fn test_dicom_object_sequences(with_std: bool) -> ParseResult<()> {
             .get_child_by_tag(tags::ReferencedSOPClassUID.tag)
             .expect("Have ref sop class")
             .element();
-        let ref_sopclass_bytes: &[u8] = ref_sopclass.data().as_ref();
+        let ref_sopclass_bytes: &[u8] = ref_sopclass.data();
         assert_eq!("1.2.840.10008.3.1.2.3.1\0".as_bytes(), ref_sopclass_bytes);
 
         let ref_sopuid: &DicomElement = rtrss_sq_item
             .get_child_by_tag(tags::ReferencedSOPInstanceUID.tag)
             .expect("Have ref sop instance uid")
             .element();
-        let ref_sopuid_bytes: &[u8] = ref_sopuid.data().as_ref();
+        let ref_sopuid_bytes: &[u8] = ref_sopuid.data();
         assert_eq!(
             "1.2.246.352.91.0000217.20050503182534\0".as_bytes(),
             ref_sopuid_bytes,
@@ -379,7 +876,7 @@ fn test_dicom_object_sequences(with_std: bool) -> ParseResult<()> {
             .get_child_by_tag(tags::SeriesInstanceUID.tag)
             .expect("Have series uid")
             .element();
-        let rtref_ser_uid_bytes: &[u8] = rtref_ser_uid.data().as_ref();
+        let rtref_ser_uid_bytes: &[u8] = rtref_ser_uid.data();
         assert_eq!(
             "1.2.246.352.91.0000217.20050503182534.1\0".as_bytes(),
             rtref_ser_uid_bytes,
@@ -409,7 +906,7 @@ fn test_dicom_object_sequences(with_std: bool) -> ParseResult<()> {
             .get_child_by_tag(tags::ReferencedSOPInstanceUID.tag)
             .expect("Get last item's ref sop uid")
             .element();
-        let last_sop_uid_bytes: &[u8] = last_sop_uid.data().as_ref();
+        let last_sop_uid_bytes: &[u8] = last_sop_uid.data();
         assert_eq!(
             "1.2.246.352.91.0000217.20050503182534671465\0".as_bytes(),
             last_sop_uid_bytes,
@@ -586,7 +1083,7 @@ fn test_seq_switch_to_ivrle(with_std: bool) -> ParseResult<()> {
     assert_eq!(dcmroot.ts(), sis_elem.ts());
 
     // Manually parse the contents of Source Image Sequence as a sequence.
-    let data: &Vec<u8> = sis_obj.element().data();
+    let data: &[u8] = sis_obj.element().data();
     // Initialize the parser to start with Element rather than file-stuff, specifying IVRLE since
     // the contents _must_ be encoded that way in a sequence.
     let mut parser = ParserBuilder::default()
@@ -842,7 +1339,7 @@ fn test_incomplete_dicom_file(with_std: bool) -> ParseResult<()> {
     assert!(result.is_err());
 
     let err = result.unwrap_err();
-    if let ParseError::DetailedError { source, detail: _ } = err {
+    if let ParseError::DetailedError { source, .. } = err {
         let source = *source;
         if let ParseError::IOError { source } = source {
             assert_eq!(ErrorKind::UnexpectedEof, source.kind());
@@ -0,0 +1,1571 @@
+use std::{io::Cursor, sync::Arc};
+
+use dcmpipe_lib::{
+    core::{
+        dcmobject::DicomRoot,
+        defn::{
+            tag::TagRef,
+            ts::{Compression, TSRef, TransferSyntax},
+            uid::{UIDType, UID},
+            vr::{self, VRRef},
+        },
+        pixeldata::{
+            codec::{encode_with_codec, CodecRegistry, PixelCodec},
+            concatenation::{Concatenation, FrameLocation},
+            decode_frame,
+            encapsulated::EncapsulatedPixelData,
+            j2k::{self, J2kComponent, J2kHeader},
+            jpeg::{encode_jpeg_baseline, JpegOptions},
+            metaimage, nifti, redact_regions,
+            video::{
+                encapsulate_video, extract_video, is_video, VideoAttributes, VideoCodec,
+                VideoFormat,
+            },
+            volume::{self, VoxelType},
+            wsi::WholeSlide,
+            FrameDecoder, Histogram, ImagePixel, Overlay, Photometric, PixelDataError, PixelFrame,
+            Region, VoiLut, Volume, Window,
+        },
+        read::{Parser, ParserBuilder},
+        repr::{DatasetRepr, ElementRepr},
+        values::RawValue,
+        write::{builder::WriterBuilder, writer::Writer},
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags, transfer_syntaxes as ts},
+};
+
+mod common;
+
+/// Builds a dataset of the given elements, using the implicit VR of each tag.
+fn build_dataset(ts: TSRef, elements: Vec<(TagRef, RawValue)>) -> DicomRoot<'static> {
+    let elements: Vec<(u32, VRRef, RawValue)> = elements
+        .into_iter()
+        .map(|(tag, value)| (tag.tag, tag.implicit_vr().unwrap_or(&vr::OB), value))
+        .collect();
+    common::build_dataset(ts, elements)
+}
+
+/// The Image Pixel attributes of a 2x2 image.
+fn image_pixel_attrs(
+    photometric: &str,
+    samples_per_pixel: u16,
+    bits_allocated: u16,
+    bits_stored: u16,
+    pixel_representation: u16,
+) -> Vec<(TagRef, RawValue)> {
+    vec![
+        (
+            &tags::SamplesperPixel,
+            RawValue::UnsignedShorts(vec![samples_per_pixel]),
+        ),
+        (
+            &tags::PhotometricInterpretation,
+            RawValue::Strings(vec![photometric.to_owned()]),
+        ),
+        (&tags::Rows, RawValue::UnsignedShorts(vec![2])),
+        (&tags::Columns, RawValue::UnsignedShorts(vec![2])),
+        (
+            &tags::BitsAllocated,
+            RawValue::UnsignedShorts(vec![bits_allocated]),
+        ),
+        (
+            &tags::BitsStored,
+            RawValue::UnsignedShorts(vec![bits_stored]),
+        ),
+        (
+            &tags::PixelRepresentation,
+            RawValue::UnsignedShorts(vec![pixel_representation]),
+        ),
+    ]
+}
+
+#[test]
+fn test_decode_signed_with_rescale() -> Result<(), PixelDataError> {
+    let mut elements = image_pixel_attrs("MONOCHROME2", 1, 16, 12, 1);
+    elements.push((&tags::RescaleSlope, RawValue::Strings(vec!["2".to_owned()])));
+    elements.push((
+        &tags::RescaleIntercept,
+        RawValue::Strings(vec!["-10".to_owned()]),
+    ));
+    // The bits above the 12 stored should be ignored, and 0x0FFF is -1 as a 12-bit signed value.
+    elements.push((
+        &tags::PixelData,
+        RawValue::Words(vec![0xF000, 0x0FFF, 0x07FF, 0x0800]),
+    ));
+
+    for ts in [&ts::ExplicitVRLittleEndian, &ts::ExplicitVRBigEndian] {
+        let dcmroot = build_dataset(ts, elements.clone());
+        let frame: PixelFrame = decode_frame(&dcmroot, 0)?;
+        assert_eq!((2, 2), (frame.rows(), frame.columns()));
+        assert_eq!(Photometric::Monochrome2, frame.photometric());
+        assert_eq!(&[-10.0, -12.0, 4084.0, -4106.0], frame.values());
+        assert_eq!(None, frame.window());
+        assert_eq!(Some((-4106.0, 4084.0)), frame.min_max());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_decode_frames() -> Result<(), PixelDataError> {
+    // 50 frames of 2x2 pixels, each pixel the index of its frame.
+    let frames: u8 = 50;
+    let mut elements = image_pixel_attrs("MONOCHROME2", 1, 8, 8, 0);
+    elements.extend([
+        (
+            &tags::NumberofFrames,
+            RawValue::Strings(vec![frames.to_string()]),
+        ),
+        (
+            &tags::RescaleIntercept,
+            RawValue::Strings(vec!["100".to_owned()]),
+        ),
+        (
+            &tags::PixelData,
+            RawValue::Bytes((0..frames).flat_map(|frame| [frame; 4]).collect()),
+        ),
+    ]);
+    let dcmroot = build_dataset(&ts::ExplicitVRLittleEndian, elements);
+
+    let decoder = FrameDecoder::new(&dcmroot)?;
+    assert_eq!(usize::from(frames), decoder.frame_count());
+    let expected: Vec<Vec<f64>> = (0..frames)
+        .map(|frame| vec![f64::from(frame) + 100.0; 4])
+        .collect();
+    let sequential: Vec<Vec<f64>> = decoder
+        .frames()
+        .map(|frame| frame.map(|frame| frame.values().to_vec()))
+        .collect::<Result<_, _>>()?;
+    assert_eq!(expected, sequential);
+
+    let parallel = decoder.par_frames();
+    assert_eq!((50, Some(50)), parallel.size_hint());
+    let parallel: Vec<Vec<f64>> = parallel
+        .map(|frame| frame.map(|frame| frame.values().to_vec()))
+        .collect::<Result<_, _>>()?;
+    assert_eq!(expected, parallel);
+    assert_eq!(
+        decode_frame(&dcmroot, 7)?.values(),
+        decoder.decode(7)?.values()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_decode_planar_rgb() -> Result<(), PixelDataError> {
+    let mut elements = image_pixel_attrs("RGB", 3, 8, 8, 0);
+    elements.push((
+        &tags::PlanarConfiguration,
+        RawValue::UnsignedShorts(vec![1]),
+    ));
+    elements.push((
+        &tags::PixelData,
+        RawValue::Bytes(vec![255, 0, 0, 10, 0, 255, 0, 20, 0, 0, 255, 30]),
+    ));
+    let dcmroot = build_dataset(&ts::ExplicitVRLittleEndian, elements);
+
+    let frame: PixelFrame = decode_frame(&dcmroot, 0)?;
+    assert_eq!(3, frame.samples_per_pixel());
+    let rgb: Vec<[u8; 3]> = frame.to_rgb8(&frame.default_window());
+    assert_eq!(
+        vec![[255, 0, 0], [0, 255, 0], [0, 0, 255], [10, 20, 30]],
+        rgb
+    );
+    Ok(())
+}
+
+#[test]
+fn test_window() -> Result<(), PixelDataError> {
+    let mut elements = image_pixel_attrs("MONOCHROME1", 1, 8, 8, 0);
+    elements.push((
+        &tags::WindowCenter,
+        RawValue::Strings(vec!["128".to_owned(), "40".to_owned()]),
+    ));
+    elements.push((
+        &tags::WindowWidth,
+        RawValue::Strings(vec!["256".to_owned(), "400".to_owned()]),
+    ));
+    elements.push((&tags::PixelData, RawValue::Bytes(vec![0, 64, 128, 255])));
+    let dcmroot = build_dataset(&ts::ImplicitVRLittleEndian, elements);
+
+    let frame: PixelFrame = decode_frame(&dcmroot, 0)?;
+    assert_eq!(Some(Window::new(128.0, 256.0)), frame.window());
+    // MONOCHROME1 is inverted so the lowest values are white.
+    let gray: Vec<u8> = frame
+        .to_rgb8(&frame.default_window())
+        .into_iter()
+        .map(|rgb| rgb[0])
+        .collect();
+    assert_eq!(vec![255, 191, 127, 0], gray);
+
+    let narrow: Window = Window::new(100.0, 20.0);
+    assert_eq!(0, narrow.apply(89.0));
+    assert_eq!(255, narrow.apply(110.0));
+    assert_eq!(128, narrow.apply(99.5));
+    Ok(())
+}
+
+/// Checks histograms and windows suggested from them ignore outlying values, and are used when the
+/// dataset's window doesn't cover any values.
+#[test]
+fn test_auto_window() -> Result<(), PixelDataError> {
+    let (rows, columns) = (40u16, 50u16);
+    // Values from 100 to 199, with a couple of outliers at either end.
+    let mut samples: Vec<u8> = (0..usize::from(rows) * usize::from(columns))
+        .map(|i| 100 + (i % 100) as u8)
+        .collect();
+    samples[..2].copy_from_slice(&[0, 0]);
+    samples[10..12].copy_from_slice(&[255, 255]);
+    let dataset = |window: Option<(&str, &str)>| {
+        let mut elements = image_pixel_attrs("MONOCHROME2", 1, 8, 8, 0);
+        elements.extend([
+            (&tags::Rows, RawValue::UnsignedShorts(vec![rows])),
+            (&tags::Columns, RawValue::UnsignedShorts(vec![columns])),
+            (&tags::PixelData, RawValue::Bytes(samples.clone())),
+        ]);
+        if let Some((center, width)) = window {
+            elements.push((
+                &tags::WindowCenter,
+                RawValue::Strings(vec![center.to_owned()]),
+            ));
+            elements.push((
+                &tags::WindowWidth,
+                RawValue::Strings(vec![width.to_owned()]),
+            ));
+        }
+        build_dataset(&ts::ExplicitVRLittleEndian, elements)
+    };
+
+    let frame: PixelFrame = decode_frame(&dataset(None), 0)?;
+    let histogram: Histogram = frame.histogram(256).expect("Frame should have values");
+    assert_eq!((0.0, 255.0), (histogram.min(), histogram.max()));
+    assert_eq!(samples.len() as u64, histogram.total());
+    assert_eq!(2, histogram.counts()[0]);
+    assert_eq!(Some(0.0), histogram.percentile(0.0));
+    assert_eq!(Some(255.0), histogram.percentile(100.0));
+    let median: f64 = histogram
+        .percentile(50.0)
+        .expect("Histogram should have values");
+    assert!((149.0..=151.0).contains(&median), "median {median}");
+
+    let (lower, upper) = frame.auto_window().bounds();
+    assert!((99.0..=101.0).contains(&lower), "lower {lower}");
+    assert!((198.0..=201.0).contains(&upper), "upper {upper}");
+    assert_eq!(frame.auto_window(), frame.suggested_window());
+
+    // The histogram of a volume spans the values of all its frames.
+    let volume: Histogram =
+        Histogram::of_frames(&[frame, decode_frame(&dataset(None), 0)?], 256).expect("values");
+    assert_eq!(2 * samples.len() as u64, volume.total());
+    let mut merged: Histogram = histogram.clone();
+    assert!(merged.merge(&histogram));
+    assert_eq!(volume, merged);
+    assert!(!merged.merge(&Histogram::new(0.0, 100.0, 256)));
+    assert_eq!(None, Histogram::of_values(&[f64::NAN], 16));
+
+    // The dataset's window is used unless it doesn't cover any values.
+    let frame: PixelFrame = decode_frame(&dataset(Some(("150", "20"))), 0)?;
+    assert_eq!(Window::new(150.0, 20.0), frame.suggested_window());
+    let frame: PixelFrame = decode_frame(&dataset(Some(("5000", "100"))), 0)?;
+    assert_eq!(frame.auto_window(), frame.suggested_window());
+    Ok(())
+}
+
+/// Checks decoding frames whose number of samples isn't a multiple of the number processed at once
+/// with the `simd` feature, so samples are processed both ways.
+#[test]
+fn test_decode_transforms() -> Result<(), PixelDataError> {
+    let (rows, columns) = (5u16, 7u16);
+    let pixels: usize = usize::from(rows) * usize::from(columns);
+
+    // 12 bits stored, signed, in big endian with junk in the high bits.
+    let words: Vec<u16> = (0..pixels as u16).map(|i| 0xA000 | (i * 117)).collect();
+    let mut elements = image_pixel_attrs("MONOCHROME2", 1, 16, 12, 1);
+    elements.extend([
+        (&tags::Rows, RawValue::UnsignedShorts(vec![rows])),
+        (&tags::Columns, RawValue::UnsignedShorts(vec![columns])),
+        (
+            &tags::RescaleSlope,
+            RawValue::Strings(vec!["0.5".to_owned()]),
+        ),
+        (
+            &tags::RescaleIntercept,
+            RawValue::Strings(vec!["-1024".to_owned()]),
+        ),
+        (&tags::PixelData, RawValue::Words(words.clone())),
+    ]);
+    let dcmroot = build_dataset(&ts::ExplicitVRBigEndian, elements);
+    let frame: PixelFrame = decode_frame(&dcmroot, 0)?;
+    let expected: Vec<f64> = words
+        .iter()
+        .map(|word| f64::from(((word << 4) as i16) >> 4) * 0.5 - 1024.0)
+        .collect();
+    assert_eq!(expected, frame.values());
+
+    let window: Window = Window::new(-1000.0, 700.0);
+    let gray: Vec<u8> = frame
+        .to_rgb8(&window)
+        .into_iter()
+        .map(|rgb| rgb[0])
+        .collect();
+    let expected: Vec<u8> = expected.iter().map(|value| window.apply(*value)).collect();
+    assert_eq!(expected, gray);
+    assert!(gray.contains(&0) && gray.contains(&255));
+
+    // YBR_FULL of a color for each pixel, including those outside of the RGB gamut.
+    let ybr: Vec<u8> = (0..pixels * 3).map(|i| (i * 53 % 256) as u8).collect();
+    let mut elements = image_pixel_attrs("YBR_FULL", 3, 8, 8, 0);
+    elements.extend([
+        (&tags::Rows, RawValue::UnsignedShorts(vec![rows])),
+        (&tags::Columns, RawValue::UnsignedShorts(vec![columns])),
+        (
+            &tags::PlanarConfiguration,
+            RawValue::UnsignedShorts(vec![0]),
+        ),
+        (&tags::PixelData, RawValue::Bytes(ybr.clone())),
+    ]);
+    let dcmroot = build_dataset(&ts::ExplicitVRLittleEndian, elements);
+    let frame: PixelFrame = decode_frame(&dcmroot, 0)?;
+    let expected: Vec<f64> = ybr
+        .chunks_exact(3)
+        .flat_map(|pixel| {
+            let y: f64 = f64::from(pixel[0]);
+            let (cb, cr) = (f64::from(pixel[1]) - 128.0, f64::from(pixel[2]) - 128.0);
+            [
+                y + 1.402 * cr,
+                y - 0.344136 * cb - 0.714136 * cr,
+                y + 1.772 * cb,
+            ]
+            .map(|sample| sample.clamp(0.0, 255.0))
+        })
+        .collect();
+    assert_eq!(expected, frame.values());
+    Ok(())
+}
+
+#[test]
+fn test_decode_errors() {
+    let no_pixels = build_dataset(
+        &ts::ExplicitVRLittleEndian,
+        image_pixel_attrs("MONOCHROME2", 1, 8, 8, 0),
+    );
+    assert!(matches!(
+        decode_frame(&no_pixels, 0),
+        Err(PixelDataError::MissingPixelData)
+    ));
+
+    let mut elements = image_pixel_attrs("MONOCHROME2", 1, 8, 8, 0);
+    elements.push((&tags::PixelData, RawValue::Bytes(vec![0, 1])));
+    let short = build_dataset(&ts::ExplicitVRLittleEndian, elements.clone());
+    assert!(matches!(
+        decode_frame(&short, 0),
+        Err(PixelDataError::InsufficientData {
+            expected: 4,
+            actual: 2
+        })
+    ));
+    assert!(matches!(
+        decode_frame(&short, 1),
+        Err(PixelDataError::FrameOutOfRange {
+            frame: 1,
+            frames: 1
+        })
+    ));
+
+    let encapsulated = build_dataset(&ts::JPEGBaselineProcess1, elements);
+    assert!(matches!(
+        decode_frame(&encapsulated, 0),
+        Err(PixelDataError::UnsupportedTransferSyntax(_))
+    ));
+
+    let mut elements = image_pixel_attrs("PALETTE COLOR", 1, 8, 8, 0);
+    elements.push((&tags::PixelData, RawValue::Bytes(vec![0, 1, 2, 3])));
+    let palette = build_dataset(&ts::ExplicitVRLittleEndian, elements);
+    assert!(matches!(
+        decode_frame(&palette, 0),
+        Err(PixelDataError::UnsupportedEncoding(_))
+    ));
+}
+
+#[test]
+fn test_image_pixel() -> Result<(), PixelDataError> {
+    let dcmroot = build_dataset(
+        &ts::ExplicitVRLittleEndian,
+        image_pixel_attrs("RGB", 3, 16, 12, 0),
+    );
+    let mut image: ImagePixel = ImagePixel::from_dataset(&dcmroot)?;
+    assert_eq!(
+        ImagePixel {
+            rows: 2,
+            columns: 2,
+            samples_per_pixel: 3,
+            photometric_interpretation: "RGB".to_owned(),
+            planar_configuration: None,
+            bits_allocated: 16,
+            bits_stored: 12,
+            high_bit: 11,
+            pixel_representation: 0,
+            number_of_frames: 1,
+        },
+        image
+    );
+    assert_eq!(24, image.frame_len());
+    // Planar Configuration is required when there are multiple samples per pixel.
+    assert!(matches!(
+        image.validate(),
+        Err(PixelDataError::InvalidImagePixel(_))
+    ));
+    image.planar_configuration = Some(1);
+    image.validate()?;
+    assert!(image.is_planar());
+
+    for invalid in [
+        ImagePixel {
+            high_bit: 15,
+            ..image.clone()
+        },
+        ImagePixel {
+            bits_stored: 17,
+            high_bit: 16,
+            ..image.clone()
+        },
+        ImagePixel {
+            samples_per_pixel: 1,
+            ..image.clone()
+        },
+        ImagePixel {
+            pixel_representation: 2,
+            ..image.clone()
+        },
+        ImagePixel {
+            rows: 0,
+            ..image.clone()
+        },
+    ] {
+        assert!(matches!(
+            invalid.validate(),
+            Err(PixelDataError::InvalidImagePixel(_))
+        ));
+    }
+
+    // Attributes written to a dataset are read back the same.
+    image.number_of_frames = 3;
+    let mut written = build_dataset(&ts::ExplicitVRLittleEndian, Vec::new());
+    image.insert_into(&mut written, "test")?;
+    assert_eq!(image, ImagePixel::from_dataset(&written)?);
+
+    let missing = build_dataset(&ts::ExplicitVRLittleEndian, Vec::new());
+    assert!(matches!(
+        ImagePixel::from_dataset(&missing),
+        Err(PixelDataError::MissingAttribute("BitsAllocated"))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_redact_regions() -> Result<(), PixelDataError> {
+    // The region extends beyond the image and is clipped to the top-right pixel.
+    let region: Region = Region {
+        x: 1,
+        y: 0,
+        width: 5,
+        height: 1,
+    };
+
+    let mut elements = image_pixel_attrs("MONOCHROME2", 1, 16, 12, 1);
+    elements.push((
+        &tags::NumberofFrames,
+        RawValue::Strings(vec!["2".to_owned()]),
+    ));
+    elements.push((
+        &tags::PixelData,
+        RawValue::Words(vec![1, 2, 3, 4, 5, 6, 7, 8]),
+    ));
+    for ts in [&ts::ExplicitVRLittleEndian, &ts::ExplicitVRBigEndian] {
+        let mut dcmroot = build_dataset(ts, elements.clone());
+        redact_regions(&mut dcmroot, &[region], "redact")?;
+        // The lowest 12-bit signed value is displayed as black, in every frame.
+        assert_eq!(
+            &[1.0, -2048.0, 3.0, 4.0],
+            decode_frame(&dcmroot, 0)?.values()
+        );
+        assert_eq!(
+            &[5.0, -2048.0, 7.0, 8.0],
+            decode_frame(&dcmroot, 1)?.values()
+        );
+    }
+
+    let mut elements = image_pixel_attrs("MONOCHROME1", 1, 8, 8, 0);
+    elements.push((&tags::PixelData, RawValue::Bytes(vec![0, 64, 128, 255])));
+    let mut dcmroot = build_dataset(&ts::ExplicitVRLittleEndian, elements);
+    redact_regions(&mut dcmroot, &[region], "redact")?;
+    assert_eq!(
+        &[0.0, 255.0, 128.0, 255.0],
+        decode_frame(&dcmroot, 0)?.values()
+    );
+
+    let mut elements = image_pixel_attrs("RGB", 3, 8, 8, 0);
+    elements.push((
+        &tags::PlanarConfiguration,
+        RawValue::UnsignedShorts(vec![1]),
+    ));
+    elements.push((
+        &tags::PixelData,
+        RawValue::Bytes(vec![255, 0, 0, 10, 0, 255, 0, 20, 0, 0, 255, 30]),
+    ));
+    let mut dcmroot = build_dataset(&ts::ExplicitVRLittleEndian, elements);
+    redact_regions(&mut dcmroot, &[region], "redact")?;
+    let rgb: Vec<[u8; 3]> = decode_frame(&dcmroot, 0)?.to_rgb8(&Window::new(0.0, 1.0));
+    assert_eq!(vec![[255, 0, 0], [0, 0, 0], [0, 0, 255], [10, 20, 30]], rgb);
+    Ok(())
+}
+
+#[test]
+fn test_redact_errors() {
+    let region: Region = Region {
+        x: 0,
+        y: 0,
+        width: 1,
+        height: 1,
+    };
+
+    let mut elements = image_pixel_attrs("MONOCHROME2", 1, 32, 32, 0);
+    elements.push((&tags::FloatPixelData, RawValue::Floats(vec![0.0; 4])));
+    let mut float = build_dataset(&ts::ExplicitVRLittleEndian, elements);
+    assert!(matches!(
+        redact_regions(&mut float, &[region], "redact"),
+        Err(PixelDataError::UnsupportedEncoding(_))
+    ));
+
+    let mut elements = image_pixel_attrs("MONOCHROME2", 1, 8, 8, 0);
+    elements.push((&tags::PixelData, RawValue::Bytes(vec![0, 1])));
+    let mut short = build_dataset(&ts::ExplicitVRLittleEndian, elements);
+    assert!(matches!(
+        redact_regions(&mut short, &[region], "redact"),
+        Err(PixelDataError::InsufficientData { .. })
+    ));
+}
+
+#[test]
+fn test_encode_jpeg_baseline() -> Result<(), Box<dyn std::error::Error>> {
+    // Two frames of a planar RGB gradient, 16x8 pixels.
+    let (rows, columns) = (8u16, 16u16);
+    let plane_len: usize = usize::from(rows) * usize::from(columns);
+    let frame: Vec<u8> = (0..3 * plane_len)
+        .map(|index| {
+            let (plane, pixel) = (index / plane_len, index % plane_len);
+            let (row, column) = (pixel / usize::from(columns), pixel % usize::from(columns));
+            (column * 8 + row * 4 + plane * 20) as u8
+        })
+        .collect();
+    let mut elements = image_pixel_attrs("RGB", 3, 8, 8, 0);
+    elements.extend([
+        (
+            &tags::TransferSyntaxUID,
+            RawValue::Uid(ts::ExplicitVRLittleEndian.uid().uid().to_owned()),
+        ),
+        (&tags::Rows, RawValue::UnsignedShorts(vec![rows])),
+        (&tags::Columns, RawValue::UnsignedShorts(vec![columns])),
+        (
+            &tags::PlanarConfiguration,
+            RawValue::UnsignedShorts(vec![1]),
+        ),
+        (
+            &tags::NumberofFrames,
+            RawValue::Strings(vec!["2".to_owned()]),
+        ),
+        (&tags::PatientID, RawValue::Strings(vec!["JPEG".to_owned()])),
+        (&tags::PixelData, RawValue::Bytes(frame.repeat(2))),
+    ]);
+    let mut dcmroot = build_dataset(&ts::ExplicitVRLittleEndian, elements);
+    encode_jpeg_baseline(&mut dcmroot, &JpegOptions::default(), "jpeg")?;
+
+    let mut writer: Writer<Vec<u8>> = WriterBuilder::for_file().ts(dcmroot.ts()).build(Vec::new());
+    writer.write_dcmroot(&dcmroot)?;
+    let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .build(Cursor::new(writer.into_dataset()?));
+    let parsed: DicomRoot = DicomRoot::parse(&mut parser)?.expect("Should parse as DICOM");
+    assert_eq!(&ts::JPEGBaselineProcess1, parsed.ts());
+
+    let string = |tag: TagRef| -> Vec<String> {
+        parsed
+            .get_child_by_tag(tag.tag)
+            .map(|obj| Vec::<String>::try_from(obj.element()).expect("Should be text"))
+            .unwrap_or_default()
+    };
+    assert_eq!(
+        vec!["YBR_FULL_422"],
+        string(&tags::PhotometricInterpretation)
+    );
+    assert_eq!(vec!["01"], string(&tags::LossyImageCompression));
+    assert_eq!(
+        vec!["ISO_10918_1"],
+        string(&tags::LossyImageCompressionMethod)
+    );
+    assert_eq!(1, string(&tags::LossyImageCompressionRatio).len());
+    assert!(string(&tags::DerivationDescription)[0].contains("quality 90"));
+    assert_eq!(vec!["JPEG"], string(&tags::PatientID));
+    let image: ImagePixel = ImagePixel::from_dataset(&parsed)?;
+    assert_eq!(Some(0), image.planar_configuration);
+    image.validate()?;
+
+    // The Basic Offset Table is followed by a fragment for each frame.
+    let fragments: Vec<&[u8]> = parsed
+        .flatten()?
+        .into_iter()
+        .filter(|element| element.tag() == tags::Item.tag)
+        .map(|element| element.data())
+        .collect();
+    assert_eq!(3, fragments.len());
+    assert_eq!(
+        3,
+        dcmroot
+            .get_child_by_tag(tags::PixelData.tag)
+            .expect("Should have pixel data")
+            .item_count()
+    );
+    assert_eq!(0u32.to_le_bytes(), fragments[0][..4]);
+    assert_eq!(
+        (8 + fragments[1].len() as u32).to_le_bytes(),
+        fragments[0][4..]
+    );
+
+    // Decoding is close to the original RGB values.
+    let decoded: Vec<u8> = jpeg_decoder::Decoder::new(fragments[2]).decode()?;
+    assert_eq!(3 * plane_len, decoded.len());
+    for (pixel, rgb) in decoded.chunks_exact(3).enumerate() {
+        for (sample, value) in rgb.iter().enumerate() {
+            let expected: u8 = frame[sample * plane_len + pixel];
+            assert!(
+                value.abs_diff(expected) <= 8,
+                "pixel {pixel} sample {sample} is {value} not {expected}"
+            );
+        }
+    }
+
+    // Pixel Data which is already encapsulated can't be compressed.
+    assert!(matches!(
+        encode_jpeg_baseline(&mut dcmroot, &JpegOptions::default(), "jpeg"),
+        Err(PixelDataError::UnsupportedTransferSyntax(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_encode_jpeg_baseline_lossy_source() -> Result<(), PixelDataError> {
+    let mut elements = image_pixel_attrs("MONOCHROME2", 1, 8, 8, 0);
+    elements.extend([
+        (
+            &tags::LossyImageCompression,
+            RawValue::Strings(vec!["01".to_owned()]),
+        ),
+        (
+            &tags::LossyImageCompressionRatio,
+            RawValue::Strings(vec!["5".to_owned()]),
+        ),
+        (
+            &tags::LossyImageCompressionMethod,
+            RawValue::Strings(vec!["ISO_14495_1".to_owned()]),
+        ),
+        (&tags::PixelData, RawValue::Bytes(vec![0, 64, 128, 255])),
+    ]);
+    let mut dcmroot = build_dataset(&ts::ExplicitVRLittleEndian, elements);
+    dcmroot.set_track_provenance(true);
+    let options = JpegOptions {
+        quality: 50,
+        subsample_chroma: false,
+    };
+    encode_jpeg_baseline(&mut dcmroot, &options, "jpeg")?;
+
+    let method: Vec<String> = Vec::<String>::try_from(
+        dcmroot
+            .get_child_by_tag(tags::LossyImageCompressionMethod.tag)
+            .expect("Should have method")
+            .element(),
+    )?;
+    assert_eq!(vec!["ISO_14495_1", "ISO_10918_1"], method);
+    assert!(dcmroot
+        .get_child_by_tag(tags::DerivationDescription.tag)
+        .is_none());
+    assert_eq!(
+        "MONOCHROME2",
+        ImagePixel::from_dataset(&dcmroot)?.photometric_interpretation
+    );
+    let pixel_data = dcmroot
+        .get_child_by_tag(tags::PixelData.tag)
+        .expect("Should have pixel data");
+    // A single frame has an empty Basic Offset Table.
+    assert_eq!(2, pixel_data.item_count());
+    assert!(pixel_data.provenance().is_some());
+
+    for quality in [0, 101] {
+        let mut elements = image_pixel_attrs("MONOCHROME2", 1, 8, 8, 0);
+        elements.push((&tags::PixelData, RawValue::Bytes(vec![0; 4])));
+        let mut dcmroot = build_dataset(&ts::ExplicitVRLittleEndian, elements);
+        let options = JpegOptions {
+            quality,
+            ..JpegOptions::default()
+        };
+        assert!(matches!(
+            encode_jpeg_baseline(&mut dcmroot, &options, "jpeg"),
+            Err(PixelDataError::InvalidQuality(_))
+        ));
+    }
+
+    let mut elements = image_pixel_attrs("MONOCHROME2", 1, 16, 12, 0);
+    elements.push((&tags::PixelData, RawValue::Words(vec![0; 4])));
+    let mut deep = build_dataset(&ts::ExplicitVRLittleEndian, elements);
+    assert!(matches!(
+        encode_jpeg_baseline(&mut deep, &JpegOptions::default(), "jpeg"),
+        Err(PixelDataError::UnsupportedEncoding(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_encapsulated_frames() -> Result<(), PixelDataError> {
+    let mut elements = image_pixel_attrs("MONOCHROME2", 1, 8, 8, 0);
+    elements.extend([
+        (
+            &tags::NumberofFrames,
+            RawValue::Strings(vec!["3".to_owned()]),
+        ),
+        (&tags::PixelData, RawValue::Bytes((0..12).collect())),
+    ]);
+    let mut dcmroot = build_dataset(&ts::ExplicitVRLittleEndian, elements);
+    assert!(matches!(
+        EncapsulatedPixelData::read(&dcmroot),
+        Err(PixelDataError::UnsupportedTransferSyntax(_))
+    ));
+
+    encode_jpeg_baseline(&mut dcmroot, &JpegOptions::default(), "jpeg")?;
+    let encapsulated = EncapsulatedPixelData::read(&dcmroot)?;
+    assert_eq!(3, encapsulated.frame_count());
+    assert_eq!(3, encapsulated.fragments().len());
+    assert_eq!(12, encapsulated.basic_offset_table().len());
+    let frames: Vec<Vec<u8>> = encapsulated.frames()?;
+    for (frame, fragment) in frames.iter().zip(encapsulated.fragments()) {
+        assert_eq!(fragment, frame);
+        assert_eq!([0xFF, 0xD8], frame[..2]);
+    }
+    assert_eq!(frames[1], encapsulated.frame(1)?);
+    assert!(matches!(
+        encapsulated.frame(3),
+        Err(PixelDataError::FrameOutOfRange {
+            frame: 3,
+            frames: 3
+        })
+    ));
+    Ok(())
+}
+
+static INVERTED_UID: UID = UID {
+    ident: "Inverted",
+    uid: "1.2.3.4.5.6.7.8.9.10.2",
+    name: "Inverted",
+    uid_type: UIDType::TransferSyntax,
+};
+
+/// A private encapsulated transfer syntax whose frames are compressed with `InvertingCodec`.
+static INVERTED_TS: TransferSyntax = TransferSyntax {
+    uid: &INVERTED_UID,
+    explicit_vr: true,
+    big_endian: false,
+    deflated: false,
+    encapsulated: true,
+    compression: Compression::Lossless,
+};
+
+/// A codec which "compresses" frames by inverting their bytes. Decoded frames are described as
+/// MONOCHROME1, to check the codec's attributes are used.
+#[derive(Debug)]
+struct InvertingCodec;
+
+impl PixelCodec for InvertingCodec {
+    fn decode(&self, frame: &[u8], _image: &ImagePixel) -> Result<Vec<u8>, PixelDataError> {
+        Ok(frame.iter().map(|b| !b).collect())
+    }
+
+    fn encode(&self, frame: &[u8], _image: &ImagePixel) -> Result<Vec<u8>, PixelDataError> {
+        Ok(frame.iter().map(|b| !b).collect())
+    }
+
+    fn decoded_image(&self, image: &ImagePixel) -> ImagePixel {
+        ImagePixel {
+            photometric_interpretation: "MONOCHROME1".to_owned(),
+            ..image.clone()
+        }
+    }
+}
+
+#[test]
+fn test_codec_registry() -> Result<(), PixelDataError> {
+    let mut elements = image_pixel_attrs("MONOCHROME2", 1, 16, 12, 0);
+    elements.extend([
+        (
+            &tags::NumberofFrames,
+            RawValue::Strings(vec!["3".to_owned()]),
+        ),
+        (&tags::PixelData, RawValue::Bytes((0..24).collect())),
+    ]);
+    let mut dcmroot = build_dataset(&ts::ExplicitVRBigEndian, elements);
+    let native: Vec<Vec<f64>> = FrameDecoder::new(&dcmroot)?
+        .frames()
+        .map(|frame| frame.map(|frame| frame.values().to_vec()))
+        .collect::<Result<_, _>>()?;
+
+    let mut codecs = CodecRegistry::new();
+    assert!(matches!(
+        encode_with_codec(&mut dcmroot, &INVERTED_TS, &codecs, "codec"),
+        Err(PixelDataError::UnsupportedTransferSyntax("Inverted"))
+    ));
+    assert!(codecs
+        .register(INVERTED_UID.uid(), Arc::new(InvertingCodec))
+        .is_none());
+    assert_eq!(
+        vec![INVERTED_UID.uid()],
+        codecs.transfer_syntaxes().collect::<Vec<_>>()
+    );
+    encode_with_codec(&mut dcmroot, &INVERTED_TS, &codecs, "codec")?;
+    assert_eq!(&INVERTED_TS, dcmroot.ts());
+    let encapsulated = EncapsulatedPixelData::read(&dcmroot)?;
+    assert_eq!(3, encapsulated.frame_count());
+    // The big endian samples were given to the codec as little endian.
+    assert_eq!(vec![!1, !0, !3, !2], encapsulated.frame(0)?[..4]);
+
+    // Without the codec, the frames can't be decoded.
+    assert!(matches!(
+        FrameDecoder::new(&dcmroot),
+        Err(PixelDataError::UnsupportedTransferSyntax("Inverted"))
+    ));
+    let decoder = FrameDecoder::with_codecs(&dcmroot, &codecs)?;
+    assert_eq!(3, decoder.frame_count());
+    for (frame, native) in native.iter().enumerate() {
+        let decoded = decoder.decode(frame)?;
+        assert_eq!(Photometric::Monochrome1, decoded.photometric());
+        assert_eq!(native, decoded.values());
+    }
+    assert!(matches!(
+        decoder.decode(3),
+        Err(PixelDataError::FrameOutOfRange {
+            frame: 3,
+            frames: 3
+        })
+    ));
+
+    assert!(codecs.unregister(INVERTED_UID.uid()).is_some());
+    assert!(codecs.get_for_ts(&INVERTED_TS).is_none());
+    Ok(())
+}
+
+/// Builds the main header of a JPEG 2000 codestream for a 512x256 image with 12-bit signed samples.
+fn j2k_codestream(components: u8, high_throughput: bool) -> Vec<u8> {
+    let mut codestream: Vec<u8> = vec![0xFF, 0x4F];
+    // SIZ, with an image offset of 1 horizontally.
+    codestream.extend_from_slice(&[0xFF, 0x51]);
+    codestream.extend_from_slice(&(38 + 3 * u16::from(components)).to_be_bytes());
+    codestream.extend_from_slice(&0u16.to_be_bytes());
+    for value in [513u32, 256, 1, 0, 513, 256, 0, 0] {
+        codestream.extend_from_slice(&value.to_be_bytes());
+    }
+    codestream.extend_from_slice(&u16::from(components).to_be_bytes());
+    for _ in 0..components {
+        codestream.extend_from_slice(&[0x80 | 11, 1, 1]);
+    }
+    if high_throughput {
+        // CAP, with the Part 15 bit of Pcap and a Ccap15.
+        codestream.extend_from_slice(&[0xFF, 0x50, 0, 8, 0, 0x02, 0, 0, 0, 0]);
+    }
+    // COD, with 2 layers, 5 decomposition levels, and the reversible transform.
+    let cb_style: u8 = if high_throughput { 0x40 } else { 0 };
+    codestream.extend_from_slice(&[0xFF, 0x52, 0, 12, 0, 0, 0, 2, 0, 5, 4, 4, cb_style, 1]);
+    // QCD, which isn't read, then the start of the first tile-part.
+    codestream.extend_from_slice(&[0xFF, 0x5C, 0, 4, 0x40, 0x48]);
+    codestream.extend_from_slice(&[0xFF, 0x90, 0, 10]);
+    codestream
+}
+
+#[test]
+fn test_j2k_header() -> Result<(), PixelDataError> {
+    assert!(j2k::is_htj2k(
+        &ts::HighThroughputJPEG2000ImageCompressionLosslessOnly
+    ));
+    assert!(j2k::is_j2k(&ts::HighThroughputJPEG2000ImageCompression));
+    assert!(j2k::is_j2k(&ts::JPEG2000ImageCompressionLosslessOnly));
+    assert!(!j2k::is_htj2k(&ts::JPEG2000ImageCompression));
+    assert!(!j2k::is_j2k(&ts::ExplicitVRLittleEndian));
+
+    let header: J2kHeader = J2kHeader::parse(&j2k_codestream(1, true))?;
+    assert_eq!((512, 256), (header.width, header.height));
+    assert_eq!(
+        vec![J2kComponent {
+            bits: 12,
+            signed: true,
+            dx: 1,
+            dy: 1,
+        }],
+        header.components
+    );
+    assert!(header.high_throughput);
+    assert!(header.reversible);
+    assert_eq!(5, header.decomposition_levels);
+    assert_eq!(2, header.layers);
+    assert!(!J2kHeader::parse(&j2k_codestream(1, false))?.high_throughput);
+
+    let dcmroot = build_dataset(
+        &ts::ExplicitVRLittleEndian,
+        image_pixel_attrs("MONOCHROME2", 1, 16, 12, 1),
+    );
+    let mut image: ImagePixel = ImagePixel::from_dataset(&dcmroot)?;
+    image.rows = 256;
+    image.columns = 512;
+    header.check_image_pixel(&image)?;
+    image.pixel_representation = 0;
+    assert!(matches!(
+        header.check_image_pixel(&image),
+        Err(PixelDataError::InvalidImagePixel(_))
+    ));
+    assert!(matches!(
+        J2kHeader::parse(&j2k_codestream(3, true))?.check_image_pixel(&image),
+        Err(PixelDataError::InvalidImagePixel(_))
+    ));
+
+    assert!(matches!(
+        J2kHeader::parse(&[0xFF, 0xD8, 0xFF, 0xE0]),
+        Err(PixelDataError::InvalidCodestream(_))
+    ));
+    let truncated: Vec<u8> = j2k_codestream(1, true)[..20].to_vec();
+    assert!(matches!(
+        J2kHeader::parse(&truncated),
+        Err(PixelDataError::InsufficientData { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_video_pass_through() -> Result<(), Box<dyn std::error::Error>> {
+    assert!(is_video(&ts::FragmentableMPEG4AVCH264HighProfileLevel41));
+    assert_eq!(
+        Some(VideoCodec::Hevc),
+        VideoCodec::of(&ts::HEVCH265MainProfileLevel51)
+    );
+    assert_eq!(
+        Some(VideoCodec::Mpeg2),
+        VideoCodec::of(&ts::FragmentableMPEG2MainProfileHighLevel)
+    );
+    assert!(!is_video(&ts::JPEGBaselineProcess1));
+
+    // Two packets of a transport stream, of odd length to check the padding of fragments.
+    let mut stream: Vec<u8> = vec![0x47];
+    stream.resize(188, 0xAB);
+    stream.push(0x47);
+    stream.resize(188 * 2 - 1, 0xCD);
+    assert_eq!(
+        VideoFormat::TransportStream,
+        VideoFormat::detect(&stream, VideoCodec::H264)
+    );
+    assert_eq!(
+        VideoFormat::Elementary(VideoCodec::H264),
+        VideoFormat::detect(&[0, 0, 0, 1, 0x67], VideoCodec::H264)
+    );
+    assert_eq!(
+        "mpg",
+        VideoFormat::detect(&[0, 0, 1, 0xBA], VideoCodec::Mpeg2).extension()
+    );
+
+    let attributes = VideoAttributes {
+        rows: 720,
+        columns: 1280,
+        frames: 30,
+        frame_time: 33.33,
+    };
+    let mut dcmroot = build_dataset(
+        &ts::ExplicitVRLittleEndian,
+        vec![
+            (
+                &tags::TransferSyntaxUID,
+                RawValue::Uid(ts::ExplicitVRLittleEndian.uid().uid().to_owned()),
+            ),
+            (
+                &tags::PatientID,
+                RawValue::Strings(vec!["VIDEO".to_owned()]),
+            ),
+        ],
+    );
+    assert!(matches!(
+        encapsulate_video(
+            &mut dcmroot,
+            &stream,
+            &ts::JPEGBaselineProcess1,
+            &attributes,
+            "video"
+        ),
+        Err(PixelDataError::UnsupportedTransferSyntax(_))
+    ));
+    let to: TSRef = &ts::MPEG4AVCH264HighProfileLevel41;
+    encapsulate_video(&mut dcmroot, &stream, to, &attributes, "video")?;
+
+    let mut writer: Writer<Vec<u8>> = WriterBuilder::for_file().ts(dcmroot.ts()).build(Vec::new());
+    writer.write_dcmroot(&dcmroot)?;
+    let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .build(Cursor::new(writer.into_dataset()?));
+    let parsed: DicomRoot = DicomRoot::parse(&mut parser)?.expect("Should parse as DICOM");
+    assert_eq!(to, parsed.ts());
+
+    let image: ImagePixel = ImagePixel::from_dataset(&parsed)?;
+    assert_eq!("YBR_PARTIAL_420", image.photometric_interpretation);
+    assert_eq!(
+        (720, 1280, 30),
+        (image.rows, image.columns, image.number_of_frames)
+    );
+    let frame_time: Vec<f64> = parsed
+        .get_child_by_tag(tags::FrameTime.tag)
+        .map(|obj| Vec::<f64>::try_from(obj.element()))
+        .transpose()?
+        .unwrap_or_default();
+    assert_eq!(vec![33.33], frame_time);
+
+    let (format, extracted) = extract_video(&parsed)?;
+    assert_eq!(VideoFormat::TransportStream, format);
+    assert_eq!("ts", format.extension());
+    assert_eq!(stream, extracted[..stream.len()]);
+    assert_eq!(vec![0], extracted[stream.len()..]);
+    Ok(())
+}
+
+fn repr(tag: TagRef, vr: &str, value: RawValue) -> ElementRepr {
+    ElementRepr {
+        tag: tag.tag,
+        vr: vr.to_owned(),
+        value: Some(value),
+        undefined_length: false,
+        elements: Vec::new(),
+        items: Vec::new(),
+    }
+}
+
+fn seq_repr(tag: TagRef, items: Vec<Vec<ElementRepr>>) -> ElementRepr {
+    let item = |elements: Vec<ElementRepr>| ElementRepr {
+        tag: tags::Item.tag,
+        vr: "UN".to_owned(),
+        value: None,
+        undefined_length: false,
+        elements,
+        items: Vec::new(),
+    };
+    ElementRepr {
+        tag: tag.tag,
+        vr: "SQ".to_owned(),
+        value: None,
+        undefined_length: false,
+        elements: Vec::new(),
+        items: items.into_iter().map(item).collect(),
+    }
+}
+
+/// The attributes of the instance of a concatenation of the object "1.2.3".
+fn concatenation_attrs(number: u16, total: u16, offset: u32) -> Vec<ElementRepr> {
+    let uid = |tag: TagRef, uid: String| repr(tag, "UI", RawValue::Uid(uid));
+    vec![
+        uid(&tags::SOPInstanceUID, format!("1.2.3.{number}")),
+        uid(
+            &tags::SOPInstanceUIDofConcatenationSource,
+            "1.2.3".to_owned(),
+        ),
+        uid(&tags::ConcatenationUID, "1.2.3.100".to_owned()),
+        repr(
+            &tags::InconcatenationNumber,
+            "US",
+            RawValue::UnsignedShorts(vec![number]),
+        ),
+        repr(
+            &tags::InconcatenationTotalNumber,
+            "US",
+            RawValue::UnsignedShorts(vec![total]),
+        ),
+        repr(
+            &tags::ConcatenationFrameOffsetNumber,
+            "UL",
+            RawValue::UnsignedIntegers(vec![offset]),
+        ),
+    ]
+}
+
+/// An instance of a whole slide image with 2x2 tiles of 8-bit samples, each tile filled with the
+/// given value.
+fn wsi_instance(
+    total_size: (u32, u32),
+    tiles: &[u8],
+    mut elements: Vec<ElementRepr>,
+) -> DicomRoot<'static> {
+    elements.extend([
+        repr(
+            &tags::SamplesperPixel,
+            "US",
+            RawValue::UnsignedShorts(vec![1]),
+        ),
+        repr(
+            &tags::PhotometricInterpretation,
+            "CS",
+            RawValue::Strings(vec!["MONOCHROME2".to_owned()]),
+        ),
+        repr(
+            &tags::NumberofFrames,
+            "IS",
+            RawValue::Strings(vec![tiles.len().to_string()]),
+        ),
+        repr(&tags::Rows, "US", RawValue::UnsignedShorts(vec![2])),
+        repr(&tags::Columns, "US", RawValue::UnsignedShorts(vec![2])),
+        repr(
+            &tags::BitsAllocated,
+            "US",
+            RawValue::UnsignedShorts(vec![8]),
+        ),
+        repr(&tags::BitsStored, "US", RawValue::UnsignedShorts(vec![8])),
+        repr(
+            &tags::PixelRepresentation,
+            "US",
+            RawValue::UnsignedShorts(vec![0]),
+        ),
+        repr(
+            &tags::TotalPixelMatrixRows,
+            "UL",
+            RawValue::UnsignedIntegers(vec![total_size.0]),
+        ),
+        repr(
+            &tags::TotalPixelMatrixColumns,
+            "UL",
+            RawValue::UnsignedIntegers(vec![total_size.1]),
+        ),
+        repr(
+            &tags::PixelData,
+            "OB",
+            RawValue::Bytes(tiles.iter().flat_map(|tile| [*tile; 4]).collect()),
+        ),
+    ]);
+    elements.sort_by_key(|element| element.tag);
+    DatasetRepr {
+        ts: ts::ExplicitVRLittleEndian.uid().uid().to_owned(),
+        cs: "windows-1252".to_owned(),
+        elements,
+    }
+    .to_dcmroot(&STANDARD_DICOM_DICTIONARY)
+    .expect("Dataset should be created")
+}
+
+#[test]
+fn test_whole_slide_tiles() -> Result<(), PixelDataError> {
+    let uid = |tag: TagRef, uid: &str| repr(tag, "UI", RawValue::Uid(uid.to_owned()));
+    let image_type = |flavor: &str| {
+        repr(
+            &tags::ImageType,
+            "CS",
+            RawValue::Strings(
+                ["DERIVED", "PRIMARY", flavor, "NONE"]
+                    .map(str::to_owned)
+                    .to_vec(),
+            ),
+        )
+    };
+    let tiled_full = || {
+        repr(
+            &tags::DimensionOrganizationType,
+            "CS",
+            RawValue::Strings(vec!["TILED_FULL".to_owned()]),
+        )
+    };
+    // The highest resolution has 2x3 tiles, the last column partial, split across two instances.
+    let concatenation = |number: u16, offset: u32| {
+        let mut elements: Vec<ElementRepr> = concatenation_attrs(number, 2, offset);
+        elements.extend([image_type("VOLUME"), tiled_full()]);
+        elements
+    };
+    let second: DicomRoot = wsi_instance((4, 5), &[40, 50], concatenation(2, 4));
+    let first: DicomRoot = wsi_instance((4, 5), &[0, 10, 20, 30], concatenation(1, 0));
+
+    // The lower resolution has 1x2 tiles, of which only the second is present.
+    let position: ElementRepr = seq_repr(
+        &tags::PlanePositionSlideSequence,
+        vec![vec![
+            repr(
+                &tags::ColumnPositionInTotalImagePixelMatrix,
+                "SL",
+                RawValue::Integers(vec![3]),
+            ),
+            repr(
+                &tags::RowPositionInTotalImagePixelMatrix,
+                "SL",
+                RawValue::Integers(vec![1]),
+            ),
+        ]],
+    );
+    let per_frame: ElementRepr = seq_repr(
+        &tags::PerFrameFunctionalGroupsSequence,
+        vec![vec![position]],
+    );
+    let sparse: DicomRoot = wsi_instance(
+        (2, 3),
+        &[99],
+        vec![uid(&tags::SOPInstanceUID, "1.2.4"), per_frame],
+    );
+    let label: DicomRoot = wsi_instance(
+        (2, 2),
+        &[1],
+        vec![image_type("LABEL"), uid(&tags::SOPInstanceUID, "1.2.5")],
+    );
+
+    let slide: WholeSlide = WholeSlide::new([&sparse, &second, &label, &first])?;
+    assert_eq!(2, slide.levels().len());
+    let level = slide.level(0).expect("Level should exist");
+    assert_eq!("1.2.3", level.frames().uid());
+    assert!(level.frames().is_concatenated());
+    assert_eq!((4, 5), level.size());
+    assert_eq!((2, 2), level.tile_size());
+    assert_eq!((2, 3), level.tile_grid());
+    assert_eq!(
+        Some(FrameLocation {
+            instance: 1,
+            frame: 1
+        }),
+        slide.tile_location(0, 1, 2)
+    );
+    assert_eq!(None, slide.tile_location(0, 2, 0));
+    for (row, column, value) in [(0, 0, 0.0), (1, 0, 30.0), (1, 2, 50.0)] {
+        let tile: PixelFrame = slide
+            .decode_tile(0, row, column)?
+            .expect("Tile should exist");
+        assert_eq!(&[value; 4], tile.values());
+    }
+
+    let level = slide.level(1).expect("Level should exist");
+    assert_eq!("1.2.4", level.frames().uid());
+    assert!(!level.frames().is_concatenated());
+    assert_eq!((1, 2), level.tile_grid());
+    assert_eq!(None, level.tile_location(0, 0));
+    let tile: PixelFrame = slide.decode_tile(1, 0, 1)?.expect("Tile should exist");
+    assert_eq!(&[99.0; 4], tile.values());
+    assert!(slide.decode_tile(2, 0, 0)?.is_none());
+    assert!(matches!(
+        level.compressed_tile(0, 1),
+        Err(PixelDataError::UnsupportedTransferSyntax(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_concatenation() -> Result<(), PixelDataError> {
+    let frame_number = |number: i32| {
+        vec![repr(
+            &tags::FrameAcquisitionNumber,
+            "US",
+            RawValue::UnsignedShorts(vec![number as u16]),
+        )]
+    };
+    let part = |number: u16, offset: u32, tiles: &[u8]| {
+        let mut elements: Vec<ElementRepr> = concatenation_attrs(number, 3, offset);
+        elements.push(seq_repr(
+            &tags::PerFrameFunctionalGroupsSequence,
+            (0..tiles.len())
+                .map(|frame| frame_number(offset as i32 + frame as i32))
+                .collect(),
+        ));
+        wsi_instance((2, 2), tiles, elements)
+    };
+    let first: DicomRoot = part(1, 0, &[0, 1]);
+    let second: DicomRoot = part(2, 2, &[2]);
+    let third: DicomRoot = part(3, 3, &[3, 4]);
+    let other: DicomRoot = wsi_instance(
+        (2, 2),
+        &[9],
+        vec![repr(
+            &tags::SOPInstanceUID,
+            "UI",
+            RawValue::Uid("1.2.9".to_owned()),
+        )],
+    );
+
+    let concatenations: Vec<Concatenation> =
+        Concatenation::group([&third, &other, &first, &second])?;
+    assert_eq!(2, concatenations.len());
+    let concatenation: &Concatenation = &concatenations[0];
+    assert_eq!("1.2.3", concatenation.uid());
+    assert!(concatenation.is_concatenated());
+    assert_eq!(5, concatenation.frame_count());
+    assert!(std::ptr::eq(&first, concatenation.dataset()));
+    assert_eq!(
+        Some(FrameLocation {
+            instance: 2,
+            frame: 0
+        }),
+        concatenation.locate(3)
+    );
+    assert_eq!(None, concatenation.locate(5));
+    for frame in 0..5 {
+        assert_eq!(&[frame as f64; 4], concatenation.decode(frame)?.values());
+        let groups = concatenation
+            .per_frame_groups(frame)
+            .and_then(|item| item.get_child_by_tag(tags::FrameAcquisitionNumber.tag))
+            .expect("Frame should have functional groups");
+        assert_eq!(frame as u16, u16::try_from(groups.element())?);
+    }
+    assert!(matches!(
+        concatenation.decode(5),
+        Err(PixelDataError::FrameOutOfRange {
+            frame: 5,
+            frames: 5
+        })
+    ));
+
+    assert!(!concatenations[1].is_concatenated());
+    assert_eq!(1, concatenations[1].frame_count());
+
+    assert!(matches!(
+        Concatenation::new(vec![&first, &third]),
+        Err(PixelDataError::InvalidConcatenation(_))
+    ));
+    let misplaced: DicomRoot = part(2, 1, &[2]);
+    assert!(matches!(
+        Concatenation::new(vec![&first, &misplaced, &third]),
+        Err(PixelDataError::InvalidConcatenation(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_voi_lut_and_overlay() -> Result<(), PixelDataError> {
+    let raw = |tag: u32, vr: &str, value: RawValue| ElementRepr {
+        tag,
+        vr: vr.to_owned(),
+        value: Some(value),
+        undefined_length: false,
+        elements: Vec::new(),
+        items: Vec::new(),
+    };
+    let mut elements: Vec<ElementRepr> = image_pixel_attrs("MONOCHROME2", 1, 8, 8, 0)
+        .into_iter()
+        .map(|(tag, value)| {
+            let vr: &str = tag.implicit_vr.map_or("OB", |vr| vr.ident);
+            repr(tag, vr, value)
+        })
+        .collect();
+    elements.extend([
+        repr(&tags::PixelData, "OB", RawValue::Bytes(vec![9, 10, 12, 20])),
+        seq_repr(
+            &tags::VOILUTSequence,
+            vec![vec![
+                repr(
+                    &tags::LUTDescriptor,
+                    "US",
+                    RawValue::UnsignedShorts(vec![4, 10, 8]),
+                ),
+                repr(
+                    &tags::LUTExplanation,
+                    "LO",
+                    RawValue::Strings(vec!["RAMP".to_owned()]),
+                ),
+                repr(
+                    &tags::LUTData,
+                    "US",
+                    RawValue::UnsignedShorts(vec![0, 85, 170, 255]),
+                ),
+            ]],
+        ),
+        // A 2x2 overlay whose top-left pixel is at the second column of the image.
+        raw(0x6000_0010, "US", RawValue::UnsignedShorts(vec![2])),
+        raw(0x6000_0011, "US", RawValue::UnsignedShorts(vec![2])),
+        raw(0x6000_0040, "CS", RawValue::Strings(vec!["G".to_owned()])),
+        raw(0x6000_0050, "SS", RawValue::Shorts(vec![1, 2])),
+        raw(0x6000_0100, "US", RawValue::UnsignedShorts(vec![1])),
+        raw(0x6000_0102, "US", RawValue::UnsignedShorts(vec![0])),
+        raw(0x6000_3000, "OB", RawValue::Bytes(vec![0b1011, 0])),
+    ]);
+    elements.sort_by_key(|element| element.tag);
+    let dcmroot: DicomRoot = DatasetRepr {
+        ts: ts::ExplicitVRLittleEndian.uid().uid().to_owned(),
+        cs: "windows-1252".to_owned(),
+        elements,
+    }
+    .to_dcmroot(&STANDARD_DICOM_DICTIONARY)
+    .expect("Dataset should be created");
+
+    let luts: Vec<VoiLut> = VoiLut::read_all(&dcmroot)?;
+    assert_eq!(1, luts.len());
+    assert_eq!(Some("RAMP"), luts[0].explanation());
+    let frame: PixelFrame = decode_frame(&dcmroot, 0)?;
+    let mut pixels: Vec<[u8; 3]> = frame.to_rgb8_with_lut(&luts[0]);
+    assert_eq!(vec![[0; 3], [0; 3], [170; 3], [255; 3]], pixels);
+
+    let overlays: Vec<Overlay> = Overlay::read_all(&dcmroot)?;
+    assert_eq!(1, overlays.len());
+    let overlay: &Overlay = &overlays[0];
+    assert_eq!(
+        (0x6000, (2, 2), (1, 2)),
+        (
+            overlay.group(),
+            (overlay.rows(), overlay.columns()),
+            overlay.origin()
+        )
+    );
+    assert!(overlay.applies_to(0));
+    assert!(!overlay.applies_to(1));
+    assert!(overlay.is_set(0, 0, 1));
+    assert!(overlay.is_set(0, 0, 2));
+    assert!(!overlay.is_set(0, 1, 1));
+    assert!(!overlay.is_set(0, 0, 0));
+    overlay.burn_in(0, &mut pixels, frame.columns(), [255, 0, 0]);
+    assert_eq!(vec![[0; 3], [255, 0, 0], [170; 3], [255; 3]], pixels);
+    Ok(())
+}
+
+/// A 2x2 axial slice of 8-bit samples, each filled with the given value, at the given height.
+fn axial_slice(z: f64, value: u8) -> DicomRoot<'static> {
+    let ds = |values: &[f64]| RawValue::Strings(values.iter().map(f64::to_string).collect());
+    let mut elements = image_pixel_attrs("MONOCHROME2", 1, 8, 8, 0);
+    elements.extend([
+        (&tags::ImagePositionPatient, ds(&[-10.0, 20.0, z])),
+        (
+            &tags::ImageOrientationPatient,
+            ds(&[1.0, 0.0, 0.0, 0.0, 1.0, 0.0]),
+        ),
+        (&tags::PixelSpacing, ds(&[0.5, 0.75])),
+        (&tags::PixelData, RawValue::Bytes(vec![value; 4])),
+    ]);
+    build_dataset(&ts::ExplicitVRLittleEndian, elements)
+}
+
+#[test]
+fn test_volume_export() -> Result<(), PixelDataError> {
+    // Slices are ordered by their position rather than the order given.
+    let slices = [
+        axial_slice(10.0, 3),
+        axial_slice(0.0, 1),
+        axial_slice(5.0, 2),
+    ];
+    let volume: Volume = Volume::from_instances(&slices)?;
+    assert_eq!([2, 2, 3], volume.dimensions());
+    assert_eq!([0.75, 0.5, 5.0], volume.spacing());
+    assert_eq!([-10.0, 20.0, 0.0], volume.origin());
+    assert_eq!(
+        [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        volume.direction()
+    );
+    assert_eq!(Some(&[2.0; 4][..]), volume.slice(1));
+    assert_eq!([-8.5, 20.5, 10.0], volume.position(2.0, 1.0, 2.0));
+    assert_eq!(VoxelType::I16, volume.voxel_type());
+
+    let encoded: Vec<u8> = nifti::encode(&volume)?;
+    let f32_at = |offset: usize| {
+        f32::from_le_bytes(encoded[offset..offset + 4].try_into().expect("4 bytes"))
+    };
+    assert_eq!(352 + 12 * 2, encoded.len());
+    assert_eq!(348i32.to_le_bytes(), encoded[0..4]);
+    assert_eq!(b"n+1\0", &encoded[344..348]);
+    // Dimensions, then the INT16 datatype.
+    assert_eq!([3, 0, 2, 0, 2, 0, 3, 0], encoded[40..48]);
+    assert_eq!(4i16.to_le_bytes(), encoded[70..72]);
+    // The first two axes are flipped into RAS, a rotation of 180 degrees about the third.
+    assert_eq!(1.0, f32_at(76));
+    assert_eq!([0.0, 0.0, 1.0], [f32_at(256), f32_at(260), f32_at(264)]);
+    assert_eq!(
+        [-0.75, 0.0, 0.0, 10.0],
+        [f32_at(280), f32_at(284), f32_at(288), f32_at(292)]
+    );
+    assert_eq!(
+        [0.0, 0.0, 5.0, 0.0],
+        [f32_at(312), f32_at(316), f32_at(320), f32_at(324)]
+    );
+    assert_eq!([2, 0], encoded[352 + 8..352 + 10]);
+
+    let header: String = metaimage::header(&volume, "volume.raw");
+    assert!(header.contains("TransformMatrix = 1 0 0 0 1 0 0 0 1\n"));
+    assert!(header.contains("Offset = -10 20 0\n"));
+    assert!(header.contains("AnatomicalOrientation = RAI\n"));
+    assert!(header.contains("ElementSpacing = 0.75 0.5 5\n"));
+    assert!(header.contains("DimSize = 2 2 3\n"));
+    assert!(header.contains("ElementType = MET_SHORT\n"));
+    assert!(header.ends_with("ElementDataFile = volume.raw\n"));
+    assert_eq!(12 * 2, metaimage::encode_raw(&volume).len());
+
+    // Slices must be evenly spaced.
+    let uneven = [
+        axial_slice(0.0, 1),
+        axial_slice(5.0, 2),
+        axial_slice(12.0, 3),
+    ];
+    assert!(matches!(
+        Volume::from_instances(&uneven),
+        Err(PixelDataError::InvalidGeometry(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_volume_import() -> Result<(), PixelDataError> {
+    let close = |a: &[f64], b: &[f64], tolerance: f64| {
+        a.len() == b.len() && a.iter().zip(b).all(|(a, b)| (a - b).abs() <= tolerance)
+    };
+    // An oblique volume of values which aren't integers, rotated about the third axis.
+    let (sin, cos) = 30f64.to_radians().sin_cos();
+    let direction: [[f64; 3]; 3] = [[cos, sin, 0.0], [-sin, cos, 0.0], [0.0, 0.0, 1.0]];
+    let values: Vec<f64> = (0..12).map(|i| f64::from(i) * 0.25 - 1.0).collect();
+    let volume: Volume = Volume::new(
+        [2, 2, 3],
+        [0.75, 0.5, 2.5],
+        [-10.0, 20.0, 5.0],
+        direction,
+        values.clone(),
+    )?;
+
+    let decoded: Volume = nifti::decode(&nifti::encode(&volume)?)?;
+    assert_eq!([2, 2, 3], decoded.dimensions());
+    assert!(close(&decoded.spacing(), &volume.spacing(), 1e-6));
+    assert!(close(&decoded.origin(), &volume.origin(), 1e-6));
+    for (decoded, axis) in decoded.direction().iter().zip(&direction) {
+        assert!(close(decoded, axis, 1e-6));
+    }
+    assert!(close(decoded.values(), &values, 1e-6));
+
+    let header: String = metaimage::header(&volume, "volume.raw");
+    assert_eq!("volume.raw", metaimage::data_file(&header)?);
+    let decoded: Volume = metaimage::decode(&header, &metaimage::encode_raw(&volume))?;
+    assert!(close(&decoded.origin(), &volume.origin(), 1e-9));
+    assert!(close(decoded.values(), &values, 1e-6));
+    assert!(matches!(
+        metaimage::decode(&header, &[0; 8]),
+        Err(PixelDataError::InvalidVolumeFile(_))
+    ));
+
+    // Values are rescaled to span unsigned samples.
+    let encoding = volume.sample_encoding();
+    assert!(!encoding.signed);
+    assert_eq!(-1.0, encoding.intercept);
+
+    // Each slice replaces the Pixel Data of a template, which is re-assembled into the volume.
+    let mut slices: Vec<DicomRoot<'static>> = Vec::new();
+    for slice in 0..3 {
+        let mut dcmroot: DicomRoot<'static> = axial_slice(0.0, 9);
+        volume::insert_slice(&mut dcmroot, &volume, slice, "test")?;
+        slices.push(dcmroot);
+    }
+    let mut enhanced: DicomRoot<'static> = axial_slice(0.0, 9);
+    volume::insert_frames(&mut enhanced, &volume, "test")?;
+    assert!(enhanced
+        .get_child_by_tag(tags::PerFrameFunctionalGroupsSequence.tag)
+        .is_some_and(|obj| obj.item_count() == 3));
+    for assembled in [
+        Volume::from_instances(&slices)?,
+        Volume::from_instances([&enhanced])?,
+    ] {
+        assert_eq!([2, 2, 3], assembled.dimensions());
+        assert!(close(&assembled.spacing(), &volume.spacing(), 1e-9));
+        assert!(close(&assembled.origin(), &volume.origin(), 1e-9));
+        assert!(close(&assembled.direction()[2], &direction[2], 1e-9));
+        assert!(close(assembled.values(), &values, encoding.slope));
+    }
+    assert!(matches!(
+        volume::insert_slice(&mut enhanced, &volume, 3, "test"),
+        Err(PixelDataError::FrameOutOfRange { .. })
+    ));
+    Ok(())
+}
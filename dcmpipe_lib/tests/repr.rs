@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+
+use dcmpipe_lib::{
+    core::{
+        charset::DEFAULT_CHARACTER_SET,
+        dcmelement::DicomElement,
+        dcmobject::{DicomObject, DicomRoot},
+        defn::vl::ValueLength,
+        repr::{DatasetRepr, ReprError},
+        values::RawValue,
+        write::{builder::WriterBuilder, error::WriteError, writer::Writer},
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags, transfer_syntaxes as ts},
+    testkit::Fixture,
+};
+
+mod common;
+
+use common::parse_fixture;
+
+fn write(dcmroot: &DicomRoot<'_>) -> Result<Vec<u8>, WriteError> {
+    let mut writer: Writer<Vec<u8>> = WriterBuilder::for_file().ts(dcmroot.ts()).build(Vec::new());
+    writer.write_dcmroot(dcmroot)?;
+    writer.into_dataset()
+}
+
+/// Checks that datasets deserialized from their representation write the same bytes as the
+/// datasets they were serialized from.
+#[test]
+fn test_roundtrip_fixtures() -> Result<(), WriteError> {
+    for fixture in Fixture::ALL {
+        let dcmroot: DicomRoot<'_> = parse_fixture(fixture, true)?;
+        let json: String = serde_json::to_string(&dcmroot).expect("Dataset should serialize");
+        let deserialized: DicomRoot<'static> =
+            serde_json::from_str(&json).expect("Dataset should deserialize");
+        assert_eq!(
+            write(&dcmroot)?,
+            write(&deserialized)?,
+            "fixture {}",
+            fixture.name()
+        );
+    }
+    Ok(())
+}
+
+/// Checks the structure of the representation, and that it can be edited before creating a
+/// dataset.
+#[test]
+fn test_repr_structure() -> Result<(), ReprError> {
+    let fixture = Fixture::UndefinedLengthSequence;
+    let dcmroot: DicomRoot<'_> = parse_fixture(fixture, true)?;
+    let mut repr: DatasetRepr = DatasetRepr::try_from(&dcmroot)?;
+    assert_eq!(ts::ExplicitVRLittleEndian.uid().uid(), repr.ts);
+
+    let json: serde_json::Value = serde_json::to_value(&repr).expect("Dataset should serialize");
+    let elements: &Vec<serde_json::Value> = json["elements"].as_array().expect("elements");
+    let patient_id: &serde_json::Value = elements
+        .iter()
+        .find(|element| element["tag"] == "00100020")
+        .expect("PatientID should be present");
+    assert_eq!("LO", patient_id["vr"]);
+    assert_eq!(
+        serde_json::json!({ "Strings": ["TESTKIT"] }),
+        patient_id["value"]
+    );
+
+    // Delimiters are omitted in favor of marking the sequence and its items as undefined length.
+    let sequence: &serde_json::Value = elements
+        .iter()
+        .find(|element| element["tag"] == "00081140")
+        .expect("ReferencedImageSequence should be present");
+    assert_eq!(true, sequence["undefined_length"]);
+    assert!(sequence.get("elements").is_none());
+    let items: &Vec<serde_json::Value> = sequence["items"].as_array().expect("items");
+    assert_eq!(2, items.len());
+    assert_eq!("FFFEE000", items[1]["tag"]);
+    assert_eq!(
+        serde_json::json!({ "Uid": format!("{}.2", fixture.sop_instance_uid()) }),
+        items[1]["elements"][1]["value"]
+    );
+
+    let patient_id = repr
+        .elements
+        .iter_mut()
+        .find(|element| element.tag == tags::PatientID.tag)
+        .expect("PatientID should be present");
+    patient_id.value = Some(RawValue::Strings(vec!["EDITED".to_owned()]));
+    let edited: DicomRoot<'_> = repr.to_dcmroot(&STANDARD_DICOM_DICTIONARY)?;
+    let value: String = String::try_from(
+        edited
+            .get_child_by_tag(tags::PatientID.tag)
+            .expect("PatientID should be present")
+            .element(),
+    )?;
+    assert_eq!("EDITED", value);
+    Ok(())
+}
+
+/// Checks that decimal strings aren't reformatted, and that RawValue round-trips on its own.
+#[test]
+fn test_decimal_strings() -> Result<(), ReprError> {
+    let mut element: DicomElement<'static> = DicomElement::new_empty(
+        &tags::SliceThickness,
+        &dcmpipe_lib::core::defn::vr::DS,
+        &ts::ExplicitVRLittleEndian,
+    );
+    element.encode_value(RawValue::Strings(vec!["1.50".to_owned()]), None)?;
+    let mut child_nodes: BTreeMap<u32, DicomObject<'static>> = BTreeMap::new();
+    child_nodes.insert(element.tag(), DicomObject::new(element));
+    let dcmroot: DicomRoot<'_> = DicomRoot::new(
+        &ts::ExplicitVRLittleEndian,
+        DEFAULT_CHARACTER_SET,
+        &STANDARD_DICOM_DICTIONARY,
+        child_nodes,
+        Vec::new(),
+    );
+
+    let repr: DatasetRepr = DatasetRepr::try_from(&dcmroot)?;
+    let json: String = serde_json::to_string(&repr.elements[0].value).expect("value");
+    assert_eq!(r#"{"Strings":["1.50"]}"#, json);
+    let value: RawValue = serde_json::from_str(&json).expect("value");
+    assert!(matches!(value, RawValue::Strings(values) if values == ["1.50"]));
+
+    let deserialized: DicomRoot<'_> = repr.to_dcmroot(&STANDARD_DICOM_DICTIONARY)?;
+    let element: &DicomElement<'_> = deserialized
+        .get_child_by_tag(tags::SliceThickness.tag)
+        .expect("SliceThickness should be present")
+        .element();
+    assert_eq!(b"1.50", element.data());
+    assert_eq!(ValueLength::Explicit(4), element.vl());
+
+    let mut invalid: DatasetRepr = repr.clone();
+    invalid.elements[0].vr = "XX".to_owned();
+    assert!(matches!(
+        invalid.to_dcmroot(&STANDARD_DICOM_DICTIONARY),
+        Err(ReprError::UnknownVR { .. })
+    ));
+    Ok(())
+}
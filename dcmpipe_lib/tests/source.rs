@@ -0,0 +1,179 @@
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use libflate::gzip;
+use zip::{write::SimpleFileOptions, ZipWriter};
+
+use dcmpipe_lib::{
+    core::{dcmobject::DicomRoot, read::ParserBuilder},
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags},
+    progress::Progress,
+    source::{archive_kind, ArchiveKind, Entries, Source, SourceEntry},
+    testkit::Fixture,
+};
+
+/// Creates an empty folder for a test's source.
+fn source_folder(name: &str) -> PathBuf {
+    let folder: PathBuf = std::env::temp_dir().join(format!("dcmpipe_source_{name}"));
+    let _ = fs::remove_dir_all(&folder);
+    fs::create_dir_all(&folder).expect("Source folder should be created");
+    folder
+}
+
+/// The fixtures as files named as they would be on a patient CD.
+fn fixture_files() -> Vec<(String, Vec<u8>)> {
+    Fixture::ALL
+        .iter()
+        .enumerate()
+        .map(|(index, fixture)| {
+            let bytes: Vec<u8> = fixture.to_bytes().expect("Fixture should be writable");
+            (format!("DICOM/IM{index:04}"), bytes)
+        })
+        .collect()
+}
+
+fn write_zip(path: &Path) {
+    let mut zip: ZipWriter<File> =
+        ZipWriter::new(File::create(path).expect("Zip should be created"));
+    zip.add_directory("DICOM", SimpleFileOptions::default())
+        .expect("Folder should be added");
+    for (name, bytes) in fixture_files() {
+        zip.start_file(name, SimpleFileOptions::default())
+            .expect("File should be added");
+        zip.write_all(&bytes).expect("File should be written");
+    }
+    zip.finish().expect("Zip should be written");
+}
+
+fn tar_bytes() -> Vec<u8> {
+    let mut tar: tar::Builder<Vec<u8>> = tar::Builder::new(Vec::new());
+    for (name, bytes) in fixture_files() {
+        // A name longer than the 100 bytes of the header requires an extension header.
+        let name: String = if name.ends_with("0001") {
+            format!("{}/{name}", "LONG".repeat(30))
+        } else {
+            name
+        };
+        let mut header: tar::Header = tar::Header::new_gnu();
+        header.set_size(bytes.len() as u64);
+        header.set_mode(0o644);
+        tar.append_data(&mut header, name, bytes.as_slice())
+            .expect("File should be added");
+    }
+    tar.into_inner().expect("Tar should be written")
+}
+
+/// Parses each entry, returning its path relative to the source and its SOPInstanceUID.
+fn parse_entries(source: &Source) -> Vec<(PathBuf, String)> {
+    let parser_builder: ParserBuilder<'_> =
+        ParserBuilder::default().dictionary(&STANDARD_DICOM_DICTIONARY);
+    source
+        .entries()
+        .expect("Source should be listed")
+        .filter_map(|entry| {
+            let entry: SourceEntry = entry.expect("Entry should be read");
+            let reader = entry.open().expect("Entry should open");
+            let dcmroot: DicomRoot<'_> =
+                DicomRoot::parse(&mut parser_builder.build(reader)).ok()??;
+            let sop_uid: String = dcmroot
+                .get_child_by_tag(tags::SOPInstanceUID.tag)
+                .expect("SOPInstanceUID")
+                .element()
+                .try_into()
+                .expect("SOPInstanceUID value");
+            let relative: PathBuf = entry
+                .path()
+                .strip_prefix(source.path())
+                .expect("Entry should be within the source")
+                .to_path_buf();
+            Some((relative, sop_uid))
+        })
+        .collect()
+}
+
+#[test]
+fn test_source_reads_archives() {
+    let folder: PathBuf = source_folder("archives");
+    write_zip(&folder.join("export.zip"));
+    fs::write(folder.join("export.tar"), tar_bytes()).expect("Tar should be written");
+    let mut encoder = gzip::Encoder::new(Vec::new()).expect("Encoder");
+    encoder
+        .write_all(&tar_bytes())
+        .expect("Tar should be compressed");
+    let tgz: Vec<u8> = encoder
+        .finish()
+        .into_result()
+        .expect("Tar should be compressed");
+    fs::write(folder.join("export.tgz"), tgz).expect("Tar should be written");
+    fs::write(folder.join("notes.txt"), b"not dicom").expect("File should be written");
+
+    assert_eq!(
+        Some(ArchiveKind::Zip),
+        archive_kind(&folder.join("export.zip")).expect("Kind")
+    );
+    assert_eq!(
+        Some(ArchiveKind::Tar),
+        archive_kind(&folder.join("export.tar")).expect("Kind")
+    );
+    assert_eq!(
+        Some(ArchiveKind::TarGz),
+        archive_kind(&folder.join("export.tgz")).expect("Kind")
+    );
+    assert_eq!(None, archive_kind(&folder.join("notes.txt")).expect("Kind"));
+
+    let long_name: String = format!("{}/DICOM/IM0001", "LONG".repeat(30));
+    let sop_uid = |index: usize| Fixture::ALL[index].sop_instance_uid().to_owned();
+    let mut expected: Vec<(PathBuf, String)> = Vec::new();
+    for archive in ["export.tar", "export.tgz"] {
+        for index in 0..Fixture::ALL.len() {
+            let name: String = if index == 1 {
+                long_name.clone()
+            } else {
+                format!("DICOM/IM{index:04}")
+            };
+            expected.push((Path::new(archive).join(name), sop_uid(index)));
+        }
+    }
+    for index in 0..Fixture::ALL.len() {
+        let name: String = format!("DICOM/IM{index:04}");
+        expected.push((Path::new("export.zip").join(name), sop_uid(index)));
+    }
+    assert_eq!(expected, parse_entries(&Source::new(&folder)));
+
+    // An archive can also be the source itself, and plain files are single entries.
+    let zip: Source = Source::new(folder.join("export.zip"));
+    assert_eq!(Fixture::ALL.len(), parse_entries(&zip).len());
+    let notes: Vec<SourceEntry> = Source::new(folder.join("notes.txt"))
+        .entries()
+        .expect("Source should be listed")
+        .collect::<Result<_, _>>()
+        .expect("Entry should be read");
+    assert!(matches!(notes.as_slice(), [entry] if !entry.is_archived()));
+
+    // Progress is measured through the files on disk, including archives as they're read.
+    let total_bytes: u64 = ["export.zip", "export.tar", "export.tgz", "notes.txt"]
+        .iter()
+        .map(|name| fs::metadata(folder.join(name)).expect("Metadata").len())
+        .sum();
+    let mut entries: Entries = Source::new(&folder).entries().expect("Source");
+    assert_eq!(Some(total_bytes), entries.progress().total_bytes());
+    assert_eq!(0, entries.progress().bytes());
+    let mut listed: u64 = 0;
+    let mut last_bytes: u64 = 0;
+    while let Some(entry) = entries.next() {
+        entry.expect("Entry should be read");
+        listed += 1;
+        let progress: &Progress = entries.progress();
+        assert_eq!(listed, progress.files());
+        assert!(progress.bytes() >= last_bytes);
+        last_bytes = progress.bytes();
+    }
+    assert_eq!(total_bytes, entries.progress().bytes());
+    assert_eq!(Some(1.0), entries.progress().fraction());
+    assert_eq!(Some(std::time::Duration::ZERO), entries.progress().eta());
+
+    fs::remove_dir_all(&folder).expect("Source folder should be removed");
+}
@@ -0,0 +1,460 @@
+use std::{
+    convert::TryFrom,
+    fs,
+    io::Cursor,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use dcmpipe_lib::{
+    core::{
+        dcmelement::ValueField,
+        dcmobject::{DicomObject, DicomRoot},
+        defn::{vl::ValueLength, vr},
+        read::{
+            mmap::MappedFile,
+            observer::{ElementEvent, ParseStats, LARGEST_ELEMENTS},
+            stop::ParseStop,
+            ParseResult, Parser, ParserBuilder,
+        },
+    },
+    dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags},
+    testkit::{self, Fixture},
+};
+
+mod common;
+
+use common::{parse_all_dcmroot_values, parse_fixture};
+
+#[test]
+fn test_all_fixtures_parse() -> ParseResult<()> {
+    for fixture in Fixture::ALL {
+        for with_std in [true, false] {
+            let dcmroot: DicomRoot<'_> = parse_fixture(fixture, with_std)?;
+            assert_eq!(fixture.ts(), dcmroot.ts());
+            let sop_uid = dcmroot
+                .get_child_by_tag(tags::SOPInstanceUID.tag)
+                .expect("Fixture should have SOPInstanceUID")
+                .element();
+            assert_eq!(fixture.sop_instance_uid(), String::try_from(sop_uid)?);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_small_buffsize() -> ParseResult<()> {
+    // Buffer sizes smaller than element headers force their bytes to span refills of the buffer.
+    for fixture in Fixture::ALL {
+        let expected: DicomRoot<'static> = parse_fixture(fixture, true)?;
+        let expected_elements = expected.flatten().expect("Fixture should flatten");
+        let bytes: Vec<u8> = fixture.to_bytes().expect("Fixture should be writable");
+        for buffsize in [1, 3, 7] {
+            let mut parser: Parser<'_, &[u8]> = ParserBuilder::default()
+                .dictionary(&STANDARD_DICOM_DICTIONARY)
+                .buffsize(buffsize)
+                .build(bytes.as_slice());
+            let dcmroot: DicomRoot<'_> =
+                DicomRoot::parse(&mut parser)?.expect("Fixture should be DICOM");
+            let elements = dcmroot.flatten().expect("Fixture should flatten");
+            assert_eq!(expected_elements.len(), elements.len());
+            for (expected, element) in expected_elements.iter().zip(elements) {
+                assert_eq!(expected.tag(), element.tag());
+                assert_eq!(expected.vr(), element.vr());
+                assert_eq!(expected.data(), element.data());
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_undefined_length_sq() -> ParseResult<()> {
+    let dcmroot: DicomRoot<'_> = parse_fixture(Fixture::UndefinedLengthSequence, true)?;
+
+    let seq_obj: &DicomObject = dcmroot
+        .get_child_by_tag(tags::ReferencedImageSequence.tag)
+        .expect("Fixture should have sequence");
+    assert_eq!(ValueLength::UndefinedLength, seq_obj.element().vl());
+    assert_eq!(2, seq_obj.item_count());
+
+    let nested_obj: &DicomObject = seq_obj
+        .get_item_by_index(1)
+        .expect("Sequence should have first item")
+        .get_child_by_tag(tags::SourceImageSequence.tag)
+        .expect("First item should have nested sequence");
+    assert_eq!(1, nested_obj.item_count());
+
+    // The element following the sequence should be back at the root level.
+    assert!(dcmroot.get_child_by_tag(tags::PatientID.tag).is_some());
+    Ok(())
+}
+
+#[test]
+fn test_nested_un_sq() -> ParseResult<()> {
+    for with_std in [true, false] {
+        let dcmroot: DicomRoot<'_> = parse_fixture(Fixture::NestedUnSequence, with_std)?;
+
+        let un_seq_obj: &DicomObject = dcmroot
+            .get_child_by_tag(testkit::FIXTURE_PRIVATE_UN_SEQ_TAG)
+            .expect("Fixture should have private sequence");
+        assert_eq!(&vr::UN, un_seq_obj.element().vr());
+        assert!(un_seq_obj.element().is_seq_like());
+        assert_eq!(1, un_seq_obj.item_count());
+
+        let nested_obj: &DicomObject = un_seq_obj
+            .get_item_by_index(1)
+            .expect("Private sequence should have one item")
+            .get_child_by_tag(testkit::FIXTURE_PRIVATE_NESTED_SEQ_TAG)
+            .expect("Item should have nested private sequence");
+        assert!(nested_obj.element().is_seq_like());
+        assert_eq!(2, nested_obj.item_count());
+
+        let leaf = nested_obj
+            .get_item_by_index(2)
+            .expect("Nested sequence should have two items")
+            .get_child_by_tag(testkit::FIXTURE_PRIVATE_VALUE_TAG)
+            .expect("Item should have private value");
+        assert_eq!(&[2u8, 0u8], leaf.element().data());
+
+        assert!(dcmroot.get_child_by_tag(tags::PatientID.tag).is_some());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_deflated() -> ParseResult<()> {
+    let dcmroot: DicomRoot<'_> = parse_fixture(Fixture::DeflatedDataset, true)?;
+    assert!(dcmroot.ts().deflated());
+
+    let pn = dcmroot
+        .get_child_by_tag(tags::PatientsName.tag)
+        .expect("Fixture should have PatientsName")
+        .element();
+    assert_eq!("Deflated^Dataset", String::try_from(pn)?);
+    Ok(())
+}
+
+#[test]
+fn test_multi_charset_pn() -> ParseResult<()> {
+    let dcmroot: DicomRoot<'_> = parse_fixture(Fixture::MultiCharsetPersonName, true)?;
+
+    let pn = dcmroot
+        .get_child_by_tag(tags::PatientsName.tag)
+        .expect("Fixture should have PatientsName")
+        .element();
+    // The encoded value should include ISO 2022 escape sequences.
+    assert!(pn.data().contains(&0x1B));
+    assert_eq!(testkit::FIXTURE_MULTI_CHARSET_PN, String::try_from(pn)?);
+    Ok(())
+}
+
+#[test]
+fn test_mapped_file_values() -> ParseResult<()> {
+    for fixture in Fixture::ALL {
+        let path: PathBuf =
+            std::env::temp_dir().join(format!("dcmpipe_mmap_{}.dcm", fixture.name()));
+        fs::write(
+            &path,
+            fixture.to_bytes().expect("Fixture should be writable"),
+        )?;
+        let mapped: MappedFile = MappedFile::open(&path)?;
+        fs::remove_file(&path)?;
+
+        let builder: ParserBuilder<'_> =
+            ParserBuilder::default().dictionary(&STANDARD_DICOM_DICTIONARY);
+        let mut parser: Parser<'_, &[u8]> = mapped.parser(&builder);
+        let dcmroot: DicomRoot<'_> =
+            DicomRoot::parse(&mut parser)?.expect("Fixture should be DICOM");
+        parse_all_dcmroot_values(&dcmroot)?;
+
+        let elements = dcmroot.flatten().expect("Fixture should flatten");
+        for element in elements {
+            // Only the File Meta group of a deflated dataset has positions within the file.
+            let in_deflated: bool = dcmroot.ts().deflated() && element.tag() > 0x0002_FFFF;
+            match mapped.value(element) {
+                Some(value) => {
+                    assert!(!in_deflated);
+                    assert_eq!(&element.data()[..value.len()], value);
+                }
+                None => assert!(in_deflated || element.is_seq_like() || element.data().is_empty()),
+            }
+        }
+
+        let borrowed: DicomRoot<'_> = mapped.parse(&builder)?.expect("Fixture should be DICOM");
+        let elements = dcmroot.flatten().expect("Fixture should flatten");
+        let borrowed_elements = borrowed.flatten().expect("Fixture should flatten");
+        assert_eq!(elements.len(), borrowed_elements.len());
+        for (element, borrowed_element) in elements.iter().zip(borrowed_elements) {
+            assert_eq!(element.data(), borrowed_element.data());
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_deferred_values() -> ParseResult<()> {
+    for fixture in Fixture::ALL {
+        let owned: DicomRoot<'static> = parse_fixture(fixture, true)?;
+
+        let bytes: Vec<u8> = fixture.to_bytes().expect("Fixture should be writable");
+        let mut parser: Parser<'_, &[u8]> = ParserBuilder::default()
+            .dictionary(&STANDARD_DICOM_DICTIONARY)
+            .defer_values(0)
+            .build(bytes.as_slice());
+        let deferred: DicomRoot<'_> =
+            DicomRoot::parse(&mut parser)?.expect("Fixture should be DICOM");
+
+        let elements = deferred.flatten().expect("Fixture should flatten");
+        let owned_elements = owned.flatten().expect("Fixture should flatten");
+        assert_eq!(owned_elements.len(), elements.len());
+        let was_deferred: Vec<bool> = elements.iter().map(|e| e.is_deferred()).collect();
+        for (owned_element, element) in owned_elements.iter().zip(elements) {
+            assert_eq!(owned_element.value_len(), element.value_len());
+            if element.is_deferred() {
+                assert!(element.tag() > 0x0002_FFFF);
+                assert!(!deferred.ts().deflated());
+                assert!(element.parse_value().is_err());
+            }
+        }
+
+        let resolved: DicomRoot<'_> = deferred.resolve_deferred(&bytes)?;
+        parse_all_dcmroot_values(&resolved)?;
+        let elements = resolved.flatten().expect("Fixture should flatten");
+        for ((owned_element, element), was_deferred) in
+            owned_elements.iter().zip(elements).zip(was_deferred)
+        {
+            assert!(!element.is_deferred());
+            assert_eq!(owned_element.data(), element.data());
+            let is_even: bool = matches!(element.vl(), ValueLength::Explicit(len) if len % 2 == 0);
+            if was_deferred && is_even {
+                assert!(matches!(element.value_field(), ValueField::Borrowed(_)));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_resume_after_stop() -> ParseResult<()> {
+    for fixture in Fixture::ALL {
+        let bytes: Vec<u8> = fixture.to_bytes().expect("Fixture should be writable");
+        let mut parser: Parser<'_, Cursor<&[u8]>> = ParserBuilder::default()
+            .dictionary(&STANDARD_DICOM_DICTIONARY)
+            .build(Cursor::new(bytes.as_slice()));
+        let full: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Fixture should be DICOM");
+        let full_elements = full.flatten().expect("Fixture should flatten");
+
+        // Stop before each element in turn, including those within sequences.
+        for (tagpath, _) in full.iter_elements().skip(1) {
+            let mut parser: Parser<'_, Cursor<&[u8]>> = ParserBuilder::default()
+                .dictionary(&STANDARD_DICOM_DICTIONARY)
+                .stop(ParseStop::BeforeTagValue(tagpath.clone()))
+                .build(Cursor::new(bytes.as_slice()));
+            let mut dcmroot: DicomRoot<'_> =
+                DicomRoot::parse(&mut parser)?.expect("Fixture should be DICOM");
+            assert!(parser.is_stopped(), "{tagpath:?}");
+            assert!(dcmroot.get_child_by_tagpath(&tagpath).is_none());
+
+            assert!(parser.resume(ParseStop::EndOfDataset));
+            dcmroot.parse_resumed(&mut parser)?;
+            assert!(!parser.is_stopped());
+            assert!(!parser.resume(ParseStop::EndOfDataset));
+
+            assert_eq!(full.ts(), dcmroot.ts());
+            let elements = dcmroot.flatten().expect("Fixture should flatten");
+            assert_eq!(full_elements.len(), elements.len(), "{tagpath:?}");
+            for (full_element, element) in full_elements.iter().zip(elements) {
+                assert_eq!(full_element.create_tagpath(), element.create_tagpath());
+                assert_eq!(full_element.data(), element.data());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Parses the fixture with the given stop, checking the elements following sequences are read.
+fn parse_fixture_with_stop(fixture: Fixture, stop: ParseStop) -> ParseResult<DicomRoot<'static>> {
+    let bytes: Vec<u8> = fixture.to_bytes().expect("Fixture should be writable");
+    let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .stop(stop)
+        .build(Cursor::new(bytes));
+    let dcmroot: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Fixture should be DICOM");
+    assert!(!parser.is_stopped());
+    assert!(dcmroot.get_child_by_tag(tags::PatientID.tag).is_some());
+    Ok(dcmroot)
+}
+
+/// Creates an Explicit VR Little Endian element header.
+fn evrle_header(tag: u32, vr: &[u8; 2], len: u32) -> Vec<u8> {
+    let mut header: Vec<u8> = Vec::new();
+    header.extend_from_slice(&((tag >> 16) as u16).to_le_bytes());
+    header.extend_from_slice(&(tag as u16).to_le_bytes());
+    header.extend_from_slice(vr);
+    if vr == b"SQ" {
+        header.extend_from_slice(&[0, 0]);
+        header.extend_from_slice(&len.to_le_bytes());
+    } else {
+        header.extend_from_slice(&(len as u16).to_le_bytes());
+    }
+    header
+}
+
+#[test]
+fn test_skip_sequences() -> ParseResult<()> {
+    let item_count = |dcmroot: &DicomRoot<'_>| {
+        dcmroot
+            .get_child_by_tag(tags::ReferencedImageSequence.tag)
+            .expect("Fixture should have sequence")
+            .item_count()
+    };
+
+    let skipped: DicomRoot<'_> = parse_fixture_with_stop(
+        Fixture::UndefinedLengthSequence,
+        ParseStop::SkipSequences([&tags::ReferencedImageSequence].into_iter().collect()),
+    )?;
+    assert_eq!(0, item_count(&skipped));
+
+    let unrelated: DicomRoot<'_> = parse_fixture_with_stop(
+        Fixture::UndefinedLengthSequence,
+        ParseStop::SkipSequences([&tags::SourceImageSequence].into_iter().collect()),
+    )?;
+    assert_eq!(2, item_count(&unrelated));
+    let nested_obj: &DicomObject = unrelated
+        .get_child_by_tag(tags::ReferencedImageSequence.tag)
+        .and_then(|seq_obj| seq_obj.get_item_by_index(1))
+        .and_then(|item_obj| item_obj.get_child_by_tag(tags::SourceImageSequence.tag))
+        .expect("First item should have nested sequence");
+    assert_eq!(0, nested_obj.item_count());
+
+    let root_only: DicomRoot<'_> = parse_fixture_with_stop(
+        Fixture::UndefinedLengthSequence,
+        ParseStop::MaxSequenceDepth(0),
+    )?;
+    assert_eq!(0, item_count(&root_only));
+
+    // The nested private sequence is within the item of the first, so is at a depth of 2.
+    let depth_one: DicomRoot<'_> =
+        parse_fixture_with_stop(Fixture::NestedUnSequence, ParseStop::MaxSequenceDepth(1))?;
+    let un_seq_obj: &DicomObject = depth_one
+        .get_child_by_tag(testkit::FIXTURE_PRIVATE_UN_SEQ_TAG)
+        .expect("Fixture should have private sequence");
+    assert_eq!(1, un_seq_obj.item_count());
+    let nested_obj: &DicomObject = un_seq_obj
+        .get_item_by_index(1)
+        .and_then(|item_obj| item_obj.get_child_by_tag(testkit::FIXTURE_PRIVATE_NESTED_SEQ_TAG))
+        .expect("Item should have nested private sequence");
+    assert_eq!(0, nested_obj.item_count());
+
+    for fixture in Fixture::ALL {
+        let full: DicomRoot<'_> = parse_fixture(fixture, true)?;
+        let unlimited: DicomRoot<'_> =
+            parse_fixture_with_stop(fixture, ParseStop::MaxSequenceDepth(u32::MAX))?;
+        assert_eq!(
+            full.flatten().expect("Fixture should flatten").len(),
+            unlimited.flatten().expect("Fixture should flatten").len()
+        );
+    }
+
+    // Sequences and items with explicit lengths are skipped over without parsing their contents.
+    let mut item: Vec<u8> = evrle_header(tags::ReferencedSOPInstanceUID.tag, b"UI", 4);
+    item.extend_from_slice(b"1.2\0");
+    let mut seq: Vec<u8> = Vec::new();
+    seq.extend_from_slice(&0xFFFE_u16.to_le_bytes());
+    seq.extend_from_slice(&0xE000_u16.to_le_bytes());
+    seq.extend_from_slice(&(item.len() as u32).to_le_bytes());
+    seq.extend_from_slice(&item);
+    let mut dataset: Vec<u8> = evrle_header(tags::SOPInstanceUID.tag, b"UI", 4);
+    dataset.extend_from_slice(b"1.2\0");
+    dataset.extend(evrle_header(
+        tags::ReferencedImageSequence.tag,
+        b"SQ",
+        seq.len() as u32,
+    ));
+    dataset.extend_from_slice(&seq);
+    dataset.extend(evrle_header(tags::PatientID.tag, b"LO", 4));
+    dataset.extend_from_slice(b"TEST");
+
+    for (stop, expected_items) in [
+        (ParseStop::EndOfDataset, 1),
+        (ParseStop::MaxSequenceDepth(0), 0),
+    ] {
+        let mut parser: Parser<'_, Cursor<&[u8]>> = ParserBuilder::default()
+            .dictionary(&STANDARD_DICOM_DICTIONARY)
+            .stop(stop)
+            .build(Cursor::new(dataset.as_slice()));
+        let dcmroot: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Should be DICOM");
+        assert_eq!(expected_items, item_count(&dcmroot));
+        let patient_id: String = dcmroot
+            .get_child_by_tag(tags::PatientID.tag)
+            .expect("Should have PatientID")
+            .element()
+            .try_into()?;
+        assert_eq!("TEST", patient_id);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_parse_observer() -> ParseResult<()> {
+    let stats: Arc<Mutex<ParseStats>> = ParseStats::shared();
+    let parser_builder: ParserBuilder<'_> = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .observer(stats.clone());
+    let mut total_elements: usize = 0;
+    for fixture in Fixture::ALL {
+        let bytes: Vec<u8> = fixture.to_bytes().expect("Fixture should be writable");
+        let events: Arc<Mutex<Vec<(u32, u64, u64)>>> = Arc::new(Mutex::new(Vec::new()));
+        let recorded = events.clone();
+        let mut parser: Parser<'_, Cursor<&[u8]>> = ParserBuilder::default()
+            .dictionary(&STANDARD_DICOM_DICTIONARY)
+            .observer(Arc::new(Mutex::new(move |event: &ElementEvent| {
+                recorded.lock().expect("Events").push((
+                    event.element.tag(),
+                    event.offset,
+                    event.len,
+                ));
+            })))
+            .build(Cursor::new(bytes.as_slice()));
+        let dcmroot: DicomRoot<'_> =
+            DicomRoot::parse(&mut parser)?.expect("Fixture should be DICOM");
+        DicomRoot::parse(&mut parser_builder.build(Cursor::new(bytes.as_slice())))?;
+
+        // Elements are reported in order, each starting where the previous one ended.
+        let events = events.lock().expect("Events");
+        assert_eq!(
+            dcmroot.flatten().expect("Should flatten").len(),
+            events.len()
+        );
+        total_elements += events.len();
+        assert_eq!(
+            Some(tags::FileMetaInformationGroupLength.tag),
+            events.first().map(|e| e.0)
+        );
+        assert_eq!(132, events[0].1);
+        for window in events.windows(2) {
+            assert_eq!(
+                window[0].1 + window[0].2,
+                window[1].1,
+                "{:08X}",
+                window[1].0
+            );
+        }
+        if !fixture.ts().deflated() {
+            let last = events.last().expect("Should have elements");
+            assert_eq!(bytes.len() as u64, last.1 + last.2);
+        }
+    }
+
+    let stats = stats.lock().expect("Stats");
+    assert_eq!(total_elements, stats.elements());
+    assert_eq!(0, stats.failures());
+    assert_eq!(total_elements, stats.group_counts().values().sum::<usize>());
+    assert_eq!(LARGEST_ELEMENTS, stats.largest().len());
+    assert!(stats
+        .largest()
+        .windows(2)
+        .all(|window| window[0].len >= window[1].len));
+    Ok(())
+}
@@ -0,0 +1,348 @@
+use dcmpipe_lib::{
+    core::{
+        dcmobject::DicomRoot,
+        defn::vr::{self, VRRef},
+        private::{get_private, private_creator_tag, private_tag},
+        values::RawValue,
+        vendor::{
+            ge,
+            philips::{self, PhilipsScale},
+            siemens::{demosaic, CsaFormat, CsaHeader, Mosaic},
+            Diffusion, VendorError,
+        },
+    },
+    dict::transfer_syntaxes as ts,
+};
+
+mod common;
+
+use common::build_dataset;
+
+/// Encodes a CSA header of the given elements, each with a VR, VM, and values. Each element
+/// reserves six values, as scanners do.
+fn encode_csa(format: CsaFormat, elements: &[(&str, &str, u32, &[&str])]) -> Vec<u8> {
+    let mut data: Vec<u8> = Vec::new();
+    if format == CsaFormat::Csa2 {
+        data.extend(b"SV10\x04\x03\x02\x01");
+    }
+    data.extend((elements.len() as u32).to_le_bytes());
+    data.extend(77u32.to_le_bytes());
+    for (name, vr, vm, values) in elements {
+        let mut padded_name: Vec<u8> = name.as_bytes().to_vec();
+        padded_name.resize(64, 0);
+        data.extend(padded_name);
+        data.extend(vm.to_le_bytes());
+        let mut padded_vr: Vec<u8> = vr.as_bytes().to_vec();
+        padded_vr.resize(4, 0);
+        data.extend(padded_vr);
+        data.extend(3u32.to_le_bytes());
+        let items: usize = values.len().max(6);
+        data.extend((items as u32).to_le_bytes());
+        data.extend(77u32.to_le_bytes());
+        for item in 0..items {
+            let mut value: Vec<u8> = values.get(item).map_or(Vec::new(), |value| {
+                let mut value: Vec<u8> = value.as_bytes().to_vec();
+                value.push(0);
+                value
+            });
+            let len: u32 = value.len() as u32;
+            for word in [len, len, 77, len] {
+                data.extend(word.to_le_bytes());
+            }
+            value.resize(value.len().div_ceil(4) * 4, 0);
+            data.extend(value);
+        }
+    }
+    data
+}
+
+/// Checks parsing both formats of CSA header.
+#[test]
+fn test_csa_header() -> Result<(), VendorError> {
+    let elements: [(&str, &str, u32, &[&str]); 3] = [
+        ("NumberOfImagesInMosaic", "US", 1, &["3"]),
+        ("B_value", "IS", 1, &["1000 "]),
+        (
+            "DiffusionGradientDirection",
+            "FD",
+            3,
+            &["0.6", "-0.8", "0.00000000"],
+        ),
+    ];
+    for format in [CsaFormat::Csa1, CsaFormat::Csa2] {
+        let csa: CsaHeader = CsaHeader::parse(&encode_csa(format, &elements))?;
+        assert_eq!(format, csa.format);
+        assert_eq!(3, csa.elements.len());
+
+        let b_value = csa.get("B_value").expect("B_value should be present");
+        assert_eq!(("IS", 1), (b_value.vr.as_str(), b_value.vm));
+        assert_eq!(vec!["1000".to_owned()], b_value.values);
+        assert_eq!(vec![1000], b_value.ints());
+        let direction = csa
+            .get("DiffusionGradientDirection")
+            .expect("DiffusionGradientDirection should be present");
+        assert_eq!(vec![0.6, -0.8, 0.0], direction.floats());
+        assert!(csa.get("SliceNormalVector").is_none());
+    }
+
+    let data: Vec<u8> = encode_csa(CsaFormat::Csa2, &elements);
+    assert!(matches!(
+        CsaHeader::parse(&data[..data.len() - 10]),
+        Err(VendorError::InvalidCsaHeader(_))
+    ));
+    assert!(matches!(
+        CsaHeader::parse(&[0u8; 16]),
+        Err(VendorError::InvalidCsaHeader(_))
+    ));
+    Ok(())
+}
+
+/// The elements of an 8-bit mosaic of 3 slices of 3x2 pixels, whose values are the slice number
+/// times 10 plus the index of the pixel in the slice.
+fn mosaic_elements() -> Vec<(u32, VRRef, RawValue)> {
+    let (rows, columns) = (4usize, 6usize);
+    let pixels: Vec<u8> = (0..rows * columns)
+        .map(|i| {
+            let (row, column) = (i / columns, i % columns);
+            let slice: usize = (row / 2) * 2 + column / 3;
+            if slice < 3 {
+                (slice * 10 + (row % 2) * 3 + column % 3) as u8
+            } else {
+                0
+            }
+        })
+        .collect();
+    vec![
+        (
+            0x0008_0008,
+            &vr::CS,
+            RawValue::Strings(
+                ["ORIGINAL", "PRIMARY", "M", "ND", "MOSAIC"]
+                    .map(str::to_owned)
+                    .to_vec(),
+            ),
+        ),
+        (0x0028_0002, &vr::US, RawValue::UnsignedShorts(vec![1])),
+        (
+            0x0028_0004,
+            &vr::CS,
+            RawValue::Strings(vec!["MONOCHROME2".to_owned()]),
+        ),
+        (0x0028_0010, &vr::US, RawValue::UnsignedShorts(vec![4])),
+        (0x0028_0011, &vr::US, RawValue::UnsignedShorts(vec![6])),
+        (0x0028_0100, &vr::US, RawValue::UnsignedShorts(vec![8])),
+        (0x0028_0101, &vr::US, RawValue::UnsignedShorts(vec![8])),
+        (0x0028_0103, &vr::US, RawValue::UnsignedShorts(vec![0])),
+        (0x7FE0_0010, &vr::OB, RawValue::Bytes(pixels)),
+    ]
+}
+
+/// Checks splitting a mosaic into slices, with the number of slices from either the CSA header or
+/// the MR header, each reserving a block other than the first.
+#[test]
+fn test_demosaic() -> Result<(), VendorError> {
+    let csa: Vec<u8> = encode_csa(
+        CsaFormat::Csa2,
+        &[("NumberOfImagesInMosaic", "US", 1, &["3"])],
+    );
+    let mut with_csa = mosaic_elements();
+    with_csa.extend([
+        (
+            0x0029_0010,
+            &vr::LO,
+            RawValue::Strings(vec!["SIEMENS MEDCOM HEADER".to_owned()]),
+        ),
+        (
+            0x0029_0011,
+            &vr::LO,
+            RawValue::Strings(vec!["SIEMENS CSA HEADER".to_owned()]),
+        ),
+        (0x0029_1110, &vr::OB, RawValue::Bytes(csa)),
+    ]);
+    let mut with_mr_header = mosaic_elements();
+    with_mr_header.extend([
+        (
+            0x0019_0012,
+            &vr::LO,
+            RawValue::Strings(vec!["SIEMENS MR HEADER".to_owned()]),
+        ),
+        (0x0019_120A, &vr::US, RawValue::UnsignedShorts(vec![3])),
+    ]);
+
+    for elements in [with_csa, with_mr_header] {
+        let dcmroot: DicomRoot = build_dataset(&ts::ExplicitVRLittleEndian, elements);
+        let mosaic: Mosaic = Mosaic::from_dataset(&dcmroot)?.expect("Dataset should be a mosaic");
+        assert_eq!(
+            Mosaic {
+                images: 3,
+                tiles: 2,
+                rows: 2,
+                columns: 3,
+            },
+            mosaic
+        );
+        let slices = demosaic(&dcmroot)?;
+        assert_eq!(3, slices.len());
+        for (slice, frame) in slices.iter().enumerate() {
+            assert_eq!((2, 3), (frame.rows(), frame.columns()));
+            let expected: Vec<f64> = (0..6).map(|i| (slice * 10 + i) as f64).collect();
+            assert_eq!(expected, frame.values());
+        }
+    }
+
+    let mut without_images = mosaic_elements();
+    without_images.push((
+        0x0019_0010,
+        &vr::LO,
+        RawValue::Strings(vec!["SIEMENS MR HEADER".to_owned()]),
+    ));
+    assert!(matches!(
+        Mosaic::from_dataset(&build_dataset(&ts::ExplicitVRLittleEndian, without_images)),
+        Err(VendorError::InvalidMosaic(_))
+    ));
+
+    let mut not_mosaic = mosaic_elements();
+    not_mosaic.remove(0);
+    let dcmroot: DicomRoot = build_dataset(&ts::ExplicitVRLittleEndian, not_mosaic);
+    assert_eq!(None, Mosaic::from_dataset(&dcmroot)?);
+    assert!(matches!(
+        demosaic(&dcmroot),
+        Err(VendorError::InvalidMosaic(_))
+    ));
+    Ok(())
+}
+
+/// Checks private attributes are resolved within the block reserved by their creator.
+#[test]
+fn test_private_tags() {
+    let dcmroot: DicomRoot = build_dataset(
+        &ts::ExplicitVRLittleEndian,
+        vec![
+            (
+                0x0029_0010,
+                &vr::LO,
+                RawValue::Strings(vec!["OTHER CREATOR".to_owned()]),
+            ),
+            (
+                0x0029_0011,
+                &vr::LO,
+                RawValue::Strings(vec!["SIEMENS CSA HEADER".to_owned()]),
+            ),
+            (0x0029_1010, &vr::OB, RawValue::Bytes(vec![1, 2])),
+            (0x0029_1110, &vr::OB, RawValue::Bytes(vec![3, 4])),
+        ],
+    );
+    let obj = dcmroot.as_obj();
+    assert_eq!(
+        Some(0x0029_0011),
+        private_creator_tag(obj, 0x0029, "SIEMENS CSA HEADER")
+    );
+    assert_eq!(
+        Some(0x0029_1120),
+        private_tag(obj, 0x0029, "SIEMENS CSA HEADER", 0x20)
+    );
+    assert_eq!(
+        Some([3u8, 4].as_slice()),
+        get_private(obj, 0x0029, "SIEMENS CSA HEADER", 0x10).map(|obj| obj.element().data())
+    );
+    assert_eq!(None, private_tag(obj, 0x0019, "SIEMENS CSA HEADER", 0x10));
+    assert_eq!(None, private_creator_tag(obj, 0x0029, "SIEMENS"));
+}
+
+/// Checks the Philips scale and diffusion attributes, encoded with their VR or as `UN`.
+#[test]
+fn test_philips() -> Result<(), VendorError> {
+    let creators = || {
+        vec![
+            (
+                0x2001_0010,
+                &vr::LO,
+                RawValue::Strings(vec!["Philips Imaging DD 001".to_owned()]),
+            ),
+            (
+                0x2005_0010,
+                &vr::LO,
+                RawValue::Strings(vec!["Philips MR Imaging DD 001".to_owned()]),
+            ),
+        ]
+    };
+    let mut explicit = creators();
+    explicit.extend([
+        (0x2001_1003, &vr::FL, RawValue::Floats(vec![800.0])),
+        (0x2005_100E, &vr::FL, RawValue::Floats(vec![0.5])),
+        (0x2005_10B0, &vr::FL, RawValue::Floats(vec![0.6])),
+        (0x2005_10B1, &vr::FL, RawValue::Floats(vec![0.0])),
+        (0x2005_10B2, &vr::FL, RawValue::Floats(vec![-0.8])),
+    ]);
+    let mut implicit = creators();
+    implicit.extend(
+        [
+            (0x2001_1003, 800.0f32),
+            (0x2005_100E, 0.5),
+            (0x2005_10B0, 0.6),
+            (0x2005_10B1, 0.0),
+            (0x2005_10B2, -0.8),
+        ]
+        .map(|(tag, value)| (tag, &vr::UN, RawValue::Bytes(value.to_le_bytes().to_vec()))),
+    );
+
+    for elements in [explicit, implicit] {
+        let dcmroot: DicomRoot = build_dataset(&ts::ExplicitVRLittleEndian, elements);
+        let scale: PhilipsScale = philips::scale(dcmroot.as_obj())?.expect("Scale should be set");
+        assert_eq!(
+            PhilipsScale {
+                slope: 0.5,
+                intercept: 0.0,
+            },
+            scale
+        );
+        assert_eq!(10.0, scale.floating_point(10.0, 2.0));
+        let diffusion: Option<Diffusion> = philips::diffusion(dcmroot.as_obj())?;
+        assert_eq!(Some(800.0), diffusion.map(|diffusion| diffusion.b_value));
+        let direction: [f64; 3] = diffusion
+            .and_then(|diffusion| diffusion.direction)
+            .expect("Direction should be set");
+        assert_eq!([0.6f32, 0.0, -0.8].map(f64::from), direction);
+    }
+
+    let dcmroot: DicomRoot = build_dataset(&ts::ExplicitVRLittleEndian, creators());
+    assert_eq!(None, philips::scale(dcmroot.as_obj())?);
+    assert_eq!(None, philips::diffusion(dcmroot.as_obj())?);
+    Ok(())
+}
+
+/// Checks the GE diffusion attributes, including a b-value with the offset some versions add.
+#[test]
+fn test_ge() -> Result<(), VendorError> {
+    let dcmroot: DicomRoot = build_dataset(
+        &ts::ExplicitVRLittleEndian,
+        vec![
+            (
+                0x0019_0010,
+                &vr::LO,
+                RawValue::Strings(vec!["GEMS_ACQU_01".to_owned()]),
+            ),
+            (0x0019_10BB, &vr::DS, RawValue::Doubles(vec![0.6])),
+            (0x0019_10BC, &vr::UN, RawValue::Bytes(b"-0.8".to_vec())),
+            (0x0019_10BD, &vr::DS, RawValue::Doubles(vec![0.0])),
+            (
+                0x0043_0010,
+                &vr::LO,
+                RawValue::Strings(vec!["GEMS_PARM_01".to_owned()]),
+            ),
+            (
+                0x0043_1039,
+                &vr::UN,
+                RawValue::Bytes(b"1000001000\\8\\0\\0 ".to_vec()),
+            ),
+        ],
+    );
+    assert_eq!(
+        Some(Diffusion {
+            b_value: 1000.0,
+            direction: Some([0.6, -0.8, 0.0]),
+        }),
+        ge::diffusion(dcmroot.as_obj())?
+    );
+    Ok(())
+}
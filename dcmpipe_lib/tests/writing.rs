@@ -1,21 +1,139 @@
-use std::{fs::File, io::Read, path::PathBuf};
+// These tests predate some of the current clippy lints.
+#![allow(clippy::needless_bool, clippy::ptr_arg, clippy::vec_init_then_push)]
+
+use std::{
+    fs::{self, File},
+    io::{Cursor, Read},
+    path::PathBuf,
+    sync::mpsc,
+    thread,
+};
 
 use dcmpipe_lib::{
     core::{
+        bulkdata::{BulkDataRef, BulkDataSource},
         charset,
         dcmelement::DicomElement,
         dcmobject::DicomRoot,
+        defn::ts::TSRef,
         defn::{tag::TagPath, vl::ValueLength, vr},
+        padding::{PaddingIssue, PaddingPolicy},
         read::{Parser, ParserBuilder},
         values::{Attribute, RawValue},
-        write::{builder::WriterBuilder, error::WriteError, writer::Writer},
+        write::{
+            builder::WriterBuilder,
+            error::WriteError,
+            grouplength::GroupLengthPolicy,
+            transcode::transcode,
+            writer::{Writer, WriterState},
+        },
     },
     dict::{stdlookup::STANDARD_DICOM_DICTIONARY, tags, transfer_syntaxes as ts, uids},
+    testkit::Fixture,
 };
 
 mod common;
 
-use common::{fixture, get_dicom_file_paths, mockdata};
+use common::{fixture, get_dicom_file_paths, mockdata, transcode_bytes};
+
+/// Writes the elements of each fixture as they're sent from another thread, which should produce
+/// the same bytes as writing them all at once.
+#[test]
+fn test_write_stream() -> Result<(), WriteError> {
+    for fixture in Fixture::ALL {
+        let elements: Vec<DicomElement<'static>> = fixture.elements()?;
+        let (sender, receiver) = mpsc::sync_channel::<DicomElement<'static>>(1);
+        let producer = thread::spawn(move || {
+            for element in elements {
+                sender
+                    .send(element)
+                    .expect("Writer should receive elements");
+            }
+        });
+
+        let mut writer: Writer<Vec<u8>> =
+            WriterBuilder::for_file().ts(fixture.ts()).build(Vec::new());
+        writer.write_stream(receiver)?;
+        producer.join().expect("Producer should finish");
+
+        assert_eq!(
+            fixture.to_bytes()?,
+            writer.into_dataset()?,
+            "{}",
+            fixture.name()
+        );
+    }
+    Ok(())
+}
+
+/// Writes a value in chunks, which should produce the same bytes as writing the whole element, and
+/// an encapsulated value as fragments.
+#[test]
+fn test_write_value_chunks() -> Result<(), WriteError> {
+    let pixels: Vec<u8> = (0..=255u8).cycle().take(1000).collect();
+    let mut writer: Writer<Vec<u8>> = WriterBuilder::default()
+        .state(WriterState::Element)
+        .ts(&ts::ExplicitVRLittleEndian)
+        .build(Vec::new());
+    let rows: DicomElement =
+        writer.create_element(&tags::Rows, &vr::US, RawValue::UnsignedShorts(vec![10]))?;
+    let pixel_data: DicomElement =
+        writer.create_element(&tags::PixelData, &vr::OW, RawValue::Bytes(pixels.clone()))?;
+    writer.write_elements([&rows, &pixel_data].into_iter())?;
+    let expected: Vec<u8> = writer.into_dataset()?;
+
+    let mut writer: Writer<Vec<u8>> = WriterBuilder::default()
+        .state(WriterState::Element)
+        .ts(&ts::ExplicitVRLittleEndian)
+        .build(Vec::new());
+    writer.write_elements([&rows].into_iter())?;
+    writer.begin_value(&tags::PixelData, &vr::OW, ValueLength::Explicit(1000))?;
+    for chunk in pixels.chunks(300) {
+        writer.write_value_chunk(chunk)?;
+    }
+    assert!(matches!(
+        writer.write_value_chunk(&[0, 0]),
+        Err(WriteError::ChunkedValue { .. })
+    ));
+    writer.end_value()?;
+    assert_eq!(expected, writer.into_dataset()?);
+
+    let mut writer: Writer<Vec<u8>> = WriterBuilder::default()
+        .state(WriterState::Element)
+        .ts(&ts::ExplicitVRLittleEndian)
+        .build(Vec::new());
+    writer.begin_value(&tags::PixelData, &vr::OB, ValueLength::UndefinedLength)?;
+    assert!(matches!(
+        writer.write_value_chunk(&[0, 0]),
+        Err(WriteError::ChunkedValue { .. })
+    ));
+    writer.begin_fragment(0)?;
+    writer.end_value()?;
+    writer.begin_fragment(4)?;
+    writer.write_value_chunk(&[1, 2])?;
+    assert!(matches!(
+        writer.end_value(),
+        Err(WriteError::ChunkedValue { .. })
+    ));
+    assert!(matches!(
+        writer.write_elements([&rows].into_iter()),
+        Err(WriteError::ChunkedValue { .. })
+    ));
+    writer.write_value_chunk(&[3, 4])?;
+    writer.end_value()?;
+    writer.end_value()?;
+    assert_eq!(
+        vec![
+            0xE0, 0x7F, 0x10, 0x00, b'O', b'B', 0x00, 0x00, 0xFF, 0xFF, 0xFF,
+            0xFF, // Pixel Data
+            0xFE, 0xFF, 0x00, 0xE0, 0x00, 0x00, 0x00, 0x00, // empty offset table
+            0xFE, 0xFF, 0x00, 0xE0, 0x04, 0x00, 0x00, 0x00, 1, 2, 3, 4, // fragment
+            0xFE, 0xFF, 0xDD, 0xE0, 0x00, 0x00, 0x00, 0x00, // sequence delimitation
+        ],
+        writer.into_dataset()?
+    );
+    Ok(())
+}
 
 /// This builds up an in-memory dicom dataset that when written out will result in the same bytes
 /// as `mockdata::STANDARD_HEADER`.
@@ -273,6 +391,168 @@ pub fn test_write_double() -> Result<(), WriteError> {
     Ok(())
 }
 
+/// Encodes the value into a new element and parses it back from the encoded bytes.
+fn reencode(
+    tag: u32,
+    vr: vr::VRRef,
+    ts: TSRef,
+    value: RawValue,
+) -> Result<(Vec<u8>, RawValue), WriteError> {
+    let mut elem = DicomElement::new_empty(tag, vr, ts);
+    elem.encode_value(value, None)?;
+    let raw_data: Vec<u8> = elem.data().to_vec();
+
+    let elem = DicomElement::new(
+        tag,
+        vr,
+        ValueLength::Explicit(raw_data.len() as u32),
+        ts,
+        charset::DEFAULT_CHARACTER_SET,
+        raw_data.clone(),
+        Vec::with_capacity(0),
+    );
+    Ok((raw_data, elem.parse_value()?))
+}
+
+#[test]
+pub fn test_write_64bit_and_other_vrs() -> Result<(), WriteError> {
+    let doubles: Vec<f64> = vec![1.5, -0.0, f64::MAX, f64::INFINITY, f64::NAN];
+    let longs: Vec<i64> = vec![i64::MIN, -1, i64::MAX];
+    let ulongs: Vec<u64> = vec![0, 1 << 40, u64::MAX];
+    let dwords: Vec<u32> = vec![0, 1, u32::MAX];
+
+    for ts in [&ts::ExplicitVRLittleEndian, &ts::ExplicitVRBigEndian] {
+        match reencode(
+            tags::SelectorODValue.tag,
+            &vr::OD,
+            ts,
+            RawValue::Doubles(doubles.clone()),
+        )? {
+            (raw_data, RawValue::Doubles(parsed)) => {
+                assert_eq!(doubles.len() * 8, raw_data.len());
+                // Compare bits so that NaN is considered equal to itself.
+                let expected: Vec<u64> = doubles.iter().map(|d| d.to_bits()).collect();
+                let actual: Vec<u64> = parsed.iter().map(|d| d.to_bits()).collect();
+                assert_eq!(expected, actual, "mismatch OD in {:?}", ts.uid().ident());
+            }
+            other => panic!("Parsed value was not doubles. Actually: {:?}", other),
+        }
+
+        match reencode(
+            tags::SelectorSVValue.tag,
+            &vr::SV,
+            ts,
+            RawValue::Longs(longs.clone()),
+        )? {
+            (_, RawValue::Longs(parsed)) => assert_eq!(longs, parsed),
+            other => panic!("Parsed value was not longs. Actually: {:?}", other),
+        }
+
+        match reencode(
+            tags::SelectorUVValue.tag,
+            &vr::UV,
+            ts,
+            RawValue::UnsignedLongs(ulongs.clone()),
+        )? {
+            (_, RawValue::UnsignedLongs(parsed)) => assert_eq!(ulongs, parsed),
+            other => panic!("Parsed value was not unsigned longs. Actually: {:?}", other),
+        }
+
+        match reencode(
+            tags::SelectorOVValue.tag,
+            &vr::OV,
+            ts,
+            RawValue::QuadWords(ulongs.clone()),
+        )? {
+            (raw_data, RawValue::QuadWords(parsed)) => {
+                let first: [u8; 8] = if ts.big_endian() {
+                    (1u64 << 40).to_be_bytes()
+                } else {
+                    (1u64 << 40).to_le_bytes()
+                };
+                assert_eq!(&first, &raw_data[8..16]);
+                assert_eq!(ulongs, parsed);
+            }
+            other => panic!("Parsed value was not quad words. Actually: {:?}", other),
+        }
+
+        match reencode(
+            tags::SelectorOLValue.tag,
+            &vr::OL,
+            ts,
+            RawValue::DoubleWords(dwords.clone()),
+        )? {
+            (_, RawValue::DoubleWords(parsed)) => assert_eq!(dwords, parsed),
+            other => panic!("Parsed value was not double words. Actually: {:?}", other),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+pub fn test_write_long_text_vrs() -> Result<(), WriteError> {
+    let ts: TSRef = &ts::ExplicitVRLittleEndian;
+    let strings =
+        |values: &[&str]| RawValue::Strings(values.iter().map(|s| s.to_string()).collect());
+
+    // UT is never multi-valued, so backslashes are part of the text. Only trailing spaces are
+    // padding.
+    match reencode(tags::TextValue.tag, &vr::UT, ts, strings(&["  a\\b  "]))? {
+        (_, RawValue::Strings(parsed)) => assert_eq!(vec!["  a\\b".to_string()], parsed),
+        other => panic!("Parsed value was not strings. Actually: {:?}", other),
+    }
+    assert!(reencode(tags::TextValue.tag, &vr::UT, ts, strings(&["a", "b"])).is_err());
+
+    // Each value of UC may be padded with trailing spaces.
+    match reencode(
+        tags::LongCodeValue.tag,
+        &vr::UC,
+        ts,
+        strings(&["a  ", " bc"]),
+    )? {
+        (raw_data, RawValue::Strings(parsed)) => {
+            assert_eq!(b"a  \\ bc ".to_vec(), raw_data);
+            assert_eq!(vec!["a".to_string(), " bc".to_string()], parsed);
+        }
+        other => panic!("Parsed value was not strings. Actually: {:?}", other),
+    }
+    assert!(reencode(tags::LongCodeValue.tag, &vr::UC, ts, strings(&["a\\b"])).is_err());
+
+    // UR is padded with trailing spaces, and must only contain characters valid in a URI.
+    let uri: &str = "http://example.com/a%20b?x=12";
+    match reencode(tags::CodingSchemeURL.tag, &vr::UR, ts, strings(&[uri]))? {
+        (raw_data, RawValue::Strings(parsed)) => {
+            assert_eq!(Some(&b' '), raw_data.last());
+            assert_eq!(vec![uri.to_string()], parsed);
+        }
+        other => panic!("Parsed value was not strings. Actually: {:?}", other),
+    }
+    for invalid in [" http://example.com", "http://a b", "a\\b", "%2", "a^b"] {
+        assert!(
+            reencode(tags::CodingSchemeURL.tag, &vr::UR, ts, strings(&[invalid])).is_err(),
+            "{invalid} should be invalid"
+        );
+    }
+
+    // A UR value is never split into multiple values, even if it contains a backslash.
+    let elem = DicomElement::new(
+        &tags::CodingSchemeURL,
+        &vr::UR,
+        ValueLength::Explicit(4),
+        ts,
+        charset::DEFAULT_CHARACTER_SET,
+        b" a\\b".to_vec(),
+        Vec::with_capacity(0),
+    );
+    match elem.parse_value()? {
+        RawValue::Strings(parsed) => assert_eq!(vec![" a\\b".to_string()], parsed),
+        other => panic!("Parsed value was not strings. Actually: {:?}", other),
+    }
+
+    Ok(())
+}
+
 #[test]
 #[ignore]
 pub fn test_reencoded_values_all_files() -> Result<(), WriteError> {
@@ -305,7 +585,7 @@ fn reencode_file_elements(path: PathBuf) -> Result<(), WriteError> {
 }
 
 fn assert_reencode_element(path_str: &str, elem: &mut DicomElement) -> Result<(), WriteError> {
-    let orig_parsed_data = elem.data().clone();
+    let orig_parsed_data = elem.data().to_vec();
     let value = elem.parse_value();
     if let Err(e) = value {
         eprintln!("Parsing error in file.\n\tfile: {path_str}\n\terr: {e:?}");
@@ -313,7 +593,7 @@ fn assert_reencode_element(path_str: &str, elem: &mut DicomElement) -> Result<()
     }
     let value = value?;
     elem.encode_value(value.clone(), Some(elem.vl()))?;
-    let reencoded_data = elem.data().clone();
+    let reencoded_data = elem.data();
 
     if orig_parsed_data == reencoded_data {
         return Ok(());
@@ -333,7 +613,7 @@ fn assert_reencode_element(path_str: &str, elem: &mut DicomElement) -> Result<()
     // If strings consist of only the padding character then ignore size differences.
     if elem.vr().is_character_string {
         // Some character-based elements seem to include trailing null-byte padding.
-        let trimmer = |v: &Vec<u8>| {
+        let trimmer = |v: &[u8]| {
             v.iter()
                 .rev()
                 .map(|b| b.to_owned())
@@ -347,7 +627,7 @@ fn assert_reencode_element(path_str: &str, elem: &mut DicomElement) -> Result<()
         };
 
         let orig_end_trimmed = trimmer(&orig_parsed_data);
-        let reencoded_end_trimmed = trimmer(&reencoded_data);
+        let reencoded_end_trimmed = trimmer(reencoded_data);
 
         if orig_end_trimmed == reencoded_end_trimmed {
             return Ok(());
@@ -423,3 +703,451 @@ fn assert_reencode_element(path_str: &str, elem: &mut DicomElement) -> Result<()
 
     Ok(())
 }
+
+#[test]
+pub fn test_transcode_roundtrip() -> Result<(), WriteError> {
+    for fixture in [Fixture::UndefinedLengthSequence, Fixture::NestedUnSequence] {
+        let original: Vec<u8> = fixture.to_bytes()?;
+        for to in [
+            &ts::ImplicitVRLittleEndian,
+            &ts::ExplicitVRBigEndian,
+            &ts::DeflatedExplicitVRLittleEndian,
+        ] {
+            let transcoded: Vec<u8> = transcode_bytes(original.clone(), to)?;
+            assert_ne!(original, transcoded);
+
+            let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+                .dictionary(&STANDARD_DICOM_DICTIONARY)
+                .build(Cursor::new(transcoded.clone()));
+            let dcmroot: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Should parse");
+            assert_eq!(to, dcmroot.ts());
+
+            // Transcoding back should produce the original encoding exactly.
+            let roundtrip: Vec<u8> = transcode_bytes(transcoded, fixture.ts())?;
+            assert_eq!(
+                original,
+                roundtrip,
+                "{} via {:?}",
+                fixture.name(),
+                to.uid().ident()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// The bytes following the FileMeta group of a DICOM file, found by its group length.
+fn dataset_body(bytes: &[u8]) -> &[u8] {
+    // The 128-byte preamble and prefix, followed by the FileMetaInformationGroupLength element.
+    let group_length_end: usize = 132 + 12;
+    let group_length: [u8; 4] = bytes[group_length_end - 4..group_length_end]
+        .try_into()
+        .expect("Should have a group length");
+    &bytes[group_length_end + u32::from_le_bytes(group_length) as usize..]
+}
+
+/// Everything after the FileMeta group of a deflated dataset is deflated, including the tag, VR,
+/// and value length of each element, and the deflate stream is finished.
+#[test]
+pub fn test_write_deflated() -> Result<(), WriteError> {
+    for fixture in Fixture::ALL {
+        let original: Vec<u8> = fixture.to_bytes()?;
+        let explicit: Vec<u8> = transcode_bytes(original.clone(), &ts::ExplicitVRLittleEndian)?;
+        let deflated: Vec<u8> = transcode_bytes(original, &ts::DeflatedExplicitVRLittleEndian)?;
+
+        let mut inflated: Vec<u8> = Vec::new();
+        libflate::deflate::Decoder::new(dataset_body(&deflated)).read_to_end(&mut inflated)?;
+        assert_eq!(dataset_body(&explicit), inflated, "{}", fixture.name());
+    }
+    Ok(())
+}
+
+#[test]
+pub fn test_encoded_len() -> Result<(), WriteError> {
+    for fixture in Fixture::ALL {
+        let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+            .dictionary(&STANDARD_DICOM_DICTIONARY)
+            .build(Cursor::new(fixture.to_bytes()?));
+        let dcmroot: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Should parse");
+        for to in [
+            &ts::ImplicitVRLittleEndian,
+            &ts::ExplicitVRLittleEndian,
+            &ts::ExplicitVRBigEndian,
+        ] {
+            let transcoded: Vec<u8> = transcode_bytes(fixture.to_bytes()?, to)?;
+            // The file preamble and DICM prefix aren't part of the dataset.
+            assert_eq!(
+                transcoded.len() as u64 - 132,
+                dcmroot.encoded_len(to),
+                "{} to {:?}",
+                fixture.name(),
+                to.uid().ident()
+            );
+
+            // The offset of each element follows from the lengths of those preceding it, checked
+            // against where the value of each element was parsed from.
+            let mut parser: Parser<'_, Cursor<Vec<u8>>> = ParserBuilder::default()
+                .dictionary(&STANDARD_DICOM_DICTIONARY)
+                .build(Cursor::new(transcoded));
+            let written: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Should parse");
+            let body_len: u64 = dcmroot
+                .iter_child_nodes()
+                .filter(|(tag, _child)| **tag > 0x0002_FFFF)
+                .map(|(_tag, child)| child.encoded_len(to))
+                .sum();
+            let mut offset: u64 = 132 + dcmroot.encoded_len(to) - body_len;
+            for (tag, child) in dcmroot
+                .iter_child_nodes()
+                .filter(|(tag, _)| **tag > 0x0002_FFFF)
+            {
+                if !child.element().is_seq_like() {
+                    let value_offset: Option<u64> = written
+                        .get_child_by_tag(*tag)
+                        .and_then(|written_child| written_child.element().value_offset());
+                    let header_len: u64 =
+                        child.encoded_len(to) - child.element().value_len() as u64;
+                    assert_eq!(Some(offset + header_len), value_offset);
+                }
+                offset += child.encoded_len(to);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+pub fn test_transcode_encapsulated_unsupported() -> Result<(), WriteError> {
+    let dcmroot: DicomRoot<'_> = DicomRoot::new(
+        &ts::RLELossless,
+        charset::DEFAULT_CHARACTER_SET,
+        &STANDARD_DICOM_DICTIONARY,
+        Default::default(),
+        Vec::new(),
+    );
+    assert!(matches!(
+        transcode(&dcmroot, &ts::ExplicitVRLittleEndian),
+        Err(WriteError::UnsupportedTranscode { .. })
+    ));
+    Ok(())
+}
+
+/// Serves the bytes of a single URI, in place of a DICOMweb bulkdata endpoint.
+#[derive(Debug)]
+struct UriSource(&'static str, Vec<u8>);
+
+impl BulkDataSource for UriSource {
+    fn open(&self, bulk_data: &BulkDataRef) -> std::io::Result<Box<dyn Read + '_>> {
+        match bulk_data {
+            BulkDataRef::Uri { uri, .. } if uri == self.0 => Ok(Box::new(self.1.as_slice())),
+            _ => Err(std::io::ErrorKind::NotFound.into()),
+        }
+    }
+}
+
+#[test]
+pub fn test_write_bulk_data_from_file() -> Result<(), WriteError> {
+    for fixture in [
+        Fixture::UndefinedLengthSequence,
+        Fixture::MultiCharsetPersonName,
+    ] {
+        let original: Vec<u8> = fixture.to_bytes()?;
+        let path: PathBuf =
+            std::env::temp_dir().join(format!("dcmpipe_bulkdata_{}.dcm", fixture.name()));
+        fs::write(&path, &original)?;
+
+        let mut parser: Parser<'_, File> = ParserBuilder::default()
+            .dictionary(&STANDARD_DICOM_DICTIONARY)
+            .defer_values(0)
+            .build(File::open(&path)?);
+        let mut dcmroot: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Should parse");
+        dcmroot.reference_deferred(&path);
+
+        let elements = dcmroot.flatten()?;
+        assert!(elements.iter().any(|e| e.bulk_data().is_some()));
+        for element in elements.iter().filter(|e| e.bulk_data().is_some()) {
+            assert!(matches!(element.parse_value()?, RawValue::BulkData(_)));
+        }
+
+        let mut writer: Writer<Vec<u8>> =
+            WriterBuilder::for_file().ts(dcmroot.ts()).build(Vec::new());
+        let written = writer.write_dcmroot(&dcmroot);
+        fs::remove_file(&path)?;
+        written?;
+        assert_eq!(original, writer.into_dataset()?, "{}", fixture.name());
+    }
+    Ok(())
+}
+
+#[test]
+pub fn test_write_bulk_data_from_uri() -> Result<(), WriteError> {
+    let uri: &str = "https://example.com/studies/1.2.3/bulkdata/7fe00010";
+    let bulk_data: BulkDataRef = BulkDataRef::Uri {
+        uri: uri.to_owned(),
+        length: 5,
+    };
+    let mut dcmroot: DicomRoot<'_> = DicomRoot::parse(
+        &mut ParserBuilder::default()
+            .dictionary(&STANDARD_DICOM_DICTIONARY)
+            .build(Cursor::new(Fixture::UndefinedLengthSequence.to_bytes()?)),
+    )?
+    .expect("Should parse");
+    let mut pixel_data: DicomElement =
+        DicomElement::new_empty(&tags::PixelData, &vr::OB, dcmroot.ts());
+    pixel_data.encode_value(RawValue::BulkData(bulk_data.clone()), None)?;
+    assert_eq!(ValueLength::Explicit(6), pixel_data.vl());
+    assert_eq!(Some(&bulk_data), pixel_data.bulk_data());
+    dcmroot.insert_element(&TagPath::from(&tags::PixelData), pixel_data, "test")?;
+
+    // The default source only reads files.
+    let mut writer: Writer<Vec<u8>> = WriterBuilder::for_file().ts(dcmroot.ts()).build(Vec::new());
+    assert!(matches!(
+        writer.write_dcmroot(&dcmroot),
+        Err(WriteError::BulkDataError { .. })
+    ));
+
+    let mut writer: Writer<Vec<u8>> = WriterBuilder::for_file()
+        .ts(dcmroot.ts())
+        .bulk_data_source(UriSource(uri, vec![1, 2, 3, 4, 5]))
+        .build(Vec::new());
+    writer.write_dcmroot(&dcmroot)?;
+    let written: DicomRoot<'_> = DicomRoot::parse(
+        &mut ParserBuilder::default()
+            .dictionary(&STANDARD_DICOM_DICTIONARY)
+            .build(Cursor::new(writer.into_dataset()?)),
+    )?
+    .expect("Should parse");
+    let pixel_data: &DicomElement = written
+        .get_child_by_tag(tags::PixelData.tag)
+        .expect("PixelData should be written")
+        .element();
+    assert_eq!(&[1, 2, 3, 4, 5, 0], pixel_data.data());
+
+    // Too few bytes are available for the length referenced.
+    let mut writer: Writer<Vec<u8>> = WriterBuilder::for_file()
+        .ts(dcmroot.ts())
+        .bulk_data_source(UriSource(uri, vec![1, 2, 3]))
+        .build(Vec::new());
+    assert!(matches!(
+        writer.write_dcmroot(&dcmroot),
+        Err(WriteError::BulkDataError { .. })
+    ));
+
+    // Bulk data of OB needs no byte-swapping so can be transcoded.
+    let elements: Vec<DicomElement> = transcode(&dcmroot, &ts::ExplicitVRBigEndian)?;
+    let transcoded = elements
+        .iter()
+        .find(|e| e.tag() == tags::PixelData.tag)
+        .expect("PixelData should be transcoded");
+    assert_eq!(Some(&bulk_data), transcoded.bulk_data());
+    Ok(())
+}
+
+/// Parses and writes the dataset preserving its encoding.
+fn preserve_bytes(bytes: &[u8], min_deferred_len: Option<u32>) -> Result<Vec<u8>, WriteError> {
+    let mut parser_builder: ParserBuilder<'_> =
+        ParserBuilder::default().dictionary(&STANDARD_DICOM_DICTIONARY);
+    if let Some(min_len) = min_deferred_len {
+        parser_builder = parser_builder.defer_values(min_len);
+    }
+    let mut parser: Parser<'_, Cursor<&[u8]>> = parser_builder.build(Cursor::new(bytes));
+    let mut dcmroot: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Should parse");
+    if min_deferred_len.is_some() {
+        dcmroot = dcmroot.resolve_deferred(bytes)?;
+    }
+
+    let writer_builder: WriterBuilder = match parser.file_preamble() {
+        Some(preamble) => WriterBuilder::for_file().file_preamble(*preamble),
+        None => WriterBuilder::default().state(WriterState::Element),
+    };
+    let mut writer: Writer<Vec<u8>> = writer_builder
+        .ts(dcmroot.ts())
+        .preserve_encoding(true)
+        .build(Vec::new());
+    writer.write_dcmroot(&dcmroot)?;
+    writer.into_dataset()
+}
+
+#[test]
+pub fn test_preserve_encoding_roundtrip() -> Result<(), WriteError> {
+    for fixture in [
+        Fixture::UndefinedLengthSequence,
+        Fixture::NestedUnSequence,
+        Fixture::MultiCharsetPersonName,
+    ] {
+        let mut original: Vec<u8> = fixture.to_bytes()?;
+        // A preamble with content, as some applications write.
+        original[..8].copy_from_slice(b"PREAMBLE");
+        assert_eq!(
+            original,
+            preserve_bytes(&original, None)?,
+            "{}",
+            fixture.name()
+        );
+    }
+
+    // Values of odd length, which the standard doesn't allow but some datasets have.
+    let original: Vec<u8> = explicit_vr_elements(&[
+        (tags::SOPInstanceUID.tag, b"UI", b"1.2"),
+        (tags::PatientsName.tag, b"PN", b"Last^First "),
+        (tags::PatientID.tag, b"LO", b"ODD"),
+        (0x0029_1010, b"OB", &[1, 2, 3]),
+    ]);
+    assert_eq!(original, preserve_bytes(&original, None)?);
+    assert_eq!(original, preserve_bytes(&original, Some(1))?);
+    Ok(())
+}
+
+/// Encodes the elements in Explicit VR Little Endian, with values as given rather than padded.
+fn explicit_vr_elements(elements: &[(u32, &[u8; 2], &[u8])]) -> Vec<u8> {
+    let mut bytes: Vec<u8> = Vec::new();
+    for (tag, vr, value) in elements {
+        bytes.extend_from_slice(&((tag >> 16) as u16).to_le_bytes());
+        bytes.extend_from_slice(&(*tag as u16).to_le_bytes());
+        bytes.extend_from_slice(*vr);
+        if *vr == b"OB" || *vr == b"SQ" {
+            bytes.extend_from_slice(&[0, 0]);
+            bytes.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        } else {
+            bytes.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        }
+        bytes.extend_from_slice(value);
+    }
+    bytes
+}
+
+/// Encodes an item of explicit length with the given contents.
+fn explicit_length_item(contents: &[u8]) -> Vec<u8> {
+    let mut bytes: Vec<u8> = vec![0xFE, 0xFF, 0x00, 0xE0];
+    bytes.extend_from_slice(&(contents.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(contents);
+    bytes
+}
+
+/// Parses the Explicit VR Little Endian elements and writes them with the given builder.
+fn rewrite(bytes: &[u8], writer_builder: WriterBuilder) -> Result<Vec<u8>, WriteError> {
+    let mut parser: Parser<'_, Cursor<&[u8]>> = ParserBuilder::default()
+        .dictionary(&STANDARD_DICOM_DICTIONARY)
+        .build(Cursor::new(bytes));
+    let dcmroot: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Should parse");
+    let mut writer: Writer<Vec<u8>> = writer_builder
+        .state(WriterState::Element)
+        .ts(&ts::ExplicitVRLittleEndian)
+        .build(Vec::new());
+    writer.write_dcmroot(&dcmroot)?;
+    writer.into_dataset()
+}
+
+/// Parses the elements and writes them with the given `PaddingPolicy`.
+fn write_with_padding(bytes: &[u8], padding: PaddingPolicy) -> Result<Vec<u8>, WriteError> {
+    rewrite(bytes, WriterBuilder::default().padding(padding))
+}
+
+#[test]
+pub fn test_write_padding_policy() -> Result<(), WriteError> {
+    let original: Vec<u8> = explicit_vr_elements(&[
+        (tags::SOPInstanceUID.tag, b"UI", b"1.2.3\0\0\0"),
+        (tags::PatientID.tag, b"LO", b"ODD"),
+    ]);
+
+    // Values of odd length are written padded to even length, with their value length updated.
+    assert_eq!(
+        explicit_vr_elements(&[
+            (tags::SOPInstanceUID.tag, b"UI", b"1.2.3\0\0\0"),
+            (tags::PatientID.tag, b"LO", b"ODD\0"),
+        ]),
+        write_with_padding(&original, PaddingPolicy::Preserve)?
+    );
+    assert_eq!(
+        explicit_vr_elements(&[
+            (tags::SOPInstanceUID.tag, b"UI", b"1.2.3\0"),
+            (tags::PatientID.tag, b"LO", b"ODD "),
+        ]),
+        write_with_padding(&original, PaddingPolicy::Normalize)?
+    );
+    assert!(matches!(
+        write_with_padding(&original, PaddingPolicy::Error),
+        Err(WriteError::InvalidPadding {
+            issue: PaddingIssue::ExtraNulls,
+            ..
+        })
+    ));
+    Ok(())
+}
+
+/// Encodes a dataset with Group Length elements whose values are wrong, including within an item.
+fn group_length_dataset(group_length: Option<u32>) -> Vec<u8> {
+    let group_length = |group: u16| match group_length {
+        Some(length) => vec![(u32::from(group) << 16, b"UL", length.to_le_bytes().to_vec())],
+        None => Vec::new(),
+    };
+    let encode = |elements: Vec<(u32, &[u8; 2], Vec<u8>)>| {
+        let elements: Vec<(u32, &[u8; 2], &[u8])> = elements
+            .iter()
+            .map(|(tag, vr, value)| (*tag, *vr, value.as_slice()))
+            .collect();
+        explicit_vr_elements(&elements)
+    };
+
+    let item: Vec<u8> = explicit_length_item(&encode(
+        [
+            group_length(0x0008),
+            vec![(tags::ReferencedSOPInstanceUID.tag, b"UI", b"1.2\0".to_vec())],
+        ]
+        .concat(),
+    ));
+    encode(
+        [
+            group_length(0x0008),
+            vec![
+                (tags::SOPInstanceUID.tag, b"UI", b"1.2.3\0".to_vec()),
+                (tags::ReferencedImageSequence.tag, b"SQ", item),
+            ],
+            group_length(0x0010),
+            vec![(tags::PatientID.tag, b"LO", b"ID".to_vec())],
+        ]
+        .concat(),
+    )
+}
+
+#[test]
+pub fn test_write_group_length_policy() -> Result<(), WriteError> {
+    let original: Vec<u8> = group_length_dataset(Some(999));
+    assert_eq!(
+        original,
+        rewrite(
+            &original,
+            WriterBuilder::default().group_length(GroupLengthPolicy::Preserve)
+        )?
+    );
+
+    // The lengths of the sequence and item are reduced by the omitted Group Length.
+    assert_eq!(
+        group_length_dataset(None),
+        rewrite(
+            &original,
+            WriterBuilder::default().group_length(GroupLengthPolicy::Strip)
+        )?
+    );
+
+    let recomputed: Vec<u8> = rewrite(
+        &original,
+        WriterBuilder::default().group_length(GroupLengthPolicy::Recompute),
+    )?;
+    let mut parser: Parser<'_, Cursor<&[u8]>> =
+        ParserBuilder::default().build(Cursor::new(&recomputed));
+    let dcmroot: DicomRoot<'_> = DicomRoot::parse(&mut parser)?.expect("Should parse");
+    let group_length = |tagpath: &str| -> Result<Vec<u32>, WriteError> {
+        let tagpath: TagPath = TagPath::parse(tagpath, None)?;
+        let element: &DicomElement = dcmroot
+            .get_child_by_tagpath(&tagpath)
+            .expect("Should have group length")
+            .element();
+        Ok(Vec::<u32>::try_from(element)?)
+    };
+    // SOPInstanceUID is 14 bytes, and the sequence 12 bytes along with its 32 byte item.
+    assert_eq!(vec![58], group_length("00080000")?);
+    assert_eq!(vec![12], group_length("00081140[1].00080000")?);
+    assert_eq!(vec![10], group_length("00100000")?);
+    Ok(())
+}